@@ -1,46 +1,11 @@
 // Copyright 2024 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use fearless_simd::{Level, Select, Simd, SimdInto, f32x4, simd_dispatch};
+use fearless_simd::{Level, Select, Simd, SimdInto, f32x4, shuffle, simd_dispatch};
 
-// This block shows how to use safe wrappers for compile-time enforcement
-// of using valid SIMD intrinsics.
-#[cfg(feature = "safe_wrappers")]
 #[inline(always)]
 fn copy_alpha<S: Simd>(a: f32x4<S>, b: f32x4<S>) -> f32x4<S> {
-    #[cfg(target_arch = "x86_64")]
-    if let Some(avx2) = a.simd.level().as_avx2() {
-        return avx2
-            .sse4_1
-            ._mm_blend_ps::<8>(a.into(), b.into())
-            .simd_into(a.simd);
-    }
-    #[cfg(target_arch = "aarch64")]
-    if let Some(neon) = a.simd.level().as_neon() {
-        return neon
-            .neon
-            .vcopyq_laneq_f32::<3, 3>(a.into(), b.into())
-            .simd_into(a.simd);
-    }
-    let mut result = a;
-    result[3] = b[3];
-    result
-}
-
-// This block lets the example compile without safe wrappers.
-#[cfg(not(feature = "safe_wrappers"))]
-#[inline(always)]
-fn copy_alpha<S: Simd>(a: f32x4<S>, b: f32x4<S>) -> f32x4<S> {
-    #[cfg(target_arch = "aarch64")]
-    if let Some(_neon) = a.simd.level().as_neon() {
-        unsafe {
-            return core::arch::aarch64::vcopyq_laneq_f32::<3, 3>(a.into(), b.into())
-                .simd_into(a.simd);
-        }
-    }
-    let mut result = a;
-    result[3] = b[3];
-    result
+    shuffle!(a, b, [0, 1, 2, 7])
 }
 
 #[inline(always)]
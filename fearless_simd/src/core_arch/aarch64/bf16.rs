@@ -0,0 +1,95 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Access to bf16 intrinsics on aarch64.
+
+use core::arch::aarch64::*;
+
+/// A token for BF16 intrinsics on aarch64.
+///
+/// This is an optional extension of Armv8.2, so the levels which can use it
+/// detect it separately.
+#[derive(Clone, Copy, Debug)]
+pub struct Bf16 {
+    _private: (),
+}
+
+macro_rules! neon_bf16_unaryop {
+    ( $opfn:ident ( $ty:ty ) -> $ret:ty = $asm:literal ) => {
+        #[inline(always)]
+        pub fn $opfn(self, a: $ty) -> $ret {
+            #[target_feature(enable = "bf16")]
+            #[inline]
+            pub unsafe fn inner(a: $ty) -> $ret {
+                let result;
+                unsafe {
+                    core::arch::asm!(
+                        $asm,
+                        out(vreg) result,
+                        in(vreg) a,
+                        options(pure, nomem, nostack, preserves_flags)
+                    );
+                }
+                result
+            }
+            unsafe { inner(a) }
+        }
+    };
+}
+
+macro_rules! neon_bf16_ternary {
+    ( $opfn:ident ( $tya:ty, $tyb:ty, $tyc:ty ) -> $ret:ty = $asm:literal ) => {
+        #[inline(always)]
+        pub fn $opfn(self, a: $tya, b: $tyb, c: $tyc) -> $ret {
+            #[target_feature(enable = "bf16")]
+            #[inline]
+            pub unsafe fn inner(a: $tya, b: $tyb, c: $tyc) -> $ret {
+                let result;
+                unsafe {
+                    core::arch::asm!(
+                        $asm,
+                        inout(vreg) a => result,
+                        in(vreg) b,
+                        in(vreg) c,
+                        options(pure, nomem, nostack, preserves_flags)
+                    );
+                }
+                result
+            }
+            unsafe { inner(a, b, c) }
+        }
+    };
+}
+
+// When bf16 support lands in Rust, these types will be defined in core::arch::aarch64,
+// hopefully compatibly.
+pub type bfloat16x4_t = uint16x4_t;
+pub type bfloat16x8_t = uint16x8_t;
+
+impl Bf16 {
+    /// Create a SIMD token.
+    ///
+    /// # Safety:
+    ///
+    /// The required CPU features must be available.
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Self { _private: () }
+    }
+
+    /// Create a SIMD token if the CPU supports BF16.
+    ///
+    /// This always returns `None` without the `std` feature.
+    #[inline]
+    pub fn try_new() -> Option<Self> {
+        #[cfg(feature = "std")]
+        if std::arch::is_aarch64_feature_detected!("bf16") {
+            return Some(unsafe { Self::new_unchecked() });
+        }
+        None
+    }
+
+    neon_bf16_unaryop!(vcvt_bf16_f32(float32x4_t) -> bfloat16x4_t = "bfcvtn {0:v}.4h, {1:v}.4s");
+    neon_bf16_ternary!(vbfdotq_f32(float32x4_t, bfloat16x8_t, bfloat16x8_t) -> float32x4_t = "bfdot {0:v}.4s, {1:v}.8h, {2:v}.8h");
+    neon_bf16_ternary!(vbfmmlaq_f32(float32x4_t, bfloat16x8_t, bfloat16x8_t) -> float32x4_t = "bfmmla {0:v}.4s, {1:v}.8h, {2:v}.8h");
+}
@@ -14,12 +14,14 @@ pub struct Fp16 {
     neon: Neon,
 }
 
-#[cfg(feature = "safe_wrappers")]
 macro_rules! neon_f16_unaryop {
     ( $opfn:ident ( $ty:ty ) -> $ret:ty = $asm:literal ) => {
+        neon_f16_unaryop!($opfn($ty) -> $ret = $asm, "fp16");
+    };
+    ( $opfn:ident ( $ty:ty ) -> $ret:ty = $asm:literal, $feature:literal ) => {
         #[inline(always)]
         pub fn $opfn(self, a: $ty) -> $ret {
-            #[target_feature(enable = "fp16")]
+            #[target_feature(enable = $feature)]
             #[inline]
             pub unsafe fn inner(a: $ty) -> $ret {
                 let result;
@@ -38,7 +40,6 @@ macro_rules! neon_f16_unaryop {
     };
 }
 
-#[cfg(feature = "safe_wrappers")]
 macro_rules! neon_f16_binop {
     ( $opfn:ident ( $tya:ty, $tyb:ty ) -> $ret:ty = $asm:literal ) => {
         #[inline(always)]
@@ -63,12 +64,14 @@ macro_rules! neon_f16_binop {
     };
 }
 
-#[cfg(feature = "safe_wrappers")]
 macro_rules! neon_f16_binop_inout {
     ( $opfn:ident ( $tya:ty, $tyb:ty ) -> $ret:ty = $asm:literal ) => {
+        neon_f16_binop_inout!($opfn($tya, $tyb) -> $ret = $asm, "fp16");
+    };
+    ( $opfn:ident ( $tya:ty, $tyb:ty ) -> $ret:ty = $asm:literal, $feature:literal ) => {
         #[inline(always)]
         pub fn $opfn(self, a: $tya, b: $tyb) -> $ret {
-            #[target_feature(enable = "fp16")]
+            #[target_feature(enable = $feature)]
             #[inline]
             pub unsafe fn inner(a: $tya, b: $tyb) -> $ret {
                 let result;
@@ -87,7 +90,6 @@ macro_rules! neon_f16_binop_inout {
     };
 }
 
-#[cfg(feature = "safe_wrappers")]
 macro_rules! neon_f16_ternary {
     ( $opfn:ident ( $tya:ty, $tyb:ty, $tyc:ty ) -> $ret:ty = $asm:literal ) => {
         #[inline(always)]
@@ -116,6 +118,7 @@ macro_rules! neon_f16_ternary {
 // hopefully compatibly.
 pub type float16x4_t = uint16x4_t;
 pub type float16x8_t = uint16x8_t;
+pub type float16x8x2_t = uint16x8x2_t;
 
 impl Fp16 {
     /// Create a SIMD token.
@@ -131,13 +134,22 @@ impl Fp16 {
     }
 }
 
-#[cfg(feature = "safe_wrappers")]
+// The conversions between f16 and f32 are part of the base Armv8 instruction set,
+// so they only need Neon.
+impl Neon {
+    neon_f16_unaryop!(vcvt_f32_f16(float16x4_t) -> float32x4_t = "fcvtl {0:v}.4s, {1:v}.4h", "neon");
+    neon_f16_unaryop!(vcvt_high_f32_f16(float16x8_t) -> float32x4_t = "fcvtl2 {0:v}.4s, {1:v}.8h", "neon");
+    neon_f16_unaryop!(vcvt_f16_f32(float32x4_t) -> float16x4_t = "fcvtn {0:v}.4h, {1:v}.4s", "neon");
+    // Only the low half of the first argument is kept.
+    neon_f16_binop_inout!(vcvt_high_f16_f32(float16x8_t, float32x4_t) -> float16x8_t = "fcvtn2 {0:v}.8h, {1:v}.4s", "neon");
+}
+
 use crate::f16;
 
-#[cfg(feature = "safe_wrappers")]
 impl Fp16 {
     // This is a somewhat curated set for now, but we should make it reasonably complete.
     neon_f16_unaryop!(vabs_f16(float16x4_t) -> float16x4_t = "fabs.4h {0:v}, {1:v}");
+    neon_f16_unaryop!(vneg_f16(float16x4_t) -> float16x4_t = "fneg.4h {0:v}, {1:v}");
     neon_f16_unaryop!(vrnd_f16(float16x4_t) -> float16x4_t = "frintz.4h {0:v}, {1:v}");
     neon_f16_unaryop!(vrnda_f16(float16x4_t) -> float16x4_t = "frinta.4h {0:v}, {1:v}");
     neon_f16_unaryop!(vrndm_f16(float16x4_t) -> float16x4_t = "frintm.4h {0:v}, {1:v}");
@@ -158,8 +170,11 @@ impl Fp16 {
     neon_f16_binop!(vcgt_f16(float16x4_t, float16x4_t) -> uint16x4_t = "fcmgt.4h {0:v}, {1:v}, {2:v}");
     neon_f16_binop!(vcge_f16(float16x4_t, float16x4_t) -> uint16x4_t = "fcmge.4h {0:v}, {1:v}, {2:v}");
     neon_f16_ternary!(vmla_f16(float16x4_t, float16x4_t, float16x4_t) -> float16x4_t = "fmla.4h {0:v}, {1:v}, {2:v}");
+    neon_f16_ternary!(vfma_f16(float16x4_t, float16x4_t, float16x4_t) -> float16x4_t = "fmla.4h {0:v}, {1:v}, {2:v}");
+    neon_f16_ternary!(vfms_f16(float16x4_t, float16x4_t, float16x4_t) -> float16x4_t = "fmls.4h {0:v}, {1:v}, {2:v}");
 
     neon_f16_unaryop!(vabsq_f16(float16x8_t) -> float16x8_t = "fabs.8h {0:v}, {1:v}");
+    neon_f16_unaryop!(vnegq_f16(float16x8_t) -> float16x8_t = "fneg.8h {0:v}, {1:v}");
     neon_f16_unaryop!(vrndq_f16(float16x8_t) -> float16x8_t = "frintz.8h {0:v}, {1:v}");
     neon_f16_unaryop!(vrndaq_f16(float16x8_t) -> float16x8_t = "frinta.8h {0:v}, {1:v}");
     neon_f16_unaryop!(vrndmq_f16(float16x8_t) -> float16x8_t = "frintm.8h {0:v}, {1:v}");
@@ -181,40 +196,46 @@ impl Fp16 {
     neon_f16_binop!(vcgeq_f16(float16x8_t, float16x8_t) -> uint16x8_t = "fcmge.8h {0:v}, {1:v}, {2:v}");
     neon_f16_unaryop!(vceqzq_f16(float16x8_t) -> uint16x8_t = "fcmeq.8h {0:v}, {1:v}, #0");
     neon_f16_ternary!(vmlaq_f16(float16x8_t, float16x8_t, float16x8_t) -> float16x8_t = "fmla.8h {0:v}, {1:v}, {2:v}");
+    neon_f16_ternary!(vfmaq_f16(float16x8_t, float16x8_t, float16x8_t) -> float16x8_t = "fmla.8h {0:v}, {1:v}, {2:v}");
+    neon_f16_ternary!(vfmsq_f16(float16x8_t, float16x8_t, float16x8_t) -> float16x8_t = "fmls.8h {0:v}, {1:v}, {2:v}");
 
-    neon_f16_unaryop!(vcvt_f32_f16(float16x4_t) -> float32x4_t = "fcvtl {0:v}.4s, {1:v}.4h");
-    neon_f16_unaryop!(vcvt_high_f32_f16(float16x8_t) -> float32x4_t = "fcvtl2 {0:v}.4s, {1:v}.8h");
-    neon_f16_unaryop!(vcvt_f16_f32(float32x4_t) -> float16x4_t = "fcvtn {0:v}.4h, {1:v}.4s");
-    neon_f16_binop_inout!(vcvt_high_f16_f32(float16x4_t, float32x4_t) -> float16x4_t = "fcvtn2 {0:v}.8h, {1:v}.4s");
     neon_f16_unaryop!(vcvtq_f16_u16(uint16x8_t) -> float16x8_t = "ucvtf.8h {0:v}, {1:v}");
     neon_f16_unaryop!(vcvtnq_u16_f16(float16x8_t) -> uint16x8_t = "fcvtnu.8h {0:v}, {1:v}");
 
     #[inline(always)]
     pub fn vbsl_f16(self, mask: uint16x4_t, a: float16x4_t, b: float16x4_t) -> float16x4_t {
-        self.vreinterpret_f16_u16(self.neon.vbsl_u16(
-            mask,
-            self.vreinterpret_u16_f16(a),
-            self.vreinterpret_u16_f16(b),
-        ))
+        // Safety: the token guarantees Neon support.
+        self.vreinterpret_f16_u16(unsafe {
+            vbsl_u16(
+                mask,
+                self.vreinterpret_u16_f16(a),
+                self.vreinterpret_u16_f16(b),
+            )
+        })
     }
 
     #[inline(always)]
     pub fn vbslq_f16(self, mask: uint16x8_t, a: float16x8_t, b: float16x8_t) -> float16x8_t {
-        self.vreinterpretq_f16_u16(self.neon.vbslq_u16(
-            mask,
-            self.vreinterpretq_u16_f16(a),
-            self.vreinterpretq_u16_f16(b),
-        ))
+        // Safety: the token guarantees Neon support.
+        self.vreinterpretq_f16_u16(unsafe {
+            vbslq_u16(
+                mask,
+                self.vreinterpretq_u16_f16(a),
+                self.vreinterpretq_u16_f16(b),
+            )
+        })
     }
 
     #[inline(always)]
     pub fn vdup_n_f16(self, value: f16) -> float16x4_t {
-        self.vreinterpret_f16_u16(self.neon.vdup_n_u16(value.to_bits()))
+        // Safety: the token guarantees Neon support.
+        self.vreinterpret_f16_u16(unsafe { vdup_n_u16(value.to_bits()) })
     }
 
     #[inline(always)]
     pub fn vdupq_n_f16(self, value: f16) -> float16x8_t {
-        self.vreinterpretq_f16_u16(self.neon.vdupq_n_u16(value.to_bits()))
+        // Safety: the token guarantees Neon support.
+        self.vreinterpretq_f16_u16(unsafe { vdupq_n_u16(value.to_bits()) })
     }
 
     #[inline(always)]
@@ -5,9 +5,11 @@
 
 // These implementations are cut and pasted from pulp.
 
+mod bf16;
 mod fp16;
 
-pub use fp16::{Fp16, float16x4_t, float16x8_t};
+pub use bf16::{Bf16, bfloat16x4_t, bfloat16x8_t};
+pub use fp16::{Fp16, float16x4_t, float16x8_t, float16x8x2_t};
 
 /// A token for Neon intrinsics on aarch64.
 #[derive(Clone, Copy, Debug)]
@@ -0,0 +1,206 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Access to AVX-512 intrinsics.
+
+use crate::impl_macros::delegate;
+use core::arch::x86_64::*;
+
+/// A token for AVX-512 intrinsics on x86_64.
+///
+/// This covers the F, BW, CD, DQ and VL subsets, which together make up the
+/// x86-64-v4 microarchitecture level.
+#[derive(Clone, Copy, Debug)]
+pub struct Avx512 {
+    _private: (),
+}
+
+impl Avx512 {
+    /// Create a SIMD token.
+    ///
+    /// # Safety
+    ///
+    /// The required CPU features must be available.
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Self { _private: () }
+    }
+
+    delegate! { core::arch::x86_64:
+        fn _mm512_abs_epi8(a: __m512i) -> __m512i;
+        fn _mm512_abs_epi16(a: __m512i) -> __m512i;
+        fn _mm512_abs_epi32(a: __m512i) -> __m512i;
+        fn _mm512_abs_ps(v2: __m512) -> __m512;
+        fn _mm512_add_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_add_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_add_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_add_epi64(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_add_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_adds_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_adds_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_adds_epu8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_adds_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_and_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_and_si512(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_andnot_si512(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_avg_epu8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_avg_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_castps_si512(a: __m512) -> __m512i;
+        fn _mm512_castps256_ps512(a: __m256) -> __m512;
+        fn _mm512_castps512_ps256(a: __m512) -> __m256;
+        fn _mm512_castsi256_si512(a: __m256i) -> __m512i;
+        fn _mm512_castsi512_ps(a: __m512i) -> __m512;
+        fn _mm512_castsi512_si256(a: __m512i) -> __m256i;
+        fn _mm512_cmp_ps_mask<const IMM8: i32>(a: __m512, b: __m512) -> __mmask16;
+        fn _mm512_cmpeq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmpeq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmpeq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmpge_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmpge_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmpge_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmpge_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmpge_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmpge_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmpgt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmpgt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmpgt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmpgt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmpgt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmpgt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmple_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmple_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmple_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmple_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmple_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmple_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmplt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmplt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmplt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cmplt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64;
+        fn _mm512_cmplt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32;
+        fn _mm512_cmplt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16;
+        fn _mm512_cvtepi8_epi16(a: __m256i) -> __m512i;
+        fn _mm512_cvtepi16_epi32(a: __m256i) -> __m512i;
+        fn _mm512_cvtepi16_epi8(a: __m512i) -> __m256i;
+        fn _mm512_cvtepi32_epi16(a: __m512i) -> __m256i;
+        fn _mm512_cvtepi32_ps(a: __m512i) -> __m512;
+        fn _mm512_cvtepu8_epi16(a: __m256i) -> __m512i;
+        fn _mm512_cvtepu16_epi32(a: __m256i) -> __m512i;
+        fn _mm512_cvtepu32_ps(a: __m512i) -> __m512;
+        fn _mm512_cvttps_epi32(a: __m512) -> __m512i;
+        fn _mm512_cvttps_epu32(a: __m512) -> __m512i;
+        fn _mm512_div_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_extractf32x8_ps<const IMM8: i32>(a: __m512) -> __m256;
+        fn _mm512_extracti64x4_epi64<const IMM1: i32>(a: __m512i) -> __m256i;
+        fn _mm512_fmadd_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        fn _mm512_fmsub_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        fn _mm512_fnmadd_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        fn _mm512_fnmsub_ps(a: __m512, b: __m512, c: __m512) -> __m512;
+        fn _mm512_insertf32x8<const IMM8: i32>(a: __m512, b: __m256) -> __m512;
+        fn _mm512_inserti64x4<const IMM8: i32>(a: __m512i, b: __m256i) -> __m512i;
+        unsafe fn _mm512_loadu_ps(mem_addr: *const f32) -> __m512;
+        unsafe fn _mm512_loadu_si512(mem_addr: *const __m512i) -> __m512i;
+        fn _mm512_mask_blend_epi8(k: __mmask64, a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mask_blend_epi16(k: __mmask32, a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mask_blend_epi32(k: __mmask16, a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mask_blend_ps(k: __mmask16, a: __m512, b: __m512) -> __m512;
+        fn _mm512_mask_mov_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i;
+        fn _mm512_maskz_mov_epi32(k: __mmask16, a: __m512i) -> __m512i;
+        fn _mm512_max_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_epu8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_epu32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_max_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_min_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_epu8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_epu32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_min_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_movepi8_mask(a: __m512i) -> __mmask64;
+        fn _mm512_movepi16_mask(a: __m512i) -> __mmask32;
+        fn _mm512_movepi32_mask(a: __m512i) -> __mmask16;
+        fn _mm512_movm_epi8(k: __mmask64) -> __m512i;
+        fn _mm512_movm_epi16(k: __mmask32) -> __m512i;
+        fn _mm512_movm_epi32(k: __mmask16) -> __m512i;
+        fn _mm512_mul_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_mulhi_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mulhi_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mullo_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_mullo_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_or_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_or_si512(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_packs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_packs_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_packus_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_packus_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_permutex2var_epi16(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_permutex2var_epi32(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_permutex2var_epi64(a: __m512i, idx: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_permutex2var_ps(a: __m512, idx: __m512i, b: __m512) -> __m512;
+        fn _mm512_permutexvar_epi32(idx: __m512i, a: __m512i) -> __m512i;
+        fn _mm512_permutexvar_ps(idx: __m512i, a: __m512) -> __m512;
+        fn _mm512_reduce_add_epi32(a: __m512i) -> i32;
+        fn _mm512_reduce_add_ps(a: __m512) -> f32;
+        fn _mm512_reduce_max_ps(a: __m512) -> f32;
+        fn _mm512_reduce_min_ps(a: __m512) -> f32;
+        fn _mm512_roundscale_ps<const IMM8: i32>(a: __m512) -> __m512;
+        fn _mm512_set1_epi8(a: i8) -> __m512i;
+        fn _mm512_set1_epi16(a: i16) -> __m512i;
+        fn _mm512_set1_epi32(a: i32) -> __m512i;
+        fn _mm512_set1_epi64(a: i64) -> __m512i;
+        fn _mm512_set1_ps(a: f32) -> __m512;
+        fn _mm512_setzero_ps() -> __m512;
+        fn _mm512_setzero_si512() -> __m512i;
+        fn _mm512_shuffle_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_shuffle_i64x2<const MASK: i32>(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_sll_epi16(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_sll_epi32(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_slli_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_slli_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_sllv_epi16(a: __m512i, count: __m512i) -> __m512i;
+        fn _mm512_sllv_epi32(a: __m512i, count: __m512i) -> __m512i;
+        fn _mm512_sqrt_ps(a: __m512) -> __m512;
+        fn _mm512_sra_epi16(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_sra_epi32(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_srai_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_srai_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_srav_epi16(a: __m512i, count: __m512i) -> __m512i;
+        fn _mm512_srav_epi32(a: __m512i, count: __m512i) -> __m512i;
+        fn _mm512_srl_epi16(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_srl_epi32(a: __m512i, count: __m128i) -> __m512i;
+        fn _mm512_srli_epi16<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_srli_epi32<const IMM8: u32>(a: __m512i) -> __m512i;
+        fn _mm512_srlv_epi16(a: __m512i, count: __m512i) -> __m512i;
+        fn _mm512_srlv_epi32(a: __m512i, count: __m512i) -> __m512i;
+        unsafe fn _mm512_storeu_ps(mem_addr: *mut f32, a: __m512);
+        unsafe fn _mm512_storeu_si512(mem_addr: *mut __m512i, a: __m512i);
+        fn _mm512_sub_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_sub_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_sub_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_sub_epi64(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_sub_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_subs_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_subs_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_subs_epu8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_subs_epu16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpackhi_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpackhi_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpackhi_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpackhi_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_unpacklo_epi8(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpacklo_epi16(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpacklo_epi32(a: __m512i, b: __m512i) -> __m512i;
+        fn _mm512_unpacklo_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512;
+        fn _mm512_xor_si512(a: __m512i, b: __m512i) -> __m512i;
+        fn _kand_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        fn _kor_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        fn _kxor_mask16(a: __mmask16, b: __mmask16) -> __mmask16;
+        fn _knot_mask16(a: __mmask16) -> __mmask16;
+    }
+}
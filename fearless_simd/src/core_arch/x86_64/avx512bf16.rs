@@ -0,0 +1,53 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Access to AVX-512 BF16 intrinsics.
+
+use crate::impl_macros::delegate;
+use core::arch::x86_64::*;
+
+/// A token for AVX-512 BF16 intrinsics on x86_64.
+///
+/// This is not part of any x86-64 microarchitecture level, so the levels
+/// which can use it detect it separately.
+#[derive(Clone, Copy, Debug)]
+pub struct Avx512Bf16 {
+    _private: (),
+}
+
+impl Avx512Bf16 {
+    /// Create a SIMD token.
+    ///
+    /// # Safety
+    ///
+    /// The required CPU features must be available.
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Self { _private: () }
+    }
+
+    /// Create a SIMD token if the CPU supports AVX-512 BF16.
+    ///
+    /// This always returns `None` without the `std` feature.
+    #[inline]
+    pub fn try_new() -> Option<Self> {
+        #[cfg(feature = "std")]
+        if std::arch::is_x86_feature_detected!("avx512bf16")
+            && std::arch::is_x86_feature_detected!("avx512vl")
+        {
+            return Some(unsafe { Self::new_unchecked() });
+        }
+        None
+    }
+
+    delegate! { core::arch::x86_64:
+        fn _mm_cvtne2ps_pbh(a: __m128, b: __m128) -> __m128bh;
+        fn _mm256_cvtne2ps_pbh(a: __m256, b: __m256) -> __m256bh;
+        fn _mm512_cvtne2ps_pbh(a: __m512, b: __m512) -> __m512bh;
+        fn _mm256_cvtneps_pbh(a: __m256) -> __m128bh;
+        fn _mm512_cvtneps_pbh(a: __m512) -> __m256bh;
+        fn _mm_dpbf16_ps(src: __m128, a: __m128bh, b: __m128bh) -> __m128;
+        fn _mm256_dpbf16_ps(src: __m256, a: __m256bh, b: __m256bh) -> __m256;
+        fn _mm512_dpbf16_ps(src: __m512, a: __m512bh, b: __m512bh) -> __m512;
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Access to F16C intrinsics.
+
+use crate::impl_macros::delegate;
+use core::arch::x86_64::*;
+
+/// A token for F16C intrinsics on x86_64.
+#[derive(Clone, Copy, Debug)]
+pub struct F16c {
+    _private: (),
+}
+
+impl F16c {
+    /// Create a SIMD token.
+    ///
+    /// # Safety
+    ///
+    /// The required CPU features must be available.
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Self { _private: () }
+    }
+
+    delegate! { core::arch::x86_64:
+        fn _mm_cvtph_ps(a: __m128i) -> __m128;
+        fn _mm256_cvtph_ps(a: __m128i) -> __m256;
+        fn _mm_cvtps_ph<const IMM_ROUNDING: i32>(a: __m128) -> __m128i;
+        fn _mm256_cvtps_ph<const IMM_ROUNDING: i32>(a: __m256) -> __m128i;
+    }
+}
@@ -5,6 +5,9 @@
 
 mod avx;
 mod avx2;
+mod avx512;
+mod avx512bf16;
+mod f16c;
 mod fma;
 mod sse;
 mod sse2;
@@ -15,6 +18,9 @@ mod ssse3;
 
 pub use avx::Avx;
 pub use avx2::Avx2;
+pub use avx512::Avx512;
+pub use avx512bf16::Avx512Bf16;
+pub use f16c::F16c;
 pub use fma::Fma;
 pub use sse::Sse;
 pub use sse2::Sse2;
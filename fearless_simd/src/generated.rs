@@ -5,19 +5,47 @@
 //!
 //! All files in this subdirectory are autogenerated by the `fearless_simd_gen` crate.
 
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod avx2;
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod avx512;
 mod fallback;
 #[cfg(all(feature = "std", target_arch = "aarch64"))]
+mod fp16;
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
 mod neon;
 mod ops;
 mod simd_trait;
 mod simd_types;
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod sse4_2;
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 mod wasm;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    target_feature = "relaxed-simd"
+))]
+mod wasm_relaxed_simd;
 
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub use avx2::*;
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub use avx512::*;
 pub use fallback::*;
 #[cfg(all(feature = "std", target_arch = "aarch64"))]
+pub use fp16::*;
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
 pub use neon::*;
 pub use simd_trait::*;
 pub use simd_types::*;
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub use sse4_2::*;
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 pub use wasm::*;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    target_feature = "relaxed-simd"
+))]
+pub use wasm_relaxed_simd::*;
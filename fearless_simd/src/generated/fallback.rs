@@ -1,18 +1,24 @@
 // This file is autogenerated by fearless_simd_gen
 
-use crate::{Level, Simd, SimdInto, seal::Seal};
+use crate::{Bytes, Indices, Level, Simd, SimdInto, seal::Seal};
 use crate::{
-    f32x4, f32x8, f32x16, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4, i32x8, i32x16,
-    mask8x16, mask8x32, mask8x64, mask16x8, mask16x16, mask16x32, mask32x4, mask32x8, mask32x16,
-    u8x16, u8x32, u8x64, u16x8, u16x16, u16x32, u32x4, u32x8, u32x16,
+    bf16, bf16x8, bf16x16, f16, f16x8, f16x16, f32x2, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8,
+    i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x2, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8,
+    mask8x8, mask8x16, mask8x32, mask8x64, mask16x4, mask16x8, mask16x16, mask16x32, mask32x2,
+    mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x8, u8x16, u8x32, u8x64, u16x4,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
 };
 use core::ops::*;
 #[cfg(all(feature = "libm", not(feature = "std")))]
 trait FloatExt {
-    fn floor(self) -> f32;
-    fn fract(self) -> f32;
-    fn sqrt(self) -> f32;
-    fn trunc(self) -> f32;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn fract(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn trunc(self) -> Self;
 }
 #[cfg(all(feature = "libm", not(feature = "std")))]
 impl FloatExt for f32 {
@@ -21,6 +27,18 @@ impl FloatExt for f32 {
         libm::floorf(self)
     }
     #[inline(always)]
+    fn ceil(self) -> f32 {
+        libm::ceilf(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f32 {
+        libm::roundevenf(self)
+    }
+    #[inline(always)]
     fn sqrt(self) -> f32 {
         libm::sqrtf(self)
     }
@@ -29,10 +47,49 @@ impl FloatExt for f32 {
         self - self.trunc()
     }
     #[inline(always)]
+    fn mul_add(self, a: f32, b: f32) -> f32 {
+        libm::fmaf(self, a, b)
+    }
+    #[inline(always)]
     fn trunc(self) -> f32 {
         libm::truncf(self)
     }
 }
+#[cfg(all(feature = "libm", not(feature = "std")))]
+impl FloatExt for f64 {
+    #[inline(always)]
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f64 {
+        libm::roundeven(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f64 {
+        self - self.trunc()
+    }
+    #[inline(always)]
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        libm::fma(self, a, b)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f64 {
+        libm::trunc(self)
+    }
+}
 #[doc = r#" The SIMD token for the "fallback" level."#]
 #[derive(Clone, Copy, Debug)]
 pub struct Fallback {
@@ -49,15 +106,19 @@ impl Fallback {
 impl Seal for Fallback {}
 impl Simd for Fallback {
     type f32s = f32x4<Self>;
+    type f64s = f64x2<Self>;
     type u8s = u8x16<Self>;
     type i8s = i8x16<Self>;
     type u16s = u16x8<Self>;
     type i16s = i16x8<Self>;
     type u32s = u32x4<Self>;
     type i32s = i32x4<Self>;
+    type u64s = u64x2<Self>;
+    type i64s = i64x2<Self>;
     type mask8s = mask8x16<Self>;
     type mask16s = mask16x8<Self>;
     type mask32s = mask32x4<Self>;
+    type mask64s = mask64x2<Self>;
     #[inline(always)]
     fn level(self) -> Level {
         Level::Fallback(self)
@@ -67,571 +128,622 @@ impl Simd for Fallback {
         f()
     }
     #[inline(always)]
-    fn splat_f32x4(self, val: f32) -> f32x4<Self> {
-        [val; 4usize].simd_into(self)
+    fn splat_f32x2(self, val: f32) -> f32x2<Self> {
+        [val; 2usize].simd_into(self)
     }
     #[inline(always)]
-    fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
-        [
-            f32::abs(a[0usize]),
-            f32::abs(a[1usize]),
-            f32::abs(a[2usize]),
-            f32::abs(a[3usize]),
-        ]
-        .simd_into(self)
+    fn abs_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::abs(a[0usize]), f32::abs(a[1usize])].simd_into(self)
     }
     #[inline(always)]
-    fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
-        [
-            f32::neg(a[0usize]),
-            f32::neg(a[1usize]),
-            f32::neg(a[2usize]),
-            f32::neg(a[3usize]),
-        ]
-        .simd_into(self)
+    fn neg_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::neg(a[0usize]), f32::neg(a[1usize])].simd_into(self)
     }
     #[inline(always)]
-    fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
-        [
-            f32::sqrt(a[0usize]),
-            f32::sqrt(a[1usize]),
-            f32::sqrt(a[2usize]),
-            f32::sqrt(a[3usize]),
-        ]
-        .simd_into(self)
+    fn sqrt_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::sqrt(a[0usize]), f32::sqrt(a[1usize])].simd_into(self)
     }
     #[inline(always)]
-    fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn recip_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.div_f32x2(self.splat_f32x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.div_f32x2(self.splat_f32x2(1.0), self.sqrt_f32x2(a))
+    }
+    #[inline(always)]
+    fn recip_f32x2(self, a: f32x2<Self>, _steps: u32) -> f32x2<Self> {
+        self.div_f32x2(self.splat_f32x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_f32x2(self, a: f32x2<Self>, _steps: u32) -> f32x2<Self> {
+        self.div_f32x2(self.splat_f32x2(1.0), self.sqrt_f32x2(a))
+    }
+    #[inline(always)]
+    fn add_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::add(a[0usize], &b[0usize]),
             f32::add(a[1usize], &b[1usize]),
-            f32::add(a[2usize], &b[2usize]),
-            f32::add(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn sub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::sub(a[0usize], &b[0usize]),
             f32::sub(a[1usize], &b[1usize]),
-            f32::sub(a[2usize], &b[2usize]),
-            f32::sub(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn mul_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::mul(a[0usize], &b[0usize]),
             f32::mul(a[1usize], &b[1usize]),
-            f32::mul(a[2usize], &b[2usize]),
-            f32::mul(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn div_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::div(a[0usize], &b[0usize]),
             f32::div(a[1usize], &b[1usize]),
-            f32::div(a[2usize], &b[2usize]),
-            f32::div(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn copysign_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::copysign(a[0usize], b[0usize]),
             f32::copysign(a[1usize], b[1usize]),
-            f32::copysign(a[2usize], b[2usize]),
-            f32::copysign(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+    fn simd_eq_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
         [
             -(f32::eq(&a[0usize], &b[0usize]) as i32),
             -(f32::eq(&a[1usize], &b[1usize]) as i32),
-            -(f32::eq(&a[2usize], &b[2usize]) as i32),
-            -(f32::eq(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+    fn simd_ne_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        [
+            -(f32::ne(&a[0usize], &b[0usize]) as i32),
+            -(f32::ne(&a[1usize], &b[1usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
         [
             -(f32::lt(&a[0usize], &b[0usize]) as i32),
             -(f32::lt(&a[1usize], &b[1usize]) as i32),
-            -(f32::lt(&a[2usize], &b[2usize]) as i32),
-            -(f32::lt(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+    fn simd_le_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
         [
             -(f32::le(&a[0usize], &b[0usize]) as i32),
             -(f32::le(&a[1usize], &b[1usize]) as i32),
-            -(f32::le(&a[2usize], &b[2usize]) as i32),
-            -(f32::le(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+    fn simd_ge_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
         [
             -(f32::ge(&a[0usize], &b[0usize]) as i32),
             -(f32::ge(&a[1usize], &b[1usize]) as i32),
-            -(f32::ge(&a[2usize], &b[2usize]) as i32),
-            -(f32::ge(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+    fn simd_gt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
         [
             -(f32::gt(&a[0usize], &b[0usize]) as i32),
             -(f32::gt(&a[1usize], &b[1usize]) as i32),
-            -(f32::gt(&a[2usize], &b[2usize]) as i32),
-            -(f32::gt(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
-        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    fn is_nan_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_ne_f32x2(a, a)
     }
     #[inline(always)]
-    fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
-        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    fn is_finite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
     }
     #[inline(always)]
-    fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn is_infinite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_eq_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        let abs = self.abs_f32x2(a);
+        self.and_mask32x2(
+            self.simd_lt_f32x2(abs, self.splat_f32x2(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x2(abs, self.splat_f32x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(
+            self.copysign_f32x2(self.splat_f32x2(1.0), a),
+            self.splat_f32x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        [a[0usize], b[0usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        [a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::max(a[0usize], b[0usize]),
             f32::max(a[1usize], b[1usize]),
-            f32::max(a[2usize], b[2usize]),
-            f32::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn max_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::max(a[0usize], b[0usize]),
             f32::max(a[1usize], b[1usize]),
-            f32::max(a[2usize], b[2usize]),
-            f32::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn min_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::min(a[0usize], b[0usize]),
             f32::min(a[1usize], b[1usize]),
-            f32::min(a[2usize], b[2usize]),
-            f32::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+    fn min_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
         [
             f32::min(a[0usize], b[0usize]),
             f32::min(a[1usize], b[1usize]),
-            f32::min(a[2usize], b[2usize]),
-            f32::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn madd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+    fn madd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
         a.add(b.mul(c))
     }
     #[inline(always)]
-    fn msub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+    fn msub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
         a.sub(b.mul(c))
     }
     #[inline(always)]
-    fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
-        [
-            f32::floor(a[0usize]),
-            f32::floor(a[1usize]),
-            f32::floor(a[2usize]),
-            f32::floor(a[3usize]),
-        ]
-        .simd_into(self)
+    fn nmadd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.neg_f32x2(self.madd_f32x2(a, b, c))
     }
     #[inline(always)]
-    fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+    fn nmsub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.neg_f32x2(self.msub_f32x2(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
         [
-            f32::fract(a[0usize]),
-            f32::fract(a[1usize]),
-            f32::fract(a[2usize]),
-            f32::fract(a[3usize]),
+            f32::mul_add(a[0usize], b[0usize], c[0usize]),
+            f32::mul_add(a[1usize], b[1usize], c[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+    fn mul_add_fast_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.madd_f32x2(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::floor(a[0usize]), f32::floor(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::ceil(a[0usize]), f32::ceil(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::round(a[0usize]), f32::round(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
         [
-            f32::trunc(a[0usize]),
-            f32::trunc(a[1usize]),
-            f32::trunc(a[2usize]),
-            f32::trunc(a[3usize]),
+            f32::round_ties_even(a[0usize]),
+            f32::round_ties_even(a[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+    fn fract_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::fract(a[0usize]), f32::fract(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        [f32::trunc(a[0usize]), f32::trunc(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f32x2(self, a: mask32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
-        let mut result = [0.0; 8usize];
-        result[0..4usize].copy_from_slice(&a.val);
-        result[4usize..8usize].copy_from_slice(&b.val);
+    fn reduce_add_f32x2(self, a: f32x2<Self>) -> f32 {
+        a[0] + a[1]
+    }
+    #[inline(always)]
+    fn reduce_min_f32x2(self, a: f32x2<Self>) -> f32 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_f32x2(self, a: f32x2<Self>) -> f32 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn shuffle_f32x2<I: Indices<2>>(self, a: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x2<I: Indices<2>>(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x2<const L: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x4<Self> {
+        let mut result = [0.0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
         result.simd_into(self)
     }
     #[inline(always)]
-    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
-        [
-            a[0usize] as u32,
-            a[1usize] as u32,
-            a[2usize] as u32,
-            a[3usize] as u32,
-        ]
-        .simd_into(self)
+    fn reinterpret_u8_f32x2(self, a: f32x2<Self>) -> u8x8<Self> {
+        u8x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+    fn reinterpret_u16_f32x2(self, a: f32x2<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x2(self, a: f32x2<Self>) -> f64x2<Self> {
+        [a[0usize] as f64, a[1usize] as f64].simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        [a[0usize] as i32, a[1usize] as i32].simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
         [
-            a[0usize] as i32,
-            a[1usize] as i32,
-            a[2usize] as i32,
-            a[3usize] as i32,
+            f32::round_ties_even(a[0usize]) as i32,
+            f32::round_ties_even(a[1usize]) as i32,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn splat_i8x16(self, val: i8) -> i8x16<Self> {
-        [val; 16usize].simd_into(self)
+    fn cvt_i64_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_f64x2(self.cvt_f64_f32x2(a))
     }
     #[inline(always)]
-    fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+    fn cvt_i64_round_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn splat_u8x8(self, val: u8) -> u8x8<Self> {
+        [val; 8usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::not(a[0usize]),
-            i8::not(a[1usize]),
-            i8::not(a[2usize]),
-            i8::not(a[3usize]),
-            i8::not(a[4usize]),
-            i8::not(a[5usize]),
-            i8::not(a[6usize]),
-            i8::not(a[7usize]),
-            i8::not(a[8usize]),
-            i8::not(a[9usize]),
-            i8::not(a[10usize]),
-            i8::not(a[11usize]),
-            i8::not(a[12usize]),
-            i8::not(a[13usize]),
-            i8::not(a[14usize]),
-            i8::not(a[15usize]),
+            u8::not(a[0usize]),
+            u8::not(a[1usize]),
+            u8::not(a[2usize]),
+            u8::not(a[3usize]),
+            u8::not(a[4usize]),
+            u8::not(a[5usize]),
+            u8::not(a[6usize]),
+            u8::not(a[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::add(a[0usize], &b[0usize]),
-            i8::add(a[1usize], &b[1usize]),
-            i8::add(a[2usize], &b[2usize]),
-            i8::add(a[3usize], &b[3usize]),
-            i8::add(a[4usize], &b[4usize]),
-            i8::add(a[5usize], &b[5usize]),
-            i8::add(a[6usize], &b[6usize]),
-            i8::add(a[7usize], &b[7usize]),
-            i8::add(a[8usize], &b[8usize]),
-            i8::add(a[9usize], &b[9usize]),
-            i8::add(a[10usize], &b[10usize]),
-            i8::add(a[11usize], &b[11usize]),
-            i8::add(a[12usize], &b[12usize]),
-            i8::add(a[13usize], &b[13usize]),
-            i8::add(a[14usize], &b[14usize]),
-            i8::add(a[15usize], &b[15usize]),
+            u8::wrapping_add(a[0usize], b[0usize]),
+            u8::wrapping_add(a[1usize], b[1usize]),
+            u8::wrapping_add(a[2usize], b[2usize]),
+            u8::wrapping_add(a[3usize], b[3usize]),
+            u8::wrapping_add(a[4usize], b[4usize]),
+            u8::wrapping_add(a[5usize], b[5usize]),
+            u8::wrapping_add(a[6usize], b[6usize]),
+            u8::wrapping_add(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::wrapping_sub(a[0usize], b[0usize]),
-            i8::wrapping_sub(a[1usize], b[1usize]),
-            i8::wrapping_sub(a[2usize], b[2usize]),
-            i8::wrapping_sub(a[3usize], b[3usize]),
-            i8::wrapping_sub(a[4usize], b[4usize]),
-            i8::wrapping_sub(a[5usize], b[5usize]),
-            i8::wrapping_sub(a[6usize], b[6usize]),
-            i8::wrapping_sub(a[7usize], b[7usize]),
-            i8::wrapping_sub(a[8usize], b[8usize]),
-            i8::wrapping_sub(a[9usize], b[9usize]),
-            i8::wrapping_sub(a[10usize], b[10usize]),
-            i8::wrapping_sub(a[11usize], b[11usize]),
-            i8::wrapping_sub(a[12usize], b[12usize]),
-            i8::wrapping_sub(a[13usize], b[13usize]),
-            i8::wrapping_sub(a[14usize], b[14usize]),
-            i8::wrapping_sub(a[15usize], b[15usize]),
+            u8::wrapping_sub(a[0usize], b[0usize]),
+            u8::wrapping_sub(a[1usize], b[1usize]),
+            u8::wrapping_sub(a[2usize], b[2usize]),
+            u8::wrapping_sub(a[3usize], b[3usize]),
+            u8::wrapping_sub(a[4usize], b[4usize]),
+            u8::wrapping_sub(a[5usize], b[5usize]),
+            u8::wrapping_sub(a[6usize], b[6usize]),
+            u8::wrapping_sub(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn mul_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn mul_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::mul(a[0usize], &b[0usize]),
-            i8::mul(a[1usize], &b[1usize]),
-            i8::mul(a[2usize], &b[2usize]),
-            i8::mul(a[3usize], &b[3usize]),
-            i8::mul(a[4usize], &b[4usize]),
-            i8::mul(a[5usize], &b[5usize]),
-            i8::mul(a[6usize], &b[6usize]),
-            i8::mul(a[7usize], &b[7usize]),
-            i8::mul(a[8usize], &b[8usize]),
-            i8::mul(a[9usize], &b[9usize]),
-            i8::mul(a[10usize], &b[10usize]),
-            i8::mul(a[11usize], &b[11usize]),
-            i8::mul(a[12usize], &b[12usize]),
-            i8::mul(a[13usize], &b[13usize]),
-            i8::mul(a[14usize], &b[14usize]),
-            i8::mul(a[15usize], &b[15usize]),
+            u8::wrapping_mul(a[0usize], b[0usize]),
+            u8::wrapping_mul(a[1usize], b[1usize]),
+            u8::wrapping_mul(a[2usize], b[2usize]),
+            u8::wrapping_mul(a[3usize], b[3usize]),
+            u8::wrapping_mul(a[4usize], b[4usize]),
+            u8::wrapping_mul(a[5usize], b[5usize]),
+            u8::wrapping_mul(a[6usize], b[6usize]),
+            u8::wrapping_mul(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn and_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::bitand(a[0usize], &b[0usize]),
-            i8::bitand(a[1usize], &b[1usize]),
-            i8::bitand(a[2usize], &b[2usize]),
-            i8::bitand(a[3usize], &b[3usize]),
-            i8::bitand(a[4usize], &b[4usize]),
-            i8::bitand(a[5usize], &b[5usize]),
-            i8::bitand(a[6usize], &b[6usize]),
-            i8::bitand(a[7usize], &b[7usize]),
-            i8::bitand(a[8usize], &b[8usize]),
-            i8::bitand(a[9usize], &b[9usize]),
-            i8::bitand(a[10usize], &b[10usize]),
-            i8::bitand(a[11usize], &b[11usize]),
-            i8::bitand(a[12usize], &b[12usize]),
-            i8::bitand(a[13usize], &b[13usize]),
-            i8::bitand(a[14usize], &b[14usize]),
-            i8::bitand(a[15usize], &b[15usize]),
+            u8::bitand(a[0usize], &b[0usize]),
+            u8::bitand(a[1usize], &b[1usize]),
+            u8::bitand(a[2usize], &b[2usize]),
+            u8::bitand(a[3usize], &b[3usize]),
+            u8::bitand(a[4usize], &b[4usize]),
+            u8::bitand(a[5usize], &b[5usize]),
+            u8::bitand(a[6usize], &b[6usize]),
+            u8::bitand(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn or_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::bitor(a[0usize], &b[0usize]),
-            i8::bitor(a[1usize], &b[1usize]),
-            i8::bitor(a[2usize], &b[2usize]),
-            i8::bitor(a[3usize], &b[3usize]),
-            i8::bitor(a[4usize], &b[4usize]),
-            i8::bitor(a[5usize], &b[5usize]),
-            i8::bitor(a[6usize], &b[6usize]),
-            i8::bitor(a[7usize], &b[7usize]),
-            i8::bitor(a[8usize], &b[8usize]),
-            i8::bitor(a[9usize], &b[9usize]),
-            i8::bitor(a[10usize], &b[10usize]),
-            i8::bitor(a[11usize], &b[11usize]),
-            i8::bitor(a[12usize], &b[12usize]),
-            i8::bitor(a[13usize], &b[13usize]),
-            i8::bitor(a[14usize], &b[14usize]),
-            i8::bitor(a[15usize], &b[15usize]),
+            u8::bitor(a[0usize], &b[0usize]),
+            u8::bitor(a[1usize], &b[1usize]),
+            u8::bitor(a[2usize], &b[2usize]),
+            u8::bitor(a[3usize], &b[3usize]),
+            u8::bitor(a[4usize], &b[4usize]),
+            u8::bitor(a[5usize], &b[5usize]),
+            u8::bitor(a[6usize], &b[6usize]),
+            u8::bitor(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn xor_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::bitxor(a[0usize], &b[0usize]),
-            i8::bitxor(a[1usize], &b[1usize]),
-            i8::bitxor(a[2usize], &b[2usize]),
-            i8::bitxor(a[3usize], &b[3usize]),
-            i8::bitxor(a[4usize], &b[4usize]),
-            i8::bitxor(a[5usize], &b[5usize]),
-            i8::bitxor(a[6usize], &b[6usize]),
-            i8::bitxor(a[7usize], &b[7usize]),
-            i8::bitxor(a[8usize], &b[8usize]),
-            i8::bitxor(a[9usize], &b[9usize]),
-            i8::bitxor(a[10usize], &b[10usize]),
-            i8::bitxor(a[11usize], &b[11usize]),
-            i8::bitxor(a[12usize], &b[12usize]),
-            i8::bitxor(a[13usize], &b[13usize]),
-            i8::bitxor(a[14usize], &b[14usize]),
-            i8::bitxor(a[15usize], &b[15usize]),
+            u8::bitxor(a[0usize], &b[0usize]),
+            u8::bitxor(a[1usize], &b[1usize]),
+            u8::bitxor(a[2usize], &b[2usize]),
+            u8::bitxor(a[3usize], &b[3usize]),
+            u8::bitxor(a[4usize], &b[4usize]),
+            u8::bitxor(a[5usize], &b[5usize]),
+            u8::bitxor(a[6usize], &b[6usize]),
+            u8::bitxor(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn shr_i8x16(self, a: i8x16<Self>, b: u32) -> i8x16<Self> {
+    fn shl_u8x8(self, a: u8x8<Self>, b: u32) -> u8x8<Self> {
         [
-            i8::shr(a[0usize], b as i8),
-            i8::shr(a[1usize], b as i8),
-            i8::shr(a[2usize], b as i8),
-            i8::shr(a[3usize], b as i8),
-            i8::shr(a[4usize], b as i8),
-            i8::shr(a[5usize], b as i8),
-            i8::shr(a[6usize], b as i8),
-            i8::shr(a[7usize], b as i8),
-            i8::shr(a[8usize], b as i8),
-            i8::shr(a[9usize], b as i8),
-            i8::shr(a[10usize], b as i8),
-            i8::shr(a[11usize], b as i8),
-            i8::shr(a[12usize], b as i8),
-            i8::shr(a[13usize], b as i8),
-            i8::shr(a[14usize], b as i8),
-            i8::shr(a[15usize], b as i8),
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+            a[4usize].checked_shl(b).unwrap_or(0),
+            a[5usize].checked_shl(b).unwrap_or(0),
+            a[6usize].checked_shl(b).unwrap_or(0),
+            a[7usize].checked_shl(b).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+    fn shr_u8x8(self, a: u8x8<Self>, b: u32) -> u8x8<Self> {
         [
-            -(i8::eq(&a[0usize], &b[0usize]) as i8),
-            -(i8::eq(&a[1usize], &b[1usize]) as i8),
-            -(i8::eq(&a[2usize], &b[2usize]) as i8),
-            -(i8::eq(&a[3usize], &b[3usize]) as i8),
-            -(i8::eq(&a[4usize], &b[4usize]) as i8),
-            -(i8::eq(&a[5usize], &b[5usize]) as i8),
-            -(i8::eq(&a[6usize], &b[6usize]) as i8),
-            -(i8::eq(&a[7usize], &b[7usize]) as i8),
-            -(i8::eq(&a[8usize], &b[8usize]) as i8),
-            -(i8::eq(&a[9usize], &b[9usize]) as i8),
-            -(i8::eq(&a[10usize], &b[10usize]) as i8),
-            -(i8::eq(&a[11usize], &b[11usize]) as i8),
-            -(i8::eq(&a[12usize], &b[12usize]) as i8),
-            -(i8::eq(&a[13usize], &b[13usize]) as i8),
-            -(i8::eq(&a[14usize], &b[14usize]) as i8),
-            -(i8::eq(&a[15usize], &b[15usize]) as i8),
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+            a[2usize].checked_shr(b).unwrap_or(0),
+            a[3usize].checked_shr(b).unwrap_or(0),
+            a[4usize].checked_shr(b).unwrap_or(0),
+            a[5usize].checked_shr(b).unwrap_or(0),
+            a[6usize].checked_shr(b).unwrap_or(0),
+            a[7usize].checked_shr(b).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+    fn shl_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            -(i8::lt(&a[0usize], &b[0usize]) as i8),
-            -(i8::lt(&a[1usize], &b[1usize]) as i8),
-            -(i8::lt(&a[2usize], &b[2usize]) as i8),
-            -(i8::lt(&a[3usize], &b[3usize]) as i8),
-            -(i8::lt(&a[4usize], &b[4usize]) as i8),
-            -(i8::lt(&a[5usize], &b[5usize]) as i8),
-            -(i8::lt(&a[6usize], &b[6usize]) as i8),
-            -(i8::lt(&a[7usize], &b[7usize]) as i8),
-            -(i8::lt(&a[8usize], &b[8usize]) as i8),
-            -(i8::lt(&a[9usize], &b[9usize]) as i8),
-            -(i8::lt(&a[10usize], &b[10usize]) as i8),
-            -(i8::lt(&a[11usize], &b[11usize]) as i8),
-            -(i8::lt(&a[12usize], &b[12usize]) as i8),
-            -(i8::lt(&a[13usize], &b[13usize]) as i8),
-            -(i8::lt(&a[14usize], &b[14usize]) as i8),
-            -(i8::lt(&a[15usize], &b[15usize]) as i8),
+            a[0usize].checked_shl(b[0usize].min(8) as u32).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(8) as u32).unwrap_or(0),
+            a[2usize].checked_shl(b[2usize].min(8) as u32).unwrap_or(0),
+            a[3usize].checked_shl(b[3usize].min(8) as u32).unwrap_or(0),
+            a[4usize].checked_shl(b[4usize].min(8) as u32).unwrap_or(0),
+            a[5usize].checked_shl(b[5usize].min(8) as u32).unwrap_or(0),
+            a[6usize].checked_shl(b[6usize].min(8) as u32).unwrap_or(0),
+            a[7usize].checked_shl(b[7usize].min(8) as u32).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+    fn shr_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            -(i8::le(&a[0usize], &b[0usize]) as i8),
-            -(i8::le(&a[1usize], &b[1usize]) as i8),
-            -(i8::le(&a[2usize], &b[2usize]) as i8),
-            -(i8::le(&a[3usize], &b[3usize]) as i8),
-            -(i8::le(&a[4usize], &b[4usize]) as i8),
-            -(i8::le(&a[5usize], &b[5usize]) as i8),
-            -(i8::le(&a[6usize], &b[6usize]) as i8),
-            -(i8::le(&a[7usize], &b[7usize]) as i8),
-            -(i8::le(&a[8usize], &b[8usize]) as i8),
-            -(i8::le(&a[9usize], &b[9usize]) as i8),
-            -(i8::le(&a[10usize], &b[10usize]) as i8),
-            -(i8::le(&a[11usize], &b[11usize]) as i8),
-            -(i8::le(&a[12usize], &b[12usize]) as i8),
-            -(i8::le(&a[13usize], &b[13usize]) as i8),
-            -(i8::le(&a[14usize], &b[14usize]) as i8),
-            -(i8::le(&a[15usize], &b[15usize]) as i8),
+            a[0usize].checked_shr(b[0usize].min(8) as u32).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(8) as u32).unwrap_or(0),
+            a[2usize].checked_shr(b[2usize].min(8) as u32).unwrap_or(0),
+            a[3usize].checked_shr(b[3usize].min(8) as u32).unwrap_or(0),
+            a[4usize].checked_shr(b[4usize].min(8) as u32).unwrap_or(0),
+            a[5usize].checked_shr(b[5usize].min(8) as u32).unwrap_or(0),
+            a[6usize].checked_shr(b[6usize].min(8) as u32).unwrap_or(0),
+            a[7usize].checked_shr(b[7usize].min(8) as u32).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+    fn simd_eq_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
         [
-            -(i8::ge(&a[0usize], &b[0usize]) as i8),
-            -(i8::ge(&a[1usize], &b[1usize]) as i8),
-            -(i8::ge(&a[2usize], &b[2usize]) as i8),
-            -(i8::ge(&a[3usize], &b[3usize]) as i8),
-            -(i8::ge(&a[4usize], &b[4usize]) as i8),
-            -(i8::ge(&a[5usize], &b[5usize]) as i8),
-            -(i8::ge(&a[6usize], &b[6usize]) as i8),
-            -(i8::ge(&a[7usize], &b[7usize]) as i8),
-            -(i8::ge(&a[8usize], &b[8usize]) as i8),
-            -(i8::ge(&a[9usize], &b[9usize]) as i8),
-            -(i8::ge(&a[10usize], &b[10usize]) as i8),
-            -(i8::ge(&a[11usize], &b[11usize]) as i8),
-            -(i8::ge(&a[12usize], &b[12usize]) as i8),
-            -(i8::ge(&a[13usize], &b[13usize]) as i8),
-            -(i8::ge(&a[14usize], &b[14usize]) as i8),
-            -(i8::ge(&a[15usize], &b[15usize]) as i8),
+            -(u8::eq(&a[0usize], &b[0usize]) as i8),
+            -(u8::eq(&a[1usize], &b[1usize]) as i8),
+            -(u8::eq(&a[2usize], &b[2usize]) as i8),
+            -(u8::eq(&a[3usize], &b[3usize]) as i8),
+            -(u8::eq(&a[4usize], &b[4usize]) as i8),
+            -(u8::eq(&a[5usize], &b[5usize]) as i8),
+            -(u8::eq(&a[6usize], &b[6usize]) as i8),
+            -(u8::eq(&a[7usize], &b[7usize]) as i8),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+    fn simd_lt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
         [
-            -(i8::gt(&a[0usize], &b[0usize]) as i8),
-            -(i8::gt(&a[1usize], &b[1usize]) as i8),
-            -(i8::gt(&a[2usize], &b[2usize]) as i8),
-            -(i8::gt(&a[3usize], &b[3usize]) as i8),
-            -(i8::gt(&a[4usize], &b[4usize]) as i8),
-            -(i8::gt(&a[5usize], &b[5usize]) as i8),
-            -(i8::gt(&a[6usize], &b[6usize]) as i8),
-            -(i8::gt(&a[7usize], &b[7usize]) as i8),
-            -(i8::gt(&a[8usize], &b[8usize]) as i8),
-            -(i8::gt(&a[9usize], &b[9usize]) as i8),
-            -(i8::gt(&a[10usize], &b[10usize]) as i8),
-            -(i8::gt(&a[11usize], &b[11usize]) as i8),
-            -(i8::gt(&a[12usize], &b[12usize]) as i8),
-            -(i8::gt(&a[13usize], &b[13usize]) as i8),
-            -(i8::gt(&a[14usize], &b[14usize]) as i8),
-            -(i8::gt(&a[15usize], &b[15usize]) as i8),
+            -(u8::lt(&a[0usize], &b[0usize]) as i8),
+            -(u8::lt(&a[1usize], &b[1usize]) as i8),
+            -(u8::lt(&a[2usize], &b[2usize]) as i8),
+            -(u8::lt(&a[3usize], &b[3usize]) as i8),
+            -(u8::lt(&a[4usize], &b[4usize]) as i8),
+            -(u8::lt(&a[5usize], &b[5usize]) as i8),
+            -(u8::lt(&a[6usize], &b[6usize]) as i8),
+            -(u8::lt(&a[7usize], &b[7usize]) as i8),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn simd_le_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        [
+            -(u8::le(&a[0usize], &b[0usize]) as i8),
+            -(u8::le(&a[1usize], &b[1usize]) as i8),
+            -(u8::le(&a[2usize], &b[2usize]) as i8),
+            -(u8::le(&a[3usize], &b[3usize]) as i8),
+            -(u8::le(&a[4usize], &b[4usize]) as i8),
+            -(u8::le(&a[5usize], &b[5usize]) as i8),
+            -(u8::le(&a[6usize], &b[6usize]) as i8),
+            -(u8::le(&a[7usize], &b[7usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        [
+            -(u8::ge(&a[0usize], &b[0usize]) as i8),
+            -(u8::ge(&a[1usize], &b[1usize]) as i8),
+            -(u8::ge(&a[2usize], &b[2usize]) as i8),
+            -(u8::ge(&a[3usize], &b[3usize]) as i8),
+            -(u8::ge(&a[4usize], &b[4usize]) as i8),
+            -(u8::ge(&a[5usize], &b[5usize]) as i8),
+            -(u8::ge(&a[6usize], &b[6usize]) as i8),
+            -(u8::ge(&a[7usize], &b[7usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        [
+            -(u8::gt(&a[0usize], &b[0usize]) as i8),
+            -(u8::gt(&a[1usize], &b[1usize]) as i8),
+            -(u8::gt(&a[2usize], &b[2usize]) as i8),
+            -(u8::gt(&a[3usize], &b[3usize]) as i8),
+            -(u8::gt(&a[4usize], &b[4usize]) as i8),
+            -(u8::gt(&a[5usize], &b[5usize]) as i8),
+            -(u8::gt(&a[6usize], &b[6usize]) as i8),
+            -(u8::gt(&a[7usize], &b[7usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
             a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
-            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn zip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            a[8usize], b[8usize], a[9usize], b[9usize], a[10usize], b[10usize], a[11usize],
-            b[11usize], a[12usize], b[12usize], a[13usize], b[13usize], a[14usize], b[14usize],
-            a[15usize], b[15usize],
+            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self> {
+    fn select_u8x8(self, a: mask8x8<Self>, b: u8x8<Self>, c: u8x8<Self>) -> u8x8<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
@@ -641,531 +753,287 @@ impl Simd for Fallback {
             if a[5usize] != 0 { b[5usize] } else { c[5usize] },
             if a[6usize] != 0 { b[6usize] } else { c[6usize] },
             if a[7usize] != 0 { b[7usize] } else { c[7usize] },
-            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
-            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
-            if a[10usize] != 0 {
-                b[10usize]
-            } else {
-                c[10usize]
-            },
-            if a[11usize] != 0 {
-                b[11usize]
-            } else {
-                c[11usize]
-            },
-            if a[12usize] != 0 {
-                b[12usize]
-            } else {
-                c[12usize]
-            },
-            if a[13usize] != 0 {
-                b[13usize]
-            } else {
-                c[13usize]
-            },
-            if a[14usize] != 0 {
-                b[14usize]
-            } else {
-                c[14usize]
-            },
-            if a[15usize] != 0 {
-                b[15usize]
-            } else {
-                c[15usize]
-            },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn min_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::min(a[0usize], b[0usize]),
-            i8::min(a[1usize], b[1usize]),
-            i8::min(a[2usize], b[2usize]),
-            i8::min(a[3usize], b[3usize]),
-            i8::min(a[4usize], b[4usize]),
-            i8::min(a[5usize], b[5usize]),
-            i8::min(a[6usize], b[6usize]),
-            i8::min(a[7usize], b[7usize]),
-            i8::min(a[8usize], b[8usize]),
-            i8::min(a[9usize], b[9usize]),
-            i8::min(a[10usize], b[10usize]),
-            i8::min(a[11usize], b[11usize]),
-            i8::min(a[12usize], b[12usize]),
-            i8::min(a[13usize], b[13usize]),
-            i8::min(a[14usize], b[14usize]),
-            i8::min(a[15usize], b[15usize]),
+            u8::min(a[0usize], b[0usize]),
+            u8::min(a[1usize], b[1usize]),
+            u8::min(a[2usize], b[2usize]),
+            u8::min(a[3usize], b[3usize]),
+            u8::min(a[4usize], b[4usize]),
+            u8::min(a[5usize], b[5usize]),
+            u8::min(a[6usize], b[6usize]),
+            u8::min(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+    fn max_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            i8::max(a[0usize], b[0usize]),
-            i8::max(a[1usize], b[1usize]),
-            i8::max(a[2usize], b[2usize]),
-            i8::max(a[3usize], b[3usize]),
-            i8::max(a[4usize], b[4usize]),
-            i8::max(a[5usize], b[5usize]),
-            i8::max(a[6usize], b[6usize]),
-            i8::max(a[7usize], b[7usize]),
-            i8::max(a[8usize], b[8usize]),
-            i8::max(a[9usize], b[9usize]),
-            i8::max(a[10usize], b[10usize]),
-            i8::max(a[11usize], b[11usize]),
-            i8::max(a[12usize], b[12usize]),
-            i8::max(a[13usize], b[13usize]),
-            i8::max(a[14usize], b[14usize]),
-            i8::max(a[15usize], b[15usize]),
+            u8::max(a[0usize], b[0usize]),
+            u8::max(a[1usize], b[1usize]),
+            u8::max(a[2usize], b[2usize]),
+            u8::max(a[3usize], b[3usize]),
+            u8::max(a[4usize], b[4usize]),
+            u8::max(a[5usize], b[5usize]),
+            u8::max(a[6usize], b[6usize]),
+            u8::max(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reduce_add_u8x8(self, a: u8x8<Self>) -> u8 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+            .wrapping_add(
+                a[4].wrapping_add(a[5])
+                    .wrapping_add(a[6].wrapping_add(a[7])),
+            )
     }
     #[inline(always)]
-    fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
-        u8x16 {
-            val: bytemuck::cast(a.val),
-            simd: a.simd,
-        }
+    fn reduce_min_u8x8(self, a: u8x8<Self>) -> u8 {
+        a[0].min(a[1])
+            .min(a[2].min(a[3]))
+            .min(a[4].min(a[5]).min(a[6].min(a[7])))
     }
     #[inline(always)]
-    fn splat_u8x16(self, val: u8) -> u8x16<Self> {
-        [val; 16usize].simd_into(self)
+    fn reduce_max_u8x8(self, a: u8x8<Self>) -> u8 {
+        a[0].max(a[1])
+            .max(a[2].max(a[3]))
+            .max(a[4].max(a[5]).max(a[6].max(a[7])))
     }
     #[inline(always)]
-    fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
-        [
-            u8::not(a[0usize]),
-            u8::not(a[1usize]),
-            u8::not(a[2usize]),
-            u8::not(a[3usize]),
-            u8::not(a[4usize]),
-            u8::not(a[5usize]),
-            u8::not(a[6usize]),
-            u8::not(a[7usize]),
-            u8::not(a[8usize]),
-            u8::not(a[9usize]),
-            u8::not(a[10usize]),
-            u8::not(a[11usize]),
-            u8::not(a[12usize]),
-            u8::not(a[13usize]),
-            u8::not(a[14usize]),
-            u8::not(a[15usize]),
-        ]
-        .simd_into(self)
+    fn reduce_and_u8x8(self, a: u8x8<Self>) -> u8 {
+        ((a[0] & a[1]) & (a[2] & a[3])) & ((a[4] & a[5]) & (a[6] & a[7]))
     }
     #[inline(always)]
-    fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            u8::add(a[0usize], &b[0usize]),
-            u8::add(a[1usize], &b[1usize]),
-            u8::add(a[2usize], &b[2usize]),
-            u8::add(a[3usize], &b[3usize]),
-            u8::add(a[4usize], &b[4usize]),
-            u8::add(a[5usize], &b[5usize]),
-            u8::add(a[6usize], &b[6usize]),
-            u8::add(a[7usize], &b[7usize]),
-            u8::add(a[8usize], &b[8usize]),
-            u8::add(a[9usize], &b[9usize]),
-            u8::add(a[10usize], &b[10usize]),
-            u8::add(a[11usize], &b[11usize]),
-            u8::add(a[12usize], &b[12usize]),
-            u8::add(a[13usize], &b[13usize]),
-            u8::add(a[14usize], &b[14usize]),
-            u8::add(a[15usize], &b[15usize]),
-        ]
-        .simd_into(self)
+    fn reduce_or_u8x8(self, a: u8x8<Self>) -> u8 {
+        ((a[0] | a[1]) | (a[2] | a[3])) | ((a[4] | a[5]) | (a[6] | a[7]))
     }
     #[inline(always)]
-    fn sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            u8::wrapping_sub(a[0usize], b[0usize]),
-            u8::wrapping_sub(a[1usize], b[1usize]),
-            u8::wrapping_sub(a[2usize], b[2usize]),
-            u8::wrapping_sub(a[3usize], b[3usize]),
-            u8::wrapping_sub(a[4usize], b[4usize]),
-            u8::wrapping_sub(a[5usize], b[5usize]),
-            u8::wrapping_sub(a[6usize], b[6usize]),
-            u8::wrapping_sub(a[7usize], b[7usize]),
-            u8::wrapping_sub(a[8usize], b[8usize]),
-            u8::wrapping_sub(a[9usize], b[9usize]),
-            u8::wrapping_sub(a[10usize], b[10usize]),
-            u8::wrapping_sub(a[11usize], b[11usize]),
-            u8::wrapping_sub(a[12usize], b[12usize]),
-            u8::wrapping_sub(a[13usize], b[13usize]),
-            u8::wrapping_sub(a[14usize], b[14usize]),
-            u8::wrapping_sub(a[15usize], b[15usize]),
-        ]
-        .simd_into(self)
+    fn shuffle_u8x8<I: Indices<8>>(self, a: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn mul_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+    fn shuffle2_u8x8<I: Indices<8>>(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x8<const L: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            u8::mul(a[0usize], &b[0usize]),
-            u8::mul(a[1usize], &b[1usize]),
-            u8::mul(a[2usize], &b[2usize]),
-            u8::mul(a[3usize], &b[3usize]),
-            u8::mul(a[4usize], &b[4usize]),
-            u8::mul(a[5usize], &b[5usize]),
-            u8::mul(a[6usize], &b[6usize]),
-            u8::mul(a[7usize], &b[7usize]),
-            u8::mul(a[8usize], &b[8usize]),
-            u8::mul(a[9usize], &b[9usize]),
-            u8::mul(a[10usize], &b[10usize]),
-            u8::mul(a[11usize], &b[11usize]),
-            u8::mul(a[12usize], &b[12usize]),
-            u8::mul(a[13usize], &b[13usize]),
-            u8::mul(a[14usize], &b[14usize]),
-            u8::mul(a[15usize], &b[15usize]),
+            u8::saturating_add(a[0usize], b[0usize]),
+            u8::saturating_add(a[1usize], b[1usize]),
+            u8::saturating_add(a[2usize], b[2usize]),
+            u8::saturating_add(a[3usize], b[3usize]),
+            u8::saturating_add(a[4usize], b[4usize]),
+            u8::saturating_add(a[5usize], b[5usize]),
+            u8::saturating_add(a[6usize], b[6usize]),
+            u8::saturating_add(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+    fn saturating_sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            u8::bitand(a[0usize], &b[0usize]),
-            u8::bitand(a[1usize], &b[1usize]),
-            u8::bitand(a[2usize], &b[2usize]),
-            u8::bitand(a[3usize], &b[3usize]),
-            u8::bitand(a[4usize], &b[4usize]),
-            u8::bitand(a[5usize], &b[5usize]),
-            u8::bitand(a[6usize], &b[6usize]),
-            u8::bitand(a[7usize], &b[7usize]),
-            u8::bitand(a[8usize], &b[8usize]),
-            u8::bitand(a[9usize], &b[9usize]),
-            u8::bitand(a[10usize], &b[10usize]),
-            u8::bitand(a[11usize], &b[11usize]),
-            u8::bitand(a[12usize], &b[12usize]),
-            u8::bitand(a[13usize], &b[13usize]),
-            u8::bitand(a[14usize], &b[14usize]),
-            u8::bitand(a[15usize], &b[15usize]),
+            u8::saturating_sub(a[0usize], b[0usize]),
+            u8::saturating_sub(a[1usize], b[1usize]),
+            u8::saturating_sub(a[2usize], b[2usize]),
+            u8::saturating_sub(a[3usize], b[3usize]),
+            u8::saturating_sub(a[4usize], b[4usize]),
+            u8::saturating_sub(a[5usize], b[5usize]),
+            u8::saturating_sub(a[6usize], b[6usize]),
+            u8::saturating_sub(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+    fn abs_diff_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            u8::bitor(a[0usize], &b[0usize]),
-            u8::bitor(a[1usize], &b[1usize]),
-            u8::bitor(a[2usize], &b[2usize]),
-            u8::bitor(a[3usize], &b[3usize]),
-            u8::bitor(a[4usize], &b[4usize]),
-            u8::bitor(a[5usize], &b[5usize]),
-            u8::bitor(a[6usize], &b[6usize]),
-            u8::bitor(a[7usize], &b[7usize]),
-            u8::bitor(a[8usize], &b[8usize]),
-            u8::bitor(a[9usize], &b[9usize]),
-            u8::bitor(a[10usize], &b[10usize]),
-            u8::bitor(a[11usize], &b[11usize]),
-            u8::bitor(a[12usize], &b[12usize]),
-            u8::bitor(a[13usize], &b[13usize]),
-            u8::bitor(a[14usize], &b[14usize]),
-            u8::bitor(a[15usize], &b[15usize]),
+            u8::abs_diff(a[0usize], b[0usize]),
+            u8::abs_diff(a[1usize], b[1usize]),
+            u8::abs_diff(a[2usize], b[2usize]),
+            u8::abs_diff(a[3usize], b[3usize]),
+            u8::abs_diff(a[4usize], b[4usize]),
+            u8::abs_diff(a[5usize], b[5usize]),
+            u8::abs_diff(a[6usize], b[6usize]),
+            u8::abs_diff(a[7usize], b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+    fn avg_round_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            u8::bitxor(a[0usize], &b[0usize]),
-            u8::bitxor(a[1usize], &b[1usize]),
-            u8::bitxor(a[2usize], &b[2usize]),
-            u8::bitxor(a[3usize], &b[3usize]),
-            u8::bitxor(a[4usize], &b[4usize]),
-            u8::bitxor(a[5usize], &b[5usize]),
-            u8::bitxor(a[6usize], &b[6usize]),
-            u8::bitxor(a[7usize], &b[7usize]),
-            u8::bitxor(a[8usize], &b[8usize]),
-            u8::bitxor(a[9usize], &b[9usize]),
-            u8::bitxor(a[10usize], &b[10usize]),
-            u8::bitxor(a[11usize], &b[11usize]),
-            u8::bitxor(a[12usize], &b[12usize]),
-            u8::bitxor(a[13usize], &b[13usize]),
-            u8::bitxor(a[14usize], &b[14usize]),
-            u8::bitxor(a[15usize], &b[15usize]),
+            ((a[0usize] as u16 + b[0usize] as u16 + 1) >> 1) as u8,
+            ((a[1usize] as u16 + b[1usize] as u16 + 1) >> 1) as u8,
+            ((a[2usize] as u16 + b[2usize] as u16 + 1) >> 1) as u8,
+            ((a[3usize] as u16 + b[3usize] as u16 + 1) >> 1) as u8,
+            ((a[4usize] as u16 + b[4usize] as u16 + 1) >> 1) as u8,
+            ((a[5usize] as u16 + b[5usize] as u16 + 1) >> 1) as u8,
+            ((a[6usize] as u16 + b[6usize] as u16 + 1) >> 1) as u8,
+            ((a[7usize] as u16 + b[7usize] as u16 + 1) >> 1) as u8,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn shr_u8x16(self, a: u8x16<Self>, b: u32) -> u8x16<Self> {
+    fn mul_widen_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u16x8<Self> {
         [
-            u8::shr(a[0usize], b as u8),
-            u8::shr(a[1usize], b as u8),
-            u8::shr(a[2usize], b as u8),
-            u8::shr(a[3usize], b as u8),
-            u8::shr(a[4usize], b as u8),
-            u8::shr(a[5usize], b as u8),
-            u8::shr(a[6usize], b as u8),
-            u8::shr(a[7usize], b as u8),
-            u8::shr(a[8usize], b as u8),
-            u8::shr(a[9usize], b as u8),
-            u8::shr(a[10usize], b as u8),
-            u8::shr(a[11usize], b as u8),
-            u8::shr(a[12usize], b as u8),
-            u8::shr(a[13usize], b as u8),
-            u8::shr(a[14usize], b as u8),
-            u8::shr(a[15usize], b as u8),
+            a[0usize] as u16 * b[0usize] as u16,
+            a[1usize] as u16 * b[1usize] as u16,
+            a[2usize] as u16 * b[2usize] as u16,
+            a[3usize] as u16 * b[3usize] as u16,
+            a[4usize] as u16 * b[4usize] as u16,
+            a[5usize] as u16 * b[5usize] as u16,
+            a[6usize] as u16 * b[6usize] as u16,
+            a[7usize] as u16 * b[7usize] as u16,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+    fn mul_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
         [
-            -(u8::eq(&a[0usize], &b[0usize]) as i8),
-            -(u8::eq(&a[1usize], &b[1usize]) as i8),
-            -(u8::eq(&a[2usize], &b[2usize]) as i8),
-            -(u8::eq(&a[3usize], &b[3usize]) as i8),
-            -(u8::eq(&a[4usize], &b[4usize]) as i8),
-            -(u8::eq(&a[5usize], &b[5usize]) as i8),
-            -(u8::eq(&a[6usize], &b[6usize]) as i8),
-            -(u8::eq(&a[7usize], &b[7usize]) as i8),
-            -(u8::eq(&a[8usize], &b[8usize]) as i8),
-            -(u8::eq(&a[9usize], &b[9usize]) as i8),
-            -(u8::eq(&a[10usize], &b[10usize]) as i8),
-            -(u8::eq(&a[11usize], &b[11usize]) as i8),
-            -(u8::eq(&a[12usize], &b[12usize]) as i8),
-            -(u8::eq(&a[13usize], &b[13usize]) as i8),
-            -(u8::eq(&a[14usize], &b[14usize]) as i8),
-            -(u8::eq(&a[15usize], &b[15usize]) as i8),
+            ((a[0usize] as u16 * b[0usize] as u16) >> 8) as u8,
+            ((a[1usize] as u16 * b[1usize] as u16) >> 8) as u8,
+            ((a[2usize] as u16 * b[2usize] as u16) >> 8) as u8,
+            ((a[3usize] as u16 * b[3usize] as u16) >> 8) as u8,
+            ((a[4usize] as u16 * b[4usize] as u16) >> 8) as u8,
+            ((a[5usize] as u16 * b[5usize] as u16) >> 8) as u8,
+            ((a[6usize] as u16 * b[6usize] as u16) >> 8) as u8,
+            ((a[7usize] as u16 * b[7usize] as u16) >> 8) as u8,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+    fn swizzle_dyn_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [u8; 8] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_32_u8x8(self, a: u8x32<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [u8; 8] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_48_u8x8(self, a: [u8x16<Self>; 3], b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [u8; 8] = core::array::from_fn(|i| {
+            a.get(b[i] as usize / 16)
+                .map_or(0, |t| t[b[i] as usize % 16])
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_64_u8x8(self, a: u8x64<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [u8; 8] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_u8x8(self, a: u8x8<Self>) -> u16x8<Self> {
         [
-            -(u8::lt(&a[0usize], &b[0usize]) as i8),
-            -(u8::lt(&a[1usize], &b[1usize]) as i8),
-            -(u8::lt(&a[2usize], &b[2usize]) as i8),
-            -(u8::lt(&a[3usize], &b[3usize]) as i8),
-            -(u8::lt(&a[4usize], &b[4usize]) as i8),
-            -(u8::lt(&a[5usize], &b[5usize]) as i8),
-            -(u8::lt(&a[6usize], &b[6usize]) as i8),
-            -(u8::lt(&a[7usize], &b[7usize]) as i8),
-            -(u8::lt(&a[8usize], &b[8usize]) as i8),
-            -(u8::lt(&a[9usize], &b[9usize]) as i8),
-            -(u8::lt(&a[10usize], &b[10usize]) as i8),
-            -(u8::lt(&a[11usize], &b[11usize]) as i8),
-            -(u8::lt(&a[12usize], &b[12usize]) as i8),
-            -(u8::lt(&a[13usize], &b[13usize]) as i8),
-            -(u8::lt(&a[14usize], &b[14usize]) as i8),
-            -(u8::lt(&a[15usize], &b[15usize]) as i8),
+            a[0usize] as u16,
+            a[1usize] as u16,
+            a[2usize] as u16,
+            a[3usize] as u16,
+            a[4usize] as u16,
+            a[5usize] as u16,
+            a[6usize] as u16,
+            a[7usize] as u16,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
-        [
-            -(u8::le(&a[0usize], &b[0usize]) as i8),
-            -(u8::le(&a[1usize], &b[1usize]) as i8),
-            -(u8::le(&a[2usize], &b[2usize]) as i8),
-            -(u8::le(&a[3usize], &b[3usize]) as i8),
-            -(u8::le(&a[4usize], &b[4usize]) as i8),
-            -(u8::le(&a[5usize], &b[5usize]) as i8),
-            -(u8::le(&a[6usize], &b[6usize]) as i8),
-            -(u8::le(&a[7usize], &b[7usize]) as i8),
-            -(u8::le(&a[8usize], &b[8usize]) as i8),
-            -(u8::le(&a[9usize], &b[9usize]) as i8),
-            -(u8::le(&a[10usize], &b[10usize]) as i8),
-            -(u8::le(&a[11usize], &b[11usize]) as i8),
-            -(u8::le(&a[12usize], &b[12usize]) as i8),
-            -(u8::le(&a[13usize], &b[13usize]) as i8),
-            -(u8::le(&a[14usize], &b[14usize]) as i8),
-            -(u8::le(&a[15usize], &b[15usize]) as i8),
-        ]
-        .simd_into(self)
-    }
-    #[inline(always)]
-    fn simd_ge_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
-        [
-            -(u8::ge(&a[0usize], &b[0usize]) as i8),
-            -(u8::ge(&a[1usize], &b[1usize]) as i8),
-            -(u8::ge(&a[2usize], &b[2usize]) as i8),
-            -(u8::ge(&a[3usize], &b[3usize]) as i8),
-            -(u8::ge(&a[4usize], &b[4usize]) as i8),
-            -(u8::ge(&a[5usize], &b[5usize]) as i8),
-            -(u8::ge(&a[6usize], &b[6usize]) as i8),
-            -(u8::ge(&a[7usize], &b[7usize]) as i8),
-            -(u8::ge(&a[8usize], &b[8usize]) as i8),
-            -(u8::ge(&a[9usize], &b[9usize]) as i8),
-            -(u8::ge(&a[10usize], &b[10usize]) as i8),
-            -(u8::ge(&a[11usize], &b[11usize]) as i8),
-            -(u8::ge(&a[12usize], &b[12usize]) as i8),
-            -(u8::ge(&a[13usize], &b[13usize]) as i8),
-            -(u8::ge(&a[14usize], &b[14usize]) as i8),
-            -(u8::ge(&a[15usize], &b[15usize]) as i8),
-        ]
-        .simd_into(self)
-    }
-    #[inline(always)]
-    fn simd_gt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
-        [
-            -(u8::gt(&a[0usize], &b[0usize]) as i8),
-            -(u8::gt(&a[1usize], &b[1usize]) as i8),
-            -(u8::gt(&a[2usize], &b[2usize]) as i8),
-            -(u8::gt(&a[3usize], &b[3usize]) as i8),
-            -(u8::gt(&a[4usize], &b[4usize]) as i8),
-            -(u8::gt(&a[5usize], &b[5usize]) as i8),
-            -(u8::gt(&a[6usize], &b[6usize]) as i8),
-            -(u8::gt(&a[7usize], &b[7usize]) as i8),
-            -(u8::gt(&a[8usize], &b[8usize]) as i8),
-            -(u8::gt(&a[9usize], &b[9usize]) as i8),
-            -(u8::gt(&a[10usize], &b[10usize]) as i8),
-            -(u8::gt(&a[11usize], &b[11usize]) as i8),
-            -(u8::gt(&a[12usize], &b[12usize]) as i8),
-            -(u8::gt(&a[13usize], &b[13usize]) as i8),
-            -(u8::gt(&a[14usize], &b[14usize]) as i8),
-            -(u8::gt(&a[15usize], &b[15usize]) as i8),
-        ]
-        .simd_into(self)
-    }
-    #[inline(always)]
-    fn zip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
-            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
-        ]
-        .simd_into(self)
+    fn reinterpret_f32_u8x8(self, a: u8x8<Self>) -> f32x2<Self> {
+        f32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn zip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            a[8usize], b[8usize], a[9usize], b[9usize], a[10usize], b[10usize], a[11usize],
-            b[11usize], a[12usize], b[12usize], a[13usize], b[13usize], a[14usize], b[14usize],
-            a[15usize], b[15usize],
-        ]
-        .simd_into(self)
+    fn reinterpret_u16_u8x8(self, a: u8x8<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self> {
-        [
-            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
-            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
-            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
-            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
-            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
-            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
-            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
-            if a[10usize] != 0 {
-                b[10usize]
-            } else {
-                c[10usize]
-            },
-            if a[11usize] != 0 {
-                b[11usize]
-            } else {
-                c[11usize]
-            },
-            if a[12usize] != 0 {
-                b[12usize]
-            } else {
-                c[12usize]
-            },
-            if a[13usize] != 0 {
-                b[13usize]
-            } else {
-                c[13usize]
-            },
-            if a[14usize] != 0 {
-                b[14usize]
-            } else {
-                c[14usize]
-            },
-            if a[15usize] != 0 {
-                b[15usize]
-            } else {
-                c[15usize]
-            },
-        ]
-        .simd_into(self)
+    fn reinterpret_i32_u8x8(self, a: u8x8<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            u8::min(a[0usize], b[0usize]),
-            u8::min(a[1usize], b[1usize]),
-            u8::min(a[2usize], b[2usize]),
-            u8::min(a[3usize], b[3usize]),
-            u8::min(a[4usize], b[4usize]),
-            u8::min(a[5usize], b[5usize]),
-            u8::min(a[6usize], b[6usize]),
-            u8::min(a[7usize], b[7usize]),
-            u8::min(a[8usize], b[8usize]),
-            u8::min(a[9usize], b[9usize]),
-            u8::min(a[10usize], b[10usize]),
-            u8::min(a[11usize], b[11usize]),
-            u8::min(a[12usize], b[12usize]),
-            u8::min(a[13usize], b[13usize]),
-            u8::min(a[14usize], b[14usize]),
-            u8::min(a[15usize], b[15usize]),
-        ]
-        .simd_into(self)
+    fn cvt_f16_u8x8(self, a: u8x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u8x8(a))
     }
     #[inline(always)]
-    fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
-        [
-            u8::max(a[0usize], b[0usize]),
-            u8::max(a[1usize], b[1usize]),
-            u8::max(a[2usize], b[2usize]),
-            u8::max(a[3usize], b[3usize]),
-            u8::max(a[4usize], b[4usize]),
-            u8::max(a[5usize], b[5usize]),
-            u8::max(a[6usize], b[6usize]),
-            u8::max(a[7usize], b[7usize]),
-            u8::max(a[8usize], b[8usize]),
-            u8::max(a[9usize], b[9usize]),
-            u8::max(a[10usize], b[10usize]),
-            u8::max(a[11usize], b[11usize]),
-            u8::max(a[12usize], b[12usize]),
-            u8::max(a[13usize], b[13usize]),
-            u8::max(a[14usize], b[14usize]),
-            u8::max(a[15usize], b[15usize]),
-        ]
-        .simd_into(self)
+    fn cvt_f32_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u16x8(self.widen_u8x8(a))
     }
     #[inline(always)]
-    fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn cvt_f64_u8x8(self, a: u8x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u16x8(self.widen_u8x8(a))
     }
     #[inline(always)]
-    fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self> {
-        [
-            a[0usize] as u16,
-            a[1usize] as u16,
-            a[2usize] as u16,
-            a[3usize] as u16,
-            a[4usize] as u16,
-            a[5usize] as u16,
-            a[6usize] as u16,
-            a[7usize] as u16,
-            a[8usize] as u16,
-            a[9usize] as u16,
-            a[10usize] as u16,
-            a[11usize] as u16,
-            a[12usize] as u16,
-            a[13usize] as u16,
-            a[14usize] as u16,
-            a[15usize] as u16,
-        ]
-        .simd_into(self)
+    fn cvt_f32_unorm_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.div_f32x8(self.cvt_f32_u8x8(a), self.splat_f32x8(255.0))
     }
     #[inline(always)]
-    fn splat_mask8x16(self, val: i8) -> mask8x16<Self> {
-        [val; 16usize].simd_into(self)
+    fn splat_mask8x8(self, val: i8) -> mask8x8<Self> {
+        [val; 8usize].simd_into(self)
     }
     #[inline(always)]
-    fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+    fn not_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
         [
             i8::not(a[0usize]),
             i8::not(a[1usize]),
@@ -1175,19 +1043,11 @@ impl Simd for Fallback {
             i8::not(a[5usize]),
             i8::not(a[6usize]),
             i8::not(a[7usize]),
-            i8::not(a[8usize]),
-            i8::not(a[9usize]),
-            i8::not(a[10usize]),
-            i8::not(a[11usize]),
-            i8::not(a[12usize]),
-            i8::not(a[13usize]),
-            i8::not(a[14usize]),
-            i8::not(a[15usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+    fn and_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
         [
             i8::bitand(a[0usize], &b[0usize]),
             i8::bitand(a[1usize], &b[1usize]),
@@ -1197,19 +1057,11 @@ impl Simd for Fallback {
             i8::bitand(a[5usize], &b[5usize]),
             i8::bitand(a[6usize], &b[6usize]),
             i8::bitand(a[7usize], &b[7usize]),
-            i8::bitand(a[8usize], &b[8usize]),
-            i8::bitand(a[9usize], &b[9usize]),
-            i8::bitand(a[10usize], &b[10usize]),
-            i8::bitand(a[11usize], &b[11usize]),
-            i8::bitand(a[12usize], &b[12usize]),
-            i8::bitand(a[13usize], &b[13usize]),
-            i8::bitand(a[14usize], &b[14usize]),
-            i8::bitand(a[15usize], &b[15usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+    fn or_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
         [
             i8::bitor(a[0usize], &b[0usize]),
             i8::bitor(a[1usize], &b[1usize]),
@@ -1219,19 +1071,11 @@ impl Simd for Fallback {
             i8::bitor(a[5usize], &b[5usize]),
             i8::bitor(a[6usize], &b[6usize]),
             i8::bitor(a[7usize], &b[7usize]),
-            i8::bitor(a[8usize], &b[8usize]),
-            i8::bitor(a[9usize], &b[9usize]),
-            i8::bitor(a[10usize], &b[10usize]),
-            i8::bitor(a[11usize], &b[11usize]),
-            i8::bitor(a[12usize], &b[12usize]),
-            i8::bitor(a[13usize], &b[13usize]),
-            i8::bitor(a[14usize], &b[14usize]),
-            i8::bitor(a[15usize], &b[15usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+    fn xor_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
         [
             i8::bitxor(a[0usize], &b[0usize]),
             i8::bitxor(a[1usize], &b[1usize]),
@@ -1241,24 +1085,11 @@ impl Simd for Fallback {
             i8::bitxor(a[5usize], &b[5usize]),
             i8::bitxor(a[6usize], &b[6usize]),
             i8::bitxor(a[7usize], &b[7usize]),
-            i8::bitxor(a[8usize], &b[8usize]),
-            i8::bitxor(a[9usize], &b[9usize]),
-            i8::bitxor(a[10usize], &b[10usize]),
-            i8::bitxor(a[11usize], &b[11usize]),
-            i8::bitxor(a[12usize], &b[12usize]),
-            i8::bitxor(a[13usize], &b[13usize]),
-            i8::bitxor(a[14usize], &b[14usize]),
-            i8::bitxor(a[15usize], &b[15usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_mask8x16(
-        self,
-        a: mask8x16<Self>,
-        b: mask8x16<Self>,
-        c: mask8x16<Self>,
-    ) -> mask8x16<Self> {
+    fn select_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>, c: mask8x8<Self>) -> mask8x8<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
@@ -1268,43 +1099,11 @@ impl Simd for Fallback {
             if a[5usize] != 0 { b[5usize] } else { c[5usize] },
             if a[6usize] != 0 { b[6usize] } else { c[6usize] },
             if a[7usize] != 0 { b[7usize] } else { c[7usize] },
-            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
-            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
-            if a[10usize] != 0 {
-                b[10usize]
-            } else {
-                c[10usize]
-            },
-            if a[11usize] != 0 {
-                b[11usize]
-            } else {
-                c[11usize]
-            },
-            if a[12usize] != 0 {
-                b[12usize]
-            } else {
-                c[12usize]
-            },
-            if a[13usize] != 0 {
-                b[13usize]
-            } else {
-                c[13usize]
-            },
-            if a[14usize] != 0 {
-                b[14usize]
-            } else {
-                c[14usize]
-            },
-            if a[15usize] != 0 {
-                b[15usize]
-            } else {
-                c[15usize]
-            },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+    fn simd_eq_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
         [
             -(i8::eq(&a[0usize], &b[0usize]) as i8),
             -(i8::eq(&a[1usize], &b[1usize]) as i8),
@@ -1314,1868 +1113,17233 @@ impl Simd for Fallback {
             -(i8::eq(&a[5usize], &b[5usize]) as i8),
             -(i8::eq(&a[6usize], &b[6usize]) as i8),
             -(i8::eq(&a[7usize], &b[7usize]) as i8),
-            -(i8::eq(&a[8usize], &b[8usize]) as i8),
-            -(i8::eq(&a[9usize], &b[9usize]) as i8),
-            -(i8::eq(&a[10usize], &b[10usize]) as i8),
-            -(i8::eq(&a[11usize], &b[11usize]) as i8),
-            -(i8::eq(&a[12usize], &b[12usize]) as i8),
-            -(i8::eq(&a[13usize], &b[13usize]) as i8),
-            -(i8::eq(&a[14usize], &b[14usize]) as i8),
-            -(i8::eq(&a[15usize], &b[15usize]) as i8),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
+    fn any_mask8x8(self, a: mask8x8<Self>) -> bool {
+        a[0usize] != 0
+            || a[1usize] != 0
+            || a[2usize] != 0
+            || a[3usize] != 0
+            || a[4usize] != 0
+            || a[5usize] != 0
+            || a[6usize] != 0
+            || a[7usize] != 0
+    }
+    #[inline(always)]
+    fn all_mask8x8(self, a: mask8x8<Self>) -> bool {
+        a[0usize] != 0
+            && a[1usize] != 0
+            && a[2usize] != 0
+            && a[3usize] != 0
+            && a[4usize] != 0
+            && a[5usize] != 0
+            && a[6usize] != 0
+            && a[7usize] != 0
+    }
+    #[inline(always)]
+    fn none_mask8x8(self, a: mask8x8<Self>) -> bool {
+        !self.any_mask8x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x8(self, a: mask8x8<Self>) -> usize {
+        self.to_bitmask_mask8x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x8(self, a: mask8x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x8(self, a: mask8x8<Self>) -> u64 {
+        (a[0] != 0) as u64
+            | ((a[1usize] != 0) as u64) << 1
+            | ((a[2usize] != 0) as u64) << 2
+            | ((a[3usize] != 0) as u64) << 3
+            | ((a[4usize] != 0) as u64) << 4
+            | ((a[5usize] != 0) as u64) << 5
+            | ((a[6usize] != 0) as u64) << 6
+            | ((a[7usize] != 0) as u64) << 7
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x8(self, bits: u64) -> mask8x8<Self> {
+        [
+            -((bits & 1) as i8),
+            -(((bits >> 1) & 1) as i8),
+            -(((bits >> 2) & 1) as i8),
+            -(((bits >> 3) & 1) as i8),
+            -(((bits >> 4) & 1) as i8),
+            -(((bits >> 5) & 1) as i8),
+            -(((bits >> 6) & 1) as i8),
+            -(((bits >> 7) & 1) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x8<const L: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
         result.simd_into(self)
     }
     #[inline(always)]
-    fn splat_i16x8(self, val: i16) -> i16x8<Self> {
-        [val; 8usize].simd_into(self)
+    fn reinterpret_u8_mask8x8(self, a: mask8x8<Self>) -> u8x8<Self> {
+        u8x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+    fn reinterpret_u16_mask8x8(self, a: mask8x8<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x8(self, a: mask8x8<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_u16x4(self, val: u16) -> u16x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::not(a[0usize]),
-            i16::not(a[1usize]),
-            i16::not(a[2usize]),
-            i16::not(a[3usize]),
-            i16::not(a[4usize]),
-            i16::not(a[5usize]),
-            i16::not(a[6usize]),
-            i16::not(a[7usize]),
+            u16::not(a[0usize]),
+            u16::not(a[1usize]),
+            u16::not(a[2usize]),
+            u16::not(a[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::add(a[0usize], &b[0usize]),
-            i16::add(a[1usize], &b[1usize]),
-            i16::add(a[2usize], &b[2usize]),
-            i16::add(a[3usize], &b[3usize]),
-            i16::add(a[4usize], &b[4usize]),
-            i16::add(a[5usize], &b[5usize]),
-            i16::add(a[6usize], &b[6usize]),
-            i16::add(a[7usize], &b[7usize]),
+            u16::wrapping_add(a[0usize], b[0usize]),
+            u16::wrapping_add(a[1usize], b[1usize]),
+            u16::wrapping_add(a[2usize], b[2usize]),
+            u16::wrapping_add(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::wrapping_sub(a[0usize], b[0usize]),
-            i16::wrapping_sub(a[1usize], b[1usize]),
-            i16::wrapping_sub(a[2usize], b[2usize]),
-            i16::wrapping_sub(a[3usize], b[3usize]),
-            i16::wrapping_sub(a[4usize], b[4usize]),
-            i16::wrapping_sub(a[5usize], b[5usize]),
-            i16::wrapping_sub(a[6usize], b[6usize]),
-            i16::wrapping_sub(a[7usize], b[7usize]),
+            u16::wrapping_sub(a[0usize], b[0usize]),
+            u16::wrapping_sub(a[1usize], b[1usize]),
+            u16::wrapping_sub(a[2usize], b[2usize]),
+            u16::wrapping_sub(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn mul_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn mul_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::mul(a[0usize], &b[0usize]),
-            i16::mul(a[1usize], &b[1usize]),
-            i16::mul(a[2usize], &b[2usize]),
-            i16::mul(a[3usize], &b[3usize]),
-            i16::mul(a[4usize], &b[4usize]),
-            i16::mul(a[5usize], &b[5usize]),
-            i16::mul(a[6usize], &b[6usize]),
-            i16::mul(a[7usize], &b[7usize]),
+            u16::wrapping_mul(a[0usize], b[0usize]),
+            u16::wrapping_mul(a[1usize], b[1usize]),
+            u16::wrapping_mul(a[2usize], b[2usize]),
+            u16::wrapping_mul(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn and_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::bitand(a[0usize], &b[0usize]),
-            i16::bitand(a[1usize], &b[1usize]),
-            i16::bitand(a[2usize], &b[2usize]),
-            i16::bitand(a[3usize], &b[3usize]),
-            i16::bitand(a[4usize], &b[4usize]),
-            i16::bitand(a[5usize], &b[5usize]),
-            i16::bitand(a[6usize], &b[6usize]),
-            i16::bitand(a[7usize], &b[7usize]),
+            u16::bitand(a[0usize], &b[0usize]),
+            u16::bitand(a[1usize], &b[1usize]),
+            u16::bitand(a[2usize], &b[2usize]),
+            u16::bitand(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn or_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::bitor(a[0usize], &b[0usize]),
-            i16::bitor(a[1usize], &b[1usize]),
-            i16::bitor(a[2usize], &b[2usize]),
-            i16::bitor(a[3usize], &b[3usize]),
-            i16::bitor(a[4usize], &b[4usize]),
-            i16::bitor(a[5usize], &b[5usize]),
-            i16::bitor(a[6usize], &b[6usize]),
-            i16::bitor(a[7usize], &b[7usize]),
+            u16::bitor(a[0usize], &b[0usize]),
+            u16::bitor(a[1usize], &b[1usize]),
+            u16::bitor(a[2usize], &b[2usize]),
+            u16::bitor(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn xor_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::bitxor(a[0usize], &b[0usize]),
-            i16::bitxor(a[1usize], &b[1usize]),
-            i16::bitxor(a[2usize], &b[2usize]),
-            i16::bitxor(a[3usize], &b[3usize]),
-            i16::bitxor(a[4usize], &b[4usize]),
-            i16::bitxor(a[5usize], &b[5usize]),
-            i16::bitxor(a[6usize], &b[6usize]),
-            i16::bitxor(a[7usize], &b[7usize]),
+            u16::bitxor(a[0usize], &b[0usize]),
+            u16::bitxor(a[1usize], &b[1usize]),
+            u16::bitxor(a[2usize], &b[2usize]),
+            u16::bitxor(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn shr_i16x8(self, a: i16x8<Self>, b: u32) -> i16x8<Self> {
+    fn shl_u16x4(self, a: u16x4<Self>, b: u32) -> u16x4<Self> {
         [
-            i16::shr(a[0usize], b as i16),
-            i16::shr(a[1usize], b as i16),
-            i16::shr(a[2usize], b as i16),
-            i16::shr(a[3usize], b as i16),
-            i16::shr(a[4usize], b as i16),
-            i16::shr(a[5usize], b as i16),
-            i16::shr(a[6usize], b as i16),
-            i16::shr(a[7usize], b as i16),
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+    fn shr_u16x4(self, a: u16x4<Self>, b: u32) -> u16x4<Self> {
         [
-            -(i16::eq(&a[0usize], &b[0usize]) as i16),
-            -(i16::eq(&a[1usize], &b[1usize]) as i16),
-            -(i16::eq(&a[2usize], &b[2usize]) as i16),
-            -(i16::eq(&a[3usize], &b[3usize]) as i16),
-            -(i16::eq(&a[4usize], &b[4usize]) as i16),
-            -(i16::eq(&a[5usize], &b[5usize]) as i16),
-            -(i16::eq(&a[6usize], &b[6usize]) as i16),
-            -(i16::eq(&a[7usize], &b[7usize]) as i16),
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+            a[2usize].checked_shr(b).unwrap_or(0),
+            a[3usize].checked_shr(b).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+    fn shl_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            -(i16::lt(&a[0usize], &b[0usize]) as i16),
-            -(i16::lt(&a[1usize], &b[1usize]) as i16),
-            -(i16::lt(&a[2usize], &b[2usize]) as i16),
-            -(i16::lt(&a[3usize], &b[3usize]) as i16),
-            -(i16::lt(&a[4usize], &b[4usize]) as i16),
-            -(i16::lt(&a[5usize], &b[5usize]) as i16),
-            -(i16::lt(&a[6usize], &b[6usize]) as i16),
-            -(i16::lt(&a[7usize], &b[7usize]) as i16),
+            a[0usize].checked_shl(b[0usize].min(16) as u32).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(16) as u32).unwrap_or(0),
+            a[2usize].checked_shl(b[2usize].min(16) as u32).unwrap_or(0),
+            a[3usize].checked_shl(b[3usize].min(16) as u32).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+    fn shr_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            -(i16::le(&a[0usize], &b[0usize]) as i16),
-            -(i16::le(&a[1usize], &b[1usize]) as i16),
-            -(i16::le(&a[2usize], &b[2usize]) as i16),
-            -(i16::le(&a[3usize], &b[3usize]) as i16),
-            -(i16::le(&a[4usize], &b[4usize]) as i16),
-            -(i16::le(&a[5usize], &b[5usize]) as i16),
-            -(i16::le(&a[6usize], &b[6usize]) as i16),
-            -(i16::le(&a[7usize], &b[7usize]) as i16),
+            a[0usize].checked_shr(b[0usize].min(16) as u32).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(16) as u32).unwrap_or(0),
+            a[2usize].checked_shr(b[2usize].min(16) as u32).unwrap_or(0),
+            a[3usize].checked_shr(b[3usize].min(16) as u32).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+    fn simd_eq_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
         [
-            -(i16::ge(&a[0usize], &b[0usize]) as i16),
-            -(i16::ge(&a[1usize], &b[1usize]) as i16),
-            -(i16::ge(&a[2usize], &b[2usize]) as i16),
-            -(i16::ge(&a[3usize], &b[3usize]) as i16),
-            -(i16::ge(&a[4usize], &b[4usize]) as i16),
-            -(i16::ge(&a[5usize], &b[5usize]) as i16),
-            -(i16::ge(&a[6usize], &b[6usize]) as i16),
-            -(i16::ge(&a[7usize], &b[7usize]) as i16),
+            -(u16::eq(&a[0usize], &b[0usize]) as i16),
+            -(u16::eq(&a[1usize], &b[1usize]) as i16),
+            -(u16::eq(&a[2usize], &b[2usize]) as i16),
+            -(u16::eq(&a[3usize], &b[3usize]) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+    fn simd_lt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
         [
-            -(i16::gt(&a[0usize], &b[0usize]) as i16),
-            -(i16::gt(&a[1usize], &b[1usize]) as i16),
-            -(i16::gt(&a[2usize], &b[2usize]) as i16),
-            -(i16::gt(&a[3usize], &b[3usize]) as i16),
-            -(i16::gt(&a[4usize], &b[4usize]) as i16),
-            -(i16::gt(&a[5usize], &b[5usize]) as i16),
-            -(i16::gt(&a[6usize], &b[6usize]) as i16),
-            -(i16::gt(&a[7usize], &b[7usize]) as i16),
+            -(u16::lt(&a[0usize], &b[0usize]) as i16),
+            -(u16::lt(&a[1usize], &b[1usize]) as i16),
+            -(u16::lt(&a[2usize], &b[2usize]) as i16),
+            -(u16::lt(&a[3usize], &b[3usize]) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn simd_le_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
         [
-            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+            -(u16::le(&a[0usize], &b[0usize]) as i16),
+            -(u16::le(&a[1usize], &b[1usize]) as i16),
+            -(u16::le(&a[2usize], &b[2usize]) as i16),
+            -(u16::le(&a[3usize], &b[3usize]) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn simd_ge_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
         [
-            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+            -(u16::ge(&a[0usize], &b[0usize]) as i16),
+            -(u16::ge(&a[1usize], &b[1usize]) as i16),
+            -(u16::ge(&a[2usize], &b[2usize]) as i16),
+            -(u16::ge(&a[3usize], &b[3usize]) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self> {
+    fn simd_gt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        [
+            -(u16::gt(&a[0usize], &b[0usize]) as i16),
+            -(u16::gt(&a[1usize], &b[1usize]) as i16),
+            -(u16::gt(&a[2usize], &b[2usize]) as i16),
+            -(u16::gt(&a[3usize], &b[3usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_u16x4(self, a: mask16x4<Self>, b: u16x4<Self>, c: u16x4<Self>) -> u16x4<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
             if a[2usize] != 0 { b[2usize] } else { c[2usize] },
             if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
-            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
-            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
-            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn min_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::min(a[0usize], b[0usize]),
-            i16::min(a[1usize], b[1usize]),
-            i16::min(a[2usize], b[2usize]),
-            i16::min(a[3usize], b[3usize]),
-            i16::min(a[4usize], b[4usize]),
-            i16::min(a[5usize], b[5usize]),
-            i16::min(a[6usize], b[6usize]),
-            i16::min(a[7usize], b[7usize]),
+            u16::min(a[0usize], b[0usize]),
+            u16::min(a[1usize], b[1usize]),
+            u16::min(a[2usize], b[2usize]),
+            u16::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+    fn max_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            i16::max(a[0usize], b[0usize]),
-            i16::max(a[1usize], b[1usize]),
-            i16::max(a[2usize], b[2usize]),
-            i16::max(a[3usize], b[3usize]),
-            i16::max(a[4usize], b[4usize]),
-            i16::max(a[5usize], b[5usize]),
-            i16::max(a[6usize], b[6usize]),
-            i16::max(a[7usize], b[7usize]),
+            u16::max(a[0usize], b[0usize]),
+            u16::max(a[1usize], b[1usize]),
+            u16::max(a[2usize], b[2usize]),
+            u16::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reduce_add_u16x4(self, a: u16x4<Self>) -> u16 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
     }
     #[inline(always)]
-    fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
-        u8x16 {
-            val: bytemuck::cast(a.val),
-            simd: a.simd,
-        }
+    fn reduce_min_u16x4(self, a: u16x4<Self>) -> u16 {
+        a[0].min(a[1]).min(a[2].min(a[3]))
     }
     #[inline(always)]
-    fn splat_u16x8(self, val: u16) -> u16x8<Self> {
-        [val; 8usize].simd_into(self)
+    fn reduce_max_u16x4(self, a: u16x4<Self>) -> u16 {
+        a[0].max(a[1]).max(a[2].max(a[3]))
     }
     #[inline(always)]
-    fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::not(a[0usize]),
-            u16::not(a[1usize]),
-            u16::not(a[2usize]),
-            u16::not(a[3usize]),
-            u16::not(a[4usize]),
-            u16::not(a[5usize]),
-            u16::not(a[6usize]),
-            u16::not(a[7usize]),
-        ]
-        .simd_into(self)
+    fn reduce_and_u16x4(self, a: u16x4<Self>) -> u16 {
+        (a[0] & a[1]) & (a[2] & a[3])
     }
     #[inline(always)]
-    fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::add(a[0usize], &b[0usize]),
-            u16::add(a[1usize], &b[1usize]),
-            u16::add(a[2usize], &b[2usize]),
-            u16::add(a[3usize], &b[3usize]),
-            u16::add(a[4usize], &b[4usize]),
-            u16::add(a[5usize], &b[5usize]),
-            u16::add(a[6usize], &b[6usize]),
-            u16::add(a[7usize], &b[7usize]),
-        ]
-        .simd_into(self)
+    fn reduce_or_u16x4(self, a: u16x4<Self>) -> u16 {
+        (a[0] | a[1]) | (a[2] | a[3])
     }
     #[inline(always)]
-    fn sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::wrapping_sub(a[0usize], b[0usize]),
-            u16::wrapping_sub(a[1usize], b[1usize]),
-            u16::wrapping_sub(a[2usize], b[2usize]),
-            u16::wrapping_sub(a[3usize], b[3usize]),
-            u16::wrapping_sub(a[4usize], b[4usize]),
-            u16::wrapping_sub(a[5usize], b[5usize]),
-            u16::wrapping_sub(a[6usize], b[6usize]),
-            u16::wrapping_sub(a[7usize], b[7usize]),
-        ]
-        .simd_into(self)
+    fn shuffle_u16x4<I: Indices<4>>(self, a: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn mul_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::mul(a[0usize], &b[0usize]),
-            u16::mul(a[1usize], &b[1usize]),
-            u16::mul(a[2usize], &b[2usize]),
-            u16::mul(a[3usize], &b[3usize]),
-            u16::mul(a[4usize], &b[4usize]),
-            u16::mul(a[5usize], &b[5usize]),
-            u16::mul(a[6usize], &b[6usize]),
-            u16::mul(a[7usize], &b[7usize]),
-        ]
-        .simd_into(self)
+    fn shuffle2_u16x4<I: Indices<4>>(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::bitand(a[0usize], &b[0usize]),
-            u16::bitand(a[1usize], &b[1usize]),
-            u16::bitand(a[2usize], &b[2usize]),
-            u16::bitand(a[3usize], &b[3usize]),
-            u16::bitand(a[4usize], &b[4usize]),
-            u16::bitand(a[5usize], &b[5usize]),
-            u16::bitand(a[6usize], &b[6usize]),
-            u16::bitand(a[7usize], &b[7usize]),
-        ]
-        .simd_into(self)
+    fn reverse_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::bitor(a[0usize], &b[0usize]),
-            u16::bitor(a[1usize], &b[1usize]),
-            u16::bitor(a[2usize], &b[2usize]),
-            u16::bitor(a[3usize], &b[3usize]),
-            u16::bitor(a[4usize], &b[4usize]),
-            u16::bitor(a[5usize], &b[5usize]),
-            u16::bitor(a[6usize], &b[6usize]),
-            u16::bitor(a[7usize], &b[7usize]),
-        ]
-        .simd_into(self)
+    fn rotate_lanes_left_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::bitxor(a[0usize], &b[0usize]),
-            u16::bitxor(a[1usize], &b[1usize]),
-            u16::bitxor(a[2usize], &b[2usize]),
-            u16::bitxor(a[3usize], &b[3usize]),
-            u16::bitxor(a[4usize], &b[4usize]),
-            u16::bitxor(a[5usize], &b[5usize]),
-            u16::bitxor(a[6usize], &b[6usize]),
-            u16::bitxor(a[7usize], &b[7usize]),
-        ]
-        .simd_into(self)
+    fn rotate_lanes_right_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn shr_u16x8(self, a: u16x8<Self>, b: u32) -> u16x8<Self> {
-        [
-            u16::shr(a[0usize], b as u16),
-            u16::shr(a[1usize], b as u16),
-            u16::shr(a[2usize], b as u16),
-            u16::shr(a[3usize], b as u16),
-            u16::shr(a[4usize], b as u16),
-            u16::shr(a[5usize], b as u16),
-            u16::shr(a[6usize], b as u16),
-            u16::shr(a[7usize], b as u16),
-        ]
-        .simd_into(self)
+    fn broadcast_lane_u16x4<const L: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
-        [
-            -(u16::eq(&a[0usize], &b[0usize]) as i16),
-            -(u16::eq(&a[1usize], &b[1usize]) as i16),
-            -(u16::eq(&a[2usize], &b[2usize]) as i16),
-            -(u16::eq(&a[3usize], &b[3usize]) as i16),
-            -(u16::eq(&a[4usize], &b[4usize]) as i16),
-            -(u16::eq(&a[5usize], &b[5usize]) as i16),
-            -(u16::eq(&a[6usize], &b[6usize]) as i16),
-            -(u16::eq(&a[7usize], &b[7usize]) as i16),
-        ]
-        .simd_into(self)
+    fn unzip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
-        [
-            -(u16::lt(&a[0usize], &b[0usize]) as i16),
-            -(u16::lt(&a[1usize], &b[1usize]) as i16),
-            -(u16::lt(&a[2usize], &b[2usize]) as i16),
-            -(u16::lt(&a[3usize], &b[3usize]) as i16),
-            -(u16::lt(&a[4usize], &b[4usize]) as i16),
-            -(u16::lt(&a[5usize], &b[5usize]) as i16),
-            -(u16::lt(&a[6usize], &b[6usize]) as i16),
-            -(u16::lt(&a[7usize], &b[7usize]) as i16),
-        ]
-        .simd_into(self)
+    fn unzip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+    fn saturating_add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            -(u16::le(&a[0usize], &b[0usize]) as i16),
-            -(u16::le(&a[1usize], &b[1usize]) as i16),
-            -(u16::le(&a[2usize], &b[2usize]) as i16),
-            -(u16::le(&a[3usize], &b[3usize]) as i16),
-            -(u16::le(&a[4usize], &b[4usize]) as i16),
-            -(u16::le(&a[5usize], &b[5usize]) as i16),
-            -(u16::le(&a[6usize], &b[6usize]) as i16),
-            -(u16::le(&a[7usize], &b[7usize]) as i16),
+            u16::saturating_add(a[0usize], b[0usize]),
+            u16::saturating_add(a[1usize], b[1usize]),
+            u16::saturating_add(a[2usize], b[2usize]),
+            u16::saturating_add(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+    fn saturating_sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            -(u16::ge(&a[0usize], &b[0usize]) as i16),
-            -(u16::ge(&a[1usize], &b[1usize]) as i16),
-            -(u16::ge(&a[2usize], &b[2usize]) as i16),
-            -(u16::ge(&a[3usize], &b[3usize]) as i16),
-            -(u16::ge(&a[4usize], &b[4usize]) as i16),
-            -(u16::ge(&a[5usize], &b[5usize]) as i16),
-            -(u16::ge(&a[6usize], &b[6usize]) as i16),
-            -(u16::ge(&a[7usize], &b[7usize]) as i16),
+            u16::saturating_sub(a[0usize], b[0usize]),
+            u16::saturating_sub(a[1usize], b[1usize]),
+            u16::saturating_sub(a[2usize], b[2usize]),
+            u16::saturating_sub(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+    fn abs_diff_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            -(u16::gt(&a[0usize], &b[0usize]) as i16),
-            -(u16::gt(&a[1usize], &b[1usize]) as i16),
-            -(u16::gt(&a[2usize], &b[2usize]) as i16),
-            -(u16::gt(&a[3usize], &b[3usize]) as i16),
-            -(u16::gt(&a[4usize], &b[4usize]) as i16),
-            -(u16::gt(&a[5usize], &b[5usize]) as i16),
-            -(u16::gt(&a[6usize], &b[6usize]) as i16),
-            -(u16::gt(&a[7usize], &b[7usize]) as i16),
+            u16::abs_diff(a[0usize], b[0usize]),
+            u16::abs_diff(a[1usize], b[1usize]),
+            u16::abs_diff(a[2usize], b[2usize]),
+            u16::abs_diff(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+    fn avg_round_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+            ((a[0usize] as u32 + b[0usize] as u32 + 1) >> 1) as u16,
+            ((a[1usize] as u32 + b[1usize] as u32 + 1) >> 1) as u16,
+            ((a[2usize] as u32 + b[2usize] as u32 + 1) >> 1) as u16,
+            ((a[3usize] as u32 + b[3usize] as u32 + 1) >> 1) as u16,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+    fn mul_widen_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u32x4<Self> {
         [
-            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+            a[0usize] as u32 * b[0usize] as u32,
+            a[1usize] as u32 * b[1usize] as u32,
+            a[2usize] as u32 * b[2usize] as u32,
+            a[3usize] as u32 * b[3usize] as u32,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self> {
+    fn mul_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
         [
-            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
-            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
-            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
-            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
-            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+            ((a[0usize] as u32 * b[0usize] as u32) >> 16) as u16,
+            ((a[1usize] as u32 * b[1usize] as u32) >> 16) as u16,
+            ((a[2usize] as u32 * b[2usize] as u32) >> 16) as u16,
+            ((a[3usize] as u32 * b[3usize] as u32) >> 16) as u16,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
-        [
-            u16::min(a[0usize], b[0usize]),
-            u16::min(a[1usize], b[1usize]),
-            u16::min(a[2usize], b[2usize]),
-            u16::min(a[3usize], b[3usize]),
-            u16::min(a[4usize], b[4usize]),
-            u16::min(a[5usize], b[5usize]),
-            u16::min(a[6usize], b[6usize]),
-            u16::min(a[7usize], b[7usize]),
-        ]
-        .simd_into(self)
+    fn combine_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
     }
     #[inline(always)]
-    fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+    fn widen_u16x4(self, a: u16x4<Self>) -> u32x4<Self> {
         [
-            u16::max(a[0usize], b[0usize]),
-            u16::max(a[1usize], b[1usize]),
-            u16::max(a[2usize], b[2usize]),
-            u16::max(a[3usize], b[3usize]),
-            u16::max(a[4usize], b[4usize]),
-            u16::max(a[5usize], b[5usize]),
-            u16::max(a[6usize], b[6usize]),
-            u16::max(a[7usize], b[7usize]),
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reinterpret_f32_u16x4(self, a: u16x4<Self>) -> f32x2<Self> {
+        f32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
-        u8x16 {
+    fn reinterpret_u8_u16x4(self, a: u16x4<Self>) -> u8x8<Self> {
+        u8x8 {
             val: bytemuck::cast(a.val),
             simd: a.simd,
         }
     }
     #[inline(always)]
-    fn splat_mask16x8(self, val: i16) -> mask16x8<Self> {
-        [val; 8usize].simd_into(self)
+    fn reinterpret_i32_u16x4(self, a: u16x4<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+    fn cvt_f32_u16x4(self, a: u16x4<Self>) -> f32x4<Self> {
+        self.cvt_f32_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x4(self, a: u16x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x4(self, val: i16) -> mask16x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
         [
             i16::not(a[0usize]),
             i16::not(a[1usize]),
             i16::not(a[2usize]),
             i16::not(a[3usize]),
-            i16::not(a[4usize]),
-            i16::not(a[5usize]),
-            i16::not(a[6usize]),
-            i16::not(a[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+    fn and_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
         [
             i16::bitand(a[0usize], &b[0usize]),
             i16::bitand(a[1usize], &b[1usize]),
             i16::bitand(a[2usize], &b[2usize]),
             i16::bitand(a[3usize], &b[3usize]),
-            i16::bitand(a[4usize], &b[4usize]),
-            i16::bitand(a[5usize], &b[5usize]),
-            i16::bitand(a[6usize], &b[6usize]),
-            i16::bitand(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+    fn or_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
         [
             i16::bitor(a[0usize], &b[0usize]),
             i16::bitor(a[1usize], &b[1usize]),
             i16::bitor(a[2usize], &b[2usize]),
             i16::bitor(a[3usize], &b[3usize]),
-            i16::bitor(a[4usize], &b[4usize]),
-            i16::bitor(a[5usize], &b[5usize]),
-            i16::bitor(a[6usize], &b[6usize]),
-            i16::bitor(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+    fn xor_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
         [
             i16::bitxor(a[0usize], &b[0usize]),
             i16::bitxor(a[1usize], &b[1usize]),
             i16::bitxor(a[2usize], &b[2usize]),
             i16::bitxor(a[3usize], &b[3usize]),
-            i16::bitxor(a[4usize], &b[4usize]),
-            i16::bitxor(a[5usize], &b[5usize]),
-            i16::bitxor(a[6usize], &b[6usize]),
-            i16::bitxor(a[7usize], &b[7usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn select_mask16x8(
+    fn select_mask16x4(
         self,
-        a: mask16x8<Self>,
-        b: mask16x8<Self>,
-        c: mask16x8<Self>,
-    ) -> mask16x8<Self> {
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+        c: mask16x4<Self>,
+    ) -> mask16x4<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
             if a[2usize] != 0 { b[2usize] } else { c[2usize] },
             if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
-            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
-            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
-            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+    fn simd_eq_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
         [
             -(i16::eq(&a[0usize], &b[0usize]) as i16),
             -(i16::eq(&a[1usize], &b[1usize]) as i16),
             -(i16::eq(&a[2usize], &b[2usize]) as i16),
             -(i16::eq(&a[3usize], &b[3usize]) as i16),
-            -(i16::eq(&a[4usize], &b[4usize]) as i16),
-            -(i16::eq(&a[5usize], &b[5usize]) as i16),
-            -(i16::eq(&a[6usize], &b[6usize]) as i16),
-            -(i16::eq(&a[7usize], &b[7usize]) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn any_mask16x4(self, a: mask16x4<Self>) -> bool {
+        a[0usize] != 0 || a[1usize] != 0 || a[2usize] != 0 || a[3usize] != 0
     }
     #[inline(always)]
-    fn splat_i32x4(self, val: i32) -> i32x4<Self> {
-        [val; 4usize].simd_into(self)
+    fn all_mask16x4(self, a: mask16x4<Self>) -> bool {
+        a[0usize] != 0 && a[1usize] != 0 && a[2usize] != 0 && a[3usize] != 0
     }
     #[inline(always)]
-    fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
-        [
-            i32::not(a[0usize]),
-            i32::not(a[1usize]),
-            i32::not(a[2usize]),
-            i32::not(a[3usize]),
+    fn none_mask16x4(self, a: mask16x4<Self>) -> bool {
+        !self.any_mask16x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x4(self, a: mask16x4<Self>) -> usize {
+        self.to_bitmask_mask16x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x4(self, a: mask16x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x4(self, a: mask16x4<Self>) -> u64 {
+        (a[0] != 0) as u64
+            | ((a[1usize] != 0) as u64) << 1
+            | ((a[2usize] != 0) as u64) << 2
+            | ((a[3usize] != 0) as u64) << 3
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x4(self, bits: u64) -> mask16x4<Self> {
+        [
+            -((bits & 1) as i16),
+            -(((bits >> 1) & 1) as i16),
+            -(((bits >> 2) & 1) as i16),
+            -(((bits >> 3) & 1) as i16),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn shuffle_mask16x4<I: Indices<4>>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x4<I: Indices<4>>(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+    ) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x4<const L: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x4(self, a: mask16x4<Self>) -> u8x8<Self> {
+        u8x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x4(self, a: mask16x4<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x4(self, a: mask16x4<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_i32x2(self, val: i32) -> i32x2<Self> {
+        [val; 2usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        [i32::not(a[0usize]), i32::not(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn add_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
-            i32::add(a[0usize], &b[0usize]),
-            i32::add(a[1usize], &b[1usize]),
-            i32::add(a[2usize], &b[2usize]),
-            i32::add(a[3usize], &b[3usize]),
+            i32::wrapping_add(a[0usize], b[0usize]),
+            i32::wrapping_add(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn sub_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::wrapping_sub(a[0usize], b[0usize]),
             i32::wrapping_sub(a[1usize], b[1usize]),
-            i32::wrapping_sub(a[2usize], b[2usize]),
-            i32::wrapping_sub(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn mul_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn mul_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
-            i32::mul(a[0usize], &b[0usize]),
-            i32::mul(a[1usize], &b[1usize]),
-            i32::mul(a[2usize], &b[2usize]),
-            i32::mul(a[3usize], &b[3usize]),
+            i32::wrapping_mul(a[0usize], b[0usize]),
+            i32::wrapping_mul(a[1usize], b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn and_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::bitand(a[0usize], &b[0usize]),
             i32::bitand(a[1usize], &b[1usize]),
-            i32::bitand(a[2usize], &b[2usize]),
-            i32::bitand(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn or_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::bitor(a[0usize], &b[0usize]),
             i32::bitor(a[1usize], &b[1usize]),
-            i32::bitor(a[2usize], &b[2usize]),
-            i32::bitor(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn xor_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::bitxor(a[0usize], &b[0usize]),
             i32::bitxor(a[1usize], &b[1usize]),
-            i32::bitxor(a[2usize], &b[2usize]),
-            i32::bitxor(a[3usize], &b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn shr_i32x4(self, a: i32x4<Self>, b: u32) -> i32x4<Self> {
+    fn shl_i32x2(self, a: i32x2<Self>, b: u32) -> i32x2<Self> {
         [
-            i32::shr(a[0usize], b as i32),
-            i32::shr(a[1usize], b as i32),
-            i32::shr(a[2usize], b as i32),
-            i32::shr(a[3usize], b as i32),
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+    fn shr_i32x2(self, a: i32x2<Self>, b: u32) -> i32x2<Self> {
+        [a[0usize] >> b.min(31), a[1usize] >> b.min(31)].simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        [
+            a[0usize]
+                .checked_shl((b[0usize] as u32).min(32))
+                .unwrap_or(0),
+            a[1usize]
+                .checked_shl((b[1usize] as u32).min(32))
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        [
+            a[0usize] >> (b[0usize] as u32).min(31),
+            a[1usize] >> (b[1usize] as u32).min(31),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
         [
             -(i32::eq(&a[0usize], &b[0usize]) as i32),
             -(i32::eq(&a[1usize], &b[1usize]) as i32),
-            -(i32::eq(&a[2usize], &b[2usize]) as i32),
-            -(i32::eq(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+    fn simd_lt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
         [
             -(i32::lt(&a[0usize], &b[0usize]) as i32),
             -(i32::lt(&a[1usize], &b[1usize]) as i32),
-            -(i32::lt(&a[2usize], &b[2usize]) as i32),
-            -(i32::lt(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+    fn simd_le_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
         [
             -(i32::le(&a[0usize], &b[0usize]) as i32),
             -(i32::le(&a[1usize], &b[1usize]) as i32),
-            -(i32::le(&a[2usize], &b[2usize]) as i32),
-            -(i32::le(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+    fn simd_ge_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
         [
             -(i32::ge(&a[0usize], &b[0usize]) as i32),
             -(i32::ge(&a[1usize], &b[1usize]) as i32),
-            -(i32::ge(&a[2usize], &b[2usize]) as i32),
-            -(i32::ge(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+    fn simd_gt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
         [
             -(i32::gt(&a[0usize], &b[0usize]) as i32),
             -(i32::gt(&a[1usize], &b[1usize]) as i32),
-            -(i32::gt(&a[2usize], &b[2usize]) as i32),
-            -(i32::gt(&a[3usize], &b[3usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
-        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    fn zip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        [a[0usize], b[0usize]].simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
-        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    fn zip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        [a[1usize], b[1usize]].simd_into(self)
     }
     #[inline(always)]
-    fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self> {
+    fn select_i32x2(self, a: mask32x2<Self>, b: i32x2<Self>, c: i32x2<Self>) -> i32x2<Self> {
         [
             if a[0usize] != 0 { b[0usize] } else { c[0usize] },
             if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn min_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::min(a[0usize], b[0usize]),
             i32::min(a[1usize], b[1usize]),
-            i32::min(a[2usize], b[2usize]),
-            i32::min(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+    fn max_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
         [
             i32::max(a[0usize], b[0usize]),
             i32::max(a[1usize], b[1usize]),
-            i32::max(a[2usize], b[2usize]),
-            i32::max(a[3usize], b[3usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
-        let mut result = [0; 8usize];
-        result[0..4usize].copy_from_slice(&a.val);
-        result[4usize..8usize].copy_from_slice(&b.val);
+    fn reduce_add_i32x2(self, a: i32x2<Self>) -> i32 {
+        a[0].wrapping_add(a[1])
+    }
+    #[inline(always)]
+    fn reduce_min_i32x2(self, a: i32x2<Self>) -> i32 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_i32x2(self, a: i32x2<Self>) -> i32 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_i32x2(self, a: i32x2<Self>) -> i32 {
+        a[0] & a[1]
+    }
+    #[inline(always)]
+    fn reduce_or_i32x2(self, a: i32x2<Self>) -> i32 {
+        a[0] | a[1]
+    }
+    #[inline(always)]
+    fn shuffle_i32x2<I: Indices<2>>(self, a: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x2<I: Indices<2>>(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x2<const L: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        [i32::wrapping_abs(a[0usize]), i32::wrapping_abs(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        [i32::wrapping_neg(a[0usize]), i32::wrapping_neg(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
         result.simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
-        u8x16 {
+    fn widen_i32x2(self, a: i32x2<Self>) -> i64x2<Self> {
+        [a[0usize] as i64, a[1usize] as i64].simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        f32x2 {
             val: bytemuck::cast(a.val),
             simd: a.simd,
         }
     }
     #[inline(always)]
-    fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
-        [
-            a[0usize] as f32,
-            a[1usize] as f32,
-            a[2usize] as f32,
-            a[3usize] as f32,
-        ]
-        .simd_into(self)
+    fn reinterpret_u8_i32x2(self, a: i32x2<Self>) -> u8x8<Self> {
+        u8x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn splat_u32x4(self, val: u32) -> u32x4<Self> {
-        [val; 4usize].simd_into(self)
+    fn reinterpret_u16_i32x2(self, a: i32x2<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::not(a[0usize]),
-            u32::not(a[1usize]),
-            u32::not(a[2usize]),
-            u32::not(a[3usize]),
-        ]
-        .simd_into(self)
+    fn cvt_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        [a[0usize] as f32, a[1usize] as f32].simd_into(self)
     }
     #[inline(always)]
-    fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::add(a[0usize], &b[0usize]),
-            u32::add(a[1usize], &b[1usize]),
-            u32::add(a[2usize], &b[2usize]),
-            u32::add(a[3usize], &b[3usize]),
-        ]
-        .simd_into(self)
+    fn cvt_f64_i32x2(self, a: i32x2<Self>) -> f64x2<Self> {
+        self.cvt_f64_i64x2(self.widen_i32x2(a))
     }
     #[inline(always)]
-    fn sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::wrapping_sub(a[0usize], b[0usize]),
-            u32::wrapping_sub(a[1usize], b[1usize]),
-            u32::wrapping_sub(a[2usize], b[2usize]),
-            u32::wrapping_sub(a[3usize], b[3usize]),
-        ]
-        .simd_into(self)
+    fn splat_mask32x2(self, val: i32) -> mask32x2<Self> {
+        [val; 2usize].simd_into(self)
     }
     #[inline(always)]
-    fn mul_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::mul(a[0usize], &b[0usize]),
-            u32::mul(a[1usize], &b[1usize]),
-            u32::mul(a[2usize], &b[2usize]),
-            u32::mul(a[3usize], &b[3usize]),
-        ]
-        .simd_into(self)
+    fn not_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        [i32::not(a[0usize]), i32::not(a[1usize])].simd_into(self)
     }
     #[inline(always)]
-    fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+    fn and_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
         [
-            u32::bitand(a[0usize], &b[0usize]),
-            u32::bitand(a[1usize], &b[1usize]),
-            u32::bitand(a[2usize], &b[2usize]),
-            u32::bitand(a[3usize], &b[3usize]),
+            i32::bitand(a[0usize], &b[0usize]),
+            i32::bitand(a[1usize], &b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+    fn or_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
         [
-            u32::bitor(a[0usize], &b[0usize]),
-            u32::bitor(a[1usize], &b[1usize]),
-            u32::bitor(a[2usize], &b[2usize]),
-            u32::bitor(a[3usize], &b[3usize]),
+            i32::bitor(a[0usize], &b[0usize]),
+            i32::bitor(a[1usize], &b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+    fn xor_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
         [
-            u32::bitxor(a[0usize], &b[0usize]),
-            u32::bitxor(a[1usize], &b[1usize]),
-            u32::bitxor(a[2usize], &b[2usize]),
-            u32::bitxor(a[3usize], &b[3usize]),
+            i32::bitxor(a[0usize], &b[0usize]),
+            i32::bitxor(a[1usize], &b[1usize]),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn shr_u32x4(self, a: u32x4<Self>, b: u32) -> u32x4<Self> {
+    fn select_mask32x2(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+        c: mask32x2<Self>,
+    ) -> mask32x2<Self> {
         [
-            u32::shr(a[0usize], b as u32),
-            u32::shr(a[1usize], b as u32),
-            u32::shr(a[2usize], b as u32),
-            u32::shr(a[3usize], b as u32),
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+    fn simd_eq_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
         [
-            -(u32::eq(&a[0usize], &b[0usize]) as i32),
-            -(u32::eq(&a[1usize], &b[1usize]) as i32),
-            -(u32::eq(&a[2usize], &b[2usize]) as i32),
-            -(u32::eq(&a[3usize], &b[3usize]) as i32),
+            -(i32::eq(&a[0usize], &b[0usize]) as i32),
+            -(i32::eq(&a[1usize], &b[1usize]) as i32),
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
-        [
-            -(u32::lt(&a[0usize], &b[0usize]) as i32),
-            -(u32::lt(&a[1usize], &b[1usize]) as i32),
-            -(u32::lt(&a[2usize], &b[2usize]) as i32),
-            -(u32::lt(&a[3usize], &b[3usize]) as i32),
-        ]
-        .simd_into(self)
+    fn any_mask32x2(self, a: mask32x2<Self>) -> bool {
+        a[0usize] != 0 || a[1usize] != 0
     }
     #[inline(always)]
-    fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
-        [
-            -(u32::le(&a[0usize], &b[0usize]) as i32),
-            -(u32::le(&a[1usize], &b[1usize]) as i32),
-            -(u32::le(&a[2usize], &b[2usize]) as i32),
-            -(u32::le(&a[3usize], &b[3usize]) as i32),
-        ]
-        .simd_into(self)
+    fn all_mask32x2(self, a: mask32x2<Self>) -> bool {
+        a[0usize] != 0 && a[1usize] != 0
     }
     #[inline(always)]
-    fn simd_ge_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
-        [
-            -(u32::ge(&a[0usize], &b[0usize]) as i32),
-            -(u32::ge(&a[1usize], &b[1usize]) as i32),
-            -(u32::ge(&a[2usize], &b[2usize]) as i32),
-            -(u32::ge(&a[3usize], &b[3usize]) as i32),
-        ]
-        .simd_into(self)
+    fn none_mask32x2(self, a: mask32x2<Self>) -> bool {
+        !self.any_mask32x2(a)
     }
     #[inline(always)]
-    fn simd_gt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
-        [
-            -(u32::gt(&a[0usize], &b[0usize]) as i32),
-            -(u32::gt(&a[1usize], &b[1usize]) as i32),
-            -(u32::gt(&a[2usize], &b[2usize]) as i32),
-            -(u32::gt(&a[3usize], &b[3usize]) as i32),
-        ]
-        .simd_into(self)
+    fn count_true_mask32x2(self, a: mask32x2<Self>) -> usize {
+        self.to_bitmask_mask32x2(a).count_ones() as usize
     }
     #[inline(always)]
-    fn zip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    fn first_true_mask32x2(self, a: mask32x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
     }
     #[inline(always)]
-    fn zip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    fn to_bitmask_mask32x2(self, a: mask32x2<Self>) -> u64 {
+        (a[0] != 0) as u64 | ((a[1usize] != 0) as u64) << 1
     }
     #[inline(always)]
-    fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self> {
-        [
-            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
-            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-        ]
-        .simd_into(self)
+    fn from_bitmask_mask32x2(self, bits: u64) -> mask32x2<Self> {
+        [-((bits & 1) as i32), -(((bits >> 1) & 1) as i32)].simd_into(self)
     }
     #[inline(always)]
-    fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::min(a[0usize], b[0usize]),
-            u32::min(a[1usize], b[1usize]),
-            u32::min(a[2usize], b[2usize]),
-            u32::min(a[3usize], b[3usize]),
-        ]
-        .simd_into(self)
+    fn shuffle_mask32x2<I: Indices<2>>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
-        [
-            u32::max(a[0usize], b[0usize]),
-            u32::max(a[1usize], b[1usize]),
-            u32::max(a[2usize], b[2usize]),
-            u32::max(a[3usize], b[3usize]),
-        ]
-        .simd_into(self)
+    fn shuffle2_mask32x2<I: Indices<2>>(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+    ) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
-        let mut result = [0; 8usize];
-        result[0..4usize].copy_from_slice(&a.val);
-        result[4usize..8usize].copy_from_slice(&b.val);
+    fn reverse_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x2<const L: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
         result.simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
-        u8x16 {
+    fn reinterpret_u8_mask32x2(self, a: mask32x2<Self>) -> u8x8<Self> {
+        u8x8 {
             val: bytemuck::cast(a.val),
             simd: a.simd,
         }
     }
     #[inline(always)]
-    fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
-        [
-            a[0usize] as f32,
-            a[1usize] as f32,
-            a[2usize] as f32,
-            a[3usize] as f32,
-        ]
-        .simd_into(self)
+    fn reinterpret_u16_mask32x2(self, a: mask32x2<Self>) -> u16x4<Self> {
+        u16x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn splat_mask32x4(self, val: i32) -> mask32x4<Self> {
-        [val; 4usize].simd_into(self)
+    fn reinterpret_i32_mask32x2(self, a: mask32x2<Self>) -> i32x2<Self> {
+        i32x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
     }
     #[inline(always)]
-    fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
-        [
-            i32::not(a[0usize]),
-            i32::not(a[1usize]),
-            i32::not(a[2usize]),
-            i32::not(a[3usize]),
-        ]
-        .simd_into(self)
+    fn splat_f16x8(self, val: f16) -> f16x8<Self> {
+        [val; 8usize].simd_into(self)
     }
     #[inline(always)]
-    fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
-        [
-            i32::bitand(a[0usize], &b[0usize]),
-            i32::bitand(a[1usize], &b[1usize]),
-            i32::bitand(a[2usize], &b[2usize]),
-            i32::bitand(a[3usize], &b[3usize]),
-        ]
-        .simd_into(self)
+    fn abs_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.abs_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.neg_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.sqrt_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_approx_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_approx_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_f32x8(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_f32x8(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.add_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.sub_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.div_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.copysign_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_eq_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ne_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_lt_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_le_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ge_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_gt_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_ne_f16x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_infinite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_eq_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        let abs = self.abs_f16x8(a);
+        self.and_mask16x8(
+            self.simd_lt_f16x8(abs, self.splat_f16x8(f16::from_bits(0x0400))),
+            self.simd_gt_f16x8(abs, self.splat_f16x8(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(
+            self.copysign_f16x8(self.splat_f16x8(f16::from_bits(0x3c00)), a),
+            self.splat_f16x8(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[i / 2]
+            } else {
+                b.val[i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[4usize + i / 2]
+            } else {
+                b.val[4usize + i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_precise_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_precise_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.madd_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn msub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.msub_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmadd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmadd_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmsub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmsub_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        let product = self.mul_f32x8(a, b);
+        let sum = self.add_f32x8(product, c);
+        let product_part = self.sub_f32x8(sum, c);
+        let c_part = self.sub_f32x8(sum, product_part);
+        let error = self.add_f32x8(
+            self.sub_f32x8(product, product_part),
+            self.sub_f32x8(c, c_part),
+        );
+        let sum_bits: u32x8<Self> = sum.bitcast();
+        let error_bits: u32x8<Self> = error.bitcast();
+        let zero = self.splat_u32x8(0);
+        let same_sign = self.simd_eq_u32x8(
+            self.shr_u32x8(self.xor_u32x8(sum_bits, error_bits), 31),
+            zero,
+        );
+        let step = self.select_u32x8(same_sign, self.splat_u32x8(1), self.splat_u32x8(u32::MAX));
+        let odd = self.simd_eq_u32x8(
+            self.and_u32x8(sum_bits, self.splat_u32x8(1)),
+            self.splat_u32x8(1),
+        );
+        let step = self.select_u32x8(odd, zero, step);
+        let step = self.select_u32x8(self.simd_eq_f32x8(error, self.splat_f32x8(0.0)), zero, step);
+        let finite = self.simd_lt_f32x8(self.abs_f32x8(sum), self.splat_f32x8(f32::INFINITY));
+        let step = self.select_u32x8(finite, step, zero);
+        let sum: f32x8<Self> = self.add_u32x8(sum_bits, step).bitcast();
+        sum.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_add_fast_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn floor_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.floor_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.ceil_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_ties_even_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.fract_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.trunc_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f16x8(self, a: mask16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] =
+            core::array::from_fn(|i| if a.val[i] != 0 { b.val[i] } else { c.val[i] });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x8(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x8<I: Indices<8>>(self, a: f16x8<Self>) -> f16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f16x8<I: Indices<8>>(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x8<const L: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x16<Self> {
+        let mut result = [f16::from_bits(0); 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x8(self, a: f16x8<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val.map(f16::to_bits)).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x8(self, a: f16x8<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x8(self, a: f16x8<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x8(self, a: f16x8<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x8(self, a: f16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x8(self, a: f16x8<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x8(self, a: f16x8<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x8(self, a: f16x8<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x8(self, a: f16x8<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val.map(f16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x8(self, a: f16x8<Self>) -> f32x8<Self> {
+        a.val.map(f16::to_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_f16x8(self, a: f16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x8(self, val: bf16) -> bf16x8<Self> {
+        self.splat_u16x8(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x8(self, a: mask16x8<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> bf16x8<Self> {
+        self.select_u16x8(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_bf16x8(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x8<const L: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x16<Self> {
+        self.combine_u16x8(a.bitcast(), b.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x8(self, a: bf16x8<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val.map(bf16::to_bits)).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x8(self, a: bf16x8<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x8(self, a: bf16x8<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x8(self, a: bf16x8<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x8(self, a: bf16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x8(self, a: bf16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x8(self, a: bf16x8<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x8(self, a: bf16x8<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x8(self, a: bf16x8<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x8(self, a: bf16x8<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x8(self, a: bf16x8<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val.map(bf16::to_bits)),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x8(self, a: bf16x8<Self>) -> f32x8<Self> {
+        a.val.map(bf16::to_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_f32x4(self, val: f32) -> f32x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::abs(a[0usize]),
+            f32::abs(a[1usize]),
+            f32::abs(a[2usize]),
+            f32::abs(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::neg(a[0usize]),
+            f32::neg(a[1usize]),
+            f32::neg(a[2usize]),
+            f32::neg(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::sqrt(a[0usize]),
+            f32::sqrt(a[1usize]),
+            f32::sqrt(a[2usize]),
+            f32::sqrt(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        self.div_f32x4(self.splat_f32x4(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        self.div_f32x4(self.splat_f32x4(1.0), self.sqrt_f32x4(a))
+    }
+    #[inline(always)]
+    fn recip_f32x4(self, a: f32x4<Self>, _steps: u32) -> f32x4<Self> {
+        self.div_f32x4(self.splat_f32x4(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_f32x4(self, a: f32x4<Self>, _steps: u32) -> f32x4<Self> {
+        self.div_f32x4(self.splat_f32x4(1.0), self.sqrt_f32x4(a))
+    }
+    #[inline(always)]
+    fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::add(a[0usize], &b[0usize]),
+            f32::add(a[1usize], &b[1usize]),
+            f32::add(a[2usize], &b[2usize]),
+            f32::add(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::sub(a[0usize], &b[0usize]),
+            f32::sub(a[1usize], &b[1usize]),
+            f32::sub(a[2usize], &b[2usize]),
+            f32::sub(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::mul(a[0usize], &b[0usize]),
+            f32::mul(a[1usize], &b[1usize]),
+            f32::mul(a[2usize], &b[2usize]),
+            f32::mul(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::div(a[0usize], &b[0usize]),
+            f32::div(a[1usize], &b[1usize]),
+            f32::div(a[2usize], &b[2usize]),
+            f32::div(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::copysign(a[0usize], b[0usize]),
+            f32::copysign(a[1usize], b[1usize]),
+            f32::copysign(a[2usize], b[2usize]),
+            f32::copysign(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::eq(&a[0usize], &b[0usize]) as i32),
+            -(f32::eq(&a[1usize], &b[1usize]) as i32),
+            -(f32::eq(&a[2usize], &b[2usize]) as i32),
+            -(f32::eq(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::ne(&a[0usize], &b[0usize]) as i32),
+            -(f32::ne(&a[1usize], &b[1usize]) as i32),
+            -(f32::ne(&a[2usize], &b[2usize]) as i32),
+            -(f32::ne(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::lt(&a[0usize], &b[0usize]) as i32),
+            -(f32::lt(&a[1usize], &b[1usize]) as i32),
+            -(f32::lt(&a[2usize], &b[2usize]) as i32),
+            -(f32::lt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::le(&a[0usize], &b[0usize]) as i32),
+            -(f32::le(&a[1usize], &b[1usize]) as i32),
+            -(f32::le(&a[2usize], &b[2usize]) as i32),
+            -(f32::le(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::ge(&a[0usize], &b[0usize]) as i32),
+            -(f32::ge(&a[1usize], &b[1usize]) as i32),
+            -(f32::ge(&a[2usize], &b[2usize]) as i32),
+            -(f32::ge(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        [
+            -(f32::gt(&a[0usize], &b[0usize]) as i32),
+            -(f32::gt(&a[1usize], &b[1usize]) as i32),
+            -(f32::gt(&a[2usize], &b[2usize]) as i32),
+            -(f32::gt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_ne_f32x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_eq_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        let abs = self.abs_f32x4(a);
+        self.and_mask32x4(
+            self.simd_lt_f32x4(abs, self.splat_f32x4(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x4(abs, self.splat_f32x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(
+            self.copysign_f32x4(self.splat_f32x4(1.0), a),
+            self.splat_f32x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::max(a[0usize], b[0usize]),
+            f32::max(a[1usize], b[1usize]),
+            f32::max(a[2usize], b[2usize]),
+            f32::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::max(a[0usize], b[0usize]),
+            f32::max(a[1usize], b[1usize]),
+            f32::max(a[2usize], b[2usize]),
+            f32::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::min(a[0usize], b[0usize]),
+            f32::min(a[1usize], b[1usize]),
+            f32::min(a[2usize], b[2usize]),
+            f32::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::min(a[0usize], b[0usize]),
+            f32::min(a[1usize], b[1usize]),
+            f32::min(a[2usize], b[2usize]),
+            f32::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        a.add(b.mul(c))
+    }
+    #[inline(always)]
+    fn msub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        a.sub(b.mul(c))
+    }
+    #[inline(always)]
+    fn nmadd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.madd_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.msub_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::mul_add(a[0usize], b[0usize], c[0usize]),
+            f32::mul_add(a[1usize], b[1usize], c[1usize]),
+            f32::mul_add(a[2usize], b[2usize], c[2usize]),
+            f32::mul_add(a[3usize], b[3usize], c[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.madd_f32x4(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::floor(a[0usize]),
+            f32::floor(a[1usize]),
+            f32::floor(a[2usize]),
+            f32::floor(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::ceil(a[0usize]),
+            f32::ceil(a[1usize]),
+            f32::ceil(a[2usize]),
+            f32::ceil(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::round(a[0usize]),
+            f32::round(a[1usize]),
+            f32::round(a[2usize]),
+            f32::round(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::round_ties_even(a[0usize]),
+            f32::round_ties_even(a[1usize]),
+            f32::round_ties_even(a[2usize]),
+            f32::round_ties_even(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::fract(a[0usize]),
+            f32::fract(a[1usize]),
+            f32::fract(a[2usize]),
+            f32::fract(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        [
+            f32::trunc(a[0usize]),
+            f32::trunc(a[1usize]),
+            f32::trunc(a[2usize]),
+            f32::trunc(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f32x4(self, a: f32x4<Self>) -> f32 {
+        (a[0] + a[1]) + (a[2] + a[3])
+    }
+    #[inline(always)]
+    fn reduce_min_f32x4(self, a: f32x4<Self>) -> f32 {
+        a[0].min(a[1]).min(a[2].min(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x4(self, a: f32x4<Self>) -> f32 {
+        a[0].max(a[1]).max(a[2].max(a[3]))
+    }
+    #[inline(always)]
+    fn shuffle_f32x4<I: Indices<4>>(self, a: f32x4<Self>) -> f32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x4<I: Indices<4>>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x4<const L: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x4(self, a: f32x4<Self>) -> (f32x2<Self>, f32x2<Self>) {
+        let mut b0 = [0.0; 2usize];
+        let mut b1 = [0.0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x4(self, a: f32x4<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x4(self, a: f32x4<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x4(self, a: f32x4<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x4(self, a: f32x4<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x4(self, a: f32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x4(self, a: f32x4<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x4(self, a: f32x4<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x4(self, a: f32x4<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x4(self, a: f32x4<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x4(self, a: f32x4<Self>) -> f64x4<Self> {
+        [
+            a[0usize] as f64,
+            a[1usize] as f64,
+            a[2usize] as f64,
+            a[3usize] as f64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        [
+            a[0usize] as i32,
+            a[1usize] as i32,
+            a[2usize] as i32,
+            a[3usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        [
+            f32::round_ties_even(a[0usize]) as i32,
+            f32::round_ties_even(a[1usize]) as i32,
+            f32::round_ties_even(a[2usize]) as i32,
+            f32::round_ties_even(a[3usize]) as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        [
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        [
+            f32::round_ties_even(a[0usize]) as u32,
+            f32::round_ties_even(a[1usize]) as u32,
+            f32::round_ties_even(a[2usize]) as u32,
+            f32::round_ties_even(a[3usize]) as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        let b = self.cvt_f32_bf16x8(b).val;
+        let c = self.cvt_f32_bf16x8(c).val;
+        let result: [f32; 4usize] =
+            core::array::from_fn(|i| a.val[i] + b[2 * i + 1] * c[2 * i + 1] + b[2 * i] * c[2 * i]);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn mmla_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        let b = self.cvt_f32_bf16x8(b).val;
+        let c = self.cvt_f32_bf16x8(c).val;
+        let result: [f32; 4usize] = core::array::from_fn(|i| {
+            let (row, col) = (4 * (i / 2), 4 * (i % 2));
+            a.val[i]
+                + b[row] * c[col]
+                + b[row + 1] * c[col + 1]
+                + b[row + 2] * c[col + 2]
+                + b[row + 3] * c[col + 3]
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i8x16(self, val: i8) -> i8x16<Self> {
+        [val; 16usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::not(a[0usize]),
+            i8::not(a[1usize]),
+            i8::not(a[2usize]),
+            i8::not(a[3usize]),
+            i8::not(a[4usize]),
+            i8::not(a[5usize]),
+            i8::not(a[6usize]),
+            i8::not(a[7usize]),
+            i8::not(a[8usize]),
+            i8::not(a[9usize]),
+            i8::not(a[10usize]),
+            i8::not(a[11usize]),
+            i8::not(a[12usize]),
+            i8::not(a[13usize]),
+            i8::not(a[14usize]),
+            i8::not(a[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_add(a[0usize], b[0usize]),
+            i8::wrapping_add(a[1usize], b[1usize]),
+            i8::wrapping_add(a[2usize], b[2usize]),
+            i8::wrapping_add(a[3usize], b[3usize]),
+            i8::wrapping_add(a[4usize], b[4usize]),
+            i8::wrapping_add(a[5usize], b[5usize]),
+            i8::wrapping_add(a[6usize], b[6usize]),
+            i8::wrapping_add(a[7usize], b[7usize]),
+            i8::wrapping_add(a[8usize], b[8usize]),
+            i8::wrapping_add(a[9usize], b[9usize]),
+            i8::wrapping_add(a[10usize], b[10usize]),
+            i8::wrapping_add(a[11usize], b[11usize]),
+            i8::wrapping_add(a[12usize], b[12usize]),
+            i8::wrapping_add(a[13usize], b[13usize]),
+            i8::wrapping_add(a[14usize], b[14usize]),
+            i8::wrapping_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_sub(a[0usize], b[0usize]),
+            i8::wrapping_sub(a[1usize], b[1usize]),
+            i8::wrapping_sub(a[2usize], b[2usize]),
+            i8::wrapping_sub(a[3usize], b[3usize]),
+            i8::wrapping_sub(a[4usize], b[4usize]),
+            i8::wrapping_sub(a[5usize], b[5usize]),
+            i8::wrapping_sub(a[6usize], b[6usize]),
+            i8::wrapping_sub(a[7usize], b[7usize]),
+            i8::wrapping_sub(a[8usize], b[8usize]),
+            i8::wrapping_sub(a[9usize], b[9usize]),
+            i8::wrapping_sub(a[10usize], b[10usize]),
+            i8::wrapping_sub(a[11usize], b[11usize]),
+            i8::wrapping_sub(a[12usize], b[12usize]),
+            i8::wrapping_sub(a[13usize], b[13usize]),
+            i8::wrapping_sub(a[14usize], b[14usize]),
+            i8::wrapping_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_mul(a[0usize], b[0usize]),
+            i8::wrapping_mul(a[1usize], b[1usize]),
+            i8::wrapping_mul(a[2usize], b[2usize]),
+            i8::wrapping_mul(a[3usize], b[3usize]),
+            i8::wrapping_mul(a[4usize], b[4usize]),
+            i8::wrapping_mul(a[5usize], b[5usize]),
+            i8::wrapping_mul(a[6usize], b[6usize]),
+            i8::wrapping_mul(a[7usize], b[7usize]),
+            i8::wrapping_mul(a[8usize], b[8usize]),
+            i8::wrapping_mul(a[9usize], b[9usize]),
+            i8::wrapping_mul(a[10usize], b[10usize]),
+            i8::wrapping_mul(a[11usize], b[11usize]),
+            i8::wrapping_mul(a[12usize], b[12usize]),
+            i8::wrapping_mul(a[13usize], b[13usize]),
+            i8::wrapping_mul(a[14usize], b[14usize]),
+            i8::wrapping_mul(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::bitand(a[0usize], &b[0usize]),
+            i8::bitand(a[1usize], &b[1usize]),
+            i8::bitand(a[2usize], &b[2usize]),
+            i8::bitand(a[3usize], &b[3usize]),
+            i8::bitand(a[4usize], &b[4usize]),
+            i8::bitand(a[5usize], &b[5usize]),
+            i8::bitand(a[6usize], &b[6usize]),
+            i8::bitand(a[7usize], &b[7usize]),
+            i8::bitand(a[8usize], &b[8usize]),
+            i8::bitand(a[9usize], &b[9usize]),
+            i8::bitand(a[10usize], &b[10usize]),
+            i8::bitand(a[11usize], &b[11usize]),
+            i8::bitand(a[12usize], &b[12usize]),
+            i8::bitand(a[13usize], &b[13usize]),
+            i8::bitand(a[14usize], &b[14usize]),
+            i8::bitand(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::bitor(a[0usize], &b[0usize]),
+            i8::bitor(a[1usize], &b[1usize]),
+            i8::bitor(a[2usize], &b[2usize]),
+            i8::bitor(a[3usize], &b[3usize]),
+            i8::bitor(a[4usize], &b[4usize]),
+            i8::bitor(a[5usize], &b[5usize]),
+            i8::bitor(a[6usize], &b[6usize]),
+            i8::bitor(a[7usize], &b[7usize]),
+            i8::bitor(a[8usize], &b[8usize]),
+            i8::bitor(a[9usize], &b[9usize]),
+            i8::bitor(a[10usize], &b[10usize]),
+            i8::bitor(a[11usize], &b[11usize]),
+            i8::bitor(a[12usize], &b[12usize]),
+            i8::bitor(a[13usize], &b[13usize]),
+            i8::bitor(a[14usize], &b[14usize]),
+            i8::bitor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::bitxor(a[0usize], &b[0usize]),
+            i8::bitxor(a[1usize], &b[1usize]),
+            i8::bitxor(a[2usize], &b[2usize]),
+            i8::bitxor(a[3usize], &b[3usize]),
+            i8::bitxor(a[4usize], &b[4usize]),
+            i8::bitxor(a[5usize], &b[5usize]),
+            i8::bitxor(a[6usize], &b[6usize]),
+            i8::bitxor(a[7usize], &b[7usize]),
+            i8::bitxor(a[8usize], &b[8usize]),
+            i8::bitxor(a[9usize], &b[9usize]),
+            i8::bitxor(a[10usize], &b[10usize]),
+            i8::bitxor(a[11usize], &b[11usize]),
+            i8::bitxor(a[12usize], &b[12usize]),
+            i8::bitxor(a[13usize], &b[13usize]),
+            i8::bitxor(a[14usize], &b[14usize]),
+            i8::bitxor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_i8x16(self, a: i8x16<Self>, b: u32) -> i8x16<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+            a[4usize].checked_shl(b).unwrap_or(0),
+            a[5usize].checked_shl(b).unwrap_or(0),
+            a[6usize].checked_shl(b).unwrap_or(0),
+            a[7usize].checked_shl(b).unwrap_or(0),
+            a[8usize].checked_shl(b).unwrap_or(0),
+            a[9usize].checked_shl(b).unwrap_or(0),
+            a[10usize].checked_shl(b).unwrap_or(0),
+            a[11usize].checked_shl(b).unwrap_or(0),
+            a[12usize].checked_shl(b).unwrap_or(0),
+            a[13usize].checked_shl(b).unwrap_or(0),
+            a[14usize].checked_shl(b).unwrap_or(0),
+            a[15usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i8x16(self, a: i8x16<Self>, b: u32) -> i8x16<Self> {
+        [
+            a[0usize] >> b.min(7),
+            a[1usize] >> b.min(7),
+            a[2usize] >> b.min(7),
+            a[3usize] >> b.min(7),
+            a[4usize] >> b.min(7),
+            a[5usize] >> b.min(7),
+            a[6usize] >> b.min(7),
+            a[7usize] >> b.min(7),
+            a[8usize] >> b.min(7),
+            a[9usize] >> b.min(7),
+            a[10usize] >> b.min(7),
+            a[11usize] >> b.min(7),
+            a[12usize] >> b.min(7),
+            a[13usize] >> b.min(7),
+            a[14usize] >> b.min(7),
+            a[15usize] >> b.min(7),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize]
+                .checked_shl((b[0usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[1usize]
+                .checked_shl((b[1usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[2usize]
+                .checked_shl((b[2usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[3usize]
+                .checked_shl((b[3usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[4usize]
+                .checked_shl((b[4usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[5usize]
+                .checked_shl((b[5usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[6usize]
+                .checked_shl((b[6usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[7usize]
+                .checked_shl((b[7usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[8usize]
+                .checked_shl((b[8usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[9usize]
+                .checked_shl((b[9usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[10usize]
+                .checked_shl((b[10usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[11usize]
+                .checked_shl((b[11usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[12usize]
+                .checked_shl((b[12usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[13usize]
+                .checked_shl((b[13usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[14usize]
+                .checked_shl((b[14usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+            a[15usize]
+                .checked_shl((b[15usize] as u8).min(8) as u32)
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize] >> (b[0usize] as u8).min(7),
+            a[1usize] >> (b[1usize] as u8).min(7),
+            a[2usize] >> (b[2usize] as u8).min(7),
+            a[3usize] >> (b[3usize] as u8).min(7),
+            a[4usize] >> (b[4usize] as u8).min(7),
+            a[5usize] >> (b[5usize] as u8).min(7),
+            a[6usize] >> (b[6usize] as u8).min(7),
+            a[7usize] >> (b[7usize] as u8).min(7),
+            a[8usize] >> (b[8usize] as u8).min(7),
+            a[9usize] >> (b[9usize] as u8).min(7),
+            a[10usize] >> (b[10usize] as u8).min(7),
+            a[11usize] >> (b[11usize] as u8).min(7),
+            a[12usize] >> (b[12usize] as u8).min(7),
+            a[13usize] >> (b[13usize] as u8).min(7),
+            a[14usize] >> (b[14usize] as u8).min(7),
+            a[15usize] >> (b[15usize] as u8).min(7),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::eq(&a[0usize], &b[0usize]) as i8),
+            -(i8::eq(&a[1usize], &b[1usize]) as i8),
+            -(i8::eq(&a[2usize], &b[2usize]) as i8),
+            -(i8::eq(&a[3usize], &b[3usize]) as i8),
+            -(i8::eq(&a[4usize], &b[4usize]) as i8),
+            -(i8::eq(&a[5usize], &b[5usize]) as i8),
+            -(i8::eq(&a[6usize], &b[6usize]) as i8),
+            -(i8::eq(&a[7usize], &b[7usize]) as i8),
+            -(i8::eq(&a[8usize], &b[8usize]) as i8),
+            -(i8::eq(&a[9usize], &b[9usize]) as i8),
+            -(i8::eq(&a[10usize], &b[10usize]) as i8),
+            -(i8::eq(&a[11usize], &b[11usize]) as i8),
+            -(i8::eq(&a[12usize], &b[12usize]) as i8),
+            -(i8::eq(&a[13usize], &b[13usize]) as i8),
+            -(i8::eq(&a[14usize], &b[14usize]) as i8),
+            -(i8::eq(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::lt(&a[0usize], &b[0usize]) as i8),
+            -(i8::lt(&a[1usize], &b[1usize]) as i8),
+            -(i8::lt(&a[2usize], &b[2usize]) as i8),
+            -(i8::lt(&a[3usize], &b[3usize]) as i8),
+            -(i8::lt(&a[4usize], &b[4usize]) as i8),
+            -(i8::lt(&a[5usize], &b[5usize]) as i8),
+            -(i8::lt(&a[6usize], &b[6usize]) as i8),
+            -(i8::lt(&a[7usize], &b[7usize]) as i8),
+            -(i8::lt(&a[8usize], &b[8usize]) as i8),
+            -(i8::lt(&a[9usize], &b[9usize]) as i8),
+            -(i8::lt(&a[10usize], &b[10usize]) as i8),
+            -(i8::lt(&a[11usize], &b[11usize]) as i8),
+            -(i8::lt(&a[12usize], &b[12usize]) as i8),
+            -(i8::lt(&a[13usize], &b[13usize]) as i8),
+            -(i8::lt(&a[14usize], &b[14usize]) as i8),
+            -(i8::lt(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::le(&a[0usize], &b[0usize]) as i8),
+            -(i8::le(&a[1usize], &b[1usize]) as i8),
+            -(i8::le(&a[2usize], &b[2usize]) as i8),
+            -(i8::le(&a[3usize], &b[3usize]) as i8),
+            -(i8::le(&a[4usize], &b[4usize]) as i8),
+            -(i8::le(&a[5usize], &b[5usize]) as i8),
+            -(i8::le(&a[6usize], &b[6usize]) as i8),
+            -(i8::le(&a[7usize], &b[7usize]) as i8),
+            -(i8::le(&a[8usize], &b[8usize]) as i8),
+            -(i8::le(&a[9usize], &b[9usize]) as i8),
+            -(i8::le(&a[10usize], &b[10usize]) as i8),
+            -(i8::le(&a[11usize], &b[11usize]) as i8),
+            -(i8::le(&a[12usize], &b[12usize]) as i8),
+            -(i8::le(&a[13usize], &b[13usize]) as i8),
+            -(i8::le(&a[14usize], &b[14usize]) as i8),
+            -(i8::le(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::ge(&a[0usize], &b[0usize]) as i8),
+            -(i8::ge(&a[1usize], &b[1usize]) as i8),
+            -(i8::ge(&a[2usize], &b[2usize]) as i8),
+            -(i8::ge(&a[3usize], &b[3usize]) as i8),
+            -(i8::ge(&a[4usize], &b[4usize]) as i8),
+            -(i8::ge(&a[5usize], &b[5usize]) as i8),
+            -(i8::ge(&a[6usize], &b[6usize]) as i8),
+            -(i8::ge(&a[7usize], &b[7usize]) as i8),
+            -(i8::ge(&a[8usize], &b[8usize]) as i8),
+            -(i8::ge(&a[9usize], &b[9usize]) as i8),
+            -(i8::ge(&a[10usize], &b[10usize]) as i8),
+            -(i8::ge(&a[11usize], &b[11usize]) as i8),
+            -(i8::ge(&a[12usize], &b[12usize]) as i8),
+            -(i8::ge(&a[13usize], &b[13usize]) as i8),
+            -(i8::ge(&a[14usize], &b[14usize]) as i8),
+            -(i8::ge(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::gt(&a[0usize], &b[0usize]) as i8),
+            -(i8::gt(&a[1usize], &b[1usize]) as i8),
+            -(i8::gt(&a[2usize], &b[2usize]) as i8),
+            -(i8::gt(&a[3usize], &b[3usize]) as i8),
+            -(i8::gt(&a[4usize], &b[4usize]) as i8),
+            -(i8::gt(&a[5usize], &b[5usize]) as i8),
+            -(i8::gt(&a[6usize], &b[6usize]) as i8),
+            -(i8::gt(&a[7usize], &b[7usize]) as i8),
+            -(i8::gt(&a[8usize], &b[8usize]) as i8),
+            -(i8::gt(&a[9usize], &b[9usize]) as i8),
+            -(i8::gt(&a[10usize], &b[10usize]) as i8),
+            -(i8::gt(&a[11usize], &b[11usize]) as i8),
+            -(i8::gt(&a[12usize], &b[12usize]) as i8),
+            -(i8::gt(&a[13usize], &b[13usize]) as i8),
+            -(i8::gt(&a[14usize], &b[14usize]) as i8),
+            -(i8::gt(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            a[8usize], b[8usize], a[9usize], b[9usize], a[10usize], b[10usize], a[11usize],
+            b[11usize], a[12usize], b[12usize], a[13usize], b[13usize], a[14usize], b[14usize],
+            a[15usize], b[15usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
+            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
+            if a[10usize] != 0 {
+                b[10usize]
+            } else {
+                c[10usize]
+            },
+            if a[11usize] != 0 {
+                b[11usize]
+            } else {
+                c[11usize]
+            },
+            if a[12usize] != 0 {
+                b[12usize]
+            } else {
+                c[12usize]
+            },
+            if a[13usize] != 0 {
+                b[13usize]
+            } else {
+                c[13usize]
+            },
+            if a[14usize] != 0 {
+                b[14usize]
+            } else {
+                c[14usize]
+            },
+            if a[15usize] != 0 {
+                b[15usize]
+            } else {
+                c[15usize]
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::min(a[0usize], b[0usize]),
+            i8::min(a[1usize], b[1usize]),
+            i8::min(a[2usize], b[2usize]),
+            i8::min(a[3usize], b[3usize]),
+            i8::min(a[4usize], b[4usize]),
+            i8::min(a[5usize], b[5usize]),
+            i8::min(a[6usize], b[6usize]),
+            i8::min(a[7usize], b[7usize]),
+            i8::min(a[8usize], b[8usize]),
+            i8::min(a[9usize], b[9usize]),
+            i8::min(a[10usize], b[10usize]),
+            i8::min(a[11usize], b[11usize]),
+            i8::min(a[12usize], b[12usize]),
+            i8::min(a[13usize], b[13usize]),
+            i8::min(a[14usize], b[14usize]),
+            i8::min(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::max(a[0usize], b[0usize]),
+            i8::max(a[1usize], b[1usize]),
+            i8::max(a[2usize], b[2usize]),
+            i8::max(a[3usize], b[3usize]),
+            i8::max(a[4usize], b[4usize]),
+            i8::max(a[5usize], b[5usize]),
+            i8::max(a[6usize], b[6usize]),
+            i8::max(a[7usize], b[7usize]),
+            i8::max(a[8usize], b[8usize]),
+            i8::max(a[9usize], b[9usize]),
+            i8::max(a[10usize], b[10usize]),
+            i8::max(a[11usize], b[11usize]),
+            i8::max(a[12usize], b[12usize]),
+            i8::max(a[13usize], b[13usize]),
+            i8::max(a[14usize], b[14usize]),
+            i8::max(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_i8x16(self, a: i8x16<Self>) -> i8 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+            .wrapping_add(
+                a[4].wrapping_add(a[5])
+                    .wrapping_add(a[6].wrapping_add(a[7])),
+            )
+            .wrapping_add(
+                a[8].wrapping_add(a[9])
+                    .wrapping_add(a[10].wrapping_add(a[11]))
+                    .wrapping_add(
+                        a[12]
+                            .wrapping_add(a[13])
+                            .wrapping_add(a[14].wrapping_add(a[15])),
+                    ),
+            )
+    }
+    #[inline(always)]
+    fn reduce_min_i8x16(self, a: i8x16<Self>) -> i8 {
+        a[0].min(a[1])
+            .min(a[2].min(a[3]))
+            .min(a[4].min(a[5]).min(a[6].min(a[7])))
+            .min(
+                a[8].min(a[9])
+                    .min(a[10].min(a[11]))
+                    .min(a[12].min(a[13]).min(a[14].min(a[15]))),
+            )
+    }
+    #[inline(always)]
+    fn reduce_max_i8x16(self, a: i8x16<Self>) -> i8 {
+        a[0].max(a[1])
+            .max(a[2].max(a[3]))
+            .max(a[4].max(a[5]).max(a[6].max(a[7])))
+            .max(
+                a[8].max(a[9])
+                    .max(a[10].max(a[11]))
+                    .max(a[12].max(a[13]).max(a[14].max(a[15]))),
+            )
+    }
+    #[inline(always)]
+    fn reduce_and_i8x16(self, a: i8x16<Self>) -> i8 {
+        (((a[0] & a[1]) & (a[2] & a[3])) & ((a[4] & a[5]) & (a[6] & a[7])))
+            & (((a[8] & a[9]) & (a[10] & a[11])) & ((a[12] & a[13]) & (a[14] & a[15])))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x16(self, a: i8x16<Self>) -> i8 {
+        (((a[0] | a[1]) | (a[2] | a[3])) | ((a[4] | a[5]) | (a[6] | a[7])))
+            | (((a[8] | a[9]) | (a[10] | a[11])) | ((a[12] | a[13]) | (a[14] | a[15])))
+    }
+    #[inline(always)]
+    fn shuffle_i8x16<I: Indices<16>>(self, a: i8x16<Self>) -> i8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x16<I: Indices<16>>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x16<const L: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::saturating_add(a[0usize], b[0usize]),
+            i8::saturating_add(a[1usize], b[1usize]),
+            i8::saturating_add(a[2usize], b[2usize]),
+            i8::saturating_add(a[3usize], b[3usize]),
+            i8::saturating_add(a[4usize], b[4usize]),
+            i8::saturating_add(a[5usize], b[5usize]),
+            i8::saturating_add(a[6usize], b[6usize]),
+            i8::saturating_add(a[7usize], b[7usize]),
+            i8::saturating_add(a[8usize], b[8usize]),
+            i8::saturating_add(a[9usize], b[9usize]),
+            i8::saturating_add(a[10usize], b[10usize]),
+            i8::saturating_add(a[11usize], b[11usize]),
+            i8::saturating_add(a[12usize], b[12usize]),
+            i8::saturating_add(a[13usize], b[13usize]),
+            i8::saturating_add(a[14usize], b[14usize]),
+            i8::saturating_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::saturating_sub(a[0usize], b[0usize]),
+            i8::saturating_sub(a[1usize], b[1usize]),
+            i8::saturating_sub(a[2usize], b[2usize]),
+            i8::saturating_sub(a[3usize], b[3usize]),
+            i8::saturating_sub(a[4usize], b[4usize]),
+            i8::saturating_sub(a[5usize], b[5usize]),
+            i8::saturating_sub(a[6usize], b[6usize]),
+            i8::saturating_sub(a[7usize], b[7usize]),
+            i8::saturating_sub(a[8usize], b[8usize]),
+            i8::saturating_sub(a[9usize], b[9usize]),
+            i8::saturating_sub(a[10usize], b[10usize]),
+            i8::saturating_sub(a[11usize], b[11usize]),
+            i8::saturating_sub(a[12usize], b[12usize]),
+            i8::saturating_sub(a[13usize], b[13usize]),
+            i8::saturating_sub(a[14usize], b[14usize]),
+            i8::saturating_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_abs(a[0usize]),
+            i8::wrapping_abs(a[1usize]),
+            i8::wrapping_abs(a[2usize]),
+            i8::wrapping_abs(a[3usize]),
+            i8::wrapping_abs(a[4usize]),
+            i8::wrapping_abs(a[5usize]),
+            i8::wrapping_abs(a[6usize]),
+            i8::wrapping_abs(a[7usize]),
+            i8::wrapping_abs(a[8usize]),
+            i8::wrapping_abs(a[9usize]),
+            i8::wrapping_abs(a[10usize]),
+            i8::wrapping_abs(a[11usize]),
+            i8::wrapping_abs(a[12usize]),
+            i8::wrapping_abs(a[13usize]),
+            i8::wrapping_abs(a[14usize]),
+            i8::wrapping_abs(a[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        [
+            i8::wrapping_neg(a[0usize]),
+            i8::wrapping_neg(a[1usize]),
+            i8::wrapping_neg(a[2usize]),
+            i8::wrapping_neg(a[3usize]),
+            i8::wrapping_neg(a[4usize]),
+            i8::wrapping_neg(a[5usize]),
+            i8::wrapping_neg(a[6usize]),
+            i8::wrapping_neg(a[7usize]),
+            i8::wrapping_neg(a[8usize]),
+            i8::wrapping_neg(a[9usize]),
+            i8::wrapping_neg(a[10usize]),
+            i8::wrapping_neg(a[11usize]),
+            i8::wrapping_neg(a[12usize]),
+            i8::wrapping_neg(a[13usize]),
+            i8::wrapping_neg(a[14usize]),
+            i8::wrapping_neg(a[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        [
+            i8::abs_diff(a[0usize], b[0usize]),
+            i8::abs_diff(a[1usize], b[1usize]),
+            i8::abs_diff(a[2usize], b[2usize]),
+            i8::abs_diff(a[3usize], b[3usize]),
+            i8::abs_diff(a[4usize], b[4usize]),
+            i8::abs_diff(a[5usize], b[5usize]),
+            i8::abs_diff(a[6usize], b[6usize]),
+            i8::abs_diff(a[7usize], b[7usize]),
+            i8::abs_diff(a[8usize], b[8usize]),
+            i8::abs_diff(a[9usize], b[9usize]),
+            i8::abs_diff(a[10usize], b[10usize]),
+            i8::abs_diff(a[11usize], b[11usize]),
+            i8::abs_diff(a[12usize], b[12usize]),
+            i8::abs_diff(a[13usize], b[13usize]),
+            i8::abs_diff(a[14usize], b[14usize]),
+            i8::abs_diff(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_widen_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i16x16<Self> {
+        [
+            a[0usize] as i16 * b[0usize] as i16,
+            a[1usize] as i16 * b[1usize] as i16,
+            a[2usize] as i16 * b[2usize] as i16,
+            a[3usize] as i16 * b[3usize] as i16,
+            a[4usize] as i16 * b[4usize] as i16,
+            a[5usize] as i16 * b[5usize] as i16,
+            a[6usize] as i16 * b[6usize] as i16,
+            a[7usize] as i16 * b[7usize] as i16,
+            a[8usize] as i16 * b[8usize] as i16,
+            a[9usize] as i16 * b[9usize] as i16,
+            a[10usize] as i16 * b[10usize] as i16,
+            a[11usize] as i16 * b[11usize] as i16,
+            a[12usize] as i16 * b[12usize] as i16,
+            a[13usize] as i16 * b[13usize] as i16,
+            a[14usize] as i16 * b[14usize] as i16,
+            a[15usize] as i16 * b[15usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        [
+            ((a[0usize] as i16 * b[0usize] as i16) >> 8) as i8,
+            ((a[1usize] as i16 * b[1usize] as i16) >> 8) as i8,
+            ((a[2usize] as i16 * b[2usize] as i16) >> 8) as i8,
+            ((a[3usize] as i16 * b[3usize] as i16) >> 8) as i8,
+            ((a[4usize] as i16 * b[4usize] as i16) >> 8) as i8,
+            ((a[5usize] as i16 * b[5usize] as i16) >> 8) as i8,
+            ((a[6usize] as i16 * b[6usize] as i16) >> 8) as i8,
+            ((a[7usize] as i16 * b[7usize] as i16) >> 8) as i8,
+            ((a[8usize] as i16 * b[8usize] as i16) >> 8) as i8,
+            ((a[9usize] as i16 * b[9usize] as i16) >> 8) as i8,
+            ((a[10usize] as i16 * b[10usize] as i16) >> 8) as i8,
+            ((a[11usize] as i16 * b[11usize] as i16) >> 8) as i8,
+            ((a[12usize] as i16 * b[12usize] as i16) >> 8) as i8,
+            ((a[13usize] as i16 * b[13usize] as i16) >> 8) as i8,
+            ((a[14usize] as i16 * b[14usize] as i16) >> 8) as i8,
+            ((a[15usize] as i16 * b[15usize] as i16) >> 8) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        [
+            a[0usize] as i16,
+            a[1usize] as i16,
+            a[2usize] as i16,
+            a[3usize] as i16,
+            a[4usize] as i16,
+            a[5usize] as i16,
+            a[6usize] as i16,
+            a[7usize] as i16,
+            a[8usize] as i16,
+            a[9usize] as i16,
+            a[10usize] as i16,
+            a[11usize] as i16,
+            a[12usize] as i16,
+            a[13usize] as i16,
+            a[14usize] as i16,
+            a[15usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        [
+            a[0usize] as i16,
+            a[1usize] as i16,
+            a[2usize] as i16,
+            a[3usize] as i16,
+            a[4usize] as i16,
+            a[5usize] as i16,
+            a[6usize] as i16,
+            a[7usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        [
+            a[8usize] as i16,
+            a[9usize] as i16,
+            a[10usize] as i16,
+            a[11usize] as i16,
+            a[12usize] as i16,
+            a[13usize] as i16,
+            a[14usize] as i16,
+            a[15usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x16(self, a: i8x16<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x16(self, a: i8x16<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x16(self, a: i8x16<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x16(self, a: i8x16<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x16(self, a: i8x16<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x16(self, a: i8x16<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x16(self, a: i8x16<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x16(self, a: i8x16<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x16(self, a: i8x16<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f16_i8x16(self, a: i8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i8x16(self, a: i8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i16x16(self.widen_i8x16(a))
+    }
+    #[inline(always)]
+    fn splat_u8x16(self, val: u8) -> u8x16<Self> {
+        [val; 16usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::not(a[0usize]),
+            u8::not(a[1usize]),
+            u8::not(a[2usize]),
+            u8::not(a[3usize]),
+            u8::not(a[4usize]),
+            u8::not(a[5usize]),
+            u8::not(a[6usize]),
+            u8::not(a[7usize]),
+            u8::not(a[8usize]),
+            u8::not(a[9usize]),
+            u8::not(a[10usize]),
+            u8::not(a[11usize]),
+            u8::not(a[12usize]),
+            u8::not(a[13usize]),
+            u8::not(a[14usize]),
+            u8::not(a[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::wrapping_add(a[0usize], b[0usize]),
+            u8::wrapping_add(a[1usize], b[1usize]),
+            u8::wrapping_add(a[2usize], b[2usize]),
+            u8::wrapping_add(a[3usize], b[3usize]),
+            u8::wrapping_add(a[4usize], b[4usize]),
+            u8::wrapping_add(a[5usize], b[5usize]),
+            u8::wrapping_add(a[6usize], b[6usize]),
+            u8::wrapping_add(a[7usize], b[7usize]),
+            u8::wrapping_add(a[8usize], b[8usize]),
+            u8::wrapping_add(a[9usize], b[9usize]),
+            u8::wrapping_add(a[10usize], b[10usize]),
+            u8::wrapping_add(a[11usize], b[11usize]),
+            u8::wrapping_add(a[12usize], b[12usize]),
+            u8::wrapping_add(a[13usize], b[13usize]),
+            u8::wrapping_add(a[14usize], b[14usize]),
+            u8::wrapping_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::wrapping_sub(a[0usize], b[0usize]),
+            u8::wrapping_sub(a[1usize], b[1usize]),
+            u8::wrapping_sub(a[2usize], b[2usize]),
+            u8::wrapping_sub(a[3usize], b[3usize]),
+            u8::wrapping_sub(a[4usize], b[4usize]),
+            u8::wrapping_sub(a[5usize], b[5usize]),
+            u8::wrapping_sub(a[6usize], b[6usize]),
+            u8::wrapping_sub(a[7usize], b[7usize]),
+            u8::wrapping_sub(a[8usize], b[8usize]),
+            u8::wrapping_sub(a[9usize], b[9usize]),
+            u8::wrapping_sub(a[10usize], b[10usize]),
+            u8::wrapping_sub(a[11usize], b[11usize]),
+            u8::wrapping_sub(a[12usize], b[12usize]),
+            u8::wrapping_sub(a[13usize], b[13usize]),
+            u8::wrapping_sub(a[14usize], b[14usize]),
+            u8::wrapping_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::wrapping_mul(a[0usize], b[0usize]),
+            u8::wrapping_mul(a[1usize], b[1usize]),
+            u8::wrapping_mul(a[2usize], b[2usize]),
+            u8::wrapping_mul(a[3usize], b[3usize]),
+            u8::wrapping_mul(a[4usize], b[4usize]),
+            u8::wrapping_mul(a[5usize], b[5usize]),
+            u8::wrapping_mul(a[6usize], b[6usize]),
+            u8::wrapping_mul(a[7usize], b[7usize]),
+            u8::wrapping_mul(a[8usize], b[8usize]),
+            u8::wrapping_mul(a[9usize], b[9usize]),
+            u8::wrapping_mul(a[10usize], b[10usize]),
+            u8::wrapping_mul(a[11usize], b[11usize]),
+            u8::wrapping_mul(a[12usize], b[12usize]),
+            u8::wrapping_mul(a[13usize], b[13usize]),
+            u8::wrapping_mul(a[14usize], b[14usize]),
+            u8::wrapping_mul(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::bitand(a[0usize], &b[0usize]),
+            u8::bitand(a[1usize], &b[1usize]),
+            u8::bitand(a[2usize], &b[2usize]),
+            u8::bitand(a[3usize], &b[3usize]),
+            u8::bitand(a[4usize], &b[4usize]),
+            u8::bitand(a[5usize], &b[5usize]),
+            u8::bitand(a[6usize], &b[6usize]),
+            u8::bitand(a[7usize], &b[7usize]),
+            u8::bitand(a[8usize], &b[8usize]),
+            u8::bitand(a[9usize], &b[9usize]),
+            u8::bitand(a[10usize], &b[10usize]),
+            u8::bitand(a[11usize], &b[11usize]),
+            u8::bitand(a[12usize], &b[12usize]),
+            u8::bitand(a[13usize], &b[13usize]),
+            u8::bitand(a[14usize], &b[14usize]),
+            u8::bitand(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::bitor(a[0usize], &b[0usize]),
+            u8::bitor(a[1usize], &b[1usize]),
+            u8::bitor(a[2usize], &b[2usize]),
+            u8::bitor(a[3usize], &b[3usize]),
+            u8::bitor(a[4usize], &b[4usize]),
+            u8::bitor(a[5usize], &b[5usize]),
+            u8::bitor(a[6usize], &b[6usize]),
+            u8::bitor(a[7usize], &b[7usize]),
+            u8::bitor(a[8usize], &b[8usize]),
+            u8::bitor(a[9usize], &b[9usize]),
+            u8::bitor(a[10usize], &b[10usize]),
+            u8::bitor(a[11usize], &b[11usize]),
+            u8::bitor(a[12usize], &b[12usize]),
+            u8::bitor(a[13usize], &b[13usize]),
+            u8::bitor(a[14usize], &b[14usize]),
+            u8::bitor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::bitxor(a[0usize], &b[0usize]),
+            u8::bitxor(a[1usize], &b[1usize]),
+            u8::bitxor(a[2usize], &b[2usize]),
+            u8::bitxor(a[3usize], &b[3usize]),
+            u8::bitxor(a[4usize], &b[4usize]),
+            u8::bitxor(a[5usize], &b[5usize]),
+            u8::bitxor(a[6usize], &b[6usize]),
+            u8::bitxor(a[7usize], &b[7usize]),
+            u8::bitxor(a[8usize], &b[8usize]),
+            u8::bitxor(a[9usize], &b[9usize]),
+            u8::bitxor(a[10usize], &b[10usize]),
+            u8::bitxor(a[11usize], &b[11usize]),
+            u8::bitxor(a[12usize], &b[12usize]),
+            u8::bitxor(a[13usize], &b[13usize]),
+            u8::bitxor(a[14usize], &b[14usize]),
+            u8::bitxor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_u8x16(self, a: u8x16<Self>, b: u32) -> u8x16<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+            a[4usize].checked_shl(b).unwrap_or(0),
+            a[5usize].checked_shl(b).unwrap_or(0),
+            a[6usize].checked_shl(b).unwrap_or(0),
+            a[7usize].checked_shl(b).unwrap_or(0),
+            a[8usize].checked_shl(b).unwrap_or(0),
+            a[9usize].checked_shl(b).unwrap_or(0),
+            a[10usize].checked_shl(b).unwrap_or(0),
+            a[11usize].checked_shl(b).unwrap_or(0),
+            a[12usize].checked_shl(b).unwrap_or(0),
+            a[13usize].checked_shl(b).unwrap_or(0),
+            a[14usize].checked_shl(b).unwrap_or(0),
+            a[15usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u8x16(self, a: u8x16<Self>, b: u32) -> u8x16<Self> {
+        [
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+            a[2usize].checked_shr(b).unwrap_or(0),
+            a[3usize].checked_shr(b).unwrap_or(0),
+            a[4usize].checked_shr(b).unwrap_or(0),
+            a[5usize].checked_shr(b).unwrap_or(0),
+            a[6usize].checked_shr(b).unwrap_or(0),
+            a[7usize].checked_shr(b).unwrap_or(0),
+            a[8usize].checked_shr(b).unwrap_or(0),
+            a[9usize].checked_shr(b).unwrap_or(0),
+            a[10usize].checked_shr(b).unwrap_or(0),
+            a[11usize].checked_shr(b).unwrap_or(0),
+            a[12usize].checked_shr(b).unwrap_or(0),
+            a[13usize].checked_shr(b).unwrap_or(0),
+            a[14usize].checked_shr(b).unwrap_or(0),
+            a[15usize].checked_shr(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize].checked_shl(b[0usize].min(8) as u32).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(8) as u32).unwrap_or(0),
+            a[2usize].checked_shl(b[2usize].min(8) as u32).unwrap_or(0),
+            a[3usize].checked_shl(b[3usize].min(8) as u32).unwrap_or(0),
+            a[4usize].checked_shl(b[4usize].min(8) as u32).unwrap_or(0),
+            a[5usize].checked_shl(b[5usize].min(8) as u32).unwrap_or(0),
+            a[6usize].checked_shl(b[6usize].min(8) as u32).unwrap_or(0),
+            a[7usize].checked_shl(b[7usize].min(8) as u32).unwrap_or(0),
+            a[8usize].checked_shl(b[8usize].min(8) as u32).unwrap_or(0),
+            a[9usize].checked_shl(b[9usize].min(8) as u32).unwrap_or(0),
+            a[10usize]
+                .checked_shl(b[10usize].min(8) as u32)
+                .unwrap_or(0),
+            a[11usize]
+                .checked_shl(b[11usize].min(8) as u32)
+                .unwrap_or(0),
+            a[12usize]
+                .checked_shl(b[12usize].min(8) as u32)
+                .unwrap_or(0),
+            a[13usize]
+                .checked_shl(b[13usize].min(8) as u32)
+                .unwrap_or(0),
+            a[14usize]
+                .checked_shl(b[14usize].min(8) as u32)
+                .unwrap_or(0),
+            a[15usize]
+                .checked_shl(b[15usize].min(8) as u32)
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize].checked_shr(b[0usize].min(8) as u32).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(8) as u32).unwrap_or(0),
+            a[2usize].checked_shr(b[2usize].min(8) as u32).unwrap_or(0),
+            a[3usize].checked_shr(b[3usize].min(8) as u32).unwrap_or(0),
+            a[4usize].checked_shr(b[4usize].min(8) as u32).unwrap_or(0),
+            a[5usize].checked_shr(b[5usize].min(8) as u32).unwrap_or(0),
+            a[6usize].checked_shr(b[6usize].min(8) as u32).unwrap_or(0),
+            a[7usize].checked_shr(b[7usize].min(8) as u32).unwrap_or(0),
+            a[8usize].checked_shr(b[8usize].min(8) as u32).unwrap_or(0),
+            a[9usize].checked_shr(b[9usize].min(8) as u32).unwrap_or(0),
+            a[10usize]
+                .checked_shr(b[10usize].min(8) as u32)
+                .unwrap_or(0),
+            a[11usize]
+                .checked_shr(b[11usize].min(8) as u32)
+                .unwrap_or(0),
+            a[12usize]
+                .checked_shr(b[12usize].min(8) as u32)
+                .unwrap_or(0),
+            a[13usize]
+                .checked_shr(b[13usize].min(8) as u32)
+                .unwrap_or(0),
+            a[14usize]
+                .checked_shr(b[14usize].min(8) as u32)
+                .unwrap_or(0),
+            a[15usize]
+                .checked_shr(b[15usize].min(8) as u32)
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        [
+            -(u8::eq(&a[0usize], &b[0usize]) as i8),
+            -(u8::eq(&a[1usize], &b[1usize]) as i8),
+            -(u8::eq(&a[2usize], &b[2usize]) as i8),
+            -(u8::eq(&a[3usize], &b[3usize]) as i8),
+            -(u8::eq(&a[4usize], &b[4usize]) as i8),
+            -(u8::eq(&a[5usize], &b[5usize]) as i8),
+            -(u8::eq(&a[6usize], &b[6usize]) as i8),
+            -(u8::eq(&a[7usize], &b[7usize]) as i8),
+            -(u8::eq(&a[8usize], &b[8usize]) as i8),
+            -(u8::eq(&a[9usize], &b[9usize]) as i8),
+            -(u8::eq(&a[10usize], &b[10usize]) as i8),
+            -(u8::eq(&a[11usize], &b[11usize]) as i8),
+            -(u8::eq(&a[12usize], &b[12usize]) as i8),
+            -(u8::eq(&a[13usize], &b[13usize]) as i8),
+            -(u8::eq(&a[14usize], &b[14usize]) as i8),
+            -(u8::eq(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        [
+            -(u8::lt(&a[0usize], &b[0usize]) as i8),
+            -(u8::lt(&a[1usize], &b[1usize]) as i8),
+            -(u8::lt(&a[2usize], &b[2usize]) as i8),
+            -(u8::lt(&a[3usize], &b[3usize]) as i8),
+            -(u8::lt(&a[4usize], &b[4usize]) as i8),
+            -(u8::lt(&a[5usize], &b[5usize]) as i8),
+            -(u8::lt(&a[6usize], &b[6usize]) as i8),
+            -(u8::lt(&a[7usize], &b[7usize]) as i8),
+            -(u8::lt(&a[8usize], &b[8usize]) as i8),
+            -(u8::lt(&a[9usize], &b[9usize]) as i8),
+            -(u8::lt(&a[10usize], &b[10usize]) as i8),
+            -(u8::lt(&a[11usize], &b[11usize]) as i8),
+            -(u8::lt(&a[12usize], &b[12usize]) as i8),
+            -(u8::lt(&a[13usize], &b[13usize]) as i8),
+            -(u8::lt(&a[14usize], &b[14usize]) as i8),
+            -(u8::lt(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        [
+            -(u8::le(&a[0usize], &b[0usize]) as i8),
+            -(u8::le(&a[1usize], &b[1usize]) as i8),
+            -(u8::le(&a[2usize], &b[2usize]) as i8),
+            -(u8::le(&a[3usize], &b[3usize]) as i8),
+            -(u8::le(&a[4usize], &b[4usize]) as i8),
+            -(u8::le(&a[5usize], &b[5usize]) as i8),
+            -(u8::le(&a[6usize], &b[6usize]) as i8),
+            -(u8::le(&a[7usize], &b[7usize]) as i8),
+            -(u8::le(&a[8usize], &b[8usize]) as i8),
+            -(u8::le(&a[9usize], &b[9usize]) as i8),
+            -(u8::le(&a[10usize], &b[10usize]) as i8),
+            -(u8::le(&a[11usize], &b[11usize]) as i8),
+            -(u8::le(&a[12usize], &b[12usize]) as i8),
+            -(u8::le(&a[13usize], &b[13usize]) as i8),
+            -(u8::le(&a[14usize], &b[14usize]) as i8),
+            -(u8::le(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        [
+            -(u8::ge(&a[0usize], &b[0usize]) as i8),
+            -(u8::ge(&a[1usize], &b[1usize]) as i8),
+            -(u8::ge(&a[2usize], &b[2usize]) as i8),
+            -(u8::ge(&a[3usize], &b[3usize]) as i8),
+            -(u8::ge(&a[4usize], &b[4usize]) as i8),
+            -(u8::ge(&a[5usize], &b[5usize]) as i8),
+            -(u8::ge(&a[6usize], &b[6usize]) as i8),
+            -(u8::ge(&a[7usize], &b[7usize]) as i8),
+            -(u8::ge(&a[8usize], &b[8usize]) as i8),
+            -(u8::ge(&a[9usize], &b[9usize]) as i8),
+            -(u8::ge(&a[10usize], &b[10usize]) as i8),
+            -(u8::ge(&a[11usize], &b[11usize]) as i8),
+            -(u8::ge(&a[12usize], &b[12usize]) as i8),
+            -(u8::ge(&a[13usize], &b[13usize]) as i8),
+            -(u8::ge(&a[14usize], &b[14usize]) as i8),
+            -(u8::ge(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        [
+            -(u8::gt(&a[0usize], &b[0usize]) as i8),
+            -(u8::gt(&a[1usize], &b[1usize]) as i8),
+            -(u8::gt(&a[2usize], &b[2usize]) as i8),
+            -(u8::gt(&a[3usize], &b[3usize]) as i8),
+            -(u8::gt(&a[4usize], &b[4usize]) as i8),
+            -(u8::gt(&a[5usize], &b[5usize]) as i8),
+            -(u8::gt(&a[6usize], &b[6usize]) as i8),
+            -(u8::gt(&a[7usize], &b[7usize]) as i8),
+            -(u8::gt(&a[8usize], &b[8usize]) as i8),
+            -(u8::gt(&a[9usize], &b[9usize]) as i8),
+            -(u8::gt(&a[10usize], &b[10usize]) as i8),
+            -(u8::gt(&a[11usize], &b[11usize]) as i8),
+            -(u8::gt(&a[12usize], &b[12usize]) as i8),
+            -(u8::gt(&a[13usize], &b[13usize]) as i8),
+            -(u8::gt(&a[14usize], &b[14usize]) as i8),
+            -(u8::gt(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            a[8usize], b[8usize], a[9usize], b[9usize], a[10usize], b[10usize], a[11usize],
+            b[11usize], a[12usize], b[12usize], a[13usize], b[13usize], a[14usize], b[14usize],
+            a[15usize], b[15usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
+            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
+            if a[10usize] != 0 {
+                b[10usize]
+            } else {
+                c[10usize]
+            },
+            if a[11usize] != 0 {
+                b[11usize]
+            } else {
+                c[11usize]
+            },
+            if a[12usize] != 0 {
+                b[12usize]
+            } else {
+                c[12usize]
+            },
+            if a[13usize] != 0 {
+                b[13usize]
+            } else {
+                c[13usize]
+            },
+            if a[14usize] != 0 {
+                b[14usize]
+            } else {
+                c[14usize]
+            },
+            if a[15usize] != 0 {
+                b[15usize]
+            } else {
+                c[15usize]
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::min(a[0usize], b[0usize]),
+            u8::min(a[1usize], b[1usize]),
+            u8::min(a[2usize], b[2usize]),
+            u8::min(a[3usize], b[3usize]),
+            u8::min(a[4usize], b[4usize]),
+            u8::min(a[5usize], b[5usize]),
+            u8::min(a[6usize], b[6usize]),
+            u8::min(a[7usize], b[7usize]),
+            u8::min(a[8usize], b[8usize]),
+            u8::min(a[9usize], b[9usize]),
+            u8::min(a[10usize], b[10usize]),
+            u8::min(a[11usize], b[11usize]),
+            u8::min(a[12usize], b[12usize]),
+            u8::min(a[13usize], b[13usize]),
+            u8::min(a[14usize], b[14usize]),
+            u8::min(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::max(a[0usize], b[0usize]),
+            u8::max(a[1usize], b[1usize]),
+            u8::max(a[2usize], b[2usize]),
+            u8::max(a[3usize], b[3usize]),
+            u8::max(a[4usize], b[4usize]),
+            u8::max(a[5usize], b[5usize]),
+            u8::max(a[6usize], b[6usize]),
+            u8::max(a[7usize], b[7usize]),
+            u8::max(a[8usize], b[8usize]),
+            u8::max(a[9usize], b[9usize]),
+            u8::max(a[10usize], b[10usize]),
+            u8::max(a[11usize], b[11usize]),
+            u8::max(a[12usize], b[12usize]),
+            u8::max(a[13usize], b[13usize]),
+            u8::max(a[14usize], b[14usize]),
+            u8::max(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_u8x16(self, a: u8x16<Self>) -> u8 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+            .wrapping_add(
+                a[4].wrapping_add(a[5])
+                    .wrapping_add(a[6].wrapping_add(a[7])),
+            )
+            .wrapping_add(
+                a[8].wrapping_add(a[9])
+                    .wrapping_add(a[10].wrapping_add(a[11]))
+                    .wrapping_add(
+                        a[12]
+                            .wrapping_add(a[13])
+                            .wrapping_add(a[14].wrapping_add(a[15])),
+                    ),
+            )
+    }
+    #[inline(always)]
+    fn reduce_min_u8x16(self, a: u8x16<Self>) -> u8 {
+        a[0].min(a[1])
+            .min(a[2].min(a[3]))
+            .min(a[4].min(a[5]).min(a[6].min(a[7])))
+            .min(
+                a[8].min(a[9])
+                    .min(a[10].min(a[11]))
+                    .min(a[12].min(a[13]).min(a[14].min(a[15]))),
+            )
+    }
+    #[inline(always)]
+    fn reduce_max_u8x16(self, a: u8x16<Self>) -> u8 {
+        a[0].max(a[1])
+            .max(a[2].max(a[3]))
+            .max(a[4].max(a[5]).max(a[6].max(a[7])))
+            .max(
+                a[8].max(a[9])
+                    .max(a[10].max(a[11]))
+                    .max(a[12].max(a[13]).max(a[14].max(a[15]))),
+            )
+    }
+    #[inline(always)]
+    fn reduce_and_u8x16(self, a: u8x16<Self>) -> u8 {
+        (((a[0] & a[1]) & (a[2] & a[3])) & ((a[4] & a[5]) & (a[6] & a[7])))
+            & (((a[8] & a[9]) & (a[10] & a[11])) & ((a[12] & a[13]) & (a[14] & a[15])))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x16(self, a: u8x16<Self>) -> u8 {
+        (((a[0] | a[1]) | (a[2] | a[3])) | ((a[4] | a[5]) | (a[6] | a[7])))
+            | (((a[8] | a[9]) | (a[10] | a[11])) | ((a[12] | a[13]) | (a[14] | a[15])))
+    }
+    #[inline(always)]
+    fn shuffle_u8x16<I: Indices<16>>(self, a: u8x16<Self>) -> u8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x16<I: Indices<16>>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x16<const L: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::saturating_add(a[0usize], b[0usize]),
+            u8::saturating_add(a[1usize], b[1usize]),
+            u8::saturating_add(a[2usize], b[2usize]),
+            u8::saturating_add(a[3usize], b[3usize]),
+            u8::saturating_add(a[4usize], b[4usize]),
+            u8::saturating_add(a[5usize], b[5usize]),
+            u8::saturating_add(a[6usize], b[6usize]),
+            u8::saturating_add(a[7usize], b[7usize]),
+            u8::saturating_add(a[8usize], b[8usize]),
+            u8::saturating_add(a[9usize], b[9usize]),
+            u8::saturating_add(a[10usize], b[10usize]),
+            u8::saturating_add(a[11usize], b[11usize]),
+            u8::saturating_add(a[12usize], b[12usize]),
+            u8::saturating_add(a[13usize], b[13usize]),
+            u8::saturating_add(a[14usize], b[14usize]),
+            u8::saturating_add(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::saturating_sub(a[0usize], b[0usize]),
+            u8::saturating_sub(a[1usize], b[1usize]),
+            u8::saturating_sub(a[2usize], b[2usize]),
+            u8::saturating_sub(a[3usize], b[3usize]),
+            u8::saturating_sub(a[4usize], b[4usize]),
+            u8::saturating_sub(a[5usize], b[5usize]),
+            u8::saturating_sub(a[6usize], b[6usize]),
+            u8::saturating_sub(a[7usize], b[7usize]),
+            u8::saturating_sub(a[8usize], b[8usize]),
+            u8::saturating_sub(a[9usize], b[9usize]),
+            u8::saturating_sub(a[10usize], b[10usize]),
+            u8::saturating_sub(a[11usize], b[11usize]),
+            u8::saturating_sub(a[12usize], b[12usize]),
+            u8::saturating_sub(a[13usize], b[13usize]),
+            u8::saturating_sub(a[14usize], b[14usize]),
+            u8::saturating_sub(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            u8::abs_diff(a[0usize], b[0usize]),
+            u8::abs_diff(a[1usize], b[1usize]),
+            u8::abs_diff(a[2usize], b[2usize]),
+            u8::abs_diff(a[3usize], b[3usize]),
+            u8::abs_diff(a[4usize], b[4usize]),
+            u8::abs_diff(a[5usize], b[5usize]),
+            u8::abs_diff(a[6usize], b[6usize]),
+            u8::abs_diff(a[7usize], b[7usize]),
+            u8::abs_diff(a[8usize], b[8usize]),
+            u8::abs_diff(a[9usize], b[9usize]),
+            u8::abs_diff(a[10usize], b[10usize]),
+            u8::abs_diff(a[11usize], b[11usize]),
+            u8::abs_diff(a[12usize], b[12usize]),
+            u8::abs_diff(a[13usize], b[13usize]),
+            u8::abs_diff(a[14usize], b[14usize]),
+            u8::abs_diff(a[15usize], b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            ((a[0usize] as u16 + b[0usize] as u16 + 1) >> 1) as u8,
+            ((a[1usize] as u16 + b[1usize] as u16 + 1) >> 1) as u8,
+            ((a[2usize] as u16 + b[2usize] as u16 + 1) >> 1) as u8,
+            ((a[3usize] as u16 + b[3usize] as u16 + 1) >> 1) as u8,
+            ((a[4usize] as u16 + b[4usize] as u16 + 1) >> 1) as u8,
+            ((a[5usize] as u16 + b[5usize] as u16 + 1) >> 1) as u8,
+            ((a[6usize] as u16 + b[6usize] as u16 + 1) >> 1) as u8,
+            ((a[7usize] as u16 + b[7usize] as u16 + 1) >> 1) as u8,
+            ((a[8usize] as u16 + b[8usize] as u16 + 1) >> 1) as u8,
+            ((a[9usize] as u16 + b[9usize] as u16 + 1) >> 1) as u8,
+            ((a[10usize] as u16 + b[10usize] as u16 + 1) >> 1) as u8,
+            ((a[11usize] as u16 + b[11usize] as u16 + 1) >> 1) as u8,
+            ((a[12usize] as u16 + b[12usize] as u16 + 1) >> 1) as u8,
+            ((a[13usize] as u16 + b[13usize] as u16 + 1) >> 1) as u8,
+            ((a[14usize] as u16 + b[14usize] as u16 + 1) >> 1) as u8,
+            ((a[15usize] as u16 + b[15usize] as u16 + 1) >> 1) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_widen_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u16x16<Self> {
+        [
+            a[0usize] as u16 * b[0usize] as u16,
+            a[1usize] as u16 * b[1usize] as u16,
+            a[2usize] as u16 * b[2usize] as u16,
+            a[3usize] as u16 * b[3usize] as u16,
+            a[4usize] as u16 * b[4usize] as u16,
+            a[5usize] as u16 * b[5usize] as u16,
+            a[6usize] as u16 * b[6usize] as u16,
+            a[7usize] as u16 * b[7usize] as u16,
+            a[8usize] as u16 * b[8usize] as u16,
+            a[9usize] as u16 * b[9usize] as u16,
+            a[10usize] as u16 * b[10usize] as u16,
+            a[11usize] as u16 * b[11usize] as u16,
+            a[12usize] as u16 * b[12usize] as u16,
+            a[13usize] as u16 * b[13usize] as u16,
+            a[14usize] as u16 * b[14usize] as u16,
+            a[15usize] as u16 * b[15usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        [
+            ((a[0usize] as u16 * b[0usize] as u16) >> 8) as u8,
+            ((a[1usize] as u16 * b[1usize] as u16) >> 8) as u8,
+            ((a[2usize] as u16 * b[2usize] as u16) >> 8) as u8,
+            ((a[3usize] as u16 * b[3usize] as u16) >> 8) as u8,
+            ((a[4usize] as u16 * b[4usize] as u16) >> 8) as u8,
+            ((a[5usize] as u16 * b[5usize] as u16) >> 8) as u8,
+            ((a[6usize] as u16 * b[6usize] as u16) >> 8) as u8,
+            ((a[7usize] as u16 * b[7usize] as u16) >> 8) as u8,
+            ((a[8usize] as u16 * b[8usize] as u16) >> 8) as u8,
+            ((a[9usize] as u16 * b[9usize] as u16) >> 8) as u8,
+            ((a[10usize] as u16 * b[10usize] as u16) >> 8) as u8,
+            ((a[11usize] as u16 * b[11usize] as u16) >> 8) as u8,
+            ((a[12usize] as u16 * b[12usize] as u16) >> 8) as u8,
+            ((a[13usize] as u16 * b[13usize] as u16) >> 8) as u8,
+            ((a[14usize] as u16 * b[14usize] as u16) >> 8) as u8,
+            ((a[15usize] as u16 * b[15usize] as u16) >> 8) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [u8; 16] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_32_u8x16(self, a: u8x32<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [u8; 16] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_48_u8x16(self, a: [u8x16<Self>; 3], b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [u8; 16] = core::array::from_fn(|i| {
+            a.get(b[i] as usize / 16)
+                .map_or(0, |t| t[b[i] as usize % 16])
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn lookup_64_u8x16(self, a: u8x64<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let lanes: [u8; 16] = core::array::from_fn(|i| a.get(b[i] as usize).copied().unwrap_or(0));
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x16(self, a: u8x16<Self>) -> (u8x8<Self>, u8x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self> {
+        [
+            a[0usize] as u16,
+            a[1usize] as u16,
+            a[2usize] as u16,
+            a[3usize] as u16,
+            a[4usize] as u16,
+            a[5usize] as u16,
+            a[6usize] as u16,
+            a[7usize] as u16,
+            a[8usize] as u16,
+            a[9usize] as u16,
+            a[10usize] as u16,
+            a[11usize] as u16,
+            a[12usize] as u16,
+            a[13usize] as u16,
+            a[14usize] as u16,
+            a[15usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        [
+            a[0usize] as u16,
+            a[1usize] as u16,
+            a[2usize] as u16,
+            a[3usize] as u16,
+            a[4usize] as u16,
+            a[5usize] as u16,
+            a[6usize] as u16,
+            a[7usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        [
+            a[8usize] as u16,
+            a[9usize] as u16,
+            a[10usize] as u16,
+            a[11usize] as u16,
+            a[12usize] as u16,
+            a[13usize] as u16,
+            a[14usize] as u16,
+            a[15usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x16(self, a: u8x16<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x16(self, a: u8x16<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x16(self, a: u8x16<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x16(self, a: u8x16<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x16(self, a: u8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x16(self, a: u8x16<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x16(self, a: u8x16<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x16(self, a: u8x16<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x16(self, a: u8x16<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f16_u8x16(self, a: u8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u16x16(self.widen_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_unorm_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.div_f32x16(self.cvt_f32_u8x16(a), self.splat_f32x16(255.0))
+    }
+    #[inline(always)]
+    fn splat_mask8x16(self, val: i8) -> mask8x16<Self> {
+        [val; 16usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        [
+            i8::not(a[0usize]),
+            i8::not(a[1usize]),
+            i8::not(a[2usize]),
+            i8::not(a[3usize]),
+            i8::not(a[4usize]),
+            i8::not(a[5usize]),
+            i8::not(a[6usize]),
+            i8::not(a[7usize]),
+            i8::not(a[8usize]),
+            i8::not(a[9usize]),
+            i8::not(a[10usize]),
+            i8::not(a[11usize]),
+            i8::not(a[12usize]),
+            i8::not(a[13usize]),
+            i8::not(a[14usize]),
+            i8::not(a[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        [
+            i8::bitand(a[0usize], &b[0usize]),
+            i8::bitand(a[1usize], &b[1usize]),
+            i8::bitand(a[2usize], &b[2usize]),
+            i8::bitand(a[3usize], &b[3usize]),
+            i8::bitand(a[4usize], &b[4usize]),
+            i8::bitand(a[5usize], &b[5usize]),
+            i8::bitand(a[6usize], &b[6usize]),
+            i8::bitand(a[7usize], &b[7usize]),
+            i8::bitand(a[8usize], &b[8usize]),
+            i8::bitand(a[9usize], &b[9usize]),
+            i8::bitand(a[10usize], &b[10usize]),
+            i8::bitand(a[11usize], &b[11usize]),
+            i8::bitand(a[12usize], &b[12usize]),
+            i8::bitand(a[13usize], &b[13usize]),
+            i8::bitand(a[14usize], &b[14usize]),
+            i8::bitand(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        [
+            i8::bitor(a[0usize], &b[0usize]),
+            i8::bitor(a[1usize], &b[1usize]),
+            i8::bitor(a[2usize], &b[2usize]),
+            i8::bitor(a[3usize], &b[3usize]),
+            i8::bitor(a[4usize], &b[4usize]),
+            i8::bitor(a[5usize], &b[5usize]),
+            i8::bitor(a[6usize], &b[6usize]),
+            i8::bitor(a[7usize], &b[7usize]),
+            i8::bitor(a[8usize], &b[8usize]),
+            i8::bitor(a[9usize], &b[9usize]),
+            i8::bitor(a[10usize], &b[10usize]),
+            i8::bitor(a[11usize], &b[11usize]),
+            i8::bitor(a[12usize], &b[12usize]),
+            i8::bitor(a[13usize], &b[13usize]),
+            i8::bitor(a[14usize], &b[14usize]),
+            i8::bitor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        [
+            i8::bitxor(a[0usize], &b[0usize]),
+            i8::bitxor(a[1usize], &b[1usize]),
+            i8::bitxor(a[2usize], &b[2usize]),
+            i8::bitxor(a[3usize], &b[3usize]),
+            i8::bitxor(a[4usize], &b[4usize]),
+            i8::bitxor(a[5usize], &b[5usize]),
+            i8::bitxor(a[6usize], &b[6usize]),
+            i8::bitxor(a[7usize], &b[7usize]),
+            i8::bitxor(a[8usize], &b[8usize]),
+            i8::bitxor(a[9usize], &b[9usize]),
+            i8::bitxor(a[10usize], &b[10usize]),
+            i8::bitxor(a[11usize], &b[11usize]),
+            i8::bitxor(a[12usize], &b[12usize]),
+            i8::bitxor(a[13usize], &b[13usize]),
+            i8::bitxor(a[14usize], &b[14usize]),
+            i8::bitxor(a[15usize], &b[15usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+            if a[8usize] != 0 { b[8usize] } else { c[8usize] },
+            if a[9usize] != 0 { b[9usize] } else { c[9usize] },
+            if a[10usize] != 0 {
+                b[10usize]
+            } else {
+                c[10usize]
+            },
+            if a[11usize] != 0 {
+                b[11usize]
+            } else {
+                c[11usize]
+            },
+            if a[12usize] != 0 {
+                b[12usize]
+            } else {
+                c[12usize]
+            },
+            if a[13usize] != 0 {
+                b[13usize]
+            } else {
+                c[13usize]
+            },
+            if a[14usize] != 0 {
+                b[14usize]
+            } else {
+                c[14usize]
+            },
+            if a[15usize] != 0 {
+                b[15usize]
+            } else {
+                c[15usize]
+            },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        [
+            -(i8::eq(&a[0usize], &b[0usize]) as i8),
+            -(i8::eq(&a[1usize], &b[1usize]) as i8),
+            -(i8::eq(&a[2usize], &b[2usize]) as i8),
+            -(i8::eq(&a[3usize], &b[3usize]) as i8),
+            -(i8::eq(&a[4usize], &b[4usize]) as i8),
+            -(i8::eq(&a[5usize], &b[5usize]) as i8),
+            -(i8::eq(&a[6usize], &b[6usize]) as i8),
+            -(i8::eq(&a[7usize], &b[7usize]) as i8),
+            -(i8::eq(&a[8usize], &b[8usize]) as i8),
+            -(i8::eq(&a[9usize], &b[9usize]) as i8),
+            -(i8::eq(&a[10usize], &b[10usize]) as i8),
+            -(i8::eq(&a[11usize], &b[11usize]) as i8),
+            -(i8::eq(&a[12usize], &b[12usize]) as i8),
+            -(i8::eq(&a[13usize], &b[13usize]) as i8),
+            -(i8::eq(&a[14usize], &b[14usize]) as i8),
+            -(i8::eq(&a[15usize], &b[15usize]) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn any_mask8x16(self, a: mask8x16<Self>) -> bool {
+        a[0usize] != 0
+            || a[1usize] != 0
+            || a[2usize] != 0
+            || a[3usize] != 0
+            || a[4usize] != 0
+            || a[5usize] != 0
+            || a[6usize] != 0
+            || a[7usize] != 0
+            || a[8usize] != 0
+            || a[9usize] != 0
+            || a[10usize] != 0
+            || a[11usize] != 0
+            || a[12usize] != 0
+            || a[13usize] != 0
+            || a[14usize] != 0
+            || a[15usize] != 0
+    }
+    #[inline(always)]
+    fn all_mask8x16(self, a: mask8x16<Self>) -> bool {
+        a[0usize] != 0
+            && a[1usize] != 0
+            && a[2usize] != 0
+            && a[3usize] != 0
+            && a[4usize] != 0
+            && a[5usize] != 0
+            && a[6usize] != 0
+            && a[7usize] != 0
+            && a[8usize] != 0
+            && a[9usize] != 0
+            && a[10usize] != 0
+            && a[11usize] != 0
+            && a[12usize] != 0
+            && a[13usize] != 0
+            && a[14usize] != 0
+            && a[15usize] != 0
+    }
+    #[inline(always)]
+    fn none_mask8x16(self, a: mask8x16<Self>) -> bool {
+        !self.any_mask8x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x16(self, a: mask8x16<Self>) -> usize {
+        self.to_bitmask_mask8x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x16(self, a: mask8x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x16(self, a: mask8x16<Self>) -> u64 {
+        (a[0] != 0) as u64
+            | ((a[1usize] != 0) as u64) << 1
+            | ((a[2usize] != 0) as u64) << 2
+            | ((a[3usize] != 0) as u64) << 3
+            | ((a[4usize] != 0) as u64) << 4
+            | ((a[5usize] != 0) as u64) << 5
+            | ((a[6usize] != 0) as u64) << 6
+            | ((a[7usize] != 0) as u64) << 7
+            | ((a[8usize] != 0) as u64) << 8
+            | ((a[9usize] != 0) as u64) << 9
+            | ((a[10usize] != 0) as u64) << 10
+            | ((a[11usize] != 0) as u64) << 11
+            | ((a[12usize] != 0) as u64) << 12
+            | ((a[13usize] != 0) as u64) << 13
+            | ((a[14usize] != 0) as u64) << 14
+            | ((a[15usize] != 0) as u64) << 15
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
+        [
+            -((bits & 1) as i8),
+            -(((bits >> 1) & 1) as i8),
+            -(((bits >> 2) & 1) as i8),
+            -(((bits >> 3) & 1) as i8),
+            -(((bits >> 4) & 1) as i8),
+            -(((bits >> 5) & 1) as i8),
+            -(((bits >> 6) & 1) as i8),
+            -(((bits >> 7) & 1) as i8),
+            -(((bits >> 8) & 1) as i8),
+            -(((bits >> 9) & 1) as i8),
+            -(((bits >> 10) & 1) as i8),
+            -(((bits >> 11) & 1) as i8),
+            -(((bits >> 12) & 1) as i8),
+            -(((bits >> 13) & 1) as i8),
+            -(((bits >> 14) & 1) as i8),
+            -(((bits >> 15) & 1) as i8),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle_mask8x16<I: Indices<16>>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x16<I: Indices<16>>(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x16<const L: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x16(self, a: mask8x16<Self>) -> (mask8x8<Self>, mask8x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x16(self, a: mask8x16<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x16(self, a: mask8x16<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x16(self, a: mask8x16<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x16(self, a: mask8x16<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x16(self, a: mask8x16<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x16(self, a: mask8x16<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x16(self, a: mask8x16<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_i16x8(self, val: i16) -> i16x8<Self> {
+        [val; 8usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::not(a[0usize]),
+            i16::not(a[1usize]),
+            i16::not(a[2usize]),
+            i16::not(a[3usize]),
+            i16::not(a[4usize]),
+            i16::not(a[5usize]),
+            i16::not(a[6usize]),
+            i16::not(a[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_add(a[0usize], b[0usize]),
+            i16::wrapping_add(a[1usize], b[1usize]),
+            i16::wrapping_add(a[2usize], b[2usize]),
+            i16::wrapping_add(a[3usize], b[3usize]),
+            i16::wrapping_add(a[4usize], b[4usize]),
+            i16::wrapping_add(a[5usize], b[5usize]),
+            i16::wrapping_add(a[6usize], b[6usize]),
+            i16::wrapping_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_sub(a[0usize], b[0usize]),
+            i16::wrapping_sub(a[1usize], b[1usize]),
+            i16::wrapping_sub(a[2usize], b[2usize]),
+            i16::wrapping_sub(a[3usize], b[3usize]),
+            i16::wrapping_sub(a[4usize], b[4usize]),
+            i16::wrapping_sub(a[5usize], b[5usize]),
+            i16::wrapping_sub(a[6usize], b[6usize]),
+            i16::wrapping_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_mul(a[0usize], b[0usize]),
+            i16::wrapping_mul(a[1usize], b[1usize]),
+            i16::wrapping_mul(a[2usize], b[2usize]),
+            i16::wrapping_mul(a[3usize], b[3usize]),
+            i16::wrapping_mul(a[4usize], b[4usize]),
+            i16::wrapping_mul(a[5usize], b[5usize]),
+            i16::wrapping_mul(a[6usize], b[6usize]),
+            i16::wrapping_mul(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::bitand(a[0usize], &b[0usize]),
+            i16::bitand(a[1usize], &b[1usize]),
+            i16::bitand(a[2usize], &b[2usize]),
+            i16::bitand(a[3usize], &b[3usize]),
+            i16::bitand(a[4usize], &b[4usize]),
+            i16::bitand(a[5usize], &b[5usize]),
+            i16::bitand(a[6usize], &b[6usize]),
+            i16::bitand(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::bitor(a[0usize], &b[0usize]),
+            i16::bitor(a[1usize], &b[1usize]),
+            i16::bitor(a[2usize], &b[2usize]),
+            i16::bitor(a[3usize], &b[3usize]),
+            i16::bitor(a[4usize], &b[4usize]),
+            i16::bitor(a[5usize], &b[5usize]),
+            i16::bitor(a[6usize], &b[6usize]),
+            i16::bitor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::bitxor(a[0usize], &b[0usize]),
+            i16::bitxor(a[1usize], &b[1usize]),
+            i16::bitxor(a[2usize], &b[2usize]),
+            i16::bitxor(a[3usize], &b[3usize]),
+            i16::bitxor(a[4usize], &b[4usize]),
+            i16::bitxor(a[5usize], &b[5usize]),
+            i16::bitxor(a[6usize], &b[6usize]),
+            i16::bitxor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_i16x8(self, a: i16x8<Self>, b: u32) -> i16x8<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+            a[4usize].checked_shl(b).unwrap_or(0),
+            a[5usize].checked_shl(b).unwrap_or(0),
+            a[6usize].checked_shl(b).unwrap_or(0),
+            a[7usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i16x8(self, a: i16x8<Self>, b: u32) -> i16x8<Self> {
+        [
+            a[0usize] >> b.min(15),
+            a[1usize] >> b.min(15),
+            a[2usize] >> b.min(15),
+            a[3usize] >> b.min(15),
+            a[4usize] >> b.min(15),
+            a[5usize] >> b.min(15),
+            a[6usize] >> b.min(15),
+            a[7usize] >> b.min(15),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            a[0usize]
+                .checked_shl((b[0usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[1usize]
+                .checked_shl((b[1usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[2usize]
+                .checked_shl((b[2usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[3usize]
+                .checked_shl((b[3usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[4usize]
+                .checked_shl((b[4usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[5usize]
+                .checked_shl((b[5usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[6usize]
+                .checked_shl((b[6usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+            a[7usize]
+                .checked_shl((b[7usize] as u16).min(16) as u32)
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            a[0usize] >> (b[0usize] as u16).min(15),
+            a[1usize] >> (b[1usize] as u16).min(15),
+            a[2usize] >> (b[2usize] as u16).min(15),
+            a[3usize] >> (b[3usize] as u16).min(15),
+            a[4usize] >> (b[4usize] as u16).min(15),
+            a[5usize] >> (b[5usize] as u16).min(15),
+            a[6usize] >> (b[6usize] as u16).min(15),
+            a[7usize] >> (b[7usize] as u16).min(15),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::eq(&a[0usize], &b[0usize]) as i16),
+            -(i16::eq(&a[1usize], &b[1usize]) as i16),
+            -(i16::eq(&a[2usize], &b[2usize]) as i16),
+            -(i16::eq(&a[3usize], &b[3usize]) as i16),
+            -(i16::eq(&a[4usize], &b[4usize]) as i16),
+            -(i16::eq(&a[5usize], &b[5usize]) as i16),
+            -(i16::eq(&a[6usize], &b[6usize]) as i16),
+            -(i16::eq(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::lt(&a[0usize], &b[0usize]) as i16),
+            -(i16::lt(&a[1usize], &b[1usize]) as i16),
+            -(i16::lt(&a[2usize], &b[2usize]) as i16),
+            -(i16::lt(&a[3usize], &b[3usize]) as i16),
+            -(i16::lt(&a[4usize], &b[4usize]) as i16),
+            -(i16::lt(&a[5usize], &b[5usize]) as i16),
+            -(i16::lt(&a[6usize], &b[6usize]) as i16),
+            -(i16::lt(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::le(&a[0usize], &b[0usize]) as i16),
+            -(i16::le(&a[1usize], &b[1usize]) as i16),
+            -(i16::le(&a[2usize], &b[2usize]) as i16),
+            -(i16::le(&a[3usize], &b[3usize]) as i16),
+            -(i16::le(&a[4usize], &b[4usize]) as i16),
+            -(i16::le(&a[5usize], &b[5usize]) as i16),
+            -(i16::le(&a[6usize], &b[6usize]) as i16),
+            -(i16::le(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::ge(&a[0usize], &b[0usize]) as i16),
+            -(i16::ge(&a[1usize], &b[1usize]) as i16),
+            -(i16::ge(&a[2usize], &b[2usize]) as i16),
+            -(i16::ge(&a[3usize], &b[3usize]) as i16),
+            -(i16::ge(&a[4usize], &b[4usize]) as i16),
+            -(i16::ge(&a[5usize], &b[5usize]) as i16),
+            -(i16::ge(&a[6usize], &b[6usize]) as i16),
+            -(i16::ge(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::gt(&a[0usize], &b[0usize]) as i16),
+            -(i16::gt(&a[1usize], &b[1usize]) as i16),
+            -(i16::gt(&a[2usize], &b[2usize]) as i16),
+            -(i16::gt(&a[3usize], &b[3usize]) as i16),
+            -(i16::gt(&a[4usize], &b[4usize]) as i16),
+            -(i16::gt(&a[5usize], &b[5usize]) as i16),
+            -(i16::gt(&a[6usize], &b[6usize]) as i16),
+            -(i16::gt(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::min(a[0usize], b[0usize]),
+            i16::min(a[1usize], b[1usize]),
+            i16::min(a[2usize], b[2usize]),
+            i16::min(a[3usize], b[3usize]),
+            i16::min(a[4usize], b[4usize]),
+            i16::min(a[5usize], b[5usize]),
+            i16::min(a[6usize], b[6usize]),
+            i16::min(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::max(a[0usize], b[0usize]),
+            i16::max(a[1usize], b[1usize]),
+            i16::max(a[2usize], b[2usize]),
+            i16::max(a[3usize], b[3usize]),
+            i16::max(a[4usize], b[4usize]),
+            i16::max(a[5usize], b[5usize]),
+            i16::max(a[6usize], b[6usize]),
+            i16::max(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_i16x8(self, a: i16x8<Self>) -> i16 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+            .wrapping_add(
+                a[4].wrapping_add(a[5])
+                    .wrapping_add(a[6].wrapping_add(a[7])),
+            )
+    }
+    #[inline(always)]
+    fn reduce_min_i16x8(self, a: i16x8<Self>) -> i16 {
+        a[0].min(a[1])
+            .min(a[2].min(a[3]))
+            .min(a[4].min(a[5]).min(a[6].min(a[7])))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x8(self, a: i16x8<Self>) -> i16 {
+        a[0].max(a[1])
+            .max(a[2].max(a[3]))
+            .max(a[4].max(a[5]).max(a[6].max(a[7])))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x8(self, a: i16x8<Self>) -> i16 {
+        ((a[0] & a[1]) & (a[2] & a[3])) & ((a[4] & a[5]) & (a[6] & a[7]))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x8(self, a: i16x8<Self>) -> i16 {
+        ((a[0] | a[1]) | (a[2] | a[3])) | ((a[4] | a[5]) | (a[6] | a[7]))
+    }
+    #[inline(always)]
+    fn shuffle_i16x8<I: Indices<8>>(self, a: i16x8<Self>) -> i16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x8<I: Indices<8>>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x8<const L: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::saturating_add(a[0usize], b[0usize]),
+            i16::saturating_add(a[1usize], b[1usize]),
+            i16::saturating_add(a[2usize], b[2usize]),
+            i16::saturating_add(a[3usize], b[3usize]),
+            i16::saturating_add(a[4usize], b[4usize]),
+            i16::saturating_add(a[5usize], b[5usize]),
+            i16::saturating_add(a[6usize], b[6usize]),
+            i16::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::saturating_sub(a[0usize], b[0usize]),
+            i16::saturating_sub(a[1usize], b[1usize]),
+            i16::saturating_sub(a[2usize], b[2usize]),
+            i16::saturating_sub(a[3usize], b[3usize]),
+            i16::saturating_sub(a[4usize], b[4usize]),
+            i16::saturating_sub(a[5usize], b[5usize]),
+            i16::saturating_sub(a[6usize], b[6usize]),
+            i16::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_abs(a[0usize]),
+            i16::wrapping_abs(a[1usize]),
+            i16::wrapping_abs(a[2usize]),
+            i16::wrapping_abs(a[3usize]),
+            i16::wrapping_abs(a[4usize]),
+            i16::wrapping_abs(a[5usize]),
+            i16::wrapping_abs(a[6usize]),
+            i16::wrapping_abs(a[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        [
+            i16::wrapping_neg(a[0usize]),
+            i16::wrapping_neg(a[1usize]),
+            i16::wrapping_neg(a[2usize]),
+            i16::wrapping_neg(a[3usize]),
+            i16::wrapping_neg(a[4usize]),
+            i16::wrapping_neg(a[5usize]),
+            i16::wrapping_neg(a[6usize]),
+            i16::wrapping_neg(a[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        [
+            i16::abs_diff(a[0usize], b[0usize]),
+            i16::abs_diff(a[1usize], b[1usize]),
+            i16::abs_diff(a[2usize], b[2usize]),
+            i16::abs_diff(a[3usize], b[3usize]),
+            i16::abs_diff(a[4usize], b[4usize]),
+            i16::abs_diff(a[5usize], b[5usize]),
+            i16::abs_diff(a[6usize], b[6usize]),
+            i16::abs_diff(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_widen_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x8<Self> {
+        [
+            a[0usize] as i32 * b[0usize] as i32,
+            a[1usize] as i32 * b[1usize] as i32,
+            a[2usize] as i32 * b[2usize] as i32,
+            a[3usize] as i32 * b[3usize] as i32,
+            a[4usize] as i32 * b[4usize] as i32,
+            a[5usize] as i32 * b[5usize] as i32,
+            a[6usize] as i32 * b[6usize] as i32,
+            a[7usize] as i32 * b[7usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            ((a[0usize] as i32 * b[0usize] as i32) >> 16) as i16,
+            ((a[1usize] as i32 * b[1usize] as i32) >> 16) as i16,
+            ((a[2usize] as i32 * b[2usize] as i32) >> 16) as i16,
+            ((a[3usize] as i32 * b[3usize] as i32) >> 16) as i16,
+            ((a[4usize] as i32 * b[4usize] as i32) >> 16) as i16,
+            ((a[5usize] as i32 * b[5usize] as i32) >> 16) as i16,
+            ((a[6usize] as i32 * b[6usize] as i32) >> 16) as i16,
+            ((a[7usize] as i32 * b[7usize] as i32) >> 16) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        [
+            ((a[0usize] as i32 * b[0usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[1usize] as i32 * b[1usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[2usize] as i32 * b[2usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[3usize] as i32 * b[3usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[4usize] as i32 * b[4usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[5usize] as i32 * b[5usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[6usize] as i32 * b[6usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+            ((a[7usize] as i32 * b[7usize] as i32 + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn dot_i16_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x4<Self> {
+        [
+            (a[0usize] as i32 * b[0usize] as i32).wrapping_add(a[1usize] as i32 * b[1usize] as i32),
+            (a[2usize] as i32 * b[2usize] as i32).wrapping_add(a[3usize] as i32 * b[3usize] as i32),
+            (a[4usize] as i32 * b[4usize] as i32).wrapping_add(a[5usize] as i32 * b[5usize] as i32),
+            (a[6usize] as i32 * b[6usize] as i32).wrapping_add(a[7usize] as i32 * b[7usize] as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        [
+            a[0usize] as i32,
+            a[1usize] as i32,
+            a[2usize] as i32,
+            a[3usize] as i32,
+            a[4usize] as i32,
+            a[5usize] as i32,
+            a[6usize] as i32,
+            a[7usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        [
+            a[0usize] as i32,
+            a[1usize] as i32,
+            a[2usize] as i32,
+            a[3usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        [
+            a[4usize] as i32,
+            a[5usize] as i32,
+            a[6usize] as i32,
+            a[7usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x8(self, a: i16x8<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x8(self, a: i16x8<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x8(self, a: i16x8<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x8(self, a: i16x8<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x8(self, a: i16x8<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x8(self, a: i16x8<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x8(self, a: i16x8<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x8(self, a: i16x8<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x8(self, a: i16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_i16x8(self, a: i16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn splat_u16x8(self, val: u16) -> u16x8<Self> {
+        [val; 8usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::not(a[0usize]),
+            u16::not(a[1usize]),
+            u16::not(a[2usize]),
+            u16::not(a[3usize]),
+            u16::not(a[4usize]),
+            u16::not(a[5usize]),
+            u16::not(a[6usize]),
+            u16::not(a[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::wrapping_add(a[0usize], b[0usize]),
+            u16::wrapping_add(a[1usize], b[1usize]),
+            u16::wrapping_add(a[2usize], b[2usize]),
+            u16::wrapping_add(a[3usize], b[3usize]),
+            u16::wrapping_add(a[4usize], b[4usize]),
+            u16::wrapping_add(a[5usize], b[5usize]),
+            u16::wrapping_add(a[6usize], b[6usize]),
+            u16::wrapping_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::wrapping_sub(a[0usize], b[0usize]),
+            u16::wrapping_sub(a[1usize], b[1usize]),
+            u16::wrapping_sub(a[2usize], b[2usize]),
+            u16::wrapping_sub(a[3usize], b[3usize]),
+            u16::wrapping_sub(a[4usize], b[4usize]),
+            u16::wrapping_sub(a[5usize], b[5usize]),
+            u16::wrapping_sub(a[6usize], b[6usize]),
+            u16::wrapping_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::wrapping_mul(a[0usize], b[0usize]),
+            u16::wrapping_mul(a[1usize], b[1usize]),
+            u16::wrapping_mul(a[2usize], b[2usize]),
+            u16::wrapping_mul(a[3usize], b[3usize]),
+            u16::wrapping_mul(a[4usize], b[4usize]),
+            u16::wrapping_mul(a[5usize], b[5usize]),
+            u16::wrapping_mul(a[6usize], b[6usize]),
+            u16::wrapping_mul(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::bitand(a[0usize], &b[0usize]),
+            u16::bitand(a[1usize], &b[1usize]),
+            u16::bitand(a[2usize], &b[2usize]),
+            u16::bitand(a[3usize], &b[3usize]),
+            u16::bitand(a[4usize], &b[4usize]),
+            u16::bitand(a[5usize], &b[5usize]),
+            u16::bitand(a[6usize], &b[6usize]),
+            u16::bitand(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::bitor(a[0usize], &b[0usize]),
+            u16::bitor(a[1usize], &b[1usize]),
+            u16::bitor(a[2usize], &b[2usize]),
+            u16::bitor(a[3usize], &b[3usize]),
+            u16::bitor(a[4usize], &b[4usize]),
+            u16::bitor(a[5usize], &b[5usize]),
+            u16::bitor(a[6usize], &b[6usize]),
+            u16::bitor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::bitxor(a[0usize], &b[0usize]),
+            u16::bitxor(a[1usize], &b[1usize]),
+            u16::bitxor(a[2usize], &b[2usize]),
+            u16::bitxor(a[3usize], &b[3usize]),
+            u16::bitxor(a[4usize], &b[4usize]),
+            u16::bitxor(a[5usize], &b[5usize]),
+            u16::bitxor(a[6usize], &b[6usize]),
+            u16::bitxor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_u16x8(self, a: u16x8<Self>, b: u32) -> u16x8<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+            a[4usize].checked_shl(b).unwrap_or(0),
+            a[5usize].checked_shl(b).unwrap_or(0),
+            a[6usize].checked_shl(b).unwrap_or(0),
+            a[7usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u16x8(self, a: u16x8<Self>, b: u32) -> u16x8<Self> {
+        [
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+            a[2usize].checked_shr(b).unwrap_or(0),
+            a[3usize].checked_shr(b).unwrap_or(0),
+            a[4usize].checked_shr(b).unwrap_or(0),
+            a[5usize].checked_shr(b).unwrap_or(0),
+            a[6usize].checked_shr(b).unwrap_or(0),
+            a[7usize].checked_shr(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            a[0usize].checked_shl(b[0usize].min(16) as u32).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(16) as u32).unwrap_or(0),
+            a[2usize].checked_shl(b[2usize].min(16) as u32).unwrap_or(0),
+            a[3usize].checked_shl(b[3usize].min(16) as u32).unwrap_or(0),
+            a[4usize].checked_shl(b[4usize].min(16) as u32).unwrap_or(0),
+            a[5usize].checked_shl(b[5usize].min(16) as u32).unwrap_or(0),
+            a[6usize].checked_shl(b[6usize].min(16) as u32).unwrap_or(0),
+            a[7usize].checked_shl(b[7usize].min(16) as u32).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            a[0usize].checked_shr(b[0usize].min(16) as u32).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(16) as u32).unwrap_or(0),
+            a[2usize].checked_shr(b[2usize].min(16) as u32).unwrap_or(0),
+            a[3usize].checked_shr(b[3usize].min(16) as u32).unwrap_or(0),
+            a[4usize].checked_shr(b[4usize].min(16) as u32).unwrap_or(0),
+            a[5usize].checked_shr(b[5usize].min(16) as u32).unwrap_or(0),
+            a[6usize].checked_shr(b[6usize].min(16) as u32).unwrap_or(0),
+            a[7usize].checked_shr(b[7usize].min(16) as u32).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        [
+            -(u16::eq(&a[0usize], &b[0usize]) as i16),
+            -(u16::eq(&a[1usize], &b[1usize]) as i16),
+            -(u16::eq(&a[2usize], &b[2usize]) as i16),
+            -(u16::eq(&a[3usize], &b[3usize]) as i16),
+            -(u16::eq(&a[4usize], &b[4usize]) as i16),
+            -(u16::eq(&a[5usize], &b[5usize]) as i16),
+            -(u16::eq(&a[6usize], &b[6usize]) as i16),
+            -(u16::eq(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        [
+            -(u16::lt(&a[0usize], &b[0usize]) as i16),
+            -(u16::lt(&a[1usize], &b[1usize]) as i16),
+            -(u16::lt(&a[2usize], &b[2usize]) as i16),
+            -(u16::lt(&a[3usize], &b[3usize]) as i16),
+            -(u16::lt(&a[4usize], &b[4usize]) as i16),
+            -(u16::lt(&a[5usize], &b[5usize]) as i16),
+            -(u16::lt(&a[6usize], &b[6usize]) as i16),
+            -(u16::lt(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        [
+            -(u16::le(&a[0usize], &b[0usize]) as i16),
+            -(u16::le(&a[1usize], &b[1usize]) as i16),
+            -(u16::le(&a[2usize], &b[2usize]) as i16),
+            -(u16::le(&a[3usize], &b[3usize]) as i16),
+            -(u16::le(&a[4usize], &b[4usize]) as i16),
+            -(u16::le(&a[5usize], &b[5usize]) as i16),
+            -(u16::le(&a[6usize], &b[6usize]) as i16),
+            -(u16::le(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        [
+            -(u16::ge(&a[0usize], &b[0usize]) as i16),
+            -(u16::ge(&a[1usize], &b[1usize]) as i16),
+            -(u16::ge(&a[2usize], &b[2usize]) as i16),
+            -(u16::ge(&a[3usize], &b[3usize]) as i16),
+            -(u16::ge(&a[4usize], &b[4usize]) as i16),
+            -(u16::ge(&a[5usize], &b[5usize]) as i16),
+            -(u16::ge(&a[6usize], &b[6usize]) as i16),
+            -(u16::ge(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        [
+            -(u16::gt(&a[0usize], &b[0usize]) as i16),
+            -(u16::gt(&a[1usize], &b[1usize]) as i16),
+            -(u16::gt(&a[2usize], &b[2usize]) as i16),
+            -(u16::gt(&a[3usize], &b[3usize]) as i16),
+            -(u16::gt(&a[4usize], &b[4usize]) as i16),
+            -(u16::gt(&a[5usize], &b[5usize]) as i16),
+            -(u16::gt(&a[6usize], &b[6usize]) as i16),
+            -(u16::gt(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            a[0usize], b[0usize], a[1usize], b[1usize], a[2usize], b[2usize], a[3usize], b[3usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            a[4usize], b[4usize], a[5usize], b[5usize], a[6usize], b[6usize], a[7usize], b[7usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::min(a[0usize], b[0usize]),
+            u16::min(a[1usize], b[1usize]),
+            u16::min(a[2usize], b[2usize]),
+            u16::min(a[3usize], b[3usize]),
+            u16::min(a[4usize], b[4usize]),
+            u16::min(a[5usize], b[5usize]),
+            u16::min(a[6usize], b[6usize]),
+            u16::min(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::max(a[0usize], b[0usize]),
+            u16::max(a[1usize], b[1usize]),
+            u16::max(a[2usize], b[2usize]),
+            u16::max(a[3usize], b[3usize]),
+            u16::max(a[4usize], b[4usize]),
+            u16::max(a[5usize], b[5usize]),
+            u16::max(a[6usize], b[6usize]),
+            u16::max(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_u16x8(self, a: u16x8<Self>) -> u16 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+            .wrapping_add(
+                a[4].wrapping_add(a[5])
+                    .wrapping_add(a[6].wrapping_add(a[7])),
+            )
+    }
+    #[inline(always)]
+    fn reduce_min_u16x8(self, a: u16x8<Self>) -> u16 {
+        a[0].min(a[1])
+            .min(a[2].min(a[3]))
+            .min(a[4].min(a[5]).min(a[6].min(a[7])))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x8(self, a: u16x8<Self>) -> u16 {
+        a[0].max(a[1])
+            .max(a[2].max(a[3]))
+            .max(a[4].max(a[5]).max(a[6].max(a[7])))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x8(self, a: u16x8<Self>) -> u16 {
+        ((a[0] & a[1]) & (a[2] & a[3])) & ((a[4] & a[5]) & (a[6] & a[7]))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x8(self, a: u16x8<Self>) -> u16 {
+        ((a[0] | a[1]) | (a[2] | a[3])) | ((a[4] | a[5]) | (a[6] | a[7]))
+    }
+    #[inline(always)]
+    fn shuffle_u16x8<I: Indices<8>>(self, a: u16x8<Self>) -> u16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x8<I: Indices<8>>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x8<const L: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::saturating_add(a[0usize], b[0usize]),
+            u16::saturating_add(a[1usize], b[1usize]),
+            u16::saturating_add(a[2usize], b[2usize]),
+            u16::saturating_add(a[3usize], b[3usize]),
+            u16::saturating_add(a[4usize], b[4usize]),
+            u16::saturating_add(a[5usize], b[5usize]),
+            u16::saturating_add(a[6usize], b[6usize]),
+            u16::saturating_add(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::saturating_sub(a[0usize], b[0usize]),
+            u16::saturating_sub(a[1usize], b[1usize]),
+            u16::saturating_sub(a[2usize], b[2usize]),
+            u16::saturating_sub(a[3usize], b[3usize]),
+            u16::saturating_sub(a[4usize], b[4usize]),
+            u16::saturating_sub(a[5usize], b[5usize]),
+            u16::saturating_sub(a[6usize], b[6usize]),
+            u16::saturating_sub(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            u16::abs_diff(a[0usize], b[0usize]),
+            u16::abs_diff(a[1usize], b[1usize]),
+            u16::abs_diff(a[2usize], b[2usize]),
+            u16::abs_diff(a[3usize], b[3usize]),
+            u16::abs_diff(a[4usize], b[4usize]),
+            u16::abs_diff(a[5usize], b[5usize]),
+            u16::abs_diff(a[6usize], b[6usize]),
+            u16::abs_diff(a[7usize], b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            ((a[0usize] as u32 + b[0usize] as u32 + 1) >> 1) as u16,
+            ((a[1usize] as u32 + b[1usize] as u32 + 1) >> 1) as u16,
+            ((a[2usize] as u32 + b[2usize] as u32 + 1) >> 1) as u16,
+            ((a[3usize] as u32 + b[3usize] as u32 + 1) >> 1) as u16,
+            ((a[4usize] as u32 + b[4usize] as u32 + 1) >> 1) as u16,
+            ((a[5usize] as u32 + b[5usize] as u32 + 1) >> 1) as u16,
+            ((a[6usize] as u32 + b[6usize] as u32 + 1) >> 1) as u16,
+            ((a[7usize] as u32 + b[7usize] as u32 + 1) >> 1) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_widen_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        [
+            a[0usize] as u32 * b[0usize] as u32,
+            a[1usize] as u32 * b[1usize] as u32,
+            a[2usize] as u32 * b[2usize] as u32,
+            a[3usize] as u32 * b[3usize] as u32,
+            a[4usize] as u32 * b[4usize] as u32,
+            a[5usize] as u32 * b[5usize] as u32,
+            a[6usize] as u32 * b[6usize] as u32,
+            a[7usize] as u32 * b[7usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        [
+            ((a[0usize] as u32 * b[0usize] as u32) >> 16) as u16,
+            ((a[1usize] as u32 * b[1usize] as u32) >> 16) as u16,
+            ((a[2usize] as u32 * b[2usize] as u32) >> 16) as u16,
+            ((a[3usize] as u32 * b[3usize] as u32) >> 16) as u16,
+            ((a[4usize] as u32 * b[4usize] as u32) >> 16) as u16,
+            ((a[5usize] as u32 * b[5usize] as u32) >> 16) as u16,
+            ((a[6usize] as u32 * b[6usize] as u32) >> 16) as u16,
+            ((a[7usize] as u32 * b[7usize] as u32) >> 16) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x8(self, a: u16x8<Self>) -> (u16x4<Self>, u16x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        [
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
+            a[4usize] as u32,
+            a[5usize] as u32,
+            a[6usize] as u32,
+            a[7usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        [
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        [
+            a[4usize] as u32,
+            a[5usize] as u32,
+            a[6usize] as u32,
+            a[7usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        [
+            a[0usize] as u8,
+            a[1usize] as u8,
+            a[2usize] as u8,
+            a[3usize] as u8,
+            a[4usize] as u8,
+            a[5usize] as u8,
+            a[6usize] as u8,
+            a[7usize] as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        [
+            a[0usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[1usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[2usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[3usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[4usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[5usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[6usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[7usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x8(self, a: u16x8<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x8(self, a: u16x8<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x8(self, a: u16x8<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x8(self, a: u16x8<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x8(self, a: u16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x8(self, a: u16x8<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x8(self, a: u16x8<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x8(self, a: u16x8<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x8(self, a: u16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x8(self, a: u16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x8(self, val: i16) -> mask16x8<Self> {
+        [val; 8usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        [
+            i16::not(a[0usize]),
+            i16::not(a[1usize]),
+            i16::not(a[2usize]),
+            i16::not(a[3usize]),
+            i16::not(a[4usize]),
+            i16::not(a[5usize]),
+            i16::not(a[6usize]),
+            i16::not(a[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        [
+            i16::bitand(a[0usize], &b[0usize]),
+            i16::bitand(a[1usize], &b[1usize]),
+            i16::bitand(a[2usize], &b[2usize]),
+            i16::bitand(a[3usize], &b[3usize]),
+            i16::bitand(a[4usize], &b[4usize]),
+            i16::bitand(a[5usize], &b[5usize]),
+            i16::bitand(a[6usize], &b[6usize]),
+            i16::bitand(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        [
+            i16::bitor(a[0usize], &b[0usize]),
+            i16::bitor(a[1usize], &b[1usize]),
+            i16::bitor(a[2usize], &b[2usize]),
+            i16::bitor(a[3usize], &b[3usize]),
+            i16::bitor(a[4usize], &b[4usize]),
+            i16::bitor(a[5usize], &b[5usize]),
+            i16::bitor(a[6usize], &b[6usize]),
+            i16::bitor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        [
+            i16::bitxor(a[0usize], &b[0usize]),
+            i16::bitxor(a[1usize], &b[1usize]),
+            i16::bitxor(a[2usize], &b[2usize]),
+            i16::bitxor(a[3usize], &b[3usize]),
+            i16::bitxor(a[4usize], &b[4usize]),
+            i16::bitxor(a[5usize], &b[5usize]),
+            i16::bitxor(a[6usize], &b[6usize]),
+            i16::bitxor(a[7usize], &b[7usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+            if a[4usize] != 0 { b[4usize] } else { c[4usize] },
+            if a[5usize] != 0 { b[5usize] } else { c[5usize] },
+            if a[6usize] != 0 { b[6usize] } else { c[6usize] },
+            if a[7usize] != 0 { b[7usize] } else { c[7usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        [
+            -(i16::eq(&a[0usize], &b[0usize]) as i16),
+            -(i16::eq(&a[1usize], &b[1usize]) as i16),
+            -(i16::eq(&a[2usize], &b[2usize]) as i16),
+            -(i16::eq(&a[3usize], &b[3usize]) as i16),
+            -(i16::eq(&a[4usize], &b[4usize]) as i16),
+            -(i16::eq(&a[5usize], &b[5usize]) as i16),
+            -(i16::eq(&a[6usize], &b[6usize]) as i16),
+            -(i16::eq(&a[7usize], &b[7usize]) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn any_mask16x8(self, a: mask16x8<Self>) -> bool {
+        a[0usize] != 0
+            || a[1usize] != 0
+            || a[2usize] != 0
+            || a[3usize] != 0
+            || a[4usize] != 0
+            || a[5usize] != 0
+            || a[6usize] != 0
+            || a[7usize] != 0
+    }
+    #[inline(always)]
+    fn all_mask16x8(self, a: mask16x8<Self>) -> bool {
+        a[0usize] != 0
+            && a[1usize] != 0
+            && a[2usize] != 0
+            && a[3usize] != 0
+            && a[4usize] != 0
+            && a[5usize] != 0
+            && a[6usize] != 0
+            && a[7usize] != 0
+    }
+    #[inline(always)]
+    fn none_mask16x8(self, a: mask16x8<Self>) -> bool {
+        !self.any_mask16x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x8(self, a: mask16x8<Self>) -> usize {
+        self.to_bitmask_mask16x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x8(self, a: mask16x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x8(self, a: mask16x8<Self>) -> u64 {
+        (a[0] != 0) as u64
+            | ((a[1usize] != 0) as u64) << 1
+            | ((a[2usize] != 0) as u64) << 2
+            | ((a[3usize] != 0) as u64) << 3
+            | ((a[4usize] != 0) as u64) << 4
+            | ((a[5usize] != 0) as u64) << 5
+            | ((a[6usize] != 0) as u64) << 6
+            | ((a[7usize] != 0) as u64) << 7
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
+        [
+            -((bits & 1) as i16),
+            -(((bits >> 1) & 1) as i16),
+            -(((bits >> 2) & 1) as i16),
+            -(((bits >> 3) & 1) as i16),
+            -(((bits >> 4) & 1) as i16),
+            -(((bits >> 5) & 1) as i16),
+            -(((bits >> 6) & 1) as i16),
+            -(((bits >> 7) & 1) as i16),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle_mask16x8<I: Indices<8>>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x8<I: Indices<8>>(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x8<const L: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x8(self, a: mask16x8<Self>) -> (mask16x4<Self>, mask16x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x8(self, a: mask16x8<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x8(self, a: mask16x8<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x8(self, a: mask16x8<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x8(self, a: mask16x8<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x8(self, a: mask16x8<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x8(self, a: mask16x8<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x8(self, a: mask16x8<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_i32x4(self, val: i32) -> i32x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::not(a[0usize]),
+            i32::not(a[1usize]),
+            i32::not(a[2usize]),
+            i32::not(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::wrapping_add(a[0usize], b[0usize]),
+            i32::wrapping_add(a[1usize], b[1usize]),
+            i32::wrapping_add(a[2usize], b[2usize]),
+            i32::wrapping_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::wrapping_sub(a[0usize], b[0usize]),
+            i32::wrapping_sub(a[1usize], b[1usize]),
+            i32::wrapping_sub(a[2usize], b[2usize]),
+            i32::wrapping_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::wrapping_mul(a[0usize], b[0usize]),
+            i32::wrapping_mul(a[1usize], b[1usize]),
+            i32::wrapping_mul(a[2usize], b[2usize]),
+            i32::wrapping_mul(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::bitand(a[0usize], &b[0usize]),
+            i32::bitand(a[1usize], &b[1usize]),
+            i32::bitand(a[2usize], &b[2usize]),
+            i32::bitand(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::bitor(a[0usize], &b[0usize]),
+            i32::bitor(a[1usize], &b[1usize]),
+            i32::bitor(a[2usize], &b[2usize]),
+            i32::bitor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::bitxor(a[0usize], &b[0usize]),
+            i32::bitxor(a[1usize], &b[1usize]),
+            i32::bitxor(a[2usize], &b[2usize]),
+            i32::bitxor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_i32x4(self, a: i32x4<Self>, b: u32) -> i32x4<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i32x4(self, a: i32x4<Self>, b: u32) -> i32x4<Self> {
+        [
+            a[0usize] >> b.min(31),
+            a[1usize] >> b.min(31),
+            a[2usize] >> b.min(31),
+            a[3usize] >> b.min(31),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            a[0usize]
+                .checked_shl((b[0usize] as u32).min(32))
+                .unwrap_or(0),
+            a[1usize]
+                .checked_shl((b[1usize] as u32).min(32))
+                .unwrap_or(0),
+            a[2usize]
+                .checked_shl((b[2usize] as u32).min(32))
+                .unwrap_or(0),
+            a[3usize]
+                .checked_shl((b[3usize] as u32).min(32))
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            a[0usize] >> (b[0usize] as u32).min(31),
+            a[1usize] >> (b[1usize] as u32).min(31),
+            a[2usize] >> (b[2usize] as u32).min(31),
+            a[3usize] >> (b[3usize] as u32).min(31),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::eq(&a[0usize], &b[0usize]) as i32),
+            -(i32::eq(&a[1usize], &b[1usize]) as i32),
+            -(i32::eq(&a[2usize], &b[2usize]) as i32),
+            -(i32::eq(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::lt(&a[0usize], &b[0usize]) as i32),
+            -(i32::lt(&a[1usize], &b[1usize]) as i32),
+            -(i32::lt(&a[2usize], &b[2usize]) as i32),
+            -(i32::lt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::le(&a[0usize], &b[0usize]) as i32),
+            -(i32::le(&a[1usize], &b[1usize]) as i32),
+            -(i32::le(&a[2usize], &b[2usize]) as i32),
+            -(i32::le(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::ge(&a[0usize], &b[0usize]) as i32),
+            -(i32::ge(&a[1usize], &b[1usize]) as i32),
+            -(i32::ge(&a[2usize], &b[2usize]) as i32),
+            -(i32::ge(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::gt(&a[0usize], &b[0usize]) as i32),
+            -(i32::gt(&a[1usize], &b[1usize]) as i32),
+            -(i32::gt(&a[2usize], &b[2usize]) as i32),
+            -(i32::gt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::min(a[0usize], b[0usize]),
+            i32::min(a[1usize], b[1usize]),
+            i32::min(a[2usize], b[2usize]),
+            i32::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::max(a[0usize], b[0usize]),
+            i32::max(a[1usize], b[1usize]),
+            i32::max(a[2usize], b[2usize]),
+            i32::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_i32x4(self, a: i32x4<Self>) -> i32 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x4(self, a: i32x4<Self>) -> i32 {
+        a[0].min(a[1]).min(a[2].min(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x4(self, a: i32x4<Self>) -> i32 {
+        a[0].max(a[1]).max(a[2].max(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x4(self, a: i32x4<Self>) -> i32 {
+        (a[0] & a[1]) & (a[2] & a[3])
+    }
+    #[inline(always)]
+    fn reduce_or_i32x4(self, a: i32x4<Self>) -> i32 {
+        (a[0] | a[1]) | (a[2] | a[3])
+    }
+    #[inline(always)]
+    fn shuffle_i32x4<I: Indices<4>>(self, a: i32x4<Self>) -> i32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x4<I: Indices<4>>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x4<const L: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::wrapping_abs(a[0usize]),
+            i32::wrapping_abs(a[1usize]),
+            i32::wrapping_abs(a[2usize]),
+            i32::wrapping_abs(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        [
+            i32::wrapping_neg(a[0usize]),
+            i32::wrapping_neg(a[1usize]),
+            i32::wrapping_neg(a[2usize]),
+            i32::wrapping_neg(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        [
+            i32::abs_diff(a[0usize], b[0usize]),
+            i32::abs_diff(a[1usize], b[1usize]),
+            i32::abs_diff(a[2usize], b[2usize]),
+            i32::abs_diff(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x4(self, a: i32x4<Self>) -> (i32x2<Self>, i32x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        [
+            a[0usize] as i64,
+            a[1usize] as i64,
+            a[2usize] as i64,
+            a[3usize] as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        [a[0usize] as i64, a[1usize] as i64].simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        [a[2usize] as i64, a[3usize] as i64].simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x4(self, a: i32x4<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x4(self, a: i32x4<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x4(self, a: i32x4<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x4(self, a: i32x4<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x4(self, a: i32x4<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x4(self, a: i32x4<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x4(self, a: i32x4<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x4(self, a: i32x4<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        [
+            a[0usize] as f32,
+            a[1usize] as f32,
+            a[2usize] as f32,
+            a[3usize] as f32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x4(self, a: i32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_i64x4(self.widen_i32x4(a))
+    }
+    #[inline(always)]
+    fn splat_u32x4(self, val: u32) -> u32x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::not(a[0usize]),
+            u32::not(a[1usize]),
+            u32::not(a[2usize]),
+            u32::not(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::wrapping_add(a[0usize], b[0usize]),
+            u32::wrapping_add(a[1usize], b[1usize]),
+            u32::wrapping_add(a[2usize], b[2usize]),
+            u32::wrapping_add(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::wrapping_sub(a[0usize], b[0usize]),
+            u32::wrapping_sub(a[1usize], b[1usize]),
+            u32::wrapping_sub(a[2usize], b[2usize]),
+            u32::wrapping_sub(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::wrapping_mul(a[0usize], b[0usize]),
+            u32::wrapping_mul(a[1usize], b[1usize]),
+            u32::wrapping_mul(a[2usize], b[2usize]),
+            u32::wrapping_mul(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::bitand(a[0usize], &b[0usize]),
+            u32::bitand(a[1usize], &b[1usize]),
+            u32::bitand(a[2usize], &b[2usize]),
+            u32::bitand(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::bitor(a[0usize], &b[0usize]),
+            u32::bitor(a[1usize], &b[1usize]),
+            u32::bitor(a[2usize], &b[2usize]),
+            u32::bitor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::bitxor(a[0usize], &b[0usize]),
+            u32::bitxor(a[1usize], &b[1usize]),
+            u32::bitxor(a[2usize], &b[2usize]),
+            u32::bitxor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_u32x4(self, a: u32x4<Self>, b: u32) -> u32x4<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+            a[2usize].checked_shl(b).unwrap_or(0),
+            a[3usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u32x4(self, a: u32x4<Self>, b: u32) -> u32x4<Self> {
+        [
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+            a[2usize].checked_shr(b).unwrap_or(0),
+            a[3usize].checked_shr(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            a[0usize].checked_shl(b[0usize].min(32)).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(32)).unwrap_or(0),
+            a[2usize].checked_shl(b[2usize].min(32)).unwrap_or(0),
+            a[3usize].checked_shl(b[3usize].min(32)).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            a[0usize].checked_shr(b[0usize].min(32)).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(32)).unwrap_or(0),
+            a[2usize].checked_shr(b[2usize].min(32)).unwrap_or(0),
+            a[3usize].checked_shr(b[3usize].min(32)).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        [
+            -(u32::eq(&a[0usize], &b[0usize]) as i32),
+            -(u32::eq(&a[1usize], &b[1usize]) as i32),
+            -(u32::eq(&a[2usize], &b[2usize]) as i32),
+            -(u32::eq(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        [
+            -(u32::lt(&a[0usize], &b[0usize]) as i32),
+            -(u32::lt(&a[1usize], &b[1usize]) as i32),
+            -(u32::lt(&a[2usize], &b[2usize]) as i32),
+            -(u32::lt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        [
+            -(u32::le(&a[0usize], &b[0usize]) as i32),
+            -(u32::le(&a[1usize], &b[1usize]) as i32),
+            -(u32::le(&a[2usize], &b[2usize]) as i32),
+            -(u32::le(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        [
+            -(u32::ge(&a[0usize], &b[0usize]) as i32),
+            -(u32::ge(&a[1usize], &b[1usize]) as i32),
+            -(u32::ge(&a[2usize], &b[2usize]) as i32),
+            -(u32::ge(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        [
+            -(u32::gt(&a[0usize], &b[0usize]) as i32),
+            -(u32::gt(&a[1usize], &b[1usize]) as i32),
+            -(u32::gt(&a[2usize], &b[2usize]) as i32),
+            -(u32::gt(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [a[0usize], b[0usize], a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [a[2usize], b[2usize], a[3usize], b[3usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::min(a[0usize], b[0usize]),
+            u32::min(a[1usize], b[1usize]),
+            u32::min(a[2usize], b[2usize]),
+            u32::min(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::max(a[0usize], b[0usize]),
+            u32::max(a[1usize], b[1usize]),
+            u32::max(a[2usize], b[2usize]),
+            u32::max(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_u32x4(self, a: u32x4<Self>) -> u32 {
+        a[0].wrapping_add(a[1])
+            .wrapping_add(a[2].wrapping_add(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x4(self, a: u32x4<Self>) -> u32 {
+        a[0].min(a[1]).min(a[2].min(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x4(self, a: u32x4<Self>) -> u32 {
+        a[0].max(a[1]).max(a[2].max(a[3]))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x4(self, a: u32x4<Self>) -> u32 {
+        (a[0] & a[1]) & (a[2] & a[3])
+    }
+    #[inline(always)]
+    fn reduce_or_u32x4(self, a: u32x4<Self>) -> u32 {
+        (a[0] | a[1]) | (a[2] | a[3])
+    }
+    #[inline(always)]
+    fn shuffle_u32x4<I: Indices<4>>(self, a: u32x4<Self>) -> u32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x4<I: Indices<4>>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x4<const L: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        [
+            u32::abs_diff(a[0usize], b[0usize]),
+            u32::abs_diff(a[1usize], b[1usize]),
+            u32::abs_diff(a[2usize], b[2usize]),
+            u32::abs_diff(a[3usize], b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        [
+            a[0usize] as u64,
+            a[1usize] as u64,
+            a[2usize] as u64,
+            a[3usize] as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_low_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        [a[0usize] as u64, a[1usize] as u64].simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_high_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        [a[2usize] as u64, a[3usize] as u64].simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        [
+            a[0usize] as u16,
+            a[1usize] as u16,
+            a[2usize] as u16,
+            a[3usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        [
+            a[0usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[1usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[2usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[3usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x4(self, a: u32x4<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x4(self, a: u32x4<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x4(self, a: u32x4<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x4(self, a: u32x4<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x4(self, a: u32x4<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x4(self, a: u32x4<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x4(self, a: u32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x4(self, a: u32x4<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        [
+            a[0usize] as f32,
+            a[1usize] as f32,
+            a[2usize] as f32,
+            a[3usize] as f32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x4(self, a: u32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u64x4(self.widen_u32x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x4(self, val: i32) -> mask32x4<Self> {
+        [val; 4usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        [
+            i32::not(a[0usize]),
+            i32::not(a[1usize]),
+            i32::not(a[2usize]),
+            i32::not(a[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        [
+            i32::bitand(a[0usize], &b[0usize]),
+            i32::bitand(a[1usize], &b[1usize]),
+            i32::bitand(a[2usize], &b[2usize]),
+            i32::bitand(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        [
+            i32::bitor(a[0usize], &b[0usize]),
+            i32::bitor(a[1usize], &b[1usize]),
+            i32::bitor(a[2usize], &b[2usize]),
+            i32::bitor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        [
+            i32::bitxor(a[0usize], &b[0usize]),
+            i32::bitxor(a[1usize], &b[1usize]),
+            i32::bitxor(a[2usize], &b[2usize]),
+            i32::bitxor(a[3usize], &b[3usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
+            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        [
+            -(i32::eq(&a[0usize], &b[0usize]) as i32),
+            -(i32::eq(&a[1usize], &b[1usize]) as i32),
+            -(i32::eq(&a[2usize], &b[2usize]) as i32),
+            -(i32::eq(&a[3usize], &b[3usize]) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn any_mask32x4(self, a: mask32x4<Self>) -> bool {
+        a[0usize] != 0 || a[1usize] != 0 || a[2usize] != 0 || a[3usize] != 0
+    }
+    #[inline(always)]
+    fn all_mask32x4(self, a: mask32x4<Self>) -> bool {
+        a[0usize] != 0 && a[1usize] != 0 && a[2usize] != 0 && a[3usize] != 0
+    }
+    #[inline(always)]
+    fn none_mask32x4(self, a: mask32x4<Self>) -> bool {
+        !self.any_mask32x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x4(self, a: mask32x4<Self>) -> usize {
+        self.to_bitmask_mask32x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x4(self, a: mask32x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x4(self, a: mask32x4<Self>) -> u64 {
+        (a[0] != 0) as u64
+            | ((a[1usize] != 0) as u64) << 1
+            | ((a[2usize] != 0) as u64) << 2
+            | ((a[3usize] != 0) as u64) << 3
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
+        [
+            -((bits & 1) as i32),
+            -(((bits >> 1) & 1) as i32),
+            -(((bits >> 2) & 1) as i32),
+            -(((bits >> 3) & 1) as i32),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle_mask32x4<I: Indices<4>>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x4<I: Indices<4>>(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x4<const L: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x4(self, a: mask32x4<Self>) -> (mask32x2<Self>, mask32x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x4(self, a: mask32x4<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x4(self, a: mask32x4<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x4(self, a: mask32x4<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x4(self, a: mask32x4<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x4(self, a: mask32x4<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x4(self, a: mask32x4<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x4(self, a: mask32x4<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_f64x2(self, val: f64) -> f64x2<Self> {
+        [val; 2usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::abs(a[0usize]), f64::abs(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::neg(a[0usize]), f64::neg(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::sqrt(a[0usize]), f64::sqrt(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), self.sqrt_f64x2(a))
+    }
+    #[inline(always)]
+    fn recip_f64x2(self, a: f64x2<Self>, _steps: u32) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_f64x2(self, a: f64x2<Self>, _steps: u32) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), self.sqrt_f64x2(a))
+    }
+    #[inline(always)]
+    fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::add(a[0usize], &b[0usize]),
+            f64::add(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::sub(a[0usize], &b[0usize]),
+            f64::sub(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::mul(a[0usize], &b[0usize]),
+            f64::mul(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::div(a[0usize], &b[0usize]),
+            f64::div(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::copysign(a[0usize], b[0usize]),
+            f64::copysign(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::eq(&a[0usize], &b[0usize]) as i64),
+            -(f64::eq(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::ne(&a[0usize], &b[0usize]) as i64),
+            -(f64::ne(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::lt(&a[0usize], &b[0usize]) as i64),
+            -(f64::lt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::le(&a[0usize], &b[0usize]) as i64),
+            -(f64::le(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::ge(&a[0usize], &b[0usize]) as i64),
+            -(f64::ge(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        [
+            -(f64::gt(&a[0usize], &b[0usize]) as i64),
+            -(f64::gt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_ne_f64x2(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_eq_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        let abs = self.abs_f64x2(a);
+        self.and_mask64x2(
+            self.simd_lt_f64x2(abs, self.splat_f64x2(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x2(abs, self.splat_f64x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(
+            self.copysign_f64x2(self.splat_f64x2(1.0), a),
+            self.splat_f64x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [a[0usize], b[0usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::max(a[0usize], b[0usize]),
+            f64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::max(a[0usize], b[0usize]),
+            f64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::min(a[0usize], b[0usize]),
+            f64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::min(a[0usize], b[0usize]),
+            f64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        a.add(b.mul(c))
+    }
+    #[inline(always)]
+    fn msub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        a.sub(b.mul(c))
+    }
+    #[inline(always)]
+    fn nmadd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.madd_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.msub_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::mul_add(a[0usize], b[0usize], c[0usize]),
+            f64::mul_add(a[1usize], b[1usize], c[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.madd_f64x2(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::floor(a[0usize]), f64::floor(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::ceil(a[0usize]), f64::ceil(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::round(a[0usize]), f64::round(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [
+            f64::round_ties_even(a[0usize]),
+            f64::round_ties_even(a[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::fract(a[0usize]), f64::fract(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        [f64::trunc(a[0usize]), f64::trunc(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f64x2(self, a: f64x2<Self>) -> f64 {
+        a[0] + a[1]
+    }
+    #[inline(always)]
+    fn reduce_min_f64x2(self, a: f64x2<Self>) -> f64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_f64x2(self, a: f64x2<Self>) -> f64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn shuffle_f64x2<I: Indices<2>>(self, a: f64x2<Self>) -> f64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x2<I: Indices<2>>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x2<const L: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
+        let mut result = [0.0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x2(self, a: f64x2<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x2(self, a: f64x2<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x2(self, a: f64x2<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x2(self, a: f64x2<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x2(self, a: f64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x2(self, a: f64x2<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x2(self, a: f64x2<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x2(self, a: f64x2<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x2(self, a: f64x2<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x2(self, a: f64x2<Self>) -> f32x2<Self> {
+        [a[0usize] as f32, a[1usize] as f32].simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_round_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        [a[0usize] as i64, a[1usize] as i64].simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        [
+            f64::round_ties_even(a[0usize]) as i64,
+            f64::round_ties_even(a[1usize]) as i64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        [a[0usize] as u64, a[1usize] as u64].simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        [
+            f64::round_ties_even(a[0usize]) as u64,
+            f64::round_ties_even(a[1usize]) as u64,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i64x2(self, val: i64) -> i64x2<Self> {
+        [val; 2usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [i64::not(a[0usize]), i64::not(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::wrapping_add(a[0usize], b[0usize]),
+            i64::wrapping_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::wrapping_sub(a[0usize], b[0usize]),
+            i64::wrapping_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::wrapping_mul(a[0usize], b[0usize]),
+            i64::wrapping_mul(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::bitand(a[0usize], &b[0usize]),
+            i64::bitand(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::bitor(a[0usize], &b[0usize]),
+            i64::bitor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::bitxor(a[0usize], &b[0usize]),
+            i64::bitxor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_i64x2(self, a: i64x2<Self>, b: u32) -> i64x2<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_i64x2(self, a: i64x2<Self>, b: u32) -> i64x2<Self> {
+        [a[0usize] >> b.min(63), a[1usize] >> b.min(63)].simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            a[0usize]
+                .checked_shl((b[0usize] as u64).min(64) as u32)
+                .unwrap_or(0),
+            a[1usize]
+                .checked_shl((b[1usize] as u64).min(64) as u32)
+                .unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            a[0usize] >> (b[0usize] as u64).min(63),
+            a[1usize] >> (b[1usize] as u64).min(63),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::eq(&a[0usize], &b[0usize]) as i64),
+            -(i64::eq(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::lt(&a[0usize], &b[0usize]) as i64),
+            -(i64::lt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::le(&a[0usize], &b[0usize]) as i64),
+            -(i64::le(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::ge(&a[0usize], &b[0usize]) as i64),
+            -(i64::ge(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::gt(&a[0usize], &b[0usize]) as i64),
+            -(i64::gt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [a[0usize], b[0usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_i64x2(self, a: mask64x2<Self>, b: i64x2<Self>, c: i64x2<Self>) -> i64x2<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::min(a[0usize], b[0usize]),
+            i64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        [
+            i64::max(a[0usize], b[0usize]),
+            i64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].wrapping_add(a[1])
+    }
+    #[inline(always)]
+    fn reduce_min_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0] & a[1]
+    }
+    #[inline(always)]
+    fn reduce_or_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0] | a[1]
+    }
+    #[inline(always)]
+    fn shuffle_i64x2<I: Indices<2>>(self, a: i64x2<Self>) -> i64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x2<I: Indices<2>>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x2<const L: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [i64::wrapping_abs(a[0usize]), i64::wrapping_abs(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        [i64::wrapping_neg(a[0usize]), i64::wrapping_neg(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        [
+            i64::abs_diff(a[0usize], b[0usize]),
+            i64::abs_diff(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        [a[0usize] as i32, a[1usize] as i32].simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        [
+            a[0usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            a[1usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x2(self, a: i64x2<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x2(self, a: i64x2<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x2(self, a: i64x2<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x2(self, a: i64x2<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x2(self, a: i64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x2(self, a: i64x2<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x2(self, a: i64x2<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x2(self, a: i64x2<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x2(self, a: i64x2<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x2(self, a: i64x2<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x2(self, a: i64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        [a[0usize] as f64, a[1usize] as f64].simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_u64x2(self, val: u64) -> u64x2<Self> {
+        [val; 2usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        [u64::not(a[0usize]), u64::not(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::wrapping_add(a[0usize], b[0usize]),
+            u64::wrapping_add(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::wrapping_sub(a[0usize], b[0usize]),
+            u64::wrapping_sub(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::wrapping_mul(a[0usize], b[0usize]),
+            u64::wrapping_mul(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn and_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::bitand(a[0usize], &b[0usize]),
+            u64::bitand(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::bitor(a[0usize], &b[0usize]),
+            u64::bitor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::bitxor(a[0usize], &b[0usize]),
+            u64::bitxor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_u64x2(self, a: u64x2<Self>, b: u32) -> u64x2<Self> {
+        [
+            a[0usize].checked_shl(b).unwrap_or(0),
+            a[1usize].checked_shl(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_u64x2(self, a: u64x2<Self>, b: u32) -> u64x2<Self> {
+        [
+            a[0usize].checked_shr(b).unwrap_or(0),
+            a[1usize].checked_shr(b).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shl_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            a[0usize].checked_shl(b[0usize].min(64) as u32).unwrap_or(0),
+            a[1usize].checked_shl(b[1usize].min(64) as u32).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            a[0usize].checked_shr(b[0usize].min(64) as u32).unwrap_or(0),
+            a[1usize].checked_shr(b[1usize].min(64) as u32).unwrap_or(0),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::eq(&a[0usize], &b[0usize]) as i64),
+            -(u64::eq(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::lt(&a[0usize], &b[0usize]) as i64),
+            -(u64::lt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::le(&a[0usize], &b[0usize]) as i64),
+            -(u64::le(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::ge(&a[0usize], &b[0usize]) as i64),
+            -(u64::ge(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        [
+            -(u64::gt(&a[0usize], &b[0usize]) as i64),
+            -(u64::gt(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [a[0usize], b[0usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [a[1usize], b[1usize]].simd_into(self)
+    }
+    #[inline(always)]
+    fn select_u64x2(self, a: mask64x2<Self>, b: u64x2<Self>, c: u64x2<Self>) -> u64x2<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::min(a[0usize], b[0usize]),
+            u64::min(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::max(a[0usize], b[0usize]),
+            u64::max(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].wrapping_add(a[1])
+    }
+    #[inline(always)]
+    fn reduce_min_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0] & a[1]
+    }
+    #[inline(always)]
+    fn reduce_or_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0] | a[1]
+    }
+    #[inline(always)]
+    fn shuffle_u64x2<I: Indices<2>>(self, a: u64x2<Self>) -> u64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x2<I: Indices<2>>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x2<const L: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        [
+            u64::abs_diff(a[0usize], b[0usize]),
+            u64::abs_diff(a[1usize], b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x2(self, a: u64x2<Self>) -> f16x8<Self> {
+        f16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(f16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x2(self, a: u64x2<Self>) -> bf16x8<Self> {
+        bf16x8 {
+            val: bytemuck::cast::<_, [u16; 8usize]>(a.val).map(bf16::from_bits),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x2(self, a: u64x2<Self>) -> f32x4<Self> {
+        f32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        f64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x2(self, a: u64x2<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x2(self, a: u64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x2(self, a: u64x2<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x2(self, a: u64x2<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x2(self, a: u64x2<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x2(self, a: u64x2<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x2(self, a: u64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x2(self, a: u64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        [a[0usize] as f64, a[1usize] as f64].simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_mask64x2(self, val: i64) -> mask64x2<Self> {
+        [val; 2usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn not_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        [i64::not(a[0usize]), i64::not(a[1usize])].simd_into(self)
+    }
+    #[inline(always)]
+    fn and_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        [
+            i64::bitand(a[0usize], &b[0usize]),
+            i64::bitand(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn or_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        [
+            i64::bitor(a[0usize], &b[0usize]),
+            i64::bitor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn xor_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        [
+            i64::bitxor(a[0usize], &b[0usize]),
+            i64::bitxor(a[1usize], &b[1usize]),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        [
+            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
+            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        [
+            -(i64::eq(&a[0usize], &b[0usize]) as i64),
+            -(i64::eq(&a[1usize], &b[1usize]) as i64),
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn any_mask64x2(self, a: mask64x2<Self>) -> bool {
+        a[0usize] != 0 || a[1usize] != 0
+    }
+    #[inline(always)]
+    fn all_mask64x2(self, a: mask64x2<Self>) -> bool {
+        a[0usize] != 0 && a[1usize] != 0
+    }
+    #[inline(always)]
+    fn none_mask64x2(self, a: mask64x2<Self>) -> bool {
+        !self.any_mask64x2(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x2(self, a: mask64x2<Self>) -> usize {
+        self.to_bitmask_mask64x2(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x2(self, a: mask64x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x2(self, a: mask64x2<Self>) -> u64 {
+        (a[0] != 0) as u64 | ((a[1usize] != 0) as u64) << 1
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
+        [-((bits & 1) as i64), -(((bits >> 1) & 1) as i64)].simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle_mask64x2<I: Indices<2>>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x2<I: Indices<2>>(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + K) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[(i + 2 - K % 2) % 2]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x2<const L: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        [a[L % 2]; 2].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x2(self, a: mask64x2<Self>) -> i8x16<Self> {
+        i8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x2(self, a: mask64x2<Self>) -> u8x16<Self> {
+        u8x16 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x2(self, a: mask64x2<Self>) -> i16x8<Self> {
+        i16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x2(self, a: mask64x2<Self>) -> u16x8<Self> {
+        u16x8 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x2(self, a: mask64x2<Self>) -> i32x4<Self> {
+        i32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x2(self, a: mask64x2<Self>) -> u32x4<Self> {
+        u32x4 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        i64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x2(self, a: mask64x2<Self>) -> u64x2<Self> {
+        u64x2 {
+            val: bytemuck::cast(a.val),
+            simd: a.simd,
+        }
+    }
+    #[inline(always)]
+    fn splat_f16x16(self, val: f16) -> f16x16<Self> {
+        [val; 16usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.abs_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.neg_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.sqrt_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_approx_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_approx_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_f32x16(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_f32x16(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.add_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.sub_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.div_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.copysign_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_eq_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ne_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_lt_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_le_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ge_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_gt_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_ne_f16x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_infinite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_eq_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        let abs = self.abs_f16x16(a);
+        self.and_mask16x16(
+            self.simd_lt_f16x16(abs, self.splat_f16x16(f16::from_bits(0x0400))),
+            self.simd_gt_f16x16(abs, self.splat_f16x16(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.copysign_f16x16(self.splat_f16x16(f16::from_bits(0x3c00)), a),
+            self.splat_f16x16(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[i / 2]
+            } else {
+                b.val[i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[8usize + i / 2]
+            } else {
+                b.val[8usize + i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_precise_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_precise_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.madd_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn msub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.msub_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmadd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmadd_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmsub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmsub_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        let product = self.mul_f32x16(a, b);
+        let sum = self.add_f32x16(product, c);
+        let product_part = self.sub_f32x16(sum, c);
+        let c_part = self.sub_f32x16(sum, product_part);
+        let error = self.add_f32x16(
+            self.sub_f32x16(product, product_part),
+            self.sub_f32x16(c, c_part),
+        );
+        let sum_bits: u32x16<Self> = sum.bitcast();
+        let error_bits: u32x16<Self> = error.bitcast();
+        let zero = self.splat_u32x16(0);
+        let same_sign = self.simd_eq_u32x16(
+            self.shr_u32x16(self.xor_u32x16(sum_bits, error_bits), 31),
+            zero,
+        );
+        let step = self.select_u32x16(same_sign, self.splat_u32x16(1), self.splat_u32x16(u32::MAX));
+        let odd = self.simd_eq_u32x16(
+            self.and_u32x16(sum_bits, self.splat_u32x16(1)),
+            self.splat_u32x16(1),
+        );
+        let step = self.select_u32x16(odd, zero, step);
+        let step = self.select_u32x16(
+            self.simd_eq_f32x16(error, self.splat_f32x16(0.0)),
+            zero,
+            step,
+        );
+        let finite = self.simd_lt_f32x16(self.abs_f32x16(sum), self.splat_f32x16(f32::INFINITY));
+        let step = self.select_u32x16(finite, step, zero);
+        let sum: f32x16<Self> = self.add_u32x16(sum_bits, step).bitcast();
+        sum.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_add_fast_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn floor_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.floor_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.ceil_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_ties_even_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.fract_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.trunc_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f16x16(self, a: mask16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] =
+            core::array::from_fn(|i| if a.val[i] != 0 { b.val[i] } else { c.val[i] });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x16(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x16<I: Indices<16>>(self, a: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f16x16<I: Indices<16>>(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x16<const L: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f16x16(self, a: f16x16<Self>) -> (f16x8<Self>, f16x8<Self>) {
+        let mut b0 = [f16::from_bits(0); 8usize];
+        let mut b1 = [f16::from_bits(0); 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x16(self, a: f16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f16x8(a0),
+            self.reinterpret_bf16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x16(self, a: f16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f16x8(a0),
+            self.reinterpret_f32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x16(self, a: f16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f16x8(a0),
+            self.reinterpret_f64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x16(self, a: f16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_f16x8(a0), self.reinterpret_i8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x16(self, a: f16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_f16x8(a0), self.reinterpret_u8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f16x8(a0),
+            self.reinterpret_i16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f16x8(a0),
+            self.reinterpret_u16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x16(self, a: f16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f16x8(a0),
+            self.reinterpret_i32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x16(self, a: f16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f16x8(a0),
+            self.reinterpret_u32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x16(self, a: f16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f16x8(a0),
+            self.reinterpret_i64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x16(self, a: f16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f16x8(a0),
+            self.reinterpret_u64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x16(self, a: f16x16<Self>) -> f32x16<Self> {
+        a.val.map(f16::to_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i8_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x16(self, val: bf16) -> bf16x16<Self> {
+        self.splat_u16x16(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x16(
+        self,
+        a: mask16x16<Self>,
+        b: bf16x16<Self>,
+        c: bf16x16<Self>,
+    ) -> bf16x16<Self> {
+        self.select_u16x16(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_bf16x16(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x16<const L: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_bf16x16(self, a: bf16x16<Self>) -> (bf16x8<Self>, bf16x8<Self>) {
+        let (a0, a1) = self.split_u16x16(a.bitcast());
+        (a0.bitcast(), a1.bitcast())
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x16(self, a: bf16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_bf16x8(a0),
+            self.reinterpret_f16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x16(self, a: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_bf16x8(a0),
+            self.reinterpret_f32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x16(self, a: bf16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_bf16x8(a0),
+            self.reinterpret_f64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x16(self, a: bf16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_bf16x8(a0),
+            self.reinterpret_i8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x16(self, a: bf16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_bf16x8(a0),
+            self.reinterpret_u8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x16(self, a: bf16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_bf16x8(a0),
+            self.reinterpret_i16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x16(self, a: bf16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_bf16x8(a0),
+            self.reinterpret_u16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x16(self, a: bf16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_bf16x8(a0),
+            self.reinterpret_i32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x16(self, a: bf16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_bf16x8(a0),
+            self.reinterpret_u32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x16(self, a: bf16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_bf16x8(a0),
+            self.reinterpret_i64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x16(self, a: bf16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_bf16x8(a0),
+            self.reinterpret_u64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x16(self, a: bf16x16<Self>) -> f32x16<Self> {
+        a.val.map(bf16::to_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_f32x8(self, a: f32) -> f32x8<Self> {
+        let half = self.splat_f32x4(a);
+        self.combine_f32x4(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.abs_f32x4(a0), self.abs_f32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.neg_f32x4(a0), self.neg_f32x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.sqrt_f32x4(a0), self.sqrt_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_approx_f32x4(a0), self.recip_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_approx_f32x4(a0), self.rsqrt_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_f32x4(a0, steps), self.recip_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_f32x4(a0, steps), self.rsqrt_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.add_f32x4(a0, b0), self.add_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.sub_f32x4(a0, b0), self.sub_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.mul_f32x4(a0, b0), self.mul_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.div_f32x4(a0, b0), self.div_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.copysign_f32x4(a0, b0), self.copysign_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_eq_f32x4(a0, b0), self.simd_eq_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ne_f32x4(a0, b0), self.simd_ne_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_lt_f32x4(a0, b0), self.simd_lt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_le_f32x4(a0, b0), self.simd_le_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ge_f32x4(a0, b0), self.simd_ge_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_gt_f32x4(a0, b0), self.simd_gt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_ne_f32x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_eq_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        let abs = self.abs_f32x8(a);
+        self.and_mask32x8(
+            self.simd_lt_f32x8(abs, self.splat_f32x8(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x8(abs, self.splat_f32x8(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(
+            self.copysign_f32x8(self.splat_f32x8(1.0), a),
+            self.splat_f32x8(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, _) = self.split_f32x8(a);
+        let (b0, _) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a0, b0), self.zip_high_f32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (_, a1) = self.split_f32x8(a);
+        let (_, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a1, b1), self.zip_high_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.max_f32x4(a0, b0), self.max_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.max_precise_f32x4(a0, b0),
+            self.max_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.min_f32x4(a0, b0), self.min_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.min_precise_f32x4(a0, b0),
+            self.min_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.madd_f32x4(a0, b0, c0), self.madd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.msub_f32x4(a0, b0, c0), self.msub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmadd_f32x4(a0, b0, c0), self.nmadd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmsub_f32x4(a0, b0, c0), self.nmsub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fused_f32x4(a0, b0, c0),
+            self.mul_add_fused_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fast_f32x4(a0, b0, c0),
+            self.mul_add_fast_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.ceil_f32x4(a0), self.ceil_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.round_f32x4(a0), self.round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(
+            self.round_ties_even_f32x4(a0),
+            self.round_ties_even_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.fract_f32x4(a0), self.fract_f32x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.trunc_f32x4(a0), self.trunc_f32x4(a1))
+    }
+    #[inline(always)]
+    fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        self.reduce_add_f32x4(a0) + self.reduce_add_f32x4(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_min_f32x4(self.min_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_max_f32x4(self.max_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x8<I: Indices<8>>(self, a: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x8<I: Indices<8>>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x8<const L: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
+        let mut result = [0.0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x8(self, a: f32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f32x4(a0),
+            self.reinterpret_f16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x8(self, a: f32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f32x4(a0),
+            self.reinterpret_bf16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x8(self, a: f32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f32x4(a0),
+            self.reinterpret_f64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x8(self, a: f32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_f32x4(a0), self.reinterpret_i8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x8(self, a: f32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_f32x4(a0), self.reinterpret_u8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x8(self, a: f32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f32x4(a0),
+            self.reinterpret_i16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x8(self, a: f32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f32x4(a0),
+            self.reinterpret_u16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f32x4(a0),
+            self.reinterpret_i32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f32x4(a0),
+            self.reinterpret_u32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x8(self, a: f32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f32x4(a0),
+            self.reinterpret_i64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x8(self, a: f32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f32x4(a0),
+            self.reinterpret_u64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x8(self, a: f32x8<Self>) -> f16x8<Self> {
+        a.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x8(self, a: f32x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x4(self.cvt_f64_f32x4(a0), self.cvt_f64_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_f32x4(a0), self.cvt_i32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_round_f32x4(a0), self.cvt_i32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_f32x4(a0), self.cvt_u32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_round_f32x4(a0), self.cvt_u32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x8(self, a: f32x8<Self>) -> bf16x8<Self> {
+        a.val.map(bf16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x8(self, a: f32x8<Self>, b: bf16x16<Self>, c: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        let (c0, c1) = self.split_bf16x16(c);
+        self.combine_f32x4(
+            self.dot_bf16_f32x4(a0, b0, c0),
+            self.dot_bf16_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i8x32(self, a: i8) -> i8x32<Self> {
+        let half = self.splat_i8x16(a);
+        self.combine_i8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.not_i8x16(a0), self.not_i8x16(a1))
+    }
+    #[inline(always)]
+    fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.add_i8x16(a0, b0), self.add_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.sub_i8x16(a0, b0), self.sub_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_i8x16(a0, b0), self.mul_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.and_i8x16(a0, b0), self.and_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.or_i8x16(a0, b0), self.or_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.xor_i8x16(a0, b0), self.xor_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shl_i8x16(a0, b), self.shl_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shr_i8x16(a0, b), self.shr_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.shl_var_i8x16(a0, b0), self.shl_var_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.shr_var_i8x16(a0, b0), self.shr_var_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_eq_i8x16(a0, b0), self.simd_eq_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_lt_i8x16(a0, b0), self.simd_lt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_le_i8x16(a0, b0), self.simd_le_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_ge_i8x16(a0, b0), self.simd_ge_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_gt_i8x16(a0, b0), self.simd_gt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, _) = self.split_i8x32(a);
+        let (b0, _) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a0, b0), self.zip_high_i8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (_, a1) = self.split_i8x32(a);
+        let (_, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a1, b1), self.zip_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        let (c0, c1) = self.split_i8x32(c);
+        self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.min_i8x16(a0, b0), self.min_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_add_i8x16(self.add_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_min_i8x16(self.min_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_max_i8x16(self.max_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_and_i8x16(self.and_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_or_i8x16(self.or_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x32<I: Indices<32>>(self, a: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x32<I: Indices<32>>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x32<const L: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_add_i8x16(a0, b0),
+            self.saturating_add_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_sub_i8x16(a0, b0),
+            self.saturating_sub_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.abs_i8x16(a0), self.abs_i8x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.neg_i8x16(a0), self.neg_i8x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_u8x16(self.abs_diff_i8x16(a0, b0), self.abs_diff_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i16x16(self.mul_widen_i8x16(a0, b0), self.mul_widen_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_high_i8x16(a0, b0), self.mul_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x16(self.widen_i8x16(a0), self.widen_i8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x32(self, a: i8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i8x16(a0),
+            self.reinterpret_f16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x32(self, a: i8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i8x16(a0),
+            self.reinterpret_bf16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x32(self, a: i8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i8x16(a0),
+            self.reinterpret_f32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x32(self, a: i8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i8x16(a0),
+            self.reinterpret_f64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i8x16(a0),
+            self.reinterpret_i16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x32(self, a: i8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i8x16(a0),
+            self.reinterpret_u16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x32(self, a: i8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i8x16(a0),
+            self.reinterpret_i32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x32(self, a: i8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i8x16(a0),
+            self.reinterpret_u32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x32(self, a: i8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i8x16(a0),
+            self.reinterpret_i64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x32(self, a: i8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i8x16(a0),
+            self.reinterpret_u64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x32(self, a: u8) -> u8x32<Self> {
+        let half = self.splat_u8x16(a);
+        self.combine_u8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.not_u8x16(a0), self.not_u8x16(a1))
+    }
+    #[inline(always)]
+    fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.add_u8x16(a0, b0), self.add_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.sub_u8x16(a0, b0), self.sub_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_u8x16(a0, b0), self.mul_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.and_u8x16(a0, b0), self.and_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.or_u8x16(a0, b0), self.or_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.xor_u8x16(a0, b0), self.xor_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shl_u8x16(a0, b), self.shl_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shr_u8x16(a0, b), self.shr_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.shl_var_u8x16(a0, b0), self.shl_var_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.shr_var_u8x16(a0, b0), self.shr_var_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_eq_u8x16(a0, b0), self.simd_eq_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_lt_u8x16(a0, b0), self.simd_lt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_le_u8x16(a0, b0), self.simd_le_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_ge_u8x16(a0, b0), self.simd_ge_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_gt_u8x16(a0, b0), self.simd_gt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, _) = self.split_u8x32(a);
+        let (b0, _) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a0, b0), self.zip_high_u8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (_, a1) = self.split_u8x32(a);
+        let (_, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a1, b1), self.zip_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        let (c0, c1) = self.split_u8x32(c);
+        self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.min_u8x16(a0, b0), self.min_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_add_u8x16(self.add_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_min_u8x16(self.min_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_max_u8x16(self.max_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_and_u8x16(self.and_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_or_u8x16(self.or_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x32<I: Indices<32>>(self, a: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x32<I: Indices<32>>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x32<const L: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_add_u8x16(a0, b0),
+            self.saturating_add_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_sub_u8x16(a0, b0),
+            self.saturating_sub_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.abs_diff_u8x16(a0, b0), self.abs_diff_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.avg_round_u8x16(a0, b0), self.avg_round_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u16x16(self.mul_widen_u8x16(a0, b0), self.mul_widen_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_high_u8x16(a0, b0), self.mul_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x32(self, a: [u8x16<Self>; 3], b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_48_u8x16(a, b0), self.lookup_48_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x32(self, a: u8x64<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_64_u8x16(a, b0), self.lookup_64_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x32(self, a: u8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u8x16(a0),
+            self.reinterpret_f16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x32(self, a: u8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u8x16(a0),
+            self.reinterpret_bf16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x32(self, a: u8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u8x16(a0),
+            self.reinterpret_f32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x32(self, a: u8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u8x16(a0),
+            self.reinterpret_f64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x32(self, a: u8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i8x16(self.reinterpret_i8_u8x16(a0), self.reinterpret_i8_u8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x32(self, a: u8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u8x16(a0),
+            self.reinterpret_i16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u8x16(a0),
+            self.reinterpret_u16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x32(self, a: u8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u8x16(a0),
+            self.reinterpret_i32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u8x16(a0),
+            self.reinterpret_u32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x32(self, a: u8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u8x16(a0),
+            self.reinterpret_i64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x32(self, a: u8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u8x16(a0),
+            self.reinterpret_u64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x32(self, a: i8) -> mask8x32<Self> {
+        let half = self.splat_mask8x16(a);
+        self.combine_mask8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_mask8x16(self.not_mask8x16(a0), self.not_mask8x16(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.and_mask8x16(a0, b0), self.and_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.or_mask8x16(a0, b0), self.or_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.xor_mask8x16(a0, b0), self.xor_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        let (c0, c1) = self.split_mask8x32(c);
+        self.combine_mask8x16(
+            self.select_mask8x16(a0, b0, c0),
+            self.select_mask8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.simd_eq_mask8x16(a0, b0), self.simd_eq_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.any_mask8x16(a0) || self.any_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.all_mask8x16(a0) && self.all_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x32(self, a: mask8x32<Self>) -> bool {
+        !self.any_mask8x32(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x32(self, a: mask8x32<Self>) -> usize {
+        self.to_bitmask_mask8x32(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x32(self, a: mask8x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x32(self, a: mask8x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.to_bitmask_mask8x16(a0) | (self.to_bitmask_mask8x16(a1) << 16)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
+        self.combine_mask8x16(
+            self.from_bitmask_mask8x16(bits),
+            self.from_bitmask_mask8x16(bits >> 16),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x32<I: Indices<32>>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x32<I: Indices<32>>(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x32<const L: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask8x16(a0),
+            self.reinterpret_i8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x32(self, a: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask8x16(a0),
+            self.reinterpret_u8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x32(self, a: mask8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask8x16(a0),
+            self.reinterpret_i16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x32(self, a: mask8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask8x16(a0),
+            self.reinterpret_u16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x32(self, a: mask8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask8x16(a0),
+            self.reinterpret_i32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x32(self, a: mask8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask8x16(a0),
+            self.reinterpret_u32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x32(self, a: mask8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask8x16(a0),
+            self.reinterpret_i64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x32(self, a: mask8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask8x16(a0),
+            self.reinterpret_u64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x16(self, a: i16) -> i16x16<Self> {
+        let half = self.splat_i16x8(a);
+        self.combine_i16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.not_i16x8(a0), self.not_i16x8(a1))
+    }
+    #[inline(always)]
+    fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.add_i16x8(a0, b0), self.add_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.sub_i16x8(a0, b0), self.sub_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_i16x8(a0, b0), self.mul_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.and_i16x8(a0, b0), self.and_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.or_i16x8(a0, b0), self.or_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.xor_i16x8(a0, b0), self.xor_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shl_i16x8(a0, b), self.shl_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shr_i16x8(a0, b), self.shr_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.shl_var_i16x8(a0, b0), self.shl_var_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.shr_var_i16x8(a0, b0), self.shr_var_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_eq_i16x8(a0, b0), self.simd_eq_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_lt_i16x8(a0, b0), self.simd_lt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_le_i16x8(a0, b0), self.simd_le_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_ge_i16x8(a0, b0), self.simd_ge_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_gt_i16x8(a0, b0), self.simd_gt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, _) = self.split_i16x16(a);
+        let (b0, _) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a0, b0), self.zip_high_i16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (_, a1) = self.split_i16x16(a);
+        let (_, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a1, b1), self.zip_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        let (c0, c1) = self.split_i16x16(c);
+        self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.min_i16x8(a0, b0), self.min_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_add_i16x8(self.add_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_min_i16x8(self.min_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_max_i16x8(self.max_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_and_i16x8(self.and_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_or_i16x8(self.or_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x16<I: Indices<16>>(self, a: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x16<I: Indices<16>>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x16<const L: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_add_i16x8(a0, b0),
+            self.saturating_add_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_sub_i16x8(a0, b0),
+            self.saturating_sub_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.abs_i16x8(a0), self.abs_i16x8(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.neg_i16x8(a0), self.neg_i16x8(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_u16x8(self.abs_diff_i16x8(a0, b0), self.abs_diff_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x8(self.mul_widen_i16x8(a0, b0), self.mul_widen_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_high_i16x8(a0, b0), self.mul_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.mul_q15_round_sat_i16x8(a0, b0),
+            self.mul_q15_round_sat_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x4(self.dot_i16_i16x8(a0, b0), self.dot_i16_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x8(self.widen_i16x8(a0), self.widen_i16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize] as i8,
+            a[1usize] as i8,
+            a[2usize] as i8,
+            a[3usize] as i8,
+            a[4usize] as i8,
+            a[5usize] as i8,
+            a[6usize] as i8,
+            a[7usize] as i8,
+            a[8usize] as i8,
+            a[9usize] as i8,
+            a[10usize] as i8,
+            a[11usize] as i8,
+            a[12usize] as i8,
+            a[13usize] as i8,
+            a[14usize] as i8,
+            a[15usize] as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        [
+            a[0usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[1usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[2usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[3usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[4usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[5usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[6usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[7usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[8usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[9usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[10usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[11usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[12usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[13usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[14usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+            a[15usize].clamp(i8::MIN as i16, i8::MAX as i16) as i8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i16x8(a0),
+            self.reinterpret_f16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x16(self, a: i16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i16x8(a0),
+            self.reinterpret_bf16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x16(self, a: i16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i16x8(a0),
+            self.reinterpret_f32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x16(self, a: i16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i16x8(a0),
+            self.reinterpret_f64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x16(self, a: i16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_i16x8(a0), self.reinterpret_i8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x16(self, a: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i16x8(a0),
+            self.reinterpret_u16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i16x8(a0),
+            self.reinterpret_i32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x16(self, a: i16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i16x8(a0),
+            self.reinterpret_u32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x16(self, a: i16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i16x8(a0),
+            self.reinterpret_i64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x16(self, a: i16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i16x8(a0),
+            self.reinterpret_u64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x16(self, a: i16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i32x16(self.widen_i16x16(a))
+    }
+    #[inline(always)]
+    fn splat_u16x16(self, a: u16) -> u16x16<Self> {
+        let half = self.splat_u16x8(a);
+        self.combine_u16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.not_u16x8(a0), self.not_u16x8(a1))
+    }
+    #[inline(always)]
+    fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.add_u16x8(a0, b0), self.add_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.sub_u16x8(a0, b0), self.sub_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_u16x8(a0, b0), self.mul_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.and_u16x8(a0, b0), self.and_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.or_u16x8(a0, b0), self.or_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.xor_u16x8(a0, b0), self.xor_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shl_u16x8(a0, b), self.shl_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shr_u16x8(a0, b), self.shr_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.shl_var_u16x8(a0, b0), self.shl_var_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.shr_var_u16x8(a0, b0), self.shr_var_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_eq_u16x8(a0, b0), self.simd_eq_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_lt_u16x8(a0, b0), self.simd_lt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_le_u16x8(a0, b0), self.simd_le_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_ge_u16x8(a0, b0), self.simd_ge_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_gt_u16x8(a0, b0), self.simd_gt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, _) = self.split_u16x16(a);
+        let (b0, _) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a0, b0), self.zip_high_u16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (_, a1) = self.split_u16x16(a);
+        let (_, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a1, b1), self.zip_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        let (c0, c1) = self.split_u16x16(c);
+        self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.min_u16x8(a0, b0), self.min_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_add_u16x8(self.add_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_min_u16x8(self.min_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_max_u16x8(self.max_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_and_u16x8(self.and_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_or_u16x8(self.or_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x16<I: Indices<16>>(self, a: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x16<I: Indices<16>>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x16<const L: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_add_u16x8(a0, b0),
+            self.saturating_add_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_sub_u16x8(a0, b0),
+            self.saturating_sub_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.abs_diff_u16x8(a0, b0), self.abs_diff_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.avg_round_u16x8(a0, b0), self.avg_round_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u32x8(self.mul_widen_u16x8(a0, b0), self.mul_widen_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_high_u16x8(a0, b0), self.mul_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x8(self.widen_u16x8(a0), self.widen_u16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize] as u8,
+            a[1usize] as u8,
+            a[2usize] as u8,
+            a[3usize] as u8,
+            a[4usize] as u8,
+            a[5usize] as u8,
+            a[6usize] as u8,
+            a[7usize] as u8,
+            a[8usize] as u8,
+            a[9usize] as u8,
+            a[10usize] as u8,
+            a[11usize] as u8,
+            a[12usize] as u8,
+            a[13usize] as u8,
+            a[14usize] as u8,
+            a[15usize] as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        [
+            a[0usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[1usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[2usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[3usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[4usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[5usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[6usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[7usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[8usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[9usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[10usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[11usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[12usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[13usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[14usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+            a[15usize].clamp(u8::MIN as u16, u8::MAX as u16) as u8,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u16x8(a0),
+            self.reinterpret_f16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x16(self, a: u16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u16x8(a0),
+            self.reinterpret_bf16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x16(self, a: u16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u16x8(a0),
+            self.reinterpret_f32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x16(self, a: u16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u16x8(a0),
+            self.reinterpret_f64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x16(self, a: u16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_u16x8(a0), self.reinterpret_i8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x16(self, a: u16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u16x8(a0),
+            self.reinterpret_i16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x16(self, a: u16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u16x8(a0),
+            self.reinterpret_i32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u16x8(a0),
+            self.reinterpret_u32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x16(self, a: u16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u16x8(a0),
+            self.reinterpret_i64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x16(self, a: u16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u16x8(a0),
+            self.reinterpret_u64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x16(self, a: u16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u32x16(self.widen_u16x16(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x16(self, a: i16) -> mask16x16<Self> {
+        let half = self.splat_mask16x8(a);
+        self.combine_mask16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_mask16x8(self.not_mask16x8(a0), self.not_mask16x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.and_mask16x8(a0, b0), self.and_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.or_mask16x8(a0, b0), self.or_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.xor_mask16x8(a0, b0), self.xor_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        let (c0, c1) = self.split_mask16x16(c);
+        self.combine_mask16x8(
+            self.select_mask16x8(a0, b0, c0),
+            self.select_mask16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.simd_eq_mask16x8(a0, b0), self.simd_eq_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.any_mask16x8(a0) || self.any_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn all_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.all_mask16x8(a0) && self.all_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn none_mask16x16(self, a: mask16x16<Self>) -> bool {
+        !self.any_mask16x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x16(self, a: mask16x16<Self>) -> usize {
+        self.to_bitmask_mask16x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x16(self, a: mask16x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x16(self, a: mask16x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.to_bitmask_mask16x8(a0) | (self.to_bitmask_mask16x8(a1) << 8)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
+        self.combine_mask16x8(
+            self.from_bitmask_mask16x8(bits),
+            self.from_bitmask_mask16x8(bits >> 8),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask16x16<I: Indices<16>>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x16<I: Indices<16>>(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x16<const L: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x16(self, a: mask16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask16x8(a0),
+            self.reinterpret_i8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x16(self, a: mask16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask16x8(a0),
+            self.reinterpret_u8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask16x8(a0),
+            self.reinterpret_i16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x16(self, a: mask16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask16x8(a0),
+            self.reinterpret_u16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x16(self, a: mask16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask16x8(a0),
+            self.reinterpret_i32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x16(self, a: mask16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask16x8(a0),
+            self.reinterpret_u32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x16(self, a: mask16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask16x8(a0),
+            self.reinterpret_i64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x16(self, a: mask16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask16x8(a0),
+            self.reinterpret_u64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x8(self, a: i32) -> i32x8<Self> {
+        let half = self.splat_i32x4(a);
+        self.combine_i32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.not_i32x4(a0), self.not_i32x4(a1))
+    }
+    #[inline(always)]
+    fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.add_i32x4(a0, b0), self.add_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.sub_i32x4(a0, b0), self.sub_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.mul_i32x4(a0, b0), self.mul_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.and_i32x4(a0, b0), self.and_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.or_i32x4(a0, b0), self.or_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.xor_i32x4(a0, b0), self.xor_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shl_i32x4(a0, b), self.shl_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shr_i32x4(a0, b), self.shr_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.shl_var_i32x4(a0, b0), self.shl_var_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.shr_var_i32x4(a0, b0), self.shr_var_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_eq_i32x4(a0, b0), self.simd_eq_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_lt_i32x4(a0, b0), self.simd_lt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_le_i32x4(a0, b0), self.simd_le_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_ge_i32x4(a0, b0), self.simd_ge_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_gt_i32x4(a0, b0), self.simd_gt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, _) = self.split_i32x8(a);
+        let (b0, _) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a0, b0), self.zip_high_i32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (_, a1) = self.split_i32x8(a);
+        let (_, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a1, b1), self.zip_high_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        let (c0, c1) = self.split_i32x8(c);
+        self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.min_i32x4(a0, b0), self.min_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_add_i32x4(self.add_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_min_i32x4(self.min_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_max_i32x4(self.max_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_and_i32x4(self.and_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_or_i32x4(self.or_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i32x8<I: Indices<8>>(self, a: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x8<I: Indices<8>>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x8<const L: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.abs_i32x4(a0), self.abs_i32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.neg_i32x4(a0), self.neg_i32x4(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_u32x4(self.abs_diff_i32x4(a0, b0), self.abs_diff_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x4(self.widen_i32x4(a0), self.widen_i32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        [
+            a[0usize] as i16,
+            a[1usize] as i16,
+            a[2usize] as i16,
+            a[3usize] as i16,
+            a[4usize] as i16,
+            a[5usize] as i16,
+            a[6usize] as i16,
+            a[7usize] as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        [
+            a[0usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[1usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[2usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[3usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[4usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[5usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[6usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+            a[7usize].clamp(i16::MIN as i32, i16::MAX as i32) as i16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x8(self, a: i32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i32x4(a0),
+            self.reinterpret_f16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x8(self, a: i32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i32x4(a0),
+            self.reinterpret_bf16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i32x4(a0),
+            self.reinterpret_f32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x8(self, a: i32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i32x4(a0),
+            self.reinterpret_f64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x8(self, a: i32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_i32x4(a0), self.reinterpret_i8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x8(self, a: i32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i32x4(a0),
+            self.reinterpret_i16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x8(self, a: i32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i32x4(a0),
+            self.reinterpret_u16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x8(self, a: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i32x4(a0),
+            self.reinterpret_u32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i32x4(a0),
+            self.reinterpret_i64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x8(self, a: i32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i32x4(a0),
+            self.reinterpret_u64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i32x8(self, a: i32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(self.cvt_f32_i32x4(a0), self.cvt_f32_i32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x8(self, a: i32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i64x8(self.widen_i32x8(a))
+    }
+    #[inline(always)]
+    fn splat_u32x8(self, a: u32) -> u32x8<Self> {
+        let half = self.splat_u32x4(a);
+        self.combine_u32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.not_u32x4(a0), self.not_u32x4(a1))
+    }
+    #[inline(always)]
+    fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.add_u32x4(a0, b0), self.add_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.sub_u32x4(a0, b0), self.sub_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.mul_u32x4(a0, b0), self.mul_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.and_u32x4(a0, b0), self.and_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.or_u32x4(a0, b0), self.or_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.xor_u32x4(a0, b0), self.xor_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shl_u32x4(a0, b), self.shl_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shr_u32x4(a0, b), self.shr_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.shl_var_u32x4(a0, b0), self.shl_var_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.shr_var_u32x4(a0, b0), self.shr_var_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_eq_u32x4(a0, b0), self.simd_eq_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_lt_u32x4(a0, b0), self.simd_lt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_le_u32x4(a0, b0), self.simd_le_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_ge_u32x4(a0, b0), self.simd_ge_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_gt_u32x4(a0, b0), self.simd_gt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, _) = self.split_u32x8(a);
+        let (b0, _) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a0, b0), self.zip_high_u32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (_, a1) = self.split_u32x8(a);
+        let (_, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a1, b1), self.zip_high_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        let (c0, c1) = self.split_u32x8(c);
+        self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.min_u32x4(a0, b0), self.min_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_add_u32x4(self.add_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_min_u32x4(self.min_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_max_u32x4(self.max_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_and_u32x4(self.and_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_or_u32x4(self.or_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u32x8<I: Indices<8>>(self, a: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x8<I: Indices<8>>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x8<const L: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.abs_diff_u32x4(a0, b0), self.abs_diff_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x4(self.widen_u32x4(a0), self.widen_u32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        [
+            a[0usize] as u16,
+            a[1usize] as u16,
+            a[2usize] as u16,
+            a[3usize] as u16,
+            a[4usize] as u16,
+            a[5usize] as u16,
+            a[6usize] as u16,
+            a[7usize] as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        [
+            a[0usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[1usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[2usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[3usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[4usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[5usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[6usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+            a[7usize].clamp(u16::MIN as u32, u16::MAX as u32) as u16,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x8(self, a: u32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u32x4(a0),
+            self.reinterpret_f16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x8(self, a: u32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u32x4(a0),
+            self.reinterpret_bf16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u32x4(a0),
+            self.reinterpret_f32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x8(self, a: u32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u32x4(a0),
+            self.reinterpret_f64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x8(self, a: u32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_u32x4(a0), self.reinterpret_i8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x8(self, a: u32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u32x4(a0),
+            self.reinterpret_i16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x8(self, a: u32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u32x4(a0),
+            self.reinterpret_u16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x8(self, a: u32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u32x4(a0),
+            self.reinterpret_i32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x8(self, a: u32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u32x4(a0),
+            self.reinterpret_i64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u32x4(a0),
+            self.reinterpret_u64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u32x8(self, a: u32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(self.cvt_f32_u32x4(a0), self.cvt_f32_u32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x8(self, a: u32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u64x8(self.widen_u32x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x8(self, a: i32) -> mask32x8<Self> {
+        let half = self.splat_mask32x4(a);
+        self.combine_mask32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_mask32x4(self.not_mask32x4(a0), self.not_mask32x4(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.and_mask32x4(a0, b0), self.and_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.or_mask32x4(a0, b0), self.or_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.xor_mask32x4(a0, b0), self.xor_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        let (c0, c1) = self.split_mask32x8(c);
+        self.combine_mask32x4(
+            self.select_mask32x4(a0, b0, c0),
+            self.select_mask32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.simd_eq_mask32x4(a0, b0), self.simd_eq_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.any_mask32x4(a0) || self.any_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn all_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.all_mask32x4(a0) && self.all_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn none_mask32x8(self, a: mask32x8<Self>) -> bool {
+        !self.any_mask32x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x8(self, a: mask32x8<Self>) -> usize {
+        self.to_bitmask_mask32x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x8(self, a: mask32x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x8(self, a: mask32x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.to_bitmask_mask32x4(a0) | (self.to_bitmask_mask32x4(a1) << 4)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
+        self.combine_mask32x4(
+            self.from_bitmask_mask32x4(bits),
+            self.from_bitmask_mask32x4(bits >> 4),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask32x8<I: Indices<8>>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x8<I: Indices<8>>(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x8<const L: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x8(self, a: mask32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask32x4(a0),
+            self.reinterpret_i8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x8(self, a: mask32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask32x4(a0),
+            self.reinterpret_u8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x8(self, a: mask32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask32x4(a0),
+            self.reinterpret_i16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x8(self, a: mask32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask32x4(a0),
+            self.reinterpret_u16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask32x4(a0),
+            self.reinterpret_i32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x8(self, a: mask32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask32x4(a0),
+            self.reinterpret_u32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x8(self, a: mask32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask32x4(a0),
+            self.reinterpret_i64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x8(self, a: mask32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask32x4(a0),
+            self.reinterpret_u64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x4(self, a: f64) -> f64x4<Self> {
+        let half = self.splat_f64x2(a);
+        self.combine_f64x2(half, half)
+    }
+    #[inline(always)]
+    fn abs_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.abs_f64x2(a0), self.abs_f64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.neg_f64x2(a0), self.neg_f64x2(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.sqrt_f64x2(a0), self.sqrt_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_approx_f64x2(a0), self.recip_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_approx_f64x2(a0), self.rsqrt_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_f64x2(a0, steps), self.recip_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_f64x2(a0, steps), self.rsqrt_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.add_f64x2(a0, b0), self.add_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.sub_f64x2(a0, b0), self.sub_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.mul_f64x2(a0, b0), self.mul_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.div_f64x2(a0, b0), self.div_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.copysign_f64x2(a0, b0), self.copysign_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_eq_f64x2(a0, b0), self.simd_eq_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ne_f64x2(a0, b0), self.simd_ne_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_lt_f64x2(a0, b0), self.simd_lt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_le_f64x2(a0, b0), self.simd_le_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ge_f64x2(a0, b0), self.simd_ge_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_gt_f64x2(a0, b0), self.simd_gt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_ne_f64x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_eq_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        let abs = self.abs_f64x4(a);
+        self.and_mask64x4(
+            self.simd_lt_f64x4(abs, self.splat_f64x4(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x4(abs, self.splat_f64x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(
+            self.copysign_f64x4(self.splat_f64x4(1.0), a),
+            self.splat_f64x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, _) = self.split_f64x4(a);
+        let (b0, _) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a0, b0), self.zip_high_f64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (_, a1) = self.split_f64x4(a);
+        let (_, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a1, b1), self.zip_high_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.max_f64x2(a0, b0), self.max_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.max_precise_f64x2(a0, b0),
+            self.max_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.min_f64x2(a0, b0), self.min_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.min_precise_f64x2(a0, b0),
+            self.min_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.madd_f64x2(a0, b0, c0), self.madd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.msub_f64x2(a0, b0, c0), self.msub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmadd_f64x2(a0, b0, c0), self.nmadd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmsub_f64x2(a0, b0, c0), self.nmsub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fused_f64x2(a0, b0, c0),
+            self.mul_add_fused_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fast_f64x2(a0, b0, c0),
+            self.mul_add_fast_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.floor_f64x2(a0), self.floor_f64x2(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.ceil_f64x2(a0), self.ceil_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.round_f64x2(a0), self.round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(
+            self.round_ties_even_f64x2(a0),
+            self.round_ties_even_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.fract_f64x2(a0), self.fract_f64x2(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.trunc_f64x2(a0), self.trunc_f64x2(a1))
+    }
+    #[inline(always)]
+    fn select_f64x4(self, a: mask64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.select_f64x2(a0, b0, c0), self.select_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        self.reduce_add_f64x2(a0) + self.reduce_add_f64x2(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_min_f64x2(self.min_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_max_f64x2(self.max_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f64x4<I: Indices<4>>(self, a: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x4<I: Indices<4>>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x4<const L: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f64x4(self, a: f64x4<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        let mut b0 = [0.0; 2usize];
+        let mut b1 = [0.0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x4(self, a: f64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f64x2(a0),
+            self.reinterpret_f16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x4(self, a: f64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f64x2(a0),
+            self.reinterpret_bf16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x4(self, a: f64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f64x2(a0),
+            self.reinterpret_f32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x4(self, a: f64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_f64x2(a0), self.reinterpret_i8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x4(self, a: f64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_f64x2(a0), self.reinterpret_u8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x4(self, a: f64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f64x2(a0),
+            self.reinterpret_i16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x4(self, a: f64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f64x2(a0),
+            self.reinterpret_u16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x4(self, a: f64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f64x2(a0),
+            self.reinterpret_i32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x4(self, a: f64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f64x2(a0),
+            self.reinterpret_u32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f64x2(a0),
+            self.reinterpret_i64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f64x2(a0),
+            self.reinterpret_u64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x4(self, a: f64x4<Self>) -> f32x4<Self> {
+        [
+            a[0usize] as f32,
+            a[1usize] as f32,
+            a[2usize] as f32,
+            a[3usize] as f32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u16_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_f64x2(a0), self.cvt_i64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_round_f64x2(a0), self.cvt_i64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_f64x2(a0), self.cvt_u64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_round_f64x2(a0), self.cvt_u64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_i64x4(self, a: i64) -> i64x4<Self> {
+        let half = self.splat_i64x2(a);
+        self.combine_i64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.not_i64x2(a0), self.not_i64x2(a1))
+    }
+    #[inline(always)]
+    fn add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.add_i64x2(a0, b0), self.add_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.sub_i64x2(a0, b0), self.sub_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.mul_i64x2(a0, b0), self.mul_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.and_i64x2(a0, b0), self.and_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.or_i64x2(a0, b0), self.or_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.xor_i64x2(a0, b0), self.xor_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shl_i64x2(a0, b), self.shl_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shr_i64x2(a0, b), self.shr_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.shl_var_i64x2(a0, b0), self.shl_var_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.shr_var_i64x2(a0, b0), self.shr_var_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_eq_i64x2(a0, b0), self.simd_eq_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_lt_i64x2(a0, b0), self.simd_lt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_le_i64x2(a0, b0), self.simd_le_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_ge_i64x2(a0, b0), self.simd_ge_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_gt_i64x2(a0, b0), self.simd_gt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, _) = self.split_i64x4(a);
+        let (b0, _) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a0, b0), self.zip_high_i64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (_, a1) = self.split_i64x4(a);
+        let (_, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a1, b1), self.zip_high_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i64x4(self, a: mask64x4<Self>, b: i64x4<Self>, c: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        let (c0, c1) = self.split_i64x4(c);
+        self.combine_i64x2(self.select_i64x2(a0, b0, c0), self.select_i64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.min_i64x2(a0, b0), self.min_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.max_i64x2(a0, b0), self.max_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_add_i64x2(self.add_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_min_i64x2(self.min_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_max_i64x2(self.max_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_and_i64x2(self.and_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_or_i64x2(self.or_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i64x4<I: Indices<4>>(self, a: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x4<I: Indices<4>>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x4<const L: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.abs_i64x2(a0), self.abs_i64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.neg_i64x2(a0), self.neg_i64x2(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_u64x2(self.abs_diff_i64x2(a0, b0), self.abs_diff_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i64x4(self, a: i64x4<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        [
+            a[0usize] as i32,
+            a[1usize] as i32,
+            a[2usize] as i32,
+            a[3usize] as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        [
+            a[0usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            a[1usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            a[2usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+            a[3usize].clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x4(self, a: i64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i64x2(a0),
+            self.reinterpret_f16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x4(self, a: i64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i64x2(a0),
+            self.reinterpret_bf16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x4(self, a: i64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i64x2(a0),
+            self.reinterpret_f32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i64x2(a0),
+            self.reinterpret_f64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x4(self, a: i64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_i64x2(a0), self.reinterpret_i8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x4(self, a: i64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_i64x2(a0), self.reinterpret_u8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x4(self, a: i64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i64x2(a0),
+            self.reinterpret_i16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x4(self, a: i64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i64x2(a0),
+            self.reinterpret_u16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x4(self, a: i64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i64x2(a0),
+            self.reinterpret_i32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x4(self, a: i64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i64x2(a0),
+            self.reinterpret_u32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x4(self, a: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i64x2(a0),
+            self.reinterpret_u64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x4(self, a: i64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(self.cvt_f64_i64x2(a0), self.cvt_f64_i64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_u64x4(self, a: u64) -> u64x4<Self> {
+        let half = self.splat_u64x2(a);
+        self.combine_u64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.not_u64x2(a0), self.not_u64x2(a1))
+    }
+    #[inline(always)]
+    fn add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.add_u64x2(a0, b0), self.add_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.sub_u64x2(a0, b0), self.sub_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.mul_u64x2(a0, b0), self.mul_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.and_u64x2(a0, b0), self.and_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.or_u64x2(a0, b0), self.or_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.xor_u64x2(a0, b0), self.xor_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shl_u64x2(a0, b), self.shl_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shr_u64x2(a0, b), self.shr_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.shl_var_u64x2(a0, b0), self.shl_var_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.shr_var_u64x2(a0, b0), self.shr_var_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_eq_u64x2(a0, b0), self.simd_eq_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_lt_u64x2(a0, b0), self.simd_lt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_le_u64x2(a0, b0), self.simd_le_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_ge_u64x2(a0, b0), self.simd_ge_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_gt_u64x2(a0, b0), self.simd_gt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, _) = self.split_u64x4(a);
+        let (b0, _) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a0, b0), self.zip_high_u64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (_, a1) = self.split_u64x4(a);
+        let (_, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a1, b1), self.zip_high_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u64x4(self, a: mask64x4<Self>, b: u64x4<Self>, c: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        let (c0, c1) = self.split_u64x4(c);
+        self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.min_u64x2(a0, b0), self.min_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.max_u64x2(a0, b0), self.max_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_add_u64x2(self.add_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_min_u64x2(self.min_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_max_u64x2(self.max_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_and_u64x2(self.and_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_or_u64x2(self.or_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u64x4<I: Indices<4>>(self, a: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x4<I: Indices<4>>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x4<const L: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_diff_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.abs_diff_u64x2(a0, b0), self.abs_diff_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u64x4(self, a: u64x4<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        [
+            a[0usize] as u32,
+            a[1usize] as u32,
+            a[2usize] as u32,
+            a[3usize] as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_saturating_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        [
+            a[0usize].clamp(u32::MIN as u64, u32::MAX as u64) as u32,
+            a[1usize].clamp(u32::MIN as u64, u32::MAX as u64) as u32,
+            a[2usize].clamp(u32::MIN as u64, u32::MAX as u64) as u32,
+            a[3usize].clamp(u32::MIN as u64, u32::MAX as u64) as u32,
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x4(self, a: u64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u64x2(a0),
+            self.reinterpret_f16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x4(self, a: u64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u64x2(a0),
+            self.reinterpret_bf16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x4(self, a: u64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u64x2(a0),
+            self.reinterpret_f32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u64x2(a0),
+            self.reinterpret_f64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x4(self, a: u64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_u64x2(a0), self.reinterpret_i8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x4(self, a: u64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_u64x2(a0), self.reinterpret_u8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x4(self, a: u64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u64x2(a0),
+            self.reinterpret_i16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x4(self, a: u64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u64x2(a0),
+            self.reinterpret_u16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x4(self, a: u64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u64x2(a0),
+            self.reinterpret_i32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x4(self, a: u64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u64x2(a0),
+            self.reinterpret_u32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x4(self, a: u64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u64x2(a0),
+            self.reinterpret_i64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x4(self, a: u64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(self.cvt_f64_u64x2(a0), self.cvt_f64_u64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_mask64x4(self, a: i64) -> mask64x4<Self> {
+        let half = self.splat_mask64x2(a);
+        self.combine_mask64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_mask64x2(self.not_mask64x2(a0), self.not_mask64x2(a1))
+    }
+    #[inline(always)]
+    fn and_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.and_mask64x2(a0, b0), self.and_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.or_mask64x2(a0, b0), self.or_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.xor_mask64x2(a0, b0), self.xor_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        let (c0, c1) = self.split_mask64x4(c);
+        self.combine_mask64x2(
+            self.select_mask64x2(a0, b0, c0),
+            self.select_mask64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.simd_eq_mask64x2(a0, b0), self.simd_eq_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.any_mask64x2(a0) || self.any_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn all_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.all_mask64x2(a0) && self.all_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn none_mask64x4(self, a: mask64x4<Self>) -> bool {
+        !self.any_mask64x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x4(self, a: mask64x4<Self>) -> usize {
+        self.to_bitmask_mask64x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x4(self, a: mask64x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x4(self, a: mask64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.to_bitmask_mask64x2(a0) | (self.to_bitmask_mask64x2(a1) << 2)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
+        self.combine_mask64x2(
+            self.from_bitmask_mask64x2(bits),
+            self.from_bitmask_mask64x2(bits >> 2),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask64x4<I: Indices<4>>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x4<I: Indices<4>>(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + K) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[(i + 4 - K % 4) % 4]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x4<const L: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        [a[L % 4]; 4].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask64x4(self, a: mask64x4<Self>) -> (mask64x2<Self>, mask64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x4(self, a: mask64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask64x2(a0),
+            self.reinterpret_i8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x4(self, a: mask64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask64x2(a0),
+            self.reinterpret_u8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x4(self, a: mask64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask64x2(a0),
+            self.reinterpret_i16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x4(self, a: mask64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask64x2(a0),
+            self.reinterpret_u16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x4(self, a: mask64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask64x2(a0),
+            self.reinterpret_i32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x4(self, a: mask64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask64x2(a0),
+            self.reinterpret_u32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask64x2(a0),
+            self.reinterpret_i64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x4(self, a: mask64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask64x2(a0),
+            self.reinterpret_u64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f32x16(self, a: f32) -> f32x16<Self> {
+        let half = self.splat_f32x8(a);
+        self.combine_f32x8(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.abs_f32x8(a0), self.abs_f32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.neg_f32x8(a0), self.neg_f32x8(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.sqrt_f32x8(a0), self.sqrt_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_approx_f32x8(a0), self.recip_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_approx_f32x8(a0), self.rsqrt_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_f32x8(a0, steps), self.recip_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_f32x8(a0, steps), self.rsqrt_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.add_f32x8(a0, b0), self.add_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.sub_f32x8(a0, b0), self.sub_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.mul_f32x8(a0, b0), self.mul_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.div_f32x8(a0, b0), self.div_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.copysign_f32x8(a0, b0), self.copysign_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_eq_f32x8(a0, b0), self.simd_eq_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ne_f32x8(a0, b0), self.simd_ne_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_lt_f32x8(a0, b0), self.simd_lt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_le_f32x8(a0, b0), self.simd_le_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ge_f32x8(a0, b0), self.simd_ge_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_gt_f32x8(a0, b0), self.simd_gt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_ne_f32x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_eq_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        let abs = self.abs_f32x16(a);
+        self.and_mask32x16(
+            self.simd_lt_f32x16(abs, self.splat_f32x16(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x16(abs, self.splat_f32x16(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(
+            self.copysign_f32x16(self.splat_f32x16(1.0), a),
+            self.splat_f32x16(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, _) = self.split_f32x16(a);
+        let (b0, _) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a0, b0), self.zip_high_f32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (_, a1) = self.split_f32x16(a);
+        let (_, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a1, b1), self.zip_high_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.max_f32x8(a0, b0), self.max_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.max_precise_f32x8(a0, b0),
+            self.max_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.min_f32x8(a0, b0), self.min_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.min_precise_f32x8(a0, b0),
+            self.min_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.madd_f32x8(a0, b0, c0), self.madd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.msub_f32x8(a0, b0, c0), self.msub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmadd_f32x8(a0, b0, c0), self.nmadd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmsub_f32x8(a0, b0, c0), self.nmsub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fused_f32x8(a0, b0, c0),
+            self.mul_add_fused_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fast_f32x8(a0, b0, c0),
+            self.mul_add_fast_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.ceil_f32x8(a0), self.ceil_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.round_f32x8(a0), self.round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(
+            self.round_ties_even_f32x8(a0),
+            self.round_ties_even_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.fract_f32x8(a0), self.fract_f32x8(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.trunc_f32x8(a0), self.trunc_f32x8(a1))
+    }
+    #[inline(always)]
+    fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        self.reduce_add_f32x8(a0) + self.reduce_add_f32x8(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_min_f32x8(self.min_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_max_f32x8(self.max_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x16<I: Indices<16>>(self, a: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x16<I: Indices<16>>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x16<const L: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        let mut b0 = [0.0; 8usize];
+        let mut b1 = [0.0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self> {
+        [
+            src[0usize],
+            src[4usize],
+            src[8usize],
+            src[12usize],
+            src[1usize],
+            src[5usize],
+            src[9usize],
+            src[13usize],
+            src[2usize],
+            src[6usize],
+            src[10usize],
+            src[14usize],
+            src[3usize],
+            src[7usize],
+            src[11usize],
+            src[15usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
+        *dest = [
+            a[0usize], a[4usize], a[8usize], a[12usize], a[1usize], a[5usize], a[9usize],
+            a[13usize], a[2usize], a[6usize], a[10usize], a[14usize], a[3usize], a[7usize],
+            a[11usize], a[15usize],
+        ];
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x16(self, a: f32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_f32x8(a0),
+            self.reinterpret_f64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x16(self, a: f32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_f32x8(a0), self.reinterpret_i8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x16(self, a: f32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_f32x8(a0), self.reinterpret_u8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x16(self, a: f32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f32x8(a0),
+            self.reinterpret_i16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x16(self, a: f32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f32x8(a0),
+            self.reinterpret_u16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f32x8(a0),
+            self.reinterpret_i32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f32x8(a0),
+            self.reinterpret_u32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x16(self, a: f32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f32x8(a0),
+            self.reinterpret_i64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x16(self, a: f32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f32x8(a0),
+            self.reinterpret_u64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x16(self, a: f32x16<Self>) -> f16x16<Self> {
+        a.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i8_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_f32x8(a0), self.cvt_i32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_round_f32x8(a0), self.cvt_i32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_f32x8(a0), self.cvt_u32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_round_f32x8(a0), self.cvt_u32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x16(self, a: f32x16<Self>) -> bf16x16<Self> {
+        a.val.map(bf16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i8x64(self, a: i8) -> i8x64<Self> {
+        let half = self.splat_i8x32(a);
+        self.combine_i8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.not_i8x32(a0), self.not_i8x32(a1))
+    }
+    #[inline(always)]
+    fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.add_i8x32(a0, b0), self.add_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.sub_i8x32(a0, b0), self.sub_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_i8x32(a0, b0), self.mul_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.and_i8x32(a0, b0), self.and_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.or_i8x32(a0, b0), self.or_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.xor_i8x32(a0, b0), self.xor_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shl_i8x32(a0, b), self.shl_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shr_i8x32(a0, b), self.shr_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shl_var_i8x32(a0, b0), self.shl_var_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shr_var_i8x32(a0, b0), self.shr_var_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_eq_i8x32(a0, b0), self.simd_eq_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_lt_i8x32(a0, b0), self.simd_lt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_le_i8x32(a0, b0), self.simd_le_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_ge_i8x32(a0, b0), self.simd_ge_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_gt_i8x32(a0, b0), self.simd_gt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, _) = self.split_i8x64(a);
+        let (b0, _) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a0, b0), self.zip_high_i8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (_, a1) = self.split_i8x64(a);
+        let (_, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a1, b1), self.zip_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.min_i8x32(a0, b0), self.min_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_i8x32(self.add_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_min_i8x32(self.min_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_max_i8x32(self.max_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_and_i8x32(self.and_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_or_i8x32(self.or_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x64<I: Indices<64>>(self, a: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x64<I: Indices<64>>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[64 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + K) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + 64 - K % 64) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x64<const L: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        [a[L % 64]; 64].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let lanes: [_; 64] =
+            core::array::from_fn(|i| if i < 32 { a[2 * i] } else { b[2 * (i - 32)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            if i < 32 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 32) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.abs_i8x32(a0), self.abs_i8x32(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(self.abs_diff_i8x32(a0, b0), self.abs_diff_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_high_i8x32(a0, b0), self.mul_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x64(self, a: i8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i8x32(a0),
+            self.reinterpret_f32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x64(self, a: i8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i8x32(a0),
+            self.reinterpret_f64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i8x32(a0),
+            self.reinterpret_i16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x64(self, a: i8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i8x32(a0),
+            self.reinterpret_u16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x64(self, a: i8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i8x32(a0),
+            self.reinterpret_i32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x64(self, a: i8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i8x32(a0),
+            self.reinterpret_u32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x64(self, a: i8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i8x32(a0),
+            self.reinterpret_i64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x64(self, a: i8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i8x32(a0),
+            self.reinterpret_u64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x64(self, a: u8) -> u8x64<Self> {
+        let half = self.splat_u8x32(a);
+        self.combine_u8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.not_u8x32(a0), self.not_u8x32(a1))
+    }
+    #[inline(always)]
+    fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.add_u8x32(a0, b0), self.add_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.sub_u8x32(a0, b0), self.sub_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_u8x32(a0, b0), self.mul_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.and_u8x32(a0, b0), self.and_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.or_u8x32(a0, b0), self.or_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.xor_u8x32(a0, b0), self.xor_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shl_u8x32(a0, b), self.shl_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shr_u8x32(a0, b), self.shr_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shl_var_u8x32(a0, b0), self.shl_var_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shr_var_u8x32(a0, b0), self.shr_var_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_eq_u8x32(a0, b0), self.simd_eq_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_lt_u8x32(a0, b0), self.simd_lt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_le_u8x32(a0, b0), self.simd_le_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_ge_u8x32(a0, b0), self.simd_ge_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_gt_u8x32(a0, b0), self.simd_gt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, _) = self.split_u8x64(a);
+        let (b0, _) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a0, b0), self.zip_high_u8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (_, a1) = self.split_u8x64(a);
+        let (_, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a1, b1), self.zip_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.min_u8x32(a0, b0), self.min_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_u8x32(self.add_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_min_u8x32(self.min_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_max_u8x32(self.max_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_and_u8x32(self.and_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_or_u8x32(self.or_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x64<I: Indices<64>>(self, a: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x64<I: Indices<64>>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[64 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + K) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + 64 - K % 64) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x64<const L: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        [a[L % 64]; 64].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let lanes: [_; 64] =
+            core::array::from_fn(|i| if i < 32 { a[2 * i] } else { b[2 * (i - 32)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            if i < 32 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 32) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.abs_diff_u8x32(a0, b0), self.abs_diff_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_high_u8x32(a0, b0), self.mul_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x64(self, a: u8x32<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_32_u8x32(a, b0), self.lookup_32_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x64(self, a: [u8x16<Self>; 3], b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_48_u8x32(a, b0), self.lookup_48_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self> {
+        [
+            src[0usize],
+            src[16usize],
+            src[32usize],
+            src[48usize],
+            src[1usize],
+            src[17usize],
+            src[33usize],
+            src[49usize],
+            src[2usize],
+            src[18usize],
+            src[34usize],
+            src[50usize],
+            src[3usize],
+            src[19usize],
+            src[35usize],
+            src[51usize],
+            src[4usize],
+            src[20usize],
+            src[36usize],
+            src[52usize],
+            src[5usize],
+            src[21usize],
+            src[37usize],
+            src[53usize],
+            src[6usize],
+            src[22usize],
+            src[38usize],
+            src[54usize],
+            src[7usize],
+            src[23usize],
+            src[39usize],
+            src[55usize],
+            src[8usize],
+            src[24usize],
+            src[40usize],
+            src[56usize],
+            src[9usize],
+            src[25usize],
+            src[41usize],
+            src[57usize],
+            src[10usize],
+            src[26usize],
+            src[42usize],
+            src[58usize],
+            src[11usize],
+            src[27usize],
+            src[43usize],
+            src[59usize],
+            src[12usize],
+            src[28usize],
+            src[44usize],
+            src[60usize],
+            src[13usize],
+            src[29usize],
+            src[45usize],
+            src[61usize],
+            src[14usize],
+            src[30usize],
+            src[46usize],
+            src[62usize],
+            src[15usize],
+            src[31usize],
+            src[47usize],
+            src[63usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        *dest = [
+            a[0usize], a[16usize], a[32usize], a[48usize], a[1usize], a[17usize], a[33usize],
+            a[49usize], a[2usize], a[18usize], a[34usize], a[50usize], a[3usize], a[19usize],
+            a[35usize], a[51usize], a[4usize], a[20usize], a[36usize], a[52usize], a[5usize],
+            a[21usize], a[37usize], a[53usize], a[6usize], a[22usize], a[38usize], a[54usize],
+            a[7usize], a[23usize], a[39usize], a[55usize], a[8usize], a[24usize], a[40usize],
+            a[56usize], a[9usize], a[25usize], a[41usize], a[57usize], a[10usize], a[26usize],
+            a[42usize], a[58usize], a[11usize], a[27usize], a[43usize], a[59usize], a[12usize],
+            a[28usize], a[44usize], a[60usize], a[13usize], a[29usize], a[45usize], a[61usize],
+            a[14usize], a[30usize], a[46usize], a[62usize], a[15usize], a[31usize], a[47usize],
+            a[63usize],
+        ];
+    }
+    #[inline(always)]
+    fn widen_low_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x64(self, a: u8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u8x32(a0),
+            self.reinterpret_f32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x64(self, a: u8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u8x32(a0),
+            self.reinterpret_f64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x64(self, a: u8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i8x32(self.reinterpret_i8_u8x32(a0), self.reinterpret_i8_u8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x64(self, a: u8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u8x32(a0),
+            self.reinterpret_i16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u8x32(a0),
+            self.reinterpret_u16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x64(self, a: u8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u8x32(a0),
+            self.reinterpret_i32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u8x32(a0),
+            self.reinterpret_u32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x64(self, a: u8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u8x32(a0),
+            self.reinterpret_i64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x64(self, a: u8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u8x32(a0),
+            self.reinterpret_u64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x64(self, a: i8) -> mask8x64<Self> {
+        let half = self.splat_mask8x32(a);
+        self.combine_mask8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.not_mask8x32(a0), self.not_mask8x32(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.and_mask8x32(a0, b0), self.and_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.or_mask8x32(a0, b0), self.or_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.xor_mask8x32(a0, b0), self.xor_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_mask8x32(a0, b0, c0),
+            self.select_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.simd_eq_mask8x32(a0, b0), self.simd_eq_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.any_mask8x32(a0) || self.any_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.all_mask8x32(a0) && self.all_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x64(self, a: mask8x64<Self>) -> bool {
+        !self.any_mask8x64(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x64(self, a: mask8x64<Self>) -> usize {
+        self.to_bitmask_mask8x64(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x64(self, a: mask8x64<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x64(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x64(self, a: mask8x64<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.to_bitmask_mask8x32(a0) | (self.to_bitmask_mask8x32(a1) << 32)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
+        self.combine_mask8x32(
+            self.from_bitmask_mask8x32(bits),
+            self.from_bitmask_mask8x32(bits >> 32),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x64<I: Indices<64>>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x64<I: Indices<64>>(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[64 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + K) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| a[(i + 64 - K % 64) % 64]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x64<const L: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        [a[L % 64]; 64].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let lanes: [_; 64] =
+            core::array::from_fn(|i| if i < 32 { a[2 * i] } else { b[2 * (i - 32)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            if i < 32 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 32) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask8x32(a0),
+            self.reinterpret_i8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x64(self, a: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask8x32(a0),
+            self.reinterpret_u8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x64(self, a: mask8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask8x32(a0),
+            self.reinterpret_i16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x64(self, a: mask8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask8x32(a0),
+            self.reinterpret_u16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x64(self, a: mask8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask8x32(a0),
+            self.reinterpret_i32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x64(self, a: mask8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask8x32(a0),
+            self.reinterpret_u32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x64(self, a: mask8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask8x32(a0),
+            self.reinterpret_i64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x64(self, a: mask8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask8x32(a0),
+            self.reinterpret_u64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x32(self, a: i16) -> i16x32<Self> {
+        let half = self.splat_i16x16(a);
+        self.combine_i16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.not_i16x16(a0), self.not_i16x16(a1))
+    }
+    #[inline(always)]
+    fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.add_i16x16(a0, b0), self.add_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.sub_i16x16(a0, b0), self.sub_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_i16x16(a0, b0), self.mul_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.and_i16x16(a0, b0), self.and_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.or_i16x16(a0, b0), self.or_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.xor_i16x16(a0, b0), self.xor_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shl_i16x16(a0, b), self.shl_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shr_i16x16(a0, b), self.shr_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shl_var_i16x16(a0, b0), self.shl_var_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shr_var_i16x16(a0, b0), self.shr_var_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_eq_i16x16(a0, b0), self.simd_eq_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_lt_i16x16(a0, b0), self.simd_lt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_le_i16x16(a0, b0), self.simd_le_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_ge_i16x16(a0, b0), self.simd_ge_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_gt_i16x16(a0, b0), self.simd_gt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, _) = self.split_i16x32(a);
+        let (b0, _) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a0, b0), self.zip_high_i16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (_, a1) = self.split_i16x32(a);
+        let (_, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a1, b1), self.zip_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_i16x16(a0, b0, c0),
+            self.select_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.min_i16x16(a0, b0), self.min_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_i16x16(self.add_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_min_i16x16(self.min_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_max_i16x16(self.max_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_and_i16x16(self.and_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_or_i16x16(self.or_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x32<I: Indices<32>>(self, a: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x32<I: Indices<32>>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x32<const L: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.abs_i16x16(a0), self.abs_i16x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(self.abs_diff_i16x16(a0, b0), self.abs_diff_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_high_i16x16(a0, b0), self.mul_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.mul_q15_round_sat_i16x16(a0, b0),
+            self.mul_q15_round_sat_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i32x8(self.dot_i16_i16x16(a0, b0), self.dot_i16_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(self.narrow_i16x16(a0), self.narrow_i16x16(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(
+            self.narrow_saturating_i16x16(a0),
+            self.narrow_saturating_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x32(self, a: i16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i16x16(a0),
+            self.reinterpret_f32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x32(self, a: i16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i16x16(a0),
+            self.reinterpret_f64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x32(self, a: i16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_i16x16(a0),
+            self.reinterpret_i8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_i16x16(a0),
+            self.reinterpret_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x32(self, a: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i16x16(a0),
+            self.reinterpret_u16_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i16x16(a0),
+            self.reinterpret_i32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x32(self, a: i16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i16x16(a0),
+            self.reinterpret_u32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x32(self, a: i16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i16x16(a0),
+            self.reinterpret_i64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x32(self, a: i16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i16x16(a0),
+            self.reinterpret_u64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u16x32(self, a: u16) -> u16x32<Self> {
+        let half = self.splat_u16x16(a);
+        self.combine_u16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.not_u16x16(a0), self.not_u16x16(a1))
+    }
+    #[inline(always)]
+    fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.add_u16x16(a0, b0), self.add_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.sub_u16x16(a0, b0), self.sub_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_u16x16(a0, b0), self.mul_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.and_u16x16(a0, b0), self.and_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.or_u16x16(a0, b0), self.or_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.xor_u16x16(a0, b0), self.xor_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shl_u16x16(a0, b), self.shl_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shr_u16x16(a0, b), self.shr_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shl_var_u16x16(a0, b0), self.shl_var_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shr_var_u16x16(a0, b0), self.shr_var_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_eq_u16x16(a0, b0), self.simd_eq_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_lt_u16x16(a0, b0), self.simd_lt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_le_u16x16(a0, b0), self.simd_le_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_ge_u16x16(a0, b0), self.simd_ge_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_gt_u16x16(a0, b0), self.simd_gt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, _) = self.split_u16x32(a);
+        let (b0, _) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a0, b0), self.zip_high_u16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (_, a1) = self.split_u16x32(a);
+        let (_, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a1, b1), self.zip_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_u16x16(a0, b0, c0),
+            self.select_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.min_u16x16(a0, b0), self.min_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_u16x16(self.add_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_min_u16x16(self.min_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_max_u16x16(self.max_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_and_u16x16(self.and_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_or_u16x16(self.or_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x32<I: Indices<32>>(self, a: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x32<I: Indices<32>>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x32<const L: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.abs_diff_u16x16(a0, b0), self.abs_diff_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_high_u16x16(a0, b0), self.mul_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self> {
+        [
+            src[0usize],
+            src[8usize],
+            src[16usize],
+            src[24usize],
+            src[1usize],
+            src[9usize],
+            src[17usize],
+            src[25usize],
+            src[2usize],
+            src[10usize],
+            src[18usize],
+            src[26usize],
+            src[3usize],
+            src[11usize],
+            src[19usize],
+            src[27usize],
+            src[4usize],
+            src[12usize],
+            src[20usize],
+            src[28usize],
+            src[5usize],
+            src[13usize],
+            src[21usize],
+            src[29usize],
+            src[6usize],
+            src[14usize],
+            src[22usize],
+            src[30usize],
+            src[7usize],
+            src[15usize],
+            src[23usize],
+            src[31usize],
+        ]
+        .simd_into(self)
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
+        *dest = [
+            a[0usize], a[8usize], a[16usize], a[24usize], a[1usize], a[9usize], a[17usize],
+            a[25usize], a[2usize], a[10usize], a[18usize], a[26usize], a[3usize], a[11usize],
+            a[19usize], a[27usize], a[4usize], a[12usize], a[20usize], a[28usize], a[5usize],
+            a[13usize], a[21usize], a[29usize], a[6usize], a[14usize], a[22usize], a[30usize],
+            a[7usize], a[15usize], a[23usize], a[31usize],
+        ];
+    }
+    #[inline(always)]
+    fn widen_low_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
     }
     #[inline(always)]
-    fn or_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
-        [
-            i32::bitor(a[0usize], &b[0usize]),
-            i32::bitor(a[1usize], &b[1usize]),
-            i32::bitor(a[2usize], &b[2usize]),
-            i32::bitor(a[3usize], &b[3usize]),
-        ]
-        .simd_into(self)
+    fn narrow_saturating_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(
+            self.narrow_saturating_u16x16(a0),
+            self.narrow_saturating_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn xor_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
-        [
-            i32::bitxor(a[0usize], &b[0usize]),
-            i32::bitxor(a[1usize], &b[1usize]),
-            i32::bitxor(a[2usize], &b[2usize]),
-            i32::bitxor(a[3usize], &b[3usize]),
-        ]
-        .simd_into(self)
+    fn reinterpret_f32_u16x32(self, a: u16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u16x16(a0),
+            self.reinterpret_f32_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn select_mask32x4(
-        self,
-        a: mask32x4<Self>,
-        b: mask32x4<Self>,
-        c: mask32x4<Self>,
-    ) -> mask32x4<Self> {
-        [
-            if a[0usize] != 0 { b[0usize] } else { c[0usize] },
-            if a[1usize] != 0 { b[1usize] } else { c[1usize] },
-            if a[2usize] != 0 { b[2usize] } else { c[2usize] },
-            if a[3usize] != 0 { b[3usize] } else { c[3usize] },
-        ]
-        .simd_into(self)
+    fn reinterpret_f64_u16x32(self, a: u16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u16x16(a0),
+            self.reinterpret_f64_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
-        [
-            -(i32::eq(&a[0usize], &b[0usize]) as i32),
-            -(i32::eq(&a[1usize], &b[1usize]) as i32),
-            -(i32::eq(&a[2usize], &b[2usize]) as i32),
-            -(i32::eq(&a[3usize], &b[3usize]) as i32),
-        ]
-        .simd_into(self)
+    fn reinterpret_i8_u16x32(self, a: u16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_u16x16(a0),
+            self.reinterpret_i8_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self> {
-        let mut result = [0; 8usize];
-        result[0..4usize].copy_from_slice(&a.val);
-        result[4usize..8usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_u16x16(a0),
+            self.reinterpret_u8_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn splat_f32x8(self, a: f32) -> f32x8<Self> {
-        let half = self.splat_f32x4(a);
-        self.combine_f32x4(half, half)
+    fn reinterpret_i16_u16x32(self, a: u16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u16x16(a0),
+            self.reinterpret_i16_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.abs_f32x4(a0), self.abs_f32x4(a1))
+    fn reinterpret_i32_u16x32(self, a: u16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u16x16(a0),
+            self.reinterpret_i32_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.neg_f32x4(a0), self.neg_f32x4(a1))
+    fn reinterpret_u32_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u16x16(a0),
+            self.reinterpret_u32_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.sqrt_f32x4(a0), self.sqrt_f32x4(a1))
+    fn reinterpret_i64_u16x32(self, a: u16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u16x16(a0),
+            self.reinterpret_i64_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.add_f32x4(a0, b0), self.add_f32x4(a1, b1))
+    fn reinterpret_u64_u16x32(self, a: u16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u16x16(a0),
+            self.reinterpret_u64_u16x16(a1),
+        )
     }
     #[inline(always)]
-    fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.sub_f32x4(a0, b0), self.sub_f32x4(a1, b1))
+    fn splat_mask16x32(self, a: i16) -> mask16x32<Self> {
+        let half = self.splat_mask16x16(a);
+        self.combine_mask16x16(half, half)
     }
     #[inline(always)]
-    fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.mul_f32x4(a0, b0), self.mul_f32x4(a1, b1))
+    fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.not_mask16x16(a0), self.not_mask16x16(a1))
     }
     #[inline(always)]
-    fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.div_f32x4(a0, b0), self.div_f32x4(a1, b1))
+    fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.and_mask16x16(a0, b0), self.and_mask16x16(a1, b1))
     }
     #[inline(always)]
-    fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.copysign_f32x4(a0, b0), self.copysign_f32x4(a1, b1))
+    fn or_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.or_mask16x16(a0, b0), self.or_mask16x16(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_mask32x4(self.simd_eq_f32x4(a0, b0), self.simd_eq_f32x4(a1, b1))
+    fn xor_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.xor_mask16x16(a0, b0), self.xor_mask16x16(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_mask32x4(self.simd_lt_f32x4(a0, b0), self.simd_lt_f32x4(a1, b1))
+    fn select_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_mask16x16(a0, b0, c0),
+            self.select_mask16x16(a1, b1, c1),
+        )
     }
     #[inline(always)]
-    fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_mask32x4(self.simd_le_f32x4(a0, b0), self.simd_le_f32x4(a1, b1))
+    fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.simd_eq_mask16x16(a0, b0),
+            self.simd_eq_mask16x16(a1, b1),
+        )
     }
     #[inline(always)]
-    fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_mask32x4(self.simd_ge_f32x4(a0, b0), self.simd_ge_f32x4(a1, b1))
+    fn any_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.any_mask16x16(a0) || self.any_mask16x16(a1)
     }
     #[inline(always)]
-    fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_mask32x4(self.simd_gt_f32x4(a0, b0), self.simd_gt_f32x4(a1, b1))
+    fn all_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.all_mask16x16(a0) && self.all_mask16x16(a1)
     }
     #[inline(always)]
-    fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, _) = self.split_f32x8(a);
-        let (b0, _) = self.split_f32x8(b);
-        self.combine_f32x4(self.zip_low_f32x4(a0, b0), self.zip_high_f32x4(a0, b0))
+    fn none_mask16x32(self, a: mask16x32<Self>) -> bool {
+        !self.any_mask16x32(a)
     }
     #[inline(always)]
-    fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (_, a1) = self.split_f32x8(a);
-        let (_, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.zip_low_f32x4(a1, b1), self.zip_high_f32x4(a1, b1))
+    fn count_true_mask16x32(self, a: mask16x32<Self>) -> usize {
+        self.to_bitmask_mask16x32(a).count_ones() as usize
     }
     #[inline(always)]
-    fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.max_f32x4(a0, b0), self.max_f32x4(a1, b1))
+    fn first_true_mask16x32(self, a: mask16x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
     }
     #[inline(always)]
-    fn max_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(
-            self.max_precise_f32x4(a0, b0),
-            self.max_precise_f32x4(a1, b1),
-        )
+    fn to_bitmask_mask16x32(self, a: mask16x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.to_bitmask_mask16x16(a0) | (self.to_bitmask_mask16x16(a1) << 16)
     }
     #[inline(always)]
-    fn min_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(self.min_f32x4(a0, b0), self.min_f32x4(a1, b1))
+    fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
+        self.combine_mask16x16(
+            self.from_bitmask_mask16x16(bits),
+            self.from_bitmask_mask16x16(bits >> 16),
+        )
     }
     #[inline(always)]
-    fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        self.combine_f32x4(
-            self.min_precise_f32x4(a0, b0),
-            self.min_precise_f32x4(a1, b1),
-        )
+    fn shuffle_mask16x32<I: Indices<32>>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn madd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        let (c0, c1) = self.split_f32x8(c);
-        self.combine_f32x4(self.madd_f32x4(a0, b0, c0), self.madd_f32x4(a1, b1, c1))
+    fn shuffle2_mask16x32<I: Indices<32>>(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn msub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        let (c0, c1) = self.split_f32x8(c);
-        self.combine_f32x4(self.msub_f32x4(a0, b0, c0), self.msub_f32x4(a1, b1, c1))
+    fn reverse_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[32 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
+    fn rotate_lanes_left_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + K) % 32]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.fract_f32x4(a0), self.fract_f32x4(a1))
+    fn rotate_lanes_right_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| a[(i + 32 - K % 32) % 32]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_f32x4(self.trunc_f32x4(a0), self.trunc_f32x4(a1))
+    fn broadcast_lane_mask16x32<const L: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        [a[L % 32]; 32].simd_into(self)
     }
     #[inline(always)]
-    fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_f32x8(b);
-        let (c0, c1) = self.split_f32x8(c);
-        self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
+    fn unzip_low_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let lanes: [_; 32] =
+            core::array::from_fn(|i| if i < 16 { a[2 * i] } else { b[2 * (i - 16)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
-        let mut result = [0.0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn unzip_high_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            if i < 16 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 16) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>) {
-        let mut b0 = [0.0; 4usize];
-        let mut b1 = [0.0; 4usize];
-        b0.copy_from_slice(&a.val[0..4usize]);
-        b1.copy_from_slice(&a.val[4usize..8usize]);
+    fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
         (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_u32x4(self.cvt_u32_f32x4(a0), self.cvt_u32_f32x4(a1))
+    fn reinterpret_i8_mask16x32(self, a: mask16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask16x16(a0),
+            self.reinterpret_i8_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_f32x8(a);
-        self.combine_i32x4(self.cvt_i32_f32x4(a0), self.cvt_i32_f32x4(a1))
+    fn reinterpret_u8_mask16x32(self, a: mask16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask16x16(a0),
+            self.reinterpret_u8_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn splat_i8x32(self, a: i8) -> i8x32<Self> {
-        let half = self.splat_i8x16(a);
-        self.combine_i8x16(half, half)
+    fn reinterpret_i16_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask16x16(a0),
+            self.reinterpret_i16_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        self.combine_i8x16(self.not_i8x16(a0), self.not_i8x16(a1))
+    fn reinterpret_u16_mask16x32(self, a: mask16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask16x16(a0),
+            self.reinterpret_u16_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.add_i8x16(a0, b0), self.add_i8x16(a1, b1))
+    fn reinterpret_i32_mask16x32(self, a: mask16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask16x16(a0),
+            self.reinterpret_i32_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.sub_i8x16(a0, b0), self.sub_i8x16(a1, b1))
+    fn reinterpret_u32_mask16x32(self, a: mask16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask16x16(a0),
+            self.reinterpret_u32_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn mul_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.mul_i8x16(a0, b0), self.mul_i8x16(a1, b1))
+    fn reinterpret_i64_mask16x32(self, a: mask16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask16x16(a0),
+            self.reinterpret_i64_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.and_i8x16(a0, b0), self.and_i8x16(a1, b1))
+    fn reinterpret_u64_mask16x32(self, a: mask16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask16x16(a0),
+            self.reinterpret_u64_mask16x16(a1),
+        )
     }
     #[inline(always)]
-    fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.or_i8x16(a0, b0), self.or_i8x16(a1, b1))
+    fn splat_i32x16(self, a: i32) -> i32x16<Self> {
+        let half = self.splat_i32x8(a);
+        self.combine_i32x8(half, half)
     }
     #[inline(always)]
-    fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.xor_i8x16(a0, b0), self.xor_i8x16(a1, b1))
+    fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.not_i32x8(a0), self.not_i32x8(a1))
     }
     #[inline(always)]
-    fn shr_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        self.combine_i8x16(self.shr_i8x16(a0, b), self.shr_i8x16(a1, b))
+    fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.add_i32x8(a0, b0), self.add_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_mask8x16(self.simd_eq_i8x16(a0, b0), self.simd_eq_i8x16(a1, b1))
+    fn sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.sub_i32x8(a0, b0), self.sub_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_mask8x16(self.simd_lt_i8x16(a0, b0), self.simd_lt_i8x16(a1, b1))
+    fn mul_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.mul_i32x8(a0, b0), self.mul_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_mask8x16(self.simd_le_i8x16(a0, b0), self.simd_le_i8x16(a1, b1))
+    fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.and_i32x8(a0, b0), self.and_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_ge_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_mask8x16(self.simd_ge_i8x16(a0, b0), self.simd_ge_i8x16(a1, b1))
+    fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.or_i32x8(a0, b0), self.or_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_gt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_mask8x16(self.simd_gt_i8x16(a0, b0), self.simd_gt_i8x16(a1, b1))
+    fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.xor_i32x8(a0, b0), self.xor_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn zip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, _) = self.split_i8x32(a);
-        let (b0, _) = self.split_i8x32(b);
-        self.combine_i8x16(self.zip_low_i8x16(a0, b0), self.zip_high_i8x16(a0, b0))
+    fn shl_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shl_i32x8(a0, b), self.shl_i32x8(a1, b))
     }
     #[inline(always)]
-    fn zip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (_, a1) = self.split_i8x32(a);
-        let (_, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.zip_low_i8x16(a1, b1), self.zip_high_i8x16(a1, b1))
+    fn shr_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shr_i32x8(a0, b), self.shr_i32x8(a1, b))
     }
     #[inline(always)]
-    fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        let (c0, c1) = self.split_i8x32(c);
-        self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
+    fn shl_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shl_var_i32x8(a0, b0), self.shl_var_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.min_i8x16(a0, b0), self.min_i8x16(a1, b1))
+    fn shr_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shr_var_i32x8(a0, b0), self.shr_var_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        let (b0, b1) = self.split_i8x32(b);
-        self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
+    fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_eq_i32x8(a0, b0), self.simd_eq_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
-        let mut result = [0; 64usize];
-        result[0..32usize].copy_from_slice(&a.val);
-        result[32usize..64usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_lt_i32x8(a0, b0), self.simd_lt_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_le_i32x8(a0, b0), self.simd_le_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_i8x32(a);
-        self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
+    fn simd_ge_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_ge_i32x8(a0, b0), self.simd_ge_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn splat_u8x32(self, a: u8) -> u8x32<Self> {
-        let half = self.splat_u8x16(a);
-        self.combine_u8x16(half, half)
+    fn simd_gt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_gt_i32x8(a0, b0), self.simd_gt_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        self.combine_u8x16(self.not_u8x16(a0), self.not_u8x16(a1))
+    fn zip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, _) = self.split_i32x16(a);
+        let (b0, _) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a0, b0), self.zip_high_i32x8(a0, b0))
     }
     #[inline(always)]
-    fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.add_u8x16(a0, b0), self.add_u8x16(a1, b1))
+    fn zip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (_, a1) = self.split_i32x16(a);
+        let (_, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a1, b1), self.zip_high_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.sub_u8x16(a0, b0), self.sub_u8x16(a1, b1))
+    fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
     }
     #[inline(always)]
-    fn mul_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.mul_u8x16(a0, b0), self.mul_u8x16(a1, b1))
+    fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.min_i32x8(a0, b0), self.min_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.and_u8x16(a0, b0), self.and_u8x16(a1, b1))
+    fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.or_u8x16(a0, b0), self.or_u8x16(a1, b1))
+    fn reduce_add_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_add_i32x8(self.add_i32x8(a0, a1))
     }
     #[inline(always)]
-    fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.xor_u8x16(a0, b0), self.xor_u8x16(a1, b1))
+    fn reduce_min_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_min_i32x8(self.min_i32x8(a0, a1))
     }
     #[inline(always)]
-    fn shr_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        self.combine_u8x16(self.shr_u8x16(a0, b), self.shr_u8x16(a1, b))
+    fn reduce_max_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_max_i32x8(self.max_i32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_mask8x16(self.simd_eq_u8x16(a0, b0), self.simd_eq_u8x16(a1, b1))
+    fn reduce_and_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_and_i32x8(self.and_i32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_mask8x16(self.simd_lt_u8x16(a0, b0), self.simd_lt_u8x16(a1, b1))
+    fn reduce_or_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_or_i32x8(self.or_i32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_mask8x16(self.simd_le_u8x16(a0, b0), self.simd_le_u8x16(a1, b1))
+    fn shuffle_i32x16<I: Indices<16>>(self, a: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_mask8x16(self.simd_ge_u8x16(a0, b0), self.simd_ge_u8x16(a1, b1))
+    fn shuffle2_i32x16<I: Indices<16>>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_mask8x16(self.simd_gt_u8x16(a0, b0), self.simd_gt_u8x16(a1, b1))
+    fn reverse_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, _) = self.split_u8x32(a);
-        let (b0, _) = self.split_u8x32(b);
-        self.combine_u8x16(self.zip_low_u8x16(a0, b0), self.zip_high_u8x16(a0, b0))
+    fn rotate_lanes_left_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (_, a1) = self.split_u8x32(a);
-        let (_, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.zip_low_u8x16(a1, b1), self.zip_high_u8x16(a1, b1))
+    fn rotate_lanes_right_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        let (c0, c1) = self.split_u8x32(c);
-        self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
+    fn broadcast_lane_i32x16<const L: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
     }
     #[inline(always)]
-    fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.min_u8x16(a0, b0), self.min_u8x16(a1, b1))
+    fn unzip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        let (b0, b1) = self.split_u8x32(b);
-        self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
+    fn unzip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
-        let mut result = [0; 64usize];
-        result[0..32usize].copy_from_slice(&a.val);
-        result[32usize..64usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn abs_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.abs_i32x8(a0), self.abs_i32x8(a1))
     }
     #[inline(always)]
-    fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn neg_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.neg_i32x8(a0), self.neg_i32x8(a1))
     }
     #[inline(always)]
-    fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u8x32(a);
-        self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
+    fn abs_diff_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(self.abs_diff_i32x8(a0, b0), self.abs_diff_i32x8(a1, b1))
     }
     #[inline(always)]
-    fn splat_mask8x32(self, a: i8) -> mask8x32<Self> {
-        let half = self.splat_mask8x16(a);
-        self.combine_mask8x16(half, half)
+    fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        self.combine_mask8x16(self.not_mask8x16(a0), self.not_mask8x16(a1))
+    fn widen_low_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).0)
     }
     #[inline(always)]
-    fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_mask8x32(b);
-        self.combine_mask8x16(self.and_mask8x16(a0, b0), self.and_mask8x16(a1, b1))
+    fn widen_high_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).1)
     }
     #[inline(always)]
-    fn or_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_mask8x32(b);
-        self.combine_mask8x16(self.or_mask8x16(a0, b0), self.or_mask8x16(a1, b1))
+    fn narrow_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(self.narrow_i32x8(a0), self.narrow_i32x8(a1))
     }
     #[inline(always)]
-    fn xor_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_mask8x32(b);
-        self.combine_mask8x16(self.xor_mask8x16(a0, b0), self.xor_mask8x16(a1, b1))
+    fn narrow_saturating_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(
+            self.narrow_saturating_i32x8(a0),
+            self.narrow_saturating_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn select_mask8x32(
-        self,
-        a: mask8x32<Self>,
-        b: mask8x32<Self>,
-        c: mask8x32<Self>,
-    ) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_mask8x32(b);
-        let (c0, c1) = self.split_mask8x32(c);
-        self.combine_mask8x16(
-            self.select_mask8x16(a0, b0, c0),
-            self.select_mask8x16(a1, b1, c1),
+    fn reinterpret_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i32x8(a0),
+            self.reinterpret_f32_i32x8(a1),
         )
     }
     #[inline(always)]
-    fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
-        let (a0, a1) = self.split_mask8x32(a);
-        let (b0, b1) = self.split_mask8x32(b);
-        self.combine_mask8x16(self.simd_eq_mask8x16(a0, b0), self.simd_eq_mask8x16(a1, b1))
+    fn reinterpret_f64_i32x16(self, a: i32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i32x8(a0),
+            self.reinterpret_f64_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self> {
-        let mut result = [0; 64usize];
-        result[0..32usize].copy_from_slice(&a.val);
-        result[32usize..64usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reinterpret_i8_i32x16(self, a: i32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_i32x8(a0), self.reinterpret_i8_i32x8(a1))
     }
     #[inline(always)]
-    fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_i32x8(a0), self.reinterpret_u8_i32x8(a1))
     }
     #[inline(always)]
-    fn splat_i16x16(self, a: i16) -> i16x16<Self> {
-        let half = self.splat_i16x8(a);
-        self.combine_i16x8(half, half)
+    fn reinterpret_i16_i32x16(self, a: i32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i32x8(a0),
+            self.reinterpret_i16_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        self.combine_i16x8(self.not_i16x8(a0), self.not_i16x8(a1))
+    fn reinterpret_u16_i32x16(self, a: i32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i32x8(a0),
+            self.reinterpret_u16_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.add_i16x8(a0, b0), self.add_i16x8(a1, b1))
+    fn reinterpret_u32_i32x16(self, a: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i32x8(a0),
+            self.reinterpret_u32_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.sub_i16x8(a0, b0), self.sub_i16x8(a1, b1))
+    fn reinterpret_i64_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i32x8(a0),
+            self.reinterpret_i64_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn mul_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.mul_i16x8(a0, b0), self.mul_i16x8(a1, b1))
+    fn reinterpret_u64_i32x16(self, a: i32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i32x8(a0),
+            self.reinterpret_u64_i32x8(a1),
+        )
     }
     #[inline(always)]
-    fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.and_i16x8(a0, b0), self.and_i16x8(a1, b1))
+    fn cvt_f16_i32x16(self, a: i32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i32x16(a))
     }
     #[inline(always)]
-    fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.or_i16x8(a0, b0), self.or_i16x8(a1, b1))
+    fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(self.cvt_f32_i32x8(a0), self.cvt_f32_i32x8(a1))
     }
     #[inline(always)]
-    fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.xor_i16x8(a0, b0), self.xor_i16x8(a1, b1))
+    fn splat_u32x16(self, a: u32) -> u32x16<Self> {
+        let half = self.splat_u32x8(a);
+        self.combine_u32x8(half, half)
     }
     #[inline(always)]
-    fn shr_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        self.combine_i16x8(self.shr_i16x8(a0, b), self.shr_i16x8(a1, b))
+    fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.not_u32x8(a0), self.not_u32x8(a1))
     }
     #[inline(always)]
-    fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_mask16x8(self.simd_eq_i16x8(a0, b0), self.simd_eq_i16x8(a1, b1))
+    fn add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.add_u32x8(a0, b0), self.add_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_mask16x8(self.simd_lt_i16x8(a0, b0), self.simd_lt_i16x8(a1, b1))
+    fn sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.sub_u32x8(a0, b0), self.sub_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_mask16x8(self.simd_le_i16x8(a0, b0), self.simd_le_i16x8(a1, b1))
+    fn mul_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.mul_u32x8(a0, b0), self.mul_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_ge_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_mask16x8(self.simd_ge_i16x8(a0, b0), self.simd_ge_i16x8(a1, b1))
+    fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.and_u32x8(a0, b0), self.and_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_gt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_mask16x8(self.simd_gt_i16x8(a0, b0), self.simd_gt_i16x8(a1, b1))
+    fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.or_u32x8(a0, b0), self.or_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn zip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, _) = self.split_i16x16(a);
-        let (b0, _) = self.split_i16x16(b);
-        self.combine_i16x8(self.zip_low_i16x8(a0, b0), self.zip_high_i16x8(a0, b0))
+    fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.xor_u32x8(a0, b0), self.xor_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn zip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (_, a1) = self.split_i16x16(a);
-        let (_, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.zip_low_i16x8(a1, b1), self.zip_high_i16x8(a1, b1))
+    fn shl_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shl_u32x8(a0, b), self.shl_u32x8(a1, b))
     }
     #[inline(always)]
-    fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        let (c0, c1) = self.split_i16x16(c);
-        self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
+    fn shr_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shr_u32x8(a0, b), self.shr_u32x8(a1, b))
     }
     #[inline(always)]
-    fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.min_i16x8(a0, b0), self.min_i16x8(a1, b1))
+    fn shl_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shl_var_u32x8(a0, b0), self.shl_var_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        let (b0, b1) = self.split_i16x16(b);
-        self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
+    fn shr_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shr_var_u32x8(a0, b0), self.shr_var_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_eq_u32x8(a0, b0), self.simd_eq_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>) {
-        let mut b0 = [0; 8usize];
-        let mut b1 = [0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_lt_u32x8(a0, b0), self.simd_lt_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_i16x16(a);
-        self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
+    fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_le_u32x8(a0, b0), self.simd_le_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn splat_u16x16(self, a: u16) -> u16x16<Self> {
-        let half = self.splat_u16x8(a);
-        self.combine_u16x8(half, half)
+    fn simd_ge_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_ge_u32x8(a0, b0), self.simd_ge_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        self.combine_u16x8(self.not_u16x8(a0), self.not_u16x8(a1))
+    fn simd_gt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_gt_u32x8(a0, b0), self.simd_gt_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.add_u16x8(a0, b0), self.add_u16x8(a1, b1))
+    fn zip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, _) = self.split_u32x16(a);
+        let (b0, _) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a0, b0), self.zip_high_u32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (_, a1) = self.split_u32x16(a);
+        let (_, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a1, b1), self.zip_high_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.sub_u16x8(a0, b0), self.sub_u16x8(a1, b1))
+    fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
     }
     #[inline(always)]
-    fn mul_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.mul_u16x8(a0, b0), self.mul_u16x8(a1, b1))
+    fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.min_u32x8(a0, b0), self.min_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.and_u16x8(a0, b0), self.and_u16x8(a1, b1))
+    fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.or_u16x8(a0, b0), self.or_u16x8(a1, b1))
+    fn reduce_add_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_add_u32x8(self.add_u32x8(a0, a1))
     }
     #[inline(always)]
-    fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.xor_u16x8(a0, b0), self.xor_u16x8(a1, b1))
+    fn reduce_min_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_min_u32x8(self.min_u32x8(a0, a1))
     }
     #[inline(always)]
-    fn shr_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        self.combine_u16x8(self.shr_u16x8(a0, b), self.shr_u16x8(a1, b))
+    fn reduce_max_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_max_u32x8(self.max_u32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_mask16x8(self.simd_eq_u16x8(a0, b0), self.simd_eq_u16x8(a1, b1))
+    fn reduce_and_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_and_u32x8(self.and_u32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_mask16x8(self.simd_lt_u16x8(a0, b0), self.simd_lt_u16x8(a1, b1))
+    fn reduce_or_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_or_u32x8(self.or_u32x8(a0, a1))
     }
     #[inline(always)]
-    fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_mask16x8(self.simd_le_u16x8(a0, b0), self.simd_le_u16x8(a1, b1))
+    fn shuffle_u32x16<I: Indices<16>>(self, a: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_mask16x8(self.simd_ge_u16x8(a0, b0), self.simd_ge_u16x8(a1, b1))
+    fn shuffle2_u32x16<I: Indices<16>>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn simd_gt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_mask16x8(self.simd_gt_u16x8(a0, b0), self.simd_gt_u16x8(a1, b1))
+    fn reverse_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn zip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, _) = self.split_u16x16(a);
-        let (b0, _) = self.split_u16x16(b);
-        self.combine_u16x8(self.zip_low_u16x8(a0, b0), self.zip_high_u16x8(a0, b0))
+    fn rotate_lanes_left_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn zip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (_, a1) = self.split_u16x16(a);
-        let (_, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.zip_low_u16x8(a1, b1), self.zip_high_u16x8(a1, b1))
+    fn rotate_lanes_right_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        let (c0, c1) = self.split_u16x16(c);
-        self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
+    fn broadcast_lane_u32x16<const L: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
     }
     #[inline(always)]
-    fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.min_u16x8(a0, b0), self.min_u16x8(a1, b1))
+    fn unzip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        let (b0, b1) = self.split_u16x16(b);
-        self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
+    fn unzip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn abs_diff_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.abs_diff_u32x8(a0, b0), self.abs_diff_u32x8(a1, b1))
     }
     #[inline(always)]
-    fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>) {
+    fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
         let mut b0 = [0; 8usize];
         let mut b1 = [0; 8usize];
         b0.copy_from_slice(&a.val[0..8usize]);
@@ -3183,1747 +18347,1661 @@ impl Simd for Fallback {
         (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+    fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self> {
         [
-            a[0usize] as u8,
-            a[1usize] as u8,
-            a[2usize] as u8,
-            a[3usize] as u8,
-            a[4usize] as u8,
-            a[5usize] as u8,
-            a[6usize] as u8,
-            a[7usize] as u8,
-            a[8usize] as u8,
-            a[9usize] as u8,
-            a[10usize] as u8,
-            a[11usize] as u8,
-            a[12usize] as u8,
-            a[13usize] as u8,
-            a[14usize] as u8,
-            a[15usize] as u8,
+            src[0usize],
+            src[4usize],
+            src[8usize],
+            src[12usize],
+            src[1usize],
+            src[5usize],
+            src[9usize],
+            src[13usize],
+            src[2usize],
+            src[6usize],
+            src[10usize],
+            src[14usize],
+            src[3usize],
+            src[7usize],
+            src[11usize],
+            src[15usize],
         ]
         .simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u16x16(a);
-        self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
+    fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
+        *dest = [
+            a[0usize], a[4usize], a[8usize], a[12usize], a[1usize], a[5usize], a[9usize],
+            a[13usize], a[2usize], a[6usize], a[10usize], a[14usize], a[3usize], a[7usize],
+            a[11usize], a[15usize],
+        ];
     }
     #[inline(always)]
-    fn splat_mask16x16(self, a: i16) -> mask16x16<Self> {
-        let half = self.splat_mask16x8(a);
-        self.combine_mask16x8(half, half)
+    fn widen_low_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).0)
     }
     #[inline(always)]
-    fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        self.combine_mask16x8(self.not_mask16x8(a0), self.not_mask16x8(a1))
+    fn widen_high_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).1)
     }
     #[inline(always)]
-    fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_mask16x16(b);
-        self.combine_mask16x8(self.and_mask16x8(a0, b0), self.and_mask16x8(a1, b1))
+    fn narrow_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(self.narrow_u32x8(a0), self.narrow_u32x8(a1))
     }
     #[inline(always)]
-    fn or_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_mask16x16(b);
-        self.combine_mask16x8(self.or_mask16x8(a0, b0), self.or_mask16x8(a1, b1))
+    fn narrow_saturating_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(
+            self.narrow_saturating_u32x8(a0),
+            self.narrow_saturating_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn xor_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_mask16x16(b);
-        self.combine_mask16x8(self.xor_mask16x8(a0, b0), self.xor_mask16x8(a1, b1))
+    fn reinterpret_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u32x8(a0),
+            self.reinterpret_f32_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn select_mask16x16(
-        self,
-        a: mask16x16<Self>,
-        b: mask16x16<Self>,
-        c: mask16x16<Self>,
-    ) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_mask16x16(b);
-        let (c0, c1) = self.split_mask16x16(c);
-        self.combine_mask16x8(
-            self.select_mask16x8(a0, b0, c0),
-            self.select_mask16x8(a1, b1, c1),
+    fn reinterpret_f64_u32x16(self, a: u32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u32x8(a0),
+            self.reinterpret_f64_u32x8(a1),
         )
     }
     #[inline(always)]
-    fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
-        let (a0, a1) = self.split_mask16x16(a);
-        let (b0, b1) = self.split_mask16x16(b);
-        self.combine_mask16x8(self.simd_eq_mask16x8(a0, b0), self.simd_eq_mask16x8(a1, b1))
+    fn reinterpret_i8_u32x16(self, a: u32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_u32x8(a0), self.reinterpret_i8_u32x8(a1))
     }
     #[inline(always)]
-    fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self> {
-        let mut result = [0; 32usize];
-        result[0..16usize].copy_from_slice(&a.val);
-        result[16usize..32usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_u32x8(a0), self.reinterpret_u8_u32x8(a1))
     }
     #[inline(always)]
-    fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>) {
-        let mut b0 = [0; 8usize];
-        let mut b1 = [0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reinterpret_i16_u32x16(self, a: u32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u32x8(a0),
+            self.reinterpret_i16_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn splat_i32x8(self, a: i32) -> i32x8<Self> {
-        let half = self.splat_i32x4(a);
-        self.combine_i32x4(half, half)
+    fn reinterpret_u16_u32x16(self, a: u32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u32x8(a0),
+            self.reinterpret_u16_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        self.combine_i32x4(self.not_i32x4(a0), self.not_i32x4(a1))
+    fn reinterpret_i32_u32x16(self, a: u32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u32x8(a0),
+            self.reinterpret_i32_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.add_i32x4(a0, b0), self.add_i32x4(a1, b1))
+    fn reinterpret_i64_u32x16(self, a: u32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u32x8(a0),
+            self.reinterpret_i64_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.sub_i32x4(a0, b0), self.sub_i32x4(a1, b1))
+    fn reinterpret_u64_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u32x8(a0),
+            self.reinterpret_u64_u32x8(a1),
+        )
     }
     #[inline(always)]
-    fn mul_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.mul_i32x4(a0, b0), self.mul_i32x4(a1, b1))
+    fn cvt_f16_u32x16(self, a: u32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u32x16(a))
     }
     #[inline(always)]
-    fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.and_i32x4(a0, b0), self.and_i32x4(a1, b1))
+    fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(self.cvt_f32_u32x8(a0), self.cvt_f32_u32x8(a1))
     }
     #[inline(always)]
-    fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.or_i32x4(a0, b0), self.or_i32x4(a1, b1))
+    fn splat_mask32x16(self, a: i32) -> mask32x16<Self> {
+        let half = self.splat_mask32x8(a);
+        self.combine_mask32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.not_mask32x8(a0), self.not_mask32x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.and_mask32x8(a0, b0), self.and_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.or_mask32x8(a0, b0), self.or_mask32x8(a1, b1))
     }
     #[inline(always)]
-    fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.xor_i32x4(a0, b0), self.xor_i32x4(a1, b1))
+    fn xor_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.xor_mask32x8(a0, b0), self.xor_mask32x8(a1, b1))
     }
     #[inline(always)]
-    fn shr_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        self.combine_i32x4(self.shr_i32x4(a0, b), self.shr_i32x4(a1, b))
+    fn select_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_mask32x8(a0, b0, c0),
+            self.select_mask32x8(a1, b1, c1),
+        )
     }
     #[inline(always)]
-    fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_mask32x4(self.simd_eq_i32x4(a0, b0), self.simd_eq_i32x4(a1, b1))
+    fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.simd_eq_mask32x8(a0, b0), self.simd_eq_mask32x8(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_mask32x4(self.simd_lt_i32x4(a0, b0), self.simd_lt_i32x4(a1, b1))
+    fn any_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.any_mask32x8(a0) || self.any_mask32x8(a1)
     }
     #[inline(always)]
-    fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_mask32x4(self.simd_le_i32x4(a0, b0), self.simd_le_i32x4(a1, b1))
+    fn all_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.all_mask32x8(a0) && self.all_mask32x8(a1)
     }
     #[inline(always)]
-    fn simd_ge_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_mask32x4(self.simd_ge_i32x4(a0, b0), self.simd_ge_i32x4(a1, b1))
+    fn none_mask32x16(self, a: mask32x16<Self>) -> bool {
+        !self.any_mask32x16(a)
     }
     #[inline(always)]
-    fn simd_gt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_mask32x4(self.simd_gt_i32x4(a0, b0), self.simd_gt_i32x4(a1, b1))
+    fn count_true_mask32x16(self, a: mask32x16<Self>) -> usize {
+        self.to_bitmask_mask32x16(a).count_ones() as usize
     }
     #[inline(always)]
-    fn zip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, _) = self.split_i32x8(a);
-        let (b0, _) = self.split_i32x8(b);
-        self.combine_i32x4(self.zip_low_i32x4(a0, b0), self.zip_high_i32x4(a0, b0))
+    fn first_true_mask32x16(self, a: mask32x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
     }
     #[inline(always)]
-    fn zip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (_, a1) = self.split_i32x8(a);
-        let (_, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.zip_low_i32x4(a1, b1), self.zip_high_i32x4(a1, b1))
+    fn to_bitmask_mask32x16(self, a: mask32x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.to_bitmask_mask32x8(a0) | (self.to_bitmask_mask32x8(a1) << 8)
     }
     #[inline(always)]
-    fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        let (c0, c1) = self.split_i32x8(c);
-        self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
+    fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
+        self.combine_mask32x8(
+            self.from_bitmask_mask32x8(bits),
+            self.from_bitmask_mask32x8(bits >> 8),
+        )
     }
     #[inline(always)]
-    fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.min_i32x4(a0, b0), self.min_i32x4(a1, b1))
+    fn shuffle_mask32x16<I: Indices<16>>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        let (b0, b1) = self.split_i32x8(b);
-        self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
+    fn shuffle2_mask32x16<I: Indices<16>>(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn reverse_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[16 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>) {
-        let mut b0 = [0; 4usize];
-        let mut b1 = [0; 4usize];
-        b0.copy_from_slice(&a.val[0..4usize]);
-        b1.copy_from_slice(&a.val[4usize..8usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn rotate_lanes_left_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + K) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
+    fn rotate_lanes_right_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| a[(i + 16 - K % 16) % 16]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_i32x8(a);
-        self.combine_f32x4(self.cvt_f32_i32x4(a0), self.cvt_f32_i32x4(a1))
+    fn broadcast_lane_mask32x16<const L: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        [a[L % 16]; 16].simd_into(self)
     }
     #[inline(always)]
-    fn splat_u32x8(self, a: u32) -> u32x8<Self> {
-        let half = self.splat_u32x4(a);
-        self.combine_u32x4(half, half)
+    fn unzip_low_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let lanes: [_; 16] =
+            core::array::from_fn(|i| if i < 8 { a[2 * i] } else { b[2 * (i - 8)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        self.combine_u32x4(self.not_u32x4(a0), self.not_u32x4(a1))
+    fn unzip_high_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            if i < 8 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 8) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.add_u32x4(a0, b0), self.add_u32x4(a1, b1))
+    fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.sub_u32x4(a0, b0), self.sub_u32x4(a1, b1))
+    fn reinterpret_i8_mask32x16(self, a: mask32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask32x8(a0),
+            self.reinterpret_i8_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn mul_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.mul_u32x4(a0, b0), self.mul_u32x4(a1, b1))
+    fn reinterpret_u8_mask32x16(self, a: mask32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask32x8(a0),
+            self.reinterpret_u8_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.and_u32x4(a0, b0), self.and_u32x4(a1, b1))
+    fn reinterpret_i16_mask32x16(self, a: mask32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask32x8(a0),
+            self.reinterpret_i16_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.or_u32x4(a0, b0), self.or_u32x4(a1, b1))
+    fn reinterpret_u16_mask32x16(self, a: mask32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask32x8(a0),
+            self.reinterpret_u16_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.xor_u32x4(a0, b0), self.xor_u32x4(a1, b1))
+    fn reinterpret_i32_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask32x8(a0),
+            self.reinterpret_i32_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn shr_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        self.combine_u32x4(self.shr_u32x4(a0, b), self.shr_u32x4(a1, b))
+    fn reinterpret_u32_mask32x16(self, a: mask32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask32x8(a0),
+            self.reinterpret_u32_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_mask32x4(self.simd_eq_u32x4(a0, b0), self.simd_eq_u32x4(a1, b1))
+    fn reinterpret_i64_mask32x16(self, a: mask32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask32x8(a0),
+            self.reinterpret_i64_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_mask32x4(self.simd_lt_u32x4(a0, b0), self.simd_lt_u32x4(a1, b1))
+    fn reinterpret_u64_mask32x16(self, a: mask32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask32x8(a0),
+            self.reinterpret_u64_mask32x8(a1),
+        )
     }
     #[inline(always)]
-    fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_mask32x4(self.simd_le_u32x4(a0, b0), self.simd_le_u32x4(a1, b1))
+    fn splat_f64x8(self, a: f64) -> f64x8<Self> {
+        let half = self.splat_f64x4(a);
+        self.combine_f64x4(half, half)
     }
     #[inline(always)]
-    fn simd_ge_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_mask32x4(self.simd_ge_u32x4(a0, b0), self.simd_ge_u32x4(a1, b1))
+    fn abs_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.abs_f64x4(a0), self.abs_f64x4(a1))
     }
     #[inline(always)]
-    fn simd_gt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_mask32x4(self.simd_gt_u32x4(a0, b0), self.simd_gt_u32x4(a1, b1))
+    fn neg_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.neg_f64x4(a0), self.neg_f64x4(a1))
     }
     #[inline(always)]
-    fn zip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, _) = self.split_u32x8(a);
-        let (b0, _) = self.split_u32x8(b);
-        self.combine_u32x4(self.zip_low_u32x4(a0, b0), self.zip_high_u32x4(a0, b0))
+    fn sqrt_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.sqrt_f64x4(a0), self.sqrt_f64x4(a1))
     }
     #[inline(always)]
-    fn zip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (_, a1) = self.split_u32x8(a);
-        let (_, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.zip_low_u32x4(a1, b1), self.zip_high_u32x4(a1, b1))
+    fn recip_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_approx_f64x4(a0), self.recip_approx_f64x4(a1))
     }
     #[inline(always)]
-    fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        let (c0, c1) = self.split_u32x8(c);
-        self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
+    fn rsqrt_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_approx_f64x4(a0), self.rsqrt_approx_f64x4(a1))
     }
     #[inline(always)]
-    fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.min_u32x4(a0, b0), self.min_u32x4(a1, b1))
+    fn recip_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_f64x4(a0, steps), self.recip_f64x4(a1, steps))
     }
     #[inline(always)]
-    fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        let (b0, b1) = self.split_u32x8(b);
-        self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
+    fn rsqrt_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_f64x4(a0, steps), self.rsqrt_f64x4(a1, steps))
     }
     #[inline(always)]
-    fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.add_f64x4(a0, b0), self.add_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>) {
-        let mut b0 = [0; 4usize];
-        let mut b1 = [0; 4usize];
-        b0.copy_from_slice(&a.val[0..4usize]);
-        b1.copy_from_slice(&a.val[4usize..8usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.sub_f64x4(a0, b0), self.sub_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
+    fn mul_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.mul_f64x4(a0, b0), self.mul_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
-        let (a0, a1) = self.split_u32x8(a);
-        self.combine_f32x4(self.cvt_f32_u32x4(a0), self.cvt_f32_u32x4(a1))
+    fn div_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.div_f64x4(a0, b0), self.div_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn splat_mask32x8(self, a: i32) -> mask32x8<Self> {
-        let half = self.splat_mask32x4(a);
-        self.combine_mask32x4(half, half)
+    fn copysign_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.copysign_f64x4(a0, b0), self.copysign_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        self.combine_mask32x4(self.not_mask32x4(a0), self.not_mask32x4(a1))
+    fn simd_eq_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_eq_f64x4(a0, b0), self.simd_eq_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_mask32x8(b);
-        self.combine_mask32x4(self.and_mask32x4(a0, b0), self.and_mask32x4(a1, b1))
+    fn simd_ne_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ne_f64x4(a0, b0), self.simd_ne_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn or_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_mask32x8(b);
-        self.combine_mask32x4(self.or_mask32x4(a0, b0), self.or_mask32x4(a1, b1))
+    fn simd_lt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_lt_f64x4(a0, b0), self.simd_lt_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn xor_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_mask32x8(b);
-        self.combine_mask32x4(self.xor_mask32x4(a0, b0), self.xor_mask32x4(a1, b1))
+    fn simd_le_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_le_f64x4(a0, b0), self.simd_le_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn select_mask32x8(
-        self,
-        a: mask32x8<Self>,
-        b: mask32x8<Self>,
-        c: mask32x8<Self>,
-    ) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_mask32x8(b);
-        let (c0, c1) = self.split_mask32x8(c);
-        self.combine_mask32x4(
-            self.select_mask32x4(a0, b0, c0),
-            self.select_mask32x4(a1, b1, c1),
-        )
+    fn simd_ge_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ge_f64x4(a0, b0), self.simd_ge_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
-        let (a0, a1) = self.split_mask32x8(a);
-        let (b0, b1) = self.split_mask32x8(b);
-        self.combine_mask32x4(self.simd_eq_mask32x4(a0, b0), self.simd_eq_mask32x4(a1, b1))
+    fn simd_gt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_gt_f64x4(a0, b0), self.simd_gt_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self> {
-        let mut result = [0; 16usize];
-        result[0..8usize].copy_from_slice(&a.val);
-        result[8usize..16usize].copy_from_slice(&b.val);
-        result.simd_into(self)
+    fn is_nan_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_ne_f64x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
     }
     #[inline(always)]
-    fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>) {
-        let mut b0 = [0; 4usize];
-        let mut b1 = [0; 4usize];
-        b0.copy_from_slice(&a.val[0..4usize]);
-        b1.copy_from_slice(&a.val[4usize..8usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn is_infinite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_eq_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
     }
     #[inline(always)]
-    fn splat_f32x16(self, a: f32) -> f32x16<Self> {
-        let half = self.splat_f32x8(a);
-        self.combine_f32x8(half, half)
+    fn is_subnormal_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        let abs = self.abs_f64x8(a);
+        self.and_mask64x8(
+            self.simd_lt_f64x8(abs, self.splat_f64x8(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x8(abs, self.splat_f64x8(0.0)),
+        )
     }
     #[inline(always)]
-    fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.abs_f32x8(a0), self.abs_f32x8(a1))
+    fn is_sign_negative_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(
+            self.copysign_f64x8(self.splat_f64x8(1.0), a),
+            self.splat_f64x8(0.0),
+        )
     }
     #[inline(always)]
-    fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.neg_f32x8(a0), self.neg_f32x8(a1))
+    fn zip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, _) = self.split_f64x8(a);
+        let (b0, _) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a0, b0), self.zip_high_f64x4(a0, b0))
     }
     #[inline(always)]
-    fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.sqrt_f32x8(a0), self.sqrt_f32x8(a1))
+    fn zip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (_, a1) = self.split_f64x8(a);
+        let (_, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a1, b1), self.zip_high_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.add_f32x8(a0, b0), self.add_f32x8(a1, b1))
+    fn max_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.max_f64x4(a0, b0), self.max_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.sub_f32x8(a0, b0), self.sub_f32x8(a1, b1))
+    fn max_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.max_precise_f64x4(a0, b0),
+            self.max_precise_f64x4(a1, b1),
+        )
     }
     #[inline(always)]
-    fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.mul_f32x8(a0, b0), self.mul_f32x8(a1, b1))
+    fn min_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.min_f64x4(a0, b0), self.min_f64x4(a1, b1))
     }
     #[inline(always)]
-    fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.div_f32x8(a0, b0), self.div_f32x8(a1, b1))
+    fn min_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.min_precise_f64x4(a0, b0),
+            self.min_precise_f64x4(a1, b1),
+        )
     }
     #[inline(always)]
-    fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.copysign_f32x8(a0, b0), self.copysign_f32x8(a1, b1))
+    fn madd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.madd_f64x4(a0, b0, c0), self.madd_f64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_mask32x8(self.simd_eq_f32x8(a0, b0), self.simd_eq_f32x8(a1, b1))
+    fn msub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.msub_f64x4(a0, b0, c0), self.msub_f64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_mask32x8(self.simd_lt_f32x8(a0, b0), self.simd_lt_f32x8(a1, b1))
+    fn nmadd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmadd_f64x4(a0, b0, c0), self.nmadd_f64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_mask32x8(self.simd_le_f32x8(a0, b0), self.simd_le_f32x8(a1, b1))
+    fn nmsub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmsub_f64x4(a0, b0, c0), self.nmsub_f64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_mask32x8(self.simd_ge_f32x8(a0, b0), self.simd_ge_f32x8(a1, b1))
+    fn mul_add_fused_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fused_f64x4(a0, b0, c0),
+            self.mul_add_fused_f64x4(a1, b1, c1),
+        )
     }
     #[inline(always)]
-    fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_mask32x8(self.simd_gt_f32x8(a0, b0), self.simd_gt_f32x8(a1, b1))
+    fn mul_add_fast_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fast_f64x4(a0, b0, c0),
+            self.mul_add_fast_f64x4(a1, b1, c1),
+        )
     }
     #[inline(always)]
-    fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, _) = self.split_f32x16(a);
-        let (b0, _) = self.split_f32x16(b);
-        self.combine_f32x8(self.zip_low_f32x8(a0, b0), self.zip_high_f32x8(a0, b0))
+    fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
     }
     #[inline(always)]
-    fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (_, a1) = self.split_f32x16(a);
-        let (_, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.zip_low_f32x8(a1, b1), self.zip_high_f32x8(a1, b1))
+    fn ceil_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.ceil_f64x4(a0), self.ceil_f64x4(a1))
     }
     #[inline(always)]
-    fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.max_f32x8(a0, b0), self.max_f32x8(a1, b1))
+    fn round_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.round_f64x4(a0), self.round_f64x4(a1))
     }
     #[inline(always)]
-    fn max_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(
-            self.max_precise_f32x8(a0, b0),
-            self.max_precise_f32x8(a1, b1),
+    fn round_ties_even_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(
+            self.round_ties_even_f64x4(a0),
+            self.round_ties_even_f64x4(a1),
         )
     }
     #[inline(always)]
-    fn min_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(self.min_f32x8(a0, b0), self.min_f32x8(a1, b1))
+    fn fract_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.fract_f64x4(a0), self.fract_f64x4(a1))
     }
     #[inline(always)]
-    fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        self.combine_f32x8(
-            self.min_precise_f32x8(a0, b0),
-            self.min_precise_f32x8(a1, b1),
-        )
+    fn trunc_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.trunc_f64x4(a0), self.trunc_f64x4(a1))
     }
     #[inline(always)]
-    fn madd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        let (c0, c1) = self.split_f32x16(c);
-        self.combine_f32x8(self.madd_f32x8(a0, b0, c0), self.madd_f32x8(a1, b1, c1))
+    fn select_f64x8(self, a: mask64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn msub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        let (c0, c1) = self.split_f32x16(c);
-        self.combine_f32x8(self.msub_f32x8(a0, b0, c0), self.msub_f32x8(a1, b1, c1))
+    fn reduce_add_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        self.reduce_add_f64x4(a0) + self.reduce_add_f64x4(a1)
     }
     #[inline(always)]
-    fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
+    fn reduce_min_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_min_f64x4(self.min_precise_f64x4(a0, a1))
     }
     #[inline(always)]
-    fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.fract_f32x8(a0), self.fract_f32x8(a1))
+    fn reduce_max_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_max_f64x4(self.max_precise_f64x4(a0, a1))
     }
     #[inline(always)]
-    fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_f32x8(self.trunc_f32x8(a0), self.trunc_f32x8(a1))
+    fn shuffle_f64x8<I: Indices<8>>(self, a: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_f32x16(b);
-        let (c0, c1) = self.split_f32x16(c);
-        self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
+    fn shuffle2_f64x8<I: Indices<8>>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
-        let mut b0 = [0.0; 8usize];
-        let mut b1 = [0.0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reverse_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self> {
-        [
-            src[0usize],
-            src[4usize],
-            src[8usize],
-            src[12usize],
-            src[1usize],
-            src[5usize],
-            src[9usize],
-            src[13usize],
-            src[2usize],
-            src[6usize],
-            src[10usize],
-            src[14usize],
-            src[3usize],
-            src[7usize],
-            src[11usize],
-            src[15usize],
-        ]
-        .simd_into(self)
+    fn rotate_lanes_left_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
-        *dest = [
-            a[0usize], a[4usize], a[8usize], a[12usize], a[1usize], a[5usize], a[9usize],
-            a[13usize], a[2usize], a[6usize], a[10usize], a[14usize], a[3usize], a[7usize],
-            a[11usize], a[15usize],
-        ];
+    fn rotate_lanes_right_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_u32x8(self.cvt_u32_f32x8(a0), self.cvt_u32_f32x8(a1))
+    fn broadcast_lane_f64x8<const L: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
     }
     #[inline(always)]
-    fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_f32x16(a);
-        self.combine_i32x8(self.cvt_i32_f32x8(a0), self.cvt_i32_f32x8(a1))
+    fn unzip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn splat_i8x64(self, a: i8) -> i8x64<Self> {
-        let half = self.splat_i8x32(a);
-        self.combine_i8x32(half, half)
+    fn unzip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        self.combine_i8x32(self.not_i8x32(a0), self.not_i8x32(a1))
+    fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.add_i8x32(a0, b0), self.add_i8x32(a1, b1))
+    fn reinterpret_f32_f64x8(self, a: f64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_f64x4(a0),
+            self.reinterpret_f32_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.sub_i8x32(a0, b0), self.sub_i8x32(a1, b1))
+    fn reinterpret_i8_f64x8(self, a: f64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_f64x4(a0), self.reinterpret_i8_f64x4(a1))
     }
     #[inline(always)]
-    fn mul_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.mul_i8x32(a0, b0), self.mul_i8x32(a1, b1))
+    fn reinterpret_u8_f64x8(self, a: f64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_f64x4(a0), self.reinterpret_u8_f64x4(a1))
     }
     #[inline(always)]
-    fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.and_i8x32(a0, b0), self.and_i8x32(a1, b1))
+    fn reinterpret_i16_f64x8(self, a: f64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f64x4(a0),
+            self.reinterpret_i16_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.or_i8x32(a0, b0), self.or_i8x32(a1, b1))
+    fn reinterpret_u16_f64x8(self, a: f64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f64x4(a0),
+            self.reinterpret_u16_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.xor_i8x32(a0, b0), self.xor_i8x32(a1, b1))
+    fn reinterpret_i32_f64x8(self, a: f64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f64x4(a0),
+            self.reinterpret_i32_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn shr_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        self.combine_i8x32(self.shr_i8x32(a0, b), self.shr_i8x32(a1, b))
+    fn reinterpret_u32_f64x8(self, a: f64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f64x4(a0),
+            self.reinterpret_u32_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_mask8x32(self.simd_eq_i8x32(a0, b0), self.simd_eq_i8x32(a1, b1))
+    fn reinterpret_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f64x4(a0),
+            self.reinterpret_i64_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_mask8x32(self.simd_lt_i8x32(a0, b0), self.simd_lt_i8x32(a1, b1))
+    fn reinterpret_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f64x4(a0),
+            self.reinterpret_u64_f64x4(a1),
+        )
     }
     #[inline(always)]
-    fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_mask8x32(self.simd_le_i8x32(a0, b0), self.simd_le_i8x32(a1, b1))
+    fn cvt_f16_f64x8(self, a: f64x8<Self>) -> f16x8<Self> {
+        a.val.map(f16::from_f64_const).simd_into(self)
     }
     #[inline(always)]
-    fn simd_ge_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_mask8x32(self.simd_ge_i8x32(a0, b0), self.simd_ge_i8x32(a1, b1))
+    fn cvt_f32_f64x8(self, a: f64x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x4(self.cvt_f32_f64x4(a0), self.cvt_f32_f64x4(a1))
     }
     #[inline(always)]
-    fn simd_gt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_mask8x32(self.simd_gt_i8x32(a0, b0), self.simd_gt_i8x32(a1, b1))
+    fn cvt_u8_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f64x8(a))
     }
     #[inline(always)]
-    fn zip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, _) = self.split_i8x64(a);
-        let (b0, _) = self.split_i8x64(b);
-        self.combine_i8x32(self.zip_low_i8x32(a0, b0), self.zip_high_i8x32(a0, b0))
+    fn cvt_u8_round_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f64x8(a))
     }
     #[inline(always)]
-    fn zip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (_, a1) = self.split_i8x64(a);
-        let (_, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.zip_low_i8x32(a1, b1), self.zip_high_i8x32(a1, b1))
+    fn cvt_i16_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f64x8(a))
     }
     #[inline(always)]
-    fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        let (c0, c1) = self.split_i8x64(c);
-        self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
+    fn cvt_i16_round_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f64x8(a))
     }
     #[inline(always)]
-    fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.min_i8x32(a0, b0), self.min_i8x32(a1, b1))
+    fn cvt_u16_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f64x8(a))
     }
     #[inline(always)]
-    fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        let (b0, b1) = self.split_i8x64(b);
-        self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
+    fn cvt_u16_round_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f64x8(a))
     }
     #[inline(always)]
-    fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
-        let mut b0 = [0; 32usize];
-        let mut b1 = [0; 32usize];
-        b0.copy_from_slice(&a.val[0..32usize]);
-        b1.copy_from_slice(&a.val[32usize..64usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn cvt_i32_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_round_f64x8(a))
     }
     #[inline(always)]
-    fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_i8x64(a);
-        self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
+    fn cvt_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_f64x4(a0), self.cvt_i64_f64x4(a1))
     }
     #[inline(always)]
-    fn splat_u8x64(self, a: u8) -> u8x64<Self> {
-        let half = self.splat_u8x32(a);
-        self.combine_u8x32(half, half)
+    fn cvt_i64_round_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_round_f64x4(a0), self.cvt_i64_round_f64x4(a1))
     }
     #[inline(always)]
-    fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        self.combine_u8x32(self.not_u8x32(a0), self.not_u8x32(a1))
+    fn cvt_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_f64x4(a0), self.cvt_u64_f64x4(a1))
     }
     #[inline(always)]
-    fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.add_u8x32(a0, b0), self.add_u8x32(a1, b1))
+    fn cvt_u64_round_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_round_f64x4(a0), self.cvt_u64_round_f64x4(a1))
     }
     #[inline(always)]
-    fn sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.sub_u8x32(a0, b0), self.sub_u8x32(a1, b1))
+    fn splat_i64x8(self, a: i64) -> i64x8<Self> {
+        let half = self.splat_i64x4(a);
+        self.combine_i64x4(half, half)
     }
     #[inline(always)]
-    fn mul_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.mul_u8x32(a0, b0), self.mul_u8x32(a1, b1))
+    fn not_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.not_i64x4(a0), self.not_i64x4(a1))
     }
     #[inline(always)]
-    fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.and_u8x32(a0, b0), self.and_u8x32(a1, b1))
+    fn add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.add_i64x4(a0, b0), self.add_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.or_u8x32(a0, b0), self.or_u8x32(a1, b1))
+    fn sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.sub_i64x4(a0, b0), self.sub_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.xor_u8x32(a0, b0), self.xor_u8x32(a1, b1))
+    fn mul_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.mul_i64x4(a0, b0), self.mul_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn shr_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        self.combine_u8x32(self.shr_u8x32(a0, b), self.shr_u8x32(a1, b))
+    fn and_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.and_i64x4(a0, b0), self.and_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_mask8x32(self.simd_eq_u8x32(a0, b0), self.simd_eq_u8x32(a1, b1))
+    fn or_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.or_i64x4(a0, b0), self.or_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_mask8x32(self.simd_lt_u8x32(a0, b0), self.simd_lt_u8x32(a1, b1))
+    fn xor_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.xor_i64x4(a0, b0), self.xor_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_mask8x32(self.simd_le_u8x32(a0, b0), self.simd_le_u8x32(a1, b1))
+    fn shl_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shl_i64x4(a0, b), self.shl_i64x4(a1, b))
     }
     #[inline(always)]
-    fn simd_ge_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_mask8x32(self.simd_ge_u8x32(a0, b0), self.simd_ge_u8x32(a1, b1))
+    fn shr_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shr_i64x4(a0, b), self.shr_i64x4(a1, b))
     }
     #[inline(always)]
-    fn simd_gt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_mask8x32(self.simd_gt_u8x32(a0, b0), self.simd_gt_u8x32(a1, b1))
+    fn shl_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shl_var_i64x4(a0, b0), self.shl_var_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn zip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, _) = self.split_u8x64(a);
-        let (b0, _) = self.split_u8x64(b);
-        self.combine_u8x32(self.zip_low_u8x32(a0, b0), self.zip_high_u8x32(a0, b0))
+    fn shr_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shr_var_i64x4(a0, b0), self.shr_var_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn zip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (_, a1) = self.split_u8x64(a);
-        let (_, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.zip_low_u8x32(a1, b1), self.zip_high_u8x32(a1, b1))
+    fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_eq_i64x4(a0, b0), self.simd_eq_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        let (c0, c1) = self.split_u8x64(c);
-        self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
+    fn simd_lt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_lt_i64x4(a0, b0), self.simd_lt_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.min_u8x32(a0, b0), self.min_u8x32(a1, b1))
+    fn simd_le_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_le_i64x4(a0, b0), self.simd_le_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u8x64(a);
-        let (b0, b1) = self.split_u8x64(b);
-        self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
+    fn simd_ge_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_ge_i64x4(a0, b0), self.simd_ge_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
-        let mut b0 = [0; 32usize];
-        let mut b1 = [0; 32usize];
-        b0.copy_from_slice(&a.val[0..32usize]);
-        b1.copy_from_slice(&a.val[32usize..64usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn simd_gt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_gt_i64x4(a0, b0), self.simd_gt_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self> {
-        [
-            src[0usize],
-            src[16usize],
-            src[32usize],
-            src[48usize],
-            src[1usize],
-            src[17usize],
-            src[33usize],
-            src[49usize],
-            src[2usize],
-            src[18usize],
-            src[34usize],
-            src[50usize],
-            src[3usize],
-            src[19usize],
-            src[35usize],
-            src[51usize],
-            src[4usize],
-            src[20usize],
-            src[36usize],
-            src[52usize],
-            src[5usize],
-            src[21usize],
-            src[37usize],
-            src[53usize],
-            src[6usize],
-            src[22usize],
-            src[38usize],
-            src[54usize],
-            src[7usize],
-            src[23usize],
-            src[39usize],
-            src[55usize],
-            src[8usize],
-            src[24usize],
-            src[40usize],
-            src[56usize],
-            src[9usize],
-            src[25usize],
-            src[41usize],
-            src[57usize],
-            src[10usize],
-            src[26usize],
-            src[42usize],
-            src[58usize],
-            src[11usize],
-            src[27usize],
-            src[43usize],
-            src[59usize],
-            src[12usize],
-            src[28usize],
-            src[44usize],
-            src[60usize],
-            src[13usize],
-            src[29usize],
-            src[45usize],
-            src[61usize],
-            src[14usize],
-            src[30usize],
-            src[46usize],
-            src[62usize],
-            src[15usize],
-            src[31usize],
-            src[47usize],
-            src[63usize],
-        ]
-        .simd_into(self)
+    fn zip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, _) = self.split_i64x8(a);
+        let (b0, _) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a0, b0), self.zip_high_i64x4(a0, b0))
     }
     #[inline(always)]
-    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
-        *dest = [
-            a[0usize], a[16usize], a[32usize], a[48usize], a[1usize], a[17usize], a[33usize],
-            a[49usize], a[2usize], a[18usize], a[34usize], a[50usize], a[3usize], a[19usize],
-            a[35usize], a[51usize], a[4usize], a[20usize], a[36usize], a[52usize], a[5usize],
-            a[21usize], a[37usize], a[53usize], a[6usize], a[22usize], a[38usize], a[54usize],
-            a[7usize], a[23usize], a[39usize], a[55usize], a[8usize], a[24usize], a[40usize],
-            a[56usize], a[9usize], a[25usize], a[41usize], a[57usize], a[10usize], a[26usize],
-            a[42usize], a[58usize], a[11usize], a[27usize], a[43usize], a[59usize], a[12usize],
-            a[28usize], a[44usize], a[60usize], a[13usize], a[29usize], a[45usize], a[61usize],
-            a[14usize], a[30usize], a[46usize], a[62usize], a[15usize], a[31usize], a[47usize],
-            a[63usize],
-        ];
+    fn zip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (_, a1) = self.split_i64x8(a);
+        let (_, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a1, b1), self.zip_high_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn splat_mask8x64(self, a: i8) -> mask8x64<Self> {
-        let half = self.splat_mask8x32(a);
-        self.combine_mask8x32(half, half)
+    fn select_i64x8(self, a: mask64x8<Self>, b: i64x8<Self>, c: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        self.combine_mask8x32(self.not_mask8x32(a0), self.not_mask8x32(a1))
+    fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.min_i64x4(a0, b0), self.min_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_mask8x64(b);
-        self.combine_mask8x32(self.and_mask8x32(a0, b0), self.and_mask8x32(a1, b1))
+    fn max_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn or_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_mask8x64(b);
-        self.combine_mask8x32(self.or_mask8x32(a0, b0), self.or_mask8x32(a1, b1))
+    fn reduce_add_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_add_i64x4(self.add_i64x4(a0, a1))
     }
     #[inline(always)]
-    fn xor_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_mask8x64(b);
-        self.combine_mask8x32(self.xor_mask8x32(a0, b0), self.xor_mask8x32(a1, b1))
+    fn reduce_min_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_min_i64x4(self.min_i64x4(a0, a1))
     }
     #[inline(always)]
-    fn select_mask8x64(
-        self,
-        a: mask8x64<Self>,
-        b: mask8x64<Self>,
-        c: mask8x64<Self>,
-    ) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_mask8x64(b);
-        let (c0, c1) = self.split_mask8x64(c);
-        self.combine_mask8x32(
-            self.select_mask8x32(a0, b0, c0),
-            self.select_mask8x32(a1, b1, c1),
-        )
+    fn reduce_max_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_max_i64x4(self.max_i64x4(a0, a1))
     }
     #[inline(always)]
-    fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
-        let (a0, a1) = self.split_mask8x64(a);
-        let (b0, b1) = self.split_mask8x64(b);
-        self.combine_mask8x32(self.simd_eq_mask8x32(a0, b0), self.simd_eq_mask8x32(a1, b1))
+    fn reduce_and_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_and_i64x4(self.and_i64x4(a0, a1))
     }
     #[inline(always)]
-    fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>) {
-        let mut b0 = [0; 32usize];
-        let mut b1 = [0; 32usize];
-        b0.copy_from_slice(&a.val[0..32usize]);
-        b1.copy_from_slice(&a.val[32usize..64usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reduce_or_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_or_i64x4(self.or_i64x4(a0, a1))
     }
     #[inline(always)]
-    fn splat_i16x32(self, a: i16) -> i16x32<Self> {
-        let half = self.splat_i16x16(a);
-        self.combine_i16x16(half, half)
+    fn shuffle_i64x8<I: Indices<8>>(self, a: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        self.combine_i16x16(self.not_i16x16(a0), self.not_i16x16(a1))
+    fn shuffle2_i64x8<I: Indices<8>>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.add_i16x16(a0, b0), self.add_i16x16(a1, b1))
+    fn reverse_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.sub_i16x16(a0, b0), self.sub_i16x16(a1, b1))
+    fn rotate_lanes_left_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn mul_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.mul_i16x16(a0, b0), self.mul_i16x16(a1, b1))
+    fn rotate_lanes_right_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.and_i16x16(a0, b0), self.and_i16x16(a1, b1))
+    fn broadcast_lane_i64x8<const L: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
     }
     #[inline(always)]
-    fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.or_i16x16(a0, b0), self.or_i16x16(a1, b1))
+    fn unzip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.xor_i16x16(a0, b0), self.xor_i16x16(a1, b1))
+    fn unzip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn shr_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        self.combine_i16x16(self.shr_i16x16(a0, b), self.shr_i16x16(a1, b))
+    fn abs_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.abs_i64x4(a0), self.abs_i64x4(a1))
     }
     #[inline(always)]
-    fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_mask16x16(self.simd_eq_i16x16(a0, b0), self.simd_eq_i16x16(a1, b1))
+    fn neg_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.neg_i64x4(a0), self.neg_i64x4(a1))
     }
     #[inline(always)]
-    fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_mask16x16(self.simd_lt_i16x16(a0, b0), self.simd_lt_i16x16(a1, b1))
+    fn abs_diff_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(self.abs_diff_i64x4(a0, b0), self.abs_diff_i64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_mask16x16(self.simd_le_i16x16(a0, b0), self.simd_le_i16x16(a1, b1))
+    fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn simd_ge_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_mask16x16(self.simd_ge_i16x16(a0, b0), self.simd_ge_i16x16(a1, b1))
+    fn narrow_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(self.narrow_i64x4(a0), self.narrow_i64x4(a1))
     }
     #[inline(always)]
-    fn simd_gt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_mask16x16(self.simd_gt_i16x16(a0, b0), self.simd_gt_i16x16(a1, b1))
+    fn narrow_saturating_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(
+            self.narrow_saturating_i64x4(a0),
+            self.narrow_saturating_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn zip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, _) = self.split_i16x32(a);
-        let (b0, _) = self.split_i16x32(b);
-        self.combine_i16x16(self.zip_low_i16x16(a0, b0), self.zip_high_i16x16(a0, b0))
+    fn reinterpret_f32_i64x8(self, a: i64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i64x4(a0),
+            self.reinterpret_f32_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn zip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (_, a1) = self.split_i16x32(a);
-        let (_, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.zip_low_i16x16(a1, b1), self.zip_high_i16x16(a1, b1))
+    fn reinterpret_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i64x4(a0),
+            self.reinterpret_f64_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        let (c0, c1) = self.split_i16x32(c);
-        self.combine_i16x16(
-            self.select_i16x16(a0, b0, c0),
-            self.select_i16x16(a1, b1, c1),
-        )
+    fn reinterpret_i8_i64x8(self, a: i64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_i64x4(a0), self.reinterpret_i8_i64x4(a1))
     }
     #[inline(always)]
-    fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.min_i16x16(a0, b0), self.min_i16x16(a1, b1))
+    fn reinterpret_u8_i64x8(self, a: i64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_i64x4(a0), self.reinterpret_u8_i64x4(a1))
     }
     #[inline(always)]
-    fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        let (b0, b1) = self.split_i16x32(b);
-        self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
+    fn reinterpret_i16_i64x8(self, a: i64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i64x4(a0),
+            self.reinterpret_i16_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reinterpret_u16_i64x8(self, a: i64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i64x4(a0),
+            self.reinterpret_u16_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_i16x32(a);
-        self.combine_u8x32(
-            self.reinterpret_u8_i16x16(a0),
-            self.reinterpret_u8_i16x16(a1),
+    fn reinterpret_i32_i64x8(self, a: i64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i64x4(a0),
+            self.reinterpret_i32_i64x4(a1),
         )
     }
     #[inline(always)]
-    fn splat_u16x32(self, a: u16) -> u16x32<Self> {
-        let half = self.splat_u16x16(a);
-        self.combine_u16x16(half, half)
+    fn reinterpret_u32_i64x8(self, a: i64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i64x4(a0),
+            self.reinterpret_u32_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        self.combine_u16x16(self.not_u16x16(a0), self.not_u16x16(a1))
+    fn reinterpret_u64_i64x8(self, a: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i64x4(a0),
+            self.reinterpret_u64_i64x4(a1),
+        )
     }
     #[inline(always)]
-    fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.add_u16x16(a0, b0), self.add_u16x16(a1, b1))
+    fn cvt_f16_i64x8(self, a: i64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i64x8(a))
     }
     #[inline(always)]
-    fn sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.sub_u16x16(a0, b0), self.sub_u16x16(a1, b1))
+    fn cvt_f32_i64x8(self, a: i64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
     }
     #[inline(always)]
-    fn mul_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.mul_u16x16(a0, b0), self.mul_u16x16(a1, b1))
+    fn cvt_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(self.cvt_f64_i64x4(a0), self.cvt_f64_i64x4(a1))
     }
     #[inline(always)]
-    fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.and_u16x16(a0, b0), self.and_u16x16(a1, b1))
+    fn splat_u64x8(self, a: u64) -> u64x8<Self> {
+        let half = self.splat_u64x4(a);
+        self.combine_u64x4(half, half)
     }
     #[inline(always)]
-    fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.or_u16x16(a0, b0), self.or_u16x16(a1, b1))
+    fn not_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.not_u64x4(a0), self.not_u64x4(a1))
     }
     #[inline(always)]
-    fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.xor_u16x16(a0, b0), self.xor_u16x16(a1, b1))
+    fn add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.add_u64x4(a0, b0), self.add_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn shr_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        self.combine_u16x16(self.shr_u16x16(a0, b), self.shr_u16x16(a1, b))
+    fn sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.sub_u64x4(a0, b0), self.sub_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_mask16x16(self.simd_eq_u16x16(a0, b0), self.simd_eq_u16x16(a1, b1))
+    fn mul_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.mul_u64x4(a0, b0), self.mul_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_mask16x16(self.simd_lt_u16x16(a0, b0), self.simd_lt_u16x16(a1, b1))
+    fn and_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.and_u64x4(a0, b0), self.and_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_mask16x16(self.simd_le_u16x16(a0, b0), self.simd_le_u16x16(a1, b1))
+    fn or_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.or_u64x4(a0, b0), self.or_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_ge_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_mask16x16(self.simd_ge_u16x16(a0, b0), self.simd_ge_u16x16(a1, b1))
+    fn xor_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.xor_u64x4(a0, b0), self.xor_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_gt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_mask16x16(self.simd_gt_u16x16(a0, b0), self.simd_gt_u16x16(a1, b1))
+    fn shl_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shl_u64x4(a0, b), self.shl_u64x4(a1, b))
     }
     #[inline(always)]
-    fn zip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, _) = self.split_u16x32(a);
-        let (b0, _) = self.split_u16x32(b);
-        self.combine_u16x16(self.zip_low_u16x16(a0, b0), self.zip_high_u16x16(a0, b0))
+    fn shr_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shr_u64x4(a0, b), self.shr_u64x4(a1, b))
     }
     #[inline(always)]
-    fn zip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (_, a1) = self.split_u16x32(a);
-        let (_, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.zip_low_u16x16(a1, b1), self.zip_high_u16x16(a1, b1))
+    fn shl_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shl_var_u64x4(a0, b0), self.shl_var_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        let (c0, c1) = self.split_u16x32(c);
-        self.combine_u16x16(
-            self.select_u16x16(a0, b0, c0),
-            self.select_u16x16(a1, b1, c1),
-        )
+    fn shr_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shr_var_u64x4(a0, b0), self.shr_var_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.min_u16x16(a0, b0), self.min_u16x16(a1, b1))
+    fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_eq_u64x4(a0, b0), self.simd_eq_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        let (b0, b1) = self.split_u16x32(b);
-        self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
+    fn simd_lt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_lt_u64x4(a0, b0), self.simd_lt_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn simd_le_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_le_u64x4(a0, b0), self.simd_le_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self> {
-        [
-            src[0usize],
-            src[8usize],
-            src[16usize],
-            src[24usize],
-            src[1usize],
-            src[9usize],
-            src[17usize],
-            src[25usize],
-            src[2usize],
-            src[10usize],
-            src[18usize],
-            src[26usize],
-            src[3usize],
-            src[11usize],
-            src[19usize],
-            src[27usize],
-            src[4usize],
-            src[12usize],
-            src[20usize],
-            src[28usize],
-            src[5usize],
-            src[13usize],
-            src[21usize],
-            src[29usize],
-            src[6usize],
-            src[14usize],
-            src[22usize],
-            src[30usize],
-            src[7usize],
-            src[15usize],
-            src[23usize],
-            src[31usize],
-        ]
-        .simd_into(self)
+    fn simd_ge_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_ge_u64x4(a0, b0), self.simd_ge_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
-        *dest = [
-            a[0usize], a[8usize], a[16usize], a[24usize], a[1usize], a[9usize], a[17usize],
-            a[25usize], a[2usize], a[10usize], a[18usize], a[26usize], a[3usize], a[11usize],
-            a[19usize], a[27usize], a[4usize], a[12usize], a[20usize], a[28usize], a[5usize],
-            a[13usize], a[21usize], a[29usize], a[6usize], a[14usize], a[22usize], a[30usize],
-            a[7usize], a[15usize], a[23usize], a[31usize],
-        ];
+    fn simd_gt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_gt_u64x4(a0, b0), self.simd_gt_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
+    fn zip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, _) = self.split_u64x8(a);
+        let (b0, _) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a0, b0), self.zip_high_u64x4(a0, b0))
     }
     #[inline(always)]
-    fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u16x32(a);
-        self.combine_u8x32(
-            self.reinterpret_u8_u16x16(a0),
-            self.reinterpret_u8_u16x16(a1),
-        )
+    fn zip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (_, a1) = self.split_u64x8(a);
+        let (_, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a1, b1), self.zip_high_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn splat_mask16x32(self, a: i16) -> mask16x32<Self> {
-        let half = self.splat_mask16x16(a);
-        self.combine_mask16x16(half, half)
+    fn select_u64x8(self, a: mask64x8<Self>, b: u64x8<Self>, c: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
     }
     #[inline(always)]
-    fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        self.combine_mask16x16(self.not_mask16x16(a0), self.not_mask16x16(a1))
+    fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.min_u64x4(a0, b0), self.min_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_mask16x32(b);
-        self.combine_mask16x16(self.and_mask16x16(a0, b0), self.and_mask16x16(a1, b1))
+    fn max_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn or_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_mask16x32(b);
-        self.combine_mask16x16(self.or_mask16x16(a0, b0), self.or_mask16x16(a1, b1))
+    fn reduce_add_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_add_u64x4(self.add_u64x4(a0, a1))
     }
     #[inline(always)]
-    fn xor_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_mask16x32(b);
-        self.combine_mask16x16(self.xor_mask16x16(a0, b0), self.xor_mask16x16(a1, b1))
+    fn reduce_min_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_min_u64x4(self.min_u64x4(a0, a1))
     }
     #[inline(always)]
-    fn select_mask16x32(
-        self,
-        a: mask16x32<Self>,
-        b: mask16x32<Self>,
-        c: mask16x32<Self>,
-    ) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_mask16x32(b);
-        let (c0, c1) = self.split_mask16x32(c);
-        self.combine_mask16x16(
-            self.select_mask16x16(a0, b0, c0),
-            self.select_mask16x16(a1, b1, c1),
-        )
+    fn reduce_max_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_max_u64x4(self.max_u64x4(a0, a1))
     }
     #[inline(always)]
-    fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
-        let (a0, a1) = self.split_mask16x32(a);
-        let (b0, b1) = self.split_mask16x32(b);
-        self.combine_mask16x16(
-            self.simd_eq_mask16x16(a0, b0),
-            self.simd_eq_mask16x16(a1, b1),
-        )
+    fn reduce_and_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_and_u64x4(self.and_u64x4(a0, a1))
     }
     #[inline(always)]
-    fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>) {
-        let mut b0 = [0; 16usize];
-        let mut b1 = [0; 16usize];
-        b0.copy_from_slice(&a.val[0..16usize]);
-        b1.copy_from_slice(&a.val[16usize..32usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reduce_or_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_or_u64x4(self.or_u64x4(a0, a1))
     }
     #[inline(always)]
-    fn splat_i32x16(self, a: i32) -> i32x16<Self> {
-        let half = self.splat_i32x8(a);
-        self.combine_i32x8(half, half)
+    fn shuffle_u64x8<I: Indices<8>>(self, a: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        self.combine_i32x8(self.not_i32x8(a0), self.not_i32x8(a1))
+    fn shuffle2_u64x8<I: Indices<8>>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.add_i32x8(a0, b0), self.add_i32x8(a1, b1))
+    fn reverse_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.sub_i32x8(a0, b0), self.sub_i32x8(a1, b1))
+    fn rotate_lanes_left_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn mul_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.mul_i32x8(a0, b0), self.mul_i32x8(a1, b1))
+    fn rotate_lanes_right_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.and_i32x8(a0, b0), self.and_i32x8(a1, b1))
+    fn broadcast_lane_u64x8<const L: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
     }
     #[inline(always)]
-    fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.or_i32x8(a0, b0), self.or_i32x8(a1, b1))
+    fn unzip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.xor_i32x8(a0, b0), self.xor_i32x8(a1, b1))
+    fn unzip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn shr_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        self.combine_i32x8(self.shr_i32x8(a0, b), self.shr_i32x8(a1, b))
+    fn abs_diff_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.abs_diff_u64x4(a0, b0), self.abs_diff_u64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_mask32x8(self.simd_eq_i32x8(a0, b0), self.simd_eq_i32x8(a1, b1))
+    fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_mask32x8(self.simd_lt_i32x8(a0, b0), self.simd_lt_i32x8(a1, b1))
+    fn narrow_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(self.narrow_u64x4(a0), self.narrow_u64x4(a1))
     }
     #[inline(always)]
-    fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_mask32x8(self.simd_le_i32x8(a0, b0), self.simd_le_i32x8(a1, b1))
+    fn narrow_saturating_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(
+            self.narrow_saturating_u64x4(a0),
+            self.narrow_saturating_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn simd_ge_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_mask32x8(self.simd_ge_i32x8(a0, b0), self.simd_ge_i32x8(a1, b1))
+    fn reinterpret_f32_u64x8(self, a: u64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u64x4(a0),
+            self.reinterpret_f32_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn simd_gt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_mask32x8(self.simd_gt_i32x8(a0, b0), self.simd_gt_i32x8(a1, b1))
+    fn reinterpret_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u64x4(a0),
+            self.reinterpret_f64_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn zip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, _) = self.split_i32x16(a);
-        let (b0, _) = self.split_i32x16(b);
-        self.combine_i32x8(self.zip_low_i32x8(a0, b0), self.zip_high_i32x8(a0, b0))
+    fn reinterpret_i8_u64x8(self, a: u64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_u64x4(a0), self.reinterpret_i8_u64x4(a1))
     }
     #[inline(always)]
-    fn zip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (_, a1) = self.split_i32x16(a);
-        let (_, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.zip_low_i32x8(a1, b1), self.zip_high_i32x8(a1, b1))
+    fn reinterpret_u8_u64x8(self, a: u64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_u64x4(a0), self.reinterpret_u8_u64x4(a1))
     }
     #[inline(always)]
-    fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        let (c0, c1) = self.split_i32x16(c);
-        self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
+    fn reinterpret_i16_u64x8(self, a: u64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u64x4(a0),
+            self.reinterpret_i16_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.min_i32x8(a0, b0), self.min_i32x8(a1, b1))
+    fn reinterpret_u16_u64x8(self, a: u64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u64x4(a0),
+            self.reinterpret_u16_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        let (b0, b1) = self.split_i32x16(b);
-        self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
+    fn reinterpret_i32_u64x8(self, a: u64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u64x4(a0),
+            self.reinterpret_i32_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
-        let mut b0 = [0; 8usize];
-        let mut b1 = [0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reinterpret_u32_u64x8(self, a: u64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u64x4(a0),
+            self.reinterpret_u32_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        self.combine_u8x32(self.reinterpret_u8_i32x8(a0), self.reinterpret_u8_i32x8(a1))
+    fn reinterpret_i64_u64x8(self, a: u64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u64x4(a0),
+            self.reinterpret_i64_u64x4(a1),
+        )
     }
     #[inline(always)]
-    fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_i32x16(a);
-        self.combine_f32x8(self.cvt_f32_i32x8(a0), self.cvt_f32_i32x8(a1))
+    fn cvt_f16_u64x8(self, a: u64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u64x8(a))
     }
     #[inline(always)]
-    fn splat_u32x16(self, a: u32) -> u32x16<Self> {
-        let half = self.splat_u32x8(a);
-        self.combine_u32x8(half, half)
+    fn cvt_f32_u64x8(self, a: u64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
     }
     #[inline(always)]
-    fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        self.combine_u32x8(self.not_u32x8(a0), self.not_u32x8(a1))
+    fn cvt_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(self.cvt_f64_u64x4(a0), self.cvt_f64_u64x4(a1))
     }
     #[inline(always)]
-    fn add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.add_u32x8(a0, b0), self.add_u32x8(a1, b1))
+    fn splat_mask64x8(self, a: i64) -> mask64x8<Self> {
+        let half = self.splat_mask64x4(a);
+        self.combine_mask64x4(half, half)
     }
     #[inline(always)]
-    fn sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.sub_u32x8(a0, b0), self.sub_u32x8(a1, b1))
+    fn not_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.not_mask64x4(a0), self.not_mask64x4(a1))
     }
     #[inline(always)]
-    fn mul_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.mul_u32x8(a0, b0), self.mul_u32x8(a1, b1))
+    fn and_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.and_mask64x4(a0, b0), self.and_mask64x4(a1, b1))
     }
     #[inline(always)]
-    fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.and_u32x8(a0, b0), self.and_u32x8(a1, b1))
+    fn or_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.or_mask64x4(a0, b0), self.or_mask64x4(a1, b1))
     }
     #[inline(always)]
-    fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.or_u32x8(a0, b0), self.or_u32x8(a1, b1))
+    fn xor_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.xor_mask64x4(a0, b0), self.xor_mask64x4(a1, b1))
     }
     #[inline(always)]
-    fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.xor_u32x8(a0, b0), self.xor_u32x8(a1, b1))
+    fn select_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_mask64x4(a0, b0, c0),
+            self.select_mask64x4(a1, b1, c1),
+        )
     }
     #[inline(always)]
-    fn shr_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        self.combine_u32x8(self.shr_u32x8(a0, b), self.shr_u32x8(a1, b))
+    fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.simd_eq_mask64x4(a0, b0), self.simd_eq_mask64x4(a1, b1))
     }
     #[inline(always)]
-    fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_mask32x8(self.simd_eq_u32x8(a0, b0), self.simd_eq_u32x8(a1, b1))
+    fn any_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.any_mask64x4(a0) || self.any_mask64x4(a1)
     }
     #[inline(always)]
-    fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_mask32x8(self.simd_lt_u32x8(a0, b0), self.simd_lt_u32x8(a1, b1))
+    fn all_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.all_mask64x4(a0) && self.all_mask64x4(a1)
     }
     #[inline(always)]
-    fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_mask32x8(self.simd_le_u32x8(a0, b0), self.simd_le_u32x8(a1, b1))
+    fn none_mask64x8(self, a: mask64x8<Self>) -> bool {
+        !self.any_mask64x8(a)
     }
     #[inline(always)]
-    fn simd_ge_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_mask32x8(self.simd_ge_u32x8(a0, b0), self.simd_ge_u32x8(a1, b1))
+    fn count_true_mask64x8(self, a: mask64x8<Self>) -> usize {
+        self.to_bitmask_mask64x8(a).count_ones() as usize
     }
     #[inline(always)]
-    fn simd_gt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_mask32x8(self.simd_gt_u32x8(a0, b0), self.simd_gt_u32x8(a1, b1))
+    fn first_true_mask64x8(self, a: mask64x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
     }
     #[inline(always)]
-    fn zip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, _) = self.split_u32x16(a);
-        let (b0, _) = self.split_u32x16(b);
-        self.combine_u32x8(self.zip_low_u32x8(a0, b0), self.zip_high_u32x8(a0, b0))
+    fn to_bitmask_mask64x8(self, a: mask64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.to_bitmask_mask64x4(a0) | (self.to_bitmask_mask64x4(a1) << 4)
     }
     #[inline(always)]
-    fn zip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (_, a1) = self.split_u32x16(a);
-        let (_, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.zip_low_u32x8(a1, b1), self.zip_high_u32x8(a1, b1))
+    fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
+        self.combine_mask64x4(
+            self.from_bitmask_mask64x4(bits),
+            self.from_bitmask_mask64x4(bits >> 4),
+        )
     }
     #[inline(always)]
-    fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        let (c0, c1) = self.split_u32x16(c);
-        self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
+    fn shuffle_mask64x8<I: Indices<8>>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.min_u32x8(a0, b0), self.min_u32x8(a1, b1))
+    fn shuffle2_mask64x8<I: Indices<8>>(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        let (b0, b1) = self.split_u32x16(b);
-        self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
+    fn reverse_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
-        let mut b0 = [0; 8usize];
-        let mut b1 = [0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn rotate_lanes_left_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + K) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self> {
-        [
-            src[0usize],
-            src[4usize],
-            src[8usize],
-            src[12usize],
-            src[1usize],
-            src[5usize],
-            src[9usize],
-            src[13usize],
-            src[2usize],
-            src[6usize],
-            src[10usize],
-            src[14usize],
-            src[3usize],
-            src[7usize],
-            src[11usize],
-            src[15usize],
-        ]
-        .simd_into(self)
+    fn rotate_lanes_right_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[(i + 8 - K % 8) % 8]);
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
-        *dest = [
-            a[0usize], a[4usize], a[8usize], a[12usize], a[1usize], a[5usize], a[9usize],
-            a[13usize], a[2usize], a[6usize], a[10usize], a[14usize], a[3usize], a[7usize],
-            a[11usize], a[15usize],
-        ];
+    fn broadcast_lane_mask64x8<const L: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        [a[L % 8]; 8].simd_into(self)
     }
     #[inline(always)]
-    fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        self.combine_u8x32(self.reinterpret_u8_u32x8(a0), self.reinterpret_u8_u32x8(a1))
+    fn unzip_low_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
-        let (a0, a1) = self.split_u32x16(a);
-        self.combine_f32x8(self.cvt_f32_u32x8(a0), self.cvt_f32_u32x8(a1))
+    fn unzip_high_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
     }
     #[inline(always)]
-    fn splat_mask32x16(self, a: i32) -> mask32x16<Self> {
-        let half = self.splat_mask32x8(a);
-        self.combine_mask32x8(half, half)
+    fn split_mask64x8(self, a: mask64x8<Self>) -> (mask64x4<Self>, mask64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
     }
     #[inline(always)]
-    fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        self.combine_mask32x8(self.not_mask32x8(a0), self.not_mask32x8(a1))
+    fn reinterpret_i8_mask64x8(self, a: mask64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask64x4(a0),
+            self.reinterpret_i8_mask64x4(a1),
+        )
     }
     #[inline(always)]
-    fn and_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_mask32x16(b);
-        self.combine_mask32x8(self.and_mask32x8(a0, b0), self.and_mask32x8(a1, b1))
+    fn reinterpret_u8_mask64x8(self, a: mask64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask64x4(a0),
+            self.reinterpret_u8_mask64x4(a1),
+        )
     }
     #[inline(always)]
-    fn or_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_mask32x16(b);
-        self.combine_mask32x8(self.or_mask32x8(a0, b0), self.or_mask32x8(a1, b1))
+    fn reinterpret_i16_mask64x8(self, a: mask64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask64x4(a0),
+            self.reinterpret_i16_mask64x4(a1),
+        )
     }
     #[inline(always)]
-    fn xor_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_mask32x16(b);
-        self.combine_mask32x8(self.xor_mask32x8(a0, b0), self.xor_mask32x8(a1, b1))
+    fn reinterpret_u16_mask64x8(self, a: mask64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask64x4(a0),
+            self.reinterpret_u16_mask64x4(a1),
+        )
     }
     #[inline(always)]
-    fn select_mask32x16(
-        self,
-        a: mask32x16<Self>,
-        b: mask32x16<Self>,
-        c: mask32x16<Self>,
-    ) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_mask32x16(b);
-        let (c0, c1) = self.split_mask32x16(c);
-        self.combine_mask32x8(
-            self.select_mask32x8(a0, b0, c0),
-            self.select_mask32x8(a1, b1, c1),
+    fn reinterpret_i32_mask64x8(self, a: mask64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask64x4(a0),
+            self.reinterpret_i32_mask64x4(a1),
         )
     }
     #[inline(always)]
-    fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
-        let (a0, a1) = self.split_mask32x16(a);
-        let (b0, b1) = self.split_mask32x16(b);
-        self.combine_mask32x8(self.simd_eq_mask32x8(a0, b0), self.simd_eq_mask32x8(a1, b1))
+    fn reinterpret_u32_mask64x8(self, a: mask64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask64x4(a0),
+            self.reinterpret_u32_mask64x4(a1),
+        )
     }
     #[inline(always)]
-    fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>) {
-        let mut b0 = [0; 8usize];
-        let mut b1 = [0; 8usize];
-        b0.copy_from_slice(&a.val[0..8usize]);
-        b1.copy_from_slice(&a.val[8usize..16usize]);
-        (b0.simd_into(self), b1.simd_into(self))
+    fn reinterpret_i64_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask64x4(a0),
+            self.reinterpret_i64_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x8(self, a: mask64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask64x4(a0),
+            self.reinterpret_u64_mask64x4(a1),
+        )
     }
 }
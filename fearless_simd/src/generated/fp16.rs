@@ -0,0 +1,16801 @@
+// This file is autogenerated by fearless_simd_gen
+
+use crate::{Bytes, Indices, Level, Simd, SimdInto, seal::Seal};
+use crate::{
+    bf16, bf16x8, bf16x16, f16, f16x8, f16x16, f32x2, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8,
+    i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x2, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8,
+    mask8x8, mask8x16, mask8x32, mask8x64, mask16x4, mask16x8, mask16x16, mask16x32, mask32x2,
+    mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x8, u8x16, u8x32, u8x64, u16x4,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+};
+use core::arch::aarch64::*;
+#[doc = r#" The SIMD token for the "fp16" level."#]
+#[doc = r""]
+#[doc = r#" This is the "neon" level plus native half-precision arithmetic."#]
+#[derive(Clone, Copy, Debug)]
+pub struct Fp16 {
+    pub neon: crate::core_arch::aarch64::Neon,
+    pub fp16: crate::core_arch::aarch64::Fp16,
+    #[doc = r" The bf16 instructions, if the CPU supports them."]
+    pub bf16: Option<crate::core_arch::aarch64::Bf16>,
+}
+impl Fp16 {
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Fp16 {
+            neon: unsafe { crate::core_arch::aarch64::Neon::new_unchecked() },
+            fp16: unsafe { crate::core_arch::aarch64::Fp16::new_unchecked() },
+            bf16: crate::core_arch::aarch64::Bf16::try_new(),
+        }
+    }
+}
+impl Seal for Fp16 {}
+impl Simd for Fp16 {
+    type f32s = f32x4<Self>;
+    type f64s = f64x2<Self>;
+    type u8s = u8x16<Self>;
+    type i8s = i8x16<Self>;
+    type u16s = u16x8<Self>;
+    type i16s = i16x8<Self>;
+    type u32s = u32x4<Self>;
+    type i32s = i32x4<Self>;
+    type u64s = u64x2<Self>;
+    type i64s = i64x2<Self>;
+    type mask8s = mask8x16<Self>;
+    type mask16s = mask16x8<Self>;
+    type mask32s = mask32x4<Self>;
+    type mask64s = mask64x2<Self>;
+    #[inline(always)]
+    fn level(self) -> Level {
+        Level::Fp16(self)
+    }
+    #[inline]
+    fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+        #[target_feature(enable = "neon,fp16")]
+        #[inline]
+        unsafe fn vectorize_fp16<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+        unsafe { vectorize_fp16(f) }
+    }
+    #[inline(always)]
+    fn splat_f32x2(self, val: f32) -> f32x2<Self> {
+        unsafe { vdup_n_f32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vabs_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vneg_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sqrt_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vsqrt_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrecpe_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrsqrte_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self> {
+        let (estimate, x): (f32x2<Self>, f32x2<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrecpe_f32(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmul_f32(x, vrecps_f32(a, x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f32x2(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x2(estimate);
+        let out_of_range = self.or_mask32x2(
+            self.or_mask32x2(
+                self.simd_lt_f32x2(self.abs_f32x2(a), min_positive),
+                self.simd_lt_f32x2(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x2(magnitude, self.splat_f32x2(f32::INFINITY)),
+        );
+        if self.any_mask32x2(out_of_range) {
+            let exact = self.div_f32x2(self.splat_f32x2(1.0), a);
+            self.select_f32x2(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn rsqrt_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self> {
+        let (estimate, x): (f32x2<Self>, f32x2<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrsqrte_f32(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmul_f32(x, vrsqrts_f32(vmul_f32(a, x), x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f32x2(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x2(estimate);
+        let out_of_range = self.or_mask32x2(
+            self.or_mask32x2(
+                self.simd_lt_f32x2(self.abs_f32x2(a), min_positive),
+                self.simd_lt_f32x2(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x2(magnitude, self.splat_f32x2(f32::INFINITY)),
+        );
+        if self.any_mask32x2(out_of_range) {
+            let exact = self.div_f32x2(self.splat_f32x2(1.0), self.sqrt_f32x2(a));
+            self.select_f32x2(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn add_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vadd_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vsub_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vmul_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn div_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vdiv_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn copysign_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe {
+            let sign_mask = vdup_n_u32(1 << 31);
+            vbsl_f32(sign_mask, b.into(), a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vceq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe {
+            vreinterpret_s32_u32(veor_u32(vceq_f32(a.into(), b.into()), vdup_n_u32(u32::MAX)))
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_lt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vclt_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcle_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcge_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcgt_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_ne_f32x2(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_eq_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        let abs = self.abs_f32x2(a);
+        self.and_mask32x2(
+            self.simd_lt_f32x2(abs, self.splat_f32x2(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x2(abs, self.splat_f32x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(
+            self.copysign_f32x2(self.splat_f32x2(1.0), a),
+            self.splat_f32x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1_f32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2_f32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vmax_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vmaxnm_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vmin_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vminnm_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn madd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vfma_f32(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn msub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vfms_f32(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn nmadd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.neg_f32x2(self.madd_f32x2(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.neg_f32x2(self.msub_f32x2(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vfma_f32(c.into(), a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.madd_f32x2(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrndm_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn ceil_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrndp_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrnda_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrndn_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn fract_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe {
+            let c1 = vcvt_s32_f32(a.into());
+            let c2 = vcvt_f32_s32(c1);
+            vsub_f32(a.into(), c2).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn trunc_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vrnd_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_f32x2(self, a: mask32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        unsafe { vbsl_f32(vreinterpret_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f32x2(self, a: f32x2<Self>) -> f32 {
+        unsafe { vaddv_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_f32x2(self, a: f32x2<Self>) -> f32 {
+        unsafe { vminnmv_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_f32x2(self, a: f32x2<Self>) -> f32 {
+        unsafe { vmaxnmv_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn shuffle_f32x2<I: Indices<2>>(self, a: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x2<I: Indices<2>>(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.rotate_lanes_left_f32x4::<K>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.rotate_lanes_right_f32x4::<K>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x2<const L: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.broadcast_lane_f32x4::<L>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x4<Self> {
+        unsafe { vcombine_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x2(self, a: f32x2<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x2(self, a: f32x2<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        unsafe { vreinterpret_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x2(self, a: f32x2<Self>) -> f64x2<Self> {
+        unsafe { vcvt_f64_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        unsafe { vcvt_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        unsafe { vcvtn_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn splat_u8x8(self, val: u8) -> u8x8<Self> {
+        unsafe { vdup_n_u8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vmvn_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vadd_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vsub_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vmul_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vand_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vorr_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { veor_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u8x8(self, val: u8x8<Self>, shift: u32) -> u8x8<Self> {
+        unsafe { vshl_u8(val.into(), vdup_n_s8(shift.min(8) as i8)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u8x8(self, val: u8x8<Self>, shift: u32) -> u8x8<Self> {
+        unsafe { vshl_u8(val.into(), vdup_n_s8(-(shift.min(8) as i8))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdup_n_u8(8);
+            vshl_u8(a.into(), vreinterpret_s8_u8(vmin_u8(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdup_n_u8(8);
+            vshl_u8(a.into(), vneg_s8(vreinterpret_s8_u8(vmin_u8(count, width)))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vceq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vclt_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vcle_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vcge_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vcgt_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1_u8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2_u8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u8x8(self, a: mask8x8<Self>, b: u8x8<Self>, c: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vbsl_u8(vreinterpret_u8_s8(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vmin_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vmax_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u8x8(self, a: u8x8<Self>) -> u8 {
+        unsafe { vaddv_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u8x8(self, a: u8x8<Self>) -> u8 {
+        unsafe { vminv_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_u8x8(self, a: u8x8<Self>) -> u8 {
+        unsafe { vmaxv_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_u8x8(self, a: u8x8<Self>) -> u8 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        let x = x & (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn reduce_or_u8x8(self, a: u8x8<Self>) -> u8 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        let x = x | (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn shuffle_u8x8<I: Indices<8>>(self, a: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x8<I: Indices<8>>(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.rotate_lanes_left_u8x16::<K>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.rotate_lanes_right_u8x16::<K>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x8<const L: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.broadcast_lane_u8x16::<L>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vqadd_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vqsub_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vabd_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn avg_round_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vrhadd_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u16x8<Self> {
+        unsafe { vmull_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vshrn_n_u16::<8>(vmull_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vtbl1_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn lookup_32_u8x8(self, a: u8x32<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vqtbl2_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn lookup_48_u8x8(self, a: [u8x16<Self>; 3], b: u8x8<Self>) -> u8x8<Self> {
+        unsafe {
+            vqtbl3_u8(
+                uint8x16x3_t(a[0].into(), a[1].into(), a[2].into()),
+                b.into(),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn lookup_64_u8x8(self, a: u8x64<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        unsafe { vqtbl4_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x16<Self> {
+        unsafe { vcombine_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_u8x8(self, a: u8x8<Self>) -> u16x8<Self> {
+        unsafe { vmovl_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x8(self, a: u8x8<Self>) -> f32x2<Self> {
+        unsafe { vreinterpret_f32_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x8(self, a: u8x8<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x8(self, a: u8x8<Self>) -> i32x2<Self> {
+        unsafe { vreinterpret_s32_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f16_u8x8(self, a: u8x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u16x8(self.widen_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u8x8(self, a: u8x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u16x8(self.widen_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_unorm_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.div_f32x8(self.cvt_f32_u8x8(a), self.splat_f32x8(255.0))
+    }
+    #[inline(always)]
+    fn splat_mask8x8(self, val: i8) -> mask8x8<Self> {
+        unsafe { vdup_n_s8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { vmvn_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { vand_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { vorr_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { veor_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>, c: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { vbsl_s8(vreinterpret_u8_s8(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        unsafe { vreinterpret_s8_u8(vceq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask8x8(self, a: mask8x8<Self>) -> bool {
+        unsafe { vmaxv_u32(vreinterpret_u32_s8(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask8x8(self, a: mask8x8<Self>) -> bool {
+        unsafe { vminv_u8(vreinterpret_u8_s8(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask8x8(self, a: mask8x8<Self>) -> bool {
+        !self.any_mask8x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x8(self, a: mask8x8<Self>) -> usize {
+        self.to_bitmask_mask8x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x8(self, a: mask8x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x8(self, a: mask8x8<Self>) -> u64 {
+        unsafe {
+            let weights: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+            let bits = vand_u8(vreinterpret_u8_s8(a.into()), vld1_u8(weights.as_ptr()));
+            vaddv_u8(bits) as u64
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x8(self, bits: u64) -> mask8x8<Self> {
+        let weights: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+        unsafe {
+            vreinterpret_s8_u8(vtst_u8(vdup_n_u8(bits as u8), vld1_u8(weights.as_ptr())))
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.rotate_lanes_left_mask8x16::<K>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.rotate_lanes_right_mask8x16::<K>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x8<const L: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.broadcast_lane_mask8x16::<L>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x16<Self> {
+        unsafe { vcombine_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x8(self, a: mask8x8<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x8(self, a: mask8x8<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x8(self, a: mask8x8<Self>) -> i32x2<Self> {
+        unsafe { vreinterpret_s32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_u16x4(self, val: u16) -> u16x4<Self> {
+        unsafe { vdup_n_u16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vmvn_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vadd_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vsub_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vmul_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vand_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vorr_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { veor_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u16x4(self, val: u16x4<Self>, shift: u32) -> u16x4<Self> {
+        unsafe { vshl_u16(val.into(), vdup_n_s16(shift.min(16) as i16)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u16x4(self, val: u16x4<Self>, shift: u32) -> u16x4<Self> {
+        unsafe { vshl_u16(val.into(), vdup_n_s16(-(shift.min(16) as i16))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdup_n_u16(16);
+            vshl_u16(a.into(), vreinterpret_s16_u16(vmin_u16(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdup_n_u16(16);
+            vshl_u16(
+                a.into(),
+                vneg_s16(vreinterpret_s16_u16(vmin_u16(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vceq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vclt_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vcle_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vcge_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vcgt_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1_u16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2_u16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u16x4(self, a: mask16x4<Self>, b: u16x4<Self>, c: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vbsl_u16(vreinterpret_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vmin_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vmax_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u16x4(self, a: u16x4<Self>) -> u16 {
+        unsafe { vaddv_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u16x4(self, a: u16x4<Self>) -> u16 {
+        unsafe { vminv_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_u16x4(self, a: u16x4<Self>) -> u16 {
+        unsafe { vmaxv_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_u16x4(self, a: u16x4<Self>) -> u16 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn reduce_or_u16x4(self, a: u16x4<Self>) -> u16 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn shuffle_u16x4<I: Indices<4>>(self, a: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x4<I: Indices<4>>(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.rotate_lanes_left_u16x8::<K>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.rotate_lanes_right_u16x8::<K>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x4<const L: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.broadcast_lane_u16x8::<L>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vqadd_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vqsub_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vabd_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn avg_round_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vrhadd_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u32x4<Self> {
+        unsafe { vmull_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        unsafe { vshrn_n_u32::<16>(vmull_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x8<Self> {
+        unsafe { vcombine_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_u16x4(self, a: u16x4<Self>) -> u32x4<Self> {
+        unsafe { vmovl_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x4(self, a: u16x4<Self>) -> f32x2<Self> {
+        unsafe { vreinterpret_f32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x4(self, a: u16x4<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x4(self, a: u16x4<Self>) -> i32x2<Self> {
+        unsafe { vreinterpret_s32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x4(self, a: u16x4<Self>) -> f32x4<Self> {
+        self.cvt_f32_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x4(self, a: u16x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x4(self, val: i16) -> mask16x4<Self> {
+        unsafe { vdup_n_s16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        unsafe { vmvn_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        unsafe { vand_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        unsafe { vorr_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        unsafe { veor_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask16x4(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+        c: mask16x4<Self>,
+    ) -> mask16x4<Self> {
+        unsafe { vbsl_s16(vreinterpret_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        unsafe { vreinterpret_s16_u16(vceq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask16x4(self, a: mask16x4<Self>) -> bool {
+        unsafe { vmaxv_u32(vreinterpret_u32_s16(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask16x4(self, a: mask16x4<Self>) -> bool {
+        unsafe { vminv_u16(vreinterpret_u16_s16(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask16x4(self, a: mask16x4<Self>) -> bool {
+        !self.any_mask16x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x4(self, a: mask16x4<Self>) -> usize {
+        self.to_bitmask_mask16x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x4(self, a: mask16x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x4(self, a: mask16x4<Self>) -> u64 {
+        unsafe {
+            let weights: [u16; 4] = [1, 2, 4, 8];
+            let bits = vand_u16(vreinterpret_u16_s16(a.into()), vld1_u16(weights.as_ptr()));
+            vaddv_u16(bits) as u64
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x4(self, bits: u64) -> mask16x4<Self> {
+        let weights: [u16; 4] = [1, 2, 4, 8];
+        unsafe {
+            vreinterpret_s16_u16(vtst_u16(
+                vdup_n_u16(bits as u16),
+                vld1_u16(weights.as_ptr()),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask16x4<I: Indices<4>>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x4<I: Indices<4>>(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+    ) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.rotate_lanes_left_mask16x8::<K>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.rotate_lanes_right_mask16x8::<K>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x4<const L: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.broadcast_lane_mask16x8::<L>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x8<Self> {
+        unsafe { vcombine_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x4(self, a: mask16x4<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x4(self, a: mask16x4<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x4(self, a: mask16x4<Self>) -> i32x2<Self> {
+        unsafe { vreinterpret_s32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_i32x2(self, val: i32) -> i32x2<Self> {
+        unsafe { vdup_n_s32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vmvn_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vadd_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vsub_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vmul_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vand_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vorr_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { veor_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i32x2(self, val: i32x2<Self>, shift: u32) -> i32x2<Self> {
+        unsafe { vshl_s32(val.into(), vdup_n_s32(shift.min(32) as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i32x2(self, val: i32x2<Self>, shift: u32) -> i32x2<Self> {
+        unsafe { vshl_s32(val.into(), vdup_n_s32(-(shift.min(32) as i32))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe {
+            let count = vreinterpret_u32_s32(b.into());
+            let width = vdup_n_u32(32);
+            vshl_s32(a.into(), vreinterpret_s32_u32(vmin_u32(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe {
+            let count = vreinterpret_u32_s32(b.into());
+            let width = vdup_n_u32(32);
+            vshl_s32(
+                a.into(),
+                vneg_s32(vreinterpret_s32_u32(vmin_u32(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vceq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vclt_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcle_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcge_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vcgt_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1_s32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2_s32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i32x2(self, a: mask32x2<Self>, b: i32x2<Self>, c: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vbsl_s32(vreinterpret_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vmin_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vmax_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i32x2(self, a: i32x2<Self>) -> i32 {
+        unsafe { vaddv_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_i32x2(self, a: i32x2<Self>) -> i32 {
+        unsafe { vminv_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_i32x2(self, a: i32x2<Self>) -> i32 {
+        unsafe { vmaxv_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_i32x2(self, a: i32x2<Self>) -> i32 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x & (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn reduce_or_i32x2(self, a: i32x2<Self>) -> i32 {
+        let chunks: [u64; 1] = bytemuck::cast(a.val);
+        let x = chunks[0];
+        let x = x | (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn shuffle_i32x2<I: Indices<2>>(self, a: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x2<I: Indices<2>>(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.rotate_lanes_left_i32x4::<K>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.rotate_lanes_right_i32x4::<K>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x2<const L: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.broadcast_lane_i32x4::<L>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vabs_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        unsafe { vneg_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x4<Self> {
+        unsafe { vcombine_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_i32x2(self, a: i32x2<Self>) -> i64x2<Self> {
+        unsafe { vmovl_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        unsafe { vreinterpret_f32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x2(self, a: i32x2<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x2(self, a: i32x2<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        unsafe { vcvt_f32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x2(self, a: i32x2<Self>) -> f64x2<Self> {
+        self.cvt_f64_i64x2(self.widen_i32x2(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x2(self, val: i32) -> mask32x2<Self> {
+        unsafe { vdup_n_s32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        unsafe { vmvn_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        unsafe { vand_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        unsafe { vorr_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        unsafe { veor_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask32x2(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+        c: mask32x2<Self>,
+    ) -> mask32x2<Self> {
+        unsafe { vbsl_s32(vreinterpret_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        unsafe { vreinterpret_s32_u32(vceq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask32x2(self, a: mask32x2<Self>) -> bool {
+        unsafe { vmaxv_u32(vreinterpret_u32_s32(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask32x2(self, a: mask32x2<Self>) -> bool {
+        unsafe { vminv_u32(vreinterpret_u32_s32(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask32x2(self, a: mask32x2<Self>) -> bool {
+        !self.any_mask32x2(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x2(self, a: mask32x2<Self>) -> usize {
+        self.to_bitmask_mask32x2(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x2(self, a: mask32x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x2(self, a: mask32x2<Self>) -> u64 {
+        unsafe {
+            let weights: [u32; 2] = [1, 2];
+            let bits = vand_u32(vreinterpret_u32_s32(a.into()), vld1_u32(weights.as_ptr()));
+            vaddv_u32(bits) as u64
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x2(self, bits: u64) -> mask32x2<Self> {
+        let weights: [u32; 2] = [1, 2];
+        unsafe {
+            vreinterpret_s32_u32(vtst_u32(
+                vdup_n_u32(bits as u32),
+                vld1_u32(weights.as_ptr()),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask32x2<I: Indices<2>>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x2<I: Indices<2>>(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+    ) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.rotate_lanes_left_mask32x4::<K>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.rotate_lanes_right_mask32x4::<K>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x2<const L: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.broadcast_lane_mask32x4::<L>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x4<Self> {
+        unsafe { vcombine_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x2(self, a: mask32x2<Self>) -> u8x8<Self> {
+        unsafe { vreinterpret_u8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x2(self, a: mask32x2<Self>) -> u16x4<Self> {
+        unsafe { vreinterpret_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x2(self, a: mask32x2<Self>) -> i32x2<Self> {
+        <int32x2_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_f16x8(self, val: f16) -> f16x8<Self> {
+        self.fp16.vdupq_n_f16(val).simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vabsq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vnegq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vsqrtq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        self.div_f16x8(self.splat_f16x8(f16::from_f32_const(1.0)), a)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        self.div_f16x8(
+            self.splat_f16x8(f16::from_f32_const(1.0)),
+            self.sqrt_f16x8(a),
+        )
+    }
+    #[inline(always)]
+    fn recip_f16x8(self, a: f16x8<Self>, _steps: u32) -> f16x8<Self> {
+        self.div_f16x8(self.splat_f16x8(f16::from_f32_const(1.0)), a)
+    }
+    #[inline(always)]
+    fn rsqrt_f16x8(self, a: f16x8<Self>, _steps: u32) -> f16x8<Self> {
+        self.div_f16x8(
+            self.splat_f16x8(f16::from_f32_const(1.0)),
+            self.sqrt_f16x8(a),
+        )
+    }
+    #[inline(always)]
+    fn add_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vaddq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vsubq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vmulq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vdivq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let sign_mask = vdupq_n_u16(1 << 15);
+            vbslq_u16(sign_mask, b.into(), a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(self.fp16.vceqq_f16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            vreinterpretq_s16_u16(vmvnq_u16(self.fp16.vceqq_f16(a.into(), b.into())))
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_lt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(self.fp16.vcltq_f16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(self.fp16.vcleq_f16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(self.fp16.vcgeq_f16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(self.fp16.vcgtq_f16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_ne_f16x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_infinite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_eq_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        let abs = self.abs_f16x8(a);
+        self.and_mask16x8(
+            self.simd_lt_f16x8(abs, self.splat_f16x8(f16::from_bits(0x0400))),
+            self.simd_gt_f16x8(abs, self.splat_f16x8(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(
+            self.copysign_f16x8(self.splat_f16x8(f16::from_bits(0x3c00)), a),
+            self.splat_f16x8(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe { vzip1q_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe { vzip2q_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vmaxq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vmaxnmq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vminq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        self.fp16.vminnmq_f16(a.into(), b.into()).simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.fp16
+            .vfmaq_f16(a.into(), b.into(), c.into())
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn msub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.fp16
+            .vfmsq_f16(a.into(), b.into(), c.into())
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmadd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.neg_f16x8(self.madd_f16x8(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.neg_f16x8(self.msub_f16x8(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.fp16
+            .vfmaq_f16(c.into(), a.into(), b.into())
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        self.madd_f16x8(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vrndmq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vrndpq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vrndaq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vrndnq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16
+            .vsubq_f16(a, self.fp16.vrndq_f16(a))
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.into();
+        self.fp16.vrndq_f16(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f16x8(self, a: mask16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        unsafe { vbslq_u16(vreinterpretq_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x8(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x8<I: Indices<8>>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f16x8<I: Indices<8>>(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u16_u8(a.to_bytes().into());
+                let rev = vrev64q_u16(lanes);
+                vreinterpretq_u8_u16(vextq_u16::<4>(rev, rev))
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x8<const L: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp1q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp2q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x16<Self> {
+        let mut result = [f16::from_bits(0); 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x8(self, a: f16x8<Self>) -> bf16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x8(self, a: f16x8<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x8(self, a: f16x8<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x8(self, a: f16x8<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x8(self, a: f16x8<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x8(self, a: f16x8<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x8(self, a: f16x8<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x8(self, a: f16x8<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x8(self, a: f16x8<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x8(self, a: f16x8<Self>) -> f32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let low = self.neon.vcvt_f32_f16(vget_low_u16(a));
+            let high = self.neon.vcvt_high_f32_f16(a);
+            float32x4x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_f64_f16x8(self, a: f16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x8(self, val: bf16) -> bf16x8<Self> {
+        self.splat_u16x8(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x8(self, a: mask16x8<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> bf16x8<Self> {
+        self.select_u16x8(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_bf16x8(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u16_u8(a.to_bytes().into());
+                let rev = vrev64q_u16(lanes);
+                vreinterpretq_u8_u16(vextq_u16::<4>(rev, rev))
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x8<const L: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp1q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp2q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x16<Self> {
+        self.combine_u16x8(a.bitcast(), b.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x8(self, a: bf16x8<Self>) -> f16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x8(self, a: bf16x8<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x8(self, a: bf16x8<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x8(self, a: bf16x8<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x8(self, a: bf16x8<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x8(self, a: bf16x8<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x8(self, a: bf16x8<Self>) -> u16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x8(self, a: bf16x8<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x8(self, a: bf16x8<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x8(self, a: bf16x8<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x8(self, a: bf16x8<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x8(self, a: bf16x8<Self>) -> f32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let low = vreinterpretq_f32_u32(vshll_n_u16::<16>(vget_low_u16(a)));
+            let high = vreinterpretq_f32_u32(vshll_high_n_u16::<16>(a));
+            float32x4x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn splat_f32x4(self, val: f32) -> f32x4<Self> {
+        unsafe { vdupq_n_f32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vabsq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vnegq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vsqrtq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrecpeq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrsqrteq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self> {
+        let (estimate, x): (f32x4<Self>, f32x4<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrecpeq_f32(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmulq_f32(x, vrecpsq_f32(a, x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f32x4(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x4(estimate);
+        let out_of_range = self.or_mask32x4(
+            self.or_mask32x4(
+                self.simd_lt_f32x4(self.abs_f32x4(a), min_positive),
+                self.simd_lt_f32x4(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x4(magnitude, self.splat_f32x4(f32::INFINITY)),
+        );
+        if self.any_mask32x4(out_of_range) {
+            let exact = self.div_f32x4(self.splat_f32x4(1.0), a);
+            self.select_f32x4(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn rsqrt_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self> {
+        let (estimate, x): (f32x4<Self>, f32x4<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrsqrteq_f32(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmulq_f32(x, vrsqrtsq_f32(vmulq_f32(a, x), x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f32x4(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x4(estimate);
+        let out_of_range = self.or_mask32x4(
+            self.or_mask32x4(
+                self.simd_lt_f32x4(self.abs_f32x4(a), min_positive),
+                self.simd_lt_f32x4(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x4(magnitude, self.splat_f32x4(f32::INFINITY)),
+        );
+        if self.any_mask32x4(out_of_range) {
+            let exact = self.div_f32x4(self.splat_f32x4(1.0), self.sqrt_f32x4(a));
+            self.select_f32x4(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vaddq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vsubq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vmulq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vdivq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let sign_mask = vdupq_n_u32(1 << 31);
+            vbslq_f32(sign_mask, b.into(), a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vceqq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            vreinterpretq_s32_u32(veorq_u32(
+                vceqq_f32(a.into(), b.into()),
+                vdupq_n_u32(u32::MAX),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcltq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcleq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgeq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgtq_f32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_ne_f32x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_eq_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        let abs = self.abs_f32x4(a);
+        self.and_mask32x4(
+            self.simd_lt_f32x4(abs, self.splat_f32x4(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x4(abs, self.splat_f32x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(
+            self.copysign_f32x4(self.splat_f32x4(1.0), a),
+            self.splat_f32x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_f32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_f32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vmaxq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vmaxnmq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vminq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vminnmq_f32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn madd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vfmaq_f32(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn msub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vfmsq_f32(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn nmadd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.madd_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.msub_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vfmaq_f32(c.into(), a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.madd_f32x4(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrndmq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrndpq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrndaq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrndnq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let c1 = vcvtq_s32_f32(a.into());
+            let c2 = vcvtq_f32_s32(c1);
+            vsubq_f32(a.into(), c2).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vrndq_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { vbslq_f32(vreinterpretq_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe { vaddvq_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe { vminnmvq_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe { vmaxnmvq_f32(a.into()) }
+    }
+    #[inline(always)]
+    fn shuffle_f32x4<I: Indices<4>>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f32x4<I: Indices<4>>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u32_u8(a.to_bytes().into());
+                let rev = vrev64q_u32(lanes);
+                vreinterpretq_u8_u32(vextq_u32::<2>(rev, rev))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x4<const L: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp1q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp2q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x4(self, a: f32x4<Self>) -> (f32x2<Self>, f32x2<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_f32(a).simd_into(self),
+                vget_high_f32(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x4(self, a: f32x4<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x4(self, a: f32x4<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x4(self, a: f32x4<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x4(self, a: f32x4<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x4(self, a: f32x4<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x4(self, a: f32x4<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x4(self, a: f32x4<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x4(self, a: f32x4<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x4(self, a: f32x4<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x4(self, a: f32x4<Self>) -> f64x4<Self> {
+        unsafe {
+            let a = a.into();
+            let low = vcvt_f64_f32(vget_low_f32(a));
+            let high = vcvt_high_f64_f32(a);
+            float64x2x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        unsafe { vcvtq_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        unsafe { vcvtnq_s32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        unsafe { vcvtq_u32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        unsafe { vcvtnq_u32_f32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        if let Some(bf16) = self.bf16 {
+            bf16.vbfdotq_f32(a.into(), b.into(), c.into())
+                .simd_into(self)
+        } else {
+            let b = self.cvt_f32_bf16x8(b).val;
+            let c = self.cvt_f32_bf16x8(c).val;
+            let result: [f32; 4usize] = core::array::from_fn(|i| {
+                a.val[i] + b[2 * i + 1] * c[2 * i + 1] + b[2 * i] * c[2 * i]
+            });
+            result.simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mmla_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        if let Some(bf16) = self.bf16 {
+            bf16.vbfmmlaq_f32(a.into(), b.into(), c.into())
+                .simd_into(self)
+        } else {
+            let b = self.cvt_f32_bf16x8(b).val;
+            let c = self.cvt_f32_bf16x8(c).val;
+            let result: [f32; 4usize] = core::array::from_fn(|i| {
+                let (row, col) = (4 * (i / 2), 4 * (i % 2));
+                a.val[i]
+                    + b[row] * c[col]
+                    + b[row + 1] * c[col + 1]
+                    + b[row + 2] * c[col + 2]
+                    + b[row + 3] * c[col + 3]
+            });
+            result.simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn splat_i8x16(self, val: i8) -> i8x16<Self> {
+        unsafe { vdupq_n_s8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vmvnq_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vaddq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vsubq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vmulq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vandq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vorrq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { veorq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i8x16(self, val: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        unsafe { vshlq_s8(val.into(), vdupq_n_s8(shift.min(8) as i8)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i8x16(self, val: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        unsafe { vshlq_s8(val.into(), vdupq_n_s8(-(shift.min(8) as i8))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let count = vreinterpretq_u8_s8(b.into());
+            let width = vdupq_n_u8(8);
+            vshlq_s8(a.into(), vreinterpretq_s8_u8(vminq_u8(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let count = vreinterpretq_u8_s8(b.into());
+            let width = vdupq_n_u8(8);
+            vshlq_s8(
+                a.into(),
+                vnegq_s8(vreinterpretq_s8_u8(vminq_u8(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vceqq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcltq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcleq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcgeq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcgtq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_s8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_s8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vbslq_s8(vreinterpretq_u8_s8(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vminq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vmaxq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe { vaddvq_s8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe { vminvq_s8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe { vmaxvq_s8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_i8x16(self, a: i8x16<Self>) -> i8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        let x = x & (x >> 8);
+        x as i8
+    }
+    #[inline(always)]
+    fn reduce_or_i8x16(self, a: i8x16<Self>) -> i8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        let x = x | (x >> 8);
+        x as i8
+    }
+    #[inline(always)]
+    fn shuffle_i8x16<I: Indices<16>>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i8x16<I: Indices<16>>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = a.to_bytes().into();
+                let rev = vrev64q_u8(lanes);
+                vextq_u8::<8>(rev, rev)
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x16<const L: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { vuzp1q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { vuzp2q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vqaddq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vqsubq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vabsq_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { vnegq_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s8(vabdq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i16x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_s8(vget_low_s8(a), vget_low_s8(b));
+            let high = vmull_high_s8(a, b);
+            int16x8x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mul_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_s8(vget_low_s8(a), vget_low_s8(b));
+            let high = vmull_high_s8(a, b);
+            vuzp2q_s8(vreinterpretq_s8_s16(low), vreinterpretq_s8_s16(high)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        unsafe {
+            let a = a.into();
+            int16x8x2_t(vmovl_s8(vget_low_s8(a)), vmovl_high_s8(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        unsafe { vmovl_s8(vget_low_s8(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        unsafe { vmovl_high_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x16(self, a: i8x16<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x16(self, a: i8x16<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x16(self, a: i8x16<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x16(self, a: i8x16<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x16(self, a: i8x16<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x16(self, a: i8x16<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x16(self, a: i8x16<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x16(self, a: i8x16<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x16(self, a: i8x16<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f16_i8x16(self, a: i8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i8x16(self, a: i8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i16x16(self.widen_i8x16(a))
+    }
+    #[inline(always)]
+    fn splat_u8x16(self, val: u8) -> u8x16<Self> {
+        unsafe { vdupq_n_u8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vmvnq_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vaddq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vsubq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vmulq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vandq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vorrq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { veorq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u8x16(self, val: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        unsafe { vshlq_u8(val.into(), vdupq_n_s8(shift.min(8) as i8)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u8x16(self, val: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        unsafe { vshlq_u8(val.into(), vdupq_n_s8(-(shift.min(8) as i8))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u8(8);
+            vshlq_u8(a.into(), vreinterpretq_s8_u8(vminq_u8(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u8(8);
+            vshlq_u8(
+                a.into(),
+                vnegq_s8(vreinterpretq_s8_u8(vminq_u8(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vceqq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcltq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcleq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcgeq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vcgtq_u8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_u8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_u8(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vbslq_u8(vreinterpretq_u8_s8(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vminq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vmaxq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe { vaddvq_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe { vminvq_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe { vmaxvq_u8(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_u8x16(self, a: u8x16<Self>) -> u8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        let x = x & (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn reduce_or_u8x16(self, a: u8x16<Self>) -> u8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        let x = x | (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn shuffle_u8x16<I: Indices<16>>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u8x16<I: Indices<16>>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = a.to_bytes().into();
+                let rev = vrev64q_u8(lanes);
+                vextq_u8::<8>(rev, rev)
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x16<const L: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { vuzp1q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { vuzp2q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vqaddq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vqsubq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vabdq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vrhaddq_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u16x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_u8(vget_low_u8(a), vget_low_u8(b));
+            let high = vmull_high_u8(a, b);
+            uint16x8x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mul_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_u8(vget_low_u8(a), vget_low_u8(b));
+            let high = vmull_high_u8(a, b);
+            vuzp2q_u8(vreinterpretq_u8_u16(low), vreinterpretq_u8_u16(high)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vqtbl1q_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn lookup_32_u8x16(self, a: u8x32<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vqtbl2q_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn lookup_48_u8x16(self, a: [u8x16<Self>; 3], b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            vqtbl3q_u8(
+                uint8x16x3_t(a[0].into(), a[1].into(), a[2].into()),
+                b.into(),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn lookup_64_u8x16(self, a: u8x64<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { vqtbl4q_u8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x16(self, a: u8x16<Self>) -> (u8x8<Self>, u8x8<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_u8(a).simd_into(self),
+                vget_high_u8(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self> {
+        unsafe {
+            let a = a.into();
+            uint16x8x2_t(vmovl_u8(vget_low_u8(a)), vmovl_high_u8(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        unsafe { vmovl_u8(vget_low_u8(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        unsafe { vmovl_high_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x16(self, a: u8x16<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x16(self, a: u8x16<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x16(self, a: u8x16<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x16(self, a: u8x16<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x16(self, a: u8x16<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x16(self, a: u8x16<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x16(self, a: u8x16<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x16(self, a: u8x16<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x16(self, a: u8x16<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_u8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f16_u8x16(self, a: u8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u16x16(self.widen_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_unorm_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.div_f32x16(self.cvt_f32_u8x16(a), self.splat_f32x16(255.0))
+    }
+    #[inline(always)]
+    fn splat_mask8x16(self, val: i8) -> mask8x16<Self> {
+        unsafe { vdupq_n_s8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { vmvnq_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { vandq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { vorrq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { veorq_s8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        unsafe { vbslq_s8(vreinterpretq_u8_s8(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { vreinterpretq_s8_u8(vceqq_s8(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask8x16(self, a: mask8x16<Self>) -> bool {
+        unsafe { vmaxvq_u32(vreinterpretq_u32_s8(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask8x16(self, a: mask8x16<Self>) -> bool {
+        unsafe { vminvq_u8(vreinterpretq_u8_s8(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask8x16(self, a: mask8x16<Self>) -> bool {
+        !self.any_mask8x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x16(self, a: mask8x16<Self>) -> usize {
+        self.to_bitmask_mask8x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x16(self, a: mask8x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x16(self, a: mask8x16<Self>) -> u64 {
+        unsafe {
+            let weights: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+            let bits = vandq_u8(vreinterpretq_u8_s8(a.into()), vld1q_u8(weights.as_ptr()));
+            vaddv_u8(vget_low_u8(bits)) as u64 | (vaddv_u8(vget_high_u8(bits)) as u64) << 8
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
+        let weights: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+        unsafe {
+            vreinterpretq_s8_u8(vtstq_u8(
+                vcombine_u8(vdup_n_u8(bits as u8), vdup_n_u8((bits >> 8) as u8)),
+                vld1q_u8(weights.as_ptr()),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask8x16<I: Indices<16>>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x16<I: Indices<16>>(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = a.to_bytes().into();
+                let rev = vrev64q_u8(lanes);
+                vextq_u8::<8>(rev, rev)
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x16<const L: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { vuzp1q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { vuzp2q_u8(a.to_bytes().into(), b.to_bytes().into()) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x16(self, a: mask8x16<Self>) -> (mask8x8<Self>, mask8x8<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_s8(a).simd_into(self),
+                vget_high_s8(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        <int8x16_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x16(self, a: mask8x16<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x16(self, a: mask8x16<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x16(self, a: mask8x16<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x16(self, a: mask8x16<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x16(self, a: mask8x16<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x16(self, a: mask8x16<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x16(self, a: mask8x16<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_i16x8(self, val: i16) -> i16x8<Self> {
+        unsafe { vdupq_n_s16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vmvnq_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vaddq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vsubq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vmulq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vandq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vorrq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { veorq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i16x8(self, val: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        unsafe { vshlq_s16(val.into(), vdupq_n_s16(shift.min(16) as i16)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i16x8(self, val: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        unsafe { vshlq_s16(val.into(), vdupq_n_s16(-(shift.min(16) as i16))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let count = vreinterpretq_u16_s16(b.into());
+            let width = vdupq_n_u16(16);
+            vshlq_s16(a.into(), vreinterpretq_s16_u16(vminq_u16(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let count = vreinterpretq_u16_s16(b.into());
+            let width = vdupq_n_u16(16);
+            vshlq_s16(
+                a.into(),
+                vnegq_s16(vreinterpretq_s16_u16(vminq_u16(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vceqq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcltq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcleq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcgeq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcgtq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_s16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_s16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vbslq_s16(vreinterpretq_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vminq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vmaxq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe { vaddvq_s16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe { vminvq_s16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe { vmaxvq_s16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_i16x8(self, a: i16x8<Self>) -> i16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        x as i16
+    }
+    #[inline(always)]
+    fn reduce_or_i16x8(self, a: i16x8<Self>) -> i16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        x as i16
+    }
+    #[inline(always)]
+    fn shuffle_i16x8<I: Indices<8>>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i16x8<I: Indices<8>>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u16_u8(a.to_bytes().into());
+                let rev = vrev64q_u16(lanes);
+                vreinterpretq_u8_u16(vextq_u16::<4>(rev, rev))
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x8<const L: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp1q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp2q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vqaddq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vqsubq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vabsq_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vnegq_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s16(vabdq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+            let high = vmull_high_s16(a, b);
+            int32x4x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mul_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+            let high = vmull_high_s16(a, b);
+            vuzp2q_s16(vreinterpretq_s16_s32(low), vreinterpretq_s16_s32(high)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { vqrdmulhq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn dot_i16_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x4<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_s16(vget_low_s16(a), vget_low_s16(b));
+            let high = vmull_high_s16(a, b);
+            vpaddq_s32(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        unsafe {
+            let a = a.into();
+            int32x4x2_t(vmovl_s16(vget_low_s16(a)), vmovl_high_s16(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        unsafe { vmovl_s16(vget_low_s16(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        unsafe { vmovl_high_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x8(self, a: i16x8<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x8(self, a: i16x8<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x8(self, a: i16x8<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x8(self, a: i16x8<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x8(self, a: i16x8<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x8(self, a: i16x8<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x8(self, a: i16x8<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x8(self, a: i16x8<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x8(self, a: i16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_i16x8(self, a: i16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn splat_u16x8(self, val: u16) -> u16x8<Self> {
+        unsafe { vdupq_n_u16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vmvnq_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vaddq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vsubq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vmulq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vandq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vorrq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { veorq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u16x8(self, val: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        unsafe { vshlq_u16(val.into(), vdupq_n_s16(shift.min(16) as i16)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u16x8(self, val: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        unsafe { vshlq_u16(val.into(), vdupq_n_s16(-(shift.min(16) as i16))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u16(16);
+            vshlq_u16(a.into(), vreinterpretq_s16_u16(vminq_u16(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u16(16);
+            vshlq_u16(
+                a.into(),
+                vnegq_s16(vreinterpretq_s16_u16(vminq_u16(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vceqq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcltq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcleq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcgeq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vcgtq_u16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_u16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_u16(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vbslq_u16(vreinterpretq_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vminq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vmaxq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe { vaddvq_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe { vminvq_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe { vmaxvq_u16(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_u16x8(self, a: u16x8<Self>) -> u16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn reduce_or_u16x8(self, a: u16x8<Self>) -> u16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn shuffle_u16x8<I: Indices<8>>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u16x8<I: Indices<8>>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u16_u8(a.to_bytes().into());
+                let rev = vrev64q_u16(lanes);
+                vreinterpretq_u8_u16(vextq_u16::<4>(rev, rev))
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x8<const L: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp1q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp2q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vqaddq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vqsubq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vabdq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { vrhaddq_u16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_u16(vget_low_u16(a), vget_low_u16(b));
+            let high = vmull_high_u16(a, b);
+            uint32x4x2_t(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn mul_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = vmull_u16(vget_low_u16(a), vget_low_u16(b));
+            let high = vmull_high_u16(a, b);
+            vuzp2q_u16(vreinterpretq_u16_u32(low), vreinterpretq_u16_u32(high)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x8(self, a: u16x8<Self>) -> (u16x4<Self>, u16x4<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_u16(a).simd_into(self),
+                vget_high_u16(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        unsafe {
+            let a = a.into();
+            uint32x4x2_t(vmovl_u16(vget_low_u16(a)), vmovl_high_u16(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        unsafe { vmovl_u16(vget_low_u16(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        unsafe { vmovl_high_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        unsafe { vmovn_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        unsafe { vqmovn_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x8(self, a: u16x8<Self>) -> bf16x8<Self> {
+        <uint16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x8(self, a: u16x8<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x8(self, a: u16x8<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x8(self, a: u16x8<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x8(self, a: u16x8<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x8(self, a: u16x8<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x8(self, a: u16x8<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x8(self, a: u16x8<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_u16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x8(self, a: u16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x8(self, a: u16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x8(self, val: i16) -> mask16x8<Self> {
+        unsafe { vdupq_n_s16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { vmvnq_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { vandq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { vorrq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { veorq_s16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        unsafe { vbslq_s16(vreinterpretq_u16_s16(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { vreinterpretq_s16_u16(vceqq_s16(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask16x8(self, a: mask16x8<Self>) -> bool {
+        unsafe { vmaxvq_u32(vreinterpretq_u32_s16(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask16x8(self, a: mask16x8<Self>) -> bool {
+        unsafe { vminvq_u16(vreinterpretq_u16_s16(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask16x8(self, a: mask16x8<Self>) -> bool {
+        !self.any_mask16x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x8(self, a: mask16x8<Self>) -> usize {
+        self.to_bitmask_mask16x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x8(self, a: mask16x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x8(self, a: mask16x8<Self>) -> u64 {
+        unsafe {
+            let weights: [u16; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+            let bits = vandq_u16(vreinterpretq_u16_s16(a.into()), vld1q_u16(weights.as_ptr()));
+            vaddvq_u16(bits) as u64
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
+        let weights: [u16; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+        unsafe {
+            vreinterpretq_s16_u16(vtstq_u16(
+                vdupq_n_u16(bits as u16),
+                vld1q_u16(weights.as_ptr()),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask16x8<I: Indices<8>>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x8<I: Indices<8>>(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u16_u8(a.to_bytes().into());
+                let rev = vrev64q_u16(lanes);
+                vreinterpretq_u8_u16(vextq_u16::<4>(rev, rev))
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x8<const L: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp1q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u16(vuzp2q_u16(
+                    vreinterpretq_u16_u8(a.to_bytes().into()),
+                    vreinterpretq_u16_u8(b.to_bytes().into()),
+                ))
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x8(self, a: mask16x8<Self>) -> (mask16x4<Self>, mask16x4<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_s16(a).simd_into(self),
+                vget_high_s16(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x8(self, a: mask16x8<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x8(self, a: mask16x8<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        <int16x8_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x8(self, a: mask16x8<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x8(self, a: mask16x8<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x8(self, a: mask16x8<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x8(self, a: mask16x8<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x8(self, a: mask16x8<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_i32x4(self, val: i32) -> i32x4<Self> {
+        unsafe { vdupq_n_s32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vmvnq_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vaddq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vsubq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vmulq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vandq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vorrq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { veorq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i32x4(self, val: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        unsafe { vshlq_s32(val.into(), vdupq_n_s32(shift.min(32) as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i32x4(self, val: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        unsafe { vshlq_s32(val.into(), vdupq_n_s32(-(shift.min(32) as i32))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let count = vreinterpretq_u32_s32(b.into());
+            let width = vdupq_n_u32(32);
+            vshlq_s32(a.into(), vreinterpretq_s32_u32(vminq_u32(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let count = vreinterpretq_u32_s32(b.into());
+            let width = vdupq_n_u32(32);
+            vshlq_s32(
+                a.into(),
+                vnegq_s32(vreinterpretq_s32_u32(vminq_u32(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vceqq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcltq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcleq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgeq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgtq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_s32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_s32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vbslq_s32(vreinterpretq_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vminq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vmaxq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe { vaddvq_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe { vminvq_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe { vmaxvq_s32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_i32x4(self, a: i32x4<Self>) -> i32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn reduce_or_i32x4(self, a: i32x4<Self>) -> i32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn shuffle_i32x4<I: Indices<4>>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i32x4<I: Indices<4>>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u32_u8(a.to_bytes().into());
+                let rev = vrev64q_u32(lanes);
+                vreinterpretq_u8_u32(vextq_u32::<2>(rev, rev))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x4<const L: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp1q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp2q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vabsq_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { vnegq_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s32(vabdq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x4(self, a: i32x4<Self>) -> (i32x2<Self>, i32x2<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_s32(a).simd_into(self),
+                vget_high_s32(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        unsafe {
+            let a = a.into();
+            int64x2x2_t(vmovl_s32(vget_low_s32(a)), vmovl_high_s32(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        unsafe { vmovl_s32(vget_low_s32(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        unsafe { vmovl_high_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x4(self, a: i32x4<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x4(self, a: i32x4<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x4(self, a: i32x4<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x4(self, a: i32x4<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x4(self, a: i32x4<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x4(self, a: i32x4<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x4(self, a: i32x4<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x4(self, a: i32x4<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        unsafe { vcvtq_f32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x4(self, a: i32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_i64x4(self.widen_i32x4(a))
+    }
+    #[inline(always)]
+    fn splat_u32x4(self, val: u32) -> u32x4<Self> {
+        unsafe { vdupq_n_u32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vmvnq_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vaddq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vsubq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vmulq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vandq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vorrq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { veorq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u32x4(self, val: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        unsafe { vshlq_u32(val.into(), vdupq_n_s32(shift.min(32) as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u32x4(self, val: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        unsafe { vshlq_u32(val.into(), vdupq_n_s32(-(shift.min(32) as i32))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u32(32);
+            vshlq_u32(a.into(), vreinterpretq_s32_u32(vminq_u32(count, width))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u32(32);
+            vshlq_u32(
+                a.into(),
+                vnegq_s32(vreinterpretq_s32_u32(vminq_u32(count, width))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vceqq_u32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcltq_u32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcleq_u32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgeq_u32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vcgtq_u32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_u32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_u32(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vbslq_u32(vreinterpretq_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vminq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vmaxq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe { vaddvq_u32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe { vminvq_u32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe { vmaxvq_u32(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_and_u32x4(self, a: u32x4<Self>) -> u32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        x as u32
+    }
+    #[inline(always)]
+    fn reduce_or_u32x4(self, a: u32x4<Self>) -> u32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        x as u32
+    }
+    #[inline(always)]
+    fn shuffle_u32x4<I: Indices<4>>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u32x4<I: Indices<4>>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u32_u8(a.to_bytes().into());
+                let rev = vrev64q_u32(lanes);
+                vreinterpretq_u8_u32(vextq_u32::<2>(rev, rev))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x4<const L: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp1q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp2q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_diff_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { vabdq_u32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        unsafe {
+            let a = a.into();
+            uint64x2x2_t(vmovl_u32(vget_low_u32(a)), vmovl_high_u32(a)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        unsafe { vmovl_u32(vget_low_u32(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        unsafe { vmovl_high_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        unsafe { vmovn_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        unsafe { vqmovn_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x4(self, a: u32x4<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x4(self, a: u32x4<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x4(self, a: u32x4<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x4(self, a: u32x4<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x4(self, a: u32x4<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x4(self, a: u32x4<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x4(self, a: u32x4<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x4(self, a: u32x4<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        unsafe { vcvtq_f32_u32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x4(self, a: u32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u64x4(self.widen_u32x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x4(self, val: i32) -> mask32x4<Self> {
+        unsafe { vdupq_n_s32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { vmvnq_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { vandq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { vorrq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { veorq_s32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        unsafe { vbslq_s32(vreinterpretq_u32_s32(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { vreinterpretq_s32_u32(vceqq_s32(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask32x4(self, a: mask32x4<Self>) -> bool {
+        unsafe { vmaxvq_u32(vreinterpretq_u32_s32(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask32x4(self, a: mask32x4<Self>) -> bool {
+        unsafe { vminvq_u32(vreinterpretq_u32_s32(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask32x4(self, a: mask32x4<Self>) -> bool {
+        !self.any_mask32x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x4(self, a: mask32x4<Self>) -> usize {
+        self.to_bitmask_mask32x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x4(self, a: mask32x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x4(self, a: mask32x4<Self>) -> u64 {
+        unsafe {
+            let weights: [u32; 4] = [1, 2, 4, 8];
+            let bits = vandq_u32(vreinterpretq_u32_s32(a.into()), vld1q_u32(weights.as_ptr()));
+            vaddvq_u32(bits) as u64
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
+        let weights: [u32; 4] = [1, 2, 4, 8];
+        unsafe {
+            vreinterpretq_s32_u32(vtstq_u32(
+                vdupq_n_u32(bits as u32),
+                vld1q_u32(weights.as_ptr()),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask32x4<I: Indices<4>>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x4<I: Indices<4>>(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u32_u8(a.to_bytes().into());
+                let rev = vrev64q_u32(lanes);
+                vreinterpretq_u8_u32(vextq_u32::<2>(rev, rev))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x4<const L: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp1q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u32(vuzp2q_u32(
+                    vreinterpretq_u32_u8(a.to_bytes().into()),
+                    vreinterpretq_u32_u8(b.to_bytes().into()),
+                ))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x4(self, a: mask32x4<Self>) -> (mask32x2<Self>, mask32x2<Self>) {
+        unsafe {
+            let a = a.into();
+            (
+                vget_low_s32(a).simd_into(self),
+                vget_high_s32(a).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x4(self, a: mask32x4<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x4(self, a: mask32x4<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x4(self, a: mask32x4<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x4(self, a: mask32x4<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        <int32x4_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x4(self, a: mask32x4<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x4(self, a: mask32x4<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x4(self, a: mask32x4<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_f64x2(self, val: f64) -> f64x2<Self> {
+        unsafe { vdupq_n_f64(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vabsq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vnegq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vsqrtq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrecpeq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrsqrteq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_f64x2(self, a: f64x2<Self>, steps: u32) -> f64x2<Self> {
+        let (estimate, x): (f64x2<Self>, f64x2<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrecpeq_f64(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmulq_f64(x, vrecpsq_f64(a, x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f64x2(f64::MIN_POSITIVE);
+        let magnitude = self.abs_f64x2(estimate);
+        let out_of_range = self.or_mask64x2(
+            self.or_mask64x2(
+                self.simd_lt_f64x2(self.abs_f64x2(a), min_positive),
+                self.simd_lt_f64x2(magnitude, min_positive),
+            ),
+            self.simd_eq_f64x2(magnitude, self.splat_f64x2(f64::INFINITY)),
+        );
+        if self.any_mask64x2(out_of_range) {
+            let exact = self.div_f64x2(self.splat_f64x2(1.0), a);
+            self.select_f64x2(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn rsqrt_f64x2(self, a: f64x2<Self>, steps: u32) -> f64x2<Self> {
+        let (estimate, x): (f64x2<Self>, f64x2<Self>) = unsafe {
+            let a = a.into();
+            let estimate = vrsqrteq_f64(a);
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = vmulq_f64(x, vrsqrtsq_f64(vmulq_f64(a, x), x));
+            }
+            (estimate.simd_into(self), x.simd_into(self))
+        };
+        let min_positive = self.splat_f64x2(f64::MIN_POSITIVE);
+        let magnitude = self.abs_f64x2(estimate);
+        let out_of_range = self.or_mask64x2(
+            self.or_mask64x2(
+                self.simd_lt_f64x2(self.abs_f64x2(a), min_positive),
+                self.simd_lt_f64x2(magnitude, min_positive),
+            ),
+            self.simd_eq_f64x2(magnitude, self.splat_f64x2(f64::INFINITY)),
+        );
+        if self.any_mask64x2(out_of_range) {
+            let exact = self.div_f64x2(self.splat_f64x2(1.0), self.sqrt_f64x2(a));
+            self.select_f64x2(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vaddq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vsubq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vmulq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vdivq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn copysign_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let sign_mask = vdupq_n_u64(1 << 63);
+            vbslq_f64(sign_mask, b.into(), a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vceqq_f64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            vreinterpretq_s64_u64(veorq_u64(
+                vceqq_f64(a.into(), b.into()),
+                vdupq_n_u64(u64::MAX),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_lt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcltq_f64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcleq_f64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgeq_f64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgtq_f64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_ne_f64x2(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_eq_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        let abs = self.abs_f64x2(a);
+        self.and_mask64x2(
+            self.simd_lt_f64x2(abs, self.splat_f64x2(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x2(abs, self.splat_f64x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(
+            self.copysign_f64x2(self.splat_f64x2(1.0), a),
+            self.splat_f64x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_f64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_f64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vmaxq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vmaxnmq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vminq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vminnmq_f64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn madd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vfmaq_f64(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn msub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vfmsq_f64(a.into(), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn nmadd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.madd_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.msub_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vfmaq_f64(c.into(), a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.madd_f64x2(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrndmq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrndpq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrndaq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrndnq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let c1 = vcvtq_s64_f64(a.into());
+            let c2 = vcvtq_f64_s64(c1);
+            vsubq_f64(a.into(), c2).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vrndq_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { vbslq_f64(vreinterpretq_u64_s64(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe { vaddvq_f64(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe { vminnmvq_f64(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_max_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe { vmaxnmvq_f64(a.into()) }
+    }
+    #[inline(always)]
+    fn shuffle_f64x2<I: Indices<2>>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f64x2<I: Indices<2>>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u64_u8(a.to_bytes().into());
+                let rev = lanes;
+                vreinterpretq_u8_u64(vextq_u64::<1>(rev, rev))
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x2<const L: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp1q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp2q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
+        let mut result = [0.0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x2(self, a: f64x2<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x2(self, a: f64x2<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x2(self, a: f64x2<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x2(self, a: f64x2<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x2(self, a: f64x2<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x2(self, a: f64x2<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x2(self, a: f64x2<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x2(self, a: f64x2<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x2(self, a: f64x2<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x2(self, a: f64x2<Self>) -> f32x2<Self> {
+        unsafe { vcvt_f32_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_round_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        unsafe { vcvtq_s64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        unsafe { vcvtnq_s64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        unsafe { vcvtq_u64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        unsafe { vcvtnq_u64_f64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_i64x2(self, val: i64) -> i64x2<Self> {
+        unsafe { vdupq_n_s64(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe { veorq_s64(a.into(), vdupq_n_s64(!0)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vaddq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vsubq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = vreinterpretq_u64_s64(a.into());
+            let b = vreinterpretq_u64_s64(b.into());
+            let cross = vmulq_u32(
+                vreinterpretq_u32_u64(b),
+                vrev64q_u32(vreinterpretq_u32_u64(a)),
+            );
+            let high = vshlq_n_u64::<32>(vpaddlq_u32(cross));
+            let low = vmull_u32(vmovn_u64(a), vmovn_u64(b));
+            vreinterpretq_s64_u64(vaddq_u64(high, low)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vandq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vorrq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { veorq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i64x2(self, val: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        unsafe { vshlq_s64(val.into(), vdupq_n_s64(shift.min(64) as i64)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i64x2(self, val: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        unsafe { vshlq_s64(val.into(), vdupq_n_s64(-(shift.min(64) as i64))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let count = vreinterpretq_u64_s64(b.into());
+            let width = vdupq_n_u64(64);
+            vshlq_s64(
+                a.into(),
+                vreinterpretq_s64_u64(vbslq_u64(vcgtq_u64(count, width), width, count)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let count = vreinterpretq_u64_s64(b.into());
+            let width = vdupq_n_u64(64);
+            vshlq_s64(
+                a.into(),
+                vnegq_s64(vreinterpretq_s64_u64(vbslq_u64(
+                    vcgtq_u64(count, width),
+                    width,
+                    count,
+                ))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vceqq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcltq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcleq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgeq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgtq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_s64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_s64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i64x2(self, a: mask64x2<Self>, b: i64x2<Self>, c: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vbslq_s64(vreinterpretq_u64_s64(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            vbslq_s64(vcltq_s64(a, b), a, b).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn max_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            vbslq_s64(vcgtq_s64(a, b), a, b).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reduce_add_i64x2(self, a: i64x2<Self>) -> i64 {
+        unsafe { vaddvq_s64(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_i64x2(self, a: i64x2<Self>) -> i64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        (chunks[0] & chunks[1]) as i64
+    }
+    #[inline(always)]
+    fn reduce_or_i64x2(self, a: i64x2<Self>) -> i64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        (chunks[0] | chunks[1]) as i64
+    }
+    #[inline(always)]
+    fn shuffle_i64x2<I: Indices<2>>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i64x2<I: Indices<2>>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u64_u8(a.to_bytes().into());
+                let rev = lanes;
+                vreinterpretq_u8_u64(vextq_u64::<1>(rev, rev))
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x2<const L: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp1q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp2q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vabsq_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe { vnegq_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        self.sub_i64x2(self.max_i64x2(a, b), self.min_i64x2(a, b))
+            .bitcast()
+    }
+    #[inline(always)]
+    fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        unsafe { vmovn_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        unsafe { vqmovn_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x2(self, a: i64x2<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x2(self, a: i64x2<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x2(self, a: i64x2<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x2(self, a: i64x2<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x2(self, a: i64x2<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x2(self, a: i64x2<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x2(self, a: i64x2<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x2(self, a: i64x2<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x2(self, a: i64x2<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x2(self, a: i64x2<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x2(self, a: i64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        unsafe { vcvtq_f64_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_u64x2(self, val: u64) -> u64x2<Self> {
+        unsafe { vdupq_n_u64(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe { veorq_u64(a.into(), vdupq_n_u64(!0)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { vaddq_u64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { vsubq_u64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let cross = vmulq_u32(
+                vreinterpretq_u32_u64(b),
+                vrev64q_u32(vreinterpretq_u32_u64(a)),
+            );
+            let high = vshlq_n_u64::<32>(vpaddlq_u32(cross));
+            let low = vmull_u32(vmovn_u64(a), vmovn_u64(b));
+            vaddq_u64(high, low).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { vandq_u64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { vorrq_u64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { veorq_u64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u64x2(self, val: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        unsafe { vshlq_u64(val.into(), vdupq_n_s64(shift.min(64) as i64)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u64x2(self, val: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        unsafe { vshlq_u64(val.into(), vdupq_n_s64(-(shift.min(64) as i64))).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u64(64);
+            vshlq_u64(
+                a.into(),
+                vreinterpretq_s64_u64(vbslq_u64(vcgtq_u64(count, width), width, count)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let count = b.into();
+            let width = vdupq_n_u64(64);
+            vshlq_u64(
+                a.into(),
+                vnegq_s64(vreinterpretq_s64_u64(vbslq_u64(
+                    vcgtq_u64(count, width),
+                    width,
+                    count,
+                ))),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vceqq_u64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcltq_u64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcleq_u64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgeq_u64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vcgtq_u64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip1q_u64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let x = a.into();
+        let y = b.into();
+        unsafe { vzip2q_u64(x, y).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u64x2(self, a: mask64x2<Self>, b: u64x2<Self>, c: u64x2<Self>) -> u64x2<Self> {
+        unsafe { vbslq_u64(vreinterpretq_u64_s64(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            vbslq_u64(vcltq_u64(a, b), a, b).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn max_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            vbslq_u64(vcgtq_u64(a, b), a, b).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reduce_add_u64x2(self, a: u64x2<Self>) -> u64 {
+        unsafe { vaddvq_u64(a.into()) }
+    }
+    #[inline(always)]
+    fn reduce_min_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_u64x2(self, a: u64x2<Self>) -> u64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        chunks[0] & chunks[1]
+    }
+    #[inline(always)]
+    fn reduce_or_u64x2(self, a: u64x2<Self>) -> u64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        chunks[0] | chunks[1]
+    }
+    #[inline(always)]
+    fn shuffle_u64x2<I: Indices<2>>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u64x2<I: Indices<2>>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u64_u8(a.to_bytes().into());
+                let rev = lanes;
+                vreinterpretq_u8_u64(vextq_u64::<1>(rev, rev))
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x2<const L: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp1q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp2q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_diff_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        self.sub_u64x2(self.max_u64x2(a, b), self.min_u64x2(a, b))
+    }
+    #[inline(always)]
+    fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x2(self, a: u64x2<Self>) -> f16x8<Self> {
+        unsafe { vreinterpretq_u16_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x2(self, a: u64x2<Self>) -> bf16x8<Self> {
+        unsafe { vreinterpretq_u16_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x2(self, a: u64x2<Self>) -> f32x4<Self> {
+        unsafe { vreinterpretq_f32_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        unsafe { vreinterpretq_f64_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x2(self, a: u64x2<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x2(self, a: u64x2<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x2(self, a: u64x2<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x2(self, a: u64x2<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x2(self, a: u64x2<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x2(self, a: u64x2<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x2(self, a: u64x2<Self>) -> i64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x2(self, a: u64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        unsafe { vcvtq_f64_u64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_mask64x2(self, val: i64) -> mask64x2<Self> {
+        unsafe { vdupq_n_s64(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { veorq_s64(a.into(), vdupq_n_s64(!0)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { vandq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { vorrq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { veorq_s64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        unsafe { vbslq_s64(vreinterpretq_u64_s64(a.into()), b.into(), c.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { vreinterpretq_s64_u64(vceqq_s64(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask64x2(self, a: mask64x2<Self>) -> bool {
+        unsafe { vmaxvq_u32(vreinterpretq_u32_s64(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask64x2(self, a: mask64x2<Self>) -> bool {
+        unsafe { vminvq_u32(vreinterpretq_u32_s64(a.into())) != 0 }
+    }
+    #[inline(always)]
+    fn none_mask64x2(self, a: mask64x2<Self>) -> bool {
+        !self.any_mask64x2(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x2(self, a: mask64x2<Self>) -> usize {
+        self.to_bitmask_mask64x2(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x2(self, a: mask64x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x2(self, a: mask64x2<Self>) -> u64 {
+        unsafe {
+            let weights: [u64; 2] = [1, 2];
+            let bits = vandq_u64(vreinterpretq_u64_s64(a.into()), vld1q_u64(weights.as_ptr()));
+            vaddvq_u64(bits)
+        }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
+        let weights: [u64; 2] = [1, 2];
+        unsafe {
+            vreinterpretq_s64_u64(vtstq_u64(vdupq_n_u64(bits), vld1q_u64(weights.as_ptr())))
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask64x2<I: Indices<2>>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x2<I: Indices<2>>(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+                let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+                vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                let lanes = vreinterpretq_u64_u8(a.to_bytes().into());
+                let rev = lanes;
+                vreinterpretq_u8_u64(vextq_u64::<1>(rev, rev))
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x2<const L: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp1q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                vreinterpretq_u8_u64(vuzp2q_u64(
+                    vreinterpretq_u64_u8(a.to_bytes().into()),
+                    vreinterpretq_u64_u8(b.to_bytes().into()),
+                ))
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x2(self, a: mask64x2<Self>) -> i8x16<Self> {
+        unsafe { vreinterpretq_s8_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x2(self, a: mask64x2<Self>) -> u8x16<Self> {
+        unsafe { vreinterpretq_u8_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x2(self, a: mask64x2<Self>) -> i16x8<Self> {
+        unsafe { vreinterpretq_s16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x2(self, a: mask64x2<Self>) -> u16x8<Self> {
+        unsafe { vreinterpretq_u16_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x2(self, a: mask64x2<Self>) -> i32x4<Self> {
+        unsafe { vreinterpretq_s32_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x2(self, a: mask64x2<Self>) -> u32x4<Self> {
+        unsafe { vreinterpretq_u32_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        <int64x2_t>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x2(self, a: mask64x2<Self>) -> u64x2<Self> {
+        unsafe { vreinterpretq_u64_s64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn splat_f16x16(self, a: f16) -> f16x16<Self> {
+        let half = self.splat_f16x8(a);
+        self.combine_f16x8(half, half)
+    }
+    #[inline(always)]
+    fn abs_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.abs_f16x8(a0), self.abs_f16x8(a1))
+    }
+    #[inline(always)]
+    fn neg_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.neg_f16x8(a0), self.neg_f16x8(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.sqrt_f16x8(a0), self.sqrt_f16x8(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.recip_approx_f16x8(a0), self.recip_approx_f16x8(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.rsqrt_approx_f16x8(a0), self.rsqrt_approx_f16x8(a1))
+    }
+    #[inline(always)]
+    fn recip_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.recip_f16x8(a0, steps), self.recip_f16x8(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.rsqrt_f16x8(a0, steps), self.rsqrt_f16x8(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.add_f16x8(a0, b0), self.add_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.sub_f16x8(a0, b0), self.sub_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.mul_f16x8(a0, b0), self.mul_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.div_f16x8(a0, b0), self.div_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.copysign_f16x8(a0, b0), self.copysign_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_eq_f16x8(a0, b0), self.simd_eq_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_ne_f16x8(a0, b0), self.simd_ne_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_lt_f16x8(a0, b0), self.simd_lt_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_le_f16x8(a0, b0), self.simd_le_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_ge_f16x8(a0, b0), self.simd_ge_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_mask16x8(self.simd_gt_f16x8(a0, b0), self.simd_gt_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_ne_f16x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_infinite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_eq_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        let abs = self.abs_f16x16(a);
+        self.and_mask16x16(
+            self.simd_lt_f16x16(abs, self.splat_f16x16(f16::from_bits(0x0400))),
+            self.simd_gt_f16x16(abs, self.splat_f16x16(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.copysign_f16x16(self.splat_f16x16(f16::from_bits(0x3c00)), a),
+            self.splat_f16x16(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, _) = self.split_f16x16(a);
+        let (b0, _) = self.split_f16x16(b);
+        self.combine_f16x8(self.zip_low_f16x8(a0, b0), self.zip_high_f16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (_, a1) = self.split_f16x16(a);
+        let (_, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.zip_low_f16x8(a1, b1), self.zip_high_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.max_f16x8(a0, b0), self.max_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(
+            self.max_precise_f16x8(a0, b0),
+            self.max_precise_f16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.min_f16x8(a0, b0), self.min_f16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(
+            self.min_precise_f16x8(a0, b0),
+            self.min_precise_f16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(self.madd_f16x8(a0, b0, c0), self.madd_f16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(self.msub_f16x8(a0, b0, c0), self.msub_f16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(self.nmadd_f16x8(a0, b0, c0), self.nmadd_f16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(self.nmsub_f16x8(a0, b0, c0), self.nmsub_f16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(
+            self.mul_add_fused_f16x8(a0, b0, c0),
+            self.mul_add_fused_f16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(
+            self.mul_add_fast_f16x8(a0, b0, c0),
+            self.mul_add_fast_f16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.floor_f16x8(a0), self.floor_f16x8(a1))
+    }
+    #[inline(always)]
+    fn ceil_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.ceil_f16x8(a0), self.ceil_f16x8(a1))
+    }
+    #[inline(always)]
+    fn round_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.round_f16x8(a0), self.round_f16x8(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(
+            self.round_ties_even_f16x8(a0),
+            self.round_ties_even_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.fract_f16x8(a0), self.fract_f16x8(a1))
+    }
+    #[inline(always)]
+    fn trunc_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.trunc_f16x8(a0), self.trunc_f16x8(a1))
+    }
+    #[inline(always)]
+    fn select_f16x16(self, a: mask16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        let (c0, c1) = self.split_f16x16(c);
+        self.combine_f16x8(self.select_f16x8(a0, b0, c0), self.select_f16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x16(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x16<I: Indices<16>>(self, a: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f16x16<I: Indices<16>>(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.reverse_f16x8(a1), self.reverse_f16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f16x8::<K>(x),
+            self.rotate_lanes_left_f16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_f16x8(
+            self.select_f16x8(first, x, y),
+            self.select_f16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f16x8::<K>(x),
+            self.rotate_lanes_right_f16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_f16x8(
+            self.select_f16x8(first, y, x),
+            self.select_f16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x16<const L: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let lanes = self.broadcast_lane_f16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_f16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.unzip_low_f16x8(a0, a1), self.unzip_low_f16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.unzip_high_f16x8(a0, a1), self.unzip_high_f16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f16x16(self, a: f16x16<Self>) -> (f16x8<Self>, f16x8<Self>) {
+        let mut b0 = [f16::from_bits(0); 8usize];
+        let mut b1 = [f16::from_bits(0); 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x16(self, a: f16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f16x8(a0),
+            self.reinterpret_bf16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x16(self, a: f16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f16x8(a0),
+            self.reinterpret_f32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x16(self, a: f16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f16x8(a0),
+            self.reinterpret_f64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x16(self, a: f16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_f16x8(a0), self.reinterpret_i8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x16(self, a: f16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_f16x8(a0), self.reinterpret_u8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f16x8(a0),
+            self.reinterpret_i16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f16x8(a0),
+            self.reinterpret_u16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x16(self, a: f16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f16x8(a0),
+            self.reinterpret_i32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x16(self, a: f16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f16x8(a0),
+            self.reinterpret_u32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x16(self, a: f16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f16x8(a0),
+            self.reinterpret_i64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x16(self, a: f16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f16x8(a0),
+            self.reinterpret_u64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x16(self, a: f16x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f32x8(self.cvt_f32_f16x8(a0), self.cvt_f32_f16x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i8_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x16(self, val: bf16) -> bf16x16<Self> {
+        self.splat_u16x16(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x16(
+        self,
+        a: mask16x16<Self>,
+        b: bf16x16<Self>,
+        c: bf16x16<Self>,
+    ) -> bf16x16<Self> {
+        self.select_u16x16(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_bf16x16(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_bf16x8(self.reverse_bf16x8(a1), self.reverse_bf16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_bf16x8::<K>(x),
+            self.rotate_lanes_left_bf16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_bf16x8(
+            self.select_bf16x8(first, x, y),
+            self.select_bf16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_bf16x8::<K>(x),
+            self.rotate_lanes_right_bf16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_bf16x8(
+            self.select_bf16x8(first, y, x),
+            self.select_bf16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x16<const L: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let lanes = self.broadcast_lane_bf16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_bf16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        self.combine_bf16x8(self.unzip_low_bf16x8(a0, a1), self.unzip_low_bf16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        self.combine_bf16x8(
+            self.unzip_high_bf16x8(a0, a1),
+            self.unzip_high_bf16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_bf16x16(self, a: bf16x16<Self>) -> (bf16x8<Self>, bf16x8<Self>) {
+        let (a0, a1) = self.split_u16x16(a.bitcast());
+        (a0.bitcast(), a1.bitcast())
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x16(self, a: bf16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_bf16x8(a0),
+            self.reinterpret_f16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x16(self, a: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_bf16x8(a0),
+            self.reinterpret_f32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x16(self, a: bf16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_bf16x8(a0),
+            self.reinterpret_f64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x16(self, a: bf16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_bf16x8(a0),
+            self.reinterpret_i8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x16(self, a: bf16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_bf16x8(a0),
+            self.reinterpret_u8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x16(self, a: bf16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_bf16x8(a0),
+            self.reinterpret_i16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x16(self, a: bf16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_bf16x8(a0),
+            self.reinterpret_u16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x16(self, a: bf16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_bf16x8(a0),
+            self.reinterpret_i32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x16(self, a: bf16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_bf16x8(a0),
+            self.reinterpret_u32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x16(self, a: bf16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_bf16x8(a0),
+            self.reinterpret_i64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x16(self, a: bf16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_bf16x8(a0),
+            self.reinterpret_u64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x16(self, a: bf16x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f32x8(self.cvt_f32_bf16x8(a0), self.cvt_f32_bf16x8(a1))
+    }
+    #[inline(always)]
+    fn splat_f32x8(self, a: f32) -> f32x8<Self> {
+        let half = self.splat_f32x4(a);
+        self.combine_f32x4(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.abs_f32x4(a0), self.abs_f32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.neg_f32x4(a0), self.neg_f32x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.sqrt_f32x4(a0), self.sqrt_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_approx_f32x4(a0), self.recip_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_approx_f32x4(a0), self.rsqrt_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_f32x4(a0, steps), self.recip_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_f32x4(a0, steps), self.rsqrt_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.add_f32x4(a0, b0), self.add_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.sub_f32x4(a0, b0), self.sub_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.mul_f32x4(a0, b0), self.mul_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.div_f32x4(a0, b0), self.div_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.copysign_f32x4(a0, b0), self.copysign_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_eq_f32x4(a0, b0), self.simd_eq_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ne_f32x4(a0, b0), self.simd_ne_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_lt_f32x4(a0, b0), self.simd_lt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_le_f32x4(a0, b0), self.simd_le_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ge_f32x4(a0, b0), self.simd_ge_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_gt_f32x4(a0, b0), self.simd_gt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_ne_f32x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_eq_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        let abs = self.abs_f32x8(a);
+        self.and_mask32x8(
+            self.simd_lt_f32x8(abs, self.splat_f32x8(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x8(abs, self.splat_f32x8(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(
+            self.copysign_f32x8(self.splat_f32x8(1.0), a),
+            self.splat_f32x8(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, _) = self.split_f32x8(a);
+        let (b0, _) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a0, b0), self.zip_high_f32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (_, a1) = self.split_f32x8(a);
+        let (_, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a1, b1), self.zip_high_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.max_f32x4(a0, b0), self.max_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.max_precise_f32x4(a0, b0),
+            self.max_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.min_f32x4(a0, b0), self.min_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.min_precise_f32x4(a0, b0),
+            self.min_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.madd_f32x4(a0, b0, c0), self.madd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.msub_f32x4(a0, b0, c0), self.msub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmadd_f32x4(a0, b0, c0), self.nmadd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmsub_f32x4(a0, b0, c0), self.nmsub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fused_f32x4(a0, b0, c0),
+            self.mul_add_fused_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fast_f32x4(a0, b0, c0),
+            self.mul_add_fast_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.ceil_f32x4(a0), self.ceil_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.round_f32x4(a0), self.round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(
+            self.round_ties_even_f32x4(a0),
+            self.round_ties_even_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.fract_f32x4(a0), self.fract_f32x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.trunc_f32x4(a0), self.trunc_f32x4(a1))
+    }
+    #[inline(always)]
+    fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        self.reduce_add_f32x4(a0) + self.reduce_add_f32x4(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_min_f32x4(self.min_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_max_f32x4(self.max_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x8<I: Indices<8>>(self, a: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x8<I: Indices<8>>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.reverse_f32x4(a1), self.reverse_f32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f32x4::<K>(x),
+            self.rotate_lanes_left_f32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_f32x4(
+            self.select_f32x4(first, x, y),
+            self.select_f32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f32x4::<K>(x),
+            self.rotate_lanes_right_f32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_f32x4(
+            self.select_f32x4(first, y, x),
+            self.select_f32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x8<const L: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let lanes = self.broadcast_lane_f32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_f32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.unzip_low_f32x4(a0, a1), self.unzip_low_f32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.unzip_high_f32x4(a0, a1), self.unzip_high_f32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
+        let mut result = [0.0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x8(self, a: f32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f32x4(a0),
+            self.reinterpret_f16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x8(self, a: f32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f32x4(a0),
+            self.reinterpret_bf16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x8(self, a: f32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f32x4(a0),
+            self.reinterpret_f64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x8(self, a: f32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_f32x4(a0), self.reinterpret_i8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x8(self, a: f32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_f32x4(a0), self.reinterpret_u8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x8(self, a: f32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f32x4(a0),
+            self.reinterpret_i16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x8(self, a: f32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f32x4(a0),
+            self.reinterpret_u16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f32x4(a0),
+            self.reinterpret_i32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f32x4(a0),
+            self.reinterpret_u32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x8(self, a: f32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f32x4(a0),
+            self.reinterpret_i64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x8(self, a: f32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f32x4(a0),
+            self.reinterpret_u64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x8(self, a: f32x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let a: float32x4x2_t = a.into();
+            let low = self.neon.vcvt_f16_f32(a.0);
+            let high = self.neon.vcvt_f16_f32(a.1);
+            vcombine_u16(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x8(self, a: f32x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x4(self.cvt_f64_f32x4(a0), self.cvt_f64_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_f32x4(a0), self.cvt_i32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_round_f32x4(a0), self.cvt_i32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_f32x4(a0), self.cvt_u32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_round_f32x4(a0), self.cvt_u32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x8(self, a: f32x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let float32x4x2_t(low, high) = a.into();
+            let bits = vreinterpretq_u32_f32(low);
+            let lsb = vandq_u32(vshrq_n_u32::<16>(bits), vdupq_n_u32(1));
+            let rounded = vaddq_u32(vaddq_u32(bits, vdupq_n_u32(0x7fff)), lsb);
+            let quiet = vorrq_u32(bits, vdupq_n_u32(0x0040_0000));
+            let low = vshrn_n_u32::<16>(vbslq_u32(vceqq_f32(low, low), rounded, quiet));
+            let bits = vreinterpretq_u32_f32(high);
+            let lsb = vandq_u32(vshrq_n_u32::<16>(bits), vdupq_n_u32(1));
+            let rounded = vaddq_u32(vaddq_u32(bits, vdupq_n_u32(0x7fff)), lsb);
+            let quiet = vorrq_u32(bits, vdupq_n_u32(0x0040_0000));
+            let high = vshrn_n_u32::<16>(vbslq_u32(vceqq_f32(high, high), rounded, quiet));
+            vcombine_u16(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x8(self, a: f32x8<Self>, b: bf16x16<Self>, c: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        let (c0, c1) = self.split_bf16x16(c);
+        self.combine_f32x4(
+            self.dot_bf16_f32x4(a0, b0, c0),
+            self.dot_bf16_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i8x32(self, a: i8) -> i8x32<Self> {
+        let half = self.splat_i8x16(a);
+        self.combine_i8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.not_i8x16(a0), self.not_i8x16(a1))
+    }
+    #[inline(always)]
+    fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.add_i8x16(a0, b0), self.add_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.sub_i8x16(a0, b0), self.sub_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_i8x16(a0, b0), self.mul_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.and_i8x16(a0, b0), self.and_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.or_i8x16(a0, b0), self.or_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.xor_i8x16(a0, b0), self.xor_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shl_i8x16(a0, b), self.shl_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shr_i8x16(a0, b), self.shr_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.shl_var_i8x16(a0, b0), self.shl_var_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.shr_var_i8x16(a0, b0), self.shr_var_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_eq_i8x16(a0, b0), self.simd_eq_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_lt_i8x16(a0, b0), self.simd_lt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_le_i8x16(a0, b0), self.simd_le_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_ge_i8x16(a0, b0), self.simd_ge_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_gt_i8x16(a0, b0), self.simd_gt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, _) = self.split_i8x32(a);
+        let (b0, _) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a0, b0), self.zip_high_i8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (_, a1) = self.split_i8x32(a);
+        let (_, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a1, b1), self.zip_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        let (c0, c1) = self.split_i8x32(c);
+        self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.min_i8x16(a0, b0), self.min_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_add_i8x16(self.add_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_min_i8x16(self.min_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_max_i8x16(self.max_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_and_i8x16(self.and_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_or_i8x16(self.or_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x32<I: Indices<32>>(self, a: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x32<I: Indices<32>>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.reverse_i8x16(a1), self.reverse_i8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i8x16::<K>(x),
+            self.rotate_lanes_left_i8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_i8x16(
+            self.select_i8x16(first, x, y),
+            self.select_i8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i8x16::<K>(x),
+            self.rotate_lanes_right_i8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_i8x16(
+            self.select_i8x16(first, y, x),
+            self.select_i8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x32<const L: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let lanes = self.broadcast_lane_i8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_i8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.unzip_low_i8x16(a0, a1), self.unzip_low_i8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.unzip_high_i8x16(a0, a1), self.unzip_high_i8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_add_i8x16(a0, b0),
+            self.saturating_add_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_sub_i8x16(a0, b0),
+            self.saturating_sub_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.abs_i8x16(a0), self.abs_i8x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.neg_i8x16(a0), self.neg_i8x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_u8x16(self.abs_diff_i8x16(a0, b0), self.abs_diff_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i16x16(self.mul_widen_i8x16(a0, b0), self.mul_widen_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_high_i8x16(a0, b0), self.mul_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x16(self.widen_i8x16(a0), self.widen_i8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x32(self, a: i8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i8x16(a0),
+            self.reinterpret_f16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x32(self, a: i8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i8x16(a0),
+            self.reinterpret_bf16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x32(self, a: i8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i8x16(a0),
+            self.reinterpret_f32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x32(self, a: i8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i8x16(a0),
+            self.reinterpret_f64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i8x16(a0),
+            self.reinterpret_i16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x32(self, a: i8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i8x16(a0),
+            self.reinterpret_u16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x32(self, a: i8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i8x16(a0),
+            self.reinterpret_i32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x32(self, a: i8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i8x16(a0),
+            self.reinterpret_u32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x32(self, a: i8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i8x16(a0),
+            self.reinterpret_i64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x32(self, a: i8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i8x16(a0),
+            self.reinterpret_u64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x32(self, a: u8) -> u8x32<Self> {
+        let half = self.splat_u8x16(a);
+        self.combine_u8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.not_u8x16(a0), self.not_u8x16(a1))
+    }
+    #[inline(always)]
+    fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.add_u8x16(a0, b0), self.add_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.sub_u8x16(a0, b0), self.sub_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_u8x16(a0, b0), self.mul_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.and_u8x16(a0, b0), self.and_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.or_u8x16(a0, b0), self.or_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.xor_u8x16(a0, b0), self.xor_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shl_u8x16(a0, b), self.shl_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shr_u8x16(a0, b), self.shr_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.shl_var_u8x16(a0, b0), self.shl_var_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.shr_var_u8x16(a0, b0), self.shr_var_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_eq_u8x16(a0, b0), self.simd_eq_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_lt_u8x16(a0, b0), self.simd_lt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_le_u8x16(a0, b0), self.simd_le_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_ge_u8x16(a0, b0), self.simd_ge_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_gt_u8x16(a0, b0), self.simd_gt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, _) = self.split_u8x32(a);
+        let (b0, _) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a0, b0), self.zip_high_u8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (_, a1) = self.split_u8x32(a);
+        let (_, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a1, b1), self.zip_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        let (c0, c1) = self.split_u8x32(c);
+        self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.min_u8x16(a0, b0), self.min_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_add_u8x16(self.add_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_min_u8x16(self.min_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_max_u8x16(self.max_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_and_u8x16(self.and_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_or_u8x16(self.or_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x32<I: Indices<32>>(self, a: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x32<I: Indices<32>>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.reverse_u8x16(a1), self.reverse_u8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u8x16::<K>(x),
+            self.rotate_lanes_left_u8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_u8x16(
+            self.select_u8x16(first, x, y),
+            self.select_u8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u8x16::<K>(x),
+            self.rotate_lanes_right_u8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_u8x16(
+            self.select_u8x16(first, y, x),
+            self.select_u8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x32<const L: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let lanes = self.broadcast_lane_u8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_u8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.unzip_low_u8x16(a0, a1), self.unzip_low_u8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.unzip_high_u8x16(a0, a1), self.unzip_high_u8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_add_u8x16(a0, b0),
+            self.saturating_add_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_sub_u8x16(a0, b0),
+            self.saturating_sub_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.abs_diff_u8x16(a0, b0), self.abs_diff_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.avg_round_u8x16(a0, b0), self.avg_round_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u16x16(self.mul_widen_u8x16(a0, b0), self.mul_widen_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_high_u8x16(a0, b0), self.mul_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x32(self, a: [u8x16<Self>; 3], b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_48_u8x16(a, b0), self.lookup_48_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x32(self, a: u8x64<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_64_u8x16(a, b0), self.lookup_64_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x32(self, a: u8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u8x16(a0),
+            self.reinterpret_f16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x32(self, a: u8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u8x16(a0),
+            self.reinterpret_bf16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x32(self, a: u8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u8x16(a0),
+            self.reinterpret_f32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x32(self, a: u8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u8x16(a0),
+            self.reinterpret_f64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x32(self, a: u8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i8x16(self.reinterpret_i8_u8x16(a0), self.reinterpret_i8_u8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x32(self, a: u8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u8x16(a0),
+            self.reinterpret_i16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u8x16(a0),
+            self.reinterpret_u16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x32(self, a: u8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u8x16(a0),
+            self.reinterpret_i32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u8x16(a0),
+            self.reinterpret_u32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x32(self, a: u8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u8x16(a0),
+            self.reinterpret_i64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x32(self, a: u8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u8x16(a0),
+            self.reinterpret_u64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x32(self, a: i8) -> mask8x32<Self> {
+        let half = self.splat_mask8x16(a);
+        self.combine_mask8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_mask8x16(self.not_mask8x16(a0), self.not_mask8x16(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.and_mask8x16(a0, b0), self.and_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.or_mask8x16(a0, b0), self.or_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.xor_mask8x16(a0, b0), self.xor_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        let (c0, c1) = self.split_mask8x32(c);
+        self.combine_mask8x16(
+            self.select_mask8x16(a0, b0, c0),
+            self.select_mask8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.simd_eq_mask8x16(a0, b0), self.simd_eq_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.any_mask8x16(a0) || self.any_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.all_mask8x16(a0) && self.all_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x32(self, a: mask8x32<Self>) -> bool {
+        !self.any_mask8x32(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x32(self, a: mask8x32<Self>) -> usize {
+        self.to_bitmask_mask8x32(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x32(self, a: mask8x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x32(self, a: mask8x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.to_bitmask_mask8x16(a0) | (self.to_bitmask_mask8x16(a1) << 16)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
+        self.combine_mask8x16(
+            self.from_bitmask_mask8x16(bits),
+            self.from_bitmask_mask8x16(bits >> 16),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x32<I: Indices<32>>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x32<I: Indices<32>>(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_mask8x16(self.reverse_mask8x16(a1), self.reverse_mask8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask8x16::<K>(x),
+            self.rotate_lanes_left_mask8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_mask8x16(
+            self.select_mask8x16(first, x, y),
+            self.select_mask8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask8x16::<K>(x),
+            self.rotate_lanes_right_mask8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_mask8x16(
+            self.select_mask8x16(first, y, x),
+            self.select_mask8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x32<const L: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let lanes = self.broadcast_lane_mask8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_mask8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(
+            self.unzip_low_mask8x16(a0, a1),
+            self.unzip_low_mask8x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(
+            self.unzip_high_mask8x16(a0, a1),
+            self.unzip_high_mask8x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask8x16(a0),
+            self.reinterpret_i8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x32(self, a: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask8x16(a0),
+            self.reinterpret_u8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x32(self, a: mask8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask8x16(a0),
+            self.reinterpret_i16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x32(self, a: mask8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask8x16(a0),
+            self.reinterpret_u16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x32(self, a: mask8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask8x16(a0),
+            self.reinterpret_i32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x32(self, a: mask8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask8x16(a0),
+            self.reinterpret_u32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x32(self, a: mask8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask8x16(a0),
+            self.reinterpret_i64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x32(self, a: mask8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask8x16(a0),
+            self.reinterpret_u64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x16(self, a: i16) -> i16x16<Self> {
+        let half = self.splat_i16x8(a);
+        self.combine_i16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.not_i16x8(a0), self.not_i16x8(a1))
+    }
+    #[inline(always)]
+    fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.add_i16x8(a0, b0), self.add_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.sub_i16x8(a0, b0), self.sub_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_i16x8(a0, b0), self.mul_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.and_i16x8(a0, b0), self.and_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.or_i16x8(a0, b0), self.or_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.xor_i16x8(a0, b0), self.xor_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shl_i16x8(a0, b), self.shl_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shr_i16x8(a0, b), self.shr_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.shl_var_i16x8(a0, b0), self.shl_var_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.shr_var_i16x8(a0, b0), self.shr_var_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_eq_i16x8(a0, b0), self.simd_eq_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_lt_i16x8(a0, b0), self.simd_lt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_le_i16x8(a0, b0), self.simd_le_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_ge_i16x8(a0, b0), self.simd_ge_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_gt_i16x8(a0, b0), self.simd_gt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, _) = self.split_i16x16(a);
+        let (b0, _) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a0, b0), self.zip_high_i16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (_, a1) = self.split_i16x16(a);
+        let (_, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a1, b1), self.zip_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        let (c0, c1) = self.split_i16x16(c);
+        self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.min_i16x8(a0, b0), self.min_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_add_i16x8(self.add_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_min_i16x8(self.min_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_max_i16x8(self.max_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_and_i16x8(self.and_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_or_i16x8(self.or_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x16<I: Indices<16>>(self, a: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x16<I: Indices<16>>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.reverse_i16x8(a1), self.reverse_i16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i16x8::<K>(x),
+            self.rotate_lanes_left_i16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_i16x8(
+            self.select_i16x8(first, x, y),
+            self.select_i16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i16x8::<K>(x),
+            self.rotate_lanes_right_i16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_i16x8(
+            self.select_i16x8(first, y, x),
+            self.select_i16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x16<const L: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let lanes = self.broadcast_lane_i16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_i16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.unzip_low_i16x8(a0, a1), self.unzip_low_i16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.unzip_high_i16x8(a0, a1), self.unzip_high_i16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_add_i16x8(a0, b0),
+            self.saturating_add_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_sub_i16x8(a0, b0),
+            self.saturating_sub_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.abs_i16x8(a0), self.abs_i16x8(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.neg_i16x8(a0), self.neg_i16x8(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_u16x8(self.abs_diff_i16x8(a0, b0), self.abs_diff_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x8(self.mul_widen_i16x8(a0, b0), self.mul_widen_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_high_i16x8(a0, b0), self.mul_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.mul_q15_round_sat_i16x8(a0, b0),
+            self.mul_q15_round_sat_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x4(self.dot_i16_i16x8(a0, b0), self.dot_i16_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x8(self.widen_i16x8(a0), self.widen_i16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let a: int16x8x2_t = a.into();
+            vcombine_s8(vmovn_s16(a.0), vmovn_s16(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let a: int16x8x2_t = a.into();
+            vcombine_s8(vqmovn_s16(a.0), vqmovn_s16(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i16x8(a0),
+            self.reinterpret_f16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x16(self, a: i16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i16x8(a0),
+            self.reinterpret_bf16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x16(self, a: i16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i16x8(a0),
+            self.reinterpret_f32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x16(self, a: i16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i16x8(a0),
+            self.reinterpret_f64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x16(self, a: i16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_i16x8(a0), self.reinterpret_i8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x16(self, a: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i16x8(a0),
+            self.reinterpret_u16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i16x8(a0),
+            self.reinterpret_i32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x16(self, a: i16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i16x8(a0),
+            self.reinterpret_u32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x16(self, a: i16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i16x8(a0),
+            self.reinterpret_i64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x16(self, a: i16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i16x8(a0),
+            self.reinterpret_u64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x16(self, a: i16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i32x16(self.widen_i16x16(a))
+    }
+    #[inline(always)]
+    fn splat_u16x16(self, a: u16) -> u16x16<Self> {
+        let half = self.splat_u16x8(a);
+        self.combine_u16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.not_u16x8(a0), self.not_u16x8(a1))
+    }
+    #[inline(always)]
+    fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.add_u16x8(a0, b0), self.add_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.sub_u16x8(a0, b0), self.sub_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_u16x8(a0, b0), self.mul_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.and_u16x8(a0, b0), self.and_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.or_u16x8(a0, b0), self.or_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.xor_u16x8(a0, b0), self.xor_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shl_u16x8(a0, b), self.shl_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shr_u16x8(a0, b), self.shr_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.shl_var_u16x8(a0, b0), self.shl_var_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.shr_var_u16x8(a0, b0), self.shr_var_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_eq_u16x8(a0, b0), self.simd_eq_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_lt_u16x8(a0, b0), self.simd_lt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_le_u16x8(a0, b0), self.simd_le_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_ge_u16x8(a0, b0), self.simd_ge_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_gt_u16x8(a0, b0), self.simd_gt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, _) = self.split_u16x16(a);
+        let (b0, _) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a0, b0), self.zip_high_u16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (_, a1) = self.split_u16x16(a);
+        let (_, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a1, b1), self.zip_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        let (c0, c1) = self.split_u16x16(c);
+        self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.min_u16x8(a0, b0), self.min_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_add_u16x8(self.add_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_min_u16x8(self.min_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_max_u16x8(self.max_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_and_u16x8(self.and_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_or_u16x8(self.or_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x16<I: Indices<16>>(self, a: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x16<I: Indices<16>>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.reverse_u16x8(a1), self.reverse_u16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u16x8::<K>(x),
+            self.rotate_lanes_left_u16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_u16x8(
+            self.select_u16x8(first, x, y),
+            self.select_u16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u16x8::<K>(x),
+            self.rotate_lanes_right_u16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_u16x8(
+            self.select_u16x8(first, y, x),
+            self.select_u16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x16<const L: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let lanes = self.broadcast_lane_u16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_u16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.unzip_low_u16x8(a0, a1), self.unzip_low_u16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.unzip_high_u16x8(a0, a1), self.unzip_high_u16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_add_u16x8(a0, b0),
+            self.saturating_add_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_sub_u16x8(a0, b0),
+            self.saturating_sub_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.abs_diff_u16x8(a0, b0), self.abs_diff_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.avg_round_u16x8(a0, b0), self.avg_round_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u32x8(self.mul_widen_u16x8(a0, b0), self.mul_widen_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_high_u16x8(a0, b0), self.mul_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x8(self.widen_u16x8(a0), self.widen_u16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let a: uint16x8x2_t = a.into();
+            vcombine_u8(vmovn_u16(a.0), vmovn_u16(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let a: uint16x8x2_t = a.into();
+            vcombine_u8(vqmovn_u16(a.0), vqmovn_u16(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u16x8(a0),
+            self.reinterpret_f16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x16(self, a: u16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u16x8(a0),
+            self.reinterpret_bf16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x16(self, a: u16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u16x8(a0),
+            self.reinterpret_f32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x16(self, a: u16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u16x8(a0),
+            self.reinterpret_f64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x16(self, a: u16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_u16x8(a0), self.reinterpret_i8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x16(self, a: u16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u16x8(a0),
+            self.reinterpret_i16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x16(self, a: u16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u16x8(a0),
+            self.reinterpret_i32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u16x8(a0),
+            self.reinterpret_u32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x16(self, a: u16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u16x8(a0),
+            self.reinterpret_i64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x16(self, a: u16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u16x8(a0),
+            self.reinterpret_u64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x16(self, a: u16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u32x16(self.widen_u16x16(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x16(self, a: i16) -> mask16x16<Self> {
+        let half = self.splat_mask16x8(a);
+        self.combine_mask16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_mask16x8(self.not_mask16x8(a0), self.not_mask16x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.and_mask16x8(a0, b0), self.and_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.or_mask16x8(a0, b0), self.or_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.xor_mask16x8(a0, b0), self.xor_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        let (c0, c1) = self.split_mask16x16(c);
+        self.combine_mask16x8(
+            self.select_mask16x8(a0, b0, c0),
+            self.select_mask16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.simd_eq_mask16x8(a0, b0), self.simd_eq_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.any_mask16x8(a0) || self.any_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn all_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.all_mask16x8(a0) && self.all_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn none_mask16x16(self, a: mask16x16<Self>) -> bool {
+        !self.any_mask16x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x16(self, a: mask16x16<Self>) -> usize {
+        self.to_bitmask_mask16x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x16(self, a: mask16x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x16(self, a: mask16x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.to_bitmask_mask16x8(a0) | (self.to_bitmask_mask16x8(a1) << 8)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
+        self.combine_mask16x8(
+            self.from_bitmask_mask16x8(bits),
+            self.from_bitmask_mask16x8(bits >> 8),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask16x16<I: Indices<16>>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x16<I: Indices<16>>(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_mask16x8(self.reverse_mask16x8(a1), self.reverse_mask16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask16x8::<K>(x),
+            self.rotate_lanes_left_mask16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_mask16x8(
+            self.select_mask16x8(first, x, y),
+            self.select_mask16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask16x8::<K>(x),
+            self.rotate_lanes_right_mask16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_mask16x8(
+            self.select_mask16x8(first, y, x),
+            self.select_mask16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x16<const L: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let lanes = self.broadcast_lane_mask16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_mask16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(
+            self.unzip_low_mask16x8(a0, a1),
+            self.unzip_low_mask16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(
+            self.unzip_high_mask16x8(a0, a1),
+            self.unzip_high_mask16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x16(self, a: mask16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask16x8(a0),
+            self.reinterpret_i8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x16(self, a: mask16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask16x8(a0),
+            self.reinterpret_u8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask16x8(a0),
+            self.reinterpret_i16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x16(self, a: mask16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask16x8(a0),
+            self.reinterpret_u16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x16(self, a: mask16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask16x8(a0),
+            self.reinterpret_i32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x16(self, a: mask16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask16x8(a0),
+            self.reinterpret_u32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x16(self, a: mask16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask16x8(a0),
+            self.reinterpret_i64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x16(self, a: mask16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask16x8(a0),
+            self.reinterpret_u64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x8(self, a: i32) -> i32x8<Self> {
+        let half = self.splat_i32x4(a);
+        self.combine_i32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.not_i32x4(a0), self.not_i32x4(a1))
+    }
+    #[inline(always)]
+    fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.add_i32x4(a0, b0), self.add_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.sub_i32x4(a0, b0), self.sub_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.mul_i32x4(a0, b0), self.mul_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.and_i32x4(a0, b0), self.and_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.or_i32x4(a0, b0), self.or_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.xor_i32x4(a0, b0), self.xor_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shl_i32x4(a0, b), self.shl_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shr_i32x4(a0, b), self.shr_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.shl_var_i32x4(a0, b0), self.shl_var_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.shr_var_i32x4(a0, b0), self.shr_var_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_eq_i32x4(a0, b0), self.simd_eq_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_lt_i32x4(a0, b0), self.simd_lt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_le_i32x4(a0, b0), self.simd_le_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_ge_i32x4(a0, b0), self.simd_ge_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_gt_i32x4(a0, b0), self.simd_gt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, _) = self.split_i32x8(a);
+        let (b0, _) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a0, b0), self.zip_high_i32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (_, a1) = self.split_i32x8(a);
+        let (_, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a1, b1), self.zip_high_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        let (c0, c1) = self.split_i32x8(c);
+        self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.min_i32x4(a0, b0), self.min_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_add_i32x4(self.add_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_min_i32x4(self.min_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_max_i32x4(self.max_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_and_i32x4(self.and_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_or_i32x4(self.or_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i32x8<I: Indices<8>>(self, a: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x8<I: Indices<8>>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.reverse_i32x4(a1), self.reverse_i32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i32x4::<K>(x),
+            self.rotate_lanes_left_i32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_i32x4(
+            self.select_i32x4(first, x, y),
+            self.select_i32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i32x4::<K>(x),
+            self.rotate_lanes_right_i32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_i32x4(
+            self.select_i32x4(first, y, x),
+            self.select_i32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x8<const L: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let lanes = self.broadcast_lane_i32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_i32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.unzip_low_i32x4(a0, a1), self.unzip_low_i32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.unzip_high_i32x4(a0, a1), self.unzip_high_i32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.abs_i32x4(a0), self.abs_i32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.neg_i32x4(a0), self.neg_i32x4(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_u32x4(self.abs_diff_i32x4(a0, b0), self.abs_diff_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x4(self.widen_i32x4(a0), self.widen_i32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let a: int32x4x2_t = a.into();
+            vcombine_s16(vmovn_s32(a.0), vmovn_s32(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let a: int32x4x2_t = a.into();
+            vcombine_s16(vqmovn_s32(a.0), vqmovn_s32(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x8(self, a: i32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i32x4(a0),
+            self.reinterpret_f16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x8(self, a: i32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i32x4(a0),
+            self.reinterpret_bf16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i32x4(a0),
+            self.reinterpret_f32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x8(self, a: i32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i32x4(a0),
+            self.reinterpret_f64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x8(self, a: i32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_i32x4(a0), self.reinterpret_i8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x8(self, a: i32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i32x4(a0),
+            self.reinterpret_i16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x8(self, a: i32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i32x4(a0),
+            self.reinterpret_u16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x8(self, a: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i32x4(a0),
+            self.reinterpret_u32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i32x4(a0),
+            self.reinterpret_i64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x8(self, a: i32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i32x4(a0),
+            self.reinterpret_u64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i32x8(self, a: i32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(self.cvt_f32_i32x4(a0), self.cvt_f32_i32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x8(self, a: i32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i64x8(self.widen_i32x8(a))
+    }
+    #[inline(always)]
+    fn splat_u32x8(self, a: u32) -> u32x8<Self> {
+        let half = self.splat_u32x4(a);
+        self.combine_u32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.not_u32x4(a0), self.not_u32x4(a1))
+    }
+    #[inline(always)]
+    fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.add_u32x4(a0, b0), self.add_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.sub_u32x4(a0, b0), self.sub_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.mul_u32x4(a0, b0), self.mul_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.and_u32x4(a0, b0), self.and_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.or_u32x4(a0, b0), self.or_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.xor_u32x4(a0, b0), self.xor_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shl_u32x4(a0, b), self.shl_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shr_u32x4(a0, b), self.shr_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.shl_var_u32x4(a0, b0), self.shl_var_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.shr_var_u32x4(a0, b0), self.shr_var_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_eq_u32x4(a0, b0), self.simd_eq_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_lt_u32x4(a0, b0), self.simd_lt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_le_u32x4(a0, b0), self.simd_le_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_ge_u32x4(a0, b0), self.simd_ge_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_gt_u32x4(a0, b0), self.simd_gt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, _) = self.split_u32x8(a);
+        let (b0, _) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a0, b0), self.zip_high_u32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (_, a1) = self.split_u32x8(a);
+        let (_, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a1, b1), self.zip_high_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        let (c0, c1) = self.split_u32x8(c);
+        self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.min_u32x4(a0, b0), self.min_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_add_u32x4(self.add_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_min_u32x4(self.min_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_max_u32x4(self.max_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_and_u32x4(self.and_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_or_u32x4(self.or_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u32x8<I: Indices<8>>(self, a: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x8<I: Indices<8>>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.reverse_u32x4(a1), self.reverse_u32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u32x4::<K>(x),
+            self.rotate_lanes_left_u32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_u32x4(
+            self.select_u32x4(first, x, y),
+            self.select_u32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u32x4::<K>(x),
+            self.rotate_lanes_right_u32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_u32x4(
+            self.select_u32x4(first, y, x),
+            self.select_u32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x8<const L: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let lanes = self.broadcast_lane_u32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_u32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.unzip_low_u32x4(a0, a1), self.unzip_low_u32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.unzip_high_u32x4(a0, a1), self.unzip_high_u32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.abs_diff_u32x4(a0, b0), self.abs_diff_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x4(self.widen_u32x4(a0), self.widen_u32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let a: uint32x4x2_t = a.into();
+            vcombine_u16(vmovn_u32(a.0), vmovn_u32(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let a: uint32x4x2_t = a.into();
+            vcombine_u16(vqmovn_u32(a.0), vqmovn_u32(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x8(self, a: u32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u32x4(a0),
+            self.reinterpret_f16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x8(self, a: u32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u32x4(a0),
+            self.reinterpret_bf16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u32x4(a0),
+            self.reinterpret_f32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x8(self, a: u32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u32x4(a0),
+            self.reinterpret_f64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x8(self, a: u32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_u32x4(a0), self.reinterpret_i8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x8(self, a: u32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u32x4(a0),
+            self.reinterpret_i16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x8(self, a: u32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u32x4(a0),
+            self.reinterpret_u16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x8(self, a: u32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u32x4(a0),
+            self.reinterpret_i32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x8(self, a: u32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u32x4(a0),
+            self.reinterpret_i64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u32x4(a0),
+            self.reinterpret_u64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u32x8(self, a: u32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(self.cvt_f32_u32x4(a0), self.cvt_f32_u32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x8(self, a: u32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u64x8(self.widen_u32x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x8(self, a: i32) -> mask32x8<Self> {
+        let half = self.splat_mask32x4(a);
+        self.combine_mask32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_mask32x4(self.not_mask32x4(a0), self.not_mask32x4(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.and_mask32x4(a0, b0), self.and_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.or_mask32x4(a0, b0), self.or_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.xor_mask32x4(a0, b0), self.xor_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        let (c0, c1) = self.split_mask32x8(c);
+        self.combine_mask32x4(
+            self.select_mask32x4(a0, b0, c0),
+            self.select_mask32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.simd_eq_mask32x4(a0, b0), self.simd_eq_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.any_mask32x4(a0) || self.any_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn all_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.all_mask32x4(a0) && self.all_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn none_mask32x8(self, a: mask32x8<Self>) -> bool {
+        !self.any_mask32x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x8(self, a: mask32x8<Self>) -> usize {
+        self.to_bitmask_mask32x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x8(self, a: mask32x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x8(self, a: mask32x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.to_bitmask_mask32x4(a0) | (self.to_bitmask_mask32x4(a1) << 4)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
+        self.combine_mask32x4(
+            self.from_bitmask_mask32x4(bits),
+            self.from_bitmask_mask32x4(bits >> 4),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask32x8<I: Indices<8>>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x8<I: Indices<8>>(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_mask32x4(self.reverse_mask32x4(a1), self.reverse_mask32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask32x4::<K>(x),
+            self.rotate_lanes_left_mask32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_mask32x4(
+            self.select_mask32x4(first, x, y),
+            self.select_mask32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask32x4::<K>(x),
+            self.rotate_lanes_right_mask32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_mask32x4(
+            self.select_mask32x4(first, y, x),
+            self.select_mask32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x8<const L: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let lanes = self.broadcast_lane_mask32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_mask32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(
+            self.unzip_low_mask32x4(a0, a1),
+            self.unzip_low_mask32x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(
+            self.unzip_high_mask32x4(a0, a1),
+            self.unzip_high_mask32x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x8(self, a: mask32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask32x4(a0),
+            self.reinterpret_i8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x8(self, a: mask32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask32x4(a0),
+            self.reinterpret_u8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x8(self, a: mask32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask32x4(a0),
+            self.reinterpret_i16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x8(self, a: mask32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask32x4(a0),
+            self.reinterpret_u16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask32x4(a0),
+            self.reinterpret_i32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x8(self, a: mask32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask32x4(a0),
+            self.reinterpret_u32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x8(self, a: mask32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask32x4(a0),
+            self.reinterpret_i64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x8(self, a: mask32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask32x4(a0),
+            self.reinterpret_u64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x4(self, a: f64) -> f64x4<Self> {
+        let half = self.splat_f64x2(a);
+        self.combine_f64x2(half, half)
+    }
+    #[inline(always)]
+    fn abs_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.abs_f64x2(a0), self.abs_f64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.neg_f64x2(a0), self.neg_f64x2(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.sqrt_f64x2(a0), self.sqrt_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_approx_f64x2(a0), self.recip_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_approx_f64x2(a0), self.rsqrt_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_f64x2(a0, steps), self.recip_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_f64x2(a0, steps), self.rsqrt_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.add_f64x2(a0, b0), self.add_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.sub_f64x2(a0, b0), self.sub_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.mul_f64x2(a0, b0), self.mul_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.div_f64x2(a0, b0), self.div_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.copysign_f64x2(a0, b0), self.copysign_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_eq_f64x2(a0, b0), self.simd_eq_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ne_f64x2(a0, b0), self.simd_ne_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_lt_f64x2(a0, b0), self.simd_lt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_le_f64x2(a0, b0), self.simd_le_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ge_f64x2(a0, b0), self.simd_ge_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_gt_f64x2(a0, b0), self.simd_gt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_ne_f64x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_eq_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        let abs = self.abs_f64x4(a);
+        self.and_mask64x4(
+            self.simd_lt_f64x4(abs, self.splat_f64x4(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x4(abs, self.splat_f64x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(
+            self.copysign_f64x4(self.splat_f64x4(1.0), a),
+            self.splat_f64x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, _) = self.split_f64x4(a);
+        let (b0, _) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a0, b0), self.zip_high_f64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (_, a1) = self.split_f64x4(a);
+        let (_, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a1, b1), self.zip_high_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.max_f64x2(a0, b0), self.max_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.max_precise_f64x2(a0, b0),
+            self.max_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.min_f64x2(a0, b0), self.min_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.min_precise_f64x2(a0, b0),
+            self.min_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.madd_f64x2(a0, b0, c0), self.madd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.msub_f64x2(a0, b0, c0), self.msub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmadd_f64x2(a0, b0, c0), self.nmadd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmsub_f64x2(a0, b0, c0), self.nmsub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fused_f64x2(a0, b0, c0),
+            self.mul_add_fused_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fast_f64x2(a0, b0, c0),
+            self.mul_add_fast_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.floor_f64x2(a0), self.floor_f64x2(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.ceil_f64x2(a0), self.ceil_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.round_f64x2(a0), self.round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(
+            self.round_ties_even_f64x2(a0),
+            self.round_ties_even_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.fract_f64x2(a0), self.fract_f64x2(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.trunc_f64x2(a0), self.trunc_f64x2(a1))
+    }
+    #[inline(always)]
+    fn select_f64x4(self, a: mask64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.select_f64x2(a0, b0, c0), self.select_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        self.reduce_add_f64x2(a0) + self.reduce_add_f64x2(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_min_f64x2(self.min_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_max_f64x2(self.max_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f64x4<I: Indices<4>>(self, a: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x4<I: Indices<4>>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.reverse_f64x2(a1), self.reverse_f64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f64x2::<K>(x),
+            self.rotate_lanes_left_f64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_f64x2(
+            self.select_f64x2(first, x, y),
+            self.select_f64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f64x2::<K>(x),
+            self.rotate_lanes_right_f64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_f64x2(
+            self.select_f64x2(first, y, x),
+            self.select_f64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x4<const L: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let lanes = self.broadcast_lane_f64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_f64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.unzip_low_f64x2(a0, a1), self.unzip_low_f64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.unzip_high_f64x2(a0, a1), self.unzip_high_f64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f64x4(self, a: f64x4<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        let mut b0 = [0.0; 2usize];
+        let mut b1 = [0.0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x4(self, a: f64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f64x2(a0),
+            self.reinterpret_f16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x4(self, a: f64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f64x2(a0),
+            self.reinterpret_bf16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x4(self, a: f64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f64x2(a0),
+            self.reinterpret_f32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x4(self, a: f64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_f64x2(a0), self.reinterpret_i8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x4(self, a: f64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_f64x2(a0), self.reinterpret_u8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x4(self, a: f64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f64x2(a0),
+            self.reinterpret_i16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x4(self, a: f64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f64x2(a0),
+            self.reinterpret_u16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x4(self, a: f64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f64x2(a0),
+            self.reinterpret_i32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x4(self, a: f64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f64x2(a0),
+            self.reinterpret_u32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f64x2(a0),
+            self.reinterpret_i64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f64x2(a0),
+            self.reinterpret_u64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x4(self, a: f64x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let a: float64x2x2_t = a.into();
+            vcvt_high_f32_f64(vcvt_f32_f64(a.0), a.1).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_u16_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_f64x2(a0), self.cvt_i64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_round_f64x2(a0), self.cvt_i64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_f64x2(a0), self.cvt_u64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_round_f64x2(a0), self.cvt_u64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_i64x4(self, a: i64) -> i64x4<Self> {
+        let half = self.splat_i64x2(a);
+        self.combine_i64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.not_i64x2(a0), self.not_i64x2(a1))
+    }
+    #[inline(always)]
+    fn add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.add_i64x2(a0, b0), self.add_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.sub_i64x2(a0, b0), self.sub_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.mul_i64x2(a0, b0), self.mul_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.and_i64x2(a0, b0), self.and_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.or_i64x2(a0, b0), self.or_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.xor_i64x2(a0, b0), self.xor_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shl_i64x2(a0, b), self.shl_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shr_i64x2(a0, b), self.shr_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.shl_var_i64x2(a0, b0), self.shl_var_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.shr_var_i64x2(a0, b0), self.shr_var_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_eq_i64x2(a0, b0), self.simd_eq_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_lt_i64x2(a0, b0), self.simd_lt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_le_i64x2(a0, b0), self.simd_le_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_ge_i64x2(a0, b0), self.simd_ge_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_gt_i64x2(a0, b0), self.simd_gt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, _) = self.split_i64x4(a);
+        let (b0, _) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a0, b0), self.zip_high_i64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (_, a1) = self.split_i64x4(a);
+        let (_, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a1, b1), self.zip_high_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i64x4(self, a: mask64x4<Self>, b: i64x4<Self>, c: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        let (c0, c1) = self.split_i64x4(c);
+        self.combine_i64x2(self.select_i64x2(a0, b0, c0), self.select_i64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.min_i64x2(a0, b0), self.min_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.max_i64x2(a0, b0), self.max_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_add_i64x2(self.add_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_min_i64x2(self.min_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_max_i64x2(self.max_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_and_i64x2(self.and_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_or_i64x2(self.or_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i64x4<I: Indices<4>>(self, a: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x4<I: Indices<4>>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.reverse_i64x2(a1), self.reverse_i64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i64x2::<K>(x),
+            self.rotate_lanes_left_i64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_i64x2(
+            self.select_i64x2(first, x, y),
+            self.select_i64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i64x2::<K>(x),
+            self.rotate_lanes_right_i64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_i64x2(
+            self.select_i64x2(first, y, x),
+            self.select_i64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x4<const L: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let lanes = self.broadcast_lane_i64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_i64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.unzip_low_i64x2(a0, a1), self.unzip_low_i64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.unzip_high_i64x2(a0, a1), self.unzip_high_i64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.abs_i64x2(a0), self.abs_i64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.neg_i64x2(a0), self.neg_i64x2(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_u64x2(self.abs_diff_i64x2(a0, b0), self.abs_diff_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i64x4(self, a: i64x4<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let a: int64x2x2_t = a.into();
+            vcombine_s32(vmovn_s64(a.0), vmovn_s64(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let a: int64x2x2_t = a.into();
+            vcombine_s32(vqmovn_s64(a.0), vqmovn_s64(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x4(self, a: i64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i64x2(a0),
+            self.reinterpret_f16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x4(self, a: i64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i64x2(a0),
+            self.reinterpret_bf16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x4(self, a: i64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i64x2(a0),
+            self.reinterpret_f32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i64x2(a0),
+            self.reinterpret_f64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x4(self, a: i64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_i64x2(a0), self.reinterpret_i8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x4(self, a: i64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_i64x2(a0), self.reinterpret_u8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x4(self, a: i64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i64x2(a0),
+            self.reinterpret_i16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x4(self, a: i64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i64x2(a0),
+            self.reinterpret_u16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x4(self, a: i64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i64x2(a0),
+            self.reinterpret_i32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x4(self, a: i64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i64x2(a0),
+            self.reinterpret_u32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x4(self, a: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i64x2(a0),
+            self.reinterpret_u64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x4(self, a: i64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(self.cvt_f64_i64x2(a0), self.cvt_f64_i64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_u64x4(self, a: u64) -> u64x4<Self> {
+        let half = self.splat_u64x2(a);
+        self.combine_u64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.not_u64x2(a0), self.not_u64x2(a1))
+    }
+    #[inline(always)]
+    fn add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.add_u64x2(a0, b0), self.add_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.sub_u64x2(a0, b0), self.sub_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.mul_u64x2(a0, b0), self.mul_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.and_u64x2(a0, b0), self.and_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.or_u64x2(a0, b0), self.or_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.xor_u64x2(a0, b0), self.xor_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shl_u64x2(a0, b), self.shl_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shr_u64x2(a0, b), self.shr_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.shl_var_u64x2(a0, b0), self.shl_var_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.shr_var_u64x2(a0, b0), self.shr_var_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_eq_u64x2(a0, b0), self.simd_eq_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_lt_u64x2(a0, b0), self.simd_lt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_le_u64x2(a0, b0), self.simd_le_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_ge_u64x2(a0, b0), self.simd_ge_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_gt_u64x2(a0, b0), self.simd_gt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, _) = self.split_u64x4(a);
+        let (b0, _) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a0, b0), self.zip_high_u64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (_, a1) = self.split_u64x4(a);
+        let (_, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a1, b1), self.zip_high_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u64x4(self, a: mask64x4<Self>, b: u64x4<Self>, c: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        let (c0, c1) = self.split_u64x4(c);
+        self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.min_u64x2(a0, b0), self.min_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.max_u64x2(a0, b0), self.max_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_add_u64x2(self.add_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_min_u64x2(self.min_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_max_u64x2(self.max_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_and_u64x2(self.and_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_or_u64x2(self.or_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u64x4<I: Indices<4>>(self, a: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x4<I: Indices<4>>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.reverse_u64x2(a1), self.reverse_u64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u64x2::<K>(x),
+            self.rotate_lanes_left_u64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_u64x2(
+            self.select_u64x2(first, x, y),
+            self.select_u64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u64x2::<K>(x),
+            self.rotate_lanes_right_u64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_u64x2(
+            self.select_u64x2(first, y, x),
+            self.select_u64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x4<const L: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let lanes = self.broadcast_lane_u64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_u64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.unzip_low_u64x2(a0, a1), self.unzip_low_u64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.unzip_high_u64x2(a0, a1), self.unzip_high_u64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.abs_diff_u64x2(a0, b0), self.abs_diff_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u64x4(self, a: u64x4<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let a: uint64x2x2_t = a.into();
+            vcombine_u32(vmovn_u64(a.0), vmovn_u64(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let a: uint64x2x2_t = a.into();
+            vcombine_u32(vqmovn_u64(a.0), vqmovn_u64(a.1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x4(self, a: u64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u64x2(a0),
+            self.reinterpret_f16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x4(self, a: u64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u64x2(a0),
+            self.reinterpret_bf16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x4(self, a: u64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u64x2(a0),
+            self.reinterpret_f32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u64x2(a0),
+            self.reinterpret_f64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x4(self, a: u64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_u64x2(a0), self.reinterpret_i8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x4(self, a: u64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_u64x2(a0), self.reinterpret_u8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x4(self, a: u64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u64x2(a0),
+            self.reinterpret_i16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x4(self, a: u64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u64x2(a0),
+            self.reinterpret_u16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x4(self, a: u64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u64x2(a0),
+            self.reinterpret_i32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x4(self, a: u64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u64x2(a0),
+            self.reinterpret_u32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x4(self, a: u64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u64x2(a0),
+            self.reinterpret_i64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x4(self, a: u64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(self.cvt_f64_u64x2(a0), self.cvt_f64_u64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_mask64x4(self, a: i64) -> mask64x4<Self> {
+        let half = self.splat_mask64x2(a);
+        self.combine_mask64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_mask64x2(self.not_mask64x2(a0), self.not_mask64x2(a1))
+    }
+    #[inline(always)]
+    fn and_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.and_mask64x2(a0, b0), self.and_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.or_mask64x2(a0, b0), self.or_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.xor_mask64x2(a0, b0), self.xor_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        let (c0, c1) = self.split_mask64x4(c);
+        self.combine_mask64x2(
+            self.select_mask64x2(a0, b0, c0),
+            self.select_mask64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.simd_eq_mask64x2(a0, b0), self.simd_eq_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.any_mask64x2(a0) || self.any_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn all_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.all_mask64x2(a0) && self.all_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn none_mask64x4(self, a: mask64x4<Self>) -> bool {
+        !self.any_mask64x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x4(self, a: mask64x4<Self>) -> usize {
+        self.to_bitmask_mask64x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x4(self, a: mask64x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x4(self, a: mask64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.to_bitmask_mask64x2(a0) | (self.to_bitmask_mask64x2(a1) << 2)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
+        self.combine_mask64x2(
+            self.from_bitmask_mask64x2(bits),
+            self.from_bitmask_mask64x2(bits >> 2),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask64x4<I: Indices<4>>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x4<I: Indices<4>>(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_mask64x2(self.reverse_mask64x2(a1), self.reverse_mask64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask64x2::<K>(x),
+            self.rotate_lanes_left_mask64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_mask64x2(
+            self.select_mask64x2(first, x, y),
+            self.select_mask64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask64x2::<K>(x),
+            self.rotate_lanes_right_mask64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_mask64x2(
+            self.select_mask64x2(first, y, x),
+            self.select_mask64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x4<const L: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let lanes = self.broadcast_lane_mask64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_mask64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(
+            self.unzip_low_mask64x2(a0, a1),
+            self.unzip_low_mask64x2(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(
+            self.unzip_high_mask64x2(a0, a1),
+            self.unzip_high_mask64x2(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask64x4(self, a: mask64x4<Self>) -> (mask64x2<Self>, mask64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x4(self, a: mask64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask64x2(a0),
+            self.reinterpret_i8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x4(self, a: mask64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask64x2(a0),
+            self.reinterpret_u8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x4(self, a: mask64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask64x2(a0),
+            self.reinterpret_i16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x4(self, a: mask64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask64x2(a0),
+            self.reinterpret_u16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x4(self, a: mask64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask64x2(a0),
+            self.reinterpret_i32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x4(self, a: mask64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask64x2(a0),
+            self.reinterpret_u32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask64x2(a0),
+            self.reinterpret_i64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x4(self, a: mask64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask64x2(a0),
+            self.reinterpret_u64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f32x16(self, a: f32) -> f32x16<Self> {
+        let half = self.splat_f32x8(a);
+        self.combine_f32x8(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.abs_f32x8(a0), self.abs_f32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.neg_f32x8(a0), self.neg_f32x8(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.sqrt_f32x8(a0), self.sqrt_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_approx_f32x8(a0), self.recip_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_approx_f32x8(a0), self.rsqrt_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_f32x8(a0, steps), self.recip_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_f32x8(a0, steps), self.rsqrt_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.add_f32x8(a0, b0), self.add_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.sub_f32x8(a0, b0), self.sub_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.mul_f32x8(a0, b0), self.mul_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.div_f32x8(a0, b0), self.div_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.copysign_f32x8(a0, b0), self.copysign_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_eq_f32x8(a0, b0), self.simd_eq_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ne_f32x8(a0, b0), self.simd_ne_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_lt_f32x8(a0, b0), self.simd_lt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_le_f32x8(a0, b0), self.simd_le_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ge_f32x8(a0, b0), self.simd_ge_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_gt_f32x8(a0, b0), self.simd_gt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_ne_f32x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_eq_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        let abs = self.abs_f32x16(a);
+        self.and_mask32x16(
+            self.simd_lt_f32x16(abs, self.splat_f32x16(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x16(abs, self.splat_f32x16(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(
+            self.copysign_f32x16(self.splat_f32x16(1.0), a),
+            self.splat_f32x16(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, _) = self.split_f32x16(a);
+        let (b0, _) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a0, b0), self.zip_high_f32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (_, a1) = self.split_f32x16(a);
+        let (_, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a1, b1), self.zip_high_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.max_f32x8(a0, b0), self.max_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.max_precise_f32x8(a0, b0),
+            self.max_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.min_f32x8(a0, b0), self.min_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.min_precise_f32x8(a0, b0),
+            self.min_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.madd_f32x8(a0, b0, c0), self.madd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.msub_f32x8(a0, b0, c0), self.msub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmadd_f32x8(a0, b0, c0), self.nmadd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmsub_f32x8(a0, b0, c0), self.nmsub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fused_f32x8(a0, b0, c0),
+            self.mul_add_fused_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fast_f32x8(a0, b0, c0),
+            self.mul_add_fast_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.ceil_f32x8(a0), self.ceil_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.round_f32x8(a0), self.round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(
+            self.round_ties_even_f32x8(a0),
+            self.round_ties_even_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.fract_f32x8(a0), self.fract_f32x8(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.trunc_f32x8(a0), self.trunc_f32x8(a1))
+    }
+    #[inline(always)]
+    fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        self.reduce_add_f32x8(a0) + self.reduce_add_f32x8(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_min_f32x8(self.min_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_max_f32x8(self.max_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x16<I: Indices<16>>(self, a: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x16<I: Indices<16>>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.reverse_f32x8(a1), self.reverse_f32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f32x8::<K>(x),
+            self.rotate_lanes_left_f32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_f32x8(
+            self.select_f32x8(first, x, y),
+            self.select_f32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f32x8::<K>(x),
+            self.rotate_lanes_right_f32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_f32x8(
+            self.select_f32x8(first, y, x),
+            self.select_f32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x16<const L: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let lanes = self.broadcast_lane_f32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_f32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_low_f32x8(a0, a1), self.unzip_low_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_high_f32x8(a0, a1), self.unzip_high_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        let mut b0 = [0.0; 8usize];
+        let mut b1 = [0.0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self> {
+        unsafe { vld4q_f32(src.as_ptr()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
+        unsafe { vst4q_f32(dest.as_mut_ptr(), a.into()) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x16(self, a: f32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_f32x8(a0),
+            self.reinterpret_f64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x16(self, a: f32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_f32x8(a0), self.reinterpret_i8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x16(self, a: f32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_f32x8(a0), self.reinterpret_u8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x16(self, a: f32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f32x8(a0),
+            self.reinterpret_i16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x16(self, a: f32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f32x8(a0),
+            self.reinterpret_u16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f32x8(a0),
+            self.reinterpret_i32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f32x8(a0),
+            self.reinterpret_u32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x16(self, a: f32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f32x8(a0),
+            self.reinterpret_i64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x16(self, a: f32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f32x8(a0),
+            self.reinterpret_u64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x16(self, a: f32x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f16x8(self.cvt_f16_f32x8(a0), self.cvt_f16_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i8_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_f32x8(a0), self.cvt_i32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_round_f32x8(a0), self.cvt_i32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_f32x8(a0), self.cvt_u32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_round_f32x8(a0), self.cvt_u32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x16(self, a: f32x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_bf16x8(self.cvt_bf16_f32x8(a0), self.cvt_bf16_f32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_i8x64(self, a: i8) -> i8x64<Self> {
+        let half = self.splat_i8x32(a);
+        self.combine_i8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.not_i8x32(a0), self.not_i8x32(a1))
+    }
+    #[inline(always)]
+    fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.add_i8x32(a0, b0), self.add_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.sub_i8x32(a0, b0), self.sub_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_i8x32(a0, b0), self.mul_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.and_i8x32(a0, b0), self.and_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.or_i8x32(a0, b0), self.or_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.xor_i8x32(a0, b0), self.xor_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shl_i8x32(a0, b), self.shl_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shr_i8x32(a0, b), self.shr_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shl_var_i8x32(a0, b0), self.shl_var_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.shr_var_i8x32(a0, b0), self.shr_var_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_eq_i8x32(a0, b0), self.simd_eq_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_lt_i8x32(a0, b0), self.simd_lt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_le_i8x32(a0, b0), self.simd_le_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_ge_i8x32(a0, b0), self.simd_ge_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_gt_i8x32(a0, b0), self.simd_gt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, _) = self.split_i8x64(a);
+        let (b0, _) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a0, b0), self.zip_high_i8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (_, a1) = self.split_i8x64(a);
+        let (_, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a1, b1), self.zip_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.min_i8x32(a0, b0), self.min_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_i8x32(self.add_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_min_i8x32(self.min_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_max_i8x32(self.max_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_and_i8x32(self.and_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_or_i8x32(self.or_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x64<I: Indices<64>>(self, a: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x64<I: Indices<64>>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.reverse_i8x32(a1), self.reverse_i8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i8x32::<K>(x),
+            self.rotate_lanes_left_i8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_i8x32(
+            self.select_i8x32(first, x, y),
+            self.select_i8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i8x32::<K>(x),
+            self.rotate_lanes_right_i8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_i8x32(
+            self.select_i8x32(first, y, x),
+            self.select_i8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x64<const L: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let lanes = self.broadcast_lane_i8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_i8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_low_i8x32(a0, a1), self.unzip_low_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_high_i8x32(a0, a1), self.unzip_high_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.abs_i8x32(a0), self.abs_i8x32(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(self.abs_diff_i8x32(a0, b0), self.abs_diff_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_high_i8x32(a0, b0), self.mul_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x64(self, a: i8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i8x32(a0),
+            self.reinterpret_f32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x64(self, a: i8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i8x32(a0),
+            self.reinterpret_f64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i8x32(a0),
+            self.reinterpret_i16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x64(self, a: i8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i8x32(a0),
+            self.reinterpret_u16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x64(self, a: i8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i8x32(a0),
+            self.reinterpret_i32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x64(self, a: i8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i8x32(a0),
+            self.reinterpret_u32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x64(self, a: i8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i8x32(a0),
+            self.reinterpret_i64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x64(self, a: i8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i8x32(a0),
+            self.reinterpret_u64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x64(self, a: u8) -> u8x64<Self> {
+        let half = self.splat_u8x32(a);
+        self.combine_u8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.not_u8x32(a0), self.not_u8x32(a1))
+    }
+    #[inline(always)]
+    fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.add_u8x32(a0, b0), self.add_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.sub_u8x32(a0, b0), self.sub_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_u8x32(a0, b0), self.mul_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.and_u8x32(a0, b0), self.and_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.or_u8x32(a0, b0), self.or_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.xor_u8x32(a0, b0), self.xor_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shl_u8x32(a0, b), self.shl_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shr_u8x32(a0, b), self.shr_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shl_var_u8x32(a0, b0), self.shl_var_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.shr_var_u8x32(a0, b0), self.shr_var_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_eq_u8x32(a0, b0), self.simd_eq_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_lt_u8x32(a0, b0), self.simd_lt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_le_u8x32(a0, b0), self.simd_le_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_ge_u8x32(a0, b0), self.simd_ge_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_gt_u8x32(a0, b0), self.simd_gt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, _) = self.split_u8x64(a);
+        let (b0, _) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a0, b0), self.zip_high_u8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (_, a1) = self.split_u8x64(a);
+        let (_, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a1, b1), self.zip_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.min_u8x32(a0, b0), self.min_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_u8x32(self.add_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_min_u8x32(self.min_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_max_u8x32(self.max_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_and_u8x32(self.and_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_or_u8x32(self.or_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x64<I: Indices<64>>(self, a: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x64<I: Indices<64>>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.reverse_u8x32(a1), self.reverse_u8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u8x32::<K>(x),
+            self.rotate_lanes_left_u8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_u8x32(
+            self.select_u8x32(first, x, y),
+            self.select_u8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u8x32::<K>(x),
+            self.rotate_lanes_right_u8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_u8x32(
+            self.select_u8x32(first, y, x),
+            self.select_u8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x64<const L: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let lanes = self.broadcast_lane_u8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_u8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_low_u8x32(a0, a1), self.unzip_low_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_high_u8x32(a0, a1), self.unzip_high_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.abs_diff_u8x32(a0, b0), self.abs_diff_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_high_u8x32(a0, b0), self.mul_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x64(self, a: u8x32<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_32_u8x32(a, b0), self.lookup_32_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x64(self, a: [u8x16<Self>; 3], b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_48_u8x32(a, b0), self.lookup_48_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self> {
+        unsafe { vld4q_u8(src.as_ptr()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        unsafe { vst4q_u8(dest.as_mut_ptr(), a.into()) }
+    }
+    #[inline(always)]
+    fn widen_low_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x64(self, a: u8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u8x32(a0),
+            self.reinterpret_f32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x64(self, a: u8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u8x32(a0),
+            self.reinterpret_f64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x64(self, a: u8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i8x32(self.reinterpret_i8_u8x32(a0), self.reinterpret_i8_u8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x64(self, a: u8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u8x32(a0),
+            self.reinterpret_i16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u8x32(a0),
+            self.reinterpret_u16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x64(self, a: u8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u8x32(a0),
+            self.reinterpret_i32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u8x32(a0),
+            self.reinterpret_u32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x64(self, a: u8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u8x32(a0),
+            self.reinterpret_i64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x64(self, a: u8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u8x32(a0),
+            self.reinterpret_u64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x64(self, a: i8) -> mask8x64<Self> {
+        let half = self.splat_mask8x32(a);
+        self.combine_mask8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.not_mask8x32(a0), self.not_mask8x32(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.and_mask8x32(a0, b0), self.and_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.or_mask8x32(a0, b0), self.or_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.xor_mask8x32(a0, b0), self.xor_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_mask8x32(a0, b0, c0),
+            self.select_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.simd_eq_mask8x32(a0, b0), self.simd_eq_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.any_mask8x32(a0) || self.any_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.all_mask8x32(a0) && self.all_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x64(self, a: mask8x64<Self>) -> bool {
+        !self.any_mask8x64(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x64(self, a: mask8x64<Self>) -> usize {
+        self.to_bitmask_mask8x64(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x64(self, a: mask8x64<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x64(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x64(self, a: mask8x64<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.to_bitmask_mask8x32(a0) | (self.to_bitmask_mask8x32(a1) << 32)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
+        self.combine_mask8x32(
+            self.from_bitmask_mask8x32(bits),
+            self.from_bitmask_mask8x32(bits >> 32),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x64<I: Indices<64>>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x64<I: Indices<64>>(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.reverse_mask8x32(a1), self.reverse_mask8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask8x32::<K>(x),
+            self.rotate_lanes_left_mask8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_mask8x32(
+            self.select_mask8x32(first, x, y),
+            self.select_mask8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask8x32::<K>(x),
+            self.rotate_lanes_right_mask8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_mask8x32(
+            self.select_mask8x32(first, y, x),
+            self.select_mask8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x64<const L: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let lanes = self.broadcast_lane_mask8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_mask8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(
+            self.unzip_low_mask8x32(a0, a1),
+            self.unzip_low_mask8x32(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(
+            self.unzip_high_mask8x32(a0, a1),
+            self.unzip_high_mask8x32(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask8x32(a0),
+            self.reinterpret_i8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x64(self, a: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask8x32(a0),
+            self.reinterpret_u8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x64(self, a: mask8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask8x32(a0),
+            self.reinterpret_i16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x64(self, a: mask8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask8x32(a0),
+            self.reinterpret_u16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x64(self, a: mask8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask8x32(a0),
+            self.reinterpret_i32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x64(self, a: mask8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask8x32(a0),
+            self.reinterpret_u32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x64(self, a: mask8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask8x32(a0),
+            self.reinterpret_i64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x64(self, a: mask8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask8x32(a0),
+            self.reinterpret_u64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x32(self, a: i16) -> i16x32<Self> {
+        let half = self.splat_i16x16(a);
+        self.combine_i16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.not_i16x16(a0), self.not_i16x16(a1))
+    }
+    #[inline(always)]
+    fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.add_i16x16(a0, b0), self.add_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.sub_i16x16(a0, b0), self.sub_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_i16x16(a0, b0), self.mul_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.and_i16x16(a0, b0), self.and_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.or_i16x16(a0, b0), self.or_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.xor_i16x16(a0, b0), self.xor_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shl_i16x16(a0, b), self.shl_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shr_i16x16(a0, b), self.shr_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shl_var_i16x16(a0, b0), self.shl_var_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.shr_var_i16x16(a0, b0), self.shr_var_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_eq_i16x16(a0, b0), self.simd_eq_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_lt_i16x16(a0, b0), self.simd_lt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_le_i16x16(a0, b0), self.simd_le_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_ge_i16x16(a0, b0), self.simd_ge_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_gt_i16x16(a0, b0), self.simd_gt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, _) = self.split_i16x32(a);
+        let (b0, _) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a0, b0), self.zip_high_i16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (_, a1) = self.split_i16x32(a);
+        let (_, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a1, b1), self.zip_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_i16x16(a0, b0, c0),
+            self.select_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.min_i16x16(a0, b0), self.min_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_i16x16(self.add_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_min_i16x16(self.min_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_max_i16x16(self.max_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_and_i16x16(self.and_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_or_i16x16(self.or_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x32<I: Indices<32>>(self, a: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x32<I: Indices<32>>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.reverse_i16x16(a1), self.reverse_i16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i16x16::<K>(x),
+            self.rotate_lanes_left_i16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_i16x16(
+            self.select_i16x16(first, x, y),
+            self.select_i16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i16x16::<K>(x),
+            self.rotate_lanes_right_i16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_i16x16(
+            self.select_i16x16(first, y, x),
+            self.select_i16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x32<const L: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let lanes = self.broadcast_lane_i16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_i16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.unzip_low_i16x16(a0, a1), self.unzip_low_i16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.unzip_high_i16x16(a0, a1),
+            self.unzip_high_i16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.abs_i16x16(a0), self.abs_i16x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(self.abs_diff_i16x16(a0, b0), self.abs_diff_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_high_i16x16(a0, b0), self.mul_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.mul_q15_round_sat_i16x16(a0, b0),
+            self.mul_q15_round_sat_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i32x8(self.dot_i16_i16x16(a0, b0), self.dot_i16_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(self.narrow_i16x16(a0), self.narrow_i16x16(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(
+            self.narrow_saturating_i16x16(a0),
+            self.narrow_saturating_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x32(self, a: i16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i16x16(a0),
+            self.reinterpret_f32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x32(self, a: i16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i16x16(a0),
+            self.reinterpret_f64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x32(self, a: i16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_i16x16(a0),
+            self.reinterpret_i8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_i16x16(a0),
+            self.reinterpret_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x32(self, a: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i16x16(a0),
+            self.reinterpret_u16_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i16x16(a0),
+            self.reinterpret_i32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x32(self, a: i16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i16x16(a0),
+            self.reinterpret_u32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x32(self, a: i16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i16x16(a0),
+            self.reinterpret_i64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x32(self, a: i16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i16x16(a0),
+            self.reinterpret_u64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u16x32(self, a: u16) -> u16x32<Self> {
+        let half = self.splat_u16x16(a);
+        self.combine_u16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.not_u16x16(a0), self.not_u16x16(a1))
+    }
+    #[inline(always)]
+    fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.add_u16x16(a0, b0), self.add_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.sub_u16x16(a0, b0), self.sub_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_u16x16(a0, b0), self.mul_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.and_u16x16(a0, b0), self.and_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.or_u16x16(a0, b0), self.or_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.xor_u16x16(a0, b0), self.xor_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shl_u16x16(a0, b), self.shl_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shr_u16x16(a0, b), self.shr_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shl_var_u16x16(a0, b0), self.shl_var_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.shr_var_u16x16(a0, b0), self.shr_var_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_eq_u16x16(a0, b0), self.simd_eq_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_lt_u16x16(a0, b0), self.simd_lt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_le_u16x16(a0, b0), self.simd_le_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_ge_u16x16(a0, b0), self.simd_ge_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_gt_u16x16(a0, b0), self.simd_gt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, _) = self.split_u16x32(a);
+        let (b0, _) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a0, b0), self.zip_high_u16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (_, a1) = self.split_u16x32(a);
+        let (_, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a1, b1), self.zip_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_u16x16(a0, b0, c0),
+            self.select_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.min_u16x16(a0, b0), self.min_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_u16x16(self.add_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_min_u16x16(self.min_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_max_u16x16(self.max_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_and_u16x16(self.and_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_or_u16x16(self.or_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x32<I: Indices<32>>(self, a: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x32<I: Indices<32>>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.reverse_u16x16(a1), self.reverse_u16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u16x16::<K>(x),
+            self.rotate_lanes_left_u16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_u16x16(
+            self.select_u16x16(first, x, y),
+            self.select_u16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u16x16::<K>(x),
+            self.rotate_lanes_right_u16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_u16x16(
+            self.select_u16x16(first, y, x),
+            self.select_u16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x32<const L: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let lanes = self.broadcast_lane_u16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_u16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.unzip_low_u16x16(a0, a1), self.unzip_low_u16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.unzip_high_u16x16(a0, a1),
+            self.unzip_high_u16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.abs_diff_u16x16(a0, b0), self.abs_diff_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_high_u16x16(a0, b0), self.mul_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self> {
+        unsafe { vld4q_u16(src.as_ptr()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
+        unsafe { vst4q_u16(dest.as_mut_ptr(), a.into()) }
+    }
+    #[inline(always)]
+    fn widen_low_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(
+            self.narrow_saturating_u16x16(a0),
+            self.narrow_saturating_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x32(self, a: u16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u16x16(a0),
+            self.reinterpret_f32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x32(self, a: u16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u16x16(a0),
+            self.reinterpret_f64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x32(self, a: u16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_u16x16(a0),
+            self.reinterpret_i8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_u16x16(a0),
+            self.reinterpret_u8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x32(self, a: u16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u16x16(a0),
+            self.reinterpret_i16_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x32(self, a: u16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u16x16(a0),
+            self.reinterpret_i32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u16x16(a0),
+            self.reinterpret_u32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x32(self, a: u16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u16x16(a0),
+            self.reinterpret_i64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x32(self, a: u16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u16x16(a0),
+            self.reinterpret_u64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask16x32(self, a: i16) -> mask16x32<Self> {
+        let half = self.splat_mask16x16(a);
+        self.combine_mask16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.not_mask16x16(a0), self.not_mask16x16(a1))
+    }
+    #[inline(always)]
+    fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.and_mask16x16(a0, b0), self.and_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.or_mask16x16(a0, b0), self.or_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.xor_mask16x16(a0, b0), self.xor_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_mask16x16(a0, b0, c0),
+            self.select_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.simd_eq_mask16x16(a0, b0),
+            self.simd_eq_mask16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn any_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.any_mask16x16(a0) || self.any_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn all_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.all_mask16x16(a0) && self.all_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn none_mask16x32(self, a: mask16x32<Self>) -> bool {
+        !self.any_mask16x32(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x32(self, a: mask16x32<Self>) -> usize {
+        self.to_bitmask_mask16x32(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x32(self, a: mask16x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x32(self, a: mask16x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.to_bitmask_mask16x16(a0) | (self.to_bitmask_mask16x16(a1) << 16)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
+        self.combine_mask16x16(
+            self.from_bitmask_mask16x16(bits),
+            self.from_bitmask_mask16x16(bits >> 16),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask16x32<I: Indices<32>>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x32<I: Indices<32>>(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.reverse_mask16x16(a1), self.reverse_mask16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask16x16::<K>(x),
+            self.rotate_lanes_left_mask16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_mask16x16(
+            self.select_mask16x16(first, x, y),
+            self.select_mask16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask16x16::<K>(x),
+            self.rotate_lanes_right_mask16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_mask16x16(
+            self.select_mask16x16(first, y, x),
+            self.select_mask16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x32<const L: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let lanes = self.broadcast_lane_mask16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_mask16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.unzip_low_mask16x16(a0, a1),
+            self.unzip_low_mask16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.unzip_high_mask16x16(a0, a1),
+            self.unzip_high_mask16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x32(self, a: mask16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask16x16(a0),
+            self.reinterpret_i8_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x32(self, a: mask16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask16x16(a0),
+            self.reinterpret_u8_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask16x16(a0),
+            self.reinterpret_i16_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x32(self, a: mask16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask16x16(a0),
+            self.reinterpret_u16_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x32(self, a: mask16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask16x16(a0),
+            self.reinterpret_i32_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x32(self, a: mask16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask16x16(a0),
+            self.reinterpret_u32_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x32(self, a: mask16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask16x16(a0),
+            self.reinterpret_i64_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x32(self, a: mask16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask16x16(a0),
+            self.reinterpret_u64_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x16(self, a: i32) -> i32x16<Self> {
+        let half = self.splat_i32x8(a);
+        self.combine_i32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.not_i32x8(a0), self.not_i32x8(a1))
+    }
+    #[inline(always)]
+    fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.add_i32x8(a0, b0), self.add_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.sub_i32x8(a0, b0), self.sub_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.mul_i32x8(a0, b0), self.mul_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.and_i32x8(a0, b0), self.and_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.or_i32x8(a0, b0), self.or_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.xor_i32x8(a0, b0), self.xor_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shl_i32x8(a0, b), self.shl_i32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shr_i32x8(a0, b), self.shr_i32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shl_var_i32x8(a0, b0), self.shl_var_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.shr_var_i32x8(a0, b0), self.shr_var_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_eq_i32x8(a0, b0), self.simd_eq_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_lt_i32x8(a0, b0), self.simd_lt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_le_i32x8(a0, b0), self.simd_le_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_ge_i32x8(a0, b0), self.simd_ge_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_gt_i32x8(a0, b0), self.simd_gt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, _) = self.split_i32x16(a);
+        let (b0, _) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a0, b0), self.zip_high_i32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (_, a1) = self.split_i32x16(a);
+        let (_, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a1, b1), self.zip_high_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.min_i32x8(a0, b0), self.min_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_add_i32x8(self.add_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_min_i32x8(self.min_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_max_i32x8(self.max_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_and_i32x8(self.and_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_or_i32x8(self.or_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i32x16<I: Indices<16>>(self, a: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x16<I: Indices<16>>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.reverse_i32x8(a1), self.reverse_i32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i32x8::<K>(x),
+            self.rotate_lanes_left_i32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_i32x8(
+            self.select_i32x8(first, x, y),
+            self.select_i32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i32x8::<K>(x),
+            self.rotate_lanes_right_i32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_i32x8(
+            self.select_i32x8(first, y, x),
+            self.select_i32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x16<const L: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let lanes = self.broadcast_lane_i32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_i32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_low_i32x8(a0, a1), self.unzip_low_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_high_i32x8(a0, a1), self.unzip_high_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.abs_i32x8(a0), self.abs_i32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.neg_i32x8(a0), self.neg_i32x8(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(self.abs_diff_i32x8(a0, b0), self.abs_diff_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(self.narrow_i32x8(a0), self.narrow_i32x8(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(
+            self.narrow_saturating_i32x8(a0),
+            self.narrow_saturating_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i32x8(a0),
+            self.reinterpret_f32_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x16(self, a: i32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i32x8(a0),
+            self.reinterpret_f64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x16(self, a: i32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_i32x8(a0), self.reinterpret_i8_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_i32x8(a0), self.reinterpret_u8_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x16(self, a: i32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i32x8(a0),
+            self.reinterpret_i16_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x16(self, a: i32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i32x8(a0),
+            self.reinterpret_u16_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x16(self, a: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i32x8(a0),
+            self.reinterpret_u32_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i32x8(a0),
+            self.reinterpret_i64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x16(self, a: i32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i32x8(a0),
+            self.reinterpret_u64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i32x16(self, a: i32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(self.cvt_f32_i32x8(a0), self.cvt_f32_i32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_u32x16(self, a: u32) -> u32x16<Self> {
+        let half = self.splat_u32x8(a);
+        self.combine_u32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.not_u32x8(a0), self.not_u32x8(a1))
+    }
+    #[inline(always)]
+    fn add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.add_u32x8(a0, b0), self.add_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.sub_u32x8(a0, b0), self.sub_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.mul_u32x8(a0, b0), self.mul_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.and_u32x8(a0, b0), self.and_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.or_u32x8(a0, b0), self.or_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.xor_u32x8(a0, b0), self.xor_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shl_u32x8(a0, b), self.shl_u32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shr_u32x8(a0, b), self.shr_u32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shl_var_u32x8(a0, b0), self.shl_var_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.shr_var_u32x8(a0, b0), self.shr_var_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_eq_u32x8(a0, b0), self.simd_eq_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_lt_u32x8(a0, b0), self.simd_lt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_le_u32x8(a0, b0), self.simd_le_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_ge_u32x8(a0, b0), self.simd_ge_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_gt_u32x8(a0, b0), self.simd_gt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, _) = self.split_u32x16(a);
+        let (b0, _) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a0, b0), self.zip_high_u32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (_, a1) = self.split_u32x16(a);
+        let (_, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a1, b1), self.zip_high_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.min_u32x8(a0, b0), self.min_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_add_u32x8(self.add_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_min_u32x8(self.min_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_max_u32x8(self.max_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_and_u32x8(self.and_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_or_u32x8(self.or_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u32x16<I: Indices<16>>(self, a: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x16<I: Indices<16>>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.reverse_u32x8(a1), self.reverse_u32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u32x8::<K>(x),
+            self.rotate_lanes_left_u32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_u32x8(
+            self.select_u32x8(first, x, y),
+            self.select_u32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u32x8::<K>(x),
+            self.rotate_lanes_right_u32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_u32x8(
+            self.select_u32x8(first, y, x),
+            self.select_u32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x16<const L: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let lanes = self.broadcast_lane_u32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_u32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_low_u32x8(a0, a1), self.unzip_low_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_high_u32x8(a0, a1), self.unzip_high_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.abs_diff_u32x8(a0, b0), self.abs_diff_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self> {
+        unsafe { vld4q_u32(src.as_ptr()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
+        unsafe { vst4q_u32(dest.as_mut_ptr(), a.into()) }
+    }
+    #[inline(always)]
+    fn widen_low_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(self.narrow_u32x8(a0), self.narrow_u32x8(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(
+            self.narrow_saturating_u32x8(a0),
+            self.narrow_saturating_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u32x8(a0),
+            self.reinterpret_f32_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x16(self, a: u32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u32x8(a0),
+            self.reinterpret_f64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x16(self, a: u32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_u32x8(a0), self.reinterpret_i8_u32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_u32x8(a0), self.reinterpret_u8_u32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x16(self, a: u32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u32x8(a0),
+            self.reinterpret_i16_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x16(self, a: u32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u32x8(a0),
+            self.reinterpret_u16_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x16(self, a: u32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u32x8(a0),
+            self.reinterpret_i32_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x16(self, a: u32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u32x8(a0),
+            self.reinterpret_i64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u32x8(a0),
+            self.reinterpret_u64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u32x16(self, a: u32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(self.cvt_f32_u32x8(a0), self.cvt_f32_u32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_mask32x16(self, a: i32) -> mask32x16<Self> {
+        let half = self.splat_mask32x8(a);
+        self.combine_mask32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.not_mask32x8(a0), self.not_mask32x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.and_mask32x8(a0, b0), self.and_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.or_mask32x8(a0, b0), self.or_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.xor_mask32x8(a0, b0), self.xor_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_mask32x8(a0, b0, c0),
+            self.select_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.simd_eq_mask32x8(a0, b0), self.simd_eq_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.any_mask32x8(a0) || self.any_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn all_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.all_mask32x8(a0) && self.all_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn none_mask32x16(self, a: mask32x16<Self>) -> bool {
+        !self.any_mask32x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x16(self, a: mask32x16<Self>) -> usize {
+        self.to_bitmask_mask32x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x16(self, a: mask32x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x16(self, a: mask32x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.to_bitmask_mask32x8(a0) | (self.to_bitmask_mask32x8(a1) << 8)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
+        self.combine_mask32x8(
+            self.from_bitmask_mask32x8(bits),
+            self.from_bitmask_mask32x8(bits >> 8),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask32x16<I: Indices<16>>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x16<I: Indices<16>>(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.reverse_mask32x8(a1), self.reverse_mask32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask32x8::<K>(x),
+            self.rotate_lanes_left_mask32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_mask32x8(
+            self.select_mask32x8(first, x, y),
+            self.select_mask32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask32x8::<K>(x),
+            self.rotate_lanes_right_mask32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_mask32x8(
+            self.select_mask32x8(first, y, x),
+            self.select_mask32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x16<const L: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let lanes = self.broadcast_lane_mask32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_mask32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(
+            self.unzip_low_mask32x8(a0, a1),
+            self.unzip_low_mask32x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(
+            self.unzip_high_mask32x8(a0, a1),
+            self.unzip_high_mask32x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x16(self, a: mask32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask32x8(a0),
+            self.reinterpret_i8_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x16(self, a: mask32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask32x8(a0),
+            self.reinterpret_u8_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x16(self, a: mask32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask32x8(a0),
+            self.reinterpret_i16_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x16(self, a: mask32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask32x8(a0),
+            self.reinterpret_u16_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask32x8(a0),
+            self.reinterpret_i32_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x16(self, a: mask32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask32x8(a0),
+            self.reinterpret_u32_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x16(self, a: mask32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask32x8(a0),
+            self.reinterpret_i64_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x16(self, a: mask32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask32x8(a0),
+            self.reinterpret_u64_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x8(self, a: f64) -> f64x8<Self> {
+        let half = self.splat_f64x4(a);
+        self.combine_f64x4(half, half)
+    }
+    #[inline(always)]
+    fn abs_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.abs_f64x4(a0), self.abs_f64x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.neg_f64x4(a0), self.neg_f64x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.sqrt_f64x4(a0), self.sqrt_f64x4(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_approx_f64x4(a0), self.recip_approx_f64x4(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_approx_f64x4(a0), self.rsqrt_approx_f64x4(a1))
+    }
+    #[inline(always)]
+    fn recip_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_f64x4(a0, steps), self.recip_f64x4(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_f64x4(a0, steps), self.rsqrt_f64x4(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.add_f64x4(a0, b0), self.add_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.sub_f64x4(a0, b0), self.sub_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.mul_f64x4(a0, b0), self.mul_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.div_f64x4(a0, b0), self.div_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.copysign_f64x4(a0, b0), self.copysign_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_eq_f64x4(a0, b0), self.simd_eq_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ne_f64x4(a0, b0), self.simd_ne_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_lt_f64x4(a0, b0), self.simd_lt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_le_f64x4(a0, b0), self.simd_le_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ge_f64x4(a0, b0), self.simd_ge_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_gt_f64x4(a0, b0), self.simd_gt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_ne_f64x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_eq_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        let abs = self.abs_f64x8(a);
+        self.and_mask64x8(
+            self.simd_lt_f64x8(abs, self.splat_f64x8(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x8(abs, self.splat_f64x8(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(
+            self.copysign_f64x8(self.splat_f64x8(1.0), a),
+            self.splat_f64x8(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, _) = self.split_f64x8(a);
+        let (b0, _) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a0, b0), self.zip_high_f64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (_, a1) = self.split_f64x8(a);
+        let (_, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a1, b1), self.zip_high_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.max_f64x4(a0, b0), self.max_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.max_precise_f64x4(a0, b0),
+            self.max_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.min_f64x4(a0, b0), self.min_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.min_precise_f64x4(a0, b0),
+            self.min_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.madd_f64x4(a0, b0, c0), self.madd_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.msub_f64x4(a0, b0, c0), self.msub_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmadd_f64x4(a0, b0, c0), self.nmadd_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmsub_f64x4(a0, b0, c0), self.nmsub_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fused_f64x4(a0, b0, c0),
+            self.mul_add_fused_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fast_f64x4(a0, b0, c0),
+            self.mul_add_fast_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.ceil_f64x4(a0), self.ceil_f64x4(a1))
+    }
+    #[inline(always)]
+    fn round_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.round_f64x4(a0), self.round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(
+            self.round_ties_even_f64x4(a0),
+            self.round_ties_even_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.fract_f64x4(a0), self.fract_f64x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.trunc_f64x4(a0), self.trunc_f64x4(a1))
+    }
+    #[inline(always)]
+    fn select_f64x8(self, a: mask64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        self.reduce_add_f64x4(a0) + self.reduce_add_f64x4(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_min_f64x4(self.min_precise_f64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_max_f64x4(self.max_precise_f64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f64x8<I: Indices<8>>(self, a: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x8<I: Indices<8>>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.reverse_f64x4(a1), self.reverse_f64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f64x4::<K>(x),
+            self.rotate_lanes_left_f64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_f64x4(
+            self.select_f64x4(first, x, y),
+            self.select_f64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f64x4::<K>(x),
+            self.rotate_lanes_right_f64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_f64x4(
+            self.select_f64x4(first, y, x),
+            self.select_f64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x8<const L: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let lanes = self.broadcast_lane_f64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_f64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_low_f64x4(a0, a1), self.unzip_low_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_high_f64x4(a0, a1), self.unzip_high_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x8(self, a: f64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_f64x4(a0),
+            self.reinterpret_f32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x8(self, a: f64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_f64x4(a0), self.reinterpret_i8_f64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x8(self, a: f64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_f64x4(a0), self.reinterpret_u8_f64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x8(self, a: f64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f64x4(a0),
+            self.reinterpret_i16_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x8(self, a: f64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f64x4(a0),
+            self.reinterpret_u16_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x8(self, a: f64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f64x4(a0),
+            self.reinterpret_i32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x8(self, a: f64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f64x4(a0),
+            self.reinterpret_u32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f64x4(a0),
+            self.reinterpret_i64_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f64x4(a0),
+            self.reinterpret_u64_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f64x8(self, a: f64x8<Self>) -> f16x8<Self> {
+        a.val.map(f16::from_f64_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x8(self, a: f64x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x4(self.cvt_f32_f64x4(a0), self.cvt_f32_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u8_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_f64x4(a0), self.cvt_i64_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_round_f64x4(a0), self.cvt_i64_round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_f64x4(a0), self.cvt_u64_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_round_f64x4(a0), self.cvt_u64_round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_i64x8(self, a: i64) -> i64x8<Self> {
+        let half = self.splat_i64x4(a);
+        self.combine_i64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.not_i64x4(a0), self.not_i64x4(a1))
+    }
+    #[inline(always)]
+    fn add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.add_i64x4(a0, b0), self.add_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.sub_i64x4(a0, b0), self.sub_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.mul_i64x4(a0, b0), self.mul_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.and_i64x4(a0, b0), self.and_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.or_i64x4(a0, b0), self.or_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.xor_i64x4(a0, b0), self.xor_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shl_i64x4(a0, b), self.shl_i64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shr_i64x4(a0, b), self.shr_i64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shl_var_i64x4(a0, b0), self.shl_var_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.shr_var_i64x4(a0, b0), self.shr_var_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_eq_i64x4(a0, b0), self.simd_eq_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_lt_i64x4(a0, b0), self.simd_lt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_le_i64x4(a0, b0), self.simd_le_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_ge_i64x4(a0, b0), self.simd_ge_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_gt_i64x4(a0, b0), self.simd_gt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, _) = self.split_i64x8(a);
+        let (b0, _) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a0, b0), self.zip_high_i64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (_, a1) = self.split_i64x8(a);
+        let (_, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a1, b1), self.zip_high_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i64x8(self, a: mask64x8<Self>, b: i64x8<Self>, c: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.min_i64x4(a0, b0), self.min_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_add_i64x4(self.add_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_min_i64x4(self.min_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_max_i64x4(self.max_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_and_i64x4(self.and_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_or_i64x4(self.or_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i64x8<I: Indices<8>>(self, a: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x8<I: Indices<8>>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.reverse_i64x4(a1), self.reverse_i64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i64x4::<K>(x),
+            self.rotate_lanes_left_i64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_i64x4(
+            self.select_i64x4(first, x, y),
+            self.select_i64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i64x4::<K>(x),
+            self.rotate_lanes_right_i64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_i64x4(
+            self.select_i64x4(first, y, x),
+            self.select_i64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x8<const L: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let lanes = self.broadcast_lane_i64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_i64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_low_i64x4(a0, a1), self.unzip_low_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_high_i64x4(a0, a1), self.unzip_high_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.abs_i64x4(a0), self.abs_i64x4(a1))
+    }
+    #[inline(always)]
+    fn neg_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.neg_i64x4(a0), self.neg_i64x4(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(self.abs_diff_i64x4(a0, b0), self.abs_diff_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(self.narrow_i64x4(a0), self.narrow_i64x4(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(
+            self.narrow_saturating_i64x4(a0),
+            self.narrow_saturating_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x8(self, a: i64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i64x4(a0),
+            self.reinterpret_f32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i64x4(a0),
+            self.reinterpret_f64_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x8(self, a: i64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_i64x4(a0), self.reinterpret_i8_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x8(self, a: i64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_i64x4(a0), self.reinterpret_u8_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x8(self, a: i64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i64x4(a0),
+            self.reinterpret_i16_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x8(self, a: i64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i64x4(a0),
+            self.reinterpret_u16_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x8(self, a: i64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i64x4(a0),
+            self.reinterpret_i32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x8(self, a: i64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i64x4(a0),
+            self.reinterpret_u32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x8(self, a: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i64x4(a0),
+            self.reinterpret_u64_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i64x8(self, a: i64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x8(self, a: i64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(self.cvt_f64_i64x4(a0), self.cvt_f64_i64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_u64x8(self, a: u64) -> u64x8<Self> {
+        let half = self.splat_u64x4(a);
+        self.combine_u64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.not_u64x4(a0), self.not_u64x4(a1))
+    }
+    #[inline(always)]
+    fn add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.add_u64x4(a0, b0), self.add_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.sub_u64x4(a0, b0), self.sub_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.mul_u64x4(a0, b0), self.mul_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.and_u64x4(a0, b0), self.and_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.or_u64x4(a0, b0), self.or_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.xor_u64x4(a0, b0), self.xor_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shl_u64x4(a0, b), self.shl_u64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shr_u64x4(a0, b), self.shr_u64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shl_var_u64x4(a0, b0), self.shl_var_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shr_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.shr_var_u64x4(a0, b0), self.shr_var_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_eq_u64x4(a0, b0), self.simd_eq_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_lt_u64x4(a0, b0), self.simd_lt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_le_u64x4(a0, b0), self.simd_le_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_ge_u64x4(a0, b0), self.simd_ge_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_gt_u64x4(a0, b0), self.simd_gt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, _) = self.split_u64x8(a);
+        let (b0, _) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a0, b0), self.zip_high_u64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (_, a1) = self.split_u64x8(a);
+        let (_, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a1, b1), self.zip_high_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u64x8(self, a: mask64x8<Self>, b: u64x8<Self>, c: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.min_u64x4(a0, b0), self.min_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_add_u64x4(self.add_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_min_u64x4(self.min_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_max_u64x4(self.max_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_and_u64x4(self.and_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_or_u64x4(self.or_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u64x8<I: Indices<8>>(self, a: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x8<I: Indices<8>>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.reverse_u64x4(a1), self.reverse_u64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u64x4::<K>(x),
+            self.rotate_lanes_left_u64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_u64x4(
+            self.select_u64x4(first, x, y),
+            self.select_u64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u64x4::<K>(x),
+            self.rotate_lanes_right_u64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_u64x4(
+            self.select_u64x4(first, y, x),
+            self.select_u64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x8<const L: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let lanes = self.broadcast_lane_u64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_u64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_low_u64x4(a0, a1), self.unzip_low_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_high_u64x4(a0, a1), self.unzip_high_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.abs_diff_u64x4(a0, b0), self.abs_diff_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(self.narrow_u64x4(a0), self.narrow_u64x4(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(
+            self.narrow_saturating_u64x4(a0),
+            self.narrow_saturating_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x8(self, a: u64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u64x4(a0),
+            self.reinterpret_f32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u64x4(a0),
+            self.reinterpret_f64_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x8(self, a: u64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_u64x4(a0), self.reinterpret_i8_u64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x8(self, a: u64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_u64x4(a0), self.reinterpret_u8_u64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x8(self, a: u64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u64x4(a0),
+            self.reinterpret_i16_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x8(self, a: u64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u64x4(a0),
+            self.reinterpret_u16_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x8(self, a: u64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u64x4(a0),
+            self.reinterpret_i32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x8(self, a: u64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u64x4(a0),
+            self.reinterpret_u32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x8(self, a: u64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u64x4(a0),
+            self.reinterpret_i64_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u64x8(self, a: u64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x8(self, a: u64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(self.cvt_f64_u64x4(a0), self.cvt_f64_u64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_mask64x8(self, a: i64) -> mask64x8<Self> {
+        let half = self.splat_mask64x4(a);
+        self.combine_mask64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.not_mask64x4(a0), self.not_mask64x4(a1))
+    }
+    #[inline(always)]
+    fn and_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.and_mask64x4(a0, b0), self.and_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.or_mask64x4(a0, b0), self.or_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.xor_mask64x4(a0, b0), self.xor_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_mask64x4(a0, b0, c0),
+            self.select_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.simd_eq_mask64x4(a0, b0), self.simd_eq_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.any_mask64x4(a0) || self.any_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn all_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.all_mask64x4(a0) && self.all_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn none_mask64x8(self, a: mask64x8<Self>) -> bool {
+        !self.any_mask64x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x8(self, a: mask64x8<Self>) -> usize {
+        self.to_bitmask_mask64x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x8(self, a: mask64x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x8(self, a: mask64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.to_bitmask_mask64x4(a0) | (self.to_bitmask_mask64x4(a1) << 4)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
+        self.combine_mask64x4(
+            self.from_bitmask_mask64x4(bits),
+            self.from_bitmask_mask64x4(bits >> 4),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask64x8<I: Indices<8>>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x8<I: Indices<8>>(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.reverse_mask64x4(a1), self.reverse_mask64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask64x4::<K>(x),
+            self.rotate_lanes_left_mask64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_mask64x4(
+            self.select_mask64x4(first, x, y),
+            self.select_mask64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask64x4::<K>(x),
+            self.rotate_lanes_right_mask64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_mask64x4(
+            self.select_mask64x4(first, y, x),
+            self.select_mask64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x8<const L: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let lanes = self.broadcast_lane_mask64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_mask64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(
+            self.unzip_low_mask64x4(a0, a1),
+            self.unzip_low_mask64x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(
+            self.unzip_high_mask64x4(a0, a1),
+            self.unzip_high_mask64x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask64x8(self, a: mask64x8<Self>) -> (mask64x4<Self>, mask64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x8(self, a: mask64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask64x4(a0),
+            self.reinterpret_i8_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x8(self, a: mask64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask64x4(a0),
+            self.reinterpret_u8_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x8(self, a: mask64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask64x4(a0),
+            self.reinterpret_i16_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x8(self, a: mask64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask64x4(a0),
+            self.reinterpret_u16_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x8(self, a: mask64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask64x4(a0),
+            self.reinterpret_i32_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x8(self, a: mask64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask64x4(a0),
+            self.reinterpret_u32_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask64x4(a0),
+            self.reinterpret_i64_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x8(self, a: mask64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask64x4(a0),
+            self.reinterpret_u64_mask64x4(a1),
+        )
+    }
+}
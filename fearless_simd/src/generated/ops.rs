@@ -2,2800 +2,5497 @@
 
 use crate::{Simd, SimdInto};
 use crate::{
-    f32x4, f32x8, f32x16, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4, i32x8, i32x16,
-    mask8x16, mask8x32, mask8x64, mask16x8, mask16x16, mask16x32, mask32x4, mask32x8, mask32x16,
-    u8x16, u8x32, u8x64, u16x8, u16x16, u16x32, u32x4, u32x8, u32x16,
+    f16, f16x8, f16x16, f32x2, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8, i8x16, i8x32, i8x64,
+    i16x8, i16x16, i16x32, i32x2, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8, mask8x8, mask8x16,
+    mask8x32, mask8x64, mask16x4, mask16x8, mask16x16, mask16x32, mask32x2, mask32x4, mask32x8,
+    mask32x16, mask64x2, mask64x4, mask64x8, u8x8, u8x16, u8x32, u8x64, u16x4, u16x8, u16x16,
+    u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
 };
-impl<S: Simd> core::ops::Neg for f32x4<S> {
+impl<S: Simd> core::ops::Neg for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn neg(self) -> Self::Output {
-        self.simd.neg_f32x4(self)
+        self.simd.neg_f32x2(self)
     }
 }
-impl<S: Simd> core::ops::Add for f32x4<S> {
+impl<S: Simd> core::ops::Add for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_f32x4(self, rhs)
+        self.simd.add_f32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<f32> for f32x4<S> {
+impl<S: Simd> core::ops::Add<f32> for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: f32) -> Self::Output {
-        self.simd.add_f32x4(self, rhs.simd_into(self.simd))
+        self.simd.add_f32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<f32x4<S>> for f32 {
-    type Output = f32x4<S>;
+impl<S: Simd> core::ops::Add<f32x2<S>> for f32 {
+    type Output = f32x2<S>;
     #[inline(always)]
-    fn add(self, rhs: f32x4<S>) -> Self::Output {
-        rhs.simd.add_f32x4(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f32x2<S>) -> Self::Output {
+        rhs.simd.add_f32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for f32x4<S> {
+impl<S: Simd> core::ops::Sub for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_f32x4(self, rhs)
+        self.simd.sub_f32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<f32> for f32x4<S> {
+impl<S: Simd> core::ops::Sub<f32> for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: f32) -> Self::Output {
-        self.simd.sub_f32x4(self, rhs.simd_into(self.simd))
+        self.simd.sub_f32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<f32x4<S>> for f32 {
-    type Output = f32x4<S>;
+impl<S: Simd> core::ops::Sub<f32x2<S>> for f32 {
+    type Output = f32x2<S>;
     #[inline(always)]
-    fn sub(self, rhs: f32x4<S>) -> Self::Output {
-        rhs.simd.sub_f32x4(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f32x2<S>) -> Self::Output {
+        rhs.simd.sub_f32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for f32x4<S> {
+impl<S: Simd> core::ops::Mul for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_f32x4(self, rhs)
+        self.simd.mul_f32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<f32> for f32x4<S> {
+impl<S: Simd> core::ops::Mul<f32> for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: f32) -> Self::Output {
-        self.simd.mul_f32x4(self, rhs.simd_into(self.simd))
+        self.simd.mul_f32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<f32x4<S>> for f32 {
-    type Output = f32x4<S>;
+impl<S: Simd> core::ops::Mul<f32x2<S>> for f32 {
+    type Output = f32x2<S>;
     #[inline(always)]
-    fn mul(self, rhs: f32x4<S>) -> Self::Output {
-        rhs.simd.mul_f32x4(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f32x2<S>) -> Self::Output {
+        rhs.simd.mul_f32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Div for f32x4<S> {
+impl<S: Simd> core::ops::Div for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn div(self, rhs: Self) -> Self::Output {
-        self.simd.div_f32x4(self, rhs)
+        self.simd.div_f32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Div<f32> for f32x4<S> {
+impl<S: Simd> core::ops::Div<f32> for f32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn div(self, rhs: f32) -> Self::Output {
-        self.simd.div_f32x4(self, rhs.simd_into(self.simd))
+        self.simd.div_f32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Div<f32x4<S>> for f32 {
-    type Output = f32x4<S>;
+impl<S: Simd> core::ops::Div<f32x2<S>> for f32 {
+    type Output = f32x2<S>;
     #[inline(always)]
-    fn div(self, rhs: f32x4<S>) -> Self::Output {
-        rhs.simd.div_f32x4(self.simd_into(rhs.simd), rhs)
+    fn div(self, rhs: f32x2<S>) -> Self::Output {
+        rhs.simd.div_f32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for i8x16<S> {
+impl<S: Simd> core::ops::Add for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i8x16(self, rhs)
+        self.simd.add_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i8> for i8x16<S> {
+impl<S: Simd> core::ops::Add<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i8) -> Self::Output {
-        self.simd.add_i8x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u8) -> Self::Output {
+        self.simd.add_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::Add<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn add(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.add_i8x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.add_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i8x16<S> {
+impl<S: Simd> core::ops::Sub for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i8x16(self, rhs)
+        self.simd.sub_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i8> for i8x16<S> {
+impl<S: Simd> core::ops::Sub<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i8) -> Self::Output {
-        self.simd.sub_i8x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u8) -> Self::Output {
+        self.simd.sub_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::Sub<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn sub(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.sub_i8x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.sub_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i8x16<S> {
+impl<S: Simd> core::ops::Mul for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i8x16(self, rhs)
+        self.simd.mul_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i8> for i8x16<S> {
+impl<S: Simd> core::ops::Mul<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i8) -> Self::Output {
-        self.simd.mul_i8x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.simd.mul_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::Mul<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn mul(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.mul_i8x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.mul_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i8x16<S> {
+impl<S: Simd> core::ops::BitAnd for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i8x16(self, rhs)
+        self.simd.and_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8> for i8x16<S> {
+impl<S: Simd> core::ops::BitAnd<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i8) -> Self::Output {
-        self.simd.and_i8x16(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u8) -> Self::Output {
+        self.simd.and_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::BitAnd<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.and_i8x16(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.and_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i8x16<S> {
+impl<S: Simd> core::ops::BitOr for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i8x16(self, rhs)
+        self.simd.or_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i8> for i8x16<S> {
+impl<S: Simd> core::ops::BitOr<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i8) -> Self::Output {
-        self.simd.or_i8x16(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u8) -> Self::Output {
+        self.simd.or_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::BitOr<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn bitor(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.or_i8x16(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.or_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i8x16<S> {
+impl<S: Simd> core::ops::BitXor for u8x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i8x16(self, rhs)
+        self.simd.xor_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i8> for i8x16<S> {
+impl<S: Simd> core::ops::BitXor<u8> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i8) -> Self::Output {
-        self.simd.xor_i8x16(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self.simd.xor_u8x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i8x16<S>> for i8 {
-    type Output = i8x16<S>;
+impl<S: Simd> core::ops::BitXor<u8x8<S>> for u8 {
+    type Output = u8x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i8x16<S>) -> Self::Output {
-        rhs.simd.xor_i8x16(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u8x8<S>) -> Self::Output {
+        rhs.simd.xor_u8x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u8x16<S> {
+impl<S: Simd> core::ops::Shl<u32> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u8x16(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u8> for u8x16<S> {
+impl<S: Simd> core::ops::Shl for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u8) -> Self::Output {
-        self.simd.add_u8x16(self, rhs.simd_into(self.simd))
-    }
-}
-impl<S: Simd> core::ops::Add<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
-    #[inline(always)]
-    fn add(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.add_u8x16(self.simd_into(rhs.simd), rhs)
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u8x16<S> {
+impl<S: Simd> core::ops::Shr<u32> for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u8x16(self, rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u8> for u8x16<S> {
+impl<S: Simd> core::ops::Shr for u8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u8) -> Self::Output {
-        self.simd.sub_u8x16(self, rhs.simd_into(self.simd))
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
+impl<S: Simd> core::ops::BitAnd for mask8x8<S> {
+    type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.sub_u8x16(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u8x16<S> {
+impl<S: Simd> core::ops::BitOr for mask8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u8x16(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u8> for u8x16<S> {
+impl<S: Simd> core::ops::BitXor for mask8x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u8) -> Self::Output {
-        self.simd.mul_u8x16(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask8x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
+impl<S: Simd> core::ops::Not for mask8x8<S> {
+    type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.mul_u8x16(self.simd_into(rhs.simd), rhs)
+    fn not(self) -> Self::Output {
+        self.simd.not_mask8x8(self)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u8x16<S> {
+impl<S: Simd> core::ops::Add for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u8x16(self, rhs)
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8> for u8x16<S> {
+impl<S: Simd> core::ops::Add<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u8) -> Self::Output {
-        self.simd.and_u8x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u16) -> Self::Output {
+        self.simd.add_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
+impl<S: Simd> core::ops::Add<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.and_u8x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.add_u16x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u8x16<S> {
+impl<S: Simd> core::ops::Sub for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u8x16(self, rhs)
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u8> for u8x16<S> {
+impl<S: Simd> core::ops::Sub<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u8) -> Self::Output {
-        self.simd.or_u8x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u16) -> Self::Output {
+        self.simd.sub_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
+impl<S: Simd> core::ops::Sub<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.or_u8x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.sub_u16x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u8x16<S> {
+impl<S: Simd> core::ops::Mul for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u8x16(self, rhs)
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u8> for u8x16<S> {
+impl<S: Simd> core::ops::Mul<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u8) -> Self::Output {
-        self.simd.xor_u8x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u16) -> Self::Output {
+        self.simd.mul_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u8x16<S>> for u8 {
-    type Output = u8x16<S>;
+impl<S: Simd> core::ops::Mul<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u8x16<S>) -> Self::Output {
-        rhs.simd.xor_u8x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.mul_u16x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask8x16<S> {
+impl<S: Simd> core::ops::BitAnd for u16x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask8x16(self, rhs)
+        self.simd.and_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask8x16<S> {
+impl<S: Simd> core::ops::BitAnd<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask8x16(self, rhs)
+    fn bitand(self, rhs: u16) -> Self::Output {
+        self.simd.and_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor for mask8x16<S> {
+impl<S: Simd> core::ops::BitAnd<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.and_u16x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask8x16(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask8x16<S> {
+impl<S: Simd> core::ops::BitOr<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn not(self) -> Self::Output {
-        self.simd.not_mask8x16(self)
+    fn bitor(self, rhs: u16) -> Self::Output {
+        self.simd.or_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add for i16x8<S> {
+impl<S: Simd> core::ops::BitOr<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.or_u16x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i16x8(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i16> for i16x8<S> {
+impl<S: Simd> core::ops::BitXor<u16> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i16) -> Self::Output {
-        self.simd.add_i16x8(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u16) -> Self::Output {
+        self.simd.xor_u16x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::BitXor<u16x4<S>> for u16 {
+    type Output = u16x4<S>;
     #[inline(always)]
-    fn add(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.add_i16x8(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u16x4<S>) -> Self::Output {
+        rhs.simd.xor_u16x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i16x8<S> {
+impl<S: Simd> core::ops::Shl<u32> for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i16x8(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i16> for i16x8<S> {
+impl<S: Simd> core::ops::Shl for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i16) -> Self::Output {
-        self.simd.sub_i16x8(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::Shr<u32> for u16x4<S> {
+    type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.sub_i16x8(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i16x8<S> {
+impl<S: Simd> core::ops::Shr for u16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i16x8(self, rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i16> for i16x8<S> {
+impl<S: Simd> core::ops::BitAnd for mask16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i16) -> Self::Output {
-        self.simd.mul_i16x8(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::BitOr for mask16x4<S> {
+    type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.mul_i16x8(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i16x8<S> {
+impl<S: Simd> core::ops::BitXor for mask16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i16x8(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask16x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16> for i16x8<S> {
+impl<S: Simd> core::ops::Not for mask16x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i16) -> Self::Output {
-        self.simd.and_i16x8(self, rhs.simd_into(self.simd))
+    fn not(self) -> Self::Output {
+        self.simd.not_mask16x4(self)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::Neg for i32x2<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.and_i16x8(self.simd_into(rhs.simd), rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x2(self)
     }
 }
-impl<S: Simd> core::ops::BitOr for i16x8<S> {
+impl<S: Simd> core::ops::Add for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i16x8(self, rhs)
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i16> for i16x8<S> {
+impl<S: Simd> core::ops::Add<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i16) -> Self::Output {
-        self.simd.or_i16x8(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: i32) -> Self::Output {
+        self.simd.add_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::Add<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn bitor(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.or_i16x8(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.add_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i16x8<S> {
+impl<S: Simd> core::ops::Sub for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i16x8(self, rhs)
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i16> for i16x8<S> {
+impl<S: Simd> core::ops::Sub<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i16) -> Self::Output {
-        self.simd.xor_i16x8(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.simd.sub_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i16x8<S>> for i16 {
-    type Output = i16x8<S>;
+impl<S: Simd> core::ops::Sub<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i16x8<S>) -> Self::Output {
-        rhs.simd.xor_i16x8(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.sub_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u16x8<S> {
+impl<S: Simd> core::ops::Mul for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u16x8(self, rhs)
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u16> for u16x8<S> {
+impl<S: Simd> core::ops::Mul<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u16) -> Self::Output {
-        self.simd.add_u16x8(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.simd.mul_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
+impl<S: Simd> core::ops::Mul<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn add(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.add_u16x8(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.mul_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u16x8<S> {
+impl<S: Simd> core::ops::BitAnd for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u16x8(self, rhs)
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u16> for u16x8<S> {
+impl<S: Simd> core::ops::BitAnd<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u16) -> Self::Output {
-        self.simd.sub_u16x8(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.simd.and_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
+impl<S: Simd> core::ops::BitAnd<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn sub(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.sub_u16x8(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.and_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u16x8<S> {
+impl<S: Simd> core::ops::BitOr for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u16x8(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u16> for u16x8<S> {
+impl<S: Simd> core::ops::BitOr<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u16) -> Self::Output {
-        self.simd.mul_u16x8(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.simd.or_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
+impl<S: Simd> core::ops::BitOr<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn mul(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.mul_u16x8(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.or_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u16x8<S> {
+impl<S: Simd> core::ops::BitXor for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u16x8(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16> for u16x8<S> {
+impl<S: Simd> core::ops::BitXor<i32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u16) -> Self::Output {
-        self.simd.and_u16x8(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.simd.xor_i32x2(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
+impl<S: Simd> core::ops::BitXor<i32x2<S>> for i32 {
+    type Output = i32x2<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.and_u16x8(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i32x2<S>) -> Self::Output {
+        rhs.simd.xor_i32x2(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u16x8<S> {
+impl<S: Simd> core::ops::Shl<u32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u16x8(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u16> for u16x8<S> {
+impl<S: Simd> core::ops::Shl for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u16) -> Self::Output {
-        self.simd.or_u16x8(self, rhs.simd_into(self.simd))
-    }
-}
-impl<S: Simd> core::ops::BitOr<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
-    #[inline(always)]
-    fn bitor(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.or_u16x8(self.simd_into(rhs.simd), rhs)
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u16x8<S> {
+impl<S: Simd> core::ops::Shr<u32> for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u16x8(self, rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u16> for u16x8<S> {
+impl<S: Simd> core::ops::Shr for i32x2<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u16) -> Self::Output {
-        self.simd.xor_u16x8(self, rhs.simd_into(self.simd))
-    }
-}
-impl<S: Simd> core::ops::BitXor<u16x8<S>> for u16 {
-    type Output = u16x8<S>;
-    #[inline(always)]
-    fn bitxor(self, rhs: u16x8<S>) -> Self::Output {
-        rhs.simd.xor_u16x8(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask16x8<S> {
+impl<S: Simd> core::ops::BitAnd for mask32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask16x8(self, rhs)
+        self.simd.and_mask32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask16x8<S> {
+impl<S: Simd> core::ops::BitOr for mask32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask16x8(self, rhs)
+        self.simd.or_mask32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask16x8<S> {
+impl<S: Simd> core::ops::BitXor for mask32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask16x8(self, rhs)
+        self.simd.xor_mask32x2(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask16x8<S> {
+impl<S: Simd> core::ops::Not for mask32x2<S> {
     type Output = Self;
     #[inline(always)]
     fn not(self) -> Self::Output {
-        self.simd.not_mask16x8(self)
+        self.simd.not_mask32x2(self)
     }
 }
-impl<S: Simd> core::ops::Add for i32x4<S> {
+impl<S: Simd> core::ops::Neg for f16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f16x8(self)
+    }
+}
+impl<S: Simd> core::ops::Add for f16x8<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i32x4(self, rhs)
+        self.simd.add_f16x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Add<f16> for f16x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i32) -> Self::Output {
-        self.simd.add_i32x4(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: f16) -> Self::Output {
+        self.simd.add_f16x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Add<f16x8<S>> for f16 {
+    type Output = f16x8<S>;
     #[inline(always)]
-    fn add(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.add_i32x4(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f16x8<S>) -> Self::Output {
+        rhs.simd.add_f16x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i32x4<S> {
+impl<S: Simd> core::ops::Sub for f16x8<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i32x4(self, rhs)
+        self.simd.sub_f16x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Sub<f16> for f16x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i32) -> Self::Output {
-        self.simd.sub_i32x4(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: f16) -> Self::Output {
+        self.simd.sub_f16x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Sub<f16x8<S>> for f16 {
+    type Output = f16x8<S>;
     #[inline(always)]
-    fn sub(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.sub_i32x4(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f16x8<S>) -> Self::Output {
+        rhs.simd.sub_f16x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i32x4<S> {
+impl<S: Simd> core::ops::Mul for f16x8<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i32x4(self, rhs)
+        self.simd.mul_f16x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Mul<f16> for f16x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i32) -> Self::Output {
-        self.simd.mul_i32x4(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: f16) -> Self::Output {
+        self.simd.mul_f16x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Mul<f16x8<S>> for f16 {
+    type Output = f16x8<S>;
     #[inline(always)]
-    fn mul(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.mul_i32x4(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f16x8<S>) -> Self::Output {
+        rhs.simd.mul_f16x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i32x4<S> {
+impl<S: Simd> core::ops::Div for f16x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i32x4(self, rhs)
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f16x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Div<f16> for f16x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i32) -> Self::Output {
-        self.simd.and_i32x4(self, rhs.simd_into(self.simd))
+    fn div(self, rhs: f16) -> Self::Output {
+        self.simd.div_f16x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Div<f16x8<S>> for f16 {
+    type Output = f16x8<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.and_i32x4(self.simd_into(rhs.simd), rhs)
+    fn div(self, rhs: f16x8<S>) -> Self::Output {
+        rhs.simd.div_f16x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i32x4<S> {
+impl<S: Simd> core::ops::Neg for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i32x4(self, rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f32x4(self)
     }
 }
-impl<S: Simd> core::ops::BitOr<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Add for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i32) -> Self::Output {
-        self.simd.or_i32x4(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_f32x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Add<f32> for f32x4<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.or_i32x4(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f32) -> Self::Output {
+        self.simd.add_f32x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor for i32x4<S> {
+impl<S: Simd> core::ops::Add<f32x4<S>> for f32 {
+    type Output = f32x4<S>;
+    #[inline(always)]
+    fn add(self, rhs: f32x4<S>) -> Self::Output {
+        rhs.simd.add_f32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i32x4(self, rhs)
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_f32x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i32> for i32x4<S> {
+impl<S: Simd> core::ops::Sub<f32> for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i32) -> Self::Output {
-        self.simd.xor_i32x4(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.simd.sub_f32x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i32x4<S>> for i32 {
-    type Output = i32x4<S>;
+impl<S: Simd> core::ops::Sub<f32x4<S>> for f32 {
+    type Output = f32x4<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i32x4<S>) -> Self::Output {
-        rhs.simd.xor_i32x4(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f32x4<S>) -> Self::Output {
+        rhs.simd.sub_f32x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u32x4<S> {
+impl<S: Simd> core::ops::Mul for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u32x4(self, rhs)
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_f32x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u32> for u32x4<S> {
+impl<S: Simd> core::ops::Mul<f32> for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u32) -> Self::Output {
-        self.simd.add_u32x4(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.simd.mul_f32x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::Mul<f32x4<S>> for f32 {
+    type Output = f32x4<S>;
     #[inline(always)]
-    fn add(self, rhs: u32x4<S>) -> Self::Output {
-        rhs.simd.add_u32x4(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f32x4<S>) -> Self::Output {
+        rhs.simd.mul_f32x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u32x4<S> {
+impl<S: Simd> core::ops::Div for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u32x4(self, rhs)
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f32x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u32> for u32x4<S> {
+impl<S: Simd> core::ops::Div<f32> for f32x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u32) -> Self::Output {
-        self.simd.sub_u32x4(self, rhs.simd_into(self.simd))
+    fn div(self, rhs: f32) -> Self::Output {
+        self.simd.div_f32x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::Div<f32x4<S>> for f32 {
+    type Output = f32x4<S>;
     #[inline(always)]
-    fn sub(self, rhs: u32x4<S>) -> Self::Output {
-        rhs.simd.sub_u32x4(self.simd_into(rhs.simd), rhs)
+    fn div(self, rhs: f32x4<S>) -> Self::Output {
+        rhs.simd.div_f32x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u32x4<S> {
+impl<S: Simd> core::ops::Neg for i8x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u32x4(self, rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x16(self)
     }
 }
-impl<S: Simd> core::ops::Mul<u32> for u32x4<S> {
+impl<S: Simd> core::ops::Add for i8x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u32) -> Self::Output {
-        self.simd.mul_u32x4(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i8x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::Add<i8> for i8x16<S> {
+    type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u32x4<S>) -> Self::Output {
-        rhs.simd.mul_u32x4(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i8) -> Self::Output {
+        self.simd.add_i8x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd for u32x4<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::Add<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u32x4(self, rhs)
+    fn add(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.add_i8x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32> for u32x4<S> {
+impl<S: Simd> core::ops::Sub for i8x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u32) -> Self::Output {
-        self.simd.and_u32x4(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i8x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::Sub<i8> for i8x16<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u32x4<S>) -> Self::Output {
+    fn sub(self, rhs: i8) -> Self::Output {
+        self.simd.sub_i8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.sub_i8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i8> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i8) -> Self::Output {
+        self.simd.mul_i8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.mul_i8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i8> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i8) -> Self::Output {
+        self.simd.and_i8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.and_i8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i8> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i8) -> Self::Output {
+        self.simd.or_i8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.or_i8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i8> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i8) -> Self::Output {
+        self.simd.xor_i8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i8x16<S>> for i8 {
+    type Output = i8x16<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i8x16<S>) -> Self::Output {
+        rhs.simd.xor_i8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u8) -> Self::Output {
+        self.simd.add_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn add(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.add_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u8) -> Self::Output {
+        self.simd.sub_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.sub_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.simd.mul_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.mul_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u8) -> Self::Output {
+        self.simd.and_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.and_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u8) -> Self::Output {
+        self.simd.or_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.or_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u8> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self.simd.xor_u8x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u8x16<S>> for u8 {
+    type Output = u8x16<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u8x16<S>) -> Self::Output {
+        rhs.simd.xor_u8x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask8x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask8x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask8x16(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x8(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i16) -> Self::Output {
+        self.simd.add_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn add(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.add_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i16) -> Self::Output {
+        self.simd.sub_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.sub_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i16) -> Self::Output {
+        self.simd.mul_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.mul_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i16) -> Self::Output {
+        self.simd.and_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.and_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i16) -> Self::Output {
+        self.simd.or_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.or_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i16> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i16) -> Self::Output {
+        self.simd.xor_i16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i16x8<S>> for i16 {
+    type Output = i16x8<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i16x8<S>) -> Self::Output {
+        rhs.simd.xor_i16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u16) -> Self::Output {
+        self.simd.add_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn add(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.add_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u16) -> Self::Output {
+        self.simd.sub_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.sub_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u16) -> Self::Output {
+        self.simd.mul_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.mul_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u16) -> Self::Output {
+        self.simd.and_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.and_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u16) -> Self::Output {
+        self.simd.or_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.or_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u16> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u16) -> Self::Output {
+        self.simd.xor_u16x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u16x8<S>> for u16 {
+    type Output = u16x8<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u16x8<S>) -> Self::Output {
+        rhs.simd.xor_u16x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask16x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask16x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask16x8(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x4(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i32) -> Self::Output {
+        self.simd.add_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn add(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.add_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.simd.sub_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.sub_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.simd.mul_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.mul_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.simd.and_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.and_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.simd.or_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.or_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.simd.xor_i32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i32x4<S>> for i32 {
+    type Output = i32x4<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i32x4<S>) -> Self::Output {
+        rhs.simd.xor_i32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u32) -> Self::Output {
+        self.simd.add_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn add(self, rhs: u32x4<S>) -> Self::Output {
+        rhs.simd.add_u32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u32) -> Self::Output {
+        self.simd.sub_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u32x4<S>) -> Self::Output {
+        rhs.simd.sub_u32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.simd.mul_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u32x4<S>) -> Self::Output {
+        rhs.simd.mul_u32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u32) -> Self::Output {
+        self.simd.and_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u32x4<S>) -> Self::Output {
         rhs.simd.and_u32x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u32x4<S> {
+impl<S: Simd> core::ops::BitOr for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u32) -> Self::Output {
+        self.simd.or_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u32x4<S>) -> Self::Output {
+        rhs.simd.or_u32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u32) -> Self::Output {
+        self.simd.xor_u32x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u32x4<S>> for u32 {
+    type Output = u32x4<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u32x4<S>) -> Self::Output {
+        rhs.simd.xor_u32x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask32x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask32x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask32x4(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f64x2(self)
+    }
+}
+impl<S: Simd> core::ops::Add for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_f64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<f64> for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: f64) -> Self::Output {
+        self.simd.add_f64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<f64x2<S>> for f64 {
+    type Output = f64x2<S>;
+    #[inline(always)]
+    fn add(self, rhs: f64x2<S>) -> Self::Output {
+        rhs.simd.add_f64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_f64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<f64> for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.simd.sub_f64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<f64x2<S>> for f64 {
+    type Output = f64x2<S>;
+    #[inline(always)]
+    fn sub(self, rhs: f64x2<S>) -> Self::Output {
+        rhs.simd.sub_f64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_f64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<f64> for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.simd.mul_f64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<f64x2<S>> for f64 {
+    type Output = f64x2<S>;
+    #[inline(always)]
+    fn mul(self, rhs: f64x2<S>) -> Self::Output {
+        rhs.simd.mul_f64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Div for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Div<f64> for f64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: f64) -> Self::Output {
+        self.simd.div_f64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Div<f64x2<S>> for f64 {
+    type Output = f64x2<S>;
+    #[inline(always)]
+    fn div(self, rhs: f64x2<S>) -> Self::Output {
+        rhs.simd.div_f64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Neg for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x2(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i64) -> Self::Output {
+        self.simd.add_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn add(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.add_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i64) -> Self::Output {
+        self.simd.sub_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.sub_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.simd.mul_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.mul_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i64) -> Self::Output {
+        self.simd.and_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.and_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i64) -> Self::Output {
+        self.simd.or_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.or_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i64> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i64) -> Self::Output {
+        self.simd.xor_i64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i64x2<S>> for i64 {
+    type Output = i64x2<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i64x2<S>) -> Self::Output {
+        rhs.simd.xor_i64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u64) -> Self::Output {
+        self.simd.add_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn add(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.add_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u64) -> Self::Output {
+        self.simd.sub_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.sub_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.simd.mul_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.mul_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u64) -> Self::Output {
+        self.simd.and_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.and_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u64) -> Self::Output {
+        self.simd.or_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.or_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u64> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u64) -> Self::Output {
+        self.simd.xor_u64x2(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u64x2<S>> for u64 {
+    type Output = u64x2<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u64x2<S>) -> Self::Output {
+        rhs.simd.xor_u64x2(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask64x2(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask64x2<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask64x2(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f16x16(self)
+    }
+}
+impl<S: Simd> core::ops::Add for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_f16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<f16> for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: f16) -> Self::Output {
+        self.simd.add_f16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<f16x16<S>> for f16 {
+    type Output = f16x16<S>;
+    #[inline(always)]
+    fn add(self, rhs: f16x16<S>) -> Self::Output {
+        rhs.simd.add_f16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_f16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<f16> for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: f16) -> Self::Output {
+        self.simd.sub_f16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<f16x16<S>> for f16 {
+    type Output = f16x16<S>;
+    #[inline(always)]
+    fn sub(self, rhs: f16x16<S>) -> Self::Output {
+        rhs.simd.sub_f16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_f16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<f16> for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: f16) -> Self::Output {
+        self.simd.mul_f16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<f16x16<S>> for f16 {
+    type Output = f16x16<S>;
+    #[inline(always)]
+    fn mul(self, rhs: f16x16<S>) -> Self::Output {
+        rhs.simd.mul_f16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Div for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Div<f16> for f16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: f16) -> Self::Output {
+        self.simd.div_f16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Div<f16x16<S>> for f16 {
+    type Output = f16x16<S>;
+    #[inline(always)]
+    fn div(self, rhs: f16x16<S>) -> Self::Output {
+        rhs.simd.div_f16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Neg for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f32x8(self)
+    }
+}
+impl<S: Simd> core::ops::Add for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_f32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<f32> for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: f32) -> Self::Output {
+        self.simd.add_f32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<f32x8<S>> for f32 {
+    type Output = f32x8<S>;
+    #[inline(always)]
+    fn add(self, rhs: f32x8<S>) -> Self::Output {
+        rhs.simd.add_f32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_f32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<f32> for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.simd.sub_f32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<f32x8<S>> for f32 {
+    type Output = f32x8<S>;
+    #[inline(always)]
+    fn sub(self, rhs: f32x8<S>) -> Self::Output {
+        rhs.simd.sub_f32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_f32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<f32> for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.simd.mul_f32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<f32x8<S>> for f32 {
+    type Output = f32x8<S>;
+    #[inline(always)]
+    fn mul(self, rhs: f32x8<S>) -> Self::Output {
+        rhs.simd.mul_f32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Div for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Div<f32> for f32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: f32) -> Self::Output {
+        self.simd.div_f32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Div<f32x8<S>> for f32 {
+    type Output = f32x8<S>;
+    #[inline(always)]
+    fn div(self, rhs: f32x8<S>) -> Self::Output {
+        rhs.simd.div_f32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Neg for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x32(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i8) -> Self::Output {
+        self.simd.add_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn add(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.add_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i8) -> Self::Output {
+        self.simd.sub_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.sub_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i8) -> Self::Output {
+        self.simd.mul_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.mul_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i8) -> Self::Output {
+        self.simd.and_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.and_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i8) -> Self::Output {
+        self.simd.or_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.or_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i8> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i8) -> Self::Output {
+        self.simd.xor_i8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i8x32<S>> for i8 {
+    type Output = i8x32<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i8x32<S>) -> Self::Output {
+        rhs.simd.xor_i8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u8) -> Self::Output {
+        self.simd.add_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn add(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.add_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u8) -> Self::Output {
+        self.simd.sub_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.sub_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.simd.mul_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.mul_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u8) -> Self::Output {
+        self.simd.and_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.and_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u8) -> Self::Output {
+        self.simd.or_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.or_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u8> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self.simd.xor_u8x32(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u8x32<S>> for u8 {
+    type Output = u8x32<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u8x32<S>) -> Self::Output {
+        rhs.simd.xor_u8x32(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask8x32(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask8x32<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask8x32(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x16(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i16) -> Self::Output {
+        self.simd.add_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn add(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.add_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i16) -> Self::Output {
+        self.simd.sub_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.sub_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i16) -> Self::Output {
+        self.simd.mul_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.mul_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i16) -> Self::Output {
+        self.simd.and_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.and_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i16) -> Self::Output {
+        self.simd.or_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.or_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i16> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i16) -> Self::Output {
+        self.simd.xor_i16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i16x16<S>> for i16 {
+    type Output = i16x16<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i16x16<S>) -> Self::Output {
+        rhs.simd.xor_i16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u16) -> Self::Output {
+        self.simd.add_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn add(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.add_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u16) -> Self::Output {
+        self.simd.sub_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.sub_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u16) -> Self::Output {
+        self.simd.mul_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.mul_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u16) -> Self::Output {
+        self.simd.and_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.and_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: u16) -> Self::Output {
+        self.simd.or_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.or_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<u16> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: u16) -> Self::Output {
+        self.simd.xor_u16x16(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<u16x16<S>> for u16 {
+    type Output = u16x16<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: u16x16<S>) -> Self::Output {
+        rhs.simd.xor_u16x16(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for mask16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for mask16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask16x16(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Not for mask16x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        self.simd.not_mask16x16(self)
+    }
+}
+impl<S: Simd> core::ops::Neg for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x8(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: i32) -> Self::Output {
+        self.simd.add_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn add(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.add_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.simd.sub_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.sub_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.simd.mul_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn mul(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.mul_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.simd.and_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.and_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.simd.or_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitOr<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn bitor(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.or_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitXor<i32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.simd.xor_i32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitXor<i32x8<S>> for i32 {
+    type Output = i32x8<S>;
+    #[inline(always)]
+    fn bitxor(self, rhs: i32x8<S>) -> Self::Output {
+        rhs.simd.xor_i32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl<u32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn add(self, rhs: u32) -> Self::Output {
+        self.simd.add_u32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Add<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
+    #[inline(always)]
+    fn add(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.add_u32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn sub(self, rhs: u32) -> Self::Output {
+        self.simd.sub_u32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Sub<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
+    #[inline(always)]
+    fn sub(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.sub_u32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.simd.mul_u32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
+    #[inline(always)]
+    fn mul(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.mul_u32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: u32) -> Self::Output {
+        self.simd.and_u32x8(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.and_u32x8(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for u32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u32x4(self, rhs)
+        self.simd.or_u32x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u32> for u32x4<S> {
+impl<S: Simd> core::ops::BitOr<u32> for u32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: u32) -> Self::Output {
-        self.simd.or_u32x4(self, rhs.simd_into(self.simd))
+        self.simd.or_u32x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::BitOr<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u32x4<S>) -> Self::Output {
-        rhs.simd.or_u32x4(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.or_u32x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u32x4<S> {
+impl<S: Simd> core::ops::BitXor for u32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u32x4(self, rhs)
+        self.simd.xor_u32x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u32> for u32x4<S> {
+impl<S: Simd> core::ops::BitXor<u32> for u32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: u32) -> Self::Output {
-        self.simd.xor_u32x4(self, rhs.simd_into(self.simd))
+        self.simd.xor_u32x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u32x4<S>> for u32 {
-    type Output = u32x4<S>;
+impl<S: Simd> core::ops::BitXor<u32x8<S>> for u32 {
+    type Output = u32x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u32x4<S>) -> Self::Output {
-        rhs.simd.xor_u32x4(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u32x8<S>) -> Self::Output {
+        rhs.simd.xor_u32x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask32x4<S> {
+impl<S: Simd> core::ops::Shl<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u32x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u32x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask32x4(self, rhs)
+        self.simd.and_mask32x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask32x4<S> {
+impl<S: Simd> core::ops::BitOr for mask32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask32x4(self, rhs)
+        self.simd.or_mask32x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask32x4<S> {
+impl<S: Simd> core::ops::BitXor for mask32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask32x4(self, rhs)
+        self.simd.xor_mask32x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask32x4<S> {
+impl<S: Simd> core::ops::Not for mask32x8<S> {
     type Output = Self;
     #[inline(always)]
     fn not(self) -> Self::Output {
-        self.simd.not_mask32x4(self)
+        self.simd.not_mask32x8(self)
     }
 }
-impl<S: Simd> core::ops::Neg for f32x8<S> {
+impl<S: Simd> core::ops::Neg for f64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn neg(self) -> Self::Output {
-        self.simd.neg_f32x8(self)
+        self.simd.neg_f64x4(self)
     }
 }
-impl<S: Simd> core::ops::Add for f32x8<S> {
+impl<S: Simd> core::ops::Add for f64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_f32x8(self, rhs)
+        self.simd.add_f64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<f32> for f32x8<S> {
+impl<S: Simd> core::ops::Add<f64> for f64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: f32) -> Self::Output {
-        self.simd.add_f32x8(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: f64) -> Self::Output {
+        self.simd.add_f64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<f32x8<S>> for f32 {
-    type Output = f32x8<S>;
+impl<S: Simd> core::ops::Add<f64x4<S>> for f64 {
+    type Output = f64x4<S>;
     #[inline(always)]
-    fn add(self, rhs: f32x8<S>) -> Self::Output {
-        rhs.simd.add_f32x8(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f64x4<S>) -> Self::Output {
+        rhs.simd.add_f64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for f32x8<S> {
+impl<S: Simd> core::ops::Sub for f64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_f32x8(self, rhs)
+        self.simd.sub_f64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<f32> for f32x8<S> {
+impl<S: Simd> core::ops::Sub<f64> for f64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: f32) -> Self::Output {
-        self.simd.sub_f32x8(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.simd.sub_f64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<f32x8<S>> for f32 {
-    type Output = f32x8<S>;
+impl<S: Simd> core::ops::Sub<f64x4<S>> for f64 {
+    type Output = f64x4<S>;
     #[inline(always)]
-    fn sub(self, rhs: f32x8<S>) -> Self::Output {
-        rhs.simd.sub_f32x8(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f64x4<S>) -> Self::Output {
+        rhs.simd.sub_f64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for f32x8<S> {
+impl<S: Simd> core::ops::Mul for f64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_f32x8(self, rhs)
+        self.simd.mul_f64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<f32> for f32x8<S> {
+impl<S: Simd> core::ops::Mul<f64> for f64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: f32) -> Self::Output {
-        self.simd.mul_f32x8(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.simd.mul_f64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<f32x8<S>> for f32 {
-    type Output = f32x8<S>;
+impl<S: Simd> core::ops::Mul<f64x4<S>> for f64 {
+    type Output = f64x4<S>;
     #[inline(always)]
-    fn mul(self, rhs: f32x8<S>) -> Self::Output {
-        rhs.simd.mul_f32x8(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f64x4<S>) -> Self::Output {
+        rhs.simd.mul_f64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Div for f32x8<S> {
+impl<S: Simd> core::ops::Div for f64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn div(self, rhs: Self) -> Self::Output {
-        self.simd.div_f32x8(self, rhs)
+        self.simd.div_f64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Div<f32> for f32x8<S> {
+impl<S: Simd> core::ops::Div<f64> for f64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn div(self, rhs: f32) -> Self::Output {
-        self.simd.div_f32x8(self, rhs.simd_into(self.simd))
+    fn div(self, rhs: f64) -> Self::Output {
+        self.simd.div_f64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Div<f32x8<S>> for f32 {
-    type Output = f32x8<S>;
+impl<S: Simd> core::ops::Div<f64x4<S>> for f64 {
+    type Output = f64x4<S>;
     #[inline(always)]
-    fn div(self, rhs: f32x8<S>) -> Self::Output {
-        rhs.simd.div_f32x8(self.simd_into(rhs.simd), rhs)
+    fn div(self, rhs: f64x4<S>) -> Self::Output {
+        rhs.simd.div_f64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for i8x32<S> {
+impl<S: Simd> core::ops::Neg for i64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x4(self)
+    }
+}
+impl<S: Simd> core::ops::Add for i64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i8x32(self, rhs)
+        self.simd.add_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i8> for i8x32<S> {
+impl<S: Simd> core::ops::Add<i64> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i8) -> Self::Output {
-        self.simd.add_i8x32(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: i64) -> Self::Output {
+        self.simd.add_i64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::Add<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
     #[inline(always)]
-    fn add(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.add_i8x32(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.add_i64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i8x32<S> {
+impl<S: Simd> core::ops::Sub for i64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i8x32(self, rhs)
+        self.simd.sub_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i8> for i8x32<S> {
+impl<S: Simd> core::ops::Sub<i64> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i8) -> Self::Output {
-        self.simd.sub_i8x32(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i64) -> Self::Output {
+        self.simd.sub_i64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::Sub<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
     #[inline(always)]
-    fn sub(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.sub_i8x32(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.sub_i64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i8x32<S> {
+impl<S: Simd> core::ops::Mul for i64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i8x32(self, rhs)
+        self.simd.mul_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i8> for i8x32<S> {
+impl<S: Simd> core::ops::Mul<i64> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i8) -> Self::Output {
-        self.simd.mul_i8x32(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.simd.mul_i64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::Mul<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
     #[inline(always)]
-    fn mul(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.mul_i8x32(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.mul_i64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i8x32<S> {
+impl<S: Simd> core::ops::BitAnd for i64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i8x32(self, rhs)
+        self.simd.and_i64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i64> for i64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitand(self, rhs: i64) -> Self::Output {
+        self.simd.and_i64x4(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::BitAnd<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
+    #[inline(always)]
+    fn bitand(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.and_i64x4(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::BitOr for i64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8> for i8x32<S> {
+impl<S: Simd> core::ops::BitOr<i64> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i8) -> Self::Output {
-        self.simd.and_i8x32(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i64) -> Self::Output {
+        self.simd.or_i64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::BitOr<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.and_i8x32(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.or_i64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i8x32<S> {
+impl<S: Simd> core::ops::BitXor for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i8x32(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i8> for i8x32<S> {
+impl<S: Simd> core::ops::BitXor<i64> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i8) -> Self::Output {
-        self.simd.or_i8x32(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: i64) -> Self::Output {
+        self.simd.xor_i64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::BitXor<i64x4<S>> for i64 {
+    type Output = i64x4<S>;
     #[inline(always)]
-    fn bitor(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.or_i8x32(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i64x4<S>) -> Self::Output {
+        rhs.simd.xor_i64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i8x32<S> {
+impl<S: Simd> core::ops::Shl<u32> for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i8x32(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i8> for i8x32<S> {
+impl<S: Simd> core::ops::Shl for i64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i8) -> Self::Output {
-        self.simd.xor_i8x32(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i8x32<S>> for i8 {
-    type Output = i8x32<S>;
+impl<S: Simd> core::ops::Shr<u32> for i64x4<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i8x32<S>) -> Self::Output {
-        rhs.simd.xor_i8x32(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u8x32<S> {
+impl<S: Simd> core::ops::Shr for i64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u8x32(self, rhs)
+        self.simd.add_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u8> for u8x32<S> {
+impl<S: Simd> core::ops::Add<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u8) -> Self::Output {
-        self.simd.add_u8x32(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u64) -> Self::Output {
+        self.simd.add_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::Add<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn add(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.add_u8x32(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.add_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u8x32<S> {
+impl<S: Simd> core::ops::Sub for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u8x32(self, rhs)
+        self.simd.sub_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u8> for u8x32<S> {
+impl<S: Simd> core::ops::Sub<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u8) -> Self::Output {
-        self.simd.sub_u8x32(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u64) -> Self::Output {
+        self.simd.sub_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::Sub<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn sub(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.sub_u8x32(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.sub_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u8x32<S> {
+impl<S: Simd> core::ops::Mul for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u8x32(self, rhs)
+        self.simd.mul_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u8> for u8x32<S> {
+impl<S: Simd> core::ops::Mul<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u8) -> Self::Output {
-        self.simd.mul_u8x32(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.simd.mul_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::Mul<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn mul(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.mul_u8x32(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.mul_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u8x32<S> {
+impl<S: Simd> core::ops::BitAnd for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u8x32(self, rhs)
+        self.simd.and_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8> for u8x32<S> {
+impl<S: Simd> core::ops::BitAnd<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u8) -> Self::Output {
-        self.simd.and_u8x32(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u64) -> Self::Output {
+        self.simd.and_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::BitAnd<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.and_u8x32(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.and_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u8x32<S> {
+impl<S: Simd> core::ops::BitOr for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u8x32(self, rhs)
+        self.simd.or_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u8> for u8x32<S> {
+impl<S: Simd> core::ops::BitOr<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u8) -> Self::Output {
-        self.simd.or_u8x32(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u64) -> Self::Output {
+        self.simd.or_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::BitOr<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.or_u8x32(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.or_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u8x32<S> {
+impl<S: Simd> core::ops::BitXor for u64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u8x32(self, rhs)
+        self.simd.xor_u64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u8> for u8x32<S> {
+impl<S: Simd> core::ops::BitXor<u64> for u64x4<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u8) -> Self::Output {
-        self.simd.xor_u8x32(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u64) -> Self::Output {
+        self.simd.xor_u64x4(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u8x32<S>> for u8 {
-    type Output = u8x32<S>;
+impl<S: Simd> core::ops::BitXor<u64x4<S>> for u64 {
+    type Output = u64x4<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u8x32<S>) -> Self::Output {
-        rhs.simd.xor_u8x32(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u64x4<S>) -> Self::Output {
+        rhs.simd.xor_u64x4(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask8x32<S> {
+impl<S: Simd> core::ops::Shl<u32> for u64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u64x4<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u64x4(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask8x32(self, rhs)
+        self.simd.and_mask64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask8x32<S> {
+impl<S: Simd> core::ops::BitOr for mask64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask8x32(self, rhs)
+        self.simd.or_mask64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask8x32<S> {
+impl<S: Simd> core::ops::BitXor for mask64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask8x32(self, rhs)
+        self.simd.xor_mask64x4(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask8x32<S> {
+impl<S: Simd> core::ops::Not for mask64x4<S> {
     type Output = Self;
     #[inline(always)]
     fn not(self) -> Self::Output {
-        self.simd.not_mask8x32(self)
+        self.simd.not_mask64x4(self)
     }
 }
-impl<S: Simd> core::ops::Add for i16x16<S> {
+impl<S: Simd> core::ops::Neg for f32x16<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f32x16(self)
+    }
+}
+impl<S: Simd> core::ops::Add for f32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i16x16(self, rhs)
+        self.simd.add_f32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Add<f32> for f32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i16) -> Self::Output {
-        self.simd.add_i16x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: f32) -> Self::Output {
+        self.simd.add_f32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Add<f32x16<S>> for f32 {
+    type Output = f32x16<S>;
     #[inline(always)]
-    fn add(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.add_i16x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f32x16<S>) -> Self::Output {
+        rhs.simd.add_f32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i16x16<S> {
+impl<S: Simd> core::ops::Sub for f32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i16x16(self, rhs)
+        self.simd.sub_f32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Sub<f32> for f32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i16) -> Self::Output {
-        self.simd.sub_i16x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.simd.sub_f32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Sub<f32x16<S>> for f32 {
+    type Output = f32x16<S>;
     #[inline(always)]
-    fn sub(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.sub_i16x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f32x16<S>) -> Self::Output {
+        rhs.simd.sub_f32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i16x16<S> {
+impl<S: Simd> core::ops::Mul for f32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i16x16(self, rhs)
+        self.simd.mul_f32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Mul<f32> for f32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i16) -> Self::Output {
-        self.simd.mul_i16x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.simd.mul_f32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Mul<f32x16<S>> for f32 {
+    type Output = f32x16<S>;
     #[inline(always)]
-    fn mul(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.mul_i16x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f32x16<S>) -> Self::Output {
+        rhs.simd.mul_f32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i16x16<S> {
+impl<S: Simd> core::ops::Div for f32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i16x16(self, rhs)
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Div<f32> for f32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i16) -> Self::Output {
-        self.simd.and_i16x16(self, rhs.simd_into(self.simd))
+    fn div(self, rhs: f32) -> Self::Output {
+        self.simd.div_f32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Div<f32x16<S>> for f32 {
+    type Output = f32x16<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.and_i16x16(self.simd_into(rhs.simd), rhs)
+    fn div(self, rhs: f32x16<S>) -> Self::Output {
+        rhs.simd.div_f32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i16x16<S> {
+impl<S: Simd> core::ops::Neg for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i16x16(self, rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i8x64(self)
     }
 }
-impl<S: Simd> core::ops::BitOr<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Add for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i16) -> Self::Output {
-        self.simd.or_i16x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Add<i8> for i8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.or_i16x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i8) -> Self::Output {
+        self.simd.add_i8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor for i16x16<S> {
+impl<S: Simd> core::ops::Add<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
+    #[inline(always)]
+    fn add(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.add_i8x64(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Sub for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i16x16(self, rhs)
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i16> for i16x16<S> {
+impl<S: Simd> core::ops::Sub<i8> for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i16) -> Self::Output {
-        self.simd.xor_i16x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i8) -> Self::Output {
+        self.simd.sub_i8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i16x16<S>> for i16 {
-    type Output = i16x16<S>;
+impl<S: Simd> core::ops::Sub<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
+    #[inline(always)]
+    fn sub(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.sub_i8x64(self.simd_into(rhs.simd), rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul for i8x64<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_i8x64(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Mul<i8> for i8x64<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: i8) -> Self::Output {
+        self.simd.mul_i8x64(self, rhs.simd_into(self.simd))
+    }
+}
+impl<S: Simd> core::ops::Mul<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i16x16<S>) -> Self::Output {
-        rhs.simd.xor_i16x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.mul_i8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u16x16<S> {
+impl<S: Simd> core::ops::BitAnd for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u16x16(self, rhs)
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u16> for u16x16<S> {
+impl<S: Simd> core::ops::BitAnd<i8> for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u16) -> Self::Output {
-        self.simd.add_u16x16(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: i8) -> Self::Output {
+        self.simd.and_i8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::BitAnd<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
     #[inline(always)]
-    fn add(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.add_u16x16(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.and_i8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u16x16<S> {
+impl<S: Simd> core::ops::BitOr for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u16x16(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u16> for u16x16<S> {
+impl<S: Simd> core::ops::BitOr<i8> for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u16) -> Self::Output {
-        self.simd.sub_u16x16(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i8) -> Self::Output {
+        self.simd.or_i8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::BitOr<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
     #[inline(always)]
-    fn sub(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.sub_u16x16(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.or_i8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u16x16<S> {
+impl<S: Simd> core::ops::BitXor for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u16x16(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u16> for u16x16<S> {
+impl<S: Simd> core::ops::BitXor<i8> for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u16) -> Self::Output {
-        self.simd.mul_u16x16(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: i8) -> Self::Output {
+        self.simd.xor_i8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::BitXor<i8x64<S>> for i8 {
+    type Output = i8x64<S>;
     #[inline(always)]
-    fn mul(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.mul_u16x16(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i8x64<S>) -> Self::Output {
+        rhs.simd.xor_i8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u16x16<S> {
+impl<S: Simd> core::ops::Shl<u32> for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u16x16(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16> for u16x16<S> {
+impl<S: Simd> core::ops::Shl for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u16) -> Self::Output {
-        self.simd.and_u16x16(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::Shr<u32> for i8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.and_u16x16(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u16x16<S> {
+impl<S: Simd> core::ops::Shr for i8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u16x16(self, rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u16> for u16x16<S> {
+impl<S: Simd> core::ops::Add for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u16) -> Self::Output {
-        self.simd.or_u16x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::Add<u8> for u8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.or_u16x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u8) -> Self::Output {
+        self.simd.add_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor for u16x16<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::Add<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u16x16(self, rhs)
+    fn add(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.add_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u16> for u16x16<S> {
+impl<S: Simd> core::ops::Sub for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u16) -> Self::Output {
-        self.simd.xor_u16x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u16x16<S>> for u16 {
-    type Output = u16x16<S>;
+impl<S: Simd> core::ops::Sub<u8> for u8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u16x16<S>) -> Self::Output {
-        rhs.simd.xor_u16x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u8) -> Self::Output {
+        self.simd.sub_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask16x16<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::Sub<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask16x16(self, rhs)
+    fn sub(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.sub_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask16x16<S> {
+impl<S: Simd> core::ops::Mul for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask16x16(self, rhs)
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask16x16<S> {
+impl<S: Simd> core::ops::Mul<u8> for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask16x16(self, rhs)
+    fn mul(self, rhs: u8) -> Self::Output {
+        self.simd.mul_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Not for mask16x16<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::Mul<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn not(self) -> Self::Output {
-        self.simd.not_mask16x16(self)
+    fn mul(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.mul_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for i32x8<S> {
+impl<S: Simd> core::ops::BitAnd for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i32x8(self, rhs)
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i32> for i32x8<S> {
+impl<S: Simd> core::ops::BitAnd<u8> for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i32) -> Self::Output {
-        self.simd.add_i32x8(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u8) -> Self::Output {
+        self.simd.and_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::BitAnd<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn add(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.add_i32x8(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.and_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i32x8<S> {
+impl<S: Simd> core::ops::BitOr for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i32x8(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i32> for i32x8<S> {
+impl<S: Simd> core::ops::BitOr<u8> for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i32) -> Self::Output {
-        self.simd.sub_i32x8(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u8) -> Self::Output {
+        self.simd.or_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::BitOr<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn sub(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.sub_i32x8(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.or_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i32x8<S> {
+impl<S: Simd> core::ops::BitXor for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i32x8(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i32> for i32x8<S> {
+impl<S: Simd> core::ops::BitXor<u8> for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i32) -> Self::Output {
-        self.simd.mul_i32x8(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self.simd.xor_u8x64(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::BitXor<u8x64<S>> for u8 {
+    type Output = u8x64<S>;
     #[inline(always)]
-    fn mul(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.mul_i32x8(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u8x64<S>) -> Self::Output {
+        rhs.simd.xor_u8x64(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i32x8<S> {
+impl<S: Simd> core::ops::Shl<u32> for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i32x8(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32> for i32x8<S> {
+impl<S: Simd> core::ops::Shl for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i32) -> Self::Output {
-        self.simd.and_i32x8(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::Shr<u32> for u8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.and_i32x8(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i32x8<S> {
+impl<S: Simd> core::ops::Shr for u8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i32x8(self, rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i32> for i32x8<S> {
+impl<S: Simd> core::ops::BitAnd for mask8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i32) -> Self::Output {
-        self.simd.or_i32x8(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::BitOr for mask8x64<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.or_i32x8(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i32x8<S> {
+impl<S: Simd> core::ops::BitXor for mask8x64<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i32x8(self, rhs)
+        self.simd.xor_mask8x64(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i32> for i32x8<S> {
+impl<S: Simd> core::ops::Not for mask8x64<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i32) -> Self::Output {
-        self.simd.xor_i32x8(self, rhs.simd_into(self.simd))
+    fn not(self) -> Self::Output {
+        self.simd.not_mask8x64(self)
     }
 }
-impl<S: Simd> core::ops::BitXor<i32x8<S>> for i32 {
-    type Output = i32x8<S>;
+impl<S: Simd> core::ops::Neg for i16x32<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i32x8<S>) -> Self::Output {
-        rhs.simd.xor_i32x8(self.simd_into(rhs.simd), rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i16x32(self)
     }
 }
-impl<S: Simd> core::ops::Add for u32x8<S> {
+impl<S: Simd> core::ops::Add for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u32x8(self, rhs)
+        self.simd.add_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u32> for u32x8<S> {
+impl<S: Simd> core::ops::Add<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u32) -> Self::Output {
-        self.simd.add_u32x8(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: i16) -> Self::Output {
+        self.simd.add_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
+impl<S: Simd> core::ops::Add<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn add(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.add_u32x8(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.add_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u32x8<S> {
+impl<S: Simd> core::ops::Sub for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u32x8(self, rhs)
+        self.simd.sub_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u32> for u32x8<S> {
+impl<S: Simd> core::ops::Sub<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u32) -> Self::Output {
-        self.simd.sub_u32x8(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i16) -> Self::Output {
+        self.simd.sub_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
+impl<S: Simd> core::ops::Sub<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn sub(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.sub_u32x8(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.sub_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u32x8<S> {
+impl<S: Simd> core::ops::Mul for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u32x8(self, rhs)
+        self.simd.mul_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u32> for u32x8<S> {
+impl<S: Simd> core::ops::Mul<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u32) -> Self::Output {
-        self.simd.mul_u32x8(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: i16) -> Self::Output {
+        self.simd.mul_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
+impl<S: Simd> core::ops::Mul<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn mul(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.mul_u32x8(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.mul_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u32x8<S> {
+impl<S: Simd> core::ops::BitAnd for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u32x8(self, rhs)
+        self.simd.and_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32> for u32x8<S> {
+impl<S: Simd> core::ops::BitAnd<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u32) -> Self::Output {
-        self.simd.and_u32x8(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: i16) -> Self::Output {
+        self.simd.and_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
+impl<S: Simd> core::ops::BitAnd<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.and_u32x8(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.and_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u32x8<S> {
+impl<S: Simd> core::ops::BitOr for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u32x8(self, rhs)
+        self.simd.or_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u32> for u32x8<S> {
+impl<S: Simd> core::ops::BitOr<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u32) -> Self::Output {
-        self.simd.or_u32x8(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i16) -> Self::Output {
+        self.simd.or_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
+impl<S: Simd> core::ops::BitOr<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.or_u32x8(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.or_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u32x8<S> {
+impl<S: Simd> core::ops::BitXor for i16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u32x8(self, rhs)
+        self.simd.xor_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u32> for u32x8<S> {
+impl<S: Simd> core::ops::BitXor<i16> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u32) -> Self::Output {
-        self.simd.xor_u32x8(self, rhs.simd_into(self.simd))
-    }
-}
-impl<S: Simd> core::ops::BitXor<u32x8<S>> for u32 {
-    type Output = u32x8<S>;
-    #[inline(always)]
-    fn bitxor(self, rhs: u32x8<S>) -> Self::Output {
-        rhs.simd.xor_u32x8(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i16) -> Self::Output {
+        self.simd.xor_i16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask32x8<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::BitXor<i16x32<S>> for i16 {
+    type Output = i16x32<S>;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask32x8(self, rhs)
+    fn bitxor(self, rhs: i16x32<S>) -> Self::Output {
+        rhs.simd.xor_i16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask32x8<S> {
+impl<S: Simd> core::ops::Shl<u32> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask32x8(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask32x8<S> {
+impl<S: Simd> core::ops::Shl for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask32x8(self, rhs)
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask32x8<S> {
+impl<S: Simd> core::ops::Shr<u32> for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn not(self) -> Self::Output {
-        self.simd.not_mask32x8(self)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Neg for f32x16<S> {
+impl<S: Simd> core::ops::Shr for i16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn neg(self) -> Self::Output {
-        self.simd.neg_f32x16(self)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add for f32x16<S> {
+impl<S: Simd> core::ops::Add for u16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_f32x16(self, rhs)
+        self.simd.add_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<f32> for f32x16<S> {
+impl<S: Simd> core::ops::Add<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: f32) -> Self::Output {
-        self.simd.add_f32x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u16) -> Self::Output {
+        self.simd.add_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<f32x16<S>> for f32 {
-    type Output = f32x16<S>;
+impl<S: Simd> core::ops::Add<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn add(self, rhs: f32x16<S>) -> Self::Output {
-        rhs.simd.add_f32x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.add_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for f32x16<S> {
+impl<S: Simd> core::ops::Sub for u16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_f32x16(self, rhs)
+        self.simd.sub_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<f32> for f32x16<S> {
+impl<S: Simd> core::ops::Sub<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: f32) -> Self::Output {
-        self.simd.sub_f32x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u16) -> Self::Output {
+        self.simd.sub_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<f32x16<S>> for f32 {
-    type Output = f32x16<S>;
+impl<S: Simd> core::ops::Sub<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn sub(self, rhs: f32x16<S>) -> Self::Output {
-        rhs.simd.sub_f32x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.sub_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for f32x16<S> {
+impl<S: Simd> core::ops::Mul for u16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_f32x16(self, rhs)
-    }
-}
-impl<S: Simd> core::ops::Mul<f32> for f32x16<S> {
-    type Output = Self;
-    #[inline(always)]
-    fn mul(self, rhs: f32) -> Self::Output {
-        self.simd.mul_f32x16(self, rhs.simd_into(self.simd))
-    }
-}
-impl<S: Simd> core::ops::Mul<f32x16<S>> for f32 {
-    type Output = f32x16<S>;
-    #[inline(always)]
-    fn mul(self, rhs: f32x16<S>) -> Self::Output {
-        rhs.simd.mul_f32x16(self.simd_into(rhs.simd), rhs)
-    }
-}
-impl<S: Simd> core::ops::Div for f32x16<S> {
-    type Output = Self;
-    #[inline(always)]
-    fn div(self, rhs: Self) -> Self::Output {
-        self.simd.div_f32x16(self, rhs)
+        self.simd.mul_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Div<f32> for f32x16<S> {
+impl<S: Simd> core::ops::Mul<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn div(self, rhs: f32) -> Self::Output {
-        self.simd.div_f32x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u16) -> Self::Output {
+        self.simd.mul_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Div<f32x16<S>> for f32 {
-    type Output = f32x16<S>;
+impl<S: Simd> core::ops::Mul<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn div(self, rhs: f32x16<S>) -> Self::Output {
-        rhs.simd.div_f32x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.mul_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for i8x64<S> {
+impl<S: Simd> core::ops::BitAnd for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i8x64(self, rhs)
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i8> for i8x64<S> {
+impl<S: Simd> core::ops::BitAnd<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i8) -> Self::Output {
-        self.simd.add_i8x64(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u16) -> Self::Output {
+        self.simd.and_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::BitAnd<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn add(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.add_i8x64(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.and_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i8x64<S> {
+impl<S: Simd> core::ops::BitOr for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i8x64(self, rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i8> for i8x64<S> {
+impl<S: Simd> core::ops::BitOr<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i8) -> Self::Output {
-        self.simd.sub_i8x64(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u16) -> Self::Output {
+        self.simd.or_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::BitOr<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn sub(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.sub_i8x64(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.or_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i8x64<S> {
+impl<S: Simd> core::ops::BitXor for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i8x64(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i8> for i8x64<S> {
+impl<S: Simd> core::ops::BitXor<u16> for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i8) -> Self::Output {
-        self.simd.mul_i8x64(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u16) -> Self::Output {
+        self.simd.xor_u16x32(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::BitXor<u16x32<S>> for u16 {
+    type Output = u16x32<S>;
     #[inline(always)]
-    fn mul(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.mul_i8x64(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u16x32<S>) -> Self::Output {
+        rhs.simd.xor_u16x32(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i8x64<S> {
+impl<S: Simd> core::ops::Shl<u32> for u16x32<S> {
     type Output = Self;
-    #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i8x64(self, rhs)
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8> for i8x64<S> {
+impl<S: Simd> core::ops::Shl for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i8) -> Self::Output {
-        self.simd.and_i8x64(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::Shr<u32> for u16x32<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.and_i8x64(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i8x64<S> {
+impl<S: Simd> core::ops::Shr for u16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i8x64(self, rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i8> for i8x64<S> {
+impl<S: Simd> core::ops::BitAnd for mask16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i8) -> Self::Output {
-        self.simd.or_i8x64(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::BitOr for mask16x32<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.or_i8x64(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i8x64<S> {
+impl<S: Simd> core::ops::BitXor for mask16x32<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i8x64(self, rhs)
+        self.simd.xor_mask16x32(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i8> for i8x64<S> {
+impl<S: Simd> core::ops::Not for mask16x32<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i8) -> Self::Output {
-        self.simd.xor_i8x64(self, rhs.simd_into(self.simd))
+    fn not(self) -> Self::Output {
+        self.simd.not_mask16x32(self)
     }
 }
-impl<S: Simd> core::ops::BitXor<i8x64<S>> for i8 {
-    type Output = i8x64<S>;
+impl<S: Simd> core::ops::Neg for i32x16<S> {
+    type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i8x64<S>) -> Self::Output {
-        rhs.simd.xor_i8x64(self.simd_into(rhs.simd), rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i32x16(self)
     }
 }
-impl<S: Simd> core::ops::Add for u8x64<S> {
+impl<S: Simd> core::ops::Add for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u8x64(self, rhs)
+        self.simd.add_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u8> for u8x64<S> {
+impl<S: Simd> core::ops::Add<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u8) -> Self::Output {
-        self.simd.add_u8x64(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: i32) -> Self::Output {
+        self.simd.add_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::Add<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn add(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.add_u8x64(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.add_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u8x64<S> {
+impl<S: Simd> core::ops::Sub for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u8x64(self, rhs)
+        self.simd.sub_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u8> for u8x64<S> {
+impl<S: Simd> core::ops::Sub<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u8) -> Self::Output {
-        self.simd.sub_u8x64(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.simd.sub_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::Sub<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn sub(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.sub_u8x64(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.sub_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u8x64<S> {
+impl<S: Simd> core::ops::Mul for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u8x64(self, rhs)
+        self.simd.mul_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u8> for u8x64<S> {
+impl<S: Simd> core::ops::Mul<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u8) -> Self::Output {
-        self.simd.mul_u8x64(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.simd.mul_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::Mul<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn mul(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.mul_u8x64(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.mul_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u8x64<S> {
+impl<S: Simd> core::ops::BitAnd for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u8x64(self, rhs)
+        self.simd.and_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8> for u8x64<S> {
+impl<S: Simd> core::ops::BitAnd<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u8) -> Self::Output {
-        self.simd.and_u8x64(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: i32) -> Self::Output {
+        self.simd.and_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::BitAnd<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.and_u8x64(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.and_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u8x64<S> {
+impl<S: Simd> core::ops::BitOr for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u8x64(self, rhs)
+        self.simd.or_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u8> for u8x64<S> {
+impl<S: Simd> core::ops::BitOr<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u8) -> Self::Output {
-        self.simd.or_u8x64(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i32) -> Self::Output {
+        self.simd.or_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::BitOr<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.or_u8x64(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.or_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u8x64<S> {
+impl<S: Simd> core::ops::BitXor for i32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u8x64(self, rhs)
+        self.simd.xor_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u8> for u8x64<S> {
+impl<S: Simd> core::ops::BitXor<i32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u8) -> Self::Output {
-        self.simd.xor_u8x64(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: i32) -> Self::Output {
+        self.simd.xor_i32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u8x64<S>> for u8 {
-    type Output = u8x64<S>;
+impl<S: Simd> core::ops::BitXor<i32x16<S>> for i32 {
+    type Output = i32x16<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u8x64<S>) -> Self::Output {
-        rhs.simd.xor_u8x64(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i32x16<S>) -> Self::Output {
+        rhs.simd.xor_i32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask8x64<S> {
+impl<S: Simd> core::ops::Shl<u32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask8x64(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask8x64<S> {
+impl<S: Simd> core::ops::Shl for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask8x64(self, rhs)
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask8x64<S> {
+impl<S: Simd> core::ops::Shr<u32> for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask8x64(self, rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask8x64<S> {
+impl<S: Simd> core::ops::Shr for i32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn not(self) -> Self::Output {
-        self.simd.not_mask8x64(self)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add for i16x32<S> {
+impl<S: Simd> core::ops::Add for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i16x32(self, rhs)
+        self.simd.add_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i16> for i16x32<S> {
+impl<S: Simd> core::ops::Add<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i16) -> Self::Output {
-        self.simd.add_i16x32(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u32) -> Self::Output {
+        self.simd.add_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::Add<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn add(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.add_i16x32(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.add_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i16x32<S> {
+impl<S: Simd> core::ops::Sub for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i16x32(self, rhs)
+        self.simd.sub_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i16> for i16x32<S> {
+impl<S: Simd> core::ops::Sub<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i16) -> Self::Output {
-        self.simd.sub_i16x32(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u32) -> Self::Output {
+        self.simd.sub_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::Sub<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn sub(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.sub_i16x32(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.sub_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i16x32<S> {
+impl<S: Simd> core::ops::Mul for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i16x32(self, rhs)
+        self.simd.mul_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i16> for i16x32<S> {
+impl<S: Simd> core::ops::Mul<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i16) -> Self::Output {
-        self.simd.mul_i16x32(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.simd.mul_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::Mul<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn mul(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.mul_i16x32(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.mul_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i16x32<S> {
+impl<S: Simd> core::ops::BitAnd for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i16x32(self, rhs)
+        self.simd.and_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16> for i16x32<S> {
+impl<S: Simd> core::ops::BitAnd<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i16) -> Self::Output {
-        self.simd.and_i16x32(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u32) -> Self::Output {
+        self.simd.and_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::BitAnd<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.and_i16x32(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.and_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i16x32<S> {
+impl<S: Simd> core::ops::BitOr for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i16x32(self, rhs)
+        self.simd.or_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i16> for i16x32<S> {
+impl<S: Simd> core::ops::BitOr<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i16) -> Self::Output {
-        self.simd.or_i16x32(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u32) -> Self::Output {
+        self.simd.or_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::BitOr<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn bitor(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.or_i16x32(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.or_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i16x32<S> {
+impl<S: Simd> core::ops::BitXor for u32x16<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i16x32(self, rhs)
+        self.simd.xor_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i16> for i16x32<S> {
+impl<S: Simd> core::ops::BitXor<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i16) -> Self::Output {
-        self.simd.xor_i16x32(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u32) -> Self::Output {
+        self.simd.xor_u32x16(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i16x32<S>> for i16 {
-    type Output = i16x32<S>;
+impl<S: Simd> core::ops::BitXor<u32x16<S>> for u32 {
+    type Output = u32x16<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i16x32<S>) -> Self::Output {
-        rhs.simd.xor_i16x32(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u32x16<S>) -> Self::Output {
+        rhs.simd.xor_u32x16(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u16x32<S> {
+impl<S: Simd> core::ops::Shl<u32> for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u16x32(self, rhs)
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u16> for u16x32<S> {
+impl<S: Simd> core::ops::Shl for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u16) -> Self::Output {
-        self.simd.add_u16x32(self, rhs.simd_into(self.simd))
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::Shr<u32> for u32x16<S> {
+    type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.add_u16x32(self.simd_into(rhs.simd), rhs)
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u16x32<S> {
+impl<S: Simd> core::ops::Shr for u32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u16x32(self, rhs)
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u16> for u16x32<S> {
+impl<S: Simd> core::ops::BitAnd for mask32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u16) -> Self::Output {
-        self.simd.sub_u16x32(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.simd.and_mask32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::BitOr for mask32x16<S> {
+    type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.sub_u16x32(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.simd.or_mask32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u16x32<S> {
+impl<S: Simd> core::ops::BitXor for mask32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u16x32(self, rhs)
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.simd.xor_mask32x16(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u16> for u16x32<S> {
+impl<S: Simd> core::ops::Not for mask32x16<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u16) -> Self::Output {
-        self.simd.mul_u16x32(self, rhs.simd_into(self.simd))
+    fn not(self) -> Self::Output {
+        self.simd.not_mask32x16(self)
     }
 }
-impl<S: Simd> core::ops::Mul<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::Neg for f64x8<S> {
+    type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.mul_u16x32(self.simd_into(rhs.simd), rhs)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_f64x8(self)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u16x32<S> {
+impl<S: Simd> core::ops::Add for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u16x32(self, rhs)
+    fn add(self, rhs: Self) -> Self::Output {
+        self.simd.add_f64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16> for u16x32<S> {
+impl<S: Simd> core::ops::Add<f64> for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u16) -> Self::Output {
-        self.simd.and_u16x32(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: f64) -> Self::Output {
+        self.simd.add_f64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::Add<f64x8<S>> for f64 {
+    type Output = f64x8<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.and_u16x32(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: f64x8<S>) -> Self::Output {
+        rhs.simd.add_f64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u16x32<S> {
+impl<S: Simd> core::ops::Sub for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u16x32(self, rhs)
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.simd.sub_f64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u16> for u16x32<S> {
+impl<S: Simd> core::ops::Sub<f64> for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u16) -> Self::Output {
-        self.simd.or_u16x32(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: f64) -> Self::Output {
+        self.simd.sub_f64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::Sub<f64x8<S>> for f64 {
+    type Output = f64x8<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.or_u16x32(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: f64x8<S>) -> Self::Output {
+        rhs.simd.sub_f64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u16x32<S> {
+impl<S: Simd> core::ops::Mul for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u16x32(self, rhs)
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.simd.mul_f64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u16> for u16x32<S> {
+impl<S: Simd> core::ops::Mul<f64> for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u16) -> Self::Output {
-        self.simd.xor_u16x32(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.simd.mul_f64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u16x32<S>> for u16 {
-    type Output = u16x32<S>;
+impl<S: Simd> core::ops::Mul<f64x8<S>> for f64 {
+    type Output = f64x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u16x32<S>) -> Self::Output {
-        rhs.simd.xor_u16x32(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: f64x8<S>) -> Self::Output {
+        rhs.simd.mul_f64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask16x32<S> {
+impl<S: Simd> core::ops::Div for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask16x32(self, rhs)
+    fn div(self, rhs: Self) -> Self::Output {
+        self.simd.div_f64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask16x32<S> {
+impl<S: Simd> core::ops::Div<f64> for f64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask16x32(self, rhs)
+    fn div(self, rhs: f64) -> Self::Output {
+        self.simd.div_f64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor for mask16x32<S> {
-    type Output = Self;
+impl<S: Simd> core::ops::Div<f64x8<S>> for f64 {
+    type Output = f64x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask16x32(self, rhs)
+    fn div(self, rhs: f64x8<S>) -> Self::Output {
+        rhs.simd.div_f64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask16x32<S> {
+impl<S: Simd> core::ops::Neg for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn not(self) -> Self::Output {
-        self.simd.not_mask16x32(self)
+    fn neg(self) -> Self::Output {
+        self.simd.neg_i64x8(self)
     }
 }
-impl<S: Simd> core::ops::Add for i32x16<S> {
+impl<S: Simd> core::ops::Add for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_i32x16(self, rhs)
+        self.simd.add_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<i32> for i32x16<S> {
+impl<S: Simd> core::ops::Add<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: i32) -> Self::Output {
-        self.simd.add_i32x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: i64) -> Self::Output {
+        self.simd.add_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::Add<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn add(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.add_i32x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.add_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for i32x16<S> {
+impl<S: Simd> core::ops::Sub for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_i32x16(self, rhs)
+        self.simd.sub_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<i32> for i32x16<S> {
+impl<S: Simd> core::ops::Sub<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: i32) -> Self::Output {
-        self.simd.sub_i32x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: i64) -> Self::Output {
+        self.simd.sub_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::Sub<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn sub(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.sub_i32x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.sub_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for i32x16<S> {
+impl<S: Simd> core::ops::Mul for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_i32x16(self, rhs)
+        self.simd.mul_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<i32> for i32x16<S> {
+impl<S: Simd> core::ops::Mul<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: i32) -> Self::Output {
-        self.simd.mul_i32x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.simd.mul_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::Mul<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn mul(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.mul_i32x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.mul_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for i32x16<S> {
+impl<S: Simd> core::ops::BitAnd for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_i32x16(self, rhs)
+        self.simd.and_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32> for i32x16<S> {
+impl<S: Simd> core::ops::BitAnd<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: i32) -> Self::Output {
-        self.simd.and_i32x16(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: i64) -> Self::Output {
+        self.simd.and_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::BitAnd<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn bitand(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.and_i32x16(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.and_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for i32x16<S> {
+impl<S: Simd> core::ops::BitOr for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_i32x16(self, rhs)
+        self.simd.or_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<i32> for i32x16<S> {
+impl<S: Simd> core::ops::BitOr<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: i32) -> Self::Output {
-        self.simd.or_i32x16(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: i64) -> Self::Output {
+        self.simd.or_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::BitOr<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn bitor(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.or_i32x16(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.or_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for i32x16<S> {
+impl<S: Simd> core::ops::BitXor for i64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_i32x16(self, rhs)
+        self.simd.xor_i64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<i32> for i32x16<S> {
+impl<S: Simd> core::ops::BitXor<i64> for i64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: i32) -> Self::Output {
-        self.simd.xor_i32x16(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: i64) -> Self::Output {
+        self.simd.xor_i64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<i32x16<S>> for i32 {
-    type Output = i32x16<S>;
+impl<S: Simd> core::ops::BitXor<i64x8<S>> for i64 {
+    type Output = i64x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: i32x16<S>) -> Self::Output {
-        rhs.simd.xor_i32x16(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: i64x8<S>) -> Self::Output {
+        rhs.simd.xor_i64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Add for u32x16<S> {
+impl<S: Simd> core::ops::Shl<u32> for i64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_i64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for i64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_i64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for i64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_i64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for i64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_i64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Add for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        self.simd.add_u32x16(self, rhs)
+        self.simd.add_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Add<u32> for u32x16<S> {
+impl<S: Simd> core::ops::Add<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn add(self, rhs: u32) -> Self::Output {
-        self.simd.add_u32x16(self, rhs.simd_into(self.simd))
+    fn add(self, rhs: u64) -> Self::Output {
+        self.simd.add_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Add<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::Add<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn add(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.add_u32x16(self.simd_into(rhs.simd), rhs)
+    fn add(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.add_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Sub for u32x16<S> {
+impl<S: Simd> core::ops::Sub for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.simd.sub_u32x16(self, rhs)
+        self.simd.sub_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Sub<u32> for u32x16<S> {
+impl<S: Simd> core::ops::Sub<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn sub(self, rhs: u32) -> Self::Output {
-        self.simd.sub_u32x16(self, rhs.simd_into(self.simd))
+    fn sub(self, rhs: u64) -> Self::Output {
+        self.simd.sub_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Sub<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::Sub<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn sub(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.sub_u32x16(self.simd_into(rhs.simd), rhs)
+    fn sub(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.sub_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::Mul for u32x16<S> {
+impl<S: Simd> core::ops::Mul for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn mul(self, rhs: Self) -> Self::Output {
-        self.simd.mul_u32x16(self, rhs)
+        self.simd.mul_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Mul<u32> for u32x16<S> {
+impl<S: Simd> core::ops::Mul<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn mul(self, rhs: u32) -> Self::Output {
-        self.simd.mul_u32x16(self, rhs.simd_into(self.simd))
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.simd.mul_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::Mul<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::Mul<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn mul(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.mul_u32x16(self.simd_into(rhs.simd), rhs)
+    fn mul(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.mul_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for u32x16<S> {
+impl<S: Simd> core::ops::BitAnd for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_u32x16(self, rhs)
+        self.simd.and_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32> for u32x16<S> {
+impl<S: Simd> core::ops::BitAnd<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitand(self, rhs: u32) -> Self::Output {
-        self.simd.and_u32x16(self, rhs.simd_into(self.simd))
+    fn bitand(self, rhs: u64) -> Self::Output {
+        self.simd.and_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitAnd<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::BitAnd<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn bitand(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.and_u32x16(self.simd_into(rhs.simd), rhs)
+    fn bitand(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.and_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for u32x16<S> {
+impl<S: Simd> core::ops::BitOr for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_u32x16(self, rhs)
+        self.simd.or_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr<u32> for u32x16<S> {
+impl<S: Simd> core::ops::BitOr<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitor(self, rhs: u32) -> Self::Output {
-        self.simd.or_u32x16(self, rhs.simd_into(self.simd))
+    fn bitor(self, rhs: u64) -> Self::Output {
+        self.simd.or_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitOr<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::BitOr<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn bitor(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.or_u32x16(self.simd_into(rhs.simd), rhs)
+    fn bitor(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.or_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for u32x16<S> {
+impl<S: Simd> core::ops::BitXor for u64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_u32x16(self, rhs)
+        self.simd.xor_u64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor<u32> for u32x16<S> {
+impl<S: Simd> core::ops::BitXor<u64> for u64x8<S> {
     type Output = Self;
     #[inline(always)]
-    fn bitxor(self, rhs: u32) -> Self::Output {
-        self.simd.xor_u32x16(self, rhs.simd_into(self.simd))
+    fn bitxor(self, rhs: u64) -> Self::Output {
+        self.simd.xor_u64x8(self, rhs.simd_into(self.simd))
     }
 }
-impl<S: Simd> core::ops::BitXor<u32x16<S>> for u32 {
-    type Output = u32x16<S>;
+impl<S: Simd> core::ops::BitXor<u64x8<S>> for u64 {
+    type Output = u64x8<S>;
     #[inline(always)]
-    fn bitxor(self, rhs: u32x16<S>) -> Self::Output {
-        rhs.simd.xor_u32x16(self.simd_into(rhs.simd), rhs)
+    fn bitxor(self, rhs: u64x8<S>) -> Self::Output {
+        rhs.simd.xor_u64x8(self.simd_into(rhs.simd), rhs)
     }
 }
-impl<S: Simd> core::ops::BitAnd for mask32x16<S> {
+impl<S: Simd> core::ops::Shl<u32> for u64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.simd.shl_u64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shl for u64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.simd.shl_var_u64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr<u32> for u64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: u32) -> Self::Output {
+        self.simd.shr_u64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::Shr for u64x8<S> {
+    type Output = Self;
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.simd.shr_var_u64x8(self, rhs)
+    }
+}
+impl<S: Simd> core::ops::BitAnd for mask64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitand(self, rhs: Self) -> Self::Output {
-        self.simd.and_mask32x16(self, rhs)
+        self.simd.and_mask64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitOr for mask32x16<S> {
+impl<S: Simd> core::ops::BitOr for mask64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitor(self, rhs: Self) -> Self::Output {
-        self.simd.or_mask32x16(self, rhs)
+        self.simd.or_mask64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::BitXor for mask32x16<S> {
+impl<S: Simd> core::ops::BitXor for mask64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.simd.xor_mask32x16(self, rhs)
+        self.simd.xor_mask64x8(self, rhs)
     }
 }
-impl<S: Simd> core::ops::Not for mask32x16<S> {
+impl<S: Simd> core::ops::Not for mask64x8<S> {
     type Output = Self;
     #[inline(always)]
     fn not(self) -> Self::Output {
-        self.simd.not_mask32x16(self)
+        self.simd.not_mask64x8(self)
     }
 }
@@ -1,42 +1,473 @@
 // This file is autogenerated by fearless_simd_gen
 
-use crate::{Level, SimdElement, SimdInto, seal::Seal};
+use crate::{Indices, Level, SimdElement, SimdInto, SimdMath, seal::Seal};
 use crate::{
-    f32x4, f32x8, f32x16, i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4, i32x8, i32x16,
-    mask8x16, mask8x32, mask8x64, mask16x8, mask16x16, mask16x32, mask32x4, mask32x8, mask32x16,
-    u8x16, u8x32, u8x64, u16x8, u16x16, u16x32, u32x4, u32x8, u32x16,
+    bf16, bf16x8, bf16x16, f16, f16x8, f16x16, f32x2, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8,
+    i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x2, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8,
+    mask8x8, mask8x16, mask8x32, mask8x64, mask16x4, mask16x8, mask16x16, mask16x32, mask32x2,
+    mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x8, u8x16, u8x32, u8x64, u16x4,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
 };
 #[doc = r" TODO: docstring"]
 pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
-    type f32s: SimdFloat<f32, Self, Block = f32x4<Self>>;
+    type f32s: SimdFloat<f32, Self, Block = f32x4<Self>> + SimdMath<Self>;
+    type f64s: SimdFloat<f64, Self, Block = f64x2<Self>>;
     type u8s: SimdInt<u8, Self, Block = u8x16<Self>>;
     type i8s: SimdInt<i8, Self, Block = i8x16<Self>>;
     type u16s: SimdInt<u16, Self, Block = u16x8<Self>>;
     type i16s: SimdInt<i16, Self, Block = i16x8<Self>>;
     type u32s: SimdInt<u32, Self, Block = u32x4<Self>>;
     type i32s: SimdInt<i32, Self, Block = i32x4<Self>>;
+    type u64s: SimdInt<u64, Self, Block = u64x2<Self>>;
+    type i64s: SimdInt<i64, Self, Block = i64x2<Self>>;
     type mask8s: SimdMask<i8, Self, Block = mask8x16<Self>>;
     type mask16s: SimdMask<i16, Self, Block = mask16x8<Self>>;
     type mask32s: SimdMask<i32, Self, Block = mask32x4<Self>>;
+    type mask64s: SimdMask<i64, Self, Block = mask64x2<Self>>;
     fn level(self) -> Level;
     #[doc = r" Call function with CPU features enabled."]
     #[doc = r""]
     #[doc = r" For performance, the provided function should be `#[inline(always)]`."]
     fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R;
+    fn splat_f32x2(self, val: f32) -> f32x2<Self>;
+    fn abs_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn neg_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn sqrt_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn recip_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn rsqrt_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn recip_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self>;
+    fn rsqrt_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self>;
+    fn add_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn sub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn mul_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn div_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn copysign_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn simd_eq_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn simd_ne_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn simd_lt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn simd_le_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn simd_ge_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn simd_gt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self>;
+    fn is_nan_f32x2(self, a: f32x2<Self>) -> mask32x2<Self>;
+    fn is_finite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self>;
+    fn is_infinite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self>;
+    fn is_subnormal_f32x2(self, a: f32x2<Self>) -> mask32x2<Self>;
+    fn is_sign_negative_f32x2(self, a: f32x2<Self>) -> mask32x2<Self>;
+    fn zip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn zip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn max_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn max_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn min_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn min_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn madd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn msub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn nmadd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn nmsub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn mul_add_fused_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn mul_add_fast_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn floor_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn ceil_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn round_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn round_ties_even_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn fract_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn trunc_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn select_f32x2(self, a: mask32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self>;
+    fn reduce_add_f32x2(self, a: f32x2<Self>) -> f32;
+    fn reduce_min_f32x2(self, a: f32x2<Self>) -> f32;
+    fn reduce_max_f32x2(self, a: f32x2<Self>) -> f32;
+    fn shuffle_f32x2<I: Indices<2>>(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn shuffle2_f32x2<I: Indices<2>>(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn reverse_f32x2(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn rotate_lanes_left_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn rotate_lanes_right_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn broadcast_lane_f32x2<const L: usize>(self, a: f32x2<Self>) -> f32x2<Self>;
+    fn unzip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn unzip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self>;
+    fn combine_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x4<Self>;
+    fn reinterpret_u8_f32x2(self, a: f32x2<Self>) -> u8x8<Self>;
+    fn reinterpret_u16_f32x2(self, a: f32x2<Self>) -> u16x4<Self>;
+    fn reinterpret_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self>;
+    fn cvt_f64_f32x2(self, a: f32x2<Self>) -> f64x2<Self>;
+    fn cvt_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self>;
+    fn cvt_i32_round_f32x2(self, a: f32x2<Self>) -> i32x2<Self>;
+    fn cvt_i64_f32x2(self, a: f32x2<Self>) -> i64x2<Self>;
+    fn cvt_i64_round_f32x2(self, a: f32x2<Self>) -> i64x2<Self>;
+    fn cvt_u64_f32x2(self, a: f32x2<Self>) -> u64x2<Self>;
+    fn cvt_u64_round_f32x2(self, a: f32x2<Self>) -> u64x2<Self>;
+    fn splat_u8x8(self, val: u8) -> u8x8<Self>;
+    fn not_u8x8(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn mul_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn and_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn or_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn xor_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn shl_u8x8(self, a: u8x8<Self>, shift: u32) -> u8x8<Self>;
+    fn shr_u8x8(self, a: u8x8<Self>, shift: u32) -> u8x8<Self>;
+    fn shl_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn shr_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn simd_eq_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self>;
+    fn simd_lt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self>;
+    fn simd_le_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self>;
+    fn simd_ge_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self>;
+    fn simd_gt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self>;
+    fn zip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn zip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn select_u8x8(self, a: mask8x8<Self>, b: u8x8<Self>, c: u8x8<Self>) -> u8x8<Self>;
+    fn min_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn max_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn reduce_add_u8x8(self, a: u8x8<Self>) -> u8;
+    fn reduce_min_u8x8(self, a: u8x8<Self>) -> u8;
+    fn reduce_max_u8x8(self, a: u8x8<Self>) -> u8;
+    fn reduce_and_u8x8(self, a: u8x8<Self>) -> u8;
+    fn reduce_or_u8x8(self, a: u8x8<Self>) -> u8;
+    fn shuffle_u8x8<I: Indices<8>>(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn shuffle2_u8x8<I: Indices<8>>(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn reverse_u8x8(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn rotate_lanes_left_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn rotate_lanes_right_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn broadcast_lane_u8x8<const L: usize>(self, a: u8x8<Self>) -> u8x8<Self>;
+    fn unzip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn unzip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn saturating_add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn saturating_sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn abs_diff_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn avg_round_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn mul_widen_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u16x8<Self>;
+    fn mul_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn swizzle_dyn_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn lookup_32_u8x8(self, a: u8x32<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn lookup_48_u8x8(self, a: [u8x16<Self>; 3], b: u8x8<Self>) -> u8x8<Self>;
+    fn lookup_64_u8x8(self, a: u8x64<Self>, b: u8x8<Self>) -> u8x8<Self>;
+    fn combine_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x16<Self>;
+    fn widen_u8x8(self, a: u8x8<Self>) -> u16x8<Self>;
+    fn reinterpret_f32_u8x8(self, a: u8x8<Self>) -> f32x2<Self>;
+    fn reinterpret_u16_u8x8(self, a: u8x8<Self>) -> u16x4<Self>;
+    fn reinterpret_i32_u8x8(self, a: u8x8<Self>) -> i32x2<Self>;
+    fn cvt_f16_u8x8(self, a: u8x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_u8x8(self, a: u8x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_u8x8(self, a: u8x8<Self>) -> f64x8<Self>;
+    fn cvt_f32_unorm_u8x8(self, a: u8x8<Self>) -> f32x8<Self>;
+    fn splat_mask8x8(self, val: i8) -> mask8x8<Self>;
+    fn not_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn and_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn or_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn xor_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn select_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>, c: mask8x8<Self>) -> mask8x8<Self>;
+    fn simd_eq_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn any_mask8x8(self, a: mask8x8<Self>) -> bool;
+    fn all_mask8x8(self, a: mask8x8<Self>) -> bool;
+    fn none_mask8x8(self, a: mask8x8<Self>) -> bool;
+    fn count_true_mask8x8(self, a: mask8x8<Self>) -> usize;
+    fn first_true_mask8x8(self, a: mask8x8<Self>) -> Option<usize>;
+    fn to_bitmask_mask8x8(self, a: mask8x8<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask8x8(self, bits: u64) -> mask8x8<Self>;
+    fn shuffle_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn shuffle2_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn reverse_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn rotate_lanes_left_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn rotate_lanes_right_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn broadcast_lane_mask8x8<const L: usize>(self, a: mask8x8<Self>) -> mask8x8<Self>;
+    fn unzip_low_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn unzip_high_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self>;
+    fn combine_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x16<Self>;
+    fn reinterpret_u8_mask8x8(self, a: mask8x8<Self>) -> u8x8<Self>;
+    fn reinterpret_u16_mask8x8(self, a: mask8x8<Self>) -> u16x4<Self>;
+    fn reinterpret_i32_mask8x8(self, a: mask8x8<Self>) -> i32x2<Self>;
+    fn splat_u16x4(self, val: u16) -> u16x4<Self>;
+    fn not_u16x4(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn mul_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn and_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn or_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn xor_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn shl_u16x4(self, a: u16x4<Self>, shift: u32) -> u16x4<Self>;
+    fn shr_u16x4(self, a: u16x4<Self>, shift: u32) -> u16x4<Self>;
+    fn shl_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn shr_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn simd_eq_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self>;
+    fn simd_lt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self>;
+    fn simd_le_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self>;
+    fn simd_ge_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self>;
+    fn simd_gt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self>;
+    fn zip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn zip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn select_u16x4(self, a: mask16x4<Self>, b: u16x4<Self>, c: u16x4<Self>) -> u16x4<Self>;
+    fn min_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn max_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn reduce_add_u16x4(self, a: u16x4<Self>) -> u16;
+    fn reduce_min_u16x4(self, a: u16x4<Self>) -> u16;
+    fn reduce_max_u16x4(self, a: u16x4<Self>) -> u16;
+    fn reduce_and_u16x4(self, a: u16x4<Self>) -> u16;
+    fn reduce_or_u16x4(self, a: u16x4<Self>) -> u16;
+    fn shuffle_u16x4<I: Indices<4>>(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn shuffle2_u16x4<I: Indices<4>>(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn reverse_u16x4(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn rotate_lanes_left_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn rotate_lanes_right_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn broadcast_lane_u16x4<const L: usize>(self, a: u16x4<Self>) -> u16x4<Self>;
+    fn unzip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn unzip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn saturating_add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn saturating_sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn abs_diff_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn avg_round_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn mul_widen_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u32x4<Self>;
+    fn mul_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self>;
+    fn combine_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x8<Self>;
+    fn widen_u16x4(self, a: u16x4<Self>) -> u32x4<Self>;
+    fn reinterpret_f32_u16x4(self, a: u16x4<Self>) -> f32x2<Self>;
+    fn reinterpret_u8_u16x4(self, a: u16x4<Self>) -> u8x8<Self>;
+    fn reinterpret_i32_u16x4(self, a: u16x4<Self>) -> i32x2<Self>;
+    fn cvt_f32_u16x4(self, a: u16x4<Self>) -> f32x4<Self>;
+    fn cvt_f64_u16x4(self, a: u16x4<Self>) -> f64x4<Self>;
+    fn splat_mask16x4(self, val: i16) -> mask16x4<Self>;
+    fn not_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn and_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn or_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn xor_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn select_mask16x4(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+        c: mask16x4<Self>,
+    ) -> mask16x4<Self>;
+    fn simd_eq_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn any_mask16x4(self, a: mask16x4<Self>) -> bool;
+    fn all_mask16x4(self, a: mask16x4<Self>) -> bool;
+    fn none_mask16x4(self, a: mask16x4<Self>) -> bool;
+    fn count_true_mask16x4(self, a: mask16x4<Self>) -> usize;
+    fn first_true_mask16x4(self, a: mask16x4<Self>) -> Option<usize>;
+    fn to_bitmask_mask16x4(self, a: mask16x4<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask16x4(self, bits: u64) -> mask16x4<Self>;
+    fn shuffle_mask16x4<I: Indices<4>>(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn shuffle2_mask16x4<I: Indices<4>>(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+    ) -> mask16x4<Self>;
+    fn reverse_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn rotate_lanes_left_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn rotate_lanes_right_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn broadcast_lane_mask16x4<const L: usize>(self, a: mask16x4<Self>) -> mask16x4<Self>;
+    fn unzip_low_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn unzip_high_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self>;
+    fn combine_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x8<Self>;
+    fn reinterpret_u8_mask16x4(self, a: mask16x4<Self>) -> u8x8<Self>;
+    fn reinterpret_u16_mask16x4(self, a: mask16x4<Self>) -> u16x4<Self>;
+    fn reinterpret_i32_mask16x4(self, a: mask16x4<Self>) -> i32x2<Self>;
+    fn splat_i32x2(self, val: i32) -> i32x2<Self>;
+    fn not_i32x2(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn add_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn sub_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn mul_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn and_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn or_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn xor_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn shl_i32x2(self, a: i32x2<Self>, shift: u32) -> i32x2<Self>;
+    fn shr_i32x2(self, a: i32x2<Self>, shift: u32) -> i32x2<Self>;
+    fn shl_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn shr_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn simd_eq_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self>;
+    fn simd_lt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self>;
+    fn simd_le_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self>;
+    fn simd_ge_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self>;
+    fn simd_gt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self>;
+    fn zip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn zip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn select_i32x2(self, a: mask32x2<Self>, b: i32x2<Self>, c: i32x2<Self>) -> i32x2<Self>;
+    fn min_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn max_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn reduce_add_i32x2(self, a: i32x2<Self>) -> i32;
+    fn reduce_min_i32x2(self, a: i32x2<Self>) -> i32;
+    fn reduce_max_i32x2(self, a: i32x2<Self>) -> i32;
+    fn reduce_and_i32x2(self, a: i32x2<Self>) -> i32;
+    fn reduce_or_i32x2(self, a: i32x2<Self>) -> i32;
+    fn shuffle_i32x2<I: Indices<2>>(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn shuffle2_i32x2<I: Indices<2>>(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn reverse_i32x2(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn rotate_lanes_left_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn rotate_lanes_right_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn broadcast_lane_i32x2<const L: usize>(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn unzip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn unzip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self>;
+    fn abs_i32x2(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn neg_i32x2(self, a: i32x2<Self>) -> i32x2<Self>;
+    fn combine_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x4<Self>;
+    fn widen_i32x2(self, a: i32x2<Self>) -> i64x2<Self>;
+    fn reinterpret_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self>;
+    fn reinterpret_u8_i32x2(self, a: i32x2<Self>) -> u8x8<Self>;
+    fn reinterpret_u16_i32x2(self, a: i32x2<Self>) -> u16x4<Self>;
+    fn cvt_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self>;
+    fn cvt_f64_i32x2(self, a: i32x2<Self>) -> f64x2<Self>;
+    fn splat_mask32x2(self, val: i32) -> mask32x2<Self>;
+    fn not_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn and_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn or_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn xor_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn select_mask32x2(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+        c: mask32x2<Self>,
+    ) -> mask32x2<Self>;
+    fn simd_eq_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn any_mask32x2(self, a: mask32x2<Self>) -> bool;
+    fn all_mask32x2(self, a: mask32x2<Self>) -> bool;
+    fn none_mask32x2(self, a: mask32x2<Self>) -> bool;
+    fn count_true_mask32x2(self, a: mask32x2<Self>) -> usize;
+    fn first_true_mask32x2(self, a: mask32x2<Self>) -> Option<usize>;
+    fn to_bitmask_mask32x2(self, a: mask32x2<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask32x2(self, bits: u64) -> mask32x2<Self>;
+    fn shuffle_mask32x2<I: Indices<2>>(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn shuffle2_mask32x2<I: Indices<2>>(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+    ) -> mask32x2<Self>;
+    fn reverse_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn rotate_lanes_left_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn rotate_lanes_right_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn broadcast_lane_mask32x2<const L: usize>(self, a: mask32x2<Self>) -> mask32x2<Self>;
+    fn unzip_low_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn unzip_high_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self>;
+    fn combine_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x4<Self>;
+    fn reinterpret_u8_mask32x2(self, a: mask32x2<Self>) -> u8x8<Self>;
+    fn reinterpret_u16_mask32x2(self, a: mask32x2<Self>) -> u16x4<Self>;
+    fn reinterpret_i32_mask32x2(self, a: mask32x2<Self>) -> i32x2<Self>;
+    fn splat_f16x8(self, val: f16) -> f16x8<Self>;
+    fn abs_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn neg_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn sqrt_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn recip_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn rsqrt_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn recip_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self>;
+    fn rsqrt_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self>;
+    fn add_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn sub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn mul_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn div_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn copysign_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn simd_eq_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn simd_ne_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn simd_lt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn simd_le_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn simd_ge_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn simd_gt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self>;
+    fn is_nan_f16x8(self, a: f16x8<Self>) -> mask16x8<Self>;
+    fn is_finite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self>;
+    fn is_infinite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self>;
+    fn is_subnormal_f16x8(self, a: f16x8<Self>) -> mask16x8<Self>;
+    fn is_sign_negative_f16x8(self, a: f16x8<Self>) -> mask16x8<Self>;
+    fn zip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn zip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn max_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn max_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn min_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn min_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn madd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn msub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn nmadd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn nmsub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn mul_add_fused_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn mul_add_fast_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn floor_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn ceil_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn round_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn round_ties_even_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn fract_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn trunc_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn select_f16x8(self, a: mask16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self>;
+    fn reduce_add_f16x8(self, a: f16x8<Self>) -> f16;
+    fn reduce_min_f16x8(self, a: f16x8<Self>) -> f16;
+    fn reduce_max_f16x8(self, a: f16x8<Self>) -> f16;
+    fn shuffle_f16x8<I: Indices<8>>(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn shuffle2_f16x8<I: Indices<8>>(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn reverse_f16x8(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn rotate_lanes_left_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn rotate_lanes_right_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn broadcast_lane_f16x8<const L: usize>(self, a: f16x8<Self>) -> f16x8<Self>;
+    fn unzip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn unzip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self>;
+    fn combine_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_f16x8(self, a: f16x8<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_f16x8(self, a: f16x8<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_f16x8(self, a: f16x8<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_f16x8(self, a: f16x8<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_f16x8(self, a: f16x8<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_f16x8(self, a: f16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_f16x8(self, a: f16x8<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_f16x8(self, a: f16x8<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_f16x8(self, a: f16x8<Self>) -> u64x2<Self>;
+    fn cvt_f32_f16x8(self, a: f16x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_f16x8(self, a: f16x8<Self>) -> f64x8<Self>;
+    fn cvt_u8_f16x8(self, a: f16x8<Self>) -> u8x8<Self>;
+    fn cvt_u8_round_f16x8(self, a: f16x8<Self>) -> u8x8<Self>;
+    fn cvt_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self>;
+    fn cvt_i16_round_f16x8(self, a: f16x8<Self>) -> i16x8<Self>;
+    fn cvt_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self>;
+    fn cvt_u16_round_f16x8(self, a: f16x8<Self>) -> u16x8<Self>;
+    fn cvt_i32_f16x8(self, a: f16x8<Self>) -> i32x8<Self>;
+    fn cvt_i32_round_f16x8(self, a: f16x8<Self>) -> i32x8<Self>;
+    fn cvt_u32_f16x8(self, a: f16x8<Self>) -> u32x8<Self>;
+    fn cvt_u32_round_f16x8(self, a: f16x8<Self>) -> u32x8<Self>;
+    fn cvt_i64_f16x8(self, a: f16x8<Self>) -> i64x8<Self>;
+    fn cvt_i64_round_f16x8(self, a: f16x8<Self>) -> i64x8<Self>;
+    fn cvt_u64_f16x8(self, a: f16x8<Self>) -> u64x8<Self>;
+    fn cvt_u64_round_f16x8(self, a: f16x8<Self>) -> u64x8<Self>;
+    fn splat_bf16x8(self, val: bf16) -> bf16x8<Self>;
+    fn select_bf16x8(self, a: mask16x8<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> bf16x8<Self>;
+    fn shuffle_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>) -> bf16x8<Self>;
+    fn shuffle2_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self>;
+    fn reverse_bf16x8(self, a: bf16x8<Self>) -> bf16x8<Self>;
+    fn rotate_lanes_left_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self>;
+    fn rotate_lanes_right_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self>;
+    fn broadcast_lane_bf16x8<const L: usize>(self, a: bf16x8<Self>) -> bf16x8<Self>;
+    fn unzip_low_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self>;
+    fn unzip_high_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self>;
+    fn combine_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x16<Self>;
+    fn reinterpret_f16_bf16x8(self, a: bf16x8<Self>) -> f16x8<Self>;
+    fn reinterpret_f32_bf16x8(self, a: bf16x8<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_bf16x8(self, a: bf16x8<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_bf16x8(self, a: bf16x8<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_bf16x8(self, a: bf16x8<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_bf16x8(self, a: bf16x8<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_bf16x8(self, a: bf16x8<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_bf16x8(self, a: bf16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_bf16x8(self, a: bf16x8<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_bf16x8(self, a: bf16x8<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_bf16x8(self, a: bf16x8<Self>) -> u64x2<Self>;
+    fn cvt_f32_bf16x8(self, a: bf16x8<Self>) -> f32x8<Self>;
     fn splat_f32x4(self, val: f32) -> f32x4<Self>;
     fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
     fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
     fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn recip_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn rsqrt_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn recip_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self>;
+    fn rsqrt_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self>;
     fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
+    fn simd_ne_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
     fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
     fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
     fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
     fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self>;
+    fn is_nan_f32x4(self, a: f32x4<Self>) -> mask32x4<Self>;
+    fn is_finite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self>;
+    fn is_infinite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self>;
+    fn is_subnormal_f32x4(self, a: f32x4<Self>) -> mask32x4<Self>;
+    fn is_sign_negative_f32x4(self, a: f32x4<Self>) -> mask32x4<Self>;
     fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
@@ -45,13 +476,54 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn madd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
     fn msub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
+    fn nmadd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
+    fn nmsub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
+    fn mul_add_fused_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
+    fn mul_add_fast_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
     fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn round_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
     fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
     fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
     fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self>;
+    fn reduce_add_f32x4(self, a: f32x4<Self>) -> f32;
+    fn reduce_min_f32x4(self, a: f32x4<Self>) -> f32;
+    fn reduce_max_f32x4(self, a: f32x4<Self>) -> f32;
+    fn shuffle_f32x4<I: Indices<4>>(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn shuffle2_f32x4<I: Indices<4>>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
+    fn reverse_f32x4(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn rotate_lanes_left_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn rotate_lanes_right_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn broadcast_lane_f32x4<const L: usize>(self, a: f32x4<Self>) -> f32x4<Self>;
+    fn unzip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
+    fn unzip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self>;
     fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self>;
-    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self>;
+    fn split_f32x4(self, a: f32x4<Self>) -> (f32x2<Self>, f32x2<Self>);
+    fn reinterpret_f16_f32x4(self, a: f32x4<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_f32x4(self, a: f32x4<Self>) -> bf16x8<Self>;
+    fn reinterpret_f64_f32x4(self, a: f32x4<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_f32x4(self, a: f32x4<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_f32x4(self, a: f32x4<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_f32x4(self, a: f32x4<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_f32x4(self, a: f32x4<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_f32x4(self, a: f32x4<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_f32x4(self, a: f32x4<Self>) -> u64x2<Self>;
+    fn cvt_f64_f32x4(self, a: f32x4<Self>) -> f64x4<Self>;
+    fn cvt_u16_f32x4(self, a: f32x4<Self>) -> u16x4<Self>;
+    fn cvt_u16_round_f32x4(self, a: f32x4<Self>) -> u16x4<Self>;
     fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self>;
+    fn cvt_i32_round_f32x4(self, a: f32x4<Self>) -> i32x4<Self>;
+    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self>;
+    fn cvt_u32_round_f32x4(self, a: f32x4<Self>) -> u32x4<Self>;
+    fn cvt_i64_f32x4(self, a: f32x4<Self>) -> i64x4<Self>;
+    fn cvt_i64_round_f32x4(self, a: f32x4<Self>) -> i64x4<Self>;
+    fn cvt_u64_f32x4(self, a: f32x4<Self>) -> u64x4<Self>;
+    fn cvt_u64_round_f32x4(self, a: f32x4<Self>) -> u64x4<Self>;
+    fn dot_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self>;
+    fn mmla_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self>;
     fn splat_i8x16(self, val: i8) -> i8x16<Self>;
     fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self>;
     fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
@@ -60,7 +532,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
     fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
     fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn shl_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self>;
     fn shr_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self>;
+    fn shl_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn shr_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
     fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self>;
     fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self>;
     fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self>;
@@ -71,8 +546,43 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self>;
     fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
     fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn reduce_add_i8x16(self, a: i8x16<Self>) -> i8;
+    fn reduce_min_i8x16(self, a: i8x16<Self>) -> i8;
+    fn reduce_max_i8x16(self, a: i8x16<Self>) -> i8;
+    fn reduce_and_i8x16(self, a: i8x16<Self>) -> i8;
+    fn reduce_or_i8x16(self, a: i8x16<Self>) -> i8;
+    fn shuffle_i8x16<I: Indices<16>>(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn shuffle2_i8x16<I: Indices<16>>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn reverse_i8x16(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn rotate_lanes_left_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn rotate_lanes_right_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn broadcast_lane_i8x16<const L: usize>(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn unzip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn unzip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
+    fn abs_i8x16(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn neg_i8x16(self, a: i8x16<Self>) -> i8x16<Self>;
+    fn abs_diff_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> u8x16<Self>;
+    fn mul_widen_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i16x16<Self>;
+    fn mul_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self>;
     fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self>;
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self>;
+    fn widen_low_i8x16(self, a: i8x16<Self>) -> i16x8<Self>;
+    fn widen_high_i8x16(self, a: i8x16<Self>) -> i16x8<Self>;
+    fn reinterpret_f16_i8x16(self, a: i8x16<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_i8x16(self, a: i8x16<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_i8x16(self, a: i8x16<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_i8x16(self, a: i8x16<Self>) -> f64x2<Self>;
     fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_i8x16(self, a: i8x16<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_i8x16(self, a: i8x16<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_i8x16(self, a: i8x16<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_i8x16(self, a: i8x16<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_i8x16(self, a: i8x16<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_i8x16(self, a: i8x16<Self>) -> u64x2<Self>;
+    fn cvt_f16_i8x16(self, a: i8x16<Self>) -> f16x16<Self>;
+    fn cvt_f32_i8x16(self, a: i8x16<Self>) -> f32x16<Self>;
     fn splat_u8x16(self, val: u8) -> u8x16<Self>;
     fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self>;
     fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
@@ -81,7 +591,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
     fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
     fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn shl_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self>;
     fn shr_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self>;
+    fn shl_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn shr_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
     fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self>;
     fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self>;
     fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self>;
@@ -92,8 +605,48 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self>;
     fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
     fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn reduce_add_u8x16(self, a: u8x16<Self>) -> u8;
+    fn reduce_min_u8x16(self, a: u8x16<Self>) -> u8;
+    fn reduce_max_u8x16(self, a: u8x16<Self>) -> u8;
+    fn reduce_and_u8x16(self, a: u8x16<Self>) -> u8;
+    fn reduce_or_u8x16(self, a: u8x16<Self>) -> u8;
+    fn shuffle_u8x16<I: Indices<16>>(self, a: u8x16<Self>) -> u8x16<Self>;
+    fn shuffle2_u8x16<I: Indices<16>>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn reverse_u8x16(self, a: u8x16<Self>) -> u8x16<Self>;
+    fn rotate_lanes_left_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self>;
+    fn rotate_lanes_right_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self>;
+    fn broadcast_lane_u8x16<const L: usize>(self, a: u8x16<Self>) -> u8x16<Self>;
+    fn unzip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn unzip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn abs_diff_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn mul_widen_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u16x16<Self>;
+    fn mul_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn swizzle_dyn_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn lookup_32_u8x16(self, a: u8x32<Self>, b: u8x16<Self>) -> u8x16<Self>;
+    fn lookup_48_u8x16(self, a: [u8x16<Self>; 3], b: u8x16<Self>) -> u8x16<Self>;
+    fn lookup_64_u8x16(self, a: u8x64<Self>, b: u8x16<Self>) -> u8x16<Self>;
     fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self>;
+    fn split_u8x16(self, a: u8x16<Self>) -> (u8x8<Self>, u8x8<Self>);
     fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self>;
+    fn widen_low_u8x16(self, a: u8x16<Self>) -> u16x8<Self>;
+    fn widen_high_u8x16(self, a: u8x16<Self>) -> u16x8<Self>;
+    fn reinterpret_f16_u8x16(self, a: u8x16<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_u8x16(self, a: u8x16<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_u8x16(self, a: u8x16<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_u8x16(self, a: u8x16<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_u8x16(self, a: u8x16<Self>) -> i8x16<Self>;
+    fn reinterpret_i16_u8x16(self, a: u8x16<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_u8x16(self, a: u8x16<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_u8x16(self, a: u8x16<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_u8x16(self, a: u8x16<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_u8x16(self, a: u8x16<Self>) -> u64x2<Self>;
+    fn cvt_f16_u8x16(self, a: u8x16<Self>) -> f16x16<Self>;
+    fn cvt_f32_u8x16(self, a: u8x16<Self>) -> f32x16<Self>;
+    fn cvt_f32_unorm_u8x16(self, a: u8x16<Self>) -> f32x16<Self>;
     fn splat_mask8x16(self, val: i8) -> mask8x16<Self>;
     fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self>;
     fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self>;
@@ -106,7 +659,36 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask8x16<Self>,
     ) -> mask8x16<Self>;
     fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self>;
+    fn any_mask8x16(self, a: mask8x16<Self>) -> bool;
+    fn all_mask8x16(self, a: mask8x16<Self>) -> bool;
+    fn none_mask8x16(self, a: mask8x16<Self>) -> bool;
+    fn count_true_mask8x16(self, a: mask8x16<Self>) -> usize;
+    fn first_true_mask8x16(self, a: mask8x16<Self>) -> Option<usize>;
+    fn to_bitmask_mask8x16(self, a: mask8x16<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self>;
+    fn shuffle_mask8x16<I: Indices<16>>(self, a: mask8x16<Self>) -> mask8x16<Self>;
+    fn shuffle2_mask8x16<I: Indices<16>>(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+    ) -> mask8x16<Self>;
+    fn reverse_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self>;
+    fn rotate_lanes_left_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self>;
+    fn rotate_lanes_right_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self>;
+    fn broadcast_lane_mask8x16<const L: usize>(self, a: mask8x16<Self>) -> mask8x16<Self>;
+    fn unzip_low_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self>;
+    fn unzip_high_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self>;
     fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self>;
+    fn split_mask8x16(self, a: mask8x16<Self>) -> (mask8x8<Self>, mask8x8<Self>);
+    fn reinterpret_i8_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_mask8x16(self, a: mask8x16<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_mask8x16(self, a: mask8x16<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_mask8x16(self, a: mask8x16<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_mask8x16(self, a: mask8x16<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_mask8x16(self, a: mask8x16<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_mask8x16(self, a: mask8x16<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_mask8x16(self, a: mask8x16<Self>) -> u64x2<Self>;
     fn splat_i16x8(self, val: i16) -> i16x8<Self>;
     fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self>;
     fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
@@ -115,7 +697,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
     fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
     fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn shl_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self>;
     fn shr_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self>;
+    fn shl_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn shr_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
     fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self>;
     fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self>;
     fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self>;
@@ -126,8 +711,46 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self>;
     fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
     fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn reduce_add_i16x8(self, a: i16x8<Self>) -> i16;
+    fn reduce_min_i16x8(self, a: i16x8<Self>) -> i16;
+    fn reduce_max_i16x8(self, a: i16x8<Self>) -> i16;
+    fn reduce_and_i16x8(self, a: i16x8<Self>) -> i16;
+    fn reduce_or_i16x8(self, a: i16x8<Self>) -> i16;
+    fn shuffle_i16x8<I: Indices<8>>(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn shuffle2_i16x8<I: Indices<8>>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn reverse_i16x8(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn rotate_lanes_left_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn rotate_lanes_right_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn broadcast_lane_i16x8<const L: usize>(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn unzip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn unzip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn abs_i16x8(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn neg_i16x8(self, a: i16x8<Self>) -> i16x8<Self>;
+    fn abs_diff_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> u16x8<Self>;
+    fn mul_widen_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x8<Self>;
+    fn mul_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn mul_q15_round_sat_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self>;
+    fn dot_i16_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x4<Self>;
     fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self>;
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self>;
+    fn widen_low_i16x8(self, a: i16x8<Self>) -> i32x4<Self>;
+    fn widen_high_i16x8(self, a: i16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_i16x8(self, a: i16x8<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_i16x8(self, a: i16x8<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_i16x8(self, a: i16x8<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_i16x8(self, a: i16x8<Self>) -> i8x16<Self>;
     fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self>;
+    fn reinterpret_u16_i16x8(self, a: i16x8<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_i16x8(self, a: i16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_i16x8(self, a: i16x8<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_i16x8(self, a: i16x8<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_i16x8(self, a: i16x8<Self>) -> u64x2<Self>;
+    fn cvt_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_i16x8(self, a: i16x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_i16x8(self, a: i16x8<Self>) -> f64x8<Self>;
     fn splat_u16x8(self, val: u16) -> u16x8<Self>;
     fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self>;
     fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
@@ -136,7 +759,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
     fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
     fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn shl_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self>;
     fn shr_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self>;
+    fn shl_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn shr_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
     fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self>;
     fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self>;
     fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self>;
@@ -147,8 +773,46 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self>;
     fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
     fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn reduce_add_u16x8(self, a: u16x8<Self>) -> u16;
+    fn reduce_min_u16x8(self, a: u16x8<Self>) -> u16;
+    fn reduce_max_u16x8(self, a: u16x8<Self>) -> u16;
+    fn reduce_and_u16x8(self, a: u16x8<Self>) -> u16;
+    fn reduce_or_u16x8(self, a: u16x8<Self>) -> u16;
+    fn shuffle_u16x8<I: Indices<8>>(self, a: u16x8<Self>) -> u16x8<Self>;
+    fn shuffle2_u16x8<I: Indices<8>>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn reverse_u16x8(self, a: u16x8<Self>) -> u16x8<Self>;
+    fn rotate_lanes_left_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self>;
+    fn rotate_lanes_right_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self>;
+    fn broadcast_lane_u16x8<const L: usize>(self, a: u16x8<Self>) -> u16x8<Self>;
+    fn unzip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn unzip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn abs_diff_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
+    fn mul_widen_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self>;
+    fn mul_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self>;
     fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self>;
+    fn split_u16x8(self, a: u16x8<Self>) -> (u16x4<Self>, u16x4<Self>);
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self>;
+    fn widen_low_u16x8(self, a: u16x8<Self>) -> u32x4<Self>;
+    fn widen_high_u16x8(self, a: u16x8<Self>) -> u32x4<Self>;
+    fn narrow_u16x8(self, a: u16x8<Self>) -> u8x8<Self>;
+    fn narrow_saturating_u16x8(self, a: u16x8<Self>) -> u8x8<Self>;
+    fn reinterpret_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_u16x8(self, a: u16x8<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_u16x8(self, a: u16x8<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_u16x8(self, a: u16x8<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_u16x8(self, a: u16x8<Self>) -> i8x16<Self>;
     fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_u16x8(self, a: u16x8<Self>) -> i16x8<Self>;
+    fn reinterpret_i32_u16x8(self, a: u16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_u16x8(self, a: u16x8<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_u16x8(self, a: u16x8<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_u16x8(self, a: u16x8<Self>) -> u64x2<Self>;
+    fn cvt_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_u16x8(self, a: u16x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_u16x8(self, a: u16x8<Self>) -> f64x8<Self>;
     fn splat_mask16x8(self, val: i16) -> mask16x8<Self>;
     fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self>;
     fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self>;
@@ -161,7 +825,36 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask16x8<Self>,
     ) -> mask16x8<Self>;
     fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self>;
+    fn any_mask16x8(self, a: mask16x8<Self>) -> bool;
+    fn all_mask16x8(self, a: mask16x8<Self>) -> bool;
+    fn none_mask16x8(self, a: mask16x8<Self>) -> bool;
+    fn count_true_mask16x8(self, a: mask16x8<Self>) -> usize;
+    fn first_true_mask16x8(self, a: mask16x8<Self>) -> Option<usize>;
+    fn to_bitmask_mask16x8(self, a: mask16x8<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self>;
+    fn shuffle_mask16x8<I: Indices<8>>(self, a: mask16x8<Self>) -> mask16x8<Self>;
+    fn shuffle2_mask16x8<I: Indices<8>>(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+    ) -> mask16x8<Self>;
+    fn reverse_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self>;
+    fn rotate_lanes_left_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self>;
+    fn rotate_lanes_right_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self>;
+    fn broadcast_lane_mask16x8<const L: usize>(self, a: mask16x8<Self>) -> mask16x8<Self>;
+    fn unzip_low_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self>;
+    fn unzip_high_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self>;
     fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self>;
+    fn split_mask16x8(self, a: mask16x8<Self>) -> (mask16x4<Self>, mask16x4<Self>);
+    fn reinterpret_i8_mask16x8(self, a: mask16x8<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_mask16x8(self, a: mask16x8<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_mask16x8(self, a: mask16x8<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_mask16x8(self, a: mask16x8<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_mask16x8(self, a: mask16x8<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_mask16x8(self, a: mask16x8<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_mask16x8(self, a: mask16x8<Self>) -> u64x2<Self>;
     fn splat_i32x4(self, val: i32) -> i32x4<Self>;
     fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self>;
     fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
@@ -170,7 +863,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
     fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
     fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn shl_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self>;
     fn shr_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self>;
+    fn shl_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn shr_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
     fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self>;
     fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self>;
     fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self>;
@@ -181,9 +877,40 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self>;
     fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
     fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn reduce_add_i32x4(self, a: i32x4<Self>) -> i32;
+    fn reduce_min_i32x4(self, a: i32x4<Self>) -> i32;
+    fn reduce_max_i32x4(self, a: i32x4<Self>) -> i32;
+    fn reduce_and_i32x4(self, a: i32x4<Self>) -> i32;
+    fn reduce_or_i32x4(self, a: i32x4<Self>) -> i32;
+    fn shuffle_i32x4<I: Indices<4>>(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn shuffle2_i32x4<I: Indices<4>>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn reverse_i32x4(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn rotate_lanes_left_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn rotate_lanes_right_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn broadcast_lane_i32x4<const L: usize>(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn unzip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn unzip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self>;
+    fn abs_i32x4(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self>;
+    fn abs_diff_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> u32x4<Self>;
     fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self>;
+    fn split_i32x4(self, a: i32x4<Self>) -> (i32x2<Self>, i32x2<Self>);
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self>;
+    fn widen_low_i32x4(self, a: i32x4<Self>) -> i64x2<Self>;
+    fn widen_high_i32x4(self, a: i32x4<Self>) -> i64x2<Self>;
+    fn reinterpret_f16_i32x4(self, a: i32x4<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_i32x4(self, a: i32x4<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_i32x4(self, a: i32x4<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_i32x4(self, a: i32x4<Self>) -> i8x16<Self>;
     fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_i32x4(self, a: i32x4<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_i32x4(self, a: i32x4<Self>) -> u16x8<Self>;
+    fn reinterpret_u32_i32x4(self, a: i32x4<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_i32x4(self, a: i32x4<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_i32x4(self, a: i32x4<Self>) -> u64x2<Self>;
     fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self>;
+    fn cvt_f64_i32x4(self, a: i32x4<Self>) -> f64x4<Self>;
     fn splat_u32x4(self, val: u32) -> u32x4<Self>;
     fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self>;
     fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
@@ -192,7 +919,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
     fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
     fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn shl_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self>;
     fn shr_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self>;
+    fn shl_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn shr_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
     fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self>;
     fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self>;
     fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self>;
@@ -203,9 +933,39 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self>;
     fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
     fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn reduce_add_u32x4(self, a: u32x4<Self>) -> u32;
+    fn reduce_min_u32x4(self, a: u32x4<Self>) -> u32;
+    fn reduce_max_u32x4(self, a: u32x4<Self>) -> u32;
+    fn reduce_and_u32x4(self, a: u32x4<Self>) -> u32;
+    fn reduce_or_u32x4(self, a: u32x4<Self>) -> u32;
+    fn shuffle_u32x4<I: Indices<4>>(self, a: u32x4<Self>) -> u32x4<Self>;
+    fn shuffle2_u32x4<I: Indices<4>>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn reverse_u32x4(self, a: u32x4<Self>) -> u32x4<Self>;
+    fn rotate_lanes_left_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self>;
+    fn rotate_lanes_right_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self>;
+    fn broadcast_lane_u32x4<const L: usize>(self, a: u32x4<Self>) -> u32x4<Self>;
+    fn unzip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn unzip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
+    fn abs_diff_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self>;
     fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self>;
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self>;
+    fn widen_low_u32x4(self, a: u32x4<Self>) -> u64x2<Self>;
+    fn widen_high_u32x4(self, a: u32x4<Self>) -> u64x2<Self>;
+    fn narrow_u32x4(self, a: u32x4<Self>) -> u16x4<Self>;
+    fn narrow_saturating_u32x4(self, a: u32x4<Self>) -> u16x4<Self>;
+    fn reinterpret_f16_u32x4(self, a: u32x4<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_u32x4(self, a: u32x4<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_u32x4(self, a: u32x4<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_u32x4(self, a: u32x4<Self>) -> i8x16<Self>;
     fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_u32x4(self, a: u32x4<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_u32x4(self, a: u32x4<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_u32x4(self, a: u32x4<Self>) -> i32x4<Self>;
+    fn reinterpret_i64_u32x4(self, a: u32x4<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_u32x4(self, a: u32x4<Self>) -> u64x2<Self>;
     fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self>;
+    fn cvt_f64_u32x4(self, a: u32x4<Self>) -> f64x4<Self>;
     fn splat_mask32x4(self, val: i32) -> mask32x4<Self>;
     fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self>;
     fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self>;
@@ -218,21 +978,391 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask32x4<Self>,
     ) -> mask32x4<Self>;
     fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self>;
+    fn any_mask32x4(self, a: mask32x4<Self>) -> bool;
+    fn all_mask32x4(self, a: mask32x4<Self>) -> bool;
+    fn none_mask32x4(self, a: mask32x4<Self>) -> bool;
+    fn count_true_mask32x4(self, a: mask32x4<Self>) -> usize;
+    fn first_true_mask32x4(self, a: mask32x4<Self>) -> Option<usize>;
+    fn to_bitmask_mask32x4(self, a: mask32x4<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self>;
+    fn shuffle_mask32x4<I: Indices<4>>(self, a: mask32x4<Self>) -> mask32x4<Self>;
+    fn shuffle2_mask32x4<I: Indices<4>>(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+    ) -> mask32x4<Self>;
+    fn reverse_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self>;
+    fn rotate_lanes_left_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self>;
+    fn rotate_lanes_right_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self>;
+    fn broadcast_lane_mask32x4<const L: usize>(self, a: mask32x4<Self>) -> mask32x4<Self>;
+    fn unzip_low_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self>;
+    fn unzip_high_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self>;
     fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self>;
+    fn split_mask32x4(self, a: mask32x4<Self>) -> (mask32x2<Self>, mask32x2<Self>);
+    fn reinterpret_i8_mask32x4(self, a: mask32x4<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_mask32x4(self, a: mask32x4<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_mask32x4(self, a: mask32x4<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_mask32x4(self, a: mask32x4<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_mask32x4(self, a: mask32x4<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_mask32x4(self, a: mask32x4<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_mask32x4(self, a: mask32x4<Self>) -> u64x2<Self>;
+    fn splat_f64x2(self, val: f64) -> f64x2<Self>;
+    fn abs_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn neg_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn recip_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn rsqrt_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn recip_f64x2(self, a: f64x2<Self>, steps: u32) -> f64x2<Self>;
+    fn rsqrt_f64x2(self, a: f64x2<Self>, steps: u32) -> f64x2<Self>;
+    fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn copysign_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn simd_eq_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn simd_ne_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn simd_lt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn simd_le_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn simd_ge_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn simd_gt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self>;
+    fn is_nan_f64x2(self, a: f64x2<Self>) -> mask64x2<Self>;
+    fn is_finite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self>;
+    fn is_infinite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self>;
+    fn is_subnormal_f64x2(self, a: f64x2<Self>) -> mask64x2<Self>;
+    fn is_sign_negative_f64x2(self, a: f64x2<Self>) -> mask64x2<Self>;
+    fn zip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn zip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn madd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn msub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn nmadd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn nmsub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn mul_add_fused_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn mul_add_fast_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn round_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self>;
+    fn reduce_add_f64x2(self, a: f64x2<Self>) -> f64;
+    fn reduce_min_f64x2(self, a: f64x2<Self>) -> f64;
+    fn reduce_max_f64x2(self, a: f64x2<Self>) -> f64;
+    fn shuffle_f64x2<I: Indices<2>>(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn shuffle2_f64x2<I: Indices<2>>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn reverse_f64x2(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn rotate_lanes_left_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn rotate_lanes_right_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn broadcast_lane_f64x2<const L: usize>(self, a: f64x2<Self>) -> f64x2<Self>;
+    fn unzip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn unzip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self>;
+    fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self>;
+    fn reinterpret_f16_f64x2(self, a: f64x2<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_f64x2(self, a: f64x2<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_f64x2(self, a: f64x2<Self>) -> f32x4<Self>;
+    fn reinterpret_i8_f64x2(self, a: f64x2<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_f64x2(self, a: f64x2<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_f64x2(self, a: f64x2<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_f64x2(self, a: f64x2<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_f64x2(self, a: f64x2<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_f64x2(self, a: f64x2<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self>;
+    fn cvt_f32_f64x2(self, a: f64x2<Self>) -> f32x2<Self>;
+    fn cvt_i32_f64x2(self, a: f64x2<Self>) -> i32x2<Self>;
+    fn cvt_i32_round_f64x2(self, a: f64x2<Self>) -> i32x2<Self>;
+    fn cvt_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self>;
+    fn cvt_i64_round_f64x2(self, a: f64x2<Self>) -> i64x2<Self>;
+    fn cvt_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self>;
+    fn cvt_u64_round_f64x2(self, a: f64x2<Self>) -> u64x2<Self>;
+    fn splat_i64x2(self, val: i64) -> i64x2<Self>;
+    fn not_i64x2(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn mul_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn and_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn or_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn xor_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn shl_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self>;
+    fn shr_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self>;
+    fn shl_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn shr_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self>;
+    fn simd_lt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self>;
+    fn simd_le_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self>;
+    fn simd_ge_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self>;
+    fn simd_gt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self>;
+    fn zip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn zip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn select_i64x2(self, a: mask64x2<Self>, b: i64x2<Self>, c: i64x2<Self>) -> i64x2<Self>;
+    fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn max_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn reduce_add_i64x2(self, a: i64x2<Self>) -> i64;
+    fn reduce_min_i64x2(self, a: i64x2<Self>) -> i64;
+    fn reduce_max_i64x2(self, a: i64x2<Self>) -> i64;
+    fn reduce_and_i64x2(self, a: i64x2<Self>) -> i64;
+    fn reduce_or_i64x2(self, a: i64x2<Self>) -> i64;
+    fn shuffle_i64x2<I: Indices<2>>(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn shuffle2_i64x2<I: Indices<2>>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn reverse_i64x2(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn rotate_lanes_left_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn rotate_lanes_right_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn broadcast_lane_i64x2<const L: usize>(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn unzip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn unzip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self>;
+    fn abs_i64x2(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn neg_i64x2(self, a: i64x2<Self>) -> i64x2<Self>;
+    fn abs_diff_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> u64x2<Self>;
+    fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self>;
+    fn narrow_i64x2(self, a: i64x2<Self>) -> i32x2<Self>;
+    fn narrow_saturating_i64x2(self, a: i64x2<Self>) -> i32x2<Self>;
+    fn reinterpret_f16_i64x2(self, a: i64x2<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_i64x2(self, a: i64x2<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_i64x2(self, a: i64x2<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_i64x2(self, a: i64x2<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_i64x2(self, a: i64x2<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_i64x2(self, a: i64x2<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_i64x2(self, a: i64x2<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_i64x2(self, a: i64x2<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_i64x2(self, a: i64x2<Self>) -> u32x4<Self>;
+    fn reinterpret_u64_i64x2(self, a: i64x2<Self>) -> u64x2<Self>;
+    fn cvt_f32_i64x2(self, a: i64x2<Self>) -> f32x2<Self>;
+    fn cvt_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self>;
+    fn splat_u64x2(self, val: u64) -> u64x2<Self>;
+    fn not_u64x2(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn mul_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn and_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn or_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn xor_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn shl_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self>;
+    fn shr_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self>;
+    fn shl_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn shr_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self>;
+    fn simd_lt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self>;
+    fn simd_le_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self>;
+    fn simd_ge_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self>;
+    fn simd_gt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self>;
+    fn zip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn zip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn select_u64x2(self, a: mask64x2<Self>, b: u64x2<Self>, c: u64x2<Self>) -> u64x2<Self>;
+    fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn max_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn reduce_add_u64x2(self, a: u64x2<Self>) -> u64;
+    fn reduce_min_u64x2(self, a: u64x2<Self>) -> u64;
+    fn reduce_max_u64x2(self, a: u64x2<Self>) -> u64;
+    fn reduce_and_u64x2(self, a: u64x2<Self>) -> u64;
+    fn reduce_or_u64x2(self, a: u64x2<Self>) -> u64;
+    fn shuffle_u64x2<I: Indices<2>>(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn shuffle2_u64x2<I: Indices<2>>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn reverse_u64x2(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn rotate_lanes_left_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn rotate_lanes_right_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn broadcast_lane_u64x2<const L: usize>(self, a: u64x2<Self>) -> u64x2<Self>;
+    fn unzip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn unzip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn abs_diff_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self>;
+    fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self>;
+    fn reinterpret_f16_u64x2(self, a: u64x2<Self>) -> f16x8<Self>;
+    fn reinterpret_bf16_u64x2(self, a: u64x2<Self>) -> bf16x8<Self>;
+    fn reinterpret_f32_u64x2(self, a: u64x2<Self>) -> f32x4<Self>;
+    fn reinterpret_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self>;
+    fn reinterpret_i8_u64x2(self, a: u64x2<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_u64x2(self, a: u64x2<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_u64x2(self, a: u64x2<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_u64x2(self, a: u64x2<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_u64x2(self, a: u64x2<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_u64x2(self, a: u64x2<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_u64x2(self, a: u64x2<Self>) -> i64x2<Self>;
+    fn cvt_f32_u64x2(self, a: u64x2<Self>) -> f32x2<Self>;
+    fn cvt_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self>;
+    fn splat_mask64x2(self, val: i64) -> mask64x2<Self>;
+    fn not_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn and_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn or_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn xor_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn select_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self>;
+    fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn any_mask64x2(self, a: mask64x2<Self>) -> bool;
+    fn all_mask64x2(self, a: mask64x2<Self>) -> bool;
+    fn none_mask64x2(self, a: mask64x2<Self>) -> bool;
+    fn count_true_mask64x2(self, a: mask64x2<Self>) -> usize;
+    fn first_true_mask64x2(self, a: mask64x2<Self>) -> Option<usize>;
+    fn to_bitmask_mask64x2(self, a: mask64x2<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self>;
+    fn shuffle_mask64x2<I: Indices<2>>(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn shuffle2_mask64x2<I: Indices<2>>(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+    ) -> mask64x2<Self>;
+    fn reverse_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn rotate_lanes_left_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn rotate_lanes_right_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn broadcast_lane_mask64x2<const L: usize>(self, a: mask64x2<Self>) -> mask64x2<Self>;
+    fn unzip_low_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn unzip_high_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self>;
+    fn combine_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x4<Self>;
+    fn reinterpret_i8_mask64x2(self, a: mask64x2<Self>) -> i8x16<Self>;
+    fn reinterpret_u8_mask64x2(self, a: mask64x2<Self>) -> u8x16<Self>;
+    fn reinterpret_i16_mask64x2(self, a: mask64x2<Self>) -> i16x8<Self>;
+    fn reinterpret_u16_mask64x2(self, a: mask64x2<Self>) -> u16x8<Self>;
+    fn reinterpret_i32_mask64x2(self, a: mask64x2<Self>) -> i32x4<Self>;
+    fn reinterpret_u32_mask64x2(self, a: mask64x2<Self>) -> u32x4<Self>;
+    fn reinterpret_i64_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self>;
+    fn reinterpret_u64_mask64x2(self, a: mask64x2<Self>) -> u64x2<Self>;
+    fn splat_f16x16(self, val: f16) -> f16x16<Self>;
+    fn abs_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn neg_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn sqrt_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn recip_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn rsqrt_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn recip_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self>;
+    fn rsqrt_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self>;
+    fn add_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn sub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn mul_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn div_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn copysign_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn simd_eq_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn simd_ne_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn simd_lt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn simd_le_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn simd_ge_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn simd_gt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self>;
+    fn is_nan_f16x16(self, a: f16x16<Self>) -> mask16x16<Self>;
+    fn is_finite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self>;
+    fn is_infinite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self>;
+    fn is_subnormal_f16x16(self, a: f16x16<Self>) -> mask16x16<Self>;
+    fn is_sign_negative_f16x16(self, a: f16x16<Self>) -> mask16x16<Self>;
+    fn zip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn zip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn max_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn max_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn min_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn min_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn madd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self>;
+    fn msub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self>;
+    fn nmadd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self>;
+    fn nmsub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self>;
+    fn mul_add_fused_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self>;
+    fn mul_add_fast_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>)
+    -> f16x16<Self>;
+    fn floor_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn ceil_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn round_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn round_ties_even_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn fract_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn trunc_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn select_f16x16(self, a: mask16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self>;
+    fn reduce_add_f16x16(self, a: f16x16<Self>) -> f16;
+    fn reduce_min_f16x16(self, a: f16x16<Self>) -> f16;
+    fn reduce_max_f16x16(self, a: f16x16<Self>) -> f16;
+    fn shuffle_f16x16<I: Indices<16>>(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn shuffle2_f16x16<I: Indices<16>>(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn reverse_f16x16(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn rotate_lanes_left_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn rotate_lanes_right_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn broadcast_lane_f16x16<const L: usize>(self, a: f16x16<Self>) -> f16x16<Self>;
+    fn unzip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn unzip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self>;
+    fn split_f16x16(self, a: f16x16<Self>) -> (f16x8<Self>, f16x8<Self>);
+    fn reinterpret_bf16_f16x16(self, a: f16x16<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_f16x16(self, a: f16x16<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_f16x16(self, a: f16x16<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_f16x16(self, a: f16x16<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_f16x16(self, a: f16x16<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_f16x16(self, a: f16x16<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_f16x16(self, a: f16x16<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_f16x16(self, a: f16x16<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_f16x16(self, a: f16x16<Self>) -> u64x4<Self>;
+    fn cvt_f32_f16x16(self, a: f16x16<Self>) -> f32x16<Self>;
+    fn cvt_i8_f16x16(self, a: f16x16<Self>) -> i8x16<Self>;
+    fn cvt_i8_round_f16x16(self, a: f16x16<Self>) -> i8x16<Self>;
+    fn cvt_u8_f16x16(self, a: f16x16<Self>) -> u8x16<Self>;
+    fn cvt_u8_round_f16x16(self, a: f16x16<Self>) -> u8x16<Self>;
+    fn cvt_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self>;
+    fn cvt_i16_round_f16x16(self, a: f16x16<Self>) -> i16x16<Self>;
+    fn cvt_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self>;
+    fn cvt_u16_round_f16x16(self, a: f16x16<Self>) -> u16x16<Self>;
+    fn cvt_i32_f16x16(self, a: f16x16<Self>) -> i32x16<Self>;
+    fn cvt_i32_round_f16x16(self, a: f16x16<Self>) -> i32x16<Self>;
+    fn cvt_u32_f16x16(self, a: f16x16<Self>) -> u32x16<Self>;
+    fn cvt_u32_round_f16x16(self, a: f16x16<Self>) -> u32x16<Self>;
+    fn splat_bf16x16(self, val: bf16) -> bf16x16<Self>;
+    fn select_bf16x16(
+        self,
+        a: mask16x16<Self>,
+        b: bf16x16<Self>,
+        c: bf16x16<Self>,
+    ) -> bf16x16<Self>;
+    fn shuffle_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>) -> bf16x16<Self>;
+    fn shuffle2_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self>;
+    fn reverse_bf16x16(self, a: bf16x16<Self>) -> bf16x16<Self>;
+    fn rotate_lanes_left_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self>;
+    fn rotate_lanes_right_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self>;
+    fn broadcast_lane_bf16x16<const L: usize>(self, a: bf16x16<Self>) -> bf16x16<Self>;
+    fn unzip_low_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self>;
+    fn unzip_high_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self>;
+    fn split_bf16x16(self, a: bf16x16<Self>) -> (bf16x8<Self>, bf16x8<Self>);
+    fn reinterpret_f16_bf16x16(self, a: bf16x16<Self>) -> f16x16<Self>;
+    fn reinterpret_f32_bf16x16(self, a: bf16x16<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_bf16x16(self, a: bf16x16<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_bf16x16(self, a: bf16x16<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_bf16x16(self, a: bf16x16<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_bf16x16(self, a: bf16x16<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_bf16x16(self, a: bf16x16<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_bf16x16(self, a: bf16x16<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_bf16x16(self, a: bf16x16<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_bf16x16(self, a: bf16x16<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_bf16x16(self, a: bf16x16<Self>) -> u64x4<Self>;
+    fn cvt_f32_bf16x16(self, a: bf16x16<Self>) -> f32x16<Self>;
     fn splat_f32x8(self, val: f32) -> f32x8<Self>;
     fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
     fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
     fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn recip_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn rsqrt_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn recip_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self>;
+    fn rsqrt_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self>;
     fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
+    fn simd_ne_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
     fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
     fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
     fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
     fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self>;
+    fn is_nan_f32x8(self, a: f32x8<Self>) -> mask32x8<Self>;
+    fn is_finite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self>;
+    fn is_infinite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self>;
+    fn is_subnormal_f32x8(self, a: f32x8<Self>) -> mask32x8<Self>;
+    fn is_sign_negative_f32x8(self, a: f32x8<Self>) -> mask32x8<Self>;
     fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
@@ -241,14 +1371,59 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn madd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
     fn msub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
+    fn nmadd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
+    fn nmsub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
+    fn mul_add_fused_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
+    fn mul_add_fast_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
     fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn ceil_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn round_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn round_ties_even_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
     fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
     fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
     fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self>;
+    fn reduce_add_f32x8(self, a: f32x8<Self>) -> f32;
+    fn reduce_min_f32x8(self, a: f32x8<Self>) -> f32;
+    fn reduce_max_f32x8(self, a: f32x8<Self>) -> f32;
+    fn shuffle_f32x8<I: Indices<8>>(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn shuffle2_f32x8<I: Indices<8>>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
+    fn reverse_f32x8(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn rotate_lanes_left_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn rotate_lanes_right_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn broadcast_lane_f32x8<const L: usize>(self, a: f32x8<Self>) -> f32x8<Self>;
+    fn unzip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
+    fn unzip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self>;
     fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self>;
     fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>);
-    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self>;
+    fn reinterpret_f16_f32x8(self, a: f32x8<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_f32x8(self, a: f32x8<Self>) -> bf16x16<Self>;
+    fn reinterpret_f64_f32x8(self, a: f32x8<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_f32x8(self, a: f32x8<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_f32x8(self, a: f32x8<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_f32x8(self, a: f32x8<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_f32x8(self, a: f32x8<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_f32x8(self, a: f32x8<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_f32x8(self, a: f32x8<Self>) -> u64x4<Self>;
+    fn cvt_f16_f32x8(self, a: f32x8<Self>) -> f16x8<Self>;
+    fn cvt_f64_f32x8(self, a: f32x8<Self>) -> f64x8<Self>;
+    fn cvt_u8_f32x8(self, a: f32x8<Self>) -> u8x8<Self>;
+    fn cvt_u8_round_f32x8(self, a: f32x8<Self>) -> u8x8<Self>;
+    fn cvt_i16_f32x8(self, a: f32x8<Self>) -> i16x8<Self>;
+    fn cvt_i16_round_f32x8(self, a: f32x8<Self>) -> i16x8<Self>;
+    fn cvt_u16_f32x8(self, a: f32x8<Self>) -> u16x8<Self>;
+    fn cvt_u16_round_f32x8(self, a: f32x8<Self>) -> u16x8<Self>;
     fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self>;
+    fn cvt_i32_round_f32x8(self, a: f32x8<Self>) -> i32x8<Self>;
+    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self>;
+    fn cvt_u32_round_f32x8(self, a: f32x8<Self>) -> u32x8<Self>;
+    fn cvt_i64_f32x8(self, a: f32x8<Self>) -> i64x8<Self>;
+    fn cvt_i64_round_f32x8(self, a: f32x8<Self>) -> i64x8<Self>;
+    fn cvt_u64_f32x8(self, a: f32x8<Self>) -> u64x8<Self>;
+    fn cvt_u64_round_f32x8(self, a: f32x8<Self>) -> u64x8<Self>;
+    fn cvt_bf16_f32x8(self, a: f32x8<Self>) -> bf16x8<Self>;
+    fn dot_bf16_f32x8(self, a: f32x8<Self>, b: bf16x16<Self>, c: bf16x16<Self>) -> f32x8<Self>;
     fn splat_i8x32(self, val: i8) -> i8x32<Self>;
     fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self>;
     fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
@@ -257,7 +1432,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
     fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
     fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn shl_i8x32(self, a: i8x32<Self>, shift: u32) -> i8x32<Self>;
     fn shr_i8x32(self, a: i8x32<Self>, shift: u32) -> i8x32<Self>;
+    fn shl_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn shr_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
     fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self>;
     fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self>;
     fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self>;
@@ -268,9 +1446,42 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self>;
     fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
     fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn reduce_add_i8x32(self, a: i8x32<Self>) -> i8;
+    fn reduce_min_i8x32(self, a: i8x32<Self>) -> i8;
+    fn reduce_max_i8x32(self, a: i8x32<Self>) -> i8;
+    fn reduce_and_i8x32(self, a: i8x32<Self>) -> i8;
+    fn reduce_or_i8x32(self, a: i8x32<Self>) -> i8;
+    fn shuffle_i8x32<I: Indices<32>>(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn shuffle2_i8x32<I: Indices<32>>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn reverse_i8x32(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn rotate_lanes_left_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn rotate_lanes_right_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn broadcast_lane_i8x32<const L: usize>(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn unzip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn unzip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
+    fn abs_i8x32(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn neg_i8x32(self, a: i8x32<Self>) -> i8x32<Self>;
+    fn abs_diff_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> u8x32<Self>;
+    fn mul_widen_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i16x32<Self>;
+    fn mul_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self>;
     fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self>;
     fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>);
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self>;
+    fn widen_low_i8x32(self, a: i8x32<Self>) -> i16x16<Self>;
+    fn widen_high_i8x32(self, a: i8x32<Self>) -> i16x16<Self>;
+    fn reinterpret_f16_i8x32(self, a: i8x32<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_i8x32(self, a: i8x32<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_i8x32(self, a: i8x32<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_i8x32(self, a: i8x32<Self>) -> f64x4<Self>;
     fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_i8x32(self, a: i8x32<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_i8x32(self, a: i8x32<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_i8x32(self, a: i8x32<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_i8x32(self, a: i8x32<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_i8x32(self, a: i8x32<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_i8x32(self, a: i8x32<Self>) -> u64x4<Self>;
     fn splat_u8x32(self, val: u8) -> u8x32<Self>;
     fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self>;
     fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
@@ -279,7 +1490,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
     fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
     fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn shl_u8x32(self, a: u8x32<Self>, shift: u32) -> u8x32<Self>;
     fn shr_u8x32(self, a: u8x32<Self>, shift: u32) -> u8x32<Self>;
+    fn shl_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn shr_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
     fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self>;
     fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self>;
     fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self>;
@@ -290,9 +1504,45 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self>;
     fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
     fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn reduce_add_u8x32(self, a: u8x32<Self>) -> u8;
+    fn reduce_min_u8x32(self, a: u8x32<Self>) -> u8;
+    fn reduce_max_u8x32(self, a: u8x32<Self>) -> u8;
+    fn reduce_and_u8x32(self, a: u8x32<Self>) -> u8;
+    fn reduce_or_u8x32(self, a: u8x32<Self>) -> u8;
+    fn shuffle_u8x32<I: Indices<32>>(self, a: u8x32<Self>) -> u8x32<Self>;
+    fn shuffle2_u8x32<I: Indices<32>>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn reverse_u8x32(self, a: u8x32<Self>) -> u8x32<Self>;
+    fn rotate_lanes_left_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self>;
+    fn rotate_lanes_right_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self>;
+    fn broadcast_lane_u8x32<const L: usize>(self, a: u8x32<Self>) -> u8x32<Self>;
+    fn unzip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn unzip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn abs_diff_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn mul_widen_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u16x32<Self>;
+    fn mul_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn swizzle_dyn_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn lookup_32_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self>;
+    fn lookup_48_u8x32(self, a: [u8x16<Self>; 3], b: u8x32<Self>) -> u8x32<Self>;
+    fn lookup_64_u8x32(self, a: u8x64<Self>, b: u8x32<Self>) -> u8x32<Self>;
     fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self>;
     fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>);
     fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self>;
+    fn widen_low_u8x32(self, a: u8x32<Self>) -> u16x16<Self>;
+    fn widen_high_u8x32(self, a: u8x32<Self>) -> u16x16<Self>;
+    fn reinterpret_f16_u8x32(self, a: u8x32<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_u8x32(self, a: u8x32<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_u8x32(self, a: u8x32<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_u8x32(self, a: u8x32<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_u8x32(self, a: u8x32<Self>) -> i8x32<Self>;
+    fn reinterpret_i16_u8x32(self, a: u8x32<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_u8x32(self, a: u8x32<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_u8x32(self, a: u8x32<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_u8x32(self, a: u8x32<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_u8x32(self, a: u8x32<Self>) -> u64x4<Self>;
     fn splat_mask8x32(self, val: i8) -> mask8x32<Self>;
     fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self>;
     fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self>;
@@ -305,8 +1555,36 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask8x32<Self>,
     ) -> mask8x32<Self>;
     fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self>;
+    fn any_mask8x32(self, a: mask8x32<Self>) -> bool;
+    fn all_mask8x32(self, a: mask8x32<Self>) -> bool;
+    fn none_mask8x32(self, a: mask8x32<Self>) -> bool;
+    fn count_true_mask8x32(self, a: mask8x32<Self>) -> usize;
+    fn first_true_mask8x32(self, a: mask8x32<Self>) -> Option<usize>;
+    fn to_bitmask_mask8x32(self, a: mask8x32<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self>;
+    fn shuffle_mask8x32<I: Indices<32>>(self, a: mask8x32<Self>) -> mask8x32<Self>;
+    fn shuffle2_mask8x32<I: Indices<32>>(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+    ) -> mask8x32<Self>;
+    fn reverse_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self>;
+    fn rotate_lanes_left_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self>;
+    fn rotate_lanes_right_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self>;
+    fn broadcast_lane_mask8x32<const L: usize>(self, a: mask8x32<Self>) -> mask8x32<Self>;
+    fn unzip_low_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self>;
+    fn unzip_high_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self>;
     fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self>;
     fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>);
+    fn reinterpret_i8_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_mask8x32(self, a: mask8x32<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_mask8x32(self, a: mask8x32<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_mask8x32(self, a: mask8x32<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_mask8x32(self, a: mask8x32<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_mask8x32(self, a: mask8x32<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_mask8x32(self, a: mask8x32<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_mask8x32(self, a: mask8x32<Self>) -> u64x4<Self>;
     fn splat_i16x16(self, val: i16) -> i16x16<Self>;
     fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self>;
     fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
@@ -315,7 +1593,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
     fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
     fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn shl_i16x16(self, a: i16x16<Self>, shift: u32) -> i16x16<Self>;
     fn shr_i16x16(self, a: i16x16<Self>, shift: u32) -> i16x16<Self>;
+    fn shl_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn shr_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
     fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self>;
     fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self>;
     fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self>;
@@ -326,9 +1607,48 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self>;
     fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
     fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn reduce_add_i16x16(self, a: i16x16<Self>) -> i16;
+    fn reduce_min_i16x16(self, a: i16x16<Self>) -> i16;
+    fn reduce_max_i16x16(self, a: i16x16<Self>) -> i16;
+    fn reduce_and_i16x16(self, a: i16x16<Self>) -> i16;
+    fn reduce_or_i16x16(self, a: i16x16<Self>) -> i16;
+    fn shuffle_i16x16<I: Indices<16>>(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn shuffle2_i16x16<I: Indices<16>>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn reverse_i16x16(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn rotate_lanes_left_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn rotate_lanes_right_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn broadcast_lane_i16x16<const L: usize>(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn unzip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn unzip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn abs_i16x16(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn neg_i16x16(self, a: i16x16<Self>) -> i16x16<Self>;
+    fn abs_diff_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> u16x16<Self>;
+    fn mul_widen_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x16<Self>;
+    fn mul_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn mul_q15_round_sat_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self>;
+    fn dot_i16_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x8<Self>;
     fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self>;
     fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>);
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self>;
+    fn widen_low_i16x16(self, a: i16x16<Self>) -> i32x8<Self>;
+    fn widen_high_i16x16(self, a: i16x16<Self>) -> i32x8<Self>;
+    fn narrow_i16x16(self, a: i16x16<Self>) -> i8x16<Self>;
+    fn narrow_saturating_i16x16(self, a: i16x16<Self>) -> i8x16<Self>;
+    fn reinterpret_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_i16x16(self, a: i16x16<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_i16x16(self, a: i16x16<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_i16x16(self, a: i16x16<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_i16x16(self, a: i16x16<Self>) -> i8x32<Self>;
     fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self>;
+    fn reinterpret_u16_i16x16(self, a: i16x16<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_i16x16(self, a: i16x16<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_i16x16(self, a: i16x16<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_i16x16(self, a: i16x16<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_i16x16(self, a: i16x16<Self>) -> u64x4<Self>;
+    fn cvt_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self>;
+    fn cvt_f32_i16x16(self, a: i16x16<Self>) -> f32x16<Self>;
     fn splat_u16x16(self, val: u16) -> u16x16<Self>;
     fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self>;
     fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
@@ -337,7 +1657,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
     fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
     fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn shl_u16x16(self, a: u16x16<Self>, shift: u32) -> u16x16<Self>;
     fn shr_u16x16(self, a: u16x16<Self>, shift: u32) -> u16x16<Self>;
+    fn shl_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn shr_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
     fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self>;
     fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self>;
     fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self>;
@@ -348,10 +1671,45 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self>;
     fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
     fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn reduce_add_u16x16(self, a: u16x16<Self>) -> u16;
+    fn reduce_min_u16x16(self, a: u16x16<Self>) -> u16;
+    fn reduce_max_u16x16(self, a: u16x16<Self>) -> u16;
+    fn reduce_and_u16x16(self, a: u16x16<Self>) -> u16;
+    fn reduce_or_u16x16(self, a: u16x16<Self>) -> u16;
+    fn shuffle_u16x16<I: Indices<16>>(self, a: u16x16<Self>) -> u16x16<Self>;
+    fn shuffle2_u16x16<I: Indices<16>>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn reverse_u16x16(self, a: u16x16<Self>) -> u16x16<Self>;
+    fn rotate_lanes_left_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self>;
+    fn rotate_lanes_right_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self>;
+    fn broadcast_lane_u16x16<const L: usize>(self, a: u16x16<Self>) -> u16x16<Self>;
+    fn unzip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn unzip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn abs_diff_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
+    fn mul_widen_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u32x16<Self>;
+    fn mul_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self>;
     fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self>;
     fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>);
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self>;
+    fn widen_low_u16x16(self, a: u16x16<Self>) -> u32x8<Self>;
+    fn widen_high_u16x16(self, a: u16x16<Self>) -> u32x8<Self>;
     fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self>;
+    fn narrow_saturating_u16x16(self, a: u16x16<Self>) -> u8x16<Self>;
+    fn reinterpret_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_u16x16(self, a: u16x16<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_u16x16(self, a: u16x16<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_u16x16(self, a: u16x16<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_u16x16(self, a: u16x16<Self>) -> i8x32<Self>;
     fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_u16x16(self, a: u16x16<Self>) -> i16x16<Self>;
+    fn reinterpret_i32_u16x16(self, a: u16x16<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_u16x16(self, a: u16x16<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_u16x16(self, a: u16x16<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_u16x16(self, a: u16x16<Self>) -> u64x4<Self>;
+    fn cvt_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self>;
+    fn cvt_f32_u16x16(self, a: u16x16<Self>) -> f32x16<Self>;
     fn splat_mask16x16(self, val: i16) -> mask16x16<Self>;
     fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self>;
     fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self>;
@@ -364,8 +1722,36 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask16x16<Self>,
     ) -> mask16x16<Self>;
     fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self>;
+    fn any_mask16x16(self, a: mask16x16<Self>) -> bool;
+    fn all_mask16x16(self, a: mask16x16<Self>) -> bool;
+    fn none_mask16x16(self, a: mask16x16<Self>) -> bool;
+    fn count_true_mask16x16(self, a: mask16x16<Self>) -> usize;
+    fn first_true_mask16x16(self, a: mask16x16<Self>) -> Option<usize>;
+    fn to_bitmask_mask16x16(self, a: mask16x16<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self>;
+    fn shuffle_mask16x16<I: Indices<16>>(self, a: mask16x16<Self>) -> mask16x16<Self>;
+    fn shuffle2_mask16x16<I: Indices<16>>(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+    ) -> mask16x16<Self>;
+    fn reverse_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self>;
+    fn rotate_lanes_left_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self>;
+    fn rotate_lanes_right_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self>;
+    fn broadcast_lane_mask16x16<const L: usize>(self, a: mask16x16<Self>) -> mask16x16<Self>;
+    fn unzip_low_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self>;
+    fn unzip_high_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self>;
     fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self>;
     fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>);
+    fn reinterpret_i8_mask16x16(self, a: mask16x16<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_mask16x16(self, a: mask16x16<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_mask16x16(self, a: mask16x16<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_mask16x16(self, a: mask16x16<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_mask16x16(self, a: mask16x16<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_mask16x16(self, a: mask16x16<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_mask16x16(self, a: mask16x16<Self>) -> u64x4<Self>;
     fn splat_i32x8(self, val: i32) -> i32x8<Self>;
     fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self>;
     fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
@@ -374,7 +1760,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
     fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
     fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn shl_i32x8(self, a: i32x8<Self>, shift: u32) -> i32x8<Self>;
     fn shr_i32x8(self, a: i32x8<Self>, shift: u32) -> i32x8<Self>;
+    fn shl_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn shr_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
     fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self>;
     fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self>;
     fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self>;
@@ -385,10 +1774,43 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self>;
     fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
     fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn reduce_add_i32x8(self, a: i32x8<Self>) -> i32;
+    fn reduce_min_i32x8(self, a: i32x8<Self>) -> i32;
+    fn reduce_max_i32x8(self, a: i32x8<Self>) -> i32;
+    fn reduce_and_i32x8(self, a: i32x8<Self>) -> i32;
+    fn reduce_or_i32x8(self, a: i32x8<Self>) -> i32;
+    fn shuffle_i32x8<I: Indices<8>>(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn shuffle2_i32x8<I: Indices<8>>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn reverse_i32x8(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn rotate_lanes_left_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn rotate_lanes_right_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn broadcast_lane_i32x8<const L: usize>(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn unzip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn unzip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self>;
+    fn abs_i32x8(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn neg_i32x8(self, a: i32x8<Self>) -> i32x8<Self>;
+    fn abs_diff_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> u32x8<Self>;
     fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self>;
     fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>);
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self>;
+    fn widen_low_i32x8(self, a: i32x8<Self>) -> i64x4<Self>;
+    fn widen_high_i32x8(self, a: i32x8<Self>) -> i64x4<Self>;
+    fn narrow_i32x8(self, a: i32x8<Self>) -> i16x8<Self>;
+    fn narrow_saturating_i32x8(self, a: i32x8<Self>) -> i16x8<Self>;
+    fn reinterpret_f16_i32x8(self, a: i32x8<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_i32x8(self, a: i32x8<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_i32x8(self, a: i32x8<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_i32x8(self, a: i32x8<Self>) -> i8x32<Self>;
     fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_i32x8(self, a: i32x8<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_i32x8(self, a: i32x8<Self>) -> u16x16<Self>;
+    fn reinterpret_u32_i32x8(self, a: i32x8<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_i32x8(self, a: i32x8<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_i32x8(self, a: i32x8<Self>) -> u64x4<Self>;
+    fn cvt_f16_i32x8(self, a: i32x8<Self>) -> f16x8<Self>;
     fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_i32x8(self, a: i32x8<Self>) -> f64x8<Self>;
     fn splat_u32x8(self, val: u32) -> u32x8<Self>;
     fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self>;
     fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
@@ -397,7 +1819,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
     fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
     fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn shl_u32x8(self, a: u32x8<Self>, shift: u32) -> u32x8<Self>;
     fn shr_u32x8(self, a: u32x8<Self>, shift: u32) -> u32x8<Self>;
+    fn shl_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn shr_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
     fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self>;
     fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self>;
     fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self>;
@@ -408,10 +1833,41 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self>;
     fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
     fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn reduce_add_u32x8(self, a: u32x8<Self>) -> u32;
+    fn reduce_min_u32x8(self, a: u32x8<Self>) -> u32;
+    fn reduce_max_u32x8(self, a: u32x8<Self>) -> u32;
+    fn reduce_and_u32x8(self, a: u32x8<Self>) -> u32;
+    fn reduce_or_u32x8(self, a: u32x8<Self>) -> u32;
+    fn shuffle_u32x8<I: Indices<8>>(self, a: u32x8<Self>) -> u32x8<Self>;
+    fn shuffle2_u32x8<I: Indices<8>>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn reverse_u32x8(self, a: u32x8<Self>) -> u32x8<Self>;
+    fn rotate_lanes_left_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self>;
+    fn rotate_lanes_right_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self>;
+    fn broadcast_lane_u32x8<const L: usize>(self, a: u32x8<Self>) -> u32x8<Self>;
+    fn unzip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn unzip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
+    fn abs_diff_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self>;
     fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self>;
     fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>);
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self>;
+    fn widen_low_u32x8(self, a: u32x8<Self>) -> u64x4<Self>;
+    fn widen_high_u32x8(self, a: u32x8<Self>) -> u64x4<Self>;
+    fn narrow_u32x8(self, a: u32x8<Self>) -> u16x8<Self>;
+    fn narrow_saturating_u32x8(self, a: u32x8<Self>) -> u16x8<Self>;
+    fn reinterpret_f16_u32x8(self, a: u32x8<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_u32x8(self, a: u32x8<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_u32x8(self, a: u32x8<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_u32x8(self, a: u32x8<Self>) -> i8x32<Self>;
     fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_u32x8(self, a: u32x8<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_u32x8(self, a: u32x8<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_u32x8(self, a: u32x8<Self>) -> i32x8<Self>;
+    fn reinterpret_i64_u32x8(self, a: u32x8<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_u32x8(self, a: u32x8<Self>) -> u64x4<Self>;
+    fn cvt_f16_u32x8(self, a: u32x8<Self>) -> f16x8<Self>;
     fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_u32x8(self, a: u32x8<Self>) -> f64x8<Self>;
     fn splat_mask32x8(self, val: i32) -> mask32x8<Self>;
     fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self>;
     fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self>;
@@ -424,22 +1880,288 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask32x8<Self>,
     ) -> mask32x8<Self>;
     fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self>;
+    fn any_mask32x8(self, a: mask32x8<Self>) -> bool;
+    fn all_mask32x8(self, a: mask32x8<Self>) -> bool;
+    fn none_mask32x8(self, a: mask32x8<Self>) -> bool;
+    fn count_true_mask32x8(self, a: mask32x8<Self>) -> usize;
+    fn first_true_mask32x8(self, a: mask32x8<Self>) -> Option<usize>;
+    fn to_bitmask_mask32x8(self, a: mask32x8<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self>;
+    fn shuffle_mask32x8<I: Indices<8>>(self, a: mask32x8<Self>) -> mask32x8<Self>;
+    fn shuffle2_mask32x8<I: Indices<8>>(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+    ) -> mask32x8<Self>;
+    fn reverse_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self>;
+    fn rotate_lanes_left_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self>;
+    fn rotate_lanes_right_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self>;
+    fn broadcast_lane_mask32x8<const L: usize>(self, a: mask32x8<Self>) -> mask32x8<Self>;
+    fn unzip_low_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self>;
+    fn unzip_high_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self>;
     fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self>;
     fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>);
+    fn reinterpret_i8_mask32x8(self, a: mask32x8<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_mask32x8(self, a: mask32x8<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_mask32x8(self, a: mask32x8<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_mask32x8(self, a: mask32x8<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_mask32x8(self, a: mask32x8<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_mask32x8(self, a: mask32x8<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_mask32x8(self, a: mask32x8<Self>) -> u64x4<Self>;
+    fn splat_f64x4(self, val: f64) -> f64x4<Self>;
+    fn abs_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn neg_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn sqrt_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn recip_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn rsqrt_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn recip_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self>;
+    fn rsqrt_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self>;
+    fn add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn mul_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn div_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn copysign_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn simd_eq_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn simd_ne_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn simd_lt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn simd_le_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn simd_ge_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn simd_gt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self>;
+    fn is_nan_f64x4(self, a: f64x4<Self>) -> mask64x4<Self>;
+    fn is_finite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self>;
+    fn is_infinite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self>;
+    fn is_subnormal_f64x4(self, a: f64x4<Self>) -> mask64x4<Self>;
+    fn is_sign_negative_f64x4(self, a: f64x4<Self>) -> mask64x4<Self>;
+    fn zip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn zip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn max_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn max_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn min_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn min_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn madd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn msub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn nmadd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn nmsub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn mul_add_fused_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn mul_add_fast_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn ceil_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn round_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn round_ties_even_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn fract_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn trunc_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn select_f64x4(self, a: mask64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self>;
+    fn reduce_add_f64x4(self, a: f64x4<Self>) -> f64;
+    fn reduce_min_f64x4(self, a: f64x4<Self>) -> f64;
+    fn reduce_max_f64x4(self, a: f64x4<Self>) -> f64;
+    fn shuffle_f64x4<I: Indices<4>>(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn shuffle2_f64x4<I: Indices<4>>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn reverse_f64x4(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn rotate_lanes_left_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn rotate_lanes_right_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn broadcast_lane_f64x4<const L: usize>(self, a: f64x4<Self>) -> f64x4<Self>;
+    fn unzip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn unzip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self>;
+    fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self>;
+    fn split_f64x4(self, a: f64x4<Self>) -> (f64x2<Self>, f64x2<Self>);
+    fn reinterpret_f16_f64x4(self, a: f64x4<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_f64x4(self, a: f64x4<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_f64x4(self, a: f64x4<Self>) -> f32x8<Self>;
+    fn reinterpret_i8_f64x4(self, a: f64x4<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_f64x4(self, a: f64x4<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_f64x4(self, a: f64x4<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_f64x4(self, a: f64x4<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_f64x4(self, a: f64x4<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_f64x4(self, a: f64x4<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self>;
+    fn cvt_f32_f64x4(self, a: f64x4<Self>) -> f32x4<Self>;
+    fn cvt_u16_f64x4(self, a: f64x4<Self>) -> u16x4<Self>;
+    fn cvt_u16_round_f64x4(self, a: f64x4<Self>) -> u16x4<Self>;
+    fn cvt_i32_f64x4(self, a: f64x4<Self>) -> i32x4<Self>;
+    fn cvt_i32_round_f64x4(self, a: f64x4<Self>) -> i32x4<Self>;
+    fn cvt_u32_f64x4(self, a: f64x4<Self>) -> u32x4<Self>;
+    fn cvt_u32_round_f64x4(self, a: f64x4<Self>) -> u32x4<Self>;
+    fn cvt_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self>;
+    fn cvt_i64_round_f64x4(self, a: f64x4<Self>) -> i64x4<Self>;
+    fn cvt_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self>;
+    fn cvt_u64_round_f64x4(self, a: f64x4<Self>) -> u64x4<Self>;
+    fn splat_i64x4(self, val: i64) -> i64x4<Self>;
+    fn not_i64x4(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn mul_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn and_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn or_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn xor_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn shl_i64x4(self, a: i64x4<Self>, shift: u32) -> i64x4<Self>;
+    fn shr_i64x4(self, a: i64x4<Self>, shift: u32) -> i64x4<Self>;
+    fn shl_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn shr_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self>;
+    fn simd_lt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self>;
+    fn simd_le_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self>;
+    fn simd_ge_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self>;
+    fn simd_gt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self>;
+    fn zip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn zip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn select_i64x4(self, a: mask64x4<Self>, b: i64x4<Self>, c: i64x4<Self>) -> i64x4<Self>;
+    fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn max_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn reduce_add_i64x4(self, a: i64x4<Self>) -> i64;
+    fn reduce_min_i64x4(self, a: i64x4<Self>) -> i64;
+    fn reduce_max_i64x4(self, a: i64x4<Self>) -> i64;
+    fn reduce_and_i64x4(self, a: i64x4<Self>) -> i64;
+    fn reduce_or_i64x4(self, a: i64x4<Self>) -> i64;
+    fn shuffle_i64x4<I: Indices<4>>(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn shuffle2_i64x4<I: Indices<4>>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn reverse_i64x4(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn rotate_lanes_left_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn rotate_lanes_right_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn broadcast_lane_i64x4<const L: usize>(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn unzip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn unzip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self>;
+    fn abs_i64x4(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn neg_i64x4(self, a: i64x4<Self>) -> i64x4<Self>;
+    fn abs_diff_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> u64x4<Self>;
+    fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self>;
+    fn split_i64x4(self, a: i64x4<Self>) -> (i64x2<Self>, i64x2<Self>);
+    fn narrow_i64x4(self, a: i64x4<Self>) -> i32x4<Self>;
+    fn narrow_saturating_i64x4(self, a: i64x4<Self>) -> i32x4<Self>;
+    fn reinterpret_f16_i64x4(self, a: i64x4<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_i64x4(self, a: i64x4<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_i64x4(self, a: i64x4<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_i64x4(self, a: i64x4<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_i64x4(self, a: i64x4<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_i64x4(self, a: i64x4<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_i64x4(self, a: i64x4<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_i64x4(self, a: i64x4<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_i64x4(self, a: i64x4<Self>) -> u32x8<Self>;
+    fn reinterpret_u64_i64x4(self, a: i64x4<Self>) -> u64x4<Self>;
+    fn cvt_f32_i64x4(self, a: i64x4<Self>) -> f32x4<Self>;
+    fn cvt_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self>;
+    fn splat_u64x4(self, val: u64) -> u64x4<Self>;
+    fn not_u64x4(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn mul_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn and_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn or_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn xor_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn shl_u64x4(self, a: u64x4<Self>, shift: u32) -> u64x4<Self>;
+    fn shr_u64x4(self, a: u64x4<Self>, shift: u32) -> u64x4<Self>;
+    fn shl_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn shr_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self>;
+    fn simd_lt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self>;
+    fn simd_le_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self>;
+    fn simd_ge_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self>;
+    fn simd_gt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self>;
+    fn zip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn zip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn select_u64x4(self, a: mask64x4<Self>, b: u64x4<Self>, c: u64x4<Self>) -> u64x4<Self>;
+    fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn max_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn reduce_add_u64x4(self, a: u64x4<Self>) -> u64;
+    fn reduce_min_u64x4(self, a: u64x4<Self>) -> u64;
+    fn reduce_max_u64x4(self, a: u64x4<Self>) -> u64;
+    fn reduce_and_u64x4(self, a: u64x4<Self>) -> u64;
+    fn reduce_or_u64x4(self, a: u64x4<Self>) -> u64;
+    fn shuffle_u64x4<I: Indices<4>>(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn shuffle2_u64x4<I: Indices<4>>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn reverse_u64x4(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn rotate_lanes_left_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn rotate_lanes_right_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn broadcast_lane_u64x4<const L: usize>(self, a: u64x4<Self>) -> u64x4<Self>;
+    fn unzip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn unzip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn abs_diff_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self>;
+    fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self>;
+    fn split_u64x4(self, a: u64x4<Self>) -> (u64x2<Self>, u64x2<Self>);
+    fn narrow_u64x4(self, a: u64x4<Self>) -> u32x4<Self>;
+    fn narrow_saturating_u64x4(self, a: u64x4<Self>) -> u32x4<Self>;
+    fn reinterpret_f16_u64x4(self, a: u64x4<Self>) -> f16x16<Self>;
+    fn reinterpret_bf16_u64x4(self, a: u64x4<Self>) -> bf16x16<Self>;
+    fn reinterpret_f32_u64x4(self, a: u64x4<Self>) -> f32x8<Self>;
+    fn reinterpret_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self>;
+    fn reinterpret_i8_u64x4(self, a: u64x4<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_u64x4(self, a: u64x4<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_u64x4(self, a: u64x4<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_u64x4(self, a: u64x4<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_u64x4(self, a: u64x4<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_u64x4(self, a: u64x4<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_u64x4(self, a: u64x4<Self>) -> i64x4<Self>;
+    fn cvt_f32_u64x4(self, a: u64x4<Self>) -> f32x4<Self>;
+    fn cvt_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self>;
+    fn splat_mask64x4(self, val: i64) -> mask64x4<Self>;
+    fn not_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn and_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn or_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn xor_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn select_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self>;
+    fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn any_mask64x4(self, a: mask64x4<Self>) -> bool;
+    fn all_mask64x4(self, a: mask64x4<Self>) -> bool;
+    fn none_mask64x4(self, a: mask64x4<Self>) -> bool;
+    fn count_true_mask64x4(self, a: mask64x4<Self>) -> usize;
+    fn first_true_mask64x4(self, a: mask64x4<Self>) -> Option<usize>;
+    fn to_bitmask_mask64x4(self, a: mask64x4<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self>;
+    fn shuffle_mask64x4<I: Indices<4>>(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn shuffle2_mask64x4<I: Indices<4>>(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+    ) -> mask64x4<Self>;
+    fn reverse_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn rotate_lanes_left_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn rotate_lanes_right_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn broadcast_lane_mask64x4<const L: usize>(self, a: mask64x4<Self>) -> mask64x4<Self>;
+    fn unzip_low_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn unzip_high_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self>;
+    fn combine_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x8<Self>;
+    fn split_mask64x4(self, a: mask64x4<Self>) -> (mask64x2<Self>, mask64x2<Self>);
+    fn reinterpret_i8_mask64x4(self, a: mask64x4<Self>) -> i8x32<Self>;
+    fn reinterpret_u8_mask64x4(self, a: mask64x4<Self>) -> u8x32<Self>;
+    fn reinterpret_i16_mask64x4(self, a: mask64x4<Self>) -> i16x16<Self>;
+    fn reinterpret_u16_mask64x4(self, a: mask64x4<Self>) -> u16x16<Self>;
+    fn reinterpret_i32_mask64x4(self, a: mask64x4<Self>) -> i32x8<Self>;
+    fn reinterpret_u32_mask64x4(self, a: mask64x4<Self>) -> u32x8<Self>;
+    fn reinterpret_i64_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self>;
+    fn reinterpret_u64_mask64x4(self, a: mask64x4<Self>) -> u64x4<Self>;
     fn splat_f32x16(self, val: f32) -> f32x16<Self>;
     fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
     fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
     fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn recip_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn rsqrt_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn recip_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self>;
+    fn rsqrt_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self>;
     fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
+    fn simd_ne_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
     fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
     fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
     fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
     fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self>;
+    fn is_nan_f32x16(self, a: f32x16<Self>) -> mask32x16<Self>;
+    fn is_finite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self>;
+    fn is_infinite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self>;
+    fn is_subnormal_f32x16(self, a: f32x16<Self>) -> mask32x16<Self>;
+    fn is_sign_negative_f32x16(self, a: f32x16<Self>) -> mask32x16<Self>;
     fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
@@ -448,15 +2170,60 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn madd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self>;
     fn msub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self>;
+    fn nmadd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self>;
+    fn nmsub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self>;
+    fn mul_add_fused_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self>;
+    fn mul_add_fast_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>)
+    -> f32x16<Self>;
     fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn ceil_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn round_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn round_ties_even_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
     fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
     fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
     fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self>;
+    fn reduce_add_f32x16(self, a: f32x16<Self>) -> f32;
+    fn reduce_min_f32x16(self, a: f32x16<Self>) -> f32;
+    fn reduce_max_f32x16(self, a: f32x16<Self>) -> f32;
+    fn shuffle_f32x16<I: Indices<16>>(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn shuffle2_f32x16<I: Indices<16>>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
+    fn reverse_f32x16(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn rotate_lanes_left_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn rotate_lanes_right_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn broadcast_lane_f32x16<const L: usize>(self, a: f32x16<Self>) -> f32x16<Self>;
+    fn unzip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
+    fn unzip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self>;
     fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>);
     fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self>;
     fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> ();
-    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self>;
+    fn reinterpret_f64_f32x16(self, a: f32x16<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_f32x16(self, a: f32x16<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_f32x16(self, a: f32x16<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_f32x16(self, a: f32x16<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_f32x16(self, a: f32x16<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_f32x16(self, a: f32x16<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_f32x16(self, a: f32x16<Self>) -> u64x8<Self>;
+    fn cvt_f16_f32x16(self, a: f32x16<Self>) -> f16x16<Self>;
+    fn cvt_i8_f32x16(self, a: f32x16<Self>) -> i8x16<Self>;
+    fn cvt_i8_round_f32x16(self, a: f32x16<Self>) -> i8x16<Self>;
+    fn cvt_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self>;
+    fn cvt_u8_round_f32x16(self, a: f32x16<Self>) -> u8x16<Self>;
+    fn cvt_i16_f32x16(self, a: f32x16<Self>) -> i16x16<Self>;
+    fn cvt_i16_round_f32x16(self, a: f32x16<Self>) -> i16x16<Self>;
+    fn cvt_u16_f32x16(self, a: f32x16<Self>) -> u16x16<Self>;
+    fn cvt_u16_round_f32x16(self, a: f32x16<Self>) -> u16x16<Self>;
     fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self>;
+    fn cvt_i32_round_f32x16(self, a: f32x16<Self>) -> i32x16<Self>;
+    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self>;
+    fn cvt_u32_round_f32x16(self, a: f32x16<Self>) -> u32x16<Self>;
+    fn cvt_bf16_f32x16(self, a: f32x16<Self>) -> bf16x16<Self>;
     fn splat_i8x64(self, val: i8) -> i8x64<Self>;
     fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self>;
     fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
@@ -465,7 +2232,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
     fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
     fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn shl_i8x64(self, a: i8x64<Self>, shift: u32) -> i8x64<Self>;
     fn shr_i8x64(self, a: i8x64<Self>, shift: u32) -> i8x64<Self>;
+    fn shl_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn shr_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
     fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self>;
     fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self>;
     fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self>;
@@ -476,8 +2246,37 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self>;
     fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
     fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn reduce_add_i8x64(self, a: i8x64<Self>) -> i8;
+    fn reduce_min_i8x64(self, a: i8x64<Self>) -> i8;
+    fn reduce_max_i8x64(self, a: i8x64<Self>) -> i8;
+    fn reduce_and_i8x64(self, a: i8x64<Self>) -> i8;
+    fn reduce_or_i8x64(self, a: i8x64<Self>) -> i8;
+    fn shuffle_i8x64<I: Indices<64>>(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn shuffle2_i8x64<I: Indices<64>>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn reverse_i8x64(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn rotate_lanes_left_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn rotate_lanes_right_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn broadcast_lane_i8x64<const L: usize>(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn unzip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn unzip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
+    fn abs_i8x64(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn neg_i8x64(self, a: i8x64<Self>) -> i8x64<Self>;
+    fn abs_diff_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> u8x64<Self>;
+    fn mul_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self>;
     fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>);
+    fn widen_low_i8x64(self, a: i8x64<Self>) -> i16x32<Self>;
+    fn widen_high_i8x64(self, a: i8x64<Self>) -> i16x32<Self>;
+    fn reinterpret_f32_i8x64(self, a: i8x64<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_i8x64(self, a: i8x64<Self>) -> f64x8<Self>;
     fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_i8x64(self, a: i8x64<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_i8x64(self, a: i8x64<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_i8x64(self, a: i8x64<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_i8x64(self, a: i8x64<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_i8x64(self, a: i8x64<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_i8x64(self, a: i8x64<Self>) -> u64x8<Self>;
     fn splat_u8x64(self, val: u8) -> u8x64<Self>;
     fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self>;
     fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
@@ -486,7 +2285,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
     fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
     fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn shl_u8x64(self, a: u8x64<Self>, shift: u32) -> u8x64<Self>;
     fn shr_u8x64(self, a: u8x64<Self>, shift: u32) -> u8x64<Self>;
+    fn shl_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn shr_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
     fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self>;
     fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self>;
     fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self>;
@@ -497,9 +2299,42 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self>;
     fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
     fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn reduce_add_u8x64(self, a: u8x64<Self>) -> u8;
+    fn reduce_min_u8x64(self, a: u8x64<Self>) -> u8;
+    fn reduce_max_u8x64(self, a: u8x64<Self>) -> u8;
+    fn reduce_and_u8x64(self, a: u8x64<Self>) -> u8;
+    fn reduce_or_u8x64(self, a: u8x64<Self>) -> u8;
+    fn shuffle_u8x64<I: Indices<64>>(self, a: u8x64<Self>) -> u8x64<Self>;
+    fn shuffle2_u8x64<I: Indices<64>>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn reverse_u8x64(self, a: u8x64<Self>) -> u8x64<Self>;
+    fn rotate_lanes_left_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self>;
+    fn rotate_lanes_right_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self>;
+    fn broadcast_lane_u8x64<const L: usize>(self, a: u8x64<Self>) -> u8x64<Self>;
+    fn unzip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn unzip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn abs_diff_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn mul_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn swizzle_dyn_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn lookup_32_u8x64(self, a: u8x32<Self>, b: u8x64<Self>) -> u8x64<Self>;
+    fn lookup_48_u8x64(self, a: [u8x16<Self>; 3], b: u8x64<Self>) -> u8x64<Self>;
+    fn lookup_64_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self>;
     fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>);
     fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self>;
     fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> ();
+    fn widen_low_u8x64(self, a: u8x64<Self>) -> u16x32<Self>;
+    fn widen_high_u8x64(self, a: u8x64<Self>) -> u16x32<Self>;
+    fn reinterpret_f32_u8x64(self, a: u8x64<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_u8x64(self, a: u8x64<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_u8x64(self, a: u8x64<Self>) -> i8x64<Self>;
+    fn reinterpret_i16_u8x64(self, a: u8x64<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_u8x64(self, a: u8x64<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_u8x64(self, a: u8x64<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_u8x64(self, a: u8x64<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_u8x64(self, a: u8x64<Self>) -> u64x8<Self>;
     fn splat_mask8x64(self, val: i8) -> mask8x64<Self>;
     fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self>;
     fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self>;
@@ -512,7 +2347,35 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask8x64<Self>,
     ) -> mask8x64<Self>;
     fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self>;
+    fn any_mask8x64(self, a: mask8x64<Self>) -> bool;
+    fn all_mask8x64(self, a: mask8x64<Self>) -> bool;
+    fn none_mask8x64(self, a: mask8x64<Self>) -> bool;
+    fn count_true_mask8x64(self, a: mask8x64<Self>) -> usize;
+    fn first_true_mask8x64(self, a: mask8x64<Self>) -> Option<usize>;
+    fn to_bitmask_mask8x64(self, a: mask8x64<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self>;
+    fn shuffle_mask8x64<I: Indices<64>>(self, a: mask8x64<Self>) -> mask8x64<Self>;
+    fn shuffle2_mask8x64<I: Indices<64>>(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+    ) -> mask8x64<Self>;
+    fn reverse_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self>;
+    fn rotate_lanes_left_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self>;
+    fn rotate_lanes_right_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self>;
+    fn broadcast_lane_mask8x64<const L: usize>(self, a: mask8x64<Self>) -> mask8x64<Self>;
+    fn unzip_low_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self>;
+    fn unzip_high_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self>;
     fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>);
+    fn reinterpret_i8_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_mask8x64(self, a: mask8x64<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_mask8x64(self, a: mask8x64<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_mask8x64(self, a: mask8x64<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_mask8x64(self, a: mask8x64<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_mask8x64(self, a: mask8x64<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_mask8x64(self, a: mask8x64<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_mask8x64(self, a: mask8x64<Self>) -> u64x8<Self>;
     fn splat_i16x32(self, val: i16) -> i16x32<Self>;
     fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self>;
     fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
@@ -521,7 +2384,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
     fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
     fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn shl_i16x32(self, a: i16x32<Self>, shift: u32) -> i16x32<Self>;
     fn shr_i16x32(self, a: i16x32<Self>, shift: u32) -> i16x32<Self>;
+    fn shl_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn shr_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
     fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self>;
     fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self>;
     fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self>;
@@ -532,8 +2398,41 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self>;
     fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
     fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn reduce_add_i16x32(self, a: i16x32<Self>) -> i16;
+    fn reduce_min_i16x32(self, a: i16x32<Self>) -> i16;
+    fn reduce_max_i16x32(self, a: i16x32<Self>) -> i16;
+    fn reduce_and_i16x32(self, a: i16x32<Self>) -> i16;
+    fn reduce_or_i16x32(self, a: i16x32<Self>) -> i16;
+    fn shuffle_i16x32<I: Indices<32>>(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn shuffle2_i16x32<I: Indices<32>>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn reverse_i16x32(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn rotate_lanes_left_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn rotate_lanes_right_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn broadcast_lane_i16x32<const L: usize>(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn unzip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn unzip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn abs_i16x32(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn neg_i16x32(self, a: i16x32<Self>) -> i16x32<Self>;
+    fn abs_diff_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> u16x32<Self>;
+    fn mul_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn mul_q15_round_sat_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self>;
+    fn dot_i16_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i32x16<Self>;
     fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>);
+    fn widen_low_i16x32(self, a: i16x32<Self>) -> i32x16<Self>;
+    fn widen_high_i16x32(self, a: i16x32<Self>) -> i32x16<Self>;
+    fn narrow_i16x32(self, a: i16x32<Self>) -> i8x32<Self>;
+    fn narrow_saturating_i16x32(self, a: i16x32<Self>) -> i8x32<Self>;
+    fn reinterpret_f32_i16x32(self, a: i16x32<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_i16x32(self, a: i16x32<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_i16x32(self, a: i16x32<Self>) -> i8x64<Self>;
     fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self>;
+    fn reinterpret_u16_i16x32(self, a: i16x32<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_i16x32(self, a: i16x32<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_i16x32(self, a: i16x32<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_i16x32(self, a: i16x32<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_i16x32(self, a: i16x32<Self>) -> u64x8<Self>;
     fn splat_u16x32(self, val: u16) -> u16x32<Self>;
     fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self>;
     fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
@@ -542,7 +2441,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
     fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
     fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn shl_u16x32(self, a: u16x32<Self>, shift: u32) -> u16x32<Self>;
     fn shr_u16x32(self, a: u16x32<Self>, shift: u32) -> u16x32<Self>;
+    fn shl_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn shr_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
     fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self>;
     fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self>;
     fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self>;
@@ -553,11 +2455,40 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self>;
     fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
     fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn reduce_add_u16x32(self, a: u16x32<Self>) -> u16;
+    fn reduce_min_u16x32(self, a: u16x32<Self>) -> u16;
+    fn reduce_max_u16x32(self, a: u16x32<Self>) -> u16;
+    fn reduce_and_u16x32(self, a: u16x32<Self>) -> u16;
+    fn reduce_or_u16x32(self, a: u16x32<Self>) -> u16;
+    fn shuffle_u16x32<I: Indices<32>>(self, a: u16x32<Self>) -> u16x32<Self>;
+    fn shuffle2_u16x32<I: Indices<32>>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn reverse_u16x32(self, a: u16x32<Self>) -> u16x32<Self>;
+    fn rotate_lanes_left_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self>;
+    fn rotate_lanes_right_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self>;
+    fn broadcast_lane_u16x32<const L: usize>(self, a: u16x32<Self>) -> u16x32<Self>;
+    fn unzip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn unzip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn abs_diff_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
+    fn mul_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self>;
     fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>);
     fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self>;
     fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> ();
+    fn widen_low_u16x32(self, a: u16x32<Self>) -> u32x16<Self>;
+    fn widen_high_u16x32(self, a: u16x32<Self>) -> u32x16<Self>;
     fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self>;
+    fn narrow_saturating_u16x32(self, a: u16x32<Self>) -> u8x32<Self>;
+    fn reinterpret_f32_u16x32(self, a: u16x32<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_u16x32(self, a: u16x32<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_u16x32(self, a: u16x32<Self>) -> i8x64<Self>;
     fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_u16x32(self, a: u16x32<Self>) -> i16x32<Self>;
+    fn reinterpret_i32_u16x32(self, a: u16x32<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_u16x32(self, a: u16x32<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_u16x32(self, a: u16x32<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_u16x32(self, a: u16x32<Self>) -> u64x8<Self>;
     fn splat_mask16x32(self, val: i16) -> mask16x32<Self>;
     fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self>;
     fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self>;
@@ -570,7 +2501,35 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask16x32<Self>,
     ) -> mask16x32<Self>;
     fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self>;
+    fn any_mask16x32(self, a: mask16x32<Self>) -> bool;
+    fn all_mask16x32(self, a: mask16x32<Self>) -> bool;
+    fn none_mask16x32(self, a: mask16x32<Self>) -> bool;
+    fn count_true_mask16x32(self, a: mask16x32<Self>) -> usize;
+    fn first_true_mask16x32(self, a: mask16x32<Self>) -> Option<usize>;
+    fn to_bitmask_mask16x32(self, a: mask16x32<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self>;
+    fn shuffle_mask16x32<I: Indices<32>>(self, a: mask16x32<Self>) -> mask16x32<Self>;
+    fn shuffle2_mask16x32<I: Indices<32>>(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+    ) -> mask16x32<Self>;
+    fn reverse_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self>;
+    fn rotate_lanes_left_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self>;
+    fn rotate_lanes_right_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self>;
+    fn broadcast_lane_mask16x32<const L: usize>(self, a: mask16x32<Self>) -> mask16x32<Self>;
+    fn unzip_low_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self>;
+    fn unzip_high_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self>;
     fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>);
+    fn reinterpret_i8_mask16x32(self, a: mask16x32<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_mask16x32(self, a: mask16x32<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_mask16x32(self, a: mask16x32<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_mask16x32(self, a: mask16x32<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_mask16x32(self, a: mask16x32<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_mask16x32(self, a: mask16x32<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_mask16x32(self, a: mask16x32<Self>) -> u64x8<Self>;
     fn splat_i32x16(self, val: i32) -> i32x16<Self>;
     fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self>;
     fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
@@ -579,7 +2538,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
     fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
     fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn shl_i32x16(self, a: i32x16<Self>, shift: u32) -> i32x16<Self>;
     fn shr_i32x16(self, a: i32x16<Self>, shift: u32) -> i32x16<Self>;
+    fn shl_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn shr_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
     fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self>;
     fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self>;
     fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self>;
@@ -590,8 +2552,37 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self>;
     fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
     fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn reduce_add_i32x16(self, a: i32x16<Self>) -> i32;
+    fn reduce_min_i32x16(self, a: i32x16<Self>) -> i32;
+    fn reduce_max_i32x16(self, a: i32x16<Self>) -> i32;
+    fn reduce_and_i32x16(self, a: i32x16<Self>) -> i32;
+    fn reduce_or_i32x16(self, a: i32x16<Self>) -> i32;
+    fn shuffle_i32x16<I: Indices<16>>(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn shuffle2_i32x16<I: Indices<16>>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn reverse_i32x16(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn rotate_lanes_left_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn rotate_lanes_right_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn broadcast_lane_i32x16<const L: usize>(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn unzip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn unzip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self>;
+    fn abs_i32x16(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn neg_i32x16(self, a: i32x16<Self>) -> i32x16<Self>;
+    fn abs_diff_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> u32x16<Self>;
     fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>);
+    fn widen_low_i32x16(self, a: i32x16<Self>) -> i64x8<Self>;
+    fn widen_high_i32x16(self, a: i32x16<Self>) -> i64x8<Self>;
+    fn narrow_i32x16(self, a: i32x16<Self>) -> i16x16<Self>;
+    fn narrow_saturating_i32x16(self, a: i32x16<Self>) -> i16x16<Self>;
+    fn reinterpret_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_i32x16(self, a: i32x16<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_i32x16(self, a: i32x16<Self>) -> i8x64<Self>;
     fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_i32x16(self, a: i32x16<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_i32x16(self, a: i32x16<Self>) -> u16x32<Self>;
+    fn reinterpret_u32_i32x16(self, a: i32x16<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_i32x16(self, a: i32x16<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_i32x16(self, a: i32x16<Self>) -> u64x8<Self>;
+    fn cvt_f16_i32x16(self, a: i32x16<Self>) -> f16x16<Self>;
     fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self>;
     fn splat_u32x16(self, val: u32) -> u32x16<Self>;
     fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self>;
@@ -601,7 +2592,10 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
     fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
     fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn shl_u32x16(self, a: u32x16<Self>, shift: u32) -> u32x16<Self>;
     fn shr_u32x16(self, a: u32x16<Self>, shift: u32) -> u32x16<Self>;
+    fn shl_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn shr_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
     fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self>;
     fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self>;
     fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self>;
@@ -612,10 +2606,37 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
     fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self>;
     fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
     fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn reduce_add_u32x16(self, a: u32x16<Self>) -> u32;
+    fn reduce_min_u32x16(self, a: u32x16<Self>) -> u32;
+    fn reduce_max_u32x16(self, a: u32x16<Self>) -> u32;
+    fn reduce_and_u32x16(self, a: u32x16<Self>) -> u32;
+    fn reduce_or_u32x16(self, a: u32x16<Self>) -> u32;
+    fn shuffle_u32x16<I: Indices<16>>(self, a: u32x16<Self>) -> u32x16<Self>;
+    fn shuffle2_u32x16<I: Indices<16>>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn reverse_u32x16(self, a: u32x16<Self>) -> u32x16<Self>;
+    fn rotate_lanes_left_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self>;
+    fn rotate_lanes_right_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self>;
+    fn broadcast_lane_u32x16<const L: usize>(self, a: u32x16<Self>) -> u32x16<Self>;
+    fn unzip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn unzip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
+    fn abs_diff_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self>;
     fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>);
     fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self>;
     fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> ();
+    fn widen_low_u32x16(self, a: u32x16<Self>) -> u64x8<Self>;
+    fn widen_high_u32x16(self, a: u32x16<Self>) -> u64x8<Self>;
+    fn narrow_u32x16(self, a: u32x16<Self>) -> u16x16<Self>;
+    fn narrow_saturating_u32x16(self, a: u32x16<Self>) -> u16x16<Self>;
+    fn reinterpret_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_u32x16(self, a: u32x16<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_u32x16(self, a: u32x16<Self>) -> i8x64<Self>;
     fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_u32x16(self, a: u32x16<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_u32x16(self, a: u32x16<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_u32x16(self, a: u32x16<Self>) -> i32x16<Self>;
+    fn reinterpret_i64_u32x16(self, a: u32x16<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_u32x16(self, a: u32x16<Self>) -> u64x8<Self>;
+    fn cvt_f16_u32x16(self, a: u32x16<Self>) -> f16x16<Self>;
     fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self>;
     fn splat_mask32x16(self, val: i32) -> mask32x16<Self>;
     fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self>;
@@ -629,7 +2650,260 @@ pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
         c: mask32x16<Self>,
     ) -> mask32x16<Self>;
     fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self>;
+    fn any_mask32x16(self, a: mask32x16<Self>) -> bool;
+    fn all_mask32x16(self, a: mask32x16<Self>) -> bool;
+    fn none_mask32x16(self, a: mask32x16<Self>) -> bool;
+    fn count_true_mask32x16(self, a: mask32x16<Self>) -> usize;
+    fn first_true_mask32x16(self, a: mask32x16<Self>) -> Option<usize>;
+    fn to_bitmask_mask32x16(self, a: mask32x16<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self>;
+    fn shuffle_mask32x16<I: Indices<16>>(self, a: mask32x16<Self>) -> mask32x16<Self>;
+    fn shuffle2_mask32x16<I: Indices<16>>(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+    ) -> mask32x16<Self>;
+    fn reverse_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self>;
+    fn rotate_lanes_left_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self>;
+    fn rotate_lanes_right_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self>;
+    fn broadcast_lane_mask32x16<const L: usize>(self, a: mask32x16<Self>) -> mask32x16<Self>;
+    fn unzip_low_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self>;
+    fn unzip_high_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self>;
     fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>);
+    fn reinterpret_i8_mask32x16(self, a: mask32x16<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_mask32x16(self, a: mask32x16<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_mask32x16(self, a: mask32x16<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_mask32x16(self, a: mask32x16<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_mask32x16(self, a: mask32x16<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_mask32x16(self, a: mask32x16<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_mask32x16(self, a: mask32x16<Self>) -> u64x8<Self>;
+    fn splat_f64x8(self, val: f64) -> f64x8<Self>;
+    fn abs_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn neg_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn sqrt_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn recip_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn rsqrt_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn recip_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self>;
+    fn rsqrt_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self>;
+    fn add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn mul_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn div_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn copysign_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn simd_eq_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn simd_ne_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn simd_lt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn simd_le_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn simd_ge_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn simd_gt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self>;
+    fn is_nan_f64x8(self, a: f64x8<Self>) -> mask64x8<Self>;
+    fn is_finite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self>;
+    fn is_infinite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self>;
+    fn is_subnormal_f64x8(self, a: f64x8<Self>) -> mask64x8<Self>;
+    fn is_sign_negative_f64x8(self, a: f64x8<Self>) -> mask64x8<Self>;
+    fn zip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn zip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn max_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn max_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn min_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn min_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn madd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn msub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn nmadd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn nmsub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn mul_add_fused_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn mul_add_fast_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn ceil_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn round_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn round_ties_even_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn fract_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn trunc_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn select_f64x8(self, a: mask64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self>;
+    fn reduce_add_f64x8(self, a: f64x8<Self>) -> f64;
+    fn reduce_min_f64x8(self, a: f64x8<Self>) -> f64;
+    fn reduce_max_f64x8(self, a: f64x8<Self>) -> f64;
+    fn shuffle_f64x8<I: Indices<8>>(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn shuffle2_f64x8<I: Indices<8>>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn reverse_f64x8(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn rotate_lanes_left_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn rotate_lanes_right_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn broadcast_lane_f64x8<const L: usize>(self, a: f64x8<Self>) -> f64x8<Self>;
+    fn unzip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn unzip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self>;
+    fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>);
+    fn reinterpret_f32_f64x8(self, a: f64x8<Self>) -> f32x16<Self>;
+    fn reinterpret_i8_f64x8(self, a: f64x8<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_f64x8(self, a: f64x8<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_f64x8(self, a: f64x8<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_f64x8(self, a: f64x8<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_f64x8(self, a: f64x8<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_f64x8(self, a: f64x8<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self>;
+    fn cvt_f16_f64x8(self, a: f64x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_f64x8(self, a: f64x8<Self>) -> f32x8<Self>;
+    fn cvt_u8_f64x8(self, a: f64x8<Self>) -> u8x8<Self>;
+    fn cvt_u8_round_f64x8(self, a: f64x8<Self>) -> u8x8<Self>;
+    fn cvt_i16_f64x8(self, a: f64x8<Self>) -> i16x8<Self>;
+    fn cvt_i16_round_f64x8(self, a: f64x8<Self>) -> i16x8<Self>;
+    fn cvt_u16_f64x8(self, a: f64x8<Self>) -> u16x8<Self>;
+    fn cvt_u16_round_f64x8(self, a: f64x8<Self>) -> u16x8<Self>;
+    fn cvt_i32_f64x8(self, a: f64x8<Self>) -> i32x8<Self>;
+    fn cvt_i32_round_f64x8(self, a: f64x8<Self>) -> i32x8<Self>;
+    fn cvt_u32_f64x8(self, a: f64x8<Self>) -> u32x8<Self>;
+    fn cvt_u32_round_f64x8(self, a: f64x8<Self>) -> u32x8<Self>;
+    fn cvt_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self>;
+    fn cvt_i64_round_f64x8(self, a: f64x8<Self>) -> i64x8<Self>;
+    fn cvt_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self>;
+    fn cvt_u64_round_f64x8(self, a: f64x8<Self>) -> u64x8<Self>;
+    fn splat_i64x8(self, val: i64) -> i64x8<Self>;
+    fn not_i64x8(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn mul_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn and_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn or_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn xor_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn shl_i64x8(self, a: i64x8<Self>, shift: u32) -> i64x8<Self>;
+    fn shr_i64x8(self, a: i64x8<Self>, shift: u32) -> i64x8<Self>;
+    fn shl_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn shr_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self>;
+    fn simd_lt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self>;
+    fn simd_le_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self>;
+    fn simd_ge_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self>;
+    fn simd_gt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self>;
+    fn zip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn zip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn select_i64x8(self, a: mask64x8<Self>, b: i64x8<Self>, c: i64x8<Self>) -> i64x8<Self>;
+    fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn max_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn reduce_add_i64x8(self, a: i64x8<Self>) -> i64;
+    fn reduce_min_i64x8(self, a: i64x8<Self>) -> i64;
+    fn reduce_max_i64x8(self, a: i64x8<Self>) -> i64;
+    fn reduce_and_i64x8(self, a: i64x8<Self>) -> i64;
+    fn reduce_or_i64x8(self, a: i64x8<Self>) -> i64;
+    fn shuffle_i64x8<I: Indices<8>>(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn shuffle2_i64x8<I: Indices<8>>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn reverse_i64x8(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn rotate_lanes_left_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn rotate_lanes_right_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn broadcast_lane_i64x8<const L: usize>(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn unzip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn unzip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self>;
+    fn abs_i64x8(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn neg_i64x8(self, a: i64x8<Self>) -> i64x8<Self>;
+    fn abs_diff_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> u64x8<Self>;
+    fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>);
+    fn narrow_i64x8(self, a: i64x8<Self>) -> i32x8<Self>;
+    fn narrow_saturating_i64x8(self, a: i64x8<Self>) -> i32x8<Self>;
+    fn reinterpret_f32_i64x8(self, a: i64x8<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_i64x8(self, a: i64x8<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_i64x8(self, a: i64x8<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_i64x8(self, a: i64x8<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_i64x8(self, a: i64x8<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_i64x8(self, a: i64x8<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_i64x8(self, a: i64x8<Self>) -> u32x16<Self>;
+    fn reinterpret_u64_i64x8(self, a: i64x8<Self>) -> u64x8<Self>;
+    fn cvt_f16_i64x8(self, a: i64x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_i64x8(self, a: i64x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self>;
+    fn splat_u64x8(self, val: u64) -> u64x8<Self>;
+    fn not_u64x8(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn mul_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn and_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn or_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn xor_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn shl_u64x8(self, a: u64x8<Self>, shift: u32) -> u64x8<Self>;
+    fn shr_u64x8(self, a: u64x8<Self>, shift: u32) -> u64x8<Self>;
+    fn shl_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn shr_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self>;
+    fn simd_lt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self>;
+    fn simd_le_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self>;
+    fn simd_ge_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self>;
+    fn simd_gt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self>;
+    fn zip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn zip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn select_u64x8(self, a: mask64x8<Self>, b: u64x8<Self>, c: u64x8<Self>) -> u64x8<Self>;
+    fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn max_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn reduce_add_u64x8(self, a: u64x8<Self>) -> u64;
+    fn reduce_min_u64x8(self, a: u64x8<Self>) -> u64;
+    fn reduce_max_u64x8(self, a: u64x8<Self>) -> u64;
+    fn reduce_and_u64x8(self, a: u64x8<Self>) -> u64;
+    fn reduce_or_u64x8(self, a: u64x8<Self>) -> u64;
+    fn shuffle_u64x8<I: Indices<8>>(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn shuffle2_u64x8<I: Indices<8>>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn reverse_u64x8(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn rotate_lanes_left_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn rotate_lanes_right_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn broadcast_lane_u64x8<const L: usize>(self, a: u64x8<Self>) -> u64x8<Self>;
+    fn unzip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn unzip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn abs_diff_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self>;
+    fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>);
+    fn narrow_u64x8(self, a: u64x8<Self>) -> u32x8<Self>;
+    fn narrow_saturating_u64x8(self, a: u64x8<Self>) -> u32x8<Self>;
+    fn reinterpret_f32_u64x8(self, a: u64x8<Self>) -> f32x16<Self>;
+    fn reinterpret_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self>;
+    fn reinterpret_i8_u64x8(self, a: u64x8<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_u64x8(self, a: u64x8<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_u64x8(self, a: u64x8<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_u64x8(self, a: u64x8<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_u64x8(self, a: u64x8<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_u64x8(self, a: u64x8<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_u64x8(self, a: u64x8<Self>) -> i64x8<Self>;
+    fn cvt_f16_u64x8(self, a: u64x8<Self>) -> f16x8<Self>;
+    fn cvt_f32_u64x8(self, a: u64x8<Self>) -> f32x8<Self>;
+    fn cvt_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self>;
+    fn splat_mask64x8(self, val: i64) -> mask64x8<Self>;
+    fn not_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn and_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn or_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn xor_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn select_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self>;
+    fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn any_mask64x8(self, a: mask64x8<Self>) -> bool;
+    fn all_mask64x8(self, a: mask64x8<Self>) -> bool;
+    fn none_mask64x8(self, a: mask64x8<Self>) -> bool;
+    fn count_true_mask64x8(self, a: mask64x8<Self>) -> usize;
+    fn first_true_mask64x8(self, a: mask64x8<Self>) -> Option<usize>;
+    fn to_bitmask_mask64x8(self, a: mask64x8<Self>) -> u64;
+    #[allow(clippy::wrong_self_convention)]
+    fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self>;
+    fn shuffle_mask64x8<I: Indices<8>>(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn shuffle2_mask64x8<I: Indices<8>>(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+    ) -> mask64x8<Self>;
+    fn reverse_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn rotate_lanes_left_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn rotate_lanes_right_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn broadcast_lane_mask64x8<const L: usize>(self, a: mask64x8<Self>) -> mask64x8<Self>;
+    fn unzip_low_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn unzip_high_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self>;
+    fn split_mask64x8(self, a: mask64x8<Self>) -> (mask64x4<Self>, mask64x4<Self>);
+    fn reinterpret_i8_mask64x8(self, a: mask64x8<Self>) -> i8x64<Self>;
+    fn reinterpret_u8_mask64x8(self, a: mask64x8<Self>) -> u8x64<Self>;
+    fn reinterpret_i16_mask64x8(self, a: mask64x8<Self>) -> i16x32<Self>;
+    fn reinterpret_u16_mask64x8(self, a: mask64x8<Self>) -> u16x32<Self>;
+    fn reinterpret_i32_mask64x8(self, a: mask64x8<Self>) -> i32x16<Self>;
+    fn reinterpret_u32_mask64x8(self, a: mask64x8<Self>) -> u32x16<Self>;
+    fn reinterpret_i64_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self>;
+    fn reinterpret_u64_mask64x8(self, a: mask64x8<Self>) -> u64x8<Self>;
 }
 pub trait SimdBase<Element: SimdElement, S: Simd>:
     Copy + Sync + Send + 'static + crate::Bytes
@@ -654,7 +2928,43 @@ pub trait SimdBase<Element: SimdElement, S: Simd>:
     fn from_slice(simd: S, slice: &[Element]) -> Self;
     fn splat(simd: S, val: Element) -> Self;
     fn block_splat(block: Self::Block) -> Self;
+    #[doc = r" Reverse the order of the lanes."]
+    fn reverse(self) -> Self;
+    #[doc = r" The even lanes of `self` followed by the even lanes of `rhs`."]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" The odd lanes of `self` followed by the odd lanes of `rhs`."]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Pick a lane of `self` for each lane, by the indices of `I`."]
+    #[doc = r""]
+    #[doc = r" `M` is the number of lanes, which can't be written as `Self::N`"]
+    #[doc = r" here. A different count is a compile error. The [`shuffle!`]"]
+    #[doc = r" macro fills in both parameters."]
+    #[doc = r""]
+    #[doc = r" [`shuffle!`]: crate::shuffle!"]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self;
+    #[doc = r" Pick a lane of `self` or `rhs` for each lane, by the indices of `I`."]
+    #[doc = r""]
+    #[doc = r" The indices `0..M` pick lanes of `self` and `M..2 * M` pick lanes"]
+    #[doc = r" of `rhs`. See [`shuffle`](SimdBase::shuffle) for `M`."]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Move lane `i + K` to lane `i`, wrapping around."]
+    fn rotate_lanes_left<const K: usize>(self) -> Self;
+    #[doc = r" Move lane `i` to lane `i + K`, wrapping around."]
+    fn rotate_lanes_right<const K: usize>(self) -> Self;
+    #[doc = r" Copy lane `L` to every lane."]
+    #[doc = r""]
+    #[doc = r" `L` must be less than the number of lanes."]
+    fn broadcast_lane<const L: usize>(self) -> Self;
 }
+#[doc = r" A vector of floats."]
+#[doc = r""]
+#[doc = r" The vector types also have `cvt_*` methods, which convert every lane"]
+#[doc = r" to another element type with the same number of lanes. Conversions"]
+#[doc = r" to integers saturate to the minimum or maximum of the integer type,"]
+#[doc = r" and give 0 for NaN, like `as`. `cvt_i32` and the like round toward"]
+#[doc = r" zero, and `cvt_i32_round` and the like round to nearest, ties to"]
+#[doc = r" even. Conversions between float widths round to nearest, ties to"]
+#[doc = r" even, and overflow to infinity."]
 pub trait SimdFloat<Element: SimdElement, S: Simd>:
     SimdBase<Element, S>
     + core::ops::Neg<Output = Self>
@@ -669,24 +2979,133 @@ pub trait SimdFloat<Element: SimdElement, S: Simd>:
 {
     fn abs(self) -> Self;
     fn sqrt(self) -> Self;
+    #[doc = r" Compute an approximation of `1 / self`, with a relative error"]
+    #[doc = r" below 2^-8."]
+    #[doc = r""]
+    #[doc = r" This uses the estimate instruction where the level has one, so it"]
+    #[doc = r" is much faster than a division, but the result differs between"]
+    #[doc = r" levels. Levels without one divide exactly."]
+    #[doc = r""]
+    #[doc = r" The bound holds for normal inputs with a normal reciprocal. Zeros"]
+    #[doc = r" and infinities give infinities and zeros of the same sign."]
+    fn recip_approx(self) -> Self;
+    #[doc = r" Compute an approximation of `1 / self.sqrt()`, with a relative"]
+    #[doc = r" error below 2^-8."]
+    #[doc = r""]
+    #[doc = r" Like [`recip_approx`](Self::recip_approx), this uses the estimate"]
+    #[doc = r" instruction where the level has one. The bound holds for positive"]
+    #[doc = r" normal inputs, and zero and infinity give infinity and zero."]
+    fn rsqrt_approx(self) -> Self;
+    #[doc = r" Compute `1 / self` by refining [`recip_approx`](Self::recip_approx)"]
+    #[doc = r" with `steps` Newton-Raphson steps."]
+    #[doc = r""]
+    #[doc = r" Each step roughly squares the relative error, until it reaches"]
+    #[doc = r" the rounding error of the lanes. It is below 2^-15 after one step"]
+    #[doc = r" (2^-11 for `f16`), below 2^-22 for `f32` and 2^-30 for `f64` after"]
+    #[doc = r" two, and below 2^-50 for `f64` after three. Levels that divide"]
+    #[doc = r" exactly ignore `steps`."]
+    #[doc = r""]
+    #[doc = r" Zero, infinite and subnormal lanes, and lanes with a subnormal"]
+    #[doc = r" reciprocal, are divided exactly."]
+    fn recip(self, steps: u32) -> Self;
+    #[doc = r" Compute `1 / self.sqrt()` by refining"]
+    #[doc = r" [`rsqrt_approx`](Self::rsqrt_approx) with `steps` Newton-Raphson"]
+    #[doc = r" steps."]
+    #[doc = r""]
+    #[doc = r" The error after each step has the same bounds as for"]
+    #[doc = r" [`recip`](Self::recip)."]
+    fn rsqrt(self, steps: u32) -> Self;
     fn copysign(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
+    #[doc = r" Compare lanes for inequality."]
+    #[doc = r""]
+    #[doc = r" As IEEE 754 requires, this is true when either lane is NaN, so it"]
+    #[doc = r" is the negation of [`simd_eq`](Self::simd_eq)."]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_le(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
+    #[doc = r" Test which lanes are NaN."]
+    fn is_nan(self) -> Self::Mask;
+    #[doc = r" Test which lanes are neither infinite nor NaN."]
+    fn is_finite(self) -> Self::Mask;
+    #[doc = r" Test which lanes are positive or negative infinity."]
+    fn is_infinite(self) -> Self::Mask;
+    #[doc = r" Test which lanes are subnormal: nonzero, but smaller in magnitude"]
+    #[doc = r" than the smallest normal number."]
+    fn is_subnormal(self) -> Self::Mask;
+    #[doc = r" Test which lanes have the sign bit set."]
+    #[doc = r""]
+    #[doc = r" This includes `-0.0` and NaNs with the sign bit set."]
+    fn is_sign_negative(self) -> Self::Mask;
     fn zip_low(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn zip_high(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn max_precise(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn min(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn min_precise(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `self + op1 * op2`."]
+    #[doc = r""]
+    #[doc = r" This is fused where the level has a fused multiply-add, so the"]
+    #[doc = r" result can differ between levels in the last bit."]
     fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `self - op1 * op2`, fused like [`madd`](Self::madd)."]
     fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `-(self + op1 * op2)`, fused like [`madd`](Self::madd)."]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `-(self - op1 * op2)`, fused like [`madd`](Self::madd)."]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `self * op1 + op2` with a single rounding."]
+    #[doc = r""]
+    #[doc = r" The result is the same on every level. Levels without a fused"]
+    #[doc = r" multiply-add compute it in software, which is much slower."]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Compute `self * op1 + op2`, with whichever of a fused or a separate"]
+    #[doc = r" multiply and add is faster on the level."]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> Self;
     fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn round_ties_even(self) -> Self;
     fn fract(self) -> Self;
     fn trunc(self) -> Self;
+    #[doc = r" Add up the lanes."]
+    #[doc = r""]
+    #[doc = r" Integer sums wrap around. Float sums add neighbouring lanes"]
+    #[doc = r" first, then neighbouring pairs, and so on, as in"]
+    #[doc = r" `(a[0] + a[1]) + (a[2] + a[3])`. This order is the same on every"]
+    #[doc = r" level, so the result is too. `f16` lanes are added up in `f32`,"]
+    #[doc = r" and the sum is rounded once."]
+    fn reduce_add(self) -> Element;
+    #[doc = r" The smallest lane."]
+    #[doc = r""]
+    #[doc = r" Float NaN lanes are ignored, like in"]
+    #[doc = r" [`min_precise`](SimdFloat::min_precise), so the result is only NaN"]
+    #[doc = r" if every lane is NaN. If the smallest lanes are zeros of both signs,"]
+    #[doc = r" either zero can be returned."]
+    fn reduce_min(self) -> Element;
+    #[doc = r" The largest lane."]
+    #[doc = r""]
+    #[doc = r" Float NaN lanes are ignored, like in"]
+    #[doc = r" [`max_precise`](SimdFloat::max_precise), so the result is only NaN"]
+    #[doc = r" if every lane is NaN. If the largest lanes are zeros of both signs,"]
+    #[doc = r" either zero can be returned."]
+    fn reduce_max(self) -> Element;
 }
+#[doc = r" A vector of integers."]
+#[doc = r""]
+#[doc = r" The `<<` and `>>` operators shift every lane, by a `u32` or by the"]
+#[doc = r" matching lane of another vector. Right shifts are arithmetic for"]
+#[doc = r" signed lanes. Shift counts are not masked: counts of at least the"]
+#[doc = r" lane width give 0, or copies of the sign bit for arithmetic right"]
+#[doc = r" shifts, on every level."]
+#[doc = r""]
+#[doc = r" The vector types also have `cvt_f16`, `cvt_f32` and `cvt_f64`"]
+#[doc = r" methods where the float vector with the same number of lanes exists."]
+#[doc = r" They round to nearest, ties to even, like `as`, and only `f16` can"]
+#[doc = r" overflow to infinity. `u8` vectors also have `cvt_f32_unorm`, which"]
+#[doc = r" maps `0..=255` to `0.0..=1.0`."]
 pub trait SimdInt<Element: SimdElement, S: Simd>:
     SimdBase<Element, S>
     + core::ops::Add<Output = Self>
@@ -701,7 +3120,22 @@ pub trait SimdInt<Element: SimdElement, S: Simd>:
     + core::ops::BitOr<Element, Output = Self>
     + core::ops::BitXor<Output = Self>
     + core::ops::BitXor<Element, Output = Self>
+    + core::ops::Shl<u32, Output = Self>
+    + core::ops::Shr<u32, Output = Self>
+    + core::ops::Shl<Output = Self>
+    + core::ops::Shr<Output = Self>
 {
+    #[doc = r" Shift each lane left by the matching lane of `rhs`."]
+    #[doc = r""]
+    #[doc = r" The counts are taken as unsigned, and counts of at least the lane"]
+    #[doc = r" width give 0."]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Shift each lane right by the matching lane of `rhs`, arithmetically"]
+    #[doc = r" for signed lanes."]
+    #[doc = r""]
+    #[doc = r" The counts are taken as unsigned, and counts of at least the lane"]
+    #[doc = r" width give 0, or copies of the sign bit for signed lanes."]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
     fn simd_le(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
@@ -711,6 +3145,32 @@ pub trait SimdInt<Element: SimdElement, S: Simd>:
     fn zip_high(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn min(self, rhs: impl SimdInto<Self, S>) -> Self;
     fn max(self, rhs: impl SimdInto<Self, S>) -> Self;
+    #[doc = r" Add up the lanes."]
+    #[doc = r""]
+    #[doc = r" Integer sums wrap around. Float sums add neighbouring lanes"]
+    #[doc = r" first, then neighbouring pairs, and so on, as in"]
+    #[doc = r" `(a[0] + a[1]) + (a[2] + a[3])`. This order is the same on every"]
+    #[doc = r" level, so the result is too. `f16` lanes are added up in `f32`,"]
+    #[doc = r" and the sum is rounded once."]
+    fn reduce_add(self) -> Element;
+    #[doc = r" The smallest lane."]
+    #[doc = r""]
+    #[doc = r" Float NaN lanes are ignored, like in"]
+    #[doc = r" [`min_precise`](SimdFloat::min_precise), so the result is only NaN"]
+    #[doc = r" if every lane is NaN. If the smallest lanes are zeros of both signs,"]
+    #[doc = r" either zero can be returned."]
+    fn reduce_min(self) -> Element;
+    #[doc = r" The largest lane."]
+    #[doc = r""]
+    #[doc = r" Float NaN lanes are ignored, like in"]
+    #[doc = r" [`max_precise`](SimdFloat::max_precise), so the result is only NaN"]
+    #[doc = r" if every lane is NaN. If the largest lanes are zeros of both signs,"]
+    #[doc = r" either zero can be returned."]
+    fn reduce_max(self) -> Element;
+    #[doc = r" The bitwise and of all lanes."]
+    fn reduce_and(self) -> Element;
+    #[doc = r" The bitwise or of all lanes."]
+    fn reduce_or(self) -> Element;
 }
 pub trait SimdMask<Element: SimdElement, S: Simd>:
     SimdBase<Element, S>
@@ -720,4 +3180,11 @@ pub trait SimdMask<Element: SimdElement, S: Simd>:
     + core::ops::BitXor<Output = Self>
 {
     fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> Self::Mask;
+    fn any(self) -> bool;
+    fn all(self) -> bool;
+    fn none(self) -> bool;
+    fn count_true(self) -> usize;
+    fn first_true(self) -> Option<usize>;
+    fn to_bitmask(self) -> u64;
+    fn from_bitmask(simd: S, bits: u64) -> Self;
 }
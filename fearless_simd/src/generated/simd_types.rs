@@ -1,58 +1,59 @@
 // This file is autogenerated by fearless_simd_gen
 
-use crate::{Bytes, Select, Simd, SimdFrom, SimdInto};
+use crate::shuffle::SameLanes;
+use crate::{Bytes, Indices, Select, Simd, SimdFrom, SimdInto, bf16, f16};
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct f32x4<S: Simd> {
-    pub val: [f32; 4],
+#[repr(C, align(8))]
+pub struct f32x2<S: Simd> {
+    pub val: [f32; 2],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[f32; 4], S> for f32x4<S> {
+impl<S: Simd> SimdFrom<[f32; 2], S> for f32x2<S> {
     #[inline(always)]
-    fn simd_from(val: [f32; 4], simd: S) -> Self {
+    fn simd_from(val: [f32; 2], simd: S) -> Self {
         Self {
-            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            val: [val[0usize], val[1usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<f32x4<S>> for [f32; 4] {
+impl<S: Simd> From<f32x2<S>> for [f32; 2] {
     #[inline(always)]
-    fn from(value: f32x4<S>) -> Self {
+    fn from(value: f32x2<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for f32x4<S> {
-    type Target = [f32; 4];
+impl<S: Simd> core::ops::Deref for f32x2<S> {
+    type Target = [f32; 2];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for f32x4<S> {
+impl<S: Simd> core::ops::DerefMut for f32x2<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<f32, S> for f32x4<S> {
+impl<S: Simd> SimdFrom<f32, S> for f32x2<S> {
     #[inline(always)]
     fn simd_from(value: f32, simd: S) -> Self {
-        simd.splat_f32x4(value)
+        simd.splat_f32x2(value)
     }
 }
-impl<S: Simd> Select<f32x4<S>> for mask32x4<S> {
+impl<S: Simd> Select<f32x2<S>> for mask32x2<S> {
     #[inline(always)]
-    fn select(self, if_true: f32x4<S>, if_false: f32x4<S>) -> f32x4<S> {
-        self.simd.select_f32x4(self, if_true, if_false)
+    fn select(self, if_true: f32x2<S>, if_false: f32x2<S>) -> f32x2<S> {
+        self.simd.select_f32x2(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for f32x4<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for f32x2<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -68,435 +69,471 @@ impl<S: Simd> Bytes for f32x4<S> {
         }
     }
 }
-impl<S: Simd> f32x4<S> {
+impl<S: Simd> f32x2<S> {
     #[inline(always)]
-    pub fn abs(self) -> f32x4<S> {
-        self.simd.abs_f32x4(self)
+    pub fn abs(self) -> f32x2<S> {
+        self.simd.abs_f32x2(self)
     }
     #[inline(always)]
-    pub fn neg(self) -> f32x4<S> {
-        self.simd.neg_f32x4(self)
+    pub fn neg(self) -> f32x2<S> {
+        self.simd.neg_f32x2(self)
     }
     #[inline(always)]
-    pub fn sqrt(self) -> f32x4<S> {
-        self.simd.sqrt_f32x4(self)
+    pub fn sqrt(self) -> f32x2<S> {
+        self.simd.sqrt_f32x2(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.add_f32x4(self, rhs.simd_into(self.simd))
+    pub fn recip_approx(self) -> f32x2<S> {
+        self.simd.recip_approx_f32x2(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.sub_f32x4(self, rhs.simd_into(self.simd))
+    pub fn rsqrt_approx(self) -> f32x2<S> {
+        self.simd.rsqrt_approx_f32x2(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.mul_f32x4(self, rhs.simd_into(self.simd))
+    pub fn recip(self, steps: u32) -> f32x2<S> {
+        self.simd.recip_f32x2(self, steps)
     }
     #[inline(always)]
-    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.div_f32x4(self, rhs.simd_into(self.simd))
+    pub fn rsqrt(self, steps: u32) -> f32x2<S> {
+        self.simd.rsqrt_f32x2(self, steps)
     }
     #[inline(always)]
-    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.copysign_f32x4(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.add_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_f32x4(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.sub_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_f32x4(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.mul_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_f32x4(self, rhs.simd_into(self.simd))
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.div_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_f32x4(self, rhs.simd_into(self.simd))
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.copysign_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_f32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.max_f32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ne_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.max_precise_f32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_lt_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.min_f32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_le_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.min_precise_f32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ge_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn floor(self) -> f32x4<S> {
-        self.simd.floor_f32x4(self)
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_gt_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn fract(self) -> f32x4<S> {
-        self.simd.fract_f32x4(self)
+    pub fn is_nan(self) -> mask32x2<S> {
+        self.simd.is_nan_f32x2(self)
     }
     #[inline(always)]
-    pub fn trunc(self) -> f32x4<S> {
-        self.simd.trunc_f32x4(self)
+    pub fn is_finite(self) -> mask32x2<S> {
+        self.simd.is_finite_f32x2(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.combine_f32x4(self, rhs.simd_into(self.simd))
+    pub fn is_infinite(self) -> mask32x2<S> {
+        self.simd.is_infinite_f32x2(self)
     }
     #[inline(always)]
-    pub fn cvt_u32(self) -> u32x4<S> {
-        self.simd.cvt_u32_f32x4(self)
+    pub fn is_subnormal(self) -> mask32x2<S> {
+        self.simd.is_subnormal_f32x2(self)
     }
     #[inline(always)]
-    pub fn cvt_i32(self) -> i32x4<S> {
-        self.simd.cvt_i32_f32x4(self)
+    pub fn is_sign_negative(self) -> mask32x2<S> {
+        self.simd.is_sign_negative_f32x2(self)
     }
-}
-impl<S: Simd> crate::SimdBase<f32, S> for f32x4<S> {
-    const N: usize = 4;
-    type Mask = mask32x4<S>;
-    type Block = f32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[f32] {
-        &self.val
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.max_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [f32] {
-        &mut self.val
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.max_precise_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[f32]) -> Self {
-        let mut val = [0.0; 4];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.min_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: f32) -> Self {
-        simd.splat_f32x4(val)
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.min_precise_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block
+    pub fn floor(self) -> f32x2<S> {
+        self.simd.floor_f32x2(self)
     }
-}
-impl<S: Simd> crate::SimdFloat<f32, S> for f32x4<S> {
     #[inline(always)]
-    fn abs(self) -> f32x4<S> {
-        self.simd.abs_f32x4(self)
+    pub fn ceil(self) -> f32x2<S> {
+        self.simd.ceil_f32x2(self)
     }
     #[inline(always)]
-    fn sqrt(self) -> f32x4<S> {
-        self.simd.sqrt_f32x4(self)
+    pub fn round(self) -> f32x2<S> {
+        self.simd.round_f32x2(self)
     }
     #[inline(always)]
-    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.copysign_f32x4(self, rhs.simd_into(self.simd))
+    pub fn round_ties_even(self) -> f32x2<S> {
+        self.simd.round_ties_even_f32x2(self)
     }
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_f32x4(self, rhs.simd_into(self.simd))
+    pub fn fract(self) -> f32x2<S> {
+        self.simd.fract_f32x2(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_f32x4(self, rhs.simd_into(self.simd))
+    pub fn trunc(self) -> f32x2<S> {
+        self.simd.trunc_f32x2(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_f32x4(self, rhs.simd_into(self.simd))
+    pub fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x2(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_f32x4(self, rhs.simd_into(self.simd))
+    pub fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x2(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_f32x4(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x2(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.zip_low_f32x4(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<2>>(self) -> f32x2<S> {
+        self.simd.shuffle_f32x2::<I>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.zip_high_f32x4(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .shuffle2_f32x2::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.max_f32x4(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> f32x2<S> {
+        self.simd.reverse_f32x2(self)
     }
     #[inline(always)]
-    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.max_precise_f32x4(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.unzip_low_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.min_f32x4(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.unzip_high_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd.min_precise_f32x4(self, rhs.simd_into(self.simd))
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.combine_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd
-            .madd_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_f32x2(self)
     }
     #[inline(always)]
-    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
-        self.simd
-            .msub_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_f32x2(self)
     }
     #[inline(always)]
-    fn floor(self) -> f32x4<S> {
-        self.simd.floor_f32x4(self)
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_f32x2(self)
     }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn fract(self) -> f32x4<S> {
-        self.simd.fract_f32x4(self)
+    pub fn cvt_f64(self) -> f64x2<S> {
+        self.simd.cvt_f64_f32x2(self)
     }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn trunc(self) -> f32x4<S> {
-        self.simd.trunc_f32x4(self)
+    pub fn cvt_i32(self) -> i32x2<S> {
+        self.simd.cvt_i32_f32x2(self)
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct i8x16<S: Simd> {
-    pub val: [i8; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i8; 16], S> for i8x16<S> {
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn simd_from(val: [i8; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    pub fn cvt_i32_round(self) -> i32x2<S> {
+        self.simd.cvt_i32_round_f32x2(self)
     }
-}
-impl<S: Simd> From<i8x16<S>> for [i8; 16] {
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn from(value: i8x16<S>) -> Self {
-        value.val
+    pub fn cvt_i64(self) -> i64x2<S> {
+        self.simd.cvt_i64_f32x2(self)
     }
-}
-impl<S: Simd> core::ops::Deref for i8x16<S> {
-    type Target = [i8; 16];
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn cvt_i64_round(self) -> i64x2<S> {
+        self.simd.cvt_i64_round_f32x2(self)
     }
-}
-impl<S: Simd> core::ops::DerefMut for i8x16<S> {
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn cvt_u64(self) -> u64x2<S> {
+        self.simd.cvt_u64_f32x2(self)
     }
-}
-impl<S: Simd> SimdFrom<i8, S> for i8x16<S> {
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn simd_from(value: i8, simd: S) -> Self {
-        simd.splat_i8x16(value)
+    pub fn cvt_u64_round(self) -> u64x2<S> {
+        self.simd.cvt_u64_round_f32x2(self)
     }
-}
-impl<S: Simd> Select<i8x16<S>> for mask8x16<S> {
     #[inline(always)]
-    fn select(self, if_true: i8x16<S>, if_false: i8x16<S>) -> i8x16<S> {
-        self.simd.select_i8x16(self, if_true, if_false)
+    pub fn rotate_lanes_left<const K: usize>(self) -> f32x2<S> {
+        self.simd.rotate_lanes_left_f32x2::<K>(self)
     }
-}
-impl<S: Simd> Bytes for i8x16<S> {
-    type Bytes = u8x16<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x16 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn rotate_lanes_right<const K: usize>(self) -> f32x2<S> {
+        self.simd.rotate_lanes_right_f32x2::<K>(self)
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn broadcast_lane<const L: usize>(self) -> f32x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_f32x2::<L>(self)
     }
 }
-impl<S: Simd> i8x16<S> {
+impl<S: Simd> crate::SimdBase<f32, S> for f32x2<S> {
+    const N: usize = 2;
+    type Mask = mask32x2<S>;
+    type Block = f32x4<S>;
     #[inline(always)]
-    pub fn not(self) -> i8x16<S> {
-        self.simd.not_i8x16(self)
+    fn as_slice(&self) -> &[f32] {
+        &self.val
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.add_i8x16(self, rhs.simd_into(self.simd))
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.val
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.sub_i8x16(self, rhs.simd_into(self.simd))
+    fn from_slice(simd: S, slice: &[f32]) -> Self {
+        let mut val = [0.0; 2];
+        val.copy_from_slice(slice);
+        Self { val, simd }
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.mul_i8x16(self, rhs.simd_into(self.simd))
+    fn splat(simd: S, val: f32) -> Self {
+        simd.splat_f32x2(val)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.and_i8x16(self, rhs.simd_into(self.simd))
+    fn block_splat(block: Self::Block) -> Self {
+        block.split().0
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.or_i8x16(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_f32x2(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.xor_i8x16(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i8x16<S> {
-        self.simd.shr_i8x16(self, shift)
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_i8x16(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f32x2::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_lt_i8x16(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f32x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_le_i8x16(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f32x2::<K>(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_ge_i8x16(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f32x2::<K>(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_gt_i8x16(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_f32x2::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdFloat<f32, S> for f32x2<S> {
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.min_i8x16(self, rhs.simd_into(self.simd))
+    fn abs(self) -> f32x2<S> {
+        self.simd.abs_f32x2(self)
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.max_i8x16(self, rhs.simd_into(self.simd))
+    fn sqrt(self) -> f32x2<S> {
+        self.simd.sqrt_f32x2(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.combine_i8x16(self, rhs.simd_into(self.simd))
+    fn recip_approx(self) -> f32x2<S> {
+        self.simd.recip_approx_f32x2(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x16<S> {
-        self.simd.reinterpret_u8_i8x16(self)
+    fn rsqrt_approx(self) -> f32x2<S> {
+        self.simd.rsqrt_approx_f32x2(self)
     }
-}
-impl<S: Simd> crate::SimdBase<i8, S> for i8x16<S> {
-    const N: usize = 16;
-    type Mask = mask8x16<S>;
-    type Block = i8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
-        &self.val
+    fn recip(self, steps: u32) -> f32x2<S> {
+        self.simd.recip_f32x2(self, steps)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
-        &mut self.val
+    fn rsqrt(self, steps: u32) -> f32x2<S> {
+        self.simd.rsqrt_f32x2(self, steps)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.copysign_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_i8x16(val)
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ne_f32x2(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdInt<i8, S> for i8x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_i8x16(self, rhs.simd_into(self.simd))
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_lt_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_lt_i8x16(self, rhs.simd_into(self.simd))
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_le_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_le_i8x16(self, rhs.simd_into(self.simd))
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ge_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_ge_i8x16(self, rhs.simd_into(self.simd))
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_gt_f32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_gt_i8x16(self, rhs.simd_into(self.simd))
+    fn is_nan(self) -> mask32x2<S> {
+        self.simd.is_nan_f32x2(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.zip_low_i8x16(self, rhs.simd_into(self.simd))
+    fn is_finite(self) -> mask32x2<S> {
+        self.simd.is_finite_f32x2(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.zip_high_i8x16(self, rhs.simd_into(self.simd))
+    fn is_infinite(self) -> mask32x2<S> {
+        self.simd.is_infinite_f32x2(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.min_i8x16(self, rhs.simd_into(self.simd))
+    fn is_subnormal(self) -> mask32x2<S> {
+        self.simd.is_subnormal_f32x2(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
-        self.simd.max_i8x16(self, rhs.simd_into(self.simd))
+    fn is_sign_negative(self) -> mask32x2<S> {
+        self.simd.is_sign_negative_f32x2(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.zip_low_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.zip_high_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.max_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.max_precise_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.min_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd.min_precise_f32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .madd_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .msub_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .nmadd_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .nmsub_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .mul_add_fused_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x2<S> {
+        self.simd
+            .mul_add_fast_f32x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f32x2<S> {
+        self.simd.floor_f32x2(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f32x2<S> {
+        self.simd.ceil_f32x2(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f32x2<S> {
+        self.simd.round_f32x2(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f32x2<S> {
+        self.simd.round_ties_even_f32x2(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f32x2<S> {
+        self.simd.fract_f32x2(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f32x2<S> {
+        self.simd.trunc_f32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x2(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct u8x16<S: Simd> {
-    pub val: [u8; 16],
+#[repr(C, align(8))]
+pub struct u8x8<S: Simd> {
+    pub val: [u8; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u8; 16], S> for u8x16<S> {
+impl<S: Simd> SimdFrom<[u8; 8], S> for u8x8<S> {
     #[inline(always)]
-    fn simd_from(val: [u8; 16], simd: S) -> Self {
+    fn simd_from(val: [u8; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -507,56 +544,48 @@ impl<S: Simd> SimdFrom<[u8; 16], S> for u8x16<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<u8x16<S>> for [u8; 16] {
+impl<S: Simd> From<u8x8<S>> for [u8; 8] {
     #[inline(always)]
-    fn from(value: u8x16<S>) -> Self {
+    fn from(value: u8x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u8x16<S> {
-    type Target = [u8; 16];
+impl<S: Simd> core::ops::Deref for u8x8<S> {
+    type Target = [u8; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u8x16<S> {
+impl<S: Simd> core::ops::DerefMut for u8x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u8, S> for u8x16<S> {
+impl<S: Simd> SimdFrom<u8, S> for u8x8<S> {
     #[inline(always)]
     fn simd_from(value: u8, simd: S) -> Self {
-        simd.splat_u8x16(value)
+        simd.splat_u8x8(value)
     }
 }
-impl<S: Simd> Select<u8x16<S>> for mask8x16<S> {
+impl<S: Simd> Select<u8x8<S>> for mask8x8<S> {
     #[inline(always)]
-    fn select(self, if_true: u8x16<S>, if_false: u8x16<S>) -> u8x16<S> {
-        self.simd.select_u8x16(self, if_true, if_false)
+    fn select(self, if_true: u8x8<S>, if_false: u8x8<S>) -> u8x8<S> {
+        self.simd.select_u8x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u8x16<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for u8x8<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -572,283 +601,356 @@ impl<S: Simd> Bytes for u8x16<S> {
         }
     }
 }
-impl<S: Simd> u8x16<S> {
+impl<S: Simd> u8x8<S> {
     #[inline(always)]
-    pub fn not(self) -> u8x16<S> {
-        self.simd.not_u8x16(self)
+    pub fn not(self) -> u8x8<S> {
+        self.simd.not_u8x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.add_u8x16(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.add_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.sub_u8x16(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.sub_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.mul_u8x16(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.mul_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.and_u8x16(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.and_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.or_u8x16(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.or_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.xor_u8x16(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.xor_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u8x16<S> {
-        self.simd.shr_u8x16(self, shift)
+    pub fn shl(self, shift: u32) -> u8x8<S> {
+        self.simd.shl_u8x8(self, shift)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_u8x16(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> u8x8<S> {
+        self.simd.shr_u8x8(self, shift)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_lt_u8x16(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.shl_var_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_le_u8x16(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.shr_var_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_ge_u8x16(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_eq_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_gt_u8x16(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_lt_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.min_u8x16(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_le_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.max_u8x16(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_ge_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.combine_u8x16(self, rhs.simd_into(self.simd))
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_gt_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<u8, S> for u8x16<S> {
-    const N: usize = 16;
-    type Mask = mask8x16<S>;
-    type Block = u8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u8] {
-        &self.val
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.min_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u8] {
-        &mut self.val
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.max_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u8]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x8(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: u8) -> Self {
-        simd.splat_u8x16(val)
+    pub fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x8(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block
+    pub fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x8(self)
     }
-}
-impl<S: Simd> crate::SimdInt<u8, S> for u8x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_u8x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_lt_u8x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_le_u8x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<8>>(self) -> u8x8<S> {
+        self.simd.shuffle_u8x8::<I>(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_ge_u8x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.shuffle2_u8x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_gt_u8x16(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> u8x8<S> {
+        self.simd.reverse_u8x8(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.zip_low_u8x16(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.unzip_low_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.zip_high_u8x16(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.unzip_high_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.min_u8x16(self, rhs.simd_into(self.simd))
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd
+            .saturating_add_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
-        self.simd.max_u8x16(self, rhs.simd_into(self.simd))
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd
+            .saturating_sub_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct mask8x16<S: Simd> {
-    pub val: [i8; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i8; 16], S> for mask8x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i8; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.abs_diff_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<mask8x16<S>> for [i8; 16] {
     #[inline(always)]
-    fn from(value: mask8x16<S>) -> Self {
-        value.val
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.avg_round_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for mask8x16<S> {
-    type Target = [i8; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.mul_widen_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for mask8x16<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.mul_high_u8x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<mask8x16<S>> for mask8x16<S> {
+    #[doc = r" Look up each byte of `idx` in `self`, with zero for indices"]
+    #[doc = r" past the end."]
     #[inline(always)]
-    fn select(self, if_true: mask8x16<S>, if_false: mask8x16<S>) -> mask8x16<S> {
-        self.simd.select_mask8x16(self, if_true, if_false)
+    pub fn swizzle_dyn(self, idx: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.swizzle_dyn_u8x8(self, idx.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for mask8x16<S> {
-    type Bytes = u8x16<S>;
+    #[doc = " Look up each byte of `self` in a 32 byte table, with zero for indices past the end."]
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x16 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn lookup_32(self, table: u8x32<S>) -> u8x8<S> {
+        self.simd.lookup_32_u8x8(table, self)
     }
+    #[doc = " Look up each byte of `self` in a 48 byte table of three vectors, with zero for indices past the end."]
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn lookup_48(self, table: [u8x16<S>; 3]) -> u8x8<S> {
+        self.simd.lookup_48_u8x8(table, self)
     }
-}
-impl<S: Simd> mask8x16<S> {
+    #[doc = " Look up each byte of `self` in a 64 byte table, with zero for indices past the end."]
     #[inline(always)]
-    pub fn not(self) -> mask8x16<S> {
-        self.simd.not_mask8x16(self)
+    pub fn lookup_64(self, table: u8x64<S>) -> u8x8<S> {
+        self.simd.lookup_64_u8x8(table, self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.and_mask8x16(self, rhs.simd_into(self.simd))
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.combine_u8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.or_mask8x16(self, rhs.simd_into(self.simd))
+    pub fn widen(self) -> u16x8<S> {
+        self.simd.widen_u8x8(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.xor_mask8x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f32(self) -> f32x2<S> {
+        self.simd.reinterpret_f32_u8x8(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_mask8x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_u8x8(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.combine_mask8x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_u8x8(self)
     }
-}
-impl<S: Simd> crate::SimdBase<i8, S> for mask8x16<S> {
-    const N: usize = 16;
-    type Mask = mask8x16<S>;
-    type Block = mask8x16<S>;
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
-        &self.val
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_u8x8(self)
     }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
-        &mut self.val
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_u8x8(self)
     }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 16];
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_u8x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, mapping `0..=255` to `0.0..=1.0` like `x as f32 / 255.0`."]
+    #[inline(always)]
+    pub fn cvt_f32_unorm(self) -> f32x8<S> {
+        self.simd.cvt_f32_unorm_u8x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u8x8<S> {
+        self.simd.rotate_lanes_left_u8x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u8x8<S> {
+        self.simd.rotate_lanes_right_u8x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u8x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u8x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u8, S> for u8x8<S> {
+    const N: usize = 8;
+    type Mask = mask8x8<S>;
+    type Block = u8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u8]) -> Self {
+        let mut val = [0; 8];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_mask8x16(val)
+    fn splat(simd: S, val: u8) -> Self {
+        simd.splat_u8x8(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u8x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u8x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u8x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u8x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u8x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u8x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdMask<i8, S> for mask8x16<S> {
+impl<S: Simd> crate::SimdInt<u8, S> for u8x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
-        self.simd.simd_eq_mask8x16(self, rhs.simd_into(self.simd))
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.shl_var_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.shr_var_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_eq_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_lt_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_le_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_ge_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_gt_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.zip_low_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.zip_high_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.min_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x8<S> {
+        self.simd.max_u8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x8(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x8(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x8(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct i16x8<S: Simd> {
-    pub val: [i16; 8],
+#[repr(C, align(8))]
+pub struct mask8x8<S: Simd> {
+    pub val: [i8; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i16; 8], S> for i16x8<S> {
+impl<S: Simd> SimdFrom<[i8; 8], S> for mask8x8<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 8], simd: S) -> Self {
+    fn simd_from(val: [i8; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -864,43 +966,37 @@ impl<S: Simd> SimdFrom<[i16; 8], S> for i16x8<S> {
         }
     }
 }
-impl<S: Simd> From<i16x8<S>> for [i16; 8] {
+impl<S: Simd> From<mask8x8<S>> for [i8; 8] {
     #[inline(always)]
-    fn from(value: i16x8<S>) -> Self {
+    fn from(value: mask8x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for i16x8<S> {
-    type Target = [i16; 8];
+impl<S: Simd> core::ops::Deref for mask8x8<S> {
+    type Target = [i8; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for i16x8<S> {
+impl<S: Simd> core::ops::DerefMut for mask8x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<i16, S> for i16x8<S> {
-    #[inline(always)]
-    fn simd_from(value: i16, simd: S) -> Self {
-        simd.splat_i16x8(value)
-    }
-}
-impl<S: Simd> Select<i16x8<S>> for mask16x8<S> {
+impl<S: Simd> Select<mask8x8<S>> for mask8x8<S> {
     #[inline(always)]
-    fn select(self, if_true: i16x8<S>, if_false: i16x8<S>) -> i16x8<S> {
-        self.simd.select_i16x8(self, if_true, if_false)
+    fn select(self, if_true: mask8x8<S>, if_false: mask8x8<S>) -> mask8x8<S> {
+        self.simd.select_mask8x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for i16x8<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for mask8x8<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -916,202 +1012,253 @@ impl<S: Simd> Bytes for i16x8<S> {
         }
     }
 }
-impl<S: Simd> i16x8<S> {
+impl<S: Simd> mask8x8<S> {
     #[inline(always)]
-    pub fn not(self) -> i16x8<S> {
-        self.simd.not_i16x8(self)
+    pub fn not(self) -> mask8x8<S> {
+        self.simd.not_mask8x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.add_i16x8(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.and_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.sub_i16x8(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.or_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.mul_i16x8(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.xor_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.and_i16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_eq_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.or_i16x8(self, rhs.simd_into(self.simd))
+    pub fn any(self) -> bool {
+        self.simd.any_mask8x8(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.xor_i16x8(self, rhs.simd_into(self.simd))
+    pub fn all(self) -> bool {
+        self.simd.all_mask8x8(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i16x8<S> {
-        self.simd.shr_i16x8(self, shift)
+    pub fn none(self) -> bool {
+        self.simd.none_mask8x8(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_i16x8(self, rhs.simd_into(self.simd))
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask8x8(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_lt_i16x8(self, rhs.simd_into(self.simd))
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x8(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_le_i16x8(self, rhs.simd_into(self.simd))
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x8(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_ge_i16x8(self, rhs.simd_into(self.simd))
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x8(bits)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_gt_i16x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<8>>(self) -> mask8x8<S> {
+        self.simd.shuffle_mask8x8::<I>(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.min_i16x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd
+            .shuffle2_mask8x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.max_i16x8(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> mask8x8<S> {
+        self.simd.reverse_mask8x8(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.combine_i16x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.unzip_low_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x16<S> {
-        self.simd.reinterpret_u8_i16x8(self)
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.unzip_high_mask8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.combine_mask8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_mask8x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_mask8x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_mask8x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask8x8<S> {
+        self.simd.rotate_lanes_left_mask8x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask8x8<S> {
+        self.simd.rotate_lanes_right_mask8x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask8x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<i16, S> for i16x8<S> {
+impl<S: Simd> crate::SimdBase<i8, S> for mask8x8<S> {
     const N: usize = 8;
-    type Mask = mask16x8<S>;
-    type Block = i16x8<S>;
+    type Mask = mask8x8<S>;
+    type Block = mask8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
+    fn as_slice(&self) -> &[i8] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
+    fn as_mut_slice(&mut self) -> &mut [i8] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
         let mut val = [0; 8];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_i16x8(val)
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_mask8x8(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
     }
-}
-impl<S: Simd> crate::SimdInt<i16, S> for i16x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_i16x8(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask8x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_lt_i16x8(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_le_i16x8(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_mask8x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_ge_i16x8(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask8x8::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_gt_i16x8(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask8x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.zip_low_i16x8(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask8x8::<K>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.zip_high_i16x8(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask8x8::<K>(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.min_i16x8(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x8::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdMask<i8, S> for mask8x8<S> {
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
-        self.simd.max_i16x8(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x8<S> {
+        self.simd.simd_eq_mask8x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask8x8(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask8x8(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask8x8(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask8x8(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x8(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x8(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x8(bits)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct u16x8<S: Simd> {
-    pub val: [u16; 8],
+#[repr(C, align(8))]
+pub struct u16x4<S: Simd> {
+    pub val: [u16; 4],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u16; 8], S> for u16x8<S> {
+impl<S: Simd> SimdFrom<[u16; 4], S> for u16x4<S> {
     #[inline(always)]
-    fn simd_from(val: [u16; 8], simd: S) -> Self {
+    fn simd_from(val: [u16; 4], simd: S) -> Self {
         Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-            ],
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<u16x8<S>> for [u16; 8] {
+impl<S: Simd> From<u16x4<S>> for [u16; 4] {
     #[inline(always)]
-    fn from(value: u16x8<S>) -> Self {
+    fn from(value: u16x4<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u16x8<S> {
-    type Target = [u16; 8];
+impl<S: Simd> core::ops::Deref for u16x4<S> {
+    type Target = [u16; 4];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u16x8<S> {
+impl<S: Simd> core::ops::DerefMut for u16x4<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u16, S> for u16x8<S> {
+impl<S: Simd> SimdFrom<u16, S> for u16x4<S> {
     #[inline(always)]
     fn simd_from(value: u16, simd: S) -> Self {
-        simd.splat_u16x8(value)
+        simd.splat_u16x4(value)
     }
 }
-impl<S: Simd> Select<u16x8<S>> for mask16x8<S> {
+impl<S: Simd> Select<u16x4<S>> for mask16x4<S> {
     #[inline(always)]
-    fn select(self, if_true: u16x8<S>, if_false: u16x8<S>) -> u16x8<S> {
-        self.simd.select_u16x8(self, if_true, if_false)
+    fn select(self, if_true: u16x4<S>, if_false: u16x4<S>) -> u16x4<S> {
+        self.simd.select_u16x4(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u16x8<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for u16x4<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -1127,322 +1274,363 @@ impl<S: Simd> Bytes for u16x8<S> {
         }
     }
 }
-impl<S: Simd> u16x8<S> {
+impl<S: Simd> u16x4<S> {
     #[inline(always)]
-    pub fn not(self) -> u16x8<S> {
-        self.simd.not_u16x8(self)
+    pub fn not(self) -> u16x4<S> {
+        self.simd.not_u16x4(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.add_u16x8(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.add_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.sub_u16x8(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.sub_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.mul_u16x8(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.mul_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.and_u16x8(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.and_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.or_u16x8(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.or_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.xor_u16x8(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.xor_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u16x8<S> {
-        self.simd.shr_u16x8(self, shift)
+    pub fn shl(self, shift: u32) -> u16x4<S> {
+        self.simd.shl_u16x4(self, shift)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_u16x8(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> u16x4<S> {
+        self.simd.shr_u16x4(self, shift)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_lt_u16x8(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.shl_var_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_le_u16x8(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.shr_var_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_ge_u16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_eq_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_gt_u16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_lt_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.min_u16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_le_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_ge_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.combine_u16x8(self, rhs.simd_into(self.simd))
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_gt_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x16<S> {
-        self.simd.reinterpret_u8_u16x8(self)
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.min_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<u16, S> for u16x8<S> {
-    const N: usize = 8;
-    type Mask = mask16x8<S>;
-    type Block = u16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u16] {
-        &self.val
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.max_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u16] {
-        &mut self.val
+    pub fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x4(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u16]) -> Self {
-        let mut val = [0; 8];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x4(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: u16) -> Self {
-        simd.splat_u16x8(val)
+    pub fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x4(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block
+    pub fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x4(self)
     }
-}
-impl<S: Simd> crate::SimdInt<u16, S> for u16x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_u16x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x4(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_lt_u16x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<4>>(self) -> u16x4<S> {
+        self.simd.shuffle_u16x4::<I>(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_le_u16x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd
+            .shuffle2_u16x4::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_ge_u16x8(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> u16x4<S> {
+        self.simd.reverse_u16x4(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_gt_u16x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.unzip_low_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.zip_low_u16x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.unzip_high_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.zip_high_u16x8(self, rhs.simd_into(self.simd))
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd
+            .saturating_add_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.min_u16x8(self, rhs.simd_into(self.simd))
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd
+            .saturating_sub_u16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
-        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.abs_diff_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct mask16x8<S: Simd> {
-    pub val: [i16; 8],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i16; 8], S> for mask16x8<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 8], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-            ],
-            simd,
-        }
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.avg_round_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<mask16x8<S>> for [i16; 8] {
     #[inline(always)]
-    fn from(value: mask16x8<S>) -> Self {
-        value.val
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.mul_widen_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for mask16x8<S> {
-    type Target = [i16; 8];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.mul_high_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for mask16x8<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.combine_u16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<mask16x8<S>> for mask16x8<S> {
     #[inline(always)]
-    fn select(self, if_true: mask16x8<S>, if_false: mask16x8<S>) -> mask16x8<S> {
-        self.simd.select_mask16x8(self, if_true, if_false)
+    pub fn widen(self) -> u32x4<S> {
+        self.simd.widen_u16x4(self)
     }
-}
-impl<S: Simd> Bytes for mask16x8<S> {
-    type Bytes = u8x16<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x16 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn reinterpret_f32(self) -> f32x2<S> {
+        self.simd.reinterpret_f32_u16x4(self)
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_u16x4(self)
     }
-}
-impl<S: Simd> mask16x8<S> {
     #[inline(always)]
-    pub fn not(self) -> mask16x8<S> {
-        self.simd.not_mask16x8(self)
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_u16x4(self)
     }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.and_mask16x8(self, rhs.simd_into(self.simd))
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_u16x4(self)
     }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.or_mask16x8(self, rhs.simd_into(self.simd))
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_u16x4(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.xor_mask16x8(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> u16x4<S> {
+        self.simd.rotate_lanes_left_u16x4::<K>(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_mask16x8(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_right<const K: usize>(self) -> u16x4<S> {
+        self.simd.rotate_lanes_right_u16x4::<K>(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.combine_mask16x8(self, rhs.simd_into(self.simd))
+    pub fn broadcast_lane<const L: usize>(self) -> u16x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u16x4::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<i16, S> for mask16x8<S> {
-    const N: usize = 8;
-    type Mask = mask16x8<S>;
-    type Block = mask16x8<S>;
+impl<S: Simd> crate::SimdBase<u16, S> for u16x4<S> {
+    const N: usize = 4;
+    type Mask = mask16x4<S>;
+    type Block = u16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
+    fn as_slice(&self) -> &[u16] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
+    fn as_mut_slice(&mut self) -> &mut [u16] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
-        let mut val = [0; 8];
+    fn from_slice(simd: S, slice: &[u16]) -> Self {
+        let mut val = [0; 4];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_mask16x8(val)
+    fn splat(simd: S, val: u16) -> Self {
+        simd.splat_u16x4(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u16x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u16x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u16x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u16x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u16x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u16x4::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdMask<i16, S> for mask16x8<S> {
+impl<S: Simd> crate::SimdInt<u16, S> for u16x4<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
-        self.simd.simd_eq_mask16x8(self, rhs.simd_into(self.simd))
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.shl_var_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.shr_var_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_eq_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_lt_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_le_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_ge_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_gt_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.zip_low_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.zip_high_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.min_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x4<S> {
+        self.simd.max_u16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x4(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x4(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x4(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct i32x4<S: Simd> {
-    pub val: [i32; 4],
+#[repr(C, align(8))]
+pub struct mask16x4<S: Simd> {
+    pub val: [i16; 4],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i32; 4], S> for i32x4<S> {
+impl<S: Simd> SimdFrom<[i16; 4], S> for mask16x4<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 4], simd: S) -> Self {
+    fn simd_from(val: [i16; 4], simd: S) -> Self {
         Self {
             val: [val[0usize], val[1usize], val[2usize], val[3usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<i32x4<S>> for [i32; 4] {
+impl<S: Simd> From<mask16x4<S>> for [i16; 4] {
     #[inline(always)]
-    fn from(value: i32x4<S>) -> Self {
+    fn from(value: mask16x4<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for i32x4<S> {
-    type Target = [i32; 4];
+impl<S: Simd> core::ops::Deref for mask16x4<S> {
+    type Target = [i16; 4];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for i32x4<S> {
+impl<S: Simd> core::ops::DerefMut for mask16x4<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<i32, S> for i32x4<S> {
-    #[inline(always)]
-    fn simd_from(value: i32, simd: S) -> Self {
-        simd.splat_i32x4(value)
-    }
-}
-impl<S: Simd> Select<i32x4<S>> for mask32x4<S> {
+impl<S: Simd> Select<mask16x4<S>> for mask16x4<S> {
     #[inline(always)]
-    fn select(self, if_true: i32x4<S>, if_false: i32x4<S>) -> i32x4<S> {
-        self.simd.select_i32x4(self, if_true, if_false)
+    fn select(self, if_true: mask16x4<S>, if_false: mask16x4<S>) -> mask16x4<S> {
+        self.simd.select_mask16x4(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for i32x4<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for mask16x4<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -1458,197 +1646,255 @@ impl<S: Simd> Bytes for i32x4<S> {
         }
     }
 }
-impl<S: Simd> i32x4<S> {
+impl<S: Simd> mask16x4<S> {
     #[inline(always)]
-    pub fn not(self) -> i32x4<S> {
-        self.simd.not_i32x4(self)
+    pub fn not(self) -> mask16x4<S> {
+        self.simd.not_mask16x4(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.add_i32x4(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.and_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.sub_i32x4(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.or_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.mul_i32x4(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.xor_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.and_i32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_eq_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.or_i32x4(self, rhs.simd_into(self.simd))
+    pub fn any(self) -> bool {
+        self.simd.any_mask16x4(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.xor_i32x4(self, rhs.simd_into(self.simd))
+    pub fn all(self) -> bool {
+        self.simd.all_mask16x4(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i32x4<S> {
-        self.simd.shr_i32x4(self, shift)
+    pub fn none(self) -> bool {
+        self.simd.none_mask16x4(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_i32x4(self, rhs.simd_into(self.simd))
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask16x4(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_i32x4(self, rhs.simd_into(self.simd))
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x4(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_i32x4(self, rhs.simd_into(self.simd))
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x4(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_i32x4(self, rhs.simd_into(self.simd))
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x4(bits)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_i32x4(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<4>>(self) -> mask16x4<S> {
+        self.simd.shuffle_mask16x4::<I>(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.min_i32x4(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd
+            .shuffle2_mask16x4::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.max_i32x4(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> mask16x4<S> {
+        self.simd.reverse_mask16x4(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.combine_i32x4(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.unzip_low_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x16<S> {
-        self.simd.reinterpret_u8_i32x4(self)
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd
+            .unzip_high_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x4<S> {
-        self.simd.cvt_f32_i32x4(self)
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.combine_mask16x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i32, S> for i32x4<S> {
-    const N: usize = 4;
-    type Mask = mask32x4<S>;
-    type Block = i32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i32] {
-        &self.val
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_mask16x4(self)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i32] {
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_mask16x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_mask16x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask16x4<S> {
+        self.simd.rotate_lanes_left_mask16x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask16x4<S> {
+        self.simd.rotate_lanes_right_mask16x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask16x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for mask16x4<S> {
+    const N: usize = 4;
+    type Mask = mask16x4<S>;
+    type Block = mask16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i32]) -> Self {
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
         let mut val = [0; 4];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: i32) -> Self {
-        simd.splat_i32x4(val)
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_mask16x4(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
     }
-}
-impl<S: Simd> crate::SimdInt<i32, S> for i32x4<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_i32x4(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask16x4(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_i32x4(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_i32x4(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask16x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_i32x4(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask16x4::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_i32x4(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask16x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.zip_low_i32x4(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask16x4::<K>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.zip_high_i32x4(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask16x4::<K>(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.min_i32x4(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x4::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdMask<i16, S> for mask16x4<S> {
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
-        self.simd.max_i32x4(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x4<S> {
+        self.simd.simd_eq_mask16x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask16x4(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask16x4(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask16x4(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask16x4(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x4(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x4(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x4(bits)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct u32x4<S: Simd> {
-    pub val: [u32; 4],
+#[repr(C, align(8))]
+pub struct i32x2<S: Simd> {
+    pub val: [i32; 2],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u32; 4], S> for u32x4<S> {
+impl<S: Simd> SimdFrom<[i32; 2], S> for i32x2<S> {
     #[inline(always)]
-    fn simd_from(val: [u32; 4], simd: S) -> Self {
+    fn simd_from(val: [i32; 2], simd: S) -> Self {
         Self {
-            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            val: [val[0usize], val[1usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<u32x4<S>> for [u32; 4] {
+impl<S: Simd> From<i32x2<S>> for [i32; 2] {
     #[inline(always)]
-    fn from(value: u32x4<S>) -> Self {
+    fn from(value: i32x2<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u32x4<S> {
-    type Target = [u32; 4];
+impl<S: Simd> core::ops::Deref for i32x2<S> {
+    type Target = [i32; 2];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u32x4<S> {
+impl<S: Simd> core::ops::DerefMut for i32x2<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u32, S> for u32x4<S> {
+impl<S: Simd> SimdFrom<i32, S> for i32x2<S> {
     #[inline(always)]
-    fn simd_from(value: u32, simd: S) -> Self {
-        simd.splat_u32x4(value)
+    fn simd_from(value: i32, simd: S) -> Self {
+        simd.splat_i32x2(value)
     }
 }
-impl<S: Simd> Select<u32x4<S>> for mask32x4<S> {
+impl<S: Simd> Select<i32x2<S>> for mask32x2<S> {
     #[inline(always)]
-    fn select(self, if_true: u32x4<S>, if_false: u32x4<S>) -> u32x4<S> {
-        self.simd.select_u32x4(self, if_true, if_false)
+    fn select(self, if_true: i32x2<S>, if_false: i32x2<S>) -> i32x2<S> {
+        self.simd.select_i32x2(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u32x4<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for i32x2<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -1664,191 +1910,345 @@ impl<S: Simd> Bytes for u32x4<S> {
         }
     }
 }
-impl<S: Simd> u32x4<S> {
+impl<S: Simd> i32x2<S> {
     #[inline(always)]
-    pub fn not(self) -> u32x4<S> {
-        self.simd.not_u32x4(self)
+    pub fn not(self) -> i32x2<S> {
+        self.simd.not_i32x2(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.add_u32x4(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.add_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.sub_u32x4(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.sub_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.mul_u32x4(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.mul_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.and_u32x4(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.and_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.or_u32x4(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.or_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.xor_u32x4(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.xor_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u32x4<S> {
-        self.simd.shr_u32x4(self, shift)
+    pub fn shl(self, shift: u32) -> i32x2<S> {
+        self.simd.shl_i32x2(self, shift)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_u32x4(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> i32x2<S> {
+        self.simd.shr_i32x2(self, shift)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_u32x4(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.shl_var_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_u32x4(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.shr_var_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_u32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_u32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_lt_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.min_u32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_le_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.max_u32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ge_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.combine_u32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_gt_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x16<S> {
-        self.simd.reinterpret_u8_u32x4(self)
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.min_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x4<S> {
-        self.simd.cvt_f32_u32x4(self)
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.max_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> i32x2<S> {
+        self.simd.shuffle_i32x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd
+            .shuffle2_i32x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i32x2<S> {
+        self.simd.reverse_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.unzip_low_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.unzip_high_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i32x2<S> {
+        self.simd.abs_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i32x2<S> {
+        self.simd.neg_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.combine_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i64x2<S> {
+        self.simd.widen_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x2<S> {
+        self.simd.reinterpret_f32_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_i32x2(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x2<S> {
+        self.simd.cvt_f32_i32x2(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x2<S> {
+        self.simd.cvt_f64_i32x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i32x2<S> {
+        self.simd.rotate_lanes_left_i32x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i32x2<S> {
+        self.simd.rotate_lanes_right_i32x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i32x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_i32x2::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<u32, S> for u32x4<S> {
-    const N: usize = 4;
-    type Mask = mask32x4<S>;
-    type Block = u32x4<S>;
+impl<S: Simd> crate::SimdBase<i32, S> for i32x2<S> {
+    const N: usize = 2;
+    type Mask = mask32x2<S>;
+    type Block = i32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u32] {
+    fn as_slice(&self) -> &[i32] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u32] {
+    fn as_mut_slice(&mut self) -> &mut [i32] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u32]) -> Self {
-        let mut val = [0; 4];
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 2];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: u32) -> Self {
-        simd.splat_u32x4(val)
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_i32x2(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i32x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i32x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i32x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i32x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i32x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_i32x2::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdInt<u32, S> for u32x4<S> {
+impl<S: Simd> crate::SimdInt<i32, S> for i32x2<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_u32x4(self, rhs.simd_into(self.simd))
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.shl_var_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_lt_u32x4(self, rhs.simd_into(self.simd))
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.shr_var_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_le_u32x4(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_ge_u32x4(self, rhs.simd_into(self.simd))
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_lt_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_gt_u32x4(self, rhs.simd_into(self.simd))
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_le_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.zip_low_u32x4(self, rhs.simd_into(self.simd))
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_ge_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.zip_high_u32x4(self, rhs.simd_into(self.simd))
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_gt_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.min_u32x4(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.zip_low_i32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
-        self.simd.max_u32x4(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.zip_high_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.min_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x2<S> {
+        self.simd.max_i32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x2(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x2(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(16))]
-pub struct mask32x4<S: Simd> {
-    pub val: [i32; 4],
+#[repr(C, align(8))]
+pub struct mask32x2<S: Simd> {
+    pub val: [i32; 2],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i32; 4], S> for mask32x4<S> {
+impl<S: Simd> SimdFrom<[i32; 2], S> for mask32x2<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 4], simd: S) -> Self {
+    fn simd_from(val: [i32; 2], simd: S) -> Self {
         Self {
-            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            val: [val[0usize], val[1usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<mask32x4<S>> for [i32; 4] {
+impl<S: Simd> From<mask32x2<S>> for [i32; 2] {
     #[inline(always)]
-    fn from(value: mask32x4<S>) -> Self {
+    fn from(value: mask32x2<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for mask32x4<S> {
-    type Target = [i32; 4];
+impl<S: Simd> core::ops::Deref for mask32x2<S> {
+    type Target = [i32; 2];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for mask32x4<S> {
+impl<S: Simd> core::ops::DerefMut for mask32x2<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> Select<mask32x4<S>> for mask32x4<S> {
+impl<S: Simd> Select<mask32x2<S>> for mask32x2<S> {
     #[inline(always)]
-    fn select(self, if_true: mask32x4<S>, if_false: mask32x4<S>) -> mask32x4<S> {
-        self.simd.select_mask32x4(self, if_true, if_false)
+    fn select(self, if_true: mask32x2<S>, if_false: mask32x2<S>) -> mask32x2<S> {
+        self.simd.select_mask32x2(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for mask32x4<S> {
-    type Bytes = u8x16<S>;
+impl<S: Simd> Bytes for mask32x2<S> {
+    type Bytes = u8x8<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x16 {
+            u8x8 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -1864,35 +2264,110 @@ impl<S: Simd> Bytes for mask32x4<S> {
         }
     }
 }
-impl<S: Simd> mask32x4<S> {
+impl<S: Simd> mask32x2<S> {
     #[inline(always)]
-    pub fn not(self) -> mask32x4<S> {
-        self.simd.not_mask32x4(self)
+    pub fn not(self) -> mask32x2<S> {
+        self.simd.not_mask32x2(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.and_mask32x4(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.and_mask32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.or_mask32x4(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.or_mask32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.xor_mask32x4(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.xor_mask32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_mask32x4(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_mask32x2(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.combine_mask32x4(self, rhs.simd_into(self.simd))
+    pub fn any(self) -> bool {
+        self.simd.any_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x2(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> mask32x2<S> {
+        self.simd.shuffle_mask32x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd
+            .shuffle2_mask32x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask32x2<S> {
+        self.simd.reverse_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.unzip_low_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd
+            .unzip_high_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.combine_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x8<S> {
+        self.simd.reinterpret_u8_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x4<S> {
+        self.simd.reinterpret_u16_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x2<S> {
+        self.simd.reinterpret_i32_mask32x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask32x2<S> {
+        self.simd.rotate_lanes_left_mask32x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask32x2<S> {
+        self.simd.rotate_lanes_right_mask32x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask32x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x2::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<i32, S> for mask32x4<S> {
-    const N: usize = 4;
-    type Mask = mask32x4<S>;
+impl<S: Simd> crate::SimdBase<i32, S> for mask32x2<S> {
+    const N: usize = 2;
+    type Mask = mask32x2<S>;
     type Block = mask32x4<S>;
     #[inline(always)]
     fn as_slice(&self) -> &[i32] {
@@ -1904,34 +2379,97 @@ impl<S: Simd> crate::SimdBase<i32, S> for mask32x4<S> {
     }
     #[inline(always)]
     fn from_slice(simd: S, slice: &[i32]) -> Self {
-        let mut val = [0; 4];
+        let mut val = [0; 2];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
     fn splat(simd: S, val: i32) -> Self {
-        simd.splat_mask32x4(val)
+        simd.splat_mask32x2(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block
+        block.split().0
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask32x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask32x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask32x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask32x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask32x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x2::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdMask<i32, S> for mask32x4<S> {
+impl<S: Simd> crate::SimdMask<i32, S> for mask32x2<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
-        self.simd.simd_eq_mask32x4(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x2<S> {
+        self.simd.simd_eq_mask32x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask32x2(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask32x2(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask32x2(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask32x2(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x2(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x2(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x2(bits)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct f32x8<S: Simd> {
-    pub val: [f32; 8],
+#[repr(C, align(16))]
+pub struct f16x8<S: Simd> {
+    pub val: [f16; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[f32; 8], S> for f32x8<S> {
+impl<S: Simd> SimdFrom<[f16; 8], S> for f16x8<S> {
     #[inline(always)]
-    fn simd_from(val: [f32; 8], simd: S) -> Self {
+    fn simd_from(val: [f16; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -1947,43 +2485,43 @@ impl<S: Simd> SimdFrom<[f32; 8], S> for f32x8<S> {
         }
     }
 }
-impl<S: Simd> From<f32x8<S>> for [f32; 8] {
+impl<S: Simd> From<f16x8<S>> for [f16; 8] {
     #[inline(always)]
-    fn from(value: f32x8<S>) -> Self {
+    fn from(value: f16x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for f32x8<S> {
-    type Target = [f32; 8];
+impl<S: Simd> core::ops::Deref for f16x8<S> {
+    type Target = [f16; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for f32x8<S> {
+impl<S: Simd> core::ops::DerefMut for f16x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<f32, S> for f32x8<S> {
+impl<S: Simd> SimdFrom<f16, S> for f16x8<S> {
     #[inline(always)]
-    fn simd_from(value: f32, simd: S) -> Self {
-        simd.splat_f32x8(value)
+    fn simd_from(value: f16, simd: S) -> Self {
+        simd.splat_f16x8(value)
     }
 }
-impl<S: Simd> Select<f32x8<S>> for mask32x8<S> {
+impl<S: Simd> Select<f16x8<S>> for mask16x8<S> {
     #[inline(always)]
-    fn select(self, if_true: f32x8<S>, if_false: f32x8<S>) -> f32x8<S> {
-        self.simd.select_f32x8(self, if_true, if_false)
+    fn select(self, if_true: f16x8<S>, if_false: f16x8<S>) -> f16x8<S> {
+        self.simd.select_f16x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for f32x8<S> {
-    type Bytes = u8x32<S>;
+impl<S: Simd> Bytes for f16x8<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x32 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -1999,451 +2537,548 @@ impl<S: Simd> Bytes for f32x8<S> {
         }
     }
 }
-impl<S: Simd> f32x8<S> {
+impl<S: Simd> f16x8<S> {
     #[inline(always)]
-    pub fn abs(self) -> f32x8<S> {
-        self.simd.abs_f32x8(self)
+    pub fn abs(self) -> f16x8<S> {
+        self.simd.abs_f16x8(self)
     }
     #[inline(always)]
-    pub fn neg(self) -> f32x8<S> {
-        self.simd.neg_f32x8(self)
+    pub fn neg(self) -> f16x8<S> {
+        self.simd.neg_f16x8(self)
     }
     #[inline(always)]
-    pub fn sqrt(self) -> f32x8<S> {
-        self.simd.sqrt_f32x8(self)
+    pub fn sqrt(self) -> f16x8<S> {
+        self.simd.sqrt_f16x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.add_f32x8(self, rhs.simd_into(self.simd))
+    pub fn recip_approx(self) -> f16x8<S> {
+        self.simd.recip_approx_f16x8(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.sub_f32x8(self, rhs.simd_into(self.simd))
+    pub fn rsqrt_approx(self) -> f16x8<S> {
+        self.simd.rsqrt_approx_f16x8(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.mul_f32x8(self, rhs.simd_into(self.simd))
+    pub fn recip(self, steps: u32) -> f16x8<S> {
+        self.simd.recip_f16x8(self, steps)
     }
     #[inline(always)]
-    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.div_f32x8(self, rhs.simd_into(self.simd))
+    pub fn rsqrt(self, steps: u32) -> f16x8<S> {
+        self.simd.rsqrt_f16x8(self, steps)
     }
     #[inline(always)]
-    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.copysign_f32x8(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.add_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_f32x8(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.sub_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_f32x8(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.mul_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_f32x8(self, rhs.simd_into(self.simd))
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.div_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_f32x8(self, rhs.simd_into(self.simd))
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.copysign_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_f32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.max_f32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ne_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.max_precise_f32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.min_f32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.min_precise_f32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn floor(self) -> f32x8<S> {
-        self.simd.floor_f32x8(self)
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn fract(self) -> f32x8<S> {
-        self.simd.fract_f32x8(self)
+    pub fn is_nan(self) -> mask16x8<S> {
+        self.simd.is_nan_f16x8(self)
     }
     #[inline(always)]
-    pub fn trunc(self) -> f32x8<S> {
-        self.simd.trunc_f32x8(self)
+    pub fn is_finite(self) -> mask16x8<S> {
+        self.simd.is_finite_f16x8(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.combine_f32x8(self, rhs.simd_into(self.simd))
+    pub fn is_infinite(self) -> mask16x8<S> {
+        self.simd.is_infinite_f16x8(self)
     }
     #[inline(always)]
-    pub fn cvt_u32(self) -> u32x8<S> {
-        self.simd.cvt_u32_f32x8(self)
+    pub fn is_subnormal(self) -> mask16x8<S> {
+        self.simd.is_subnormal_f16x8(self)
     }
     #[inline(always)]
-    pub fn cvt_i32(self) -> i32x8<S> {
-        self.simd.cvt_i32_f32x8(self)
+    pub fn is_sign_negative(self) -> mask16x8<S> {
+        self.simd.is_sign_negative_f16x8(self)
     }
-}
-impl<S: Simd> crate::SimdBase<f32, S> for f32x8<S> {
-    const N: usize = 8;
-    type Mask = mask32x8<S>;
-    type Block = f32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[f32] {
-        &self.val
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.max_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [f32] {
-        &mut self.val
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.max_precise_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[f32]) -> Self {
-        let mut val = [0.0; 8];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.min_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: f32) -> Self {
-        simd.splat_f32x8(val)
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.min_precise_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    pub fn floor(self) -> f16x8<S> {
+        self.simd.floor_f16x8(self)
     }
-}
-impl<S: Simd> crate::SimdFloat<f32, S> for f32x8<S> {
     #[inline(always)]
-    fn abs(self) -> f32x8<S> {
-        self.simd.abs_f32x8(self)
+    pub fn ceil(self) -> f16x8<S> {
+        self.simd.ceil_f16x8(self)
     }
     #[inline(always)]
-    fn sqrt(self) -> f32x8<S> {
-        self.simd.sqrt_f32x8(self)
+    pub fn round(self) -> f16x8<S> {
+        self.simd.round_f16x8(self)
     }
     #[inline(always)]
-    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.copysign_f32x8(self, rhs.simd_into(self.simd))
+    pub fn round_ties_even(self) -> f16x8<S> {
+        self.simd.round_ties_even_f16x8(self)
     }
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_f32x8(self, rhs.simd_into(self.simd))
+    pub fn fract(self) -> f16x8<S> {
+        self.simd.fract_f16x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_f32x8(self, rhs.simd_into(self.simd))
+    pub fn trunc(self) -> f16x8<S> {
+        self.simd.trunc_f16x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_f32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_add(self) -> f16 {
+        self.simd.reduce_add_f16x8(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_f32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_min(self) -> f16 {
+        self.simd.reduce_min_f16x8(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_f32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> f16 {
+        self.simd.reduce_max_f16x8(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.zip_low_f32x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<8>>(self) -> f16x8<S> {
+        self.simd.shuffle_f16x8::<I>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.zip_high_f32x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .shuffle2_f16x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.max_f32x8(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> f16x8<S> {
+        self.simd.reverse_f16x8(self)
     }
     #[inline(always)]
-    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.max_precise_f32x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.unzip_low_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.min_f32x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.unzip_high_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd.min_precise_f32x8(self, rhs.simd_into(self.simd))
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.combine_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd
-            .madd_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_f16x8(self)
     }
     #[inline(always)]
-    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
-        self.simd
-            .msub_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_f16x8(self)
     }
     #[inline(always)]
-    fn floor(self) -> f32x8<S> {
-        self.simd.floor_f32x8(self)
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_f16x8(self)
     }
     #[inline(always)]
-    fn fract(self) -> f32x8<S> {
-        self.simd.fract_f32x8(self)
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_f16x8(self)
     }
     #[inline(always)]
-    fn trunc(self) -> f32x8<S> {
-        self.simd.trunc_f32x8(self)
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_f16x8(self)
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct i8x32<S: Simd> {
-    pub val: [i8; 32],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i8; 32], S> for i8x32<S> {
     #[inline(always)]
-    fn simd_from(val: [i8; 32], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-            ],
-            simd,
-        }
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_f16x8(self)
     }
-}
-impl<S: Simd> From<i8x32<S>> for [i8; 32] {
     #[inline(always)]
-    fn from(value: i8x32<S>) -> Self {
-        value.val
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_f16x8(self)
     }
-}
-impl<S: Simd> core::ops::Deref for i8x32<S> {
-    type Target = [i8; 32];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_f16x8(self)
     }
-}
-impl<S: Simd> core::ops::DerefMut for i8x32<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_f16x8(self)
     }
-}
-impl<S: Simd> SimdFrom<i8, S> for i8x32<S> {
     #[inline(always)]
-    fn simd_from(value: i8, simd: S) -> Self {
-        simd.splat_i8x32(value)
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_f16x8(self)
     }
-}
-impl<S: Simd> Select<i8x32<S>> for mask8x32<S> {
     #[inline(always)]
-    fn select(self, if_true: i8x32<S>, if_false: i8x32<S>) -> i8x32<S> {
-        self.simd.select_i8x32(self, if_true, if_false)
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_f16x8(self)
     }
-}
-impl<S: Simd> Bytes for i8x32<S> {
-    type Bytes = u8x32<S>;
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_f16x8(self)
     }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_f16x8(self)
     }
-}
-impl<S: Simd> i8x32<S> {
+    #[doc = " Convert each lane to `u8`, rounding toward zero. See [`cvt_u8_round`](Self::cvt_u8_round) to round to nearest.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn not(self) -> i8x32<S> {
-        self.simd.not_i8x32(self)
+    pub fn cvt_u8(self) -> u8x8<S> {
+        self.simd.cvt_u8_f16x8(self)
     }
+    #[doc = " Convert each lane to `u8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.add_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u8_round(self) -> u8x8<S> {
+        self.simd.cvt_u8_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `i16`, rounding toward zero. See [`cvt_i16_round`](Self::cvt_i16_round) to round to nearest.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.sub_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_i16(self) -> i16x8<S> {
+        self.simd.cvt_i16_f16x8(self)
     }
+    #[doc = " Convert each lane to `i16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.mul_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_i16_round(self) -> i16x8<S> {
+        self.simd.cvt_i16_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.and_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u16(self) -> u16x8<S> {
+        self.simd.cvt_u16_f16x8(self)
     }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.or_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u16_round(self) -> u16x8<S> {
+        self.simd.cvt_u16_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.xor_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_i32(self) -> i32x8<S> {
+        self.simd.cvt_i32_f16x8(self)
     }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i8x32<S> {
-        self.simd.shr_i8x32(self, shift)
+    pub fn cvt_i32_round(self) -> i32x8<S> {
+        self.simd.cvt_i32_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u32(self) -> u32x8<S> {
+        self.simd.cvt_u32_f16x8(self)
     }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_lt_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u32_round(self) -> u32x8<S> {
+        self.simd.cvt_u32_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_le_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_i64(self) -> i64x8<S> {
+        self.simd.cvt_i64_f16x8(self)
     }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_ge_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_i64_round(self) -> i64x8<S> {
+        self.simd.cvt_i64_round_f16x8(self)
     }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_gt_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u64(self) -> u64x8<S> {
+        self.simd.cvt_u64_f16x8(self)
     }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.min_i8x32(self, rhs.simd_into(self.simd))
+    pub fn cvt_u64_round(self) -> u64x8<S> {
+        self.simd.cvt_u64_round_f16x8(self)
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.max_i8x32(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> f16x8<S> {
+        self.simd.rotate_lanes_left_f16x8::<K>(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.combine_i8x32(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_right<const K: usize>(self) -> f16x8<S> {
+        self.simd.rotate_lanes_right_f16x8::<K>(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x32<S> {
-        self.simd.reinterpret_u8_i8x32(self)
+    pub fn broadcast_lane<const L: usize>(self) -> f16x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f16x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<i8, S> for i8x32<S> {
-    const N: usize = 32;
-    type Mask = mask8x32<S>;
-    type Block = i8x16<S>;
+impl<S: Simd> crate::SimdBase<f16, S> for f16x8<S> {
+    const N: usize = 8;
+    type Mask = mask16x8<S>;
+    type Block = f16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
+    fn as_slice(&self) -> &[f16] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
+    fn as_mut_slice(&mut self) -> &mut [f16] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 32];
+    fn from_slice(simd: S, slice: &[f16]) -> Self {
+        let mut val = [f16::from_bits(0); 8];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_i8x32(val)
+    fn splat(simd: S, val: f16) -> Self {
+        simd.splat_f16x8(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f16x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f16x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f16x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f16x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdInt<i8, S> for i8x32<S> {
+impl<S: Simd> crate::SimdFloat<f16, S> for f16x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_i8x32(self, rhs.simd_into(self.simd))
+    fn abs(self) -> f16x8<S> {
+        self.simd.abs_f16x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_lt_i8x32(self, rhs.simd_into(self.simd))
+    fn sqrt(self) -> f16x8<S> {
+        self.simd.sqrt_f16x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_le_i8x32(self, rhs.simd_into(self.simd))
+    fn recip_approx(self) -> f16x8<S> {
+        self.simd.recip_approx_f16x8(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_ge_i8x32(self, rhs.simd_into(self.simd))
+    fn rsqrt_approx(self) -> f16x8<S> {
+        self.simd.rsqrt_approx_f16x8(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_gt_i8x32(self, rhs.simd_into(self.simd))
+    fn recip(self, steps: u32) -> f16x8<S> {
+        self.simd.recip_f16x8(self, steps)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.zip_low_i8x32(self, rhs.simd_into(self.simd))
+    fn rsqrt(self, steps: u32) -> f16x8<S> {
+        self.simd.rsqrt_f16x8(self, steps)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.zip_high_i8x32(self, rhs.simd_into(self.simd))
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.copysign_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.min_i8x32(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_f16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
-        self.simd.max_i8x32(self, rhs.simd_into(self.simd))
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ne_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask16x8<S> {
+        self.simd.is_nan_f16x8(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask16x8<S> {
+        self.simd.is_finite_f16x8(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask16x8<S> {
+        self.simd.is_infinite_f16x8(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask16x8<S> {
+        self.simd.is_subnormal_f16x8(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask16x8<S> {
+        self.simd.is_sign_negative_f16x8(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.zip_low_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.zip_high_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.max_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.max_precise_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.min_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd.min_precise_f16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .madd_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .msub_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .nmadd_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .nmsub_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .mul_add_fused_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x8<S> {
+        self.simd
+            .mul_add_fast_f16x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f16x8<S> {
+        self.simd.floor_f16x8(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f16x8<S> {
+        self.simd.ceil_f16x8(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f16x8<S> {
+        self.simd.round_f16x8(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f16x8<S> {
+        self.simd.round_ties_even_f16x8(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f16x8<S> {
+        self.simd.fract_f16x8(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f16x8<S> {
+        self.simd.trunc_f16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f16 {
+        self.simd.reduce_add_f16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f16 {
+        self.simd.reduce_min_f16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f16 {
+        self.simd.reduce_max_f16x8(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct u8x32<S: Simd> {
-    pub val: [u8; 32],
+#[repr(C, align(16))]
+pub struct bf16x8<S: Simd> {
+    pub val: [bf16; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u8; 32], S> for u8x32<S> {
+impl<S: Simd> SimdFrom<[bf16; 8], S> for bf16x8<S> {
     #[inline(always)]
-    fn simd_from(val: [u8; 32], simd: S) -> Self {
+    fn simd_from(val: [bf16; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -2454,72 +3089,48 @@ impl<S: Simd> SimdFrom<[u8; 32], S> for u8x32<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<u8x32<S>> for [u8; 32] {
+impl<S: Simd> From<bf16x8<S>> for [bf16; 8] {
     #[inline(always)]
-    fn from(value: u8x32<S>) -> Self {
+    fn from(value: bf16x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u8x32<S> {
-    type Target = [u8; 32];
+impl<S: Simd> core::ops::Deref for bf16x8<S> {
+    type Target = [bf16; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u8x32<S> {
+impl<S: Simd> core::ops::DerefMut for bf16x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u8, S> for u8x32<S> {
+impl<S: Simd> SimdFrom<bf16, S> for bf16x8<S> {
     #[inline(always)]
-    fn simd_from(value: u8, simd: S) -> Self {
-        simd.splat_u8x32(value)
+    fn simd_from(value: bf16, simd: S) -> Self {
+        simd.splat_bf16x8(value)
     }
 }
-impl<S: Simd> Select<u8x32<S>> for mask8x32<S> {
+impl<S: Simd> Select<bf16x8<S>> for mask16x8<S> {
     #[inline(always)]
-    fn select(self, if_true: u8x32<S>, if_false: u8x32<S>) -> u8x32<S> {
-        self.simd.select_u8x32(self, if_true, if_false)
+    fn select(self, if_true: bf16x8<S>, if_false: bf16x8<S>) -> bf16x8<S> {
+        self.simd.select_bf16x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u8x32<S> {
-    type Bytes = u8x32<S>;
+impl<S: Simd> Bytes for bf16x8<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x32 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -2535,216 +3146,208 @@ impl<S: Simd> Bytes for u8x32<S> {
         }
     }
 }
-impl<S: Simd> u8x32<S> {
+impl<S: Simd> bf16x8<S> {
     #[inline(always)]
-    pub fn not(self) -> u8x32<S> {
-        self.simd.not_u8x32(self)
+    pub fn shuffle<I: Indices<8>>(self) -> bf16x8<S> {
+        self.simd.shuffle_bf16x8::<I>(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.add_u8x32(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> bf16x8<S> {
+        self.simd
+            .shuffle2_bf16x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.sub_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> bf16x8<S> {
+        self.simd.reverse_bf16x8(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.mul_u8x32(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> bf16x8<S> {
+        self.simd.unzip_low_bf16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.and_u8x32(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> bf16x8<S> {
+        self.simd.unzip_high_bf16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.or_u8x32(self, rhs.simd_into(self.simd))
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> bf16x16<S> {
+        self.simd.combine_bf16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.xor_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_bf16x8(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u8x32<S> {
-        self.simd.shr_u8x32(self, shift)
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_bf16x8(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_bf16x8(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_lt_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_bf16x8(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_le_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_bf16x8(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_ge_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_bf16x8(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_gt_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_bf16x8(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.min_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_bf16x8(self)
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.max_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_bf16x8(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.combine_u8x32(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_bf16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_bf16x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_bf16x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> bf16x8<S> {
+        self.simd.rotate_lanes_left_bf16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> bf16x8<S> {
+        self.simd.rotate_lanes_right_bf16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> bf16x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_bf16x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<u8, S> for u8x32<S> {
-    const N: usize = 32;
-    type Mask = mask8x32<S>;
-    type Block = u8x16<S>;
+impl<S: Simd> crate::SimdBase<bf16, S> for bf16x8<S> {
+    const N: usize = 8;
+    type Mask = mask16x8<S>;
+    type Block = bf16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u8] {
+    fn as_slice(&self) -> &[bf16] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u8] {
+    fn as_mut_slice(&mut self) -> &mut [bf16] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u8]) -> Self {
-        let mut val = [0; 32];
+    fn from_slice(simd: S, slice: &[bf16]) -> Self {
+        let mut val = [bf16::from_bits(0); 8];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: u8) -> Self {
-        simd.splat_u8x32(val)
+    fn splat(simd: S, val: bf16) -> Self {
+        simd.splat_bf16x8(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
-    }
-}
-impl<S: Simd> crate::SimdInt<u8, S> for u8x32<S> {
-    #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_u8x32(self, rhs.simd_into(self.simd))
+        block
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_lt_u8x32(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_bf16x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_le_u8x32(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_bf16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_ge_u8x32(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_bf16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_gt_u8x32(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_bf16x8::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.zip_low_u8x32(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_bf16x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.zip_high_u8x32(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_bf16x8::<K>(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.min_u8x32(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_bf16x8::<K>(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
-        self.simd.max_u8x32(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_bf16x8::<L>(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct mask8x32<S: Simd> {
-    pub val: [i8; 32],
+#[repr(C, align(16))]
+pub struct f32x4<S: Simd> {
+    pub val: [f32; 4],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i8; 32], S> for mask8x32<S> {
+impl<S: Simd> SimdFrom<[f32; 4], S> for f32x4<S> {
     #[inline(always)]
-    fn simd_from(val: [i8; 32], simd: S) -> Self {
+    fn simd_from(val: [f32; 4], simd: S) -> Self {
         Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-            ],
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
             simd,
         }
     }
 }
-impl<S: Simd> From<mask8x32<S>> for [i8; 32] {
+impl<S: Simd> From<f32x4<S>> for [f32; 4] {
     #[inline(always)]
-    fn from(value: mask8x32<S>) -> Self {
+    fn from(value: f32x4<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for mask8x32<S> {
-    type Target = [i8; 32];
+impl<S: Simd> core::ops::Deref for f32x4<S> {
+    type Target = [f32; 4];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for mask8x32<S> {
+impl<S: Simd> core::ops::DerefMut for f32x4<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> Select<mask8x32<S>> for mask8x32<S> {
+impl<S: Simd> SimdFrom<f32, S> for f32x4<S> {
     #[inline(always)]
-    fn select(self, if_true: mask8x32<S>, if_false: mask8x32<S>) -> mask8x32<S> {
-        self.simd.select_mask8x32(self, if_true, if_false)
+    fn simd_from(value: f32, simd: S) -> Self {
+        simd.splat_f32x4(value)
     }
 }
-impl<S: Simd> Bytes for mask8x32<S> {
-    type Bytes = u8x32<S>;
+impl<S: Simd> Select<f32x4<S>> for mask32x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: f32x4<S>, if_false: f32x4<S>) -> f32x4<S> {
+        self.simd.select_f32x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f32x4<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x32 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -2760,649 +3363,535 @@ impl<S: Simd> Bytes for mask8x32<S> {
         }
     }
 }
-impl<S: Simd> mask8x32<S> {
+impl<S: Simd> f32x4<S> {
     #[inline(always)]
-    pub fn not(self) -> mask8x32<S> {
-        self.simd.not_mask8x32(self)
+    pub fn abs(self) -> f32x4<S> {
+        self.simd.abs_f32x4(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.and_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn neg(self) -> f32x4<S> {
+        self.simd.neg_f32x4(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.or_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn sqrt(self) -> f32x4<S> {
+        self.simd.sqrt_f32x4(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.xor_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn recip_approx(self) -> f32x4<S> {
+        self.simd.recip_approx_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn rsqrt_approx(self) -> f32x4<S> {
+        self.simd.rsqrt_approx_f32x4(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.combine_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn recip(self, steps: u32) -> f32x4<S> {
+        self.simd.recip_f32x4(self, steps)
     }
-}
-impl<S: Simd> crate::SimdBase<i8, S> for mask8x32<S> {
-    const N: usize = 32;
-    type Mask = mask8x32<S>;
-    type Block = mask8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
-        &self.val
+    pub fn rsqrt(self, steps: u32) -> f32x4<S> {
+        self.simd.rsqrt_f32x4(self, steps)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
-        &mut self.val
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.add_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 32];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.sub_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_mask8x32(val)
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.mul_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.div_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdMask<i8, S> for mask8x32<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
-        self.simd.simd_eq_mask8x32(self, rhs.simd_into(self.simd))
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.copysign_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct i16x16<S: Simd> {
-    pub val: [i16; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i16; 16], S> for i16x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<i16x16<S>> for [i16; 16] {
     #[inline(always)]
-    fn from(value: i16x16<S>) -> Self {
-        value.val
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ne_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for i16x16<S> {
-    type Target = [i16; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for i16x16<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> SimdFrom<i16, S> for i16x16<S> {
     #[inline(always)]
-    fn simd_from(value: i16, simd: S) -> Self {
-        simd.splat_i16x16(value)
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<i16x16<S>> for mask16x16<S> {
     #[inline(always)]
-    fn select(self, if_true: i16x16<S>, if_false: i16x16<S>) -> i16x16<S> {
-        self.simd.select_i16x16(self, if_true, if_false)
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for i16x16<S> {
-    type Bytes = u8x32<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn is_nan(self) -> mask32x4<S> {
+        self.simd.is_nan_f32x4(self)
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn is_finite(self) -> mask32x4<S> {
+        self.simd.is_finite_f32x4(self)
     }
-}
-impl<S: Simd> i16x16<S> {
     #[inline(always)]
-    pub fn not(self) -> i16x16<S> {
-        self.simd.not_i16x16(self)
+    pub fn is_infinite(self) -> mask32x4<S> {
+        self.simd.is_infinite_f32x4(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.add_i16x16(self, rhs.simd_into(self.simd))
+    pub fn is_subnormal(self) -> mask32x4<S> {
+        self.simd.is_subnormal_f32x4(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.sub_i16x16(self, rhs.simd_into(self.simd))
+    pub fn is_sign_negative(self) -> mask32x4<S> {
+        self.simd.is_sign_negative_f32x4(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.mul_i16x16(self, rhs.simd_into(self.simd))
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.max_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.and_i16x16(self, rhs.simd_into(self.simd))
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.max_precise_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.or_i16x16(self, rhs.simd_into(self.simd))
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.min_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.xor_i16x16(self, rhs.simd_into(self.simd))
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.min_precise_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i16x16<S> {
-        self.simd.shr_i16x16(self, shift)
+    pub fn floor(self) -> f32x4<S> {
+        self.simd.floor_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_i16x16(self, rhs.simd_into(self.simd))
+    pub fn ceil(self) -> f32x4<S> {
+        self.simd.ceil_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_lt_i16x16(self, rhs.simd_into(self.simd))
+    pub fn round(self) -> f32x4<S> {
+        self.simd.round_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_le_i16x16(self, rhs.simd_into(self.simd))
+    pub fn round_ties_even(self) -> f32x4<S> {
+        self.simd.round_ties_even_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_ge_i16x16(self, rhs.simd_into(self.simd))
+    pub fn fract(self) -> f32x4<S> {
+        self.simd.fract_f32x4(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_gt_i16x16(self, rhs.simd_into(self.simd))
+    pub fn trunc(self) -> f32x4<S> {
+        self.simd.trunc_f32x4(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x4(self)
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x4(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.combine_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x4(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x32<S> {
-        self.simd.reinterpret_u8_i16x16(self)
+    pub fn shuffle<I: Indices<4>>(self) -> f32x4<S> {
+        self.simd.shuffle_f32x4::<I>(self)
     }
-}
-impl<S: Simd> crate::SimdBase<i16, S> for i16x16<S> {
-    const N: usize = 16;
-    type Mask = mask16x16<S>;
-    type Block = i16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
-        &self.val
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .shuffle2_f32x4::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
-        &mut self.val
+    pub fn reverse(self) -> f32x4<S> {
+        self.simd.reverse_f32x4(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.unzip_low_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_i16x16(val)
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.unzip_high_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.combine_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdInt<i16, S> for i16x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_i16x16(self, rhs.simd_into(self.simd))
+    pub fn split(self) -> (f32x2<S>, f32x2<S>) {
+        self.simd.split_f32x4(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_lt_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_f32x4(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_le_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_f32x4(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_ge_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_f32x4(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_gt_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_f32x4(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.zip_low_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_f32x4(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.zip_high_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_f32x4(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_f32x4(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
-        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_f32x4(self)
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct u16x16<S: Simd> {
-    pub val: [u16; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[u16; 16], S> for u16x16<S> {
     #[inline(always)]
-    fn simd_from(val: [u16; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_f32x4(self)
     }
-}
-impl<S: Simd> From<u16x16<S>> for [u16; 16] {
     #[inline(always)]
-    fn from(value: u16x16<S>) -> Self {
-        value.val
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_f32x4(self)
     }
-}
-impl<S: Simd> core::ops::Deref for u16x16<S> {
-    type Target = [u16; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_f32x4(self)
     }
-}
-impl<S: Simd> core::ops::DerefMut for u16x16<S> {
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_f32x4(self)
     }
-}
-impl<S: Simd> SimdFrom<u16, S> for u16x16<S> {
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn simd_from(value: u16, simd: S) -> Self {
-        simd.splat_u16x16(value)
+    pub fn cvt_u16(self) -> u16x4<S> {
+        self.simd.cvt_u16_f32x4(self)
     }
-}
-impl<S: Simd> Select<u16x16<S>> for mask16x16<S> {
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn select(self, if_true: u16x16<S>, if_false: u16x16<S>) -> u16x16<S> {
-        self.simd.select_u16x16(self, if_true, if_false)
+    pub fn cvt_u16_round(self) -> u16x4<S> {
+        self.simd.cvt_u16_round_f32x4(self)
     }
-}
-impl<S: Simd> Bytes for u16x16<S> {
-    type Bytes = u8x32<S>;
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn cvt_i32(self) -> i32x4<S> {
+        self.simd.cvt_i32_f32x4(self)
     }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn cvt_i32_round(self) -> i32x4<S> {
+        self.simd.cvt_i32_round_f32x4(self)
     }
-}
-impl<S: Simd> u16x16<S> {
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn not(self) -> u16x16<S> {
-        self.simd.not_u16x16(self)
+    pub fn cvt_u32(self) -> u32x4<S> {
+        self.simd.cvt_u32_f32x4(self)
     }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.add_u16x16(self, rhs.simd_into(self.simd))
+    pub fn cvt_u32_round(self) -> u32x4<S> {
+        self.simd.cvt_u32_round_f32x4(self)
     }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.sub_u16x16(self, rhs.simd_into(self.simd))
+    pub fn cvt_i64(self) -> i64x4<S> {
+        self.simd.cvt_i64_f32x4(self)
     }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.mul_u16x16(self, rhs.simd_into(self.simd))
+    pub fn cvt_i64_round(self) -> i64x4<S> {
+        self.simd.cvt_i64_round_f32x4(self)
     }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.and_u16x16(self, rhs.simd_into(self.simd))
+    pub fn cvt_u64(self) -> u64x4<S> {
+        self.simd.cvt_u64_f32x4(self)
     }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.or_u16x16(self, rhs.simd_into(self.simd))
+    pub fn cvt_u64_round(self) -> u64x4<S> {
+        self.simd.cvt_u64_round_f32x4(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.xor_u16x16(self, rhs.simd_into(self.simd))
+    pub fn dot_bf16(self, a: bf16x8<S>, b: bf16x8<S>) -> f32x4<S> {
+        self.simd.dot_bf16_f32x4(self, a, b)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u16x16<S> {
-        self.simd.shr_u16x16(self, shift)
+    pub fn mmla_bf16(self, a: bf16x8<S>, b: bf16x8<S>) -> f32x4<S> {
+        self.simd.mmla_bf16_f32x4(self, a, b)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_u16x16(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> f32x4<S> {
+        self.simd.rotate_lanes_left_f32x4::<K>(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_lt_u16x16(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_right<const K: usize>(self) -> f32x4<S> {
+        self.simd.rotate_lanes_right_f32x4::<K>(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_le_u16x16(self, rhs.simd_into(self.simd))
+    pub fn broadcast_lane<const L: usize>(self) -> f32x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_f32x4::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdBase<f32, S> for f32x4<S> {
+    const N: usize = 4;
+    type Mask = mask32x4<S>;
+    type Block = f32x4<S>;
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_ge_u16x16(self, rhs.simd_into(self.simd))
+    fn as_slice(&self) -> &[f32] {
+        &self.val
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_gt_u16x16(self, rhs.simd_into(self.simd))
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.val
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.min_u16x16(self, rhs.simd_into(self.simd))
+    fn from_slice(simd: S, slice: &[f32]) -> Self {
+        let mut val = [0.0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.max_u16x16(self, rhs.simd_into(self.simd))
+    fn splat(simd: S, val: f32) -> Self {
+        simd.splat_f32x4(val)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.combine_u16x16(self, rhs.simd_into(self.simd))
+    fn block_splat(block: Self::Block) -> Self {
+        block
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x32<S> {
-        self.simd.reinterpret_u8_u16x16(self)
+    fn reverse(self) -> Self {
+        self.simd.reverse_f32x4(self)
     }
-}
-impl<S: Simd> crate::SimdBase<u16, S> for u16x16<S> {
-    const N: usize = 16;
-    type Mask = mask16x16<S>;
-    type Block = u16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u16] {
-        &self.val
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u16] {
-        &mut self.val
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u16]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f32x4::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: u16) -> Self {
-        simd.splat_u16x16(val)
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f32x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_f32x4::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdInt<u16, S> for u16x16<S> {
+impl<S: Simd> crate::SimdFloat<f32, S> for f32x4<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_u16x16(self, rhs.simd_into(self.simd))
+    fn abs(self) -> f32x4<S> {
+        self.simd.abs_f32x4(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_lt_u16x16(self, rhs.simd_into(self.simd))
+    fn sqrt(self) -> f32x4<S> {
+        self.simd.sqrt_f32x4(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_le_u16x16(self, rhs.simd_into(self.simd))
+    fn recip_approx(self) -> f32x4<S> {
+        self.simd.recip_approx_f32x4(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_ge_u16x16(self, rhs.simd_into(self.simd))
+    fn rsqrt_approx(self) -> f32x4<S> {
+        self.simd.rsqrt_approx_f32x4(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_gt_u16x16(self, rhs.simd_into(self.simd))
+    fn recip(self, steps: u32) -> f32x4<S> {
+        self.simd.recip_f32x4(self, steps)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.zip_low_u16x16(self, rhs.simd_into(self.simd))
+    fn rsqrt(self, steps: u32) -> f32x4<S> {
+        self.simd.rsqrt_f32x4(self, steps)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.zip_high_u16x16(self, rhs.simd_into(self.simd))
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.copysign_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.min_u16x16(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
-        self.simd.max_u16x16(self, rhs.simd_into(self.simd))
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ne_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct mask16x16<S: Simd> {
-    pub val: [i16; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i16; 16], S> for mask16x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<mask16x16<S>> for [i16; 16] {
     #[inline(always)]
-    fn from(value: mask16x16<S>) -> Self {
-        value.val
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for mask16x16<S> {
-    type Target = [i16; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for mask16x16<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<mask16x16<S>> for mask16x16<S> {
     #[inline(always)]
-    fn select(self, if_true: mask16x16<S>, if_false: mask16x16<S>) -> mask16x16<S> {
-        self.simd.select_mask16x16(self, if_true, if_false)
+    fn is_nan(self) -> mask32x4<S> {
+        self.simd.is_nan_f32x4(self)
     }
-}
-impl<S: Simd> Bytes for mask16x16<S> {
-    type Bytes = u8x32<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    fn is_finite(self) -> mask32x4<S> {
+        self.simd.is_finite_f32x4(self)
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    fn is_infinite(self) -> mask32x4<S> {
+        self.simd.is_infinite_f32x4(self)
     }
-}
-impl<S: Simd> mask16x16<S> {
     #[inline(always)]
-    pub fn not(self) -> mask16x16<S> {
-        self.simd.not_mask16x16(self)
+    fn is_subnormal(self) -> mask32x4<S> {
+        self.simd.is_subnormal_f32x4(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.and_mask16x16(self, rhs.simd_into(self.simd))
+    fn is_sign_negative(self) -> mask32x4<S> {
+        self.simd.is_sign_negative_f32x4(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.or_mask16x16(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.zip_low_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.xor_mask16x16(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.zip_high_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_mask16x16(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.max_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.combine_mask16x16(self, rhs.simd_into(self.simd))
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.max_precise_f32x4(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i16, S> for mask16x16<S> {
-    const N: usize = 16;
-    type Mask = mask16x16<S>;
-    type Block = mask16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
-        &self.val
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.min_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
-        &mut self.val
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd.min_precise_f32x4(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .madd_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_mask16x16(val)
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .msub_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .nmadd_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdMask<i16, S> for mask16x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
-        self.simd.simd_eq_mask16x16(self, rhs.simd_into(self.simd))
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .nmsub_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .mul_add_fused_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x4<S> {
+        self.simd
+            .mul_add_fast_f32x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f32x4<S> {
+        self.simd.floor_f32x4(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f32x4<S> {
+        self.simd.ceil_f32x4(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f32x4<S> {
+        self.simd.round_f32x4(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f32x4<S> {
+        self.simd.round_ties_even_f32x4(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f32x4<S> {
+        self.simd.fract_f32x4(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f32x4<S> {
+        self.simd.trunc_f32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x4(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct i32x8<S: Simd> {
-    pub val: [i32; 8],
+#[repr(C, align(16))]
+pub struct i8x16<S: Simd> {
+    pub val: [i8; 16],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i32; 8], S> for i32x8<S> {
+impl<S: Simd> SimdFrom<[i8; 16], S> for i8x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 8], simd: S) -> Self {
+    fn simd_from(val: [i8; 16], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -3413,48 +3902,56 @@ impl<S: Simd> SimdFrom<[i32; 8], S> for i32x8<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<i32x8<S>> for [i32; 8] {
+impl<S: Simd> From<i8x16<S>> for [i8; 16] {
     #[inline(always)]
-    fn from(value: i32x8<S>) -> Self {
+    fn from(value: i8x16<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for i32x8<S> {
-    type Target = [i32; 8];
+impl<S: Simd> core::ops::Deref for i8x16<S> {
+    type Target = [i8; 16];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for i32x8<S> {
+impl<S: Simd> core::ops::DerefMut for i8x16<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<i32, S> for i32x8<S> {
+impl<S: Simd> SimdFrom<i8, S> for i8x16<S> {
     #[inline(always)]
-    fn simd_from(value: i32, simd: S) -> Self {
-        simd.splat_i32x8(value)
+    fn simd_from(value: i8, simd: S) -> Self {
+        simd.splat_i8x16(value)
     }
 }
-impl<S: Simd> Select<i32x8<S>> for mask32x8<S> {
+impl<S: Simd> Select<i8x16<S>> for mask8x16<S> {
     #[inline(always)]
-    fn select(self, if_true: i32x8<S>, if_false: i32x8<S>) -> i32x8<S> {
-        self.simd.select_i32x8(self, if_true, if_false)
+    fn select(self, if_true: i8x16<S>, if_false: i8x16<S>) -> i8x16<S> {
+        self.simd.select_i8x16(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for i32x8<S> {
-    type Bytes = u8x32<S>;
+impl<S: Simd> Bytes for i8x16<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x32 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -3470,498 +3967,370 @@ impl<S: Simd> Bytes for i32x8<S> {
         }
     }
 }
-impl<S: Simd> i32x8<S> {
+impl<S: Simd> i8x16<S> {
     #[inline(always)]
-    pub fn not(self) -> i32x8<S> {
-        self.simd.not_i32x8(self)
+    pub fn not(self) -> i8x16<S> {
+        self.simd.not_i8x16(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.add_i32x8(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.add_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.sub_i32x8(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.sub_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.mul_i32x8(self, rhs.simd_into(self.simd))
-    }
-    #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.and_i32x8(self, rhs.simd_into(self.simd))
-    }
-    #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.or_i32x8(self, rhs.simd_into(self.simd))
-    }
-    #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.xor_i32x8(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.mul_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i32x8<S> {
-        self.simd.shr_i32x8(self, shift)
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.and_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_i32x8(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.or_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_i32x8(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.xor_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shl(self, shift: u32) -> i8x16<S> {
+        self.simd.shl_i8x16(self, shift)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> i8x16<S> {
+        self.simd.shr_i8x16(self, shift)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.shl_var_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.min_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.shr_var_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.max_i32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.combine_i32x8(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_lt_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x32<S> {
-        self.simd.reinterpret_u8_i32x8(self)
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_le_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x8<S> {
-        self.simd.cvt_f32_i32x8(self)
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_ge_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i32, S> for i32x8<S> {
-    const N: usize = 8;
-    type Mask = mask32x8<S>;
-    type Block = i32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i32] {
-        &self.val
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_gt_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i32] {
-        &mut self.val
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.min_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i32]) -> Self {
-        let mut val = [0; 8];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.max_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i32) -> Self {
-        simd.splat_i32x8(val)
+    pub fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x16(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    pub fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x16(self)
     }
-}
-impl<S: Simd> crate::SimdInt<i32, S> for i32x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_i32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x16(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_i32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x16(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_i32x8(self, rhs.simd_into(self.simd))
+    pub fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x16(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<16>>(self) -> i8x16<S> {
+        self.simd.shuffle_i8x16::<I>(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_i32x8(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd
+            .shuffle2_i8x16::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.zip_low_i32x8(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> i8x16<S> {
+        self.simd.reverse_i8x16(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.zip_high_i32x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.unzip_low_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.min_i32x8(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.unzip_high_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
-        self.simd.max_i32x8(self, rhs.simd_into(self.simd))
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd
+            .saturating_add_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct u32x8<S: Simd> {
-    pub val: [u32; 8],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[u32; 8], S> for u32x8<S> {
     #[inline(always)]
-    fn simd_from(val: [u32; 8], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-            ],
-            simd,
-        }
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd
+            .saturating_sub_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<u32x8<S>> for [u32; 8] {
     #[inline(always)]
-    fn from(value: u32x8<S>) -> Self {
-        value.val
+    pub fn abs(self) -> i8x16<S> {
+        self.simd.abs_i8x16(self)
     }
-}
-impl<S: Simd> core::ops::Deref for u32x8<S> {
-    type Target = [u32; 8];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn neg(self) -> i8x16<S> {
+        self.simd.neg_i8x16(self)
     }
-}
-impl<S: Simd> core::ops::DerefMut for u32x8<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.abs_diff_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> SimdFrom<u32, S> for u32x8<S> {
     #[inline(always)]
-    fn simd_from(value: u32, simd: S) -> Self {
-        simd.splat_u32x8(value)
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.mul_widen_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<u32x8<S>> for mask32x8<S> {
     #[inline(always)]
-    fn select(self, if_true: u32x8<S>, if_false: u32x8<S>) -> u32x8<S> {
-        self.simd.select_u32x8(self, if_true, if_false)
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.mul_high_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for u32x8<S> {
-    type Bytes = u8x32<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.combine_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn widen(self) -> i16x16<S> {
+        self.simd.widen_i8x16(self)
     }
-}
-impl<S: Simd> u32x8<S> {
     #[inline(always)]
-    pub fn not(self) -> u32x8<S> {
-        self.simd.not_u32x8(self)
+    pub fn widen_low(self) -> i16x8<S> {
+        self.simd.widen_low_i8x16(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.add_u32x8(self, rhs.simd_into(self.simd))
+    pub fn widen_high(self) -> i16x8<S> {
+        self.simd.widen_high_i8x16(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.sub_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_i8x16(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.mul_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_i8x16(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.and_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_i8x16(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.or_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_i8x16(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.xor_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_i8x16(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u32x8<S> {
-        self.simd.shr_u32x8(self, shift)
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_i8x16(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_i8x16(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_i8x16(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_i8x16(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_i8x16(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_u32x8(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_i8x16(self)
     }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.min_u32x8(self, rhs.simd_into(self.simd))
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_i8x16(self)
     }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.max_u32x8(self, rhs.simd_into(self.simd))
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_i8x16(self)
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.combine_u32x8(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> i8x16<S> {
+        self.simd.rotate_lanes_left_i8x16::<K>(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x32<S> {
-        self.simd.reinterpret_u8_u32x8(self)
+    pub fn rotate_lanes_right<const K: usize>(self) -> i8x16<S> {
+        self.simd.rotate_lanes_right_i8x16::<K>(self)
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x8<S> {
-        self.simd.cvt_f32_u32x8(self)
+    pub fn broadcast_lane<const L: usize>(self) -> i8x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i8x16::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<u32, S> for u32x8<S> {
-    const N: usize = 8;
-    type Mask = mask32x8<S>;
-    type Block = u32x4<S>;
+impl<S: Simd> crate::SimdBase<i8, S> for i8x16<S> {
+    const N: usize = 16;
+    type Mask = mask8x16<S>;
+    type Block = i8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u32] {
+    fn as_slice(&self) -> &[i8] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u32] {
+    fn as_mut_slice(&mut self) -> &mut [i8] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u32]) -> Self {
-        let mut val = [0; 8];
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 16];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: u32) -> Self {
-        simd.splat_u32x8(val)
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_i8x16(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+        block
     }
-}
-impl<S: Simd> crate::SimdInt<u32, S> for u32x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_u32x8(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_i8x16(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_lt_u32x8(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_le_u32x8(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_ge_u32x8(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i8x16::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_gt_u32x8(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i8x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.zip_low_u32x8(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i8x16::<K>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.zip_high_u32x8(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i8x16::<K>(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.min_u32x8(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i8x16::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdInt<i8, S> for i8x16<S> {
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
-        self.simd.max_u32x8(self, rhs.simd_into(self.simd))
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.shl_var_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(32))]
-pub struct mask32x8<S: Simd> {
-    pub val: [i32; 8],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i32; 8], S> for mask32x8<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 8], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-            ],
-            simd,
-        }
-    }
-}
-impl<S: Simd> From<mask32x8<S>> for [i32; 8] {
-    #[inline(always)]
-    fn from(value: mask32x8<S>) -> Self {
-        value.val
-    }
-}
-impl<S: Simd> core::ops::Deref for mask32x8<S> {
-    type Target = [i32; 8];
-    #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
-    }
-}
-impl<S: Simd> core::ops::DerefMut for mask32x8<S> {
-    #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
-    }
-}
-impl<S: Simd> Select<mask32x8<S>> for mask32x8<S> {
-    #[inline(always)]
-    fn select(self, if_true: mask32x8<S>, if_false: mask32x8<S>) -> mask32x8<S> {
-        self.simd.select_mask32x8(self, if_true, if_false)
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.shr_var_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for mask32x8<S> {
-    type Bytes = u8x32<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x32 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_lt_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> mask32x8<S> {
     #[inline(always)]
-    pub fn not(self) -> mask32x8<S> {
-        self.simd.not_mask32x8(self)
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_le_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.and_mask32x8(self, rhs.simd_into(self.simd))
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_ge_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.or_mask32x8(self, rhs.simd_into(self.simd))
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_gt_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.xor_mask32x8(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.zip_low_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_mask32x8(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.zip_high_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.combine_mask32x8(self, rhs.simd_into(self.simd))
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.min_i8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i32, S> for mask32x8<S> {
-    const N: usize = 8;
-    type Mask = mask32x8<S>;
-    type Block = mask32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i32] {
-        &self.val
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x16<S> {
+        self.simd.max_i8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i32] {
-        &mut self.val
+    fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x16(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i32]) -> Self {
-        let mut val = [0; 8];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x16(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: i32) -> Self {
-        simd.splat_mask32x8(val)
+    fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x16(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        block.combine(block)
+    fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x16(self)
     }
-}
-impl<S: Simd> crate::SimdMask<i32, S> for mask32x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
-        self.simd.simd_eq_mask32x8(self, rhs.simd_into(self.simd))
+    fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x16(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct f32x16<S: Simd> {
-    pub val: [f32; 16],
+#[repr(C, align(16))]
+pub struct u8x16<S: Simd> {
+    pub val: [u8; 16],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[f32; 16], S> for f32x16<S> {
+impl<S: Simd> SimdFrom<[u8; 16], S> for u8x16<S> {
     #[inline(always)]
-    fn simd_from(val: [f32; 16], simd: S) -> Self {
+    fn simd_from(val: [u8; 16], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -3985,43 +4354,43 @@ impl<S: Simd> SimdFrom<[f32; 16], S> for f32x16<S> {
         }
     }
 }
-impl<S: Simd> From<f32x16<S>> for [f32; 16] {
+impl<S: Simd> From<u8x16<S>> for [u8; 16] {
     #[inline(always)]
-    fn from(value: f32x16<S>) -> Self {
+    fn from(value: u8x16<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for f32x16<S> {
-    type Target = [f32; 16];
+impl<S: Simd> core::ops::Deref for u8x16<S> {
+    type Target = [u8; 16];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for f32x16<S> {
+impl<S: Simd> core::ops::DerefMut for u8x16<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<f32, S> for f32x16<S> {
+impl<S: Simd> SimdFrom<u8, S> for u8x16<S> {
     #[inline(always)]
-    fn simd_from(value: f32, simd: S) -> Self {
-        simd.splat_f32x16(value)
+    fn simd_from(value: u8, simd: S) -> Self {
+        simd.splat_u8x16(value)
     }
 }
-impl<S: Simd> Select<f32x16<S>> for mask32x16<S> {
+impl<S: Simd> Select<u8x16<S>> for mask8x16<S> {
     #[inline(always)]
-    fn select(self, if_true: f32x16<S>, if_false: f32x16<S>) -> f32x16<S> {
-        self.simd.select_f32x16(self, if_true, if_false)
+    fn select(self, if_true: u8x16<S>, if_false: u8x16<S>) -> u8x16<S> {
+        self.simd.select_u8x16(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for f32x16<S> {
-    type Bytes = u8x64<S>;
+impl<S: Simd> Bytes for u8x16<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x64 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -4037,477 +4406,396 @@ impl<S: Simd> Bytes for f32x16<S> {
         }
     }
 }
-impl<S: Simd> f32x16<S> {
+impl<S: Simd> u8x16<S> {
     #[inline(always)]
-    pub fn abs(self) -> f32x16<S> {
-        self.simd.abs_f32x16(self)
+    pub fn not(self) -> u8x16<S> {
+        self.simd.not_u8x16(self)
     }
     #[inline(always)]
-    pub fn neg(self) -> f32x16<S> {
-        self.simd.neg_f32x16(self)
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.add_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sqrt(self) -> f32x16<S> {
-        self.simd.sqrt_f32x16(self)
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.sub_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.add_f32x16(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.mul_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.sub_f32x16(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.and_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.mul_f32x16(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.or_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.div_f32x16(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.xor_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.copysign_f32x16(self, rhs.simd_into(self.simd))
+    pub fn shl(self, shift: u32) -> u8x16<S> {
+        self.simd.shl_u8x16(self, shift)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_f32x16(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> u8x16<S> {
+        self.simd.shr_u8x16(self, shift)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_f32x16(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.shl_var_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_f32x16(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.shr_var_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_f32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_f32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_lt_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.max_f32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_le_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.max_precise_f32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_ge_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.min_f32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_gt_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.min_precise_f32x16(self, rhs.simd_into(self.simd))
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.min_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn floor(self) -> f32x16<S> {
-        self.simd.floor_f32x16(self)
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.max_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn fract(self) -> f32x16<S> {
-        self.simd.fract_f32x16(self)
+    pub fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x16(self)
     }
     #[inline(always)]
-    pub fn trunc(self) -> f32x16<S> {
-        self.simd.trunc_f32x16(self)
+    pub fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x16(self)
     }
     #[inline(always)]
-    pub fn cvt_u32(self) -> u32x16<S> {
-        self.simd.cvt_u32_f32x16(self)
+    pub fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x16(self)
     }
     #[inline(always)]
-    pub fn cvt_i32(self) -> i32x16<S> {
-        self.simd.cvt_i32_f32x16(self)
+    pub fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x16(self)
     }
-}
-impl<S: Simd> crate::SimdBase<f32, S> for f32x16<S> {
-    const N: usize = 16;
-    type Mask = mask32x16<S>;
-    type Block = f32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[f32] {
-        &self.val
+    pub fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x16(self)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [f32] {
-        &mut self.val
+    pub fn shuffle<I: Indices<16>>(self) -> u8x16<S> {
+        self.simd.shuffle_u8x16::<I>(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[f32]) -> Self {
-        let mut val = [0.0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd
+            .shuffle2_u8x16::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: f32) -> Self {
-        simd.splat_f32x16(val)
+    pub fn reverse(self) -> u8x16<S> {
+        self.simd.reverse_u8x16(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.unzip_low_u8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdFloat<f32, S> for f32x16<S> {
     #[inline(always)]
-    fn abs(self) -> f32x16<S> {
-        self.simd.abs_f32x16(self)
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.unzip_high_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn sqrt(self) -> f32x16<S> {
-        self.simd.sqrt_f32x16(self)
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd
+            .saturating_add_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.copysign_f32x16(self, rhs.simd_into(self.simd))
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd
+            .saturating_sub_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_f32x16(self, rhs.simd_into(self.simd))
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.abs_diff_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_f32x16(self, rhs.simd_into(self.simd))
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.avg_round_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_f32x16(self, rhs.simd_into(self.simd))
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.mul_widen_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_f32x16(self, rhs.simd_into(self.simd))
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.mul_high_u8x16(self, rhs.simd_into(self.simd))
     }
+    #[doc = r" Look up each byte of `idx` in `self`, with zero for indices"]
+    #[doc = r" past the end."]
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_f32x16(self, rhs.simd_into(self.simd))
+    pub fn swizzle_dyn(self, idx: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.swizzle_dyn_u8x16(self, idx.simd_into(self.simd))
     }
+    #[doc = " Look up each byte of `self` in a 32 byte table, with zero for indices past the end."]
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.zip_low_f32x16(self, rhs.simd_into(self.simd))
+    pub fn lookup_32(self, table: u8x32<S>) -> u8x16<S> {
+        self.simd.lookup_32_u8x16(table, self)
     }
+    #[doc = " Look up each byte of `self` in a 48 byte table of three vectors, with zero for indices past the end."]
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.zip_high_f32x16(self, rhs.simd_into(self.simd))
+    pub fn lookup_48(self, table: [u8x16<S>; 3]) -> u8x16<S> {
+        self.simd.lookup_48_u8x16(table, self)
     }
+    #[doc = " Look up each byte of `self` in a 64 byte table, with zero for indices past the end."]
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.max_f32x16(self, rhs.simd_into(self.simd))
+    pub fn lookup_64(self, table: u8x64<S>) -> u8x16<S> {
+        self.simd.lookup_64_u8x16(table, self)
     }
     #[inline(always)]
-    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.max_precise_f32x16(self, rhs.simd_into(self.simd))
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.combine_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.min_f32x16(self, rhs.simd_into(self.simd))
+    pub fn split(self) -> (u8x8<S>, u8x8<S>) {
+        self.simd.split_u8x16(self)
     }
     #[inline(always)]
-    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd.min_precise_f32x16(self, rhs.simd_into(self.simd))
+    pub fn widen(self) -> u16x16<S> {
+        self.simd.widen_u8x16(self)
     }
     #[inline(always)]
-    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd
-            .madd_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn widen_low(self) -> u16x8<S> {
+        self.simd.widen_low_u8x16(self)
     }
     #[inline(always)]
-    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
-        self.simd
-            .msub_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    pub fn widen_high(self) -> u16x8<S> {
+        self.simd.widen_high_u8x16(self)
     }
     #[inline(always)]
-    fn floor(self) -> f32x16<S> {
-        self.simd.floor_f32x16(self)
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_u8x16(self)
     }
     #[inline(always)]
-    fn fract(self) -> f32x16<S> {
-        self.simd.fract_f32x16(self)
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_u8x16(self)
     }
     #[inline(always)]
-    fn trunc(self) -> f32x16<S> {
-        self.simd.trunc_f32x16(self)
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_u8x16(self)
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct i8x64<S: Simd> {
-    pub val: [i8; 64],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i8; 64], S> for i8x64<S> {
     #[inline(always)]
-    fn simd_from(val: [i8; 64], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-                val[32usize],
-                val[33usize],
-                val[34usize],
-                val[35usize],
-                val[36usize],
-                val[37usize],
-                val[38usize],
-                val[39usize],
-                val[40usize],
-                val[41usize],
-                val[42usize],
-                val[43usize],
-                val[44usize],
-                val[45usize],
-                val[46usize],
-                val[47usize],
-                val[48usize],
-                val[49usize],
-                val[50usize],
-                val[51usize],
-                val[52usize],
-                val[53usize],
-                val[54usize],
-                val[55usize],
-                val[56usize],
-                val[57usize],
-                val[58usize],
-                val[59usize],
-                val[60usize],
-                val[61usize],
-                val[62usize],
-                val[63usize],
-            ],
-            simd,
-        }
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_u8x16(self)
     }
-}
-impl<S: Simd> From<i8x64<S>> for [i8; 64] {
     #[inline(always)]
-    fn from(value: i8x64<S>) -> Self {
-        value.val
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_u8x16(self)
     }
-}
-impl<S: Simd> core::ops::Deref for i8x64<S> {
-    type Target = [i8; 64];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_u8x16(self)
     }
-}
-impl<S: Simd> core::ops::DerefMut for i8x64<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_u8x16(self)
     }
-}
-impl<S: Simd> SimdFrom<i8, S> for i8x64<S> {
     #[inline(always)]
-    fn simd_from(value: i8, simd: S) -> Self {
-        simd.splat_i8x64(value)
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_u8x16(self)
     }
-}
-impl<S: Simd> Select<i8x64<S>> for mask8x64<S> {
     #[inline(always)]
-    fn select(self, if_true: i8x64<S>, if_false: i8x64<S>) -> i8x64<S> {
-        self.simd.select_i8x64(self, if_true, if_false)
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_u8x16(self)
     }
-}
-impl<S: Simd> Bytes for i8x64<S> {
-    type Bytes = u8x64<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x64 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_u8x16(self)
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_u8x16(self)
     }
-}
-impl<S: Simd> i8x64<S> {
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn not(self) -> i8x64<S> {
-        self.simd.not_i8x64(self)
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_u8x16(self)
     }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.add_i8x64(self, rhs.simd_into(self.simd))
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_u8x16(self)
     }
+    #[doc = " Convert each lane to `f32`, mapping `0..=255` to `0.0..=1.0` like `x as f32 / 255.0`."]
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.sub_i8x64(self, rhs.simd_into(self.simd))
+    pub fn cvt_f32_unorm(self) -> f32x16<S> {
+        self.simd.cvt_f32_unorm_u8x16(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.mul_i8x64(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> u8x16<S> {
+        self.simd.rotate_lanes_left_u8x16::<K>(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.and_i8x64(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_right<const K: usize>(self) -> u8x16<S> {
+        self.simd.rotate_lanes_right_u8x16::<K>(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.or_i8x64(self, rhs.simd_into(self.simd))
+    pub fn broadcast_lane<const L: usize>(self) -> u8x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u8x16::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdBase<u8, S> for u8x16<S> {
+    const N: usize = 16;
+    type Mask = mask8x16<S>;
+    type Block = u8x16<S>;
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.xor_i8x64(self, rhs.simd_into(self.simd))
+    fn as_slice(&self) -> &[u8] {
+        &self.val
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i8x64<S> {
-        self.simd.shr_i8x64(self, shift)
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.val
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_i8x64(self, rhs.simd_into(self.simd))
+    fn from_slice(simd: S, slice: &[u8]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_lt_i8x64(self, rhs.simd_into(self.simd))
+    fn splat(simd: S, val: u8) -> Self {
+        simd.splat_u8x16(val)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_le_i8x64(self, rhs.simd_into(self.simd))
+    fn block_splat(block: Self::Block) -> Self {
+        block
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_ge_i8x64(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_u8x16(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_gt_i8x64(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.min_i8x64(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.max_i8x64(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u8x16::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x64<S> {
-        self.simd.reinterpret_u8_i8x64(self)
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u8x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i8, S> for i8x64<S> {
-    const N: usize = 64;
-    type Mask = mask8x64<S>;
-    type Block = i8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
-        &self.val
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u8x16::<K>(self)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
-        &mut self.val
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u8x16::<K>(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 64];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u8x16::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdInt<u8, S> for u8x16<S> {
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_i8x64(val)
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.shl_var_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.shr_var_u8x16(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdInt<i8, S> for i8x64<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_i8x64(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_lt_i8x64(self, rhs.simd_into(self.simd))
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_lt_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_le_i8x64(self, rhs.simd_into(self.simd))
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_le_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_ge_i8x64(self, rhs.simd_into(self.simd))
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_ge_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_gt_i8x64(self, rhs.simd_into(self.simd))
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_gt_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.zip_low_i8x64(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.zip_low_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.zip_high_i8x64(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.zip_high_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.min_i8x64(self, rhs.simd_into(self.simd))
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.min_u8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
-        self.simd.max_i8x64(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x16<S> {
+        self.simd.max_u8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x16(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x16(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x16(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct u8x64<S: Simd> {
-    pub val: [u8; 64],
+#[repr(C, align(16))]
+pub struct mask8x16<S: Simd> {
+    pub val: [i8; 16],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u8; 64], S> for u8x64<S> {
+impl<S: Simd> SimdFrom<[i8; 16], S> for mask8x16<S> {
     #[inline(always)]
-    fn simd_from(val: [u8; 64], simd: S) -> Self {
+    fn simd_from(val: [i8; 16], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -4526,96 +4814,42 @@ impl<S: Simd> SimdFrom<[u8; 64], S> for u8x64<S> {
                 val[13usize],
                 val[14usize],
                 val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-                val[32usize],
-                val[33usize],
-                val[34usize],
-                val[35usize],
-                val[36usize],
-                val[37usize],
-                val[38usize],
-                val[39usize],
-                val[40usize],
-                val[41usize],
-                val[42usize],
-                val[43usize],
-                val[44usize],
-                val[45usize],
-                val[46usize],
-                val[47usize],
-                val[48usize],
-                val[49usize],
-                val[50usize],
-                val[51usize],
-                val[52usize],
-                val[53usize],
-                val[54usize],
-                val[55usize],
-                val[56usize],
-                val[57usize],
-                val[58usize],
-                val[59usize],
-                val[60usize],
-                val[61usize],
-                val[62usize],
-                val[63usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<u8x64<S>> for [u8; 64] {
+impl<S: Simd> From<mask8x16<S>> for [i8; 16] {
     #[inline(always)]
-    fn from(value: u8x64<S>) -> Self {
+    fn from(value: mask8x16<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u8x64<S> {
-    type Target = [u8; 64];
+impl<S: Simd> core::ops::Deref for mask8x16<S> {
+    type Target = [i8; 16];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u8x64<S> {
+impl<S: Simd> core::ops::DerefMut for mask8x16<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u8, S> for u8x64<S> {
-    #[inline(always)]
-    fn simd_from(value: u8, simd: S) -> Self {
-        simd.splat_u8x64(value)
-    }
-}
-impl<S: Simd> Select<u8x64<S>> for mask8x64<S> {
+impl<S: Simd> Select<mask8x16<S>> for mask8x16<S> {
     #[inline(always)]
-    fn select(self, if_true: u8x64<S>, if_false: u8x64<S>) -> u8x64<S> {
-        self.simd.select_u8x64(self, if_true, if_false)
+    fn select(self, if_true: mask8x16<S>, if_false: mask8x16<S>) -> mask8x16<S> {
+        self.simd.select_mask8x16(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u8x64<S> {
-    type Bytes = u8x64<S>;
+impl<S: Simd> Bytes for mask8x16<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x64 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -4631,143 +4865,236 @@ impl<S: Simd> Bytes for u8x64<S> {
         }
     }
 }
-impl<S: Simd> u8x64<S> {
+impl<S: Simd> mask8x16<S> {
     #[inline(always)]
-    pub fn not(self) -> u8x64<S> {
-        self.simd.not_u8x64(self)
+    pub fn not(self) -> mask8x16<S> {
+        self.simd.not_mask8x16(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.add_u8x64(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.and_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.sub_u8x64(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.or_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.mul_u8x64(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.xor_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.and_u8x64(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.or_u8x64(self, rhs.simd_into(self.simd))
+    pub fn any(self) -> bool {
+        self.simd.any_mask8x16(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.xor_u8x64(self, rhs.simd_into(self.simd))
+    pub fn all(self) -> bool {
+        self.simd.all_mask8x16(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u8x64<S> {
-        self.simd.shr_u8x64(self, shift)
+    pub fn none(self) -> bool {
+        self.simd.none_mask8x16(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_u8x64(self, rhs.simd_into(self.simd))
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask8x16(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_lt_u8x64(self, rhs.simd_into(self.simd))
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x16(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_le_u8x64(self, rhs.simd_into(self.simd))
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x16(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_ge_u8x64(self, rhs.simd_into(self.simd))
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x16(bits)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_gt_u8x64(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<16>>(self) -> mask8x16<S> {
+        self.simd.shuffle_mask8x16::<I>(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.min_u8x64(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd
+            .shuffle2_mask8x16::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.max_u8x64(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> mask8x16<S> {
+        self.simd.reverse_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.unzip_low_mask8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd
+            .unzip_high_mask8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.combine_mask8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask8x8<S>, mask8x8<S>) {
+        self.simd.split_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_mask8x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask8x16<S> {
+        self.simd.rotate_lanes_left_mask8x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask8x16<S> {
+        self.simd.rotate_lanes_right_mask8x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask8x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x16::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<u8, S> for u8x64<S> {
-    const N: usize = 64;
-    type Mask = mask8x64<S>;
-    type Block = u8x16<S>;
+impl<S: Simd> crate::SimdBase<i8, S> for mask8x16<S> {
+    const N: usize = 16;
+    type Mask = mask8x16<S>;
+    type Block = mask8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u8] {
+    fn as_slice(&self) -> &[i8] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u8] {
+    fn as_mut_slice(&mut self) -> &mut [i8] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u8]) -> Self {
-        let mut val = [0; 64];
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 16];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: u8) -> Self {
-        simd.splat_u8x64(val)
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_mask8x16(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+        block
     }
-}
-impl<S: Simd> crate::SimdInt<u8, S> for u8x64<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_u8x64(self, rhs.simd_into(self.simd))
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask8x16(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_lt_u8x64(self, rhs.simd_into(self.simd))
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_le_u8x64(self, rhs.simd_into(self.simd))
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask8x16(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_ge_u8x64(self, rhs.simd_into(self.simd))
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask8x16::<SameLanes<I, M>>(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_gt_u8x64(self, rhs.simd_into(self.simd))
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask8x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.zip_low_u8x64(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask8x16::<K>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.zip_high_u8x64(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask8x16::<K>(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.min_u8x64(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x16::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdMask<i8, S> for mask8x16<S> {
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
-        self.simd.max_u8x64(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x16<S> {
+        self.simd.simd_eq_mask8x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask8x16(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask8x16(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask8x16(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask8x16(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x16(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x16(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x16(bits)
     }
 }
 #[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct mask8x64<S: Simd> {
-    pub val: [i8; 64],
+#[repr(C, align(16))]
+pub struct i16x8<S: Simd> {
+    pub val: [i16; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i8; 64], S> for mask8x64<S> {
+impl<S: Simd> SimdFrom<[i16; 8], S> for i16x8<S> {
     #[inline(always)]
-    fn simd_from(val: [i8; 64], simd: S) -> Self {
+    fn simd_from(val: [i16; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -4778,98 +5105,48 @@ impl<S: Simd> SimdFrom<[i8; 64], S> for mask8x64<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-                val[32usize],
-                val[33usize],
-                val[34usize],
-                val[35usize],
-                val[36usize],
-                val[37usize],
-                val[38usize],
-                val[39usize],
-                val[40usize],
-                val[41usize],
-                val[42usize],
-                val[43usize],
-                val[44usize],
-                val[45usize],
-                val[46usize],
-                val[47usize],
-                val[48usize],
-                val[49usize],
-                val[50usize],
-                val[51usize],
-                val[52usize],
-                val[53usize],
-                val[54usize],
-                val[55usize],
-                val[56usize],
-                val[57usize],
-                val[58usize],
-                val[59usize],
-                val[60usize],
-                val[61usize],
-                val[62usize],
-                val[63usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<mask8x64<S>> for [i8; 64] {
+impl<S: Simd> From<i16x8<S>> for [i16; 8] {
     #[inline(always)]
-    fn from(value: mask8x64<S>) -> Self {
+    fn from(value: i16x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for mask8x64<S> {
-    type Target = [i8; 64];
+impl<S: Simd> core::ops::Deref for i16x8<S> {
+    type Target = [i16; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for mask8x64<S> {
+impl<S: Simd> core::ops::DerefMut for i16x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> Select<mask8x64<S>> for mask8x64<S> {
+impl<S: Simd> SimdFrom<i16, S> for i16x8<S> {
     #[inline(always)]
-    fn select(self, if_true: mask8x64<S>, if_false: mask8x64<S>) -> mask8x64<S> {
-        self.simd.select_mask8x64(self, if_true, if_false)
+    fn simd_from(value: i16, simd: S) -> Self {
+        simd.splat_i16x8(value)
     }
 }
-impl<S: Simd> Bytes for mask8x64<S> {
-    type Bytes = u8x64<S>;
+impl<S: Simd> Select<i16x8<S>> for mask16x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: i16x8<S>, if_false: i16x8<S>) -> i16x8<S> {
+        self.simd.select_i16x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i16x8<S> {
+    type Bytes = u8x16<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
         unsafe {
-            u8x64 {
+            u8x16 {
                 val: core::mem::transmute(self.val),
                 simd: self.simd,
             }
@@ -4885,303 +5162,15338 @@ impl<S: Simd> Bytes for mask8x64<S> {
         }
     }
 }
-impl<S: Simd> mask8x64<S> {
+impl<S: Simd> i16x8<S> {
     #[inline(always)]
-    pub fn not(self) -> mask8x64<S> {
-        self.simd.not_mask8x64(self)
+    pub fn not(self) -> i16x8<S> {
+        self.simd.not_i16x8(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.and_mask8x64(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.add_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.or_mask8x64(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.sub_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.xor_mask8x64(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.mul_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_mask8x64(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.and_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i8, S> for mask8x64<S> {
-    const N: usize = 64;
-    type Mask = mask8x64<S>;
-    type Block = mask8x16<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i8] {
-        &self.val
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.or_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i8] {
-        &mut self.val
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.xor_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i8]) -> Self {
-        let mut val = [0; 64];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn shl(self, shift: u32) -> i16x8<S> {
+        self.simd.shl_i16x8(self, shift)
     }
     #[inline(always)]
-    fn splat(simd: S, val: i8) -> Self {
-        simd.splat_mask8x64(val)
+    pub fn shr(self, shift: u32) -> i16x8<S> {
+        self.simd.shr_i16x8(self, shift)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.shl_var_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdMask<i8, S> for mask8x64<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
-        self.simd.simd_eq_mask8x64(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.shr_var_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct i16x32<S: Simd> {
-    pub val: [i16; 32],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i16; 32], S> for i16x32<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 32], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
-            ],
-            simd,
-        }
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<i16x32<S>> for [i16; 32] {
     #[inline(always)]
-    fn from(value: i16x32<S>) -> Self {
-        value.val
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for i16x32<S> {
-    type Target = [i16; 32];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for i16x32<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> SimdFrom<i16, S> for i16x32<S> {
     #[inline(always)]
-    fn simd_from(value: i16, simd: S) -> Self {
-        simd.splat_i16x32(value)
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<i16x32<S>> for mask16x32<S> {
     #[inline(always)]
-    fn select(self, if_true: i16x32<S>, if_false: i16x32<S>) -> i16x32<S> {
-        self.simd.select_i16x32(self, if_true, if_false)
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.min_i16x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for i16x32<S> {
-    type Bytes = u8x64<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x64 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.max_i16x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x8(self)
     }
-}
-impl<S: Simd> i16x32<S> {
     #[inline(always)]
-    pub fn not(self) -> i16x32<S> {
-        self.simd.not_i16x32(self)
+    pub fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.add_i16x32(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x8(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.sub_i16x32(self, rhs.simd_into(self.simd))
+    pub fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> i16x8<S> {
+        self.simd.shuffle_i16x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd
+            .shuffle2_i16x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i16x8<S> {
+        self.simd.reverse_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.unzip_low_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.unzip_high_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd
+            .saturating_add_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd
+            .saturating_sub_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i16x8<S> {
+        self.simd.abs_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i16x8<S> {
+        self.simd.neg_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.abs_diff_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.mul_widen_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.mul_high_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_q15_round_sat(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd
+            .mul_q15_round_sat_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn dot_i16(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.dot_i16_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.combine_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i32x8<S> {
+        self.simd.widen_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i32x4<S> {
+        self.simd.widen_low_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i32x4<S> {
+        self.simd.widen_high_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_i16x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_i16x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_i16x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_i16x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i16x8<S> {
+        self.simd.rotate_lanes_left_i16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i16x8<S> {
+        self.simd.rotate_lanes_right_i16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i16x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for i16x8<S> {
+    const N: usize = 8;
+    type Mask = mask16x8<S>;
+    type Block = i16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_i16x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i16x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i16x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i16x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i16, S> for i16x8<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.shl_var_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.shr_var_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.zip_low_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.zip_high_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.min_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x8<S> {
+        self.simd.max_i16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x8(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct u16x8<S: Simd> {
+    pub val: [u16; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u16; 8], S> for u16x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [u16; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x8<S>> for [u16; 8] {
+    #[inline(always)]
+    fn from(value: u16x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u16x8<S> {
+    type Target = [u16; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u16x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u16, S> for u16x8<S> {
+    #[inline(always)]
+    fn simd_from(value: u16, simd: S) -> Self {
+        simd.splat_u16x8(value)
+    }
+}
+impl<S: Simd> Select<u16x8<S>> for mask16x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: u16x8<S>, if_false: u16x8<S>) -> u16x8<S> {
+        self.simd.select_u16x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u16x8<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u16x8<S> {
+    #[inline(always)]
+    pub fn not(self) -> u16x8<S> {
+        self.simd.not_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.add_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.sub_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.mul_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.and_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.or_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.xor_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u16x8<S> {
+        self.simd.shl_u16x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u16x8<S> {
+        self.simd.shr_u16x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.shl_var_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.shr_var_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.min_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> u16x8<S> {
+        self.simd.shuffle_u16x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd
+            .shuffle2_u16x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u16x8<S> {
+        self.simd.reverse_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.unzip_low_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.unzip_high_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd
+            .saturating_add_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd
+            .saturating_sub_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.abs_diff_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.avg_round_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.mul_widen_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.mul_high_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.combine_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u16x4<S>, u16x4<S>) {
+        self.simd.split_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> u32x8<S> {
+        self.simd.widen_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u32x4<S> {
+        self.simd.widen_low_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u32x4<S> {
+        self.simd.widen_high_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u8x8<S> {
+        self.simd.narrow_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u8x8<S> {
+        self.simd.narrow_saturating_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_u16x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_u16x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_u16x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_u16x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u16x8<S> {
+        self.simd.rotate_lanes_left_u16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u16x8<S> {
+        self.simd.rotate_lanes_right_u16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u16x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u16, S> for u16x8<S> {
+    const N: usize = 8;
+    type Mask = mask16x8<S>;
+    type Block = u16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u16]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u16) -> Self {
+        simd.splat_u16x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u16x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u16x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u16x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u16, S> for u16x8<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.shl_var_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.shr_var_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_lt_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_le_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_ge_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_gt_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.zip_low_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.zip_high_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.min_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x8<S> {
+        self.simd.max_u16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x8(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x8(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct mask16x8<S: Simd> {
+    pub val: [i16; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i16; 8], S> for mask16x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [i16; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x8<S>> for [i16; 8] {
+    #[inline(always)]
+    fn from(value: mask16x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask16x8<S> {
+    type Target = [i16; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask16x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask16x8<S>> for mask16x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask16x8<S>, if_false: mask16x8<S>) -> mask16x8<S> {
+        self.simd.select_mask16x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask16x8<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask16x8<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask16x8<S> {
+        self.simd.not_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.and_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.or_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.xor_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x8(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> mask16x8<S> {
+        self.simd.shuffle_mask16x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd
+            .shuffle2_mask16x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask16x8<S> {
+        self.simd.reverse_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.unzip_low_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd
+            .unzip_high_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.combine_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask16x4<S>, mask16x4<S>) {
+        self.simd.split_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_mask16x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask16x8<S> {
+        self.simd.rotate_lanes_left_mask16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask16x8<S> {
+        self.simd.rotate_lanes_right_mask16x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask16x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for mask16x8<S> {
+    const N: usize = 8;
+    type Mask = mask16x8<S>;
+    type Block = mask16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_mask16x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask16x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask16x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask16x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask16x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i16, S> for mask16x8<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x8<S> {
+        self.simd.simd_eq_mask16x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask16x8(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask16x8(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask16x8(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask16x8(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x8(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x8(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x8(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct i32x4<S: Simd> {
+    pub val: [i32; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 4], S> for i32x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x4<S>> for [i32; 4] {
+    #[inline(always)]
+    fn from(value: i32x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i32x4<S> {
+    type Target = [i32; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i32x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i32, S> for i32x4<S> {
+    #[inline(always)]
+    fn simd_from(value: i32, simd: S) -> Self {
+        simd.splat_i32x4(value)
+    }
+}
+impl<S: Simd> Select<i32x4<S>> for mask32x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: i32x4<S>, if_false: i32x4<S>) -> i32x4<S> {
+        self.simd.select_i32x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i32x4<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i32x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> i32x4<S> {
+        self.simd.not_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.add_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.sub_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.mul_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.and_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.or_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.xor_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i32x4<S> {
+        self.simd.shl_i32x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i32x4<S> {
+        self.simd.shr_i32x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.shl_var_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.shr_var_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.min_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.max_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> i32x4<S> {
+        self.simd.shuffle_i32x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd
+            .shuffle2_i32x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i32x4<S> {
+        self.simd.reverse_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.unzip_low_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.unzip_high_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i32x4<S> {
+        self.simd.abs_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i32x4<S> {
+        self.simd.neg_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.abs_diff_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.combine_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i32x2<S>, i32x2<S>) {
+        self.simd.split_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i64x4<S> {
+        self.simd.widen_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i64x2<S> {
+        self.simd.widen_low_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i64x2<S> {
+        self.simd.widen_high_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_i32x4(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_i32x4(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_i32x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i32x4<S> {
+        self.simd.rotate_lanes_left_i32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i32x4<S> {
+        self.simd.rotate_lanes_right_i32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i32x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_i32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for i32x4<S> {
+    const N: usize = 4;
+    type Mask = mask32x4<S>;
+    type Block = i32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_i32x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i32x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i32x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i32x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_i32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i32, S> for i32x4<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.shl_var_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.shr_var_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.zip_low_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.zip_high_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.min_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x4<S> {
+        self.simd.max_i32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x4(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct u32x4<S: Simd> {
+    pub val: [u32; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u32; 4], S> for u32x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [u32; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x4<S>> for [u32; 4] {
+    #[inline(always)]
+    fn from(value: u32x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u32x4<S> {
+    type Target = [u32; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u32x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u32, S> for u32x4<S> {
+    #[inline(always)]
+    fn simd_from(value: u32, simd: S) -> Self {
+        simd.splat_u32x4(value)
+    }
+}
+impl<S: Simd> Select<u32x4<S>> for mask32x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: u32x4<S>, if_false: u32x4<S>) -> u32x4<S> {
+        self.simd.select_u32x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u32x4<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u32x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> u32x4<S> {
+        self.simd.not_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.add_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.sub_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.mul_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.and_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.or_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.xor_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u32x4<S> {
+        self.simd.shl_u32x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u32x4<S> {
+        self.simd.shr_u32x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.shl_var_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.shr_var_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.min_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.max_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> u32x4<S> {
+        self.simd.shuffle_u32x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd
+            .shuffle2_u32x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u32x4<S> {
+        self.simd.reverse_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.unzip_low_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.unzip_high_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.abs_diff_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.combine_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn widen(self) -> u64x4<S> {
+        self.simd.widen_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u64x2<S> {
+        self.simd.widen_low_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u64x2<S> {
+        self.simd.widen_high_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u16x4<S> {
+        self.simd.narrow_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u16x4<S> {
+        self.simd.narrow_saturating_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_u32x4(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_u32x4(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_u32x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u32x4<S> {
+        self.simd.rotate_lanes_left_u32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u32x4<S> {
+        self.simd.rotate_lanes_right_u32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u32x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u32, S> for u32x4<S> {
+    const N: usize = 4;
+    type Mask = mask32x4<S>;
+    type Block = u32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u32]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u32) -> Self {
+        simd.splat_u32x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u32x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u32x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u32x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u32, S> for u32x4<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.shl_var_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.shr_var_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_lt_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_le_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_ge_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_gt_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.zip_low_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.zip_high_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.min_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x4<S> {
+        self.simd.max_u32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x4(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x4(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct mask32x4<S: Simd> {
+    pub val: [i32; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 4], S> for mask32x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x4<S>> for [i32; 4] {
+    #[inline(always)]
+    fn from(value: mask32x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask32x4<S> {
+    type Target = [i32; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask32x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask32x4<S>> for mask32x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask32x4<S>, if_false: mask32x4<S>) -> mask32x4<S> {
+        self.simd.select_mask32x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask32x4<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask32x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask32x4<S> {
+        self.simd.not_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.and_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.or_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.xor_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x4(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> mask32x4<S> {
+        self.simd.shuffle_mask32x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd
+            .shuffle2_mask32x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask32x4<S> {
+        self.simd.reverse_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.unzip_low_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd
+            .unzip_high_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.combine_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask32x2<S>, mask32x2<S>) {
+        self.simd.split_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_mask32x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask32x4<S> {
+        self.simd.rotate_lanes_left_mask32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask32x4<S> {
+        self.simd.rotate_lanes_right_mask32x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask32x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for mask32x4<S> {
+    const N: usize = 4;
+    type Mask = mask32x4<S>;
+    type Block = mask32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_mask32x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask32x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask32x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask32x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask32x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i32, S> for mask32x4<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x4<S> {
+        self.simd.simd_eq_mask32x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask32x4(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask32x4(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask32x4(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask32x4(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x4(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x4(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x4(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct f64x2<S: Simd> {
+    pub val: [f64; 2],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f64; 2], S> for f64x2<S> {
+    #[inline(always)]
+    fn simd_from(val: [f64; 2], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x2<S>> for [f64; 2] {
+    #[inline(always)]
+    fn from(value: f64x2<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f64x2<S> {
+    type Target = [f64; 2];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f64x2<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f64, S> for f64x2<S> {
+    #[inline(always)]
+    fn simd_from(value: f64, simd: S) -> Self {
+        simd.splat_f64x2(value)
+    }
+}
+impl<S: Simd> Select<f64x2<S>> for mask64x2<S> {
+    #[inline(always)]
+    fn select(self, if_true: f64x2<S>, if_false: f64x2<S>) -> f64x2<S> {
+        self.simd.select_f64x2(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f64x2<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f64x2<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f64x2<S> {
+        self.simd.abs_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f64x2<S> {
+        self.simd.neg_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f64x2<S> {
+        self.simd.sqrt_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f64x2<S> {
+        self.simd.recip_approx_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f64x2<S> {
+        self.simd.rsqrt_approx_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f64x2<S> {
+        self.simd.recip_f64x2(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f64x2<S> {
+        self.simd.rsqrt_f64x2(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.add_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.sub_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.mul_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.div_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.copysign_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ne_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask64x2<S> {
+        self.simd.is_nan_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask64x2<S> {
+        self.simd.is_finite_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask64x2<S> {
+        self.simd.is_infinite_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask64x2<S> {
+        self.simd.is_subnormal_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask64x2<S> {
+        self.simd.is_sign_negative_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.max_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.max_precise_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.min_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.min_precise_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f64x2<S> {
+        self.simd.floor_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f64x2<S> {
+        self.simd.ceil_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f64x2<S> {
+        self.simd.round_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f64x2<S> {
+        self.simd.round_ties_even_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f64x2<S> {
+        self.simd.fract_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f64x2<S> {
+        self.simd.trunc_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> f64x2<S> {
+        self.simd.shuffle_f64x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .shuffle2_f64x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f64x2<S> {
+        self.simd.reverse_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.unzip_low_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.unzip_high_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.combine_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_f64x2(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f32` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x2<S> {
+        self.simd.cvt_f32_f64x2(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x2<S> {
+        self.simd.cvt_i32_f64x2(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x2<S> {
+        self.simd.cvt_i32_round_f64x2(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64(self) -> i64x2<S> {
+        self.simd.cvt_i64_f64x2(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64_round(self) -> i64x2<S> {
+        self.simd.cvt_i64_round_f64x2(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64(self) -> u64x2<S> {
+        self.simd.cvt_u64_f64x2(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64_round(self) -> u64x2<S> {
+        self.simd.cvt_u64_round_f64x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f64x2<S> {
+        self.simd.rotate_lanes_left_f64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f64x2<S> {
+        self.simd.rotate_lanes_right_f64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f64x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_f64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f64, S> for f64x2<S> {
+    const N: usize = 2;
+    type Mask = mask64x2<S>;
+    type Block = f64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f64]) -> Self {
+        let mut val = [0.0; 2];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f64) -> Self {
+        simd.splat_f64x2(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f64x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f64x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f64x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_f64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f64, S> for f64x2<S> {
+    #[inline(always)]
+    fn abs(self) -> f64x2<S> {
+        self.simd.abs_f64x2(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f64x2<S> {
+        self.simd.sqrt_f64x2(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f64x2<S> {
+        self.simd.recip_approx_f64x2(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f64x2<S> {
+        self.simd.rsqrt_approx_f64x2(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f64x2<S> {
+        self.simd.recip_f64x2(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f64x2<S> {
+        self.simd.rsqrt_f64x2(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.copysign_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ne_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask64x2<S> {
+        self.simd.is_nan_f64x2(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask64x2<S> {
+        self.simd.is_finite_f64x2(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask64x2<S> {
+        self.simd.is_infinite_f64x2(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask64x2<S> {
+        self.simd.is_subnormal_f64x2(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask64x2<S> {
+        self.simd.is_sign_negative_f64x2(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.zip_low_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.zip_high_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.max_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.max_precise_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.min_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd.min_precise_f64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .madd_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .msub_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .nmadd_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .nmsub_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .mul_add_fused_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x2<S> {
+        self.simd
+            .mul_add_fast_f64x2(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f64x2<S> {
+        self.simd.floor_f64x2(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f64x2<S> {
+        self.simd.ceil_f64x2(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f64x2<S> {
+        self.simd.round_f64x2(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f64x2<S> {
+        self.simd.round_ties_even_f64x2(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f64x2<S> {
+        self.simd.fract_f64x2(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f64x2<S> {
+        self.simd.trunc_f64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x2(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct i64x2<S: Simd> {
+    pub val: [i64; 2],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i64; 2], S> for i64x2<S> {
+    #[inline(always)]
+    fn simd_from(val: [i64; 2], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i64x2<S>> for [i64; 2] {
+    #[inline(always)]
+    fn from(value: i64x2<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i64x2<S> {
+    type Target = [i64; 2];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i64x2<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i64, S> for i64x2<S> {
+    #[inline(always)]
+    fn simd_from(value: i64, simd: S) -> Self {
+        simd.splat_i64x2(value)
+    }
+}
+impl<S: Simd> Select<i64x2<S>> for mask64x2<S> {
+    #[inline(always)]
+    fn select(self, if_true: i64x2<S>, if_false: i64x2<S>) -> i64x2<S> {
+        self.simd.select_i64x2(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i64x2<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i64x2<S> {
+    #[inline(always)]
+    pub fn not(self) -> i64x2<S> {
+        self.simd.not_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.add_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.sub_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.mul_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.and_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.or_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.xor_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i64x2<S> {
+        self.simd.shl_i64x2(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i64x2<S> {
+        self.simd.shr_i64x2(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.shl_var_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.shr_var_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.min_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.max_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> i64x2<S> {
+        self.simd.shuffle_i64x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd
+            .shuffle2_i64x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i64x2<S> {
+        self.simd.reverse_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.unzip_low_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.unzip_high_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i64x2<S> {
+        self.simd.abs_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i64x2<S> {
+        self.simd.neg_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.abs_diff_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.combine_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i32x2<S> {
+        self.simd.narrow_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i32x2<S> {
+        self.simd.narrow_saturating_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_i64x2(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x2<S> {
+        self.simd.cvt_f32_i64x2(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x2<S> {
+        self.simd.cvt_f64_i64x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i64x2<S> {
+        self.simd.rotate_lanes_left_i64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i64x2<S> {
+        self.simd.rotate_lanes_right_i64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i64x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_i64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i64, S> for i64x2<S> {
+    const N: usize = 2;
+    type Mask = mask64x2<S>;
+    type Block = i64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 2];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_i64x2(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i64x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i64x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i64x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_i64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i64, S> for i64x2<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.shl_var_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.shr_var_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.zip_low_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.zip_high_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.min_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i64x2<S> {
+        self.simd.max_i64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x2(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct u64x2<S: Simd> {
+    pub val: [u64; 2],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u64; 2], S> for u64x2<S> {
+    #[inline(always)]
+    fn simd_from(val: [u64; 2], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u64x2<S>> for [u64; 2] {
+    #[inline(always)]
+    fn from(value: u64x2<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u64x2<S> {
+    type Target = [u64; 2];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u64x2<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u64, S> for u64x2<S> {
+    #[inline(always)]
+    fn simd_from(value: u64, simd: S) -> Self {
+        simd.splat_u64x2(value)
+    }
+}
+impl<S: Simd> Select<u64x2<S>> for mask64x2<S> {
+    #[inline(always)]
+    fn select(self, if_true: u64x2<S>, if_false: u64x2<S>) -> u64x2<S> {
+        self.simd.select_u64x2(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u64x2<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u64x2<S> {
+    #[inline(always)]
+    pub fn not(self) -> u64x2<S> {
+        self.simd.not_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.add_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.sub_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.mul_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.and_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.or_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.xor_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u64x2<S> {
+        self.simd.shl_u64x2(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u64x2<S> {
+        self.simd.shr_u64x2(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.shl_var_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.shr_var_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.min_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.max_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> u64x2<S> {
+        self.simd.shuffle_u64x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd
+            .shuffle2_u64x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u64x2<S> {
+        self.simd.reverse_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.unzip_low_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.unzip_high_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.abs_diff_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.combine_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x8<S> {
+        self.simd.reinterpret_f16_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x8<S> {
+        self.simd.reinterpret_bf16_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x4<S> {
+        self.simd.reinterpret_f32_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x2<S> {
+        self.simd.reinterpret_f64_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_u64x2(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x2<S> {
+        self.simd.cvt_f32_u64x2(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x2<S> {
+        self.simd.cvt_f64_u64x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u64x2<S> {
+        self.simd.rotate_lanes_left_u64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u64x2<S> {
+        self.simd.rotate_lanes_right_u64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u64x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_u64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u64, S> for u64x2<S> {
+    const N: usize = 2;
+    type Mask = mask64x2<S>;
+    type Block = u64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u64]) -> Self {
+        let mut val = [0; 2];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u64) -> Self {
+        simd.splat_u64x2(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u64x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u64x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u64x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_u64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u64, S> for u64x2<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.shl_var_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.shr_var_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_lt_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_le_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_ge_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_gt_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.zip_low_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.zip_high_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.min_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u64x2<S> {
+        self.simd.max_u64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x2(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x2(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(16))]
+pub struct mask64x2<S: Simd> {
+    pub val: [i64; 2],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i64; 2], S> for mask64x2<S> {
+    #[inline(always)]
+    fn simd_from(val: [i64; 2], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask64x2<S>> for [i64; 2] {
+    #[inline(always)]
+    fn from(value: mask64x2<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask64x2<S> {
+    type Target = [i64; 2];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask64x2<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask64x2<S>> for mask64x2<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask64x2<S>, if_false: mask64x2<S>) -> mask64x2<S> {
+        self.simd.select_mask64x2(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask64x2<S> {
+    type Bytes = u8x16<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x16 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask64x2<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask64x2<S> {
+        self.simd.not_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.and_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.or_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.xor_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x2(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<2>>(self) -> mask64x2<S> {
+        self.simd.shuffle_mask64x2::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<2>>(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd
+            .shuffle2_mask64x2::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask64x2<S> {
+        self.simd.reverse_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.unzip_low_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd
+            .unzip_high_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.combine_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x16<S> {
+        self.simd.reinterpret_i8_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x16<S> {
+        self.simd.reinterpret_u8_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x8<S> {
+        self.simd.reinterpret_i16_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x8<S> {
+        self.simd.reinterpret_u16_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x4<S> {
+        self.simd.reinterpret_i32_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x4<S> {
+        self.simd.reinterpret_u32_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x2<S> {
+        self.simd.reinterpret_i64_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x2<S> {
+        self.simd.reinterpret_u64_mask64x2(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask64x2<S> {
+        self.simd.rotate_lanes_left_mask64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask64x2<S> {
+        self.simd.rotate_lanes_right_mask64x2::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask64x2<S> {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i64, S> for mask64x2<S> {
+    const N: usize = 2;
+    type Mask = mask64x2<S>;
+    type Block = mask64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 2];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_mask64x2(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask64x2(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask64x2::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask64x2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask64x2::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 2, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x2::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i64, S> for mask64x2<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x2<S> {
+        self.simd.simd_eq_mask64x2(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask64x2(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask64x2(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask64x2(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask64x2(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x2(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x2(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x2(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct f16x16<S: Simd> {
+    pub val: [f16; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f16; 16], S> for f16x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [f16; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f16x16<S>> for [f16; 16] {
+    #[inline(always)]
+    fn from(value: f16x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f16x16<S> {
+    type Target = [f16; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f16x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f16, S> for f16x16<S> {
+    #[inline(always)]
+    fn simd_from(value: f16, simd: S) -> Self {
+        simd.splat_f16x16(value)
+    }
+}
+impl<S: Simd> Select<f16x16<S>> for mask16x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: f16x16<S>, if_false: f16x16<S>) -> f16x16<S> {
+        self.simd.select_f16x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f16x16<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f16x16<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f16x16<S> {
+        self.simd.abs_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f16x16<S> {
+        self.simd.neg_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f16x16<S> {
+        self.simd.sqrt_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f16x16<S> {
+        self.simd.recip_approx_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f16x16<S> {
+        self.simd.rsqrt_approx_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f16x16<S> {
+        self.simd.recip_f16x16(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f16x16<S> {
+        self.simd.rsqrt_f16x16(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.add_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.sub_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.mul_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.div_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.copysign_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ne_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask16x16<S> {
+        self.simd.is_nan_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask16x16<S> {
+        self.simd.is_finite_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask16x16<S> {
+        self.simd.is_infinite_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask16x16<S> {
+        self.simd.is_subnormal_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask16x16<S> {
+        self.simd.is_sign_negative_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.max_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.max_precise_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.min_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.min_precise_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f16x16<S> {
+        self.simd.floor_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f16x16<S> {
+        self.simd.ceil_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f16x16<S> {
+        self.simd.round_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f16x16<S> {
+        self.simd.round_ties_even_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f16x16<S> {
+        self.simd.fract_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f16x16<S> {
+        self.simd.trunc_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f16 {
+        self.simd.reduce_add_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f16 {
+        self.simd.reduce_min_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f16 {
+        self.simd.reduce_max_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> f16x16<S> {
+        self.simd.shuffle_f16x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .shuffle2_f16x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f16x16<S> {
+        self.simd.reverse_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.unzip_low_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.unzip_high_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (f16x8<S>, f16x8<S>) {
+        self.simd.split_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_f16x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i8`, rounding toward zero. See [`cvt_i8_round`](Self::cvt_i8_round) to round to nearest.\n\n Lanes outside the range of `i8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i8(self) -> i8x16<S> {
+        self.simd.cvt_i8_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i8_round(self) -> i8x16<S> {
+        self.simd.cvt_i8_round_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding toward zero. See [`cvt_u8_round`](Self::cvt_u8_round) to round to nearest.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8(self) -> u8x16<S> {
+        self.simd.cvt_u8_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8_round(self) -> u8x16<S> {
+        self.simd.cvt_u8_round_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding toward zero. See [`cvt_i16_round`](Self::cvt_i16_round) to round to nearest.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16(self) -> i16x16<S> {
+        self.simd.cvt_i16_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16_round(self) -> i16x16<S> {
+        self.simd.cvt_i16_round_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16(self) -> u16x16<S> {
+        self.simd.cvt_u16_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16_round(self) -> u16x16<S> {
+        self.simd.cvt_u16_round_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x16<S> {
+        self.simd.cvt_i32_f16x16(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x16<S> {
+        self.simd.cvt_i32_round_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32(self) -> u32x16<S> {
+        self.simd.cvt_u32_f16x16(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32_round(self) -> u32x16<S> {
+        self.simd.cvt_u32_round_f16x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f16x16<S> {
+        self.simd.rotate_lanes_left_f16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f16x16<S> {
+        self.simd.rotate_lanes_right_f16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f16x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_f16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f16, S> for f16x16<S> {
+    const N: usize = 16;
+    type Mask = mask16x16<S>;
+    type Block = f16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f16]) -> Self {
+        let mut val = [f16::from_bits(0); 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f16) -> Self {
+        simd.splat_f16x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f16x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f16x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f16x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_f16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f16, S> for f16x16<S> {
+    #[inline(always)]
+    fn abs(self) -> f16x16<S> {
+        self.simd.abs_f16x16(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f16x16<S> {
+        self.simd.sqrt_f16x16(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f16x16<S> {
+        self.simd.recip_approx_f16x16(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f16x16<S> {
+        self.simd.rsqrt_approx_f16x16(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f16x16<S> {
+        self.simd.recip_f16x16(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f16x16<S> {
+        self.simd.rsqrt_f16x16(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.copysign_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ne_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask16x16<S> {
+        self.simd.is_nan_f16x16(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask16x16<S> {
+        self.simd.is_finite_f16x16(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask16x16<S> {
+        self.simd.is_infinite_f16x16(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask16x16<S> {
+        self.simd.is_subnormal_f16x16(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask16x16<S> {
+        self.simd.is_sign_negative_f16x16(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.zip_low_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.zip_high_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.max_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.max_precise_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.min_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd.min_precise_f16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .madd_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .msub_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .nmadd_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .nmsub_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .mul_add_fused_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f16x16<S> {
+        self.simd
+            .mul_add_fast_f16x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f16x16<S> {
+        self.simd.floor_f16x16(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f16x16<S> {
+        self.simd.ceil_f16x16(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f16x16<S> {
+        self.simd.round_f16x16(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f16x16<S> {
+        self.simd.round_ties_even_f16x16(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f16x16<S> {
+        self.simd.fract_f16x16(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f16x16<S> {
+        self.simd.trunc_f16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f16 {
+        self.simd.reduce_add_f16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f16 {
+        self.simd.reduce_min_f16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f16 {
+        self.simd.reduce_max_f16x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct bf16x16<S: Simd> {
+    pub val: [bf16; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[bf16; 16], S> for bf16x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [bf16; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<bf16x16<S>> for [bf16; 16] {
+    #[inline(always)]
+    fn from(value: bf16x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for bf16x16<S> {
+    type Target = [bf16; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for bf16x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<bf16, S> for bf16x16<S> {
+    #[inline(always)]
+    fn simd_from(value: bf16, simd: S) -> Self {
+        simd.splat_bf16x16(value)
+    }
+}
+impl<S: Simd> Select<bf16x16<S>> for mask16x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: bf16x16<S>, if_false: bf16x16<S>) -> bf16x16<S> {
+        self.simd.select_bf16x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for bf16x16<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> bf16x16<S> {
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> bf16x16<S> {
+        self.simd.shuffle_bf16x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> bf16x16<S> {
+        self.simd
+            .shuffle2_bf16x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> bf16x16<S> {
+        self.simd.reverse_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> bf16x16<S> {
+        self.simd.unzip_low_bf16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> bf16x16<S> {
+        self.simd.unzip_high_bf16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (bf16x8<S>, bf16x8<S>) {
+        self.simd.split_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_bf16x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_bf16x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> bf16x16<S> {
+        self.simd.rotate_lanes_left_bf16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> bf16x16<S> {
+        self.simd.rotate_lanes_right_bf16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> bf16x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_bf16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<bf16, S> for bf16x16<S> {
+    const N: usize = 16;
+    type Mask = mask16x16<S>;
+    type Block = bf16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[bf16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [bf16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[bf16]) -> Self {
+        let mut val = [bf16::from_bits(0); 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: bf16) -> Self {
+        simd.splat_bf16x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_bf16x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_bf16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_bf16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_bf16x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_bf16x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_bf16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_bf16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_bf16x16::<L>(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct f32x8<S: Simd> {
+    pub val: [f32; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f32; 8], S> for f32x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [f32; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x8<S>> for [f32; 8] {
+    #[inline(always)]
+    fn from(value: f32x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f32x8<S> {
+    type Target = [f32; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f32x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f32, S> for f32x8<S> {
+    #[inline(always)]
+    fn simd_from(value: f32, simd: S) -> Self {
+        simd.splat_f32x8(value)
+    }
+}
+impl<S: Simd> Select<f32x8<S>> for mask32x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: f32x8<S>, if_false: f32x8<S>) -> f32x8<S> {
+        self.simd.select_f32x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f32x8<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f32x8<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f32x8<S> {
+        self.simd.abs_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f32x8<S> {
+        self.simd.neg_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f32x8<S> {
+        self.simd.sqrt_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f32x8<S> {
+        self.simd.recip_approx_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f32x8<S> {
+        self.simd.rsqrt_approx_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f32x8<S> {
+        self.simd.recip_f32x8(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f32x8<S> {
+        self.simd.rsqrt_f32x8(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.add_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.sub_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.mul_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.div_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.copysign_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ne_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask32x8<S> {
+        self.simd.is_nan_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask32x8<S> {
+        self.simd.is_finite_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask32x8<S> {
+        self.simd.is_infinite_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask32x8<S> {
+        self.simd.is_subnormal_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask32x8<S> {
+        self.simd.is_sign_negative_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.max_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.max_precise_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.min_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.min_precise_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f32x8<S> {
+        self.simd.floor_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f32x8<S> {
+        self.simd.ceil_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f32x8<S> {
+        self.simd.round_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f32x8<S> {
+        self.simd.round_ties_even_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f32x8<S> {
+        self.simd.fract_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f32x8<S> {
+        self.simd.trunc_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> f32x8<S> {
+        self.simd.shuffle_f32x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .shuffle2_f32x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f32x8<S> {
+        self.simd.reverse_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.unzip_low_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.unzip_high_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.combine_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (f32x4<S>, f32x4<S>) {
+        self.simd.split_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_f32x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_f32x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding toward zero. See [`cvt_u8_round`](Self::cvt_u8_round) to round to nearest.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8(self) -> u8x8<S> {
+        self.simd.cvt_u8_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8_round(self) -> u8x8<S> {
+        self.simd.cvt_u8_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding toward zero. See [`cvt_i16_round`](Self::cvt_i16_round) to round to nearest.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16(self) -> i16x8<S> {
+        self.simd.cvt_i16_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16_round(self) -> i16x8<S> {
+        self.simd.cvt_i16_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16(self) -> u16x8<S> {
+        self.simd.cvt_u16_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16_round(self) -> u16x8<S> {
+        self.simd.cvt_u16_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x8<S> {
+        self.simd.cvt_i32_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x8<S> {
+        self.simd.cvt_i32_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32(self) -> u32x8<S> {
+        self.simd.cvt_u32_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32_round(self) -> u32x8<S> {
+        self.simd.cvt_u32_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64(self) -> i64x8<S> {
+        self.simd.cvt_i64_f32x8(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64_round(self) -> i64x8<S> {
+        self.simd.cvt_i64_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64(self) -> u64x8<S> {
+        self.simd.cvt_u64_f32x8(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64_round(self) -> u64x8<S> {
+        self.simd.cvt_u64_round_f32x8(self)
+    }
+    #[doc = " Convert each lane to `bf16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `bf16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_bf16(self) -> bf16x8<S> {
+        self.simd.cvt_bf16_f32x8(self)
+    }
+    #[inline(always)]
+    pub fn dot_bf16(self, a: bf16x16<S>, b: bf16x16<S>) -> f32x8<S> {
+        self.simd.dot_bf16_f32x8(self, a, b)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f32x8<S> {
+        self.simd.rotate_lanes_left_f32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f32x8<S> {
+        self.simd.rotate_lanes_right_f32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f32x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f32, S> for f32x8<S> {
+    const N: usize = 8;
+    type Mask = mask32x8<S>;
+    type Block = f32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f32]) -> Self {
+        let mut val = [0.0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f32) -> Self {
+        simd.splat_f32x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f32x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f32x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f32x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f32, S> for f32x8<S> {
+    #[inline(always)]
+    fn abs(self) -> f32x8<S> {
+        self.simd.abs_f32x8(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f32x8<S> {
+        self.simd.sqrt_f32x8(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f32x8<S> {
+        self.simd.recip_approx_f32x8(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f32x8<S> {
+        self.simd.rsqrt_approx_f32x8(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f32x8<S> {
+        self.simd.recip_f32x8(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f32x8<S> {
+        self.simd.rsqrt_f32x8(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.copysign_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ne_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask32x8<S> {
+        self.simd.is_nan_f32x8(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask32x8<S> {
+        self.simd.is_finite_f32x8(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask32x8<S> {
+        self.simd.is_infinite_f32x8(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask32x8<S> {
+        self.simd.is_subnormal_f32x8(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask32x8<S> {
+        self.simd.is_sign_negative_f32x8(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.zip_low_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.zip_high_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.max_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.max_precise_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.min_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd.min_precise_f32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .madd_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .msub_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .nmadd_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .nmsub_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .mul_add_fused_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x8<S> {
+        self.simd
+            .mul_add_fast_f32x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f32x8<S> {
+        self.simd.floor_f32x8(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f32x8<S> {
+        self.simd.ceil_f32x8(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f32x8<S> {
+        self.simd.round_f32x8(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f32x8<S> {
+        self.simd.round_ties_even_f32x8(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f32x8<S> {
+        self.simd.fract_f32x8(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f32x8<S> {
+        self.simd.trunc_f32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x8(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct i8x32<S: Simd> {
+    pub val: [i8; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i8; 32], S> for i8x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [i8; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i8x32<S>> for [i8; 32] {
+    #[inline(always)]
+    fn from(value: i8x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i8x32<S> {
+    type Target = [i8; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i8x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i8, S> for i8x32<S> {
+    #[inline(always)]
+    fn simd_from(value: i8, simd: S) -> Self {
+        simd.splat_i8x32(value)
+    }
+}
+impl<S: Simd> Select<i8x32<S>> for mask8x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: i8x32<S>, if_false: i8x32<S>) -> i8x32<S> {
+        self.simd.select_i8x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i8x32<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i8x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> i8x32<S> {
+        self.simd.not_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.add_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.sub_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.mul_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.and_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.or_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.xor_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i8x32<S> {
+        self.simd.shl_i8x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i8x32<S> {
+        self.simd.shr_i8x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.shl_var_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.shr_var_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_lt_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_le_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_ge_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_gt_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.min_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.max_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> i8x32<S> {
+        self.simd.shuffle_i8x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd
+            .shuffle2_i8x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i8x32<S> {
+        self.simd.reverse_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.unzip_low_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.unzip_high_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd
+            .saturating_add_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd
+            .saturating_sub_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i8x32<S> {
+        self.simd.abs_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i8x32<S> {
+        self.simd.neg_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.abs_diff_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.mul_widen_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.mul_high_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.combine_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i8x16<S>, i8x16<S>) {
+        self.simd.split_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i16x32<S> {
+        self.simd.widen_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i16x16<S> {
+        self.simd.widen_low_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i16x16<S> {
+        self.simd.widen_high_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_i8x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i8x32<S> {
+        self.simd.rotate_lanes_left_i8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i8x32<S> {
+        self.simd.rotate_lanes_right_i8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i8x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_i8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i8, S> for i8x32<S> {
+    const N: usize = 32;
+    type Mask = mask8x32<S>;
+    type Block = i8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_i8x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i8x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i8x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i8x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_i8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i8, S> for i8x32<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.shl_var_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.shr_var_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_lt_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_le_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_ge_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_gt_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.zip_low_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.zip_high_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.min_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x32<S> {
+        self.simd.max_i8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x32(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct u8x32<S: Simd> {
+    pub val: [u8; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u8; 32], S> for u8x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [u8; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x32<S>> for [u8; 32] {
+    #[inline(always)]
+    fn from(value: u8x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u8x32<S> {
+    type Target = [u8; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u8x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u8, S> for u8x32<S> {
+    #[inline(always)]
+    fn simd_from(value: u8, simd: S) -> Self {
+        simd.splat_u8x32(value)
+    }
+}
+impl<S: Simd> Select<u8x32<S>> for mask8x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: u8x32<S>, if_false: u8x32<S>) -> u8x32<S> {
+        self.simd.select_u8x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u8x32<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u8x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> u8x32<S> {
+        self.simd.not_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.add_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.sub_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.mul_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.and_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.or_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.xor_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u8x32<S> {
+        self.simd.shl_u8x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u8x32<S> {
+        self.simd.shr_u8x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.shl_var_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.shr_var_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_lt_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_le_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_ge_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_gt_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.min_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.max_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> u8x32<S> {
+        self.simd.shuffle_u8x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd
+            .shuffle2_u8x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u8x32<S> {
+        self.simd.reverse_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.unzip_low_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.unzip_high_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd
+            .saturating_add_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd
+            .saturating_sub_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.abs_diff_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.avg_round_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.mul_widen_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.mul_high_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[doc = r" Look up each byte of `idx` in `self`, with zero for indices"]
+    #[doc = r" past the end."]
+    #[inline(always)]
+    pub fn swizzle_dyn(self, idx: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.swizzle_dyn_u8x32(self, idx.simd_into(self.simd))
+    }
+    #[doc = " Look up each byte of `self` in a 32 byte table, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_32(self, table: u8x32<S>) -> u8x32<S> {
+        self.simd.lookup_32_u8x32(table, self)
+    }
+    #[doc = " Look up each byte of `self` in a 48 byte table of three vectors, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_48(self, table: [u8x16<S>; 3]) -> u8x32<S> {
+        self.simd.lookup_48_u8x32(table, self)
+    }
+    #[doc = " Look up each byte of `self` in a 64 byte table, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_64(self, table: u8x64<S>) -> u8x32<S> {
+        self.simd.lookup_64_u8x32(table, self)
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.combine_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u8x16<S>, u8x16<S>) {
+        self.simd.split_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> u16x32<S> {
+        self.simd.widen_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u16x16<S> {
+        self.simd.widen_low_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u16x16<S> {
+        self.simd.widen_high_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_u8x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u8x32<S> {
+        self.simd.rotate_lanes_left_u8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u8x32<S> {
+        self.simd.rotate_lanes_right_u8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u8x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_u8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u8, S> for u8x32<S> {
+    const N: usize = 32;
+    type Mask = mask8x32<S>;
+    type Block = u8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u8]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u8) -> Self {
+        simd.splat_u8x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u8x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u8x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u8x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_u8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u8, S> for u8x32<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.shl_var_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.shr_var_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_lt_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_le_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_ge_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_gt_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.zip_low_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.zip_high_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.min_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x32<S> {
+        self.simd.max_u8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x32(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x32(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct mask8x32<S: Simd> {
+    pub val: [i8; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i8; 32], S> for mask8x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [i8; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x32<S>> for [i8; 32] {
+    #[inline(always)]
+    fn from(value: mask8x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask8x32<S> {
+    type Target = [i8; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask8x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask8x32<S>> for mask8x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask8x32<S>, if_false: mask8x32<S>) -> mask8x32<S> {
+        self.simd.select_mask8x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask8x32<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask8x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask8x32<S> {
+        self.simd.not_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.and_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.or_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.xor_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x32(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> mask8x32<S> {
+        self.simd.shuffle_mask8x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd
+            .shuffle2_mask8x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask8x32<S> {
+        self.simd.reverse_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.unzip_low_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd
+            .unzip_high_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.combine_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask8x16<S>, mask8x16<S>) {
+        self.simd.split_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_mask8x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask8x32<S> {
+        self.simd.rotate_lanes_left_mask8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask8x32<S> {
+        self.simd.rotate_lanes_right_mask8x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask8x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i8, S> for mask8x32<S> {
+    const N: usize = 32;
+    type Mask = mask8x32<S>;
+    type Block = mask8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_mask8x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask8x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask8x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask8x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask8x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i8, S> for mask8x32<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x32<S> {
+        self.simd.simd_eq_mask8x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask8x32(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask8x32(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask8x32(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask8x32(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x32(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x32(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x32(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct i16x16<S: Simd> {
+    pub val: [i16; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i16; 16], S> for i16x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [i16; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i16x16<S>> for [i16; 16] {
+    #[inline(always)]
+    fn from(value: i16x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i16x16<S> {
+    type Target = [i16; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i16x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i16, S> for i16x16<S> {
+    #[inline(always)]
+    fn simd_from(value: i16, simd: S) -> Self {
+        simd.splat_i16x16(value)
+    }
+}
+impl<S: Simd> Select<i16x16<S>> for mask16x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: i16x16<S>, if_false: i16x16<S>) -> i16x16<S> {
+        self.simd.select_i16x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i16x16<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i16x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> i16x16<S> {
+        self.simd.not_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.add_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.sub_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.mul_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.and_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.or_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.xor_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i16x16<S> {
+        self.simd.shl_i16x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i16x16<S> {
+        self.simd.shr_i16x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.shl_var_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.shr_var_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> i16x16<S> {
+        self.simd.shuffle_i16x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd
+            .shuffle2_i16x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i16x16<S> {
+        self.simd.reverse_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.unzip_low_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.unzip_high_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd
+            .saturating_add_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd
+            .saturating_sub_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i16x16<S> {
+        self.simd.abs_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i16x16<S> {
+        self.simd.neg_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.abs_diff_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.mul_widen_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.mul_high_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_q15_round_sat(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd
+            .mul_q15_round_sat_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn dot_i16(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.dot_i16_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.combine_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i16x8<S>, i16x8<S>) {
+        self.simd.split_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i32x16<S> {
+        self.simd.widen_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i32x8<S> {
+        self.simd.widen_low_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i32x8<S> {
+        self.simd.widen_high_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i8x16<S> {
+        self.simd.narrow_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i8x16<S> {
+        self.simd.narrow_saturating_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_i16x16(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_i16x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_i16x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i16x16<S> {
+        self.simd.rotate_lanes_left_i16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i16x16<S> {
+        self.simd.rotate_lanes_right_i16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i16x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for i16x16<S> {
+    const N: usize = 16;
+    type Mask = mask16x16<S>;
+    type Block = i16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_i16x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i16x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i16x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i16x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i16, S> for i16x16<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.shl_var_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.shr_var_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.zip_low_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.zip_high_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.min_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x16<S> {
+        self.simd.max_i16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct u16x16<S: Simd> {
+    pub val: [u16; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u16; 16], S> for u16x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [u16; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x16<S>> for [u16; 16] {
+    #[inline(always)]
+    fn from(value: u16x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u16x16<S> {
+    type Target = [u16; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u16x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u16, S> for u16x16<S> {
+    #[inline(always)]
+    fn simd_from(value: u16, simd: S) -> Self {
+        simd.splat_u16x16(value)
+    }
+}
+impl<S: Simd> Select<u16x16<S>> for mask16x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: u16x16<S>, if_false: u16x16<S>) -> u16x16<S> {
+        self.simd.select_u16x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u16x16<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u16x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> u16x16<S> {
+        self.simd.not_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.add_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.sub_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.mul_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.and_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.or_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.xor_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u16x16<S> {
+        self.simd.shl_u16x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u16x16<S> {
+        self.simd.shr_u16x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.shl_var_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.shr_var_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.min_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.max_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> u16x16<S> {
+        self.simd.shuffle_u16x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd
+            .shuffle2_u16x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u16x16<S> {
+        self.simd.reverse_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.unzip_low_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.unzip_high_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd
+            .saturating_add_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd
+            .saturating_sub_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.abs_diff_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.avg_round_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_widen(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.mul_widen_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.mul_high_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.combine_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u16x8<S>, u16x8<S>) {
+        self.simd.split_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> u32x16<S> {
+        self.simd.widen_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u32x8<S> {
+        self.simd.widen_low_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u32x8<S> {
+        self.simd.widen_high_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u8x16<S> {
+        self.simd.narrow_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u8x16<S> {
+        self.simd.narrow_saturating_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_u16x16(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_u16x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_u16x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u16x16<S> {
+        self.simd.rotate_lanes_left_u16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u16x16<S> {
+        self.simd.rotate_lanes_right_u16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u16x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u16, S> for u16x16<S> {
+    const N: usize = 16;
+    type Mask = mask16x16<S>;
+    type Block = u16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u16]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u16) -> Self {
+        simd.splat_u16x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u16x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u16x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u16x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u16, S> for u16x16<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.shl_var_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.shr_var_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_lt_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_le_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_ge_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_gt_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.zip_low_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.zip_high_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.min_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x16<S> {
+        self.simd.max_u16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x16(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct mask16x16<S: Simd> {
+    pub val: [i16; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i16; 16], S> for mask16x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [i16; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x16<S>> for [i16; 16] {
+    #[inline(always)]
+    fn from(value: mask16x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask16x16<S> {
+    type Target = [i16; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask16x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask16x16<S>> for mask16x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask16x16<S>, if_false: mask16x16<S>) -> mask16x16<S> {
+        self.simd.select_mask16x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask16x16<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask16x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask16x16<S> {
+        self.simd.not_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.and_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.or_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.xor_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x16(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> mask16x16<S> {
+        self.simd.shuffle_mask16x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd
+            .shuffle2_mask16x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask16x16<S> {
+        self.simd.reverse_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd
+            .unzip_low_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd
+            .unzip_high_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.combine_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask16x8<S>, mask16x8<S>) {
+        self.simd.split_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_mask16x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask16x16<S> {
+        self.simd.rotate_lanes_left_mask16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask16x16<S> {
+        self.simd.rotate_lanes_right_mask16x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask16x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for mask16x16<S> {
+    const N: usize = 16;
+    type Mask = mask16x16<S>;
+    type Block = mask16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_mask16x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask16x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_low_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask16x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask16x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask16x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i16, S> for mask16x16<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x16<S> {
+        self.simd.simd_eq_mask16x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask16x16(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask16x16(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask16x16(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask16x16(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x16(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x16(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x16(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct i32x8<S: Simd> {
+    pub val: [i32; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 8], S> for i32x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x8<S>> for [i32; 8] {
+    #[inline(always)]
+    fn from(value: i32x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i32x8<S> {
+    type Target = [i32; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i32x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i32, S> for i32x8<S> {
+    #[inline(always)]
+    fn simd_from(value: i32, simd: S) -> Self {
+        simd.splat_i32x8(value)
+    }
+}
+impl<S: Simd> Select<i32x8<S>> for mask32x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: i32x8<S>, if_false: i32x8<S>) -> i32x8<S> {
+        self.simd.select_i32x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i32x8<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i32x8<S> {
+    #[inline(always)]
+    pub fn not(self) -> i32x8<S> {
+        self.simd.not_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.add_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.sub_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.mul_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.and_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.or_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.xor_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i32x8<S> {
+        self.simd.shl_i32x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i32x8<S> {
+        self.simd.shr_i32x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.shl_var_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.shr_var_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.min_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.max_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> i32x8<S> {
+        self.simd.shuffle_i32x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd
+            .shuffle2_i32x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i32x8<S> {
+        self.simd.reverse_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.unzip_low_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.unzip_high_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i32x8<S> {
+        self.simd.abs_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i32x8<S> {
+        self.simd.neg_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.abs_diff_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.combine_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i32x4<S>, i32x4<S>) {
+        self.simd.split_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> i64x8<S> {
+        self.simd.widen_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i64x4<S> {
+        self.simd.widen_low_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i64x4<S> {
+        self.simd.widen_high_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i16x8<S> {
+        self.simd.narrow_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i16x8<S> {
+        self.simd.narrow_saturating_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_i32x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_i32x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_i32x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_i32x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i32x8<S> {
+        self.simd.rotate_lanes_left_i32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i32x8<S> {
+        self.simd.rotate_lanes_right_i32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i32x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for i32x8<S> {
+    const N: usize = 8;
+    type Mask = mask32x8<S>;
+    type Block = i32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_i32x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i32x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i32x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i32x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i32, S> for i32x8<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.shl_var_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.shr_var_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.zip_low_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.zip_high_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.min_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x8<S> {
+        self.simd.max_i32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x8(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct u32x8<S: Simd> {
+    pub val: [u32; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u32; 8], S> for u32x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [u32; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x8<S>> for [u32; 8] {
+    #[inline(always)]
+    fn from(value: u32x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u32x8<S> {
+    type Target = [u32; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u32x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u32, S> for u32x8<S> {
+    #[inline(always)]
+    fn simd_from(value: u32, simd: S) -> Self {
+        simd.splat_u32x8(value)
+    }
+}
+impl<S: Simd> Select<u32x8<S>> for mask32x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: u32x8<S>, if_false: u32x8<S>) -> u32x8<S> {
+        self.simd.select_u32x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u32x8<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u32x8<S> {
+    #[inline(always)]
+    pub fn not(self) -> u32x8<S> {
+        self.simd.not_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.add_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.sub_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.mul_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.and_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.or_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.xor_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u32x8<S> {
+        self.simd.shl_u32x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u32x8<S> {
+        self.simd.shr_u32x8(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.shl_var_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.shr_var_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.min_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.max_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> u32x8<S> {
+        self.simd.shuffle_u32x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd
+            .shuffle2_u32x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u32x8<S> {
+        self.simd.reverse_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.unzip_low_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.unzip_high_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.abs_diff_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.combine_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u32x4<S>, u32x4<S>) {
+        self.simd.split_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen(self) -> u64x8<S> {
+        self.simd.widen_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u64x4<S> {
+        self.simd.widen_low_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u64x4<S> {
+        self.simd.widen_high_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u16x8<S> {
+        self.simd.narrow_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u16x8<S> {
+        self.simd.narrow_saturating_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_u32x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_u32x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_u32x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_u32x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u32x8<S> {
+        self.simd.rotate_lanes_left_u32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u32x8<S> {
+        self.simd.rotate_lanes_right_u32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u32x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u32, S> for u32x8<S> {
+    const N: usize = 8;
+    type Mask = mask32x8<S>;
+    type Block = u32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u32]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u32) -> Self {
+        simd.splat_u32x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u32x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u32x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u32x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u32, S> for u32x8<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.shl_var_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.shr_var_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_lt_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_le_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_ge_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_gt_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.zip_low_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.zip_high_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.min_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x8<S> {
+        self.simd.max_u32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x8(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x8(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct mask32x8<S: Simd> {
+    pub val: [i32; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 8], S> for mask32x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x8<S>> for [i32; 8] {
+    #[inline(always)]
+    fn from(value: mask32x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask32x8<S> {
+    type Target = [i32; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask32x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask32x8<S>> for mask32x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask32x8<S>, if_false: mask32x8<S>) -> mask32x8<S> {
+        self.simd.select_mask32x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask32x8<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask32x8<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask32x8<S> {
+        self.simd.not_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.and_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.or_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.xor_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x8(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> mask32x8<S> {
+        self.simd.shuffle_mask32x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd
+            .shuffle2_mask32x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask32x8<S> {
+        self.simd.reverse_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.unzip_low_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd
+            .unzip_high_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.combine_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask32x4<S>, mask32x4<S>) {
+        self.simd.split_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_mask32x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask32x8<S> {
+        self.simd.rotate_lanes_left_mask32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask32x8<S> {
+        self.simd.rotate_lanes_right_mask32x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask32x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for mask32x8<S> {
+    const N: usize = 8;
+    type Mask = mask32x8<S>;
+    type Block = mask32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_mask32x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask32x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask32x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask32x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask32x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i32, S> for mask32x8<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x8<S> {
+        self.simd.simd_eq_mask32x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask32x8(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask32x8(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask32x8(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask32x8(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x8(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x8(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x8(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct f64x4<S: Simd> {
+    pub val: [f64; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f64; 4], S> for f64x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [f64; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x4<S>> for [f64; 4] {
+    #[inline(always)]
+    fn from(value: f64x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f64x4<S> {
+    type Target = [f64; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f64x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f64, S> for f64x4<S> {
+    #[inline(always)]
+    fn simd_from(value: f64, simd: S) -> Self {
+        simd.splat_f64x4(value)
+    }
+}
+impl<S: Simd> Select<f64x4<S>> for mask64x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: f64x4<S>, if_false: f64x4<S>) -> f64x4<S> {
+        self.simd.select_f64x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f64x4<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f64x4<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f64x4<S> {
+        self.simd.abs_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f64x4<S> {
+        self.simd.neg_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f64x4<S> {
+        self.simd.sqrt_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f64x4<S> {
+        self.simd.recip_approx_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f64x4<S> {
+        self.simd.rsqrt_approx_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f64x4<S> {
+        self.simd.recip_f64x4(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f64x4<S> {
+        self.simd.rsqrt_f64x4(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.add_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.sub_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.mul_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.div_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.copysign_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ne_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask64x4<S> {
+        self.simd.is_nan_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask64x4<S> {
+        self.simd.is_finite_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask64x4<S> {
+        self.simd.is_infinite_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask64x4<S> {
+        self.simd.is_subnormal_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask64x4<S> {
+        self.simd.is_sign_negative_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.max_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.max_precise_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.min_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.min_precise_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f64x4<S> {
+        self.simd.floor_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f64x4<S> {
+        self.simd.ceil_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f64x4<S> {
+        self.simd.round_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f64x4<S> {
+        self.simd.round_ties_even_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f64x4<S> {
+        self.simd.fract_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f64x4<S> {
+        self.simd.trunc_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> f64x4<S> {
+        self.simd.shuffle_f64x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .shuffle2_f64x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f64x4<S> {
+        self.simd.reverse_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.unzip_low_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.unzip_high_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.combine_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (f64x2<S>, f64x2<S>) {
+        self.simd.split_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_f64x4(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f32` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16(self) -> u16x4<S> {
+        self.simd.cvt_u16_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16_round(self) -> u16x4<S> {
+        self.simd.cvt_u16_round_f64x4(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x4<S> {
+        self.simd.cvt_i32_f64x4(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x4<S> {
+        self.simd.cvt_i32_round_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32(self) -> u32x4<S> {
+        self.simd.cvt_u32_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32_round(self) -> u32x4<S> {
+        self.simd.cvt_u32_round_f64x4(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64(self) -> i64x4<S> {
+        self.simd.cvt_i64_f64x4(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64_round(self) -> i64x4<S> {
+        self.simd.cvt_i64_round_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64(self) -> u64x4<S> {
+        self.simd.cvt_u64_f64x4(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64_round(self) -> u64x4<S> {
+        self.simd.cvt_u64_round_f64x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f64x4<S> {
+        self.simd.rotate_lanes_left_f64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f64x4<S> {
+        self.simd.rotate_lanes_right_f64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f64x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_f64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f64, S> for f64x4<S> {
+    const N: usize = 4;
+    type Mask = mask64x4<S>;
+    type Block = f64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f64]) -> Self {
+        let mut val = [0.0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f64) -> Self {
+        simd.splat_f64x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f64x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f64x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f64x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_f64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f64, S> for f64x4<S> {
+    #[inline(always)]
+    fn abs(self) -> f64x4<S> {
+        self.simd.abs_f64x4(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f64x4<S> {
+        self.simd.sqrt_f64x4(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f64x4<S> {
+        self.simd.recip_approx_f64x4(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f64x4<S> {
+        self.simd.rsqrt_approx_f64x4(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f64x4<S> {
+        self.simd.recip_f64x4(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f64x4<S> {
+        self.simd.rsqrt_f64x4(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.copysign_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ne_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask64x4<S> {
+        self.simd.is_nan_f64x4(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask64x4<S> {
+        self.simd.is_finite_f64x4(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask64x4<S> {
+        self.simd.is_infinite_f64x4(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask64x4<S> {
+        self.simd.is_subnormal_f64x4(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask64x4<S> {
+        self.simd.is_sign_negative_f64x4(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.zip_low_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.zip_high_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.max_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.max_precise_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.min_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd.min_precise_f64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .madd_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .msub_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .nmadd_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .nmsub_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .mul_add_fused_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x4<S> {
+        self.simd
+            .mul_add_fast_f64x4(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f64x4<S> {
+        self.simd.floor_f64x4(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f64x4<S> {
+        self.simd.ceil_f64x4(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f64x4<S> {
+        self.simd.round_f64x4(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f64x4<S> {
+        self.simd.round_ties_even_f64x4(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f64x4<S> {
+        self.simd.fract_f64x4(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f64x4<S> {
+        self.simd.trunc_f64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x4(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct i64x4<S: Simd> {
+    pub val: [i64; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i64; 4], S> for i64x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [i64; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i64x4<S>> for [i64; 4] {
+    #[inline(always)]
+    fn from(value: i64x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i64x4<S> {
+    type Target = [i64; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i64x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i64, S> for i64x4<S> {
+    #[inline(always)]
+    fn simd_from(value: i64, simd: S) -> Self {
+        simd.splat_i64x4(value)
+    }
+}
+impl<S: Simd> Select<i64x4<S>> for mask64x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: i64x4<S>, if_false: i64x4<S>) -> i64x4<S> {
+        self.simd.select_i64x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i64x4<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i64x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> i64x4<S> {
+        self.simd.not_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.add_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.sub_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.mul_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.and_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.or_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.xor_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i64x4<S> {
+        self.simd.shl_i64x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i64x4<S> {
+        self.simd.shr_i64x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.shl_var_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.shr_var_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.min_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.max_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> i64x4<S> {
+        self.simd.shuffle_i64x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd
+            .shuffle2_i64x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i64x4<S> {
+        self.simd.reverse_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.unzip_low_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.unzip_high_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i64x4<S> {
+        self.simd.abs_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i64x4<S> {
+        self.simd.neg_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.abs_diff_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.combine_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i64x2<S>, i64x2<S>) {
+        self.simd.split_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i32x4<S> {
+        self.simd.narrow_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i32x4<S> {
+        self.simd.narrow_saturating_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_i64x4(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_i64x4(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_i64x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i64x4<S> {
+        self.simd.rotate_lanes_left_i64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i64x4<S> {
+        self.simd.rotate_lanes_right_i64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i64x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_i64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i64, S> for i64x4<S> {
+    const N: usize = 4;
+    type Mask = mask64x4<S>;
+    type Block = i64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_i64x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i64x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i64x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i64x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_i64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i64, S> for i64x4<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.shl_var_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.shr_var_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.zip_low_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.zip_high_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.min_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i64x4<S> {
+        self.simd.max_i64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x4(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct u64x4<S: Simd> {
+    pub val: [u64; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u64; 4], S> for u64x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [u64; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u64x4<S>> for [u64; 4] {
+    #[inline(always)]
+    fn from(value: u64x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u64x4<S> {
+    type Target = [u64; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u64x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u64, S> for u64x4<S> {
+    #[inline(always)]
+    fn simd_from(value: u64, simd: S) -> Self {
+        simd.splat_u64x4(value)
+    }
+}
+impl<S: Simd> Select<u64x4<S>> for mask64x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: u64x4<S>, if_false: u64x4<S>) -> u64x4<S> {
+        self.simd.select_u64x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u64x4<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u64x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> u64x4<S> {
+        self.simd.not_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.add_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.sub_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.mul_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.and_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.or_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.xor_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u64x4<S> {
+        self.simd.shl_u64x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u64x4<S> {
+        self.simd.shr_u64x4(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.shl_var_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.shr_var_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.min_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.max_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> u64x4<S> {
+        self.simd.shuffle_u64x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd
+            .shuffle2_u64x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u64x4<S> {
+        self.simd.reverse_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.unzip_low_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.unzip_high_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.abs_diff_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.combine_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u64x2<S>, u64x2<S>) {
+        self.simd.split_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u32x4<S> {
+        self.simd.narrow_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u32x4<S> {
+        self.simd.narrow_saturating_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f16(self) -> f16x16<S> {
+        self.simd.reinterpret_f16_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_bf16(self) -> bf16x16<S> {
+        self.simd.reinterpret_bf16_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x8<S> {
+        self.simd.reinterpret_f32_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x4<S> {
+        self.simd.reinterpret_f64_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_u64x4(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x4<S> {
+        self.simd.cvt_f32_u64x4(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x4<S> {
+        self.simd.cvt_f64_u64x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u64x4<S> {
+        self.simd.rotate_lanes_left_u64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u64x4<S> {
+        self.simd.rotate_lanes_right_u64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u64x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u64, S> for u64x4<S> {
+    const N: usize = 4;
+    type Mask = mask64x4<S>;
+    type Block = u64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u64]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u64) -> Self {
+        simd.splat_u64x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u64x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u64x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u64x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_u64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u64, S> for u64x4<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.shl_var_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.shr_var_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_lt_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_le_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_ge_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_gt_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.zip_low_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.zip_high_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.min_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u64x4<S> {
+        self.simd.max_u64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x4(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x4(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(32))]
+pub struct mask64x4<S: Simd> {
+    pub val: [i64; 4],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i64; 4], S> for mask64x4<S> {
+    #[inline(always)]
+    fn simd_from(val: [i64; 4], simd: S) -> Self {
+        Self {
+            val: [val[0usize], val[1usize], val[2usize], val[3usize]],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask64x4<S>> for [i64; 4] {
+    #[inline(always)]
+    fn from(value: mask64x4<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask64x4<S> {
+    type Target = [i64; 4];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask64x4<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask64x4<S>> for mask64x4<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask64x4<S>, if_false: mask64x4<S>) -> mask64x4<S> {
+        self.simd.select_mask64x4(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask64x4<S> {
+    type Bytes = u8x32<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x32 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask64x4<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask64x4<S> {
+        self.simd.not_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.and_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.or_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.xor_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x4(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<4>>(self) -> mask64x4<S> {
+        self.simd.shuffle_mask64x4::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<4>>(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd
+            .shuffle2_mask64x4::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask64x4<S> {
+        self.simd.reverse_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.unzip_low_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd
+            .unzip_high_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn combine(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.combine_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask64x2<S>, mask64x2<S>) {
+        self.simd.split_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x32<S> {
+        self.simd.reinterpret_i8_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x32<S> {
+        self.simd.reinterpret_u8_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x16<S> {
+        self.simd.reinterpret_i16_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x16<S> {
+        self.simd.reinterpret_u16_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x8<S> {
+        self.simd.reinterpret_i32_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x8<S> {
+        self.simd.reinterpret_u32_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x4<S> {
+        self.simd.reinterpret_i64_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x4<S> {
+        self.simd.reinterpret_u64_mask64x4(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask64x4<S> {
+        self.simd.rotate_lanes_left_mask64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask64x4<S> {
+        self.simd.rotate_lanes_right_mask64x4::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask64x4<S> {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i64, S> for mask64x4<S> {
+    const N: usize = 4;
+    type Mask = mask64x4<S>;
+    type Block = mask64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 4];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_mask64x4(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        block.combine(block)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask64x4(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask64x4::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask64x4::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask64x4::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 4, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x4::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i64, S> for mask64x4<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x4<S> {
+        self.simd.simd_eq_mask64x4(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask64x4(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask64x4(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask64x4(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask64x4(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x4(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x4(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x4(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct f32x16<S: Simd> {
+    pub val: [f32; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f32; 16], S> for f32x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [f32; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x16<S>> for [f32; 16] {
+    #[inline(always)]
+    fn from(value: f32x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f32x16<S> {
+    type Target = [f32; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f32x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f32, S> for f32x16<S> {
+    #[inline(always)]
+    fn simd_from(value: f32, simd: S) -> Self {
+        simd.splat_f32x16(value)
+    }
+}
+impl<S: Simd> Select<f32x16<S>> for mask32x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: f32x16<S>, if_false: f32x16<S>) -> f32x16<S> {
+        self.simd.select_f32x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f32x16<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f32x16<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f32x16<S> {
+        self.simd.abs_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f32x16<S> {
+        self.simd.neg_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f32x16<S> {
+        self.simd.sqrt_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f32x16<S> {
+        self.simd.recip_approx_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f32x16<S> {
+        self.simd.rsqrt_approx_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f32x16<S> {
+        self.simd.recip_f32x16(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f32x16<S> {
+        self.simd.rsqrt_f32x16(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.add_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.sub_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.mul_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.div_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.copysign_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ne_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask32x16<S> {
+        self.simd.is_nan_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask32x16<S> {
+        self.simd.is_finite_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask32x16<S> {
+        self.simd.is_infinite_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask32x16<S> {
+        self.simd.is_subnormal_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask32x16<S> {
+        self.simd.is_sign_negative_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.max_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.max_precise_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.min_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.min_precise_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f32x16<S> {
+        self.simd.floor_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f32x16<S> {
+        self.simd.ceil_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f32x16<S> {
+        self.simd.round_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f32x16<S> {
+        self.simd.round_ties_even_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f32x16<S> {
+        self.simd.fract_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f32x16<S> {
+        self.simd.trunc_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> f32x16<S> {
+        self.simd.shuffle_f32x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .shuffle2_f32x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f32x16<S> {
+        self.simd.reverse_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.unzip_low_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.unzip_high_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (f32x8<S>, f32x8<S>) {
+        self.simd.split_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_f32x16(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i8`, rounding toward zero. See [`cvt_i8_round`](Self::cvt_i8_round) to round to nearest.\n\n Lanes outside the range of `i8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i8(self) -> i8x16<S> {
+        self.simd.cvt_i8_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i8_round(self) -> i8x16<S> {
+        self.simd.cvt_i8_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding toward zero. See [`cvt_u8_round`](Self::cvt_u8_round) to round to nearest.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8(self) -> u8x16<S> {
+        self.simd.cvt_u8_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8_round(self) -> u8x16<S> {
+        self.simd.cvt_u8_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding toward zero. See [`cvt_i16_round`](Self::cvt_i16_round) to round to nearest.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16(self) -> i16x16<S> {
+        self.simd.cvt_i16_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16_round(self) -> i16x16<S> {
+        self.simd.cvt_i16_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16(self) -> u16x16<S> {
+        self.simd.cvt_u16_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16_round(self) -> u16x16<S> {
+        self.simd.cvt_u16_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x16<S> {
+        self.simd.cvt_i32_f32x16(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x16<S> {
+        self.simd.cvt_i32_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32(self) -> u32x16<S> {
+        self.simd.cvt_u32_f32x16(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32_round(self) -> u32x16<S> {
+        self.simd.cvt_u32_round_f32x16(self)
+    }
+    #[doc = " Convert each lane to `bf16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `bf16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_bf16(self) -> bf16x16<S> {
+        self.simd.cvt_bf16_f32x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f32x16<S> {
+        self.simd.rotate_lanes_left_f32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f32x16<S> {
+        self.simd.rotate_lanes_right_f32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f32x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_f32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f32, S> for f32x16<S> {
+    const N: usize = 16;
+    type Mask = mask32x16<S>;
+    type Block = f32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f32]) -> Self {
+        let mut val = [0.0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f32) -> Self {
+        simd.splat_f32x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f32x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f32x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f32x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_f32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f32, S> for f32x16<S> {
+    #[inline(always)]
+    fn abs(self) -> f32x16<S> {
+        self.simd.abs_f32x16(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f32x16<S> {
+        self.simd.sqrt_f32x16(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f32x16<S> {
+        self.simd.recip_approx_f32x16(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f32x16<S> {
+        self.simd.rsqrt_approx_f32x16(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f32x16<S> {
+        self.simd.recip_f32x16(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f32x16<S> {
+        self.simd.rsqrt_f32x16(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.copysign_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ne_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn is_nan(self) -> mask32x16<S> {
+        self.simd.is_nan_f32x16(self)
+    }
+    #[inline(always)]
+    fn is_finite(self) -> mask32x16<S> {
+        self.simd.is_finite_f32x16(self)
+    }
+    #[inline(always)]
+    fn is_infinite(self) -> mask32x16<S> {
+        self.simd.is_infinite_f32x16(self)
+    }
+    #[inline(always)]
+    fn is_subnormal(self) -> mask32x16<S> {
+        self.simd.is_subnormal_f32x16(self)
+    }
+    #[inline(always)]
+    fn is_sign_negative(self) -> mask32x16<S> {
+        self.simd.is_sign_negative_f32x16(self)
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.zip_low_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.zip_high_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.max_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.max_precise_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.min_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd.min_precise_f32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .madd_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .msub_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .nmadd_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .nmsub_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .mul_add_fused_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f32x16<S> {
+        self.simd
+            .mul_add_fast_f32x16(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn floor(self) -> f32x16<S> {
+        self.simd.floor_f32x16(self)
+    }
+    #[inline(always)]
+    fn ceil(self) -> f32x16<S> {
+        self.simd.ceil_f32x16(self)
+    }
+    #[inline(always)]
+    fn round(self) -> f32x16<S> {
+        self.simd.round_f32x16(self)
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> f32x16<S> {
+        self.simd.round_ties_even_f32x16(self)
+    }
+    #[inline(always)]
+    fn fract(self) -> f32x16<S> {
+        self.simd.fract_f32x16(self)
+    }
+    #[inline(always)]
+    fn trunc(self) -> f32x16<S> {
+        self.simd.trunc_f32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> f32 {
+        self.simd.reduce_add_f32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> f32 {
+        self.simd.reduce_min_f32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> f32 {
+        self.simd.reduce_max_f32x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct i8x64<S: Simd> {
+    pub val: [i8; 64],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i8; 64], S> for i8x64<S> {
+    #[inline(always)]
+    fn simd_from(val: [i8; 64], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+                val[32usize],
+                val[33usize],
+                val[34usize],
+                val[35usize],
+                val[36usize],
+                val[37usize],
+                val[38usize],
+                val[39usize],
+                val[40usize],
+                val[41usize],
+                val[42usize],
+                val[43usize],
+                val[44usize],
+                val[45usize],
+                val[46usize],
+                val[47usize],
+                val[48usize],
+                val[49usize],
+                val[50usize],
+                val[51usize],
+                val[52usize],
+                val[53usize],
+                val[54usize],
+                val[55usize],
+                val[56usize],
+                val[57usize],
+                val[58usize],
+                val[59usize],
+                val[60usize],
+                val[61usize],
+                val[62usize],
+                val[63usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i8x64<S>> for [i8; 64] {
+    #[inline(always)]
+    fn from(value: i8x64<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i8x64<S> {
+    type Target = [i8; 64];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i8x64<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i8, S> for i8x64<S> {
+    #[inline(always)]
+    fn simd_from(value: i8, simd: S) -> Self {
+        simd.splat_i8x64(value)
+    }
+}
+impl<S: Simd> Select<i8x64<S>> for mask8x64<S> {
+    #[inline(always)]
+    fn select(self, if_true: i8x64<S>, if_false: i8x64<S>) -> i8x64<S> {
+        self.simd.select_i8x64(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i8x64<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i8x64<S> {
+    #[inline(always)]
+    pub fn not(self) -> i8x64<S> {
+        self.simd.not_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.add_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.sub_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.mul_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.and_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.or_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.xor_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i8x64<S> {
+        self.simd.shl_i8x64(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i8x64<S> {
+        self.simd.shr_i8x64(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.shl_var_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.shr_var_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_lt_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_le_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_ge_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_gt_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.min_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.max_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<64>>(self) -> i8x64<S> {
+        self.simd.shuffle_i8x64::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<64>>(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd
+            .shuffle2_i8x64::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i8x64<S> {
+        self.simd.reverse_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.unzip_low_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.unzip_high_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd
+            .saturating_add_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd
+            .saturating_sub_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i8x64<S> {
+        self.simd.abs_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i8x64<S> {
+        self.simd.neg_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.abs_diff_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.mul_high_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i8x32<S>, i8x32<S>) {
+        self.simd.split_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i16x32<S> {
+        self.simd.widen_low_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i16x32<S> {
+        self.simd.widen_high_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_i8x64(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i8x64<S> {
+        self.simd.rotate_lanes_left_i8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i8x64<S> {
+        self.simd.rotate_lanes_right_i8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i8x64<S> {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_i8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i8, S> for i8x64<S> {
+    const N: usize = 64;
+    type Mask = mask8x64<S>;
+    type Block = i8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 64];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_i8x64(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i8x64(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i8x64::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i8x64::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_i8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i8, S> for i8x64<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.shl_var_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.shr_var_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_lt_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_le_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_ge_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_gt_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.zip_low_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.zip_high_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.min_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i8x64<S> {
+        self.simd.max_i8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i8 {
+        self.simd.reduce_add_i8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i8 {
+        self.simd.reduce_min_i8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i8 {
+        self.simd.reduce_max_i8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i8 {
+        self.simd.reduce_and_i8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i8 {
+        self.simd.reduce_or_i8x64(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct u8x64<S: Simd> {
+    pub val: [u8; 64],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u8; 64], S> for u8x64<S> {
+    #[inline(always)]
+    fn simd_from(val: [u8; 64], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+                val[32usize],
+                val[33usize],
+                val[34usize],
+                val[35usize],
+                val[36usize],
+                val[37usize],
+                val[38usize],
+                val[39usize],
+                val[40usize],
+                val[41usize],
+                val[42usize],
+                val[43usize],
+                val[44usize],
+                val[45usize],
+                val[46usize],
+                val[47usize],
+                val[48usize],
+                val[49usize],
+                val[50usize],
+                val[51usize],
+                val[52usize],
+                val[53usize],
+                val[54usize],
+                val[55usize],
+                val[56usize],
+                val[57usize],
+                val[58usize],
+                val[59usize],
+                val[60usize],
+                val[61usize],
+                val[62usize],
+                val[63usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x64<S>> for [u8; 64] {
+    #[inline(always)]
+    fn from(value: u8x64<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u8x64<S> {
+    type Target = [u8; 64];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u8x64<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u8, S> for u8x64<S> {
+    #[inline(always)]
+    fn simd_from(value: u8, simd: S) -> Self {
+        simd.splat_u8x64(value)
+    }
+}
+impl<S: Simd> Select<u8x64<S>> for mask8x64<S> {
+    #[inline(always)]
+    fn select(self, if_true: u8x64<S>, if_false: u8x64<S>) -> u8x64<S> {
+        self.simd.select_u8x64(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u8x64<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u8x64<S> {
+    #[inline(always)]
+    pub fn not(self) -> u8x64<S> {
+        self.simd.not_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.add_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.sub_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.mul_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.and_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.or_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.xor_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u8x64<S> {
+        self.simd.shl_u8x64(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u8x64<S> {
+        self.simd.shr_u8x64(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.shl_var_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.shr_var_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_lt_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_le_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_ge_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_gt_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.min_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.max_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<64>>(self) -> u8x64<S> {
+        self.simd.shuffle_u8x64::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<64>>(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd
+            .shuffle2_u8x64::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u8x64<S> {
+        self.simd.reverse_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.unzip_low_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.unzip_high_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd
+            .saturating_add_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd
+            .saturating_sub_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.abs_diff_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.avg_round_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.mul_high_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[doc = r" Look up each byte of `idx` in `self`, with zero for indices"]
+    #[doc = r" past the end."]
+    #[inline(always)]
+    pub fn swizzle_dyn(self, idx: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.swizzle_dyn_u8x64(self, idx.simd_into(self.simd))
+    }
+    #[doc = " Look up each byte of `self` in a 32 byte table, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_32(self, table: u8x32<S>) -> u8x64<S> {
+        self.simd.lookup_32_u8x64(table, self)
+    }
+    #[doc = " Look up each byte of `self` in a 48 byte table of three vectors, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_48(self, table: [u8x16<S>; 3]) -> u8x64<S> {
+        self.simd.lookup_48_u8x64(table, self)
+    }
+    #[doc = " Look up each byte of `self` in a 64 byte table, with zero for indices past the end."]
+    #[inline(always)]
+    pub fn lookup_64(self, table: u8x64<S>) -> u8x64<S> {
+        self.simd.lookup_64_u8x64(table, self)
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u8x32<S>, u8x32<S>) {
+        self.simd.split_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u16x32<S> {
+        self.simd.widen_low_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u16x32<S> {
+        self.simd.widen_high_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_u8x64(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u8x64<S> {
+        self.simd.rotate_lanes_left_u8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u8x64<S> {
+        self.simd.rotate_lanes_right_u8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u8x64<S> {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_u8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u8, S> for u8x64<S> {
+    const N: usize = 64;
+    type Mask = mask8x64<S>;
+    type Block = u8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u8]) -> Self {
+        let mut val = [0; 64];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u8) -> Self {
+        simd.splat_u8x64(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u8x64(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u8x64::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u8x64::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_u8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u8, S> for u8x64<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.shl_var_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.shr_var_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_lt_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_le_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_ge_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_gt_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.zip_low_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.zip_high_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.min_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u8x64<S> {
+        self.simd.max_u8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u8 {
+        self.simd.reduce_add_u8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u8 {
+        self.simd.reduce_min_u8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u8 {
+        self.simd.reduce_max_u8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u8 {
+        self.simd.reduce_and_u8x64(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u8 {
+        self.simd.reduce_or_u8x64(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct mask8x64<S: Simd> {
+    pub val: [i8; 64],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i8; 64], S> for mask8x64<S> {
+    #[inline(always)]
+    fn simd_from(val: [i8; 64], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+                val[32usize],
+                val[33usize],
+                val[34usize],
+                val[35usize],
+                val[36usize],
+                val[37usize],
+                val[38usize],
+                val[39usize],
+                val[40usize],
+                val[41usize],
+                val[42usize],
+                val[43usize],
+                val[44usize],
+                val[45usize],
+                val[46usize],
+                val[47usize],
+                val[48usize],
+                val[49usize],
+                val[50usize],
+                val[51usize],
+                val[52usize],
+                val[53usize],
+                val[54usize],
+                val[55usize],
+                val[56usize],
+                val[57usize],
+                val[58usize],
+                val[59usize],
+                val[60usize],
+                val[61usize],
+                val[62usize],
+                val[63usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x64<S>> for [i8; 64] {
+    #[inline(always)]
+    fn from(value: mask8x64<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask8x64<S> {
+    type Target = [i8; 64];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask8x64<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask8x64<S>> for mask8x64<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask8x64<S>, if_false: mask8x64<S>) -> mask8x64<S> {
+        self.simd.select_mask8x64(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask8x64<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask8x64<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask8x64<S> {
+        self.simd.not_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.and_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.or_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.xor_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x64(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<64>>(self) -> mask8x64<S> {
+        self.simd.shuffle_mask8x64::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<64>>(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd
+            .shuffle2_mask8x64::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask8x64<S> {
+        self.simd.reverse_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.unzip_low_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd
+            .unzip_high_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask8x32<S>, mask8x32<S>) {
+        self.simd.split_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_mask8x64(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask8x64<S> {
+        self.simd.rotate_lanes_left_mask8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask8x64<S> {
+        self.simd.rotate_lanes_right_mask8x64::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask8x64<S> {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i8, S> for mask8x64<S> {
+    const N: usize = 64;
+    type Mask = mask8x64<S>;
+    type Block = mask8x16<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i8] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i8] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i8]) -> Self {
+        let mut val = [0; 64];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i8) -> Self {
+        simd.splat_mask8x64(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask8x64(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask8x64::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask8x64::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask8x64::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 64, "lane index out of range") };
+        self.simd.broadcast_lane_mask8x64::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i8, S> for mask8x64<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask8x64<S> {
+        self.simd.simd_eq_mask8x64(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask8x64(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask8x64(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask8x64(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask8x64(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask8x64(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask8x64(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask8x64(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct i16x32<S: Simd> {
+    pub val: [i16; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i16; 32], S> for i16x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [i16; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i16x32<S>> for [i16; 32] {
+    #[inline(always)]
+    fn from(value: i16x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i16x32<S> {
+    type Target = [i16; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i16x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i16, S> for i16x32<S> {
+    #[inline(always)]
+    fn simd_from(value: i16, simd: S) -> Self {
+        simd.splat_i16x32(value)
+    }
+}
+impl<S: Simd> Select<i16x32<S>> for mask16x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: i16x32<S>, if_false: i16x32<S>) -> i16x32<S> {
+        self.simd.select_i16x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i16x32<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i16x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> i16x32<S> {
+        self.simd.not_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.add_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.sub_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.mul_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.and_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.or_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.xor_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i16x32<S> {
+        self.simd.shl_i16x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i16x32<S> {
+        self.simd.shr_i16x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.shl_var_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.shr_var_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_lt_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_le_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_ge_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_gt_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.min_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.max_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> i16x32<S> {
+        self.simd.shuffle_i16x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd
+            .shuffle2_i16x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i16x32<S> {
+        self.simd.reverse_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.unzip_low_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.unzip_high_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd
+            .saturating_add_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd
+            .saturating_sub_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i16x32<S> {
+        self.simd.abs_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i16x32<S> {
+        self.simd.neg_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.abs_diff_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.mul_high_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_q15_round_sat(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd
+            .mul_q15_round_sat_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn dot_i16(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.dot_i16_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i16x16<S>, i16x16<S>) {
+        self.simd.split_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i32x16<S> {
+        self.simd.widen_low_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i32x16<S> {
+        self.simd.widen_high_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i8x32<S> {
+        self.simd.narrow_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i8x32<S> {
+        self.simd.narrow_saturating_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_i16x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i16x32<S> {
+        self.simd.rotate_lanes_left_i16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i16x32<S> {
+        self.simd.rotate_lanes_right_i16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i16x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_i16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for i16x32<S> {
+    const N: usize = 32;
+    type Mask = mask16x32<S>;
+    type Block = i16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_i16x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i16x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i16x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i16x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_i16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i16, S> for i16x32<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.shl_var_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.shr_var_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_lt_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_le_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_ge_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_gt_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.zip_low_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.zip_high_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.min_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
+        self.simd.max_i16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i16 {
+        self.simd.reduce_add_i16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i16 {
+        self.simd.reduce_min_i16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i16 {
+        self.simd.reduce_max_i16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i16 {
+        self.simd.reduce_and_i16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i16 {
+        self.simd.reduce_or_i16x32(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct u16x32<S: Simd> {
+    pub val: [u16; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u16; 32], S> for u16x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [u16; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x32<S>> for [u16; 32] {
+    #[inline(always)]
+    fn from(value: u16x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u16x32<S> {
+    type Target = [u16; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u16x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u16, S> for u16x32<S> {
+    #[inline(always)]
+    fn simd_from(value: u16, simd: S) -> Self {
+        simd.splat_u16x32(value)
+    }
+}
+impl<S: Simd> Select<u16x32<S>> for mask16x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: u16x32<S>, if_false: u16x32<S>) -> u16x32<S> {
+        self.simd.select_u16x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u16x32<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u16x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> u16x32<S> {
+        self.simd.not_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.add_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.sub_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.mul_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.and_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.or_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.xor_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u16x32<S> {
+        self.simd.shl_u16x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u16x32<S> {
+        self.simd.shr_u16x32(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.shl_var_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.shr_var_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_lt_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_le_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_ge_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_gt_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.min_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.max_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> u16x32<S> {
+        self.simd.shuffle_u16x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd
+            .shuffle2_u16x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u16x32<S> {
+        self.simd.reverse_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.unzip_low_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.unzip_high_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_add(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd
+            .saturating_add_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd
+            .saturating_sub_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.abs_diff_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn avg_round(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.avg_round_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul_high(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.mul_high_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u16x16<S>, u16x16<S>) {
+        self.simd.split_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u32x16<S> {
+        self.simd.widen_low_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u32x16<S> {
+        self.simd.widen_high_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u8x32<S> {
+        self.simd.narrow_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u8x32<S> {
+        self.simd.narrow_saturating_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_u16x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u16x32<S> {
+        self.simd.rotate_lanes_left_u16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u16x32<S> {
+        self.simd.rotate_lanes_right_u16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u16x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_u16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u16, S> for u16x32<S> {
+    const N: usize = 32;
+    type Mask = mask16x32<S>;
+    type Block = u16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u16]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u16) -> Self {
+        simd.splat_u16x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u16x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u16x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u16x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_u16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u16, S> for u16x32<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.shl_var_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.shr_var_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_lt_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_le_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_ge_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_gt_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.zip_low_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.zip_high_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.min_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
+        self.simd.max_u16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u16 {
+        self.simd.reduce_add_u16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u16 {
+        self.simd.reduce_min_u16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u16 {
+        self.simd.reduce_max_u16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u16 {
+        self.simd.reduce_and_u16x32(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u16 {
+        self.simd.reduce_or_u16x32(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct mask16x32<S: Simd> {
+    pub val: [i16; 32],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i16; 32], S> for mask16x32<S> {
+    #[inline(always)]
+    fn simd_from(val: [i16; 32], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+                val[16usize],
+                val[17usize],
+                val[18usize],
+                val[19usize],
+                val[20usize],
+                val[21usize],
+                val[22usize],
+                val[23usize],
+                val[24usize],
+                val[25usize],
+                val[26usize],
+                val[27usize],
+                val[28usize],
+                val[29usize],
+                val[30usize],
+                val[31usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x32<S>> for [i16; 32] {
+    #[inline(always)]
+    fn from(value: mask16x32<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask16x32<S> {
+    type Target = [i16; 32];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask16x32<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask16x32<S>> for mask16x32<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask16x32<S>, if_false: mask16x32<S>) -> mask16x32<S> {
+        self.simd.select_mask16x32(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask16x32<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask16x32<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask16x32<S> {
+        self.simd.not_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.and_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.or_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.xor_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x32(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<32>>(self) -> mask16x32<S> {
+        self.simd.shuffle_mask16x32::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<32>>(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd
+            .shuffle2_mask16x32::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask16x32<S> {
+        self.simd.reverse_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd
+            .unzip_low_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd
+            .unzip_high_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask16x16<S>, mask16x16<S>) {
+        self.simd.split_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_mask16x32(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask16x32<S> {
+        self.simd.rotate_lanes_left_mask16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask16x32<S> {
+        self.simd.rotate_lanes_right_mask16x32::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask16x32<S> {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i16, S> for mask16x32<S> {
+    const N: usize = 32;
+    type Mask = mask16x32<S>;
+    type Block = mask16x8<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i16] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i16]) -> Self {
+        let mut val = [0; 32];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i16) -> Self {
+        simd.splat_mask16x32(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask16x32(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_low_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask16x32::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask16x32::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask16x32::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 32, "lane index out of range") };
+        self.simd.broadcast_lane_mask16x32::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i16, S> for mask16x32<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
+        self.simd.simd_eq_mask16x32(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask16x32(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask16x32(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask16x32(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask16x32(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask16x32(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask16x32(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask16x32(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct i32x16<S: Simd> {
+    pub val: [i32; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 16], S> for i32x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x16<S>> for [i32; 16] {
+    #[inline(always)]
+    fn from(value: i32x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for i32x16<S> {
+    type Target = [i32; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for i32x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<i32, S> for i32x16<S> {
+    #[inline(always)]
+    fn simd_from(value: i32, simd: S) -> Self {
+        simd.splat_i32x16(value)
+    }
+}
+impl<S: Simd> Select<i32x16<S>> for mask32x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: i32x16<S>, if_false: i32x16<S>) -> i32x16<S> {
+        self.simd.select_i32x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for i32x16<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> i32x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> i32x16<S> {
+        self.simd.not_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.add_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.sub_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.mul_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.and_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.or_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.xor_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> i32x16<S> {
+        self.simd.shl_i32x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> i32x16<S> {
+        self.simd.shr_i32x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.shl_var_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.shr_var_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.min_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.max_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> i32x16<S> {
+        self.simd.shuffle_i32x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd
+            .shuffle2_i32x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i32x16<S> {
+        self.simd.reverse_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.unzip_low_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.unzip_high_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i32x16<S> {
+        self.simd.abs_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i32x16<S> {
+        self.simd.neg_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.abs_diff_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i32x8<S>, i32x8<S>) {
+        self.simd.split_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> i64x8<S> {
+        self.simd.widen_low_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> i64x8<S> {
+        self.simd.widen_high_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i16x16<S> {
+        self.simd.narrow_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i16x16<S> {
+        self.simd.narrow_saturating_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_i32x16(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_i32x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_i32x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i32x16<S> {
+        self.simd.rotate_lanes_left_i32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i32x16<S> {
+        self.simd.rotate_lanes_right_i32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i32x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for i32x16<S> {
+    const N: usize = 16;
+    type Mask = mask32x16<S>;
+    type Block = i32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_i32x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i32x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i32x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i32x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_i32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<i32, S> for i32x16<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.shl_var_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.shr_var_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.zip_low_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.zip_high_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.min_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
+        self.simd.max_i32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> i32 {
+        self.simd.reduce_add_i32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> i32 {
+        self.simd.reduce_min_i32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> i32 {
+        self.simd.reduce_max_i32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> i32 {
+        self.simd.reduce_and_i32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> i32 {
+        self.simd.reduce_or_i32x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct u32x16<S: Simd> {
+    pub val: [u32; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[u32; 16], S> for u32x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [u32; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x16<S>> for [u32; 16] {
+    #[inline(always)]
+    fn from(value: u32x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for u32x16<S> {
+    type Target = [u32; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for u32x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<u32, S> for u32x16<S> {
+    #[inline(always)]
+    fn simd_from(value: u32, simd: S) -> Self {
+        simd.splat_u32x16(value)
+    }
+}
+impl<S: Simd> Select<u32x16<S>> for mask32x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: u32x16<S>, if_false: u32x16<S>) -> u32x16<S> {
+        self.simd.select_u32x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u32x16<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> u32x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> u32x16<S> {
+        self.simd.not_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.add_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.sub_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.mul_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.and_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.or_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.xor_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shl(self, shift: u32) -> u32x16<S> {
+        self.simd.shl_u32x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shr(self, shift: u32) -> u32x16<S> {
+        self.simd.shr_u32x16(self, shift)
+    }
+    #[inline(always)]
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.shl_var_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.shr_var_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.min_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.max_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> u32x16<S> {
+        self.simd.shuffle_u32x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd
+            .shuffle2_u32x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> u32x16<S> {
+        self.simd.reverse_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.unzip_low_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.unzip_high_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.abs_diff_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (u32x8<S>, u32x8<S>) {
+        self.simd.split_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_low(self) -> u64x8<S> {
+        self.simd.widen_low_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn widen_high(self) -> u64x8<S> {
+        self.simd.widen_high_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> u16x16<S> {
+        self.simd.narrow_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> u16x16<S> {
+        self.simd.narrow_saturating_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_u32x16(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x16<S> {
+        self.simd.cvt_f16_u32x16(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x16<S> {
+        self.simd.cvt_f32_u32x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u32x16<S> {
+        self.simd.rotate_lanes_left_u32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u32x16<S> {
+        self.simd.rotate_lanes_right_u32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u32x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u32, S> for u32x16<S> {
+    const N: usize = 16;
+    type Mask = mask32x16<S>;
+    type Block = u32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u32]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u32) -> Self {
+        simd.splat_u32x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u32x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u32x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u32x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_u32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u32, S> for u32x16<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.shl_var_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.shr_var_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_lt_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_le_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_ge_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_gt_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.zip_low_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.zip_high_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.min_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
+        self.simd.max_u32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn reduce_add(self) -> u32 {
+        self.simd.reduce_add_u32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_min(self) -> u32 {
+        self.simd.reduce_min_u32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_max(self) -> u32 {
+        self.simd.reduce_max_u32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_and(self) -> u32 {
+        self.simd.reduce_and_u32x16(self)
+    }
+    #[inline(always)]
+    fn reduce_or(self) -> u32 {
+        self.simd.reduce_or_u32x16(self)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct mask32x16<S: Simd> {
+    pub val: [i32; 16],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[i32; 16], S> for mask32x16<S> {
+    #[inline(always)]
+    fn simd_from(val: [i32; 16], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+                val[8usize],
+                val[9usize],
+                val[10usize],
+                val[11usize],
+                val[12usize],
+                val[13usize],
+                val[14usize],
+                val[15usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x16<S>> for [i32; 16] {
+    #[inline(always)]
+    fn from(value: mask32x16<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for mask32x16<S> {
+    type Target = [i32; 16];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for mask32x16<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> Select<mask32x16<S>> for mask32x16<S> {
+    #[inline(always)]
+    fn select(self, if_true: mask32x16<S>, if_false: mask32x16<S>) -> mask32x16<S> {
+        self.simd.select_mask32x16(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for mask32x16<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> mask32x16<S> {
+    #[inline(always)]
+    pub fn not(self) -> mask32x16<S> {
+        self.simd.not_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.and_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.or_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.xor_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn any(self) -> bool {
+        self.simd.any_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn all(self) -> bool {
+        self.simd.all_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn none(self) -> bool {
+        self.simd.none_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x16(bits)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<16>>(self) -> mask32x16<S> {
+        self.simd.shuffle_mask32x16::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<16>>(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd
+            .shuffle2_mask32x16::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> mask32x16<S> {
+        self.simd.reverse_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd
+            .unzip_low_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd
+            .unzip_high_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (mask32x8<S>, mask32x8<S>) {
+        self.simd.split_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_mask32x16(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask32x16<S> {
+        self.simd.rotate_lanes_left_mask32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask32x16<S> {
+        self.simd.rotate_lanes_right_mask32x16::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> mask32x16<S> {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<i32, S> for mask32x16<S> {
+    const N: usize = 16;
+    type Mask = mask32x16<S>;
+    type Block = mask32x4<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[i32] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [i32] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[i32]) -> Self {
+        let mut val = [0; 16];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: i32) -> Self {
+        simd.splat_mask32x16(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask32x16(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_low_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask32x16::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask32x16::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask32x16::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 16, "lane index out of range") };
+        self.simd.broadcast_lane_mask32x16::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdMask<i32, S> for mask32x16<S> {
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
+        self.simd.simd_eq_mask32x16(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.simd.any_mask32x16(self)
+    }
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.simd.all_mask32x16(self)
+    }
+    #[inline(always)]
+    fn none(self) -> bool {
+        self.simd.none_mask32x16(self)
+    }
+    #[inline(always)]
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask32x16(self)
+    }
+    #[inline(always)]
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask32x16(self)
+    }
+    #[inline(always)]
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask32x16(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask32x16(bits)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct f64x8<S: Simd> {
+    pub val: [f64; 8],
+    pub simd: S,
+}
+impl<S: Simd> SimdFrom<[f64; 8], S> for f64x8<S> {
+    #[inline(always)]
+    fn simd_from(val: [f64; 8], simd: S) -> Self {
+        Self {
+            val: [
+                val[0usize],
+                val[1usize],
+                val[2usize],
+                val[3usize],
+                val[4usize],
+                val[5usize],
+                val[6usize],
+                val[7usize],
+            ],
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x8<S>> for [f64; 8] {
+    #[inline(always)]
+    fn from(value: f64x8<S>) -> Self {
+        value.val
+    }
+}
+impl<S: Simd> core::ops::Deref for f64x8<S> {
+    type Target = [f64; 8];
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.val
+    }
+}
+impl<S: Simd> core::ops::DerefMut for f64x8<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.val
+    }
+}
+impl<S: Simd> SimdFrom<f64, S> for f64x8<S> {
+    #[inline(always)]
+    fn simd_from(value: f64, simd: S) -> Self {
+        simd.splat_f64x8(value)
+    }
+}
+impl<S: Simd> Select<f64x8<S>> for mask64x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: f64x8<S>, if_false: f64x8<S>) -> f64x8<S> {
+        self.simd.select_f64x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for f64x8<S> {
+    type Bytes = u8x64<S>;
+    #[inline(always)]
+    fn to_bytes(self) -> Self::Bytes {
+        unsafe {
+            u8x64 {
+                val: core::mem::transmute(self.val),
+                simd: self.simd,
+            }
+        }
+    }
+    #[inline(always)]
+    fn from_bytes(value: Self::Bytes) -> Self {
+        unsafe {
+            Self {
+                val: core::mem::transmute(value.val),
+                simd: value.simd,
+            }
+        }
+    }
+}
+impl<S: Simd> f64x8<S> {
+    #[inline(always)]
+    pub fn abs(self) -> f64x8<S> {
+        self.simd.abs_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> f64x8<S> {
+        self.simd.neg_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn sqrt(self) -> f64x8<S> {
+        self.simd.sqrt_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn recip_approx(self) -> f64x8<S> {
+        self.simd.recip_approx_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn rsqrt_approx(self) -> f64x8<S> {
+        self.simd.rsqrt_approx_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn recip(self, steps: u32) -> f64x8<S> {
+        self.simd.recip_f64x8(self, steps)
+    }
+    #[inline(always)]
+    pub fn rsqrt(self, steps: u32) -> f64x8<S> {
+        self.simd.rsqrt_f64x8(self, steps)
+    }
+    #[inline(always)]
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.add_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.sub_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.mul_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn div(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.div_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.copysign_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ne_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn is_nan(self) -> mask64x8<S> {
+        self.simd.is_nan_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn is_finite(self) -> mask64x8<S> {
+        self.simd.is_finite_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn is_infinite(self) -> mask64x8<S> {
+        self.simd.is_infinite_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn is_subnormal(self) -> mask64x8<S> {
+        self.simd.is_subnormal_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn is_sign_negative(self) -> mask64x8<S> {
+        self.simd.is_sign_negative_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.max_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.max_precise_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.min_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.min_precise_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn floor(self) -> f64x8<S> {
+        self.simd.floor_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn ceil(self) -> f64x8<S> {
+        self.simd.ceil_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn round(self) -> f64x8<S> {
+        self.simd.round_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn round_ties_even(self) -> f64x8<S> {
+        self.simd.round_ties_even_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn fract(self) -> f64x8<S> {
+        self.simd.fract_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn trunc(self) -> f64x8<S> {
+        self.simd.trunc_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> f64x8<S> {
+        self.simd.shuffle_f64x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .shuffle2_f64x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> f64x8<S> {
+        self.simd.reverse_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.unzip_low_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.unzip_high_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (f64x4<S>, f64x4<S>) {
+        self.simd.split_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_f64x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_f64x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f32` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding toward zero. See [`cvt_u8_round`](Self::cvt_u8_round) to round to nearest.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8(self) -> u8x8<S> {
+        self.simd.cvt_u8_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u8`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u8` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u8_round(self) -> u8x8<S> {
+        self.simd.cvt_u8_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding toward zero. See [`cvt_i16_round`](Self::cvt_i16_round) to round to nearest.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16(self) -> i16x8<S> {
+        self.simd.cvt_i16_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i16_round(self) -> i16x8<S> {
+        self.simd.cvt_i16_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding toward zero. See [`cvt_u16_round`](Self::cvt_u16_round) to round to nearest.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16(self) -> u16x8<S> {
+        self.simd.cvt_u16_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u16`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u16` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u16_round(self) -> u16x8<S> {
+        self.simd.cvt_u16_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding toward zero. See [`cvt_i32_round`](Self::cvt_i32_round) to round to nearest.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32(self) -> i32x8<S> {
+        self.simd.cvt_i32_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i32_round(self) -> i32x8<S> {
+        self.simd.cvt_i32_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding toward zero. See [`cvt_u32_round`](Self::cvt_u32_round) to round to nearest.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32(self) -> u32x8<S> {
+        self.simd.cvt_u32_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u32`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u32` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u32_round(self) -> u32x8<S> {
+        self.simd.cvt_u32_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding toward zero. See [`cvt_i64_round`](Self::cvt_i64_round) to round to nearest.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64(self) -> i64x8<S> {
+        self.simd.cvt_i64_f64x8(self)
+    }
+    #[doc = " Convert each lane to `i64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `i64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_i64_round(self) -> i64x8<S> {
+        self.simd.cvt_i64_round_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding toward zero. See [`cvt_u64_round`](Self::cvt_u64_round) to round to nearest.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64(self) -> u64x8<S> {
+        self.simd.cvt_u64_f64x8(self)
+    }
+    #[doc = " Convert each lane to `u64`, rounding to nearest, ties to even.\n\n Lanes outside the range of `u64` saturate to its minimum or maximum, and NaN gives 0, like `as`."]
+    #[inline(always)]
+    pub fn cvt_u64_round(self) -> u64x8<S> {
+        self.simd.cvt_u64_round_f64x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> f64x8<S> {
+        self.simd.rotate_lanes_left_f64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> f64x8<S> {
+        self.simd.rotate_lanes_right_f64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> f64x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f64x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<f64, S> for f64x8<S> {
+    const N: usize = 8;
+    type Mask = mask64x8<S>;
+    type Block = f64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[f64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[f64]) -> Self {
+        let mut val = [0.0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: f64) -> Self {
+        simd.splat_f64x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_f64x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_f64x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_f64x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_f64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_f64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_f64x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdFloat<f64, S> for f64x8<S> {
+    #[inline(always)]
+    fn abs(self) -> f64x8<S> {
+        self.simd.abs_f64x8(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> f64x8<S> {
+        self.simd.sqrt_f64x8(self)
+    }
+    #[inline(always)]
+    fn recip_approx(self) -> f64x8<S> {
+        self.simd.recip_approx_f64x8(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx(self) -> f64x8<S> {
+        self.simd.rsqrt_approx_f64x8(self)
+    }
+    #[inline(always)]
+    fn recip(self, steps: u32) -> f64x8<S> {
+        self.simd.recip_f64x8(self, steps)
+    }
+    #[inline(always)]
+    fn rsqrt(self, steps: u32) -> f64x8<S> {
+        self.simd.rsqrt_f64x8(self, steps)
+    }
+    #[inline(always)]
+    fn copysign(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.copysign_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ne(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ne_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_f64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.mul_i16x32(self, rhs.simd_into(self.simd))
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.and_i16x32(self, rhs.simd_into(self.simd))
+    fn is_nan(self) -> mask64x8<S> {
+        self.simd.is_nan_f64x8(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.or_i16x32(self, rhs.simd_into(self.simd))
+    fn is_finite(self) -> mask64x8<S> {
+        self.simd.is_finite_f64x8(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.xor_i16x32(self, rhs.simd_into(self.simd))
+    fn is_infinite(self) -> mask64x8<S> {
+        self.simd.is_infinite_f64x8(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i16x32<S> {
-        self.simd.shr_i16x32(self, shift)
+    fn is_subnormal(self) -> mask64x8<S> {
+        self.simd.is_subnormal_f64x8(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_i16x32(self, rhs.simd_into(self.simd))
+    fn is_sign_negative(self) -> mask64x8<S> {
+        self.simd.is_sign_negative_f64x8(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_lt_i16x32(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.zip_low_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_le_i16x32(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.zip_high_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_ge_i16x32(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.max_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_gt_i16x32(self, rhs.simd_into(self.simd))
+    fn max_precise(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.max_precise_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.min_i16x32(self, rhs.simd_into(self.simd))
+    fn min(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.min_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.max_i16x32(self, rhs.simd_into(self.simd))
+    fn min_precise(self, rhs: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd.min_precise_f64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x64<S> {
-        self.simd.reinterpret_u8_i16x32(self)
+    fn madd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .madd_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i16, S> for i16x32<S> {
-    const N: usize = 32;
-    type Mask = mask16x32<S>;
-    type Block = i16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
-        &self.val
+    fn msub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .msub_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
-        &mut self.val
+    fn nmadd(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .nmadd_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
-        let mut val = [0; 32];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn nmsub(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .nmsub_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_i16x32(val)
+    fn mul_add_fused(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .mul_add_fused_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    fn mul_add_fast(self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S>) -> f64x8<S> {
+        self.simd
+            .mul_add_fast_f64x8(self, op1.simd_into(self.simd), op2.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdInt<i16, S> for i16x32<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_i16x32(self, rhs.simd_into(self.simd))
+    fn floor(self) -> f64x8<S> {
+        self.simd.floor_f64x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_lt_i16x32(self, rhs.simd_into(self.simd))
+    fn ceil(self) -> f64x8<S> {
+        self.simd.ceil_f64x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_le_i16x32(self, rhs.simd_into(self.simd))
+    fn round(self) -> f64x8<S> {
+        self.simd.round_f64x8(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_ge_i16x32(self, rhs.simd_into(self.simd))
+    fn round_ties_even(self) -> f64x8<S> {
+        self.simd.round_ties_even_f64x8(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_gt_i16x32(self, rhs.simd_into(self.simd))
+    fn fract(self) -> f64x8<S> {
+        self.simd.fract_f64x8(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.zip_low_i16x32(self, rhs.simd_into(self.simd))
+    fn trunc(self) -> f64x8<S> {
+        self.simd.trunc_f64x8(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.zip_high_i16x32(self, rhs.simd_into(self.simd))
+    fn reduce_add(self) -> f64 {
+        self.simd.reduce_add_f64x8(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.min_i16x32(self, rhs.simd_into(self.simd))
+    fn reduce_min(self) -> f64 {
+        self.simd.reduce_min_f64x8(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i16x32<S> {
-        self.simd.max_i16x32(self, rhs.simd_into(self.simd))
+    fn reduce_max(self) -> f64 {
+        self.simd.reduce_max_f64x8(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
 #[repr(C, align(64))]
-pub struct u16x32<S: Simd> {
-    pub val: [u16; 32],
+pub struct i64x8<S: Simd> {
+    pub val: [i64; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u16; 32], S> for u16x32<S> {
+impl<S: Simd> SimdFrom<[i64; 8], S> for i64x8<S> {
     #[inline(always)]
-    fn simd_from(val: [u16; 32], simd: S) -> Self {
+    fn simd_from(val: [i64; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -5192,67 +20504,43 @@ impl<S: Simd> SimdFrom<[u16; 32], S> for u16x32<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<u16x32<S>> for [u16; 32] {
+impl<S: Simd> From<i64x8<S>> for [i64; 8] {
     #[inline(always)]
-    fn from(value: u16x32<S>) -> Self {
+    fn from(value: i64x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u16x32<S> {
-    type Target = [u16; 32];
+impl<S: Simd> core::ops::Deref for i64x8<S> {
+    type Target = [i64; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u16x32<S> {
+impl<S: Simd> core::ops::DerefMut for i64x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u16, S> for u16x32<S> {
+impl<S: Simd> SimdFrom<i64, S> for i64x8<S> {
     #[inline(always)]
-    fn simd_from(value: u16, simd: S) -> Self {
-        simd.splat_u16x32(value)
+    fn simd_from(value: i64, simd: S) -> Self {
+        simd.splat_i64x8(value)
     }
 }
-impl<S: Simd> Select<u16x32<S>> for mask16x32<S> {
+impl<S: Simd> Select<i64x8<S>> for mask64x8<S> {
     #[inline(always)]
-    fn select(self, if_true: u16x32<S>, if_false: u16x32<S>) -> u16x32<S> {
-        self.simd.select_u16x32(self, if_true, if_false)
+    fn select(self, if_true: i64x8<S>, if_false: i64x8<S>) -> i64x8<S> {
+        self.simd.select_i64x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u16x32<S> {
+impl<S: Simd> Bytes for i64x8<S> {
     type Bytes = u8x64<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
@@ -5273,147 +20561,346 @@ impl<S: Simd> Bytes for u16x32<S> {
         }
     }
 }
-impl<S: Simd> u16x32<S> {
+impl<S: Simd> i64x8<S> {
     #[inline(always)]
-    pub fn not(self) -> u16x32<S> {
-        self.simd.not_u16x32(self)
+    pub fn not(self) -> i64x8<S> {
+        self.simd.not_i64x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.add_u16x32(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.add_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.sub_u16x32(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.sub_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.mul_u16x32(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.mul_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.and_u16x32(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.and_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.or_u16x32(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.or_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.xor_u16x32(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.xor_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u16x32<S> {
-        self.simd.shr_u16x32(self, shift)
+    pub fn shl(self, shift: u32) -> i64x8<S> {
+        self.simd.shl_i64x8(self, shift)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_u16x32(self, rhs.simd_into(self.simd))
+    pub fn shr(self, shift: u32) -> i64x8<S> {
+        self.simd.shr_i64x8(self, shift)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_lt_u16x32(self, rhs.simd_into(self.simd))
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.shl_var_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_le_u16x32(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.shr_var_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_ge_u16x32(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_gt_u16x32(self, rhs.simd_into(self.simd))
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.min_u16x32(self, rhs.simd_into(self.simd))
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.max_u16x32(self, rhs.simd_into(self.simd))
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.min_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.max_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn shuffle<I: Indices<8>>(self) -> i64x8<S> {
+        self.simd.shuffle_i64x8::<I>(self)
+    }
+    #[inline(always)]
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd
+            .shuffle2_i64x8::<I>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn reverse(self) -> i64x8<S> {
+        self.simd.reverse_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.unzip_low_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.unzip_high_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn abs(self) -> i64x8<S> {
+        self.simd.abs_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn neg(self) -> i64x8<S> {
+        self.simd.neg_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.abs_diff_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    pub fn split(self) -> (i64x4<S>, i64x4<S>) {
+        self.simd.split_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow(self) -> i32x8<S> {
+        self.simd.narrow_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn narrow_saturating(self) -> i32x8<S> {
+        self.simd.narrow_saturating_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_i64x8(self)
     }
     #[inline(always)]
     pub fn reinterpret_u8(self) -> u8x64<S> {
-        self.simd.reinterpret_u8_u16x32(self)
+        self.simd.reinterpret_u8_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_i64x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_i64x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_i64x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_i64x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> i64x8<S> {
+        self.simd.rotate_lanes_left_i64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> i64x8<S> {
+        self.simd.rotate_lanes_right_i64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> i64x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i64x8::<L>(self)
     }
 }
-impl<S: Simd> crate::SimdBase<u16, S> for u16x32<S> {
-    const N: usize = 32;
-    type Mask = mask16x32<S>;
-    type Block = u16x8<S>;
+impl<S: Simd> crate::SimdBase<i64, S> for i64x8<S> {
+    const N: usize = 8;
+    type Mask = mask64x8<S>;
+    type Block = i64x2<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u16] {
+    fn as_slice(&self) -> &[i64] {
         &self.val
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u16] {
+    fn as_mut_slice(&mut self) -> &mut [i64] {
         &mut self.val
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u16]) -> Self {
-        let mut val = [0; 32];
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 8];
         val.copy_from_slice(slice);
         Self { val, simd }
     }
     #[inline(always)]
-    fn splat(simd: S, val: u16) -> Self {
-        simd.splat_u16x32(val)
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_i64x8(val)
     }
     #[inline(always)]
     fn block_splat(block: Self::Block) -> Self {
         let block2 = block.combine(block);
         block2.combine(block2)
     }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_i64x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_i64x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_i64x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_i64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_i64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_i64x8::<L>(self)
+    }
 }
-impl<S: Simd> crate::SimdInt<u16, S> for u16x32<S> {
+impl<S: Simd> crate::SimdInt<i64, S> for i64x8<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_u16x32(self, rhs.simd_into(self.simd))
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.shl_var_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.shr_var_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_i64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.zip_low_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_lt_u16x32(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.zip_high_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_le_u16x32(self, rhs.simd_into(self.simd))
+    fn min(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.min_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_ge_u16x32(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> i64x8<S> {
+        self.simd.max_i64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_gt_u16x32(self, rhs.simd_into(self.simd))
+    fn reduce_add(self) -> i64 {
+        self.simd.reduce_add_i64x8(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.zip_low_u16x32(self, rhs.simd_into(self.simd))
+    fn reduce_min(self) -> i64 {
+        self.simd.reduce_min_i64x8(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.zip_high_u16x32(self, rhs.simd_into(self.simd))
+    fn reduce_max(self) -> i64 {
+        self.simd.reduce_max_i64x8(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.min_u16x32(self, rhs.simd_into(self.simd))
+    fn reduce_and(self) -> i64 {
+        self.simd.reduce_and_i64x8(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u16x32<S> {
-        self.simd.max_u16x32(self, rhs.simd_into(self.simd))
+    fn reduce_or(self) -> i64 {
+        self.simd.reduce_or_i64x8(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
 #[repr(C, align(64))]
-pub struct mask16x32<S: Simd> {
-    pub val: [i16; 32],
+pub struct u64x8<S: Simd> {
+    pub val: [u64; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[i16; 32], S> for mask16x32<S> {
+impl<S: Simd> SimdFrom<[u64; 8], S> for u64x8<S> {
     #[inline(always)]
-    fn simd_from(val: [i16; 32], simd: S) -> Self {
+    fn simd_from(val: [u64; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -5424,61 +20911,43 @@ impl<S: Simd> SimdFrom<[i16; 32], S> for mask16x32<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-                val[16usize],
-                val[17usize],
-                val[18usize],
-                val[19usize],
-                val[20usize],
-                val[21usize],
-                val[22usize],
-                val[23usize],
-                val[24usize],
-                val[25usize],
-                val[26usize],
-                val[27usize],
-                val[28usize],
-                val[29usize],
-                val[30usize],
-                val[31usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<mask16x32<S>> for [i16; 32] {
+impl<S: Simd> From<u64x8<S>> for [u64; 8] {
     #[inline(always)]
-    fn from(value: mask16x32<S>) -> Self {
+    fn from(value: u64x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for mask16x32<S> {
-    type Target = [i16; 32];
+impl<S: Simd> core::ops::Deref for u64x8<S> {
+    type Target = [u64; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for mask16x32<S> {
+impl<S: Simd> core::ops::DerefMut for u64x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> Select<mask16x32<S>> for mask16x32<S> {
+impl<S: Simd> SimdFrom<u64, S> for u64x8<S> {
     #[inline(always)]
-    fn select(self, if_true: mask16x32<S>, if_false: mask16x32<S>) -> mask16x32<S> {
-        self.simd.select_mask16x32(self, if_true, if_false)
+    fn simd_from(value: u64, simd: S) -> Self {
+        simd.splat_u64x8(value)
     }
 }
-impl<S: Simd> Bytes for mask16x32<S> {
+impl<S: Simd> Select<u64x8<S>> for mask64x8<S> {
+    #[inline(always)]
+    fn select(self, if_true: u64x8<S>, if_false: u64x8<S>) -> u64x8<S> {
+        self.simd.select_u64x8(self, if_true, if_false)
+    }
+}
+impl<S: Simd> Bytes for u64x8<S> {
     type Bytes = u8x64<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
@@ -5499,291 +20968,338 @@ impl<S: Simd> Bytes for mask16x32<S> {
         }
     }
 }
-impl<S: Simd> mask16x32<S> {
+impl<S: Simd> u64x8<S> {
     #[inline(always)]
-    pub fn not(self) -> mask16x32<S> {
-        self.simd.not_mask16x32(self)
+    pub fn not(self) -> u64x8<S> {
+        self.simd.not_u64x8(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.and_mask16x32(self, rhs.simd_into(self.simd))
+    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.add_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.or_mask16x32(self, rhs.simd_into(self.simd))
+    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.sub_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.xor_mask16x32(self, rhs.simd_into(self.simd))
+    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.mul_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_mask16x32(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.and_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i16, S> for mask16x32<S> {
-    const N: usize = 32;
-    type Mask = mask16x32<S>;
-    type Block = mask16x8<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i16] {
-        &self.val
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.or_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i16] {
-        &mut self.val
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.xor_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i16]) -> Self {
-        let mut val = [0; 32];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn shl(self, shift: u32) -> u64x8<S> {
+        self.simd.shl_u64x8(self, shift)
     }
     #[inline(always)]
-    fn splat(simd: S, val: i16) -> Self {
-        simd.splat_mask16x32(val)
+    pub fn shr(self, shift: u32) -> u64x8<S> {
+        self.simd.shr_u64x8(self, shift)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    pub fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.shl_var_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdMask<i16, S> for mask16x32<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask16x32<S> {
-        self.simd.simd_eq_mask16x32(self, rhs.simd_into(self.simd))
+    pub fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.shr_var_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct i32x16<S: Simd> {
-    pub val: [i32; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i32; 16], S> for i32x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> From<i32x16<S>> for [i32; 16] {
     #[inline(always)]
-    fn from(value: i32x16<S>) -> Self {
-        value.val
+    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::Deref for i32x16<S> {
-    type Target = [i32; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> core::ops::DerefMut for i32x16<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> SimdFrom<i32, S> for i32x16<S> {
     #[inline(always)]
-    fn simd_from(value: i32, simd: S) -> Self {
-        simd.splat_i32x16(value)
+    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Select<i32x16<S>> for mask32x16<S> {
     #[inline(always)]
-    fn select(self, if_true: i32x16<S>, if_false: i32x16<S>) -> i32x16<S> {
-        self.simd.select_i32x16(self, if_true, if_false)
+    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.min_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for i32x16<S> {
-    type Bytes = u8x64<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x64 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.max_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    pub fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x8(self)
     }
-}
-impl<S: Simd> i32x16<S> {
     #[inline(always)]
-    pub fn not(self) -> i32x16<S> {
-        self.simd.not_i32x16(self)
+    pub fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.add_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x8(self)
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.sub_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x8(self)
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.mul_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x8(self)
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.and_i32x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<8>>(self) -> u64x8<S> {
+        self.simd.shuffle_u64x8::<I>(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.or_i32x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd
+            .shuffle2_u64x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.xor_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> u64x8<S> {
+        self.simd.reverse_u64x8(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> i32x16<S> {
-        self.simd.shr_i32x16(self, shift)
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.unzip_low_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_i32x16(self, rhs.simd_into(self.simd))
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.unzip_high_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_i32x16(self, rhs.simd_into(self.simd))
+    pub fn abs_diff(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.abs_diff_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_i32x16(self, rhs.simd_into(self.simd))
+    pub fn split(self) -> (u64x4<S>, u64x4<S>) {
+        self.simd.split_u64x8(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_i32x16(self, rhs.simd_into(self.simd))
+    pub fn narrow(self) -> u32x8<S> {
+        self.simd.narrow_u64x8(self)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_i32x16(self, rhs.simd_into(self.simd))
+    pub fn narrow_saturating(self) -> u32x8<S> {
+        self.simd.narrow_saturating_u64x8(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.min_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f32(self) -> f32x16<S> {
+        self.simd.reinterpret_f32_u64x8(self)
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.max_i32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_f64(self) -> f64x8<S> {
+        self.simd.reinterpret_f64_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_u64x8(self)
     }
     #[inline(always)]
     pub fn reinterpret_u8(self) -> u8x64<S> {
-        self.simd.reinterpret_u8_i32x16(self)
+        self.simd.reinterpret_u8_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_u64x8(self)
+    }
+    #[doc = " Convert each lane to `f16`, rounding to nearest, ties to even, like `as`.\n\n Values too large for `f16` become infinities."]
+    #[inline(always)]
+    pub fn cvt_f16(self) -> f16x8<S> {
+        self.simd.cvt_f16_u64x8(self)
+    }
+    #[doc = " Convert each lane to `f32`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f32(self) -> f32x8<S> {
+        self.simd.cvt_f32_u64x8(self)
+    }
+    #[doc = " Convert each lane to `f64`, rounding to nearest, ties to even, like `as`."]
+    #[inline(always)]
+    pub fn cvt_f64(self) -> f64x8<S> {
+        self.simd.cvt_f64_u64x8(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_left<const K: usize>(self) -> u64x8<S> {
+        self.simd.rotate_lanes_left_u64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn rotate_lanes_right<const K: usize>(self) -> u64x8<S> {
+        self.simd.rotate_lanes_right_u64x8::<K>(self)
+    }
+    #[inline(always)]
+    pub fn broadcast_lane<const L: usize>(self) -> u64x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u64x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdBase<u64, S> for u64x8<S> {
+    const N: usize = 8;
+    type Mask = mask64x8<S>;
+    type Block = u64x2<S>;
+    #[inline(always)]
+    fn as_slice(&self) -> &[u64] {
+        &self.val
+    }
+    #[inline(always)]
+    fn as_mut_slice(&mut self) -> &mut [u64] {
+        &mut self.val
+    }
+    #[inline(always)]
+    fn from_slice(simd: S, slice: &[u64]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
+    }
+    #[inline(always)]
+    fn splat(simd: S, val: u64) -> Self {
+        simd.splat_u64x8(val)
+    }
+    #[inline(always)]
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
+    }
+    #[inline(always)]
+    fn reverse(self) -> Self {
+        self.simd.reverse_u64x8(self)
+    }
+    #[inline(always)]
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_u64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_high_u64x8(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_u64x8::<SameLanes<I, M>>(self)
+    }
+    #[inline(always)]
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_u64x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_u64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_u64x8::<K>(self)
+    }
+    #[inline(always)]
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_u64x8::<L>(self)
+    }
+}
+impl<S: Simd> crate::SimdInt<u64, S> for u64x8<S> {
+    #[inline(always)]
+    fn shl_var(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.shl_var_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x16<S> {
-        self.simd.cvt_f32_i32x16(self)
+    fn shr_var(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.shr_var_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i32, S> for i32x16<S> {
-    const N: usize = 16;
-    type Mask = mask32x16<S>;
-    type Block = i32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i32] {
-        &self.val
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i32] {
-        &mut self.val
+    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_lt_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i32]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_le_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn splat(simd: S, val: i32) -> Self {
-        simd.splat_i32x16(val)
+    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_ge_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_gt_u64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdInt<i32, S> for i32x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_i32x16(self, rhs.simd_into(self.simd))
+    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.zip_low_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_i32x16(self, rhs.simd_into(self.simd))
+    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.zip_high_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_i32x16(self, rhs.simd_into(self.simd))
+    fn min(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.min_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_i32x16(self, rhs.simd_into(self.simd))
+    fn max(self, rhs: impl SimdInto<Self, S>) -> u64x8<S> {
+        self.simd.max_u64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_i32x16(self, rhs.simd_into(self.simd))
+    fn reduce_add(self) -> u64 {
+        self.simd.reduce_add_u64x8(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.zip_low_i32x16(self, rhs.simd_into(self.simd))
+    fn reduce_min(self) -> u64 {
+        self.simd.reduce_min_u64x8(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.zip_high_i32x16(self, rhs.simd_into(self.simd))
+    fn reduce_max(self) -> u64 {
+        self.simd.reduce_max_u64x8(self)
     }
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.min_i32x16(self, rhs.simd_into(self.simd))
+    fn reduce_and(self) -> u64 {
+        self.simd.reduce_and_u64x8(self)
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> i32x16<S> {
-        self.simd.max_i32x16(self, rhs.simd_into(self.simd))
+    fn reduce_or(self) -> u64 {
+        self.simd.reduce_or_u64x8(self)
     }
 }
 #[derive(Clone, Copy, Debug)]
 #[repr(C, align(64))]
-pub struct u32x16<S: Simd> {
-    pub val: [u32; 16],
+pub struct mask64x8<S: Simd> {
+    pub val: [i64; 8],
     pub simd: S,
 }
-impl<S: Simd> SimdFrom<[u32; 16], S> for u32x16<S> {
+impl<S: Simd> SimdFrom<[i64; 8], S> for mask64x8<S> {
     #[inline(always)]
-    fn simd_from(val: [u32; 16], simd: S) -> Self {
+    fn simd_from(val: [i64; 8], simd: S) -> Self {
         Self {
             val: [
                 val[0usize],
@@ -5794,51 +21310,37 @@ impl<S: Simd> SimdFrom<[u32; 16], S> for u32x16<S> {
                 val[5usize],
                 val[6usize],
                 val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
             ],
             simd,
         }
     }
 }
-impl<S: Simd> From<u32x16<S>> for [u32; 16] {
+impl<S: Simd> From<mask64x8<S>> for [i64; 8] {
     #[inline(always)]
-    fn from(value: u32x16<S>) -> Self {
+    fn from(value: mask64x8<S>) -> Self {
         value.val
     }
 }
-impl<S: Simd> core::ops::Deref for u32x16<S> {
-    type Target = [u32; 16];
+impl<S: Simd> core::ops::Deref for mask64x8<S> {
+    type Target = [i64; 8];
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
         &self.val
     }
 }
-impl<S: Simd> core::ops::DerefMut for u32x16<S> {
+impl<S: Simd> core::ops::DerefMut for mask64x8<S> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.val
     }
 }
-impl<S: Simd> SimdFrom<u32, S> for u32x16<S> {
-    #[inline(always)]
-    fn simd_from(value: u32, simd: S) -> Self {
-        simd.splat_u32x16(value)
-    }
-}
-impl<S: Simd> Select<u32x16<S>> for mask32x16<S> {
+impl<S: Simd> Select<mask64x8<S>> for mask64x8<S> {
     #[inline(always)]
-    fn select(self, if_true: u32x16<S>, if_false: u32x16<S>) -> u32x16<S> {
-        self.simd.select_u32x16(self, if_true, if_false)
+    fn select(self, if_true: mask64x8<S>, if_false: mask64x8<S>) -> mask64x8<S> {
+        self.simd.select_mask64x8(self, if_true, if_false)
     }
 }
-impl<S: Simd> Bytes for u32x16<S> {
+impl<S: Simd> Bytes for mask64x8<S> {
     type Bytes = u8x64<S>;
     #[inline(always)]
     fn to_bytes(self) -> Self::Bytes {
@@ -5859,273 +21361,221 @@ impl<S: Simd> Bytes for u32x16<S> {
         }
     }
 }
-impl<S: Simd> u32x16<S> {
+impl<S: Simd> mask64x8<S> {
     #[inline(always)]
-    pub fn not(self) -> u32x16<S> {
-        self.simd.not_u32x16(self)
+    pub fn not(self) -> mask64x8<S> {
+        self.simd.not_mask64x8(self)
     }
     #[inline(always)]
-    pub fn add(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.add_u32x16(self, rhs.simd_into(self.simd))
+    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.and_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn sub(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.sub_u32x16(self, rhs.simd_into(self.simd))
+    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.or_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn mul(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.mul_u32x16(self, rhs.simd_into(self.simd))
+    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.xor_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.and_u32x16(self, rhs.simd_into(self.simd))
+    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.or_u32x16(self, rhs.simd_into(self.simd))
+    pub fn any(self) -> bool {
+        self.simd.any_mask64x8(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.xor_u32x16(self, rhs.simd_into(self.simd))
+    pub fn all(self) -> bool {
+        self.simd.all_mask64x8(self)
     }
     #[inline(always)]
-    pub fn shr(self, shift: u32) -> u32x16<S> {
-        self.simd.shr_u32x16(self, shift)
+    pub fn none(self) -> bool {
+        self.simd.none_mask64x8(self)
     }
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_u32x16(self, rhs.simd_into(self.simd))
+    pub fn count_true(self) -> usize {
+        self.simd.count_true_mask64x8(self)
     }
     #[inline(always)]
-    pub fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_u32x16(self, rhs.simd_into(self.simd))
+    pub fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x8(self)
     }
     #[inline(always)]
-    pub fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_u32x16(self, rhs.simd_into(self.simd))
+    pub fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x8(self)
     }
     #[inline(always)]
-    pub fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_u32x16(self, rhs.simd_into(self.simd))
+    pub fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x8(bits)
     }
     #[inline(always)]
-    pub fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_u32x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle<I: Indices<8>>(self) -> mask64x8<S> {
+        self.simd.shuffle_mask64x8::<I>(self)
     }
     #[inline(always)]
-    pub fn min(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.min_u32x16(self, rhs.simd_into(self.simd))
+    pub fn shuffle2<I: Indices<8>>(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd
+            .shuffle2_mask64x8::<I>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn max(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.max_u32x16(self, rhs.simd_into(self.simd))
+    pub fn reverse(self) -> mask64x8<S> {
+        self.simd.reverse_mask64x8(self)
     }
     #[inline(always)]
-    pub fn reinterpret_u8(self) -> u8x64<S> {
-        self.simd.reinterpret_u8_u32x16(self)
+    pub fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.unzip_low_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn cvt_f32(self) -> f32x16<S> {
-        self.simd.cvt_f32_u32x16(self)
+    pub fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd
+            .unzip_high_mask64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<u32, S> for u32x16<S> {
-    const N: usize = 16;
-    type Mask = mask32x16<S>;
-    type Block = u32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[u32] {
-        &self.val
+    pub fn split(self) -> (mask64x4<S>, mask64x4<S>) {
+        self.simd.split_mask64x8(self)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [u32] {
-        &mut self.val
+    pub fn reinterpret_i8(self) -> i8x64<S> {
+        self.simd.reinterpret_i8_mask64x8(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[u32]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    pub fn reinterpret_u8(self) -> u8x64<S> {
+        self.simd.reinterpret_u8_mask64x8(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: u32) -> Self {
-        simd.splat_u32x16(val)
+    pub fn reinterpret_i16(self) -> i16x32<S> {
+        self.simd.reinterpret_i16_mask64x8(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    pub fn reinterpret_u16(self) -> u16x32<S> {
+        self.simd.reinterpret_u16_mask64x8(self)
     }
-}
-impl<S: Simd> crate::SimdInt<u32, S> for u32x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_u32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i32(self) -> i32x16<S> {
+        self.simd.reinterpret_i32_mask64x8(self)
     }
     #[inline(always)]
-    fn simd_lt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_lt_u32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u32(self) -> u32x16<S> {
+        self.simd.reinterpret_u32_mask64x8(self)
     }
     #[inline(always)]
-    fn simd_le(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_le_u32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_i64(self) -> i64x8<S> {
+        self.simd.reinterpret_i64_mask64x8(self)
     }
     #[inline(always)]
-    fn simd_ge(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_ge_u32x16(self, rhs.simd_into(self.simd))
+    pub fn reinterpret_u64(self) -> u64x8<S> {
+        self.simd.reinterpret_u64_mask64x8(self)
     }
     #[inline(always)]
-    fn simd_gt(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_gt_u32x16(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_left<const K: usize>(self) -> mask64x8<S> {
+        self.simd.rotate_lanes_left_mask64x8::<K>(self)
     }
     #[inline(always)]
-    fn zip_low(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.zip_low_u32x16(self, rhs.simd_into(self.simd))
+    pub fn rotate_lanes_right<const K: usize>(self) -> mask64x8<S> {
+        self.simd.rotate_lanes_right_mask64x8::<K>(self)
     }
     #[inline(always)]
-    fn zip_high(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.zip_high_u32x16(self, rhs.simd_into(self.simd))
+    pub fn broadcast_lane<const L: usize>(self) -> mask64x8<S> {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x8::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdBase<i64, S> for mask64x8<S> {
+    const N: usize = 8;
+    type Mask = mask64x8<S>;
+    type Block = mask64x2<S>;
     #[inline(always)]
-    fn min(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.min_u32x16(self, rhs.simd_into(self.simd))
+    fn as_slice(&self) -> &[i64] {
+        &self.val
     }
     #[inline(always)]
-    fn max(self, rhs: impl SimdInto<Self, S>) -> u32x16<S> {
-        self.simd.max_u32x16(self, rhs.simd_into(self.simd))
+    fn as_mut_slice(&mut self) -> &mut [i64] {
+        &mut self.val
     }
-}
-#[derive(Clone, Copy, Debug)]
-#[repr(C, align(64))]
-pub struct mask32x16<S: Simd> {
-    pub val: [i32; 16],
-    pub simd: S,
-}
-impl<S: Simd> SimdFrom<[i32; 16], S> for mask32x16<S> {
     #[inline(always)]
-    fn simd_from(val: [i32; 16], simd: S) -> Self {
-        Self {
-            val: [
-                val[0usize],
-                val[1usize],
-                val[2usize],
-                val[3usize],
-                val[4usize],
-                val[5usize],
-                val[6usize],
-                val[7usize],
-                val[8usize],
-                val[9usize],
-                val[10usize],
-                val[11usize],
-                val[12usize],
-                val[13usize],
-                val[14usize],
-                val[15usize],
-            ],
-            simd,
-        }
+    fn from_slice(simd: S, slice: &[i64]) -> Self {
+        let mut val = [0; 8];
+        val.copy_from_slice(slice);
+        Self { val, simd }
     }
-}
-impl<S: Simd> From<mask32x16<S>> for [i32; 16] {
     #[inline(always)]
-    fn from(value: mask32x16<S>) -> Self {
-        value.val
+    fn splat(simd: S, val: i64) -> Self {
+        simd.splat_mask64x8(val)
     }
-}
-impl<S: Simd> core::ops::Deref for mask32x16<S> {
-    type Target = [i32; 16];
     #[inline(always)]
-    fn deref(&self) -> &Self::Target {
-        &self.val
+    fn block_splat(block: Self::Block) -> Self {
+        let block2 = block.combine(block);
+        block2.combine(block2)
     }
-}
-impl<S: Simd> core::ops::DerefMut for mask32x16<S> {
     #[inline(always)]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.val
+    fn reverse(self) -> Self {
+        self.simd.reverse_mask64x8(self)
     }
-}
-impl<S: Simd> Select<mask32x16<S>> for mask32x16<S> {
     #[inline(always)]
-    fn select(self, if_true: mask32x16<S>, if_false: mask32x16<S>) -> mask32x16<S> {
-        self.simd.select_mask32x16(self, if_true, if_false)
+    fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd.unzip_low_mask64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> Bytes for mask32x16<S> {
-    type Bytes = u8x64<S>;
     #[inline(always)]
-    fn to_bytes(self) -> Self::Bytes {
-        unsafe {
-            u8x64 {
-                val: core::mem::transmute(self.val),
-                simd: self.simd,
-            }
-        }
+    fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .unzip_high_mask64x8(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    fn from_bytes(value: Self::Bytes) -> Self {
-        unsafe {
-            Self {
-                val: core::mem::transmute(value.val),
-                simd: value.simd,
-            }
-        }
+    fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+        self.simd.shuffle_mask64x8::<SameLanes<I, M>>(self)
     }
-}
-impl<S: Simd> mask32x16<S> {
     #[inline(always)]
-    pub fn not(self) -> mask32x16<S> {
-        self.simd.not_mask32x16(self)
+    fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+        self.simd
+            .shuffle2_mask64x8::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
     }
     #[inline(always)]
-    pub fn and(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.and_mask32x16(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_left<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_left_mask64x8::<K>(self)
     }
     #[inline(always)]
-    pub fn or(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.or_mask32x16(self, rhs.simd_into(self.simd))
+    fn rotate_lanes_right<const K: usize>(self) -> Self {
+        self.simd.rotate_lanes_right_mask64x8::<K>(self)
     }
     #[inline(always)]
-    pub fn xor(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.xor_mask32x16(self, rhs.simd_into(self.simd))
+    fn broadcast_lane<const L: usize>(self) -> Self {
+        const { assert!(L < 8, "lane index out of range") };
+        self.simd.broadcast_lane_mask64x8::<L>(self)
     }
+}
+impl<S: Simd> crate::SimdMask<i64, S> for mask64x8<S> {
     #[inline(always)]
-    pub fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_mask32x16(self, rhs.simd_into(self.simd))
+    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask64x8<S> {
+        self.simd.simd_eq_mask64x8(self, rhs.simd_into(self.simd))
     }
-}
-impl<S: Simd> crate::SimdBase<i32, S> for mask32x16<S> {
-    const N: usize = 16;
-    type Mask = mask32x16<S>;
-    type Block = mask32x4<S>;
     #[inline(always)]
-    fn as_slice(&self) -> &[i32] {
-        &self.val
+    fn any(self) -> bool {
+        self.simd.any_mask64x8(self)
     }
     #[inline(always)]
-    fn as_mut_slice(&mut self) -> &mut [i32] {
-        &mut self.val
+    fn all(self) -> bool {
+        self.simd.all_mask64x8(self)
     }
     #[inline(always)]
-    fn from_slice(simd: S, slice: &[i32]) -> Self {
-        let mut val = [0; 16];
-        val.copy_from_slice(slice);
-        Self { val, simd }
+    fn none(self) -> bool {
+        self.simd.none_mask64x8(self)
     }
     #[inline(always)]
-    fn splat(simd: S, val: i32) -> Self {
-        simd.splat_mask32x16(val)
+    fn count_true(self) -> usize {
+        self.simd.count_true_mask64x8(self)
     }
     #[inline(always)]
-    fn block_splat(block: Self::Block) -> Self {
-        let block2 = block.combine(block);
-        block2.combine(block2)
+    fn first_true(self) -> Option<usize> {
+        self.simd.first_true_mask64x8(self)
     }
-}
-impl<S: Simd> crate::SimdMask<i32, S> for mask32x16<S> {
     #[inline(always)]
-    fn simd_eq(self, rhs: impl SimdInto<Self, S>) -> mask32x16<S> {
-        self.simd.simd_eq_mask32x16(self, rhs.simd_into(self.simd))
+    fn to_bitmask(self) -> u64 {
+        self.simd.to_bitmask_mask64x8(self)
+    }
+    #[inline(always)]
+    fn from_bitmask(simd: S, bits: u64) -> Self {
+        simd.from_bitmask_mask64x8(bits)
     }
 }
@@ -0,0 +1,18783 @@
+// This file is autogenerated by fearless_simd_gen
+
+use crate::{Bytes, Indices, Level, Simd, SimdFrom, SimdInto, seal::Seal};
+use crate::{
+    bf16, bf16x8, bf16x16, f16, f16x8, f16x16, f32x2, f32x4, f32x8, f32x16, f64x2, f64x4, f64x8,
+    i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x2, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8,
+    mask8x8, mask8x16, mask8x32, mask8x64, mask16x4, mask16x8, mask16x16, mask16x32, mask32x2,
+    mask32x4, mask32x8, mask32x16, mask64x2, mask64x4, mask64x8, u8x8, u8x16, u8x32, u8x64, u16x4,
+    u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8,
+};
+use core::arch::x86_64::*;
+#[doc = " The SIMD token for the \"sse4.2\" level, which is x86-64-v2."]
+#[derive(Clone, Copy, Debug)]
+pub struct Sse4_2 {
+    pub sse: crate::core_arch::x86_64::Sse,
+    pub sse2: crate::core_arch::x86_64::Sse2,
+    pub sse3: crate::core_arch::x86_64::Sse3,
+    pub ssse3: crate::core_arch::x86_64::Ssse3,
+    pub sse4_1: crate::core_arch::x86_64::Sse4_1,
+    pub sse4_2: crate::core_arch::x86_64::Sse4_2,
+}
+impl Sse4_2 {
+    #[doc = r" Create a SIMD token."]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = r" The required CPU features must be available."]
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        Self {
+            sse: unsafe { crate::core_arch::x86_64::Sse::new_unchecked() },
+            sse2: unsafe { crate::core_arch::x86_64::Sse2::new_unchecked() },
+            sse3: unsafe { crate::core_arch::x86_64::Sse3::new_unchecked() },
+            ssse3: unsafe { crate::core_arch::x86_64::Ssse3::new_unchecked() },
+            sse4_1: unsafe { crate::core_arch::x86_64::Sse4_1::new_unchecked() },
+            sse4_2: unsafe { crate::core_arch::x86_64::Sse4_2::new_unchecked() },
+        }
+    }
+}
+impl Seal for Sse4_2 {}
+impl Simd for Sse4_2 {
+    type f32s = f32x4<Self>;
+    type f64s = f64x2<Self>;
+    type u8s = u8x16<Self>;
+    type i8s = i8x16<Self>;
+    type u16s = u16x8<Self>;
+    type i16s = i16x8<Self>;
+    type u32s = u32x4<Self>;
+    type i32s = i32x4<Self>;
+    type u64s = u64x2<Self>;
+    type i64s = i64x2<Self>;
+    type mask8s = mask8x16<Self>;
+    type mask16s = mask16x8<Self>;
+    type mask32s = mask32x4<Self>;
+    type mask64s = mask64x2<Self>;
+    #[inline(always)]
+    fn level(self) -> Level {
+        Level::Sse4_2(self)
+    }
+    #[inline]
+    fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+        #[target_feature(enable = "sse4.2")]
+        #[inline]
+        unsafe fn vectorize_x86<F: FnOnce() -> R, R>(f: F) -> R {
+            f()
+        }
+        unsafe { vectorize_x86(f) }
+    }
+    #[inline(always)]
+    fn splat_f32x2(self, val: f32) -> f32x2<Self> {
+        self.split_f32x4(self.splat_f32x4(val)).0
+    }
+    #[inline(always)]
+    fn abs_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.abs_f32x4(self.combine_f32x2(a, a))).0
+    }
+    #[inline(always)]
+    fn neg_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.neg_f32x4(self.combine_f32x2(a, a))).0
+    }
+    #[inline(always)]
+    fn sqrt_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.sqrt_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn recip_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.recip_approx_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.rsqrt_approx_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn recip_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self> {
+        self.split_f32x4(self.recip_f32x4(self.combine_f32x2(a, a), steps))
+            .0
+    }
+    #[inline(always)]
+    fn rsqrt_f32x2(self, a: f32x2<Self>, steps: u32) -> f32x2<Self> {
+        self.split_f32x4(self.rsqrt_f32x4(self.combine_f32x2(a, a), steps))
+            .0
+    }
+    #[inline(always)]
+    fn add_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.add_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn sub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.sub_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.mul_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn div_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.div_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn copysign_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.copysign_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_eq_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_eq_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_ne_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_ne_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_lt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_lt_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_le_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_le_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_ge_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_ge_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_gt_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_gt_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn is_nan_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_ne_f32x2(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_eq_f32x2(self.abs_f32x2(a), self.splat_f32x2(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        let abs = self.abs_f32x2(a);
+        self.and_mask32x2(
+            self.simd_lt_f32x2(abs, self.splat_f32x2(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x2(abs, self.splat_f32x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x2(self, a: f32x2<Self>) -> mask32x2<Self> {
+        self.simd_lt_f32x2(
+            self.copysign_f32x2(self.splat_f32x2(1.0), a),
+            self.splat_f32x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.zip_low_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.zip_low_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .1
+    }
+    #[inline(always)]
+    fn max_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.max_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn max_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.max_precise_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn min_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.min_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn min_precise_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.min_precise_f32x4(self.combine_f32x2(a, a), self.combine_f32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn madd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.madd_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn msub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.msub_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn nmadd_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.nmadd_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn nmsub_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.nmsub_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.mul_add_fused_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x2(self, a: f32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.mul_add_fast_f32x4(
+            self.combine_f32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn floor_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.floor_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn ceil_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.ceil_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn round_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.round_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.round_ties_even_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn fract_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.fract_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn trunc_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.trunc_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn select_f32x2(self, a: mask32x2<Self>, b: f32x2<Self>, c: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.select_f32x4(
+            self.combine_mask32x2(a, a),
+            self.combine_f32x2(b, b),
+            self.combine_f32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn reduce_add_f32x2(self, a: f32x2<Self>) -> f32 {
+        a[0] + a[1]
+    }
+    #[inline(always)]
+    fn reduce_min_f32x2(self, a: f32x2<Self>) -> f32 {
+        self.reduce_min_f32x4(self.combine_f32x2(a, a))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x2(self, a: f32x2<Self>) -> f32 {
+        self.reduce_max_f32x4(self.combine_f32x2(a, a))
+    }
+    #[inline(always)]
+    fn shuffle_f32x2<I: Indices<2>>(self, a: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x2<I: Indices<2>>(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x2(self, a: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.rotate_lanes_left_f32x4::<K>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x2<const K: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.rotate_lanes_right_f32x4::<K>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x2<const L: usize>(self, a: f32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.broadcast_lane_f32x4::<L>(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_f32x2(self, a: f32x2<Self>, b: f32x2<Self>) -> f32x4<Self> {
+        unsafe { _mm_movelh_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x2(self, a: f32x2<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x2(self, a: f32x2<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x2(self, a: f32x2<Self>) -> f64x2<Self> {
+        self.split_f64x4(self.cvt_f64_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.cvt_i32_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x2(self, a: f32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.cvt_i32_round_f32x4(self.combine_f32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x2(self, a: f32x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x2(self, a: f32x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_round_f64x2(self.cvt_f64_f32x2(a))
+    }
+    #[inline(always)]
+    fn splat_u8x8(self, val: u8) -> u8x8<Self> {
+        self.split_u8x16(self.splat_u8x16(val)).0
+    }
+    #[inline(always)]
+    fn not_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.not_u8x16(self.combine_u8x8(a, a))).0
+    }
+    #[inline(always)]
+    fn add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.add_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.sub_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.mul_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn and_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.and_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn or_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.or_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn xor_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.xor_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn shl_u8x8(self, a: u8x8<Self>, shift: u32) -> u8x8<Self> {
+        self.split_u8x16(self.shl_u8x16(self.combine_u8x8(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shr_u8x8(self, a: u8x8<Self>, shift: u32) -> u8x8<Self> {
+        self.split_u8x16(self.shr_u8x16(self.combine_u8x8(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shl_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.simd_eq_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_lt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.simd_lt_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_le_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.simd_le_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_ge_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.simd_ge_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_gt_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.simd_gt_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.zip_low_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.zip_low_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .1
+    }
+    #[inline(always)]
+    fn select_u8x8(self, a: mask8x8<Self>, b: u8x8<Self>, c: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.select_u8x16(
+            self.combine_mask8x8(a, a),
+            self.combine_u8x8(b, b),
+            self.combine_u8x8(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn min_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.min_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn max_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.max_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn reduce_add_u8x8(self, a: u8x8<Self>) -> u8 {
+        self.reduce_add_u8x16(self.combine_u8x8(a, self.splat_u8x8(0)))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x8(self, a: u8x8<Self>) -> u8 {
+        self.reduce_min_u8x16(self.combine_u8x8(a, a))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x8(self, a: u8x8<Self>) -> u8 {
+        self.reduce_max_u8x16(self.combine_u8x8(a, a))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x8(self, a: u8x8<Self>) -> u8 {
+        self.reduce_and_u8x16(self.combine_u8x8(a, a))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x8(self, a: u8x8<Self>) -> u8 {
+        self.reduce_or_u8x16(self.combine_u8x8(a, a))
+    }
+    #[inline(always)]
+    fn shuffle_u8x8<I: Indices<8>>(self, a: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x8<I: Indices<8>>(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x8(self, a: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.rotate_lanes_left_u8x16::<K>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x8<const K: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.rotate_lanes_right_u8x16::<K>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x8<const L: usize>(self, a: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.broadcast_lane_u8x16::<L>(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(
+            self.saturating_add_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(
+            self.saturating_sub_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn abs_diff_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.abs_diff_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn avg_round_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.avg_round_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_widen_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u16x8<Self> {
+        self.split_u16x16(self.mul_widen_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_high_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.mul_high_u8x16(self.combine_u8x8(a, a), self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.swizzle_dyn_u8x16(
+            self.combine_u8x8(a, self.splat_u8x8(0)),
+            self.combine_u8x8(b, b),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn lookup_32_u8x8(self, a: u8x32<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.lookup_32_u8x16(a, self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn lookup_48_u8x8(self, a: [u8x16<Self>; 3], b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.lookup_48_u8x16(a, self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn lookup_64_u8x8(self, a: u8x64<Self>, b: u8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.lookup_64_u8x16(a, self.combine_u8x8(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn combine_u8x8(self, a: u8x8<Self>, b: u8x8<Self>) -> u8x16<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_u8x8(self, a: u8x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_cvtepu8_epi16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x8(self, a: u8x8<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.reinterpret_f32_u8x16(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x8(self, a: u8x8<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_u8x16(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x8(self, a: u8x8<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_u8x16(self.combine_u8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_f16_u8x8(self, a: u8x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u16x8(self.widen_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u8x8(self, a: u8x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u16x8(self.widen_u8x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_unorm_u8x8(self, a: u8x8<Self>) -> f32x8<Self> {
+        self.div_f32x8(self.cvt_f32_u8x8(a), self.splat_f32x8(255.0))
+    }
+    #[inline(always)]
+    fn splat_mask8x8(self, val: i8) -> mask8x8<Self> {
+        self.split_mask8x16(self.splat_mask8x16(val)).0
+    }
+    #[inline(always)]
+    fn not_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.not_mask8x16(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn and_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(
+            self.and_mask8x16(self.combine_mask8x8(a, a), self.combine_mask8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn or_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(
+            self.or_mask8x16(self.combine_mask8x8(a, a), self.combine_mask8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn xor_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(
+            self.xor_mask8x16(self.combine_mask8x8(a, a), self.combine_mask8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn select_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>, c: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.select_mask8x16(
+            self.combine_mask8x8(a, a),
+            self.combine_mask8x8(b, b),
+            self.combine_mask8x8(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(
+            self.simd_eq_mask8x16(self.combine_mask8x8(a, a), self.combine_mask8x8(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn any_mask8x8(self, a: mask8x8<Self>) -> bool {
+        self.any_mask8x16(self.combine_mask8x8(a, a))
+    }
+    #[inline(always)]
+    fn all_mask8x8(self, a: mask8x8<Self>) -> bool {
+        self.all_mask8x16(self.combine_mask8x8(a, a))
+    }
+    #[inline(always)]
+    fn none_mask8x8(self, a: mask8x8<Self>) -> bool {
+        !self.any_mask8x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x8(self, a: mask8x8<Self>) -> usize {
+        self.to_bitmask_mask8x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x8(self, a: mask8x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x8(self, a: mask8x8<Self>) -> u64 {
+        self.to_bitmask_mask8x16(self.combine_mask8x8(a, a)) & 255
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x8(self, bits: u64) -> mask8x8<Self> {
+        self.split_mask8x16(self.from_bitmask_mask8x16(bits)).0
+    }
+    #[inline(always)]
+    fn shuffle_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x8<I: Indices<8>>(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x8(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| a[8 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.rotate_lanes_left_mask8x16::<K>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x8<const K: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.rotate_lanes_right_mask8x16::<K>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x8<const L: usize>(self, a: mask8x8<Self>) -> mask8x8<Self> {
+        self.split_mask8x16(self.broadcast_lane_mask8x16::<L>(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| if i < 4 { a[2 * i] } else { b[2 * (i - 4)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x8<Self> {
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            if i < 4 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 4) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask8x8(self, a: mask8x8<Self>, b: mask8x8<Self>) -> mask8x16<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x8(self, a: mask8x8<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_mask8x16(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x8(self, a: mask8x8<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_mask8x16(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x8(self, a: mask8x8<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_mask8x16(self.combine_mask8x8(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn splat_u16x4(self, val: u16) -> u16x4<Self> {
+        self.split_u16x8(self.splat_u16x8(val)).0
+    }
+    #[inline(always)]
+    fn not_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.not_u16x8(self.combine_u16x4(a, a))).0
+    }
+    #[inline(always)]
+    fn add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.add_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.sub_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.mul_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn and_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.and_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn or_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.or_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn xor_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.xor_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn shl_u16x4(self, a: u16x4<Self>, shift: u32) -> u16x4<Self> {
+        self.split_u16x8(self.shl_u16x8(self.combine_u16x4(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shr_u16x4(self, a: u16x4<Self>, shift: u32) -> u16x4<Self> {
+        self.split_u16x8(self.shr_u16x8(self.combine_u16x4(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shl_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.simd_eq_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_lt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.simd_lt_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_le_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.simd_le_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_ge_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.simd_ge_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_gt_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.simd_gt_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.zip_low_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.zip_low_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .1
+    }
+    #[inline(always)]
+    fn select_u16x4(self, a: mask16x4<Self>, b: u16x4<Self>, c: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.select_u16x8(
+            self.combine_mask16x4(a, a),
+            self.combine_u16x4(b, b),
+            self.combine_u16x4(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn min_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.min_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn max_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.max_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn reduce_add_u16x4(self, a: u16x4<Self>) -> u16 {
+        self.reduce_add_u16x8(self.combine_u16x4(a, self.splat_u16x4(0)))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x4(self, a: u16x4<Self>) -> u16 {
+        self.reduce_min_u16x8(self.combine_u16x4(a, a))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x4(self, a: u16x4<Self>) -> u16 {
+        self.reduce_max_u16x8(self.combine_u16x4(a, a))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x4(self, a: u16x4<Self>) -> u16 {
+        self.reduce_and_u16x8(self.combine_u16x4(a, a))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x4(self, a: u16x4<Self>) -> u16 {
+        self.reduce_or_u16x8(self.combine_u16x4(a, a))
+    }
+    #[inline(always)]
+    fn shuffle_u16x4<I: Indices<4>>(self, a: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x4<I: Indices<4>>(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x4(self, a: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.rotate_lanes_left_u16x8::<K>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x4<const K: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.rotate_lanes_right_u16x8::<K>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x4<const L: usize>(self, a: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.broadcast_lane_u16x8::<L>(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn saturating_add_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(
+            self.saturating_add_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(
+            self.saturating_sub_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn abs_diff_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.abs_diff_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn avg_round_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.avg_round_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_widen_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u32x4<Self> {
+        self.split_u32x8(self.mul_widen_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_high_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.mul_high_u16x8(self.combine_u16x4(a, a), self.combine_u16x4(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn combine_u16x4(self, a: u16x4<Self>, b: u16x4<Self>) -> u16x8<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_u16x4(self, a: u16x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_cvtepu16_epi32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x4(self, a: u16x4<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.reinterpret_f32_u16x8(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x4(self, a: u16x4<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_u16x8(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x4(self, a: u16x4<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_u16x8(self.combine_u16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x4(self, a: u16x4<Self>) -> f32x4<Self> {
+        self.cvt_f32_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x4(self, a: u16x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u32x4(self.widen_u16x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x4(self, val: i16) -> mask16x4<Self> {
+        self.split_mask16x8(self.splat_mask16x8(val)).0
+    }
+    #[inline(always)]
+    fn not_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.not_mask16x8(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn and_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(
+            self.and_mask16x8(self.combine_mask16x4(a, a), self.combine_mask16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn or_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(
+            self.or_mask16x8(self.combine_mask16x4(a, a), self.combine_mask16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn xor_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(
+            self.xor_mask16x8(self.combine_mask16x4(a, a), self.combine_mask16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn select_mask16x4(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+        c: mask16x4<Self>,
+    ) -> mask16x4<Self> {
+        self.split_mask16x8(self.select_mask16x8(
+            self.combine_mask16x4(a, a),
+            self.combine_mask16x4(b, b),
+            self.combine_mask16x4(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(
+            self.simd_eq_mask16x8(self.combine_mask16x4(a, a), self.combine_mask16x4(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn any_mask16x4(self, a: mask16x4<Self>) -> bool {
+        self.any_mask16x8(self.combine_mask16x4(a, a))
+    }
+    #[inline(always)]
+    fn all_mask16x4(self, a: mask16x4<Self>) -> bool {
+        self.all_mask16x8(self.combine_mask16x4(a, a))
+    }
+    #[inline(always)]
+    fn none_mask16x4(self, a: mask16x4<Self>) -> bool {
+        !self.any_mask16x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x4(self, a: mask16x4<Self>) -> usize {
+        self.to_bitmask_mask16x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x4(self, a: mask16x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x4(self, a: mask16x4<Self>) -> u64 {
+        self.to_bitmask_mask16x8(self.combine_mask16x4(a, a)) & 15
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x4(self, bits: u64) -> mask16x4<Self> {
+        self.split_mask16x8(self.from_bitmask_mask16x8(bits)).0
+    }
+    #[inline(always)]
+    fn shuffle_mask16x4<I: Indices<4>>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x4<I: Indices<4>>(
+        self,
+        a: mask16x4<Self>,
+        b: mask16x4<Self>,
+    ) -> mask16x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x4(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| a[4 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.rotate_lanes_left_mask16x8::<K>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x4<const K: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.rotate_lanes_right_mask16x8::<K>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x4<const L: usize>(self, a: mask16x4<Self>) -> mask16x4<Self> {
+        self.split_mask16x8(self.broadcast_lane_mask16x8::<L>(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| if i < 2 { a[2 * i] } else { b[2 * (i - 2)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x4<Self> {
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            if i < 2 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 2) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask16x4(self, a: mask16x4<Self>, b: mask16x4<Self>) -> mask16x8<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x4(self, a: mask16x4<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_mask16x8(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x4(self, a: mask16x4<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_mask16x8(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x4(self, a: mask16x4<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_mask16x8(self.combine_mask16x4(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn splat_i32x2(self, val: i32) -> i32x2<Self> {
+        self.split_i32x4(self.splat_i32x4(val)).0
+    }
+    #[inline(always)]
+    fn not_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.not_i32x4(self.combine_i32x2(a, a))).0
+    }
+    #[inline(always)]
+    fn add_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.add_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn sub_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.sub_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn mul_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.mul_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn and_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.and_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn or_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.or_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn xor_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.xor_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn shl_i32x2(self, a: i32x2<Self>, shift: u32) -> i32x2<Self> {
+        self.split_i32x4(self.shl_i32x4(self.combine_i32x2(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shr_i32x2(self, a: i32x2<Self>, shift: u32) -> i32x2<Self> {
+        self.split_i32x4(self.shr_i32x4(self.combine_i32x2(a, a), shift))
+            .0
+    }
+    #[inline(always)]
+    fn shl_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u32).min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u32).min(31);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_eq_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_lt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_lt_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_le_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_le_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_ge_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_ge_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn simd_gt_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.simd_gt_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.zip_low_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn zip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.zip_low_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .1
+    }
+    #[inline(always)]
+    fn select_i32x2(self, a: mask32x2<Self>, b: i32x2<Self>, c: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.select_i32x4(
+            self.combine_mask32x2(a, a),
+            self.combine_i32x2(b, b),
+            self.combine_i32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn min_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.min_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn max_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.max_i32x4(self.combine_i32x2(a, a), self.combine_i32x2(b, b)))
+            .0
+    }
+    #[inline(always)]
+    fn reduce_add_i32x2(self, a: i32x2<Self>) -> i32 {
+        self.reduce_add_i32x4(self.combine_i32x2(a, self.splat_i32x2(0)))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x2(self, a: i32x2<Self>) -> i32 {
+        self.reduce_min_i32x4(self.combine_i32x2(a, a))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x2(self, a: i32x2<Self>) -> i32 {
+        self.reduce_max_i32x4(self.combine_i32x2(a, a))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x2(self, a: i32x2<Self>) -> i32 {
+        self.reduce_and_i32x4(self.combine_i32x2(a, a))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x2(self, a: i32x2<Self>) -> i32 {
+        self.reduce_or_i32x4(self.combine_i32x2(a, a))
+    }
+    #[inline(always)]
+    fn shuffle_i32x2<I: Indices<2>>(self, a: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x2<I: Indices<2>>(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.rotate_lanes_left_i32x4::<K>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x2<const K: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.rotate_lanes_right_i32x4::<K>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x2<const L: usize>(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.broadcast_lane_i32x4::<L>(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.abs_i32x4(self.combine_i32x2(a, a))).0
+    }
+    #[inline(always)]
+    fn neg_i32x2(self, a: i32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.neg_i32x4(self.combine_i32x2(a, a))).0
+    }
+    #[inline(always)]
+    fn combine_i32x2(self, a: i32x2<Self>, b: i32x2<Self>) -> i32x4<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_i32x2(self, a: i32x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_cvtepi32_epi64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.reinterpret_f32_i32x4(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x2(self, a: i32x2<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_i32x4(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x2(self, a: i32x2<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_i32x4(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x2(self, a: i32x2<Self>) -> f32x2<Self> {
+        self.split_f32x4(self.cvt_f32_i32x4(self.combine_i32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x2(self, a: i32x2<Self>) -> f64x2<Self> {
+        self.cvt_f64_i64x2(self.widen_i32x2(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x2(self, val: i32) -> mask32x2<Self> {
+        self.split_mask32x4(self.splat_mask32x4(val)).0
+    }
+    #[inline(always)]
+    fn not_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.not_mask32x4(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn and_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(
+            self.and_mask32x4(self.combine_mask32x2(a, a), self.combine_mask32x2(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn or_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(
+            self.or_mask32x4(self.combine_mask32x2(a, a), self.combine_mask32x2(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn xor_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(
+            self.xor_mask32x4(self.combine_mask32x2(a, a), self.combine_mask32x2(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn select_mask32x2(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+        c: mask32x2<Self>,
+    ) -> mask32x2<Self> {
+        self.split_mask32x4(self.select_mask32x4(
+            self.combine_mask32x2(a, a),
+            self.combine_mask32x2(b, b),
+            self.combine_mask32x2(c, c),
+        ))
+        .0
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(
+            self.simd_eq_mask32x4(self.combine_mask32x2(a, a), self.combine_mask32x2(b, b)),
+        )
+        .0
+    }
+    #[inline(always)]
+    fn any_mask32x2(self, a: mask32x2<Self>) -> bool {
+        self.any_mask32x4(self.combine_mask32x2(a, a))
+    }
+    #[inline(always)]
+    fn all_mask32x2(self, a: mask32x2<Self>) -> bool {
+        self.all_mask32x4(self.combine_mask32x2(a, a))
+    }
+    #[inline(always)]
+    fn none_mask32x2(self, a: mask32x2<Self>) -> bool {
+        !self.any_mask32x2(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x2(self, a: mask32x2<Self>) -> usize {
+        self.to_bitmask_mask32x2(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x2(self, a: mask32x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x2(self, a: mask32x2<Self>) -> u64 {
+        self.to_bitmask_mask32x4(self.combine_mask32x2(a, a)) & 3
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x2(self, bits: u64) -> mask32x2<Self> {
+        self.split_mask32x4(self.from_bitmask_mask32x4(bits)).0
+    }
+    #[inline(always)]
+    fn shuffle_mask32x2<I: Indices<2>>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 2) };
+        let lanes: [_; 2] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x2<I: Indices<2>>(
+        self,
+        a: mask32x2<Self>,
+        b: mask32x2<Self>,
+    ) -> mask32x2<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 2 { a[index] } else { b[index - 2] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x2(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| a[2 - 1 - i]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.rotate_lanes_left_mask32x4::<K>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x2<const K: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.rotate_lanes_right_mask32x4::<K>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x2<const L: usize>(self, a: mask32x2<Self>) -> mask32x2<Self> {
+        self.split_mask32x4(self.broadcast_lane_mask32x4::<L>(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| if i < 1 { a[2 * i] } else { b[2 * (i - 1)] });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x2<Self> {
+        let lanes: [_; 2] = core::array::from_fn(|i| {
+            if i < 1 {
+                a[2 * i + 1]
+            } else {
+                b[2 * (i - 1) + 1]
+            }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn combine_mask32x2(self, a: mask32x2<Self>, b: mask32x2<Self>) -> mask32x4<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x2(self, a: mask32x2<Self>) -> u8x8<Self> {
+        self.split_u8x16(self.reinterpret_u8_mask32x4(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x2(self, a: mask32x2<Self>) -> u16x4<Self> {
+        self.split_u16x8(self.reinterpret_u16_mask32x4(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x2(self, a: mask32x2<Self>) -> i32x2<Self> {
+        self.split_i32x4(self.reinterpret_i32_mask32x4(self.combine_mask32x2(a, a)))
+            .0
+    }
+    #[inline(always)]
+    fn splat_f16x8(self, val: f16) -> f16x8<Self> {
+        [val; 8usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.abs_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.neg_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.sqrt_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_approx_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_approx_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_f32x8(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_f16x8(self, a: f16x8<Self>, steps: u32) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_f32x8(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.add_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.sub_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.div_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.copysign_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_eq_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ne_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_lt_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_le_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ge_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> mask16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_gt_f32x8(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_ne_f16x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_infinite_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_eq_f16x8(self.abs_f16x8(a), self.splat_f16x8(f16::from_bits(0x7c00)))
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        let abs = self.abs_f16x8(a);
+        self.and_mask16x8(
+            self.simd_lt_f16x8(abs, self.splat_f16x8(f16::from_bits(0x0400))),
+            self.simd_gt_f16x8(abs, self.splat_f16x8(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x8(self, a: f16x8<Self>) -> mask16x8<Self> {
+        self.simd_lt_f16x8(
+            self.copysign_f16x8(self.splat_f16x8(f16::from_bits(0x3c00)), a),
+            self.splat_f16x8(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[i / 2]
+            } else {
+                b.val[i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[4usize + i / 2]
+            } else {
+                b.val[4usize + i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_precise_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_precise_f32x8(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.madd_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn msub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.msub_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmadd_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmadd_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmsub_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmsub_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        let product = self.mul_f32x8(a, b);
+        let sum = self.add_f32x8(product, c);
+        let product_part = self.sub_f32x8(sum, c);
+        let c_part = self.sub_f32x8(sum, product_part);
+        let error = self.add_f32x8(
+            self.sub_f32x8(product, product_part),
+            self.sub_f32x8(c, c_part),
+        );
+        let sum_bits: u32x8<Self> = sum.bitcast();
+        let error_bits: u32x8<Self> = error.bitcast();
+        let zero = self.splat_u32x8(0);
+        let same_sign = self.simd_eq_u32x8(
+            self.shr_u32x8(self.xor_u32x8(sum_bits, error_bits), 31),
+            zero,
+        );
+        let step = self.select_u32x8(same_sign, self.splat_u32x8(1), self.splat_u32x8(u32::MAX));
+        let odd = self.simd_eq_u32x8(
+            self.and_u32x8(sum_bits, self.splat_u32x8(1)),
+            self.splat_u32x8(1),
+        );
+        let step = self.select_u32x8(odd, zero, step);
+        let step = self.select_u32x8(self.simd_eq_f32x8(error, self.splat_f32x8(0.0)), zero, step);
+        let finite = self.simd_lt_f32x8(self.abs_f32x8(sum), self.splat_f32x8(f32::INFINITY));
+        let step = self.select_u32x8(finite, step, zero);
+        let sum: f32x8<Self> = self.add_u32x8(sum_bits, step).bitcast();
+        sum.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x8(self, a: f16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_add_fast_f32x8(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn floor_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.floor_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.ceil_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_ties_even_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.fract_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.trunc_f32x8(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f16x8(self, a: mask16x8<Self>, b: f16x8<Self>, c: f16x8<Self>) -> f16x8<Self> {
+        let result: [f16; 8usize] =
+            core::array::from_fn(|i| if a.val[i] != 0 { b.val[i] } else { c.val[i] });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x8(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x8(self, a: f16x8<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x8(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x8<I: Indices<8>>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f16x8<I: Indices<8>>(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f16x8(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1),
+                )
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x8<const K: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x8<const L: usize>(self, a: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            f16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f16x8(self, a: f16x8<Self>, b: f16x8<Self>) -> f16x16<Self> {
+        let mut result = [f16::from_bits(0); 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x8(self, a: f16x8<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x8(self, a: f16x8<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x8(self, a: f16x8<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x8(self, a: f16x8<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x8(self, a: f16x8<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x8(self, a: f16x8<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x8(self, a: f16x8<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x8(self, a: f16x8<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x8(self, a: f16x8<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x8(self, a: f16x8<Self>) -> f32x8<Self> {
+        a.val.map(f16::to_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_f16x8(self, a: f16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x8(self, a: f16x8<Self>) -> u8x8<Self> {
+        self.cvt_u8_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x8(self, a: f16x8<Self>) -> i16x8<Self> {
+        self.cvt_i16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x8(self, a: f16x8<Self>) -> u16x8<Self> {
+        self.cvt_u16_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x8(self, a: f16x8<Self>) -> i32x8<Self> {
+        self.cvt_i32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x8(self, a: f16x8<Self>) -> u32x8<Self> {
+        self.cvt_u32_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f16x8(self, a: f16x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f16x8(self, a: f16x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f32x8(self.cvt_f32_f16x8(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x8(self, val: bf16) -> bf16x8<Self> {
+        self.splat_u16x8(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x8(self, a: mask16x8<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> bf16x8<Self> {
+        self.select_u16x8(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x8<I: Indices<8>>(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_bf16x8(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1),
+                )
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x8<const K: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x8<const L: usize>(self, a: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            bf16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_bf16x8(self, a: bf16x8<Self>, b: bf16x8<Self>) -> bf16x16<Self> {
+        self.combine_u16x8(a.bitcast(), b.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x8(self, a: bf16x8<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x8(self, a: bf16x8<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x8(self, a: bf16x8<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x8(self, a: bf16x8<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x8(self, a: bf16x8<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x8(self, a: bf16x8<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x8(self, a: bf16x8<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x8(self, a: bf16x8<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x8(self, a: bf16x8<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x8(self, a: bf16x8<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x8(self, a: bf16x8<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x8(self, a: bf16x8<Self>) -> f32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let zero = _mm_setzero_si128();
+            let low = _mm_castsi128_ps(_mm_unpacklo_epi16(zero, a));
+            let high = _mm_castsi128_ps(_mm_unpackhi_epi16(zero, a));
+            self.combine_f32x4(low.simd_into(self), high.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn splat_f32x4(self, val: f32) -> f32x4<Self> {
+        unsafe { _mm_set1_ps(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_andnot_ps(_mm_set1_ps(-0.0), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_xor_ps(a.into(), _mm_set1_ps(-0.0)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sqrt_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_sqrt_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_rcp_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_rsqrt_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self> {
+        let estimate = self.recip_approx_f32x4(a);
+        let mut x = estimate;
+        for _ in 0..steps {
+            x = self.mul_f32x4(x, self.msub_f32x4(self.splat_f32x4(2.0), a, x));
+        }
+        let min_positive = self.splat_f32x4(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x4(estimate);
+        let out_of_range = self.or_mask32x4(
+            self.or_mask32x4(
+                self.simd_lt_f32x4(self.abs_f32x4(a), min_positive),
+                self.simd_lt_f32x4(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x4(magnitude, self.splat_f32x4(f32::INFINITY)),
+        );
+        if self.any_mask32x4(out_of_range) {
+            let exact = self.div_f32x4(self.splat_f32x4(1.0), a);
+            self.select_f32x4(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn rsqrt_f32x4(self, a: f32x4<Self>, steps: u32) -> f32x4<Self> {
+        let estimate = self.rsqrt_approx_f32x4(a);
+        let half = self.mul_f32x4(self.splat_f32x4(0.5), a);
+        let mut x = estimate;
+        for _ in 0..steps {
+            x = self.mul_f32x4(
+                x,
+                self.msub_f32x4(self.splat_f32x4(1.5), self.mul_f32x4(half, x), x),
+            );
+        }
+        let min_positive = self.splat_f32x4(f32::MIN_POSITIVE);
+        let magnitude = self.abs_f32x4(estimate);
+        let out_of_range = self.or_mask32x4(
+            self.or_mask32x4(
+                self.simd_lt_f32x4(self.abs_f32x4(a), min_positive),
+                self.simd_lt_f32x4(magnitude, min_positive),
+            ),
+            self.simd_eq_f32x4(magnitude, self.splat_f32x4(f32::INFINITY)),
+        );
+        if self.any_mask32x4(out_of_range) {
+            let exact = self.div_f32x4(self.splat_f32x4(1.0), self.sqrt_f32x4(a));
+            self.select_f32x4(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+    #[inline(always)]
+    fn add_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_add_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_sub_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_mul_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn div_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_div_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn copysign_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let mask = _mm_set1_ps(-0.0);
+            _mm_or_ps(_mm_and_ps(mask, b.into()), _mm_andnot_ps(mask, a.into())).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmpeq_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmpneq_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmplt_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmple_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmpge_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_castps_si128(_mm_cmpgt_ps(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_ne_f32x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_eq_f32x4(self.abs_f32x4(a), self.splat_f32x4(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        let abs = self.abs_f32x4(a);
+        self.and_mask32x4(
+            self.simd_lt_f32x4(abs, self.splat_f32x4(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x4(abs, self.splat_f32x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x4(self, a: f32x4<Self>) -> mask32x4<Self> {
+        self.simd_lt_f32x4(
+            self.copysign_f32x4(self.splat_f32x4(1.0), a),
+            self.splat_f32x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_unpacklo_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_unpackhi_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_max_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_ps(_mm_max_ps(a, b), a, _mm_cmpunord_ps(b, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn min_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_min_ps(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_precise_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_ps(_mm_min_ps(a, b), a, _mm_cmpunord_ps(b, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn madd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_add_ps(a.into(), _mm_mul_ps(b.into(), c.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn msub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_sub_ps(a.into(), _mm_mul_ps(b.into(), c.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn nmadd_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.madd_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.neg_f32x4(self.msub_f32x4(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        let product = self.mul_f64x4(self.cvt_f64_f32x4(a), self.cvt_f64_f32x4(b));
+        let c = self.cvt_f64_f32x4(c);
+        let sum = self.add_f64x4(product, c);
+        let product_part = self.sub_f64x4(sum, c);
+        let c_part = self.sub_f64x4(sum, product_part);
+        let error = self.add_f64x4(
+            self.sub_f64x4(product, product_part),
+            self.sub_f64x4(c, c_part),
+        );
+        let sum_bits: u64x4<Self> = sum.bitcast();
+        let error_bits: u64x4<Self> = error.bitcast();
+        let zero = self.splat_u64x4(0);
+        let same_sign = self.simd_eq_u64x4(
+            self.shr_u64x4(self.xor_u64x4(sum_bits, error_bits), 63),
+            zero,
+        );
+        let step = self.select_u64x4(same_sign, self.splat_u64x4(1), self.splat_u64x4(u64::MAX));
+        let odd = self.simd_eq_u64x4(
+            self.and_u64x4(sum_bits, self.splat_u64x4(1)),
+            self.splat_u64x4(1),
+        );
+        let step = self.select_u64x4(odd, zero, step);
+        let step = self.select_u64x4(self.simd_eq_f64x4(error, self.splat_f64x4(0.0)), zero, step);
+        let finite = self.simd_lt_f64x4(self.abs_f64x4(sum), self.splat_f64x4(f64::INFINITY));
+        let step = self.select_u64x4(finite, step, zero);
+        let sum: f64x4<Self> = self.add_u64x4(sum_bits, step).bitcast();
+        self.cvt_f32_f64x4(sum)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x4(self, a: f32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        self.madd_f32x4(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_floor_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn ceil_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            _mm_round_ps::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn round_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        let int = self.trunc_f32x4(a);
+        let frac = self.abs_f32x4(self.sub_f32x4(a, int));
+        let up = self.select_f32x4(
+            self.simd_ge_f32x4(frac, self.splat_f32x4(0.5)),
+            self.splat_f32x4(1.0),
+            self.splat_f32x4(0.0),
+        );
+        self.copysign_f32x4(self.add_f32x4(self.abs_f32x4(int), up), a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn fract_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        self.sub_f32x4(a, self.trunc_f32x4(a))
+    }
+    #[inline(always)]
+    fn trunc_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn select_f32x4(self, a: mask32x4<Self>, b: f32x4<Self>, c: f32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_blendv_ps(c.into(), b.into(), _mm_castsi128_ps(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe {
+            let a = a.into();
+            let pairs = _mm_add_ps(a, _mm_movehdup_ps(a));
+            _mm_cvtss_f32(_mm_add_ss(pairs, _mm_movehl_ps(pairs, pairs)))
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe {
+            let a: __m128 = a.into();
+            let pairs: __m128 = self
+                .min_precise_f32x4(a.simd_into(self), _mm_movehdup_ps(a).simd_into(self))
+                .into();
+            let total: __m128 = self
+                .min_precise_f32x4(
+                    pairs.simd_into(self),
+                    _mm_movehl_ps(pairs, pairs).simd_into(self),
+                )
+                .into();
+            _mm_cvtss_f32(total)
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_f32x4(self, a: f32x4<Self>) -> f32 {
+        unsafe {
+            let a: __m128 = a.into();
+            let pairs: __m128 = self
+                .max_precise_f32x4(a.simd_into(self), _mm_movehdup_ps(a).simd_into(self))
+                .into();
+            let total: __m128 = self
+                .max_precise_f32x4(
+                    pairs.simd_into(self),
+                    _mm_movehl_ps(pairs, pairs).simd_into(self),
+                )
+                .into();
+            _mm_cvtss_f32(total)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_f32x4<I: Indices<4>>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f32x4<I: Indices<4>>(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f32x4(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x1b>(a.to_bytes().into()) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x4<const K: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x4<const L: usize>(self, a: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0x88>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0xdd>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            f32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f32x4(self, a: f32x4<Self>, b: f32x4<Self>) -> f32x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x4(self, a: f32x4<Self>) -> (f32x2<Self>, f32x2<Self>) {
+        unsafe {
+            let a: __m128 = a.into();
+            (a.simd_into(self), _mm_movehl_ps(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x4(self, a: f32x4<Self>) -> f16x8<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x4(self, a: f32x4<Self>) -> bf16x8<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x4(self, a: f32x4<Self>) -> f64x2<Self> {
+        unsafe { _mm_castps_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x4(self, a: f32x4<Self>) -> i8x16<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x4(self, a: f32x4<Self>) -> u8x16<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x4(self, a: f32x4<Self>) -> i16x8<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x4(self, a: f32x4<Self>) -> u16x8<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x4(self, a: f32x4<Self>) -> i64x2<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x4(self, a: f32x4<Self>) -> u64x2<Self> {
+        unsafe { _mm_castps_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x4(self, a: f32x4<Self>) -> f64x4<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtps_pd(a);
+            let high = _mm_cvtps_pd(_mm_movehl_ps(a, a));
+            self.combine_f64x2(low.simd_into(self), high.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x4(self, a: f32x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let a = a.into();
+            let converted = _mm_cvttps_epi32(a);
+            let overflow = _mm_castps_si128(_mm_cmpge_ps(a, _mm_set1_ps(2147483648.0)));
+            let nan = _mm_castps_si128(_mm_cmpunord_ps(a, a));
+            _mm_andnot_si128(nan, _mm_xor_si128(converted, overflow)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x4(self, a: f32x4<Self>) -> i32x4<Self> {
+        self.cvt_i32_f32x4(self.round_ties_even_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let a = _mm_max_ps(a.into(), _mm_setzero_ps());
+            let exceeds_i32 = _mm_castps_si128(_mm_cmpge_ps(a, _mm_set1_ps(2147483648.0)));
+            let low = _mm_cvttps_epi32(a);
+            let high = _mm_xor_si128(
+                _mm_cvttps_epi32(_mm_sub_ps(a, _mm_set1_ps(2147483648.0))),
+                _mm_set1_epi32(i32::MIN),
+            );
+            let overflow = _mm_castps_si128(_mm_cmpge_ps(a, _mm_set1_ps(4294967296.0)));
+            _mm_or_si128(_mm_blendv_epi8(low, high, exceeds_i32), overflow).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x4(self, a: f32x4<Self>) -> u32x4<Self> {
+        self.cvt_u32_f32x4(self.round_ties_even_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x4(self, a: f32x4<Self>) -> i64x4<Self> {
+        self.cvt_i64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x4(self, a: f32x4<Self>) -> u64x4<Self> {
+        self.cvt_u64_round_f64x4(self.cvt_f64_f32x4(a))
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        let b = self.cvt_f32_bf16x8(b).val;
+        let c = self.cvt_f32_bf16x8(c).val;
+        let result: [f32; 4usize] =
+            core::array::from_fn(|i| a.val[i] + b[2 * i + 1] * c[2 * i + 1] + b[2 * i] * c[2 * i]);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn mmla_bf16_f32x4(self, a: f32x4<Self>, b: bf16x8<Self>, c: bf16x8<Self>) -> f32x4<Self> {
+        let b = self.cvt_f32_bf16x8(b).val;
+        let c = self.cvt_f32_bf16x8(c).val;
+        let result: [f32; 4usize] = core::array::from_fn(|i| {
+            let (row, col) = (4 * (i / 2), 4 * (i % 2));
+            a.val[i]
+                + b[row] * c[col]
+                + b[row + 1] * c[col + 1]
+                + b[row + 2] * c[col + 2]
+                + b[row + 3] * c[col + 3]
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i8x16(self, val: i8) -> i8x16<Self> {
+        unsafe { _mm_set1_epi8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_add_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_sub_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let even = _mm_mullo_epi16(a, b);
+            let odd = _mm_mullo_epi16(_mm_srli_epi16::<8>(a), _mm_srli_epi16::<8>(b));
+            _mm_or_si128(
+                _mm_slli_epi16::<8>(odd),
+                _mm_and_si128(even, _mm_set1_epi16(0xff)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        unsafe {
+            _mm_and_si128(
+                _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)),
+                _mm_set1_epi8(0xff_u8.checked_shl(shift).unwrap_or(0) as i8),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_i8x16(self, a: i8x16<Self>, shift: u32) -> i8x16<Self> {
+        unsafe {
+            let val = a.into();
+            let shift_count = _mm_cvtsi32_si128(shift.saturating_add(8) as i32);
+            let lo_16 = _mm_unpacklo_epi8(val, val);
+            let hi_16 = _mm_unpackhi_epi8(val, val);
+            let lo_shifted = _mm_sra_epi16(lo_16, shift_count);
+            let hi_shifted = _mm_sra_epi16(hi_16, shift_count);
+            _mm_packs_epi16(lo_shifted, hi_shifted).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shl_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u8).min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u8).min(7);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpgt_epi8(b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(_mm_min_epi8(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(_mm_max_epi8(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpgt_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_unpacklo_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_unpackhi_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i8x16(self, a: mask8x16<Self>, b: i8x16<Self>, c: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_min_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_max_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as i8
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epi8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epi8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_min_epi8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_min_epi8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as i8
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_i8x16(self, a: i8x16<Self>) -> i8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epi8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epi8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_max_epi8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_max_epi8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as i8
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_i8x16(self, a: i8x16<Self>) -> i8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        let x = x & (x >> 8);
+        x as i8
+    }
+    #[inline(always)]
+    fn reduce_or_i8x16(self, a: i8x16<Self>) -> i8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        let x = x | (x >> 8);
+        x as i8
+    }
+    #[inline(always)]
+    fn shuffle_i8x16<I: Indices<16>>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i8x16<I: Indices<16>>(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0),
+                )
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x16<const K: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x16<const L: usize>(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            i8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_adds_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_subs_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_abs_epi8(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i8x16(self, a: i8x16<Self>) -> i8x16<Self> {
+        unsafe { _mm_sub_epi8(_mm_setzero_si128(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> u8x16<Self> {
+        self.sub_i8x16(self.max_i8x16(a, b), self.min_i8x16(a, b))
+            .bitcast()
+    }
+    #[inline(always)]
+    fn mul_widen_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i16x16<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let low = _mm_mullo_epi16(_mm_cvtepi8_epi16(a), _mm_cvtepi8_epi16(b));
+            let high = _mm_mullo_epi16(
+                _mm_cvtepi8_epi16(_mm_srli_si128::<8>(a)),
+                _mm_cvtepi8_epi16(_mm_srli_si128::<8>(b)),
+            );
+            self.combine_i16x8(low.simd_into(self), high.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn mul_high_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let even = _mm_mullo_epi16(
+                _mm_srai_epi16::<8>(_mm_slli_epi16::<8>(a)),
+                _mm_srai_epi16::<8>(_mm_slli_epi16::<8>(b)),
+            );
+            let odd = _mm_mullo_epi16(_mm_srai_epi16::<8>(a), _mm_srai_epi16::<8>(b));
+            _mm_or_si128(
+                _mm_srli_epi16::<8>(even),
+                _mm_and_si128(odd, _mm_set1_epi16(-0x100)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn combine_i8x16(self, a: i8x16<Self>, b: i8x16<Self>) -> i8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i8x16(self, a: i8x16<Self>) -> i16x16<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepi8_epi16(a).simd_into(self);
+            let high = _mm_cvtepi8_epi16(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_i16x8(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        unsafe { _mm_cvtepi8_epi16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        unsafe { _mm_cvtepi8_epi16(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x16(self, a: i8x16<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x16(self, a: i8x16<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x16(self, a: i8x16<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x16(self, a: i8x16<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x16(self, a: i8x16<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x16(self, a: i8x16<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x16(self, a: i8x16<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x16(self, a: i8x16<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x16(self, a: i8x16<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x16(self, a: i8x16<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x16(self, a: i8x16<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f16_i8x16(self, a: i8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i8x16(self, a: i8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i16x16(self.widen_i8x16(a))
+    }
+    #[inline(always)]
+    fn splat_u8x16(self, val: u8) -> u8x16<Self> {
+        unsafe { _mm_set1_epi8(val as i8).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_add_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_sub_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let even = _mm_mullo_epi16(a, b);
+            let odd = _mm_mullo_epi16(_mm_srli_epi16::<8>(a), _mm_srli_epi16::<8>(b));
+            _mm_or_si128(
+                _mm_slli_epi16::<8>(odd),
+                _mm_and_si128(even, _mm_set1_epi16(0xff)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        unsafe {
+            _mm_and_si128(
+                _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)),
+                _mm_set1_epi8(0xff_u8.checked_shl(shift).unwrap_or(0) as i8),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shr_u8x16(self, a: u8x16<Self>, shift: u32) -> u8x16<Self> {
+        unsafe {
+            let val = a.into();
+            let shift_count = _mm_cvtsi32_si128(shift as i32);
+            let lo_16 = _mm_unpacklo_epi8(val, _mm_setzero_si128());
+            let hi_16 = _mm_unpackhi_epi8(val, _mm_setzero_si128());
+            let lo_shifted = _mm_srl_epi16(lo_16, shift_count);
+            let hi_shifted = _mm_srl_epi16(hi_16, shift_count);
+            _mm_packus_epi16(lo_shifted, hi_shifted).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shl_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi8(i8::MIN);
+            let a_signed = _mm_xor_si128(b.into(), sign_bit);
+            let b_signed = _mm_xor_si128(a.into(), sign_bit);
+            _mm_cmpgt_epi8(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_le_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(_mm_min_epu8(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(_mm_max_epu8(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi8(i8::MIN);
+            let a_signed = _mm_xor_si128(a.into(), sign_bit);
+            let b_signed = _mm_xor_si128(b.into(), sign_bit);
+            _mm_cmpgt_epi8(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn zip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_unpacklo_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_unpackhi_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u8x16(self, a: mask8x16<Self>, b: u8x16<Self>, c: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_min_epu8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_max_epu8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_add_epi8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as u8
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epu8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epu8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_min_epu8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_min_epu8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as u8
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_u8x16(self, a: u8x16<Self>) -> u8 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epu8(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epu8(a, _mm_srli_si128::<4>(a));
+            let a = _mm_max_epu8(a, _mm_srli_si128::<2>(a));
+            let a = _mm_max_epu8(a, _mm_srli_si128::<1>(a));
+            _mm_cvtsi128_si32(a) as u8
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_u8x16(self, a: u8x16<Self>) -> u8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        let x = x & (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn reduce_or_u8x16(self, a: u8x16<Self>) -> u8 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        let x = x | (x >> 8);
+        x as u8
+    }
+    #[inline(always)]
+    fn shuffle_u8x16<I: Indices<16>>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u8x16<I: Indices<16>>(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u8x16(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0),
+                )
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x16<const K: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x16<const L: usize>(self, a: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            u8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_adds_epu8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_subs_epu8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        self.sub_u8x16(self.max_u8x16(a, b), self.min_u8x16(a, b))
+    }
+    #[inline(always)]
+    fn avg_round_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe { _mm_avg_epu8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u16x16<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let low = _mm_mullo_epi16(_mm_cvtepu8_epi16(a), _mm_cvtepu8_epi16(b));
+            let high = _mm_mullo_epi16(
+                _mm_cvtepu8_epi16(_mm_srli_si128::<8>(a)),
+                _mm_cvtepu8_epi16(_mm_srli_si128::<8>(b)),
+            );
+            self.combine_u16x8(low.simd_into(self), high.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn mul_high_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let even = _mm_mullo_epi16(
+                _mm_and_si128(a, _mm_set1_epi16(0xff)),
+                _mm_and_si128(b, _mm_set1_epi16(0xff)),
+            );
+            let odd = _mm_mullo_epi16(_mm_srli_epi16::<8>(a), _mm_srli_epi16::<8>(b));
+            _mm_or_si128(
+                _mm_srli_epi16::<8>(even),
+                _mm_and_si128(odd, _mm_set1_epi16(-0x100)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        unsafe {
+            _mm_shuffle_epi8(a.into(), _mm_adds_epu8(b.into(), _mm_set1_epi8(0x70))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn lookup_32_u8x16(self, a: u8x32<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let high = self.sub_u8x16(b, self.splat_u8x16(16));
+        self.or_u8x16(
+            self.swizzle_dyn_u8x16(a0, b),
+            self.swizzle_dyn_u8x16(a1, high),
+        )
+    }
+    #[inline(always)]
+    fn lookup_48_u8x16(self, a: [u8x16<Self>; 3], b: u8x16<Self>) -> u8x16<Self> {
+        let high = self.sub_u8x16(b, self.splat_u8x16(32));
+        self.or_u8x16(
+            self.lookup_32_u8x16(self.combine_u8x16(a[0], a[1]), b),
+            self.swizzle_dyn_u8x16(a[2], high),
+        )
+    }
+    #[inline(always)]
+    fn lookup_64_u8x16(self, a: u8x64<Self>, b: u8x16<Self>) -> u8x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let high = self.sub_u8x16(b, self.splat_u8x16(32));
+        self.or_u8x16(self.lookup_32_u8x16(a0, b), self.lookup_32_u8x16(a1, high))
+    }
+    #[inline(always)]
+    fn combine_u8x16(self, a: u8x16<Self>, b: u8x16<Self>) -> u8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x16(self, a: u8x16<Self>) -> (u8x8<Self>, u8x8<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn widen_u8x16(self, a: u8x16<Self>) -> u16x16<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepu8_epi16(a).simd_into(self);
+            let high = _mm_cvtepu8_epi16(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_u16x8(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        unsafe { _mm_cvtepu8_epi16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        unsafe { _mm_cvtepu8_epi16(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x16(self, a: u8x16<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x16(self, a: u8x16<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x16(self, a: u8x16<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x16(self, a: u8x16<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x16(self, a: u8x16<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x16(self, a: u8x16<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x16(self, a: u8x16<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x16(self, a: u8x16<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x16(self, a: u8x16<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x16(self, a: u8x16<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x16(self, a: u8x16<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f16_u8x16(self, a: u8x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u16x16(self.widen_u8x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_unorm_u8x16(self, a: u8x16<Self>) -> f32x16<Self> {
+        self.div_f32x16(self.cvt_f32_u8x16(a), self.splat_f32x16(255.0))
+    }
+    #[inline(always)]
+    fn splat_mask8x16(self, val: i8) -> mask8x16<Self> {
+        unsafe { _mm_set1_epi8(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask8x16(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+        c: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe { _mm_cmpeq_epi8(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask8x16(self, a: mask8x16<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask8x16(self, a: mask8x16<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) == 0xffff }
+    }
+    #[inline(always)]
+    fn none_mask8x16(self, a: mask8x16<Self>) -> bool {
+        !self.any_mask8x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x16(self, a: mask8x16<Self>) -> usize {
+        self.to_bitmask_mask8x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x16(self, a: mask8x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x16(self, a: mask8x16<Self>) -> u64 {
+        unsafe { _mm_movemask_epi8(a.into()) as u32 as u64 }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x16(self, bits: u64) -> mask8x16<Self> {
+        unsafe {
+            let weights = _mm_setr_epi8(1, 2, 4, 8, 16, 32, 64, -128, 1, 2, 4, 8, 16, 32, 64, -128);
+            _mm_cmpeq_epi8(
+                _mm_and_si128(
+                    _mm_shuffle_epi8(
+                        _mm_cvtsi32_si128(bits as i32),
+                        _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1),
+                    ),
+                    weights,
+                ),
+                weights,
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask8x16<I: Indices<16>>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x16<I: Indices<16>>(
+        self,
+        a: mask8x16<Self>,
+        b: mask8x16<Self>,
+    ) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask8x16(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0),
+                )
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, K % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x16<const K: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 1, (16 - K % 16) % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x16<const L: usize>(self, a: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 1, L % 16) };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x16<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            mask8x16::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask8x16(self, a: mask8x16<Self>, b: mask8x16<Self>) -> mask8x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x16(self, a: mask8x16<Self>) -> (mask8x8<Self>, mask8x8<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x16(self, a: mask8x16<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x16(self, a: mask8x16<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x16(self, a: mask8x16<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x16(self, a: mask8x16<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x16(self, a: mask8x16<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x16(self, a: mask8x16<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x16(self, a: mask8x16<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x16(self, a: mask8x16<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i16x8(self, val: i16) -> i16x8<Self> {
+        unsafe { _mm_set1_epi16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_add_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_sub_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_mullo_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        unsafe { _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i16x8(self, a: i16x8<Self>, shift: u32) -> i16x8<Self> {
+        unsafe { _mm_sra_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u16).min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u16).min(15);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpgt_epi16(b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(_mm_min_epi16(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(_mm_max_epi16(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpgt_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_unpacklo_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_unpackhi_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i16x8(self, a: mask16x8<Self>, b: i16x8<Self>, c: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_min_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_max_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_add_epi16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as i16
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epi16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epi16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_min_epi16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as i16
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_i16x8(self, a: i16x8<Self>) -> i16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epi16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epi16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_max_epi16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as i16
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_i16x8(self, a: i16x8<Self>) -> i16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        x as i16
+    }
+    #[inline(always)]
+    fn reduce_or_i16x8(self, a: i16x8<Self>) -> i16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        x as i16
+    }
+    #[inline(always)]
+    fn shuffle_i16x8<I: Indices<8>>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i16x8<I: Indices<8>>(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1),
+                )
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x8<const K: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x8<const L: usize>(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            i16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_adds_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_subs_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_abs_epi16(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i16x8(self, a: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_sub_epi16(_mm_setzero_si128(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> u16x8<Self> {
+        self.sub_i16x8(self.max_i16x8(a, b), self.min_i16x8(a, b))
+            .bitcast()
+    }
+    #[inline(always)]
+    fn mul_widen_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x8<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let low = _mm_mullo_epi16(a, b);
+            let high = _mm_mulhi_epi16(a, b);
+            self.combine_i32x4(
+                _mm_unpacklo_epi16(low, high).simd_into(self),
+                _mm_unpackhi_epi16(low, high).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn mul_high_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe { _mm_mulhi_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let result = _mm_mulhrs_epi16(a.into(), b.into());
+            _mm_xor_si128(result, _mm_cmpeq_epi16(result, _mm_set1_epi16(i16::MIN))).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn dot_i16_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i32x4<Self> {
+        unsafe { _mm_madd_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_i16x8(self, a: i16x8<Self>, b: i16x8<Self>) -> i16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_i16x8(self, a: i16x8<Self>) -> i32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepi16_epi32(a).simd_into(self);
+            let high = _mm_cvtepi16_epi32(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_i32x4(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        unsafe { _mm_cvtepi16_epi32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        unsafe { _mm_cvtepi16_epi32(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x8(self, a: i16x8<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x8(self, a: i16x8<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x8(self, a: i16x8<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x8(self, a: i16x8<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x8(self, a: i16x8<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x8(self, a: i16x8<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x8(self, a: i16x8<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x8(self, a: i16x8<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x8(self, a: i16x8<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x8(self, a: i16x8<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x8(self, a: i16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x8(self, a: i16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_i16x8(self, a: i16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i32x8(self.widen_i16x8(a))
+    }
+    #[inline(always)]
+    fn splat_u16x8(self, val: u16) -> u16x8<Self> {
+        unsafe { _mm_set1_epi16(val as i16).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_add_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_sub_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_mullo_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        unsafe { _mm_sll_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u16x8(self, a: u16x8<Self>, shift: u32) -> u16x8<Self> {
+        unsafe { _mm_srl_epi16(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi16(i16::MIN);
+            let a_signed = _mm_xor_si128(b.into(), sign_bit);
+            let b_signed = _mm_xor_si128(a.into(), sign_bit);
+            _mm_cmpgt_epi16(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_le_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(_mm_min_epu16(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(_mm_max_epu16(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi16(i16::MIN);
+            let a_signed = _mm_xor_si128(a.into(), sign_bit);
+            let b_signed = _mm_xor_si128(b.into(), sign_bit);
+            _mm_cmpgt_epi16(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn zip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_unpacklo_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_unpackhi_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u16x8(self, a: mask16x8<Self>, b: u16x8<Self>, c: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_min_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_max_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_add_epi16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as u16
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epu16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epu16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_min_epu16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as u16
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_u16x8(self, a: u16x8<Self>) -> u16 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epu16(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epu16(a, _mm_srli_si128::<4>(a));
+            let a = _mm_max_epu16(a, _mm_srli_si128::<2>(a));
+            _mm_cvtsi128_si32(a) as u16
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_u16x8(self, a: u16x8<Self>) -> u16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        let x = x & (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn reduce_or_u16x8(self, a: u16x8<Self>) -> u16 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        let x = x | (x >> 16);
+        x as u16
+    }
+    #[inline(always)]
+    fn shuffle_u16x8<I: Indices<8>>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u16x8<I: Indices<8>>(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u16x8(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1),
+                )
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x8<const K: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x8<const L: usize>(self, a: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            u16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn saturating_add_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_adds_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_subs_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        self.sub_u16x8(self.max_u16x8(a, b), self.min_u16x8(a, b))
+    }
+    #[inline(always)]
+    fn avg_round_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_avg_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_widen_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u32x8<Self> {
+        unsafe {
+            let (a, b) = (a.into(), b.into());
+            let low = _mm_mullo_epi16(a, b);
+            let high = _mm_mulhi_epu16(a, b);
+            self.combine_u32x4(
+                _mm_unpacklo_epi16(low, high).simd_into(self),
+                _mm_unpackhi_epi16(low, high).simd_into(self),
+            )
+        }
+    }
+    #[inline(always)]
+    fn mul_high_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x8<Self> {
+        unsafe { _mm_mulhi_epu16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn combine_u16x8(self, a: u16x8<Self>, b: u16x8<Self>) -> u16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x8(self, a: u16x8<Self>) -> (u16x4<Self>, u16x4<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn widen_u16x8(self, a: u16x8<Self>) -> u32x8<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepu16_epi32(a).simd_into(self);
+            let high = _mm_cvtepu16_epi32(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_u32x4(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        unsafe { _mm_cvtepu16_epi32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        unsafe { _mm_cvtepu16_epi32(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        unsafe {
+            let (low, high) = (a.into(), a.into());
+            _mm_packus_epi16(
+                _mm_and_si128(low, _mm_set1_epi16(255)),
+                _mm_and_si128(high, _mm_set1_epi16(255)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x8(self, a: u16x8<Self>) -> u8x8<Self> {
+        unsafe {
+            let (low, high) = (a.into(), a.into());
+            _mm_packus_epi16(
+                _mm_min_epu16(low, _mm_set1_epi16(255)),
+                _mm_min_epu16(high, _mm_set1_epi16(255)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x8(self, a: u16x8<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x8(self, a: u16x8<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x8(self, a: u16x8<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x8(self, a: u16x8<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x8(self, a: u16x8<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x8(self, a: u16x8<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x8(self, a: u16x8<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x8(self, a: u16x8<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x8(self, a: u16x8<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x8(self, a: u16x8<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x8(self, a: u16x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x8(self, a: u16x8<Self>) -> f32x8<Self> {
+        self.cvt_f32_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f64_u16x8(self, a: u16x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u32x8(self.widen_u16x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x8(self, val: i16) -> mask16x8<Self> {
+        unsafe { _mm_set1_epi16(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask16x8(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+        c: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe { _mm_cmpeq_epi16(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask16x8(self, a: mask16x8<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask16x8(self, a: mask16x8<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) == 0xffff }
+    }
+    #[inline(always)]
+    fn none_mask16x8(self, a: mask16x8<Self>) -> bool {
+        !self.any_mask16x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x8(self, a: mask16x8<Self>) -> usize {
+        self.to_bitmask_mask16x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x8(self, a: mask16x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x8(self, a: mask16x8<Self>) -> u64 {
+        unsafe { _mm_movemask_epi8(_mm_packs_epi16(a.into(), _mm_setzero_si128())) as u64 }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x8(self, bits: u64) -> mask16x8<Self> {
+        unsafe {
+            let weights = _mm_setr_epi16(1, 2, 4, 8, 16, 32, 64, 128);
+            _mm_cmpeq_epi16(_mm_and_si128(_mm_set1_epi16(bits as i16), weights), weights)
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask16x8<I: Indices<8>>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x8<I: Indices<8>>(
+        self,
+        a: mask16x8<Self>,
+        b: mask16x8<Self>,
+    ) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask16x8(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_setr_epi8(14, 15, 12, 13, 10, 11, 8, 9, 6, 7, 4, 5, 2, 3, 0, 1),
+                )
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, K % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x8<const K: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 2, (8 - K % 8) % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x8<const L: usize>(self, a: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 2, L % 8) };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpacklo_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x8<Self> {
+        unsafe {
+            let bytes = {
+                let table = _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15);
+                _mm_unpackhi_epi64(
+                    _mm_shuffle_epi8(a.to_bytes().into(), table),
+                    _mm_shuffle_epi8(b.to_bytes().into(), table),
+                )
+            };
+            mask16x8::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask16x8(self, a: mask16x8<Self>, b: mask16x8<Self>) -> mask16x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x8(self, a: mask16x8<Self>) -> (mask16x4<Self>, mask16x4<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x8(self, a: mask16x8<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x8(self, a: mask16x8<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x8(self, a: mask16x8<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x8(self, a: mask16x8<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x8(self, a: mask16x8<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x8(self, a: mask16x8<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x8(self, a: mask16x8<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x8(self, a: mask16x8<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_i32x4(self, val: i32) -> i32x4<Self> {
+        unsafe { _mm_set1_epi32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_add_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_sub_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_mullo_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        unsafe { _mm_sll_epi32(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i32x4(self, a: i32x4<Self>, shift: u32) -> i32x4<Self> {
+        unsafe { _mm_sra_epi32(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u32).min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u32).min(31);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpgt_epi32(b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(_mm_min_epi32(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(_mm_max_epi32(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpgt_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_unpacklo_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_unpackhi_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i32x4(self, a: mask32x4<Self>, b: i32x4<Self>, c: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_min_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_max_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as i32
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epi32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epi32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as i32
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_i32x4(self, a: i32x4<Self>) -> i32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epi32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epi32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as i32
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_i32x4(self, a: i32x4<Self>) -> i32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn reduce_or_i32x4(self, a: i32x4<Self>) -> i32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        x as i32
+    }
+    #[inline(always)]
+    fn shuffle_i32x4<I: Indices<4>>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i32x4<I: Indices<4>>(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x1b>(a.to_bytes().into()) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x4<const K: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x4<const L: usize>(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0x88>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0xdd>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            i32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_abs_epi32(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_i32x4(self, a: i32x4<Self>) -> i32x4<Self> {
+        unsafe { _mm_sub_epi32(_mm_setzero_si128(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> u32x4<Self> {
+        self.sub_i32x4(self.max_i32x4(a, b), self.min_i32x4(a, b))
+            .bitcast()
+    }
+    #[inline(always)]
+    fn combine_i32x4(self, a: i32x4<Self>, b: i32x4<Self>) -> i32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x4(self, a: i32x4<Self>) -> (i32x2<Self>, i32x2<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn widen_i32x4(self, a: i32x4<Self>) -> i64x4<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepi32_epi64(a).simd_into(self);
+            let high = _mm_cvtepi32_epi64(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_i64x2(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        unsafe { _mm_cvtepi32_epi64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        unsafe { _mm_cvtepi32_epi64(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x4(self, a: i32x4<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x4(self, a: i32x4<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x4(self, a: i32x4<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x4(self, a: i32x4<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x4(self, a: i32x4<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x4(self, a: i32x4<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x4(self, a: i32x4<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x4(self, a: i32x4<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x4(self, a: i32x4<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x4(self, a: i32x4<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x4(self, a: i32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_cvtepi32_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x4(self, a: i32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_i64x4(self.widen_i32x4(a))
+    }
+    #[inline(always)]
+    fn splat_u32x4(self, val: u32) -> u32x4<Self> {
+        unsafe { _mm_set1_epi32(val as i32).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_add_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_sub_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_mullo_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        unsafe { _mm_sll_epi32(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u32x4(self, a: u32x4<Self>, shift: u32) -> u32x4<Self> {
+        unsafe { _mm_srl_epi32(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi32(i32::MIN);
+            let a_signed = _mm_xor_si128(b.into(), sign_bit);
+            let b_signed = _mm_xor_si128(a.into(), sign_bit);
+            _mm_cmpgt_epi32(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_le_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(_mm_min_epu32(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(_mm_max_epu32(a.into(), b.into()), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi32(i32::MIN);
+            let a_signed = _mm_xor_si128(a.into(), sign_bit);
+            let b_signed = _mm_xor_si128(b.into(), sign_bit);
+            _mm_cmpgt_epi32(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn zip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_unpacklo_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_unpackhi_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u32x4(self, a: mask32x4<Self>, b: u32x4<Self>, c: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_min_epu32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe { _mm_max_epu32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_add_epi32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_add_epi32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as u32
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_min_epu32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_min_epu32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as u32
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_u32x4(self, a: u32x4<Self>) -> u32 {
+        unsafe {
+            let a = a.into();
+            let a = _mm_max_epu32(a, _mm_srli_si128::<8>(a));
+            let a = _mm_max_epu32(a, _mm_srli_si128::<4>(a));
+            _mm_cvtsi128_si32(a) as u32
+        }
+    }
+    #[inline(always)]
+    fn reduce_and_u32x4(self, a: u32x4<Self>) -> u32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] & chunks[1];
+        let x = x & (x >> 32);
+        x as u32
+    }
+    #[inline(always)]
+    fn reduce_or_u32x4(self, a: u32x4<Self>) -> u32 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        let x = chunks[0] | chunks[1];
+        let x = x | (x >> 32);
+        x as u32
+    }
+    #[inline(always)]
+    fn shuffle_u32x4<I: Indices<4>>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u32x4<I: Indices<4>>(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u32x4(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x1b>(a.to_bytes().into()) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x4<const K: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x4<const L: usize>(self, a: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0x88>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0xdd>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            u32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_diff_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x4<Self> {
+        self.sub_u32x4(self.max_u32x4(a, b), self.min_u32x4(a, b))
+    }
+    #[inline(always)]
+    fn combine_u32x4(self, a: u32x4<Self>, b: u32x4<Self>) -> u32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn widen_u32x4(self, a: u32x4<Self>) -> u64x4<Self> {
+        unsafe {
+            let a = a.into();
+            let low = _mm_cvtepu32_epi64(a).simd_into(self);
+            let high = _mm_cvtepu32_epi64(_mm_srli_si128::<8>(a)).simd_into(self);
+            self.combine_u64x2(low, high)
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        unsafe { _mm_cvtepu32_epi64(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn widen_high_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        unsafe { _mm_cvtepu32_epi64(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn narrow_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        unsafe {
+            let (low, high) = (a.into(), a.into());
+            _mm_packus_epi32(
+                _mm_and_si128(low, _mm_set1_epi32(65535)),
+                _mm_and_si128(high, _mm_set1_epi32(65535)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x4(self, a: u32x4<Self>) -> u16x4<Self> {
+        unsafe {
+            let (low, high) = (a.into(), a.into());
+            _mm_packus_epi32(
+                _mm_min_epu32(low, _mm_set1_epi32(65535)),
+                _mm_min_epu32(high, _mm_set1_epi32(65535)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x4(self, a: u32x4<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x4(self, a: u32x4<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x4(self, a: u32x4<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x4(self, a: u32x4<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x4(self, a: u32x4<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x4(self, a: u32x4<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x4(self, a: u32x4<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x4(self, a: u32x4<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x4(self, a: u32x4<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x4(self, a: u32x4<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x4(self, a: u32x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let a = a.into();
+            let high = _mm_cvtepi32_ps(_mm_srli_epi32::<16>(a));
+            let low = _mm_cvtepi32_ps(_mm_and_si128(a, _mm_set1_epi32(0xffff)));
+            _mm_add_ps(_mm_mul_ps(high, _mm_set1_ps(65536.0)), low).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x4(self, a: u32x4<Self>) -> f64x4<Self> {
+        self.cvt_f64_u64x4(self.widen_u32x4(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x4(self, val: i32) -> mask32x4<Self> {
+        unsafe { _mm_set1_epi32(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask32x4(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+        c: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe { _mm_cmpeq_epi32(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask32x4(self, a: mask32x4<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask32x4(self, a: mask32x4<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) == 0xffff }
+    }
+    #[inline(always)]
+    fn none_mask32x4(self, a: mask32x4<Self>) -> bool {
+        !self.any_mask32x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x4(self, a: mask32x4<Self>) -> usize {
+        self.to_bitmask_mask32x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x4(self, a: mask32x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x4(self, a: mask32x4<Self>) -> u64 {
+        unsafe { _mm_movemask_ps(_mm_castsi128_ps(a.into())) as u32 as u64 }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x4(self, bits: u64) -> mask32x4<Self> {
+        unsafe {
+            let weights = _mm_setr_epi32(1, 2, 4, 8);
+            _mm_cmpeq_epi32(_mm_and_si128(_mm_set1_epi32(bits as i32), weights), weights)
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask32x4<I: Indices<4>>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x4<I: Indices<4>>(
+        self,
+        a: mask32x4<Self>,
+        b: mask32x4<Self>,
+    ) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask32x4(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x1b>(a.to_bytes().into()) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, K % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x4<const K: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 4, (4 - K % 4) % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x4<const L: usize>(self, a: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 4, L % 4) };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0x88>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x4<Self> {
+        unsafe {
+            let bytes = {
+                _mm_castps_si128(_mm_shuffle_ps::<0xdd>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            };
+            mask32x4::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask32x4(self, a: mask32x4<Self>, b: mask32x4<Self>) -> mask32x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x4(self, a: mask32x4<Self>) -> (mask32x2<Self>, mask32x2<Self>) {
+        unsafe {
+            let a: __m128i = a.into();
+            (a.simd_into(self), _mm_unpackhi_epi64(a, a).simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x4(self, a: mask32x4<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x4(self, a: mask32x4<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x4(self, a: mask32x4<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x4(self, a: mask32x4<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x4(self, a: mask32x4<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x4(self, a: mask32x4<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x4(self, a: mask32x4<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x4(self, a: mask32x4<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_f64x2(self, val: f64) -> f64x2<Self> {
+        unsafe { _mm_set1_pd(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_andnot_pd(_mm_set1_pd(-0.0), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn neg_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_xor_pd(a.into(), _mm_set1_pd(-0.0)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sqrt_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_sqrt_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn recip_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), self.sqrt_f64x2(a))
+    }
+    #[inline(always)]
+    fn recip_f64x2(self, a: f64x2<Self>, _steps: u32) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), a)
+    }
+    #[inline(always)]
+    fn rsqrt_f64x2(self, a: f64x2<Self>, _steps: u32) -> f64x2<Self> {
+        self.div_f64x2(self.splat_f64x2(1.0), self.sqrt_f64x2(a))
+    }
+    #[inline(always)]
+    fn add_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_add_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_sub_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_mul_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn div_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_div_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn copysign_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let mask = _mm_set1_pd(-0.0);
+            _mm_or_pd(_mm_and_pd(mask, b.into()), _mm_andnot_pd(mask, a.into())).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_eq_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmpeq_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ne_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmpneq_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmplt_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmple_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_ge_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmpge_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_gt_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_castpd_si128(_mm_cmpgt_pd(a.into(), b.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn is_nan_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_ne_f64x2(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_eq_f64x2(self.abs_f64x2(a), self.splat_f64x2(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        let abs = self.abs_f64x2(a);
+        self.and_mask64x2(
+            self.simd_lt_f64x2(abs, self.splat_f64x2(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x2(abs, self.splat_f64x2(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x2(self, a: f64x2<Self>) -> mask64x2<Self> {
+        self.simd_lt_f64x2(
+            self.copysign_f64x2(self.splat_f64x2(1.0), a),
+            self.splat_f64x2(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_unpacklo_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_unpackhi_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_max_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn max_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_pd(_mm_max_pd(a, b), a, _mm_cmpunord_pd(b, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn min_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_min_pd(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_precise_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_pd(_mm_min_pd(a, b), a, _mm_cmpunord_pd(b, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn madd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_add_pd(a.into(), _mm_mul_pd(b.into(), c.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn msub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_sub_pd(a.into(), _mm_mul_pd(b.into(), c.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn nmadd_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.madd_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn nmsub_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.neg_f64x2(self.msub_f64x2(a, b, c))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        let fallback = crate::Fallback::new();
+        fallback
+            .mul_add_fused_f64x2(
+                a.val.simd_into(fallback),
+                b.val.simd_into(fallback),
+                c.val.simd_into(fallback),
+            )
+            .val
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x2(self, a: f64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        self.madd_f64x2(c, a, b)
+    }
+    #[inline(always)]
+    fn floor_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_floor_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn ceil_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            _mm_round_pd::<{ _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC }>(a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn round_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        let int = self.trunc_f64x2(a);
+        let frac = self.abs_f64x2(self.sub_f64x2(a, int));
+        let up = self.select_f64x2(
+            self.simd_ge_f64x2(frac, self.splat_f64x2(0.5)),
+            self.splat_f64x2(1.0),
+            self.splat_f64x2(0.0),
+        );
+        self.copysign_f64x2(self.add_f64x2(self.abs_f64x2(int), up), a)
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            _mm_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(a.into())
+                .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn fract_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        self.sub_f64x2(a, self.trunc_f64x2(a))
+    }
+    #[inline(always)]
+    fn trunc_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            _mm_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(a.into()).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn select_f64x2(self, a: mask64x2<Self>, b: f64x2<Self>, c: f64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_blendv_pd(c.into(), b.into(), _mm_castsi128_pd(a.into())).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reduce_add_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe {
+            let a = a.into();
+            _mm_cvtsd_f64(_mm_add_sd(a, _mm_unpackhi_pd(a, a)))
+        }
+    }
+    #[inline(always)]
+    fn reduce_min_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe {
+            let a: __m128d = a.into();
+            let total: __m128d = self
+                .min_precise_f64x2(a.simd_into(self), _mm_unpackhi_pd(a, a).simd_into(self))
+                .into();
+            _mm_cvtsd_f64(total)
+        }
+    }
+    #[inline(always)]
+    fn reduce_max_f64x2(self, a: f64x2<Self>) -> f64 {
+        unsafe {
+            let a: __m128d = a.into();
+            let total: __m128d = self
+                .max_precise_f64x2(a.simd_into(self), _mm_unpackhi_pd(a, a).simd_into(self))
+                .into();
+            _mm_cvtsd_f64(total)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_f64x2<I: Indices<2>>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_f64x2<I: Indices<2>>(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_f64x2(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x4e>(a.to_bytes().into()) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x2<const K: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x2<const L: usize>(self, a: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpacklo_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpackhi_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            f64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_f64x2(self, a: f64x2<Self>, b: f64x2<Self>) -> f64x4<Self> {
+        let mut result = [0.0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x2(self, a: f64x2<Self>) -> f16x8<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x2(self, a: f64x2<Self>) -> bf16x8<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x2(self, a: f64x2<Self>) -> f32x4<Self> {
+        unsafe { _mm_castpd_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x2(self, a: f64x2<Self>) -> i8x16<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x2(self, a: f64x2<Self>) -> u8x16<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x2(self, a: f64x2<Self>) -> i16x8<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x2(self, a: f64x2<Self>) -> u16x8<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x2(self, a: f64x2<Self>) -> i32x4<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x2(self, a: f64x2<Self>) -> u32x4<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_castpd_si128(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x2(self, a: f64x2<Self>) -> f32x2<Self> {
+        unsafe { _mm_cvtpd_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x2(self, a: f64x2<Self>) -> i32x2<Self> {
+        self.narrow_saturating_i64x2(self.cvt_i64_round_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        a.val.map(|x| x as i64).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x2(self, a: f64x2<Self>) -> i64x2<Self> {
+        self.cvt_i64_f64x2(self.round_ties_even_f64x2(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        a.val.map(|x| x as u64).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x2(self, a: f64x2<Self>) -> u64x2<Self> {
+        self.cvt_u64_f64x2(self.round_ties_even_f64x2(a))
+    }
+    #[inline(always)]
+    fn splat_i64x2(self, val: i64) -> i64x2<Self> {
+        unsafe { _mm_set1_epi64x(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_add_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_sub_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = _mm_mul_epu32(a, b);
+            let cross = _mm_add_epi64(
+                _mm_mul_epu32(_mm_srli_epi64::<32>(a), b),
+                _mm_mul_epu32(a, _mm_srli_epi64::<32>(b)),
+            );
+            _mm_add_epi64(low, _mm_slli_epi64::<32>(cross)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        unsafe { _mm_sll_epi64(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_i64x2(self, a: i64x2<Self>, shift: u32) -> i64x2<Self> {
+        unsafe {
+            let val = a.into();
+            let sign = _mm_cmpgt_epi64(_mm_setzero_si128(), val);
+            _mm_xor_si128(
+                _mm_srl_epi64(_mm_xor_si128(val, sign), _mm_cvtsi32_si128(shift as i32)),
+                sign,
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shl_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u64).min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u64).min(63);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_cmpeq_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_cmpgt_epi64(b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_le_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            _mm_xor_si128(_mm_cmpgt_epi64(a.into(), b.into()), _mm_set1_epi64x(-1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_ge_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            _mm_xor_si128(_mm_cmpgt_epi64(b.into(), a.into()), _mm_set1_epi64x(-1)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_gt_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_cmpgt_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_unpackhi_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_i64x2(self, a: mask64x2<Self>, b: i64x2<Self>, c: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_epi8(a, b, _mm_cmpgt_epi64(a, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn max_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_epi8(b, a, _mm_cmpgt_epi64(a, b)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reduce_add_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].wrapping_add(a[1])
+    }
+    #[inline(always)]
+    fn reduce_min_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_i64x2(self, a: i64x2<Self>) -> i64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_i64x2(self, a: i64x2<Self>) -> i64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        (chunks[0] & chunks[1]) as i64
+    }
+    #[inline(always)]
+    fn reduce_or_i64x2(self, a: i64x2<Self>) -> i64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        (chunks[0] | chunks[1]) as i64
+    }
+    #[inline(always)]
+    fn shuffle_i64x2<I: Indices<2>>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_i64x2<I: Indices<2>>(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x4e>(a.to_bytes().into()) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x2<const K: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x2<const L: usize>(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpacklo_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpackhi_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            i64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let sign = _mm_cmpgt_epi64(_mm_setzero_si128(), a);
+            _mm_sub_epi64(_mm_xor_si128(a, sign), sign).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn neg_i64x2(self, a: i64x2<Self>) -> i64x2<Self> {
+        unsafe { _mm_sub_epi64(_mm_setzero_si128(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn abs_diff_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> u64x2<Self> {
+        self.sub_i64x2(self.max_i64x2(a, b), self.min_i64x2(a, b))
+            .bitcast()
+    }
+    #[inline(always)]
+    fn combine_i64x2(self, a: i64x2<Self>, b: i64x2<Self>) -> i64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn narrow_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        unsafe {
+            let (low, high) = (a.into(), a.into());
+            _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(
+                _mm_castsi128_ps(low),
+                _mm_castsi128_ps(high),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x2(self, a: i64x2<Self>) -> i32x2<Self> {
+        self.narrow_i64x2(self.max_i64x2(
+            self.min_i64x2(a, self.splat_i64x2(i32::MAX as i64)),
+            self.splat_i64x2(i32::MIN as i64),
+        ))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x2(self, a: i64x2<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x2(self, a: i64x2<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x2(self, a: i64x2<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x2(self, a: i64x2<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x2(self, a: i64x2<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x2(self, a: i64x2<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x2(self, a: i64x2<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x2(self, a: i64x2<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x2(self, a: i64x2<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x2(self, a: i64x2<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x2(self, a: i64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x2(self, a: i64x2<Self>) -> f64x2<Self> {
+        a.val.map(|x| x as f64).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_u64x2(self, val: u64) -> u64x2<Self> {
+        unsafe { _mm_set1_epi64x(val as i64).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn add_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_add_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn sub_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_sub_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn mul_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            let low = _mm_mul_epu32(a, b);
+            let cross = _mm_add_epi64(
+                _mm_mul_epu32(_mm_srli_epi64::<32>(a), b),
+                _mm_mul_epu32(a, _mm_srli_epi64::<32>(b)),
+            );
+            _mm_add_epi64(low, _mm_slli_epi64::<32>(cross)).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn and_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        unsafe { _mm_sll_epi64(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shr_u64x2(self, a: u64x2<Self>, shift: u32) -> u64x2<Self> {
+        unsafe { _mm_srl_epi64(a.into(), _mm_cvtsi32_si128(shift as i32)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn shl_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_cmpeq_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_lt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi64x(i64::MIN);
+            let a_signed = _mm_xor_si128(b.into(), sign_bit);
+            let b_signed = _mm_xor_si128(a.into(), sign_bit);
+            _mm_cmpgt_epi64(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_le_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            _mm_xor_si128(
+                _mm_cmpgt_epi64(
+                    _mm_xor_si128(a.into(), _mm_set1_epi64x(i64::MIN)),
+                    _mm_xor_si128(b.into(), _mm_set1_epi64x(i64::MIN)),
+                ),
+                _mm_set1_epi64x(-1),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_ge_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            _mm_xor_si128(
+                _mm_cmpgt_epi64(
+                    _mm_xor_si128(b.into(), _mm_set1_epi64x(i64::MIN)),
+                    _mm_xor_si128(a.into(), _mm_set1_epi64x(i64::MIN)),
+                ),
+                _mm_set1_epi64x(-1),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn simd_gt_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let sign_bit = _mm_set1_epi64x(i64::MIN);
+            let a_signed = _mm_xor_si128(a.into(), sign_bit);
+            let b_signed = _mm_xor_si128(b.into(), sign_bit);
+            _mm_cmpgt_epi64(a_signed, b_signed).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn zip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_unpacklo_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn zip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_unpackhi_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_u64x2(self, a: mask64x2<Self>, b: u64x2<Self>, c: u64x2<Self>) -> u64x2<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn min_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_epi8(
+                a,
+                b,
+                _mm_cmpgt_epi64(
+                    _mm_xor_si128(a, _mm_set1_epi64x(i64::MIN)),
+                    _mm_xor_si128(b, _mm_set1_epi64x(i64::MIN)),
+                ),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn max_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let a = a.into();
+            let b = b.into();
+            _mm_blendv_epi8(
+                b,
+                a,
+                _mm_cmpgt_epi64(
+                    _mm_xor_si128(a, _mm_set1_epi64x(i64::MIN)),
+                    _mm_xor_si128(b, _mm_set1_epi64x(i64::MIN)),
+                ),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reduce_add_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].wrapping_add(a[1])
+    }
+    #[inline(always)]
+    fn reduce_min_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].min(a[1])
+    }
+    #[inline(always)]
+    fn reduce_max_u64x2(self, a: u64x2<Self>) -> u64 {
+        a[0].max(a[1])
+    }
+    #[inline(always)]
+    fn reduce_and_u64x2(self, a: u64x2<Self>) -> u64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        chunks[0] & chunks[1]
+    }
+    #[inline(always)]
+    fn reduce_or_u64x2(self, a: u64x2<Self>) -> u64 {
+        let chunks: [u64; 2] = bytemuck::cast(a.val);
+        chunks[0] | chunks[1]
+    }
+    #[inline(always)]
+    fn shuffle_u64x2<I: Indices<2>>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_u64x2<I: Indices<2>>(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_u64x2(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x4e>(a.to_bytes().into()) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x2<const K: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x2<const L: usize>(self, a: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpacklo_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpackhi_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            u64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn abs_diff_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x2<Self> {
+        self.sub_u64x2(self.max_u64x2(a, b), self.min_u64x2(a, b))
+    }
+    #[inline(always)]
+    fn combine_u64x2(self, a: u64x2<Self>, b: u64x2<Self>) -> u64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x2(self, a: u64x2<Self>) -> f16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x2(self, a: u64x2<Self>) -> bf16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x2(self, a: u64x2<Self>) -> f32x4<Self> {
+        unsafe { _mm_castsi128_ps(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        unsafe { _mm_castsi128_pd(a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x2(self, a: u64x2<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x2(self, a: u64x2<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x2(self, a: u64x2<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x2(self, a: u64x2<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x2(self, a: u64x2<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x2(self, a: u64x2<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x2(self, a: u64x2<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x2(self, a: u64x2<Self>) -> f32x2<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x2(self, a: u64x2<Self>) -> f64x2<Self> {
+        a.val.map(|x| x as f64).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_mask64x2(self, val: i64) -> mask64x2<Self> {
+        unsafe { _mm_set1_epi64x(val).simd_into(self) }
+    }
+    #[inline(always)]
+    fn not_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), _mm_set1_epi32(-1)).simd_into(self) }
+    }
+    #[inline(always)]
+    fn and_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_and_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn or_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_or_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn xor_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_xor_si128(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn select_mask64x2(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+        c: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        unsafe { _mm_blendv_epi8(c.into(), b.into(), a.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe { _mm_cmpeq_epi64(a.into(), b.into()).simd_into(self) }
+    }
+    #[inline(always)]
+    fn any_mask64x2(self, a: mask64x2<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) != 0 }
+    }
+    #[inline(always)]
+    fn all_mask64x2(self, a: mask64x2<Self>) -> bool {
+        unsafe { _mm_movemask_epi8(a.into()) == 0xffff }
+    }
+    #[inline(always)]
+    fn none_mask64x2(self, a: mask64x2<Self>) -> bool {
+        !self.any_mask64x2(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x2(self, a: mask64x2<Self>) -> usize {
+        self.to_bitmask_mask64x2(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x2(self, a: mask64x2<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x2(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x2(self, a: mask64x2<Self>) -> u64 {
+        unsafe { _mm_movemask_pd(_mm_castsi128_pd(a.into())) as u32 as u64 }
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x2(self, bits: u64) -> mask64x2<Self> {
+        unsafe {
+            let weights = _mm_set_epi64x(2, 1);
+            _mm_cmpeq_epi64(
+                _mm_and_si128(_mm_set1_epi64x(bits as i64), weights),
+                weights,
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn shuffle_mask64x2<I: Indices<2>>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(
+                    a.to_bytes().into(),
+                    _mm_loadu_si128(table.as_ptr() as *const __m128i),
+                )
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x2<I: Indices<2>>(
+        self,
+        a: mask64x2<Self>,
+        b: mask64x2<Self>,
+    ) -> mask64x2<Self> {
+        unsafe {
+            let bytes = {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(
+                        a.to_bytes().into(),
+                        _mm_loadu_si128(table_a.as_ptr() as *const __m128i),
+                    ),
+                    _mm_shuffle_epi8(
+                        b.to_bytes().into(),
+                        _mm_loadu_si128(table_b.as_ptr() as *const __m128i),
+                    ),
+                )
+            };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn reverse_mask64x2(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { _mm_shuffle_epi32::<0x4e>(a.to_bytes().into()) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, K % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x2<const K: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::rotate_128(a.to_bytes().into(), 8, (2 - K % 2) % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x2<const L: usize>(self, a: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { crate::shuffle::broadcast_128(a.to_bytes().into(), 8, L % 2) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpacklo_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x2<Self> {
+        unsafe {
+            let bytes = { _mm_unpackhi_epi64(a.to_bytes().into(), b.to_bytes().into()) };
+            mask64x2::from_bytes(bytes.simd_into(self))
+        }
+    }
+    #[inline(always)]
+    fn combine_mask64x2(self, a: mask64x2<Self>, b: mask64x2<Self>) -> mask64x4<Self> {
+        let mut result = [0; 4usize];
+        result[0..2usize].copy_from_slice(&a.val);
+        result[2usize..4usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x2(self, a: mask64x2<Self>) -> i8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x2(self, a: mask64x2<Self>) -> u8x16<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x2(self, a: mask64x2<Self>) -> i16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x2(self, a: mask64x2<Self>) -> u16x8<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x2(self, a: mask64x2<Self>) -> i32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x2(self, a: mask64x2<Self>) -> u32x4<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x2(self, a: mask64x2<Self>) -> i64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x2(self, a: mask64x2<Self>) -> u64x2<Self> {
+        <__m128i>::from(a).simd_into(self)
+    }
+    #[inline(always)]
+    fn splat_f16x16(self, val: f16) -> f16x16<Self> {
+        [val; 16usize].simd_into(self)
+    }
+    #[inline(always)]
+    fn abs_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.abs_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn neg_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.neg_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sqrt_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.sqrt_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_approx_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_approx_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn recip_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.recip_f32x16(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn rsqrt_f16x16(self, a: f16x16<Self>, steps: u32) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.rsqrt_f32x16(a, steps)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn add_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.add_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn sub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.sub_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn div_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.div_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn copysign_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.copysign_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_eq_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ne_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ne_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_lt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_lt_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_le_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_le_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_ge_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_ge_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_gt_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> mask16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.simd_gt_f32x16(a, b)
+            .val
+            .map(|x| x as i16)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn is_nan_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_ne_f16x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_infinite_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_eq_f16x16(
+            self.abs_f16x16(a),
+            self.splat_f16x16(f16::from_bits(0x7c00)),
+        )
+    }
+    #[inline(always)]
+    fn is_subnormal_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        let abs = self.abs_f16x16(a);
+        self.and_mask16x16(
+            self.simd_lt_f16x16(abs, self.splat_f16x16(f16::from_bits(0x0400))),
+            self.simd_gt_f16x16(abs, self.splat_f16x16(f16::from_bits(0))),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f16x16(self, a: f16x16<Self>) -> mask16x16<Self> {
+        self.simd_lt_f16x16(
+            self.copysign_f16x16(self.splat_f16x16(f16::from_bits(0x3c00)), a),
+            self.splat_f16x16(f16::from_bits(0)),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[i / 2]
+            } else {
+                b.val[i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn zip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                a.val[8usize + i / 2]
+            } else {
+                b.val[8usize + i / 2]
+            }
+        });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn max_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn max_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.max_precise_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn min_precise_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        self.min_precise_f32x16(a, b)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn madd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.madd_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn msub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.msub_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmadd_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmadd_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn nmsub_f16x16(self, a: f16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.nmsub_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fused_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        let product = self.mul_f32x16(a, b);
+        let sum = self.add_f32x16(product, c);
+        let product_part = self.sub_f32x16(sum, c);
+        let c_part = self.sub_f32x16(sum, product_part);
+        let error = self.add_f32x16(
+            self.sub_f32x16(product, product_part),
+            self.sub_f32x16(c, c_part),
+        );
+        let sum_bits: u32x16<Self> = sum.bitcast();
+        let error_bits: u32x16<Self> = error.bitcast();
+        let zero = self.splat_u32x16(0);
+        let same_sign = self.simd_eq_u32x16(
+            self.shr_u32x16(self.xor_u32x16(sum_bits, error_bits), 31),
+            zero,
+        );
+        let step = self.select_u32x16(same_sign, self.splat_u32x16(1), self.splat_u32x16(u32::MAX));
+        let odd = self.simd_eq_u32x16(
+            self.and_u32x16(sum_bits, self.splat_u32x16(1)),
+            self.splat_u32x16(1),
+        );
+        let step = self.select_u32x16(odd, zero, step);
+        let step = self.select_u32x16(
+            self.simd_eq_f32x16(error, self.splat_f32x16(0.0)),
+            zero,
+            step,
+        );
+        let finite = self.simd_lt_f32x16(self.abs_f32x16(sum), self.splat_f32x16(f32::INFINITY));
+        let step = self.select_u32x16(finite, step, zero);
+        let sum: f32x16<Self> = self.add_u32x16(sum_bits, step).bitcast();
+        sum.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn mul_add_fast_f16x16(
+        self,
+        a: f16x16<Self>,
+        b: f16x16<Self>,
+        c: f16x16<Self>,
+    ) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        let b = b.val.map(f16::to_f32_const).simd_into(self);
+        let c = c.val.map(f16::to_f32_const).simd_into(self);
+        self.mul_add_fast_f32x16(a, b, c)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn floor_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.floor_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn ceil_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.ceil_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn round_ties_even_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.round_ties_even_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn fract_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.fract_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn trunc_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        self.trunc_f32x16(a)
+            .val
+            .map(f16::from_f32_const)
+            .simd_into(self)
+    }
+    #[inline(always)]
+    fn select_f16x16(self, a: mask16x16<Self>, b: f16x16<Self>, c: f16x16<Self>) -> f16x16<Self> {
+        let result: [f16; 16usize] =
+            core::array::from_fn(|i| if a.val[i] != 0 { b.val[i] } else { c.val[i] });
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn reduce_add_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_add_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_min_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_min_f32x16(a))
+    }
+    #[inline(always)]
+    fn reduce_max_f16x16(self, a: f16x16<Self>) -> f16 {
+        let a = a.val.map(f16::to_f32_const).simd_into(self);
+        f16::from_f32_const(self.reduce_max_f32x16(a))
+    }
+    #[inline(always)]
+    fn shuffle_f16x16<I: Indices<16>>(self, a: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f16x16<I: Indices<16>>(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f16x16(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f16x8(self.reverse_f16x8(a1), self.reverse_f16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f16x8::<K>(x),
+            self.rotate_lanes_left_f16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_f16x8(
+            self.select_f16x8(first, x, y),
+            self.select_f16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f16x16<const K: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f16x8::<K>(x),
+            self.rotate_lanes_right_f16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_f16x8(
+            self.select_f16x8(first, y, x),
+            self.select_f16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f16x16<const L: usize>(self, a: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let lanes = self.broadcast_lane_f16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_f16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.unzip_low_f16x8(a0, a1), self.unzip_low_f16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f16x16(self, a: f16x16<Self>, b: f16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        let (b0, b1) = self.split_f16x16(b);
+        self.combine_f16x8(self.unzip_high_f16x8(a0, a1), self.unzip_high_f16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f16x16(self, a: f16x16<Self>) -> (f16x8<Self>, f16x8<Self>) {
+        let mut b0 = [f16::from_bits(0); 8usize];
+        let mut b1 = [f16::from_bits(0); 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f16x16(self, a: f16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f16x8(a0),
+            self.reinterpret_bf16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f16x16(self, a: f16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f16x8(a0),
+            self.reinterpret_f32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f16x16(self, a: f16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f16x8(a0),
+            self.reinterpret_f64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f16x16(self, a: f16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_f16x8(a0), self.reinterpret_i8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f16x16(self, a: f16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_f16x8(a0), self.reinterpret_u8_f16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f16x8(a0),
+            self.reinterpret_i16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f16x8(a0),
+            self.reinterpret_u16_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f16x16(self, a: f16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f16x8(a0),
+            self.reinterpret_i32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f16x16(self, a: f16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f16x8(a0),
+            self.reinterpret_u32_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f16x16(self, a: f16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f16x8(a0),
+            self.reinterpret_i64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f16x16(self, a: f16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f16x8(a0),
+            self.reinterpret_u64_f16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f16x16(self, a: f16x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f16x16(a);
+        self.combine_f32x8(self.cvt_f32_f16x8(a0), self.cvt_f32_f16x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i8_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f16x16(self, a: f16x16<Self>) -> i8x16<Self> {
+        self.cvt_i8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f16x16(self, a: f16x16<Self>) -> u8x16<Self> {
+        self.cvt_u8_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f16x16(self, a: f16x16<Self>) -> i16x16<Self> {
+        self.cvt_i16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f16x16(self, a: f16x16<Self>) -> u16x16<Self> {
+        self.cvt_u16_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f16x16(self, a: f16x16<Self>) -> i32x16<Self> {
+        self.cvt_i32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f16x16(self, a: f16x16<Self>) -> u32x16<Self> {
+        self.cvt_u32_round_f32x16(self.cvt_f32_f16x16(a))
+    }
+    #[inline(always)]
+    fn splat_bf16x16(self, val: bf16) -> bf16x16<Self> {
+        self.splat_u16x16(val.to_bits()).bitcast()
+    }
+    #[inline(always)]
+    fn select_bf16x16(
+        self,
+        a: mask16x16<Self>,
+        b: bf16x16<Self>,
+        c: bf16x16<Self>,
+    ) -> bf16x16<Self> {
+        self.select_u16x16(a, b.bitcast(), c.bitcast()).bitcast()
+    }
+    #[inline(always)]
+    fn shuffle_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_bf16x16<I: Indices<16>>(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_bf16x16(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_bf16x8(self.reverse_bf16x8(a1), self.reverse_bf16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_bf16x8::<K>(x),
+            self.rotate_lanes_left_bf16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_bf16x8(
+            self.select_bf16x8(first, x, y),
+            self.select_bf16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_bf16x16<const K: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_bf16x8::<K>(x),
+            self.rotate_lanes_right_bf16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_bf16x8(
+            self.select_bf16x8(first, y, x),
+            self.select_bf16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_bf16x16<const L: usize>(self, a: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let lanes = self.broadcast_lane_bf16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_bf16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        self.combine_bf16x8(self.unzip_low_bf16x8(a0, a1), self.unzip_low_bf16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_bf16x16(self, a: bf16x16<Self>, b: bf16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        self.combine_bf16x8(
+            self.unzip_high_bf16x8(a0, a1),
+            self.unzip_high_bf16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_bf16x16(self, a: bf16x16<Self>) -> (bf16x8<Self>, bf16x8<Self>) {
+        let (a0, a1) = self.split_u16x16(a.bitcast());
+        (a0.bitcast(), a1.bitcast())
+    }
+    #[inline(always)]
+    fn reinterpret_f16_bf16x16(self, a: bf16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_bf16x8(a0),
+            self.reinterpret_f16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_bf16x16(self, a: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_bf16x8(a0),
+            self.reinterpret_f32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_bf16x16(self, a: bf16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_bf16x8(a0),
+            self.reinterpret_f64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_bf16x16(self, a: bf16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_bf16x8(a0),
+            self.reinterpret_i8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_bf16x16(self, a: bf16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_bf16x8(a0),
+            self.reinterpret_u8_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_bf16x16(self, a: bf16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_bf16x8(a0),
+            self.reinterpret_i16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_bf16x16(self, a: bf16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_bf16x8(a0),
+            self.reinterpret_u16_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_bf16x16(self, a: bf16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_bf16x8(a0),
+            self.reinterpret_i32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_bf16x16(self, a: bf16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_bf16x8(a0),
+            self.reinterpret_u32_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_bf16x16(self, a: bf16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_bf16x8(a0),
+            self.reinterpret_i64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_bf16x16(self, a: bf16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_bf16x8(a0),
+            self.reinterpret_u64_bf16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_bf16x16(self, a: bf16x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_bf16x16(a);
+        self.combine_f32x8(self.cvt_f32_bf16x8(a0), self.cvt_f32_bf16x8(a1))
+    }
+    #[inline(always)]
+    fn splat_f32x8(self, a: f32) -> f32x8<Self> {
+        let half = self.splat_f32x4(a);
+        self.combine_f32x4(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.abs_f32x4(a0), self.abs_f32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.neg_f32x4(a0), self.neg_f32x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.sqrt_f32x4(a0), self.sqrt_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_approx_f32x4(a0), self.recip_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_approx_f32x4(a0), self.rsqrt_approx_f32x4(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.recip_f32x4(a0, steps), self.recip_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x8(self, a: f32x8<Self>, steps: u32) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.rsqrt_f32x4(a0, steps), self.rsqrt_f32x4(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.add_f32x4(a0, b0), self.add_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.sub_f32x4(a0, b0), self.sub_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.mul_f32x4(a0, b0), self.mul_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.div_f32x4(a0, b0), self.div_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.copysign_f32x4(a0, b0), self.copysign_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_eq_f32x4(a0, b0), self.simd_eq_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ne_f32x4(a0, b0), self.simd_ne_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_lt_f32x4(a0, b0), self.simd_lt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_le_f32x4(a0, b0), self.simd_le_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_ge_f32x4(a0, b0), self.simd_ge_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_mask32x4(self.simd_gt_f32x4(a0, b0), self.simd_gt_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_ne_f32x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_eq_f32x8(self.abs_f32x8(a), self.splat_f32x8(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        let abs = self.abs_f32x8(a);
+        self.and_mask32x8(
+            self.simd_lt_f32x8(abs, self.splat_f32x8(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x8(abs, self.splat_f32x8(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x8(self, a: f32x8<Self>) -> mask32x8<Self> {
+        self.simd_lt_f32x8(
+            self.copysign_f32x8(self.splat_f32x8(1.0), a),
+            self.splat_f32x8(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, _) = self.split_f32x8(a);
+        let (b0, _) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a0, b0), self.zip_high_f32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (_, a1) = self.split_f32x8(a);
+        let (_, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.zip_low_f32x4(a1, b1), self.zip_high_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.max_f32x4(a0, b0), self.max_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.max_precise_f32x4(a0, b0),
+            self.max_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.min_f32x4(a0, b0), self.min_f32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(
+            self.min_precise_f32x4(a0, b0),
+            self.min_precise_f32x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.madd_f32x4(a0, b0, c0), self.madd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.msub_f32x4(a0, b0, c0), self.msub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmadd_f32x4(a0, b0, c0), self.nmadd_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.nmsub_f32x4(a0, b0, c0), self.nmsub_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fused_f32x4(a0, b0, c0),
+            self.mul_add_fused_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x8(self, a: f32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(
+            self.mul_add_fast_f32x4(a0, b0, c0),
+            self.mul_add_fast_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.floor_f32x4(a0), self.floor_f32x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.ceil_f32x4(a0), self.ceil_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.round_f32x4(a0), self.round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(
+            self.round_ties_even_f32x4(a0),
+            self.round_ties_even_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.fract_f32x4(a0), self.fract_f32x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.trunc_f32x4(a0), self.trunc_f32x4(a1))
+    }
+    #[inline(always)]
+    fn select_f32x8(self, a: mask32x8<Self>, b: f32x8<Self>, c: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        let (c0, c1) = self.split_f32x8(c);
+        self.combine_f32x4(self.select_f32x4(a0, b0, c0), self.select_f32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        self.reduce_add_f32x4(a0) + self.reduce_add_f32x4(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_min_f32x4(self.min_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x8(self, a: f32x8<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x8(a);
+        let a1 = self.select_f32x4(self.is_nan_f32x4(a1), a0, a1);
+        self.reduce_max_f32x4(self.max_precise_f32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x8<I: Indices<8>>(self, a: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x8<I: Indices<8>>(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x8(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f32x4(self.reverse_f32x4(a1), self.reverse_f32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f32x4::<K>(x),
+            self.rotate_lanes_left_f32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_f32x4(
+            self.select_f32x4(first, x, y),
+            self.select_f32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x8<const K: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f32x4::<K>(x),
+            self.rotate_lanes_right_f32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_f32x4(
+            self.select_f32x4(first, y, x),
+            self.select_f32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x8<const L: usize>(self, a: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let lanes = self.broadcast_lane_f32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_f32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.unzip_low_f32x4(a0, a1), self.unzip_low_f32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_f32x8(b);
+        self.combine_f32x4(self.unzip_high_f32x4(a0, a1), self.unzip_high_f32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn combine_f32x8(self, a: f32x8<Self>, b: f32x8<Self>) -> f32x16<Self> {
+        let mut result = [0.0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f32x8(self, a: f32x8<Self>) -> (f32x4<Self>, f32x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f32x8(self, a: f32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f32x4(a0),
+            self.reinterpret_f16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f32x8(self, a: f32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f32x4(a0),
+            self.reinterpret_bf16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x8(self, a: f32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_f32x4(a0),
+            self.reinterpret_f64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x8(self, a: f32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_f32x4(a0), self.reinterpret_i8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x8(self, a: f32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_f32x4(a0), self.reinterpret_u8_f32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x8(self, a: f32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f32x4(a0),
+            self.reinterpret_i16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x8(self, a: f32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f32x4(a0),
+            self.reinterpret_u16_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f32x4(a0),
+            self.reinterpret_i32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f32x4(a0),
+            self.reinterpret_u32_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x8(self, a: f32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f32x4(a0),
+            self.reinterpret_i64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x8(self, a: f32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f32x4(a0),
+            self.reinterpret_u64_f32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x8(self, a: f32x8<Self>) -> f16x8<Self> {
+        a.val.map(f16::from_f32_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_f32x8(self, a: f32x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_f64x4(self.cvt_f64_f32x4(a0), self.cvt_f64_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x8(self, a: f32x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x8(self, a: f32x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x8(self, a: f32x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_f32x4(a0), self.cvt_i32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x8(self, a: f32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_i32x4(self.cvt_i32_round_f32x4(a0), self.cvt_i32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_f32x4(a0), self.cvt_u32_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x8(self, a: f32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        self.combine_u32x4(self.cvt_u32_round_f32x4(a0), self.cvt_u32_round_f32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f32x8(self, a: f32x8<Self>) -> i64x8<Self> {
+        self.cvt_i64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f32x8(self, a: f32x8<Self>) -> u64x8<Self> {
+        self.cvt_u64_round_f64x8(self.cvt_f64_f32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x8(self, a: f32x8<Self>) -> bf16x8<Self> {
+        unsafe {
+            let (low, high) = self.split_f32x8(a);
+            let (low, high) = (low.into(), high.into());
+            let bits = _mm_castps_si128(low);
+            let lsb = _mm_and_si128(_mm_srli_epi32::<16>(bits), _mm_set1_epi32(1));
+            let rounded = _mm_srli_epi32::<16>(_mm_add_epi32(
+                _mm_add_epi32(bits, _mm_set1_epi32(0x7fff)),
+                lsb,
+            ));
+            let quiet = _mm_srli_epi32::<16>(_mm_or_si128(bits, _mm_set1_epi32(0x0040_0000)));
+            let low = _mm_blendv_epi8(quiet, rounded, _mm_castps_si128(_mm_cmpord_ps(low, low)));
+            let bits = _mm_castps_si128(high);
+            let lsb = _mm_and_si128(_mm_srli_epi32::<16>(bits), _mm_set1_epi32(1));
+            let rounded = _mm_srli_epi32::<16>(_mm_add_epi32(
+                _mm_add_epi32(bits, _mm_set1_epi32(0x7fff)),
+                lsb,
+            ));
+            let quiet = _mm_srli_epi32::<16>(_mm_or_si128(bits, _mm_set1_epi32(0x0040_0000)));
+            let high = _mm_blendv_epi8(quiet, rounded, _mm_castps_si128(_mm_cmpord_ps(high, high)));
+            _mm_packus_epi32(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn dot_bf16_f32x8(self, a: f32x8<Self>, b: bf16x16<Self>, c: bf16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f32x8(a);
+        let (b0, b1) = self.split_bf16x16(b);
+        let (c0, c1) = self.split_bf16x16(c);
+        self.combine_f32x4(
+            self.dot_bf16_f32x4(a0, b0, c0),
+            self.dot_bf16_f32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i8x32(self, a: i8) -> i8x32<Self> {
+        let half = self.splat_i8x16(a);
+        self.combine_i8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.not_i8x16(a0), self.not_i8x16(a1))
+    }
+    #[inline(always)]
+    fn add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.add_i8x16(a0, b0), self.add_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.sub_i8x16(a0, b0), self.sub_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_i8x16(a0, b0), self.mul_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.and_i8x16(a0, b0), self.and_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.or_i8x16(a0, b0), self.or_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.xor_i8x16(a0, b0), self.xor_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shl_i8x16(a0, b), self.shl_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x32(self, a: i8x32<Self>, b: u32) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.shr_i8x16(a0, b), self.shr_i8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u8).min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u8).min(7);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_eq_i8x16(a0, b0), self.simd_eq_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_lt_i8x16(a0, b0), self.simd_lt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_le_i8x16(a0, b0), self.simd_le_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_ge_i8x16(a0, b0), self.simd_ge_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_mask8x16(self.simd_gt_i8x16(a0, b0), self.simd_gt_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, _) = self.split_i8x32(a);
+        let (b0, _) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a0, b0), self.zip_high_i8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (_, a1) = self.split_i8x32(a);
+        let (_, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.zip_low_i8x16(a1, b1), self.zip_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x32(self, a: mask8x32<Self>, b: i8x32<Self>, c: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        let (c0, c1) = self.split_i8x32(c);
+        self.combine_i8x16(self.select_i8x16(a0, b0, c0), self.select_i8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.min_i8x16(a0, b0), self.min_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.max_i8x16(a0, b0), self.max_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_add_i8x16(self.add_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_min_i8x16(self.min_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_max_i8x16(self.max_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_and_i8x16(self.and_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x32(self, a: i8x32<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x32(a);
+        self.reduce_or_i8x16(self.or_i8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x32<I: Indices<32>>(self, a: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x32<I: Indices<32>>(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.reverse_i8x16(a1), self.reverse_i8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i8x16::<K>(x),
+            self.rotate_lanes_left_i8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_i8x16(
+            self.select_i8x16(first, x, y),
+            self.select_i8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x32<const K: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i8x16::<K>(x),
+            self.rotate_lanes_right_i8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_i8x16(
+            self.select_i8x16(first, y, x),
+            self.select_i8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x32<const L: usize>(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let lanes = self.broadcast_lane_i8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_i8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.unzip_low_i8x16(a0, a1), self.unzip_low_i8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.unzip_high_i8x16(a0, a1), self.unzip_high_i8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_add_i8x16(a0, b0),
+            self.saturating_add_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(
+            self.saturating_sub_i8x16(a0, b0),
+            self.saturating_sub_i8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.abs_i8x16(a0), self.abs_i8x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x32(self, a: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i8x16(self.neg_i8x16(a0), self.neg_i8x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_u8x16(self.abs_diff_i8x16(a0, b0), self.abs_diff_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i16x16(self.mul_widen_i8x16(a0, b0), self.mul_widen_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        let (b0, b1) = self.split_i8x32(b);
+        self.combine_i8x16(self.mul_high_i8x16(a0, b0), self.mul_high_i8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i8x32(self, a: i8x32<Self>, b: i8x32<Self>) -> i8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i8x32(self, a: i8x32<Self>) -> (i8x16<Self>, i8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i8x32(self, a: i8x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x16(self.widen_i8x16(a0), self.widen_i8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        self.widen_i8x16(self.split_i8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i8x32(self, a: i8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i8x16(a0),
+            self.reinterpret_f16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i8x32(self, a: i8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i8x16(a0),
+            self.reinterpret_bf16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x32(self, a: i8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i8x16(a0),
+            self.reinterpret_f32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x32(self, a: i8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i8x16(a0),
+            self.reinterpret_f64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x32(self, a: i8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u8x16(self.reinterpret_u8_i8x16(a0), self.reinterpret_u8_i8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x32(self, a: i8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i8x16(a0),
+            self.reinterpret_i16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x32(self, a: i8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i8x16(a0),
+            self.reinterpret_u16_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x32(self, a: i8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i8x16(a0),
+            self.reinterpret_i32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x32(self, a: i8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i8x16(a0),
+            self.reinterpret_u32_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x32(self, a: i8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i8x16(a0),
+            self.reinterpret_i64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x32(self, a: i8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i8x16(a0),
+            self.reinterpret_u64_i8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x32(self, a: u8) -> u8x32<Self> {
+        let half = self.splat_u8x16(a);
+        self.combine_u8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.not_u8x16(a0), self.not_u8x16(a1))
+    }
+    #[inline(always)]
+    fn add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.add_u8x16(a0, b0), self.add_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.sub_u8x16(a0, b0), self.sub_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_u8x16(a0, b0), self.mul_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.and_u8x16(a0, b0), self.and_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.or_u8x16(a0, b0), self.or_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.xor_u8x16(a0, b0), self.xor_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shl_u8x16(a0, b), self.shl_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x32(self, a: u8x32<Self>, b: u32) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.shr_u8x16(a0, b), self.shr_u8x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_eq_u8x16(a0, b0), self.simd_eq_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_lt_u8x16(a0, b0), self.simd_lt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_le_u8x16(a0, b0), self.simd_le_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_ge_u8x16(a0, b0), self.simd_ge_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_mask8x16(self.simd_gt_u8x16(a0, b0), self.simd_gt_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, _) = self.split_u8x32(a);
+        let (b0, _) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a0, b0), self.zip_high_u8x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (_, a1) = self.split_u8x32(a);
+        let (_, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.zip_low_u8x16(a1, b1), self.zip_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x32(self, a: mask8x32<Self>, b: u8x32<Self>, c: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        let (c0, c1) = self.split_u8x32(c);
+        self.combine_u8x16(self.select_u8x16(a0, b0, c0), self.select_u8x16(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.min_u8x16(a0, b0), self.min_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.max_u8x16(a0, b0), self.max_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_add_u8x16(self.add_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_min_u8x16(self.min_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_max_u8x16(self.max_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_and_u8x16(self.and_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x32(self, a: u8x32<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x32(a);
+        self.reduce_or_u8x16(self.or_u8x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x32<I: Indices<32>>(self, a: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x32<I: Indices<32>>(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x32(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u8x16(self.reverse_u8x16(a1), self.reverse_u8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u8x16::<K>(x),
+            self.rotate_lanes_left_u8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_u8x16(
+            self.select_u8x16(first, x, y),
+            self.select_u8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x32<const K: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u8x16::<K>(x),
+            self.rotate_lanes_right_u8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_u8x16(
+            self.select_u8x16(first, y, x),
+            self.select_u8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x32<const L: usize>(self, a: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let lanes = self.broadcast_lane_u8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_u8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.unzip_low_u8x16(a0, a1), self.unzip_low_u8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.unzip_high_u8x16(a0, a1), self.unzip_high_u8x16(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_add_u8x16(a0, b0),
+            self.saturating_add_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(
+            self.saturating_sub_u8x16(a0, b0),
+            self.saturating_sub_u8x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.abs_diff_u8x16(a0, b0), self.abs_diff_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.avg_round_u8x16(a0, b0), self.avg_round_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u16x16(self.mul_widen_u8x16(a0, b0), self.mul_widen_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.mul_high_u8x16(a0, b0), self.mul_high_u8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_32_u8x16(a, b0), self.lookup_32_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x32(self, a: [u8x16<Self>; 3], b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_48_u8x16(a, b0), self.lookup_48_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x32(self, a: u8x64<Self>, b: u8x32<Self>) -> u8x32<Self> {
+        let (b0, b1) = self.split_u8x32(b);
+        self.combine_u8x16(self.lookup_64_u8x16(a, b0), self.lookup_64_u8x16(a, b1))
+    }
+    #[inline(always)]
+    fn combine_u8x32(self, a: u8x32<Self>, b: u8x32<Self>) -> u8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u8x32(self, a: u8x32<Self>) -> (u8x16<Self>, u8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u8x32(self, a: u8x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x16(self.widen_u8x16(a0), self.widen_u8x16(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        self.widen_u8x16(self.split_u8x32(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u8x32(self, a: u8x32<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u8x16(a0),
+            self.reinterpret_f16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u8x32(self, a: u8x32<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u8x16(a0),
+            self.reinterpret_bf16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x32(self, a: u8x32<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u8x16(a0),
+            self.reinterpret_f32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x32(self, a: u8x32<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u8x16(a0),
+            self.reinterpret_f64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x32(self, a: u8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i8x16(self.reinterpret_i8_u8x16(a0), self.reinterpret_i8_u8x16(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x32(self, a: u8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u8x16(a0),
+            self.reinterpret_i16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x32(self, a: u8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u8x16(a0),
+            self.reinterpret_u16_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x32(self, a: u8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u8x16(a0),
+            self.reinterpret_i32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x32(self, a: u8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u8x16(a0),
+            self.reinterpret_u32_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x32(self, a: u8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u8x16(a0),
+            self.reinterpret_i64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x32(self, a: u8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u8x16(a0),
+            self.reinterpret_u64_u8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x32(self, a: i8) -> mask8x32<Self> {
+        let half = self.splat_mask8x16(a);
+        self.combine_mask8x16(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_mask8x16(self.not_mask8x16(a0), self.not_mask8x16(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.and_mask8x16(a0, b0), self.and_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.or_mask8x16(a0, b0), self.or_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.xor_mask8x16(a0, b0), self.xor_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x32(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+        c: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        let (c0, c1) = self.split_mask8x32(c);
+        self.combine_mask8x16(
+            self.select_mask8x16(a0, b0, c0),
+            self.select_mask8x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(self.simd_eq_mask8x16(a0, b0), self.simd_eq_mask8x16(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.any_mask8x16(a0) || self.any_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x32(self, a: mask8x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.all_mask8x16(a0) && self.all_mask8x16(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x32(self, a: mask8x32<Self>) -> bool {
+        !self.any_mask8x32(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x32(self, a: mask8x32<Self>) -> usize {
+        self.to_bitmask_mask8x32(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x32(self, a: mask8x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x32(self, a: mask8x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.to_bitmask_mask8x16(a0) | (self.to_bitmask_mask8x16(a1) << 16)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x32(self, bits: u64) -> mask8x32<Self> {
+        self.combine_mask8x16(
+            self.from_bitmask_mask8x16(bits),
+            self.from_bitmask_mask8x16(bits >> 16),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x32<I: Indices<32>>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x32<I: Indices<32>>(
+        self,
+        a: mask8x32<Self>,
+        b: mask8x32<Self>,
+    ) -> mask8x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x32(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_mask8x16(self.reverse_mask8x16(a1), self.reverse_mask8x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask8x16::<K>(x),
+            self.rotate_lanes_left_mask8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (16 - K % 16)) - 1);
+        self.combine_mask8x16(
+            self.select_mask8x16(first, x, y),
+            self.select_mask8x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x32<const K: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask8x16::<K>(x),
+            self.rotate_lanes_right_mask8x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x16((1 << (K % 16)) - 1);
+        self.combine_mask8x16(
+            self.select_mask8x16(first, y, x),
+            self.select_mask8x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x32<const L: usize>(self, a: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let lanes = self.broadcast_lane_mask8x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_mask8x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(
+            self.unzip_low_mask8x16(a0, a1),
+            self.unzip_low_mask8x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        let (b0, b1) = self.split_mask8x32(b);
+        self.combine_mask8x16(
+            self.unzip_high_mask8x16(a0, a1),
+            self.unzip_high_mask8x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask8x32(self, a: mask8x32<Self>, b: mask8x32<Self>) -> mask8x64<Self> {
+        let mut result = [0; 64usize];
+        result[0..32usize].copy_from_slice(&a.val);
+        result[32usize..64usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask8x32(self, a: mask8x32<Self>) -> (mask8x16<Self>, mask8x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x32(self, a: mask8x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask8x16(a0),
+            self.reinterpret_i8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x32(self, a: mask8x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask8x16(a0),
+            self.reinterpret_u8_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x32(self, a: mask8x32<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask8x16(a0),
+            self.reinterpret_i16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x32(self, a: mask8x32<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask8x16(a0),
+            self.reinterpret_u16_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x32(self, a: mask8x32<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask8x16(a0),
+            self.reinterpret_i32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x32(self, a: mask8x32<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask8x16(a0),
+            self.reinterpret_u32_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x32(self, a: mask8x32<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask8x16(a0),
+            self.reinterpret_i64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x32(self, a: mask8x32<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask8x32(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask8x16(a0),
+            self.reinterpret_u64_mask8x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x16(self, a: i16) -> i16x16<Self> {
+        let half = self.splat_i16x8(a);
+        self.combine_i16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.not_i16x8(a0), self.not_i16x8(a1))
+    }
+    #[inline(always)]
+    fn add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.add_i16x8(a0, b0), self.add_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.sub_i16x8(a0, b0), self.sub_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_i16x8(a0, b0), self.mul_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.and_i16x8(a0, b0), self.and_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.or_i16x8(a0, b0), self.or_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.xor_i16x8(a0, b0), self.xor_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shl_i16x8(a0, b), self.shl_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x16(self, a: i16x16<Self>, b: u32) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.shr_i16x8(a0, b), self.shr_i16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u16).min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u16).min(15);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_eq_i16x8(a0, b0), self.simd_eq_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_lt_i16x8(a0, b0), self.simd_lt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_le_i16x8(a0, b0), self.simd_le_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_ge_i16x8(a0, b0), self.simd_ge_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_mask16x8(self.simd_gt_i16x8(a0, b0), self.simd_gt_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, _) = self.split_i16x16(a);
+        let (b0, _) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a0, b0), self.zip_high_i16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (_, a1) = self.split_i16x16(a);
+        let (_, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.zip_low_i16x8(a1, b1), self.zip_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x16(self, a: mask16x16<Self>, b: i16x16<Self>, c: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        let (c0, c1) = self.split_i16x16(c);
+        self.combine_i16x8(self.select_i16x8(a0, b0, c0), self.select_i16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.min_i16x8(a0, b0), self.min_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.max_i16x8(a0, b0), self.max_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_add_i16x8(self.add_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_min_i16x8(self.min_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_max_i16x8(self.max_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_and_i16x8(self.and_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x16(self, a: i16x16<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x16(a);
+        self.reduce_or_i16x8(self.or_i16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x16<I: Indices<16>>(self, a: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x16<I: Indices<16>>(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.reverse_i16x8(a1), self.reverse_i16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i16x8::<K>(x),
+            self.rotate_lanes_left_i16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_i16x8(
+            self.select_i16x8(first, x, y),
+            self.select_i16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x16<const K: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i16x8::<K>(x),
+            self.rotate_lanes_right_i16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_i16x8(
+            self.select_i16x8(first, y, x),
+            self.select_i16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x16<const L: usize>(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let lanes = self.broadcast_lane_i16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_i16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.unzip_low_i16x8(a0, a1), self.unzip_low_i16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.unzip_high_i16x8(a0, a1), self.unzip_high_i16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_add_i16x8(a0, b0),
+            self.saturating_add_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.saturating_sub_i16x8(a0, b0),
+            self.saturating_sub_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.abs_i16x8(a0), self.abs_i16x8(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x16(self, a: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i16x8(self.neg_i16x8(a0), self.neg_i16x8(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_u16x8(self.abs_diff_i16x8(a0, b0), self.abs_diff_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x8(self.mul_widen_i16x8(a0, b0), self.mul_widen_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(self.mul_high_i16x8(a0, b0), self.mul_high_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i16x8(
+            self.mul_q15_round_sat_i16x8(a0, b0),
+            self.mul_q15_round_sat_i16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        let (b0, b1) = self.split_i16x16(b);
+        self.combine_i32x4(self.dot_i16_i16x8(a0, b0), self.dot_i16_i16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i16x16(self, a: i16x16<Self>, b: i16x16<Self>) -> i16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i16x16(self, a: i16x16<Self>) -> (i16x8<Self>, i16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i16x16(self, a: i16x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x8(self.widen_i16x8(a0), self.widen_i16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        self.widen_i16x8(self.split_i16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let (low, high) = self.split_i16x16(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi16(
+                _mm_and_si128(low, _mm_set1_epi16(255)),
+                _mm_and_si128(high, _mm_set1_epi16(255)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x16(self, a: i16x16<Self>) -> i8x16<Self> {
+        unsafe {
+            let (low, high) = self.split_i16x16(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packs_epi16(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i16x8(a0),
+            self.reinterpret_f16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i16x16(self, a: i16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i16x8(a0),
+            self.reinterpret_bf16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x16(self, a: i16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i16x8(a0),
+            self.reinterpret_f32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x16(self, a: i16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i16x8(a0),
+            self.reinterpret_f64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x16(self, a: i16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_i16x8(a0), self.reinterpret_i8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x16(self, a: i16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_i16x8(a0), self.reinterpret_u8_i16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x16(self, a: i16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i16x8(a0),
+            self.reinterpret_u16_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x16(self, a: i16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i16x8(a0),
+            self.reinterpret_i32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x16(self, a: i16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i16x8(a0),
+            self.reinterpret_u32_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x16(self, a: i16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i16x8(a0),
+            self.reinterpret_i64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x16(self, a: i16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i16x8(a0),
+            self.reinterpret_u64_i16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i16x16(self, a: i16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i16x16(self, a: i16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_i32x16(self.widen_i16x16(a))
+    }
+    #[inline(always)]
+    fn splat_u16x16(self, a: u16) -> u16x16<Self> {
+        let half = self.splat_u16x8(a);
+        self.combine_u16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.not_u16x8(a0), self.not_u16x8(a1))
+    }
+    #[inline(always)]
+    fn add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.add_u16x8(a0, b0), self.add_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.sub_u16x8(a0, b0), self.sub_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_u16x8(a0, b0), self.mul_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.and_u16x8(a0, b0), self.and_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.or_u16x8(a0, b0), self.or_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.xor_u16x8(a0, b0), self.xor_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shl_u16x8(a0, b), self.shl_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x16(self, a: u16x16<Self>, b: u32) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.shr_u16x8(a0, b), self.shr_u16x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_eq_u16x8(a0, b0), self.simd_eq_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_lt_u16x8(a0, b0), self.simd_lt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_le_u16x8(a0, b0), self.simd_le_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_ge_u16x8(a0, b0), self.simd_ge_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_mask16x8(self.simd_gt_u16x8(a0, b0), self.simd_gt_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, _) = self.split_u16x16(a);
+        let (b0, _) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a0, b0), self.zip_high_u16x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (_, a1) = self.split_u16x16(a);
+        let (_, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.zip_low_u16x8(a1, b1), self.zip_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x16(self, a: mask16x16<Self>, b: u16x16<Self>, c: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        let (c0, c1) = self.split_u16x16(c);
+        self.combine_u16x8(self.select_u16x8(a0, b0, c0), self.select_u16x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.min_u16x8(a0, b0), self.min_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.max_u16x8(a0, b0), self.max_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_add_u16x8(self.add_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_min_u16x8(self.min_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_max_u16x8(self.max_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_and_u16x8(self.and_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x16(self, a: u16x16<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x16(a);
+        self.reduce_or_u16x8(self.or_u16x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x16<I: Indices<16>>(self, a: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x16<I: Indices<16>>(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x16(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u16x8(self.reverse_u16x8(a1), self.reverse_u16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u16x8::<K>(x),
+            self.rotate_lanes_left_u16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_u16x8(
+            self.select_u16x8(first, x, y),
+            self.select_u16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x16<const K: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u16x8::<K>(x),
+            self.rotate_lanes_right_u16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_u16x8(
+            self.select_u16x8(first, y, x),
+            self.select_u16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x16<const L: usize>(self, a: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let lanes = self.broadcast_lane_u16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_u16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.unzip_low_u16x8(a0, a1), self.unzip_low_u16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.unzip_high_u16x8(a0, a1), self.unzip_high_u16x8(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_add_u16x8(a0, b0),
+            self.saturating_add_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(
+            self.saturating_sub_u16x8(a0, b0),
+            self.saturating_sub_u16x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.abs_diff_u16x8(a0, b0), self.abs_diff_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.avg_round_u16x8(a0, b0), self.avg_round_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_widen_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u32x8(self.mul_widen_u16x8(a0, b0), self.mul_widen_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        let (b0, b1) = self.split_u16x16(b);
+        self.combine_u16x8(self.mul_high_u16x8(a0, b0), self.mul_high_u16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u16x16(self, a: u16x16<Self>, b: u16x16<Self>) -> u16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u16x16(self, a: u16x16<Self>) -> (u16x8<Self>, u16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u16x16(self, a: u16x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x8(self.widen_u16x8(a0), self.widen_u16x8(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        self.widen_u16x8(self.split_u16x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let (low, high) = self.split_u16x16(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi16(
+                _mm_and_si128(low, _mm_set1_epi16(255)),
+                _mm_and_si128(high, _mm_set1_epi16(255)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x16(self, a: u16x16<Self>) -> u8x16<Self> {
+        unsafe {
+            let (low, high) = self.split_u16x16(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi16(
+                _mm_min_epu16(low, _mm_set1_epi16(255)),
+                _mm_min_epu16(high, _mm_set1_epi16(255)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u16x8(a0),
+            self.reinterpret_f16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u16x16(self, a: u16x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u16x8(a0),
+            self.reinterpret_bf16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x16(self, a: u16x16<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u16x8(a0),
+            self.reinterpret_f32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x16(self, a: u16x16<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u16x8(a0),
+            self.reinterpret_f64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x16(self, a: u16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i8x16(self.reinterpret_i8_u16x8(a0), self.reinterpret_i8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x16(self, a: u16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u8x16(self.reinterpret_u8_u16x8(a0), self.reinterpret_u8_u16x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x16(self, a: u16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u16x8(a0),
+            self.reinterpret_i16_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x16(self, a: u16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u16x8(a0),
+            self.reinterpret_i32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x16(self, a: u16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u16x8(a0),
+            self.reinterpret_u32_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x16(self, a: u16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u16x8(a0),
+            self.reinterpret_i64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x16(self, a: u16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u16x8(a0),
+            self.reinterpret_u64_u16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u16x16(self, a: u16x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u16x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u16x16(self, a: u16x16<Self>) -> f32x16<Self> {
+        self.cvt_f32_u32x16(self.widen_u16x16(a))
+    }
+    #[inline(always)]
+    fn splat_mask16x16(self, a: i16) -> mask16x16<Self> {
+        let half = self.splat_mask16x8(a);
+        self.combine_mask16x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_mask16x8(self.not_mask16x8(a0), self.not_mask16x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.and_mask16x8(a0, b0), self.and_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.or_mask16x8(a0, b0), self.or_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.xor_mask16x8(a0, b0), self.xor_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask16x16(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+        c: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        let (c0, c1) = self.split_mask16x16(c);
+        self.combine_mask16x8(
+            self.select_mask16x8(a0, b0, c0),
+            self.select_mask16x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(self.simd_eq_mask16x8(a0, b0), self.simd_eq_mask16x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.any_mask16x8(a0) || self.any_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn all_mask16x16(self, a: mask16x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.all_mask16x8(a0) && self.all_mask16x8(a1)
+    }
+    #[inline(always)]
+    fn none_mask16x16(self, a: mask16x16<Self>) -> bool {
+        !self.any_mask16x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x16(self, a: mask16x16<Self>) -> usize {
+        self.to_bitmask_mask16x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x16(self, a: mask16x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x16(self, a: mask16x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.to_bitmask_mask16x8(a0) | (self.to_bitmask_mask16x8(a1) << 8)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x16(self, bits: u64) -> mask16x16<Self> {
+        self.combine_mask16x8(
+            self.from_bitmask_mask16x8(bits),
+            self.from_bitmask_mask16x8(bits >> 8),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask16x16<I: Indices<16>>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x16<I: Indices<16>>(
+        self,
+        a: mask16x16<Self>,
+        b: mask16x16<Self>,
+    ) -> mask16x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x16(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_mask16x8(self.reverse_mask16x8(a1), self.reverse_mask16x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask16x8::<K>(x),
+            self.rotate_lanes_left_mask16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (8 - K % 8)) - 1);
+        self.combine_mask16x8(
+            self.select_mask16x8(first, x, y),
+            self.select_mask16x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x16<const K: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask16x8::<K>(x),
+            self.rotate_lanes_right_mask16x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x8((1 << (K % 8)) - 1);
+        self.combine_mask16x8(
+            self.select_mask16x8(first, y, x),
+            self.select_mask16x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x16<const L: usize>(self, a: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let lanes = self.broadcast_lane_mask16x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_mask16x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(
+            self.unzip_low_mask16x8(a0, a1),
+            self.unzip_low_mask16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        let (b0, b1) = self.split_mask16x16(b);
+        self.combine_mask16x8(
+            self.unzip_high_mask16x8(a0, a1),
+            self.unzip_high_mask16x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask16x16(self, a: mask16x16<Self>, b: mask16x16<Self>) -> mask16x32<Self> {
+        let mut result = [0; 32usize];
+        result[0..16usize].copy_from_slice(&a.val);
+        result[16usize..32usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask16x16(self, a: mask16x16<Self>) -> (mask16x8<Self>, mask16x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x16(self, a: mask16x16<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask16x8(a0),
+            self.reinterpret_i8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x16(self, a: mask16x16<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask16x8(a0),
+            self.reinterpret_u8_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x16(self, a: mask16x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask16x8(a0),
+            self.reinterpret_i16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x16(self, a: mask16x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask16x8(a0),
+            self.reinterpret_u16_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x16(self, a: mask16x16<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask16x8(a0),
+            self.reinterpret_i32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x16(self, a: mask16x16<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask16x8(a0),
+            self.reinterpret_u32_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x16(self, a: mask16x16<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask16x8(a0),
+            self.reinterpret_i64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x16(self, a: mask16x16<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask16x16(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask16x8(a0),
+            self.reinterpret_u64_mask16x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x8(self, a: i32) -> i32x8<Self> {
+        let half = self.splat_i32x4(a);
+        self.combine_i32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.not_i32x4(a0), self.not_i32x4(a1))
+    }
+    #[inline(always)]
+    fn add_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.add_i32x4(a0, b0), self.add_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.sub_i32x4(a0, b0), self.sub_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.mul_i32x4(a0, b0), self.mul_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.and_i32x4(a0, b0), self.and_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.or_i32x4(a0, b0), self.or_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.xor_i32x4(a0, b0), self.xor_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shl_i32x4(a0, b), self.shl_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i32x8(self, a: i32x8<Self>, b: u32) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.shr_i32x4(a0, b), self.shr_i32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u32).min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u32).min(31);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_eq_i32x4(a0, b0), self.simd_eq_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_lt_i32x4(a0, b0), self.simd_lt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_le_i32x4(a0, b0), self.simd_le_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_ge_i32x4(a0, b0), self.simd_ge_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_mask32x4(self.simd_gt_i32x4(a0, b0), self.simd_gt_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, _) = self.split_i32x8(a);
+        let (b0, _) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a0, b0), self.zip_high_i32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (_, a1) = self.split_i32x8(a);
+        let (_, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.zip_low_i32x4(a1, b1), self.zip_high_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i32x8(self, a: mask32x8<Self>, b: i32x8<Self>, c: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        let (c0, c1) = self.split_i32x8(c);
+        self.combine_i32x4(self.select_i32x4(a0, b0, c0), self.select_i32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.min_i32x4(a0, b0), self.min_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.max_i32x4(a0, b0), self.max_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_add_i32x4(self.add_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_min_i32x4(self.min_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_max_i32x4(self.max_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_and_i32x4(self.and_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x8(self, a: i32x8<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x8(a);
+        self.reduce_or_i32x4(self.or_i32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i32x8<I: Indices<8>>(self, a: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x8<I: Indices<8>>(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.reverse_i32x4(a1), self.reverse_i32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i32x4::<K>(x),
+            self.rotate_lanes_left_i32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_i32x4(
+            self.select_i32x4(first, x, y),
+            self.select_i32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x8<const K: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i32x4::<K>(x),
+            self.rotate_lanes_right_i32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_i32x4(
+            self.select_i32x4(first, y, x),
+            self.select_i32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x8<const L: usize>(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let lanes = self.broadcast_lane_i32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_i32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.unzip_low_i32x4(a0, a1), self.unzip_low_i32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_i32x4(self.unzip_high_i32x4(a0, a1), self.unzip_high_i32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.abs_i32x4(a0), self.abs_i32x4(a1))
+    }
+    #[inline(always)]
+    fn neg_i32x8(self, a: i32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i32x4(self.neg_i32x4(a0), self.neg_i32x4(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        let (b0, b1) = self.split_i32x8(b);
+        self.combine_u32x4(self.abs_diff_i32x4(a0, b0), self.abs_diff_i32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i32x8(self, a: i32x8<Self>, b: i32x8<Self>) -> i32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i32x8(self, a: i32x8<Self>) -> (i32x4<Self>, i32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_i32x8(self, a: i32x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x4(self.widen_i32x4(a0), self.widen_i32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        self.widen_i32x4(self.split_i32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let (low, high) = self.split_i32x8(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi32(
+                _mm_and_si128(low, _mm_set1_epi32(65535)),
+                _mm_and_si128(high, _mm_set1_epi32(65535)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i32x8(self, a: i32x8<Self>) -> i16x8<Self> {
+        unsafe {
+            let (low, high) = self.split_i32x8(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packs_epi32(low, high).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i32x8(self, a: i32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i32x4(a0),
+            self.reinterpret_f16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i32x8(self, a: i32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i32x4(a0),
+            self.reinterpret_bf16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i32x4(a0),
+            self.reinterpret_f32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x8(self, a: i32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i32x4(a0),
+            self.reinterpret_f64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x8(self, a: i32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_i32x4(a0), self.reinterpret_i8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x8(self, a: i32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_i32x4(a0), self.reinterpret_u8_i32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x8(self, a: i32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i32x4(a0),
+            self.reinterpret_i16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x8(self, a: i32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i32x4(a0),
+            self.reinterpret_u16_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x8(self, a: i32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i32x4(a0),
+            self.reinterpret_u32_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x8(self, a: i32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_i32x4(a0),
+            self.reinterpret_i64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x8(self, a: i32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i32x4(a0),
+            self.reinterpret_u64_i32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i32x8(self, a: i32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x8(self, a: i32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i32x8(a);
+        self.combine_f32x4(self.cvt_f32_i32x4(a0), self.cvt_f32_i32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_i32x8(self, a: i32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_i64x8(self.widen_i32x8(a))
+    }
+    #[inline(always)]
+    fn splat_u32x8(self, a: u32) -> u32x8<Self> {
+        let half = self.splat_u32x4(a);
+        self.combine_u32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.not_u32x4(a0), self.not_u32x4(a1))
+    }
+    #[inline(always)]
+    fn add_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.add_u32x4(a0, b0), self.add_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.sub_u32x4(a0, b0), self.sub_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.mul_u32x4(a0, b0), self.mul_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.and_u32x4(a0, b0), self.and_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.or_u32x4(a0, b0), self.or_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.xor_u32x4(a0, b0), self.xor_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shl_u32x4(a0, b), self.shl_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u32x8(self, a: u32x8<Self>, b: u32) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.shr_u32x4(a0, b), self.shr_u32x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_eq_u32x4(a0, b0), self.simd_eq_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_lt_u32x4(a0, b0), self.simd_lt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_le_u32x4(a0, b0), self.simd_le_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_ge_u32x4(a0, b0), self.simd_ge_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_mask32x4(self.simd_gt_u32x4(a0, b0), self.simd_gt_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, _) = self.split_u32x8(a);
+        let (b0, _) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a0, b0), self.zip_high_u32x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (_, a1) = self.split_u32x8(a);
+        let (_, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.zip_low_u32x4(a1, b1), self.zip_high_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u32x8(self, a: mask32x8<Self>, b: u32x8<Self>, c: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        let (c0, c1) = self.split_u32x8(c);
+        self.combine_u32x4(self.select_u32x4(a0, b0, c0), self.select_u32x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.min_u32x4(a0, b0), self.min_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.max_u32x4(a0, b0), self.max_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_add_u32x4(self.add_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_min_u32x4(self.min_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_max_u32x4(self.max_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_and_u32x4(self.and_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u32x8(self, a: u32x8<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x8(a);
+        self.reduce_or_u32x4(self.or_u32x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u32x8<I: Indices<8>>(self, a: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x8<I: Indices<8>>(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x8(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u32x4(self.reverse_u32x4(a1), self.reverse_u32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u32x4::<K>(x),
+            self.rotate_lanes_left_u32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_u32x4(
+            self.select_u32x4(first, x, y),
+            self.select_u32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x8<const K: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u32x4::<K>(x),
+            self.rotate_lanes_right_u32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_u32x4(
+            self.select_u32x4(first, y, x),
+            self.select_u32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x8<const L: usize>(self, a: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let lanes = self.broadcast_lane_u32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_u32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.unzip_low_u32x4(a0, a1), self.unzip_low_u32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.unzip_high_u32x4(a0, a1), self.unzip_high_u32x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        let (b0, b1) = self.split_u32x8(b);
+        self.combine_u32x4(self.abs_diff_u32x4(a0, b0), self.abs_diff_u32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u32x8(self, a: u32x8<Self>, b: u32x8<Self>) -> u32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u32x8(self, a: u32x8<Self>) -> (u32x4<Self>, u32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_u32x8(self, a: u32x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x4(self.widen_u32x4(a0), self.widen_u32x4(a1))
+    }
+    #[inline(always)]
+    fn widen_low_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        self.widen_u32x4(self.split_u32x8(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let (low, high) = self.split_u32x8(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi32(
+                _mm_and_si128(low, _mm_set1_epi32(65535)),
+                _mm_and_si128(high, _mm_set1_epi32(65535)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x8(self, a: u32x8<Self>) -> u16x8<Self> {
+        unsafe {
+            let (low, high) = self.split_u32x8(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_packus_epi32(
+                _mm_min_epu32(low, _mm_set1_epi32(65535)),
+                _mm_min_epu32(high, _mm_set1_epi32(65535)),
+            )
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u32x8(self, a: u32x8<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u32x4(a0),
+            self.reinterpret_f16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u32x8(self, a: u32x8<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u32x4(a0),
+            self.reinterpret_bf16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u32x4(a0),
+            self.reinterpret_f32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x8(self, a: u32x8<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u32x4(a0),
+            self.reinterpret_f64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x8(self, a: u32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i8x16(self.reinterpret_i8_u32x4(a0), self.reinterpret_i8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x8(self, a: u32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u8x16(self.reinterpret_u8_u32x4(a0), self.reinterpret_u8_u32x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x8(self, a: u32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u32x4(a0),
+            self.reinterpret_i16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x8(self, a: u32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u32x4(a0),
+            self.reinterpret_u16_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x8(self, a: u32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u32x4(a0),
+            self.reinterpret_i32_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x8(self, a: u32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u32x4(a0),
+            self.reinterpret_i64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x8(self, a: u32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_u32x4(a0),
+            self.reinterpret_u64_u32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u32x8(self, a: u32x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u32x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x8(self, a: u32x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u32x8(a);
+        self.combine_f32x4(self.cvt_f32_u32x4(a0), self.cvt_f32_u32x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_f64_u32x8(self, a: u32x8<Self>) -> f64x8<Self> {
+        self.cvt_f64_u64x8(self.widen_u32x8(a))
+    }
+    #[inline(always)]
+    fn splat_mask32x8(self, a: i32) -> mask32x8<Self> {
+        let half = self.splat_mask32x4(a);
+        self.combine_mask32x4(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_mask32x4(self.not_mask32x4(a0), self.not_mask32x4(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.and_mask32x4(a0, b0), self.and_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.or_mask32x4(a0, b0), self.or_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.xor_mask32x4(a0, b0), self.xor_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask32x8(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+        c: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        let (c0, c1) = self.split_mask32x8(c);
+        self.combine_mask32x4(
+            self.select_mask32x4(a0, b0, c0),
+            self.select_mask32x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(self.simd_eq_mask32x4(a0, b0), self.simd_eq_mask32x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.any_mask32x4(a0) || self.any_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn all_mask32x8(self, a: mask32x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.all_mask32x4(a0) && self.all_mask32x4(a1)
+    }
+    #[inline(always)]
+    fn none_mask32x8(self, a: mask32x8<Self>) -> bool {
+        !self.any_mask32x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x8(self, a: mask32x8<Self>) -> usize {
+        self.to_bitmask_mask32x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x8(self, a: mask32x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x8(self, a: mask32x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.to_bitmask_mask32x4(a0) | (self.to_bitmask_mask32x4(a1) << 4)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x8(self, bits: u64) -> mask32x8<Self> {
+        self.combine_mask32x4(
+            self.from_bitmask_mask32x4(bits),
+            self.from_bitmask_mask32x4(bits >> 4),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask32x8<I: Indices<8>>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x8<I: Indices<8>>(
+        self,
+        a: mask32x8<Self>,
+        b: mask32x8<Self>,
+    ) -> mask32x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x8(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_mask32x4(self.reverse_mask32x4(a1), self.reverse_mask32x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask32x4::<K>(x),
+            self.rotate_lanes_left_mask32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (4 - K % 4)) - 1);
+        self.combine_mask32x4(
+            self.select_mask32x4(first, x, y),
+            self.select_mask32x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x8<const K: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask32x4::<K>(x),
+            self.rotate_lanes_right_mask32x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x4((1 << (K % 4)) - 1);
+        self.combine_mask32x4(
+            self.select_mask32x4(first, y, x),
+            self.select_mask32x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x8<const L: usize>(self, a: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let lanes = self.broadcast_lane_mask32x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_mask32x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(
+            self.unzip_low_mask32x4(a0, a1),
+            self.unzip_low_mask32x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        let (b0, b1) = self.split_mask32x8(b);
+        self.combine_mask32x4(
+            self.unzip_high_mask32x4(a0, a1),
+            self.unzip_high_mask32x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask32x8(self, a: mask32x8<Self>, b: mask32x8<Self>) -> mask32x16<Self> {
+        let mut result = [0; 16usize];
+        result[0..8usize].copy_from_slice(&a.val);
+        result[8usize..16usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask32x8(self, a: mask32x8<Self>) -> (mask32x4<Self>, mask32x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x8(self, a: mask32x8<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask32x4(a0),
+            self.reinterpret_i8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x8(self, a: mask32x8<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask32x4(a0),
+            self.reinterpret_u8_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x8(self, a: mask32x8<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask32x4(a0),
+            self.reinterpret_i16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x8(self, a: mask32x8<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask32x4(a0),
+            self.reinterpret_u16_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x8(self, a: mask32x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask32x4(a0),
+            self.reinterpret_i32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x8(self, a: mask32x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask32x4(a0),
+            self.reinterpret_u32_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x8(self, a: mask32x8<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask32x4(a0),
+            self.reinterpret_i64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x8(self, a: mask32x8<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask32x8(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask32x4(a0),
+            self.reinterpret_u64_mask32x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x4(self, a: f64) -> f64x4<Self> {
+        let half = self.splat_f64x2(a);
+        self.combine_f64x2(half, half)
+    }
+    #[inline(always)]
+    fn abs_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.abs_f64x2(a0), self.abs_f64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.neg_f64x2(a0), self.neg_f64x2(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.sqrt_f64x2(a0), self.sqrt_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_approx_f64x2(a0), self.recip_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_approx_f64x2(a0), self.rsqrt_approx_f64x2(a1))
+    }
+    #[inline(always)]
+    fn recip_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.recip_f64x2(a0, steps), self.recip_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f64x4(self, a: f64x4<Self>, steps: u32) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.rsqrt_f64x2(a0, steps), self.rsqrt_f64x2(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.add_f64x2(a0, b0), self.add_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.sub_f64x2(a0, b0), self.sub_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.mul_f64x2(a0, b0), self.mul_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.div_f64x2(a0, b0), self.div_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.copysign_f64x2(a0, b0), self.copysign_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_eq_f64x2(a0, b0), self.simd_eq_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ne_f64x2(a0, b0), self.simd_ne_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_lt_f64x2(a0, b0), self.simd_lt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_le_f64x2(a0, b0), self.simd_le_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_ge_f64x2(a0, b0), self.simd_ge_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_mask64x2(self.simd_gt_f64x2(a0, b0), self.simd_gt_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_ne_f64x4(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_eq_f64x4(self.abs_f64x4(a), self.splat_f64x4(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        let abs = self.abs_f64x4(a);
+        self.and_mask64x4(
+            self.simd_lt_f64x4(abs, self.splat_f64x4(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x4(abs, self.splat_f64x4(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x4(self, a: f64x4<Self>) -> mask64x4<Self> {
+        self.simd_lt_f64x4(
+            self.copysign_f64x4(self.splat_f64x4(1.0), a),
+            self.splat_f64x4(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, _) = self.split_f64x4(a);
+        let (b0, _) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a0, b0), self.zip_high_f64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (_, a1) = self.split_f64x4(a);
+        let (_, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.zip_low_f64x2(a1, b1), self.zip_high_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.max_f64x2(a0, b0), self.max_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.max_precise_f64x2(a0, b0),
+            self.max_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.min_f64x2(a0, b0), self.min_f64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(
+            self.min_precise_f64x2(a0, b0),
+            self.min_precise_f64x2(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.madd_f64x2(a0, b0, c0), self.madd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.msub_f64x2(a0, b0, c0), self.msub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmadd_f64x2(a0, b0, c0), self.nmadd_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.nmsub_f64x2(a0, b0, c0), self.nmsub_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fused_f64x2(a0, b0, c0),
+            self.mul_add_fused_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x4(self, a: f64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(
+            self.mul_add_fast_f64x2(a0, b0, c0),
+            self.mul_add_fast_f64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.floor_f64x2(a0), self.floor_f64x2(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.ceil_f64x2(a0), self.ceil_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.round_f64x2(a0), self.round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(
+            self.round_ties_even_f64x2(a0),
+            self.round_ties_even_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.fract_f64x2(a0), self.fract_f64x2(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.trunc_f64x2(a0), self.trunc_f64x2(a1))
+    }
+    #[inline(always)]
+    fn select_f64x4(self, a: mask64x4<Self>, b: f64x4<Self>, c: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        let (c0, c1) = self.split_f64x4(c);
+        self.combine_f64x2(self.select_f64x2(a0, b0, c0), self.select_f64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        self.reduce_add_f64x2(a0) + self.reduce_add_f64x2(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_min_f64x2(self.min_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f64x4(self, a: f64x4<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x4(a);
+        let a1 = self.select_f64x2(self.is_nan_f64x2(a1), a0, a1);
+        self.reduce_max_f64x2(self.max_precise_f64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f64x4<I: Indices<4>>(self, a: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x4<I: Indices<4>>(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x4(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f64x2(self.reverse_f64x2(a1), self.reverse_f64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f64x2::<K>(x),
+            self.rotate_lanes_left_f64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_f64x2(
+            self.select_f64x2(first, x, y),
+            self.select_f64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x4<const K: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f64x2::<K>(x),
+            self.rotate_lanes_right_f64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_f64x2(
+            self.select_f64x2(first, y, x),
+            self.select_f64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x4<const L: usize>(self, a: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let lanes = self.broadcast_lane_f64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_f64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.unzip_low_f64x2(a0, a1), self.unzip_low_f64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        let (b0, b1) = self.split_f64x4(b);
+        self.combine_f64x2(self.unzip_high_f64x2(a0, a1), self.unzip_high_f64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn combine_f64x4(self, a: f64x4<Self>, b: f64x4<Self>) -> f64x8<Self> {
+        let mut result = [0.0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_f64x4(self, a: f64x4<Self>) -> (f64x2<Self>, f64x2<Self>) {
+        let mut b0 = [0.0; 2usize];
+        let mut b1 = [0.0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_f64x4(self, a: f64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_f64x2(a0),
+            self.reinterpret_f16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_f64x4(self, a: f64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_f64x2(a0),
+            self.reinterpret_bf16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x4(self, a: f64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_f64x2(a0),
+            self.reinterpret_f32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x4(self, a: f64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_f64x2(a0), self.reinterpret_i8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x4(self, a: f64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_f64x2(a0), self.reinterpret_u8_f64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x4(self, a: f64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_f64x2(a0),
+            self.reinterpret_i16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x4(self, a: f64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_f64x2(a0),
+            self.reinterpret_u16_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x4(self, a: f64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_f64x2(a0),
+            self.reinterpret_i32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x4(self, a: f64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_f64x2(a0),
+            self.reinterpret_u32_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_f64x2(a0),
+            self.reinterpret_i64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_f64x2(a0),
+            self.reinterpret_u64_f64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x4(self, a: f64x4<Self>) -> f32x4<Self> {
+        unsafe {
+            let (low, high) = self.split_f64x4(a);
+            _mm_movelh_ps(_mm_cvtpd_ps(low.into()), _mm_cvtpd_ps(high.into())).simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn cvt_u16_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f64x4(self, a: f64x4<Self>) -> u16x4<Self> {
+        self.narrow_saturating_u32x4(self.cvt_u32_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x4(self, a: f64x4<Self>) -> i32x4<Self> {
+        self.narrow_saturating_i64x4(self.cvt_i64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x4(self, a: f64x4<Self>) -> u32x4<Self> {
+        self.narrow_saturating_u64x4(self.cvt_u64_round_f64x4(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_f64x2(a0), self.cvt_i64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x4(self, a: f64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_i64x2(self.cvt_i64_round_f64x2(a0), self.cvt_i64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_f64x2(a0), self.cvt_u64_f64x2(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x4(self, a: f64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_f64x4(a);
+        self.combine_u64x2(self.cvt_u64_round_f64x2(a0), self.cvt_u64_round_f64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_i64x4(self, a: i64) -> i64x4<Self> {
+        let half = self.splat_i64x2(a);
+        self.combine_i64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.not_i64x2(a0), self.not_i64x2(a1))
+    }
+    #[inline(always)]
+    fn add_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.add_i64x2(a0, b0), self.add_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.sub_i64x2(a0, b0), self.sub_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.mul_i64x2(a0, b0), self.mul_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.and_i64x2(a0, b0), self.and_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.or_i64x2(a0, b0), self.or_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.xor_i64x2(a0, b0), self.xor_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shl_i64x2(a0, b), self.shl_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i64x4(self, a: i64x4<Self>, b: u32) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.shr_i64x2(a0, b), self.shr_i64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u64).min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u64).min(63);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_eq_i64x2(a0, b0), self.simd_eq_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_lt_i64x2(a0, b0), self.simd_lt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_le_i64x2(a0, b0), self.simd_le_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_ge_i64x2(a0, b0), self.simd_ge_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_mask64x2(self.simd_gt_i64x2(a0, b0), self.simd_gt_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, _) = self.split_i64x4(a);
+        let (b0, _) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a0, b0), self.zip_high_i64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (_, a1) = self.split_i64x4(a);
+        let (_, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.zip_low_i64x2(a1, b1), self.zip_high_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i64x4(self, a: mask64x4<Self>, b: i64x4<Self>, c: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        let (c0, c1) = self.split_i64x4(c);
+        self.combine_i64x2(self.select_i64x2(a0, b0, c0), self.select_i64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.min_i64x2(a0, b0), self.min_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.max_i64x2(a0, b0), self.max_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_add_i64x2(self.add_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_min_i64x2(self.min_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_max_i64x2(self.max_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_and_i64x2(self.and_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i64x4(self, a: i64x4<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x4(a);
+        self.reduce_or_i64x2(self.or_i64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i64x4<I: Indices<4>>(self, a: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x4<I: Indices<4>>(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.reverse_i64x2(a1), self.reverse_i64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i64x2::<K>(x),
+            self.rotate_lanes_left_i64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_i64x2(
+            self.select_i64x2(first, x, y),
+            self.select_i64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x4<const K: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i64x2::<K>(x),
+            self.rotate_lanes_right_i64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_i64x2(
+            self.select_i64x2(first, y, x),
+            self.select_i64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x4<const L: usize>(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let lanes = self.broadcast_lane_i64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_i64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.unzip_low_i64x2(a0, a1), self.unzip_low_i64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_i64x2(self.unzip_high_i64x2(a0, a1), self.unzip_high_i64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.abs_i64x2(a0), self.abs_i64x2(a1))
+    }
+    #[inline(always)]
+    fn neg_i64x4(self, a: i64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i64x2(self.neg_i64x2(a0), self.neg_i64x2(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        let (b0, b1) = self.split_i64x4(b);
+        self.combine_u64x2(self.abs_diff_i64x2(a0, b0), self.abs_diff_i64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_i64x4(self, a: i64x4<Self>, b: i64x4<Self>) -> i64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_i64x4(self, a: i64x4<Self>) -> (i64x2<Self>, i64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        unsafe {
+            let (low, high) = self.split_i64x4(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(
+                _mm_castsi128_ps(low),
+                _mm_castsi128_ps(high),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x4(self, a: i64x4<Self>) -> i32x4<Self> {
+        self.narrow_i64x4(self.max_i64x4(
+            self.min_i64x4(a, self.splat_i64x4(i32::MAX as i64)),
+            self.splat_i64x4(i32::MIN as i64),
+        ))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_i64x4(self, a: i64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_i64x2(a0),
+            self.reinterpret_f16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_i64x4(self, a: i64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_i64x2(a0),
+            self.reinterpret_bf16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x4(self, a: i64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_i64x2(a0),
+            self.reinterpret_f32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_i64x2(a0),
+            self.reinterpret_f64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x4(self, a: i64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_i64x2(a0), self.reinterpret_i8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x4(self, a: i64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_i64x2(a0), self.reinterpret_u8_i64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x4(self, a: i64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_i64x2(a0),
+            self.reinterpret_i16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x4(self, a: i64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_i64x2(a0),
+            self.reinterpret_u16_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x4(self, a: i64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_i64x2(a0),
+            self.reinterpret_i32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x4(self, a: i64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_i64x2(a0),
+            self.reinterpret_u32_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x4(self, a: i64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_i64x2(a0),
+            self.reinterpret_u64_i64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x4(self, a: i64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x4(self, a: i64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_i64x4(a);
+        self.combine_f64x2(self.cvt_f64_i64x2(a0), self.cvt_f64_i64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_u64x4(self, a: u64) -> u64x4<Self> {
+        let half = self.splat_u64x2(a);
+        self.combine_u64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.not_u64x2(a0), self.not_u64x2(a1))
+    }
+    #[inline(always)]
+    fn add_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.add_u64x2(a0, b0), self.add_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.sub_u64x2(a0, b0), self.sub_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.mul_u64x2(a0, b0), self.mul_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.and_u64x2(a0, b0), self.and_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.or_u64x2(a0, b0), self.or_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.xor_u64x2(a0, b0), self.xor_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shl_u64x2(a0, b), self.shl_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u64x4(self, a: u64x4<Self>, b: u32) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.shr_u64x2(a0, b), self.shr_u64x2(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_eq_u64x2(a0, b0), self.simd_eq_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_lt_u64x2(a0, b0), self.simd_lt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_le_u64x2(a0, b0), self.simd_le_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_ge_u64x2(a0, b0), self.simd_ge_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_mask64x2(self.simd_gt_u64x2(a0, b0), self.simd_gt_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, _) = self.split_u64x4(a);
+        let (b0, _) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a0, b0), self.zip_high_u64x2(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (_, a1) = self.split_u64x4(a);
+        let (_, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.zip_low_u64x2(a1, b1), self.zip_high_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u64x4(self, a: mask64x4<Self>, b: u64x4<Self>, c: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        let (c0, c1) = self.split_u64x4(c);
+        self.combine_u64x2(self.select_u64x2(a0, b0, c0), self.select_u64x2(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.min_u64x2(a0, b0), self.min_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.max_u64x2(a0, b0), self.max_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_add_u64x2(self.add_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_min_u64x2(self.min_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_max_u64x2(self.max_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_and_u64x2(self.and_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u64x4(self, a: u64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x4(a);
+        self.reduce_or_u64x2(self.or_u64x2(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u64x4<I: Indices<4>>(self, a: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x4<I: Indices<4>>(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x4(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u64x2(self.reverse_u64x2(a1), self.reverse_u64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u64x2::<K>(x),
+            self.rotate_lanes_left_u64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_u64x2(
+            self.select_u64x2(first, x, y),
+            self.select_u64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x4<const K: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u64x2::<K>(x),
+            self.rotate_lanes_right_u64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_u64x2(
+            self.select_u64x2(first, y, x),
+            self.select_u64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x4<const L: usize>(self, a: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let lanes = self.broadcast_lane_u64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_u64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.unzip_low_u64x2(a0, a1), self.unzip_low_u64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.unzip_high_u64x2(a0, a1), self.unzip_high_u64x2(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        let (b0, b1) = self.split_u64x4(b);
+        self.combine_u64x2(self.abs_diff_u64x2(a0, b0), self.abs_diff_u64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn combine_u64x4(self, a: u64x4<Self>, b: u64x4<Self>) -> u64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_u64x4(self, a: u64x4<Self>) -> (u64x2<Self>, u64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        unsafe {
+            let (low, high) = self.split_u64x4(a);
+            let (low, high) = (low.into(), high.into());
+            _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(
+                _mm_castsi128_ps(low),
+                _mm_castsi128_ps(high),
+            ))
+            .simd_into(self)
+        }
+    }
+    #[inline(always)]
+    fn narrow_saturating_u64x4(self, a: u64x4<Self>) -> u32x4<Self> {
+        self.narrow_u64x4(self.min_u64x4(a, self.splat_u64x4(u32::MAX as u64)))
+    }
+    #[inline(always)]
+    fn reinterpret_f16_u64x4(self, a: u64x4<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f16x8(
+            self.reinterpret_f16_u64x2(a0),
+            self.reinterpret_f16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_bf16_u64x4(self, a: u64x4<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_bf16x8(
+            self.reinterpret_bf16_u64x2(a0),
+            self.reinterpret_bf16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x4(self, a: u64x4<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f32x4(
+            self.reinterpret_f32_u64x2(a0),
+            self.reinterpret_f32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(
+            self.reinterpret_f64_u64x2(a0),
+            self.reinterpret_f64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x4(self, a: u64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i8x16(self.reinterpret_i8_u64x2(a0), self.reinterpret_i8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x4(self, a: u64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u8x16(self.reinterpret_u8_u64x2(a0), self.reinterpret_u8_u64x2(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x4(self, a: u64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_u64x2(a0),
+            self.reinterpret_i16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x4(self, a: u64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_u64x2(a0),
+            self.reinterpret_u16_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x4(self, a: u64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_u64x2(a0),
+            self.reinterpret_i32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x4(self, a: u64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_u64x2(a0),
+            self.reinterpret_u32_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x4(self, a: u64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_u64x2(a0),
+            self.reinterpret_i64_u64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x4(self, a: u64x4<Self>) -> f32x4<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x4(self, a: u64x4<Self>) -> f64x4<Self> {
+        let (a0, a1) = self.split_u64x4(a);
+        self.combine_f64x2(self.cvt_f64_u64x2(a0), self.cvt_f64_u64x2(a1))
+    }
+    #[inline(always)]
+    fn splat_mask64x4(self, a: i64) -> mask64x4<Self> {
+        let half = self.splat_mask64x2(a);
+        self.combine_mask64x2(half, half)
+    }
+    #[inline(always)]
+    fn not_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_mask64x2(self.not_mask64x2(a0), self.not_mask64x2(a1))
+    }
+    #[inline(always)]
+    fn and_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.and_mask64x2(a0, b0), self.and_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.or_mask64x2(a0, b0), self.or_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.xor_mask64x2(a0, b0), self.xor_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask64x4(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+        c: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        let (c0, c1) = self.split_mask64x4(c);
+        self.combine_mask64x2(
+            self.select_mask64x2(a0, b0, c0),
+            self.select_mask64x2(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(self.simd_eq_mask64x2(a0, b0), self.simd_eq_mask64x2(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.any_mask64x2(a0) || self.any_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn all_mask64x4(self, a: mask64x4<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.all_mask64x2(a0) && self.all_mask64x2(a1)
+    }
+    #[inline(always)]
+    fn none_mask64x4(self, a: mask64x4<Self>) -> bool {
+        !self.any_mask64x4(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x4(self, a: mask64x4<Self>) -> usize {
+        self.to_bitmask_mask64x4(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x4(self, a: mask64x4<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x4(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x4(self, a: mask64x4<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.to_bitmask_mask64x2(a0) | (self.to_bitmask_mask64x2(a1) << 2)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x4(self, bits: u64) -> mask64x4<Self> {
+        self.combine_mask64x2(
+            self.from_bitmask_mask64x2(bits),
+            self.from_bitmask_mask64x2(bits >> 2),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask64x4<I: Indices<4>>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 4) };
+        let lanes: [_; 4] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x4<I: Indices<4>>(
+        self,
+        a: mask64x4<Self>,
+        b: mask64x4<Self>,
+    ) -> mask64x4<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 4] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 4 { a[index] } else { b[index - 4] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x4(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_mask64x2(self.reverse_mask64x2(a1), self.reverse_mask64x2(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask64x2::<K>(x),
+            self.rotate_lanes_left_mask64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (2 - K % 2)) - 1);
+        self.combine_mask64x2(
+            self.select_mask64x2(first, x, y),
+            self.select_mask64x2(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x4<const K: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (x, y) = if K % 4 < 2 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask64x2::<K>(x),
+            self.rotate_lanes_right_mask64x2::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x2((1 << (K % 2)) - 1);
+        self.combine_mask64x2(
+            self.select_mask64x2(first, y, x),
+            self.select_mask64x2(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x4<const L: usize>(self, a: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let lanes = self.broadcast_lane_mask64x2::<L>(if L % 4 < 2 { a0 } else { a1 });
+        self.combine_mask64x2(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(
+            self.unzip_low_mask64x2(a0, a1),
+            self.unzip_low_mask64x2(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        let (b0, b1) = self.split_mask64x4(b);
+        self.combine_mask64x2(
+            self.unzip_high_mask64x2(a0, a1),
+            self.unzip_high_mask64x2(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn combine_mask64x4(self, a: mask64x4<Self>, b: mask64x4<Self>) -> mask64x8<Self> {
+        let mut result = [0; 8usize];
+        result[0..4usize].copy_from_slice(&a.val);
+        result[4usize..8usize].copy_from_slice(&b.val);
+        result.simd_into(self)
+    }
+    #[inline(always)]
+    fn split_mask64x4(self, a: mask64x4<Self>) -> (mask64x2<Self>, mask64x2<Self>) {
+        let mut b0 = [0; 2usize];
+        let mut b1 = [0; 2usize];
+        b0.copy_from_slice(&a.val[0..2usize]);
+        b1.copy_from_slice(&a.val[2usize..4usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x4(self, a: mask64x4<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i8x16(
+            self.reinterpret_i8_mask64x2(a0),
+            self.reinterpret_i8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x4(self, a: mask64x4<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u8x16(
+            self.reinterpret_u8_mask64x2(a0),
+            self.reinterpret_u8_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x4(self, a: mask64x4<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i16x8(
+            self.reinterpret_i16_mask64x2(a0),
+            self.reinterpret_i16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x4(self, a: mask64x4<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u16x8(
+            self.reinterpret_u16_mask64x2(a0),
+            self.reinterpret_u16_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x4(self, a: mask64x4<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i32x4(
+            self.reinterpret_i32_mask64x2(a0),
+            self.reinterpret_i32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x4(self, a: mask64x4<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u32x4(
+            self.reinterpret_u32_mask64x2(a0),
+            self.reinterpret_u32_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x4(self, a: mask64x4<Self>) -> i64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_i64x2(
+            self.reinterpret_i64_mask64x2(a0),
+            self.reinterpret_i64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x4(self, a: mask64x4<Self>) -> u64x4<Self> {
+        let (a0, a1) = self.split_mask64x4(a);
+        self.combine_u64x2(
+            self.reinterpret_u64_mask64x2(a0),
+            self.reinterpret_u64_mask64x2(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f32x16(self, a: f32) -> f32x16<Self> {
+        let half = self.splat_f32x8(a);
+        self.combine_f32x8(half, half)
+    }
+    #[inline(always)]
+    fn abs_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.abs_f32x8(a0), self.abs_f32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.neg_f32x8(a0), self.neg_f32x8(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.sqrt_f32x8(a0), self.sqrt_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_approx_f32x8(a0), self.recip_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_approx_f32x8(a0), self.rsqrt_approx_f32x8(a1))
+    }
+    #[inline(always)]
+    fn recip_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.recip_f32x8(a0, steps), self.recip_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f32x16(self, a: f32x16<Self>, steps: u32) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.rsqrt_f32x8(a0, steps), self.rsqrt_f32x8(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.add_f32x8(a0, b0), self.add_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.sub_f32x8(a0, b0), self.sub_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.mul_f32x8(a0, b0), self.mul_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.div_f32x8(a0, b0), self.div_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.copysign_f32x8(a0, b0), self.copysign_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_eq_f32x8(a0, b0), self.simd_eq_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ne_f32x8(a0, b0), self.simd_ne_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_lt_f32x8(a0, b0), self.simd_lt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_le_f32x8(a0, b0), self.simd_le_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_ge_f32x8(a0, b0), self.simd_ge_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_mask32x8(self.simd_gt_f32x8(a0, b0), self.simd_gt_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_ne_f32x16(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_eq_f32x16(self.abs_f32x16(a), self.splat_f32x16(f32::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        let abs = self.abs_f32x16(a);
+        self.and_mask32x16(
+            self.simd_lt_f32x16(abs, self.splat_f32x16(f32::MIN_POSITIVE)),
+            self.simd_gt_f32x16(abs, self.splat_f32x16(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f32x16(self, a: f32x16<Self>) -> mask32x16<Self> {
+        self.simd_lt_f32x16(
+            self.copysign_f32x16(self.splat_f32x16(1.0), a),
+            self.splat_f32x16(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, _) = self.split_f32x16(a);
+        let (b0, _) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a0, b0), self.zip_high_f32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (_, a1) = self.split_f32x16(a);
+        let (_, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.zip_low_f32x8(a1, b1), self.zip_high_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.max_f32x8(a0, b0), self.max_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.max_precise_f32x8(a0, b0),
+            self.max_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.min_f32x8(a0, b0), self.min_f32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(
+            self.min_precise_f32x8(a0, b0),
+            self.min_precise_f32x8(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.madd_f32x8(a0, b0, c0), self.madd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.msub_f32x8(a0, b0, c0), self.msub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmadd_f32x8(a0, b0, c0), self.nmadd_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f32x16(self, a: f32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.nmsub_f32x8(a0, b0, c0), self.nmsub_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fused_f32x8(a0, b0, c0),
+            self.mul_add_fused_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f32x16(
+        self,
+        a: f32x16<Self>,
+        b: f32x16<Self>,
+        c: f32x16<Self>,
+    ) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(
+            self.mul_add_fast_f32x8(a0, b0, c0),
+            self.mul_add_fast_f32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.floor_f32x8(a0), self.floor_f32x8(a1))
+    }
+    #[inline(always)]
+    fn ceil_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.ceil_f32x8(a0), self.ceil_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.round_f32x8(a0), self.round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(
+            self.round_ties_even_f32x8(a0),
+            self.round_ties_even_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.fract_f32x8(a0), self.fract_f32x8(a1))
+    }
+    #[inline(always)]
+    fn trunc_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.trunc_f32x8(a0), self.trunc_f32x8(a1))
+    }
+    #[inline(always)]
+    fn select_f32x16(self, a: mask32x16<Self>, b: f32x16<Self>, c: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        let (c0, c1) = self.split_f32x16(c);
+        self.combine_f32x8(self.select_f32x8(a0, b0, c0), self.select_f32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        self.reduce_add_f32x8(a0) + self.reduce_add_f32x8(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_min_f32x8(self.min_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f32x16(self, a: f32x16<Self>) -> f32 {
+        let (a0, a1) = self.split_f32x16(a);
+        let a1 = self.select_f32x8(self.is_nan_f32x8(a1), a0, a1);
+        self.reduce_max_f32x8(self.max_precise_f32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f32x16<I: Indices<16>>(self, a: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f32x16<I: Indices<16>>(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f32x16(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f32x8(self.reverse_f32x8(a1), self.reverse_f32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f32x8::<K>(x),
+            self.rotate_lanes_left_f32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_f32x8(
+            self.select_f32x8(first, x, y),
+            self.select_f32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f32x16<const K: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f32x8::<K>(x),
+            self.rotate_lanes_right_f32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_f32x8(
+            self.select_f32x8(first, y, x),
+            self.select_f32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f32x16<const L: usize>(self, a: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let lanes = self.broadcast_lane_f32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_f32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_low_f32x8(a0, a1), self.unzip_low_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f32x16(self, a: f32x16<Self>, b: f32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        let (b0, b1) = self.split_f32x16(b);
+        self.combine_f32x8(self.unzip_high_f32x8(a0, a1), self.unzip_high_f32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f32x16(self, a: f32x16<Self>) -> (f32x8<Self>, f32x8<Self>) {
+        let mut b0 = [0.0; 8usize];
+        let mut b1 = [0.0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_f32x16(self, src: &[f32; 16usize]) -> f32x16<Self> {
+        unsafe {
+            let v0 = _mm_loadu_si128(src[0usize..].as_ptr() as *const __m128i);
+            let v1 = _mm_loadu_si128(src[4usize..].as_ptr() as *const __m128i);
+            let v2 = _mm_loadu_si128(src[8usize..].as_ptr() as *const __m128i);
+            let v3 = _mm_loadu_si128(src[12usize..].as_ptr() as *const __m128i);
+            let v02_lower = _mm_unpacklo_epi32(v0, v2);
+            let v13_lower = _mm_unpacklo_epi32(v1, v3);
+            let v02_upper = _mm_unpackhi_epi32(v0, v2);
+            let v13_upper = _mm_unpackhi_epi32(v1, v3);
+            let out0 = _mm_unpacklo_epi32(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi32(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi32(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi32(v02_upper, v13_upper);
+            let combined_lower = self.combine_f32x4(
+                _mm_castsi128_ps(out0).simd_into(self),
+                _mm_castsi128_ps(out1).simd_into(self),
+            );
+            let combined_upper = self.combine_f32x4(
+                _mm_castsi128_ps(out2).simd_into(self),
+                _mm_castsi128_ps(out3).simd_into(self),
+            );
+            self.combine_f32x8(combined_lower, combined_upper)
+        }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_f32x16(self, a: f32x16<Self>, dest: &mut [f32; 16usize]) -> () {
+        let (lower, upper) = self.split_f32x16(a);
+        let (v0, v1) = self.split_f32x8(lower);
+        let (v2, v3) = self.split_f32x8(upper);
+        unsafe {
+            let v0 = _mm_castps_si128(v0.into());
+            let v1 = _mm_castps_si128(v1.into());
+            let v2 = _mm_castps_si128(v2.into());
+            let v3 = _mm_castps_si128(v3.into());
+            let v02_lower = _mm_unpacklo_epi32(v0, v2);
+            let v13_lower = _mm_unpacklo_epi32(v1, v3);
+            let v02_upper = _mm_unpackhi_epi32(v0, v2);
+            let v13_upper = _mm_unpackhi_epi32(v1, v3);
+            let out0 = _mm_unpacklo_epi32(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi32(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi32(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi32(v02_upper, v13_upper);
+            _mm_storeu_si128(dest[0usize..].as_mut_ptr() as *mut __m128i, out0);
+            _mm_storeu_si128(dest[4usize..].as_mut_ptr() as *mut __m128i, out1);
+            _mm_storeu_si128(dest[8usize..].as_mut_ptr() as *mut __m128i, out2);
+            _mm_storeu_si128(dest[12usize..].as_mut_ptr() as *mut __m128i, out3);
+        }
+    }
+    #[inline(always)]
+    fn reinterpret_f64_f32x16(self, a: f32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_f32x8(a0),
+            self.reinterpret_f64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f32x16(self, a: f32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_f32x8(a0), self.reinterpret_i8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f32x16(self, a: f32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_f32x8(a0), self.reinterpret_u8_f32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f32x16(self, a: f32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f32x8(a0),
+            self.reinterpret_i16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f32x16(self, a: f32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f32x8(a0),
+            self.reinterpret_u16_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f32x8(a0),
+            self.reinterpret_i32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f32x8(a0),
+            self.reinterpret_u32_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f32x16(self, a: f32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f32x8(a0),
+            self.reinterpret_i64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f32x16(self, a: f32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f32x8(a0),
+            self.reinterpret_u64_f32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f32x16(self, a: f32x16<Self>) -> f16x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_f16x8(self.cvt_f16_f32x8(a0), self.cvt_f16_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i8_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i8_round_f32x16(self, a: f32x16<Self>) -> i8x16<Self> {
+        self.narrow_saturating_i16x16(self.cvt_i16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f32x16(self, a: f32x16<Self>) -> u8x16<Self> {
+        self.narrow_saturating_u16x16(self.cvt_u16_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f32x16(self, a: f32x16<Self>) -> i16x16<Self> {
+        self.narrow_saturating_i32x16(self.cvt_i32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f32x16(self, a: f32x16<Self>) -> u16x16<Self> {
+        self.narrow_saturating_u32x16(self.cvt_u32_round_f32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_f32x8(a0), self.cvt_i32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f32x16(self, a: f32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_i32x8(self.cvt_i32_round_f32x8(a0), self.cvt_i32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_f32x8(a0), self.cvt_u32_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f32x16(self, a: f32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_u32x8(self.cvt_u32_round_f32x8(a0), self.cvt_u32_round_f32x8(a1))
+    }
+    #[inline(always)]
+    fn cvt_bf16_f32x16(self, a: f32x16<Self>) -> bf16x16<Self> {
+        let (a0, a1) = self.split_f32x16(a);
+        self.combine_bf16x8(self.cvt_bf16_f32x8(a0), self.cvt_bf16_f32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_i8x64(self, a: i8) -> i8x64<Self> {
+        let half = self.splat_i8x32(a);
+        self.combine_i8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.not_i8x32(a0), self.not_i8x32(a1))
+    }
+    #[inline(always)]
+    fn add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.add_i8x32(a0, b0), self.add_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.sub_i8x32(a0, b0), self.sub_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_i8x32(a0, b0), self.mul_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.and_i8x32(a0, b0), self.and_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.or_i8x32(a0, b0), self.or_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.xor_i8x32(a0, b0), self.xor_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shl_i8x32(a0, b), self.shl_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i8x64(self, a: i8x64<Self>, b: u32) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.shr_i8x32(a0, b), self.shr_i8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u8).min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u8).min(7);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_eq_i8x32(a0, b0), self.simd_eq_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_lt_i8x32(a0, b0), self.simd_lt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_le_i8x32(a0, b0), self.simd_le_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_ge_i8x32(a0, b0), self.simd_ge_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_mask8x32(self.simd_gt_i8x32(a0, b0), self.simd_gt_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, _) = self.split_i8x64(a);
+        let (b0, _) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a0, b0), self.zip_high_i8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (_, a1) = self.split_i8x64(a);
+        let (_, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.zip_low_i8x32(a1, b1), self.zip_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i8x64(self, a: mask8x64<Self>, b: i8x64<Self>, c: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        let (c0, c1) = self.split_i8x64(c);
+        self.combine_i8x32(self.select_i8x32(a0, b0, c0), self.select_i8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.min_i8x32(a0, b0), self.min_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.max_i8x32(a0, b0), self.max_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_add_i8x32(self.add_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_min_i8x32(self.min_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_max_i8x32(self.max_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_and_i8x32(self.and_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i8x64(self, a: i8x64<Self>) -> i8 {
+        let (a0, a1) = self.split_i8x64(a);
+        self.reduce_or_i8x32(self.or_i8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i8x64<I: Indices<64>>(self, a: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i8x64<I: Indices<64>>(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.reverse_i8x32(a1), self.reverse_i8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i8x32::<K>(x),
+            self.rotate_lanes_left_i8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_i8x32(
+            self.select_i8x32(first, x, y),
+            self.select_i8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i8x64<const K: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i8x32::<K>(x),
+            self.rotate_lanes_right_i8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_i8x32(
+            self.select_i8x32(first, y, x),
+            self.select_i8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i8x64<const L: usize>(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let lanes = self.broadcast_lane_i8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_i8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_low_i8x32(a0, a1), self.unzip_low_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.unzip_high_i8x32(a0, a1), self.unzip_high_i8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_add_i8x32(a0, b0),
+            self.saturating_add_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(
+            self.saturating_sub_i8x32(a0, b0),
+            self.saturating_sub_i8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.abs_i8x32(a0), self.abs_i8x32(a1))
+    }
+    #[inline(always)]
+    fn neg_i8x64(self, a: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i8x32(self.neg_i8x32(a0), self.neg_i8x32(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_u8x32(self.abs_diff_i8x32(a0, b0), self.abs_diff_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i8x64(self, a: i8x64<Self>, b: i8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        let (b0, b1) = self.split_i8x64(b);
+        self.combine_i8x32(self.mul_high_i8x32(a0, b0), self.mul_high_i8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i8x64(self, a: i8x64<Self>) -> (i8x32<Self>, i8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        self.widen_i8x32(self.split_i8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i8x64(self, a: i8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i8x32(a0),
+            self.reinterpret_f32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i8x64(self, a: i8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i8x32(a0),
+            self.reinterpret_f64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i8x64(self, a: i8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u8x32(self.reinterpret_u8_i8x32(a0), self.reinterpret_u8_i8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i8x64(self, a: i8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i8x32(a0),
+            self.reinterpret_i16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i8x64(self, a: i8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i8x32(a0),
+            self.reinterpret_u16_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i8x64(self, a: i8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i8x32(a0),
+            self.reinterpret_i32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i8x64(self, a: i8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i8x32(a0),
+            self.reinterpret_u32_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i8x64(self, a: i8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i8x32(a0),
+            self.reinterpret_i64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i8x64(self, a: i8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i8x32(a0),
+            self.reinterpret_u64_i8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u8x64(self, a: u8) -> u8x64<Self> {
+        let half = self.splat_u8x32(a);
+        self.combine_u8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.not_u8x32(a0), self.not_u8x32(a1))
+    }
+    #[inline(always)]
+    fn add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.add_u8x32(a0, b0), self.add_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.sub_u8x32(a0, b0), self.sub_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_u8x32(a0, b0), self.mul_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.and_u8x32(a0, b0), self.and_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.or_u8x32(a0, b0), self.or_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.xor_u8x32(a0, b0), self.xor_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shl_u8x32(a0, b), self.shl_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u8x64(self, a: u8x64<Self>, b: u32) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.shr_u8x32(a0, b), self.shr_u8x32(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(8) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_eq_u8x32(a0, b0), self.simd_eq_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_lt_u8x32(a0, b0), self.simd_lt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_le_u8x32(a0, b0), self.simd_le_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_ge_u8x32(a0, b0), self.simd_ge_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_mask8x32(self.simd_gt_u8x32(a0, b0), self.simd_gt_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, _) = self.split_u8x64(a);
+        let (b0, _) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a0, b0), self.zip_high_u8x32(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (_, a1) = self.split_u8x64(a);
+        let (_, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.zip_low_u8x32(a1, b1), self.zip_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u8x64(self, a: mask8x64<Self>, b: u8x64<Self>, c: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        let (c0, c1) = self.split_u8x64(c);
+        self.combine_u8x32(self.select_u8x32(a0, b0, c0), self.select_u8x32(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.min_u8x32(a0, b0), self.min_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.max_u8x32(a0, b0), self.max_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_add_u8x32(self.add_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_min_u8x32(self.min_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_max_u8x32(self.max_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_and_u8x32(self.and_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u8x64(self, a: u8x64<Self>) -> u8 {
+        let (a0, a1) = self.split_u8x64(a);
+        self.reduce_or_u8x32(self.or_u8x32(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u8x64<I: Indices<64>>(self, a: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u8x64<I: Indices<64>>(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u8x64(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u8x32(self.reverse_u8x32(a1), self.reverse_u8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u8x32::<K>(x),
+            self.rotate_lanes_left_u8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_u8x32(
+            self.select_u8x32(first, x, y),
+            self.select_u8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u8x64<const K: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u8x32::<K>(x),
+            self.rotate_lanes_right_u8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_u8x32(
+            self.select_u8x32(first, y, x),
+            self.select_u8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u8x64<const L: usize>(self, a: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let lanes = self.broadcast_lane_u8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_u8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_low_u8x32(a0, a1), self.unzip_low_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.unzip_high_u8x32(a0, a1), self.unzip_high_u8x32(b0, b1))
+    }
+    #[inline(always)]
+    fn saturating_add_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_add_u8x32(a0, b0),
+            self.saturating_add_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(
+            self.saturating_sub_u8x32(a0, b0),
+            self.saturating_sub_u8x32(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.abs_diff_u8x32(a0, b0), self.abs_diff_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.avg_round_u8x32(a0, b0), self.avg_round_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.mul_high_u8x32(a0, b0), self.mul_high_u8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn swizzle_dyn_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_32_u8x64(self, a: u8x32<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_32_u8x32(a, b0), self.lookup_32_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_48_u8x64(self, a: [u8x16<Self>; 3], b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_48_u8x32(a, b0), self.lookup_48_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn lookup_64_u8x64(self, a: u8x64<Self>, b: u8x64<Self>) -> u8x64<Self> {
+        let (b0, b1) = self.split_u8x64(b);
+        self.combine_u8x32(self.lookup_64_u8x32(a, b0), self.lookup_64_u8x32(a, b1))
+    }
+    #[inline(always)]
+    fn split_u8x64(self, a: u8x64<Self>) -> (u8x32<Self>, u8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u8x64(self, src: &[u8; 64usize]) -> u8x64<Self> {
+        unsafe {
+            let v0 = _mm_loadu_si128(src[0usize..].as_ptr() as *const __m128i);
+            let v1 = _mm_loadu_si128(src[16usize..].as_ptr() as *const __m128i);
+            let v2 = _mm_loadu_si128(src[32usize..].as_ptr() as *const __m128i);
+            let v3 = _mm_loadu_si128(src[48usize..].as_ptr() as *const __m128i);
+            let v02_lower = _mm_unpacklo_epi8(v0, v2);
+            let v13_lower = _mm_unpacklo_epi8(v1, v3);
+            let v02_upper = _mm_unpackhi_epi8(v0, v2);
+            let v13_upper = _mm_unpackhi_epi8(v1, v3);
+            let out0 = _mm_unpacklo_epi8(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi8(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi8(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi8(v02_upper, v13_upper);
+            let combined_lower = self.combine_u8x16(out0.simd_into(self), out1.simd_into(self));
+            let combined_upper = self.combine_u8x16(out2.simd_into(self), out3.simd_into(self));
+            self.combine_u8x32(combined_lower, combined_upper)
+        }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u8x64(self, a: u8x64<Self>, dest: &mut [u8; 64usize]) -> () {
+        let (lower, upper) = self.split_u8x64(a);
+        let (v0, v1) = self.split_u8x32(lower);
+        let (v2, v3) = self.split_u8x32(upper);
+        unsafe {
+            let v0 = v0.into();
+            let v1 = v1.into();
+            let v2 = v2.into();
+            let v3 = v3.into();
+            let v02_lower = _mm_unpacklo_epi8(v0, v2);
+            let v13_lower = _mm_unpacklo_epi8(v1, v3);
+            let v02_upper = _mm_unpackhi_epi8(v0, v2);
+            let v13_upper = _mm_unpackhi_epi8(v1, v3);
+            let out0 = _mm_unpacklo_epi8(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi8(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi8(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi8(v02_upper, v13_upper);
+            _mm_storeu_si128(dest[0usize..].as_mut_ptr() as *mut __m128i, out0);
+            _mm_storeu_si128(dest[16usize..].as_mut_ptr() as *mut __m128i, out1);
+            _mm_storeu_si128(dest[32usize..].as_mut_ptr() as *mut __m128i, out2);
+            _mm_storeu_si128(dest[48usize..].as_mut_ptr() as *mut __m128i, out3);
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        self.widen_u8x32(self.split_u8x64(a).1)
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u8x64(self, a: u8x64<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u8x32(a0),
+            self.reinterpret_f32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u8x64(self, a: u8x64<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u8x32(a0),
+            self.reinterpret_f64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u8x64(self, a: u8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i8x32(self.reinterpret_i8_u8x32(a0), self.reinterpret_i8_u8x32(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u8x64(self, a: u8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u8x32(a0),
+            self.reinterpret_i16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u8x64(self, a: u8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u8x32(a0),
+            self.reinterpret_u16_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u8x64(self, a: u8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u8x32(a0),
+            self.reinterpret_i32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u8x64(self, a: u8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u8x32(a0),
+            self.reinterpret_u32_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u8x64(self, a: u8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u8x32(a0),
+            self.reinterpret_i64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u8x64(self, a: u8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u8x32(a0),
+            self.reinterpret_u64_u8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask8x64(self, a: i8) -> mask8x64<Self> {
+        let half = self.splat_mask8x32(a);
+        self.combine_mask8x32(half, half)
+    }
+    #[inline(always)]
+    fn not_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.not_mask8x32(a0), self.not_mask8x32(a1))
+    }
+    #[inline(always)]
+    fn and_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.and_mask8x32(a0, b0), self.and_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.or_mask8x32(a0, b0), self.or_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.xor_mask8x32(a0, b0), self.xor_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask8x64(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+        c: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        let (c0, c1) = self.split_mask8x64(c);
+        self.combine_mask8x32(
+            self.select_mask8x32(a0, b0, c0),
+            self.select_mask8x32(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(self.simd_eq_mask8x32(a0, b0), self.simd_eq_mask8x32(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.any_mask8x32(a0) || self.any_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn all_mask8x64(self, a: mask8x64<Self>) -> bool {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.all_mask8x32(a0) && self.all_mask8x32(a1)
+    }
+    #[inline(always)]
+    fn none_mask8x64(self, a: mask8x64<Self>) -> bool {
+        !self.any_mask8x64(a)
+    }
+    #[inline(always)]
+    fn count_true_mask8x64(self, a: mask8x64<Self>) -> usize {
+        self.to_bitmask_mask8x64(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask8x64(self, a: mask8x64<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask8x64(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask8x64(self, a: mask8x64<Self>) -> u64 {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.to_bitmask_mask8x32(a0) | (self.to_bitmask_mask8x32(a1) << 32)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask8x64(self, bits: u64) -> mask8x64<Self> {
+        self.combine_mask8x32(
+            self.from_bitmask_mask8x32(bits),
+            self.from_bitmask_mask8x32(bits >> 32),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask8x64<I: Indices<64>>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 64] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask8x64<I: Indices<64>>(
+        self,
+        a: mask8x64<Self>,
+        b: mask8x64<Self>,
+    ) -> mask8x64<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 128) };
+        let lanes: [_; 64] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 64 { a[index] } else { b[index - 64] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask8x64(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_mask8x32(self.reverse_mask8x32(a1), self.reverse_mask8x32(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask8x32::<K>(x),
+            self.rotate_lanes_left_mask8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (32 - K % 32)) - 1);
+        self.combine_mask8x32(
+            self.select_mask8x32(first, x, y),
+            self.select_mask8x32(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask8x64<const K: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (x, y) = if K % 64 < 32 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask8x32::<K>(x),
+            self.rotate_lanes_right_mask8x32::<K>(y),
+        );
+        let first = self.from_bitmask_mask8x32((1 << (K % 32)) - 1);
+        self.combine_mask8x32(
+            self.select_mask8x32(first, y, x),
+            self.select_mask8x32(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask8x64<const L: usize>(self, a: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let lanes = self.broadcast_lane_mask8x32::<L>(if L % 64 < 32 { a0 } else { a1 });
+        self.combine_mask8x32(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(
+            self.unzip_low_mask8x32(a0, a1),
+            self.unzip_low_mask8x32(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask8x64(self, a: mask8x64<Self>, b: mask8x64<Self>) -> mask8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        let (b0, b1) = self.split_mask8x64(b);
+        self.combine_mask8x32(
+            self.unzip_high_mask8x32(a0, a1),
+            self.unzip_high_mask8x32(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask8x64(self, a: mask8x64<Self>) -> (mask8x32<Self>, mask8x32<Self>) {
+        let mut b0 = [0; 32usize];
+        let mut b1 = [0; 32usize];
+        b0.copy_from_slice(&a.val[0..32usize]);
+        b1.copy_from_slice(&a.val[32usize..64usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask8x64(self, a: mask8x64<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask8x32(a0),
+            self.reinterpret_i8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask8x64(self, a: mask8x64<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask8x32(a0),
+            self.reinterpret_u8_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask8x64(self, a: mask8x64<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask8x32(a0),
+            self.reinterpret_i16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask8x64(self, a: mask8x64<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask8x32(a0),
+            self.reinterpret_u16_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask8x64(self, a: mask8x64<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask8x32(a0),
+            self.reinterpret_i32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask8x64(self, a: mask8x64<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask8x32(a0),
+            self.reinterpret_u32_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask8x64(self, a: mask8x64<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask8x32(a0),
+            self.reinterpret_i64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask8x64(self, a: mask8x64<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask8x64(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask8x32(a0),
+            self.reinterpret_u64_mask8x32(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i16x32(self, a: i16) -> i16x32<Self> {
+        let half = self.splat_i16x16(a);
+        self.combine_i16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.not_i16x16(a0), self.not_i16x16(a1))
+    }
+    #[inline(always)]
+    fn add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.add_i16x16(a0, b0), self.add_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.sub_i16x16(a0, b0), self.sub_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_i16x16(a0, b0), self.mul_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.and_i16x16(a0, b0), self.and_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.or_i16x16(a0, b0), self.or_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.xor_i16x16(a0, b0), self.xor_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shl_i16x16(a0, b), self.shl_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i16x32(self, a: i16x32<Self>, b: u32) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.shr_i16x16(a0, b), self.shr_i16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u16).min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u16).min(15);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_eq_i16x16(a0, b0), self.simd_eq_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_lt_i16x16(a0, b0), self.simd_lt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_le_i16x16(a0, b0), self.simd_le_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_ge_i16x16(a0, b0), self.simd_ge_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_mask16x16(self.simd_gt_i16x16(a0, b0), self.simd_gt_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, _) = self.split_i16x32(a);
+        let (b0, _) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a0, b0), self.zip_high_i16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (_, a1) = self.split_i16x32(a);
+        let (_, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.zip_low_i16x16(a1, b1), self.zip_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i16x32(self, a: mask16x32<Self>, b: i16x32<Self>, c: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        let (c0, c1) = self.split_i16x32(c);
+        self.combine_i16x16(
+            self.select_i16x16(a0, b0, c0),
+            self.select_i16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.min_i16x16(a0, b0), self.min_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.max_i16x16(a0, b0), self.max_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_add_i16x16(self.add_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_min_i16x16(self.min_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_max_i16x16(self.max_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_and_i16x16(self.and_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i16x32(self, a: i16x32<Self>) -> i16 {
+        let (a0, a1) = self.split_i16x32(a);
+        self.reduce_or_i16x16(self.or_i16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i16x32<I: Indices<32>>(self, a: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i16x32<I: Indices<32>>(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.reverse_i16x16(a1), self.reverse_i16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i16x16::<K>(x),
+            self.rotate_lanes_left_i16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_i16x16(
+            self.select_i16x16(first, x, y),
+            self.select_i16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i16x32<const K: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i16x16::<K>(x),
+            self.rotate_lanes_right_i16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_i16x16(
+            self.select_i16x16(first, y, x),
+            self.select_i16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i16x32<const L: usize>(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let lanes = self.broadcast_lane_i16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_i16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.unzip_low_i16x16(a0, a1), self.unzip_low_i16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.unzip_high_i16x16(a0, a1),
+            self.unzip_high_i16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_add_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_add_i16x16(a0, b0),
+            self.saturating_add_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.saturating_sub_i16x16(a0, b0),
+            self.saturating_sub_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.abs_i16x16(a0), self.abs_i16x16(a1))
+    }
+    #[inline(always)]
+    fn neg_i16x32(self, a: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i16x16(self.neg_i16x16(a0), self.neg_i16x16(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_u16x16(self.abs_diff_i16x16(a0, b0), self.abs_diff_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(self.mul_high_i16x16(a0, b0), self.mul_high_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_q15_round_sat_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i16x16(
+            self.mul_q15_round_sat_i16x16(a0, b0),
+            self.mul_q15_round_sat_i16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn dot_i16_i16x32(self, a: i16x32<Self>, b: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        let (b0, b1) = self.split_i16x32(b);
+        self.combine_i32x8(self.dot_i16_i16x16(a0, b0), self.dot_i16_i16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i16x32(self, a: i16x32<Self>) -> (i16x16<Self>, i16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        self.widen_i16x16(self.split_i16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(self.narrow_i16x16(a0), self.narrow_i16x16(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i16x32(self, a: i16x32<Self>) -> i8x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x16(
+            self.narrow_saturating_i16x16(a0),
+            self.narrow_saturating_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i16x32(self, a: i16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i16x16(a0),
+            self.reinterpret_f32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i16x32(self, a: i16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i16x16(a0),
+            self.reinterpret_f64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i16x32(self, a: i16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_i16x16(a0),
+            self.reinterpret_i8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i16x32(self, a: i16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_i16x16(a0),
+            self.reinterpret_u8_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i16x32(self, a: i16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i16x16(a0),
+            self.reinterpret_u16_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i16x32(self, a: i16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i16x16(a0),
+            self.reinterpret_i32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i16x32(self, a: i16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i16x16(a0),
+            self.reinterpret_u32_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i16x32(self, a: i16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i16x16(a0),
+            self.reinterpret_i64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i16x32(self, a: i16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i16x16(a0),
+            self.reinterpret_u64_i16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_u16x32(self, a: u16) -> u16x32<Self> {
+        let half = self.splat_u16x16(a);
+        self.combine_u16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.not_u16x16(a0), self.not_u16x16(a1))
+    }
+    #[inline(always)]
+    fn add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.add_u16x16(a0, b0), self.add_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.sub_u16x16(a0, b0), self.sub_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_u16x16(a0, b0), self.mul_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.and_u16x16(a0, b0), self.and_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.or_u16x16(a0, b0), self.or_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.xor_u16x16(a0, b0), self.xor_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shl_u16x16(a0, b), self.shl_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u16x32(self, a: u16x32<Self>, b: u32) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.shr_u16x16(a0, b), self.shr_u16x16(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(16) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_eq_u16x16(a0, b0), self.simd_eq_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_lt_u16x16(a0, b0), self.simd_lt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_le_u16x16(a0, b0), self.simd_le_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_ge_u16x16(a0, b0), self.simd_ge_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_mask16x16(self.simd_gt_u16x16(a0, b0), self.simd_gt_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, _) = self.split_u16x32(a);
+        let (b0, _) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a0, b0), self.zip_high_u16x16(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (_, a1) = self.split_u16x32(a);
+        let (_, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.zip_low_u16x16(a1, b1), self.zip_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u16x32(self, a: mask16x32<Self>, b: u16x32<Self>, c: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        let (c0, c1) = self.split_u16x32(c);
+        self.combine_u16x16(
+            self.select_u16x16(a0, b0, c0),
+            self.select_u16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn min_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.min_u16x16(a0, b0), self.min_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.max_u16x16(a0, b0), self.max_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_add_u16x16(self.add_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_min_u16x16(self.min_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_max_u16x16(self.max_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_and_u16x16(self.and_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u16x32(self, a: u16x32<Self>) -> u16 {
+        let (a0, a1) = self.split_u16x32(a);
+        self.reduce_or_u16x16(self.or_u16x16(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u16x32<I: Indices<32>>(self, a: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u16x32<I: Indices<32>>(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u16x32(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u16x16(self.reverse_u16x16(a1), self.reverse_u16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u16x16::<K>(x),
+            self.rotate_lanes_left_u16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_u16x16(
+            self.select_u16x16(first, x, y),
+            self.select_u16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u16x32<const K: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u16x16::<K>(x),
+            self.rotate_lanes_right_u16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_u16x16(
+            self.select_u16x16(first, y, x),
+            self.select_u16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u16x32<const L: usize>(self, a: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let lanes = self.broadcast_lane_u16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_u16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.unzip_low_u16x16(a0, a1), self.unzip_low_u16x16(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.unzip_high_u16x16(a0, a1),
+            self.unzip_high_u16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_add_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_add_u16x16(a0, b0),
+            self.saturating_add_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn saturating_sub_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(
+            self.saturating_sub_u16x16(a0, b0),
+            self.saturating_sub_u16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn abs_diff_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.abs_diff_u16x16(a0, b0), self.abs_diff_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn avg_round_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.avg_round_u16x16(a0, b0), self.avg_round_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_high_u16x32(self, a: u16x32<Self>, b: u16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        let (b0, b1) = self.split_u16x32(b);
+        self.combine_u16x16(self.mul_high_u16x16(a0, b0), self.mul_high_u16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u16x32(self, a: u16x32<Self>) -> (u16x16<Self>, u16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u16x32(self, src: &[u16; 32usize]) -> u16x32<Self> {
+        unsafe {
+            let v0 = _mm_loadu_si128(src[0usize..].as_ptr() as *const __m128i);
+            let v1 = _mm_loadu_si128(src[8usize..].as_ptr() as *const __m128i);
+            let v2 = _mm_loadu_si128(src[16usize..].as_ptr() as *const __m128i);
+            let v3 = _mm_loadu_si128(src[24usize..].as_ptr() as *const __m128i);
+            let v02_lower = _mm_unpacklo_epi16(v0, v2);
+            let v13_lower = _mm_unpacklo_epi16(v1, v3);
+            let v02_upper = _mm_unpackhi_epi16(v0, v2);
+            let v13_upper = _mm_unpackhi_epi16(v1, v3);
+            let out0 = _mm_unpacklo_epi16(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi16(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi16(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi16(v02_upper, v13_upper);
+            let combined_lower = self.combine_u16x8(out0.simd_into(self), out1.simd_into(self));
+            let combined_upper = self.combine_u16x8(out2.simd_into(self), out3.simd_into(self));
+            self.combine_u16x16(combined_lower, combined_upper)
+        }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u16x32(self, a: u16x32<Self>, dest: &mut [u16; 32usize]) -> () {
+        let (lower, upper) = self.split_u16x32(a);
+        let (v0, v1) = self.split_u16x16(lower);
+        let (v2, v3) = self.split_u16x16(upper);
+        unsafe {
+            let v0 = v0.into();
+            let v1 = v1.into();
+            let v2 = v2.into();
+            let v3 = v3.into();
+            let v02_lower = _mm_unpacklo_epi16(v0, v2);
+            let v13_lower = _mm_unpacklo_epi16(v1, v3);
+            let v02_upper = _mm_unpackhi_epi16(v0, v2);
+            let v13_upper = _mm_unpackhi_epi16(v1, v3);
+            let out0 = _mm_unpacklo_epi16(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi16(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi16(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi16(v02_upper, v13_upper);
+            _mm_storeu_si128(dest[0usize..].as_mut_ptr() as *mut __m128i, out0);
+            _mm_storeu_si128(dest[8usize..].as_mut_ptr() as *mut __m128i, out1);
+            _mm_storeu_si128(dest[16usize..].as_mut_ptr() as *mut __m128i, out2);
+            _mm_storeu_si128(dest[24usize..].as_mut_ptr() as *mut __m128i, out3);
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        self.widen_u16x16(self.split_u16x32(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(self.narrow_u16x16(a0), self.narrow_u16x16(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u16x32(self, a: u16x32<Self>) -> u8x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x16(
+            self.narrow_saturating_u16x16(a0),
+            self.narrow_saturating_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u16x32(self, a: u16x32<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u16x16(a0),
+            self.reinterpret_f32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u16x32(self, a: u16x32<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u16x16(a0),
+            self.reinterpret_f64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u16x32(self, a: u16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_u16x16(a0),
+            self.reinterpret_i8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u16x32(self, a: u16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_u16x16(a0),
+            self.reinterpret_u8_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u16x32(self, a: u16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u16x16(a0),
+            self.reinterpret_i16_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u16x32(self, a: u16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u16x16(a0),
+            self.reinterpret_i32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u16x32(self, a: u16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u16x16(a0),
+            self.reinterpret_u32_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u16x32(self, a: u16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u16x16(a0),
+            self.reinterpret_i64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u16x32(self, a: u16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u16x16(a0),
+            self.reinterpret_u64_u16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_mask16x32(self, a: i16) -> mask16x32<Self> {
+        let half = self.splat_mask16x16(a);
+        self.combine_mask16x16(half, half)
+    }
+    #[inline(always)]
+    fn not_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.not_mask16x16(a0), self.not_mask16x16(a1))
+    }
+    #[inline(always)]
+    fn and_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.and_mask16x16(a0, b0), self.and_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.or_mask16x16(a0, b0), self.or_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(self.xor_mask16x16(a0, b0), self.xor_mask16x16(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask16x32(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+        c: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        let (c0, c1) = self.split_mask16x32(c);
+        self.combine_mask16x16(
+            self.select_mask16x16(a0, b0, c0),
+            self.select_mask16x16(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.simd_eq_mask16x16(a0, b0),
+            self.simd_eq_mask16x16(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn any_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.any_mask16x16(a0) || self.any_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn all_mask16x32(self, a: mask16x32<Self>) -> bool {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.all_mask16x16(a0) && self.all_mask16x16(a1)
+    }
+    #[inline(always)]
+    fn none_mask16x32(self, a: mask16x32<Self>) -> bool {
+        !self.any_mask16x32(a)
+    }
+    #[inline(always)]
+    fn count_true_mask16x32(self, a: mask16x32<Self>) -> usize {
+        self.to_bitmask_mask16x32(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask16x32(self, a: mask16x32<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask16x32(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask16x32(self, a: mask16x32<Self>) -> u64 {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.to_bitmask_mask16x16(a0) | (self.to_bitmask_mask16x16(a1) << 16)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask16x32(self, bits: u64) -> mask16x32<Self> {
+        self.combine_mask16x16(
+            self.from_bitmask_mask16x16(bits),
+            self.from_bitmask_mask16x16(bits >> 16),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask16x32<I: Indices<32>>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 32] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask16x32<I: Indices<32>>(
+        self,
+        a: mask16x32<Self>,
+        b: mask16x32<Self>,
+    ) -> mask16x32<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 64) };
+        let lanes: [_; 32] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 32 { a[index] } else { b[index - 32] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask16x32(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_mask16x16(self.reverse_mask16x16(a1), self.reverse_mask16x16(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask16x16::<K>(x),
+            self.rotate_lanes_left_mask16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (16 - K % 16)) - 1);
+        self.combine_mask16x16(
+            self.select_mask16x16(first, x, y),
+            self.select_mask16x16(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask16x32<const K: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (x, y) = if K % 32 < 16 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask16x16::<K>(x),
+            self.rotate_lanes_right_mask16x16::<K>(y),
+        );
+        let first = self.from_bitmask_mask16x16((1 << (K % 16)) - 1);
+        self.combine_mask16x16(
+            self.select_mask16x16(first, y, x),
+            self.select_mask16x16(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask16x32<const L: usize>(self, a: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let lanes = self.broadcast_lane_mask16x16::<L>(if L % 32 < 16 { a0 } else { a1 });
+        self.combine_mask16x16(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.unzip_low_mask16x16(a0, a1),
+            self.unzip_low_mask16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask16x32(self, a: mask16x32<Self>, b: mask16x32<Self>) -> mask16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        let (b0, b1) = self.split_mask16x32(b);
+        self.combine_mask16x16(
+            self.unzip_high_mask16x16(a0, a1),
+            self.unzip_high_mask16x16(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask16x32(self, a: mask16x32<Self>) -> (mask16x16<Self>, mask16x16<Self>) {
+        let mut b0 = [0; 16usize];
+        let mut b1 = [0; 16usize];
+        b0.copy_from_slice(&a.val[0..16usize]);
+        b1.copy_from_slice(&a.val[16usize..32usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask16x32(self, a: mask16x32<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask16x16(a0),
+            self.reinterpret_i8_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask16x32(self, a: mask16x32<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask16x16(a0),
+            self.reinterpret_u8_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask16x32(self, a: mask16x32<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask16x16(a0),
+            self.reinterpret_i16_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask16x32(self, a: mask16x32<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask16x16(a0),
+            self.reinterpret_u16_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask16x32(self, a: mask16x32<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask16x16(a0),
+            self.reinterpret_i32_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask16x32(self, a: mask16x32<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask16x16(a0),
+            self.reinterpret_u32_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask16x32(self, a: mask16x32<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask16x16(a0),
+            self.reinterpret_i64_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask16x32(self, a: mask16x32<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask16x32(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask16x16(a0),
+            self.reinterpret_u64_mask16x16(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_i32x16(self, a: i32) -> i32x16<Self> {
+        let half = self.splat_i32x8(a);
+        self.combine_i32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.not_i32x8(a0), self.not_i32x8(a1))
+    }
+    #[inline(always)]
+    fn add_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.add_i32x8(a0, b0), self.add_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.sub_i32x8(a0, b0), self.sub_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.mul_i32x8(a0, b0), self.mul_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.and_i32x8(a0, b0), self.and_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.or_i32x8(a0, b0), self.or_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.xor_i32x8(a0, b0), self.xor_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shl_i32x8(a0, b), self.shl_i32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i32x16(self, a: i32x16<Self>, b: u32) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.shr_i32x8(a0, b), self.shr_i32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u32).min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u32).min(31);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_eq_i32x8(a0, b0), self.simd_eq_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_lt_i32x8(a0, b0), self.simd_lt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_le_i32x8(a0, b0), self.simd_le_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_ge_i32x8(a0, b0), self.simd_ge_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_mask32x8(self.simd_gt_i32x8(a0, b0), self.simd_gt_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, _) = self.split_i32x16(a);
+        let (b0, _) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a0, b0), self.zip_high_i32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (_, a1) = self.split_i32x16(a);
+        let (_, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.zip_low_i32x8(a1, b1), self.zip_high_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i32x16(self, a: mask32x16<Self>, b: i32x16<Self>, c: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        let (c0, c1) = self.split_i32x16(c);
+        self.combine_i32x8(self.select_i32x8(a0, b0, c0), self.select_i32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.min_i32x8(a0, b0), self.min_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.max_i32x8(a0, b0), self.max_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_add_i32x8(self.add_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_min_i32x8(self.min_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_max_i32x8(self.max_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_and_i32x8(self.and_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i32x16(self, a: i32x16<Self>) -> i32 {
+        let (a0, a1) = self.split_i32x16(a);
+        self.reduce_or_i32x8(self.or_i32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i32x16<I: Indices<16>>(self, a: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i32x16<I: Indices<16>>(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.reverse_i32x8(a1), self.reverse_i32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i32x8::<K>(x),
+            self.rotate_lanes_left_i32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_i32x8(
+            self.select_i32x8(first, x, y),
+            self.select_i32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i32x16<const K: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i32x8::<K>(x),
+            self.rotate_lanes_right_i32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_i32x8(
+            self.select_i32x8(first, y, x),
+            self.select_i32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i32x16<const L: usize>(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let lanes = self.broadcast_lane_i32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_i32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_low_i32x8(a0, a1), self.unzip_low_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_i32x8(self.unzip_high_i32x8(a0, a1), self.unzip_high_i32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.abs_i32x8(a0), self.abs_i32x8(a1))
+    }
+    #[inline(always)]
+    fn neg_i32x16(self, a: i32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i32x8(self.neg_i32x8(a0), self.neg_i32x8(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i32x16(self, a: i32x16<Self>, b: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        let (b0, b1) = self.split_i32x16(b);
+        self.combine_u32x8(self.abs_diff_i32x8(a0, b0), self.abs_diff_i32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i32x16(self, a: i32x16<Self>) -> (i32x8<Self>, i32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn widen_low_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        self.widen_i32x8(self.split_i32x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(self.narrow_i32x8(a0), self.narrow_i32x8(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i32x16(self, a: i32x16<Self>) -> i16x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x8(
+            self.narrow_saturating_i32x8(a0),
+            self.narrow_saturating_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i32x8(a0),
+            self.reinterpret_f32_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i32x16(self, a: i32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i32x8(a0),
+            self.reinterpret_f64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i32x16(self, a: i32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_i32x8(a0), self.reinterpret_i8_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i32x16(self, a: i32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_i32x8(a0), self.reinterpret_u8_i32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i32x16(self, a: i32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i32x8(a0),
+            self.reinterpret_i16_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i32x16(self, a: i32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i32x8(a0),
+            self.reinterpret_u16_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i32x16(self, a: i32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i32x8(a0),
+            self.reinterpret_u32_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_i32x16(self, a: i32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_i32x8(a0),
+            self.reinterpret_i64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i32x16(self, a: i32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i32x8(a0),
+            self.reinterpret_u64_i32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i32x16(self, a: i32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_i32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i32x16(self, a: i32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i32x16(a);
+        self.combine_f32x8(self.cvt_f32_i32x8(a0), self.cvt_f32_i32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_u32x16(self, a: u32) -> u32x16<Self> {
+        let half = self.splat_u32x8(a);
+        self.combine_u32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.not_u32x8(a0), self.not_u32x8(a1))
+    }
+    #[inline(always)]
+    fn add_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.add_u32x8(a0, b0), self.add_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.sub_u32x8(a0, b0), self.sub_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.mul_u32x8(a0, b0), self.mul_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.and_u32x8(a0, b0), self.and_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.or_u32x8(a0, b0), self.or_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.xor_u32x8(a0, b0), self.xor_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shl_u32x8(a0, b), self.shl_u32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u32x16(self, a: u32x16<Self>, b: u32) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.shr_u32x8(a0, b), self.shr_u32x8(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(32)).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_eq_u32x8(a0, b0), self.simd_eq_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_lt_u32x8(a0, b0), self.simd_lt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_le_u32x8(a0, b0), self.simd_le_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_ge_u32x8(a0, b0), self.simd_ge_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_mask32x8(self.simd_gt_u32x8(a0, b0), self.simd_gt_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, _) = self.split_u32x16(a);
+        let (b0, _) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a0, b0), self.zip_high_u32x8(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (_, a1) = self.split_u32x16(a);
+        let (_, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.zip_low_u32x8(a1, b1), self.zip_high_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u32x16(self, a: mask32x16<Self>, b: u32x16<Self>, c: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        let (c0, c1) = self.split_u32x16(c);
+        self.combine_u32x8(self.select_u32x8(a0, b0, c0), self.select_u32x8(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.min_u32x8(a0, b0), self.min_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.max_u32x8(a0, b0), self.max_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_add_u32x8(self.add_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_min_u32x8(self.min_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_max_u32x8(self.max_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_and_u32x8(self.and_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u32x16(self, a: u32x16<Self>) -> u32 {
+        let (a0, a1) = self.split_u32x16(a);
+        self.reduce_or_u32x8(self.or_u32x8(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u32x16<I: Indices<16>>(self, a: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u32x16<I: Indices<16>>(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u32x16(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u32x8(self.reverse_u32x8(a1), self.reverse_u32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u32x8::<K>(x),
+            self.rotate_lanes_left_u32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_u32x8(
+            self.select_u32x8(first, x, y),
+            self.select_u32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u32x16<const K: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u32x8::<K>(x),
+            self.rotate_lanes_right_u32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_u32x8(
+            self.select_u32x8(first, y, x),
+            self.select_u32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u32x16<const L: usize>(self, a: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let lanes = self.broadcast_lane_u32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_u32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_low_u32x8(a0, a1), self.unzip_low_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.unzip_high_u32x8(a0, a1), self.unzip_high_u32x8(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u32x16(self, a: u32x16<Self>, b: u32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        let (b0, b1) = self.split_u32x16(b);
+        self.combine_u32x8(self.abs_diff_u32x8(a0, b0), self.abs_diff_u32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u32x16(self, a: u32x16<Self>) -> (u32x8<Self>, u32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn load_interleaved_128_u32x16(self, src: &[u32; 16usize]) -> u32x16<Self> {
+        unsafe {
+            let v0 = _mm_loadu_si128(src[0usize..].as_ptr() as *const __m128i);
+            let v1 = _mm_loadu_si128(src[4usize..].as_ptr() as *const __m128i);
+            let v2 = _mm_loadu_si128(src[8usize..].as_ptr() as *const __m128i);
+            let v3 = _mm_loadu_si128(src[12usize..].as_ptr() as *const __m128i);
+            let v02_lower = _mm_unpacklo_epi32(v0, v2);
+            let v13_lower = _mm_unpacklo_epi32(v1, v3);
+            let v02_upper = _mm_unpackhi_epi32(v0, v2);
+            let v13_upper = _mm_unpackhi_epi32(v1, v3);
+            let out0 = _mm_unpacklo_epi32(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi32(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi32(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi32(v02_upper, v13_upper);
+            let combined_lower = self.combine_u32x4(out0.simd_into(self), out1.simd_into(self));
+            let combined_upper = self.combine_u32x4(out2.simd_into(self), out3.simd_into(self));
+            self.combine_u32x8(combined_lower, combined_upper)
+        }
+    }
+    #[inline(always)]
+    fn store_interleaved_128_u32x16(self, a: u32x16<Self>, dest: &mut [u32; 16usize]) -> () {
+        let (lower, upper) = self.split_u32x16(a);
+        let (v0, v1) = self.split_u32x8(lower);
+        let (v2, v3) = self.split_u32x8(upper);
+        unsafe {
+            let v0 = v0.into();
+            let v1 = v1.into();
+            let v2 = v2.into();
+            let v3 = v3.into();
+            let v02_lower = _mm_unpacklo_epi32(v0, v2);
+            let v13_lower = _mm_unpacklo_epi32(v1, v3);
+            let v02_upper = _mm_unpackhi_epi32(v0, v2);
+            let v13_upper = _mm_unpackhi_epi32(v1, v3);
+            let out0 = _mm_unpacklo_epi32(v02_lower, v13_lower);
+            let out1 = _mm_unpackhi_epi32(v02_lower, v13_lower);
+            let out2 = _mm_unpacklo_epi32(v02_upper, v13_upper);
+            let out3 = _mm_unpackhi_epi32(v02_upper, v13_upper);
+            _mm_storeu_si128(dest[0usize..].as_mut_ptr() as *mut __m128i, out0);
+            _mm_storeu_si128(dest[4usize..].as_mut_ptr() as *mut __m128i, out1);
+            _mm_storeu_si128(dest[8usize..].as_mut_ptr() as *mut __m128i, out2);
+            _mm_storeu_si128(dest[12usize..].as_mut_ptr() as *mut __m128i, out3);
+        }
+    }
+    #[inline(always)]
+    fn widen_low_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).0)
+    }
+    #[inline(always)]
+    fn widen_high_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        self.widen_u32x8(self.split_u32x16(a).1)
+    }
+    #[inline(always)]
+    fn narrow_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(self.narrow_u32x8(a0), self.narrow_u32x8(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u32x16(self, a: u32x16<Self>) -> u16x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x8(
+            self.narrow_saturating_u32x8(a0),
+            self.narrow_saturating_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u32x8(a0),
+            self.reinterpret_f32_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u32x16(self, a: u32x16<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u32x8(a0),
+            self.reinterpret_f64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u32x16(self, a: u32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i8x32(self.reinterpret_i8_u32x8(a0), self.reinterpret_i8_u32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u32x16(self, a: u32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u8x32(self.reinterpret_u8_u32x8(a0), self.reinterpret_u8_u32x8(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u32x16(self, a: u32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u32x8(a0),
+            self.reinterpret_i16_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u32x16(self, a: u32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u32x8(a0),
+            self.reinterpret_u16_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u32x16(self, a: u32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u32x8(a0),
+            self.reinterpret_i32_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u32x16(self, a: u32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u32x8(a0),
+            self.reinterpret_i64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_u32x16(self, a: u32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_u32x8(a0),
+            self.reinterpret_u64_u32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u32x16(self, a: u32x16<Self>) -> f16x16<Self> {
+        self.cvt_f16_f32x16(self.cvt_f32_u32x16(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u32x16(self, a: u32x16<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u32x16(a);
+        self.combine_f32x8(self.cvt_f32_u32x8(a0), self.cvt_f32_u32x8(a1))
+    }
+    #[inline(always)]
+    fn splat_mask32x16(self, a: i32) -> mask32x16<Self> {
+        let half = self.splat_mask32x8(a);
+        self.combine_mask32x8(half, half)
+    }
+    #[inline(always)]
+    fn not_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.not_mask32x8(a0), self.not_mask32x8(a1))
+    }
+    #[inline(always)]
+    fn and_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.and_mask32x8(a0, b0), self.and_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.or_mask32x8(a0, b0), self.or_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.xor_mask32x8(a0, b0), self.xor_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask32x16(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+        c: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        let (c0, c1) = self.split_mask32x16(c);
+        self.combine_mask32x8(
+            self.select_mask32x8(a0, b0, c0),
+            self.select_mask32x8(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(self.simd_eq_mask32x8(a0, b0), self.simd_eq_mask32x8(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.any_mask32x8(a0) || self.any_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn all_mask32x16(self, a: mask32x16<Self>) -> bool {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.all_mask32x8(a0) && self.all_mask32x8(a1)
+    }
+    #[inline(always)]
+    fn none_mask32x16(self, a: mask32x16<Self>) -> bool {
+        !self.any_mask32x16(a)
+    }
+    #[inline(always)]
+    fn count_true_mask32x16(self, a: mask32x16<Self>) -> usize {
+        self.to_bitmask_mask32x16(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask32x16(self, a: mask32x16<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask32x16(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask32x16(self, a: mask32x16<Self>) -> u64 {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.to_bitmask_mask32x8(a0) | (self.to_bitmask_mask32x8(a1) << 8)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask32x16(self, bits: u64) -> mask32x16<Self> {
+        self.combine_mask32x8(
+            self.from_bitmask_mask32x8(bits),
+            self.from_bitmask_mask32x8(bits >> 8),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask32x16<I: Indices<16>>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 16] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask32x16<I: Indices<16>>(
+        self,
+        a: mask32x16<Self>,
+        b: mask32x16<Self>,
+    ) -> mask32x16<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 32) };
+        let lanes: [_; 16] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 16 { a[index] } else { b[index - 16] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask32x16(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_mask32x8(self.reverse_mask32x8(a1), self.reverse_mask32x8(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask32x8::<K>(x),
+            self.rotate_lanes_left_mask32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (8 - K % 8)) - 1);
+        self.combine_mask32x8(
+            self.select_mask32x8(first, x, y),
+            self.select_mask32x8(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask32x16<const K: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (x, y) = if K % 16 < 8 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask32x8::<K>(x),
+            self.rotate_lanes_right_mask32x8::<K>(y),
+        );
+        let first = self.from_bitmask_mask32x8((1 << (K % 8)) - 1);
+        self.combine_mask32x8(
+            self.select_mask32x8(first, y, x),
+            self.select_mask32x8(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask32x16<const L: usize>(self, a: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let lanes = self.broadcast_lane_mask32x8::<L>(if L % 16 < 8 { a0 } else { a1 });
+        self.combine_mask32x8(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(
+            self.unzip_low_mask32x8(a0, a1),
+            self.unzip_low_mask32x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask32x16(self, a: mask32x16<Self>, b: mask32x16<Self>) -> mask32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        let (b0, b1) = self.split_mask32x16(b);
+        self.combine_mask32x8(
+            self.unzip_high_mask32x8(a0, a1),
+            self.unzip_high_mask32x8(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask32x16(self, a: mask32x16<Self>) -> (mask32x8<Self>, mask32x8<Self>) {
+        let mut b0 = [0; 8usize];
+        let mut b1 = [0; 8usize];
+        b0.copy_from_slice(&a.val[0..8usize]);
+        b1.copy_from_slice(&a.val[8usize..16usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask32x16(self, a: mask32x16<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask32x8(a0),
+            self.reinterpret_i8_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask32x16(self, a: mask32x16<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask32x8(a0),
+            self.reinterpret_u8_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask32x16(self, a: mask32x16<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask32x8(a0),
+            self.reinterpret_i16_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask32x16(self, a: mask32x16<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask32x8(a0),
+            self.reinterpret_u16_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask32x16(self, a: mask32x16<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask32x8(a0),
+            self.reinterpret_i32_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask32x16(self, a: mask32x16<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask32x8(a0),
+            self.reinterpret_u32_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask32x16(self, a: mask32x16<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask32x8(a0),
+            self.reinterpret_i64_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask32x16(self, a: mask32x16<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask32x16(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask32x8(a0),
+            self.reinterpret_u64_mask32x8(a1),
+        )
+    }
+    #[inline(always)]
+    fn splat_f64x8(self, a: f64) -> f64x8<Self> {
+        let half = self.splat_f64x4(a);
+        self.combine_f64x4(half, half)
+    }
+    #[inline(always)]
+    fn abs_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.abs_f64x4(a0), self.abs_f64x4(a1))
+    }
+    #[inline(always)]
+    fn neg_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.neg_f64x4(a0), self.neg_f64x4(a1))
+    }
+    #[inline(always)]
+    fn sqrt_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.sqrt_f64x4(a0), self.sqrt_f64x4(a1))
+    }
+    #[inline(always)]
+    fn recip_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_approx_f64x4(a0), self.recip_approx_f64x4(a1))
+    }
+    #[inline(always)]
+    fn rsqrt_approx_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_approx_f64x4(a0), self.rsqrt_approx_f64x4(a1))
+    }
+    #[inline(always)]
+    fn recip_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.recip_f64x4(a0, steps), self.recip_f64x4(a1, steps))
+    }
+    #[inline(always)]
+    fn rsqrt_f64x8(self, a: f64x8<Self>, steps: u32) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.rsqrt_f64x4(a0, steps), self.rsqrt_f64x4(a1, steps))
+    }
+    #[inline(always)]
+    fn add_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.add_f64x4(a0, b0), self.add_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.sub_f64x4(a0, b0), self.sub_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.mul_f64x4(a0, b0), self.mul_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn div_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.div_f64x4(a0, b0), self.div_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn copysign_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.copysign_f64x4(a0, b0), self.copysign_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_eq_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_eq_f64x4(a0, b0), self.simd_eq_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ne_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ne_f64x4(a0, b0), self.simd_ne_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_lt_f64x4(a0, b0), self.simd_lt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_le_f64x4(a0, b0), self.simd_le_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_ge_f64x4(a0, b0), self.simd_ge_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_mask64x4(self.simd_gt_f64x4(a0, b0), self.simd_gt_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn is_nan_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_ne_f64x8(a, a)
+    }
+    #[inline(always)]
+    fn is_finite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_infinite_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_eq_f64x8(self.abs_f64x8(a), self.splat_f64x8(f64::INFINITY))
+    }
+    #[inline(always)]
+    fn is_subnormal_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        let abs = self.abs_f64x8(a);
+        self.and_mask64x8(
+            self.simd_lt_f64x8(abs, self.splat_f64x8(f64::MIN_POSITIVE)),
+            self.simd_gt_f64x8(abs, self.splat_f64x8(0.0)),
+        )
+    }
+    #[inline(always)]
+    fn is_sign_negative_f64x8(self, a: f64x8<Self>) -> mask64x8<Self> {
+        self.simd_lt_f64x8(
+            self.copysign_f64x8(self.splat_f64x8(1.0), a),
+            self.splat_f64x8(0.0),
+        )
+    }
+    #[inline(always)]
+    fn zip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, _) = self.split_f64x8(a);
+        let (b0, _) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a0, b0), self.zip_high_f64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (_, a1) = self.split_f64x8(a);
+        let (_, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.zip_low_f64x4(a1, b1), self.zip_high_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.max_f64x4(a0, b0), self.max_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.max_precise_f64x4(a0, b0),
+            self.max_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn min_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.min_f64x4(a0, b0), self.min_f64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn min_precise_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(
+            self.min_precise_f64x4(a0, b0),
+            self.min_precise_f64x4(a1, b1),
+        )
+    }
+    #[inline(always)]
+    fn madd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.madd_f64x4(a0, b0, c0), self.madd_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn msub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.msub_f64x4(a0, b0, c0), self.msub_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmadd_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmadd_f64x4(a0, b0, c0), self.nmadd_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn nmsub_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.nmsub_f64x4(a0, b0, c0), self.nmsub_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn mul_add_fused_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fused_f64x4(a0, b0, c0),
+            self.mul_add_fused_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn mul_add_fast_f64x8(self, a: f64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(
+            self.mul_add_fast_f64x4(a0, b0, c0),
+            self.mul_add_fast_f64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn floor_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.floor_f64x4(a0), self.floor_f64x4(a1))
+    }
+    #[inline(always)]
+    fn ceil_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.ceil_f64x4(a0), self.ceil_f64x4(a1))
+    }
+    #[inline(always)]
+    fn round_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.round_f64x4(a0), self.round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn round_ties_even_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(
+            self.round_ties_even_f64x4(a0),
+            self.round_ties_even_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn fract_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.fract_f64x4(a0), self.fract_f64x4(a1))
+    }
+    #[inline(always)]
+    fn trunc_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.trunc_f64x4(a0), self.trunc_f64x4(a1))
+    }
+    #[inline(always)]
+    fn select_f64x8(self, a: mask64x8<Self>, b: f64x8<Self>, c: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        let (c0, c1) = self.split_f64x8(c);
+        self.combine_f64x4(self.select_f64x4(a0, b0, c0), self.select_f64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn reduce_add_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        self.reduce_add_f64x4(a0) + self.reduce_add_f64x4(a1)
+    }
+    #[inline(always)]
+    fn reduce_min_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_min_f64x4(self.min_precise_f64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_f64x8(self, a: f64x8<Self>) -> f64 {
+        let (a0, a1) = self.split_f64x8(a);
+        let a1 = self.select_f64x4(self.is_nan_f64x4(a1), a0, a1);
+        self.reduce_max_f64x4(self.max_precise_f64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_f64x8<I: Indices<8>>(self, a: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_f64x8<I: Indices<8>>(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_f64x8(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f64x4(self.reverse_f64x4(a1), self.reverse_f64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_f64x4::<K>(x),
+            self.rotate_lanes_left_f64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_f64x4(
+            self.select_f64x4(first, x, y),
+            self.select_f64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_f64x8<const K: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_f64x4::<K>(x),
+            self.rotate_lanes_right_f64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_f64x4(
+            self.select_f64x4(first, y, x),
+            self.select_f64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_f64x8<const L: usize>(self, a: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let lanes = self.broadcast_lane_f64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_f64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_low_f64x4(a0, a1), self.unzip_low_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_f64x8(self, a: f64x8<Self>, b: f64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        let (b0, b1) = self.split_f64x8(b);
+        self.combine_f64x4(self.unzip_high_f64x4(a0, a1), self.unzip_high_f64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn split_f64x8(self, a: f64x8<Self>) -> (f64x4<Self>, f64x4<Self>) {
+        let mut b0 = [0.0; 4usize];
+        let mut b1 = [0.0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_f32_f64x8(self, a: f64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_f64x4(a0),
+            self.reinterpret_f32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_f64x8(self, a: f64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_f64x4(a0), self.reinterpret_i8_f64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_f64x8(self, a: f64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_f64x4(a0), self.reinterpret_u8_f64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_f64x8(self, a: f64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_f64x4(a0),
+            self.reinterpret_i16_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_f64x8(self, a: f64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_f64x4(a0),
+            self.reinterpret_u16_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_f64x8(self, a: f64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_f64x4(a0),
+            self.reinterpret_i32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_f64x8(self, a: f64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_f64x4(a0),
+            self.reinterpret_u32_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_f64x4(a0),
+            self.reinterpret_i64_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_f64x4(a0),
+            self.reinterpret_u64_f64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_f64x8(self, a: f64x8<Self>) -> f16x8<Self> {
+        a.val.map(f16::from_f64_const).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f32_f64x8(self, a: f64x8<Self>) -> f32x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_f32x4(self.cvt_f32_f64x4(a0), self.cvt_f32_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u8_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u8_round_f64x8(self, a: f64x8<Self>) -> u8x8<Self> {
+        self.narrow_saturating_u16x8(self.cvt_u16_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i16_round_f64x8(self, a: f64x8<Self>) -> i16x8<Self> {
+        self.narrow_saturating_i32x8(self.cvt_i32_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u16_round_f64x8(self, a: f64x8<Self>) -> u16x8<Self> {
+        self.narrow_saturating_u32x8(self.cvt_u32_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i32_round_f64x8(self, a: f64x8<Self>) -> i32x8<Self> {
+        self.narrow_saturating_i64x8(self.cvt_i64_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_u32_round_f64x8(self, a: f64x8<Self>) -> u32x8<Self> {
+        self.narrow_saturating_u64x8(self.cvt_u64_round_f64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_i64_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_f64x4(a0), self.cvt_i64_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_i64_round_f64x8(self, a: f64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_i64x4(self.cvt_i64_round_f64x4(a0), self.cvt_i64_round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_f64x4(a0), self.cvt_u64_f64x4(a1))
+    }
+    #[inline(always)]
+    fn cvt_u64_round_f64x8(self, a: f64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_f64x8(a);
+        self.combine_u64x4(self.cvt_u64_round_f64x4(a0), self.cvt_u64_round_f64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_i64x8(self, a: i64) -> i64x8<Self> {
+        let half = self.splat_i64x4(a);
+        self.combine_i64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.not_i64x4(a0), self.not_i64x4(a1))
+    }
+    #[inline(always)]
+    fn add_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.add_i64x4(a0, b0), self.add_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.sub_i64x4(a0, b0), self.sub_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.mul_i64x4(a0, b0), self.mul_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.and_i64x4(a0, b0), self.and_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.or_i64x4(a0, b0), self.or_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.xor_i64x4(a0, b0), self.xor_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shl_i64x4(a0, b), self.shl_i64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_i64x8(self, a: i64x8<Self>, b: u32) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.shr_i64x4(a0, b), self.shr_i64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl((shift as u64).min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x >> (shift as u64).min(63);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_eq_i64x4(a0, b0), self.simd_eq_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_lt_i64x4(a0, b0), self.simd_lt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_le_i64x4(a0, b0), self.simd_le_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_ge_i64x4(a0, b0), self.simd_ge_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_mask64x4(self.simd_gt_i64x4(a0, b0), self.simd_gt_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, _) = self.split_i64x8(a);
+        let (b0, _) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a0, b0), self.zip_high_i64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (_, a1) = self.split_i64x8(a);
+        let (_, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.zip_low_i64x4(a1, b1), self.zip_high_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_i64x8(self, a: mask64x8<Self>, b: i64x8<Self>, c: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        let (c0, c1) = self.split_i64x8(c);
+        self.combine_i64x4(self.select_i64x4(a0, b0, c0), self.select_i64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.min_i64x4(a0, b0), self.min_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.max_i64x4(a0, b0), self.max_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_add_i64x4(self.add_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_min_i64x4(self.min_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_max_i64x4(self.max_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_and_i64x4(self.and_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_i64x8(self, a: i64x8<Self>) -> i64 {
+        let (a0, a1) = self.split_i64x8(a);
+        self.reduce_or_i64x4(self.or_i64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_i64x8<I: Indices<8>>(self, a: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_i64x8<I: Indices<8>>(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.reverse_i64x4(a1), self.reverse_i64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_i64x4::<K>(x),
+            self.rotate_lanes_left_i64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_i64x4(
+            self.select_i64x4(first, x, y),
+            self.select_i64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_i64x8<const K: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_i64x4::<K>(x),
+            self.rotate_lanes_right_i64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_i64x4(
+            self.select_i64x4(first, y, x),
+            self.select_i64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_i64x8<const L: usize>(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let lanes = self.broadcast_lane_i64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_i64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_low_i64x4(a0, a1), self.unzip_low_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_i64x4(self.unzip_high_i64x4(a0, a1), self.unzip_high_i64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.abs_i64x4(a0), self.abs_i64x4(a1))
+    }
+    #[inline(always)]
+    fn neg_i64x8(self, a: i64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i64x4(self.neg_i64x4(a0), self.neg_i64x4(a1))
+    }
+    #[inline(always)]
+    fn abs_diff_i64x8(self, a: i64x8<Self>, b: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        let (b0, b1) = self.split_i64x8(b);
+        self.combine_u64x4(self.abs_diff_i64x4(a0, b0), self.abs_diff_i64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn split_i64x8(self, a: i64x8<Self>) -> (i64x4<Self>, i64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(self.narrow_i64x4(a0), self.narrow_i64x4(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_i64x8(self, a: i64x8<Self>) -> i32x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x4(
+            self.narrow_saturating_i64x4(a0),
+            self.narrow_saturating_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_i64x8(self, a: i64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_i64x4(a0),
+            self.reinterpret_f32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_i64x4(a0),
+            self.reinterpret_f64_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_i64x8(self, a: i64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_i64x4(a0), self.reinterpret_i8_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_i64x8(self, a: i64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_i64x4(a0), self.reinterpret_u8_i64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_i64x8(self, a: i64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_i64x4(a0),
+            self.reinterpret_i16_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_i64x8(self, a: i64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_i64x4(a0),
+            self.reinterpret_u16_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_i64x8(self, a: i64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_i64x4(a0),
+            self.reinterpret_i32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_i64x8(self, a: i64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_i64x4(a0),
+            self.reinterpret_u32_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_i64x8(self, a: i64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_i64x4(a0),
+            self.reinterpret_u64_i64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_i64x8(self, a: i64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_i64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_i64x8(self, a: i64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_i64x8(self, a: i64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_i64x8(a);
+        self.combine_f64x4(self.cvt_f64_i64x4(a0), self.cvt_f64_i64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_u64x8(self, a: u64) -> u64x8<Self> {
+        let half = self.splat_u64x4(a);
+        self.combine_u64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.not_u64x4(a0), self.not_u64x4(a1))
+    }
+    #[inline(always)]
+    fn add_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.add_u64x4(a0, b0), self.add_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn sub_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.sub_u64x4(a0, b0), self.sub_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn mul_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.mul_u64x4(a0, b0), self.mul_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn and_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.and_u64x4(a0, b0), self.and_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.or_u64x4(a0, b0), self.or_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.xor_u64x4(a0, b0), self.xor_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn shl_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shl_u64x4(a0, b), self.shl_u64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shr_u64x8(self, a: u64x8<Self>, b: u32) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.shr_u64x4(a0, b), self.shr_u64x4(a1, b))
+    }
+    #[inline(always)]
+    fn shl_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shl(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn shr_var_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let mut val = a.val;
+        for (lane, shift) in val.iter_mut().zip(b.val) {
+            let x = *lane;
+            *lane = x.checked_shr(shift.min(64) as u32).unwrap_or(0);
+        }
+        val.simd_into(self)
+    }
+    #[inline(always)]
+    fn simd_eq_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_eq_u64x4(a0, b0), self.simd_eq_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_lt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_lt_u64x4(a0, b0), self.simd_lt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_le_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_le_u64x4(a0, b0), self.simd_le_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_ge_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_ge_u64x4(a0, b0), self.simd_ge_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn simd_gt_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_mask64x4(self.simd_gt_u64x4(a0, b0), self.simd_gt_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn zip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, _) = self.split_u64x8(a);
+        let (b0, _) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a0, b0), self.zip_high_u64x4(a0, b0))
+    }
+    #[inline(always)]
+    fn zip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (_, a1) = self.split_u64x8(a);
+        let (_, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.zip_low_u64x4(a1, b1), self.zip_high_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_u64x8(self, a: mask64x8<Self>, b: u64x8<Self>, c: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        let (c0, c1) = self.split_u64x8(c);
+        self.combine_u64x4(self.select_u64x4(a0, b0, c0), self.select_u64x4(a1, b1, c1))
+    }
+    #[inline(always)]
+    fn min_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.min_u64x4(a0, b0), self.min_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn max_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.max_u64x4(a0, b0), self.max_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn reduce_add_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_add_u64x4(self.add_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_min_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_min_u64x4(self.min_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_max_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_max_u64x4(self.max_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_and_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_and_u64x4(self.and_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn reduce_or_u64x8(self, a: u64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_u64x8(a);
+        self.reduce_or_u64x4(self.or_u64x4(a0, a1))
+    }
+    #[inline(always)]
+    fn shuffle_u64x8<I: Indices<8>>(self, a: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_u64x8<I: Indices<8>>(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_u64x8(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u64x4(self.reverse_u64x4(a1), self.reverse_u64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_u64x4::<K>(x),
+            self.rotate_lanes_left_u64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_u64x4(
+            self.select_u64x4(first, x, y),
+            self.select_u64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_u64x8<const K: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_u64x4::<K>(x),
+            self.rotate_lanes_right_u64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_u64x4(
+            self.select_u64x4(first, y, x),
+            self.select_u64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_u64x8<const L: usize>(self, a: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let lanes = self.broadcast_lane_u64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_u64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_low_u64x4(a0, a1), self.unzip_low_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn unzip_high_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.unzip_high_u64x4(a0, a1), self.unzip_high_u64x4(b0, b1))
+    }
+    #[inline(always)]
+    fn abs_diff_u64x8(self, a: u64x8<Self>, b: u64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        let (b0, b1) = self.split_u64x8(b);
+        self.combine_u64x4(self.abs_diff_u64x4(a0, b0), self.abs_diff_u64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn split_u64x8(self, a: u64x8<Self>) -> (u64x4<Self>, u64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn narrow_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(self.narrow_u64x4(a0), self.narrow_u64x4(a1))
+    }
+    #[inline(always)]
+    fn narrow_saturating_u64x8(self, a: u64x8<Self>) -> u32x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x4(
+            self.narrow_saturating_u64x4(a0),
+            self.narrow_saturating_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f32_u64x8(self, a: u64x8<Self>) -> f32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f32x8(
+            self.reinterpret_f32_u64x4(a0),
+            self.reinterpret_f32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(
+            self.reinterpret_f64_u64x4(a0),
+            self.reinterpret_f64_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i8_u64x8(self, a: u64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i8x32(self.reinterpret_i8_u64x4(a0), self.reinterpret_i8_u64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_u8_u64x8(self, a: u64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u8x32(self.reinterpret_u8_u64x4(a0), self.reinterpret_u8_u64x4(a1))
+    }
+    #[inline(always)]
+    fn reinterpret_i16_u64x8(self, a: u64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_u64x4(a0),
+            self.reinterpret_i16_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_u64x8(self, a: u64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_u64x4(a0),
+            self.reinterpret_u16_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_u64x8(self, a: u64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_u64x4(a0),
+            self.reinterpret_i32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_u64x8(self, a: u64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_u64x4(a0),
+            self.reinterpret_u32_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_u64x8(self, a: u64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_u64x4(a0),
+            self.reinterpret_i64_u64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn cvt_f16_u64x8(self, a: u64x8<Self>) -> f16x8<Self> {
+        self.cvt_f16_f32x8(self.cvt_f32_u64x8(a))
+    }
+    #[inline(always)]
+    fn cvt_f32_u64x8(self, a: u64x8<Self>) -> f32x8<Self> {
+        a.val.map(|x| x as f32).simd_into(self)
+    }
+    #[inline(always)]
+    fn cvt_f64_u64x8(self, a: u64x8<Self>) -> f64x8<Self> {
+        let (a0, a1) = self.split_u64x8(a);
+        self.combine_f64x4(self.cvt_f64_u64x4(a0), self.cvt_f64_u64x4(a1))
+    }
+    #[inline(always)]
+    fn splat_mask64x8(self, a: i64) -> mask64x8<Self> {
+        let half = self.splat_mask64x4(a);
+        self.combine_mask64x4(half, half)
+    }
+    #[inline(always)]
+    fn not_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.not_mask64x4(a0), self.not_mask64x4(a1))
+    }
+    #[inline(always)]
+    fn and_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.and_mask64x4(a0, b0), self.and_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn or_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.or_mask64x4(a0, b0), self.or_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn xor_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.xor_mask64x4(a0, b0), self.xor_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn select_mask64x8(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+        c: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        let (c0, c1) = self.split_mask64x8(c);
+        self.combine_mask64x4(
+            self.select_mask64x4(a0, b0, c0),
+            self.select_mask64x4(a1, b1, c1),
+        )
+    }
+    #[inline(always)]
+    fn simd_eq_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(self.simd_eq_mask64x4(a0, b0), self.simd_eq_mask64x4(a1, b1))
+    }
+    #[inline(always)]
+    fn any_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.any_mask64x4(a0) || self.any_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn all_mask64x8(self, a: mask64x8<Self>) -> bool {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.all_mask64x4(a0) && self.all_mask64x4(a1)
+    }
+    #[inline(always)]
+    fn none_mask64x8(self, a: mask64x8<Self>) -> bool {
+        !self.any_mask64x8(a)
+    }
+    #[inline(always)]
+    fn count_true_mask64x8(self, a: mask64x8<Self>) -> usize {
+        self.to_bitmask_mask64x8(a).count_ones() as usize
+    }
+    #[inline(always)]
+    fn first_true_mask64x8(self, a: mask64x8<Self>) -> Option<usize> {
+        let bits = self.to_bitmask_mask64x8(a);
+        if bits == 0 {
+            None
+        } else {
+            Some(bits.trailing_zeros() as usize)
+        }
+    }
+    #[inline(always)]
+    fn to_bitmask_mask64x8(self, a: mask64x8<Self>) -> u64 {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.to_bitmask_mask64x4(a0) | (self.to_bitmask_mask64x4(a1) << 4)
+    }
+    #[inline(always)]
+    fn from_bitmask_mask64x8(self, bits: u64) -> mask64x8<Self> {
+        self.combine_mask64x4(
+            self.from_bitmask_mask64x4(bits),
+            self.from_bitmask_mask64x4(bits >> 4),
+        )
+    }
+    #[inline(always)]
+    fn shuffle_mask64x8<I: Indices<8>>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 8) };
+        let lanes: [_; 8] = core::array::from_fn(|i| a[I::INDICES[i]]);
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn shuffle2_mask64x8<I: Indices<8>>(
+        self,
+        a: mask64x8<Self>,
+        b: mask64x8<Self>,
+    ) -> mask64x8<Self> {
+        const { crate::shuffle::check_indices(&I::INDICES, 16) };
+        let lanes: [_; 8] = core::array::from_fn(|i| {
+            let index = I::INDICES[i];
+            if index < 8 { a[index] } else { b[index - 8] }
+        });
+        lanes.simd_into(self)
+    }
+    #[inline(always)]
+    fn reverse_mask64x8(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_mask64x4(self.reverse_mask64x4(a1), self.reverse_mask64x4(a0))
+    }
+    #[inline(always)]
+    fn rotate_lanes_left_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_left_mask64x4::<K>(x),
+            self.rotate_lanes_left_mask64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (4 - K % 4)) - 1);
+        self.combine_mask64x4(
+            self.select_mask64x4(first, x, y),
+            self.select_mask64x4(first, y, x),
+        )
+    }
+    #[inline(always)]
+    fn rotate_lanes_right_mask64x8<const K: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (x, y) = if K % 8 < 4 { (a0, a1) } else { (a1, a0) };
+        let (x, y) = (
+            self.rotate_lanes_right_mask64x4::<K>(x),
+            self.rotate_lanes_right_mask64x4::<K>(y),
+        );
+        let first = self.from_bitmask_mask64x4((1 << (K % 4)) - 1);
+        self.combine_mask64x4(
+            self.select_mask64x4(first, y, x),
+            self.select_mask64x4(first, x, y),
+        )
+    }
+    #[inline(always)]
+    fn broadcast_lane_mask64x8<const L: usize>(self, a: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let lanes = self.broadcast_lane_mask64x4::<L>(if L % 8 < 4 { a0 } else { a1 });
+        self.combine_mask64x4(lanes, lanes)
+    }
+    #[inline(always)]
+    fn unzip_low_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(
+            self.unzip_low_mask64x4(a0, a1),
+            self.unzip_low_mask64x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn unzip_high_mask64x8(self, a: mask64x8<Self>, b: mask64x8<Self>) -> mask64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        let (b0, b1) = self.split_mask64x8(b);
+        self.combine_mask64x4(
+            self.unzip_high_mask64x4(a0, a1),
+            self.unzip_high_mask64x4(b0, b1),
+        )
+    }
+    #[inline(always)]
+    fn split_mask64x8(self, a: mask64x8<Self>) -> (mask64x4<Self>, mask64x4<Self>) {
+        let mut b0 = [0; 4usize];
+        let mut b1 = [0; 4usize];
+        b0.copy_from_slice(&a.val[0..4usize]);
+        b1.copy_from_slice(&a.val[4usize..8usize]);
+        (b0.simd_into(self), b1.simd_into(self))
+    }
+    #[inline(always)]
+    fn reinterpret_i8_mask64x8(self, a: mask64x8<Self>) -> i8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i8x32(
+            self.reinterpret_i8_mask64x4(a0),
+            self.reinterpret_i8_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u8_mask64x8(self, a: mask64x8<Self>) -> u8x64<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u8x32(
+            self.reinterpret_u8_mask64x4(a0),
+            self.reinterpret_u8_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i16_mask64x8(self, a: mask64x8<Self>) -> i16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i16x16(
+            self.reinterpret_i16_mask64x4(a0),
+            self.reinterpret_i16_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u16_mask64x8(self, a: mask64x8<Self>) -> u16x32<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u16x16(
+            self.reinterpret_u16_mask64x4(a0),
+            self.reinterpret_u16_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i32_mask64x8(self, a: mask64x8<Self>) -> i32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i32x8(
+            self.reinterpret_i32_mask64x4(a0),
+            self.reinterpret_i32_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u32_mask64x8(self, a: mask64x8<Self>) -> u32x16<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u32x8(
+            self.reinterpret_u32_mask64x4(a0),
+            self.reinterpret_u32_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_i64_mask64x8(self, a: mask64x8<Self>) -> i64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_i64x4(
+            self.reinterpret_i64_mask64x4(a0),
+            self.reinterpret_i64_mask64x4(a1),
+        )
+    }
+    #[inline(always)]
+    fn reinterpret_u64_mask64x8(self, a: mask64x8<Self>) -> u64x8<Self> {
+        let (a0, a1) = self.split_mask64x8(a);
+        self.combine_u64x4(
+            self.reinterpret_u64_mask64x4(a0),
+            self.reinterpret_u64_mask64x4(a1),
+        )
+    }
+}
+impl<S: Simd> SimdFrom<__m128, S> for f32x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(_mm_castps_si128(arch))) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x2<S>> for __m128 {
+    #[inline(always)]
+    fn from(value: f32x2<S>) -> Self {
+        unsafe { _mm_castsi128_ps(_mm_cvtsi64_si128(bytemuck::cast(value.val))) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u8x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u8x8<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask8x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask8x8<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u16x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x4<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u16x4<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask16x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x4<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask16x4<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for i32x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x2<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: i32x2<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask32x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(arch)) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x2<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask32x2<S>) -> Self {
+        unsafe { _mm_cvtsi64_si128(bytemuck::cast(value.val)) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for f16x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f16x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: f16x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for bf16x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<bf16x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: bf16x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128, S> for f32x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x4<S>> for __m128 {
+    #[inline(always)]
+    fn from(value: f32x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for i8x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i8x16<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: i8x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u8x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x16<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u8x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask8x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x16<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask8x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for i16x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i16x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: i16x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u16x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u16x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask16x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x8<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask16x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for i32x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x4<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: i32x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u32x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x4<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u32x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask32x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x4<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask32x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128d, S> for f64x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128d, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x2<S>> for __m128d {
+    #[inline(always)]
+    fn from(value: f64x2<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for i64x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i64x2<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: i64x2<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for u64x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u64x2<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: u64x2<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m128i, S> for mask64x2<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m128i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask64x2<S>> for __m128i {
+    #[inline(always)]
+    fn from(value: mask64x2<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for f16x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f16x16<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: f16x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for bf16x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<bf16x16<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: bf16x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256, S> for f32x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x8<S>> for __m256 {
+    #[inline(always)]
+    fn from(value: f32x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for i8x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i8x32<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: i8x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for u8x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x32<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: u8x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for mask8x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x32<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: mask8x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for i16x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i16x16<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: i16x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for u16x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x16<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: u16x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for mask16x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x16<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: mask16x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for i32x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x8<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: i32x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for u32x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x8<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: u32x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for mask32x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x8<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: mask32x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256d, S> for f64x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256d, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x4<S>> for __m256d {
+    #[inline(always)]
+    fn from(value: f64x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for i64x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i64x4<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: i64x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for u64x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u64x4<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: u64x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m256i, S> for mask64x4<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m256i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask64x4<S>> for __m256i {
+    #[inline(always)]
+    fn from(value: mask64x4<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512, S> for f32x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f32x16<S>> for __m512 {
+    #[inline(always)]
+    fn from(value: f32x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for i8x64<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i8x64<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: i8x64<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for u8x64<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u8x64<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: u8x64<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for mask8x64<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask8x64<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: mask8x64<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for i16x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i16x32<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: i16x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for u16x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u16x32<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: u16x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for mask16x32<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask16x32<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: mask16x32<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for i32x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i32x16<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: i32x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for u32x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u32x16<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: u32x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for mask32x16<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask32x16<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: mask32x16<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512d, S> for f64x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512d, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<f64x8<S>> for __m512d {
+    #[inline(always)]
+    fn from(value: f64x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for i64x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<i64x8<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: i64x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for u64x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<u64x8<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: u64x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
+impl<S: Simd> SimdFrom<__m512i, S> for mask64x8<S> {
+    #[inline(always)]
+    fn simd_from(arch: __m512i, simd: S) -> Self {
+        Self {
+            val: unsafe { core::mem::transmute(arch) },
+            simd,
+        }
+    }
+}
+impl<S: Simd> From<mask64x8<S>> for __m512i {
+    #[inline(always)]
+    fn from(value: mask64x8<S>) -> Self {
+        unsafe { core::mem::transmute(value.val) }
+    }
+}
@@ -32,6 +32,16 @@ impl f16 {
         f16(f32_to_f16_fallback(value))
     }
 
+    /// Constructs a 16-bit floating point value from a 64-bit floating point value.
+    ///
+    /// This operation is lossy, and rounds like [`from_f32_const`][Self::from_f32_const]. The
+    /// value is rounded once, so the result can differ from converting through [`f32`].
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_const(value: f64) -> f16 {
+        f16(f64_to_f16_fallback(value))
+    }
+
     #[cfg(target_arch = "aarch64")]
     #[target_feature(enable = "neon", enable = "fp16")]
     #[inline]
@@ -53,7 +63,7 @@ impl f16 {
     #[inline]
     #[must_use]
     pub fn from_f32(value: f32) -> f16 {
-        from_f32_const(value)
+        Self::from_f32_const(value)
     }
 
     /// Converts a [`f16`][`struct@f16`] into the underlying bit representation.
@@ -62,6 +72,128 @@ impl f16 {
     pub const fn to_bits(self) -> u16 {
         self.0
     }
+
+    /// Converts a [`f16`][`struct@f16`] value into an [`f32`] value.
+    ///
+    /// This function is identical to [`to_f32`][Self::to_f32] except it never uses hardware
+    /// intrinsics, which allows it to be `const`.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be represented exactly
+    /// in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub const fn to_f32_const(self) -> f32 {
+        f16_to_f32_fallback(self.0)
+    }
+
+    /// Converts a [`f16`][`struct@f16`] value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all 16-bit floating point values can be represented exactly
+    /// in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        self.to_f32_const()
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        self.to_f32_const() == other.to_f32_const()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<core::cmp::Ordering> {
+        self.to_f32_const().partial_cmp(&other.to_f32_const())
+    }
+}
+
+impl core::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_f32_const(), f)
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+impl bf16 {
+    /// Constructs a 16-bit brain floating point value from the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> bf16 {
+        bf16(bits)
+    }
+
+    /// Constructs a 16-bit brain floating point value from a 32-bit floating point value.
+    ///
+    /// This operation is lossy. The mantissa is rounded to the nearest representable value, with
+    /// ties to even. NaN values are preserved, and the exponent range is the same as [`f32`], so
+    /// there is no overflow or underflow beyond rounding.
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_const(value: f32) -> bf16 {
+        bf16(f32_to_bf16_fallback(value))
+    }
+
+    /// Constructs a 16-bit brain floating point value from a 32-bit floating point value.
+    ///
+    /// See [`from_f32_const`][Self::from_f32_const].
+    #[inline]
+    #[must_use]
+    pub fn from_f32(value: f32) -> bf16 {
+        Self::from_f32_const(value)
+    }
+
+    /// Converts a [`bf16`][`struct@bf16`] into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts a [`bf16`][`struct@bf16`] value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all 16-bit brain floating point values can be represented
+    /// exactly in 32-bit floating point.
+    #[inline]
+    #[must_use]
+    pub const fn to_f32_const(self) -> f32 {
+        bf16_to_f32_fallback(self.0)
+    }
+
+    /// Converts a [`bf16`][`struct@bf16`] value into an [`f32`] value.
+    ///
+    /// See [`to_f32_const`][Self::to_f32_const].
+    #[inline]
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        self.to_f32_const()
+    }
+}
+
+impl PartialEq for bf16 {
+    #[inline]
+    fn eq(&self, other: &bf16) -> bool {
+        self.to_f32_const() == other.to_f32_const()
+    }
+}
+
+impl PartialOrd for bf16 {
+    #[inline]
+    fn partial_cmp(&self, other: &bf16) -> Option<core::cmp::Ordering> {
+        self.to_f32_const().partial_cmp(&other.to_f32_const())
+    }
+}
+
+impl core::fmt::Debug for bf16 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.to_f32_const(), f)
+    }
 }
 
 /////////////// Fallbacks ////////////////
@@ -138,3 +270,135 @@ pub(crate) const fn f32_to_f16_fallback(value: f32) -> u16 {
         (half_sign | half_exp | half_man) as u16
     }
 }
+
+#[inline]
+pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
+    let val: u64 = value.to_bits();
+    // The low 32 bits of the mantissa are always rounded off, so keep only whether
+    // any of them are set, as the lowest bit of the rest. That bit is below the
+    // round bit, so it only breaks ties.
+    let x = (val >> 32) as u32 | (val as u32 != 0) as u32;
+
+    // Extract IEEE754 components
+    let sign = x & 0x8000_0000u32;
+    let exp = x & 0x7FF0_0000u32;
+    let man = x & 0x000F_FFFFu32;
+
+    // Check for all exponent bits being set, which is Infinity or NaN
+    if exp == 0x7FF0_0000u32 {
+        // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+        let nan_bit = if man == 0 { 0 } else { 0x0200u32 };
+        return ((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 10)) as u16;
+    }
+
+    // The number is normalized, start assembling half precision version
+    let half_sign = sign >> 16;
+    // Unbias the exponent, then bias for half precision
+    let unbiased_exp = ((exp >> 20) as i32) - 1023;
+    let half_exp = unbiased_exp + 15;
+
+    // Check for exponent overflow, return +infinity
+    if half_exp >= 0x1F {
+        return (half_sign | 0x7C00u32) as u16;
+    }
+
+    // Check for underflow
+    if half_exp <= 0 {
+        // Check mantissa for what we can do
+        if 10 - half_exp > 21 {
+            // No rounding possibility, so this is a full underflow, return signed zero
+            return half_sign as u16;
+        }
+        // Don't forget about hidden leading mantissa bit when assembling mantissa
+        let man = man | 0x0010_0000u32;
+        let mut half_man = man >> (11 - half_exp);
+        // Check for rounding (see comment above functions)
+        let round_bit = 1 << (10 - half_exp);
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            half_man += 1;
+        }
+        // No exponent for subnormals
+        return (half_sign | half_man) as u16;
+    }
+
+    // Rebias the exponent
+    let half_exp = (half_exp as u32) << 10;
+    let half_man = man >> 10;
+    // Check for rounding (see comment above functions)
+    let round_bit = 0x0000_0200u32;
+    if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+        // Round it
+        ((half_sign | half_exp | half_man) + 1) as u16
+    } else {
+        (half_sign | half_exp | half_man) as u16
+    }
+}
+
+#[inline]
+pub(crate) const fn f16_to_f32_fallback(i: u16) -> f32 {
+    // Check for signed zero
+    if i & 0x7FFFu16 == 0 {
+        return f32::from_bits((i as u32) << 16);
+    }
+
+    let half_sign = (i & 0x8000u16) as u32;
+    let half_exp = (i & 0x7C00u16) as u32;
+    let half_man = (i & 0x03FFu16) as u32;
+
+    // Check for an infinity or NaN when all exponent bits set
+    if half_exp == 0x7C00u32 {
+        // Check for signed infinity if mantissa is zero
+        if half_man == 0 {
+            return f32::from_bits((half_sign << 16) | 0x7F80_0000u32);
+        } else {
+            // NaN, keep current mantissa but also set most significiant mantissa bit
+            return f32::from_bits((half_sign << 16) | 0x7FC0_0000u32 | (half_man << 13));
+        }
+    }
+
+    // Calculate single-precision components with adjusted exponent
+    let sign = half_sign << 16;
+    // Unbias exponent
+    let unbiased_exp = ((half_exp as i32) >> 10) - 15;
+
+    // Check for subnormals, which will be normalized by adjusting exponent
+    if half_exp == 0 {
+        // Calculate how much to adjust the exponent by
+        let e = (half_man as u16).leading_zeros() - 6;
+
+        // Rebias and adjust exponent
+        let exp = (127 - 15 - e) << 23;
+        let man = (half_man << (14 + e)) & 0x7F_FF_FFu32;
+        return f32::from_bits(sign | exp | man);
+    }
+
+    // Rebias exponent for a normalized normal
+    let exp = ((unbiased_exp + 127) as u32) << 23;
+    let man = (half_man & 0x03FFu32) << 13;
+    f32::from_bits(sign | exp | man)
+}
+
+#[inline]
+pub(crate) const fn f32_to_bf16_fallback(value: f32) -> u16 {
+    let x: u32 = value.to_bits();
+
+    // Check for NaN
+    if x & 0x7FFF_FFFFu32 > 0x7F80_0000u32 {
+        // Keep high part of current mantissa but also set most significiant mantissa bit
+        return ((x >> 16) | 0x0040u32) as u16;
+    }
+
+    // Round and shift (see comment above functions)
+    let round_bit = 0x0000_8000u32;
+    if (x & round_bit) != 0 && (x & (3 * round_bit - 1)) != 0 {
+        (x >> 16) as u16 + 1
+    } else {
+        (x >> 16) as u16
+    }
+}
+
+#[inline]
+pub(crate) const fn bf16_to_f32_fallback(i: u16) -> f32 {
+    // bf16 is the high half of an f32, so widening is exact.
+    f32::from_bits((i as u32) << 16)
+}
@@ -11,31 +11,48 @@ mod impl_macros;
 
 mod generated;
 mod macros;
+mod math;
+mod shuffle;
 mod traits;
 
 pub use generated::*;
+pub use math::SimdMath;
+pub use shuffle::Indices;
 pub use traits::*;
 
-// For now, only bring in f16 on aarch64. We can also bring it in
-// on x86_64, but only Sapphire Rapids supports it.
+// The f16 type is the element of the `f16x8` and `f16x16` vector types on
+// all targets. Only the aarch64 `Fp16` level does arithmetic on it natively;
+// the other levels convert through f32.
+//
+// The bf16 type is the element of the `bf16x8` and `bf16x16` vector types,
+// which are for storage and conversion to f32 only.
 
-#[cfg(all(target_arch = "aarch64", feature = "half"))]
+#[cfg(feature = "half")]
 pub type f16 = half::f16;
-#[cfg(all(target_arch = "aarch64", not(feature = "half")))]
+#[cfg(feature = "half")]
+pub type bf16 = half::bf16;
+#[cfg(not(feature = "half"))]
 mod half_assed;
-#[cfg(all(target_arch = "aarch64", not(feature = "half")))]
-pub use half_assed::f16;
+#[cfg(not(feature = "half"))]
+pub use half_assed::{bf16, f16};
 
 #[cfg(all(not(feature = "libm"), not(feature = "std")))]
 compile_error!("fearless_simd requires either the `std` or `libm` feature");
 
 #[cfg(all(feature = "std", target_arch = "aarch64"))]
 pub mod aarch64 {
-    pub use crate::generated::Neon;
+    pub use crate::generated::{Fp16, Neon};
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub mod x86_64 {
+    pub use crate::generated::{Avx2, Avx512, Sse4_2};
 }
 
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 pub mod wasm32 {
+    #[cfg(target_feature = "relaxed-simd")]
+    pub use crate::generated::WasmRelaxedSimd;
     pub use crate::generated::WasmSimd128;
 }
 
@@ -45,17 +62,65 @@ pub enum Level {
     Fallback(Fallback),
     #[cfg(all(feature = "std", target_arch = "aarch64"))]
     Neon(Neon),
+    #[cfg(all(feature = "std", target_arch = "aarch64"))]
+    Fp16(Fp16),
     #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
     WasmSimd128(WasmSimd128),
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        target_feature = "relaxed-simd"
+    ))]
+    WasmRelaxedSimd(WasmRelaxedSimd),
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    Sse4_2(Sse4_2),
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    Avx2(Avx2),
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    Avx512(Avx512),
 }
 
 impl Level {
     pub fn new() -> Self {
+        #[cfg(all(feature = "std", target_arch = "aarch64"))]
+        if std::arch::is_aarch64_feature_detected!("fp16") {
+            return unsafe { Level::Fp16(Fp16::new_unchecked()) };
+        }
         #[cfg(all(feature = "std", target_arch = "aarch64"))]
         if std::arch::is_aarch64_feature_detected!("neon") {
             return unsafe { Level::Neon(Neon::new_unchecked()) };
         }
-        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        if std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512bw")
+            && std::arch::is_x86_feature_detected!("avx512cd")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+            && std::arch::is_x86_feature_detected!("avx512vl")
+        {
+            return unsafe { Level::Avx512(Avx512::new_unchecked()) };
+        }
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        if std::arch::is_x86_feature_detected!("avx2")
+            && std::arch::is_x86_feature_detected!("fma")
+            && std::arch::is_x86_feature_detected!("f16c")
+        {
+            return unsafe { Level::Avx2(Avx2::new_unchecked()) };
+        }
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        if std::arch::is_x86_feature_detected!("sse4.2") {
+            return unsafe { Level::Sse4_2(Sse4_2::new_unchecked()) };
+        }
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            target_feature = "relaxed-simd"
+        ))]
+        return Level::WasmRelaxedSimd(WasmRelaxedSimd::new_unchecked());
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(target_feature = "relaxed-simd")
+        ))]
         return Level::WasmSimd128(WasmSimd128::new_unchecked());
         #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
         Self::fallback()
@@ -66,6 +131,20 @@ impl Level {
     pub fn as_neon(self) -> Option<Neon> {
         match self {
             Level::Neon(neon) => Some(neon),
+            // Fp16 is a superset of Neon.
+            Level::Fp16(fp16) => Some(Neon {
+                neon: fp16.neon,
+                bf16: fp16.bf16,
+            }),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "aarch64"))]
+    #[inline]
+    pub fn as_fp16(self) -> Option<Fp16> {
+        match self {
+            Level::Fp16(fp16) => Some(fp16),
             _ => None,
         }
     }
@@ -75,6 +154,53 @@ impl Level {
     pub fn as_wasm_simd128(self) -> Option<WasmSimd128> {
         match self {
             Level::WasmSimd128(simd128) => Some(simd128),
+            // Relaxed SIMD is a superset of SIMD128.
+            #[cfg(target_feature = "relaxed-simd")]
+            Level::WasmRelaxedSimd(_) => Some(WasmSimd128::new_unchecked()),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        target_feature = "relaxed-simd"
+    ))]
+    #[inline]
+    pub fn as_wasm_relaxed_simd(self) -> Option<WasmRelaxedSimd> {
+        match self {
+            Level::WasmRelaxedSimd(relaxed) => Some(relaxed),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[inline]
+    pub fn as_sse4_2(self) -> Option<Sse4_2> {
+        match self {
+            Level::Sse4_2(sse4_2) => Some(sse4_2),
+            // SAFETY: Avx2 and Avx512 are supersets of Sse4_2.
+            Level::Avx2(_) | Level::Avx512(_) => Some(unsafe { Sse4_2::new_unchecked() }),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[inline]
+    pub fn as_avx2(self) -> Option<Avx2> {
+        match self {
+            Level::Avx2(avx2) => Some(avx2),
+            // SAFETY: `avx512f` implies `avx2`, `fma` and `f16c`, so Avx512 is a superset of Avx2.
+            Level::Avx512(_) => Some(unsafe { Avx2::new_unchecked() }),
+            _ => None,
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[inline]
+    pub fn as_avx512(self) -> Option<Avx512> {
+        match self {
+            Level::Avx512(avx512) => Some(avx512),
             _ => None,
         }
     }
@@ -94,12 +220,54 @@ impl Level {
             f.with_simd(neon)
         }
 
+        #[cfg(all(feature = "std", target_arch = "aarch64"))]
+        #[target_feature(enable = "neon,fp16")]
+        #[inline]
+        // unsafe not needed here with tf11, but can be justified
+        unsafe fn dispatch_fp16<W: WithSimd>(f: W, fp16: Fp16) -> W::Output {
+            f.with_simd(fp16)
+        }
+
         #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
         #[inline]
         fn dispatch_simd128<W: WithSimd>(f: W, simd128: WasmSimd128) -> W::Output {
             f.with_simd(simd128)
         }
 
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            target_feature = "relaxed-simd"
+        ))]
+        #[inline]
+        fn dispatch_relaxed_simd<W: WithSimd>(f: W, relaxed: WasmRelaxedSimd) -> W::Output {
+            f.with_simd(relaxed)
+        }
+
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        #[target_feature(enable = "sse4.2")]
+        #[inline]
+        // unsafe not needed here with tf11, but can be justified
+        unsafe fn dispatch_sse4_2<W: WithSimd>(f: W, sse4_2: Sse4_2) -> W::Output {
+            f.with_simd(sse4_2)
+        }
+
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx2,fma,f16c")]
+        #[inline]
+        // unsafe not needed here with tf11, but can be justified
+        unsafe fn dispatch_avx2<W: WithSimd>(f: W, avx2: Avx2) -> W::Output {
+            f.with_simd(avx2)
+        }
+
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx512f,avx512bw,avx512cd,avx512dq,avx512vl")]
+        #[inline]
+        // unsafe not needed here with tf11, but can be justified
+        unsafe fn dispatch_avx512<W: WithSimd>(f: W, avx512: Avx512) -> W::Output {
+            f.with_simd(avx512)
+        }
+
         #[inline]
         fn dispatch_fallback<W: WithSimd>(f: W, fallback: Fallback) -> W::Output {
             f.with_simd(fallback)
@@ -108,8 +276,22 @@ impl Level {
         match self {
             #[cfg(all(feature = "std", target_arch = "aarch64"))]
             Level::Neon(neon) => unsafe { dispatch_neon(f, neon) },
+            #[cfg(all(feature = "std", target_arch = "aarch64"))]
+            Level::Fp16(fp16) => unsafe { dispatch_fp16(f, fp16) },
             #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
             Level::WasmSimd128(simd128) => dispatch_simd128(f, simd128),
+            #[cfg(all(
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                target_feature = "relaxed-simd"
+            ))]
+            Level::WasmRelaxedSimd(relaxed) => dispatch_relaxed_simd(f, relaxed),
+            #[cfg(all(feature = "std", target_arch = "x86_64"))]
+            Level::Sse4_2(sse4_2) => unsafe { dispatch_sse4_2(f, sse4_2) },
+            #[cfg(all(feature = "std", target_arch = "x86_64"))]
+            Level::Avx2(avx2) => unsafe { dispatch_avx2(f, avx2) },
+            #[cfg(all(feature = "std", target_arch = "x86_64"))]
+            Level::Avx512(avx512) => unsafe { dispatch_avx512(f, avx512) },
             Level::Fallback(fallback) => dispatch_fallback(f, fallback),
         }
     }
@@ -19,19 +19,58 @@ macro_rules! simd_dispatch {
             unsafe fn inner_neon(neon: $crate::aarch64::Neon $( , $arg: $ty )* ) $( -> $ret )? {
                 $inner( neon $( , $arg )* )
             }
+            #[cfg(target_arch = "aarch64")]
+            #[target_feature(enable = "neon,fp16")]
+            #[inline]
+            unsafe fn inner_fp16(fp16: $crate::aarch64::Fp16 $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( fp16 $( , $arg )* )
+            }
             #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
             #[inline]
             unsafe fn inner_wasm_simd128(simd128: $crate::wasm32::WasmSimd128 $( , $arg: $ty )* ) $( -> $ret )? {
                 $inner( simd128 $( , $arg )* )
             }
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128", target_feature = "relaxed-simd"))]
+            #[inline]
+            unsafe fn inner_wasm_relaxed_simd(relaxed: $crate::wasm32::WasmRelaxedSimd $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( relaxed $( , $arg )* )
+            }
+            #[cfg(target_arch = "x86_64")]
+            #[target_feature(enable = "sse4.2")]
+            #[inline]
+            unsafe fn inner_sse4_2(sse4_2: $crate::x86_64::Sse4_2 $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( sse4_2 $( , $arg )* )
+            }
+            #[cfg(target_arch = "x86_64")]
+            #[target_feature(enable = "avx2,fma,f16c")]
+            #[inline]
+            unsafe fn inner_avx2(avx2: $crate::x86_64::Avx2 $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( avx2 $( , $arg )* )
+            }
+            #[cfg(target_arch = "x86_64")]
+            #[target_feature(enable = "avx512f,avx512bw,avx512cd,avx512dq,avx512vl")]
+            #[inline]
+            unsafe fn inner_avx512(avx512: $crate::x86_64::Avx512 $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( avx512 $( , $arg )* )
+            }
             match level {
                 Level::Fallback(fb) => {
                     $inner(fb $( , $arg )* )
                 },
                 #[cfg(target_arch = "aarch64")]
                 Level::Neon(neon) => unsafe { inner_neon (neon $( , $arg )* ) }
+                #[cfg(target_arch = "aarch64")]
+                Level::Fp16(fp16) => unsafe { inner_fp16 (fp16 $( , $arg )* ) }
                 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
                 Level::WasmSimd128(wasm) => unsafe { inner_wasm_simd128 (wasm $( , $arg )* ) }
+                #[cfg(all(target_arch = "wasm32", target_feature = "simd128", target_feature = "relaxed-simd"))]
+                Level::WasmRelaxedSimd(relaxed) => unsafe { inner_wasm_relaxed_simd (relaxed $( , $arg )* ) }
+                #[cfg(target_arch = "x86_64")]
+                Level::Sse4_2(sse4_2) => unsafe { inner_sse4_2 (sse4_2 $( , $arg )* ) }
+                #[cfg(target_arch = "x86_64")]
+                Level::Avx2(avx2) => unsafe { inner_avx2 (avx2 $( , $arg )* ) }
+                #[cfg(target_arch = "x86_64")]
+                Level::Avx512(avx512) => unsafe { inner_avx512 (avx512 $( , $arg )* ) }
             }
         }
     };
@@ -52,11 +91,44 @@ macro_rules! simd_dispatch {
             unsafe fn inner_wasm_simd128(simd128: $crate::wasm32::WasmSimd128 $( , $arg: $ty )* ) $( -> $ret )? {
                 $inner( simd128 $( , $arg )* )
             }
+            #[cfg(all(target_arch = "wasm32", target_feature = "simd128", target_feature = "relaxed-simd"))]
+            #[inline]
+            unsafe fn inner_wasm_relaxed_simd(relaxed: $crate::wasm32::WasmRelaxedSimd $( , $arg: $ty )* ) $( -> $ret )? {
+                $inner( relaxed $( , $arg )* )
+            }
             match level {
                 Level::Fallback(fb) => $inner(fb $( , $arg )* ),
                 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
                 Level::WasmSimd128(wasm) => unsafe { inner_wasm_simd128 (wasm $( , $arg )* ) }
+                #[cfg(all(target_arch = "wasm32", target_feature = "simd128", target_feature = "relaxed-simd"))]
+                Level::WasmRelaxedSimd(relaxed) => unsafe { inner_wasm_relaxed_simd (relaxed $( , $arg )* ) }
             }
         }
     };
 }
+
+/// Shuffle the lanes of one or two vectors, with indices known at compile time.
+///
+/// `shuffle!(a, [3, 2, 1, 0])` reverses a four lane vector. With two vectors,
+/// the indices continue into the second vector, so `shuffle!(a, b, [0, 1, 2, 7])`
+/// replaces the last lane of `a` with the last lane of `b`.
+///
+/// There must be one index for each lane of the result. See [`Indices`](crate::Indices)
+/// for a way to name the indices.
+#[macro_export]
+macro_rules! shuffle {
+    ($a:expr, [$( $index:expr ),+ $(,)?]) => {{
+        struct ShuffleIndices;
+        impl $crate::Indices<{ [$( $index ),+].len() }> for ShuffleIndices {
+            const INDICES: [usize; { [$( $index ),+].len() }] = [$( $index ),+];
+        }
+        $crate::SimdBase::shuffle::<ShuffleIndices, { [$( $index ),+].len() }>($a)
+    }};
+    ($a:expr, $b:expr, [$( $index:expr ),+ $(,)?]) => {{
+        struct ShuffleIndices;
+        impl $crate::Indices<{ [$( $index ),+].len() }> for ShuffleIndices {
+            const INDICES: [usize; { [$( $index ),+].len() }] = [$( $index ),+];
+        }
+        $crate::SimdBase::shuffle2::<ShuffleIndices, { [$( $index ),+].len() }>($a, $b)
+    }};
+}
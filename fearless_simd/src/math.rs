@@ -0,0 +1,603 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Elementary functions on vectors of `f32`.
+
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, LOG2_E, PI};
+
+use crate::{Simd, SimdFloat, SimdFrom, SimdInt, f32x4, f32x8, f32x16};
+
+/// Elementary functions on vectors of `f32`.
+///
+/// The functions are built from the other vector operations, so they are
+/// available on every level. The error bounds are in units in the last place
+/// (ULP) of the exact result, and hold on every level, whether or not it has
+/// a fused multiply-add. The results are not bit-identical across levels
+/// though, because the levels with a fused multiply-add round less often.
+///
+/// The `fast_*` variants use shorter approximations, and only handle the
+/// inputs listed in their documentation. Other inputs, including NaN, give
+/// unspecified results.
+pub trait SimdMath<S: Simd>: SimdFloat<f32, S> {
+    /// Compute `e^self`, within 2 ULP.
+    fn exp(self) -> Self;
+    /// Compute `2^self`, within 2 ULP.
+    fn exp2(self) -> Self;
+    /// Compute the natural logarithm, within 1 ULP.
+    ///
+    /// Negative inputs give NaN, and zero gives negative infinity.
+    fn ln(self) -> Self;
+    /// Compute the base 2 logarithm, within 1 ULP.
+    ///
+    /// Negative inputs give NaN, and zero gives negative infinity.
+    fn log2(self) -> Self;
+    /// Compute `self^rhs`, within 4 ULP for results between `2^-32` and
+    /// `2^32`, and within 16 ULP otherwise.
+    ///
+    /// The special cases follow the IEEE 754 `pow` function.
+    fn pow(self, rhs: Self) -> Self;
+    /// Compute the sine, within 3 ULP for `|self| <= 8192`.
+    ///
+    /// Larger inputs lose accuracy, but stay in `[-1, 1]`.
+    fn sin(self) -> Self;
+    /// Compute the cosine, within 3 ULP for `|self| <= 8192`.
+    ///
+    /// Larger inputs lose accuracy, but stay in `[-1, 1]`.
+    fn cos(self) -> Self;
+    /// Compute the tangent, within 4 ULP for `|self| <= 8192`.
+    fn tan(self) -> Self;
+    /// Compute the angle of the point `(rhs, self)`, within 3 ULP.
+    ///
+    /// The special cases follow the IEEE 754 `atan2` function.
+    fn atan2(self, rhs: Self) -> Self;
+    /// Compute `e^self` with a relative error below `2e-5`.
+    ///
+    /// Results that would be subnormal may be flushed to zero.
+    fn fast_exp(self) -> Self;
+    /// Compute `2^self` with a relative error below `1e-5`.
+    ///
+    /// Results that would be subnormal may be flushed to zero.
+    fn fast_exp2(self) -> Self;
+    /// Compute the natural logarithm of a positive normal number, with an
+    /// absolute error below `2e-5`.
+    fn fast_ln(self) -> Self;
+    /// Compute the base 2 logarithm of a positive normal number, with an
+    /// absolute error below `2e-5`.
+    fn fast_log2(self) -> Self;
+    /// Compute `self^rhs` for positive normal `self`, as
+    /// `(rhs * self.fast_log2()).fast_exp2()`.
+    ///
+    /// The relative error is below `1e-5 * (1 + |rhs|)`.
+    fn fast_pow(self, rhs: Self) -> Self;
+    /// Compute the sine with an absolute error below `2e-5`, for
+    /// `|self| <= 1000`.
+    fn fast_sin(self) -> Self;
+    /// Compute the cosine with an absolute error below `2e-5`, for
+    /// `|self| <= 1000`.
+    fn fast_cos(self) -> Self;
+    /// Compute the tangent with a relative error below `2e-5`, for
+    /// `|self| <= pi / 2`.
+    ///
+    /// Larger inputs lose accuracy near the poles.
+    fn fast_tan(self) -> Self;
+    /// Compute the angle of the point `(rhs, self)`, with an absolute error
+    /// below `5e-5`, for finite inputs that are not both zero.
+    fn fast_atan2(self, rhs: Self) -> Self;
+}
+
+/// Bit level access to the lanes, which the functions are built on.
+trait Lanes<S: Simd>: SimdFloat<f32, S> + SimdFrom<f32, S> {
+    type Bits: SimdInt<i32, S, Mask = Self::Mask> + SimdFrom<i32, S>;
+
+    fn simd(self) -> S;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+
+    #[inline(always)]
+    fn splat_like(self, val: f32) -> Self {
+        Self::splat(self.simd(), val)
+    }
+}
+
+macro_rules! impl_math {
+    ($($ty:ident, $bits:ident;)*) => {
+        $(
+            impl<S: Simd> Lanes<S> for $ty<S> {
+                type Bits = crate::$bits<S>;
+
+                #[inline(always)]
+                fn simd(self) -> S {
+                    self.simd
+                }
+
+                #[inline(always)]
+                fn to_bits(self) -> Self::Bits {
+                    self.reinterpret_i32()
+                }
+
+                #[inline(always)]
+                fn from_bits(bits: Self::Bits) -> Self {
+                    bits.reinterpret_f32()
+                }
+
+                #[inline(always)]
+                fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+                    crate::Select::select(mask, if_true, if_false)
+                }
+            }
+
+            impl<S: Simd> SimdMath<S> for $ty<S> {
+                #[inline(always)]
+                fn exp(self) -> Self {
+                    exp(self)
+                }
+
+                #[inline(always)]
+                fn exp2(self) -> Self {
+                    exp2(self)
+                }
+
+                #[inline(always)]
+                fn ln(self) -> Self {
+                    ln(self)
+                }
+
+                #[inline(always)]
+                fn log2(self) -> Self {
+                    log2(self)
+                }
+
+                #[inline(always)]
+                fn pow(self, rhs: Self) -> Self {
+                    pow(self, rhs)
+                }
+
+                #[inline(always)]
+                fn sin(self) -> Self {
+                    sin_cos(self, 0)
+                }
+
+                #[inline(always)]
+                fn cos(self) -> Self {
+                    sin_cos(self, 1)
+                }
+
+                #[inline(always)]
+                fn tan(self) -> Self {
+                    tan(self)
+                }
+
+                #[inline(always)]
+                fn atan2(self, rhs: Self) -> Self {
+                    atan2(self, rhs)
+                }
+
+                #[inline(always)]
+                fn fast_exp(self) -> Self {
+                    fast_exp2(self * LOG2_E)
+                }
+
+                #[inline(always)]
+                fn fast_exp2(self) -> Self {
+                    fast_exp2(self)
+                }
+
+                #[inline(always)]
+                fn fast_ln(self) -> Self {
+                    fast_log2(self) * core::f32::consts::LN_2
+                }
+
+                #[inline(always)]
+                fn fast_log2(self) -> Self {
+                    fast_log2(self)
+                }
+
+                #[inline(always)]
+                fn fast_pow(self, rhs: Self) -> Self {
+                    fast_exp2(rhs * fast_log2(self))
+                }
+
+                #[inline(always)]
+                fn fast_sin(self) -> Self {
+                    fast_sin_cos(self, 0)
+                }
+
+                #[inline(always)]
+                fn fast_cos(self) -> Self {
+                    fast_sin_cos(self, 1)
+                }
+
+                #[inline(always)]
+                fn fast_tan(self) -> Self {
+                    fast_tan(self)
+                }
+
+                #[inline(always)]
+                fn fast_atan2(self, rhs: Self) -> Self {
+                    fast_atan2(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_math! {
+    f32x4, i32x4;
+    f32x8, i32x8;
+    f32x16, i32x16;
+}
+
+/// Adding and subtracting this rounds to an integer, which ends up in the low
+/// bits of the sum.
+const ROUND: f32 = 12582912.0;
+const ROUND_BITS: i32 = 0x4b40_0000;
+
+/// Round to the nearest integer, as a float and as an integer.
+///
+/// The magnitude of `x` must be below `2^22`.
+#[inline(always)]
+fn round_int<S: Simd, V: Lanes<S>>(x: V) -> (V, V::Bits) {
+    let t = x + ROUND;
+    (t - ROUND, t.to_bits() - ROUND_BITS)
+}
+
+/// Convert an integer with a magnitude below `2^22` to a float.
+#[inline(always)]
+fn int_to_float<S: Simd, V: Lanes<S>>(n: V::Bits) -> V {
+    V::from_bits(n + ROUND_BITS) - ROUND
+}
+
+/// Compute `x * 2^n`, for `x` near 1 and `n` in `-252..=254`.
+#[inline(always)]
+fn ldexp<S: Simd, V: Lanes<S>>(x: V, n: V::Bits) -> V {
+    // Two steps keep both factors normal. For the `n` used here, the first
+    // product is exact, so subnormal results are only rounded once.
+    let half = n >> 1;
+    let a = V::from_bits((half + 127) << 23);
+    let b = V::from_bits((n - half + 127) << 23);
+    x * a * b
+}
+
+/// Clear the low 12 bits, leaving 12 significant bits.
+#[inline(always)]
+fn trunc_bits<S: Simd, V: Lanes<S>>(x: V) -> V {
+    V::from_bits(x.to_bits() & !0xfff)
+}
+
+/// Evaluate the polynomial with the given coefficients, constant term first.
+#[inline(always)]
+fn poly<S: Simd, V: Lanes<S>>(x: V, coeffs: &[f32]) -> V {
+    let (last, rest) = coeffs.split_last().unwrap();
+    rest.iter()
+        .rev()
+        .fold(x.splat_like(*last), |p, &c| p.mul_add_fast(x, c))
+}
+
+/// Keep the NaN lanes of `x`, and take the other lanes from `r`.
+#[inline(always)]
+fn keep_nan<S: Simd, V: Lanes<S>>(x: V, r: V) -> V {
+    V::select(x.simd_eq(x), r, x)
+}
+
+/// Minimax approximation of `2^x` on `[-0.5, 0.5]`.
+const EXP2: [f32; 7] = [
+    1.0,
+    0.69314724,
+    0.24022652,
+    0.055503104,
+    0.009617693,
+    0.0013406643,
+    0.00015594678,
+];
+
+#[inline(always)]
+fn exp2<S: Simd, V: Lanes<S>>(x: V) -> V {
+    // Beyond this range, the result is zero or infinite.
+    let xc = x.max(-152.0).min(130.0);
+    let (n, k) = round_int(xc);
+    keep_nan(x, ldexp(poly(xc - n, &EXP2), k))
+}
+
+/// Minimax approximation of `e^x` on `[-ln(2) / 2, ln(2) / 2]`.
+const EXP: [f32; 7] = [
+    1.0,
+    1.0,
+    0.49999994,
+    0.16666521,
+    0.04166839,
+    0.00836871,
+    0.0013814613,
+];
+
+/// `ln(2)` split into a high part with 15 significant bits and the rest.
+const LN2_HI: f32 = 0.69314575;
+const LN2_LO: f32 = 1.4286068e-06;
+
+#[inline(always)]
+fn exp<S: Simd, V: Lanes<S>>(x: V) -> V {
+    let xc = x.max(-105.0).min(89.0);
+    let (n, k) = round_int(xc * LOG2_E);
+    // `n * LN2_HI` is exact, and so is the first subtraction.
+    let r = (xc - n * LN2_HI) - n * LN2_LO;
+    keep_nan(x, ldexp(poly(r, &EXP), k))
+}
+
+/// Split positive `x` into `k` and `f`, with `x = 2^k * (1 + f)` and `1 + f`
+/// in `[sqrt(1/2), sqrt(2))`.
+#[inline(always)]
+fn log_reduce<S: Simd, V: Lanes<S>>(x: V) -> (V, V) {
+    let subnormal = x.simd_lt(f32::MIN_POSITIVE);
+    let x = V::select(subnormal, x * 33554432.0, x);
+    let bits = x.to_bits() + (0x3f80_0000 - 0x3f35_04f3);
+    let k = int_to_float::<S, V>((bits >> 23) - 127);
+    let k = k - V::select(subnormal, x.splat_like(25.0), x.splat_like(0.0));
+    let m = V::from_bits((bits & 0x007f_ffff) + 0x3f35_04f3);
+    (k, m - 1.0)
+}
+
+// The kernel of `ln(1 + f)` from FreeBSD's `logf`, with `s = f / (2 + f)`.
+const LG1: f32 = 0.6666666;
+const LG2: f32 = 0.40000972;
+const LG3: f32 = 0.28498787;
+const LG4: f32 = 0.24279079;
+
+/// The odd part of `ln(1 + f)`, beyond `2 * s`.
+#[inline(always)]
+fn log_kernel<S: Simd, V: Lanes<S>>(s: V) -> V {
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * w.mul_add_fast(LG4, LG2);
+    let t2 = z * w.mul_add_fast(LG3, LG1);
+    t2 + t1
+}
+
+/// Fix up the results of a logarithm at zero, infinity and negative inputs.
+#[inline(always)]
+fn log_special<S: Simd, V: Lanes<S>>(x: V, r: V) -> V {
+    let r = V::select(x.simd_eq(f32::INFINITY), x, r);
+    let r = V::select(x.simd_eq(0.0), x.splat_like(f32::NEG_INFINITY), r);
+    V::select(x.simd_ge(0.0), r, x.splat_like(f32::NAN))
+}
+
+#[inline(always)]
+fn ln<S: Simd, V: Lanes<S>>(x: V) -> V {
+    // `ln(2)` split for `logf`, with fewer bits in the high part than `LN2_HI`.
+    const LN2_HI: f32 = 0.6931381;
+    const LN2_LO: f32 = 9.058001e-06;
+    let (k, f) = log_reduce(x);
+    let s = f / (f + 2.0);
+    let hfsq = f * f * 0.5;
+    let r = s * (hfsq + log_kernel(s)) + k * LN2_LO - hfsq + f + k * LN2_HI;
+    log_special(x, r)
+}
+
+/// `1 / ln(2)` split into a high part with 12 significant bits and the rest.
+const INV_LN2_HI: f32 = 1.4428711;
+const INV_LN2_LO: f32 = -0.00017605285;
+
+/// The base 2 logarithm of positive finite `x`, as the sum of a rounded
+/// result and a small correction.
+#[inline(always)]
+fn log2_split<S: Simd, V: Lanes<S>>(x: V) -> (V, V) {
+    let (k, f) = log_reduce(x);
+    let s = f / (f + 2.0);
+    let hfsq = f * f * 0.5;
+    // The rounding error of `hfsq`, which would limit the accuracy of `pow`.
+    let fh = trunc_bits(f);
+    let fl = f - fh;
+    let hfsq_err = ((fh * fh - (hfsq + hfsq)) + (fh + fh) * fl + fl * fl) * 0.5;
+    // Split `ln(1 + f)` into `hi + lo`, so that `hi * INV_LN2_HI` is exact.
+    let hi = trunc_bits(f - hfsq);
+    let lo = (f - hi) - hfsq - hfsq_err + s * (hfsq + log_kernel(s));
+    let a = hi * INV_LN2_HI;
+    let b = (lo + hi) * INV_LN2_LO + lo * INV_LN2_HI;
+    // `|k| >= |a|` unless `k` is zero, so the first correction is exact.
+    let sum = k + a;
+    let lo = (k - sum) + a + b;
+    let hi = sum + lo;
+    (hi, lo - (hi - sum))
+}
+
+#[inline(always)]
+fn log2<S: Simd, V: Lanes<S>>(x: V) -> V {
+    let (hi, lo) = log2_split(x);
+    log_special(x, hi + lo)
+}
+
+#[inline(always)]
+fn pow<S: Simd, V: Lanes<S>>(x: V, y: V) -> V {
+    let zero = x.splat_like(0.0);
+    let one = x.splat_like(1.0);
+    let nan = x.splat_like(f32::NAN);
+    let ax = x.abs();
+    let (lh, ll) = log2_split(ax);
+    let lh = V::select(ax.simd_eq(0.0), x.splat_like(f32::NEG_INFINITY), lh);
+    let lh = V::select(ax.simd_eq(f32::INFINITY), ax, lh);
+    let ll = V::select(ax.simd_eq(0.0) | ax.simd_eq(f32::INFINITY), zero, ll);
+
+    // Compute `y * (lh + ll)` as `ph + pl`, with the exact error of `y * lh`
+    // in `pl`.
+    let ph = y * lh;
+    let (yh, lhh) = (trunc_bits(y), trunc_bits(lh));
+    let (yl, lhl) = (y - yh, lh - lhh);
+    let pl = (((yh * lhh - ph) + yh * lhl + yl * lhh) + yl * lhl) + y * ll;
+
+    // Beyond this range, the result is zero or infinite, and `pl` may be NaN.
+    let hc = ph.max(-152.0).min(130.0);
+    let pl = V::select(hc.simd_eq(ph), pl, zero);
+    let (n, k) = round_int(hc);
+    let r = ldexp(poly((hc - n) + pl, &EXP2), k);
+
+    let y_int = y.trunc().simd_eq(y);
+    let half_y = y * 0.5;
+    let y_odd = y_int & !half_y.trunc().simd_eq(half_y);
+    let negative = x.to_bits().simd_lt(0);
+    let r = V::select(ax.simd_eq(1.0), one, r);
+    let r = V::select(negative & y_odd, -r, r);
+    let finite_negative = x.simd_lt(0.0) & x.simd_gt(f32::NEG_INFINITY);
+    let r = V::select(finite_negative & !y_int, nan, r);
+    let r = V::select(x.simd_eq(x) & y.simd_eq(y), r, nan);
+    V::select(x.simd_eq(1.0) | y.simd_eq(0.0), one, r)
+}
+
+/// `pi / 2` split into parts with 11 significant bits, and the rest.
+///
+/// The products with the quadrant are exact up to `8192`.
+const PIO2_1: f32 = 1.5703125;
+const PIO2_2: f32 = 0.0004837513;
+const PIO2_3: f32 = 7.5495336e-08;
+const PIO2_4: f32 = 2.563344e-12;
+
+/// Reduce `x` to `[-pi / 4, pi / 4]`, returning the remainder and the
+/// quadrant.
+#[inline(always)]
+fn reduce_pio2<S: Simd, V: Lanes<S>>(x: V) -> (V, V::Bits) {
+    let (n, q) = round_int(x * (2.0 / PI));
+    let r = x - n * PIO2_1;
+    let r = r - n * PIO2_2;
+    let r = r - n * PIO2_3;
+    (r - n * PIO2_4, q)
+}
+
+// The sine and cosine kernels of Cephes' `sinf` and `cosf`.
+const SIN: [f32; 3] = [-0.16666655, 0.008332161, -0.00019515296];
+const COS: [f32; 3] = [0.041666646, -0.0013887316, 2.4433157e-05];
+
+/// The sine and cosine of `r` in `[-pi / 4, pi / 4]`.
+#[inline(always)]
+fn sin_cos_kernel<S: Simd, V: Lanes<S>>(r: V) -> (V, V) {
+    let z = r * r;
+    let sin = (poly(z, &SIN) * z).mul_add_fast(r, r);
+    let cos = (poly(z, &COS) * z * z - z * 0.5) + 1.0;
+    (sin, cos)
+}
+
+/// The sine of `x` when `offset` is 0, and the cosine when it's 1.
+#[inline(always)]
+fn sin_cos<S: Simd, V: Lanes<S>>(x: V, offset: i32) -> V {
+    let (r, q) = reduce_pio2(x);
+    let (sin, cos) = sin_cos_kernel(r);
+    let r = quadrant(sin, cos, q + offset);
+    keep_nan(r, r.max(-1.0).min(1.0))
+}
+
+/// Pick `sin(x)` from the sine and cosine of the remainder in quadrant `q`.
+#[inline(always)]
+fn quadrant<S: Simd, V: Lanes<S>>(sin: V, cos: V, q: V::Bits) -> V {
+    let r = V::select((q & 1).simd_eq(0), sin, cos);
+    V::from_bits(r.to_bits() ^ ((q & 2) << 30))
+}
+
+#[inline(always)]
+fn tan<S: Simd, V: Lanes<S>>(x: V) -> V {
+    let (r, q) = reduce_pio2(x);
+    let (sin, cos) = sin_cos_kernel(r);
+    let even = (q & 1).simd_eq(0);
+    V::select(even, sin, -cos) / V::select(even, cos, sin)
+}
+
+// The kernel of Cephes' `atanf`, for `|t| <= tan(pi / 8)`.
+const ATAN: [f32; 4] = [-0.3333295, 0.19977711, -0.13877685, 0.080537446];
+const TAN_PI_8: f32 = 0.41421357;
+
+/// Compute `atan2(y, x)` from an approximation of `atan(t)` for
+/// `t = min(|x|, |y|) / max(|x|, |y|)`, which is given the numerator and
+/// denominator.
+#[inline(always)]
+fn atan2_with<S: Simd, V: Lanes<S>>(y: V, x: V, atan: impl FnOnce(V, V) -> V) -> V {
+    let (ax, ay) = (x.abs(), y.abs());
+    let swap = ay.simd_gt(ax);
+    let num = V::select(swap, ax, ay);
+    let den = V::select(swap, ay, ax);
+    let r = atan(num, den);
+    let r = V::select(swap, r.splat_like(FRAC_PI_2) - r, r);
+    let r = V::select(x.to_bits().simd_lt(0), r.splat_like(PI) - r, r);
+    r.copysign(y)
+}
+
+#[inline(always)]
+fn atan2<S: Simd, V: Lanes<S>>(y: V, x: V) -> V {
+    atan2_with(y, x, |num, den| {
+        // Scale large inputs down, so that `num + den` doesn't overflow.
+        let scale = V::select(den.simd_gt(1e30), num.splat_like(0.5), num.splat_like(1.0));
+        let (num, den) = (num * scale, den * scale);
+        // Both infinite gives `pi / 4`, and both zero gives zero.
+        let inf = num.simd_eq(f32::INFINITY);
+        let big = num.simd_gt(den * TAN_PI_8) | inf;
+        let t = V::select(big, num - den, num) / V::select(big, num + den, den);
+        let t = V::select(den.simd_eq(0.0) | inf, num.splat_like(0.0), t);
+        let z = t * t;
+        let r = (poly(z, &ATAN) * z).mul_add_fast(t, t);
+        V::select(big, r + FRAC_PI_4, r)
+    })
+}
+
+/// Minimax approximation of `2^x` on `[-0.5, 0.5]`.
+const FAST_EXP2: [f32; 5] = [1.0, 0.69310534, 0.24022168, 0.056005787, 0.009676037];
+
+#[inline(always)]
+fn fast_exp2<S: Simd, V: Lanes<S>>(x: V) -> V {
+    // The scale is zero below this range, and infinite above.
+    let xc = x.max(-127.0).min(128.0);
+    let (n, k) = round_int(xc);
+    poly(xc - n, &FAST_EXP2) * V::from_bits((k + 127) << 23)
+}
+
+/// Minimax approximation of `log2(1 + f) / f` on `[sqrt(1/2) - 1, sqrt(2) - 1]`.
+const FAST_LOG2: [f32; 6] = [
+    1.4428095,
+    -0.72136736,
+    0.47498608,
+    -0.35763976,
+    0.36139068,
+    -0.29807237,
+];
+
+#[inline(always)]
+fn fast_log2<S: Simd, V: Lanes<S>>(x: V) -> V {
+    let bits = x.to_bits() + (0x3f80_0000 - 0x3f35_04f3);
+    let k = int_to_float::<S, V>((bits >> 23) - 127);
+    let f = V::from_bits((bits & 0x007f_ffff) + 0x3f35_04f3) - 1.0;
+    (poly(f, &FAST_LOG2) * f) + k
+}
+
+/// `pi / 2` split into a part with 12 significant bits, and the rest.
+const FAST_PIO2_1: f32 = 1.5708008;
+const FAST_PIO2_2: f32 = -4.454455e-06;
+
+/// Minimax approximations of `sin(r)` and `cos(r)` on `[-pi / 4, pi / 4]`.
+const FAST_SIN: [f32; 3] = [0.9999985, -0.16662382, 0.008150057];
+const FAST_COS: [f32; 3] = [0.99999005, -0.49970815, 0.040398534];
+
+#[inline(always)]
+fn fast_sin_cos_kernel<S: Simd, V: Lanes<S>>(x: V) -> (V, V, V::Bits) {
+    let (n, q) = round_int(x * (2.0 / PI));
+    let r = (x - n * FAST_PIO2_1) - n * FAST_PIO2_2;
+    let z = r * r;
+    (poly(z, &FAST_SIN) * r, poly(z, &FAST_COS), q)
+}
+
+#[inline(always)]
+fn fast_sin_cos<S: Simd, V: Lanes<S>>(x: V, offset: i32) -> V {
+    let (sin, cos, q) = fast_sin_cos_kernel(x);
+    quadrant(sin, cos, q + offset)
+}
+
+#[inline(always)]
+fn fast_tan<S: Simd, V: Lanes<S>>(x: V) -> V {
+    let (sin, cos, q) = fast_sin_cos_kernel(x);
+    let even = (q & 1).simd_eq(0);
+    V::select(even, sin, -cos) / V::select(even, cos, sin)
+}
+
+/// Minimax approximation of `atan(t)` on `[0, 1]`, in odd powers of `t`.
+const FAST_ATAN: [f32; 5] = [0.99997, -0.33170083, 0.18521568, -0.09192658, 0.023863409];
+
+#[inline(always)]
+fn fast_atan2<S: Simd, V: Lanes<S>>(y: V, x: V) -> V {
+    atan2_with(y, x, |num, den| {
+        let t = num / den;
+        poly(t * t, &FAST_ATAN) * t
+    })
+}
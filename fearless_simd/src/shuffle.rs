@@ -0,0 +1,445 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Lane indices for shuffles.
+
+use core::marker::PhantomData;
+
+/// The lane indices of a shuffle of `N` lanes, known at compile time.
+///
+/// Arrays can't be const generic parameters on stable Rust, so the indices
+/// are an associated constant of a marker type instead. The [`shuffle!`]
+/// macro declares such a type inline.
+///
+/// For `shuffle`, each index picks a lane of the input. For `shuffle2`, the
+/// indices `0..N` pick lanes of the first input and `N..2 * N` pick lanes of
+/// the second. Indices out of range are a compile error.
+///
+/// [`shuffle!`]: crate::shuffle!
+pub trait Indices<const N: usize> {
+    const INDICES: [usize; N];
+}
+
+/// The indices of `I`, for vectors of `N` lanes.
+///
+/// The lane count of a generic vector is an associated constant, which can't
+/// be a const generic argument on stable Rust. The `SimdBase` shuffles take it
+/// as a separate parameter `M` instead, and this checks that it matches.
+pub(crate) struct SameLanes<I, const M: usize>(PhantomData<I>);
+
+impl<I: Indices<M>, const M: usize, const N: usize> Indices<N> for SameLanes<I, M> {
+    const INDICES: [usize; N] = {
+        assert!(M == N, "wrong number of shuffle indices");
+        let mut indices = [0; N];
+        let mut i = 0;
+        while i < N {
+            indices[i] = I::INDICES[i];
+            i += 1;
+        }
+        indices
+    };
+}
+
+/// Panics if any of the indices is not below `limit`.
+///
+/// This is called in a `const` block, which turns the panic into a compile
+/// error.
+pub(crate) const fn check_indices(indices: &[usize], limit: usize) {
+    let mut i = 0;
+    while i < indices.len() {
+        assert!(indices[i] < limit, "shuffle index out of range");
+        i += 1;
+    }
+}
+
+/// The byte indices of a shuffle of a 128 bit vector, for table lookups.
+///
+/// With two inputs, the bytes of the second input are numbered from 16.
+#[allow(dead_code, reason = "only used by the levels with table lookups")]
+pub(crate) const fn shuffle_bytes<const N: usize>(indices: [usize; N], inputs: usize) -> [u8; 16] {
+    check_indices(&indices, N * inputs);
+    let lane_bytes = 16 / N;
+    let mut bytes = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        bytes[i] = (indices[i / lane_bytes] * lane_bytes + i % lane_bytes) as u8;
+        i += 1;
+    }
+    bytes
+}
+
+/// The bytes of a two input table that come from one of the inputs.
+///
+/// The bytes from the other input are `0x80`, which both `pshufb` and
+/// `i8x16_swizzle` turn into zero, so the two lookups can be or-ed.
+#[allow(
+    dead_code,
+    reason = "only used by the levels with single table lookups"
+)]
+pub(crate) const fn select_bytes(bytes: [u8; 16], input: u8) -> [u8; 16] {
+    let mut selected = [0x80; 16];
+    let mut i = 0;
+    while i < 16 {
+        if bytes[i] / 16 == input {
+            selected[i] = bytes[i] % 16;
+        }
+        i += 1;
+    }
+    selected
+}
+
+/// The byte indices of a 128 bit vector that copy lane `lane` to every lane.
+#[allow(
+    dead_code,
+    reason = "only used by the levels with table lookups for broadcasts"
+)]
+const fn broadcast_bytes(lane_bytes: usize, lane: usize) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        bytes[i] = (lane * lane_bytes + i % lane_bytes) as u8;
+        i += 1;
+    }
+    bytes
+}
+
+/// Expands to a `match` on `$value` with an arm for each of the literals, in
+/// which the constant `$name` has the value of that literal.
+///
+/// The immediates of intrinsics are const generics, which can't be computed
+/// from the const generics of the caller on stable Rust. Every arm passes a
+/// literal instead, and the match folds away once the caller is inlined.
+#[allow(unused_macros, reason = "only used by the native rotations")]
+macro_rules! const_match {
+    ($value:expr, const $name:ident: $ty:ty = [$( $n:literal ),+] => $body:expr) => {
+        match $value {
+            $(
+                $n => {
+                    const $name: $ty = $n;
+                    $body
+                }
+            )+
+            _ => unreachable!(),
+        }
+    };
+}
+
+// The helpers below rotate and broadcast the lanes of native vectors. They
+// take the lane size in bytes and the lane count or index as plain values,
+// which are constants in the generated callers, and must be below the lane
+// count of the vector.
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+pub(crate) use x86::*;
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod x86 {
+    use super::broadcast_bytes;
+    use core::arch::x86_64::*;
+
+    /// Moves lane `i + lanes` to lane `i`, wrapping around.
+    ///
+    /// # Safety
+    ///
+    /// SSSE3 must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn rotate_128(a: __m128i, lane_bytes: usize, lanes: usize) -> __m128i {
+        unsafe {
+            const_match!(
+                lanes * lane_bytes,
+                const B: i32 = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                    => _mm_alignr_epi8::<B>(a, a)
+            )
+        }
+    }
+
+    /// Moves lane `i + lanes` to lane `i`, wrapping around.
+    ///
+    /// # Safety
+    ///
+    /// AVX2 must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn rotate_256(a: __m256i, lane_bytes: usize, lanes: usize) -> __m256i {
+        let bytes = lanes * lane_bytes;
+        unsafe {
+            if bytes.is_multiple_of(4) {
+                let dwords = _mm256_add_epi32(
+                    _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7),
+                    _mm256_set1_epi32((bytes / 4) as i32),
+                );
+                // Only the low 3 bits of each index are used, which wraps them.
+                return _mm256_permutevar8x32_epi32(a, dwords);
+            }
+            // `alignr` shifts the concatenation of each pair of 128 bit blocks
+            // separately, so each block needs its neighbour as the other input.
+            let swapped = _mm256_permute2x128_si256::<0x01>(a, a);
+            let (low, high) = if bytes < 16 {
+                (a, swapped)
+            } else {
+                (swapped, a)
+            };
+            const_match!(
+                bytes % 16,
+                const B: i32 = [1, 2, 3, 5, 6, 7, 9, 10, 11, 13, 14, 15]
+                    => _mm256_alignr_epi8::<B>(high, low)
+            )
+        }
+    }
+
+    /// Moves lane `i + lanes` to lane `i`, wrapping around.
+    ///
+    /// # Safety
+    ///
+    /// AVX-512 F and BW must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn rotate_512(a: __m512i, lane_bytes: usize, lanes: usize) -> __m512i {
+        let bytes = lanes * lane_bytes;
+        unsafe {
+            let rotate_dwords = |dwords: usize| {
+                let indices = _mm512_add_epi32(
+                    _mm512_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+                    _mm512_set1_epi32(dwords as i32),
+                );
+                // Only the low 4 bits of each index are used, which wraps them.
+                _mm512_permutexvar_epi32(indices, a)
+            };
+            if bytes.is_multiple_of(4) {
+                return rotate_dwords(bytes / 4);
+            }
+            // As in `rotate_256`, each block is shifted together with its
+            // neighbour, which here is one block further along.
+            let low = rotate_dwords(bytes / 16 * 4);
+            let high = rotate_dwords(bytes / 16 * 4 + 4);
+            const_match!(
+                bytes % 16,
+                const B: i32 = [1, 2, 3, 5, 6, 7, 9, 10, 11, 13, 14, 15]
+                    => _mm512_alignr_epi8::<B>(high, low)
+            )
+        }
+    }
+
+    /// Copies lane `lane` to every lane.
+    ///
+    /// # Safety
+    ///
+    /// SSSE3 must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn broadcast_128(a: __m128i, lane_bytes: usize, lane: usize) -> __m128i {
+        unsafe {
+            match lane_bytes {
+                4 => const_match!(
+                    lane,
+                    const L: i32 = [0, 1, 2, 3] => _mm_shuffle_epi32::<{ L * 0x55 }>(a)
+                ),
+                8 => const_match!(
+                    lane,
+                    const L: i32 = [0, 1] => _mm_shuffle_epi32::<{ 0x44 + L * 0xaa }>(a)
+                ),
+                _ => {
+                    let bytes = broadcast_bytes(lane_bytes, lane);
+                    _mm_shuffle_epi8(a, _mm_loadu_si128(bytes.as_ptr().cast()))
+                }
+            }
+        }
+    }
+
+    /// Copies lane `lane` to every lane.
+    ///
+    /// # Safety
+    ///
+    /// AVX2 must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn broadcast_256(a: __m256i, lane_bytes: usize, lane: usize) -> __m256i {
+        let dword = (lane * lane_bytes / 4) as i32;
+        unsafe {
+            if lane_bytes == 8 {
+                return _mm256_permutevar8x32_epi32(
+                    a,
+                    _mm256_setr_epi32(
+                        dword,
+                        dword + 1,
+                        dword,
+                        dword + 1,
+                        dword,
+                        dword + 1,
+                        dword,
+                        dword + 1,
+                    ),
+                );
+            }
+            let dwords = _mm256_permutevar8x32_epi32(a, _mm256_set1_epi32(dword));
+            if lane_bytes == 4 {
+                return dwords;
+            }
+            // Then pick the lane out of each copy of its dword.
+            let bytes = broadcast_bytes(lane_bytes, lane % (4 / lane_bytes));
+            _mm256_shuffle_epi8(
+                dwords,
+                _mm256_broadcastsi128_si256(_mm_loadu_si128(bytes.as_ptr().cast())),
+            )
+        }
+    }
+
+    /// Copies lane `lane` to every lane.
+    ///
+    /// # Safety
+    ///
+    /// AVX-512 F and BW must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn broadcast_512(a: __m512i, lane_bytes: usize, lane: usize) -> __m512i {
+        let dword = (lane * lane_bytes / 4) as i32;
+        unsafe {
+            if lane_bytes == 8 {
+                let pair = (i64::from(dword + 1) << 32) | i64::from(dword);
+                return _mm512_permutexvar_epi32(_mm512_set1_epi64(pair), a);
+            }
+            let dwords = _mm512_permutexvar_epi32(_mm512_set1_epi32(dword), a);
+            if lane_bytes == 4 {
+                return dwords;
+            }
+            let bytes = broadcast_bytes(lane_bytes, lane % (4 / lane_bytes));
+            _mm512_shuffle_epi8(
+                dwords,
+                _mm512_broadcast_i32x4(_mm_loadu_si128(bytes.as_ptr().cast())),
+            )
+        }
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+pub(crate) use neon::*;
+
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    /// Moves lane `i + lanes` to lane `i`, wrapping around.
+    ///
+    /// # Safety
+    ///
+    /// NEON must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn rotate_128(a: uint8x16_t, lane_bytes: usize, lanes: usize) -> uint8x16_t {
+        unsafe {
+            const_match!(
+                lanes * lane_bytes,
+                const B: i32 = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                    => vextq_u8::<B>(a, a)
+            )
+        }
+    }
+
+    /// Copies lane `lane` to every lane.
+    ///
+    /// # Safety
+    ///
+    /// NEON must be available.
+    #[inline(always)]
+    pub(crate) unsafe fn broadcast_128(
+        a: uint8x16_t,
+        lane_bytes: usize,
+        lane: usize,
+    ) -> uint8x16_t {
+        unsafe {
+            match lane_bytes {
+                1 => const_match!(
+                    lane,
+                    const L: i32 = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                        => vdupq_laneq_u8::<L>(a)
+                ),
+                2 => vreinterpretq_u8_u16(const_match!(
+                    lane,
+                    const L: i32 = [0, 1, 2, 3, 4, 5, 6, 7]
+                        => vdupq_laneq_u16::<L>(vreinterpretq_u16_u8(a))
+                )),
+                4 => vreinterpretq_u8_u32(const_match!(
+                    lane,
+                    const L: i32 = [0, 1, 2, 3] => vdupq_laneq_u32::<L>(vreinterpretq_u32_u8(a))
+                )),
+                _ => vreinterpretq_u8_u64(const_match!(
+                    lane,
+                    const L: i32 = [0, 1] => vdupq_laneq_u64::<L>(vreinterpretq_u64_u8(a))
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub(crate) use wasm::*;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm {
+    use super::broadcast_bytes;
+    use core::arch::wasm32::*;
+
+    /// Shuffles the bytes of `$a` by a constant table of byte indices.
+    macro_rules! byte_shuffle {
+        ($a:expr, $bytes:expr) => {{
+            const BYTES: [u8; 16] = $bytes;
+            i8x16_shuffle::<
+                { BYTES[0] as usize },
+                { BYTES[1] as usize },
+                { BYTES[2] as usize },
+                { BYTES[3] as usize },
+                { BYTES[4] as usize },
+                { BYTES[5] as usize },
+                { BYTES[6] as usize },
+                { BYTES[7] as usize },
+                { BYTES[8] as usize },
+                { BYTES[9] as usize },
+                { BYTES[10] as usize },
+                { BYTES[11] as usize },
+                { BYTES[12] as usize },
+                { BYTES[13] as usize },
+                { BYTES[14] as usize },
+                { BYTES[15] as usize },
+            >($a, $a)
+        }};
+    }
+
+    /// The byte indices that move byte `i + bytes` to byte `i`, wrapping around.
+    const fn rotate_bytes(bytes: usize) -> [u8; 16] {
+        let mut indices = [0; 16];
+        let mut i = 0;
+        while i < 16 {
+            indices[i] = ((i + bytes) % 16) as u8;
+            i += 1;
+        }
+        indices
+    }
+
+    /// Moves lane `i + lanes` to lane `i`, wrapping around.
+    #[inline(always)]
+    pub(crate) fn rotate_128(a: v128, lane_bytes: usize, lanes: usize) -> v128 {
+        const_match!(
+            lanes * lane_bytes,
+            const B: usize = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                => byte_shuffle!(a, rotate_bytes(B))
+        )
+    }
+
+    /// Copies lane `lane` to every lane.
+    #[inline(always)]
+    pub(crate) fn broadcast_128(a: v128, lane_bytes: usize, lane: usize) -> v128 {
+        match lane_bytes {
+            1 => const_match!(
+                lane,
+                const L: usize = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+                    => byte_shuffle!(a, broadcast_bytes(1, L))
+            ),
+            2 => const_match!(
+                lane,
+                const L: usize = [0, 1, 2, 3, 4, 5, 6, 7] => byte_shuffle!(a, broadcast_bytes(2, L))
+            ),
+            4 => const_match!(
+                lane,
+                const L: usize = [0, 1, 2, 3] => byte_shuffle!(a, broadcast_bytes(4, L))
+            ),
+            _ => const_match!(
+                lane,
+                const L: usize = [0, 1] => byte_shuffle!(a, broadcast_bytes(8, L))
+            ),
+        }
+    }
+}
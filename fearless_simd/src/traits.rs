@@ -82,6 +82,18 @@ impl SimdElement for f32 {
     type Mask = i32;
 }
 
+impl SimdElement for f64 {
+    type Mask = i64;
+}
+
+impl SimdElement for crate::f16 {
+    type Mask = i16;
+}
+
+impl SimdElement for crate::bf16 {
+    type Mask = i16;
+}
+
 impl SimdElement for u8 {
     type Mask = i8;
 }
@@ -105,3 +117,11 @@ impl SimdElement for u32 {
 impl SimdElement for i32 {
     type Mask = i32;
 }
+
+impl SimdElement for u64 {
+    type Mask = i64;
+}
+
+impl SimdElement for i64 {
+    type Mask = i64;
+}
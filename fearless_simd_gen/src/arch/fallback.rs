@@ -8,14 +8,37 @@ use quote::quote;
 
 pub(crate) fn translate_op(op: &str, is_float: bool) -> Option<&'static str> {
     Some(match op {
-        "abs" => "abs",
+        "abs" => {
+            if is_float {
+                "abs"
+            } else {
+                "wrapping_abs"
+            }
+        }
         "copysign" => "copysign",
-        "neg" => "neg",
+        "neg" => {
+            if is_float {
+                "neg"
+            } else {
+                "wrapping_neg"
+            }
+        }
+        "abs_diff" => "abs_diff",
         "floor" => "floor",
+        "ceil" => "ceil",
+        "round" => "round",
+        "round_ties_even" => "round_ties_even",
         "fract" => "fract",
         "trunc" => "trunc",
         "sqrt" => "sqrt",
-        "add" => "add",
+        "mul_add_fused" => "mul_add",
+        "add" => {
+            if is_float {
+                "add"
+            } else {
+                "wrapping_add"
+            }
+        }
         "sub" => {
             if is_float {
                 "sub"
@@ -23,9 +46,16 @@ pub(crate) fn translate_op(op: &str, is_float: bool) -> Option<&'static str> {
                 "wrapping_sub"
             }
         }
-        "mul" => "mul",
+        "mul" => {
+            if is_float {
+                "mul"
+            } else {
+                "wrapping_mul"
+            }
+        }
         "div" => "div",
         "simd_eq" => "eq",
+        "simd_ne" => "ne",
         "simd_lt" => "lt",
         "simd_le" => "le",
         "simd_ge" => "ge",
@@ -34,7 +64,8 @@ pub(crate) fn translate_op(op: &str, is_float: bool) -> Option<&'static str> {
         "and" => "bitand",
         "or" => "bitor",
         "xor" => "bitxor",
-        "shr" => "shr",
+        "saturating_add" => "saturating_add",
+        "saturating_sub" => "saturating_sub",
         // TODO: Do we need to polyfill so behavior is consistent with NEON?
         "max" => "max",
         "min" => "min",
@@ -57,7 +88,7 @@ impl Arch for Fallback {
     fn arch_ty(&self, ty: &VecType) -> TokenStream {
         let scalar = match ty.scalar {
             ScalarType::Float => "f",
-            ScalarType::Unsigned => "u",
+            ScalarType::Unsigned | ScalarType::BFloat => "u",
             ScalarType::Int | ScalarType::Mask => "i",
         };
         let name = format!("{}{}", scalar, ty.scalar_bits);
@@ -4,6 +4,7 @@
 pub(crate) mod fallback;
 pub(crate) mod neon;
 pub(crate) mod wasm;
+pub(crate) mod x86;
 
 use proc_macro2::TokenStream;
 
@@ -13,8 +13,13 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "abs" => "vabs",
         "neg" => "vneg",
         "floor" => "vrndm",
+        "ceil" => "vrndp",
+        "round" => "vrnda",
+        "round_ties_even" => "vrndn",
         "trunc" => "vrnd",
         "sqrt" => "vsqrt",
+        "recip_approx" => "vrecpe",
+        "rsqrt_approx" => "vrsqrte",
         "add" => "vadd",
         "sub" => "vsub",
         "mul" => "vmul",
@@ -30,7 +35,11 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "xor" => "veor",
         "max" => "vmax",
         "min" => "vmin",
-        "shr" => "vshl",
+        "saturating_add" => "vqadd",
+        "saturating_sub" => "vqsub",
+        "avg_round" => "vrhadd",
+        "mul_q15_round_sat" => "vqrdmulh",
+        "shl" | "shr" => "vshl",
         "max_precise" => "vmaxnm",
         "min_precise" => "vminnm",
         "madd" => "vfma",
@@ -43,7 +52,8 @@ impl Arch for Neon {
     fn arch_ty(&self, ty: &VecType) -> TokenStream {
         let scalar = match ty.scalar {
             ScalarType::Float => "float",
-            ScalarType::Unsigned => "uint",
+            // The bf16 lanes are carried in integer vectors.
+            ScalarType::Unsigned | ScalarType::BFloat => "uint",
             ScalarType::Int | ScalarType::Mask => "int",
         };
         let name = if ty.n_bits() == 256 {
@@ -54,11 +64,21 @@ impl Arch for Neon {
             format!("{}{}x{}_t", scalar, ty.scalar_bits, ty.len)
         };
         let ident = Ident::new(&name, Span::call_site());
-        quote! { #ident }
+        if ty.is_f16() {
+            // The f16 vector types are not yet available in `core::arch`.
+            quote! { crate::core_arch::aarch64::#ident }
+        } else {
+            quote! { #ident }
+        }
     }
 
     // expects args and return value in arch dialect
     fn expr(&self, op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream {
+        if ty.scalar_bits == 64 && ty.scalar != ScalarType::Float {
+            if let Some(expr) = int64_expr(op, ty, args) {
+                return expr;
+            }
+        }
         if let Some(xlat) = translate_op(op) {
             let intrinsic = simple_intrinsic(xlat, ty);
             return quote! { #intrinsic ( #( #args ),* ) };
@@ -85,10 +105,86 @@ impl Arch for Neon {
     }
 }
 
+/// Operations on 64 bit integer lanes that NEON doesn't provide directly.
+fn int64_expr(op: &str, ty: &VecType, args: &[TokenStream]) -> Option<TokenStream> {
+    let expr = match op {
+        "not" => {
+            let xor = simple_intrinsic("veor", ty);
+            let dup = split_intrinsic("vdup", "n", ty);
+            let a = &args[0];
+            quote! { #xor(#a, #dup(!0)) }
+        }
+        "min" | "max" => {
+            let cmp = simple_intrinsic(if op == "min" { "vclt" } else { "vcgt" }, ty);
+            let bsl = simple_intrinsic("vbsl", ty);
+            let (a, b) = (&args[0], &args[1]);
+            quote! {
+                let a = #a;
+                let b = #b;
+                #bsl(#cmp(a, b), a, b)
+            }
+        }
+        "mul" => {
+            // Compute the product from 32 bit pieces. The cross terms only
+            // contribute to the high half, so they can wrap at 32 bits.
+            let u64_ty = VecType::new(ScalarType::Unsigned, 64, ty.len);
+            let (a, b) = (&args[0], &args[1]);
+            let (a, b, result) = if ty.scalar == ScalarType::Unsigned {
+                (
+                    quote! { #a },
+                    quote! { #b },
+                    quote! { vaddq_u64(high, low) },
+                )
+            } else {
+                let to = cvt_intrinsic("vreinterpret", &u64_ty, ty);
+                let from = cvt_intrinsic("vreinterpret", ty, &u64_ty);
+                (
+                    quote! { #to(#a) },
+                    quote! { #to(#b) },
+                    quote! { #from(vaddq_u64(high, low)) },
+                )
+            };
+            quote! {
+                let a = #a;
+                let b = #b;
+                let cross = vmulq_u32(vreinterpretq_u32_u64(b), vrev64q_u32(vreinterpretq_u32_u64(a)));
+                let high = vshlq_n_u64::<32>(vpaddlq_u32(cross));
+                let low = vmull_u32(vmovn_u64(a), vmovn_u64(b));
+                #result
+            }
+        }
+        _ => return None,
+    };
+    Some(expr)
+}
+
+/// Half-precision arithmetic through the `core_arch::aarch64::Fp16` token.
+///
+/// Expects args and returns a value in arch dialect.
+pub fn fp16_expr(op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream {
+    if let Some(xlat) = translate_op(op) {
+        let intrinsic = simple_intrinsic(xlat, ty);
+        return quote! { self.fp16.#intrinsic ( #( #args ),* ) };
+    }
+    match op {
+        "splat" => {
+            let intrinsic = split_intrinsic("vdup", "n", ty);
+            quote! { self.fp16.#intrinsic ( #( #args ),* ) }
+        }
+        "fract" => {
+            let trunc = simple_intrinsic("vrnd", ty);
+            let sub = simple_intrinsic("vsub", ty);
+            let a = &args[0];
+            quote! { self.fp16.#sub(#a, self.fp16.#trunc(#a)) }
+        }
+        _ => unimplemented!("missing {op}"),
+    }
+}
+
 fn neon_array_type(ty: &VecType) -> (&'static str, &'static str, usize) {
     let scalar_c = match ty.scalar {
         ScalarType::Float => "f",
-        ScalarType::Unsigned => "u",
+        ScalarType::Unsigned | ScalarType::BFloat => "u",
         ScalarType::Int | ScalarType::Mask => "s",
     };
     (opt_q(ty), scalar_c, ty.scalar_bits)
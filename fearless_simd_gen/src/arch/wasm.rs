@@ -13,6 +13,8 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "abs" => "abs",
         "neg" => "neg",
         "floor" => "floor",
+        "ceil" => "ceil",
+        "round_ties_even" => "nearest",
         "trunc" => "trunc",
         "sqrt" => "sqrt",
         "add" => "add",
@@ -20,7 +22,12 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "sub" => "sub",
         "mul" => "mul",
         "div" => "div",
+        "saturating_add" => "add_sat",
+        "saturating_sub" => "sub_sat",
+        "avg_round" => "avgr",
+        "mul_q15_round_sat" => "q15mulr_sat",
         "simd_eq" => "eq",
+        "simd_ne" => "ne",
         "simd_lt" => "lt",
         "simd_le" => "le",
         "simd_ge" => "ge",
@@ -34,7 +41,6 @@ fn translate_op(op: &str) -> Option<&'static str> {
         "max_precise" => "pmax",
         "min_precise" => "pmin",
         "splat" => "splat",
-        // TODO: Only target-feature "relaxed-simd" has "relaxed_madd".
         _ => return None,
     })
 }
@@ -63,7 +69,7 @@ impl Arch for Wasm {
     fn arch_ty(&self, ty: &VecType) -> TokenStream {
         let scalar = match ty.scalar {
             ScalarType::Float => "f",
-            ScalarType::Unsigned => "u",
+            ScalarType::Unsigned | ScalarType::BFloat => "u",
             ScalarType::Int | ScalarType::Mask => "i",
         };
         let name = format!("{}{}x{}", scalar, ty.scalar_bits, ty.len);
@@ -73,6 +79,11 @@ impl Arch for Wasm {
 
     // expects args and return value in arch dialect
     fn expr(&self, op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream {
+        if ty.scalar_bits == 64 && matches!(ty.scalar, ScalarType::Unsigned | ScalarType::Int) {
+            if let Some(expr) = int64_expr(op, ty, args) {
+                return expr;
+            }
+        }
         if let Some(translated) = translate_op(op) {
             let intrinsic = match translated {
                 "not" => v128_intrinsic(translated),
@@ -91,3 +102,40 @@ impl Arch for Wasm {
         }
     }
 }
+
+/// Operations on 64 bit integer lanes that WASM doesn't provide directly.
+fn int64_expr(op: &str, ty: &VecType, args: &[TokenStream]) -> Option<TokenStream> {
+    let unsigned = ty.scalar == ScalarType::Unsigned;
+    let expr = match op {
+        "simd_lt" | "simd_le" | "simd_ge" | "simd_gt" if unsigned => {
+            // Only signed comparisons are available, so flip the sign bits first.
+            let cmp = Ident::new(&format!("i64x2_{}", &op[5..]), Span::call_site());
+            let (a, b) = (&args[0], &args[1]);
+            quote! {
+                let sign_bit = i64x2_splat(i64::MIN);
+                #cmp(v128_xor(#a, sign_bit), v128_xor(#b, sign_bit))
+            }
+        }
+        "min" | "max" => {
+            let cmp = if op == "min" { "i64x2_lt" } else { "i64x2_gt" };
+            let cmp = Ident::new(cmp, Span::call_site());
+            let (a, b) = (&args[0], &args[1]);
+            let mask = if unsigned {
+                quote! {
+                    let sign_bit = i64x2_splat(i64::MIN);
+                    let mask = #cmp(v128_xor(a, sign_bit), v128_xor(b, sign_bit));
+                }
+            } else {
+                quote! { let mask = #cmp(a, b); }
+            };
+            quote! {
+                let a = #a;
+                let b = #b;
+                #mask
+                v128_bitselect(a, b, mask)
+            }
+        }
+        _ => return None,
+    };
+    Some(expr)
+}
@@ -0,0 +1,147 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::arch::Arch;
+use crate::types::{ScalarType, VecType};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+pub struct X86;
+
+fn translate_op(op: &str) -> Option<&'static str> {
+    Some(match op {
+        "floor" => "floor",
+        "sqrt" => "sqrt",
+        "add" => "add",
+        "sub" => "sub",
+        "div" => "div",
+        "saturating_add" => "adds",
+        "saturating_sub" => "subs",
+        "avg_round" => "avg",
+        "and" => "and",
+        "or" => "or",
+        "xor" => "xor",
+        "max" => "max",
+        "min" => "min",
+        "simd_eq" => "cmpeq",
+        _ => return None,
+    })
+}
+
+impl Arch for X86 {
+    fn arch_ty(&self, ty: &VecType) -> TokenStream {
+        // f16 lanes are carried in integer vectors.
+        let suffix = match (ty.scalar, ty.scalar_bits) {
+            (ScalarType::Float, 32) => "",
+            (ScalarType::Float, 64) => "d",
+            _ => "i",
+        };
+        // The 64 bit vectors live in the low half of a 128 bit register.
+        let name = format!("__m{}{}", ty.n_bits().max(128), suffix);
+        let ident = Ident::new(&name, Span::call_site());
+        quote! { #ident }
+    }
+
+    // expects args and return value in arch dialect
+    fn expr(&self, op: &str, ty: &VecType, args: &[TokenStream]) -> TokenStream {
+        if let Some(translated) = translate_op(op) {
+            let suffix = match translated {
+                "and" | "or" | "xor" => coarse_suffix(ty),
+                "max" | "min" | "adds" | "subs" | "avg" => op_suffix(ty, true),
+                _ => op_suffix(ty, false),
+            };
+            let intrinsic = intrinsic_ident(translated, suffix, ty.n_bits());
+            return quote! { #intrinsic ( #( #args ),* ) };
+        }
+        match op {
+            "splat" => {
+                let intrinsic = set1_intrinsic(ty);
+                let arg = &args[0];
+                if ty.scalar == ScalarType::Unsigned {
+                    let signed = ScalarType::Int.rust(ty.scalar_bits);
+                    quote! { #intrinsic ( #arg as #signed ) }
+                } else {
+                    quote! { #intrinsic ( #arg ) }
+                }
+            }
+            "mul" => {
+                let name = match ty.scalar {
+                    ScalarType::Float => "mul",
+                    _ => "mullo",
+                };
+                let intrinsic = intrinsic_ident(name, op_suffix(ty, false), ty.n_bits());
+                quote! { #intrinsic ( #( #args ),* ) }
+            }
+            _ => unimplemented!("missing {op}"),
+        }
+    }
+}
+
+/// The `_mm` prefix for the given vector width, as in `_mm256`.
+fn width_prefix(n_bits: usize) -> &'static str {
+    match n_bits {
+        128 => "_mm",
+        256 => "_mm256",
+        512 => "_mm512",
+        _ => panic!("unsupported simd width"),
+    }
+}
+
+/// An intrinsic name of the form `_mm{width}_{name}_{suffix}`.
+pub fn intrinsic_ident(name: &str, suffix: &str, n_bits: usize) -> Ident {
+    let prefix = width_prefix(n_bits);
+    Ident::new(&format!("{prefix}_{name}_{suffix}"), Span::call_site())
+}
+
+/// The lane suffix for an arithmetic intrinsic, as in `epi16` or `ps`.
+///
+/// When `sign_aware` is set, unsigned types get the `epu` suffix, which
+/// is what min, max and the widening conversions expect.
+pub fn op_suffix(ty: &VecType, sign_aware: bool) -> &'static str {
+    match (ty.scalar, ty.scalar_bits) {
+        (ScalarType::Float, 32) => "ps",
+        (ScalarType::Float, 64) => "pd",
+        (ScalarType::Unsigned, 8) if sign_aware => "epu8",
+        (ScalarType::Unsigned, 16) if sign_aware => "epu16",
+        (ScalarType::Unsigned, 32) if sign_aware => "epu32",
+        (ScalarType::Unsigned, 64) if sign_aware => "epu64",
+        (_, 8) => "epi8",
+        (_, 16) => "epi16",
+        (_, 32) => "epi32",
+        (_, 64) => "epi64",
+        _ => unimplemented!("unsupported lane type {}", ty.rust_name()),
+    }
+}
+
+/// The suffix for bitwise intrinsics, which don't care about lanes.
+pub fn coarse_suffix(ty: &VecType) -> &'static str {
+    match (ty.scalar, ty.n_bits()) {
+        (ScalarType::Float, _) => op_suffix(ty, false),
+        (_, 128) => "si128",
+        (_, 256) => "si256",
+        (_, 512) => "si512",
+        _ => panic!("unsupported simd width"),
+    }
+}
+
+pub fn set1_intrinsic(ty: &VecType) -> Ident {
+    // The 64 bit splats below 512 bits carry an `x` suffix.
+    let suffix = match (ty.scalar, ty.scalar_bits, ty.n_bits()) {
+        (ScalarType::Unsigned | ScalarType::Int | ScalarType::Mask, 64, 128 | 256) => "epi64x",
+        _ => op_suffix(ty, false),
+    };
+    intrinsic_ident("set1", suffix, ty.n_bits())
+}
+
+/// The cast from an integer register to the float register of `ty`.
+pub fn cast_to_float(ty: &VecType) -> Ident {
+    let n_bits = ty.n_bits();
+    intrinsic_ident(&format!("castsi{n_bits}"), op_suffix(ty, false), n_bits)
+}
+
+/// The cast from the float register of `ty` to the integer register of the same width.
+pub fn cast_from_float(ty: &VecType) -> Ident {
+    let n_bits = ty.n_bits();
+    let name = format!("cast{}", op_suffix(ty, false));
+    intrinsic_ident(&name, &format!("si{n_bits}"), n_bits)
+}
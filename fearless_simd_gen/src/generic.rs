@@ -1,10 +1,10 @@
 // Copyright 2025 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
-use crate::ops::reinterpret_ty;
+use crate::ops::{bf16_pairs_ty, lookup_table_ty, reinterpret_ty};
 use crate::{
     ops::{OpSig, TyFlavor},
     types::{ScalarType, VecType},
@@ -17,10 +17,7 @@ pub fn generic_combine(ty: &VecType) -> TokenStream {
     let n2 = n * 2;
     let result = VecType::new(ty.scalar, ty.scalar_bits, n2).rust();
     let name = Ident::new(&format!("combine_{}", ty.rust_name()), Span::call_site());
-    let default = match ty.scalar {
-        ScalarType::Float => quote! { 0.0 },
-        _ => quote! { 0 },
-    };
+    let default = ty.scalar.zero(ty.scalar_bits);
     quote! {
         #[inline(always)]
         fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #result<Self> {
@@ -39,10 +36,7 @@ pub fn generic_split(ty: &VecType) -> TokenStream {
     let nhalf = n / 2;
     let result = VecType::new(ty.scalar, ty.scalar_bits, nhalf).rust();
     let name = Ident::new(&format!("split_{}", ty.rust_name()), Span::call_site());
-    let default = match ty.scalar {
-        ScalarType::Float => quote! { 0.0 },
-        _ => quote! { 0 },
-    };
+    let default = ty.scalar.zero(ty.scalar_bits);
     quote! {
         #[inline(always)]
         fn #name(self, a: #ty_rust<Self>) -> (#result<Self>, #result<Self>) {
@@ -105,6 +99,30 @@ pub fn generic_op(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::BinaryTo(to) => {
+            let half_to = VecType::new(to.scalar, to.scalar_bits, to.len / 2);
+            let combine = Ident::new(
+                &format!("combine_{}", half_to.rust_name()),
+                Span::call_site(),
+            );
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    let (b0, b1) = self.#split(b);
+                    self.#combine(self.#do_half(a0, b0), self.#do_half(a1, b1))
+                }
+            }
+        }
+        OpSig::Refine => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, steps: u32) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    self.#combine(self.#do_half(a0, steps), self.#do_half(a1, steps))
+                }
+            }
+        }
         OpSig::Ternary => {
             quote! {
                 #[inline(always)]
@@ -221,8 +239,1520 @@ pub fn generic_op(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
                 }
             }
         }
+        OpSig::WidenHalf(low) => {
+            let widen = Ident::new(&format!("widen_{}", half.rust_name()), Span::call_site());
+            let part = if low {
+                quote! { 0 }
+            } else {
+                quote! { 1 }
+            };
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    self.#widen(self.#split(a).#part)
+                }
+            }
+        }
+        OpSig::Reduce => {
+            // Float sums add the sums of the halves, which keeps the pairwise
+            // order. Everything else combines the halves first.
+            let body = if op == "reduce_add" && ty.scalar == ScalarType::Float {
+                quote! { self.#do_half(a0) + self.#do_half(a1) }
+            } else if ty.scalar == ScalarType::Float {
+                // To ignore NaN lanes, the NaN lanes of `a1` are replaced with
+                // those of `a0` first. `min_precise` and `max_precise` then give
+                // the number for a NaN in `a0` on every level.
+                let lane_op = Ident::new(
+                    &format!("{}_precise_{}", &op["reduce_".len()..], half.rust_name()),
+                    Span::call_site(),
+                );
+                let is_nan = Ident::new(&format!("is_nan_{}", half.rust_name()), Span::call_site());
+                let select = Ident::new(&format!("select_{}", half.rust_name()), Span::call_site());
+                quote! {
+                    let a1 = self.#select(self.#is_nan(a1), a0, a1);
+                    self.#do_half(self.#lane_op(a0, a1))
+                }
+            } else {
+                let lane_op = Ident::new(
+                    &format!("{}_{}", &op["reduce_".len()..], half.rust_name()),
+                    Span::call_site(),
+                );
+                quote! { self.#do_half(self.#lane_op(a0, a1)) }
+            };
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    #body
+                }
+            }
+        }
+        OpSig::MaskQuery(_) => {
+            let body = match op {
+                "any" => quote! { self.#do_half(a0) || self.#do_half(a1) },
+                "all" => quote! { self.#do_half(a0) && self.#do_half(a1) },
+                "to_bitmask" => {
+                    let shift = Literal::usize_unsuffixed(half.len);
+                    quote! { self.#do_half(a0) | (self.#do_half(a1) << #shift) }
+                }
+                _ => return generic_mask_query(op, ty),
+            };
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    #body
+                }
+            }
+        }
+        OpSig::Shuffle(_) => generic_shuffle(op, sig, ty),
+        OpSig::Reverse => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    self.#combine(self.#do_half(a1), self.#do_half(a0))
+                }
+            }
+        }
+        // Both halves are rotated by the same amount, which wraps around within
+        // each half. The lanes that should have crossed into the other half are
+        // then swapped with a select. `first` has the first lanes of each half,
+        // which stay in that half for a left rotation, and come from the other
+        // half for a right rotation.
+        OpSig::Rotate(left) => {
+            let (n, h) = (
+                Literal::usize_unsuffixed(ty.len),
+                Literal::usize_unsuffixed(half.len),
+            );
+            let mask_name = half.mask_ty().rust_name();
+            let from_bitmask = Ident::new(&format!("from_bitmask_{mask_name}"), Span::call_site());
+            let select = Ident::new(&format!("select_{}", half.rust_name()), Span::call_site());
+            let (first_lanes, low, high) = if left {
+                (
+                    quote! { #h - K % #h },
+                    quote! { self.#select(first, x, y) },
+                    quote! { self.#select(first, y, x) },
+                )
+            } else {
+                (
+                    quote! { K % #h },
+                    quote! { self.#select(first, y, x) },
+                    quote! { self.#select(first, x, y) },
+                )
+            };
+            quote! {
+                #[inline(always)]
+                fn #name<const K: usize>(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    let (x, y) = if K % #n < #h { (a0, a1) } else { (a1, a0) };
+                    let (x, y) = (self.#do_half::<K>(x), self.#do_half::<K>(y));
+                    let first = self.#from_bitmask((1 << (#first_lanes)) - 1);
+                    self.#combine(#low, #high)
+                }
+            }
+        }
+        OpSig::Broadcast => {
+            let (n, h) = (
+                Literal::usize_unsuffixed(ty.len),
+                Literal::usize_unsuffixed(half.len),
+            );
+            quote! {
+                #[inline(always)]
+                fn #name<const L: usize>(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    let lanes = self.#do_half::<L>(if L % #n < #h { a0 } else { a1 });
+                    self.#combine(lanes, lanes)
+                }
+            }
+        }
+        OpSig::Unzip(_) => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    let (b0, b1) = self.#split(b);
+                    self.#combine(self.#do_half(a0, a1), self.#do_half(b0, b1))
+                }
+            }
+        }
+        OpSig::FromBitmask => {
+            let shift = Literal::usize_unsuffixed(half.len);
+            quote! {
+                #[inline(always)]
+                fn #name(self, bits: u64) -> #ret_ty {
+                    self.#combine(self.#do_half(bits), self.#do_half(bits >> #shift))
+                }
+            }
+        }
+        OpSig::DotBf16 => {
+            let split_pairs = Ident::new(
+                &format!("split_{}", bf16_pairs_ty(ty).rust_name()),
+                Span::call_site(),
+            );
+            let pairs_ty = bf16_pairs_ty(ty).rust();
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #pairs_ty<Self>, c: #pairs_ty<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#split(a);
+                    let (b0, b1) = self.#split_pairs(b);
+                    let (c0, c1) = self.#split_pairs(c);
+                    self.#combine(self.#do_half(a0, b0, c0), self.#do_half(a1, b1, c1))
+                }
+            }
+        }
+        OpSig::Lookup(table_len) => {
+            // Only the indices are split; each half looks in the whole table.
+            let table_ty = lookup_table_ty(table_len, TyFlavor::SimdTrait);
+            let lookup = Ident::new(
+                &format!("lookup_{table_len}_{}", half.rust_name()),
+                Span::call_site(),
+            );
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #table_ty, b: #ty_rust<Self>) -> #ret_ty {
+                    let (b0, b1) = self.#split(b);
+                    self.#combine(self.#lookup(a, b0), self.#lookup(a, b1))
+                }
+            }
+        }
+        OpSig::Classify => generic_classify(op, ty),
         OpSig::Split => generic_split(ty),
         OpSig::Combine => generic_combine(ty),
         OpSig::LoadInterleaved(_, _) | OpSig::StoreInterleaved(_, _) => unimplemented!(),
     }
 }
+
+/// Implementation of an op on a 64 bit vector with the 128 bit op
+///
+/// The arguments are duplicated into both halves of a 128 bit vector, and
+/// the low half of the result is kept. Only suitable for lane-wise operations
+/// and zips, for levels whose registers are all 128 bits or wider.
+pub fn generic_half_op(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let combine = Ident::new(&format!("combine_{}", ty.rust_name()), Span::call_site());
+    let double = VecType::new(ty.scalar, ty.scalar_bits, ty.len * 2);
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    if let OpSig::Reduce = sig {
+        let do_double = Ident::new(&format!("{op}_{}", double.rust_name()), Span::call_site());
+        let body = if op != "reduce_add" {
+            // The other reductions don't care about duplicated lanes.
+            quote! { self.#do_double(self.#combine(a, a)) }
+        } else if ty.scalar == ScalarType::Float {
+            quote! { a[0] + a[1] }
+        } else {
+            let splat = Ident::new(&format!("splat_{}", ty.rust_name()), Span::call_site());
+            quote! { self.#do_double(self.#combine(a, self.#splat(0))) }
+        };
+        return quote! {
+            #[inline(always)]
+            fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                #body
+            }
+        };
+    }
+    // Rotating the doubled vector wraps around the same way.
+    if let OpSig::Rotate(_) | OpSig::Broadcast = sig {
+        let do_double = Ident::new(&format!("{op}_{}", double.rust_name()), Span::call_site());
+        let split = Ident::new(&format!("split_{}", double.rust_name()), Span::call_site());
+        let generics = sig.generics(ty);
+        let param = if let OpSig::Broadcast = sig {
+            quote! { L }
+        } else {
+            quote! { K }
+        };
+        return quote! {
+            #[inline(always)]
+            fn #name #generics (self, a: #ty_rust<Self>) -> #ret_ty {
+                self.#split(self.#do_double::<#param>(self.#combine(a, a))).0
+            }
+        };
+    }
+    if sig.is_permutation() {
+        return generic_shuffle(op, sig, ty);
+    }
+    if let OpSig::Lookup(table_len) = sig {
+        let table_ty = lookup_table_ty(table_len, TyFlavor::SimdTrait);
+        let do_double = Ident::new(&format!("{op}_{}", double.rust_name()), Span::call_site());
+        let split = Ident::new(&format!("split_{}", double.rust_name()), Span::call_site());
+        // The upper half of the table must be zero, so that the indices past
+        // the end of the 64 bit table still give zero.
+        let table = if op == "swizzle_dyn" {
+            let splat = Ident::new(&format!("splat_{}", ty.rust_name()), Span::call_site());
+            quote! { self.#combine(a, self.#splat(0)) }
+        } else {
+            quote! { a }
+        };
+        return quote! {
+            #[inline(always)]
+            fn #name(self, a: #table_ty, b: #ty_rust<Self>) -> #ret_ty {
+                self.#split(self.#do_double(#table, self.#combine(b, b))).0
+            }
+        };
+    }
+    if let OpSig::MaskQuery(_) = sig {
+        let do_double = Ident::new(&format!("{op}_{}", double.rust_name()), Span::call_site());
+        let body = match op {
+            // Duplicated lanes don't change `any` or `all`.
+            "any" | "all" => quote! { self.#do_double(self.#combine(a, a)) },
+            "to_bitmask" => {
+                let low_bits = Literal::u64_unsuffixed((1u64 << ty.len) - 1);
+                quote! { self.#do_double(self.#combine(a, a)) & #low_bits }
+            }
+            _ => return generic_mask_query(op, ty),
+        };
+        return quote! {
+            #[inline(always)]
+            fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                #body
+            }
+        };
+    }
+    // The zip of the low halves of the doubled vectors has the high zip
+    // of the original vectors in its high half.
+    let (do_double, half) = match sig {
+        OpSig::Zip(zip_low) => (
+            Ident::new(
+                &format!("zip_low_{}", double.rust_name()),
+                Span::call_site(),
+            ),
+            if zip_low {
+                quote! { 0 }
+            } else {
+                quote! { 1 }
+            },
+        ),
+        _ => (
+            Ident::new(&format!("{op}_{}", double.rust_name()), Span::call_site()),
+            quote! { 0 },
+        ),
+    };
+    let result_ty = match sig {
+        OpSig::Compare => double.mask_ty(),
+        OpSig::Cvt(scalar, scalar_bits) => VecType::new(scalar, scalar_bits, double.len),
+        OpSig::Reinterpret(scalar, scalar_bits) => reinterpret_ty(&double, scalar, scalar_bits),
+        OpSig::BinaryTo(to) => VecType::new(to.scalar, to.scalar_bits, to.len * 2),
+        _ => double,
+    };
+    let split = Ident::new(
+        &format!("split_{}", result_ty.rust_name()),
+        Span::call_site(),
+    );
+    let (args, call_args) = match sig {
+        OpSig::Splat => {
+            let scalar = ty.scalar.rust(ty.scalar_bits);
+            (quote! { val: #scalar }, quote! { val })
+        }
+        OpSig::Unary | OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _) => {
+            (quote! { a: #ty_rust<Self> }, quote! { self.#combine(a, a) })
+        }
+        OpSig::Binary | OpSig::BinaryTo(_) | OpSig::Compare | OpSig::Zip(_) => (
+            quote! { a: #ty_rust<Self>, b: #ty_rust<Self> },
+            quote! { self.#combine(a, a), self.#combine(b, b) },
+        ),
+        OpSig::Ternary => (
+            quote! { a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self> },
+            quote! { self.#combine(a, a), self.#combine(b, b), self.#combine(c, c) },
+        ),
+        OpSig::Shift => (
+            quote! { a: #ty_rust<Self>, shift: u32 },
+            quote! { self.#combine(a, a), shift },
+        ),
+        OpSig::Refine => (
+            quote! { a: #ty_rust<Self>, steps: u32 },
+            quote! { self.#combine(a, a), steps },
+        ),
+        OpSig::Select => {
+            let mask = ty.mask_ty();
+            let mask_rust = mask.rust();
+            let combine_mask =
+                Ident::new(&format!("combine_{}", mask.rust_name()), Span::call_site());
+            (
+                quote! { a: #mask_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self> },
+                quote! { self.#combine_mask(a, a), self.#combine(b, b), self.#combine(c, c) },
+            )
+        }
+        OpSig::FromBitmask => (quote! { bits: u64 }, quote! { bits }),
+        _ => unimplemented!("{op} is not a lane-wise operation"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, #args) -> #ret_ty {
+            self.#split(self.#do_double(#call_args)).#half
+        }
+    }
+}
+
+/// The call of the `crate::shuffle` helper that rotates or broadcasts the
+/// lanes of a native vector, as bytes.
+///
+/// The helpers match on the lane count or index to pass it to the byte
+/// alignment and permutation intrinsics as an immediate.
+pub fn native_lane_move(sig: OpSig, ty: &VecType) -> TokenStream {
+    let n = Literal::usize_unsuffixed(ty.len);
+    let lane_bytes = Literal::usize_unsuffixed(ty.scalar_bits / 8);
+    let (helper, lanes) = match sig {
+        OpSig::Rotate(true) => ("rotate", quote! { K % #n }),
+        OpSig::Rotate(false) => ("rotate", quote! { (#n - K % #n) % #n }),
+        OpSig::Broadcast => ("broadcast", quote! { L % #n }),
+        _ => unreachable!(),
+    };
+    let helper = Ident::new(&format!("{helper}_{}", ty.n_bits()), Span::call_site());
+    quote! { crate::shuffle::#helper(a.to_bytes().into(), #lane_bytes, #lanes) }
+}
+
+/// Implementation of lane permutations one lane at a time
+///
+/// With constant indices, the compiler can usually turn this into a
+/// shuffle instruction.
+pub fn generic_shuffle(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let generics = sig.generics(ty);
+    let args = sig.simd_trait_args(ty);
+    let n = Literal::usize_unsuffixed(ty.len);
+    let half = Literal::usize_unsuffixed(ty.len / 2);
+    let (check, lanes) = match sig {
+        OpSig::Shuffle(inputs) => {
+            let limit = Literal::usize_unsuffixed(inputs * ty.len);
+            let lane = if inputs == 1 {
+                quote! { a[I::INDICES[i]] }
+            } else {
+                quote! {
+                    let index = I::INDICES[i];
+                    if index < #n { a[index] } else { b[index - #n] }
+                }
+            };
+            (
+                quote! { const { crate::shuffle::check_indices(&I::INDICES, #limit) }; },
+                lane,
+            )
+        }
+        OpSig::Reverse => (quote! {}, quote! { a[#n - 1 - i] }),
+        OpSig::Rotate(true) => (quote! {}, quote! { a[(i + K) % #n] }),
+        OpSig::Rotate(false) => (quote! {}, quote! { a[(i + #n - K % #n) % #n] }),
+        OpSig::Broadcast => {
+            return quote! {
+                #[inline(always)]
+                fn #name #generics (#args) -> #ret_ty {
+                    [a[L % #n]; #n].simd_into(self)
+                }
+            };
+        }
+        OpSig::Unzip(low) => {
+            let lane = if low {
+                quote! { if i < #half { a[2 * i] } else { b[2 * (i - #half)] } }
+            } else {
+                quote! { if i < #half { a[2 * i + 1] } else { b[2 * (i - #half) + 1] } }
+            };
+            (quote! {}, lane)
+        }
+        _ => unimplemented!("{op} is not a permutation"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #name #generics (#args) -> #ret_ty {
+            #check
+            let lanes: [_; #n] = core::array::from_fn(|i| { #lanes });
+            lanes.simd_into(self)
+        }
+    }
+}
+
+/// Implementation of rounding to nearest with ties away from zero, which
+/// has no instruction on x86 or WASM
+///
+/// The fraction is exact, so comparing it with 0.5 doesn't round. Working on
+/// magnitudes and copying the sign back keeps the sign of a zero result.
+pub fn generic_round(ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (name, trunc, abs, sub, add, splat, simd_ge, select, copysign) = (
+        method("round"),
+        method("trunc"),
+        method("abs"),
+        method("sub"),
+        method("add"),
+        method("splat"),
+        method("simd_ge"),
+        method("select"),
+        method("copysign"),
+    );
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ty_rust<Self> {
+            let int = self.#trunc(a);
+            let frac = self.#abs(self.#sub(a, int));
+            let up = self.#select(
+                self.#simd_ge(frac, self.#splat(0.5)),
+                self.#splat(1.0),
+                self.#splat(0.0),
+            );
+            self.#copysign(self.#add(self.#abs(int), up), a)
+        }
+    }
+}
+
+/// Implementation of a rounding conversion by rounding to nearest, ties
+/// to even, then doing the saturating conversion
+pub fn generic_cvt_round(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let cvt = Ident::new(
+        &format!("{}_{ty_name}", op.trim_end_matches("_round")),
+        Span::call_site(),
+    );
+    let round = Ident::new(&format!("round_ties_even_{ty_name}"), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+            self.#cvt(self.#round(a))
+        }
+    }
+}
+
+/// Whether a conversion is one that `generic_cvt` builds from others on
+/// every level
+///
+/// The levels implement the conversions between f32 and 32 bit integers,
+/// between f64 and 64 bit integers, and from f32 to the other float widths
+/// and back.
+pub fn is_generic_cvt(sig: OpSig, ty: &VecType) -> bool {
+    let OpSig::Cvt(scalar, scalar_bits) = sig else {
+        return false;
+    };
+    let (from, to) = ((ty.scalar, ty.scalar_bits), (scalar, scalar_bits));
+    let native = |float: (ScalarType, usize), other: (ScalarType, usize)| match (float, other) {
+        ((ScalarType::Float, 32), (ScalarType::Int | ScalarType::Unsigned, 32)) => true,
+        ((ScalarType::Float, 64), (ScalarType::Int | ScalarType::Unsigned, 64)) => true,
+        ((ScalarType::Float, 32), (ScalarType::Float | ScalarType::BFloat, _)) => true,
+        _ => false,
+    };
+    !native(from, to) && !native(to, from)
+}
+
+/// Implementation of the conversions that aren't native to any level
+///
+/// Integers are widened or narrowed one step at a time towards a native
+/// conversion. The narrowing saturates, so the result is the same as
+/// converting directly. f16 goes through f32, which holds every f16 value
+/// exactly, and every integer that doesn't overflow f16. The conversions
+/// that would round twice that way go one lane at a time.
+pub fn generic_cvt(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let OpSig::Cvt(scalar, scalar_bits) = sig else {
+        unreachable!("{op} is not a conversion");
+    };
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let to = VecType::new(scalar, scalar_bits, ty.len);
+    let round = if op.ends_with("_round") { "_round" } else { "" };
+    let call = |op: &str, ty: &VecType, arg: TokenStream| {
+        let method = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+        quote! { self.#method(#arg) }
+    };
+    let cvt = |to: &VecType| {
+        let round = if to.scalar == ScalarType::Float {
+            ""
+        } else {
+            round
+        };
+        format!("cvt_{}{round}", to.scalar.rust(to.scalar_bits))
+    };
+    let f32_ty = VecType::new(ScalarType::Float, 32, ty.len);
+    let a = quote! { a };
+    let body = match (ty.scalar, scalar) {
+        // Dividing rounds correctly, unlike multiplying by 1 / 255.
+        _ if op == "cvt_f32_unorm" => {
+            let (div, splat) = (
+                Ident::new(&format!("div_{}", f32_ty.rust_name()), Span::call_site()),
+                Ident::new(&format!("splat_{}", f32_ty.rust_name()), Span::call_site()),
+            );
+            let float = call("cvt_f32", ty, a);
+            quote! { self.#div(#float, self.#splat(255.0)) }
+        }
+        (ScalarType::Int | ScalarType::Unsigned, ScalarType::Float)
+            if ty.scalar_bits == 64 && scalar_bits == 32 =>
+        {
+            return generic_cvt_lanes(op, sig, ty);
+        }
+        (ScalarType::Float, ScalarType::Float) if ty.scalar_bits == 64 => {
+            quote! { a.val.map(f16::from_f64_const).simd_into(self) }
+        }
+        _ if ty.is_f16() => call(&cvt(&to), &f32_ty, call("cvt_f32", ty, a)),
+        (ScalarType::Float, _) if scalar_bits > ty.scalar_bits => {
+            let f64_ty = VecType::new(ScalarType::Float, 64, ty.len);
+            call(&cvt(&to), &f64_ty, call("cvt_f64", ty, a))
+        }
+        (ScalarType::Float, _) => {
+            let wide = VecType::new(scalar, scalar_bits * 2, ty.len);
+            call("narrow_saturating", &wide, call(&cvt(&wide), ty, a))
+        }
+        _ if to.is_f16() => call("cvt_f16", &f32_ty, call("cvt_f32", ty, a)),
+        _ => {
+            let wide = ty.widened().unwrap();
+            call(&cvt(&to), &wide, call("widen", ty, a))
+        }
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+            #body
+        }
+    }
+}
+
+/// Implementation of conversions one lane at a time, for levels without
+/// the instructions
+pub fn generic_cvt_lanes(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    if op.ends_with("_round") {
+        return generic_cvt_round(op, sig, ty);
+    }
+    let OpSig::Cvt(scalar, scalar_bits) = sig else {
+        unreachable!("{op} is not a conversion");
+    };
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let to = scalar.rust(scalar_bits);
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+            a.val.map(|x| x as #to).simd_into(self)
+        }
+    }
+}
+
+/// Implementation of `mul_add_fast` with `madd`, which already picks the
+/// faster of fusing or not
+pub fn generic_mul_add_fast(ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(
+        &format!("mul_add_fast_{}", ty.rust_name()),
+        Span::call_site(),
+    );
+    let madd = Ident::new(&format!("madd_{}", ty.rust_name()), Span::call_site());
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ty_rust<Self> {
+            self.#madd(c, a, b)
+        }
+    }
+}
+
+/// Implementation of `nmadd` and `nmsub` by negating `madd` and `msub`
+///
+/// Negation is exact, so this rounds the same as the op it negates.
+pub fn generic_negated_madd(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let neg = Ident::new(&format!("neg_{}", ty.rust_name()), Span::call_site());
+    let madd = Ident::new(
+        &format!("{}_{}", &op[1..], ty.rust_name()),
+        Span::call_site(),
+    );
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ty_rust<Self> {
+            self.#neg(self.#madd(a, b, c))
+        }
+    }
+}
+
+/// Implementation of `mul_add_fused` for levels without a fused multiply-add
+///
+/// For f32 lanes, the product is exact in f64, so only the sum rounds. Rounding
+/// the sum to odd, and then to f32, gives the correctly rounded result. There
+/// is no wider type for f64 lanes, so they use the scalar fused multiply-add.
+pub fn generic_mul_add_fused(ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("mul_add_fused_{ty_name}"), Span::call_site());
+    if ty.scalar_bits == 64 {
+        return quote! {
+            #[inline(always)]
+            fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ty_rust<Self> {
+                let fallback = crate::Fallback::new();
+                fallback
+                    .#name(
+                        a.val.simd_into(fallback),
+                        b.val.simd_into(fallback),
+                        c.val.simd_into(fallback),
+                    )
+                    .val
+                    .simd_into(self)
+            }
+        };
+    }
+    assert_eq!(
+        (ty.scalar_bits, ty.len),
+        (32, 4),
+        "{ty_name} has no f64 vector of the same length"
+    );
+    let wide = VecType::new(ScalarType::Float, 64, ty.len);
+    let mul = Ident::new(&format!("mul_{}", wide.rust_name()), Span::call_site());
+    let cvt_f64 = Ident::new(&format!("cvt_f64_{ty_name}"), Span::call_site());
+    let cvt_f32 = Ident::new(&format!("cvt_f32_{}", wide.rust_name()), Span::call_site());
+    let sum_to_odd = wide_sum_to_odd(&wide);
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ty_rust<Self> {
+            let product = self.#mul(self.#cvt_f64(a), self.#cvt_f64(b));
+            let c = self.#cvt_f64(c);
+            #sum_to_odd
+            self.#cvt_f32(sum)
+        }
+    }
+}
+
+/// Statements that add the exact `product` and `c`, which are vectors of
+/// `wide`, into `sum`, rounded to odd.
+///
+/// Rounding to odd keeps the sum from rounding twice when it is then narrowed
+/// to a type with at most half the precision, minus one bit.
+fn wide_sum_to_odd(wide: &VecType) -> TokenStream {
+    let bits = VecType::new(ScalarType::Unsigned, wide.scalar_bits, wide.len);
+    let wide_ty = wide.rust();
+    let bits_ty = bits.rust();
+    let (sign_shift, minus_one, infinity) = if wide.scalar_bits == 64 {
+        (quote! { 63 }, quote! { u64::MAX }, quote! { f64::INFINITY })
+    } else {
+        (quote! { 31 }, quote! { u32::MAX }, quote! { f32::INFINITY })
+    };
+    let f =
+        |op: &str, ty: &VecType| Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let (add, sub, abs, splat, simd_eq, simd_lt) = (
+        f("add", wide),
+        f("sub", wide),
+        f("abs", wide),
+        f("splat", wide),
+        f("simd_eq", wide),
+        f("simd_lt", wide),
+    );
+    let (bits_and, bits_xor, bits_add, bits_shr, bits_splat, bits_eq, select) = (
+        f("and", &bits),
+        f("xor", &bits),
+        f("add", &bits),
+        f("shr", &bits),
+        f("splat", &bits),
+        f("simd_eq", &bits),
+        f("select", &bits),
+    );
+    quote! {
+        let sum = self.#add(product, c);
+        // The rounding error of the sum, which is exact.
+        let product_part = self.#sub(sum, c);
+        let c_part = self.#sub(sum, product_part);
+        let error = self.#add(
+            self.#sub(product, product_part),
+            self.#sub(c, c_part),
+        );
+        // Round to odd: if the sum is inexact and its last bit is even, move
+        // it one ulp towards the exact result.
+        let sum_bits: #bits_ty<Self> = sum.bitcast();
+        let error_bits: #bits_ty<Self> = error.bitcast();
+        let zero = self.#bits_splat(0);
+        let same_sign = self.#bits_eq(self.#bits_shr(self.#bits_xor(sum_bits, error_bits), #sign_shift), zero);
+        let step = self.#select(same_sign, self.#bits_splat(1), self.#bits_splat(#minus_one));
+        let odd = self.#bits_eq(self.#bits_and(sum_bits, self.#bits_splat(1)), self.#bits_splat(1));
+        let step = self.#select(odd, zero, step);
+        let step = self.#select(self.#simd_eq(error, self.#splat(0.0)), zero, step);
+        // The error is meaningless for infinities and NaNs.
+        let finite = self.#simd_lt(self.#abs(sum), self.#splat(#infinity));
+        let step = self.#select(finite, step, zero);
+        let sum: #wide_ty<Self> = self.#bits_add(sum_bits, step).bitcast();
+    }
+}
+
+/// Implementation of the reciprocal ops with exact division, for levels
+/// without an estimate instruction
+///
+/// The exact result is within every error bound, so `steps` is ignored.
+pub fn generic_recip(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (div, sqrt, splat) = (method("div"), method("sqrt"), method("splat"));
+    let one = if ty.is_f16() {
+        quote! { f16::from_f32_const(1.0) }
+    } else {
+        quote! { 1.0 }
+    };
+    let divisor = if op.starts_with("rsqrt") {
+        quote! { self.#sqrt(a) }
+    } else {
+        quote! { a }
+    };
+    let args = match sig {
+        OpSig::Refine => quote! { a: #ty_rust<Self>, _steps: u32 },
+        _ => quote! { a: #ty_rust<Self> },
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, #args) -> #ty_rust<Self> {
+            self.#div(self.#splat(#one), #divisor)
+        }
+    }
+}
+
+/// Implementation of `recip` and `rsqrt` by refining the estimate with
+/// Newton-Raphson steps
+///
+/// Lanes whose estimate is not normal are fixed up by [`refine_fixup`].
+pub fn generic_refine(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (approx, mul, msub, splat) = (
+        method(&format!("{op}_approx")),
+        method("mul"),
+        method("msub"),
+        method("splat"),
+    );
+    let fixup = refine_fixup(op, ty);
+    let (setup, step) = if op == "recip" {
+        // x * (2 - a * x)
+        (
+            quote! {},
+            quote! { self.#mul(x, self.#msub(self.#splat(2.0), a, x)) },
+        )
+    } else {
+        // x * (1.5 - a / 2 * x * x)
+        (
+            quote! { let half = self.#mul(self.#splat(0.5), a); },
+            quote! { self.#mul(x, self.#msub(self.#splat(1.5), self.#mul(half, x), x)) },
+        )
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, steps: u32) -> #ty_rust<Self> {
+            let estimate = self.#approx(a);
+            #setup
+            let mut x = estimate;
+            for _ in 0..steps {
+                x = #step;
+            }
+            #fixup
+        }
+    }
+}
+
+/// The end of a `recip` or `rsqrt` body that refines an estimate
+///
+/// Most estimate instructions only cover normal inputs with normal
+/// reciprocals, and give zero or infinity otherwise. The steps can't recover
+/// from that (they multiply zero by infinity, or infinity by a negative
+/// factor), and lose precision on subnormal inputs even with a good estimate,
+/// so those lanes are divided exactly instead. This expects `a`, `estimate`
+/// and the refined `x` as vectors, and evaluates to the result.
+pub fn refine_fixup(op: &str, ty: &VecType) -> TokenStream {
+    let ty_name = ty.rust_name();
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let mask_name = ty.mask_ty().rust_name();
+    let (abs, div, sqrt, splat, simd_lt, simd_eq, select) = (
+        method("abs"),
+        method("div"),
+        method("sqrt"),
+        method("splat"),
+        method("simd_lt"),
+        method("simd_eq"),
+        method("select"),
+    );
+    let or = Ident::new(&format!("or_{mask_name}"), Span::call_site());
+    let any = Ident::new(&format!("any_{mask_name}"), Span::call_site());
+    let scalar = ty.scalar.rust(ty.scalar_bits);
+    let divisor = if op == "rsqrt" {
+        quote! { self.#sqrt(a) }
+    } else {
+        quote! { a }
+    };
+    quote! {
+        let min_positive = self.#splat(#scalar::MIN_POSITIVE);
+        let magnitude = self.#abs(estimate);
+        let out_of_range = self.#or(
+            self.#or(
+                self.#simd_lt(self.#abs(a), min_positive),
+                self.#simd_lt(magnitude, min_positive),
+            ),
+            self.#simd_eq(magnitude, self.#splat(#scalar::INFINITY)),
+        );
+        if self.#any(out_of_range) {
+            let exact = self.#div(self.#splat(1.0), #divisor);
+            self.#select(out_of_range, exact, x)
+        } else {
+            x
+        }
+    }
+}
+
+/// Implementation of the float classification ops with comparisons
+///
+/// These work on the lanes directly, rather than on their bits, so every lane
+/// type and width is covered. f16 lanes must not go through f32 here, as that
+/// would make subnormals normal.
+pub fn generic_classify(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let mask_ty = ty.mask_ty();
+    let mask_rust = mask_ty.rust();
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (abs, splat, copysign, simd_eq, simd_ne, simd_lt, simd_gt) = (
+        method("abs"),
+        method("splat"),
+        method("copysign"),
+        method("simd_eq"),
+        method("simd_ne"),
+        method("simd_lt"),
+        method("simd_gt"),
+    );
+    let and_mask = Ident::new(&format!("and_{}", mask_ty.rust_name()), Span::call_site());
+    let (zero, one, infinity, min_positive) = if ty.is_f16() {
+        (
+            quote! { f16::from_bits(0) },
+            quote! { f16::from_bits(0x3c00) },
+            quote! { f16::from_bits(0x7c00) },
+            quote! { f16::from_bits(0x0400) },
+        )
+    } else {
+        let scalar = ty.scalar.rust(ty.scalar_bits);
+        (
+            quote! { 0.0 },
+            quote! { 1.0 },
+            quote! { #scalar::INFINITY },
+            quote! { #scalar::MIN_POSITIVE },
+        )
+    };
+    let body = match op {
+        "is_nan" => quote! { self.#simd_ne(a, a) },
+        "is_finite" => quote! { self.#simd_lt(self.#abs(a), self.#splat(#infinity)) },
+        "is_infinite" => quote! { self.#simd_eq(self.#abs(a), self.#splat(#infinity)) },
+        "is_subnormal" => quote! {
+            let abs = self.#abs(a);
+            self.#and_mask(
+                self.#simd_lt(abs, self.#splat(#min_positive)),
+                self.#simd_gt(abs, self.#splat(#zero)),
+            )
+        },
+        // `copysign` moves the sign bit of zeros and NaNs too.
+        "is_sign_negative" => quote! {
+            self.#simd_lt(self.#copysign(self.#splat(#one), a), self.#splat(#zero))
+        },
+        _ => unimplemented!("{op} is not a classification"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #mask_rust<Self> {
+            #body
+        }
+    }
+}
+
+/// Implementation of `abs_diff` as the difference of the larger and the
+/// smaller lane
+///
+/// The difference wraps, so it is right as an unsigned number even when it
+/// doesn't fit in the signed lane type.
+pub fn generic_abs_diff(ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("abs_diff_{ty_name}"), Span::call_site());
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (sub, max, min) = (method("sub"), method("max"), method("min"));
+    let to = VecType::new(ScalarType::Unsigned, ty.scalar_bits, ty.len);
+    let to_rust = to.rust();
+    let diff = quote! { self.#sub(self.#max(a, b), self.#min(a, b)) };
+    let body = if ty.scalar == ScalarType::Unsigned {
+        diff
+    } else {
+        quote! { #diff.bitcast() }
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #to_rust<Self> {
+            #body
+        }
+    }
+}
+
+/// Implementation of saturating narrowing, which clamps the lanes to the
+/// range of the narrower type before truncating them
+pub fn generic_narrow_saturating(ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("narrow_saturating_{ty_name}"), Span::call_site());
+    let method = |op: &str| Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let (min, max, splat, narrow) = (
+        method("min"),
+        method("max"),
+        method("splat"),
+        method("narrow"),
+    );
+    let to = ty.narrowed().unwrap();
+    let to_rust = to.rust();
+    let scalar = ty.scalar.rust(ty.scalar_bits);
+    let to_scalar = to.scalar.rust(to.scalar_bits);
+    let clamped = quote! { self.#min(a, self.#splat(#to_scalar::MAX as #scalar)) };
+    let clamped = if ty.scalar == ScalarType::Unsigned {
+        clamped
+    } else {
+        quote! { self.#max(#clamped, self.#splat(#to_scalar::MIN as #scalar)) }
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #to_rust<Self> {
+            self.#narrow(#clamped)
+        }
+    }
+}
+
+/// Implementation of table lookups one byte at a time
+pub fn generic_lookup(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let args = sig.simd_trait_args(ty);
+    let n = Literal::usize_unsuffixed(ty.len);
+    // The 48 byte table is an array of three vectors.
+    let get = if matches!(sig, OpSig::Lookup(48)) {
+        quote! { a.get(b[i] as usize / 16).map_or(0, |t| t[b[i] as usize % 16]) }
+    } else {
+        quote! { a.get(b[i] as usize).copied().unwrap_or(0) }
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(#args) -> #ret_ty {
+            let lanes: [u8; #n] = core::array::from_fn(|i| #get);
+            lanes.simd_into(self)
+        }
+    }
+}
+
+/// Implementation of a lookup in a 32, 48 or 64 byte table with lookups in
+/// each half of the table
+///
+/// The indices for the upper half are moved down with a wrapping subtract,
+/// which moves the indices for the lower half out of range, so the results
+/// can be or-ed. The 48 byte table is split into its first 32 bytes and its
+/// last 16 bytes instead.
+pub fn generic_lookup_tables(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let OpSig::Lookup(table_len) = sig else {
+        unimplemented!("{op} is not a lookup");
+    };
+    let ty_rust = ty.rust();
+    let ty_name = ty.rust_name();
+    let name = Ident::new(&format!("{op}_{ty_name}"), Span::call_site());
+    let sub = Ident::new(&format!("sub_{ty_name}"), Span::call_site());
+    let or = Ident::new(&format!("or_{ty_name}"), Span::call_site());
+    let splat = Ident::new(&format!("splat_{ty_name}"), Span::call_site());
+    if table_len == 48 {
+        let table_ty = lookup_table_ty(table_len, TyFlavor::SimdTrait);
+        let lookup_32 = Ident::new(&format!("lookup_32_{ty_name}"), Span::call_site());
+        let swizzle = Ident::new(&format!("swizzle_dyn_{ty_name}"), Span::call_site());
+        return quote! {
+            #[inline(always)]
+            fn #name(self, a: #table_ty, b: #ty_rust<Self>) -> #ty_rust<Self> {
+                let high = self.#sub(b, self.#splat(32));
+                self.#or(
+                    self.#lookup_32(self.combine_u8x16(a[0], a[1]), b),
+                    self.#swizzle(a[2], high),
+                )
+            }
+        };
+    }
+    let table_ty = VecType::new(ScalarType::Unsigned, 8, table_len);
+    let split = Ident::new(
+        &format!("split_{}", table_ty.rust_name()),
+        Span::call_site(),
+    );
+    let table_ty = table_ty.rust();
+    let do_half = if table_len == 32 {
+        Ident::new(&format!("swizzle_dyn_{ty_name}"), Span::call_site())
+    } else {
+        Ident::new(
+            &format!("lookup_{}_{ty_name}", table_len / 2),
+            Span::call_site(),
+        )
+    };
+    let half = Literal::u8_unsuffixed((table_len / 2) as u8);
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #table_ty<Self>, b: #ty_rust<Self>) -> #ty_rust<Self> {
+            let (a0, a1) = self.#split(a);
+            let high = self.#sub(b, self.#splat(#half));
+            self.#or(self.#do_half(a0, b), self.#do_half(a1, high))
+        }
+    }
+}
+
+/// The byte indices of a fixed permutation of a 128 bit vector.
+///
+/// For `Unzip`, the bytes of the second input are numbered from 16.
+pub fn permutation_bytes(sig: OpSig, ty: &VecType) -> Vec<u8> {
+    let lane_bytes = ty.scalar_bits / 8;
+    let half = ty.len / 2;
+    (0..16)
+        .map(|i| {
+            let lane = i / lane_bytes;
+            let src_lane = match sig {
+                OpSig::Reverse => ty.len - 1 - lane,
+                OpSig::Unzip(low) => {
+                    let odd = usize::from(!low);
+                    if lane < half {
+                        2 * lane + odd
+                    } else {
+                        ty.len + 2 * (lane - half) + odd
+                    }
+                }
+                _ => unimplemented!("not a fixed permutation"),
+            };
+            (src_lane * lane_bytes + i % lane_bytes) as u8
+        })
+        .collect()
+}
+
+/// Implementation of the mask queries that follow from `any` and `to_bitmask`
+///
+/// Used by every level for `none`, `count_true` and `first_true`.
+pub fn generic_mask_query(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let any = Ident::new(&format!("any_{}", ty.rust_name()), Span::call_site());
+    let to_bitmask = Ident::new(&format!("to_bitmask_{}", ty.rust_name()), Span::call_site());
+    let (ret_ty, body) = match op {
+        "none" => (quote! { bool }, quote! { !self.#any(a) }),
+        "count_true" => (
+            quote! { usize },
+            quote! { self.#to_bitmask(a).count_ones() as usize },
+        ),
+        "first_true" => (
+            quote! { Option<usize> },
+            quote! {
+                let bits = self.#to_bitmask(a);
+                if bits == 0 {
+                    None
+                } else {
+                    Some(bits.trailing_zeros() as usize)
+                }
+            },
+        ),
+        _ => unimplemented!("{op} needs a native implementation"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+            #body
+        }
+    }
+}
+
+/// Implementation of f16 operations based on the f32 operations
+///
+/// Lanes are converted to f32, the operation is applied to the f32
+/// vector of the same length, and the result is rounded back to f16.
+/// Used by levels without native half-precision arithmetic.
+pub fn generic_f16_op(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let f32_ty = VecType::new(ScalarType::Float, 32, ty.len);
+    let do_f32 = Ident::new(&format!("{op}_{}", f32_ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let n = ty.len;
+    match sig {
+        OpSig::Splat => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, val: f16) -> #ret_ty {
+                    [val; #n].simd_into(self)
+                }
+            }
+        }
+        OpSig::Unary => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    self.#do_f32(a).val.map(f16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::Refine => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, steps: u32) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    self.#do_f32(a, steps).val.map(f16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::Binary => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    let b = b.val.map(f16::to_f32_const).simd_into(self);
+                    self.#do_f32(a, b).val.map(f16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        // The product of two f16 values is exact in f32, so the fused
+        // multiply-add only has to avoid rounding the sum twice.
+        OpSig::Ternary if op == "mul_add_fused" => {
+            let mul = Ident::new(&format!("mul_{}", f32_ty.rust_name()), Span::call_site());
+            let sum_to_odd = wide_sum_to_odd(&f32_ty);
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    let b = b.val.map(f16::to_f32_const).simd_into(self);
+                    let c = c.val.map(f16::to_f32_const).simd_into(self);
+                    let product = self.#mul(a, b);
+                    #sum_to_odd
+                    sum.val.map(f16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::Ternary => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    let b = b.val.map(f16::to_f32_const).simd_into(self);
+                    let c = c.val.map(f16::to_f32_const).simd_into(self);
+                    self.#do_f32(a, b, c).val.map(f16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::Compare => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    let b = b.val.map(f16::to_f32_const).simd_into(self);
+                    self.#do_f32(a, b).val.map(|x| x as i16).simd_into(self)
+                }
+            }
+        }
+        OpSig::Select => {
+            let mask = ty.mask_ty().rust();
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #mask<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ret_ty {
+                    let result: [f16; #n] =
+                        core::array::from_fn(|i| if a.val[i] != 0 { b.val[i] } else { c.val[i] });
+                    result.simd_into(self)
+                }
+            }
+        }
+        OpSig::Zip(zip1) => {
+            // Zips don't do arithmetic, so they move the f16 lanes directly.
+            let idx = if zip1 {
+                quote! { i / 2 }
+            } else {
+                let half = n / 2;
+                quote! { #half + i / 2 }
+            };
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    let result: [f16; #n] = core::array::from_fn(|i| {
+                        if i % 2 == 0 { a.val[#idx] } else { b.val[#idx] }
+                    });
+                    result.simd_into(self)
+                }
+            }
+        }
+        OpSig::Reduce => {
+            // Reducing in f32 and rounding once gives the same result on every level.
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let a = a.val.map(f16::to_f32_const).simd_into(self);
+                    f16::from_f32_const(self.#do_f32(a))
+                }
+            }
+        }
+        OpSig::Cvt(_, _) => generic_f16_cvt(op, sig, ty),
+        OpSig::Split => generic_split(ty),
+        OpSig::Combine => generic_combine(ty),
+        _ => unimplemented!("{op} is not supported for f16"),
+    }
+}
+
+/// Implementation of conversions between f16 and f32 one lane at a time
+///
+/// This uses the software rounding in `f16`, for levels without hardware
+/// conversion instructions.
+pub fn generic_f16_cvt(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    let convert = match sig {
+        OpSig::Cvt(ScalarType::Float, 32) if ty.is_f16() => quote! { f16::to_f32_const },
+        OpSig::Cvt(ScalarType::Float, 16) if ty.scalar_bits == 32 => {
+            quote! { f16::from_f32_const }
+        }
+        _ => unimplemented!("{op} is not an f16 conversion"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+            a.val.map(#convert).simd_into(self)
+        }
+    }
+}
+
+/// Implementation of reductions on the lanes as scalars
+///
+/// Neighbouring lanes are combined pairwise, which is the order that all
+/// levels use for float sums.
+pub fn scalar_reduce(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let scalar = ty.scalar.rust(ty.scalar_bits);
+    let body = reduce_tree(op, ty, 0, ty.len, false);
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #scalar {
+            #body
+        }
+    }
+}
+
+fn reduce_tree(op: &str, ty: &VecType, start: usize, end: usize, nested: bool) -> TokenStream {
+    if end - start == 1 {
+        let idx = Literal::usize_unsuffixed(start);
+        return quote! { a[#idx] };
+    }
+    let mid = (start + end) / 2;
+    let x = reduce_tree(op, ty, start, mid, true);
+    let y = reduce_tree(op, ty, mid, end, true);
+    let expr = match op {
+        "reduce_add" if ty.scalar == ScalarType::Float => quote! { #x + #y },
+        "reduce_add" => return quote! { #x.wrapping_add(#y) },
+        "reduce_min" => return quote! { #x.min(#y) },
+        "reduce_max" => return quote! { #x.max(#y) },
+        "reduce_and" => quote! { #x & #y },
+        "reduce_or" => quote! { #x | #y },
+        _ => unimplemented!("{op} is not a reduction"),
+    };
+    if nested {
+        quote! { (#expr) }
+    } else {
+        expr
+    }
+}
+
+/// Implementation of bitwise reductions on the 64 bit chunks of a vector
+///
+/// The chunks are combined, and the result is folded in half until it is
+/// the width of a lane. This doesn't need any lane-aware instructions.
+pub fn generic_reduce_bits(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let scalar = ty.scalar.rust(ty.scalar_bits);
+    let bit_op = match op {
+        "reduce_and" => quote! { & },
+        "reduce_or" => quote! { | },
+        _ => unimplemented!("{op} is not a bitwise reduction"),
+    };
+    let n_chunks = Literal::usize_unsuffixed(ty.n_bits() / 64);
+    let rest = (1..ty.n_bits() / 64).map(|i| {
+        let i = Literal::usize_unsuffixed(i);
+        quote! { #bit_op chunks[#i] }
+    });
+    let mut folds = vec![];
+    let mut width = 64;
+    while width > ty.scalar_bits {
+        width /= 2;
+        let shift = Literal::usize_unsuffixed(width);
+        folds.push(quote! { let x = x #bit_op (x >> #shift); });
+    }
+    let combined = quote! { chunks[0] #( #rest )* };
+    let body = match (ty.scalar, ty.scalar_bits) {
+        (ScalarType::Unsigned, 64) => combined,
+        (_, 64) => quote! { (#combined) as #scalar },
+        _ => quote! {
+            let x = #combined;
+            #( #folds )*
+            x as #scalar
+        },
+    };
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>) -> #scalar {
+            let chunks: [u64; #n_chunks] = bytemuck::cast(a.val);
+            #body
+        }
+    }
+}
+
+/// One lane of a shift of `x` by `count`.
+///
+/// For the uniform shifts `count` is a `u32`, and for the variable shifts it is
+/// a lane of the same type as `x`, taken as unsigned. Counts of at least the
+/// lane width give 0, or copies of the sign bit for arithmetic right shifts,
+/// rather than being masked like the `<<` and `>>` of the scalar types.
+pub fn shift_lane(op: &str, ty: &VecType, x: TokenStream, count: TokenStream) -> TokenStream {
+    let bits = ty.scalar_bits;
+    let var = matches!(op, "shl_var" | "shr_var");
+    let count = if var && ty.scalar == ScalarType::Int {
+        let unsigned = ScalarType::Unsigned.rust(bits);
+        quote! { (#count as #unsigned) }
+    } else {
+        count
+    };
+    if ty.scalar == ScalarType::Int && matches!(op, "shr" | "shr_var") {
+        let max = Literal::usize_unsuffixed(bits - 1);
+        return quote! { #x >> #count.min(#max) };
+    }
+    let count = if var {
+        let width = Literal::usize_unsuffixed(bits);
+        if bits == 32 {
+            quote! { #count.min(#width) }
+        } else {
+            quote! { #count.min(#width) as u32 }
+        }
+    } else {
+        count
+    };
+    match op {
+        "shl" | "shl_var" => quote! { #x.checked_shl(#count).unwrap_or(0) },
+        "shr" | "shr_var" => quote! { #x.checked_shr(#count).unwrap_or(0) },
+        _ => unimplemented!("{op} is not a shift"),
+    }
+}
+
+/// Implementation of shifts by a vector of shift counts one lane at a time
+///
+/// Used by levels without an instruction that shifts each lane by its own
+/// amount.
+pub fn generic_shift_var(op: &str, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let shift = shift_lane(op, ty, quote! { x }, quote! { shift });
+    quote! {
+        #[inline(always)]
+        fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ty_rust<Self> {
+            let mut val = a.val;
+            for (lane, shift) in val.iter_mut().zip(b.val) {
+                let x = *lane;
+                *lane = #shift;
+            }
+            val.simd_into(self)
+        }
+    }
+}
+
+/// Implementation of bf16 operations
+///
+/// The lanes are moved around with the u16 operations of the same shape,
+/// and conversions are done one lane at a time in software.
+pub fn generic_bf16_op(op: &str, sig: OpSig, ty: &VecType) -> TokenStream {
+    let ty_rust = ty.rust();
+    let name = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+    let u16_ty = VecType::new(ScalarType::Unsigned, 16, ty.len);
+    let do_u16 = Ident::new(&format!("{op}_{}", u16_ty.rust_name()), Span::call_site());
+    let ret_ty = sig.ret_ty(ty, TyFlavor::SimdTrait);
+    match sig {
+        OpSig::Splat => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, val: bf16) -> #ret_ty {
+                    self.#do_u16(val.to_bits()).bitcast()
+                }
+            }
+        }
+        OpSig::Select => {
+            let mask = ty.mask_ty().rust();
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #mask<Self>, b: #ty_rust<Self>, c: #ty_rust<Self>) -> #ret_ty {
+                    self.#do_u16(a, b.bitcast(), c.bitcast()).bitcast()
+                }
+            }
+        }
+        OpSig::Combine => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #ty_rust<Self>) -> #ret_ty {
+                    self.#do_u16(a.bitcast(), b.bitcast()).bitcast()
+                }
+            }
+        }
+        OpSig::Split => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    let (a0, a1) = self.#do_u16(a.bitcast());
+                    (a0.bitcast(), a1.bitcast())
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 32) if ty.scalar == ScalarType::BFloat => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    a.val.map(bf16::to_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::BFloat, 16) => {
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>) -> #ret_ty {
+                    a.val.map(bf16::from_f32_const).simd_into(self)
+                }
+            }
+        }
+        OpSig::DotBf16 => {
+            let pairs_ty = bf16_pairs_ty(ty).rust();
+            let expr = bf16_dot_expr(op, ty);
+            quote! {
+                #[inline(always)]
+                fn #name(self, a: #ty_rust<Self>, b: #pairs_ty<Self>, c: #pairs_ty<Self>) -> #ret_ty {
+                    #expr
+                }
+            }
+        }
+        _ => unimplemented!("{op} is not supported for bf16"),
+    }
+}
+
+/// The body of a bf16 dot product, for levels without hardware support
+///
+/// The products of two bf16 values are exact in f32, so this matches
+/// the hardware instructions up to the rounding of the sums.
+pub fn bf16_dot_expr(op: &str, ty: &VecType) -> TokenStream {
+    let pairs_ty = bf16_pairs_ty(ty);
+    let cvt = Ident::new(
+        &format!("cvt_f32_{}", pairs_ty.rust_name()),
+        Span::call_site(),
+    );
+    let n = ty.len;
+    let sum = match op {
+        // Each f32 lane accumulates the products of the two bf16 lanes it overlaps.
+        "dot_bf16" => quote! { a.val[i] + b[2 * i + 1] * c[2 * i + 1] + b[2 * i] * c[2 * i] },
+        // `b` is a row-major 2x4 matrix, and `c` is the transpose of a 4x2 matrix.
+        "mmla_bf16" => quote! {
+            let (row, col) = (4 * (i / 2), 4 * (i % 2));
+            a.val[i]
+                + b[row] * c[col]
+                + b[row + 1] * c[col + 1]
+                + b[row + 2] * c[col + 2]
+                + b[row + 3] * c[col + 3]
+        },
+        _ => unimplemented!("missing {op}"),
+    };
+    quote! {
+        let b = self.#cvt(b).val;
+        let c = self.#cvt(c).val;
+        let result: [f32; #n] = core::array::from_fn(|i| { #sum });
+        result.simd_into(self)
+    }
+}
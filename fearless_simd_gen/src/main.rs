@@ -14,6 +14,7 @@ mod mk_ops;
 mod mk_simd_trait;
 mod mk_simd_types;
 mod mk_wasm;
+mod mk_x86;
 mod ops;
 mod types;
 
@@ -23,8 +24,13 @@ enum Module {
     SimdTrait,
     Ops,
     Neon,
+    Fp16,
     Wasm,
+    WasmRelaxedSimd,
     Fallback,
+    Sse4_2,
+    Avx2,
+    Avx512,
 }
 
 #[derive(Parser)]
@@ -34,7 +40,7 @@ enum Module {
     long_about = "Generate SIMD trait implementations for fearless_simd.\n\
                   \n\
                   Generates code for SIMD types, traits, operations, and architecture-specific \
-                  implementations (NEON, WASM, fallback).\n\
+                  implementations (NEON, FP16, WASM, WASM relaxed-simd, SSE4.2, AVX2, AVX-512, fallback).\n\
                   \n\
                   Run from the root of the repository without arguments to automatically \
                   generate all module files in ./fearless_simd/src/generated/."
@@ -51,8 +57,13 @@ impl Module {
             Module::SimdTrait => mk_simd_trait::mk_simd_trait(),
             Module::Ops => mk_ops::mk_ops(),
             Module::Neon => mk_neon::mk_neon_impl(mk_neon::Level::Neon),
+            Module::Fp16 => mk_neon::mk_neon_impl(mk_neon::Level::Fp16),
             Module::Wasm => mk_wasm::mk_wasm128_impl(mk_wasm::Level::WasmSimd128),
+            Module::WasmRelaxedSimd => mk_wasm::mk_wasm128_impl(mk_wasm::Level::WasmRelaxedSimd),
             Module::Fallback => mk_fallback::mk_fallback_impl(),
+            Module::Sse4_2 => mk_x86::mk_x86_impl(mk_x86::Level::Sse4_2),
+            Module::Avx2 => mk_x86::mk_x86_impl(mk_x86::Level::Avx2),
+            Module::Avx512 => mk_x86::mk_x86_impl(mk_x86::Level::Avx512),
         }
     }
 
@@ -79,8 +90,13 @@ impl Module {
             Module::SimdTrait => "simd_trait",
             Module::Ops => "ops",
             Module::Neon => "neon",
+            Module::Fp16 => "fp16",
             Module::Fallback => "fallback",
             Module::Wasm => "wasm",
+            Module::WasmRelaxedSimd => "wasm_relaxed_simd",
+            Module::Sse4_2 => "sse4_2",
+            Module::Avx2 => "avx2",
+            Module::Avx512 => "avx512",
         }
     }
 }
@@ -90,8 +106,13 @@ const MODULES: &[Module] = &[
     Module::SimdTrait,
     Module::Ops,
     Module::Neon,
+    Module::Fp16,
     Module::Fallback,
     Module::Wasm,
+    Module::WasmRelaxedSimd,
+    Module::Sse4_2,
+    Module::Avx2,
+    Module::Avx512,
 ];
 
 const FILE_BASE: &str = "./fearless_simd/src/generated";
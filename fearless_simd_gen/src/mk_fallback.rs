@@ -3,13 +3,18 @@
 
 use crate::arch::fallback::Fallback;
 use crate::arch::{Arch, fallback};
-use crate::generic::{generic_combine, generic_op, generic_split};
+use crate::generic::{
+    generic_bf16_op, generic_classify, generic_combine, generic_cvt, generic_f16_cvt,
+    generic_f16_op, generic_lookup, generic_mask_query, generic_mul_add_fast, generic_negated_madd,
+    generic_op, generic_recip, generic_shuffle, generic_split, is_generic_cvt, scalar_reduce,
+    shift_lane,
+};
 use crate::ops::{
     OpSig, TyFlavor, load_interleaved_arg_ty, ops_for_type, reinterpret_ty,
     store_interleaved_arg_ty, valid_reinterpret,
 };
 use crate::types::{SIMD_TYPES, ScalarType, VecType, type_imports};
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
 #[derive(Clone, Copy)]
@@ -32,16 +37,20 @@ pub fn mk_fallback_impl() -> TokenStream {
 
     quote! {
         use core::ops::*;
-        use crate::{seal::Seal, Level, Simd, SimdInto};
+        use crate::{seal::Seal, Bytes, Indices, Level, Simd, SimdInto};
 
         #imports
 
         #[cfg(all(feature = "libm", not(feature = "std")))]
         trait FloatExt {
-            fn floor(self) -> f32;
-            fn fract(self) -> f32;
-            fn sqrt(self) -> f32;
-            fn trunc(self) -> f32;
+            fn floor(self) -> Self;
+            fn ceil(self) -> Self;
+            fn round(self) -> Self;
+            fn round_ties_even(self) -> Self;
+            fn fract(self) -> Self;
+            fn mul_add(self, a: Self, b: Self) -> Self;
+            fn sqrt(self) -> Self;
+            fn trunc(self) -> Self;
         }
 
         #[cfg(all(feature = "libm", not(feature = "std")))]
@@ -51,6 +60,18 @@ pub fn mk_fallback_impl() -> TokenStream {
                 libm::floorf(self)
             }
             #[inline(always)]
+            fn ceil(self) -> f32 {
+                libm::ceilf(self)
+            }
+            #[inline(always)]
+            fn round(self) -> f32 {
+                libm::roundf(self)
+            }
+            #[inline(always)]
+            fn round_ties_even(self) -> f32 {
+                libm::roundevenf(self)
+            }
+            #[inline(always)]
             fn sqrt(self) -> f32 {
                 libm::sqrtf(self)
             }
@@ -59,11 +80,51 @@ pub fn mk_fallback_impl() -> TokenStream {
                 self - self.trunc()
             }
             #[inline(always)]
+            fn mul_add(self, a: f32, b: f32) -> f32 {
+                libm::fmaf(self, a, b)
+            }
+            #[inline(always)]
             fn trunc(self) -> f32 {
                 libm::truncf(self)
             }
         }
 
+        #[cfg(all(feature = "libm", not(feature = "std")))]
+        impl FloatExt for f64 {
+            #[inline(always)]
+            fn floor(self) -> f64 {
+                libm::floor(self)
+            }
+            #[inline(always)]
+            fn ceil(self) -> f64 {
+                libm::ceil(self)
+            }
+            #[inline(always)]
+            fn round(self) -> f64 {
+                libm::round(self)
+            }
+            #[inline(always)]
+            fn round_ties_even(self) -> f64 {
+                libm::roundeven(self)
+            }
+            #[inline(always)]
+            fn sqrt(self) -> f64 {
+                libm::sqrt(self)
+            }
+            #[inline(always)]
+            fn fract(self) -> f64 {
+                self - self.trunc()
+            }
+            #[inline(always)]
+            fn mul_add(self, a: f64, b: f64) -> f64 {
+                libm::fma(self, a, b)
+            }
+            #[inline(always)]
+            fn trunc(self) -> f64 {
+                libm::trunc(self)
+            }
+        }
+
         /// The SIMD token for the "fallback" level.
         #[derive(Clone, Copy, Debug)]
         pub struct Fallback {
@@ -93,7 +154,33 @@ fn mk_simd_impl() -> TokenStream {
         let ty_name = vec_ty.rust_name();
         let ty = vec_ty.rust();
         for (method, sig) in ops_for_type(vec_ty, true) {
-            let b1 = (vec_ty.n_bits() > 128 && !matches!(method, "split" | "narrow"))
+            if sig.is_permutation() {
+                methods.push(generic_shuffle(method, sig, vec_ty));
+                continue;
+            }
+            if let OpSig::Classify = sig {
+                methods.push(generic_classify(method, vec_ty));
+                continue;
+            }
+            if is_generic_cvt(sig, vec_ty) {
+                methods.push(generic_cvt(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.is_f16() && !matches!(sig, OpSig::Reinterpret(_, _)) {
+                methods.push(generic_f16_op(method, sig, vec_ty));
+                continue;
+            }
+            if matches!(sig, OpSig::Cvt(ScalarType::Float, 16)) {
+                methods.push(generic_f16_cvt(method, sig, vec_ty));
+                continue;
+            }
+            if (vec_ty.scalar == ScalarType::BFloat && !matches!(sig, OpSig::Reinterpret(_, _)))
+                || matches!(sig, OpSig::Cvt(ScalarType::BFloat, _))
+            {
+                methods.push(generic_bf16_op(method, sig, vec_ty));
+                continue;
+            }
+            let b1 = (vec_ty.n_bits() > 128 && method != "split" && !sig.is_narrowing(vec_ty))
                 || vec_ty.n_bits() > 256;
             let b2 = !matches!(method, "load_interleaved_128")
                 && !matches!(method, "store_interleaved_128");
@@ -116,6 +203,8 @@ fn mk_simd_impl() -> TokenStream {
                         }
                     }
                 }
+                OpSig::Unary if method.ends_with("_approx") => generic_recip(method, sig, vec_ty),
+                OpSig::Refine => generic_recip(method, sig, vec_ty),
                 OpSig::Unary => {
                     let items = make_list(
                         (0..vec_ty.len)
@@ -135,11 +224,19 @@ fn mk_simd_impl() -> TokenStream {
                     }
                 }
                 OpSig::WidenNarrow(t) => {
+                    let scalar_ty = t.scalar.rust(t.scalar_bits);
                     let items = make_list(
                         (0..vec_ty.len)
                             .map(|idx| {
-                                let scalar_ty = t.scalar.rust(t.scalar_bits);
-                                quote! { a[#idx] as #scalar_ty }
+                                if method == "narrow_saturating" {
+                                    let src_ty = vec_ty.scalar.rust(vec_ty.scalar_bits);
+                                    quote! {
+                                        a[#idx].clamp(#scalar_ty::MIN as #src_ty, #scalar_ty::MAX as #src_ty)
+                                            as #scalar_ty
+                                    }
+                                } else {
+                                    quote! { a[#idx] as #scalar_ty }
+                                }
                             })
                             .collect::<Vec<_>>(),
                     );
@@ -151,6 +248,55 @@ fn mk_simd_impl() -> TokenStream {
                         }
                     }
                 }
+                OpSig::WidenHalf(low) => {
+                    let t = vec_ty.widened_half().unwrap();
+                    let scalar_ty = t.scalar.rust(t.scalar_bits);
+                    let start = if low { 0 } else { t.len };
+                    let items = make_list(
+                        (start..start + t.len)
+                            .map(|idx| quote! { a[#idx] as #scalar_ty })
+                            .collect::<Vec<_>>(),
+                    );
+
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #items.simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Binary
+                    if matches!(method, "avg_round" | "mul_high" | "mul_q15_round_sat") =>
+                {
+                    let items = make_list(
+                        (0..vec_ty.len)
+                            .map(|idx| widened_lane(method, vec_ty, idx))
+                            .collect::<Vec<_>>(),
+                    );
+
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            #items.simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Binary if matches!(method, "shl_var" | "shr_var") => {
+                    let items = make_list(
+                        (0..vec_ty.len)
+                            .map(|idx| {
+                                shift_lane(method, vec_ty, quote! { a[#idx] }, quote! { b[#idx] })
+                            })
+                            .collect::<Vec<_>>(),
+                    );
+
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            #items.simd_into(self)
+                        }
+                    }
+                }
                 OpSig::Binary => {
                     let items = make_list(
                         (0..vec_ty.len)
@@ -181,18 +327,34 @@ fn mk_simd_impl() -> TokenStream {
                         }
                     }
                 }
-                OpSig::Shift => {
-                    let arch_ty = Fallback.arch_ty(vec_ty);
+                OpSig::BinaryTo(to) => {
                     let items = make_list(
-                        (0..vec_ty.len)
+                        (0..to.len)
                             .map(|idx| {
-                                let args = [quote! { a[#idx] }, quote! { b as #arch_ty }];
-                                let expr = Fallback.expr(method, vec_ty, &args);
-                                quote! { #expr }
+                                if method == "abs_diff" {
+                                    let args = [quote! { a[#idx] }, quote! { b[#idx] }];
+                                    Fallback.expr(method, vec_ty, &args)
+                                } else {
+                                    widened_lane(method, vec_ty, idx)
+                                }
                             })
                             .collect::<Vec<_>>(),
                     );
 
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            #items.simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Shift => {
+                    let items = make_list(
+                        (0..vec_ty.len)
+                            .map(|idx| shift_lane(method, vec_ty, quote! { a[#idx] }, quote! { b }))
+                            .collect::<Vec<_>>(),
+                    );
+
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, a: #ty<Self>, b: u32) -> #ret_ty {
@@ -200,40 +362,44 @@ fn mk_simd_impl() -> TokenStream {
                         }
                     }
                 }
-                OpSig::Ternary => {
-                    if method == "madd" {
-                        // TODO: This is has slightly different semantics than a fused multiply-add,
-                        // since we are not actually fusing it, should this be documented?
-                        quote! {
-                            #[inline(always)]
-                            fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
-                               a.add(b.mul(c))
-                            }
+                OpSig::Ternary => match method {
+                    // Fusing without an instruction for it is slow, so `madd`
+                    // and `msub` round twice here.
+                    "madd" => quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                           a.add(b.mul(c))
                         }
-                    } else if method == "msub" {
-                        // TODO: Same as above
-                        quote! {
-                            #[inline(always)]
-                            fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
-                               a.sub(b.mul(c))
-                            }
+                    },
+                    "msub" => quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                           a.sub(b.mul(c))
                         }
-                    } else {
-                        let args = [
-                            quote! { a.into() },
-                            quote! { b.into() },
-                            quote! { c.into() },
-                        ];
-
-                        let expr = Fallback.expr(method, vec_ty, &args);
+                    },
+                    "nmadd" | "nmsub" => generic_negated_madd(method, vec_ty),
+                    "mul_add_fast" => generic_mul_add_fast(vec_ty),
+                    _ => {
+                        let items = make_list(
+                            (0..vec_ty.len)
+                                .map(|idx| {
+                                    let args = [
+                                        quote! { a[#idx] },
+                                        quote! { b[#idx] },
+                                        quote! { c[#idx] },
+                                    ];
+                                    Fallback.expr(method, vec_ty, &args)
+                                })
+                                .collect::<Vec<_>>(),
+                        );
                         quote! {
                             #[inline(always)]
                             fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
-                               #expr.simd_into(self)
+                               #items.simd_into(self)
                             }
                         }
                     }
-                }
+                },
                 OpSig::Compare => {
                     let mask_type = VecType::new(ScalarType::Mask, vec_ty.scalar_bits, vec_ty.len);
                     let items = make_list(
@@ -273,6 +439,64 @@ fn mk_simd_impl() -> TokenStream {
                 }
                 OpSig::Combine => generic_combine(vec_ty),
                 OpSig::Split => generic_split(vec_ty),
+                OpSig::Reduce => scalar_reduce(method, vec_ty),
+                // Permutations are handled before the per-op dispatch.
+                OpSig::Shuffle(_)
+                | OpSig::Reverse
+                | OpSig::Rotate(_)
+                | OpSig::Broadcast
+                | OpSig::Unzip(_)
+                | OpSig::Classify => {
+                    unreachable!()
+                }
+                OpSig::Lookup(_) => generic_lookup(method, sig, vec_ty),
+                OpSig::MaskQuery(_) => {
+                    let lanes = (0..vec_ty.len).map(|idx| match (method, idx) {
+                        ("to_bitmask", 0) => quote! { (a[0] != 0) as u64 },
+                        ("to_bitmask", _) => {
+                            let shift = Literal::usize_unsuffixed(idx);
+                            quote! { ((a[#idx] != 0) as u64) << #shift }
+                        }
+                        _ => quote! { a[#idx] != 0 },
+                    });
+                    let body = match method {
+                        "any" => quote! { #( #lanes )||* },
+                        "all" => quote! { #( #lanes )&&* },
+                        "to_bitmask" => quote! { #( #lanes )|* },
+                        _ => {
+                            methods.push(generic_mask_query(method, vec_ty));
+                            continue;
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #body
+                        }
+                    }
+                }
+                OpSig::FromBitmask => {
+                    let mask_ty = vec_ty.scalar.rust(scalar_bits);
+                    let items = make_list(
+                        (0..vec_ty.len)
+                            .map(|idx| {
+                                let bit = if idx == 0 {
+                                    quote! { bits & 1 }
+                                } else {
+                                    let shift = Literal::usize_unsuffixed(idx);
+                                    quote! { (bits >> #shift) & 1 }
+                                };
+                                quote! { -((#bit) as #mask_ty) }
+                            })
+                            .collect::<Vec<_>>(),
+                    );
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, bits: u64) -> #ret_ty {
+                            #items.simd_into(self)
+                        }
+                    }
+                }
                 OpSig::Zip(zip1) => {
                     let indices = if zip1 {
                         0..vec_ty.len / 2
@@ -301,7 +525,16 @@ fn mk_simd_impl() -> TokenStream {
                     let items = make_list(
                         (0..vec_ty.len)
                             .map(|idx| {
-                                quote! { a[#idx] as #scalar }
+                                if method.ends_with("_round") {
+                                    let round = Fallback.expr(
+                                        "round_ties_even",
+                                        vec_ty,
+                                        &[quote! { a[#idx] }],
+                                    );
+                                    quote! { #round as #scalar }
+                                } else {
+                                    quote! { a[#idx] as #scalar }
+                                }
                             })
                             .collect::<Vec<_>>(),
                     );
@@ -314,13 +547,31 @@ fn mk_simd_impl() -> TokenStream {
                 }
                 OpSig::Reinterpret(scalar, scalar_bits) => {
                     if valid_reinterpret(vec_ty, scalar, scalar_bits) {
-                        let to_ty = reinterpret_ty(vec_ty, scalar, scalar_bits).rust();
+                        let to = reinterpret_ty(vec_ty, scalar, scalar_bits);
+                        let to_ty = to.rust();
+                        // The 16 bit floats aren't `Pod`, so they are cast through their bits.
+                        let is_half = |ty: &VecType| {
+                            ty.scalar_bits == 16
+                                && matches!(ty.scalar, ScalarType::Float | ScalarType::BFloat)
+                        };
+                        let mut val = quote! { a.val };
+                        if is_half(vec_ty) {
+                            let half = vec_ty.scalar.rust(16);
+                            val = quote! { #val.map(#half::to_bits) };
+                        }
+                        let val = if is_half(&to) {
+                            let half = to.scalar.rust(16);
+                            let len = to.len;
+                            quote! { bytemuck::cast::<_, [u16; #len]>(#val).map(#half::from_bits) }
+                        } else {
+                            quote! { bytemuck::cast(#val) }
+                        };
 
                         quote! {
                             #[inline(always)]
                             fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
                                 #to_ty {
-                                    val: bytemuck::cast(a.val),
+                                    val: #val,
                                     simd: a.simd,
                                 }
                             }
@@ -329,6 +580,7 @@ fn mk_simd_impl() -> TokenStream {
                         quote! {}
                     }
                 }
+                OpSig::DotBf16 => generic_bf16_op(method, sig, vec_ty),
                 OpSig::LoadInterleaved(block_size, count) => {
                     let len = (block_size * count) as usize / vec_ty.scalar_bits;
                     let items = interleave_indices(len, count as usize, |idx| quote! { src[#idx] });
@@ -364,15 +616,19 @@ fn mk_simd_impl() -> TokenStream {
     quote! {
         impl Simd for #level_tok {
             type f32s = f32x4<Self>;
+            type f64s = f64x2<Self>;
             type u8s = u8x16<Self>;
             type i8s = i8x16<Self>;
             type u16s = u16x8<Self>;
             type i16s = i16x8<Self>;
             type u32s = u32x4<Self>;
             type i32s = i32x4<Self>;
+            type u64s = u64x2<Self>;
+            type i64s = i64x2<Self>;
             type mask8s = mask8x16<Self>;
             type mask16s = mask16x8<Self>;
             type mask32s = mask32x4<Self>;
+            type mask64s = mask64x2<Self>;
             #[inline(always)]
             fn level(self) -> Level {
                 Level::#level_tok(self)
@@ -403,7 +659,46 @@ fn interleave_indices(
 
 /// Whether the second argument of the function needs to be passed by reference.
 fn rhs_reference(method: &str) -> bool {
-    !matches!(method, "copysign" | "min" | "max" | "wrapping_sub")
+    !matches!(
+        method,
+        "copysign"
+            | "min"
+            | "max"
+            | "wrapping_add"
+            | "wrapping_sub"
+            | "wrapping_mul"
+            | "saturating_add"
+            | "saturating_sub"
+    )
+}
+
+/// One lane of an integer op that is computed in lanes of twice the width.
+fn widened_lane(method: &str, vec_ty: &VecType, idx: usize) -> TokenStream {
+    let bits = vec_ty.scalar_bits;
+    let scalar = vec_ty.scalar.rust(bits);
+    let wide = vec_ty.scalar.rust(bits * 2);
+    let product = |i: TokenStream| quote! { a[#i] as #wide * b[#i] as #wide };
+    match method {
+        "avg_round" => quote! { ((a[#idx] as #wide + b[#idx] as #wide + 1) >> 1) as #scalar },
+        "mul_widen" => product(quote! { #idx }),
+        "mul_high" => {
+            let product = product(quote! { #idx });
+            let shift = Literal::usize_unsuffixed(bits);
+            quote! { ((#product) >> #shift) as #scalar }
+        }
+        "mul_q15_round_sat" => {
+            let product = product(quote! { #idx });
+            quote! { ((#product + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16 }
+        }
+        "dot_i16" => {
+            let (even, odd) = (2 * idx, 2 * idx + 1);
+            let even = product(quote! { #even });
+            let odd = product(quote! { #odd });
+            // Only `-0x8000` squared twice overflows, and it wraps like `pmaddwd`.
+            quote! { (#even).wrapping_add(#odd) }
+        }
+        _ => unimplemented!("missing {method}"),
+    }
 }
 
 fn make_list(items: Vec<TokenStream>) -> TokenStream {
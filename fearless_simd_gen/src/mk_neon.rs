@@ -4,29 +4,36 @@
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
-use crate::arch::neon::split_intrinsic;
+use crate::arch::neon::{fp16_expr, split_intrinsic};
 use crate::ops::{
-    load_interleaved_arg_ty, reinterpret_ty, store_interleaved_arg_ty, valid_reinterpret,
+    bf16_pairs_ty, load_interleaved_arg_ty, lookup_table_ty, reinterpret_ty,
+    store_interleaved_arg_ty, valid_reinterpret,
 };
 use crate::types::ScalarType;
 use crate::{
     arch::Arch,
     arch::neon::{Neon, cvt_intrinsic, simple_intrinsic},
-    generic::{generic_combine, generic_op, generic_split},
+    generic::{
+        bf16_dot_expr, generic_abs_diff, generic_bf16_op, generic_classify, generic_combine,
+        generic_cvt, generic_f16_op, generic_half_op, generic_mask_query, generic_mul_add_fast,
+        generic_negated_madd, generic_op, generic_recip, generic_reduce_bits, generic_shuffle,
+        generic_split, is_generic_cvt, native_lane_move, refine_fixup, scalar_reduce,
+    },
     ops::{OpSig, TyFlavor, ops_for_type},
     types::{SIMD_TYPES, VecType, type_imports},
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Level {
     Neon,
-    // TODO: Fp16,
+    Fp16,
 }
 
 impl Level {
     fn name(self) -> &'static str {
         match self {
             Level::Neon => "Neon",
+            Level::Fp16 => "Fp16",
         }
     }
 
@@ -34,40 +41,89 @@ impl Level {
         let ident = Ident::new(self.name(), Span::call_site());
         quote! { #ident }
     }
+
+    fn target_features(self) -> &'static str {
+        match self {
+            Level::Neon => "neon",
+            Level::Fp16 => "neon,fp16",
+        }
+    }
 }
 
 pub fn mk_neon_impl(level: Level) -> TokenStream {
     let imports = type_imports();
     let simd_impl = mk_simd_impl(level);
-    let ty_impl = mk_type_impl();
+    let token = mk_token(level);
+    // The conversions between vector types and arch types are shared by
+    // all aarch64 levels, so they're only emitted once.
+    let (ty_impl, simd_from) = match level {
+        Level::Neon => (mk_type_impl(), quote! { SimdFrom, }),
+        Level::Fp16 => (quote! {}, quote! {}),
+    };
 
     quote! {
         use core::arch::aarch64::*;
 
-        use crate::{seal::Seal, Level, Simd, SimdFrom, SimdInto};
+        use crate::{seal::Seal, Bytes, Indices, Level, Simd, #simd_from SimdInto};
 
         #imports
 
-        /// The SIMD token for the "neon" level.
-        #[derive(Clone, Copy, Debug)]
-        pub struct Neon {
-            pub neon: crate::core_arch::aarch64::Neon,
-        }
+        #token
 
-        impl Neon {
-            #[inline]
-            pub unsafe fn new_unchecked() -> Self {
-                Neon {
-                    neon: unsafe { crate::core_arch::aarch64::Neon::new_unchecked() },
+        #simd_impl
+
+        #ty_impl
+    }
+}
+
+fn mk_token(level: Level) -> TokenStream {
+    match level {
+        Level::Neon => quote! {
+            /// The SIMD token for the "neon" level.
+            #[derive(Clone, Copy, Debug)]
+            pub struct Neon {
+                pub neon: crate::core_arch::aarch64::Neon,
+                /// The bf16 instructions, if the CPU supports them.
+                pub bf16: Option<crate::core_arch::aarch64::Bf16>,
+            }
+
+            impl Neon {
+                #[inline]
+                pub unsafe fn new_unchecked() -> Self {
+                    Neon {
+                        neon: unsafe { crate::core_arch::aarch64::Neon::new_unchecked() },
+                        bf16: crate::core_arch::aarch64::Bf16::try_new(),
+                    }
                 }
             }
-        }
 
-        impl Seal for Neon {}
+            impl Seal for Neon {}
+        },
+        Level::Fp16 => quote! {
+            /// The SIMD token for the "fp16" level.
+            ///
+            /// This is the "neon" level plus native half-precision arithmetic.
+            #[derive(Clone, Copy, Debug)]
+            pub struct Fp16 {
+                pub neon: crate::core_arch::aarch64::Neon,
+                pub fp16: crate::core_arch::aarch64::Fp16,
+                /// The bf16 instructions, if the CPU supports them.
+                pub bf16: Option<crate::core_arch::aarch64::Bf16>,
+            }
 
-        #simd_impl
+            impl Fp16 {
+                #[inline]
+                pub unsafe fn new_unchecked() -> Self {
+                    Fp16 {
+                        neon: unsafe { crate::core_arch::aarch64::Neon::new_unchecked() },
+                        fp16: unsafe { crate::core_arch::aarch64::Fp16::new_unchecked() },
+                        bf16: crate::core_arch::aarch64::Bf16::try_new(),
+                    }
+                }
+            }
 
-        #ty_impl
+            impl Seal for Fp16 {}
+        },
     }
 }
 
@@ -80,7 +136,59 @@ fn mk_simd_impl(level: Level) -> TokenStream {
         let ty = vec_ty.rust();
 
         for (method, sig) in ops_for_type(vec_ty, true) {
-            let b1 = (vec_ty.n_bits() > 128 && !matches!(method, "split" | "narrow"))
+            if sig.is_permutation() {
+                methods.push(mk_permutation(method, sig, vec_ty));
+                continue;
+            }
+            if let OpSig::Classify = sig {
+                methods.push(generic_classify(method, vec_ty));
+                continue;
+            }
+            if is_generic_cvt(sig, vec_ty) {
+                methods.push(generic_cvt(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.is_f16() && !matches!(sig, OpSig::Reinterpret(_, _)) {
+                let method = if let OpSig::Reduce = sig {
+                    // Reductions go through f32 on every level, so they round the same way.
+                    generic_f16_op(method, sig, vec_ty)
+                } else if let OpSig::Cvt(_, _) = sig {
+                    // Widening to f32 only needs the base Neon instructions.
+                    if vec_ty.n_bits() == 128 {
+                        let method_ident =
+                            Ident::new(&format!("{method}_{ty_name}"), Span::call_site());
+                        let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+                        quote! {
+                            #[inline(always)]
+                            fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                                unsafe {
+                                    let a = a.into();
+                                    let low = self.neon.vcvt_f32_f16(vget_low_u16(a));
+                                    let high = self.neon.vcvt_high_f32_f16(a);
+                                    float32x4x2_t(low, high).simd_into(self)
+                                }
+                            }
+                        }
+                    } else {
+                        generic_op(method, sig, vec_ty)
+                    }
+                } else if level == Level::Fp16 && vec_ty.n_bits() == 128 {
+                    mk_fp16_method(method, sig, vec_ty)
+                } else if level == Level::Fp16 {
+                    generic_op(method, sig, vec_ty)
+                } else {
+                    generic_f16_op(method, sig, vec_ty)
+                };
+                methods.push(method);
+                continue;
+            }
+            if vec_ty.scalar == ScalarType::BFloat
+                && !matches!(sig, OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _))
+            {
+                methods.push(generic_bf16_op(method, sig, vec_ty));
+                continue;
+            }
+            let b1 = (vec_ty.n_bits() > 128 && method != "split" && !sig.is_narrowing(vec_ty))
                 || vec_ty.n_bits() > 256;
 
             let b2 = !matches!(method, "load_interleaved_128")
@@ -110,10 +218,13 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                     let dup_type = VecType::new(ScalarType::Int, vec_ty.scalar_bits, vec_ty.len);
                     let scalar = dup_type.scalar.rust(scalar_bits);
                     let dup_intrinsic = split_intrinsic("vdup", "n", &dup_type);
+                    // `vshl` only looks at the low byte of the count, so clamp it to the
+                    // lane width, which shifts out every bit.
+                    let width = Literal::u32_unsuffixed(scalar_bits as u32);
                     let shift = if method == "shr" {
-                        quote! { -(shift as #scalar) }
+                        quote! { -(shift.min(#width) as #scalar) }
                     } else {
-                        quote! { shift as #scalar }
+                        quote! { shift.min(#width) as #scalar }
                     };
                     let expr = Neon.expr(
                         method,
@@ -129,6 +240,61 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::BinaryTo(_) | OpSig::Binary
+                    if matches!(method, "mul_widen" | "mul_high" | "dot_i16") =>
+                {
+                    mk_widening_mul(method, sig, vec_ty)
+                }
+                // There is no absolute difference for 64 bit lanes.
+                OpSig::BinaryTo(_) if scalar_bits == 64 => generic_abs_diff(vec_ty),
+                OpSig::BinaryTo(to_ty) => {
+                    let abd = simple_intrinsic("vabd", vec_ty);
+                    let mut expr = quote! { #abd(a.into(), b.into()) };
+                    if vec_ty.scalar == ScalarType::Int {
+                        let opt_q = crate::arch::neon::opt_q(vec_ty);
+                        let reinterpret = Ident::new(
+                            &format!("vreinterpret{opt_q}_u{scalar_bits}_s{scalar_bits}"),
+                            Span::call_site(),
+                        );
+                        expr = quote! { #reinterpret(#expr) };
+                    }
+                    let to_ty = to_ty.rust();
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #to_ty<Self> {
+                            unsafe {
+                                #expr.simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Refine => {
+                    let estimate = Neon.expr(&format!("{method}_approx"), vec_ty, &[quote! { a }]);
+                    let mul = simple_intrinsic("vmul", vec_ty);
+                    let step = if method == "recip" {
+                        let recps = simple_intrinsic("vrecps", vec_ty);
+                        quote! { #mul(x, #recps(a, x)) }
+                    } else {
+                        let rsqrts = simple_intrinsic("vrsqrts", vec_ty);
+                        quote! { #mul(x, #rsqrts(#mul(a, x), x)) }
+                    };
+                    let fixup = refine_fixup(method, vec_ty);
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, steps: u32) -> #ret_ty {
+                            let (estimate, x): (#ty<Self>, #ty<Self>) = unsafe {
+                                let a = a.into();
+                                let estimate = #estimate;
+                                let mut x = estimate;
+                                for _ in 0..steps {
+                                    x = #step;
+                                }
+                                (estimate.simd_into(self), x.simd_into(self))
+                            };
+                            #fixup
+                        }
+                    }
+                }
                 OpSig::Unary => {
                     let args = [quote! { a.into() }];
                     let expr = Neon.expr(method, vec_ty, &args);
@@ -183,51 +349,77 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::WidenNarrow(_) if method == "widen" && vec_ty.n_bits() == 64 => {
+                    let movl = simple_intrinsic("vmovl", vec_ty);
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                #movl(a.into()).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::WidenNarrow(target_ty) if method == "widen" => {
+                    let half = VecType::new(vec_ty.scalar, scalar_bits, vec_ty.len / 2);
+                    let movl = simple_intrinsic("vmovl", &half);
+                    let movl_high = simple_intrinsic("vmovl_high", &half);
+                    let get_low = simple_intrinsic("vget_low", &half);
+                    let arch = Neon.arch_ty(&target_ty);
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a = a.into();
+                                #arch(#movl(#get_low(a)), #movl_high(a)).simd_into(self)
+                            }
+                        }
+                    }
+                }
                 OpSig::WidenNarrow(target_ty) => {
-                    let ret_ty = sig.ret_ty(&target_ty, TyFlavor::SimdTrait);
-                    let vec_scalar_ty = vec_ty.scalar.rust(vec_ty.scalar_bits);
-                    let target_scalar_ty = target_ty.scalar.rust(target_ty.scalar_bits);
-
-                    if method == "narrow" {
+                    // The narrowing moves are named after their 128 bit input.
+                    let input = VecType::new(vec_ty.scalar, scalar_bits, 64 / scalar_bits);
+                    let movn = if method == "narrow_saturating" {
+                        simple_intrinsic("vqmovn", &input)
+                    } else {
+                        simple_intrinsic("vmovn", &input)
+                    };
+                    let body = if vec_ty.n_bits() == 128 {
+                        quote! { #movn(a.into()) }
+                    } else {
                         let arch = Neon.arch_ty(vec_ty);
-
-                        let id1 =
-                            Ident::new(&format!("vmovn_{}", vec_scalar_ty), Span::call_site());
-                        let id2 = Ident::new(
-                            &format!("vcombine_{}", target_scalar_ty),
-                            Span::call_site(),
-                        );
-
+                        let half =
+                            VecType::new(target_ty.scalar, target_ty.scalar_bits, vec_ty.len / 2);
+                        let combine = simple_intrinsic("vcombine", &half);
                         quote! {
-                            #[inline(always)]
-                            fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
-                                unsafe {
-                                    let converted: #arch = a.into();
-                                    let low = #id1(converted.0);
-                                    let high = #id1(converted.1);
-
-                                    #id2(low, high).simd_into(self)
-                                }
+                            let a: #arch = a.into();
+                            #combine(#movn(a.0), #movn(a.1))
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                #body.simd_into(self)
                             }
                         }
+                    }
+                }
+                OpSig::WidenHalf(low) => {
+                    let half = VecType::new(vec_ty.scalar, scalar_bits, vec_ty.len / 2);
+                    let body = if low {
+                        let movl = simple_intrinsic("vmovl", &half);
+                        let get_low = simple_intrinsic("vget_low", &half);
+                        quote! { #movl(#get_low(a.into())) }
                     } else {
-                        let arch = Neon.arch_ty(&target_ty);
-                        let id1 =
-                            Ident::new(&format!("vmovl_{}", vec_scalar_ty), Span::call_site());
-                        let id2 =
-                            Ident::new(&format!("vget_low_{}", vec_scalar_ty), Span::call_site());
-                        let id3 =
-                            Ident::new(&format!("vget_high_{}", vec_scalar_ty), Span::call_site());
-
-                        quote! {
-                            #[inline(always)]
-                            fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
-                                unsafe {
-                                    let low = #id1(#id2(a.into()));
-                                    let high = #id1(#id3(a.into()));
-
-                                    #arch(low, high).simd_into(self)
-                                }
+                        let movl_high = simple_intrinsic("vmovl_high", &half);
+                        quote! { #movl_high(a.into()) }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                #body.simd_into(self)
                             }
                         }
                     }
@@ -254,6 +446,47 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                                 }
                             }
                         }
+                    } else if matches!(method, "shl_var" | "shr_var") {
+                        // `vshl` takes signed shift counts from the low byte of each
+                        // lane, and shifts right for negative ones. The counts are
+                        // unsigned, so clamp them to the lane width first.
+                        let int_ty = VecType::new(ScalarType::Int, vec_ty.scalar_bits, vec_ty.len);
+                        let uint_ty =
+                            VecType::new(ScalarType::Unsigned, vec_ty.scalar_bits, vec_ty.len);
+                        let unsigned = if vec_ty.scalar == ScalarType::Int {
+                            let to_uint = cvt_intrinsic("vreinterpret", &uint_ty, vec_ty);
+                            quote! { #to_uint(b.into()) }
+                        } else {
+                            quote! { b.into() }
+                        };
+                        let dup = split_intrinsic("vdup", "n", &uint_ty);
+                        let width = Literal::usize_unsuffixed(scalar_bits);
+                        let clamped = if scalar_bits == 64 {
+                            // There is no 64 bit min.
+                            let vbsl = simple_intrinsic("vbsl", &uint_ty);
+                            let vcgt = simple_intrinsic("vcgt", &uint_ty);
+                            quote! { #vbsl(#vcgt(count, width), width, count) }
+                        } else {
+                            let vmin = simple_intrinsic("vmin", &uint_ty);
+                            quote! { #vmin(count, width) }
+                        };
+                        let to_int = cvt_intrinsic("vreinterpret", &int_ty, &uint_ty);
+                        let mut count = quote! { #to_int(#clamped) };
+                        if method == "shr_var" {
+                            let neg = simple_intrinsic("vneg", &int_ty);
+                            count = quote! { #neg(#count) };
+                        }
+                        let vshl = simple_intrinsic("vshl", vec_ty);
+                        quote! {
+                            #[inline(always)]
+                            fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                                unsafe {
+                                    let count = #unsigned;
+                                    let width = #dup(#width);
+                                    #vshl(a.into(), #count).simd_into(self)
+                                }
+                            }
+                        }
                     } else {
                         let expr = Neon.expr(method, vec_ty, &args);
                         quote! {
@@ -266,13 +499,109 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Reduce => match method {
+                    "reduce_and" | "reduce_or" => generic_reduce_bits(method, vec_ty),
+                    // There are no min and max across 64 bit lanes.
+                    "reduce_min" | "reduce_max"
+                        if vec_ty.scalar_bits == 64 && vec_ty.scalar != ScalarType::Float =>
+                    {
+                        scalar_reduce(method, vec_ty)
+                    }
+                    _ => {
+                        // The float sums add neighbouring lanes pairwise.
+                        // The float min and max ignore NaN lanes, like `min_precise`
+                        // and `max_precise`.
+                        let float = vec_ty.scalar == ScalarType::Float;
+                        let name = match method {
+                            "reduce_add" => "vaddv",
+                            "reduce_min" if float => "vminnmv",
+                            "reduce_max" if float => "vmaxnmv",
+                            "reduce_min" => "vminv",
+                            "reduce_max" => "vmaxv",
+                            _ => unimplemented!("missing {method}"),
+                        };
+                        let intrinsic = simple_intrinsic(name, vec_ty);
+                        quote! {
+                            #[inline(always)]
+                            fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                                unsafe {
+                                    #intrinsic(a.into())
+                                }
+                            }
+                        }
+                    }
+                },
+                OpSig::MaskQuery(_) => mk_mask_query(method, vec_ty),
+                // Permutations are handled before the per-op dispatch.
+                OpSig::Shuffle(_)
+                | OpSig::Reverse
+                | OpSig::Rotate(_)
+                | OpSig::Broadcast
+                | OpSig::Unzip(_)
+                | OpSig::Classify => {
+                    unreachable!()
+                }
+                OpSig::Lookup(table_len) => {
+                    let table_ty = lookup_table_ty(table_len, TyFlavor::SimdTrait);
+                    // `vtbl` and `vqtbl` give zero for indices past the end of the table.
+                    let lookup = match (table_len, vec_ty.n_bits()) {
+                        (8, _) => quote! { vtbl1_u8 },
+                        (16, _) => quote! { vqtbl1q_u8 },
+                        (32, 64) => quote! { vqtbl2_u8 },
+                        (32, _) => quote! { vqtbl2q_u8 },
+                        (48, 64) => quote! { vqtbl3_u8 },
+                        (48, _) => quote! { vqtbl3q_u8 },
+                        (64, 64) => quote! { vqtbl4_u8 },
+                        _ => quote! { vqtbl4q_u8 },
+                    };
+                    let table = if table_len == 48 {
+                        quote! { uint8x16x3_t(a[0].into(), a[1].into(), a[2].into()) }
+                    } else {
+                        quote! { a.into() }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #table_ty, b: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                #lookup(#table, b.into()).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::FromBitmask => {
+                    let u_ty = VecType::new(ScalarType::Unsigned, scalar_bits, vec_ty.len);
+                    let (weights_ty, weights) = mask_weights(&u_ty);
+                    let load = simple_intrinsic("vld1", &u_ty);
+                    let tst = simple_intrinsic("vtst", &u_ty);
+                    let to_mask = cvt_intrinsic("vreinterpret", vec_ty, &u_ty);
+                    // Every lane holds the bits of its group of 8 lanes.
+                    let splat = match (scalar_bits, vec_ty.len) {
+                        (8, 16) => quote! {
+                            vcombine_u8(vdup_n_u8(bits as u8), vdup_n_u8((bits >> 8) as u8))
+                        },
+                        (64, _) => quote! { vdupq_n_u64(bits) },
+                        _ => {
+                            let dup = split_intrinsic("vdup", "n", &u_ty);
+                            let scalar = u_ty.scalar.rust(scalar_bits);
+                            quote! { #dup(bits as #scalar) }
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, bits: u64) -> #ret_ty {
+                            let weights: #weights_ty = #weights;
+                            unsafe {
+                                #to_mask(#tst(#splat, #load(weights.as_ptr()))).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Ternary if matches!(method, "nmadd" | "nmsub") => {
+                    generic_negated_madd(method, vec_ty)
+                }
+                OpSig::Ternary if method == "mul_add_fast" => generic_mul_add_fast(vec_ty),
                 OpSig::Ternary => {
-                    let args = [
-                        quote! { a.into() },
-                        quote! { b.into() },
-                        quote! { c.into() },
-                    ];
-
+                    let (method, args) = fma_args(method);
                     let expr = Neon.expr(method, vec_ty, &args);
                     quote! {
                         #[inline(always)]
@@ -285,7 +614,18 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                 }
                 OpSig::Compare => {
                     let args = [quote! { a.into() }, quote! { b.into() }];
-                    let expr = Neon.expr(method, vec_ty, &args);
+                    let expr = if method == "simd_ne" {
+                        // There is no not-equal comparison, and no `vmvn` for
+                        // 64 bit lanes, so flip the bits with an xor.
+                        let eq = Neon.expr("simd_eq", vec_ty, &args);
+                        let u_ty = VecType::new(ScalarType::Unsigned, scalar_bits, vec_ty.len);
+                        let eor = simple_intrinsic("veor", &u_ty);
+                        let dup = split_intrinsic("vdup", "n", &u_ty);
+                        let max = u_ty.scalar.rust(scalar_bits);
+                        quote! { #eor(#eq, #dup(#max::MAX)) }
+                    } else {
+                        Neon.expr(method, vec_ty, &args)
+                    };
                     let opt_q = crate::arch::neon::opt_q(vec_ty);
                     let reinterpret_str =
                         format!("vreinterpret{opt_q}_s{scalar_bits}_u{scalar_bits}");
@@ -315,7 +655,32 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Combine if vec_ty.n_bits() == 64 => {
+                    let combine = simple_intrinsic("vcombine", vec_ty);
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                #combine(a.into(), b.into()).simd_into(self)
+                            }
+                        }
+                    }
+                }
                 OpSig::Combine => generic_combine(vec_ty),
+                OpSig::Split if vec_ty.n_bits() == 128 => {
+                    let half = VecType::new(vec_ty.scalar, scalar_bits, vec_ty.len / 2);
+                    let get_low = simple_intrinsic("vget_low", &half);
+                    let get_high = simple_intrinsic("vget_high", &half);
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a = a.into();
+                                (#get_low(a).simd_into(self), #get_high(a).simd_into(self))
+                            }
+                        }
+                    }
+                }
                 OpSig::Split => generic_split(vec_ty),
                 OpSig::Zip(zip1) => {
                     let neon = if zip1 { "vzip1" } else { "vzip2" };
@@ -331,9 +696,123 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Cvt(ScalarType::Float, 64) if ty_name == "f32x2" => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                vcvt_f64_f32(a.into()).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if ty_name == "f64x2" => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                vcvt_f32_f64(a.into()).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 64) if scalar_bits == 32 => {
+                    assert_eq!(ty_name, "f32x4", "only support cvt_f64_f32x4");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a = a.into();
+                                let low = vcvt_f64_f32(vget_low_f32(a));
+                                let high = vcvt_high_f64_f32(a);
+                                float64x2x2_t(low, high).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if scalar_bits == 64 => {
+                    assert_eq!(ty_name, "f64x4", "only support cvt_f32_f64x4");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a: float64x2x2_t = a.into();
+                                vcvt_high_f32_f64(vcvt_f32_f64(a.0), a.1).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if vec_ty.scalar == ScalarType::BFloat => {
+                    assert_eq!(ty_name, "bf16x8", "only support cvt_f32_bf16x8");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a = a.into();
+                                let low = vreinterpretq_f32_u32(vshll_n_u16::<16>(vget_low_u16(a)));
+                                let high = vreinterpretq_f32_u32(vshll_high_n_u16::<16>(a));
+                                float32x4x2_t(low, high).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::BFloat, 16) => {
+                    assert_eq!(ty_name, "f32x8", "only support cvt_bf16_f32x8");
+                    let low = bf16_round(quote! { low });
+                    let high = bf16_round(quote! { high });
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let float32x4x2_t(low, high) = a.into();
+                                #low
+                                #high
+                                vcombine_u16(low, high).simd_into(self)
+                            }
+                        }
+                    }
+                }
+                OpSig::DotBf16 => {
+                    let native = match method {
+                        "dot_bf16" => quote! { vbfdotq_f32 },
+                        _ => quote! { vbfmmlaq_f32 },
+                    };
+                    let fallback = bf16_dot_expr(method, vec_ty);
+                    let pairs_ty = bf16_pairs_ty(vec_ty).rust();
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #pairs_ty<Self>, c: #pairs_ty<Self>) -> #ret_ty {
+                            if let Some(bf16) = self.bf16 {
+                                bf16.#native(a.into(), b.into(), c.into()).simd_into(self)
+                            } else {
+                                #fallback
+                            }
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 16) => {
+                    assert_eq!(ty_name, "f32x8", "only support cvt_f16_f32x8");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            unsafe {
+                                let a: float32x4x2_t = a.into();
+                                let low = self.neon.vcvt_f16_f32(a.0);
+                                let high = self.neon.vcvt_f16_f32(a.1);
+                                vcombine_u16(low, high).simd_into(self)
+                            }
+                        }
+                    }
+                }
                 OpSig::Cvt(scalar, scalar_bits) => {
                     let to_ty = &VecType::new(scalar, scalar_bits, vec_ty.len);
-                    let neon = cvt_intrinsic("vcvt", to_ty, vec_ty);
+                    // `vcvtn` rounds to nearest, ties to even, and saturates like `vcvt`.
+                    let name = if method.ends_with("_round") {
+                        "vcvtn"
+                    } else {
+                        "vcvt"
+                    };
+                    let neon = cvt_intrinsic(name, to_ty, vec_ty);
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
@@ -346,14 +825,28 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                 OpSig::Reinterpret(scalar, scalar_bits) => {
                     if valid_reinterpret(vec_ty, scalar, scalar_bits) {
                         let to_ty = reinterpret_ty(vec_ty, scalar, scalar_bits);
-                        let neon = cvt_intrinsic("vreinterpret", &to_ty, vec_ty);
+                        // The f16 lanes are carried in `uint16` vectors, like the bf16 lanes.
+                        let carrier = |ty: VecType| {
+                            if ty.is_f16() {
+                                VecType::new(ScalarType::Unsigned, 16, ty.len)
+                            } else {
+                                ty
+                            }
+                        };
+                        let (from, to) = (carrier(*vec_ty), carrier(to_ty));
+                        let body =
+                            if Neon.arch_ty(&from).to_string() == Neon.arch_ty(&to).to_string() {
+                                let arch = Neon.arch_ty(&from);
+                                quote! { <#arch>::from(a).simd_into(self) }
+                            } else {
+                                let neon = cvt_intrinsic("vreinterpret", &to, &from);
+                                quote! { unsafe { #neon(a.into()).simd_into(self) } }
+                            };
 
                         quote! {
                             #[inline(always)]
                             fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
-                                unsafe {
-                                    #neon(a.into()).simd_into(self)
-                                }
+                                #body
                             }
                         }
                     } else {
@@ -364,20 +857,29 @@ fn mk_simd_impl(level: Level) -> TokenStream {
             methods.push(method);
         }
     }
+    let target_features = level.target_features();
+    let vectorize = Ident::new(
+        &format!("vectorize_{}", level.name().to_lowercase()),
+        Span::call_site(),
+    );
     // Note: the `vectorize` implementation is pretty boilerplate and should probably
     // be factored out for DRY.
     quote! {
         impl Simd for #level_tok {
             type f32s = f32x4<Self>;
+            type f64s = f64x2<Self>;
             type u8s = u8x16<Self>;
             type i8s = i8x16<Self>;
             type u16s = u16x8<Self>;
             type i16s = i16x8<Self>;
             type u32s = u32x4<Self>;
             type i32s = i32x4<Self>;
+            type u64s = u64x2<Self>;
+            type i64s = i64x2<Self>;
             type mask8s = mask8x16<Self>;
             type mask16s = mask16x8<Self>;
             type mask32s = mask32x4<Self>;
+            type mask64s = mask64x2<Self>;
             #[inline(always)]
             fn level(self) -> Level {
                 Level::#level_tok(self)
@@ -385,13 +887,13 @@ fn mk_simd_impl(level: Level) -> TokenStream {
 
             #[inline]
             fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
-                #[target_feature(enable = "neon")]
+                #[target_feature(enable = #target_features)]
                 #[inline]
                 // unsafe not needed here with tf11, but can be justified
-                unsafe fn vectorize_neon<F: FnOnce() -> R, R>(f: F) -> R {
+                unsafe fn #vectorize<F: FnOnce() -> R, R>(f: F) -> R {
                     f()
                 }
-                unsafe { vectorize_neon(f) }
+                unsafe { #vectorize(f) }
             }
 
             #( #methods )*
@@ -399,6 +901,189 @@ fn mk_simd_impl(level: Level) -> TokenStream {
     }
 }
 
+/// Native half-precision methods for the "fp16" level.
+fn mk_fp16_method(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    match sig {
+        OpSig::Splat => {
+            let expr = fp16_expr(method, vec_ty, &[quote! { val }]);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, val: f16) -> #ret_ty {
+                    #expr.simd_into(self)
+                }
+            }
+        }
+        // The exact results are within the bounds, and avoid the f16 estimates.
+        OpSig::Unary if method.ends_with("_approx") => generic_recip(method, sig, vec_ty),
+        OpSig::Refine => generic_recip(method, sig, vec_ty),
+        OpSig::Unary => {
+            let expr = fp16_expr(method, vec_ty, &[quote! { a }]);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    let a = a.into();
+                    #expr.simd_into(self)
+                }
+            }
+        }
+        OpSig::Binary => {
+            if method == "copysign" {
+                quote! {
+                    #[inline(always)]
+                    fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                        unsafe {
+                            let sign_mask = vdupq_n_u16(1 << 15);
+                            vbslq_u16(sign_mask, b.into(), a.into()).simd_into(self)
+                        }
+                    }
+                }
+            } else {
+                let expr = fp16_expr(method, vec_ty, &[quote! { a.into() }, quote! { b.into() }]);
+                quote! {
+                    #[inline(always)]
+                    fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                        #expr.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Ternary if matches!(method, "nmadd" | "nmsub") => {
+            generic_negated_madd(method, vec_ty)
+        }
+        OpSig::Ternary if method == "mul_add_fast" => generic_mul_add_fast(vec_ty),
+        OpSig::Ternary => {
+            let (method, args) = fma_args(method);
+            let expr = fp16_expr(method, vec_ty, &args);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                    #expr.simd_into(self)
+                }
+            }
+        }
+        OpSig::Compare => {
+            let args = [quote! { a.into() }, quote! { b.into() }];
+            let expr = if method == "simd_ne" {
+                let eq = fp16_expr("simd_eq", vec_ty, &args);
+                quote! { vmvnq_u16(#eq) }
+            } else {
+                fp16_expr(method, vec_ty, &args)
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe { vreinterpretq_s16_u16(#expr).simd_into(self) }
+                }
+            }
+        }
+        OpSig::Select => {
+            let mask_ty = vec_ty.mask_ty().rust();
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #mask_ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        vbslq_u16(vreinterpretq_u16_s16(a.into()), b.into(), c.into()).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Zip(zip1) => {
+            // Zips only move lanes, so the u16 intrinsics do the job.
+            let zip = if zip1 {
+                quote! { vzip1q_u16 }
+            } else {
+                quote! { vzip2q_u16 }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe { #zip(a.into(), b.into()).simd_into(self) }
+                }
+            }
+        }
+        OpSig::Combine => generic_combine(vec_ty),
+        _ => unimplemented!("{method} is not supported for f16"),
+    }
+}
+
+/// The ops built on `vmull`, which multiplies into lanes of twice the width.
+fn mk_widening_mul(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    let bits = vec_ty.scalar_bits;
+    let wide_ty = VecType::new(vec_ty.scalar, bits * 2, vec_ty.len / 2);
+    let half_ty = VecType::new(vec_ty.scalar, bits, 64 / bits);
+    let vmull = simple_intrinsic("vmull", &half_ty);
+    let body = if vec_ty.n_bits() == 64 {
+        let product = quote! { #vmull(a.into(), b.into()) };
+        match method {
+            "mul_widen" => product,
+            "mul_high" => {
+                let shrn = split_intrinsic("vshrn", "n", &wide_ty);
+                let shift = Literal::usize_unsuffixed(bits);
+                quote! { #shrn::<#shift>(#product) }
+            }
+            _ => unimplemented!("missing {method}"),
+        }
+    } else {
+        let get_low = simple_intrinsic("vget_low", &half_ty);
+        let vmull_high = simple_intrinsic("vmull_high", &half_ty);
+        let products = quote! {
+            let a = a.into();
+            let b = b.into();
+            let low = #vmull(#get_low(a), #get_low(b));
+            let high = #vmull_high(a, b);
+        };
+        let combined = match method {
+            "mul_widen" => {
+                let prefix = if vec_ty.scalar == ScalarType::Int {
+                    "int"
+                } else {
+                    "uint"
+                };
+                let pair = Ident::new(
+                    &format!("{prefix}{}x{}x2_t", bits * 2, wide_ty.len),
+                    Span::call_site(),
+                );
+                quote! { #pair(low, high) }
+            }
+            // The high halves are the odd lanes of the products.
+            "mul_high" => {
+                let uzp2 = simple_intrinsic("vuzp2", vec_ty);
+                let reinterpret = cvt_intrinsic("vreinterpret", vec_ty, &wide_ty);
+                quote! { #uzp2(#reinterpret(low), #reinterpret(high)) }
+            }
+            "dot_i16" => {
+                let padd = simple_intrinsic("vpadd", &wide_ty);
+                quote! { #padd(low, high) }
+            }
+            _ => unimplemented!("missing {method}"),
+        };
+        quote! {
+            #products
+            #combined
+        }
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+            unsafe {
+                #body.simd_into(self)
+            }
+        }
+    }
+}
+
 fn mk_type_impl() -> TokenStream {
     let mut result = vec![];
     for ty in SIMD_TYPES {
@@ -430,3 +1115,181 @@ fn mk_type_impl() -> TokenStream {
         #( #result )*
     }
 }
+
+/// Round the f32 lanes of `x` to the nearest bf16, with ties to even.
+///
+/// NaNs are quieted rather than rounded, so they can't turn into infinities.
+fn bf16_round(x: TokenStream) -> TokenStream {
+    quote! {
+        let bits = vreinterpretq_u32_f32(#x);
+        let lsb = vandq_u32(vshrq_n_u32::<16>(bits), vdupq_n_u32(1));
+        let rounded = vaddq_u32(vaddq_u32(bits, vdupq_n_u32(0x7fff)), lsb);
+        let quiet = vorrq_u32(bits, vdupq_n_u32(0x0040_0000));
+        let #x = vshrn_n_u32::<16>(vbslq_u32(vceqq_f32(#x, #x), rounded, quiet));
+    }
+}
+
+/// The `vfma` or `vfms` op and its arguments for a fused ternary op.
+///
+/// `vfma` adds to its first argument, where `mul_add_fused` adds its last.
+fn fma_args(method: &str) -> (&str, [TokenStream; 3]) {
+    let [a, b, c] = [
+        quote! { a.into() },
+        quote! { b.into() },
+        quote! { c.into() },
+    ];
+    if method == "mul_add_fused" {
+        ("madd", [c, a, b])
+    } else {
+        (method, [a, b, c])
+    }
+}
+
+/// The bit of each lane in a bitmask, repeating every 8 lanes.
+fn mask_weights(u_ty: &VecType) -> (TokenStream, TokenStream) {
+    let scalar = u_ty.scalar.rust(u_ty.scalar_bits);
+    let len = Literal::usize_unsuffixed(u_ty.len);
+    let weights = (0..u_ty.len).map(|i| Literal::u64_unsuffixed(1 << (i % 8)));
+    (quote! { [#scalar; #len] }, quote! { [#( #weights ),*] })
+}
+
+fn mk_mask_query(method: &str, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let u_ty = VecType::new(ScalarType::Unsigned, vec_ty.scalar_bits, vec_ty.len);
+    // Neither `vmaxv` nor `vminv` support 64 bit lanes, but the 32 bit
+    // halves of a mask lane are the same.
+    let u32_ty = VecType::new(ScalarType::Unsigned, 32, vec_ty.n_bits() / 32);
+    let lanes_ty = if vec_ty.scalar_bits == 64 {
+        u32_ty
+    } else {
+        u_ty
+    };
+    let (ret_ty, body) = match method {
+        "any" => {
+            let to_u32 = cvt_intrinsic("vreinterpret", &u32_ty, vec_ty);
+            let max = simple_intrinsic("vmaxv", &u32_ty);
+            (quote! { bool }, quote! { #max(#to_u32(a.into())) != 0 })
+        }
+        "all" => {
+            let to_lanes = cvt_intrinsic("vreinterpret", &lanes_ty, vec_ty);
+            let min = simple_intrinsic("vminv", &lanes_ty);
+            (quote! { bool }, quote! { #min(#to_lanes(a.into())) != 0 })
+        }
+        "to_bitmask" => {
+            let (weights_ty, weights) = mask_weights(&u_ty);
+            let to_u = cvt_intrinsic("vreinterpret", &u_ty, vec_ty);
+            let load = simple_intrinsic("vld1", &u_ty);
+            let and = simple_intrinsic("vand", &u_ty);
+            // Adding up the weights of the set lanes gives 8 bits at a time.
+            let sum = match (vec_ty.scalar_bits, vec_ty.len) {
+                (8, 16) => quote! {
+                    vaddv_u8(vget_low_u8(bits)) as u64 | (vaddv_u8(vget_high_u8(bits)) as u64) << 8
+                },
+                (64, _) => quote! { vaddvq_u64(bits) },
+                _ => {
+                    let add = simple_intrinsic("vaddv", &u_ty);
+                    quote! { #add(bits) as u64 }
+                }
+            };
+            (
+                quote! { u64 },
+                quote! {
+                    let weights: #weights_ty = #weights;
+                    let bits = #and(#to_u(a.into()), #load(weights.as_ptr()));
+                    #sum
+                },
+            )
+        }
+        _ => return generic_mask_query(method, vec_ty),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+            unsafe {
+                #body
+            }
+        }
+    }
+}
+
+/// Lane permutations of 128 bit vectors work on the bytes, so they don't
+/// depend on the lane type.
+fn mk_permutation(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    match (sig, vec_ty.n_bits()) {
+        (_, 128) => {}
+        (OpSig::Rotate(_) | OpSig::Broadcast, 64) => return generic_half_op(method, sig, vec_ty),
+        (OpSig::Shuffle(_), _) | (_, 64) => return generic_shuffle(method, sig, vec_ty),
+        _ => return generic_op(method, sig, vec_ty),
+    }
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let generics = sig.generics(vec_ty);
+    let args = sig.simd_trait_args(vec_ty);
+    let u8_ty = VecType::new(ScalarType::Unsigned, 8, 16);
+    let lanes_ty = VecType::new(ScalarType::Unsigned, vec_ty.scalar_bits, vec_ty.len);
+    // Byte vectors need no reinterpretation.
+    let reinterpret = |from: &VecType, to: &VecType, arg: TokenStream| {
+        if vec_ty.scalar_bits == 8 {
+            arg
+        } else {
+            let intrinsic = cvt_intrinsic("vreinterpret", to, from);
+            quote! { #intrinsic(#arg) }
+        }
+    };
+    let to_lanes = |arg| reinterpret(&u8_ty, &lanes_ty, arg);
+    let from_lanes = |arg| reinterpret(&lanes_ty, &u8_ty, arg);
+    let bytes = match sig {
+        OpSig::Shuffle(1) => quote! {
+            let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+            vqtbl1q_u8(a.to_bytes().into(), vld1q_u8(table.as_ptr()))
+        },
+        OpSig::Shuffle(_) => quote! {
+            let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 2) };
+            let inputs = uint8x16x2_t(a.to_bytes().into(), b.to_bytes().into());
+            vqtbl2q_u8(inputs, vld1q_u8(table.as_ptr()))
+        },
+        OpSig::Rotate(_) | OpSig::Broadcast => native_lane_move(sig, vec_ty),
+        OpSig::Reverse => {
+            let ext = simple_intrinsic("vext", &lanes_ty);
+            let half = Literal::usize_unsuffixed(vec_ty.len / 2);
+            // Reverse the lanes within each half, then swap the halves.
+            let rev = if vec_ty.scalar_bits == 64 {
+                quote! { lanes }
+            } else {
+                let rev = simple_intrinsic("vrev64", &lanes_ty);
+                quote! { #rev(lanes) }
+            };
+            let lanes = to_lanes(quote! { a.to_bytes().into() });
+            let bytes = from_lanes(quote! { #ext::<#half>(rev, rev) });
+            quote! {
+                let lanes = #lanes;
+                let rev = #rev;
+                #bytes
+            }
+        }
+        OpSig::Unzip(low) => {
+            let uzp = if low { "vuzp1" } else { "vuzp2" };
+            let uzp = simple_intrinsic(uzp, &lanes_ty);
+            let a = to_lanes(quote! { a.to_bytes().into() });
+            let b = to_lanes(quote! { b.to_bytes().into() });
+            from_lanes(quote! { #uzp(#a, #b) })
+        }
+        _ => unreachable!(),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident #generics (#args) -> #ty<Self> {
+            unsafe {
+                let bytes = { #bytes };
+                #ty::from_bytes(bytes.simd_into(self))
+            }
+        }
+    }
+}
@@ -4,18 +4,25 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
-use crate::types::{SIMD_TYPES, ScalarType, type_imports};
+use crate::types::{SIMD_TYPES, ScalarType};
 
 pub fn mk_ops() -> TokenStream {
-    let imports = type_imports();
+    // bf16 vectors have no operators, so they aren't imported.
+    let types = SIMD_TYPES
+        .iter()
+        .filter(|ty| ty.scalar != ScalarType::BFloat)
+        .map(|ty| ty.rust());
+    let imports = quote! { use crate::{ f16, #( #types ),* }; };
 
     let mut impls = vec![];
 
     for ty in SIMD_TYPES {
         let ops = match ty.scalar {
             ScalarType::Float => &["neg", "add", "sub", "mul", "div"][..],
-            ScalarType::Int | ScalarType::Unsigned => &["add", "sub", "mul", "and", "or", "xor"],
+            ScalarType::Int => &["neg", "add", "sub", "mul", "and", "or", "xor"],
+            ScalarType::Unsigned => &["add", "sub", "mul", "and", "or", "xor"],
             ScalarType::Mask => &["and", "or", "xor", "not"],
+            ScalarType::BFloat => &[],
         };
         let simd = ty.rust();
         for op in ops {
@@ -74,6 +81,33 @@ pub fn mk_ops() -> TokenStream {
                 }
             }
         }
+        if matches!(ty.scalar, ScalarType::Int | ScalarType::Unsigned) {
+            // Shifting by a vector shifts each lane by the matching lane.
+            for (trait_name, op) in [("Shl", "shl"), ("Shr", "shr")] {
+                let trait_id = Ident::new(trait_name, Span::call_site());
+                let opfn = Ident::new(op, Span::call_site());
+                let simd_fn = Ident::new(&format!("{op}_{}", ty.rust_name()), Span::call_site());
+                let simd_var_fn =
+                    Ident::new(&format!("{op}_var_{}", ty.rust_name()), Span::call_site());
+                impls.push(quote! {
+                    impl<S: Simd> core::ops::#trait_id<u32> for #simd<S> {
+                        type Output = Self;
+                        #[inline(always)]
+                        fn #opfn(self, rhs: u32) -> Self::Output {
+                            self.simd.#simd_fn(self, rhs)
+                        }
+                    }
+
+                    impl<S: Simd> core::ops::#trait_id for #simd<S> {
+                        type Output = Self;
+                        #[inline(always)]
+                        fn #opfn(self, rhs: Self) -> Self::Output {
+                            self.simd.#simd_var_fn(self, rhs)
+                        }
+                    }
+                });
+            }
+        }
     }
 
     quote! {
@@ -18,29 +18,41 @@ pub fn mk_simd_trait() -> TokenStream {
         for (method, sig) in &ops_for_type(&vec_ty, true) {
             let method_name = format!("{method}_{ty_name}");
             let method = Ident::new(&method_name, Span::call_site());
+            let generics = sig.generics(vec_ty);
             let args = sig.simd_trait_args(vec_ty);
             let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+            // Like the other methods, this takes the level as `self`.
+            let allow = if let OpSig::FromBitmask = sig {
+                quote! { #[allow(clippy::wrong_self_convention)] }
+            } else {
+                quote! {}
+            };
             methods.extend(quote! {
-                fn #method(#args) -> #ret_ty;
+                #allow
+                fn #method #generics (#args) -> #ret_ty;
             });
         }
     }
     let mut code = quote! {
-        use crate::{seal::Seal, Level, SimdElement, SimdInto};
+        use crate::{seal::Seal, Indices, Level, SimdElement, SimdInto, SimdMath};
         #imports
         /// TODO: docstring
         // TODO: Seal
         pub trait Simd: Sized + Clone + Copy + Send + Sync + Seal + 'static {
-            type f32s: SimdFloat<f32, Self, Block = f32x4<Self>>;
+            type f32s: SimdFloat<f32, Self, Block = f32x4<Self>> + SimdMath<Self>;
+            type f64s: SimdFloat<f64, Self, Block = f64x2<Self>>;
             type u8s: SimdInt<u8, Self, Block = u8x16<Self>>;
             type i8s: SimdInt<i8, Self, Block = i8x16<Self>>;
             type u16s: SimdInt<u16, Self, Block = u16x8<Self>>;
             type i16s: SimdInt<i16, Self, Block = i16x8<Self>>;
             type u32s: SimdInt<u32, Self, Block = u32x4<Self>>;
             type i32s: SimdInt<i32, Self, Block = i32x4<Self>>;
+            type u64s: SimdInt<u64, Self, Block = u64x2<Self>>;
+            type i64s: SimdInt<i64, Self, Block = i64x2<Self>>;
             type mask8s: SimdMask<i8, Self, Block = mask8x16<Self>>;
             type mask16s: SimdMask<i16, Self, Block = mask16x8<Self>>;
             type mask32s: SimdMask<i32, Self, Block = mask32x4<Self>>;
+            type mask64s: SimdMask<i64, Self, Block = mask64x2<Self>>;
             fn level(self) -> Level;
 
             /// Call function with CPU features enabled.
@@ -83,6 +95,33 @@ fn mk_simd_base() -> TokenStream {
             fn from_slice(simd: S, slice: &[Element]) -> Self;
             fn splat(simd: S, val: Element) -> Self;
             fn block_splat(block: Self::Block) -> Self;
+            /// Reverse the order of the lanes.
+            fn reverse(self) -> Self;
+            /// The even lanes of `self` followed by the even lanes of `rhs`.
+            fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self;
+            /// The odd lanes of `self` followed by the odd lanes of `rhs`.
+            fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self;
+            /// Pick a lane of `self` for each lane, by the indices of `I`.
+            ///
+            /// `M` is the number of lanes, which can't be written as `Self::N`
+            /// here. A different count is a compile error. The [`shuffle!`]
+            /// macro fills in both parameters.
+            ///
+            /// [`shuffle!`]: crate::shuffle!
+            fn shuffle<I: Indices<M>, const M: usize>(self) -> Self;
+            /// Pick a lane of `self` or `rhs` for each lane, by the indices of `I`.
+            ///
+            /// The indices `0..M` pick lanes of `self` and `M..2 * M` pick lanes
+            /// of `rhs`. See [`shuffle`](SimdBase::shuffle) for `M`.
+            fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self;
+            /// Move lane `i + K` to lane `i`, wrapping around.
+            fn rotate_lanes_left<const K: usize>(self) -> Self;
+            /// Move lane `i` to lane `i + K`, wrapping around.
+            fn rotate_lanes_right<const K: usize>(self) -> Self;
+            /// Copy lane `L` to every lane.
+            ///
+            /// `L` must be less than the number of lanes.
+            fn broadcast_lane<const L: usize>(self) -> Self;
         }
     }
 }
@@ -90,6 +129,15 @@ fn mk_simd_base() -> TokenStream {
 fn mk_simd_float() -> TokenStream {
     let methods = methods_for_vec_trait(FLOAT_OPS);
     quote! {
+        /// A vector of floats.
+        ///
+        /// The vector types also have `cvt_*` methods, which convert every lane
+        /// to another element type with the same number of lanes. Conversions
+        /// to integers saturate to the minimum or maximum of the integer type,
+        /// and give 0 for NaN, like `as`. `cvt_i32` and the like round toward
+        /// zero, and `cvt_i32_round` and the like round to nearest, ties to
+        /// even. Conversions between float widths round to nearest, ties to
+        /// even, and overflow to infinity.
         pub trait SimdFloat<Element: SimdElement, S: Simd>: SimdBase<Element, S>
             + core::ops::Neg<Output = Self>
             + core::ops::Add<Output = Self>
@@ -109,6 +157,19 @@ fn mk_simd_float() -> TokenStream {
 fn mk_simd_int() -> TokenStream {
     let methods = methods_for_vec_trait(INT_OPS);
     quote! {
+        /// A vector of integers.
+        ///
+        /// The `<<` and `>>` operators shift every lane, by a `u32` or by the
+        /// matching lane of another vector. Right shifts are arithmetic for
+        /// signed lanes. Shift counts are not masked: counts of at least the
+        /// lane width give 0, or copies of the sign bit for arithmetic right
+        /// shifts, on every level.
+        ///
+        /// The vector types also have `cvt_f16`, `cvt_f32` and `cvt_f64`
+        /// methods where the float vector with the same number of lanes exists.
+        /// They round to nearest, ties to even, like `as`, and only `f16` can
+        /// overflow to infinity. `u8` vectors also have `cvt_f32_unorm`, which
+        /// maps `0..=255` to `0.0..=1.0`.
         pub trait SimdInt<Element: SimdElement, S: Simd>: SimdBase<Element, S>
             + core::ops::Add<Output = Self>
             + core::ops::Add<Element, Output = Self>
@@ -122,6 +183,10 @@ fn mk_simd_int() -> TokenStream {
             + core::ops::BitOr<Element, Output = Self>
             + core::ops::BitXor<Output = Self>
             + core::ops::BitXor<Element, Output = Self>
+            + core::ops::Shl<u32, Output = Self>
+            + core::ops::Shr<u32, Output = Self>
+            + core::ops::Shl<Output = Self>
+            + core::ops::Shr<Output = Self>
         {
             #( #methods )*
         }
@@ -142,6 +207,147 @@ fn mk_simd_mask() -> TokenStream {
     }
 }
 
+/// Documentation for the vec trait methods whose contract isn't obvious
+/// from the name.
+fn vec_trait_doc(method: &str) -> TokenStream {
+    match method {
+        "simd_ne" => quote! {
+            /// Compare lanes for inequality.
+            ///
+            /// As IEEE 754 requires, this is true when either lane is NaN, so it
+            /// is the negation of [`simd_eq`](Self::simd_eq).
+        },
+        "is_nan" => quote! {
+            /// Test which lanes are NaN.
+        },
+        "is_finite" => quote! {
+            /// Test which lanes are neither infinite nor NaN.
+        },
+        "is_infinite" => quote! {
+            /// Test which lanes are positive or negative infinity.
+        },
+        "is_subnormal" => quote! {
+            /// Test which lanes are subnormal: nonzero, but smaller in magnitude
+            /// than the smallest normal number.
+        },
+        "is_sign_negative" => quote! {
+            /// Test which lanes have the sign bit set.
+            ///
+            /// This includes `-0.0` and NaNs with the sign bit set.
+        },
+        "shl_var" => quote! {
+            /// Shift each lane left by the matching lane of `rhs`.
+            ///
+            /// The counts are taken as unsigned, and counts of at least the lane
+            /// width give 0.
+        },
+        "shr_var" => quote! {
+            /// Shift each lane right by the matching lane of `rhs`, arithmetically
+            /// for signed lanes.
+            ///
+            /// The counts are taken as unsigned, and counts of at least the lane
+            /// width give 0, or copies of the sign bit for signed lanes.
+        },
+        "recip_approx" => quote! {
+            /// Compute an approximation of `1 / self`, with a relative error
+            /// below 2^-8.
+            ///
+            /// This uses the estimate instruction where the level has one, so it
+            /// is much faster than a division, but the result differs between
+            /// levels. Levels without one divide exactly.
+            ///
+            /// The bound holds for normal inputs with a normal reciprocal. Zeros
+            /// and infinities give infinities and zeros of the same sign.
+        },
+        "rsqrt_approx" => quote! {
+            /// Compute an approximation of `1 / self.sqrt()`, with a relative
+            /// error below 2^-8.
+            ///
+            /// Like [`recip_approx`](Self::recip_approx), this uses the estimate
+            /// instruction where the level has one. The bound holds for positive
+            /// normal inputs, and zero and infinity give infinity and zero.
+        },
+        "recip" => quote! {
+            /// Compute `1 / self` by refining [`recip_approx`](Self::recip_approx)
+            /// with `steps` Newton-Raphson steps.
+            ///
+            /// Each step roughly squares the relative error, until it reaches
+            /// the rounding error of the lanes. It is below 2^-15 after one step
+            /// (2^-11 for `f16`), below 2^-22 for `f32` and 2^-30 for `f64` after
+            /// two, and below 2^-50 for `f64` after three. Levels that divide
+            /// exactly ignore `steps`.
+            ///
+            /// Zero, infinite and subnormal lanes, and lanes with a subnormal
+            /// reciprocal, are divided exactly.
+        },
+        "rsqrt" => quote! {
+            /// Compute `1 / self.sqrt()` by refining
+            /// [`rsqrt_approx`](Self::rsqrt_approx) with `steps` Newton-Raphson
+            /// steps.
+            ///
+            /// The error after each step has the same bounds as for
+            /// [`recip`](Self::recip).
+        },
+        "madd" => quote! {
+            /// Compute `self + op1 * op2`.
+            ///
+            /// This is fused where the level has a fused multiply-add, so the
+            /// result can differ between levels in the last bit.
+        },
+        "msub" => quote! {
+            /// Compute `self - op1 * op2`, fused like [`madd`](Self::madd).
+        },
+        "nmadd" => quote! {
+            /// Compute `-(self + op1 * op2)`, fused like [`madd`](Self::madd).
+        },
+        "nmsub" => quote! {
+            /// Compute `-(self - op1 * op2)`, fused like [`madd`](Self::madd).
+        },
+        "mul_add_fused" => quote! {
+            /// Compute `self * op1 + op2` with a single rounding.
+            ///
+            /// The result is the same on every level. Levels without a fused
+            /// multiply-add compute it in software, which is much slower.
+        },
+        "reduce_add" => quote! {
+            /// Add up the lanes.
+            ///
+            /// Integer sums wrap around. Float sums add neighbouring lanes
+            /// first, then neighbouring pairs, and so on, as in
+            /// `(a[0] + a[1]) + (a[2] + a[3])`. This order is the same on every
+            /// level, so the result is too. `f16` lanes are added up in `f32`,
+            /// and the sum is rounded once.
+        },
+        "reduce_min" => quote! {
+            /// The smallest lane.
+            ///
+            /// Float NaN lanes are ignored, like in
+            /// [`min_precise`](SimdFloat::min_precise), so the result is only NaN
+            /// if every lane is NaN. If the smallest lanes are zeros of both signs,
+            /// either zero can be returned.
+        },
+        "reduce_max" => quote! {
+            /// The largest lane.
+            ///
+            /// Float NaN lanes are ignored, like in
+            /// [`max_precise`](SimdFloat::max_precise), so the result is only NaN
+            /// if every lane is NaN. If the largest lanes are zeros of both signs,
+            /// either zero can be returned.
+        },
+        "reduce_and" => quote! {
+            /// The bitwise and of all lanes.
+        },
+        "reduce_or" => quote! {
+            /// The bitwise or of all lanes.
+        },
+        "mul_add_fast" => quote! {
+            /// Compute `self * op1 + op2`, with whichever of a fused or a separate
+            /// multiply and add is faster on the level.
+        },
+        _ => quote! {},
+    }
+}
+
 fn methods_for_vec_trait(ops: &[(&str, OpSig)]) -> Vec<TokenStream> {
     let mut methods = vec![];
     for (method, sig) in ops {
@@ -152,11 +358,15 @@ fn methods_for_vec_trait(ops: &[(&str, OpSig)]) -> Vec<TokenStream> {
         let method_name = Ident::new(method, Span::call_site());
         if let Some(args) = sig.vec_trait_args() {
             let ret_ty = match sig {
-                OpSig::Compare => quote! { Self::Mask },
+                OpSig::Compare | OpSig::Classify => quote! { Self::Mask },
                 OpSig::Zip(_) => quote! { Self },
+                OpSig::Reduce => quote! { Element },
+                OpSig::MaskQuery(ret) => ret.rust(),
                 _ => quote! { Self },
             };
+            let doc = vec_trait_doc(method);
             methods.push(quote! {
+                #doc
                 fn #method_name(#args) -> #ret_ty;
             });
         }
@@ -5,13 +5,14 @@ use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
 use crate::{
-    ops::{CORE_OPS, OpSig, TyFlavor, ops_for_type},
+    ops::{CORE_OPS, OpSig, TyFlavor, base_ops, bf16_pairs_ty, lookup_table_ty, ops_for_type},
     types::{SIMD_TYPES, ScalarType, VecType},
 };
 
 pub fn mk_simd_types() -> TokenStream {
     let mut result = quote! {
-        use crate::{Bytes, Select, Simd, SimdFrom, SimdInto};
+        use crate::{Bytes, Indices, Select, Simd, SimdFrom, SimdInto, bf16, f16};
+        use crate::shuffle::SameLanes;
     };
     for ty in SIMD_TYPES {
         let name = ty.rust();
@@ -138,72 +139,247 @@ fn simd_impl(ty: &VecType) -> TokenStream {
             sig,
             OpSig::Unary
                 | OpSig::Binary
+                | OpSig::BinaryTo(_)
                 | OpSig::Compare
                 | OpSig::Combine
                 | OpSig::Cvt(_, _)
                 | OpSig::Reinterpret(_, _)
+                | OpSig::WidenNarrow(_)
+                | OpSig::WidenHalf(_)
                 | OpSig::Shift
+                | OpSig::Refine
+                | OpSig::Classify
+                | OpSig::Reduce
+                | OpSig::MaskQuery(_)
+                | OpSig::Reverse
+                | OpSig::Unzip(_)
         ) {
             if let Some(args) = sig.vec_trait_args() {
                 let ret_ty = sig.ret_ty(ty, TyFlavor::VecImpl);
                 let call_args = match sig {
-                    OpSig::Unary | OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _) => quote! { self },
-                    OpSig::Binary | OpSig::Compare | OpSig::Combine => {
+                    OpSig::Unary
+                    | OpSig::Cvt(_, _)
+                    | OpSig::Reinterpret(_, _)
+                    | OpSig::WidenNarrow(_)
+                    | OpSig::WidenHalf(_)
+                    | OpSig::Reduce
+                    | OpSig::Classify
+                    | OpSig::MaskQuery(_)
+                    | OpSig::Reverse => {
+                        quote! { self }
+                    }
+                    OpSig::Binary
+                    | OpSig::BinaryTo(_)
+                    | OpSig::Compare
+                    | OpSig::Combine
+                    | OpSig::Unzip(_) => {
                         quote! { self, rhs.simd_into(self.simd) }
                     }
                     OpSig::Shift => {
                         quote! { self, shift }
                     }
+                    OpSig::Refine => {
+                        quote! { self, steps }
+                    }
                     OpSig::Ternary => {
                         quote! { self, op1.simd_into(self.simd), op2.simd_into(self.simd) }
                     }
                     _ => quote! { todo!() },
                 };
+                let doc = match sig {
+                    OpSig::Cvt(scalar, scalar_bits) => {
+                        let doc = cvt_doc(method, ty, scalar, scalar_bits);
+                        quote! { #[doc = #doc] }
+                    }
+                    _ => quote! {},
+                };
                 methods.push(quote! {
+                    #doc
                     #[inline(always)]
                     pub fn #method_name(#args) -> #ret_ty {
                         self.simd.#trait_method(#call_args)
                     }
                 });
             }
+        } else if matches!(sig, OpSig::Split) {
+            let ret_ty = sig.ret_ty(ty, TyFlavor::VecImpl);
+            methods.push(quote! {
+                #[inline(always)]
+                pub fn #method_name(self) -> #ret_ty {
+                    self.simd.#trait_method(self)
+                }
+            });
+        } else if let OpSig::Shuffle(inputs) = sig {
+            let generics = sig.generics(ty);
+            let (args, call_args) = if inputs == 1 {
+                (quote! { self }, quote! { self })
+            } else {
+                (
+                    quote! { self, rhs: impl SimdInto<Self, S> },
+                    quote! { self, rhs.simd_into(self.simd) },
+                )
+            };
+            methods.push(quote! {
+                #[inline(always)]
+                pub fn #method_name #generics (#args) -> #name<S> {
+                    self.simd.#trait_method::<I>(#call_args)
+                }
+            });
+        } else if matches!(sig, OpSig::FromBitmask) {
+            methods.push(quote! {
+                #[inline(always)]
+                pub fn #method_name(simd: S, bits: u64) -> Self {
+                    simd.#trait_method(bits)
+                }
+            });
+        } else if let OpSig::Lookup(table_len) = sig {
+            // `swizzle_dyn` is called on the table, and the wider lookups on
+            // the indices, since their table has a different type.
+            methods.push(if method == "swizzle_dyn" {
+                quote! {
+                    /// Look up each byte of `idx` in `self`, with zero for indices
+                    /// past the end.
+                    #[inline(always)]
+                    pub fn #method_name(self, idx: impl SimdInto<Self, S>) -> #name<S> {
+                        self.simd.#trait_method(self, idx.simd_into(self.simd))
+                    }
+                }
+            } else {
+                let table_ty = lookup_table_ty(table_len, TyFlavor::VecImpl);
+                let doc = if table_len == 48 {
+                    " Look up each byte of `self` in a 48 byte table of three vectors, with zero for indices past the end.".to_string()
+                } else {
+                    format!(
+                        " Look up each byte of `self` in a {table_len} byte table, with zero for indices past the end."
+                    )
+                };
+                quote! {
+                    #[doc = #doc]
+                    #[inline(always)]
+                    pub fn #method_name(self, table: #table_ty) -> #name<S> {
+                        self.simd.#trait_method(table, self)
+                    }
+                }
+            });
+        } else if matches!(sig, OpSig::DotBf16) {
+            let pairs_ty = bf16_pairs_ty(ty).rust();
+            methods.push(quote! {
+                #[inline(always)]
+                pub fn #method_name(self, a: #pairs_ty<S>, b: #pairs_ty<S>) -> #name<S> {
+                    self.simd.#trait_method(self, a, b)
+                }
+            });
         }
     }
+    let rotate_left = Ident::new(&format!("rotate_lanes_left_{ty_name}"), Span::call_site());
+    let rotate_right = Ident::new(&format!("rotate_lanes_right_{ty_name}"), Span::call_site());
+    let broadcast = Ident::new(&format!("broadcast_lane_{ty_name}"), Span::call_site());
+    let len = Literal::usize_unsuffixed(ty.len);
     let vec_impl = simd_vec_impl(ty);
     quote! {
         impl<S: Simd> #name<S> {
             #( #methods )*
+
+            #[inline(always)]
+            pub fn rotate_lanes_left<const K: usize>(self) -> #name<S> {
+                self.simd.#rotate_left::<K>(self)
+            }
+
+            #[inline(always)]
+            pub fn rotate_lanes_right<const K: usize>(self) -> #name<S> {
+                self.simd.#rotate_right::<K>(self)
+            }
+
+            #[inline(always)]
+            pub fn broadcast_lane<const L: usize>(self) -> #name<S> {
+                const { assert!(L < #len, "lane index out of range") };
+                self.simd.#broadcast::<L>(self)
+            }
         }
         #vec_impl
     }
 }
 
+/// The doc comment of a conversion, with its rounding and saturation rules
+fn cvt_doc(method: &str, ty: &VecType, scalar: ScalarType, scalar_bits: usize) -> String {
+    let to = scalar.rust(scalar_bits);
+    let float = |scalar| matches!(scalar, ScalarType::Float | ScalarType::BFloat);
+    if method == "cvt_f32_unorm" {
+        format!(
+            " Convert each lane to `{to}`, mapping `0..=255` to `0.0..=1.0` like `x as f32 / 255.0`."
+        )
+    } else if float(scalar) {
+        let overflows = if float(ty.scalar) {
+            scalar_bits < ty.scalar_bits
+        } else {
+            scalar_bits == 16
+                && (ty.scalar_bits > 16
+                    || ty.scalar_bits == 16 && ty.scalar == ScalarType::Unsigned)
+        };
+        let overflow = if overflows {
+            format!("\n\n Values too large for `{to}` become infinities.")
+        } else {
+            String::new()
+        };
+        format!(
+            " Convert each lane to `{to}`, rounding to nearest, ties to even, like `as`.{overflow}"
+        )
+    } else {
+        let rounding = if method.ends_with("_round") {
+            "rounding to nearest, ties to even".to_string()
+        } else {
+            format!(
+                "rounding toward zero. See [`{method}_round`](Self::{method}_round) to round to nearest"
+            )
+        };
+        format!(
+            " Convert each lane to `{to}`, {rounding}.\n\n Lanes outside the range of `{to}` \
+             saturate to its minimum or maximum, and NaN gives 0, like `as`."
+        )
+    }
+}
+
 fn simd_vec_impl(ty: &VecType) -> TokenStream {
     let name = ty.rust();
     let ty_name = ty.rust_name();
     let scalar = ty.scalar.rust(ty.scalar_bits);
     let len = Literal::usize_unsuffixed(ty.len);
     let vec_trait = match ty.scalar {
-        ScalarType::Float => "SimdFloat",
-        ScalarType::Unsigned | ScalarType::Int => "SimdInt",
-        ScalarType::Mask => "SimdMask",
-    };
-    let zero = match ty.scalar {
-        ScalarType::Float => quote! { 0.0 },
-        _ => quote! { 0 },
+        ScalarType::Float => Some("SimdFloat"),
+        ScalarType::Unsigned | ScalarType::Int => Some("SimdInt"),
+        ScalarType::Mask => Some("SimdMask"),
+        // There is no arithmetic on bf16 vectors, so they only implement `SimdBase`.
+        ScalarType::BFloat => None,
     };
-    let vec_trait_id = Ident::new(vec_trait, Span::call_site());
+    let zero = ty.scalar.zero(ty.scalar_bits);
     let splat = Ident::new(&format!("splat_{}", ty.rust_name()), Span::call_site());
     let mut methods = vec![];
     for (method, sig) in ops_for_type(ty, false) {
         if CORE_OPS.contains(&method) || matches!(sig, OpSig::Combine) {
             continue;
         }
+        // Ops that only exist for some lane widths aren't in the vec traits.
+        if !base_ops(ty.scalar).iter().any(|(name, _)| *name == method) {
+            continue;
+        }
         let method_name = Ident::new(method, Span::call_site());
         let trait_method = Ident::new(&format!("{method}_{ty_name}"), Span::call_site());
+        if let OpSig::FromBitmask = sig {
+            methods.push(quote! {
+                #[inline(always)]
+                fn #method_name(simd: S, bits: u64) -> Self {
+                    simd.#trait_method(bits)
+                }
+            });
+            continue;
+        }
         if let Some(args) = sig.vec_trait_args() {
             let ret_ty = sig.ret_ty(ty, TyFlavor::VecImpl);
             let call_args = match sig {
-                OpSig::Unary => quote! { self },
+                OpSig::Unary | OpSig::Reduce | OpSig::Classify | OpSig::MaskQuery(_) => {
+                    quote! { self }
+                }
+                OpSig::Refine => quote! { self, steps },
                 OpSig::Binary | OpSig::Compare | OpSig::Combine | OpSig::Zip(_) => {
                     quote! { self, rhs.simd_into(self.simd) }
                 }
@@ -220,7 +396,23 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
             });
         }
     }
+    let vec_trait_impl = vec_trait.map(|vec_trait| {
+        let vec_trait_id = Ident::new(vec_trait, Span::call_site());
+        quote! {
+            impl<S: Simd> crate::#vec_trait_id<#scalar, S> for #name<S> {
+                #( #methods )*
+            }
+        }
+    });
     let mask_ty = ty.mask_ty().rust();
+    let reverse = Ident::new(&format!("reverse_{ty_name}"), Span::call_site());
+    let unzip_low = Ident::new(&format!("unzip_low_{ty_name}"), Span::call_site());
+    let unzip_high = Ident::new(&format!("unzip_high_{ty_name}"), Span::call_site());
+    let shuffle = Ident::new(&format!("shuffle_{ty_name}"), Span::call_site());
+    let shuffle2 = Ident::new(&format!("shuffle2_{ty_name}"), Span::call_site());
+    let rotate_left = Ident::new(&format!("rotate_lanes_left_{ty_name}"), Span::call_site());
+    let rotate_right = Ident::new(&format!("rotate_lanes_right_{ty_name}"), Span::call_site());
+    let broadcast = Ident::new(&format!("broadcast_lane_{ty_name}"), Span::call_site());
     let block_ty = VecType::new(ty.scalar, ty.scalar_bits, 128 / ty.scalar_bits).rust();
     let block_splat_body = match ty.n_bits() {
         64 => quote! {
@@ -271,10 +463,49 @@ fn simd_vec_impl(ty: &VecType) -> TokenStream {
                 #block_splat_body
             }
 
+            #[inline(always)]
+            fn reverse(self) -> Self {
+                self.simd.#reverse(self)
+            }
+
+            #[inline(always)]
+            fn unzip_low(self, rhs: impl SimdInto<Self, S>) -> Self {
+                self.simd.#unzip_low(self, rhs.simd_into(self.simd))
+            }
+
+            #[inline(always)]
+            fn unzip_high(self, rhs: impl SimdInto<Self, S>) -> Self {
+                self.simd.#unzip_high(self, rhs.simd_into(self.simd))
+            }
+
+            #[inline(always)]
+            fn shuffle<I: Indices<M>, const M: usize>(self) -> Self {
+                self.simd.#shuffle::<SameLanes<I, M>>(self)
+            }
+
+            #[inline(always)]
+            fn shuffle2<I: Indices<M>, const M: usize>(self, rhs: impl SimdInto<Self, S>) -> Self {
+                self.simd.#shuffle2::<SameLanes<I, M>>(self, rhs.simd_into(self.simd))
+            }
+
+            #[inline(always)]
+            fn rotate_lanes_left<const K: usize>(self) -> Self {
+                self.simd.#rotate_left::<K>(self)
+            }
+
+            #[inline(always)]
+            fn rotate_lanes_right<const K: usize>(self) -> Self {
+                self.simd.#rotate_right::<K>(self)
+            }
+
+            #[inline(always)]
+            fn broadcast_lane<const L: usize>(self) -> Self {
+                const { assert!(L < #len, "lane index out of range") };
+                self.simd.#broadcast::<L>(self)
+            }
+
         }
-        impl<S: Simd> crate::#vec_trait_id<#scalar, S> for #name<S> {
-            #( #methods )*
-        }
+        #vec_trait_impl
     }
 }
 
@@ -1,26 +1,47 @@
 // Copyright 2025 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::ops::{load_interleaved_arg_ty, store_interleaved_arg_ty, valid_reinterpret};
 use crate::{
     arch::{Arch, wasm::Wasm},
-    generic::{generic_combine, generic_op, generic_split},
+    generic::{
+        generic_abs_diff, generic_bf16_op, generic_classify, generic_combine, generic_cvt,
+        generic_cvt_lanes, generic_cvt_round, generic_f16_cvt, generic_f16_op, generic_half_op,
+        generic_lookup_tables, generic_mask_query, generic_mul_add_fast, generic_mul_add_fused,
+        generic_narrow_saturating, generic_negated_madd, generic_op, generic_recip,
+        generic_reduce_bits, generic_round, generic_shift_var, generic_shuffle, generic_split,
+        is_generic_cvt, native_lane_move, permutation_bytes, scalar_reduce,
+    },
     ops::{OpSig, TyFlavor, ops_for_type},
     types::{SIMD_TYPES, ScalarType, VecType, type_imports},
 };
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Level {
     WasmSimd128,
+    WasmRelaxedSimd,
 }
 
 impl Level {
     fn name(self) -> &'static str {
         match self {
             Level::WasmSimd128 => "WasmSimd128",
+            Level::WasmRelaxedSimd => "WasmRelaxedSimd",
+        }
+    }
+
+    fn doc(self) -> &'static str {
+        match self {
+            Level::WasmSimd128 => r#" The SIMD token for the "wasm128" level."#,
+            Level::WasmRelaxedSimd => {
+                r#" The SIMD token for the "relaxed-simd" level.
+
+ This is the "wasm128" level plus the relaxed SIMD instructions, whose results
+ may differ between engines for NaN, signed zero and out-of-range inputs."#
+            }
         }
     }
 
@@ -40,7 +61,43 @@ fn mk_simd_impl(level: Level) -> TokenStream {
         let ty = vec_ty.rust();
 
         for (method, sig) in ops_for_type(vec_ty, true) {
-            let b1 = vec_ty.n_bits() > 128 && !matches!(method, "split" | "narrow")
+            if sig.is_permutation() {
+                methods.push(mk_permutation(method, sig, vec_ty));
+                continue;
+            }
+            if let OpSig::Classify = sig {
+                methods.push(generic_classify(method, vec_ty));
+                continue;
+            }
+            if is_generic_cvt(sig, vec_ty) {
+                methods.push(generic_cvt(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.is_f16() && !matches!(sig, OpSig::Reinterpret(_, _)) {
+                methods.push(generic_f16_op(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.scalar == ScalarType::BFloat
+                && !matches!(sig, OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _))
+            {
+                methods.push(generic_bf16_op(method, sig, vec_ty));
+                continue;
+            }
+            if matches!(sig, OpSig::Cvt(ScalarType::Float, 16)) {
+                methods.push(generic_f16_cvt(method, sig, vec_ty));
+                continue;
+            }
+            // WASM has no shifts by a vector of shift counts.
+            if matches!(method, "shl_var" | "shr_var") {
+                methods.push(generic_shift_var(method, vec_ty));
+                continue;
+            }
+            // The 64 bit vectors live in the low half of a `v128`.
+            if vec_ty.n_bits() == 64 && !matches!(sig, OpSig::Combine | OpSig::WidenNarrow(_)) {
+                methods.push(generic_half_op(method, sig, vec_ty));
+                continue;
+            }
+            let b1 = vec_ty.n_bits() > 128 && method != "split" && !sig.is_narrowing(vec_ty)
                 || vec_ty.n_bits() > 256;
             let b2 = !matches!(method, "load_interleaved_128")
                 && !matches!(method, "store_interleaved_128");
@@ -64,12 +121,28 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Unary if method == "round" => generic_round(vec_ty),
+                // There are no estimate instructions, even in relaxed SIMD.
+                OpSig::Unary if method.ends_with("_approx") => generic_recip(method, sig, vec_ty),
+                OpSig::Refine => generic_recip(method, sig, vec_ty),
+                OpSig::BinaryTo(_) | OpSig::Binary
+                    if matches!(method, "mul_widen" | "mul_high" | "dot_i16") =>
+                {
+                    mk_widening_mul(method, sig, vec_ty)
+                }
+                OpSig::BinaryTo(_) => generic_abs_diff(vec_ty),
+                OpSig::Cvt(_, _) if method.ends_with("_round") => {
+                    generic_cvt_round(method, sig, vec_ty)
+                }
+                // There are no conversions between f64 and 64 bit integers.
+                OpSig::Cvt(_, 64) if scalar_bits == 64 => generic_cvt_lanes(method, sig, vec_ty),
                 OpSig::Unary => {
                     let args = [quote! { a.into() }];
                     let expr = if matches!(method, "fract") {
-                        assert_eq!(ty_name, "f32x4", "only support fract_f32x4");
+                        let sub = format_ident!("sub_{ty_name}");
+                        let trunc = format_ident!("trunc_{ty_name}");
                         quote! {
-                            self.sub_f32x4(a, self.trunc_f32x4(a))
+                            self.#sub(a, self.#trunc(a))
                         }
                     } else {
                         let expr = Wasm.expr(method, vec_ty, &args);
@@ -83,11 +156,11 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                     }
                 }
                 OpSig::Binary if method == "copysign" => {
-                    assert_eq!(ty_name, "f32x4", "only support copysign_f32x4");
+                    let splat = format_ident!("{ty_name}_splat");
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
-                            let sign_mask = f32x4_splat(-0.0_f32);
+                            let sign_mask = #splat(-0.0);
                             let sign_bits = v128_and(b.into(), sign_mask.into());
                             let magnitude = v128_andnot(a.into(), sign_mask.into());
                             v128_or(magnitude, sign_bits).simd_into(self)
@@ -97,6 +170,18 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                 OpSig::Binary => {
                     let args = [quote! { a.into() }, quote! { b.into() }];
                     match method {
+                        "max" | "min"
+                            if level == Level::WasmRelaxedSimd
+                                && vec_ty.scalar == ScalarType::Float =>
+                        {
+                            let relaxed = format_ident!("{ty_name}_relaxed_{method}");
+                            quote! {
+                                #[inline(always)]
+                                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                                    #relaxed(a.into(), b.into()).simd_into(self)
+                                }
+                            }
+                        }
                         "mul" if vec_ty.scalar_bits == 8 && vec_ty.len == 16 => {
                             let (extmul_low, extmul_high) = match vec_ty.scalar {
                                 ScalarType::Unsigned => (
@@ -143,31 +228,41 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Ternary if matches!(method, "nmadd" | "nmsub") => {
+                    generic_negated_madd(method, vec_ty)
+                }
+                OpSig::Ternary if method == "mul_add_fast" => generic_mul_add_fast(vec_ty),
+                // `relaxed_madd` may or may not fuse, so neither level can use it.
+                OpSig::Ternary if method == "mul_add_fused" => generic_mul_add_fused(vec_ty),
                 OpSig::Ternary => {
                     if matches!(method, "madd" | "msub") {
                         let first_ident = {
-                            let str = if method == "madd" {
-                                "add_f32x4"
-                            } else {
-                                "sub_f32x4"
-                            };
+                            let str = if method == "madd" { "add" } else { "sub" };
 
-                            Ident::new(str, Span::call_site())
+                            format_ident!("{str}_{ty_name}")
                         };
+                        let mul = format_ident!("mul_{ty_name}");
 
-                        assert_eq!(
-                            vec_ty,
-                            &VecType {
-                                scalar: ScalarType::Float,
-                                scalar_bits: 32,
-                                len: 4,
+                        assert_eq!(vec_ty.scalar, ScalarType::Float);
+                        if level == Level::WasmRelaxedSimd {
+                            // The relaxed instructions compute `a * b + c` and `-(a * b) + c`.
+                            let relaxed = if method == "madd" {
+                                format_ident!("{ty_name}_relaxed_madd")
+                            } else {
+                                format_ident!("{ty_name}_relaxed_nmadd")
+                            };
+                            quote! {
+                                #[inline(always)]
+                                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                                    #relaxed(b.into(), c.into(), a.into()).simd_into(self)
+                                }
                             }
-                        );
-                        // TODO: `relaxed-simd` has madd.
-                        quote! {
-                            #[inline(always)]
-                            fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
-                                self.#first_ident(a, self.mul_f32x4(b, c))
+                        } else {
+                            quote! {
+                                #[inline(always)]
+                                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                                    self.#first_ident(a, self.#mul(b, c))
+                                }
                             }
                         }
                     } else {
@@ -186,14 +281,37 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                 }
                 OpSig::Select => {
                     let mask_ty = vec_ty.mask_ty().rust();
+                    // Mask lanes are all ones or all zeros, so a lane select is enough.
+                    let select = if level == Level::WasmRelaxedSimd {
+                        format_ident!("i{scalar_bits}x{}_relaxed_laneselect", vec_ty.len)
+                    } else {
+                        format_ident!("v128_bitselect")
+                    };
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, a: #mask_ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
-                            v128_bitselect(b.into(), c.into(), a.into()).simd_into(self)
+                            #select(b.into(), c.into(), a.into()).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Combine if vec_ty.n_bits() == 64 => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            u64x2_shuffle::<0, 2>(a.into(), b.into()).simd_into(self)
                         }
                     }
                 }
                 OpSig::Combine => generic_combine(vec_ty),
+                OpSig::Split if vec_ty.n_bits() == 128 => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            let a: v128 = a.into();
+                            (a.simd_into(self), u64x2_shuffle::<1, 1>(a, a).simd_into(self))
+                        }
+                    }
+                }
                 OpSig::Split => generic_split(vec_ty),
                 OpSig::Zip(is_low) => {
                     let (indices, shuffle_fn) = match vec_ty.scalar_bits {
@@ -221,6 +339,14 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                             };
                             (indices, quote! { u32x4_shuffle })
                         }
+                        64 => {
+                            let indices = if is_low {
+                                quote! { 0, 2 }
+                            } else {
+                                quote! { 1, 3 }
+                            };
+                            (indices, quote! { u64x2_shuffle })
+                        }
                         _ => panic!("unsupported scalar_bits for zip operation"),
                     };
 
@@ -231,19 +357,165 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::MaskQuery(_) => mk_mask_query(method, vec_ty),
+                // Permutations are handled before the per-op dispatch.
+                OpSig::Shuffle(_)
+                | OpSig::Reverse
+                | OpSig::Rotate(_)
+                | OpSig::Broadcast
+                | OpSig::Unzip(_)
+                | OpSig::Classify => {
+                    unreachable!()
+                }
+                // Like `vqtbl`, `i8x16_swizzle` gives zero for indices past the end.
+                OpSig::Lookup(16) => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                            i8x16_swizzle(a.into(), b.into()).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Lookup(_) => generic_lookup_tables(method, sig, vec_ty),
+                OpSig::FromBitmask => {
+                    let bits = scalar_bits;
+                    let lanes = vec_ty.len;
+                    let weights = (0..lanes).map(|i| Literal::u64_unsuffixed(1 << (i % 8)));
+                    let weights_fn = format_ident!("u{bits}x{lanes}");
+                    let eq = format_ident!("i{bits}x{lanes}_eq");
+                    // Every lane holds the bits of its group of 8 lanes.
+                    let splat = match bits {
+                        8 => quote! {
+                            u64x2(
+                                (bits & 0xff) * 0x0101_0101_0101_0101,
+                                ((bits >> 8) & 0xff) * 0x0101_0101_0101_0101,
+                            )
+                        },
+                        64 => quote! { u64x2_splat(bits) },
+                        _ => {
+                            let splat = format_ident!("u{bits}x{lanes}_splat");
+                            let scalar = format_ident!("u{bits}");
+                            quote! { #splat(bits as #scalar) }
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, bits: u64) -> #ret_ty {
+                            let weights = #weights_fn(#( #weights ),*);
+                            #eq(v128_and(#splat, weights), weights).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Reduce if matches!(method, "reduce_and" | "reduce_or") => {
+                    generic_reduce_bits(method, vec_ty)
+                }
+                OpSig::Reduce if vec_ty.scalar_bits == 64 && vec_ty.scalar != ScalarType::Float => {
+                    scalar_reduce(method, vec_ty)
+                }
+                OpSig::Reduce => {
+                    let lane_op = &method["reduce_".len()..];
+                    // The float min and max ignore NaN lanes. `pmin` and `pmax` with
+                    // switched arguments give `y` where `x` is NaN, and the select
+                    // gives `x` where `y` is NaN.
+                    let ne = format_ident!("{}_ne", Wasm.arch_ty(vec_ty).to_string());
+                    let float_op = |x: TokenStream, y: TokenStream| {
+                        if lane_op == "add" {
+                            return Wasm.expr(lane_op, vec_ty, &[x, y]);
+                        }
+                        let pick = Wasm.expr(
+                            &format!("{lane_op}_precise"),
+                            vec_ty,
+                            &[quote! { y }, quote! { x }],
+                        );
+                        quote! {{
+                            let (x, y) = (#x, #y);
+                            v128_bitselect(x, #pick, #ne(y, y))
+                        }}
+                    };
+                    let body = match (vec_ty.scalar, vec_ty.scalar_bits) {
+                        (ScalarType::Float, 32) => {
+                            // Combine neighbouring lanes, then the two pairs.
+                            let pairs = float_op(
+                                quote! { a },
+                                quote! { i32x4_shuffle::<1, 0, 3, 2>(a, a) },
+                            );
+                            let total = float_op(
+                                quote! { pairs },
+                                quote! { i32x4_shuffle::<2, 3, 0, 1>(pairs, pairs) },
+                            );
+                            quote! {
+                                let a: v128 = a.into();
+                                let pairs = #pairs;
+                                f32x4_extract_lane::<0>(#total)
+                            }
+                        }
+                        (ScalarType::Float, 64) => {
+                            let total =
+                                float_op(quote! { a }, quote! { i64x2_shuffle::<1, 0>(a, a) });
+                            quote! {
+                                let a: v128 = a.into();
+                                f64x2_extract_lane::<0>(#total)
+                            }
+                        }
+                        _ => {
+                            // Fold the upper half of the vector onto the lower half until
+                            // a single lane is left.
+                            let mut folds = vec![];
+                            let mut width = 64;
+                            while width >= vec_ty.scalar_bits {
+                                let lanes = 128 / width;
+                                let shuffle = format_ident!("u{width}x{lanes}_shuffle");
+                                let indices = vec![quote! { 1 }; lanes];
+                                let shifted = quote! { #shuffle::<#( #indices ),*>(a, a) };
+                                folds.push(Wasm.expr(lane_op, vec_ty, &[quote! { a }, shifted]));
+                                width /= 2;
+                            }
+                            let extract =
+                                format_ident!("{}_extract_lane", Wasm.arch_ty(vec_ty).to_string());
+                            quote! {
+                                let a: v128 = a.into();
+                                #( let a = #folds; )*
+                                #extract::<0>(a)
+                            }
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #body
+                        }
+                    }
+                }
                 OpSig::Shift => {
                     let prefix = match vec_ty.scalar {
                         ScalarType::Int => "i",
                         ScalarType::Unsigned => "u",
                         _ => unimplemented!(),
                     };
-                    let shift_name = format!("{prefix}{}x{}_shr", vec_ty.scalar_bits, vec_ty.len);
+                    let shift_name =
+                        format!("{prefix}{}x{}_{method}", vec_ty.scalar_bits, vec_ty.len);
                     let shift_fn = Ident::new(&shift_name, Span::call_site());
 
+                    // The shifts take the count modulo the lane width, so larger
+                    // counts are handled separately.
+                    let width = Literal::u32_unsuffixed(scalar_bits as u32);
+                    let body = if vec_ty.scalar == ScalarType::Int && method == "shr" {
+                        let max = Literal::u32_unsuffixed(scalar_bits as u32 - 1);
+                        quote! { #shift_fn(a.into(), shift.min(#max)).simd_into(self) }
+                    } else {
+                        quote! {
+                            if shift < #width {
+                                #shift_fn(a.into(), shift).simd_into(self)
+                            } else {
+                                u64x2_splat(0).simd_into(self)
+                            }
+                        }
+                    };
+
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, a: #ty<Self>, shift: u32) -> #ret_ty {
-                            #shift_fn(a.into(), shift).simd_into(self)
+                            #body
                         }
                     }
                 }
@@ -259,6 +531,64 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::Cvt(ScalarType::Float, 64) => {
+                    assert_eq!(ty_name, "f32x4", "only support cvt_f64_f32x4");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            let low = f64x2_promote_low_f32x4(a.into());
+                            let high = f64x2_promote_low_f32x4(u64x2_shuffle::<1, 1>(a.into(), a.into()));
+                            self.combine_f64x2(low.simd_into(self), high.simd_into(self))
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if ty_name == "f64x2" => {
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            f32x4_demote_f64x2_zero(a.into()).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if scalar_bits == 64 => {
+                    assert_eq!(ty_name, "f64x4", "only support cvt_f32_f64x4");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            let (low, high) = self.split_f64x4(a);
+                            let low = f32x4_demote_f64x2_zero(low.into());
+                            let high = f32x4_demote_f64x2_zero(high.into());
+                            u64x2_shuffle::<0, 2>(low, high).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::Float, 32) if vec_ty.scalar == ScalarType::BFloat => {
+                    assert_eq!(ty_name, "bf16x8", "only support cvt_f32_bf16x8");
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            let low = i32x4_shl(u32x4_extend_low_u16x8(a.into()), 16);
+                            let high = i32x4_shl(u32x4_extend_high_u16x8(a.into()), 16);
+                            self.combine_f32x4(low.simd_into(self), high.simd_into(self))
+                        }
+                    }
+                }
+                OpSig::Cvt(ScalarType::BFloat, 16) => {
+                    assert_eq!(ty_name, "f32x8", "only support cvt_bf16_f32x8");
+                    let low = bf16_round(quote! { low });
+                    let high = bf16_round(quote! { high });
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            let (low, high) = self.split_f32x8(a);
+                            let (low, high): (v128, v128) = (low.into(), high.into());
+                            #low
+                            #high
+                            u16x8_narrow_i32x4(low, high).simd_into(self)
+                        }
+                    }
+                }
+                OpSig::DotBf16 => generic_bf16_op(method, sig, vec_ty),
                 OpSig::Cvt(scalar, scalar_bits) => {
                     let conversion_fn =
                         match (scalar, scalar_bits, vec_ty.scalar, vec_ty.scalar_bits) {
@@ -284,44 +614,109 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                         }
                     }
                 }
+                OpSig::WidenNarrow(_) if method == "narrow_saturating" && scalar_bits == 64 => {
+                    generic_narrow_saturating(vec_ty)
+                }
+                OpSig::WidenNarrow(to_ty) if method == "widen" => {
+                    let register = VecType::new(vec_ty.scalar, scalar_bits, 128 / scalar_bits);
+                    let half_to = VecType::new(to_ty.scalar, to_ty.scalar_bits, 64 / scalar_bits);
+                    let (from, to) = (
+                        Wasm.arch_ty(&register).to_string(),
+                        Wasm.arch_ty(&half_to).to_string(),
+                    );
+                    let extend_low = format_ident!("{to}_extend_low_{from}");
+                    let extend_high = format_ident!("{to}_extend_high_{from}");
+                    let body = if vec_ty.n_bits() == 64 {
+                        // The 64 bit vectors live in the low half of a `v128`.
+                        quote! { #extend_low(a.into()).simd_into(self) }
+                    } else {
+                        let combine = format_ident!("combine_{}", half_to.rust_name());
+                        quote! {
+                            let low = #extend_low(a.into());
+                            let high = #extend_high(a.into());
+                            self.#combine(low.simd_into(self), high.simd_into(self))
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #body
+                        }
+                    }
+                }
                 OpSig::WidenNarrow(to_ty) => {
-                    match method {
-                        "widen" => {
-                            assert_eq!(vec_ty.rust_name(), "u8x16");
-                            assert_eq!(to_ty.rust_name(), "u16x16");
-                            quote! {
-                                #[inline(always)]
-                                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
-                                    let low = u16x8_extend_low_u8x16(a.into());
-                                    let high = u16x8_extend_high_u8x16(a.into());
-                                    self.combine_u16x8(low.simd_into(self), high.simd_into(self))
-                                }
-                            }
+                    let register = VecType::new(vec_ty.scalar, scalar_bits, 128 / scalar_bits);
+                    let to_register =
+                        VecType::new(to_ty.scalar, to_ty.scalar_bits, 128 / to_ty.scalar_bits);
+                    let (from, to) = (
+                        Wasm.arch_ty(&register).to_string(),
+                        Wasm.arch_ty(&to_register).to_string(),
+                    );
+                    // A 128 bit vector narrows into the low half of the register.
+                    let halves = if vec_ty.n_bits() == 128 {
+                        quote! { let (low, high) = (a.into(), a.into()); }
+                    } else {
+                        let split = format_ident!("split_{ty_name}");
+                        quote! {
+                            let (low, high) = self.#split(a);
+                            let (low, high) = (low.into(), high.into());
                         }
-                        "narrow" => {
-                            assert_eq!(vec_ty.rust_name(), "u16x16");
-                            assert_eq!(to_ty.rust_name(), "u8x16");
-                            // WASM SIMD only has saturating narrowing instructions, so we emulate
-                            // truncated narrowing by masking out the
+                    };
+                    let narrowed = if method == "narrow" {
+                        // The low half of each lane is the even lane of the narrower type.
+                        let shuffle = format_ident!("{to}_shuffle");
+                        let even = (0..to_register.len).map(|i| Literal::usize_unsuffixed(2 * i));
+                        quote! { #shuffle::<#( #even ),*>(low, high) }
+                    } else {
+                        // The narrowing instructions read signed lanes, so unsigned lanes
+                        // are clamped first.
+                        let signed_from = VecType::new(ScalarType::Int, scalar_bits, register.len);
+                        let signed_from = Wasm.arch_ty(&signed_from).to_string();
+                        let narrow = format_ident!("{to}_narrow_{signed_from}");
+                        if vec_ty.scalar == ScalarType::Unsigned {
+                            let min = format_ident!("{from}_min");
+                            let splat = format_ident!("{from}_splat");
+                            let max = to_ty.scalar.rust(to_ty.scalar_bits);
+                            let from_scalar = vec_ty.scalar.rust(scalar_bits);
                             quote! {
-                                #[inline(always)]
-                                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
-                                    let mask = u16x8_splat(0xFF);
-                                    let (low, high) = self.split_u16x16(a);
-                                    let low_masked = v128_and(low.into(), mask);
-                                    let high_masked = v128_and(high.into(), mask);
-                                    let result = u8x16_narrow_i16x8(low_masked, high_masked);
-                                    result.simd_into(self)
-                                }
+                                let max = #splat(#max::MAX as #from_scalar);
+                                #narrow(#min(low, max), #min(high, max))
                             }
+                        } else {
+                            quote! { #narrow(low, high) }
+                        }
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #halves
+                            #narrowed.simd_into(self)
+                        }
+                    }
+                }
+                OpSig::WidenHalf(low) => {
+                    let to_ty = vec_ty.widened_half().unwrap();
+                    let (from, to) = (
+                        Wasm.arch_ty(vec_ty).to_string(),
+                        Wasm.arch_ty(&to_ty).to_string(),
+                    );
+                    let extend = if low {
+                        format_ident!("{to}_extend_low_{from}")
+                    } else {
+                        format_ident!("{to}_extend_high_{from}")
+                    };
+                    quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            #extend(a.into()).simd_into(self)
                         }
-                        _ => unimplemented!(),
                     }
                 }
                 OpSig::LoadInterleaved(block_size, count) => {
                     assert_eq!(count, 4, "only count of 4 is crrently supported");
                     let arg = load_interleaved_arg_ty(block_size, count, vec_ty);
                     let elems_per_vec = block_size as usize / vec_ty.scalar_bits;
+                    let [off0, off1, off2, off3] = [0, 1, 2, 3].map(|i| i * elems_per_vec);
 
                     // For WASM we need to simulate interleaving with shuffle, and we only have
                     // access to 2, 4 and 16 lanes. So, for 64 u8's, we need to split and recombine
@@ -369,10 +764,10 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                     quote! {
                         #[inline(always)]
                         fn #method_ident(self, #arg) -> #ret_ty {
-                                let v0: v128 = unsafe { v128_load(src[0 * #elems_per_vec..].as_ptr() as *const v128) };
-                                let v1: v128 = unsafe { v128_load(src[1 * #elems_per_vec..].as_ptr() as *const v128) };
-                                let v2: v128 = unsafe { v128_load(src[2 * #elems_per_vec..].as_ptr() as *const v128) };
-                                let v3: v128 = unsafe { v128_load(src[3 * #elems_per_vec..].as_ptr() as *const v128) };
+                                let v0: v128 = unsafe { v128_load(src[#off0..].as_ptr() as *const v128) };
+                                let v1: v128 = unsafe { v128_load(src[#off1..].as_ptr() as *const v128) };
+                                let v2: v128 = unsafe { v128_load(src[#off2..].as_ptr() as *const v128) };
+                                let v3: v128 = unsafe { v128_load(src[#off3..].as_ptr() as *const v128) };
 
                                 // InterleaveLowerLanes(v0, v2) and InterleaveLowerLanes(v1, v3)
                                 let v02_lower = #shuffle_fn::<#lower_indices>(v0, v2);
@@ -396,6 +791,7 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                     assert_eq!(count, 4, "only count of 4 is currently supported");
                     let arg = store_interleaved_arg_ty(block_size, count, vec_ty);
                     let elems_per_vec = block_size as usize / vec_ty.scalar_bits;
+                    let [off0, off1, off2, off3] = [0, 1, 2, 3].map(|i| i * elems_per_vec);
 
                     let (lower_indices, upper_indices, shuffle_fn) = match vec_ty.scalar_bits {
                         8 => (
@@ -462,10 +858,10 @@ fn mk_simd_impl(level: Level) -> TokenStream {
                             let out3 = #shuffle_fn::<#upper_indices>(v02_upper, v13_upper);
 
                             unsafe {
-                                v128_store(dest[0 * #elems_per_vec..].as_mut_ptr() as *mut v128, out0);
-                                v128_store(dest[1 * #elems_per_vec..].as_mut_ptr() as *mut v128, out1);
-                                v128_store(dest[2 * #elems_per_vec..].as_mut_ptr() as *mut v128, out2);
-                                v128_store(dest[3 * #elems_per_vec..].as_mut_ptr() as *mut v128, out3);
+                                v128_store(dest[#off0..].as_mut_ptr() as *mut v128, out0);
+                                v128_store(dest[#off1..].as_mut_ptr() as *mut v128, out1);
+                                v128_store(dest[#off2..].as_mut_ptr() as *mut v128, out2);
+                                v128_store(dest[#off3..].as_mut_ptr() as *mut v128, out3);
                             }
                         }
                     }
@@ -479,15 +875,19 @@ fn mk_simd_impl(level: Level) -> TokenStream {
     quote! {
         impl Simd for #level_tok {
             type f32s = f32x4<Self>;
+            type f64s = f64x2<Self>;
             type u8s = u8x16<Self>;
             type i8s = i8x16<Self>;
             type u16s = u16x8<Self>;
             type i16s = i16x8<Self>;
             type u32s = u32x4<Self>;
             type i32s = i32x4<Self>;
+            type u64s = u64x2<Self>;
+            type i64s = i64x2<Self>;
             type mask8s = mask8x16<Self>;
             type mask16s = mask16x8<Self>;
             type mask32s = mask32x4<Self>;
+            type mask64s = mask64x2<Self>;
 
             #[inline(always)]
             fn level(self) -> Level {
@@ -512,17 +912,23 @@ fn mk_simd_impl(level: Level) -> TokenStream {
 pub fn mk_wasm128_impl(level: Level) -> TokenStream {
     let imports = type_imports();
     let simd_impl = mk_simd_impl(level);
-    let ty_impl = mk_type_impl();
     let level_tok = level.token();
+    let doc = level.doc();
+    // The conversions between vector types and `v128` are shared by all wasm
+    // levels, so they're only emitted once.
+    let (ty_impl, simd_from) = match level {
+        Level::WasmSimd128 => (mk_type_impl(), quote! { SimdFrom, }),
+        Level::WasmRelaxedSimd => (quote! {}, quote! {}),
+    };
 
     quote! {
         use core::arch::wasm32::*;
 
-        use crate::{seal::Seal, Level, Simd, SimdFrom, SimdInto};
+        use crate::{seal::Seal, Bytes, Indices, Level, Simd, #simd_from SimdInto};
 
         #imports
 
-        /// The SIMD token for the "wasm128" level.
+        #[doc = #doc]
         #[derive(Clone, Copy, Debug)]
         pub struct #level_tok {
             _private: (),
@@ -543,13 +949,74 @@ pub fn mk_wasm128_impl(level: Level) -> TokenStream {
     }
 }
 
+/// The ops built on `extmul`, which multiplies into lanes of twice the width.
+fn mk_widening_mul(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    let bits = vec_ty.scalar_bits;
+    let wide_ty = VecType::new(vec_ty.scalar, bits * 2, vec_ty.len / 2);
+    let (narrow, wide) = (Wasm.arch_ty(vec_ty), Wasm.arch_ty(&wide_ty));
+    if method == "dot_i16" {
+        return quote! {
+            #[inline(always)]
+            fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                i32x4_dot_i16x8(a.into(), b.into()).simd_into(self)
+            }
+        };
+    }
+    let extmul_low = format_ident!("{wide}_extmul_low_{narrow}");
+    let extmul_high = format_ident!("{wide}_extmul_high_{narrow}");
+    let result = if method == "mul_widen" {
+        let combine = format_ident!("combine_{}", wide_ty.rust_name());
+        quote! { self.#combine(low.simd_into(self), high.simd_into(self)) }
+    } else {
+        // The high halves are the odd lanes of the products.
+        let shuffle = format_ident!("{narrow}_shuffle");
+        let odd = (0..vec_ty.len).map(|i| Literal::usize_unsuffixed(2 * i + 1));
+        quote! { #shuffle::<#( #odd ),*>(low, high).simd_into(self) }
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+            let low = #extmul_low(a.into(), b.into());
+            let high = #extmul_high(a.into(), b.into());
+            #result
+        }
+    }
+}
+
 fn mk_type_impl() -> TokenStream {
     let mut result = vec![];
     for ty in SIMD_TYPES {
+        let simd = ty.rust();
+        if ty.n_bits() == 64 {
+            // The upper half of the `v128` is unused.
+            result.push(quote! {
+                impl<S: Simd> SimdFrom<v128, S> for #simd<S> {
+                    #[inline(always)]
+                    fn simd_from(arch: v128, simd: S) -> Self {
+                        Self {
+                            val: bytemuck::cast(u64x2_extract_lane::<0>(arch)),
+                            simd
+                        }
+                    }
+                }
+                impl<S: Simd> From<#simd<S>> for v128 {
+                    #[inline(always)]
+                    fn from(value: #simd<S>) -> Self {
+                        u64x2_splat(bytemuck::cast(value.val))
+                    }
+                }
+            });
+            continue;
+        }
         if ty.n_bits() != 128 {
             continue;
         }
-        let simd = ty.rust();
         result.push(quote! {
             impl<S: Simd> SimdFrom<v128, S> for #simd<S> {
                 #[inline(always)]
@@ -572,3 +1039,110 @@ fn mk_type_impl() -> TokenStream {
         #( #result )*
     }
 }
+
+/// Round the f32 lanes of `x` to the nearest bf16, with ties to even.
+///
+/// The bf16 bits are left in the low half of each 32 bit lane. NaNs are
+/// quieted rather than rounded, so they can't turn into infinities.
+/// Lane permutations of 128 bit vectors work on the bytes, so they don't
+/// depend on the lane type.
+fn mk_permutation(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    match (sig, vec_ty.n_bits()) {
+        (_, 128) => {}
+        (OpSig::Rotate(_) | OpSig::Broadcast, 64) => return generic_half_op(method, sig, vec_ty),
+        (OpSig::Shuffle(_), _) | (_, 64) => return generic_shuffle(method, sig, vec_ty),
+        _ => return generic_op(method, sig, vec_ty),
+    }
+    let ty = vec_ty.rust();
+    let method_ident = format_ident!("{method}_{}", vec_ty.rust_name());
+    let generics = sig.generics(vec_ty);
+    let args = sig.simd_trait_args(vec_ty);
+    let table = |n| {
+        let bytes = (0..16).map(|i| {
+            let i = Literal::usize_unsuffixed(i);
+            quote! { #n[#i] }
+        });
+        quote! { u8x16(#( #bytes ),*) }
+    };
+    let bytes = match sig {
+        OpSig::Shuffle(1) => {
+            let table = table(quote! { table });
+            quote! {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                i8x16_swizzle(a.to_bytes().into(), #table)
+            }
+        }
+        OpSig::Shuffle(_) => {
+            let table_a = table(quote! { table_a });
+            let table_b = table(quote! { table_b });
+            // Out of range bytes of a swizzle are zero, so each input only
+            // fills in the bytes it provides.
+            quote! {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                v128_or(
+                    i8x16_swizzle(a.to_bytes().into(), #table_a),
+                    i8x16_swizzle(b.to_bytes().into(), #table_b),
+                )
+            }
+        }
+        OpSig::Rotate(_) | OpSig::Broadcast => native_lane_move(sig, vec_ty),
+        _ => {
+            let indices = permutation_bytes(sig, vec_ty)
+                .into_iter()
+                .map(Literal::u8_unsuffixed);
+            let b = if let OpSig::Unzip(_) = sig {
+                quote! { b }
+            } else {
+                quote! { a }
+            };
+            quote! {
+                i8x16_shuffle::<#( #indices ),*>(a.to_bytes().into(), #b.to_bytes().into())
+            }
+        }
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident #generics (#args) -> #ty<Self> {
+            let bytes = { #bytes };
+            #ty::from_bytes(bytes.simd_into(self))
+        }
+    }
+}
+
+fn mk_mask_query(method: &str, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let method_ident = format_ident!("{method}_{}", vec_ty.rust_name());
+    let prefix = format_ident!("i{}x{}", vec_ty.scalar_bits, vec_ty.len);
+    let (ret_ty, body) = match method {
+        "any" => (quote! { bool }, quote! { v128_any_true(a.into()) }),
+        "all" => {
+            let all_true = format_ident!("{prefix}_all_true");
+            (quote! { bool }, quote! { #all_true(a.into()) })
+        }
+        "to_bitmask" => {
+            let bitmask = format_ident!("{prefix}_bitmask");
+            (quote! { u64 }, quote! { #bitmask(a.into()).into() })
+        }
+        _ => return generic_mask_query(method, vec_ty),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+            #body
+        }
+    }
+}
+
+fn bf16_round(x: TokenStream) -> TokenStream {
+    quote! {
+        let lsb = v128_and(u32x4_shr(#x, 16), u32x4_splat(1));
+        let rounded = u32x4_shr(i32x4_add(i32x4_add(#x, u32x4_splat(0x7fff)), lsb), 16);
+        let quiet = u32x4_shr(v128_or(#x, u32x4_splat(0x0040_0000)), 16);
+        let #x = v128_bitselect(rounded, quiet, f32x4_eq(#x, #x));
+    }
+}
@@ -0,0 +1,2274 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{ToTokens, format_ident, quote};
+
+use crate::arch::x86::{
+    X86, cast_from_float, cast_to_float, coarse_suffix, intrinsic_ident, op_suffix, set1_intrinsic,
+};
+use crate::ops::{
+    load_interleaved_arg_ty, reinterpret_ty, store_interleaved_arg_ty, valid_reinterpret,
+};
+use crate::{
+    arch::Arch,
+    generic::{
+        bf16_dot_expr, generic_abs_diff, generic_bf16_op, generic_classify, generic_combine,
+        generic_cvt, generic_cvt_lanes, generic_cvt_round, generic_f16_cvt, generic_f16_op,
+        generic_half_op, generic_lookup_tables, generic_mask_query, generic_mul_add_fast,
+        generic_mul_add_fused, generic_narrow_saturating, generic_negated_madd, generic_op,
+        generic_recip, generic_reduce_bits, generic_refine, generic_round, generic_shift_var,
+        generic_shuffle, generic_split, is_generic_cvt, native_lane_move, permutation_bytes,
+        scalar_reduce,
+    },
+    ops::{OpSig, TyFlavor, bf16_pairs_ty, ops_for_type},
+    types::{SIMD_TYPES, ScalarType, VecType, type_imports},
+};
+
+#[derive(Clone, Copy)]
+pub enum Level {
+    Sse4_2,
+    Avx2,
+    Avx512,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Sse4_2 => "Sse4_2",
+            Level::Avx2 => "Avx2",
+            Level::Avx512 => "Avx512",
+        }
+    }
+
+    fn token(self) -> TokenStream {
+        let ident = Ident::new(self.name(), Span::call_site());
+        quote! { #ident }
+    }
+
+    /// The widest vector that has native registers at this level.
+    fn max_native_bits(self) -> usize {
+        match self {
+            Level::Sse4_2 => 128,
+            Level::Avx2 => 256,
+            Level::Avx512 => 512,
+        }
+    }
+
+    /// The target features enabled by `vectorize`.
+    fn target_features(self) -> &'static str {
+        match self {
+            Level::Sse4_2 => "sse4.2",
+            Level::Avx2 => "avx2,fma,f16c",
+            Level::Avx512 => "avx512f,avx512bw,avx512cd,avx512dq,avx512vl",
+        }
+    }
+
+    /// Whether the AVX-512 instructions are available for all vector widths.
+    fn has_avx512(self) -> bool {
+        matches!(self, Level::Avx512)
+    }
+
+    /// Whether there is an instruction that shifts each lane by its own amount.
+    fn has_shift_var(self, method: &str, ty: &VecType) -> bool {
+        match (ty.scalar, ty.scalar_bits) {
+            (_, 32) | (ScalarType::Unsigned, 64) => !matches!(self, Level::Sse4_2),
+            (ScalarType::Int, 64) if method == "shl_var" => !matches!(self, Level::Sse4_2),
+            (_, 16 | 64) => self.has_avx512(),
+            _ => false,
+        }
+    }
+
+    /// Whether fused multiply-add instructions are available.
+    fn has_fma(self) -> bool {
+        match self {
+            Level::Sse4_2 => false,
+            Level::Avx2 | Level::Avx512 => true,
+        }
+    }
+
+    /// Whether the half-precision conversion instructions are available.
+    fn has_f16c(self) -> bool {
+        match self {
+            Level::Sse4_2 => false,
+            Level::Avx2 | Level::Avx512 => true,
+        }
+    }
+
+    /// The `core_arch` tokens carried by the level token.
+    fn core_arch_tokens(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Level::Sse4_2 => &[
+                ("sse", "Sse"),
+                ("sse2", "Sse2"),
+                ("sse3", "Sse3"),
+                ("ssse3", "Ssse3"),
+                ("sse4_1", "Sse4_1"),
+                ("sse4_2", "Sse4_2"),
+            ],
+            Level::Avx2 => &[
+                ("sse", "Sse"),
+                ("sse2", "Sse2"),
+                ("sse3", "Sse3"),
+                ("ssse3", "Ssse3"),
+                ("sse4_1", "Sse4_1"),
+                ("sse4_2", "Sse4_2"),
+                ("avx", "Avx"),
+                ("avx2", "Avx2"),
+                ("fma", "Fma"),
+                ("f16c", "F16c"),
+            ],
+            Level::Avx512 => &[
+                ("sse", "Sse"),
+                ("sse2", "Sse2"),
+                ("sse3", "Sse3"),
+                ("ssse3", "Ssse3"),
+                ("sse4_1", "Sse4_1"),
+                ("sse4_2", "Sse4_2"),
+                ("avx", "Avx"),
+                ("avx2", "Avx2"),
+                ("fma", "Fma"),
+                ("f16c", "F16c"),
+                ("avx512", "Avx512"),
+            ],
+        }
+    }
+
+    /// The `core_arch` tokens for extensions outside the level, which are
+    /// detected when the level token is created.
+    fn optional_core_arch_tokens(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Level::Sse4_2 | Level::Avx2 => &[],
+            Level::Avx512 => &[("avx512bf16", "Avx512Bf16")],
+        }
+    }
+
+    fn doc(self) -> &'static str {
+        match self {
+            Level::Sse4_2 => " The SIMD token for the \"sse4.2\" level, which is x86-64-v2.",
+            Level::Avx2 => " The SIMD token for the \"avx2\" level, which is x86-64-v3.",
+            Level::Avx512 => " The SIMD token for the \"avx512\" level, which is x86-64-v4.",
+        }
+    }
+}
+
+pub fn mk_x86_impl(level: Level) -> TokenStream {
+    let imports = type_imports();
+    let simd_impl = mk_simd_impl(level);
+    // The conversions to and from the arch types don't depend on the level,
+    // so they are only generated once, alongside the baseline level.
+    let (ty_impl, simd_from) = match level {
+        Level::Sse4_2 => (mk_type_impl(), quote! { SimdFrom, }),
+        Level::Avx2 | Level::Avx512 => (quote! {}, quote! {}),
+    };
+    let level_tok = level.token();
+    let doc = level.doc();
+    let mut fields = vec![];
+    let mut inits = vec![];
+    for (field, token) in level.core_arch_tokens() {
+        let field = Ident::new(field, Span::call_site());
+        let token = Ident::new(token, Span::call_site());
+        fields.push(quote! { pub #field: crate::core_arch::x86_64::#token });
+        inits.push(quote! {
+            #field: unsafe { crate::core_arch::x86_64::#token::new_unchecked() }
+        });
+    }
+    for (field, token) in level.optional_core_arch_tokens() {
+        let field = Ident::new(field, Span::call_site());
+        let token = Ident::new(token, Span::call_site());
+        fields.push(quote! { pub #field: Option<crate::core_arch::x86_64::#token> });
+        inits.push(quote! {
+            #field: crate::core_arch::x86_64::#token::try_new()
+        });
+    }
+
+    quote! {
+        use core::arch::x86_64::*;
+
+        use crate::{seal::Seal, Bytes, Indices, Level, Simd, #simd_from SimdInto};
+
+        #imports
+
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug)]
+        pub struct #level_tok {
+            #( #fields, )*
+        }
+
+        impl #level_tok {
+            /// Create a SIMD token.
+            ///
+            /// # Safety
+            ///
+            /// The required CPU features must be available.
+            #[inline]
+            pub unsafe fn new_unchecked() -> Self {
+                Self {
+                    #( #inits, )*
+                }
+            }
+        }
+
+        impl Seal for #level_tok {}
+
+        #simd_impl
+
+        #ty_impl
+    }
+}
+
+fn mk_simd_impl(level: Level) -> TokenStream {
+    let level_tok = level.token();
+    let target_features = level.target_features();
+    let mut methods = vec![];
+    for vec_ty in SIMD_TYPES {
+        for (method, sig) in ops_for_type(vec_ty, true) {
+            if sig.is_permutation() {
+                methods.push(mk_permutation(level, method, sig, vec_ty));
+                continue;
+            }
+            if let OpSig::Classify = sig {
+                methods.push(generic_classify(method, vec_ty));
+                continue;
+            }
+            if is_generic_cvt(sig, vec_ty) {
+                methods.push(generic_cvt(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.is_f16() && !matches!(sig, OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _)) {
+                methods.push(generic_f16_op(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.scalar == ScalarType::BFloat
+                && !matches!(sig, OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _))
+            {
+                methods.push(generic_bf16_op(method, sig, vec_ty));
+                continue;
+            }
+            if matches!(method, "shl_var" | "shr_var") && !level.has_shift_var(method, vec_ty) {
+                methods.push(generic_shift_var(method, vec_ty));
+                continue;
+            }
+            // The 64 bit vectors live in the low half of a 128 bit register.
+            if vec_ty.n_bits() == 64 && !matches!(sig, OpSig::Combine | OpSig::WidenNarrow(_)) {
+                methods.push(generic_half_op(method, sig, vec_ty));
+                continue;
+            }
+            let is_interleaved = matches!(
+                sig,
+                OpSig::LoadInterleaved(_, _) | OpSig::StoreInterleaved(_, _)
+            );
+            // Narrowing has no half-width equivalent to split into, so it is done
+            // natively from twice the native width.
+            let native_bits = if sig.is_narrowing(vec_ty) {
+                2 * level.max_native_bits()
+            } else {
+                level.max_native_bits()
+            };
+            if vec_ty.n_bits() > native_bits && !is_interleaved {
+                methods.push(generic_op(method, sig, vec_ty));
+                continue;
+            }
+            if vec_ty.n_bits() == 512 && level.max_native_bits() == 512 {
+                methods.push(mk_method_512(level, method, sig, vec_ty));
+            } else {
+                methods.push(mk_method(level, method, sig, vec_ty));
+            }
+        }
+    }
+    // Note: the `vectorize` implementation is pretty boilerplate and should probably
+    // be factored out for DRY.
+    quote! {
+        impl Simd for #level_tok {
+            type f32s = f32x4<Self>;
+            type f64s = f64x2<Self>;
+            type u8s = u8x16<Self>;
+            type i8s = i8x16<Self>;
+            type u16s = u16x8<Self>;
+            type i16s = i16x8<Self>;
+            type u32s = u32x4<Self>;
+            type i32s = i32x4<Self>;
+            type u64s = u64x2<Self>;
+            type i64s = i64x2<Self>;
+            type mask8s = mask8x16<Self>;
+            type mask16s = mask16x8<Self>;
+            type mask32s = mask32x4<Self>;
+            type mask64s = mask64x2<Self>;
+            #[inline(always)]
+            fn level(self) -> Level {
+                Level::#level_tok(self)
+            }
+
+            #[inline]
+            fn vectorize<F: FnOnce() -> R, R>(self, f: F) -> R {
+                #[target_feature(enable = #target_features)]
+                #[inline]
+                // unsafe not needed here with tf11, but can be justified
+                unsafe fn vectorize_x86<F: FnOnce() -> R, R>(f: F) -> R {
+                    f()
+                }
+                unsafe { vectorize_x86(f) }
+            }
+
+            #( #methods )*
+        }
+    }
+}
+
+/// Generate a single method for a 128 or 256 bit type.
+///
+/// The 128 bit paths only use instructions up to SSE4.2.
+fn mk_method(level: Level, method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty_name = vec_ty.rust_name();
+    let ty = vec_ty.rust();
+    let n_bits = vec_ty.n_bits();
+    let method_ident = Ident::new(&format!("{method}_{ty_name}"), Span::call_site());
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    // The suffix of the float intrinsics for this lane width.
+    let float_suffix = if vec_ty.scalar_bits == 64 { "pd" } else { "ps" };
+    match sig {
+        OpSig::Splat => {
+            let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+            let expr = X86.expr(method, vec_ty, &[quote! { val }]);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, val: #scalar) -> #ret_ty {
+                    unsafe {
+                        #expr.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Unary if method == "round" => generic_round(vec_ty),
+        // Only f32 lanes have estimate instructions below 512 bits.
+        OpSig::Unary | OpSig::Refine
+            if matches!(method, "recip_approx" | "rsqrt_approx" | "recip" | "rsqrt")
+                && vec_ty.scalar_bits == 64
+                && n_bits < 512 =>
+        {
+            generic_recip(method, sig, vec_ty)
+        }
+        OpSig::Refine => generic_refine(method, vec_ty),
+        OpSig::BinaryTo(_) | OpSig::Binary
+            if matches!(
+                method,
+                "mul_widen" | "mul_high" | "mul_q15_round_sat" | "dot_i16"
+            ) =>
+        {
+            mk_widening_mul(method, sig, vec_ty)
+        }
+        OpSig::BinaryTo(_) => generic_abs_diff(vec_ty),
+        OpSig::Unary => {
+            let body = match (method, vec_ty.scalar) {
+                ("fract", _) => {
+                    let sub = Ident::new(&format!("sub_{ty_name}"), Span::call_site());
+                    let trunc = Ident::new(&format!("trunc_{ty_name}"), Span::call_site());
+                    return quote! {
+                        #[inline(always)]
+                        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                            self.#sub(a, self.#trunc(a))
+                        }
+                    };
+                }
+                ("abs", ScalarType::Float) => {
+                    let andnot = intrinsic_ident("andnot", float_suffix, n_bits);
+                    let set1 = set1_intrinsic(vec_ty);
+                    quote! { #andnot(#set1(-0.0), a.into()) }
+                }
+                ("neg", ScalarType::Float) => {
+                    let xor = intrinsic_ident("xor", float_suffix, n_bits);
+                    let set1 = set1_intrinsic(vec_ty);
+                    quote! { #xor(a.into(), #set1(-0.0)) }
+                }
+                ("abs", _) if vec_ty.scalar_bits == 64 && !level.has_avx512() => {
+                    // There is no 64 bit abs before AVX-512, so flip the negative lanes.
+                    let sub = intrinsic_ident("sub", "epi64", n_bits);
+                    let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+                    let setzero = intrinsic_ident("setzero", coarse_suffix(vec_ty), n_bits);
+                    let sign = cmpgt_64(vec_ty, quote! { #setzero() }, quote! { a });
+                    quote! {
+                        let a = a.into();
+                        let sign = #sign;
+                        #sub(#xor(a, sign), sign)
+                    }
+                }
+                ("abs", _) => {
+                    let abs = intrinsic_ident("abs", op_suffix(vec_ty, false), n_bits);
+                    quote! { #abs(a.into()) }
+                }
+                ("neg", _) => {
+                    let sub = intrinsic_ident("sub", op_suffix(vec_ty, false), n_bits);
+                    let setzero = intrinsic_ident("setzero", coarse_suffix(vec_ty), n_bits);
+                    quote! { #sub(#setzero(), a.into()) }
+                }
+                ("recip_approx" | "rsqrt_approx", ScalarType::Float) => {
+                    let name = match (method, n_bits) {
+                        ("recip_approx", 512) => "rcp14",
+                        ("recip_approx", _) => "rcp",
+                        (_, 512) => "rsqrt14",
+                        _ => "rsqrt",
+                    };
+                    let estimate = intrinsic_ident(name, float_suffix, n_bits);
+                    quote! { #estimate(a.into()) }
+                }
+                ("ceil" | "round_ties_even" | "trunc", ScalarType::Float) => {
+                    let round = intrinsic_ident("round", float_suffix, n_bits);
+                    let rounding = rounding_mode(method);
+                    quote! { #round::<{ #rounding | _MM_FROUND_NO_EXC }>(a.into()) }
+                }
+                ("not", _) => {
+                    let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+                    let set1 = intrinsic_ident("set1", "epi32", n_bits);
+                    quote! { #xor(a.into(), #set1(-1)) }
+                }
+                _ => X86.expr(method, vec_ty, &[quote! { a.into() }]),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Binary => {
+            let body = match method {
+                "copysign" => {
+                    let set1 = set1_intrinsic(vec_ty);
+                    let or = intrinsic_ident("or", float_suffix, n_bits);
+                    let and = intrinsic_ident("and", float_suffix, n_bits);
+                    let andnot = intrinsic_ident("andnot", float_suffix, n_bits);
+                    quote! {
+                        let mask = #set1(-0.0);
+                        #or(#and(mask, b.into()), #andnot(mask, a.into()))
+                    }
+                }
+                "mul" if vec_ty.scalar_bits == 8 => {
+                    // There is no 8 bit multiply, so multiply the even and odd bytes
+                    // separately as 16 bit lanes and keep the low byte of each product.
+                    let mullo = intrinsic_ident("mullo", "epi16", n_bits);
+                    let srli = intrinsic_ident("srli", "epi16", n_bits);
+                    let slli = intrinsic_ident("slli", "epi16", n_bits);
+                    let and = intrinsic_ident("and", coarse_suffix(vec_ty), n_bits);
+                    let or = intrinsic_ident("or", coarse_suffix(vec_ty), n_bits);
+                    let set1 = intrinsic_ident("set1", "epi16", n_bits);
+                    quote! {
+                        let a = a.into();
+                        let b = b.into();
+                        let even = #mullo(a, b);
+                        let odd = #mullo(#srli::<8>(a), #srli::<8>(b));
+                        #or(#slli::<8>(odd), #and(even, #set1(0xff)))
+                    }
+                }
+                "mul"
+                    if vec_ty.scalar != ScalarType::Float
+                        && vec_ty.scalar_bits == 64
+                        && !level.has_avx512() =>
+                {
+                    // There is no 64 bit multiply before AVX-512, so build the product
+                    // from 32 bit multiplies. The cross terms only affect the high half.
+                    let mul = intrinsic_ident("mul", "epu32", n_bits);
+                    let add = intrinsic_ident("add", "epi64", n_bits);
+                    let srli = intrinsic_ident("srli", "epi64", n_bits);
+                    let slli = intrinsic_ident("slli", "epi64", n_bits);
+                    quote! {
+                        let a = a.into();
+                        let b = b.into();
+                        let low = #mul(a, b);
+                        let cross = #add(#mul(#srli::<32>(a), b), #mul(a, #srli::<32>(b)));
+                        #add(low, #slli::<32>(cross))
+                    }
+                }
+                "min" | "max"
+                    if vec_ty.scalar != ScalarType::Float
+                        && vec_ty.scalar_bits == 64
+                        && !level.has_avx512() =>
+                {
+                    // There is no 64 bit min or max before AVX-512, so select on a comparison.
+                    let blendv = intrinsic_ident("blendv", "epi8", n_bits);
+                    let gt = cmpgt_64(vec_ty, quote! { a }, quote! { b });
+                    let (x, y) = if method == "min" {
+                        (quote! { a }, quote! { b })
+                    } else {
+                        (quote! { b }, quote! { a })
+                    };
+                    quote! {
+                        let a = a.into();
+                        let b = b.into();
+                        #blendv(#x, #y, #gt)
+                    }
+                }
+                "max_precise" | "min_precise" => {
+                    // `max` and `min` return `b` when either lane is NaN, so take `a`
+                    // where `b` is NaN to return the number, like IEEE `maxNum`.
+                    let op = intrinsic_ident(&method[..3], float_suffix, n_bits);
+                    let pick_a = match n_bits {
+                        128 => {
+                            let cmpunord = intrinsic_ident("cmpunord", float_suffix, n_bits);
+                            let blendv = intrinsic_ident("blendv", float_suffix, n_bits);
+                            quote! { #blendv(#op(a, b), a, #cmpunord(b, b)) }
+                        }
+                        256 => {
+                            let cmp = intrinsic_ident("cmp", float_suffix, n_bits);
+                            let blendv = intrinsic_ident("blendv", float_suffix, n_bits);
+                            quote! { #blendv(#op(a, b), a, #cmp::<_CMP_UNORD_Q>(b, b)) }
+                        }
+                        _ => {
+                            let cmp =
+                                intrinsic_ident("cmp", &format!("{float_suffix}_mask"), n_bits);
+                            let mask_mov = intrinsic_ident("mask_mov", float_suffix, n_bits);
+                            quote! { #mask_mov(#op(a, b), #cmp::<_CMP_UNORD_Q>(b, b), a) }
+                        }
+                    };
+                    quote! {
+                        let a = a.into();
+                        let b = b.into();
+                        #pick_a
+                    }
+                }
+                "shl_var" | "shr_var" => {
+                    let name = match method {
+                        "shl_var" => "sllv",
+                        _ if vec_ty.scalar == ScalarType::Int => "srav",
+                        _ => "srlv",
+                    };
+                    let intrinsic = intrinsic_ident(name, op_suffix(vec_ty, false), n_bits);
+                    quote! { #intrinsic(a.into(), b.into()) }
+                }
+                _ => X86.expr(method, vec_ty, &[quote! { a.into() }, quote! { b.into() }]),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Ternary if method == "mul_add_fast" => generic_mul_add_fast(vec_ty),
+        OpSig::Ternary if !level.has_fma() && matches!(method, "nmadd" | "nmsub") => {
+            generic_negated_madd(method, vec_ty)
+        }
+        OpSig::Ternary if !level.has_fma() && method == "mul_add_fused" => {
+            generic_mul_add_fused(vec_ty)
+        }
+        OpSig::Ternary => {
+            if !level.has_fma() {
+                // Without FMA, this is computed with a separate multiply and add.
+                let combine_name = match method {
+                    "madd" => "add",
+                    "msub" => "sub",
+                    _ => unimplemented!("missing {method}"),
+                };
+                let combine = intrinsic_ident(combine_name, float_suffix, n_bits);
+                let mul = intrinsic_ident("mul", float_suffix, n_bits);
+                return quote! {
+                    #[inline(always)]
+                    fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                        unsafe {
+                            #combine(a.into(), #mul(b.into(), c.into())).simd_into(self)
+                        }
+                    }
+                };
+            }
+            // The intrinsics compute `±(a * b) ± c`.
+            let (name, args) = match method {
+                "madd" => ("fmadd", quote! { b.into(), c.into(), a.into() }),
+                "msub" => ("fnmadd", quote! { b.into(), c.into(), a.into() }),
+                "nmadd" => ("fnmsub", quote! { b.into(), c.into(), a.into() }),
+                "nmsub" => ("fmsub", quote! { b.into(), c.into(), a.into() }),
+                "mul_add_fused" => ("fmadd", quote! { a.into(), b.into(), c.into() }),
+                _ => unimplemented!("missing {method}"),
+            };
+            let intrinsic = intrinsic_ident(name, float_suffix, n_bits);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #intrinsic(#args).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Reduce => {
+            if n_bits > 128 {
+                return generic_op(method, sig, vec_ty);
+            }
+            if matches!(method, "reduce_and" | "reduce_or") {
+                return generic_reduce_bits(method, vec_ty);
+            }
+            let lane_op = &method["reduce_".len()..];
+            // The min and max ignore NaN lanes, like `min_precise` and `max_precise`.
+            let precise = Ident::new(
+                &format!("{lane_op}_precise_{}", vec_ty.rust_name()),
+                Span::call_site(),
+            );
+            let body = match (vec_ty.scalar, vec_ty.scalar_bits) {
+                (ScalarType::Float, 32) if lane_op != "add" => quote! {
+                    let a: __m128 = a.into();
+                    let pairs: __m128 = self.#precise(a.simd_into(self), _mm_movehdup_ps(a).simd_into(self)).into();
+                    let total: __m128 = self
+                        .#precise(pairs.simd_into(self), _mm_movehl_ps(pairs, pairs).simd_into(self))
+                        .into();
+                    _mm_cvtss_f32(total)
+                },
+                (ScalarType::Float, 64) if lane_op != "add" => quote! {
+                    let a: __m128d = a.into();
+                    let total: __m128d = self.#precise(a.simd_into(self), _mm_unpackhi_pd(a, a).simd_into(self)).into();
+                    _mm_cvtsd_f64(total)
+                },
+                (ScalarType::Float, 32) => {
+                    // Combine neighbouring lanes, then the two pairs.
+                    let op = intrinsic_ident(lane_op, "ps", n_bits);
+                    let op_ss = intrinsic_ident(lane_op, "ss", n_bits);
+                    quote! {
+                        let a = a.into();
+                        let pairs = #op(a, _mm_movehdup_ps(a));
+                        _mm_cvtss_f32(#op_ss(pairs, _mm_movehl_ps(pairs, pairs)))
+                    }
+                }
+                (ScalarType::Float, _) => {
+                    let op_sd = intrinsic_ident(lane_op, "sd", n_bits);
+                    quote! {
+                        let a = a.into();
+                        _mm_cvtsd_f64(#op_sd(a, _mm_unpackhi_pd(a, a)))
+                    }
+                }
+                (_, 64) => return scalar_reduce(method, vec_ty),
+                _ => {
+                    // Fold the upper half of the vector onto the lower half until
+                    // a single lane is left.
+                    let scalar = vec_ty.scalar.rust(vec_ty.scalar_bits);
+                    let mut folds = vec![];
+                    let mut bytes = 8;
+                    while bytes >= vec_ty.scalar_bits / 8 {
+                        let shift = Literal::usize_unsuffixed(bytes);
+                        let shifted = quote! { _mm_srli_si128::<#shift>(a) };
+                        folds.push(X86.expr(lane_op, vec_ty, &[quote! { a }, shifted]));
+                        bytes /= 2;
+                    }
+                    quote! {
+                        let a = a.into();
+                        #( let a = #folds; )*
+                        _mm_cvtsi128_si32(a) as #scalar
+                    }
+                }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body
+                    }
+                }
+            }
+        }
+        OpSig::MaskQuery(_) => mk_mask_query(method, vec_ty),
+        // Permutations are handled before the per-op dispatch.
+        OpSig::Shuffle(_)
+        | OpSig::Reverse
+        | OpSig::Rotate(_)
+        | OpSig::Broadcast
+        | OpSig::Unzip(_)
+        | OpSig::Classify => {
+            unreachable!()
+        }
+        // `pshufb` only looks within 128 bit blocks.
+        OpSig::Lookup(_) if n_bits > 128 => generic_op(method, sig, vec_ty),
+        OpSig::Lookup(16) => {
+            // `pshufb` zeroes the bytes whose index has the high bit set. The
+            // saturating add sets it for all indices past 15, and keeps the low
+            // 4 bits of the others.
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        _mm_shuffle_epi8(a.into(), _mm_adds_epu8(b.into(), _mm_set1_epi8(0x70)))
+                            .simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Lookup(_) => generic_lookup_tables(method, sig, vec_ty),
+        OpSig::FromBitmask => {
+            let (weights, body) = mk_from_bitmask(vec_ty);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, bits: u64) -> #ret_ty {
+                    unsafe {
+                        let weights = #weights;
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Shift => {
+            let body = mk_shift(level, method, vec_ty);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, shift: u32) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Compare => {
+            let body = mk_compare(level, method, vec_ty);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Select => {
+            let mask_ty = vec_ty.mask_ty().rust();
+            let body = if vec_ty.scalar == ScalarType::Float {
+                let blendv = intrinsic_ident("blendv", float_suffix, n_bits);
+                let cast = cast_to_float(vec_ty);
+                quote! { #blendv(c.into(), b.into(), #cast(a.into())) }
+            } else {
+                let blendv = intrinsic_ident("blendv", "epi8", n_bits);
+                quote! { #blendv(c.into(), b.into(), a.into()) }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #mask_ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Combine => {
+            if n_bits * 2 > level.max_native_bits() {
+                return generic_combine(vec_ty);
+            }
+            let body = match (n_bits, vec_ty.scalar) {
+                (64, ScalarType::Float) => quote! { _mm_movelh_ps(a.into(), b.into()) },
+                (64, _) => quote! { _mm_unpacklo_epi64(a.into(), b.into()) },
+                (128, ScalarType::Float) if vec_ty.scalar_bits == 64 => {
+                    quote! { _mm256_setr_m128d(a.into(), b.into()) }
+                }
+                (128, ScalarType::Float) => quote! { _mm256_setr_m128(a.into(), b.into()) },
+                (128, _) => quote! { _mm256_setr_m128i(a.into(), b.into()) },
+                (_, ScalarType::Float) if vec_ty.scalar_bits == 64 => quote! {
+                    _mm512_insertf64x4::<1>(_mm512_castpd256_pd512(a.into()), b.into())
+                },
+                (_, ScalarType::Float) => quote! {
+                    _mm512_insertf32x8::<1>(_mm512_castps256_ps512(a.into()), b.into())
+                },
+                _ => quote! {
+                    _mm512_inserti64x4::<1>(_mm512_castsi256_si512(a.into()), b.into())
+                },
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Split if n_bits == 128 => {
+            // The low half is already in place, and the high half is moved down.
+            let high = if vec_ty.scalar == ScalarType::Float {
+                quote! { _mm_movehl_ps(a, a) }
+            } else {
+                quote! { _mm_unpackhi_epi64(a, a) }
+            };
+            let arch = X86.arch_ty(vec_ty);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        let a: #arch = a.into();
+                        (a.simd_into(self), #high.simd_into(self))
+                    }
+                }
+            }
+        }
+        OpSig::Split => {
+            if n_bits != 256 {
+                return generic_split(vec_ty);
+            }
+            let (low, high) = if vec_ty.scalar == ScalarType::Float {
+                let cast = format_ident!("_mm256_cast{float_suffix}256_{float_suffix}128");
+                let extract = format_ident!("_mm256_extractf128_{float_suffix}");
+                (quote! { #cast }, quote! { #extract::<1> })
+            } else {
+                (
+                    quote! { _mm256_castsi256_si128 },
+                    quote! { _mm256_extracti128_si256::<1> },
+                )
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        let a = a.into();
+                        (#low(a).simd_into(self), #high(a).simd_into(self))
+                    }
+                }
+            }
+        }
+        OpSig::Zip(zip_low) => {
+            let suffix = op_suffix(vec_ty, false);
+            let body = if n_bits == 128 {
+                let unpack = if zip_low { "unpacklo" } else { "unpackhi" };
+                let unpack = intrinsic_ident(unpack, suffix, n_bits);
+                quote! { #unpack(a.into(), b.into()) }
+            } else {
+                // The 256 bit unpacks work within 128 bit lanes, so the halves
+                // need to be stitched back together.
+                let unpacklo = intrinsic_ident("unpacklo", suffix, n_bits);
+                let unpackhi = intrinsic_ident("unpackhi", suffix, n_bits);
+                let permute = if vec_ty.scalar == ScalarType::Float {
+                    let permute = format_ident!("_mm256_permute2f128_{float_suffix}");
+                    quote! { #permute }
+                } else {
+                    quote! { _mm256_permute2x128_si256 }
+                };
+                let imm = if zip_low { 0x20 } else { 0x31 };
+                let imm = Literal::i32_unsuffixed(imm);
+                quote! {
+                    let lo = #unpacklo(a.into(), b.into());
+                    let hi = #unpackhi(a.into(), b.into());
+                    #permute::<#imm>(lo, hi)
+                }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 64) if vec_ty.scalar_bits == 32 => {
+            let body = match (n_bits, level.max_native_bits()) {
+                // The result would be wider than the native registers.
+                (256, 256) => return generic_op(method, sig, vec_ty),
+                (128, 128) => quote! {
+                    let a = a.into();
+                    let low = _mm_cvtps_pd(a);
+                    let high = _mm_cvtps_pd(_mm_movehl_ps(a, a));
+                    self.combine_f64x2(low.simd_into(self), high.simd_into(self))
+                },
+                (128, _) => quote! { _mm256_cvtps_pd(a.into()).simd_into(self) },
+                _ => quote! { _mm512_cvtps_pd(a.into()).simd_into(self) },
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 32) if vec_ty.scalar_bits == 64 => {
+            let body = match (n_bits, level.max_native_bits()) {
+                (128, _) => quote! { _mm_cvtpd_ps(a.into()) },
+                (256, 128) => quote! {
+                    let (low, high) = self.split_f64x4(a);
+                    _mm_movelh_ps(_mm_cvtpd_ps(low.into()), _mm_cvtpd_ps(high.into()))
+                },
+                (256, _) => quote! { _mm256_cvtpd_ps(a.into()) },
+                // The result only fills half of a native register.
+                (_, 256) => return generic_op(method, sig, vec_ty),
+                _ => quote! { _mm512_cvtpd_ps(a.into()) },
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 32) if vec_ty.scalar == ScalarType::BFloat => {
+            // The bf16 bits become the high half of the f32 bits.
+            let body = match (n_bits, level.max_native_bits()) {
+                (128, 128) => quote! {
+                    let a = a.into();
+                    let zero = _mm_setzero_si128();
+                    let low = _mm_castsi128_ps(_mm_unpacklo_epi16(zero, a));
+                    let high = _mm_castsi128_ps(_mm_unpackhi_epi16(zero, a));
+                    self.combine_f32x4(low.simd_into(self), high.simd_into(self))
+                },
+                (128, _) => quote! {
+                    _mm256_castsi256_ps(_mm256_slli_epi32::<16>(_mm256_cvtepu16_epi32(a.into())))
+                        .simd_into(self)
+                },
+                (256, 512) => quote! {
+                    _mm512_castsi512_ps(_mm512_slli_epi32::<16>(_mm512_cvtepu16_epi32(a.into())))
+                        .simd_into(self)
+                },
+                _ => return generic_op(method, sig, vec_ty),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::BFloat, 16) => {
+            let body = match (n_bits, level.max_native_bits()) {
+                (256, 128) => {
+                    let low = bf16_round(quote! { low }, 128);
+                    let high = bf16_round(quote! { high }, 128);
+                    quote! {
+                        let (low, high) = self.split_f32x8(a);
+                        let (low, high) = (low.into(), high.into());
+                        #low
+                        #high
+                        _mm_packus_epi32(low, high)
+                    }
+                }
+                (256, _) => {
+                    let round = bf16_round(quote! { a }, 256);
+                    quote! {
+                        let a = a.into();
+                        #round
+                        _mm_packus_epi32(_mm256_castsi256_si128(a), _mm256_extracti128_si256::<1>(a))
+                    }
+                }
+                _ => return generic_op(method, sig, vec_ty),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::DotBf16 if level.optional_core_arch_tokens().is_empty() => {
+            generic_bf16_op(method, sig, vec_ty)
+        }
+        OpSig::DotBf16 => {
+            let pairs_ty = bf16_pairs_ty(vec_ty).rust();
+            let fallback = bf16_dot_expr(method, vec_ty);
+            let native = match (method, n_bits) {
+                ("dot_bf16", 128) => quote! { _mm_dpbf16_ps },
+                ("dot_bf16", 256) => quote! { _mm256_dpbf16_ps },
+                // There is no matrix multiply in AVX-512 BF16.
+                _ => return generic_bf16_op(method, sig, vec_ty),
+            };
+            let bh = format_ident!("__m{n_bits}bh");
+            let int = format_ident!("__m{n_bits}i");
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #pairs_ty<Self>, c: #pairs_ty<Self>) -> #ret_ty {
+                    if let Some(avx512bf16) = self.avx512bf16 {
+                        unsafe {
+                            let b = core::mem::transmute::<#int, #bh>(b.into());
+                            let c = core::mem::transmute::<#int, #bh>(c.into());
+                            avx512bf16.#native(a.into(), b, c).simd_into(self)
+                        }
+                    } else {
+                        #fallback
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(_, _) if method.ends_with("_round") => generic_cvt_round(method, sig, vec_ty),
+        // Only AVX-512 converts between f64 and 64 bit integers.
+        OpSig::Cvt(_, 64) if vec_ty.scalar_bits == 64 && !level.has_avx512() => {
+            generic_cvt_lanes(method, sig, vec_ty)
+        }
+        OpSig::Cvt(scalar, 64) if vec_ty.scalar_bits == 64 => {
+            let body = match (vec_ty.scalar, scalar) {
+                // `cvttpd` produces `i64::MIN` for NaN and out of range values, so
+                // fix those up like the 32 bit conversion.
+                (ScalarType::Float, ScalarType::Int) => {
+                    let cmp = intrinsic_ident("cmp", "pd_mask", n_bits);
+                    let set1_pd = intrinsic_ident("set1", "pd", n_bits);
+                    let set1 = set1_intrinsic(&VecType::new(ScalarType::Int, 64, vec_ty.len));
+                    let cvtt = intrinsic_ident("cvttpd", "epi64", n_bits);
+                    let mask_mov = intrinsic_ident("mask_mov", "epi64", n_bits);
+                    let maskz_mov = intrinsic_ident("maskz_mov", "epi64", n_bits);
+                    quote! {
+                        let a = a.into();
+                        let converted = #cvtt(a);
+                        let overflow = #cmp::<_CMP_GE_OQ>(a, #set1_pd(9223372036854775808.0));
+                        let nan = #cmp::<_CMP_UNORD_Q>(a, a);
+                        let saturated = #mask_mov(converted, overflow, #set1(i64::MAX));
+                        #maskz_mov(!nan, saturated)
+                    }
+                }
+                // The unsigned conversion saturates values that are too large.
+                (ScalarType::Float, _) => {
+                    let cvtt = intrinsic_ident("cvttpd", "epu64", n_bits);
+                    let max = intrinsic_ident("max", "pd", n_bits);
+                    let setzero = intrinsic_ident("setzero", "pd", n_bits);
+                    quote! { #cvtt(#max(a.into(), #setzero())) }
+                }
+                _ => {
+                    let cvt =
+                        intrinsic_ident(&format!("cvt{}", op_suffix(vec_ty, true)), "pd", n_bits);
+                    quote! { #cvt(a.into()) }
+                }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 32) if vec_ty.is_f16() => {
+            let body = match n_bits {
+                _ if !level.has_f16c() => return generic_f16_cvt(method, sig, vec_ty),
+                128 => quote! { _mm256_cvtph_ps(a.into()) },
+                256 if level.has_avx512() => quote! { _mm512_cvtph_ps(a.into()) },
+                _ => return generic_op(method, sig, vec_ty),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 16) => {
+            let body = match n_bits {
+                _ if !level.has_f16c() => return generic_f16_cvt(method, sig, vec_ty),
+                256 => quote! { _mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(a.into()) },
+                _ => return generic_op(method, sig, vec_ty),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(scalar, scalar_bits) => {
+            let body = mk_cvt(vec_ty, scalar, scalar_bits);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Reinterpret(scalar, scalar_bits) => {
+            assert!(valid_reinterpret(vec_ty, scalar, scalar_bits));
+            let to_ty = reinterpret_ty(vec_ty, scalar, scalar_bits);
+            let arch = X86.arch_ty(vec_ty);
+            // Only the f32 and f64 lanes live in float registers, so everything else is
+            // already the right type.
+            let is_float = |ty: &VecType| ty.scalar == ScalarType::Float && ty.scalar_bits >= 32;
+            let cast = match (is_float(vec_ty), is_float(&to_ty)) {
+                (false, false) => None,
+                (true, false) => Some(cast_from_float(vec_ty)),
+                (false, true) => Some(cast_to_float(&to_ty)),
+                (true, true) => Some(intrinsic_ident(
+                    &format!("cast{}", op_suffix(vec_ty, false)),
+                    op_suffix(&to_ty, false),
+                    vec_ty.n_bits(),
+                )),
+            };
+            let body = match cast {
+                Some(cast) => quote! { unsafe { #cast(a.into()).simd_into(self) } },
+                None => quote! { <#arch>::from(a).simd_into(self) },
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    #body
+                }
+            }
+        }
+        OpSig::WidenNarrow(_) | OpSig::WidenHalf(_) => mk_widen_narrow(level, method, sig, vec_ty),
+        OpSig::LoadInterleaved(block_size, count) => {
+            assert_eq!(count, 4, "only count of 4 is currently supported");
+            let arg = load_interleaved_arg_ty(block_size, count, vec_ty);
+            let elems_per_vec = block_size as usize / vec_ty.scalar_bits;
+            let [off0, off1, off2, off3] = [0, 1, 2, 3].map(|i| i * elems_per_vec);
+            let (unpacklo, unpackhi) = interleave_intrinsics(vec_ty);
+            let block = VecType::new(vec_ty.scalar, vec_ty.scalar_bits, elems_per_vec);
+            let [out0, out1, out2, out3] = ["out0", "out1", "out2", "out3"]
+                .map(|out| block_from_si128(&block, format_ident!("{out}").into_token_stream()));
+            let combine_block = format_ident!("combine_{}", block.rust_name());
+            let combine_2x = format_ident!(
+                "combine_{}",
+                VecType::new(vec_ty.scalar, vec_ty.scalar_bits, elems_per_vec * 2).rust_name()
+            );
+
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, #arg) -> #ret_ty {
+                    unsafe {
+                        let v0 = _mm_loadu_si128(src[#off0..].as_ptr() as *const __m128i);
+                        let v1 = _mm_loadu_si128(src[#off1..].as_ptr() as *const __m128i);
+                        let v2 = _mm_loadu_si128(src[#off2..].as_ptr() as *const __m128i);
+                        let v3 = _mm_loadu_si128(src[#off3..].as_ptr() as *const __m128i);
+
+                        let v02_lower = #unpacklo(v0, v2);
+                        let v13_lower = #unpacklo(v1, v3);
+                        let v02_upper = #unpackhi(v0, v2);
+                        let v13_upper = #unpackhi(v1, v3);
+
+                        let out0 = #unpacklo(v02_lower, v13_lower);
+                        let out1 = #unpackhi(v02_lower, v13_lower);
+                        let out2 = #unpacklo(v02_upper, v13_upper);
+                        let out3 = #unpackhi(v02_upper, v13_upper);
+
+                        let combined_lower = self.#combine_block(
+                            #out0.simd_into(self),
+                            #out1.simd_into(self),
+                        );
+                        let combined_upper = self.#combine_block(
+                            #out2.simd_into(self),
+                            #out3.simd_into(self),
+                        );
+                        self.#combine_2x(combined_lower, combined_upper)
+                    }
+                }
+            }
+        }
+        OpSig::StoreInterleaved(block_size, count) => {
+            assert_eq!(count, 4, "only count of 4 is currently supported");
+            let arg = store_interleaved_arg_ty(block_size, count, vec_ty);
+            let elems_per_vec = block_size as usize / vec_ty.scalar_bits;
+            let [off0, off1, off2, off3] = [0, 1, 2, 3].map(|i| i * elems_per_vec);
+            let (unpacklo, unpackhi) = interleave_intrinsics(vec_ty);
+            let block = VecType::new(vec_ty.scalar, vec_ty.scalar_bits, elems_per_vec);
+            let [v0, v1, v2, v3] = ["v0", "v1", "v2", "v3"].map(|v| {
+                let v = format_ident!("{v}");
+                block_to_si128(&block, quote! { #v.into() })
+            });
+            let split_2x = format_ident!("split_{}", vec_ty.rust_name());
+            let split = format_ident!(
+                "split_{}",
+                VecType::new(vec_ty.scalar, vec_ty.scalar_bits, elems_per_vec * 2).rust_name()
+            );
+
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, #arg) -> #ret_ty {
+                    let (lower, upper) = self.#split_2x(a);
+                    let (v0, v1) = self.#split(lower);
+                    let (v2, v3) = self.#split(upper);
+                    unsafe {
+                        let v0 = #v0;
+                        let v1 = #v1;
+                        let v2 = #v2;
+                        let v3 = #v3;
+
+                        let v02_lower = #unpacklo(v0, v2);
+                        let v13_lower = #unpacklo(v1, v3);
+                        let v02_upper = #unpackhi(v0, v2);
+                        let v13_upper = #unpackhi(v1, v3);
+
+                        let out0 = #unpacklo(v02_lower, v13_lower);
+                        let out1 = #unpackhi(v02_lower, v13_lower);
+                        let out2 = #unpacklo(v02_upper, v13_upper);
+                        let out3 = #unpackhi(v02_upper, v13_upper);
+
+                        _mm_storeu_si128(dest[#off0..].as_mut_ptr() as *mut __m128i, out0);
+                        _mm_storeu_si128(dest[#off1..].as_mut_ptr() as *mut __m128i, out1);
+                        _mm_storeu_si128(dest[#off2..].as_mut_ptr() as *mut __m128i, out2);
+                        _mm_storeu_si128(dest[#off3..].as_mut_ptr() as *mut __m128i, out3);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate a single method for a 512 bit type, at a level where those are native.
+///
+/// Comparisons produce a mask in a k-register, which is expanded to a vector at
+/// the boundary. Likewise, mask vectors are moved into a k-register for selects.
+/// The remaining ops are shared with the narrower types.
+fn mk_method_512(level: Level, method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty_name = vec_ty.rust_name();
+    let ty = vec_ty.rust();
+    let method_ident = Ident::new(&format!("{method}_{ty_name}"), Span::call_site());
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    let is_float = vec_ty.scalar == ScalarType::Float;
+    let bits = vec_ty.scalar_bits;
+    let movm = format_ident!("_mm512_movm_epi{bits}");
+    let movepi_mask = format_ident!("_mm512_movepi{bits}_mask");
+    let float_suffix = if bits == 64 { "pd" } else { "ps" };
+    match sig {
+        OpSig::Unary if matches!(method, "floor" | "ceil" | "round_ties_even" | "trunc") => {
+            let rounding = rounding_mode(method);
+            let roundscale = format_ident!("_mm512_roundscale_{float_suffix}");
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #roundscale::<{ #rounding | _MM_FROUND_NO_EXC }>(a.into())
+                            .simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Compare => {
+            let k = if is_float {
+                let predicate = match method {
+                    "simd_eq" => quote! { _CMP_EQ_OQ },
+                    "simd_ne" => quote! { _CMP_NEQ_UQ },
+                    "simd_lt" => quote! { _CMP_LT_OQ },
+                    "simd_le" => quote! { _CMP_LE_OQ },
+                    "simd_ge" => quote! { _CMP_GE_OQ },
+                    "simd_gt" => quote! { _CMP_GT_OQ },
+                    _ => unimplemented!("missing {method}"),
+                };
+                let cmp = format_ident!("_mm512_cmp_{float_suffix}_mask");
+                quote! { #cmp::<#predicate>(a.into(), b.into()) }
+            } else {
+                let name = match method {
+                    "simd_eq" => "cmpeq",
+                    "simd_lt" => "cmplt",
+                    "simd_le" => "cmple",
+                    "simd_ge" => "cmpge",
+                    "simd_gt" => "cmpgt",
+                    _ => unimplemented!("missing {method}"),
+                };
+                let suffix = op_suffix(vec_ty, true);
+                let cmp = format_ident!("_mm512_{name}_{suffix}_mask");
+                quote! { #cmp(a.into(), b.into()) }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #movm(#k).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Select => {
+            let mask_ty = vec_ty.mask_ty().rust();
+            let blend = if is_float {
+                format_ident!("_mm512_mask_blend_{float_suffix}")
+            } else {
+                format_ident!("_mm512_mask_blend_epi{bits}")
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #mask_ty<Self>, b: #ty<Self>, c: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        let k = #movepi_mask(a.into());
+                        #blend(k, c.into(), b.into()).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Split => {
+            let (low, high) = if is_float && bits == 64 {
+                (
+                    quote! { _mm512_castpd512_pd256 },
+                    quote! { _mm512_extractf64x4_pd::<1> },
+                )
+            } else if is_float {
+                (
+                    quote! { _mm512_castps512_ps256 },
+                    quote! { _mm512_extractf32x8_ps::<1> },
+                )
+            } else {
+                (
+                    quote! { _mm512_castsi512_si256 },
+                    quote! { _mm512_extracti64x4_epi64::<1> },
+                )
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        let a = a.into();
+                        (#low(a).simd_into(self), #high(a).simd_into(self))
+                    }
+                }
+            }
+        }
+        OpSig::Zip(zip_low) => {
+            // The unpacks work within 128 bit lanes, so gather the 128 bit
+            // blocks from the low and high unpacks in order.
+            let suffix = op_suffix(vec_ty, false);
+            let unpacklo = intrinsic_ident("unpacklo", suffix, 512);
+            let unpackhi = intrinsic_ident("unpackhi", suffix, 512);
+            let idx = if zip_low {
+                quote! { _mm512_setr_epi64(0, 1, 8, 9, 2, 3, 10, 11) }
+            } else {
+                quote! { _mm512_setr_epi64(4, 5, 12, 13, 6, 7, 14, 15) }
+            };
+            let body = if is_float {
+                let cast_from = cast_from_float(vec_ty);
+                let cast_to = cast_to_float(vec_ty);
+                quote! {
+                    let lo = #cast_from(#unpacklo(a.into(), b.into()));
+                    let hi = #cast_from(#unpackhi(a.into(), b.into()));
+                    #cast_to(_mm512_permutex2var_epi64(lo, #idx, hi))
+                }
+            } else {
+                quote! {
+                    let lo = #unpacklo(a.into(), b.into());
+                    let hi = #unpackhi(a.into(), b.into());
+                    _mm512_permutex2var_epi64(lo, #idx, hi)
+                }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::BFloat, 16) => {
+            let round = bf16_round(quote! { a }, 512);
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        let a = a.into();
+                        #round
+                        _mm512_cvtepi32_epi16(a).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(ScalarType::Float, 16) => {
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        _mm512_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(a.into()).simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::Cvt(_, _) if method.ends_with("_round") => generic_cvt_round(method, sig, vec_ty),
+        OpSig::Cvt(scalar, scalar_bits) if bits == 32 => {
+            let body = match (vec_ty.scalar, scalar, scalar_bits) {
+                // `cvttps` produces `i32::MIN` for NaN and out of range values. Fix
+                // those up to get the same saturating semantics as `as`.
+                (ScalarType::Float, ScalarType::Int, 32) => quote! {
+                    let a = a.into();
+                    let converted = _mm512_cvttps_epi32(a);
+                    let overflow = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(a, _mm512_set1_ps(2147483648.0));
+                    let nan = _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(a, a);
+                    let saturated = _mm512_mask_mov_epi32(converted, overflow, _mm512_set1_epi32(i32::MAX));
+                    _mm512_maskz_mov_epi32(!nan, saturated)
+                },
+                // Clamping to zero also maps NaN to zero, and the unsigned conversion
+                // already saturates values that are too large.
+                (ScalarType::Float, ScalarType::Unsigned, 32) => quote! {
+                    _mm512_cvttps_epu32(_mm512_max_ps(a.into(), _mm512_setzero_ps()))
+                },
+                (ScalarType::Int, ScalarType::Float, 32) => quote! { _mm512_cvtepi32_ps(a.into()) },
+                (ScalarType::Unsigned, ScalarType::Float, 32) => {
+                    quote! { _mm512_cvtepu32_ps(a.into()) }
+                }
+                _ => unimplemented!(),
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body.simd_into(self)
+                    }
+                }
+            }
+        }
+        OpSig::MaskQuery(_) if matches!(method, "any" | "all" | "to_bitmask") => {
+            // The mask register holds the bitmask.
+            let k = quote! { #movepi_mask(a.into()) };
+            let body = match (method, vec_ty.len) {
+                ("any", _) => quote! { #k != 0 },
+                ("all", _) => quote! { #k == !0 },
+                (_, 64) => k,
+                _ => quote! { #k.into() },
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+                    unsafe {
+                        #body
+                    }
+                }
+            }
+        }
+        OpSig::FromBitmask => {
+            let k = match vec_ty.len {
+                64 => quote! { bits },
+                len => {
+                    let mmask = format_ident!("__mmask{len}");
+                    quote! { bits as #mmask }
+                }
+            };
+            quote! {
+                #[inline(always)]
+                fn #method_ident(self, bits: u64) -> #ret_ty {
+                    unsafe {
+                        #movm(#k).simd_into(self)
+                    }
+                }
+            }
+        }
+        _ => mk_method(level, method, sig, vec_ty),
+    }
+}
+
+/// Mask queries on a 128 or 256 bit mask, based on `movemask`.
+fn mk_mask_query(method: &str, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let n_bits = vec_ty.n_bits();
+    let method_ident = format_ident!("{method}_{}", vec_ty.rust_name());
+    let movemask_epi8 = intrinsic_ident("movemask", "epi8", n_bits);
+    let (ret_ty, body) = match method {
+        "any" => (quote! { bool }, quote! { #movemask_epi8(a.into()) != 0 }),
+        "all" => {
+            let all = if n_bits == 256 {
+                quote! { -1 }
+            } else {
+                quote! { 0xffff }
+            };
+            (quote! { bool }, quote! { #movemask_epi8(a.into()) == #all })
+        }
+        "to_bitmask" => {
+            let body = match (vec_ty.scalar_bits, n_bits) {
+                (8, _) => quote! { #movemask_epi8(a.into()) as u32 as u64 },
+                (16, 128) => quote! {
+                    _mm_movemask_epi8(_mm_packs_epi16(a.into(), _mm_setzero_si128())) as u64
+                },
+                // Packing works within 128 bit lanes, which leaves the halves of
+                // the bitmask 16 bits apart.
+                (16, _) => quote! {
+                    let bits = _mm256_movemask_epi8(_mm256_packs_epi16(a.into(), _mm256_setzero_si256())) as u32;
+                    (bits & 0xff | (bits >> 8) & 0xff00) as u64
+                },
+                _ => {
+                    let float_ty = VecType::new(ScalarType::Float, vec_ty.scalar_bits, vec_ty.len);
+                    let movemask = intrinsic_ident("movemask", op_suffix(&float_ty, false), n_bits);
+                    let cast = cast_to_float(&float_ty);
+                    quote! { #movemask(#cast(a.into())) as u32 as u64 }
+                }
+            };
+            (quote! { u64 }, body)
+        }
+        _ => return generic_mask_query(method, vec_ty),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+            unsafe {
+                #body
+            }
+        }
+    }
+}
+
+/// The rounding mode constant for a rounding op.
+fn rounding_mode(method: &str) -> TokenStream {
+    match method {
+        "floor" => quote! { _MM_FROUND_TO_NEG_INF },
+        "ceil" => quote! { _MM_FROUND_TO_POS_INF },
+        "round_ties_even" => quote! { _MM_FROUND_TO_NEAREST_INT },
+        "trunc" => quote! { _MM_FROUND_TO_ZERO },
+        _ => unimplemented!("{method} is not a rounding op"),
+    }
+}
+
+/// Lane permutations of 128 bit vectors work on the bytes, so they don't
+/// depend on the lane type.
+fn mk_permutation(level: Level, method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    match (sig, vec_ty.n_bits()) {
+        (_, 128) => {}
+        (OpSig::Rotate(_) | OpSig::Broadcast, 64) => return generic_half_op(method, sig, vec_ty),
+        (OpSig::Rotate(_) | OpSig::Broadcast, n_bits) if n_bits <= level.max_native_bits() => {}
+        (OpSig::Shuffle(_), _) | (_, 64) => return generic_shuffle(method, sig, vec_ty),
+        _ => return generic_op(method, sig, vec_ty),
+    }
+    let ty = vec_ty.rust();
+    let method_ident = format_ident!("{method}_{}", vec_ty.rust_name());
+    let generics = sig.generics(vec_ty);
+    let args = sig.simd_trait_args(vec_ty);
+    let load = |table| quote! { _mm_loadu_si128(#table.as_ptr() as *const __m128i) };
+    let bytes = match (sig, vec_ty.scalar_bits) {
+        (OpSig::Shuffle(1), _) => {
+            let table = load(quote! { table });
+            quote! {
+                let table = const { crate::shuffle::shuffle_bytes(I::INDICES, 1) };
+                _mm_shuffle_epi8(a.to_bytes().into(), #table)
+            }
+        }
+        (OpSig::Shuffle(_), _) => {
+            let table_a = load(quote! { table_a });
+            let table_b = load(quote! { table_b });
+            // `pshufb` zeroes the bytes with the high bit of the index set, so
+            // each input only fills in the bytes it provides.
+            quote! {
+                let table_a = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 0)
+                };
+                let table_b = const {
+                    crate::shuffle::select_bytes(crate::shuffle::shuffle_bytes(I::INDICES, 2), 1)
+                };
+                _mm_or_si128(
+                    _mm_shuffle_epi8(a.to_bytes().into(), #table_a),
+                    _mm_shuffle_epi8(b.to_bytes().into(), #table_b),
+                )
+            }
+        }
+        (OpSig::Rotate(_) | OpSig::Broadcast, _) => native_lane_move(sig, vec_ty),
+        (OpSig::Reverse, 32) => quote! { _mm_shuffle_epi32::<0x1b>(a.to_bytes().into()) },
+        (OpSig::Reverse, 64) => quote! { _mm_shuffle_epi32::<0x4e>(a.to_bytes().into()) },
+        (OpSig::Reverse, _) => {
+            let indices = permutation_bytes(sig, vec_ty)
+                .into_iter()
+                .map(|i| Literal::i8_unsuffixed(i as i8));
+            quote! {
+                _mm_shuffle_epi8(a.to_bytes().into(), _mm_setr_epi8(#( #indices ),*))
+            }
+        }
+        (OpSig::Unzip(low), 32) => {
+            let imm = if low {
+                quote! { 0x88 }
+            } else {
+                quote! { 0xdd }
+            };
+            quote! {
+                _mm_castps_si128(_mm_shuffle_ps::<#imm>(
+                    _mm_castsi128_ps(a.to_bytes().into()),
+                    _mm_castsi128_ps(b.to_bytes().into()),
+                ))
+            }
+        }
+        (OpSig::Unzip(low), _) => {
+            let unpack = if low {
+                quote! { _mm_unpacklo_epi64 }
+            } else {
+                quote! { _mm_unpackhi_epi64 }
+            };
+            if vec_ty.scalar_bits == 64 {
+                quote! { #unpack(a.to_bytes().into(), b.to_bytes().into()) }
+            } else {
+                // Gather the even lanes into the low half and the odd lanes into
+                // the high half of each input, then pick the wanted halves.
+                let even = permutation_bytes(OpSig::Unzip(true), vec_ty);
+                let odd = permutation_bytes(OpSig::Unzip(false), vec_ty);
+                let indices = even[..8]
+                    .iter()
+                    .chain(&odd[..8])
+                    .map(|&i| Literal::i8_unsuffixed(i as i8));
+                quote! {
+                    let table = _mm_setr_epi8(#( #indices ),*);
+                    #unpack(
+                        _mm_shuffle_epi8(a.to_bytes().into(), table),
+                        _mm_shuffle_epi8(b.to_bytes().into(), table),
+                    )
+                }
+            }
+        }
+        _ => unreachable!(),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident #generics (#args) -> #ty<Self> {
+            unsafe {
+                let bytes = { #bytes };
+                #ty::from_bytes(bytes.simd_into(self))
+            }
+        }
+    }
+}
+
+/// Expand the low bits of `bits` into a 128 or 256 bit mask.
+///
+/// Each lane tests its own bit of a splat of the bitmask. Returns the
+/// weights, which are the bit of each lane, and the test against them.
+fn mk_from_bitmask(vec_ty: &VecType) -> (TokenStream, TokenStream) {
+    let n_bits = vec_ty.n_bits();
+    let suffix = op_suffix(vec_ty, false);
+    let cmpeq = intrinsic_ident("cmpeq", suffix, n_bits);
+    let and = intrinsic_ident("and", coarse_suffix(vec_ty), n_bits);
+    let set1 = set1_intrinsic(vec_ty);
+    // The 8 bit lanes only have room for a byte of the bitmask, so the
+    // right byte is shuffled into each lane.
+    let splat = match (vec_ty.scalar_bits, n_bits) {
+        (8, 128) => quote! {
+            _mm_shuffle_epi8(
+                _mm_cvtsi32_si128(bits as i32),
+                _mm_setr_epi8(0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1),
+            )
+        },
+        (8, _) => quote! {
+            _mm256_shuffle_epi8(
+                _mm256_set1_epi32(bits as i32),
+                _mm256_setr_epi8(
+                    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1,
+                    2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 3, 3,
+                ),
+            )
+        },
+        (64, _) => quote! { #set1(bits as i64) },
+        (lane_bits, _) => {
+            let scalar = format_ident!("i{lane_bits}");
+            quote! { #set1(bits as #scalar) }
+        }
+    };
+    let weights = (0..vec_ty.len).map(|i| {
+        // The weights wrap around into the sign bit of the lane.
+        let weight = match vec_ty.scalar_bits {
+            8 => (1_u8 << (i % 8)) as i8 as i64,
+            16 => (1_u16 << i) as i16 as i64,
+            _ => 1 << i,
+        };
+        Literal::i64_unsuffixed(weight)
+    });
+    let weights = if vec_ty.scalar_bits == 64 && n_bits == 128 {
+        // There is no `_mm_setr_epi64x`.
+        quote! { _mm_set_epi64x(2, 1) }
+    } else {
+        let setr = intrinsic_ident(
+            "setr",
+            if vec_ty.scalar_bits == 64 {
+                "epi64x"
+            } else {
+                suffix
+            },
+            n_bits,
+        );
+        quote! { #setr(#( #weights ),*) }
+    };
+    (weights, quote! { #cmpeq(#and(#splat, weights), weights) })
+}
+
+/// Left or right shift by a uniform amount.
+fn mk_shift(level: Level, method: &str, vec_ty: &VecType) -> TokenStream {
+    let n_bits = vec_ty.n_bits();
+    let signed = vec_ty.scalar == ScalarType::Int;
+    if method == "shl" {
+        let sll = intrinsic_ident("sll", op_suffix(vec_ty, false), n_bits);
+        if vec_ty.scalar_bits != 8 {
+            return quote! { #sll(a.into(), _mm_cvtsi32_si128(shift as i32)) };
+        }
+        // There are no 8 bit shifts, so shift 16 bit lanes and clear the bits
+        // that crossed over from the neighbouring byte.
+        let sll = intrinsic_ident("sll", "epi16", n_bits);
+        let and = intrinsic_ident("and", coarse_suffix(vec_ty), n_bits);
+        let set1 = intrinsic_ident("set1", "epi8", n_bits);
+        return quote! {
+            #and(
+                #sll(a.into(), _mm_cvtsi32_si128(shift as i32)),
+                #set1(0xff_u8.checked_shl(shift).unwrap_or(0) as i8),
+            )
+        };
+    }
+    assert_eq!(method, "shr");
+    if vec_ty.scalar_bits == 8 {
+        // There are no 8 bit shifts, so widen each byte to 16 bits, shift, and
+        // pack back down. Signed values are placed in the high byte so that the
+        // arithmetic shift sees their sign bit.
+        let unpacklo = intrinsic_ident("unpacklo", "epi8", n_bits);
+        let unpackhi = intrinsic_ident("unpackhi", "epi8", n_bits);
+        let (shift_16, pack, shift_amount, extend) = if signed {
+            (
+                intrinsic_ident("sra", "epi16", n_bits),
+                intrinsic_ident("packs", "epi16", n_bits),
+                quote! { shift.saturating_add(8) },
+                quote! { val },
+            )
+        } else {
+            let setzero = intrinsic_ident("setzero", coarse_suffix(vec_ty), n_bits);
+            (
+                intrinsic_ident("srl", "epi16", n_bits),
+                intrinsic_ident("packus", "epi16", n_bits),
+                quote! { shift },
+                quote! { #setzero() },
+            )
+        };
+        quote! {
+            let val = a.into();
+            let shift_count = _mm_cvtsi32_si128(#shift_amount as i32);
+            let lo_16 = #unpacklo(val, #extend);
+            let hi_16 = #unpackhi(val, #extend);
+            let lo_shifted = #shift_16(lo_16, shift_count);
+            let hi_shifted = #shift_16(hi_16, shift_count);
+            #pack(lo_shifted, hi_shifted)
+        }
+    } else if signed && vec_ty.scalar_bits == 64 && !level.has_avx512() {
+        // There is no 64 bit arithmetic shift before AVX-512. Flipping negative
+        // values makes them positive, so a logical shift can be flipped back.
+        let setzero = intrinsic_ident("setzero", coarse_suffix(vec_ty), n_bits);
+        let cmpgt = intrinsic_ident("cmpgt", "epi64", n_bits);
+        let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+        let srl = intrinsic_ident("srl", "epi64", n_bits);
+        quote! {
+            let val = a.into();
+            let sign = #cmpgt(#setzero(), val);
+            #xor(#srl(#xor(val, sign), _mm_cvtsi32_si128(shift as i32)), sign)
+        }
+    } else {
+        let name = if signed { "sra" } else { "srl" };
+        let intrinsic = intrinsic_ident(name, op_suffix(vec_ty, false), n_bits);
+        quote! { #intrinsic(a.into(), _mm_cvtsi32_si128(shift as i32)) }
+    }
+}
+
+/// Conversions to lanes of twice or half the width.
+fn mk_widen_narrow(level: Level, method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let ty_name = vec_ty.rust_name();
+    let n_bits = vec_ty.n_bits();
+    let bits = vec_ty.scalar_bits;
+    let method_ident = Ident::new(&format!("{method}_{ty_name}"), Span::call_site());
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    let signed = vec_ty.scalar == ScalarType::Int;
+    // The sign or zero extension of the lanes in the low half of a register.
+    let extend = |width: usize| {
+        let name = format!("cvtep{}{bits}", if signed { "i" } else { "u" });
+        intrinsic_ident(&name, &format!("epi{}", 2 * bits), width)
+    };
+    let body = match sig {
+        OpSig::WidenNarrow(to_ty) if method == "widen" => {
+            if level.max_native_bits() >= 2 * n_bits.max(128) || n_bits == 64 {
+                let extend = extend((2 * n_bits).max(128));
+                quote! { #extend(a.into()).simd_into(self) }
+            } else if n_bits == 128 {
+                let extend = extend(128);
+                let half_to = VecType::new(to_ty.scalar, to_ty.scalar_bits, to_ty.len / 2);
+                let combine = format_ident!("combine_{}", half_to.rust_name());
+                quote! {
+                    let a = a.into();
+                    let low = #extend(a).simd_into(self);
+                    let high = #extend(_mm_srli_si128::<8>(a)).simd_into(self);
+                    self.#combine(low, high)
+                }
+            } else {
+                return generic_op(method, sig, vec_ty);
+            }
+        }
+        OpSig::WidenHalf(low) if n_bits == 128 => {
+            let extend = extend(128);
+            if low {
+                quote! { #extend(a.into()).simd_into(self) }
+            } else {
+                quote! { #extend(_mm_srli_si128::<8>(a.into())).simd_into(self) }
+            }
+        }
+        OpSig::WidenNarrow(_) if bits == 64 && method == "narrow_saturating" => {
+            return generic_narrow_saturating(vec_ty);
+        }
+        OpSig::WidenNarrow(_) if n_bits <= 256 => {
+            // A 128 bit vector narrows into the low half of the register.
+            let native_256 = n_bits == 256 && level.max_native_bits() >= 256;
+            let width = if native_256 { 256 } else { 128 };
+            let halves = if native_256 {
+                quote! { let a = a.into(); }
+            } else if n_bits == 128 {
+                quote! { let (low, high) = (a.into(), a.into()); }
+            } else {
+                let split = format_ident!("split_{ty_name}");
+                quote! {
+                    let (low, high) = self.#split(a);
+                    let (low, high) = (low.into(), high.into());
+                }
+            };
+            let narrowed = if bits == 64 {
+                // The even 32 bit lanes hold the low halves.
+                if native_256 {
+                    quote! {
+                        let even = _mm256_setr_epi32(0, 2, 4, 6, 0, 2, 4, 6);
+                        _mm256_castsi256_si128(_mm256_permutevar8x32_epi32(a, even))
+                    }
+                } else {
+                    quote! {
+                        _mm_castps_si128(_mm_shuffle_ps::<0b10_00_10_00>(
+                            _mm_castsi128_ps(low),
+                            _mm_castsi128_ps(high),
+                        ))
+                    }
+                }
+            } else {
+                // The pack instructions saturate, as signed or unsigned lanes. Masking
+                // off the high halves gives truncating semantics, and clamping to the
+                // unsigned maximum gives unsigned saturation.
+                let set1 = intrinsic_ident("set1", &format!("epi{bits}"), width);
+                let max = Literal::u64_unsuffixed((1 << (bits / 2)) - 1);
+                let clamp = |x: TokenStream| match (method, vec_ty.scalar) {
+                    ("narrow", _) => {
+                        let and = intrinsic_ident("and", &format!("si{width}"), width);
+                        quote! { #and(#x, #set1(#max)) }
+                    }
+                    (_, ScalarType::Unsigned) => {
+                        let min = intrinsic_ident("min", &format!("epu{bits}"), width);
+                        quote! { #min(#x, #set1(#max)) }
+                    }
+                    _ => x,
+                };
+                let pack_name = if method == "narrow_saturating" && signed {
+                    "packs"
+                } else {
+                    "packus"
+                };
+                let pack = intrinsic_ident(pack_name, &format!("epi{bits}"), width);
+                if native_256 {
+                    let clamped = clamp(quote! { a });
+                    // The pack works within 128 bit lanes, so gather the two useful
+                    // 64 bit chunks into the low half.
+                    quote! {
+                        let clamped = #clamped;
+                        let packed = #pack(clamped, clamped);
+                        let ordered = _mm256_permute4x64_epi64::<0b11_01_10_00>(packed);
+                        _mm256_castsi256_si128(ordered)
+                    }
+                } else {
+                    let (low, high) = (clamp(quote! { low }), clamp(quote! { high }));
+                    quote! { #pack(#low, #high) }
+                }
+            };
+            quote! {
+                #halves
+                #narrowed.simd_into(self)
+            }
+        }
+        _ => return generic_op(method, sig, vec_ty),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>) -> #ret_ty {
+            unsafe {
+                #body
+            }
+        }
+    }
+}
+
+/// The integer multiplies that need the full product of each pair of lanes.
+fn mk_widening_mul(method: &str, sig: OpSig, vec_ty: &VecType) -> TokenStream {
+    let ty = vec_ty.rust();
+    let n_bits = vec_ty.n_bits();
+    let method_ident = Ident::new(
+        &format!("{method}_{}", vec_ty.rust_name()),
+        Span::call_site(),
+    );
+    let ret_ty = sig.ret_ty(vec_ty, TyFlavor::SimdTrait);
+    let signed = vec_ty.scalar == ScalarType::Int;
+    let int_suffix = coarse_suffix(vec_ty);
+    let body = match (method, vec_ty.scalar_bits) {
+        ("dot_i16", _) => {
+            let madd = intrinsic_ident("madd", "epi16", n_bits);
+            quote! { #madd(a.into(), b.into()).simd_into(self) }
+        }
+        ("mul_q15_round_sat", _) => {
+            // `mulhrs` only overflows for `-0x8000` squared, which gives `-0x8000`
+            // instead of `0x7fff`, so flip the bits of those lanes.
+            let mulhrs = intrinsic_ident("mulhrs", "epi16", n_bits);
+            let xor = intrinsic_ident("xor", int_suffix, n_bits);
+            let set1 = intrinsic_ident("set1", "epi16", n_bits);
+            let overflow = if n_bits == 512 {
+                quote! { _mm512_movm_epi16(_mm512_cmpeq_epi16_mask(result, #set1(i16::MIN))) }
+            } else {
+                let cmpeq = intrinsic_ident("cmpeq", "epi16", n_bits);
+                quote! { #cmpeq(result, #set1(i16::MIN)) }
+            };
+            quote! {
+                let result = #mulhrs(a.into(), b.into());
+                #xor(result, #overflow).simd_into(self)
+            }
+        }
+        ("mul_high", 16) => {
+            let mulhi = intrinsic_ident("mulhi", op_suffix(vec_ty, true), n_bits);
+            quote! { #mulhi(a.into(), b.into()).simd_into(self) }
+        }
+        ("mul_high", _) => {
+            // Multiply the even and odd bytes separately as 16 bit lanes, which
+            // holds the full products.
+            let mullo = intrinsic_ident("mullo", "epi16", n_bits);
+            let srli = intrinsic_ident("srli", "epi16", n_bits);
+            let and = intrinsic_ident("and", int_suffix, n_bits);
+            let or = intrinsic_ident("or", int_suffix, n_bits);
+            let set1 = intrinsic_ident("set1", "epi16", n_bits);
+            let (even_a, even_b, odd_a, odd_b) = if signed {
+                let srai = intrinsic_ident("srai", "epi16", n_bits);
+                let slli = intrinsic_ident("slli", "epi16", n_bits);
+                (
+                    quote! { #srai::<8>(#slli::<8>(a)) },
+                    quote! { #srai::<8>(#slli::<8>(b)) },
+                    quote! { #srai::<8>(a) },
+                    quote! { #srai::<8>(b) },
+                )
+            } else {
+                (
+                    quote! { #and(a, #set1(0xff)) },
+                    quote! { #and(b, #set1(0xff)) },
+                    quote! { #srli::<8>(a) },
+                    quote! { #srli::<8>(b) },
+                )
+            };
+            quote! {
+                let (a, b) = (a.into(), b.into());
+                let even = #mullo(#even_a, #even_b);
+                let odd = #mullo(#odd_a, #odd_b);
+                #or(#srli::<8>(even), #and(odd, #set1(-0x100))).simd_into(self)
+            }
+        }
+        ("mul_widen", _) if n_bits > 128 => return generic_op(method, sig, vec_ty),
+        ("mul_widen", 16) => {
+            // The low and high halves of the products, interleaved into 32 bit lanes.
+            let mulhi = intrinsic_ident("mulhi", op_suffix(vec_ty, true), n_bits);
+            let wide = VecType::new(vec_ty.scalar, 32, vec_ty.len / 2).rust_name();
+            let combine = format_ident!("combine_{wide}");
+            quote! {
+                let (a, b) = (a.into(), b.into());
+                let low = _mm_mullo_epi16(a, b);
+                let high = #mulhi(a, b);
+                self.#combine(
+                    _mm_unpacklo_epi16(low, high).simd_into(self),
+                    _mm_unpackhi_epi16(low, high).simd_into(self),
+                )
+            }
+        }
+        ("mul_widen", _) => {
+            let extend = if signed {
+                quote! { _mm_cvtepi8_epi16 }
+            } else {
+                quote! { _mm_cvtepu8_epi16 }
+            };
+            let wide = VecType::new(vec_ty.scalar, 16, vec_ty.len / 2).rust_name();
+            let combine = format_ident!("combine_{wide}");
+            quote! {
+                let (a, b) = (a.into(), b.into());
+                let low = _mm_mullo_epi16(#extend(a), #extend(b));
+                let high = _mm_mullo_epi16(
+                    #extend(_mm_srli_si128::<8>(a)),
+                    #extend(_mm_srli_si128::<8>(b)),
+                );
+                self.#combine(low.simd_into(self), high.simd_into(self))
+            }
+        }
+        _ => unimplemented!("missing {method}"),
+    };
+    quote! {
+        #[inline(always)]
+        fn #method_ident(self, a: #ty<Self>, b: #ty<Self>) -> #ret_ty {
+            unsafe {
+                #body
+            }
+        }
+    }
+}
+
+fn mk_compare(level: Level, method: &str, vec_ty: &VecType) -> TokenStream {
+    let n_bits = vec_ty.n_bits();
+    if vec_ty.scalar == ScalarType::Float {
+        let suffix = op_suffix(vec_ty, false);
+        let cast = cast_from_float(vec_ty);
+        let cmp = if n_bits == 128 {
+            let name = match method {
+                "simd_eq" => "cmpeq",
+                // `cmpneq` is unordered, so it is true for NaNs.
+                "simd_ne" => "cmpneq",
+                "simd_lt" => "cmplt",
+                "simd_le" => "cmple",
+                "simd_ge" => "cmpge",
+                "simd_gt" => "cmpgt",
+                _ => unimplemented!("missing {method}"),
+            };
+            let intrinsic = intrinsic_ident(name, suffix, n_bits);
+            quote! { #intrinsic(a.into(), b.into()) }
+        } else {
+            let predicate = match method {
+                "simd_eq" => quote! { _CMP_EQ_OQ },
+                "simd_ne" => quote! { _CMP_NEQ_UQ },
+                "simd_lt" => quote! { _CMP_LT_OQ },
+                "simd_le" => quote! { _CMP_LE_OQ },
+                "simd_ge" => quote! { _CMP_GE_OQ },
+                "simd_gt" => quote! { _CMP_GT_OQ },
+                _ => unimplemented!("missing {method}"),
+            };
+            let intrinsic = intrinsic_ident("cmp", suffix, n_bits);
+            quote! { #intrinsic::<#predicate>(a.into(), b.into()) }
+        };
+        return quote! { #cast(#cmp) };
+    }
+
+    let suffix = op_suffix(vec_ty, false);
+    let cmpeq = intrinsic_ident("cmpeq", suffix, n_bits);
+    let cmpgt = intrinsic_ident("cmpgt", suffix, n_bits);
+    match method {
+        "simd_eq" => quote! { #cmpeq(a.into(), b.into()) },
+        "simd_le" | "simd_ge" if vec_ty.scalar_bits == 64 && !level.has_avx512() => {
+            // There is no 64 bit min or max before AVX-512, so negate the
+            // strict comparison instead.
+            let gt = if method == "simd_le" {
+                cmpgt_64(vec_ty, quote! { a.into() }, quote! { b.into() })
+            } else {
+                cmpgt_64(vec_ty, quote! { b.into() }, quote! { a.into() })
+            };
+            let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+            let set1 = set1_intrinsic(vec_ty);
+            quote! { #xor(#gt, #set1(-1)) }
+        }
+        "simd_le" | "simd_ge" => {
+            // a <= b exactly when min(a, b) == a, and likewise for max.
+            let name = if method == "simd_le" { "min" } else { "max" };
+            let min_max = intrinsic_ident(name, op_suffix(vec_ty, true), n_bits);
+            quote! { #cmpeq(#min_max(a.into(), b.into()), a.into()) }
+        }
+        "simd_lt" | "simd_gt" => {
+            let (lhs, rhs) = if method == "simd_gt" {
+                (quote! { a }, quote! { b })
+            } else {
+                (quote! { b }, quote! { a })
+            };
+            if vec_ty.scalar == ScalarType::Unsigned {
+                // Only signed comparisons are available, so flip the sign bits first.
+                let set1 = set1_intrinsic(vec_ty);
+                let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+                let signed = ScalarType::Int.rust(vec_ty.scalar_bits);
+                quote! {
+                    let sign_bit = #set1(#signed::MIN);
+                    let a_signed = #xor(#lhs.into(), sign_bit);
+                    let b_signed = #xor(#rhs.into(), sign_bit);
+                    #cmpgt(a_signed, b_signed)
+                }
+            } else {
+                quote! { #cmpgt(#lhs.into(), #rhs.into()) }
+            }
+        }
+        _ => unimplemented!("missing {method}"),
+    }
+}
+
+/// A signed comparison of 64 bit lanes, flipping the sign bits of unsigned lanes first.
+fn cmpgt_64(vec_ty: &VecType, lhs: TokenStream, rhs: TokenStream) -> TokenStream {
+    let n_bits = vec_ty.n_bits();
+    let cmpgt = intrinsic_ident("cmpgt", "epi64", n_bits);
+    if vec_ty.scalar == ScalarType::Unsigned {
+        let xor = intrinsic_ident("xor", coarse_suffix(vec_ty), n_bits);
+        let set1 = set1_intrinsic(vec_ty);
+        quote! {
+            #cmpgt(#xor(#lhs, #set1(i64::MIN)), #xor(#rhs, #set1(i64::MIN)))
+        }
+    } else {
+        quote! { #cmpgt(#lhs, #rhs) }
+    }
+}
+
+fn mk_cvt(vec_ty: &VecType, scalar: ScalarType, scalar_bits: usize) -> TokenStream {
+    let n_bits = vec_ty.n_bits();
+    let set1_ps = intrinsic_ident("set1", "ps", n_bits);
+    let cvtt = intrinsic_ident("cvttps", "epi32", n_bits);
+    let cvt_to_float = intrinsic_ident("cvtepi32", "ps", n_bits);
+    let cast = cast_from_float(&VecType::new(ScalarType::Float, 32, vec_ty.len));
+    let (cmp_ge, cmp_unord) = if n_bits == 128 {
+        (quote! { _mm_cmpge_ps }, quote! { _mm_cmpunord_ps })
+    } else {
+        (
+            quote! { _mm256_cmp_ps::<_CMP_GE_OQ> },
+            quote! { _mm256_cmp_ps::<_CMP_UNORD_Q> },
+        )
+    };
+    let xor = intrinsic_ident("xor", coarse_suffix(&vec_ty.mask_ty()), n_bits);
+    let or = intrinsic_ident("or", coarse_suffix(&vec_ty.mask_ty()), n_bits);
+    let andnot = intrinsic_ident("andnot", coarse_suffix(&vec_ty.mask_ty()), n_bits);
+    match (vec_ty.scalar, scalar, scalar_bits) {
+        (ScalarType::Float, ScalarType::Int, 32) => {
+            // `cvttps` produces `i32::MIN` for NaN and out of range values. Fix
+            // those up to get the same saturating semantics as `as`.
+            quote! {
+                let a = a.into();
+                let converted = #cvtt(a);
+                let overflow = #cast(#cmp_ge(a, #set1_ps(2147483648.0)));
+                let nan = #cast(#cmp_unord(a, a));
+                #andnot(nan, #xor(converted, overflow))
+            }
+        }
+        (ScalarType::Float, ScalarType::Unsigned, 32) => {
+            // Clamp negative values and NaN to zero, then convert values at or
+            // above 2^31 with a bias since only the signed conversion exists.
+            let max = intrinsic_ident("max", "ps", n_bits);
+            let sub = intrinsic_ident("sub", "ps", n_bits);
+            let setzero = intrinsic_ident("setzero", "ps", n_bits);
+            let set1_epi32 = intrinsic_ident("set1", "epi32", n_bits);
+            let blendv = intrinsic_ident("blendv", "epi8", n_bits);
+            quote! {
+                let a = #max(a.into(), #setzero());
+                let exceeds_i32 = #cast(#cmp_ge(a, #set1_ps(2147483648.0)));
+                let low = #cvtt(a);
+                let high = #xor(
+                    #cvtt(#sub(a, #set1_ps(2147483648.0))),
+                    #set1_epi32(i32::MIN),
+                );
+                let overflow = #cast(#cmp_ge(a, #set1_ps(4294967296.0)));
+                #or(#blendv(low, high, exceeds_i32), overflow)
+            }
+        }
+        (ScalarType::Int, ScalarType::Float, 32) => quote! { #cvt_to_float(a.into()) },
+        (ScalarType::Unsigned, ScalarType::Float, 32) => {
+            // Only the signed conversion exists, so convert the high and low 16 bits
+            // separately. Both halves are exact, so the final add rounds only once.
+            let srli = intrinsic_ident("srli", "epi32", n_bits);
+            let and = intrinsic_ident("and", coarse_suffix(vec_ty), n_bits);
+            let set1_epi32 = intrinsic_ident("set1", "epi32", n_bits);
+            let add = intrinsic_ident("add", "ps", n_bits);
+            let mul = intrinsic_ident("mul", "ps", n_bits);
+            quote! {
+                let a = a.into();
+                let high = #cvt_to_float(#srli::<16>(a));
+                let low = #cvt_to_float(#and(a, #set1_epi32(0xffff)));
+                #add(#mul(high, #set1_ps(65536.0)), low)
+            }
+        }
+        _ => unimplemented!(),
+    }
+}
+
+/// The unpack intrinsics used to emulate interleaved loads and stores.
+fn interleave_intrinsics(vec_ty: &VecType) -> (Ident, Ident) {
+    let suffix = match vec_ty.scalar_bits {
+        8 => "epi8",
+        16 => "epi16",
+        32 => "epi32",
+        _ => panic!("unsupported scalar_bits"),
+    };
+    (
+        intrinsic_ident("unpacklo", suffix, 128),
+        intrinsic_ident("unpackhi", suffix, 128),
+    )
+}
+
+/// Reinterpret an `__m128i` as the arch type of a 128 bit block.
+fn block_from_si128(block: &VecType, expr: TokenStream) -> TokenStream {
+    if block.scalar == ScalarType::Float {
+        quote! { _mm_castsi128_ps(#expr) }
+    } else {
+        expr
+    }
+}
+
+/// Reinterpret the arch type of a 128 bit block as an `__m128i`.
+fn block_to_si128(block: &VecType, expr: TokenStream) -> TokenStream {
+    if block.scalar == ScalarType::Float {
+        quote! { _mm_castps_si128(#expr) }
+    } else {
+        expr
+    }
+}
+
+fn mk_type_impl() -> TokenStream {
+    let mut result = vec![];
+    for ty in SIMD_TYPES {
+        let n_bits = ty.n_bits();
+        let simd = ty.rust();
+        let arch = X86.arch_ty(ty);
+        if n_bits == 64 {
+            let bits = quote! { _mm_cvtsi64_si128(bytemuck::cast(value.val)) };
+            let (to_int, from_int) = if ty.scalar == ScalarType::Float {
+                (
+                    quote! { _mm_castps_si128(arch) },
+                    quote! { _mm_castsi128_ps(#bits) },
+                )
+            } else {
+                (quote! { arch }, bits)
+            };
+            // The upper half of the register is unused.
+            result.push(quote! {
+                impl<S: Simd> SimdFrom<#arch, S> for #simd<S> {
+                    #[inline(always)]
+                    fn simd_from(arch: #arch, simd: S) -> Self {
+                        Self {
+                            val: unsafe { bytemuck::cast(_mm_cvtsi128_si64(#to_int)) },
+                            simd
+                        }
+                    }
+                }
+                impl<S: Simd> From<#simd<S>> for #arch {
+                    #[inline(always)]
+                    fn from(value: #simd<S>) -> Self {
+                        unsafe { #from_int }
+                    }
+                }
+            });
+            continue;
+        }
+        if !matches!(n_bits, 128 | 256 | 512) {
+            continue;
+        }
+        result.push(quote! {
+            impl<S: Simd> SimdFrom<#arch, S> for #simd<S> {
+                #[inline(always)]
+                fn simd_from(arch: #arch, simd: S) -> Self {
+                    Self {
+                        val: unsafe { core::mem::transmute(arch) },
+                        simd
+                    }
+                }
+            }
+            impl<S: Simd> From<#simd<S>> for #arch {
+                #[inline(always)]
+                fn from(value: #simd<S>) -> Self {
+                    unsafe { core::mem::transmute(value.val) }
+                }
+            }
+        })
+    }
+    quote! {
+        #( #result )*
+    }
+}
+
+/// Round the f32 lanes of `x` to the nearest bf16, with ties to even.
+///
+/// The bf16 bits are left in the low half of each 32 bit lane of an integer
+/// register. NaNs are quieted rather than rounded, so they can't turn into
+/// infinities.
+fn bf16_round(x: TokenStream, n_bits: usize) -> TokenStream {
+    let cast = format_ident!(
+        "{}",
+        cast_from_float(&VecType::new(ScalarType::Float, 32, n_bits / 32))
+    );
+    let si = format!("si{n_bits}");
+    let and = intrinsic_ident("and", &si, n_bits);
+    let or = intrinsic_ident("or", &si, n_bits);
+    let add = intrinsic_ident("add", "epi32", n_bits);
+    let srli = intrinsic_ident("srli", "epi32", n_bits);
+    let set1 = intrinsic_ident("set1", "epi32", n_bits);
+    let select = match n_bits {
+        128 => quote! { _mm_blendv_epi8(quiet, rounded, _mm_castps_si128(_mm_cmpord_ps(#x, #x))) },
+        256 => quote! {
+            _mm256_blendv_epi8(quiet, rounded, _mm256_castps_si256(_mm256_cmp_ps::<_CMP_ORD_Q>(#x, #x)))
+        },
+        _ => {
+            quote! { _mm512_mask_blend_epi32(_mm512_cmp_ps_mask::<_CMP_ORD_Q>(#x, #x), quiet, rounded) }
+        }
+    };
+    quote! {
+        let bits = #cast(#x);
+        let lsb = #and(#srli::<16>(bits), #set1(1));
+        let rounded = #srli::<16>(#add(#add(bits, #set1(0x7fff)), lsb));
+        let quiet = #srli::<16>(#or(bits, #set1(0x0040_0000)));
+        let #x = #select;
+    }
+}
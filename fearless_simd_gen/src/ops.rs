@@ -1,10 +1,10 @@
 // Copyright 2025 the Fearless_SIMD Authors
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
-use crate::types::{ScalarType, VecType};
+use crate::types::{SIMD_TYPES, ScalarType, VecType};
 
 #[derive(Clone, Copy)]
 pub enum OpSig {
@@ -19,15 +19,85 @@ pub enum OpSig {
     Zip(bool),
     Cvt(ScalarType, usize),
     Reinterpret(ScalarType, usize),
+    /// Convert each lane to the given type, which has lanes of twice or half
+    /// the width. Signed lanes are sign extended, and narrowing truncates or
+    /// saturates, depending on the op.
     WidenNarrow(VecType),
-    // TODO: Make clear that this is right-shift
+    /// Widen the lanes of the low (`true`) or high half of the vector, which
+    /// gives a vector of the same size.
+    WidenHalf(bool),
+    /// A shift of every lane by the same `u32` amount.
     Shift,
+    /// An estimate refined by a `u32` number of Newton-Raphson steps.
+    Refine,
+    /// A test of each lane on its own, which gives a mask.
+    Classify,
+    /// A binary op whose result is a vector of the given type.
+    BinaryTo(VecType),
     // First argument is the base block size (i.e. 128), second argument
     // is how many blocks. For example, `LoadInterleaved(128, 4)` would correspond to the
     // NEON instructions `vld4q_f32`, while `LoadInterleaved(64, 4)` would correspond to
     // `vld4_f32`.
     LoadInterleaved(u16, u16),
     StoreInterleaved(u16, u16), // TODO: fma
+    /// Combine all of the lanes into a scalar.
+    ///
+    /// Float sums add neighbouring lanes pairwise, so that every level
+    /// rounds the same way.
+    Reduce,
+    /// A permutation of the lanes of one or two vectors, with the indices
+    /// given by a type implementing `Indices`.
+    ///
+    /// The argument is the number of input vectors.
+    Shuffle(usize),
+    /// Reverse the order of the lanes.
+    Reverse,
+    /// Rotate the lanes left (`true`) or right by a const generic `K`.
+    ///
+    /// `K` wraps around, so any value is allowed.
+    Rotate(bool),
+    /// Copy the lane given by a const generic `L` to every lane.
+    ///
+    /// `L` wraps around like the rotation amount. The methods on the vector
+    /// types check it at compile time instead.
+    Broadcast,
+    /// The even (`true`) or odd lanes of the concatenation of two vectors.
+    Unzip(bool),
+    /// A query on the lanes of a mask, such as `any` or `to_bitmask`.
+    MaskQuery(QueryRet),
+    /// Build a mask from a `u64` with one bit for each lane.
+    FromBitmask,
+    /// Look up each byte of the second argument in a table of bytes, which
+    /// is the first argument. Indices past the end of the table give zero.
+    ///
+    /// The argument is the number of bytes in the table.
+    Lookup(usize),
+    /// Accumulate products of bf16 pairs into an f32 vector, like `bfdot`.
+    ///
+    /// The bf16 arguments have twice as many lanes as the f32 vector.
+    DotBf16,
+}
+
+/// The result of a `MaskQuery`.
+#[derive(Clone, Copy)]
+pub enum QueryRet {
+    Bool,
+    Count,
+    /// The index of a lane, if there is one.
+    Index,
+    /// One bit for each lane, with lane 0 in the lowest bit.
+    Bitmask,
+}
+
+impl QueryRet {
+    pub fn rust(self) -> TokenStream {
+        match self {
+            QueryRet::Bool => quote! { bool },
+            QueryRet::Count => quote! { usize },
+            QueryRet::Index => quote! { Option<usize> },
+            QueryRet::Bitmask => quote! { u64 },
+        }
+    }
 }
 
 pub const FLOAT_OPS: &[(&str, OpSig)] = &[
@@ -35,16 +105,26 @@ pub const FLOAT_OPS: &[(&str, OpSig)] = &[
     ("abs", OpSig::Unary),
     ("neg", OpSig::Unary),
     ("sqrt", OpSig::Unary),
+    ("recip_approx", OpSig::Unary),
+    ("rsqrt_approx", OpSig::Unary),
+    ("recip", OpSig::Refine),
+    ("rsqrt", OpSig::Refine),
     ("add", OpSig::Binary),
     ("sub", OpSig::Binary),
     ("mul", OpSig::Binary),
     ("div", OpSig::Binary),
     ("copysign", OpSig::Binary),
     ("simd_eq", OpSig::Compare),
+    ("simd_ne", OpSig::Compare),
     ("simd_lt", OpSig::Compare),
     ("simd_le", OpSig::Compare),
     ("simd_ge", OpSig::Compare),
     ("simd_gt", OpSig::Compare),
+    ("is_nan", OpSig::Classify),
+    ("is_finite", OpSig::Classify),
+    ("is_infinite", OpSig::Classify),
+    ("is_subnormal", OpSig::Classify),
+    ("is_sign_negative", OpSig::Classify),
     ("zip_low", OpSig::Zip(true)),
     ("zip_high", OpSig::Zip(false)),
     ("max", OpSig::Binary),
@@ -53,11 +133,20 @@ pub const FLOAT_OPS: &[(&str, OpSig)] = &[
     ("min_precise", OpSig::Binary),
     ("madd", OpSig::Ternary),
     ("msub", OpSig::Ternary),
+    ("nmadd", OpSig::Ternary),
+    ("nmsub", OpSig::Ternary),
+    ("mul_add_fused", OpSig::Ternary),
+    ("mul_add_fast", OpSig::Ternary),
     ("floor", OpSig::Unary),
+    ("ceil", OpSig::Unary),
+    ("round", OpSig::Unary),
+    ("round_ties_even", OpSig::Unary),
     ("fract", OpSig::Unary),
     ("trunc", OpSig::Unary),
-    // TODO: simd_ne, but this requires additional implementation work on Neon
     ("select", OpSig::Select),
+    ("reduce_add", OpSig::Reduce),
+    ("reduce_min", OpSig::Reduce),
+    ("reduce_max", OpSig::Reduce),
 ];
 
 pub const INT_OPS: &[(&str, OpSig)] = &[
@@ -69,7 +158,10 @@ pub const INT_OPS: &[(&str, OpSig)] = &[
     ("and", OpSig::Binary),
     ("or", OpSig::Binary),
     ("xor", OpSig::Binary),
+    ("shl", OpSig::Shift),
     ("shr", OpSig::Shift),
+    ("shl_var", OpSig::Binary),
+    ("shr_var", OpSig::Binary),
     ("simd_eq", OpSig::Compare),
     ("simd_lt", OpSig::Compare),
     ("simd_le", OpSig::Compare),
@@ -80,6 +172,11 @@ pub const INT_OPS: &[(&str, OpSig)] = &[
     ("select", OpSig::Select),
     ("min", OpSig::Binary),
     ("max", OpSig::Binary),
+    ("reduce_add", OpSig::Reduce),
+    ("reduce_min", OpSig::Reduce),
+    ("reduce_max", OpSig::Reduce),
+    ("reduce_and", OpSig::Reduce),
+    ("reduce_or", OpSig::Reduce),
 ];
 
 pub const MASK_OPS: &[(&str, OpSig)] = &[
@@ -90,66 +187,240 @@ pub const MASK_OPS: &[(&str, OpSig)] = &[
     ("xor", OpSig::Binary),
     ("select", OpSig::Select),
     ("simd_eq", OpSig::Compare),
+    ("any", OpSig::MaskQuery(QueryRet::Bool)),
+    ("all", OpSig::MaskQuery(QueryRet::Bool)),
+    ("none", OpSig::MaskQuery(QueryRet::Bool)),
+    ("count_true", OpSig::MaskQuery(QueryRet::Count)),
+    ("first_true", OpSig::MaskQuery(QueryRet::Index)),
+    ("to_bitmask", OpSig::MaskQuery(QueryRet::Bitmask)),
+    ("from_bitmask", OpSig::FromBitmask),
+];
+
+/// Lane permutations, which every vector type has.
+pub const SHUFFLE_OPS: &[(&str, OpSig)] = &[
+    ("shuffle", OpSig::Shuffle(1)),
+    ("shuffle2", OpSig::Shuffle(2)),
+    ("reverse", OpSig::Reverse),
+    ("rotate_lanes_left", OpSig::Rotate(true)),
+    ("rotate_lanes_right", OpSig::Rotate(false)),
+    ("broadcast_lane", OpSig::Broadcast),
+    ("unzip_low", OpSig::Unzip(true)),
+    ("unzip_high", OpSig::Unzip(false)),
+];
+
+/// bf16 vectors are only for storage, so there is no arithmetic on them.
+pub const BF16_OPS: &[(&str, OpSig)] = &[("splat", OpSig::Splat), ("select", OpSig::Select)];
+
+/// The element types that `cvt` converts between, with the names of the
+/// conversions to them, and of the rounding conversions from floats.
+///
+/// Conversions to integers saturate, like `as`, and either truncate or round
+/// to nearest, ties to even. Conversions to floats round to nearest, ties to
+/// even.
+const CVT_TARGETS: &[(ScalarType, usize, &str, &str)] = &[
+    (ScalarType::Float, 16, "cvt_f16", ""),
+    (ScalarType::Float, 32, "cvt_f32", ""),
+    (ScalarType::Float, 64, "cvt_f64", ""),
+    (ScalarType::Int, 8, "cvt_i8", "cvt_i8_round"),
+    (ScalarType::Unsigned, 8, "cvt_u8", "cvt_u8_round"),
+    (ScalarType::Int, 16, "cvt_i16", "cvt_i16_round"),
+    (ScalarType::Unsigned, 16, "cvt_u16", "cvt_u16_round"),
+    (ScalarType::Int, 32, "cvt_i32", "cvt_i32_round"),
+    (ScalarType::Unsigned, 32, "cvt_u32", "cvt_u32_round"),
+    (ScalarType::Int, 64, "cvt_i64", "cvt_i64_round"),
+    (ScalarType::Unsigned, 64, "cvt_u64", "cvt_u64_round"),
+];
+
+/// The element types that `reinterpret` casts to, with the names of the casts.
+const REINTERPRET_TARGETS: &[(ScalarType, usize, &str)] = &[
+    (ScalarType::Float, 16, "reinterpret_f16"),
+    (ScalarType::BFloat, 16, "reinterpret_bf16"),
+    (ScalarType::Float, 32, "reinterpret_f32"),
+    (ScalarType::Float, 64, "reinterpret_f64"),
+    (ScalarType::Int, 8, "reinterpret_i8"),
+    (ScalarType::Unsigned, 8, "reinterpret_u8"),
+    (ScalarType::Int, 16, "reinterpret_i16"),
+    (ScalarType::Unsigned, 16, "reinterpret_u16"),
+    (ScalarType::Int, 32, "reinterpret_i32"),
+    (ScalarType::Unsigned, 32, "reinterpret_u32"),
+    (ScalarType::Int, 64, "reinterpret_i64"),
+    (ScalarType::Unsigned, 64, "reinterpret_u64"),
 ];
 
 /// Ops covered by core::ops
 pub const CORE_OPS: &[&str] = &[
-    "not", "neg", "add", "sub", "mul", "div", "and", "or", "xor", "shr",
+    "not", "neg", "add", "sub", "mul", "div", "and", "or", "xor", "shl", "shr",
 ];
 
-pub fn ops_for_type(ty: &VecType, cvt: bool) -> Vec<(&str, OpSig)> {
-    let base = match ty.scalar {
+/// The ops shared by every vector of the given scalar type.
+///
+/// These are the ops that make up the vec traits, such as `SimdInt`.
+pub fn base_ops(scalar: ScalarType) -> &'static [(&'static str, OpSig)] {
+    match scalar {
         ScalarType::Float => FLOAT_OPS,
         ScalarType::Int | ScalarType::Unsigned => INT_OPS,
         ScalarType::Mask => MASK_OPS,
-    };
-    let mut ops = base.to_vec();
-    if ty.n_bits() < 512 {
+        ScalarType::BFloat => BF16_OPS,
+    }
+}
+
+pub fn ops_for_type(ty: &VecType, cvt: bool) -> Vec<(&str, OpSig)> {
+    let mut ops = base_ops(ty.scalar).to_vec();
+    ops.extend_from_slice(SHUFFLE_OPS);
+    // Saturating arithmetic is mostly useful for pixels and audio samples,
+    // and only has instructions for 8 and 16 bit lanes.
+    if matches!(ty.scalar, ScalarType::Int | ScalarType::Unsigned) && ty.scalar_bits <= 16 {
+        ops.push(("saturating_add", OpSig::Binary));
+        ops.push(("saturating_sub", OpSig::Binary));
+    }
+    if ty.scalar == ScalarType::Int {
+        ops.push(("abs", OpSig::Unary));
+        ops.push(("neg", OpSig::Unary));
+    }
+    if matches!(ty.scalar, ScalarType::Int | ScalarType::Unsigned) {
+        let unsigned = VecType::new(ScalarType::Unsigned, ty.scalar_bits, ty.len);
+        if SIMD_TYPES.contains(&unsigned) {
+            ops.push(("abs_diff", OpSig::BinaryTo(unsigned)));
+        }
+    }
+    if ty.scalar == ScalarType::Unsigned && ty.scalar_bits <= 16 {
+        ops.push(("avg_round", OpSig::Binary));
+    }
+    // Products in twice the lane width, mostly for fixed-point math.
+    if matches!(ty.scalar, ScalarType::Int | ScalarType::Unsigned) && ty.scalar_bits <= 16 {
+        if let Some(wide) = ty.widened() {
+            ops.push(("mul_widen", OpSig::BinaryTo(wide)));
+        }
+        ops.push(("mul_high", OpSig::Binary));
+    }
+    if ty.scalar == ScalarType::Int && ty.scalar_bits == 16 {
+        ops.push(("mul_q15_round_sat", OpSig::Binary));
+        let pairs = VecType::new(ScalarType::Int, 32, ty.len / 2);
+        ops.push(("dot_i16", OpSig::BinaryTo(pairs)));
+    }
+    if ty.scalar == ScalarType::Unsigned && ty.scalar_bits == 8 {
+        ops.push(("swizzle_dyn", OpSig::Lookup(ty.len)));
+        ops.push(("lookup_32", OpSig::Lookup(32)));
+        ops.push(("lookup_48", OpSig::Lookup(48)));
+        ops.push(("lookup_64", OpSig::Lookup(64)));
+    }
+    if SIMD_TYPES.contains(&VecType::new(ty.scalar, ty.scalar_bits, ty.len * 2)) {
         ops.push(("combine", OpSig::Combine));
     }
-    if ty.n_bits() > 128 {
+    if SIMD_TYPES.contains(&VecType::new(ty.scalar, ty.scalar_bits, ty.len / 2)) {
         ops.push(("split", OpSig::Split));
     }
 
-    if matches!(ty.scalar, ScalarType::Unsigned | ScalarType::Float) && ty.n_bits() == 512 {
+    // The interleaved ops are only provided for 8, 16 and 32 bit lanes.
+    let interleaved = matches!(ty.scalar, ScalarType::Unsigned | ScalarType::Float)
+        && ty.n_bits() == 512
+        && ty.scalar_bits <= 32;
+
+    if interleaved {
         ops.push(("load_interleaved_128", OpSig::LoadInterleaved(128, 4)));
     }
 
-    if matches!(ty.scalar, ScalarType::Unsigned | ScalarType::Float) && ty.n_bits() == 512 {
+    if interleaved {
         ops.push(("store_interleaved_128", OpSig::StoreInterleaved(128, 4)));
     }
 
     if cvt {
-        if matches!(ty.scalar, ScalarType::Unsigned) {
-            if let Some(widened) = ty.widened() {
-                ops.push(("widen", OpSig::WidenNarrow(widened)));
-            }
+        if let Some(widened) = ty.widened() {
+            ops.push(("widen", OpSig::WidenNarrow(widened)));
+        }
+        if ty.n_bits() >= 128 && ty.widened_half().is_some() {
+            ops.push(("widen_low", OpSig::WidenHalf(true)));
+            ops.push(("widen_high", OpSig::WidenHalf(false)));
+        }
+        if let Some(narrowed) = ty.narrowed() {
+            ops.push(("narrow", OpSig::WidenNarrow(narrowed)));
+            ops.push(("narrow_saturating", OpSig::WidenNarrow(narrowed)));
+        }
 
-            if let Some(narrowed) = ty.narrowed() {
-                ops.push(("narrow", OpSig::WidenNarrow(narrowed)));
+        for &(scalar, scalar_bits, name) in REINTERPRET_TARGETS {
+            if valid_reinterpret(ty, scalar, scalar_bits)
+                && SIMD_TYPES.contains(&reinterpret_ty(ty, scalar, scalar_bits))
+            {
+                ops.push((name, OpSig::Reinterpret(scalar, scalar_bits)));
             }
         }
 
-        if valid_reinterpret(ty, ScalarType::Unsigned, 8) {
-            ops.push((
-                "reinterpret_u8",
-                OpSig::Reinterpret(ScalarType::Unsigned, 8),
-            ));
+        // Every conversion between floats and integers with the same number of
+        // lanes, and between the float widths.
+        if matches!(
+            ty.scalar,
+            ScalarType::Float | ScalarType::Int | ScalarType::Unsigned
+        ) {
+            for &(scalar, scalar_bits, name, round_name) in CVT_TARGETS {
+                let to = VecType::new(scalar, scalar_bits, ty.len);
+                let is_float = |scalar| scalar == ScalarType::Float;
+                if to == *ty
+                    || !(is_float(ty.scalar) || is_float(scalar))
+                    || !SIMD_TYPES.contains(&to)
+                {
+                    continue;
+                }
+                ops.push((name, OpSig::Cvt(scalar, scalar_bits)));
+                if is_float(ty.scalar) && !is_float(scalar) {
+                    ops.push((round_name, OpSig::Cvt(scalar, scalar_bits)));
+                }
+            }
         }
-
         match (ty.scalar, ty.scalar_bits) {
+            // Normalized pixel values, mapping 0..=255 to 0.0..=1.0.
+            (ScalarType::Unsigned, 8) => {
+                if SIMD_TYPES.contains(&VecType::new(ScalarType::Float, 32, ty.len)) {
+                    ops.push(("cvt_f32_unorm", OpSig::Cvt(ScalarType::Float, 32)));
+                }
+            }
             (ScalarType::Float, 32) => {
-                ops.push(("cvt_u32", OpSig::Cvt(ScalarType::Unsigned, 32)));
-                ops.push(("cvt_i32", OpSig::Cvt(ScalarType::Int, 32)));
+                if SIMD_TYPES.contains(&VecType::new(ScalarType::BFloat, 16, ty.len)) {
+                    ops.push(("cvt_bf16", OpSig::Cvt(ScalarType::BFloat, 16)));
+                }
+            }
+            (ScalarType::BFloat, 16) => {
+                if SIMD_TYPES.contains(&VecType::new(ScalarType::Float, 32, ty.len)) {
+                    ops.push(("cvt_f32", OpSig::Cvt(ScalarType::Float, 32)));
+                }
             }
-            (ScalarType::Unsigned, 32) => ops.push(("cvt_f32", OpSig::Cvt(ScalarType::Float, 32))),
-            (ScalarType::Int, 32) => ops.push(("cvt_f32", OpSig::Cvt(ScalarType::Float, 32))),
             _ => (),
         }
     }
+
+    if ty.scalar == ScalarType::Float && ty.scalar_bits == 32 {
+        if SIMD_TYPES.contains(&bf16_pairs_ty(ty)) {
+            ops.push(("dot_bf16", OpSig::DotBf16));
+        }
+        // A 2x4 by 4x2 matrix product, like `bfmmla`.
+        if ty.n_bits() == 128 {
+            ops.push(("mmla_bf16", OpSig::DotBf16));
+        }
+    }
     ops
 }
 
+/// The bf16 vector type with two lanes for each lane of the f32 vector.
+pub fn bf16_pairs_ty(ty: &VecType) -> VecType {
+    VecType::new(ScalarType::BFloat, 16, ty.len * 2)
+}
+
+/// The type of the table of a lookup in `table_len` bytes.
+///
+/// There is no 48 byte vector, so that table is three `u8x16`, like the
+/// `uint8x16x3_t` of `vqtbl3q_u8`.
+pub fn lookup_table_ty(table_len: usize, flavor: TyFlavor) -> TokenStream {
+    let simd = match flavor {
+        TyFlavor::SimdTrait => quote! { Self },
+        TyFlavor::VecImpl => quote! { S },
+    };
+    if table_len == 48 {
+        quote! { [u8x16<#simd>; 3] }
+    } else {
+        let table_ty = VecType::new(ScalarType::Unsigned, 8, table_len).rust();
+        quote! { #table_ty<#simd> }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TyFlavor {
     /// Types for methods in the `Simd` trait; `f32x4<Self>`
@@ -178,13 +449,31 @@ impl OpSig {
             | OpSig::Split
             | OpSig::Cvt(_, _)
             | OpSig::Reinterpret(_, _)
-            | OpSig::WidenNarrow(_) => quote! { self, a: #ty<Self> },
-            OpSig::Binary | OpSig::Compare | OpSig::Combine | OpSig::Zip(_) => {
+            | OpSig::WidenNarrow(_)
+            | OpSig::WidenHalf(_)
+            | OpSig::Reduce
+            | OpSig::Classify
+            | OpSig::MaskQuery(_)
+            | OpSig::Shuffle(1)
+            | OpSig::Reverse
+            | OpSig::Rotate(_)
+            | OpSig::Broadcast => quote! { self, a: #ty<Self> },
+            OpSig::FromBitmask => quote! { self, bits: u64 },
+            OpSig::Binary
+            | OpSig::BinaryTo(_)
+            | OpSig::Compare
+            | OpSig::Combine
+            | OpSig::Zip(_)
+            | OpSig::Shuffle(_)
+            | OpSig::Unzip(_) => {
                 quote! { self, a: #ty<Self>, b: #ty<Self> }
             }
             OpSig::Shift => {
                 quote! { self, a: #ty<Self>, shift: u32 }
             }
+            OpSig::Refine => {
+                quote! { self, a: #ty<Self>, steps: u32 }
+            }
             OpSig::Ternary => {
                 quote! { self, a: #ty<Self>, b: #ty<Self>, c: #ty<Self> }
             }
@@ -192,6 +481,52 @@ impl OpSig {
                 let mask_ty = vec_ty.mask_ty().rust();
                 quote! { self, a: #mask_ty<Self>, b: #ty<Self>, c: #ty<Self> }
             }
+            OpSig::DotBf16 => {
+                let pairs_ty = bf16_pairs_ty(vec_ty).rust();
+                quote! { self, a: #ty<Self>, b: #pairs_ty<Self>, c: #pairs_ty<Self> }
+            }
+            OpSig::Lookup(table_len) => {
+                let table_ty = lookup_table_ty(*table_len, TyFlavor::SimdTrait);
+                quote! { self, a: #table_ty, b: #ty<Self> }
+            }
+        }
+    }
+
+    /// Whether the op only moves lanes around.
+    pub fn is_permutation(&self) -> bool {
+        matches!(
+            self,
+            OpSig::Shuffle(_)
+                | OpSig::Reverse
+                | OpSig::Rotate(_)
+                | OpSig::Broadcast
+                | OpSig::Unzip(_)
+        )
+    }
+
+    /// The generic parameters of the method, if any.
+    pub fn generics(&self, ty: &VecType) -> TokenStream {
+        match self {
+            OpSig::Shuffle(_) => {
+                let len = Literal::usize_unsuffixed(ty.len);
+                quote! { <I: Indices<#len>> }
+            }
+            OpSig::Rotate(_) => quote! { <const K: usize> },
+            OpSig::Broadcast => quote! { <const L: usize> },
+            _ => quote! {},
+        }
+    }
+
+    /// Whether the result is a narrower vector than the input.
+    ///
+    /// These ops can't be computed by splitting the input in half, since
+    /// the same op on the halves would produce a vector type that doesn't
+    /// exist.
+    pub fn is_narrowing(&self, ty: &VecType) -> bool {
+        match self {
+            OpSig::Cvt(_, scalar_bits) => *scalar_bits < ty.scalar_bits,
+            OpSig::WidenNarrow(to_ty) => to_ty.n_bits() < ty.n_bits(),
+            _ => false,
         }
     }
 
@@ -200,15 +535,32 @@ impl OpSig {
             OpSig::Splat | OpSig::LoadInterleaved(_, _) | OpSig::StoreInterleaved(_, _) => {
                 return None;
             }
-            OpSig::Unary | OpSig::Cvt(_, _) | OpSig::Reinterpret(_, _) | OpSig::WidenNarrow(_) => {
+            OpSig::Unary
+            | OpSig::Cvt(_, _)
+            | OpSig::Reinterpret(_, _)
+            | OpSig::WidenNarrow(_)
+            | OpSig::WidenHalf(_)
+            | OpSig::Reduce
+            | OpSig::Classify
+            | OpSig::MaskQuery(_)
+            | OpSig::Reverse => {
                 quote! { self }
             }
-            OpSig::Binary | OpSig::Compare | OpSig::Zip(_) | OpSig::Combine => {
+            OpSig::FromBitmask => quote! { simd: S, bits: u64 },
+            OpSig::Binary
+            | OpSig::BinaryTo(_)
+            | OpSig::Compare
+            | OpSig::Zip(_)
+            | OpSig::Combine
+            | OpSig::Unzip(_) => {
                 quote! { self, rhs: impl SimdInto<Self, S> }
             }
             OpSig::Shift => {
                 quote! { self, shift: u32 }
             }
+            OpSig::Refine => {
+                quote! { self, steps: u32 }
+            }
             OpSig::Ternary => {
                 quote! { self, op1: impl SimdInto<Self, S>, op2: impl SimdInto<Self, S> }
             }
@@ -216,7 +568,9 @@ impl OpSig {
             // masks.
             OpSig::Select => return None,
             // These signatures involve types not in the Simd trait
-            OpSig::Split => return None,
+            OpSig::Split | OpSig::DotBf16 | OpSig::Lookup(_) => return None,
+            // The indices are a generic parameter.
+            OpSig::Shuffle(_) | OpSig::Rotate(_) | OpSig::Broadcast => return None,
         };
         Some(args)
     }
@@ -233,11 +587,20 @@ impl OpSig {
             | OpSig::Select
             | OpSig::Ternary
             | OpSig::Shift
+            | OpSig::Refine
+            | OpSig::DotBf16
+            | OpSig::FromBitmask
+            | OpSig::Shuffle(_)
+            | OpSig::Reverse
+            | OpSig::Rotate(_)
+            | OpSig::Broadcast
+            | OpSig::Unzip(_)
+            | OpSig::Lookup(_)
             | OpSig::LoadInterleaved(_, _) => {
                 let rust = ty.rust();
                 quote! { #rust #quant }
             }
-            OpSig::Compare => {
+            OpSig::Compare | OpSig::Classify => {
                 let rust = ty.mask_ty().rust();
                 quote! { #rust #quant }
             }
@@ -263,10 +626,16 @@ impl OpSig {
                 let result = reinterpret_ty(ty, *scalar, *scalar_bits).rust();
                 quote! { #result #quant }
             }
-            OpSig::WidenNarrow(t) => {
+            OpSig::WidenNarrow(t) | OpSig::BinaryTo(t) => {
                 let result = t.rust();
                 quote! { #result #quant }
             }
+            OpSig::WidenHalf(_) => {
+                let result = ty.widened_half().unwrap().rust();
+                quote! { #result #quant }
+            }
+            OpSig::Reduce => ty.scalar.rust(ty.scalar_bits),
+            OpSig::MaskQuery(ret) => ret.rust(),
             OpSig::StoreInterleaved(_, _) => quote! {()},
         }
     }
@@ -294,9 +663,10 @@ pub(crate) fn valid_reinterpret(src: &VecType, dst_scalar: ScalarType, dst_bits:
         return false;
     }
 
-    if matches!(src.scalar, ScalarType::Mask | ScalarType::Float) {
-        return false;
+    // Not every bit pattern is a valid mask, and masks only reinterpret to integers.
+    match (src.scalar, dst_scalar) {
+        (_, ScalarType::Mask) => false,
+        (ScalarType::Mask, dst) => matches!(dst, ScalarType::Int | ScalarType::Unsigned),
+        _ => true,
     }
-
-    true
 }
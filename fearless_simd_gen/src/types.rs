@@ -7,6 +7,8 @@ use quote::quote;
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ScalarType {
     Float,
+    /// The bfloat16 format, which is only used for storage and conversions.
+    BFloat,
     Unsigned,
     Int,
     Mask,
@@ -23,6 +25,7 @@ impl ScalarType {
     pub fn rust(&self, scalar_bits: usize) -> TokenStream {
         let scalar = match self {
             ScalarType::Float => "f",
+            ScalarType::BFloat => "bf",
             ScalarType::Unsigned => "u",
             ScalarType::Int | ScalarType::Mask => "i",
         };
@@ -30,6 +33,16 @@ impl ScalarType {
         let ident = Ident::new(&name, Span::call_site());
         quote! { #ident }
     }
+
+    /// A zero value of the scalar type, usable in a const context.
+    pub fn zero(&self, scalar_bits: usize) -> TokenStream {
+        match (self, scalar_bits) {
+            (ScalarType::Float, 16) => quote! { f16::from_bits(0) },
+            (ScalarType::BFloat, _) => quote! { bf16::from_bits(0) },
+            (ScalarType::Float, _) => quote! { 0.0 },
+            _ => quote! { 0 },
+        }
+    }
 }
 
 impl VecType {
@@ -49,6 +62,7 @@ impl VecType {
     pub fn rust_name(&self) -> String {
         let scalar = match self.scalar {
             ScalarType::Float => "f",
+            ScalarType::BFloat => "bf",
             ScalarType::Unsigned => "u",
             ScalarType::Int => "i",
             ScalarType::Mask => "mask",
@@ -62,28 +76,33 @@ impl VecType {
         quote! { #ident }
     }
 
+    /// The integer type with lanes of twice the width, if there is one.
     pub fn widened(&self) -> Option<VecType> {
-        if matches!(self.scalar, ScalarType::Mask | ScalarType::Float)
-            || self.n_bits() > 256
-            || self.scalar_bits != 8
-        {
-            return None;
-        }
+        self.int_with_lanes(self.scalar_bits * 2, self.len)
+    }
 
-        let scalar_bits = self.scalar_bits * 2;
-        Some(Self::new(self.scalar, scalar_bits, self.len))
+    /// The integer type with lanes of twice the width and half as many of
+    /// them, which holds one half of this vector widened.
+    pub fn widened_half(&self) -> Option<VecType> {
+        self.int_with_lanes(self.scalar_bits * 2, self.len / 2)
     }
 
+    /// The integer type with lanes of half the width, if there is one.
     pub fn narrowed(&self) -> Option<VecType> {
-        if matches!(self.scalar, ScalarType::Mask | ScalarType::Float)
-            || self.n_bits() < 256
-            || self.scalar_bits != 16
-        {
+        self.int_with_lanes(self.scalar_bits / 2, self.len)
+    }
+
+    fn int_with_lanes(&self, scalar_bits: usize, len: usize) -> Option<VecType> {
+        if !matches!(self.scalar, ScalarType::Unsigned | ScalarType::Int) {
             return None;
         }
+        let ty = Self::new(self.scalar, scalar_bits, len);
+        SIMD_TYPES.contains(&ty).then_some(ty)
+    }
 
-        let scalar_bits = self.scalar_bits / 2;
-        Some(Self::new(self.scalar, scalar_bits, self.len))
+    /// Whether this is a vector of f16 elements.
+    pub fn is_f16(&self) -> bool {
+        self.scalar == ScalarType::Float && self.scalar_bits == 16
     }
 
     pub fn mask_ty(&self) -> Self {
@@ -92,7 +111,17 @@ impl VecType {
 }
 
 pub const SIMD_TYPES: &[VecType] = &[
+    // 64 bit types
+    VecType::new(ScalarType::Float, 32, 2),
+    VecType::new(ScalarType::Unsigned, 8, 8),
+    VecType::new(ScalarType::Mask, 8, 8),
+    VecType::new(ScalarType::Unsigned, 16, 4),
+    VecType::new(ScalarType::Mask, 16, 4),
+    VecType::new(ScalarType::Int, 32, 2),
+    VecType::new(ScalarType::Mask, 32, 2),
     // 128 bit types
+    VecType::new(ScalarType::Float, 16, 8),
+    VecType::new(ScalarType::BFloat, 16, 8),
     VecType::new(ScalarType::Float, 32, 4),
     VecType::new(ScalarType::Int, 8, 16),
     VecType::new(ScalarType::Unsigned, 8, 16),
@@ -103,7 +132,13 @@ pub const SIMD_TYPES: &[VecType] = &[
     VecType::new(ScalarType::Int, 32, 4),
     VecType::new(ScalarType::Unsigned, 32, 4),
     VecType::new(ScalarType::Mask, 32, 4),
+    VecType::new(ScalarType::Float, 64, 2),
+    VecType::new(ScalarType::Int, 64, 2),
+    VecType::new(ScalarType::Unsigned, 64, 2),
+    VecType::new(ScalarType::Mask, 64, 2),
     // 256 bit types
+    VecType::new(ScalarType::Float, 16, 16),
+    VecType::new(ScalarType::BFloat, 16, 16),
     VecType::new(ScalarType::Float, 32, 8),
     VecType::new(ScalarType::Int, 8, 32),
     VecType::new(ScalarType::Unsigned, 8, 32),
@@ -114,6 +149,10 @@ pub const SIMD_TYPES: &[VecType] = &[
     VecType::new(ScalarType::Int, 32, 8),
     VecType::new(ScalarType::Unsigned, 32, 8),
     VecType::new(ScalarType::Mask, 32, 8),
+    VecType::new(ScalarType::Float, 64, 4),
+    VecType::new(ScalarType::Int, 64, 4),
+    VecType::new(ScalarType::Unsigned, 64, 4),
+    VecType::new(ScalarType::Mask, 64, 4),
     // 512 bit types
     VecType::new(ScalarType::Float, 32, 16),
     VecType::new(ScalarType::Int, 8, 64),
@@ -125,6 +164,10 @@ pub const SIMD_TYPES: &[VecType] = &[
     VecType::new(ScalarType::Int, 32, 16),
     VecType::new(ScalarType::Unsigned, 32, 16),
     VecType::new(ScalarType::Mask, 32, 16),
+    VecType::new(ScalarType::Float, 64, 8),
+    VecType::new(ScalarType::Int, 64, 8),
+    VecType::new(ScalarType::Unsigned, 64, 8),
+    VecType::new(ScalarType::Mask, 64, 8),
 ];
 
 pub fn type_imports() -> TokenStream {
@@ -133,5 +176,5 @@ pub fn type_imports() -> TokenStream {
         let ident = ty.rust();
         imports.push(quote! { #ident });
     }
-    quote! { use crate::{ #( #imports ),* }; }
+    quote! { use crate::{ bf16, f16, #( #imports ),* }; }
 }
@@ -9,6 +9,20 @@ wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 mod wasm;
 
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+/// Run `f` on the best level, the fallback level and, on x86, every x86
+/// level the CPU supports.
+fn for_each_level(f: impl Fn(Level)) {
+    f(Level::new());
+    f(Level::fallback());
+    #[cfg(target_arch = "x86_64")]
+    for level in x86::x86_levels() {
+        f(level);
+    }
+}
+
 #[test]
 fn saturate_float_to_int() {
     #[inline(always)]
@@ -34,3 +48,1931 @@ fn saturate_float_to_int() {
     simd_dispatch!(helper(level) = helper_inner);
     helper(Level::new());
 }
+
+#[test]
+fn cvt_matrix() {
+    // Every conversion matches `as`, which saturates and maps NaN to zero, with
+    // the lanes of f16 going through f32, which holds them exactly.
+    const FLOATS: [f64; 16] = [
+        f64::INFINITY,
+        -f64::INFINITY,
+        f64::NAN,
+        3e9,
+        -3e9,
+        1e20,
+        -1e20,
+        255.5,
+        -128.5,
+        0.5,
+        1.5,
+        -2.5,
+        65535.6,
+        -32768.7,
+        127.49,
+        1.8446744073709552e19,
+    ];
+    const INTS: [u64; 16] = [
+        0,
+        1,
+        u64::MAX,
+        1 << 63,
+        (1 << 63) - 1,
+        0x80,
+        0x7f,
+        0xff,
+        0x8000,
+        0x7fff,
+        0xffff,
+        0x8000_0000,
+        0x7fff_ffff,
+        0xffff_ffff,
+        (1 << 53) + 1,
+        0x0123_4567_89ab_cdef,
+    ];
+
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        macro_rules! from_float {
+            ($ty:ident, $scalar:ident, $n:literal; $( $cvt:ident, $round:ident -> $to:ty ),*) => {
+                for start in 0..16 {
+                    let vals: [$scalar; $n] =
+                        core::array::from_fn(|i| FLOATS[(start + i) % 16] as $scalar);
+                    let a = $ty::from_slice(simd, &vals);
+                    $(
+                        assert_eq!(*a.$cvt(), vals.map(|x| x as $to), stringify!($ty::$cvt));
+                        assert_eq!(
+                            *a.$round(),
+                            vals.map(|x| x.round_ties_even() as $to),
+                            stringify!($ty::$round)
+                        );
+                    )*
+                }
+            };
+        }
+        macro_rules! from_f16 {
+            ($ty:ident, $n:literal; $( $cvt:ident, $round:ident -> $to:ty ),*) => {
+                for start in 0..16 {
+                    let vals: [f32; $n] = core::array::from_fn(|i| {
+                        f16::from_f32_const(FLOATS[(start + i) % 16] as f32).to_f32()
+                    });
+                    let a = $ty::from_slice(simd, &vals.map(f16::from_f32_const));
+                    $(
+                        assert_eq!(*a.$cvt(), vals.map(|x| x as $to), stringify!($ty::$cvt));
+                        assert_eq!(
+                            *a.$round(),
+                            vals.map(|x| x.round_ties_even() as $to),
+                            stringify!($ty::$round)
+                        );
+                    )*
+                }
+            };
+        }
+        macro_rules! from_int {
+            ($ty:ident, $scalar:ident, $n:literal; $( $cvt:ident -> $to:ty ),* $(; $cvt_f16:ident)?) => {
+                for start in 0..16 {
+                    let vals: [$scalar; $n] =
+                        core::array::from_fn(|i| INTS[(start + i) % 16] as $scalar);
+                    let a = $ty::from_slice(simd, &vals);
+                    $(
+                        assert_eq!(*a.$cvt(), vals.map(|x| x as $to), stringify!($ty::$cvt));
+                    )*
+                    $(
+                        assert_eq!(
+                            *a.$cvt_f16(),
+                            vals.map(|x| f16::from_f32_const(x as f32)),
+                            stringify!($ty::$cvt_f16)
+                        );
+                    )?
+                }
+            };
+        }
+
+        from_float!(f32x2, f32, 2;
+            cvt_i32, cvt_i32_round -> i32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_float!(f32x4, f32, 4;
+            cvt_u16, cvt_u16_round -> u16, cvt_i32, cvt_i32_round -> i32,
+            cvt_u32, cvt_u32_round -> u32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_float!(f32x8, f32, 8;
+            cvt_u8, cvt_u8_round -> u8, cvt_i16, cvt_i16_round -> i16,
+            cvt_u16, cvt_u16_round -> u16, cvt_i32, cvt_i32_round -> i32,
+            cvt_u32, cvt_u32_round -> u32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_float!(f32x16, f32, 16;
+            cvt_i8, cvt_i8_round -> i8, cvt_u8, cvt_u8_round -> u8,
+            cvt_i16, cvt_i16_round -> i16, cvt_u16, cvt_u16_round -> u16,
+            cvt_i32, cvt_i32_round -> i32, cvt_u32, cvt_u32_round -> u32);
+        from_float!(f64x2, f64, 2;
+            cvt_i32, cvt_i32_round -> i32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_float!(f64x4, f64, 4;
+            cvt_u16, cvt_u16_round -> u16, cvt_i32, cvt_i32_round -> i32,
+            cvt_u32, cvt_u32_round -> u32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_float!(f64x8, f64, 8;
+            cvt_u8, cvt_u8_round -> u8, cvt_i16, cvt_i16_round -> i16,
+            cvt_u16, cvt_u16_round -> u16, cvt_i32, cvt_i32_round -> i32,
+            cvt_u32, cvt_u32_round -> u32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_f16!(f16x8, 8;
+            cvt_u8, cvt_u8_round -> u8, cvt_i16, cvt_i16_round -> i16,
+            cvt_u16, cvt_u16_round -> u16, cvt_i32, cvt_i32_round -> i32,
+            cvt_u32, cvt_u32_round -> u32, cvt_i64, cvt_i64_round -> i64,
+            cvt_u64, cvt_u64_round -> u64);
+        from_f16!(f16x16, 16;
+            cvt_i8, cvt_i8_round -> i8, cvt_u8, cvt_u8_round -> u8,
+            cvt_i16, cvt_i16_round -> i16, cvt_u16, cvt_u16_round -> u16,
+            cvt_i32, cvt_i32_round -> i32, cvt_u32, cvt_u32_round -> u32);
+
+        from_int!(i32x2, i32, 2; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(i64x2, i64, 2; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(u64x2, u64, 2; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(u16x4, u16, 4; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(i32x4, i32, 4; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(u32x4, u32, 4; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(i64x4, i64, 4; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(u64x4, u64, 4; cvt_f32 -> f32, cvt_f64 -> f64);
+        from_int!(u8x8, u8, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(i16x8, i16, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(u16x8, u16, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(i32x8, i32, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(u32x8, u32, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(i64x8, i64, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(u64x8, u64, 8; cvt_f32 -> f32, cvt_f64 -> f64; cvt_f16);
+        from_int!(i8x16, i8, 16; cvt_f32 -> f32; cvt_f16);
+        from_int!(u8x16, u8, 16; cvt_f32 -> f32; cvt_f16);
+        from_int!(i16x16, i16, 16; cvt_f32 -> f32; cvt_f16);
+        from_int!(u16x16, u16, 16; cvt_f32 -> f32; cvt_f16);
+        from_int!(i32x16, i32, 16; cvt_f32 -> f32; cvt_f16);
+        from_int!(u32x16, u32, 16; cvt_f32 -> f32; cvt_f16);
+
+        // The float widths that don't go through f32 natively. f64 rounds to
+        // f16 only once.
+        let f64s: [f64; 8] = core::array::from_fn(|i| {
+            [1.0009765625, -3.0517578125e-5, 1e6, 0.1][i % 4] * (i + 1) as f64
+        });
+        let a = f64x8::from_slice(simd, &f64s);
+        assert_eq!(*a.cvt_f16(), f64s.map(f16::from_f64_const));
+        let h = f16x8::from_slice(simd, &f64s.map(f16::from_f64_const));
+        assert_eq!(*h.cvt_f64(), h.val.map(|x| x.to_f32() as f64));
+
+        // Decoding 8 bit channels to 0..1 and back.
+        for chunk in (0..=255).collect::<Vec<u8>>().chunks_exact(16) {
+            let unorm = u8x16::from_slice(simd, chunk).cvt_f32_unorm();
+            assert_eq!(*unorm, core::array::from_fn(|i| chunk[i] as f32 / 255.0));
+            assert_eq!(*(unorm * 255.0).cvt_u8_round(), chunk);
+        }
+        let u8s: [u8; 8] = [0, 1, 2, 127, 128, 200, 254, 255];
+        let unorm = u8x8::from_slice(simd, &u8s).cvt_f32_unorm();
+        assert_eq!(*unorm, u8s.map(|x| x as f32 / 255.0));
+        assert_eq!((unorm[0], unorm[7]), (0.0, 1.0));
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+#[test]
+fn reinterpret_casts() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let f32s = [
+            1.5,
+            -0.0,
+            f32::INFINITY,
+            -3.25e-20,
+            7.0,
+            f32::MIN,
+            0.1,
+            -1.0,
+        ];
+        let a = f32x8::from_slice(simd, &f32s);
+        assert_eq!(*a.reinterpret_u32(), f32s.map(f32::to_bits));
+        assert_eq!(*a.reinterpret_i32(), f32s.map(|x| x.to_bits() as i32));
+        assert_eq!(*a.reinterpret_u32().reinterpret_f32(), f32s);
+        let bytes: Vec<u8> = f32s
+            .iter()
+            .flat_map(|x| x.to_bits().to_ne_bytes())
+            .collect();
+        assert_eq!(*a.reinterpret_u8(), bytes[..]);
+        assert_eq!(*a.reinterpret_f64().reinterpret_u8(), bytes[..]);
+        assert_eq!(*a.reinterpret_i16().reinterpret_f32(), f32s);
+
+        // Sign manipulation, as in the bit-trick float code.
+        let a = f32x16::from_slice(
+            simd,
+            &core::array::from_fn::<f32, 16, _>(|i| i as f32 - 8.5),
+        );
+        let abs = (a.reinterpret_u32() & u32x16::splat(simd, 0x7fff_ffff)).reinterpret_f32();
+        assert_eq!(*abs, a.val.map(f32::abs));
+        let exponents = (a.reinterpret_i32() >> 23) & i32x16::splat(simd, 0xff);
+        assert_eq!(
+            *exponents,
+            a.val.map(|x| ((x.to_bits() >> 23) & 0xff) as i32)
+        );
+
+        // The 64 bit vectors.
+        let a = f32x2::from_slice(simd, &[-2.0, 0.5]);
+        assert_eq!(
+            *a.reinterpret_i32(),
+            [(-2.0f32).to_bits() as i32, 0.5f32.to_bits() as i32]
+        );
+        assert_eq!(*a.reinterpret_u16().reinterpret_f32(), [-2.0, 0.5]);
+
+        // The 16 bit floats.
+        let bits: [u16; 8] = [
+            0x3c00, 0xc000, 0x7c00, 0x0001, 0x8000, 0x7bff, 0x3555, 0x7e00,
+        ];
+        let h = u16x8::from_slice(simd, &bits).reinterpret_f16();
+        assert_eq!(h.val.map(f16::to_bits), bits);
+        assert_eq!(*h.reinterpret_u16(), bits);
+        assert_eq!(
+            *h.reinterpret_bf16().reinterpret_i16(),
+            bits.map(|x| x as i16)
+        );
+        let b = u16x16::from_slice(simd, &[bits, bits].concat()).reinterpret_bf16();
+        assert_eq!(
+            *b.reinterpret_u16(),
+            *u16x16::from_slice(simd, &[bits, bits].concat())
+        );
+
+        // Masks become all-zero or all-one integers.
+        let a = f64x4::from_slice(simd, &[1.0, -1.0, f64::NAN, -0.0]);
+        let mask = a.simd_lt(f64x4::splat(simd, 0.0));
+        assert_eq!(*mask.reinterpret_u64(), [0, u64::MAX, 0, 0]);
+        assert_eq!(
+            *mask.reinterpret_i8(),
+            [[0; 8], [-1; 8], [0; 8], [0; 8]].concat()[..]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+#[test]
+fn f16_arithmetic() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let h = |x: f32| f16::from_f32_const(x);
+        let a = f16x8::from_slice(
+            simd,
+            &[1.5, -2.0, 0.25, 3.0, -0.5, 8.0, 65504.0, 0.0].map(h),
+        );
+        let b = f16x8::from_slice(simd, &[2.0, 0.5, -4.0, 3.0, 1.0, 0.125, 2.0, -1.0].map(h));
+        assert_eq!(
+            <[f16; 8]>::from(a + b),
+            [3.5, -1.5, -3.75, 6.0, 0.5, 8.125, 65504.0, -1.0].map(h)
+        );
+        assert_eq!(
+            <[f16; 8]>::from(a * b),
+            [3.0, -1.0, -1.0, 9.0, -0.5, 1.0, f32::INFINITY, -0.0].map(h)
+        );
+        assert_eq!(
+            <[f16; 8]>::from(a.madd(b, b)),
+            [5.5, -1.75, 16.25, 12.0, 0.5, 8.015625, 65504.0, 1.0].map(h)
+        );
+        assert_eq!(
+            <[f16; 8]>::from(a.floor()),
+            [1.0, -2.0, 0.0, 3.0, -1.0, 8.0, 65504.0, 0.0].map(h)
+        );
+        assert_eq!(<[i16; 8]>::from(a.simd_lt(b)), [-1, -1, 0, 0, -1, 0, 0, 0]);
+        assert_eq!(
+            <[f16; 8]>::from(a.zip_high(b)),
+            [-0.5, 1.0, 8.0, 0.125, 65504.0, 2.0, 0.0, -1.0].map(h)
+        );
+        assert_eq!(
+            <[f16; 8]>::from(a.simd_lt(b).select(a, b)),
+            [1.5, -2.0, -4.0, 3.0, -0.5, 0.125, 2.0, -1.0].map(h)
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn f16_conversions() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let h = |x: f32| f16::from_f32_const(x);
+        // Ties round to even, and values past the largest f16 overflow to infinity.
+        let a = f32x8::from_slice(
+            simd,
+            &[
+                1.0,
+                -2.5,
+                65504.0,
+                65520.0,
+                1.00048828125,
+                1.00146484375,
+                5.9604645e-8,
+                -0.0,
+            ],
+        );
+        let narrowed = a.cvt_f16();
+        assert_eq!(
+            <[f16; 8]>::from(narrowed),
+            [
+                1.0,
+                -2.5,
+                65504.0,
+                f32::INFINITY,
+                1.0,
+                1.001953125,
+                5.9604645e-8,
+                -0.0
+            ]
+            .map(h)
+        );
+        assert_eq!(
+            <[f32; 8]>::from(narrowed.cvt_f32()),
+            [
+                1.0,
+                -2.5,
+                65504.0,
+                f32::INFINITY,
+                1.0,
+                1.001953125,
+                5.9604645e-8,
+                -0.0
+            ]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn bf16_conversions() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // Ties round to even, and values past the largest bf16 overflow to infinity.
+        let a = f32x8::from_slice(
+            simd,
+            &[
+                1.0,
+                -2.5,
+                1.00390625,
+                1.01171875,
+                f32::MAX,
+                1e-40,
+                f32::NEG_INFINITY,
+                -0.0,
+            ],
+        );
+        let narrowed = a.cvt_bf16();
+        assert_eq!(
+            <[u16; 8]>::from(narrowed.val.map(bf16::to_bits)),
+            [
+                0x3f80, 0xc020, 0x3f80, 0x3f82, 0x7f80, 0x0001, 0xff80, 0x8000
+            ]
+        );
+        assert_eq!(
+            <[f32; 8]>::from(narrowed.cvt_f32()),
+            [
+                1.0,
+                -2.5,
+                1.0,
+                1.015625,
+                f32::INFINITY,
+                9.18355e-41,
+                f32::NEG_INFINITY,
+                -0.0
+            ]
+        );
+        let nan = simd.splat_f32x8(f32::NAN).cvt_bf16().cvt_f32();
+        assert!(nan.val.iter().all(|x| x.is_nan()));
+
+        let b = bf16x8::from_slice(
+            simd,
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].map(bf16::from_f32_const),
+        );
+        let acc = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            <[f32; 4]>::from(acc.dot_bf16(b, b)),
+            [6.0, 27.0, 64.0, 117.0]
+        );
+        assert_eq!(
+            <[f32; 4]>::from(acc.mmla_bf16(b, b)),
+            [31.0, 72.0, 73.0, 178.0]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn f64_arithmetic() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = f64x4::from_slice(simd, &[1.5, -2.25, 1e300, 0.1]);
+        let b = f64x4::from_slice(simd, &[2.0, 0.5, 1e10, -3.0]);
+        assert_eq!(<[f64; 4]>::from(a + b), [3.5, -1.75, 1e300, -2.9]);
+        assert_eq!(
+            <[f64; 4]>::from(a * b),
+            [3.0, -1.125, f64::INFINITY, -0.30000000000000004]
+        );
+        assert_eq!(<[f64; 4]>::from(a.floor()), [1.0, -3.0, 1e300, 0.0]);
+        assert_eq!(<[f64; 4]>::from(a.copysign(b)), [1.5, 2.25, 1e300, -0.1]);
+        assert_eq!(<[i64; 4]>::from(a.simd_lt(b)), [-1, -1, 0, 0]);
+        assert_eq!(
+            <[f32; 4]>::from(a.cvt_f32()),
+            [1.5, -2.25, f32::INFINITY, 0.1]
+        );
+        let c = f32x4::from_slice(simd, &[0.1, -0.0, f32::MAX, 3.0]);
+        assert_eq!(
+            <[f64; 4]>::from(c.cvt_f64()),
+            [0.1_f32 as f64, -0.0, f32::MAX as f64, 3.0]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn int64_arithmetic() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = u64x2::from_slice(simd, &[u64::MAX, 1 << 40]);
+        let b = u64x2::from_slice(simd, &[3, (1 << 40) + 5]);
+        assert_eq!(<[u64; 2]>::from(a * b), [u64::MAX - 2, (1 << 40) * 5]);
+        assert_eq!(<[u64; 2]>::from(simd.min_u64x2(a, b)), [3, 1 << 40]);
+        assert_eq!(<[i64; 2]>::from(a.simd_gt(b)), [-1, 0]);
+        assert_eq!(<[u64; 2]>::from(a.shr(36)), [u64::MAX >> 36, 16]);
+        let c = i64x2::from_slice(simd, &[i64::MIN, -5]);
+        let d = i64x2::from_slice(simd, &[7, -6]);
+        assert_eq!(<[i64; 2]>::from(c - d), [i64::MAX - 6, 1]);
+        assert_eq!(<[i64; 2]>::from(c * d), [i64::MIN, 30]);
+        assert_eq!(<[i64; 2]>::from(simd.max_i64x2(c, d)), [7, -5]);
+        assert_eq!(<[i64; 2]>::from(c.simd_le(d)), [-1, 0]);
+        assert_eq!(<[i64; 2]>::from(c.shr(2)), [i64::MIN >> 2, -2]);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn half_register_types() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = f32x2::from_slice(simd, &[1.5, -2.0]);
+        let b = f32x2::from_slice(simd, &[0.5, 3.0]);
+        assert_eq!(<[f32; 2]>::from(a * b + a), [2.25, -8.0]);
+        assert_eq!(<[f32; 2]>::from(a.simd_lt(b).select(a, b)), [0.5, -2.0]);
+        assert_eq!(<[f64; 2]>::from(a.cvt_f64()), [1.5, -2.0]);
+        let c = f64x2::from_slice(simd, &[0.25, -1e300]);
+        assert_eq!(<[f32; 2]>::from(c.cvt_f32()), [0.25, f32::NEG_INFINITY]);
+        let d = i32x2::from_slice(simd, &[-7, 1 << 24]);
+        assert_eq!(<[f32; 2]>::from(d.cvt_f32()), [-7.0, 16777216.0]);
+
+        let lo = u8x8::from_slice(simd, &[0, 1, 2, 3, 4, 5, 6, 7]);
+        let hi = u8x8::from_slice(simd, &[8, 9, 10, 11, 12, 13, 14, 255]);
+        assert_eq!(
+            <[u8; 8]>::from(lo.zip_high(hi)),
+            [4, 12, 5, 13, 6, 14, 7, 255]
+        );
+        assert_eq!(
+            <[u16; 8]>::from(simd.widen_u8x8(hi)),
+            [8, 9, 10, 11, 12, 13, 14, 255]
+        );
+        let both = lo.combine(hi);
+        assert_eq!(
+            <[u8; 16]>::from(both),
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 255]
+        );
+        let (x, y) = both.split();
+        assert_eq!(<[u8; 8]>::from(x), <[u8; 8]>::from(lo));
+        assert_eq!(<[u8; 8]>::from(y), <[u8; 8]>::from(hi));
+
+        let e = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+        let (e0, e1) = e.split();
+        assert_eq!(<[f32; 2]>::from(e0), [1.0, 2.0]);
+        assert_eq!(<[f32; 2]>::from(e1), [3.0, 4.0]);
+        assert_eq!(<[f32; 2]>::from(f32x2::block_splat(e)), [1.0, 2.0]);
+        let f = u16x4::from_slice(simd, &[1, 2, 0x8000, u16::MAX]);
+        assert_eq!(<[u16; 4]>::from(f.shr(1)), [0, 1, 0x4000, 0x7fff]);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn integer_shifts() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = u8x16::from_slice(
+            simd,
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0xff],
+        );
+        assert_eq!(
+            <[u8; 16]>::from(a << 3),
+            [
+                8, 16, 24, 32, 40, 48, 56, 64, 72, 80, 88, 96, 104, 112, 120, 0xf8
+            ]
+        );
+        let b = i16x8::from_slice(simd, &[1, -1, 0x4000, -0x8000, 7, -7, 0, 3]);
+        let counts = i16x8::from_slice(simd, &[0, 1, 1, 15, 2, 2, 5, 14]);
+        assert_eq!(
+            <[i16; 8]>::from(b << counts),
+            [1, -2, -0x8000, 0, 28, -28, 0, -0x4000]
+        );
+        assert_eq!(
+            <[i16; 8]>::from(b >> counts),
+            [1, -1, 0x2000, -1, 1, -2, 0, 0]
+        );
+        let c = u32x8::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, u32::MAX]);
+        let counts = u32x8::from_slice(simd, &[31, 0, 1, 2, 3, 4, 5, 31]);
+        assert_eq!(
+            <[u32; 8]>::from(c.shl_var(counts)),
+            [1 << 31, 2, 6, 16, 40, 96, 224, 1 << 31]
+        );
+        assert_eq!(
+            <[u32; 8]>::from(c.shr_var(counts)),
+            [0, 2, 1, 1, 0, 0, 0, 1]
+        );
+        let d = i64x2::from_slice(simd, &[i64::MIN, 5]);
+        let counts = i64x2::from_slice(simd, &[63, 1]);
+        assert_eq!(<[i64; 2]>::from(d >> counts), [-1, 2]);
+        assert_eq!(<[i64; 2]>::from(d << 1), [0, 10]);
+        let e = u16x4::from_slice(simd, &[1, 2, 3, 0xffff]);
+        assert_eq!(<[u16; 4]>::from(e << 4), [16, 32, 48, 0xfff0]);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn shift_counts_at_least_width() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // Counts of at least the lane width shift out every bit, leaving 0 or
+        // copies of the sign bit.
+        macro_rules! check {
+            ($ty:ident, $scalar:ty, $n:literal) => {{
+                let bits = <$scalar>::BITS;
+                let x = 0xa5a5_a5a5_a5a5_a5a5_u64 as $scalar;
+                let signed = <$scalar>::MIN != 0;
+                let shl = |c: u32| if c >= bits { 0 } else { x << c };
+                let shr = |c: u32| match (signed, c >= bits) {
+                    (true, _) => x >> c.min(bits - 1),
+                    (false, true) => 0,
+                    (false, false) => x >> c,
+                };
+                let counts = [0, bits - 1, bits, 255];
+                let a = $ty::splat(simd, x);
+                for c in counts {
+                    assert_eq!(*(a << c), [shl(c); $n], "{} << {c}", stringify!($ty));
+                    assert_eq!(*(a >> c), [shr(c); $n], "{} >> {c}", stringify!($ty));
+                }
+                let lanes: [$scalar; $n] = core::array::from_fn(|i| counts[i % 4] as $scalar);
+                let b = $ty::from_slice(simd, &lanes);
+                let shl_var: [$scalar; $n] = core::array::from_fn(|i| shl(counts[i % 4]));
+                let shr_var: [$scalar; $n] = core::array::from_fn(|i| shr(counts[i % 4]));
+                assert_eq!(*(a << b), shl_var, "{} << vector", stringify!($ty));
+                assert_eq!(*(a >> b), shr_var, "{} >> vector", stringify!($ty));
+            }};
+        }
+        check!(u8x8, u8, 8);
+        check!(u8x16, u8, 16);
+        check!(i8x16, i8, 16);
+        check!(u16x8, u16, 8);
+        check!(i16x8, i16, 8);
+        check!(u32x4, u32, 4);
+        check!(i32x4, i32, 4);
+        check!(u64x2, u64, 2);
+        check!(i64x2, i64, 2);
+        check!(i8x32, i8, 32);
+        check!(u16x16, u16, 16);
+        check!(i32x8, i32, 8);
+        check!(i64x4, i64, 4);
+        check!(u8x64, u8, 64);
+        check!(i16x32, i16, 32);
+        check!(u64x8, u64, 8);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+#[test]
+fn saturating_arithmetic() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = u8x16::from_slice(
+            simd,
+            &[0, 1, 100, 200, 255, 128, 127, 50, 0, 0, 0, 0, 0, 0, 0, 9],
+        );
+        let b = u8x16::from_slice(
+            simd,
+            &[0, 255, 100, 100, 1, 128, 128, 60, 1, 2, 3, 4, 5, 6, 7, 8],
+        );
+        assert_eq!(
+            <[u8; 16]>::from(a.saturating_add(b)),
+            [
+                0, 255, 200, 255, 255, 255, 255, 110, 1, 2, 3, 4, 5, 6, 7, 17
+            ]
+        );
+        assert_eq!(
+            <[u8; 16]>::from(a.saturating_sub(b)),
+            [0, 0, 0, 100, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]
+        );
+        let c = i16x8::from_slice(simd, &[i16::MAX, i16::MIN, 5, -5, 30000, -30000, 0, 1]);
+        let d = i16x8::from_slice(simd, &[1, -1, -10, 10, 30000, 30000, i16::MIN, i16::MAX]);
+        assert_eq!(
+            <[i16; 8]>::from(c.saturating_add(d)),
+            [i16::MAX, i16::MIN, -5, 5, i16::MAX, 0, i16::MIN, i16::MAX]
+        );
+        assert_eq!(
+            <[i16; 8]>::from(c.saturating_sub(d)),
+            [
+                i16::MAX - 1,
+                i16::MIN + 1,
+                15,
+                -15,
+                0,
+                i16::MIN,
+                i16::MAX,
+                1 - i16::MAX
+            ]
+        );
+        let e = u16x16::from_slice(
+            simd,
+            &[
+                0,
+                1,
+                254,
+                255,
+                256,
+                0x7fff,
+                0x8000,
+                u16::MAX,
+                3,
+                300,
+                0x1ff,
+                0x100,
+                77,
+                0xff00,
+                12,
+                255,
+            ],
+        );
+        assert_eq!(
+            <[u8; 16]>::from(simd.narrow_saturating_u16x16(e)),
+            [
+                0, 1, 254, 255, 255, 255, 255, 255, 3, 255, 255, 255, 77, 255, 12, 255
+            ]
+        );
+        assert_eq!(
+            <[u8; 16]>::from(simd.narrow_u16x16(e)),
+            [
+                0, 1, 254, 255, 0, 255, 0, 255, 3, 44, 255, 0, 77, 0, 12, 255
+            ]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn reductions() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // Sums add neighbouring lanes first, so the large terms cancel before the
+        // small ones are lost.
+        let a = f32x8::from_slice(simd, &[1e8, 1.0, -1e8, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(a.reduce_add(), 4.0);
+        let b = f32x4::from_slice(simd, &[3.0, -7.5, 2.0, 9.25]);
+        assert_eq!(b.reduce_min(), -7.5);
+        assert_eq!(b.reduce_max(), 9.25);
+        assert_eq!(b.reduce_add(), 6.75);
+        let c = f32x2::from_slice(simd, &[0.5, 1.25]);
+        assert_eq!(c.reduce_add(), 1.75);
+        let d = f64x4::from_slice(simd, &[1.0, 2.0, 3.0, -10.0]);
+        assert_eq!(d.reduce_add(), -4.0);
+        assert_eq!(d.reduce_min(), -10.0);
+
+        let e = u8x16::from_slice(simd, &[200; 16]);
+        assert_eq!(e.reduce_add(), 200u8.wrapping_mul(16));
+        let f = i16x8::from_slice(simd, &[5, -3, 100, i16::MIN, 7, i16::MAX, 0, -1]);
+        assert_eq!(f.reduce_min(), i16::MIN);
+        assert_eq!(f.reduce_max(), i16::MAX);
+        assert_eq!(f.reduce_add(), 107);
+        let g = u32x4::from_slice(simd, &[0xff0f, 0x0ff1, 0xfff3, 0x1f07]);
+        assert_eq!(g.reduce_and(), 0x0f01);
+        assert_eq!(g.reduce_or(), 0xffff);
+        let h = u64x2::from_slice(simd, &[u64::MAX - 1, 3]);
+        assert_eq!(h.reduce_max(), u64::MAX - 1);
+        assert_eq!(h.reduce_add(), 1);
+        let i = i32x2::from_slice(simd, &[-40, 2]);
+        assert_eq!(i.reduce_add(), -38);
+        assert_eq!(i.reduce_min(), -40);
+        let j = i8x32::from_slice(simd, &core::array::from_fn::<i8, 32, _>(|i| i as i8 - 20));
+        assert_eq!(j.reduce_min(), -20);
+        assert_eq!(j.reduce_max(), 11);
+        assert_eq!(j.reduce_or(), -1);
+        let k = u16x4::from_slice(simd, &[0x8001, 0x8003, 0x8007, 0x800f]);
+        assert_eq!(k.reduce_and(), 0x8001);
+        assert_eq!(k.reduce_add(), 0x001a);
+
+        // NaN lanes are ignored by the float min and max.
+        let nan = f32::NAN;
+        let l = f32x4::from_slice(simd, &[1.0, nan, 2.0, 3.0]);
+        assert_eq!(l.reduce_min(), 1.0);
+        assert_eq!(l.reduce_max(), 3.0);
+        let m = f32x8::from_slice(simd, &[5.0, 1.0, 2.0, 3.0, 4.0, 6.0, 7.0, nan]);
+        assert_eq!(m.reduce_min(), 1.0);
+        assert_eq!(m.reduce_max(), 7.0);
+        let n = f32x16::from_slice(
+            simd,
+            &[
+                nan, 9.0, nan, nan, nan, nan, nan, nan, -2.0, nan, nan, nan, nan, nan, nan, nan,
+            ],
+        );
+        assert_eq!(n.reduce_min(), -2.0);
+        assert_eq!(n.reduce_max(), 9.0);
+        assert!(f32x8::splat(simd, nan).reduce_min().is_nan());
+        assert!(f32x8::splat(simd, nan).reduce_max().is_nan());
+        let o = f64x4::from_slice(simd, &[f64::NAN, -1.0, f64::NAN, 2.0]);
+        assert_eq!(o.reduce_min(), -1.0);
+        assert_eq!(o.reduce_max(), 2.0);
+        let p = f64x2::from_slice(simd, &[3.0, f64::NAN]);
+        assert_eq!(p.reduce_min(), 3.0);
+        assert_eq!(p.reduce_max(), 3.0);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn mask_queries() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = u8x16::from_slice(simd, &[0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 7]);
+        let m = a.simd_eq(7);
+        assert!(m.any());
+        assert!(!m.all());
+        assert!(!m.none());
+        assert_eq!(m.count_true(), 3);
+        assert_eq!(m.first_true(), Some(1));
+        assert_eq!(m.to_bitmask(), 0b1000_0100_0000_0010);
+        assert_eq!(
+            <[i8; 16]>::from(mask8x16::from_bitmask(simd, 0b1000_0100_0000_0010)),
+            <[i8; 16]>::from(m)
+        );
+        let none = a.simd_gt(7);
+        assert!(!none.any());
+        assert!(none.none());
+        assert_eq!(none.first_true(), None);
+        assert!(a.simd_le(7).all());
+
+        for bits in [0, 1, 0x8000_0000_0000_0001, 0x5a5a_a5a5_0ff0_1234, u64::MAX] {
+            assert_eq!(mask8x8::from_bitmask(simd, bits).to_bitmask(), bits & 0xff);
+            assert_eq!(
+                mask8x16::from_bitmask(simd, bits).to_bitmask(),
+                bits & 0xffff
+            );
+            assert_eq!(
+                mask8x32::from_bitmask(simd, bits).to_bitmask(),
+                bits & 0xffff_ffff
+            );
+            assert_eq!(mask8x64::from_bitmask(simd, bits).to_bitmask(), bits);
+            assert_eq!(mask16x4::from_bitmask(simd, bits).to_bitmask(), bits & 0xf);
+            assert_eq!(mask16x8::from_bitmask(simd, bits).to_bitmask(), bits & 0xff);
+            assert_eq!(
+                mask16x16::from_bitmask(simd, bits).to_bitmask(),
+                bits & 0xffff
+            );
+            assert_eq!(mask32x2::from_bitmask(simd, bits).to_bitmask(), bits & 0x3);
+            assert_eq!(mask32x4::from_bitmask(simd, bits).to_bitmask(), bits & 0xf);
+            assert_eq!(mask32x8::from_bitmask(simd, bits).to_bitmask(), bits & 0xff);
+            assert_eq!(mask64x2::from_bitmask(simd, bits).to_bitmask(), bits & 0x3);
+            assert_eq!(mask64x4::from_bitmask(simd, bits).to_bitmask(), bits & 0xf);
+        }
+
+        let b = i16x16::from_slice(simd, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0]);
+        let n = b.simd_lt(0);
+        assert_eq!(n.first_true(), Some(11));
+        assert_eq!(n.to_bitmask(), 1 << 11);
+        let c = f32x2::from_slice(simd, &[1.0, 2.0]);
+        assert!(c.simd_gt(0.0).all());
+        assert_eq!(c.simd_gt(1.5).to_bitmask(), 0b10);
+        let d = u32x8::from_slice(simd, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(d.simd_ge(4).count_true(), 5);
+        assert_eq!(d.simd_ge(4).first_true(), Some(3));
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn shuffles() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = f32x4::from_slice(simd, &[1.0, 2.0, 3.0, 4.0]);
+        let b = f32x4::from_slice(simd, &[5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(
+            <[f32; 4]>::from(shuffle!(a, [3, 3, 0, 1])),
+            [4.0, 4.0, 1.0, 2.0]
+        );
+        assert_eq!(
+            <[f32; 4]>::from(shuffle!(a, b, [0, 1, 2, 7])),
+            [1.0, 2.0, 3.0, 8.0]
+        );
+        assert_eq!(<[f32; 4]>::from(a.reverse()), [4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(<[f32; 4]>::from(a.unzip_low(b)), [1.0, 3.0, 5.0, 7.0]);
+        assert_eq!(<[f32; 4]>::from(a.unzip_high(b)), [2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(
+            <[f32; 4]>::from(a.rotate_lanes_left::<1>()),
+            [2.0, 3.0, 4.0, 1.0]
+        );
+        assert_eq!(
+            <[f32; 4]>::from(a.rotate_lanes_right::<1>()),
+            [4.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            <[f32; 4]>::from(a.rotate_lanes_left::<5>()),
+            [2.0, 3.0, 4.0, 1.0]
+        );
+        assert_eq!(<[f32; 4]>::from(a.broadcast_lane::<2>()), [3.0; 4]);
+
+        // The macro also works on vectors that are only known to be `SimdBase`.
+        fn swap_pairs<S: Simd, V: SimdBase<f32, S>>(a: V, b: V) -> (V, V) {
+            (shuffle!(a, [1, 0, 3, 2]), shuffle!(a, b, [1, 4, 3, 6]))
+        }
+        let (swapped, mixed) = swap_pairs(a, b);
+        assert_eq!(<[f32; 4]>::from(swapped), [2.0, 1.0, 4.0, 3.0]);
+        assert_eq!(<[f32; 4]>::from(mixed), [2.0, 5.0, 4.0, 7.0]);
+
+        let c: [u8; 16] = core::array::from_fn(|i| i as u8);
+        let d: [u8; 16] = core::array::from_fn(|i| i as u8 + 16);
+        let c = u8x16::from_slice(simd, &c);
+        let d = u8x16::from_slice(simd, &d);
+        assert_eq!(
+            <[u8; 16]>::from(c.reverse()),
+            core::array::from_fn(|i| 15 - i as u8)
+        );
+        assert_eq!(
+            <[u8; 16]>::from(c.unzip_high(d)),
+            core::array::from_fn(|i| 2 * i as u8 + 1)
+        );
+        assert_eq!(
+            <[u8; 16]>::from(shuffle!(
+                c,
+                d,
+                [31, 0, 30, 1, 29, 2, 28, 3, 27, 4, 26, 5, 25, 6, 24, 7]
+            )),
+            [31, 0, 30, 1, 29, 2, 28, 3, 27, 4, 26, 5, 25, 6, 24, 7]
+        );
+
+        let e: [i16; 16] = core::array::from_fn(|i| i as i16);
+        let e = i16x16::from_slice(simd, &e);
+        assert_eq!(
+            <[i16; 16]>::from(e.reverse()),
+            core::array::from_fn(|i| 15 - i as i16)
+        );
+        assert_eq!(
+            <[i16; 16]>::from(e.unzip_low(e.reverse())),
+            [0, 2, 4, 6, 8, 10, 12, 14, 15, 13, 11, 9, 7, 5, 3, 1]
+        );
+        assert_eq!(
+            <[i16; 16]>::from(e.rotate_lanes_left::<3>()),
+            core::array::from_fn(|i| (i as i16 + 3) % 16)
+        );
+
+        let f = u16x4::from_slice(simd, &[1, 2, 3, 4]);
+        assert_eq!(<[u16; 4]>::from(f.reverse()), [4, 3, 2, 1]);
+        assert_eq!(<[u16; 4]>::from(shuffle!(f, [1, 1, 3, 0])), [2, 2, 4, 1]);
+
+        let g = i64x2::from_slice(simd, &[-1, 2]);
+        let h = i64x2::from_slice(simd, &[3, -4]);
+        assert_eq!(<[i64; 2]>::from(g.reverse()), [2, -1]);
+        assert_eq!(<[i64; 2]>::from(g.unzip_low(h)), [-1, 3]);
+        assert_eq!(<[i64; 2]>::from(g.unzip_high(h)), [2, -4]);
+
+        let m = a.simd_gt(2.0);
+        assert_eq!(<[i32; 4]>::from(m.reverse()), [-1, -1, 0, 0]);
+
+        let w: [f32; 16] = core::array::from_fn(|i| i as f32);
+        let w = f32x16::from_slice(simd, &w);
+        assert_eq!(
+            <[f32; 16]>::from(w.reverse()),
+            core::array::from_fn(|i| 15.0 - i as f32)
+        );
+        assert_eq!(
+            <[f32; 16]>::from(w.unzip_high(w)),
+            core::array::from_fn(|i| (2 * i % 16 + 1) as f32)
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn byte_lookups() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let table: [u8; 64] = core::array::from_fn(|i| 100 + i as u8);
+        let idx: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(37));
+        let expected = |len: usize| -> [u8; 64] {
+            core::array::from_fn(|i| {
+                let index = idx[i] as usize;
+                if index < len { table[index] } else { 0 }
+            })
+        };
+
+        let t8 = u8x8::from_slice(simd, &table[..8]);
+        let t16 = u8x16::from_slice(simd, &table[..16]);
+        let t32 = u8x32::from_slice(simd, &table[..32]);
+        let t64 = u8x64::from_slice(simd, &table);
+        let i8 = u8x8::from_slice(simd, &idx[..8]);
+        let i16 = u8x16::from_slice(simd, &idx[..16]);
+        let i32 = u8x32::from_slice(simd, &idx[..32]);
+        let i64 = u8x64::from_slice(simd, &idx);
+        let t48 = [0, 16, 32].map(|i| u8x16::from_slice(simd, &table[i..i + 16]));
+
+        assert_eq!(<[u8; 8]>::from(t8.swizzle_dyn(i8)), expected(8)[..8]);
+        assert_eq!(<[u8; 16]>::from(t16.swizzle_dyn(i16)), expected(16)[..16]);
+        assert_eq!(<[u8; 32]>::from(t32.swizzle_dyn(i32)), expected(32)[..32]);
+        assert_eq!(<[u8; 64]>::from(t64.swizzle_dyn(i64)), expected(64));
+
+        assert_eq!(<[u8; 8]>::from(i8.lookup_32(t32)), expected(32)[..8]);
+        assert_eq!(<[u8; 16]>::from(i16.lookup_32(t32)), expected(32)[..16]);
+        assert_eq!(<[u8; 64]>::from(i64.lookup_32(t32)), expected(32));
+        assert_eq!(<[u8; 8]>::from(i8.lookup_48(t48)), expected(48)[..8]);
+        assert_eq!(<[u8; 16]>::from(i16.lookup_48(t48)), expected(48)[..16]);
+        assert_eq!(<[u8; 32]>::from(i32.lookup_48(t48)), expected(48)[..32]);
+        assert_eq!(<[u8; 64]>::from(i64.lookup_48(t48)), expected(48));
+        assert_eq!(<[u8; 8]>::from(i8.lookup_64(t64)), expected(64)[..8]);
+        assert_eq!(<[u8; 16]>::from(i16.lookup_64(t64)), expected(64)[..16]);
+        assert_eq!(<[u8; 32]>::from(i32.lookup_64(t64)), expected(64)[..32]);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn rounding() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let a = f32x8::from_slice(simd, &[-2.5, -1.5, -0.5, -0.3, 0.5, 1.5, 2.5, 0.49999997]);
+        // Compare the bits, so that the signs of zeros are checked.
+        let bits = |v: f32x8<S>| <[f32; 8]>::from(v).map(f32::to_bits);
+        let expected = |v: [f32; 8]| v.map(f32::to_bits);
+        assert_eq!(
+            bits(a.ceil()),
+            expected([-2.0, -1.0, -0.0, -0.0, 1.0, 2.0, 3.0, 1.0])
+        );
+        assert_eq!(
+            bits(a.round()),
+            expected([-3.0, -2.0, -1.0, -0.0, 1.0, 2.0, 3.0, 0.0])
+        );
+        assert_eq!(
+            bits(a.round_ties_even()),
+            expected([-2.0, -2.0, -0.0, -0.0, 0.0, 2.0, 2.0, 0.0])
+        );
+        assert_eq!(
+            <[i32; 8]>::from(a.cvt_i32_round()),
+            [-2, -2, 0, 0, 0, 2, 2, 0]
+        );
+        assert_eq!(
+            <[u32; 8]>::from(a.cvt_u32_round()),
+            [0, 0, 0, 0, 0, 2, 2, 0]
+        );
+
+        let b = f64x2::from_slice(simd, &[-4503599627370495.5, 2.5]);
+        assert_eq!(<[f64; 2]>::from(b.round()), [-4503599627370496.0, 3.0]);
+        assert_eq!(<[f64; 2]>::from(b.ceil()), [-4503599627370495.0, 3.0]);
+        let c = f32x16::splat(simd, 8388607.5);
+        assert_eq!(<[f32; 16]>::from(c.round()), [8388608.0; 16]);
+        assert_eq!(<[f32; 16]>::from(c.round_ties_even()), [8388608.0; 16]);
+        assert_eq!(<[i32; 16]>::from(c.cvt_i32_round()), [8388608; 16]);
+
+        // Out of range values saturate and NaN gives zero, like `as`.
+        let d = f32x4::from_slice(simd, &[f32::INFINITY, -f32::INFINITY, f32::NAN, 3e9]);
+        assert_eq!(
+            <[u32; 4]>::from(d.cvt_u32_round()),
+            [u32::MAX, 0, 0, 3_000_000_000]
+        );
+        assert_eq!(
+            <[i32; 4]>::from(d.cvt_i32_round()),
+            [i32::MAX, i32::MIN, 0, i32::MAX]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn float_classification() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // One character for each lane, with `1` for the lanes in the mask.
+        fn lanes(expected: &str) -> Vec<i64> {
+            expected.bytes().map(|c| -i64::from(c == b'1')).collect()
+        }
+        let m32 = |m: mask32x8<S>| <[i32; 8]>::from(m).map(i64::from).to_vec();
+        let m64 = |m: mask64x4<S>| <[i64; 4]>::from(m).to_vec();
+        let m16 = |m: mask16x8<S>| <[i16; 8]>::from(m).map(i64::from).to_vec();
+
+        let specials = f32x8::from_slice(
+            simd,
+            &[
+                f32::NAN,
+                -f32::NAN,
+                f32::INFINITY,
+                f32::NEG_INFINITY,
+                0.0,
+                -0.0,
+                1e-40,
+                -1e-40,
+            ],
+        );
+        let others = f32x8::from_slice(
+            simd,
+            &[
+                f32::MIN_POSITIVE,
+                -1.5,
+                3.0,
+                f32::MAX,
+                -f32::MIN_POSITIVE / 2.0,
+                1.0,
+                // A signaling NaN, and a negative NaN with a payload.
+                f32::from_bits(0x7f80_0001),
+                f32::from_bits(0xffc0_0001),
+            ],
+        );
+        assert_eq!(m32(specials.is_nan()), lanes("11000000"));
+        assert_eq!(m32(others.is_nan()), lanes("00000011"));
+        assert_eq!(m32(specials.is_finite()), lanes("00001111"));
+        assert_eq!(m32(others.is_finite()), lanes("11111100"));
+        assert_eq!(m32(specials.is_infinite()), lanes("00110000"));
+        assert_eq!(m32(others.is_infinite()), lanes("00000000"));
+        assert_eq!(m32(specials.is_subnormal()), lanes("00000011"));
+        assert_eq!(m32(others.is_subnormal()), lanes("00001000"));
+        assert_eq!(m32(specials.is_sign_negative()), lanes("01010101"));
+        assert_eq!(m32(others.is_sign_negative()), lanes("01001001"));
+
+        let zero = f32x8::splat(simd, 0.0);
+        assert_eq!(m32(specials.simd_ne(specials)), lanes("11000000"));
+        assert_eq!(m32(specials.simd_ne(zero)), lanes("11110011"));
+        assert_eq!(m32(others.simd_ne(others)), lanes("00000011"));
+        assert_eq!(
+            m32(!specials.simd_eq(others)),
+            m32(specials.simd_ne(others))
+        );
+
+        let a = f64x4::from_slice(simd, &[-f64::NAN, f64::NEG_INFINITY, -0.0, 1e-310]);
+        assert_eq!(m64(a.is_nan()), lanes("1000"));
+        assert_eq!(m64(a.is_finite()), lanes("0011"));
+        assert_eq!(m64(a.is_infinite()), lanes("0100"));
+        assert_eq!(m64(a.is_subnormal()), lanes("0001"));
+        assert_eq!(m64(a.is_sign_negative()), lanes("1110"));
+        assert_eq!(m64(a.simd_ne(a)), lanes("1000"));
+
+        // The f16 subnormals are normal in f32, so they check that the lanes
+        // aren't converted.
+        let a = f16x8::from_slice(
+            simd,
+            &[
+                0x7e00, 0xfc00, 0x8000, 0x0001, 0x03ff, 0x0400, 0x7bff, 0xbc00,
+            ]
+            .map(f16::from_bits),
+        );
+        assert_eq!(m16(a.is_nan()), lanes("10000000"));
+        assert_eq!(m16(a.is_finite()), lanes("00111111"));
+        assert_eq!(m16(a.is_infinite()), lanes("01000000"));
+        assert_eq!(m16(a.is_subnormal()), lanes("00011000"));
+        assert_eq!(m16(a.is_sign_negative()), lanes("01100001"));
+        assert_eq!(m16(a.simd_ne(a)), lanes("10000000"));
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn fused_multiply_add() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // With a separate multiply, `a * b` rounds to 1 and the result is 0.
+        let a = f32x4::splat(simd, 1.0 + f32::EPSILON);
+        let b = f32x4::splat(simd, 1.0 - f32::EPSILON);
+        let c = f32x4::splat(simd, -1.0);
+        assert_eq!(
+            <[f32; 4]>::from(a.mul_add_fused(b, c)),
+            [-(2.0f32.powi(-46)); 4]
+        );
+        let a = f64x2::splat(simd, 1.0 + f64::EPSILON);
+        let b = f64x2::splat(simd, 1.0 - f64::EPSILON);
+        let c = f64x2::splat(simd, -1.0);
+        assert_eq!(
+            <[f64; 2]>::from(a.mul_add_fused(b, c)),
+            [-(2.0f64.powi(-104)); 2]
+        );
+
+        // The exact result is `1 + 2^-11 + 2^-31`, just above the midpoint of
+        // two f16 values. Rounding it to f32 first would give the midpoint,
+        // which rounds down to 1.
+        let h = f16::from_f64_const;
+        let a = f16x8::splat(simd, h(-(2.0f64.powi(-11) + 2.0f64.powi(-21))));
+        let b = f16x8::splat(simd, h(1.0 - 2.0f64.powi(-10)));
+        let c = f16x8::splat(simd, h(1.0 + 2.0f64.powi(-10)));
+        assert_eq!(
+            <[f16; 8]>::from(a.mul_add_fused(b, c)).map(f16::to_bits),
+            [h(1.0009765625).to_bits(); 8]
+        );
+
+        // Compare against the scalar fused multiply-add, including sums that
+        // nearly cancel.
+        let mut state = 0x2545_f491_u32;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            // Keep the exponents small enough to avoid overflow.
+            f32::from_bits(state & 0x8000_0000 | (state % 48 + 100) << 23 | state & 0x7f_ffff)
+        };
+        for i in 0..64 {
+            let a: [f32; 8] = core::array::from_fn(|_| random());
+            let b: [f32; 8] = core::array::from_fn(|_| random());
+            let c: [f32; 8] =
+                core::array::from_fn(|j| if i % 2 == 0 { -a[j] * b[j] } else { random() });
+            let fused = f32x8::from_slice(simd, &a)
+                .mul_add_fused(f32x8::from_slice(simd, &b), f32x8::from_slice(simd, &c));
+            let expected: [f32; 8] = core::array::from_fn(|j| a[j].mul_add(b[j], c[j]));
+            assert_eq!(
+                <[f32; 8]>::from(fused).map(f32::to_bits),
+                expected.map(f32::to_bits),
+                "{a:?} * {b:?} + {c:?}"
+            );
+        }
+
+        // The f16 sum is exact in f64, which then rounds once to f16.
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let bits = state as u16;
+            f16::from_bits(bits & 0x8000 | (bits % 15 + 8) << 10 | bits & 0x3ff)
+        };
+        for i in 0..64 {
+            let a: [f16; 16] = core::array::from_fn(|_| random());
+            let b: [f16; 16] = core::array::from_fn(|_| random());
+            let c: [f16; 16] = core::array::from_fn(|j| {
+                if i % 2 == 0 {
+                    h(-a[j].to_f32() as f64 * b[j].to_f32() as f64)
+                } else {
+                    random()
+                }
+            });
+            let fused = f16x16::from_slice(simd, &a)
+                .mul_add_fused(f16x16::from_slice(simd, &b), f16x16::from_slice(simd, &c));
+            let expected: [f16; 16] = core::array::from_fn(|j| {
+                let [a, b, c] = [a[j], b[j], c[j]].map(|x| x.to_f32() as f64);
+                h(a * b + c)
+            });
+            assert_eq!(
+                <[f16; 16]>::from(fused).map(f16::to_bits),
+                expected.map(f16::to_bits),
+                "{a:?} * {b:?} + {c:?}"
+            );
+        }
+
+        let a = f32x4::from_slice(simd, &[1.0, 2.0, -3.0, 0.5]);
+        let b = f32x4::from_slice(simd, &[4.0, -1.0, 2.0, 8.0]);
+        let c = f32x4::from_slice(simd, &[0.5, 3.0, 1.0, -2.0]);
+        assert_eq!(<[f32; 4]>::from(a.madd(b, c)), [3.0, -1.0, -1.0, -15.5]);
+        assert_eq!(<[f32; 4]>::from(a.msub(b, c)), [-1.0, 5.0, -5.0, 16.5]);
+        assert_eq!(<[f32; 4]>::from(a.nmadd(b, c)), [-3.0, 1.0, 1.0, 15.5]);
+        assert_eq!(<[f32; 4]>::from(a.nmsub(b, c)), [1.0, -5.0, 5.0, -16.5]);
+        assert_eq!(
+            <[f32; 4]>::from(a.mul_add_fast(b, c)),
+            [4.5, 1.0, -5.0, 2.0]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    helper(Level::new());
+    helper(Level::fallback());
+}
+
+#[test]
+fn reciprocals() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // The documented bounds on the relative error after each number of steps.
+        let f32_bounds = [2f64.powi(-8), 2f64.powi(-15), 2f64.powi(-22)];
+        let f64_bounds = [
+            2f64.powi(-8),
+            2f64.powi(-15),
+            2f64.powi(-30),
+            2f64.powi(-50),
+        ];
+        let relative = |actual: f64, expected: f64| (actual / expected - 1.0).abs();
+
+        // Normal inputs with normal reciprocals, with alternating signs.
+        let inputs: Vec<f32> = (0..1 << 14)
+            .map(|i: u32| f32::from_bits(i << 31 | (0x0080_0000 + i * 0x1_f800)))
+            .collect();
+        for chunk in inputs.chunks_exact(16) {
+            let a = f32x16::from_slice(simd, chunk);
+            let lanes = |v: f32x16<S>| <[f32; 16]>::from(v).map(f64::from);
+            for (steps, bound) in f32_bounds.iter().enumerate() {
+                let approx = lanes(a.recip(steps as u32));
+                let sqrt_approx = lanes(a.abs().rsqrt(steps as u32));
+                for i in 0..16 {
+                    let x = chunk[i] as f64;
+                    assert!(relative(approx[i], 1.0 / x) < *bound, "recip({x}, {steps})");
+                    assert!(
+                        relative(sqrt_approx[i], 1.0 / x.abs().sqrt()) < *bound,
+                        "rsqrt({x}, {steps})"
+                    );
+                }
+            }
+            assert_eq!(lanes(a.recip_approx()), lanes(a.recip(0)));
+            assert_eq!(lanes(a.abs().rsqrt_approx()), lanes(a.abs().rsqrt(0)));
+        }
+
+        let inputs: Vec<f64> = (0..1 << 12)
+            .map(|i: u64| {
+                f64::from_bits(i << 63 | (0x0010_0000_0000_0000 + i * 0x7_fbff_ffff_ffff))
+            })
+            .collect();
+        for chunk in inputs.chunks_exact(8) {
+            let a = f64x8::from_slice(simd, chunk);
+            for (steps, bound) in f64_bounds.iter().enumerate() {
+                let approx = <[f64; 8]>::from(a.recip(steps as u32));
+                let sqrt_approx = <[f64; 8]>::from(a.abs().rsqrt(steps as u32));
+                for i in 0..8 {
+                    let x = chunk[i];
+                    assert!(relative(approx[i], 1.0 / x) < *bound, "recip({x}, {steps})");
+                    assert!(
+                        relative(sqrt_approx[i], 1.0 / x.abs().sqrt()) < *bound,
+                        "rsqrt({x}, {steps})"
+                    );
+                }
+            }
+        }
+
+        // Subnormal inputs, and huge inputs with subnormal reciprocals, are outside the
+        // range of the estimate instructions.
+        let subnormal = [
+            1e-38, -5e-39, 3e-39, 1e-39, -1e-40, 1e-45, 1.1e-38, -2.9e-39,
+        ];
+        let huge = [
+            1e38,
+            -3e38,
+            f32::MAX,
+            -f32::MAX,
+            8.6e37,
+            2e38,
+            -1.5e38,
+            3.4e38,
+        ];
+        for steps in 0..3 {
+            let a = f32x8::from_slice(simd, &subnormal);
+            let recip = <[f32; 8]>::from(a.recip(steps));
+            let rsqrt = <[f32; 8]>::from(a.abs().rsqrt(steps));
+            for i in 0..8 {
+                let x = subnormal[i] as f64;
+                if (1.0 / x).abs() > f32::MAX as f64 {
+                    assert_eq!(recip[i], 1.0 / subnormal[i], "recip({x}, {steps})");
+                } else {
+                    let bound = f32_bounds[steps as usize];
+                    assert!(
+                        relative(recip[i] as f64, 1.0 / x) < bound,
+                        "recip({x}, {steps})"
+                    );
+                }
+                let bound = f32_bounds[steps as usize];
+                assert!(
+                    relative(rsqrt[i] as f64, 1.0 / x.abs().sqrt()) < bound,
+                    "rsqrt({x}, {steps})"
+                );
+            }
+            let a = f32x8::from_slice(simd, &huge);
+            let recip = <[f32; 8]>::from(a.recip(steps));
+            let rsqrt = <[f32; 8]>::from(a.abs().rsqrt(steps));
+            for i in 0..8 {
+                let x = huge[i] as f64;
+                // Subnormal reciprocals are rounded more coarsely than the bounds.
+                assert_eq!(recip[i], 1.0 / huge[i], "recip({x}, {steps})");
+                let bound = f32_bounds[steps as usize];
+                assert!(
+                    relative(rsqrt[i] as f64, 1.0 / x.abs().sqrt()) < bound,
+                    "rsqrt({x}, {steps})"
+                );
+            }
+        }
+
+        let inputs = [
+            1e-308,
+            -3e-308,
+            1e-310,
+            5e-324,
+            1e308,
+            -f64::MAX,
+            4e307,
+            1e306,
+        ];
+        let a = f64x8::from_slice(simd, &inputs);
+        for (steps, bound) in f64_bounds.iter().enumerate() {
+            let recip = <[f64; 8]>::from(a.recip(steps as u32));
+            let rsqrt = <[f64; 8]>::from(a.abs().rsqrt(steps as u32));
+            for i in 0..8 {
+                let x = inputs[i];
+                if (1.0 / x).abs() < f64::MIN_POSITIVE || (1.0 / x).is_infinite() {
+                    assert_eq!(recip[i], 1.0 / x, "recip({x}, {steps})");
+                } else {
+                    assert!(relative(recip[i], 1.0 / x) < *bound, "recip({x}, {steps})");
+                }
+                assert!(
+                    relative(rsqrt[i], 1.0 / x.abs().sqrt()) < *bound,
+                    "rsqrt({x}, {steps})"
+                );
+            }
+        }
+
+        let a = f32x4::from_slice(simd, &[0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY]);
+        let bits = |v: f32x4<S>| <[f32; 4]>::from(v).map(f32::to_bits);
+        let expected = [f32::INFINITY, f32::NEG_INFINITY, 0.0, -0.0].map(f32::to_bits);
+        assert_eq!(bits(a.recip(2)), expected);
+        assert_eq!(bits(a.abs().rsqrt(2)), [expected[0], expected[0], 0, 0]);
+        assert!(<[f32; 4]>::from(a.rsqrt(2))[3].is_nan());
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+/// The error of `actual` in units in the last place of `expected`.
+fn ulp_error(actual: f32, expected: f64) -> f64 {
+    let rounded = expected as f32;
+    if actual == rounded || (actual.is_nan() && expected.is_nan()) {
+        return 0.0;
+    }
+    if !actual.is_finite() || !rounded.is_finite() {
+        return f64::INFINITY;
+    }
+    let exponent = (rounded.abs().to_bits() >> 23).max(1) as i32;
+    (actual as f64 - expected).abs() / 2f64.powi(exponent - 150)
+}
+
+/// Apply `f` to `x` and `y`, a vector of type `V` at a time.
+fn map_lanes<S: Simd, V: SimdMath<S>>(
+    simd: S,
+    x: &[f32],
+    y: &[f32],
+    f: impl Fn(V, V) -> V,
+) -> Vec<f32> {
+    x.chunks_exact(V::N)
+        .zip(y.chunks_exact(V::N))
+        .flat_map(|(x, y)| {
+            f(V::from_slice(simd, x), V::from_slice(simd, y))
+                .as_slice()
+                .to_vec()
+        })
+        .collect()
+}
+
+fn math_accuracy_inner<S: Simd>(simd: S) {
+    const N: usize = 4096;
+    let lerp = |lo: f32, hi: f32| -> Vec<f32> {
+        (0..N)
+            .map(|i| lo + (hi - lo) * i as f32 / N as f32)
+            .collect()
+    };
+    // Shuffle the order, so that pairs of inputs cover the plane.
+    let shuffled = |v: &[f32]| -> Vec<f32> { (0..N).map(|i| v[i * 1237 % N]).collect() };
+    let positive: Vec<f32> = (0..N as u32)
+        .map(|i| f32::from_bits(1 + i * (0x7f7f_ffff / N as u32)))
+        .collect();
+    let normal: Vec<f32> = positive
+        .iter()
+        .copied()
+        .filter(|x| x.is_normal())
+        .chain(lerp(0.5, 2.0))
+        .take(N)
+        .collect();
+
+    let check = |name: &str,
+                 x: &[f32],
+                 y: &[f32],
+                 actual: Vec<f32>,
+                 expected: fn(f64, f64) -> f64,
+                 error: &dyn Fn(f32, f64) -> f64,
+                 bound: f64| {
+        for ((&x, &y), actual) in x.iter().zip(y).zip(actual) {
+            let expected = expected(x as f64, y as f64);
+            let error = error(actual, expected);
+            assert!(
+                error <= bound,
+                "{name}({x:e}, {y:e}) = {actual:e}, expected {expected:e}, error {error}"
+            );
+        }
+    };
+    let ulp = &ulp_error;
+    let relative = &|actual: f32, expected: f64| {
+        let error = (actual as f64 - expected).abs();
+        if error == 0.0 {
+            0.0
+        } else {
+            error / expected.abs()
+        }
+    };
+    let absolute = &|actual: f32, expected: f64| (actual as f64 - expected).abs();
+
+    let x = lerp(-104.0, 89.0);
+    let r = map_lanes::<S, f32x4<S>>(simd, &x, &x, |a, _| a.exp());
+    check("exp", &x, &x, r, |x, _| libm::exp(x), ulp, 2.0);
+    let x = lerp(-150.0, 128.0);
+    let r = map_lanes::<S, f32x8<S>>(simd, &x, &x, |a, _| a.exp2());
+    check("exp2", &x, &x, r, |x, _| libm::exp2(x), ulp, 2.0);
+    let r = map_lanes::<S, f32x16<S>>(simd, &positive, &x, |a, _| a.ln());
+    check("ln", &positive, &x, r, |x, _| libm::log(x), ulp, 1.0);
+    let r = map_lanes::<S, f32x4<S>>(simd, &positive, &x, |a, _| a.log2());
+    check("log2", &positive, &x, r, |x, _| libm::log2(x), ulp, 1.0);
+
+    let (x, y) = (lerp(0.01, 10.0), shuffled(&lerp(-30.0, 30.0)));
+    let r = map_lanes::<S, f32x8<S>>(simd, &x, &y, |a, b| a.pow(b));
+    let pow_error = &|actual: f32, expected: f64| {
+        let bound = if (2f64.powi(-32)..=2f64.powi(32)).contains(&expected) {
+            4.0
+        } else {
+            16.0
+        };
+        ulp_error(actual, expected) / bound
+    };
+    check("pow", &x, &y, r, |x, y| libm::pow(x, y), pow_error, 1.0);
+    // Exponents that take the results close to overflow and underflow.
+    let y: Vec<f32> = positive
+        .iter()
+        .zip(shuffled(&lerp(-1.0, 1.0)))
+        .map(|(x, t)| t * 150.0 / x.log2().abs())
+        .collect();
+    let r = map_lanes::<S, f32x16<S>>(simd, &positive, &y, |a, b| a.pow(b));
+    check(
+        "pow",
+        &positive,
+        &y,
+        r,
+        |x, y| libm::pow(x, y),
+        pow_error,
+        1.0,
+    );
+
+    for x in [lerp(-4.0, 4.0), lerp(-8192.0, 8192.0)] {
+        let r = map_lanes::<S, f32x4<S>>(simd, &x, &x, |a, _| a.sin());
+        check("sin", &x, &x, r, |x, _| libm::sin(x), ulp, 3.0);
+        let r = map_lanes::<S, f32x8<S>>(simd, &x, &x, |a, _| a.cos());
+        check("cos", &x, &x, r, |x, _| libm::cos(x), ulp, 3.0);
+        let r = map_lanes::<S, f32x16<S>>(simd, &x, &x, |a, _| a.tan());
+        check("tan", &x, &x, r, |x, _| libm::tan(x), ulp, 4.0);
+    }
+
+    let (y, x) = (lerp(-10.0, 10.0), shuffled(&lerp(-10.0, 10.0)));
+    let r = map_lanes::<S, f32x4<S>>(simd, &y, &x, |a, b| a.atan2(b));
+    check("atan2", &y, &x, r, libm::atan2, ulp, 3.0);
+    let huge: Vec<f32> = positive.iter().map(|x| -x).collect();
+    let r = map_lanes::<S, f32x8<S>>(simd, &positive, &huge, |a, b| a.atan2(b));
+    check("atan2", &positive, &huge, r, libm::atan2, ulp, 3.0);
+    // The fast version doesn't handle both inputs being zero.
+    let x = shuffled(&lerp(-9.5, 10.5));
+    let r = map_lanes::<S, f32x8<S>>(simd, &y, &x, |a, b| a.fast_atan2(b));
+    check("fast_atan2", &y, &x, r, libm::atan2, absolute, 5e-5);
+
+    let x = lerp(-87.0, 88.0);
+    let r = map_lanes::<S, f32x16<S>>(simd, &x, &x, |a, _| a.fast_exp());
+    check("fast_exp", &x, &x, r, |x, _| libm::exp(x), relative, 2e-5);
+    let x = lerp(-126.0, 127.0);
+    let r = map_lanes::<S, f32x4<S>>(simd, &x, &x, |a, _| a.fast_exp2());
+    check("fast_exp2", &x, &x, r, |x, _| libm::exp2(x), relative, 1e-5);
+    let r = map_lanes::<S, f32x8<S>>(simd, &normal, &x, |a, _| a.fast_ln());
+    check(
+        "fast_ln",
+        &normal,
+        &x,
+        r,
+        |x, _| libm::log(x),
+        absolute,
+        2e-5,
+    );
+    let r = map_lanes::<S, f32x16<S>>(simd, &normal, &x, |a, _| a.fast_log2());
+    check(
+        "fast_log2",
+        &normal,
+        &x,
+        r,
+        |x, _| libm::log2(x),
+        absolute,
+        2e-5,
+    );
+    let (x, y) = (lerp(0.01, 10.0), shuffled(&lerp(-10.0, 10.0)));
+    let r = map_lanes::<S, f32x4<S>>(simd, &x, &y, |a, b| a.fast_pow(b));
+    let fast_pow_error = &|actual: f32, expected: f64| relative(actual, expected) / 1.1e-4;
+    check(
+        "fast_pow",
+        &x,
+        &y,
+        r,
+        |x, y| libm::pow(x, y),
+        fast_pow_error,
+        1.0,
+    );
+
+    let x = lerp(-1000.0, 1000.0);
+    let r = map_lanes::<S, f32x8<S>>(simd, &x, &x, |a, _| a.fast_sin());
+    check("fast_sin", &x, &x, r, |x, _| libm::sin(x), absolute, 2e-5);
+    let r = map_lanes::<S, f32x16<S>>(simd, &x, &x, |a, _| a.fast_cos());
+    check("fast_cos", &x, &x, r, |x, _| libm::cos(x), absolute, 2e-5);
+    let x = lerp(-1.5707963, 1.5707963);
+    let r = map_lanes::<S, f32x4<S>>(simd, &x, &x, |a, _| a.fast_tan());
+    check("fast_tan", &x, &x, r, |x, _| libm::tan(x), relative, 2e-5);
+
+    // Special values.
+    let (inf, nan) = (f32::INFINITY, f32::NAN);
+    let bits = |v: f32x4<S>| <[f32; 4]>::from(v).map(f32::to_bits);
+    let expected = |v: [f32; 4]| v.map(f32::to_bits);
+    let a = f32x4::from_slice(simd, &[inf, -inf, 0.0, -1.0]);
+    assert_eq!(bits(a.exp()), expected([inf, 0.0, 1.0, 0.36787945]));
+    assert_eq!(bits(a.ln()), expected([inf, nan, -inf, nan]));
+    assert_eq!(bits(a.log2()), expected([inf, nan, -inf, nan]));
+    assert!(<[f32; 4]>::from(a.sin()).iter().take(2).all(|x| x.is_nan()));
+    assert!(
+        f32x4::splat(simd, nan)
+            .exp2()
+            .as_slice()
+            .iter()
+            .all(|x| x.is_nan())
+    );
+    let x = f32x4::from_slice(simd, &[0.0, -0.0, -2.0, -8.0]);
+    let y = f32x4::from_slice(simd, &[-1.0, -1.0, 3.0, 1.0 / 3.0]);
+    let r = <[f32; 4]>::from(x.pow(y));
+    assert_eq!(r[..3], [inf, -inf, -8.0]);
+    assert!(r[3].is_nan());
+    let x = f32x4::from_slice(simd, &[nan, 1.0, -1.0, 0.5]);
+    let y = f32x4::from_slice(simd, &[0.0, nan, inf, -inf]);
+    assert_eq!(<[f32; 4]>::from(x.pow(y)), [1.0, 1.0, 1.0, inf]);
+    let y = f32x4::from_slice(simd, &[0.0, -0.0, inf, -1.0]);
+    let x = f32x4::from_slice(simd, &[-0.0, -0.0, -inf, -inf]);
+    assert_eq!(
+        <[f32; 4]>::from(y.atan2(x)),
+        [
+            core::f32::consts::PI,
+            -core::f32::consts::PI,
+            3.0 * core::f32::consts::FRAC_PI_4,
+            -core::f32::consts::PI,
+        ]
+    );
+}
+
+simd_dispatch!(math_accuracy(level) = math_accuracy_inner);
+
+#[test]
+fn math() {
+    for_each_level(math_accuracy);
+}
+
+#[test]
+fn integer_abs_diff_avg() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        // `abs` and `neg` wrap, so the minimum value maps to itself.
+        let a = i8x16::from_slice(
+            simd,
+            &[
+                i8::MIN,
+                -127,
+                -1,
+                0,
+                1,
+                127,
+                -5,
+                5,
+                i8::MIN,
+                0,
+                1,
+                -1,
+                100,
+                -100,
+                3,
+                -3,
+            ],
+        );
+        let b = i8x16::from_slice(
+            simd,
+            &[
+                i8::MAX,
+                127,
+                1,
+                0,
+                -1,
+                i8::MIN,
+                5,
+                -5,
+                0,
+                i8::MIN,
+                1,
+                1,
+                -100,
+                100,
+                3,
+                4,
+            ],
+        );
+        assert_eq!(
+            <[i8; 16]>::from(a.abs()),
+            [
+                i8::MIN,
+                127,
+                1,
+                0,
+                1,
+                127,
+                5,
+                5,
+                i8::MIN,
+                0,
+                1,
+                1,
+                100,
+                100,
+                3,
+                3
+            ]
+        );
+        assert_eq!(
+            <[i8; 16]>::from(-a),
+            [
+                i8::MIN,
+                127,
+                1,
+                0,
+                -1,
+                -127,
+                5,
+                -5,
+                i8::MIN,
+                0,
+                -1,
+                1,
+                -100,
+                100,
+                -3,
+                3
+            ]
+        );
+        assert_eq!(
+            <[u8; 16]>::from(a.abs_diff(b)),
+            [
+                255, 254, 2, 0, 2, 255, 10, 10, 128, 128, 0, 2, 200, 200, 0, 7
+            ]
+        );
+
+        let a = i64x4::from_slice(simd, &[i64::MIN, -7, 0, i64::MAX]);
+        let b = i64x4::from_slice(simd, &[i64::MAX, 7, 0, -1]);
+        assert_eq!(<[i64; 4]>::from(a.abs()), [i64::MIN, 7, 0, i64::MAX]);
+        assert_eq!(<[i64; 4]>::from(-a), [i64::MIN, 7, 0, -i64::MAX]);
+        assert_eq!(<[u64; 4]>::from(a.abs_diff(b)), [u64::MAX, 14, 0, 1 << 63]);
+        let a = u64x2::from_slice(simd, &[0, u64::MAX]);
+        let b = u64x2::from_slice(simd, &[u64::MAX, 1]);
+        assert_eq!(<[u64; 2]>::from(a.abs_diff(b)), [u64::MAX, u64::MAX - 1]);
+
+        let a = i32x8::from_slice(simd, &[i32::MIN, -3, 2, 0, 9, -9, 1, i32::MAX]);
+        assert_eq!(
+            <[i32; 8]>::from(a.abs()),
+            [i32::MIN, 3, 2, 0, 9, 9, 1, i32::MAX]
+        );
+        let a = i16x8::from_slice(simd, &[i16::MIN, -3, 2, 0, 9, -9, 1, i16::MAX]);
+        assert_eq!(
+            <[i16; 8]>::from(-a),
+            [i16::MIN, 3, -2, 0, -9, 9, -1, -i16::MAX]
+        );
+
+        // The average rounds up, and doesn't overflow.
+        let a = u8x16::from_slice(
+            simd,
+            &[
+                0, 1, 2, 255, 255, 254, 10, 0, 7, 8, 100, 200, 0, 1, 128, 127,
+            ],
+        );
+        let b = u8x16::from_slice(
+            simd,
+            &[0, 0, 1, 255, 0, 255, 11, 255, 8, 7, 101, 55, 1, 1, 128, 128],
+        );
+        assert_eq!(
+            <[u8; 16]>::from(a.avg_round(b)),
+            [
+                0, 1, 2, 255, 128, 255, 11, 128, 8, 8, 101, 128, 1, 1, 128, 128
+            ]
+        );
+        let a = u16x8::from_slice(simd, &[0, 1, u16::MAX, u16::MAX, 3, 4, 1000, 0]);
+        let b = u16x8::from_slice(simd, &[1, 1, u16::MAX, 0, 4, 3, 2001, 0]);
+        assert_eq!(
+            <[u16; 8]>::from(a.avg_round(b)),
+            [1, 1, u16::MAX, 32768, 4, 4, 1501, 0]
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+#[test]
+fn widening_multiplies() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let mut a16: [i16; 16] = core::array::from_fn(|i| (i as i16 - 8).wrapping_mul(4099));
+        let mut b16: [i16; 16] = core::array::from_fn(|i| (i as i16).wrapping_mul(-7919) ^ 0x55);
+        // The extremes, where the rounding and saturation matter.
+        for (i, (a, b)) in [
+            (i16::MIN, i16::MIN),
+            (i16::MIN, i16::MIN),
+            (i16::MIN, i16::MAX),
+            (i16::MAX, i16::MAX),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            (a16[i], b16[i]) = (a, b);
+        }
+        let a = i16x16::from_slice(simd, &a16);
+        let b = i16x16::from_slice(simd, &b16);
+        let products: [i32; 16] = core::array::from_fn(|i| a16[i] as i32 * b16[i] as i32);
+        assert_eq!(<[i32; 16]>::from(a.mul_widen(b)), products);
+        assert_eq!(
+            <[i16; 16]>::from(a.mul_high(b)),
+            products.map(|p| (p >> 16) as i16)
+        );
+        assert_eq!(
+            <[i16; 16]>::from(a.mul_q15_round_sat(b)),
+            products.map(|p| ((p + (1 << 14)) >> 15).clamp(-0x8000, 0x7fff) as i16)
+        );
+        // The first pair is `-0x8000` squared twice, which wraps.
+        assert_eq!(
+            <[i32; 8]>::from(a.dot_i16(b)),
+            core::array::from_fn::<i32, 8, _>(|i| {
+                products[2 * i].wrapping_add(products[2 * i + 1])
+            })
+        );
+        let a = i16x8::from_slice(simd, &a16[..8]);
+        let b = i16x8::from_slice(simd, &b16[..8]);
+        assert_eq!(<[i32; 4]>::from(a.dot_i16(b))[0], i32::MIN);
+        assert_eq!(
+            <[i16; 8]>::from(a.mul_q15_round_sat(b))[..4],
+            [i16::MAX, i16::MAX, -i16::MAX, 0x7ffe]
+        );
+
+        let u16s: [u16; 8] = core::array::from_fn(|i| (i as u16 * 9001) ^ 0xf00f);
+        let a = u16x8::from_slice(simd, &u16s);
+        let b = a.reverse();
+        let products: [u32; 8] = core::array::from_fn(|i| u16s[i] as u32 * u16s[7 - i] as u32);
+        assert_eq!(<[u32; 8]>::from(a.mul_widen(b)), products);
+        assert_eq!(
+            <[u16; 8]>::from(a.mul_high(b)),
+            products.map(|p| (p >> 16) as u16)
+        );
+
+        let u8s: [u8; 32] = core::array::from_fn(|i| (i as u8).wrapping_mul(73) ^ 0x81);
+        let a = u8x32::from_slice(simd, &u8s);
+        let b = a.reverse();
+        let products: [u16; 32] = core::array::from_fn(|i| u8s[i] as u16 * u8s[31 - i] as u16);
+        assert_eq!(
+            <[u8; 32]>::from(a.mul_high(b)),
+            products.map(|p| (p >> 8) as u8)
+        );
+        let (low, high) = (
+            u8x16::from_slice(simd, &u8s[..16]),
+            u8x16::from_slice(simd, &u8s[16..]),
+        );
+        assert_eq!(
+            <[u16; 16]>::from(low.mul_widen(high.reverse())),
+            <[u16; 16]>::try_from(&products[..16]).unwrap()
+        );
+        let a = u8x8::from_slice(simd, &u8s[..8]);
+        let b = u8x8::from_slice(simd, &u8s[24..]).reverse();
+        assert_eq!(
+            <[u16; 8]>::from(a.mul_widen(b)),
+            <[u16; 8]>::try_from(&products[..8]).unwrap()
+        );
+        assert_eq!(
+            <[u8; 8]>::from(a.mul_high(b)),
+            <[u16; 8]>::try_from(&products[..8])
+                .unwrap()
+                .map(|p| (p >> 8) as u8)
+        );
+
+        let i8s: [i8; 16] = core::array::from_fn(|i| (i as i8).wrapping_mul(-45) ^ 0x40);
+        let a = i8x16::from_slice(simd, &i8s);
+        let b = a.reverse();
+        let products: [i16; 16] = core::array::from_fn(|i| i8s[i] as i16 * i8s[15 - i] as i16);
+        assert_eq!(<[i16; 16]>::from(a.mul_widen(b)), products);
+        assert_eq!(
+            <[i8; 16]>::from(a.mul_high(b)),
+            products.map(|p| (p >> 8) as i8)
+        );
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
+
+#[test]
+fn widen_narrow_all_widths() {
+    #[inline(always)]
+    fn helper_inner<S: Simd>(simd: S) {
+        let i8s: [i8; 16] = core::array::from_fn(|i| (i as i8).wrapping_mul(-45) ^ 0x40);
+        let a = i8x16::from_slice(simd, &i8s);
+        assert_eq!(<[i16; 16]>::from(a.widen()), i8s.map(i16::from));
+        assert_eq!(
+            <[i16; 8]>::from(a.widen_low()),
+            <[i8; 8]>::try_from(&i8s[..8]).unwrap().map(i16::from)
+        );
+        assert_eq!(
+            <[i16; 8]>::from(a.widen_high()),
+            <[i8; 8]>::try_from(&i8s[8..]).unwrap().map(i16::from)
+        );
+
+        let i16s: [i16; 16] = core::array::from_fn(|i| (i as i16 - 8).wrapping_mul(4099));
+        let a = i16x16::from_slice(simd, &i16s);
+        assert_eq!(<[i32; 16]>::from(a.widen()), i16s.map(i32::from));
+        assert_eq!(<[i8; 16]>::from(a.narrow()), i16s.map(|x| x as i8));
+        assert_eq!(
+            <[i8; 16]>::from(a.narrow_saturating()),
+            i16s.map(|x| x.clamp(i8::MIN.into(), i8::MAX.into()) as i8)
+        );
+        let a = i16x8::from_slice(simd, &i16s[..8]);
+        assert_eq!(
+            <[i32; 4]>::from(a.widen_high()),
+            <[i16; 4]>::try_from(&i16s[4..8]).unwrap().map(i32::from)
+        );
+
+        let u16s: [u16; 8] = core::array::from_fn(|i| (i as u16 * 9001) ^ 0xf00f);
+        let a = u16x8::from_slice(simd, &u16s);
+        assert_eq!(<[u32; 8]>::from(a.widen()), u16s.map(u32::from));
+        assert_eq!(<[u8; 8]>::from(a.narrow()), u16s.map(|x| x as u8));
+        assert_eq!(
+            <[u8; 8]>::from(a.narrow_saturating()),
+            u16s.map(|x| x.min(u8::MAX.into()) as u8)
+        );
+
+        let i32s: [i32; 8] = core::array::from_fn(|i| (i as i32 - 4).wrapping_mul(0x0123_4567));
+        let a = i32x8::from_slice(simd, &i32s);
+        assert_eq!(<[i64; 8]>::from(a.widen()), i32s.map(i64::from));
+        assert_eq!(<[i16; 8]>::from(a.narrow()), i32s.map(|x| x as i16));
+        assert_eq!(
+            <[i16; 8]>::from(a.narrow_saturating()),
+            i32s.map(|x| x.clamp(i16::MIN.into(), i16::MAX.into()) as i16)
+        );
+
+        let u32s: [u32; 8] =
+            core::array::from_fn(|i| (i as u32).wrapping_mul(0x9e37_79b9) >> (i % 3 * 8));
+        let a = u32x8::from_slice(simd, &u32s);
+        assert_eq!(<[u16; 8]>::from(a.narrow()), u32s.map(|x| x as u16));
+        assert_eq!(
+            <[u16; 8]>::from(a.narrow_saturating()),
+            u32s.map(|x| x.min(u16::MAX.into()) as u16)
+        );
+
+        let i64s: [i64; 4] = [i64::MIN, -5, 1 << 40, 0x7fff_ffff];
+        let a = i64x4::from_slice(simd, &i64s);
+        assert_eq!(<[i32; 4]>::from(a.narrow()), i64s.map(|x| x as i32));
+        assert_eq!(
+            <[i32; 4]>::from(a.narrow_saturating()),
+            i64s.map(|x| x.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+        );
+        let a = i64x2::from_slice(simd, &i64s[..2]);
+        assert_eq!(<[i32; 2]>::from(a.narrow_saturating()), [i32::MIN, -5]);
+    }
+
+    simd_dispatch!(helper(level) = helper_inner);
+    for_each_level(helper);
+}
@@ -7,7 +7,7 @@ use fearless_simd::*;
 use wasm_bindgen_test::*;
 
 /// `test_wasm_simd_parity` enforces that the fallback level and +simd128 levels output the same
-/// results.
+/// results. When built with +relaxed-simd, the relaxed level is checked as well.
 macro_rules! test_wasm_simd_parity {
     (
         fn $test_name:ident() {
@@ -25,6 +25,14 @@ macro_rules! test_wasm_simd_parity {
 
             assert_eq!(fallback_result, wasm_result,
                 concat!(stringify!($test_name), ": Expected fallback and WASM SIMD results to match."));
+
+            #[cfg(target_feature = "relaxed-simd")]
+            {
+                let relaxed_result =
+                    dispatch(Level::WasmRelaxedSimd(wasm32::WasmRelaxedSimd::new_unchecked()));
+                assert_eq!(fallback_result, relaxed_result,
+                    concat!(stringify!($test_name), ": Expected fallback and WASM relaxed SIMD results to match."));
+            }
         }
     };
 }
@@ -195,6 +203,52 @@ test_wasm_simd_parity! {
     }
 }
 
+test_wasm_simd_parity! {
+    fn msub_f32x4() {
+        |s| -> [f32; 4] {
+            let a = f32x4::from_slice(s, &[2.0, -3.0, 0.0, 0.5]);
+            let b = f32x4::from_slice(s, &[5.0, 4.0, 100.0, 8.0]);
+            let c = f32x4::from_slice(s, &[1.0, -2.0, 7.0, 3.0]);
+            a.msub(b, c).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn nmadd_f32x4() {
+        |s| -> [[f32; 4]; 2] {
+            let a = f32x4::from_slice(s, &[2.0, -3.0, 0.0, 0.5]);
+            let b = f32x4::from_slice(s, &[5.0, 4.0, 100.0, 8.0]);
+            let c = f32x4::from_slice(s, &[1.0, -2.0, 7.0, 3.0]);
+            [a.nmadd(b, c).into(), a.nmsub(b, c).into()]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn mul_add_fused_f32x4() {
+        |s| -> [u32; 4] {
+            let e = f32::EPSILON;
+            let a = f32x4::from_slice(s, &[1.0 + e, 3.0, 1e30, 16777215.0]);
+            let b = f32x4::from_slice(s, &[1.0 - e, 1.0 / 3.0, 1e10, 16777215.0]);
+            let c = f32x4::from_slice(s, &[-1.0, -1.0, -3e38, -2.8147495e14]);
+            <[f32; 4]>::from(a.mul_add_fused(b, c)).map(f32::to_bits)
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn mul_add_fused_f64x2() {
+        |s| -> [u64; 2] {
+            let e = f64::EPSILON;
+            let a = f64x2::from_slice(s, &[1.0 + e, 0.1]);
+            let b = f64x2::from_slice(s, &[1.0 - e, 10.0]);
+            let c = f64x2::from_slice(s, &[-1.0, -1.0]);
+            <[f64; 2]>::from(a.mul_add_fused(b, c)).map(f64::to_bits)
+        }
+    }
+}
+
 test_wasm_simd_parity! {
     fn max_f32x4() {
         |s| -> [f32; 4] {
@@ -1008,3 +1062,575 @@ test_wasm_simd_parity! {
         }
     }
 }
+
+// 64 bit lanes
+
+test_wasm_simd_parity! {
+    fn arith_f64x2() {
+        |s| -> [[f64; 2]; 3] {
+            let a = f64x2::from_slice(s, &[1.5, -2.25]);
+            let b = f64x2::from_slice(s, &[2.0, 0.5]);
+            [
+                (a * b - a.abs()).into(),
+                a.madd(b, a).into(),
+                a.copysign(b).max_precise(a.fract()).into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn min_max_u64x2() {
+        |s| -> [u64; 4] {
+            let a = u64x2::from_slice(s, &[u64::MAX, 1 << 63]);
+            let b = u64x2::from_slice(s, &[1, (1 << 63) - 1]);
+            s.min_u64x2(a, b).combine(s.max_u64x2(a, b)).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn simd_cmp_u64x2() {
+        |s| -> [[i64; 2]; 5] {
+            let a = u64x2::from_slice(s, &[0, u64::MAX]);
+            let b = u64x2::from_slice(s, &[1, 0]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_f64_f32x4() {
+        |s| -> [f32; 4] {
+            let a = f32x4::from_slice(s, &[0.1, -0.0, f32::MAX, 3.0]);
+            a.cvt_f64().cvt_f32().into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn zip_i64x2() {
+        |s| -> [i64; 4] {
+            let a = i64x2::from_slice(s, &[1, 2]);
+            let b = i64x2::from_slice(s, &[-1, -2]);
+            a.zip_low(b).combine(a.zip_high(b)).into()
+        }
+    }
+}
+
+// f16 conversions
+
+test_wasm_simd_parity! {
+    fn cvt_f16_f32x8() {
+        |s| -> [u16; 8] {
+            let a = f32x8::from_slice(s, &[0.1, -0.0, 65520.0, 1e-8, 2049.0, f32::NAN, -1.5, 1e10]);
+            a.cvt_f16().val.map(f16::to_bits)
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_f32_f16x8() {
+        |s| -> [f32; 8] {
+            let a = f16x8::from_slice(s, &[0x3c00, 0x8001, 0x7bff, 0xfc00, 0x0400, 0x3555, 0xc000, 0].map(f16::from_bits));
+            a.cvt_f32().into()
+        }
+    }
+}
+
+// bf16 conversions
+
+test_wasm_simd_parity! {
+    fn cvt_bf16_f32x8() {
+        |s| -> [u16; 8] {
+            let a = f32x8::from_slice(s, &[0.1, -0.0, 1.00390625, 1.01171875, 1e-40, f32::NAN, f32::MAX, 1e10]);
+            a.cvt_bf16().val.map(bf16::to_bits)
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_f32_bf16x8() {
+        |s| -> [f32; 8] {
+            let a = bf16x8::from_slice(s, &[0x3f80, 0x8001, 0x7f7f, 0xff80, 0x0080, 0x3eab, 0xc000, 0].map(bf16::from_bits));
+            a.cvt_f32().into()
+        }
+    }
+}
+
+// 64 bit vectors
+
+test_wasm_simd_parity! {
+    fn zip_low_u8x8() {
+        |s| -> [u8; 8] {
+            let a = u8x8::from_slice(s, &[0, 1, 2, 3, 4, 5, 6, 7]);
+            let b = u8x8::from_slice(s, &[8, 9, 10, 11, 12, 13, 14, 15]);
+            a.zip_low(b).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn split_f32x4() {
+        |s| -> [f32; 4] {
+            let (a, b) = f32x4::from_slice(s, &[1.0, 2.0, 3.0, 4.0]).split();
+            (b * a).combine(a).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn widen_u8x8() {
+        |s| -> [u16; 8] {
+            let a = u8x8::from_slice(s, &[0, 1, 2, 3, 128, 200, 254, 255]);
+            s.widen_u8x8(a).into()
+        }
+    }
+}
+
+// Shifts
+
+test_wasm_simd_parity! {
+    fn shl_u8x16() {
+        |s| -> [u8; 16] {
+            let a = u8x16::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0xff]);
+            (a << 5).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn shr_var_i32x4() {
+        |s| -> [i32; 4] {
+            let a = i32x4::from_slice(s, &[i32::MIN, -100, 1 << 20, 7]);
+            let b = i32x4::from_slice(s, &[31, 3, 20, 1]);
+            (a >> b).into()
+        }
+    }
+}
+
+// Saturating arithmetic
+
+test_wasm_simd_parity! {
+    fn saturating_add_u16x8() {
+        |s| -> [u16; 8] {
+            let a = u16x8::from_slice(s, &[0, 1, 0x8000, 0xffff, 100, 60000, 30000, 7]);
+            let b = u16x8::from_slice(s, &[0, 0xffff, 0x8000, 1, 200, 10000, 30000, 8]);
+            a.saturating_add(b).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn narrow_saturating_u16x16() {
+        |s| -> [u8; 16] {
+            let a = u16x16::from_slice(s, &[0, 1, 254, 255, 256, 0x7fff, 0x8000, 0xffff, 3, 300, 0x1ff, 0x100, 77, 0xff00, 12, 255]);
+            s.narrow_saturating_u16x16(a).into()
+        }
+    }
+}
+
+// Reductions
+
+test_wasm_simd_parity! {
+    fn reduce_add_f32x4() {
+        |s| -> [f32; 1] {
+            let a = f32x4::from_slice(s, &[1e8, 1.0, -1e8, 1.0]);
+            [a.reduce_add()]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reduce_max_u16x8() {
+        |s| -> [u16; 1] {
+            let a = u16x8::from_slice(s, &[3, 0x8000, 12, 0xfffe, 7, 0, 1, 0x7fff]);
+            [a.reduce_max()]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reduce_or_i8x16() {
+        |s| -> [i8; 1] {
+            let a = i8x16::from_slice(s, &[1, 2, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16]);
+            [a.reduce_or()]
+        }
+    }
+}
+
+// Mask queries
+
+test_wasm_simd_parity! {
+    fn to_bitmask_mask8x16() {
+        |s| -> [u64; 1] {
+            let a = u8x16::from_slice(s, &[0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 7]);
+            [a.simd_eq(7).to_bitmask()]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn from_bitmask_mask16x8() {
+        |s| -> [i16; 8] {
+            mask16x8::from_bitmask(s, 0b1010_0110).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn any_all_mask64x2() {
+        |s| -> [bool; 3] {
+            let a = f64x2::from_slice(s, &[1.0, -1.0]);
+            [a.simd_lt(0.0).any(), a.simd_lt(0.0).all(), a.simd_lt(2.0).all()]
+        }
+    }
+}
+
+// Shuffles
+
+test_wasm_simd_parity! {
+    fn shuffle2_f32x4() {
+        |s| -> [f32; 4] {
+            let a = f32x4::from_slice(s, &[1.0, 2.0, 3.0, 4.0]);
+            let b = f32x4::from_slice(s, &[5.0, 6.0, 7.0, 8.0]);
+            shuffle!(a, b, [0, 1, 2, 7]).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn shuffle_u8x16() {
+        |s| -> [u8; 16] {
+            let a: [u8; 16] = core::array::from_fn(|i| i as u8 * 5);
+            let a = u8x16::from_slice(s, &a);
+            shuffle!(a, [3, 3, 0, 15, 14, 1, 2, 9, 9, 9, 4, 5, 6, 7, 8, 10]).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reverse_unzip_i16x8() {
+        |s| -> [[i16; 8]; 3] {
+            let a = i16x8::from_slice(s, &[1, -2, 3, -4, 5, -6, 7, -8]);
+            let b = i16x8::from_slice(s, &[10, 20, 30, 40, 50, 60, 70, 80]);
+            [a.reverse().into(), a.unzip_low(b).into(), a.unzip_high(b).into()]
+        }
+    }
+}
+
+// Byte lookups
+
+test_wasm_simd_parity! {
+    fn lookup_32_u8x16() {
+        |s| -> [u8; 16] {
+            let table: [u8; 32] = core::array::from_fn(|i| 200 - i as u8);
+            let table = u8x32::from_slice(s, &table);
+            let idx = u8x16::from_slice(s, &[0, 15, 16, 17, 127, 128, 255, 3, 1, 2, 31, 32, 8, 9, 112, 240]);
+            idx.lookup_32(table).into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn lookup_48_u8x16() {
+        |s| -> [u8; 16] {
+            let table: [u8; 48] = core::array::from_fn(|i| 200 - i as u8);
+            let table = [0, 16, 32].map(|i| u8x16::from_slice(s, &table[i..i + 16]));
+            let idx = u8x16::from_slice(s, &[0, 15, 16, 47, 48, 49, 255, 3, 32, 33, 31, 64, 8, 9, 112, 240]);
+            idx.lookup_48(table).into()
+        }
+    }
+}
+
+// Rounding
+
+test_wasm_simd_parity! {
+    fn round_f32x4() {
+        |s| -> [[f32; 4]; 3] {
+            let a = f32x4::from_slice(s, &[-2.5, -0.5, 1.5, 8388607.5]);
+            [a.ceil().into(), a.round().into(), a.round_ties_even().into()]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_u32_round_f32x4() {
+        |s| -> [u32; 4] {
+            let a = f32x4::from_slice(s, &[-2.5, 0.5, 2.5, 5e9]);
+            a.cvt_u32_round().into()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn recip_rsqrt_f32x4() {
+        |s| -> [u32; 8] {
+            let a = f32x4::from_slice(s, &[3.0, -0.1, 1e-30, f32::INFINITY]);
+            let b = f32x4::from_slice(s, &[3.0, 0.1, 1e-30, 0.0]);
+            let recip = <[f32; 4]>::from(a.recip(2)).map(f32::to_bits);
+            let rsqrt = <[f32; 4]>::from(b.rsqrt_approx()).map(f32::to_bits);
+            [recip, rsqrt].concat().try_into().unwrap()
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn float_classification_f32x4() {
+        |s| -> [[i32; 4]; 6] {
+            let a = f32x4::from_slice(s, &[f32::NAN, -f32::INFINITY, -1e-40, f32::MIN_POSITIVE]);
+            [
+                a.simd_ne(a).into(),
+                a.is_nan().into(),
+                a.is_finite().into(),
+                a.is_infinite().into(),
+                a.is_subnormal().into(),
+                a.is_sign_negative().into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn integer_abs_diff_avg_round() {
+        |s| -> [[u8; 16]; 4] {
+            let a = i8x16::from_slice(s, &core::array::from_fn::<i8, 16, _>(|i| (i * 53) as i8));
+            let b = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| (i * 37) as u8));
+            let c = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| (255 - i * 7) as u8));
+            [
+                <[i8; 16]>::from(a.abs()).map(|x| x as u8),
+                a.abs_diff(-a).into(),
+                b.abs_diff(c).into(),
+                b.avg_round(c).into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn widening_multiplies_i16x8() {
+        |s| -> [[i32; 8]; 3] {
+            let a = i16x8::from_slice(s, &core::array::from_fn::<i16, 8, _>(|i| i16::MIN.wrapping_add((i as i16).wrapping_mul(8191))));
+            let b = a.reverse();
+            let dot = <[i32; 4]>::from(a.dot_i16(b));
+            let high = <[i16; 8]>::from(a.mul_high(b));
+            let q15 = <[i16; 8]>::from(a.mul_q15_round_sat(a));
+            [
+                a.mul_widen(b).into(),
+                core::array::from_fn(|i| dot[i % 4]),
+                core::array::from_fn(|i| (high[i] as i32) << 16 | (q15[i] as u16 as i32)),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn widening_multiplies_u8x16() {
+        |s| -> [[u16; 16]; 2] {
+            let a = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| (i as u8).wrapping_mul(73) ^ 0x81));
+            let high = <[u8; 16]>::from(a.mul_high(a.reverse()));
+            [a.mul_widen(a.reverse()).into(), high.map(u16::from)]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn widen_narrow_i16x16() {
+        |s| -> [[i16; 16]; 3] {
+            let a = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| (i as i16 - 8).wrapping_mul(4099)));
+            let b = i8x16::from_slice(s, &core::array::from_fn::<i8, 16, _>(|i| (i as i8).wrapping_mul(-45) ^ 0x40));
+            let narrow = <[i8; 16]>::from(a.narrow());
+            let saturated = <[i8; 16]>::from(a.narrow_saturating());
+            [
+                b.widen().into(),
+                core::array::from_fn(|i| narrow[i] as i16),
+                core::array::from_fn(|i| saturated[i] as i16),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn widen_narrow_u32x8() {
+        |s| -> [[u16; 8]; 4] {
+            let a = u32x8::from_slice(s, &core::array::from_fn::<u32, 8, _>(|i| (i as u32).wrapping_mul(0x9e37_79b9) >> (i % 3 * 8)));
+            let b = u16x8::from_slice(s, &core::array::from_fn::<u16, 8, _>(|i| (i as u16 * 9001) ^ 0xf00f));
+            let low = <[u32; 4]>::from(b.widen_low());
+            let high = <[u32; 4]>::from(b.widen_high());
+            [
+                a.narrow_saturating().into(),
+                core::array::from_fn(|i| <[u8; 8]>::from(b.narrow_saturating())[i] as u16),
+                core::array::from_fn(|i| low[i % 4] as u16),
+                core::array::from_fn(|i| high[i % 4] as u16),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_f64x2_i64x2() {
+        |s| -> [[i64; 2]; 4] {
+            let a = f64x2::from_slice(s, &[f64::NAN, -2.5]);
+            let b = f64x2::from_slice(s, &[1e19, -1e19]);
+            let c = i64x2::from_slice(s, &[i64::MIN, (1 << 53) + 1]);
+            [
+                a.cvt_i64_round().into(),
+                <[u64; 2]>::from(b.cvt_u64()).map(|x| x as i64),
+                b.cvt_i64().into(),
+                <[f64; 2]>::from(c.cvt_f64()).map(|x| x as i64),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn cvt_f32x8_u8x8() {
+        |s| -> [[u8; 8]; 2] {
+            let a = f32x8::from_slice(s, &core::array::from_fn::<f32, 8, _>(|i| (i as f32 - 2.0) * 75.5));
+            let b = u8x8::from_slice(s, &core::array::from_fn::<u8, 8, _>(|i| (i as u8).wrapping_mul(37)));
+            [
+                a.cvt_u8_round().into(),
+                (b.cvt_f32() * (1.0 / 255.0) * 255.0).cvt_u8_round().into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reinterpret_f32x4_f16x8() {
+        |s| -> [[u16; 8]; 2] {
+            let a = f32x4::from_slice(s, &[1.5, -0.0, f32::NAN, 3.0e-9]);
+            [
+                a.reinterpret_f16().reinterpret_u16().into(),
+                a.reinterpret_f64().reinterpret_bf16().reinterpret_u16().into(),
+            ]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn shift_counts_u8x16() {
+        |s| -> [[u8; 16]; 3] {
+            let a = u8x16::splat(s, 0xa5);
+            let b = u8x16::from_slice(s, &[0, 7, 8, 255, 0, 7, 8, 255, 0, 7, 8, 255, 0, 7, 8, 255]);
+            let uniform: [u8; 16] = core::array::from_fn(|i| {
+                let c = [0, 7, 8, 255][i % 4];
+                if i < 8 { (a << c)[0] } else { (a >> c)[0] }
+            });
+            [(a << b).into(), (a >> b).into(), uniform]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn shift_counts_i32x4() {
+        |s| -> [[i32; 4]; 4] {
+            let a = i32x4::splat(s, -0x5a5a_5a5b);
+            let b = i32x4::from_slice(s, &[0, 31, 32, -1]);
+            let shl = [0, 31, 32, 255].map(|c| (a << c)[0]);
+            let shr = [0, 31, 32, 255].map(|c| (a >> c)[0]);
+            [(a << b).into(), (a >> b).into(), shl, shr]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn shift_counts_u64x2() {
+        |s| -> [[u64; 4]; 2] {
+            let a = u64x2::splat(s, 0xa5a5_a5a5_a5a5_a5a5);
+            let shl = [0, 63, 64, 255].map(|c| (a << c)[0]);
+            let shr = [0, 63, 64, 255].map(|c| (a >> c)[0]);
+            [shl, shr]
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn lane_moves_128() {
+        |s| -> ([[u8; 16]; 4], [[i16; 8]; 3], [[f32; 4]; 3], [[u64; 2]; 3]) {
+            let a = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| i as u8));
+            let b = i16x8::from_slice(s, &[1, -2, 3, -4, 5, -6, 7, -8]);
+            let c = f32x4::from_slice(s, &[1.0, 2.0, 3.0, 4.0]);
+            let d = u64x2::from_slice(s, &[1, 2]);
+            (
+                [
+                    a.rotate_lanes_left::<0>().into(),
+                    a.rotate_lanes_left::<15>().into(),
+                    a.rotate_lanes_right::<17>().into(),
+                    a.broadcast_lane::<13>().into(),
+                ],
+                [
+                    b.rotate_lanes_left::<3>().into(),
+                    b.rotate_lanes_right::<8>().into(),
+                    b.broadcast_lane::<7>().into(),
+                ],
+                [
+                    c.rotate_lanes_left::<5>().into(),
+                    c.rotate_lanes_right::<3>().into(),
+                    c.broadcast_lane::<2>().into(),
+                ],
+                [
+                    d.rotate_lanes_left::<1>().into(),
+                    d.rotate_lanes_right::<2>().into(),
+                    d.broadcast_lane::<1>().into(),
+                ],
+            )
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn lane_moves_256() {
+        |s| -> ([[u8; 32]; 3], [[f32; 8]; 3]) {
+            let a = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| i as u8));
+            let b = f32x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            (
+                [
+                    a.rotate_lanes_left::<20>().into(),
+                    a.rotate_lanes_right::<33>().into(),
+                    a.broadcast_lane::<30>().into(),
+                ],
+                [
+                    b.rotate_lanes_left::<3>().into(),
+                    b.rotate_lanes_right::<5>().into(),
+                    b.broadcast_lane::<6>().into(),
+                ],
+            )
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reduce_min_max_nan() {
+        |s| -> [u32; 6] {
+            let nan = f32::NAN;
+            let a = f32x4::from_slice(s, &[1.0, nan, 2.0, 3.0]);
+            let b = f32x8::from_slice(s, &[5.0, 1.0, 2.0, 3.0, 4.0, 6.0, 7.0, nan]);
+            let c = f32x4::splat(s, nan);
+            [
+                a.reduce_min(),
+                a.reduce_max(),
+                b.reduce_min(),
+                b.reduce_max(),
+                c.reduce_min(),
+                c.reduce_max(),
+            ]
+            .map(f32::to_bits)
+        }
+    }
+}
+
+test_wasm_simd_parity! {
+    fn reduce_min_max_nan_f64x2() {
+        |s| -> [u64; 2] {
+            let a = f64x2::from_slice(s, &[f64::NAN, -3.0]);
+            [a.reduce_min(), a.reduce_max()].map(f64::to_bits)
+        }
+    }
+}
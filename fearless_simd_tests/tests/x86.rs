@@ -0,0 +1,1688 @@
+// Copyright 2025 the Fearless_SIMD Authors
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_arch = "x86_64")]
+
+use fearless_simd::*;
+
+/// All x86 levels supported by the CPU running the tests.
+pub(crate) fn x86_levels() -> Vec<Level> {
+    let mut levels = vec![];
+    if std::arch::is_x86_feature_detected!("sse4.2") {
+        levels.push(Level::Sse4_2(unsafe { x86_64::Sse4_2::new_unchecked() }));
+    }
+    if std::arch::is_x86_feature_detected!("avx2")
+        && std::arch::is_x86_feature_detected!("fma")
+        && std::arch::is_x86_feature_detected!("f16c")
+    {
+        levels.push(Level::Avx2(unsafe { x86_64::Avx2::new_unchecked() }));
+    }
+    if std::arch::is_x86_feature_detected!("avx512f")
+        && std::arch::is_x86_feature_detected!("avx512bw")
+        && std::arch::is_x86_feature_detected!("avx512cd")
+        && std::arch::is_x86_feature_detected!("avx512dq")
+        && std::arch::is_x86_feature_detected!("avx512vl")
+    {
+        levels.push(Level::Avx512(unsafe { x86_64::Avx512::new_unchecked() }));
+    }
+    levels
+}
+
+#[test]
+fn level_downcasts() {
+    // Every level can be used as the levels it is a superset of.
+    for level in x86_levels() {
+        assert!(level.as_sse4_2().is_some(), "{level:?}");
+        if matches!(level, Level::Avx2(_) | Level::Avx512(_)) {
+            assert!(level.as_avx2().is_some(), "{level:?}");
+        }
+    }
+}
+
+/// `test_x86_parity` enforces that the fallback level and every available x86 level output the
+/// same results.
+macro_rules! test_x86_parity {
+    (
+        fn $test_name:ident() {
+            |$s:ident| -> $ret_type:ty $body:block
+        }
+    ) => {
+        #[test]
+        fn $test_name() {
+            fn test_impl<S: Simd>($s: S) -> $ret_type $body
+
+            simd_dispatch!(dispatch(level) -> $ret_type = test_impl);
+
+            let fallback_result = dispatch(Level::Fallback(Fallback::new()));
+            for level in x86_levels() {
+                assert_eq!(fallback_result, dispatch(level),
+                    "{}: Expected fallback and {level:?} results to match.", stringify!($test_name));
+            }
+        }
+    };
+}
+
+test_x86_parity! {
+    fn add_f32x8() {
+        |s| -> [f32; 8] {
+            let a = f32x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let b = f32x8::from_slice(s, &[5.0, 4.0, 3.0, 2.0, 1.0, 0.0, -1.0, -2.0]);
+            (a + b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn abs_neg_f32x4() {
+        |s| -> [f32; 8] {
+            let a = f32x4::from_slice(s, &[-1.0, 0.0, -0.0, 2.3]);
+            a.abs().combine(-a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn copysign_f32x8() {
+        |s| -> [f32; 8] {
+            let a = f32x8::from_slice(s, &[1.0, -2.0, 3.0, -4.0, 0.0, -0.0, f32::INFINITY, 8.0]);
+            let b = f32x8::from_slice(s, &[-1.0, 1.0, 0.0, -0.0, -5.0, 5.0, -1.0, 1.0]);
+            a.copysign(b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn floor_trunc_fract_f32x8() {
+        |s| -> [[f32; 8]; 3] {
+            let a = f32x8::from_slice(s, &[1.7, -2.3, 3.9, -4.1, 0.0, -0.5, 1e10, -1e10]);
+            [a.floor().into(), a.trunc().into(), s.fract_f32x8(a).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_f32x8() {
+        |s| -> [[i32; 8]; 5] {
+            let a = f32x8::from_slice(s, &[4.0, 3.0, 2.0, 1.0, f32::NAN, 0.0, -1.0, 5.0]);
+            let b = f32x8::from_slice(s, &[1.0, 2.0, 2.0, 4.0, 1.0, -0.0, -1.0, f32::NAN]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn madd_f32x8() {
+        |s| -> [[f32; 8]; 2] {
+            let a = f32x8::from_slice(s, &[2.0, -3.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0]);
+            let b = f32x8::from_slice(s, &[5.0, 4.0, 100.0, 8.0, 0.25, 0.5, 2.0, -1.0]);
+            let c = f32x8::from_slice(s, &[1.0, -2.0, 7.0, 3.0, 4.0, 4.0, -8.0, 16.0]);
+            [a.madd(b, c).into(), a.msub(b, c).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn nmadd_f32x8() {
+        |s| -> [[f32; 8]; 2] {
+            let a = f32x8::from_slice(s, &[2.0, -3.0, 0.0, 0.5, 1.0, 2.0, 3.0, 4.0]);
+            let b = f32x8::from_slice(s, &[5.0, 4.0, 100.0, 8.0, 0.25, 0.5, 2.0, -1.0]);
+            let c = f32x8::from_slice(s, &[1.0, -2.0, 7.0, 3.0, 4.0, 4.0, -8.0, 16.0]);
+            [a.nmadd(b, c).into(), a.nmsub(b, c).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_add_fused_f32x8() {
+        |s| -> [u32; 8] {
+            let e = f32::EPSILON;
+            let a = f32x8::from_slice(s, &[1.0 + e, 1.0 + e, 3.0, 1e30, -1e-30, 0.1, 1.0 - e, 16777215.0]);
+            let b = f32x8::from_slice(s, &[1.0 - e, 1.0 + e, 1.0 / 3.0, 1e10, 1e-20, 10.0, 1.0 + e, 16777215.0]);
+            let c = f32x8::from_slice(s, &[-1.0, -1.0, -1.0, -3e38, 1e-45, -1.0, 0.5, -2.8147495e14]);
+            <[f32; 8]>::from(a.mul_add_fused(b, c)).map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_add_fused_f64x4() {
+        |s| -> [u64; 4] {
+            let e = f64::EPSILON;
+            let a = f64x4::from_slice(s, &[1.0 + e, 3.0, 0.1, 1e300]);
+            let b = f64x4::from_slice(s, &[1.0 - e, 1.0 / 3.0, 10.0, 1e10]);
+            let c = f64x4::from_slice(s, &[-1.0, -1.0, -1.0, -1e308]);
+            <[f64; 4]>::from(a.mul_add_fused(b, c)).map(f64::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_u8x16() {
+        |s| -> [u8; 16] {
+            let a = u8x16::from_slice(s, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 200, 255]);
+            let b = u8x16::from_slice(s, &[0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 255]);
+            (a * b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_i8x32() {
+        |s| -> [i8; 32] {
+            let a = i8x32::from_slice(s, &[
+                0, -0, 3, -3, 0, -0, 3, -3, 0, -0, 3, -3, 0, -0, 3, -3,
+                127, -128, 100, -100, 7, -7, 11, -11, 1, 2, 3, 4, 5, 6, 7, 8,
+            ]);
+            let b = i8x32::from_slice(s, &[
+                0, 0, 0, 0, -0, -0, -0, -0, 3, 3, 3, 3, -3, -3, -3, -3,
+                2, 2, -3, 3, 19, 19, -19, -19, -1, -2, -3, -4, -5, -6, -7, -8,
+            ]);
+            (a * b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_i32x8() {
+        |s| -> [i32; 8] {
+            let a = i32x8::from_slice(s, &[1, -2, 3, i32::MAX, i32::MIN, 65536, -7, 0]);
+            let b = i32x8::from_slice(s, &[5, 4, -3, 2, 2, 65536, -7, 100]);
+            (a * b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn min_max_u32x4() {
+        |s| -> [u32; 8] {
+            let a = u32x4::from_slice(s, &[0, u32::MAX, 2147483648, 5]);
+            let b = u32x4::from_slice(s, &[1, 1, 2147483647, 5]);
+            s.min_u32x4(a, b).combine(s.max_u32x4(a, b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_u8x16() {
+        |s| -> [[i8; 16]; 5] {
+            let a = u8x16::from_slice(s, &[0, 1, 127, 128, 255, 0, 200, 100, 5, 5, 6, 7, 255, 254, 128, 127]);
+            let b = u8x16::from_slice(s, &[1, 0, 128, 127, 0, 255, 100, 200, 5, 6, 5, 7, 254, 255, 128, 128]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_i16x16() {
+        |s| -> [[i16; 16]; 5] {
+            let a = i16x16::from_slice(s, &[
+                0, 1, -1, i16::MIN, i16::MAX, 5, -5, 100,
+                7, 8, 9, 10, -11, -12, -13, -14,
+            ]);
+            let b = i16x16::from_slice(s, &[
+                1, 0, 1, i16::MAX, i16::MIN, 5, -5, -100,
+                8, 7, 9, 10, -12, -11, -13, 14,
+            ]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_u32x8() {
+        |s| -> [[i32; 8]; 5] {
+            let a = u32x8::from_slice(s, &[0, 1, u32::MAX, 2147483648, 5, 7, 9, 2147483647]);
+            let b = u32x8::from_slice(s, &[1, 0, 0, 2147483647, 5, 8, 9, 2147483648]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_i8x32() {
+        |s| -> [i8; 32] {
+            let a = i8x32::from_slice(s, &[
+                -128, -64, -32, -16, -8, -4, -2, -1,
+                127, 64, 32, 16, 8, 4, 2, 1,
+                -127, -63, -31, -15, -7, -3, -1, 0,
+                126, 63, 31, 15, 7, 3, 1, 0,
+            ]);
+            a.shr(3).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_u8x32() {
+        |s| -> [u8; 32] {
+            let a = u8x32::from_slice(s, &[
+                255, 128, 64, 32, 16, 8, 4, 2,
+                254, 127, 63, 31, 15, 7, 3, 1,
+                255, 192, 160, 144, 136, 132, 130, 129,
+                0, 1, 2, 3, 4, 5, 6, 7,
+            ]);
+            a.shr(3).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_i8x16() {
+        |s| -> [i8; 16] {
+            let a = i8x16::from_slice(s, &[
+                -128, -64, -32, -16, -8, -4, -2, -1,
+                127, 64, 32, 16, 8, 4, 2, 1
+            ]);
+            a.shr(2).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_u16x16() {
+        |s| -> [u16; 16] {
+            let a = u16x16::from_slice(s, &[
+                65535, 32768, 16384, 8192, 4096, 2048, 1024, 512,
+                1, 2, 3, 4, 5, 6, 7, 65534,
+            ]);
+            a.shr(4).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_i32x8() {
+        |s| -> [i32; 8] {
+            let a = i32x8::from_slice(s, &[i32::MIN, -65536, 65536, i32::MAX, -1, 1, -256, 256]);
+            a.shr(8).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_u32_f32x8() {
+        |s| -> [u32; 8] {
+            let a = f32x8::from_slice(s, &[
+                -1.0, f32::NAN, 1.5, 2147483648.0, 3e9, 4294967040.0, 5e9, f32::INFINITY,
+            ]);
+            a.cvt_u32().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_i32_f32x4() {
+        |s| -> [i32; 8] {
+            let a = f32x4::from_slice(s, &[-1.5, f32::NAN, 2147483648.0, -3e9]);
+            let b = f32x4::from_slice(s, &[2147483520.0, -2147483648.0, 0.99, -0.99]);
+            a.cvt_i32().combine(b.cvt_i32()).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_u32x8() {
+        |s| -> [f32; 8] {
+            let a = u32x8::from_slice(s, &[0, 1, 65535, 65536, 16777217, 2147483649, 4294967295, 123456789]);
+            a.cvt_f32().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_i32x4() {
+        |s| -> [f32; 4] {
+            let a = i32x4::from_slice(s, &[i32::MIN, -1, 16777217, i32::MAX]);
+            a.cvt_f32().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_f32x8() {
+        |s| -> [f32; 16] {
+            let a = f32x8::from_slice(s, &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+            let b = f32x8::from_slice(s, &[8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0]);
+            s.zip_low_f32x8(a, b).combine(s.zip_high_f32x8(a, b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_u8x32() {
+        |s| -> [u8; 64] {
+            let a = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| i as u8));
+            let b = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| 100 + i as u8));
+            s.zip_low_u8x32(a, b).combine(s.zip_high_u8x32(a, b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_i16x8() {
+        |s| -> [i16; 16] {
+            let a = i16x8::from_slice(s, &[0, 1, 2, 3, 4, 5, 6, 7]);
+            let b = i16x8::from_slice(s, &[-8, -9, -10, -11, -12, -13, -14, -15]);
+            s.zip_low_i16x8(a, b).combine(s.zip_high_i16x8(a, b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn split_combine_u32x8() {
+        |s| -> [u32; 8] {
+            let a = u32x8::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            let (lo, hi) = s.split_u32x8(a);
+            hi.combine(lo).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn select_f32x8() {
+        |s| -> [f32; 8] {
+            let mask = mask32x8::from_slice(s, &[-1, 0, -1, 0, 0, 0, -1, -1]);
+            let b = f32x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let c = f32x8::from_slice(s, &[9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0]);
+            mask.select(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn select_u16x16() {
+        |s| -> [u16; 16] {
+            let mask = mask16x16::from_slice(s, &[0, -1, 0, -1, 0, -1, 0, -1, -1, -1, 0, 0, -1, -1, 0, 0]);
+            let b = u16x16::from_slice(s, &[1000, 2000, 3000, 4000, 5000, 6000, 7000, 8000, 1, 2, 3, 4, 5, 6, 7, 8]);
+            let c = u16x16::from_slice(s, &[100, 200, 300, 400, 500, 600, 700, 800, 9, 10, 11, 12, 13, 14, 15, 16]);
+            mask.select(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn not_mask8x32() {
+        |s| -> [i8; 32] {
+            let a = mask8x32::from_slice(s, &[
+                0, -1, 0, -1, 0, -1, 0, -1, -1, -1, 0, 0, -1, -1, 0, 0,
+                -1, -1, -1, -1, 0, 0, 0, 0, -1, 0, -1, 0, -1, 0, -1, 0,
+            ]);
+            (!a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widen_u8x32() {
+        |s| -> [u16; 32] {
+            let a = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| (i * 8) as u8));
+            s.widen_u8x32(a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn narrow_u16x16() {
+        |s| -> [u8; 16] {
+            let a = u16x16::from_slice(s, &[
+                0, 1, 127, 128, 255, 256, 300, 1000,
+                128, 192, 224, 240, 248, 252, 254, 65535
+            ]);
+            s.narrow_u16x16(a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn load_interleaved_128_u8x64() {
+        |s| -> [u8; 64] {
+            let data = core::array::from_fn::<u8, 64, _>(|i| i as u8);
+            s.load_interleaved_128_u8x64(&data).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn load_interleaved_128_f32x16() {
+        |s| -> [f32; 16] {
+            let data = core::array::from_fn::<f32, 16, _>(|i| i as f32);
+            s.load_interleaved_128_f32x16(&data).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn store_interleaved_128_u16x32() {
+        |s| -> [u16; 32] {
+            let input = core::array::from_fn::<u16, 32, _>(|i| 1000 + i as u16);
+            let a = u16x32::from_slice(s, &input);
+            let mut dest = [0u16; 32];
+            s.store_interleaved_128_u16x32(a, &mut dest);
+            dest
+        }
+    }
+}
+
+test_x86_parity! {
+    fn store_interleaved_128_f32x16() {
+        |s| -> [f32; 16] {
+            let input = core::array::from_fn::<f32, 16, _>(|i| i as f32 * 0.5);
+            let a = f32x16::from_slice(s, &input);
+            let mut dest = [0.0f32; 16];
+            s.store_interleaved_128_f32x16(a, &mut dest);
+            dest
+        }
+    }
+}
+
+test_x86_parity! {
+    fn floor_trunc_f32x16() {
+        |s| -> [[f32; 16]; 2] {
+            let a = f32x16::from_slice(s, &[
+                1.7, -2.3, 3.9, -4.1, 0.0, -0.5, 1e10, -1e10,
+                0.5, -0.0, 2.0, -2.0, 8388607.5, -8388607.5, f32::INFINITY, f32::NEG_INFINITY,
+            ]);
+            [a.floor().into(), a.trunc().into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn madd_f32x16() {
+        |s| -> [[f32; 16]; 2] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            let b = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| 0.5 * i as f32));
+            let c = f32x16::splat(s, -3.0);
+            [a.madd(b, c).into(), a.msub(b, c).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_f32x16() {
+        |s| -> [[i32; 16]; 5] {
+            let a = f32x16::from_slice(s, &[
+                4.0, 3.0, 2.0, 1.0, f32::NAN, 0.0, -1.0, 5.0,
+                f32::INFINITY, -2.0, 7.0, 7.5, f32::NAN, 1e10, -0.0, 3.0,
+            ]);
+            let b = f32x16::from_slice(s, &[
+                1.0, 2.0, 2.0, 4.0, 1.0, -0.0, -1.0, f32::NAN,
+                f32::INFINITY, -3.0, 7.0, 8.0, f32::NAN, 1e9, 0.0, 3.5,
+            ]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_u8x64() {
+        |s| -> [[i8; 64]; 5] {
+            let a = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| (i * 37) as u8));
+            let b = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| (i * 53 + 7) as u8));
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_i16x32() {
+        |s| -> [[i16; 32]; 5] {
+            let a = i16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| (i as i16 - 16) * 2000));
+            let b = i16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| (8 - i as i16) * 1000));
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_u32x16() {
+        |s| -> [[i32; 16]; 5] {
+            let a = u32x16::from_slice(s, &[
+                0, 1, u32::MAX, 2147483648, 5, 7, 9, 2147483647,
+                3, 3, 0, u32::MAX, 10, 20, 30, 40,
+            ]);
+            let b = u32x16::from_slice(s, &[
+                1, 0, 0, 2147483647, 5, 8, 9, 2147483648,
+                3, 4, u32::MAX, 0, 40, 30, 20, 10,
+            ]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_i8x64() {
+        |s| -> [[i8; 64]; 2] {
+            let a = i8x64::from_slice(s, &core::array::from_fn::<i8, 64, _>(|i| (i * 13) as i8));
+            [a.shr(1).into(), a.shr(7).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_u8x64() {
+        |s| -> [u8; 64] {
+            let a = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| i as u8));
+            let b = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| (i * 7) as u8));
+            (a * b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_i32_u32_f32x16() {
+        |s| -> ([i32; 16], [u32; 16]) {
+            let a = f32x16::from_slice(s, &[
+                -1.5, f32::NAN, 2147483648.0, -3e9, 2147483520.0, -2147483648.0, 0.99, -0.99,
+                1.5, 3e9, 4294967040.0, 5e9, f32::INFINITY, f32::NEG_INFINITY, 0.0, 42.7,
+            ]);
+            (a.cvt_i32().into(), a.cvt_u32().into())
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_u32x16() {
+        |s| -> [f32; 16] {
+            let a = u32x16::from_slice(s, &[
+                0, 1, 16777217, 2147483648, u32::MAX, 4294967040, 65535, 65536,
+                123456789, 3000000000, 7, 8, 9, 10, 11, 12,
+            ]);
+            a.cvt_f32().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_f32x16() {
+        |s| -> [f32; 32] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            let b = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| -(i as f32)));
+            let mut out = [0.0; 32];
+            out[..16].copy_from_slice(&s.zip_low_f32x16(a, b).val);
+            out[16..].copy_from_slice(&s.zip_high_f32x16(a, b).val);
+            out
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_u8x64() {
+        |s| -> [[u8; 64]; 2] {
+            let a = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| i as u8));
+            let b = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| 100 + i as u8));
+            [s.zip_low_u8x64(a, b).into(), s.zip_high_u8x64(a, b).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn split_combine_f32x16() {
+        |s| -> [f32; 16] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            let (lo, hi) = s.split_f32x16(a);
+            s.combine_f32x8(hi, lo).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn select_f32x16() {
+        |s| -> [f32; 16] {
+            let mask = mask32x16::from_slice(s, &[-1, 0, -1, 0, 0, 0, -1, -1, 0, -1, 0, -1, -1, 0, 0, -1]);
+            let b = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            let c = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| 100.0 + i as f32));
+            mask.select(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn select_u8x64() {
+        |s| -> [u8; 64] {
+            let mask = mask8x64::from_slice(s, &core::array::from_fn::<i8, 64, _>(|i| if i % 3 == 0 { -1 } else { 0 }));
+            let b = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| i as u8));
+            let c = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| 200 - i as u8));
+            mask.select(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn select_mask16x32() {
+        |s| -> [i16; 32] {
+            let mask = mask16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| if i % 2 == 0 { -1 } else { 0 }));
+            let b = mask16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| if i % 4 == 0 { -1 } else { 0 }));
+            let c = mask16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| if i % 5 == 0 { -1 } else { 0 }));
+            s.select_mask16x32(mask, b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn arith_f16x16() {
+        |s| -> [f16; 16] {
+            let a = f16x16::from_slice(
+                s,
+                &[1.0, -2.5, 3.25, 0.0, 7.0, -0.125, 100.0, 2.0, 9.5, -3.0, 0.75, 6.0, -8.0, 1.25, 4.0, -0.5]
+                    .map(f16::from_f32_const),
+            );
+            let b = a.zip_low(a.sqrt());
+            (a * b - a.fract()).max(a.abs()).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn arith_f64x4() {
+        |s| -> [[f64; 4]; 4] {
+            let a = f64x4::from_slice(s, &[1.5, -2.25, 1e300, -0.0]);
+            let b = f64x4::from_slice(s, &[2.0, 0.5, -1e10, 3.0]);
+            [
+                (a * b - a.abs()).into(),
+                a.madd(b, a).into(),
+                a.copysign(b).max(a.floor()).into(),
+                (-a).trunc().min_precise(a.fract()).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_f64x8() {
+        |s| -> [[i64; 8]; 5] {
+            let a = f64x8::from_slice(s, &[0.0, 1.0, -1.0, f64::NAN, 2.0, f64::INFINITY, -0.0, 5.0]);
+            let b = f64x8::from_slice(s, &[1.0, 0.0, -1.0, 1.0, 3.0, f64::INFINITY, 0.0, f64::NAN]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mul_u64x4() {
+        |s| -> ([u64; 4], [i64; 4]) {
+            let a = u64x4::from_slice(s, &[u64::MAX, 1 << 40, 0x1234_5678_9abc_def0, 7]);
+            let b = u64x4::from_slice(s, &[3, (1 << 40) + 5, 0xfedc_ba98_7654_3210, 0]);
+            let c = i64x4::from_slice(s, &[i64::MIN, -5, -0x1234_5678_9abc, 3]);
+            let d = i64x4::from_slice(s, &[7, -6, 0x7654_3210, -1]);
+            ((a * b).into(), (c * d).into())
+        }
+    }
+}
+
+test_x86_parity! {
+    fn min_max_u64x4() {
+        |s| -> [u64; 8] {
+            let a = u64x4::from_slice(s, &[0, u64::MAX, 1 << 63, 5]);
+            let b = u64x4::from_slice(s, &[1, 1, (1 << 63) - 1, 5]);
+            s.min_u64x4(a, b).combine(s.max_u64x4(a, b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_u64x4() {
+        |s| -> [[i64; 4]; 5] {
+            let a = u64x4::from_slice(s, &[0, u64::MAX, 1 << 63, 5]);
+            let b = u64x4::from_slice(s, &[1, 0, (1 << 63) - 1, 5]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_cmp_i64x2() {
+        |s| -> [[i64; 2]; 5] {
+            let a = i64x2::from_slice(s, &[i64::MIN, -1]);
+            let b = i64x2::from_slice(s, &[i64::MAX, -1]);
+            [
+                a.simd_eq(b).into(),
+                a.simd_lt(b).into(),
+                a.simd_le(b).into(),
+                a.simd_ge(b).into(),
+                a.simd_gt(b).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_i64x4() {
+        |s| -> [i64; 8] {
+            let a = i64x4::from_slice(s, &[i64::MIN, -1, i64::MAX, -0x1234_5678_9abc]);
+            a.shr(7).combine(a.shr(63)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f64_f32x8() {
+        |s| -> [f64; 8] {
+            let a = f32x8::from_slice(s, &[0.1, -0.0, f32::MAX, 3.0, f32::MIN_POSITIVE, -1e-40, 7.5, f32::INFINITY]);
+            a.cvt_f64().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_f64x8() {
+        |s| -> [f32; 8] {
+            let a = f64x8::from_slice(s, &[0.1, -0.0, 1e300, 3.0, 1e-300, -1e-40, 7.5, 0.30000000000000004]);
+            a.cvt_f32().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_select_f64x8() {
+        |s| -> [f64; 8] {
+            let a = f64x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let b = f64x8::from_slice(s, &[-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0]);
+            let mask = mask64x8::from_slice(s, &[-1, 0, 0, -1, -1, 0, -1, 0]);
+            mask.select(a.zip_low(b), a.zip_high(b)).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f16_f32x16() {
+        |s| -> [u16; 16] {
+            let a = f32x16::from_slice(
+                s,
+                &[
+                    0.1, -0.0, 65519.0, 65520.0, 1e-8, -3e-5, 2049.0, 2051.0,
+                    f32::NAN, f32::NEG_INFINITY, 6.1035156e-5, -1.5, 1e10, 0.33333334, 4096.5, 7.0,
+                ],
+            );
+            a.cvt_f16().val.map(f16::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_bf16_f32x16() {
+        |s| -> [u16; 16] {
+            let a = f32x16::from_slice(
+                s,
+                &[
+                    0.1, -0.0, 1.00390625, 1.01171875, 1e-40, -3e-39, f32::MAX, -f32::MAX,
+                    f32::NAN, f32::NEG_INFINITY, 6.1035156e-5, -1.5, 1e10, 0.33333334, 4096.5, 7.0,
+                ],
+            );
+            a.cvt_bf16().val.map(bf16::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_bf16x16() {
+        |s| -> [u32; 16] {
+            let a = bf16x16::from_slice(
+                s,
+                &core::array::from_fn::<u16, 16, _>(|i| (i as u16).wrapping_mul(0x1357) ^ 0x8000)
+                    .map(bf16::from_bits),
+            );
+            a.cvt_f32().val.map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn dot_bf16_f32x8() {
+        |s| -> [f32; 8] {
+            let a = f32x8::from_slice(s, &[1.0, -2.0, 0.5, 100.0, 0.0, -0.25, 3.0, 1e6]);
+            let b = bf16x16::from_slice(
+                s,
+                &core::array::from_fn::<f32, 16, _>(|i| i as f32 * 0.75 - 4.0).map(bf16::from_f32_const),
+            );
+            let c = bf16x16::from_slice(
+                s,
+                &core::array::from_fn::<f32, 16, _>(|i| 2.5 - i as f32).map(bf16::from_f32_const),
+            );
+            a.dot_bf16(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn mmla_bf16_f32x4() {
+        |s| -> [f32; 4] {
+            let a = f32x4::from_slice(s, &[1.0, -2.0, 0.5, 100.0]);
+            let b = bf16x8::from_slice(
+                s,
+                &core::array::from_fn::<f32, 8, _>(|i| i as f32 * 0.75 - 4.0).map(bf16::from_f32_const),
+            );
+            let c = bf16x8::from_slice(
+                s,
+                &core::array::from_fn::<f32, 8, _>(|i| 2.5 - i as f32).map(bf16::from_f32_const),
+            );
+            a.mmla_bf16(b, c).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32_f16x16() {
+        |s| -> [u32; 16] {
+            let a = f16x16::from_slice(
+                s,
+                &core::array::from_fn::<u16, 16, _>(|i| (i as u16).wrapping_mul(0x1357) ^ 0x8000)
+                    .map(f16::from_bits),
+            );
+            a.cvt_f32().val.map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn zip_high_u8x8() {
+        |s| -> [u8; 8] {
+            let a = u8x8::from_slice(s, &[0, 1, 2, 3, 4, 5, 6, 7]);
+            let b = u8x8::from_slice(s, &[8, 9, 10, 11, 12, 13, 14, 15]);
+            a.zip_high(b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn split_f32x4() {
+        |s| -> [f32; 4] {
+            let (a, b) = f32x4::from_slice(s, &[1.0, 2.0, 3.0, 4.0]).split();
+            (b * a).combine(a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn simd_gt_i32x2() {
+        |s| -> [i32; 2] {
+            let a = i32x2::from_slice(s, &[i32::MIN, 5]);
+            let b = i32x2::from_slice(s, &[0, -5]);
+            a.simd_gt(b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shl_i8x32() {
+        |s| -> [i8; 32] {
+            let a = i8x32::from_slice(s, &core::array::from_fn::<i8, 32, _>(|i| (i as i8).wrapping_mul(37)));
+            (a << 3).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_var_i32x8() {
+        |s| -> [i32; 8] {
+            let a = i32x8::from_slice(s, &[i32::MIN, -1, 1 << 20, -100, 7, i32::MAX, -5, 3]);
+            let b = i32x8::from_slice(s, &[31, 0, 20, 3, 1, 30, 2, 0]);
+            (a >> b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shl_var_u16x16() {
+        |s| -> [u16; 16] {
+            let a = u16x16::from_slice(s, &core::array::from_fn::<u16, 16, _>(|i| 0x1234u16.rotate_left(i as u32)));
+            let b = u16x16::from_slice(s, &core::array::from_fn::<u16, 16, _>(|i| i as u16));
+            (a << b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shr_var_i64x4() {
+        |s| -> [i64; 4] {
+            let a = i64x4::from_slice(s, &[i64::MIN, -9, 1 << 40, 17]);
+            let b = i64x4::from_slice(s, &[63, 2, 40, 1]);
+            (a >> b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn saturating_sub_i8x32() {
+        |s| -> [i8; 32] {
+            let a = i8x32::from_slice(s, &core::array::from_fn::<i8, 32, _>(|i| (i as i8).wrapping_mul(41)));
+            let b = i8x32::from_slice(s, &core::array::from_fn::<i8, 32, _>(|i| (i as i8).wrapping_mul(-77)));
+            a.saturating_sub(b).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn narrow_saturating_u16x32() {
+        |s| -> [u8; 32] {
+            let a = u16x32::from_slice(s, &core::array::from_fn::<u16, 32, _>(|i| (i as u16).wrapping_mul(0x0f3d)));
+            s.narrow_saturating_u16x32(a).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reduce_add_f32x16() {
+        |s| -> [f32; 1] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| (i as f32 - 7.3) * 1.7e7));
+            [a.reduce_add()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reduce_min_max_nan() {
+        |s| -> [u32; 8] {
+            let nan = f32::NAN;
+            let a = f32x4::from_slice(s, &[1.0, nan, 2.0, 3.0]);
+            let b = f32x8::from_slice(s, &[5.0, 1.0, 2.0, 3.0, 4.0, 6.0, 7.0, nan]);
+            let c = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| {
+                if i % 3 == 1 { nan } else { i as f32 - 7.5 }
+            }));
+            let d = f32x8::splat(s, nan);
+            [
+                a.reduce_min(), a.reduce_max(),
+                b.reduce_min(), b.reduce_max(),
+                c.reduce_min(), c.reduce_max(),
+                d.reduce_min(), d.reduce_max(),
+            ]
+            .map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reduce_min_max_nan_f64() {
+        |s| -> [u64; 4] {
+            let a = f64x2::from_slice(s, &[f64::NAN, -3.0]);
+            let b = f64x8::from_slice(s, &[4.0, f64::NAN, -1.0, f64::NAN, f64::NAN, 2.0, f64::NAN, -6.0]);
+            [a.reduce_min(), a.reduce_max(), b.reduce_min(), b.reduce_max()].map(f64::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reduce_min_i8x32() {
+        |s| -> [i8; 1] {
+            let a = i8x32::from_slice(s, &core::array::from_fn::<i8, 32, _>(|i| (i as i8).wrapping_mul(37)));
+            [a.reduce_min()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reduce_and_u16x16() {
+        |s| -> [u16; 1] {
+            let a = u16x16::from_slice(s, &core::array::from_fn::<u16, 16, _>(|i| 0xfff0 | i as u16));
+            [a.reduce_and()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn to_bitmask_mask16x16() {
+        |s| -> [u64; 1] {
+            let a = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| (i as i16).wrapping_mul(0x1357)));
+            [a.simd_lt(0).to_bitmask()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn from_bitmask_mask8x32() {
+        |s| -> [i8; 32] {
+            mask8x32::from_bitmask(s, 0xdead_beef_1234_5678).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn any_all_mask32x16() {
+        |s| -> [bool; 4] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            [a.simd_ge(15.0).any(), a.simd_ge(15.0).all(), a.simd_ge(0.0).all(), a.simd_lt(0.0).any()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn bitmask_round_trip() {
+        |s| -> [u64; 6] {
+            let bits = 0x5a5a_a5a5_0ff0_1234;
+            [
+                mask8x16::from_bitmask(s, bits).to_bitmask(),
+                mask16x8::from_bitmask(s, bits).to_bitmask(),
+                mask32x8::from_bitmask(s, bits).to_bitmask(),
+                mask64x2::from_bitmask(s, bits).to_bitmask(),
+                mask64x4::from_bitmask(s, bits).to_bitmask(),
+                mask16x32::from_bitmask(s, bits).to_bitmask(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shuffle2_u16x8() {
+        |s| -> [u16; 8] {
+            let a = u16x8::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            let b = u16x8::from_slice(s, &[9, 10, 11, 12, 13, 14, 15, 16]);
+            shuffle!(a, b, [15, 0, 8, 3, 3, 12, 7, 1]).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reverse_i8x16() {
+        |s| -> [i8; 16] {
+            let a = i8x16::from_slice(s, &[1, -2, 3, -4, 5, -6, 7, -8, 9, -10, 11, -12, 13, -14, 15, -16]);
+            a.reverse().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lane_moves_128() {
+        |s| -> ([[u8; 16]; 4], [[u16; 8]; 4], [[f32; 4]; 4], [[i64; 2]; 4]) {
+            let a = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| i as u8));
+            let b = u16x8::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            let c = f32x4::from_slice(s, &[1.0, 2.0, 3.0, 4.0]);
+            let d = i64x2::from_slice(s, &[-1, 2]);
+            (
+                [
+                    a.rotate_lanes_left::<0>().into(),
+                    a.rotate_lanes_left::<15>().into(),
+                    a.rotate_lanes_right::<17>().into(),
+                    a.broadcast_lane::<13>().into(),
+                ],
+                [
+                    b.rotate_lanes_left::<3>().into(),
+                    b.rotate_lanes_right::<8>().into(),
+                    b.broadcast_lane::<0>().into(),
+                    b.broadcast_lane::<7>().into(),
+                ],
+                [
+                    c.rotate_lanes_left::<5>().into(),
+                    c.rotate_lanes_right::<3>().into(),
+                    c.broadcast_lane::<1>().into(),
+                    c.broadcast_lane::<3>().into(),
+                ],
+                [
+                    d.rotate_lanes_left::<1>().into(),
+                    d.rotate_lanes_right::<2>().into(),
+                    d.broadcast_lane::<0>().into(),
+                    d.broadcast_lane::<1>().into(),
+                ],
+            )
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lane_moves_256() {
+        |s| -> ([[u8; 32]; 6], [[i16; 16]; 4], [[f32; 8]; 4], [[u64; 4]; 4]) {
+            let a = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| i as u8));
+            let b = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| i as i16 - 8));
+            let c = f32x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let d = u64x4::from_slice(s, &[1, 2, 3, 4]);
+            (
+                [
+                    a.rotate_lanes_left::<5>().into(),
+                    a.rotate_lanes_left::<16>().into(),
+                    a.rotate_lanes_left::<20>().into(),
+                    a.rotate_lanes_right::<33>().into(),
+                    a.broadcast_lane::<1>().into(),
+                    a.broadcast_lane::<30>().into(),
+                ],
+                [
+                    b.rotate_lanes_left::<15>().into(),
+                    b.rotate_lanes_right::<9>().into(),
+                    b.broadcast_lane::<3>().into(),
+                    b.broadcast_lane::<12>().into(),
+                ],
+                [
+                    c.rotate_lanes_left::<3>().into(),
+                    c.rotate_lanes_right::<8>().into(),
+                    c.broadcast_lane::<0>().into(),
+                    c.broadcast_lane::<6>().into(),
+                ],
+                [
+                    d.rotate_lanes_left::<1>().into(),
+                    d.rotate_lanes_right::<3>().into(),
+                    d.broadcast_lane::<2>().into(),
+                    d.broadcast_lane::<3>().into(),
+                ],
+            )
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lane_moves_512() {
+        |s| -> ([[u8; 64]; 6], [[u16; 32]; 4], [[f32; 16]; 4], [[i64; 8]; 4]) {
+            let a = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| i as u8));
+            let b = u16x32::from_slice(s, &core::array::from_fn::<u16, 32, _>(|i| i as u16));
+            let c = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32));
+            let d = i64x8::from_slice(s, &[-1, 2, -3, 4, -5, 6, -7, 8]);
+            (
+                [
+                    a.rotate_lanes_left::<7>().into(),
+                    a.rotate_lanes_left::<36>().into(),
+                    a.rotate_lanes_left::<63>().into(),
+                    a.rotate_lanes_right::<65>().into(),
+                    a.broadcast_lane::<2>().into(),
+                    a.broadcast_lane::<45>().into(),
+                ],
+                [
+                    b.rotate_lanes_left::<17>().into(),
+                    b.rotate_lanes_right::<6>().into(),
+                    b.broadcast_lane::<1>().into(),
+                    b.broadcast_lane::<30>().into(),
+                ],
+                [
+                    c.rotate_lanes_left::<9>().into(),
+                    c.rotate_lanes_right::<16>().into(),
+                    c.broadcast_lane::<4>().into(),
+                    c.broadcast_lane::<15>().into(),
+                ],
+                [
+                    d.rotate_lanes_left::<3>().into(),
+                    d.rotate_lanes_right::<7>().into(),
+                    d.broadcast_lane::<5>().into(),
+                    d.broadcast_lane::<7>().into(),
+                ],
+            )
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lane_moves_64() {
+        |s| -> [[u8; 8]; 4] {
+            let a = u8x8::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            [
+                a.rotate_lanes_left::<3>().into(),
+                a.rotate_lanes_left::<8>().into(),
+                a.rotate_lanes_right::<9>().into(),
+                a.broadcast_lane::<6>().into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn unzip_u8x16() {
+        |s| -> [[u8; 16]; 2] {
+            let a: [u8; 16] = core::array::from_fn(|i| i as u8 * 3);
+            let b: [u8; 16] = core::array::from_fn(|i| 200 - i as u8);
+            let a = u8x16::from_slice(s, &a);
+            let b = u8x16::from_slice(s, &b);
+            [a.unzip_low(b).into(), a.unzip_high(b).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn unzip_f32x8() {
+        |s| -> [[f32; 8]; 2] {
+            let a = f32x8::from_slice(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+            let b = f32x8::from_slice(s, &[-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0]);
+            [a.unzip_low(b).into(), a.unzip_high(b).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reverse_u64x8() {
+        |s| -> [u64; 8] {
+            let a = u64x8::from_slice(s, &[1, 2, 3, 4, 5, 6, 7, u64::MAX]);
+            a.reverse().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn swizzle_dyn_u8x16() {
+        |s| -> [u8; 16] {
+            let table: [u8; 16] = core::array::from_fn(|i| 200 - i as u8);
+            let table = u8x16::from_slice(s, &table);
+            let idx = u8x16::from_slice(s, &[0, 15, 16, 17, 127, 128, 255, 3, 1, 2, 31, 32, 8, 9, 112, 240]);
+            table.swizzle_dyn(idx).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lookup_64_u8x32() {
+        |s| -> [u8; 32] {
+            let table: [u8; 64] = core::array::from_fn(|i| i as u8 ^ 0x5a);
+            let idx: [u8; 32] = core::array::from_fn(|i| (i as u8).wrapping_mul(29));
+            let table = u8x64::from_slice(s, &table);
+            u8x32::from_slice(s, &idx).lookup_64(table).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn lookup_48_u8x32() {
+        |s| -> [u8; 32] {
+            let table: [u8; 48] = core::array::from_fn(|i| i as u8 ^ 0x5a);
+            let idx: [u8; 32] = core::array::from_fn(|i| (i as u8).wrapping_mul(29));
+            let table = [0, 16, 32].map(|i| u8x16::from_slice(s, &table[i..i + 16]));
+            u8x32::from_slice(s, &idx).lookup_48(table).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn rounding_f32x8() {
+        |s| -> [[f32; 8]; 3] {
+            let a = f32x8::from_slice(s, &[-2.5, -1.5, -0.5, 0.5, 1.5, 1e10, -0.3, -f32::INFINITY]);
+            [a.ceil().into(), a.round().into(), a.round_ties_even().into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_i32_round_f32x16() {
+        |s| -> [i32; 16] {
+            let a = f32x16::from_slice(
+                s,
+                &[-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 3e9, -3e9, f32::NAN, 1.49, -1.51, 7.5, 8.5, 0.0, -0.0, 100.5],
+            );
+            a.cvt_i32_round().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn round_f64x8() {
+        |s| -> [f64; 8] {
+            let a = f64x8::from_slice(s, &[-2.5, -1.5, -0.5, 0.5, 1.5, 1e300, -0.3, 0.49999999999999994]);
+            a.round().into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn recip_rsqrt_special_f32x4() {
+        |s| -> [u32; 16] {
+            let a = f32x4::from_slice(s, &[0.0, -0.0, f32::INFINITY, f32::NEG_INFINITY]);
+            let recip = a.recip(0).combine(a.recip(2));
+            let rsqrt = a.abs().rsqrt(0).combine(a.abs().rsqrt(2));
+            <[f32; 16]>::from(recip.combine(rsqrt)).map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn recip_rsqrt_out_of_range_f32x8() {
+        |s| -> [u32; 16] {
+            // Only these lanes are divided exactly, so they match the fallback level.
+            let a = f32x8::from_slice(s, &[1e-38, -5e-39, 1e-40, 1e-45, 1e38, -3e38, f32::MAX, 2e38]);
+            let b = f32x8::from_slice(s, &[1e-38, 5e-39, 1e-40, 1e-45, 2e-39, 7e-39, 1.1e-38, 1e-44]);
+            <[f32; 16]>::from(a.recip(2).combine(b.rsqrt(2))).map(f32::to_bits)
+        }
+    }
+}
+
+test_x86_parity! {
+    fn float_classification_f32x8() {
+        |s| -> [[i32; 8]; 6] {
+            let a = f32x8::from_slice(
+                s,
+                &[f32::NAN, -f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0, 1e-40, f32::MIN_POSITIVE, -1.0],
+            );
+            [
+                a.simd_ne(a).into(),
+                a.is_nan().into(),
+                a.is_finite().into(),
+                a.is_infinite().into(),
+                a.is_subnormal().into(),
+                a.is_sign_negative().into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn integer_abs_diff_i64x4() {
+        |s| -> [[u64; 4]; 4] {
+            let a = i64x4::from_slice(s, &[i64::MIN, -7, 0, 12]);
+            let b = i64x4::from_slice(s, &[i64::MAX, 7, 5, -1]);
+            let c = u64x4::from_slice(s, &[0, u64::MAX, 3, 9]);
+            let d = u64x4::from_slice(s, &[u64::MAX, 0, 9, 3]);
+            [
+                <[i64; 4]>::from(a.abs()).map(|x| x as u64),
+                <[i64; 4]>::from(-a).map(|x| x as u64),
+                a.abs_diff(b).into(),
+                c.abs_diff(d).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn integer_abs_avg_round_u8x32() {
+        |s| -> [[u8; 32]; 3] {
+            let a = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| (i * 37) as u8));
+            let b = u8x32::from_slice(s, &core::array::from_fn::<u8, 32, _>(|i| (255 - i * 7) as u8));
+            let c = i8x32::from_slice(s, &core::array::from_fn::<i8, 32, _>(|i| (i * 53) as i8));
+            [
+                a.avg_round(b).into(),
+                <[i8; 32]>::from(c.abs()).map(|x| x as u8),
+                c.abs_diff(-c).into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widening_multiplies_i16x16() {
+        |s| -> [[i32; 16]; 3] {
+            let a = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| (i as i16 - 3).wrapping_mul(4099)));
+            let b = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| i16::MIN.wrapping_add((i as i16).wrapping_mul(1021))));
+            let dot = <[i32; 8]>::from(a.dot_i16(b));
+            let high = <[i16; 16]>::from(a.mul_high(b));
+            let q15 = <[i16; 16]>::from(b.mul_q15_round_sat(b));
+            [
+                a.mul_widen(b).into(),
+                core::array::from_fn(|i| dot[i % 8]),
+                core::array::from_fn(|i| (high[i] as i32) << 16 | (q15[i] as u16 as i32)),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widening_multiplies_i16x32() {
+        |s| -> [[i16; 32]; 2] {
+            let a = i16x32::from_slice(s, &core::array::from_fn::<i16, 32, _>(|i| i16::MIN.wrapping_add((i as i16).wrapping_mul(2053))));
+            [a.mul_q15_round_sat(a).into(), a.mul_high(a.reverse()).into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widening_multiplies_u8x64() {
+        |s| -> [[u8; 64]; 2] {
+            let a = u8x64::from_slice(s, &core::array::from_fn::<u8, 64, _>(|i| (i as u8).wrapping_mul(73) ^ 0x81));
+            let b = i8x64::from_slice(s, &core::array::from_fn::<i8, 64, _>(|i| (i as i8).wrapping_mul(-45) ^ 0x40));
+            [
+                a.mul_high(a.reverse()).into(),
+                <[i8; 64]>::from(b.mul_high(b.reverse())).map(|x| x as u8),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widening_multiplies_u8x16() {
+        |s| -> [u16; 16] {
+            let a = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| (i as u8).wrapping_mul(73) ^ 0x81));
+            a.mul_widen(a.reverse()).into()
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widen_narrow_i16x16() {
+        |s| -> [[i16; 16]; 3] {
+            let a = i16x16::from_slice(s, &core::array::from_fn::<i16, 16, _>(|i| (i as i16 - 8).wrapping_mul(4099)));
+            let b = i8x16::from_slice(s, &core::array::from_fn::<i8, 16, _>(|i| (i as i8).wrapping_mul(-45) ^ 0x40));
+            let narrow = <[i8; 16]>::from(a.narrow());
+            let saturated = <[i8; 16]>::from(a.narrow_saturating());
+            [
+                b.widen().into(),
+                core::array::from_fn(|i| narrow[i] as i16),
+                core::array::from_fn(|i| saturated[i] as i16),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widen_narrow_u32x8() {
+        |s| -> [[u16; 8]; 4] {
+            let a = u32x8::from_slice(s, &core::array::from_fn::<u32, 8, _>(|i| (i as u32).wrapping_mul(0x9e37_79b9) >> (i % 3 * 8)));
+            let b = u16x8::from_slice(s, &core::array::from_fn::<u16, 8, _>(|i| (i as u16 * 9001) ^ 0xf00f));
+            let low = <[u32; 4]>::from(b.widen_low());
+            let high = <[u32; 4]>::from(b.widen_high());
+            [
+                a.narrow().into(),
+                a.narrow_saturating().into(),
+                core::array::from_fn(|i| low[i % 4] as u16),
+                core::array::from_fn(|i| high[i % 4] as u16),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn widen_narrow_i64x4() {
+        |s| -> [[i32; 4]; 3] {
+            let a = i64x4::from_slice(s, &[i64::MIN, -5, 1 << 40, 0x7fff_ffff]);
+            let b = i32x4::from_slice(s, &[i32::MIN, -5, 7, i32::MAX]);
+            let wide = <[i64; 4]>::from(b.widen());
+            [
+                a.narrow().into(),
+                a.narrow_saturating().into(),
+                wide.map(|x| (x >> 1) as i32),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f64x4_i64x4() {
+        |s| -> [[i64; 4]; 4] {
+            let a = f64x4::from_slice(s, &[f64::NAN, -1e19, 9.3e18, -2.5]);
+            let b = i64x4::from_slice(s, &[i64::MIN, i64::MAX, (1 << 53) + 1, -7]);
+            let c = u64x4::from_slice(s, &[u64::MAX, 1 << 63, (1 << 53) + 1, 7]);
+            [
+                a.cvt_i64().into(),
+                <[u64; 4]>::from(a.cvt_u64_round()).map(|x| x as i64),
+                <[f64; 4]>::from(b.cvt_f64()).map(|x| x as i64),
+                <[f64; 4]>::from(c.cvt_f64()).map(f64::to_bits).map(|x| x as i64),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn cvt_f32x16_u8x16() {
+        |s| -> [[u8; 16]; 3] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| (i as f32 - 4.0) * 37.5));
+            let b = u8x16::from_slice(s, &core::array::from_fn::<u8, 16, _>(|i| (i as u8).wrapping_mul(37)));
+            let i8s = <[i8; 16]>::from(a.cvt_i8_round());
+            [
+                a.cvt_u8_round().into(),
+                i8s.map(|x| x as u8),
+                (b.cvt_f32() * (1.0 / 255.0) * 255.0).cvt_u8_round().into(),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn reinterpret_f32x16_f64x8() {
+        |s| -> [[u64; 8]; 2] {
+            let a = f32x16::from_slice(s, &core::array::from_fn::<f32, 16, _>(|i| i as f32 * -1.25));
+            let b = a.reinterpret_i32().reinterpret_f64();
+            [b.reinterpret_u64().into(), a.reinterpret_u16().reinterpret_u64().into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn max_min_precise_nan() {
+        |s| -> [[u32; 16]; 4] {
+            let nan = f32::NAN;
+            let a = f32x16::from_slice(s, &[
+                nan, 1.0, nan, -2.0, 3.0, nan, -4.0, 5.0,
+                nan, f32::INFINITY, nan, -f32::INFINITY, 0.5, nan, nan, -0.5,
+            ]);
+            let b = f32x16::from_slice(s, &[
+                1.0, nan, nan, 2.0, nan, -3.0, 4.0, -5.0,
+                -1.0, nan, 7.0, nan, nan, 0.25, nan, 0.5,
+            ]);
+            // The 128 and 256 bit vectors, as well as the 512 bit ones.
+            let (a0, a1) = s.split_f32x16(a);
+            let (b0, b1) = s.split_f32x16(b);
+            let (a00, a01) = s.split_f32x8(a0);
+            let (b00, b01) = s.split_f32x8(b0);
+            let narrow = s.combine_f32x8(
+                s.combine_f32x4(a00.max_precise(b00), a01.min_precise(b01)),
+                a1.max_precise(b1),
+            );
+            [
+                <[f32; 16]>::from(a.max_precise(b)).map(f32::to_bits),
+                <[f32; 16]>::from(a.min_precise(b)).map(f32::to_bits),
+                <[f32; 16]>::from(narrow).map(f32::to_bits),
+                <[f32; 16]>::from(b.max_precise(a)).map(f32::to_bits),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn max_min_precise_nan_f64x4() {
+        |s| -> [[u64; 4]; 2] {
+            let a = f64x4::from_slice(s, &[f64::NAN, 1.0, f64::NAN, -2.0]);
+            let b = f64x4::from_slice(s, &[1.0, f64::NAN, f64::NAN, 2.0]);
+            [
+                <[f64; 4]>::from(a.max_precise(b)).map(f64::to_bits),
+                <[f64; 4]>::from(a.min_precise(b)).map(f64::to_bits),
+            ]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shift_counts_u8x16() {
+        |s| -> [[u8; 16]; 4] {
+            let a = u8x16::splat(s, 0xa5);
+            let b = u8x16::from_slice(s, &[0, 7, 8, 255, 0, 7, 8, 255, 0, 7, 8, 255, 0, 7, 8, 255]);
+            let uniform: [u8; 16] = core::array::from_fn(|i| {
+                let c = [0, 7, 8, 255][i % 4];
+                if i < 8 { (a << c)[0] } else { (a >> c)[0] }
+            });
+            let shr_i8 = (a.reinterpret_i8() >> 8).reinterpret_u8();
+            [(a << b).into(), (a >> b).into(), uniform, shr_i8.into()]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shift_counts_i8x32() {
+        |s| -> [[i8; 32]; 3] {
+            let a = i8x32::splat(s, -0x5b);
+            let counts: [i8; 32] = core::array::from_fn(|i| [0, 7, 8, -1][i % 4]);
+            let b = i8x32::from_slice(s, &counts);
+            let uniform: [i8; 32] = core::array::from_fn(|i| {
+                let c = [0, 7, 8, 255][i % 4];
+                if i < 16 { (a << c)[0] } else { (a >> c)[0] }
+            });
+            [(a << b).into(), (a >> b).into(), uniform]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shift_counts_i16x8() {
+        |s| -> [[i16; 8]; 3] {
+            let a = i16x8::splat(s, -0x5a5b);
+            let b = i16x8::from_slice(s, &[0, 15, 16, 255, 0, 15, 16, -1]);
+            let uniform: [i16; 8] = core::array::from_fn(|i| {
+                let c = [0, 15, 16, 255][i % 4];
+                if i < 4 { (a << c)[0] } else { (a >> c)[0] }
+            });
+            [(a << b).into(), (a >> b).into(), uniform]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shift_counts_u32x8() {
+        |s| -> [[u32; 8]; 3] {
+            let a = u32x8::splat(s, 0xa5a5_a5a5);
+            let b = u32x8::from_slice(s, &[0, 31, 32, 255, 0, 31, 32, u32::MAX]);
+            let uniform: [u32; 8] = core::array::from_fn(|i| {
+                let c = [0, 31, 32, 255][i % 4];
+                if i < 4 { (a << c)[0] } else { (a >> c)[0] }
+            });
+            [(a << b).into(), (a >> b).into(), uniform]
+        }
+    }
+}
+
+test_x86_parity! {
+    fn shift_counts_i64x4() {
+        |s| -> [[i64; 4]; 4] {
+            let a = i64x4::splat(s, -0x5a5a_5a5a_5a5a_5a5b);
+            let b = i64x4::from_slice(s, &[0, 63, 64, 255]);
+            let shl = [0, 63, 64, 255].map(|c| (a << c)[0]);
+            let shr = [0, 63, 64, 255].map(|c| (a >> c)[0]);
+            [(a << b).into(), (a >> b).into(), shl, shr]
+        }
+    }
+}